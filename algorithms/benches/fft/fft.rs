@@ -14,7 +14,7 @@
 
 extern crate criterion;
 
-use snarkvm_algorithms::fft::{DensePolynomial, EvaluationDomain};
+use snarkvm_algorithms::fft::{six_step, DensePolynomial, EvaluationDomain, FFTParallelism};
 use snarkvm_curves::bls12_377::Fr as Bls12_377_Fr;
 use snarkvm_fields::PrimeField;
 use snarkvm_utilities::TestRng;
@@ -114,5 +114,32 @@ fn bench_bls12_377(c: &mut Criterion) {
     fft_benches::<Bls12_377_Fr>(c, "BLS12-377 - radix-2");
 }
 
-criterion_group!(benches, bench_bls12_377);
+/// Compares the radix-2 FFT with the six-step FFT on large domains, for an increasing number of threads.
+fn bench_bls12_377_six_step(c: &mut Criterion) {
+    let max_threads = std::thread::available_parallelism().map_or(1, |threads| threads.get());
+    let mut num_threads = vec![1];
+    while *num_threads.last().unwrap() < max_threads {
+        num_threads.push(min(2 * num_threads.last().unwrap(), max_threads));
+    }
+
+    for (name, min_log_size) in [("radix-2", u32::MAX), ("six-step", 20)] {
+        // Note: The FFTs run on the benchmark's thread pool, which sets the number of threads.
+        six_step::set_parallelism(FFTParallelism { min_log_size, num_threads: 0 });
+        let mut group = c.benchmark_group(format!("BLS12-377 - {name} - fft_in_place"));
+        group.sample_size(10);
+        for degree in size_range(BENCHMARK_LOG_INTERVAL_DEGREE, 1 << 20, BENCHMARK_MAX_DEGREE) {
+            let (domain, mut a) = create_evaluation_domain::<Bls12_377_Fr>(degree);
+            for num_threads in &num_threads {
+                let pool = rayon::ThreadPoolBuilder::new().num_threads(*num_threads).build().unwrap();
+                group.bench_with_input(BenchmarkId::new(degree.to_string(), num_threads), &degree, |b, _| {
+                    b.iter(|| pool.install(|| domain.fft_in_place(&mut a)));
+                });
+            }
+        }
+        group.finish();
+    }
+    six_step::set_parallelism(FFTParallelism::default());
+}
+
+criterion_group!(benches, bench_bls12_377, bench_bls12_377_six_step);
 criterion_main!(benches);
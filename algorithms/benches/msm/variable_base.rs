@@ -55,10 +55,24 @@ fn variable_base_edwards_bls12(c: &mut Criterion) {
     }
 }
 
+fn fixed_base_msm_bls12_377(c: &mut Criterion) {
+    use snarkvm_curves::bls12_377::{Fr, G1Affine};
+    let (bases, scalars) = create_scalar_bases::<G1Affine, Fr>(1_000_000);
+
+    for size in [10_000, 100_000, 1_000_000] {
+        for num_tables in [1, 4, 16] {
+            let fixed_base = FixedBaseMsm::new(&bases[..size], FixedBaseMsmConfig { num_tables, window_size: None });
+            c.bench_function(&format!("FixedBase MSM on BLS12-377 ({size}, {num_tables} tables)"), |b| {
+                b.iter(|| fixed_base.msm(&scalars[..size]))
+            });
+        }
+    }
+}
+
 criterion_group! {
     name = variable_base_group;
     config = Criterion::default().sample_size(10);
-    targets = variable_base_bls12_377, variable_base_edwards_bls12
+    targets = variable_base_bls12_377, variable_base_edwards_bls12, fixed_base_msm_bls12_377
 }
 
 criterion_main!(variable_base_group);
@@ -18,5 +18,8 @@ pub use poseidon::*;
 pub mod sha256;
 pub use sha256::*;
 
+pub mod transcript;
+pub use transcript::*;
+
 #[cfg(test)]
 pub mod tests;
@@ -0,0 +1,190 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::AlgebraicSponge;
+use snarkvm_fields::{PrimeField, ToConstraintField};
+
+use parking_lot::Mutex;
+use serde::{Serialize, Serializer};
+use smallvec::SmallVec;
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+/// The operation of an entry in a Fiat-Shamir transcript.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TranscriptOperation {
+    /// Absorbs elements of the sponge field.
+    AbsorbNative,
+    /// Absorbs elements of another field.
+    AbsorbNonnative,
+    /// Absorbs bytes.
+    AbsorbBytes,
+    /// Squeezes elements of the sponge field.
+    SqueezeNative,
+    /// Squeezes elements of another field.
+    SqueezeNonnative,
+    /// Squeezes elements of another field, of 168 bits.
+    SqueezeShortNonnative,
+}
+
+/// An entry in a Fiat-Shamir transcript.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct TranscriptEntry {
+    /// The index of the sponge, in the order in which the sponges of the transcript were initialized.
+    pub sponge: usize,
+    /// The operation.
+    pub operation: TranscriptOperation,
+    /// The absorbed or squeezed elements as decimal integers, or the absorbed bytes as a hex string.
+    pub elements: Vec<String>,
+}
+
+/// A Fiat-Shamir transcript, which is shared by the sponges that record into it.
+///
+/// The transcript serializes as the list of its entries, in the order in which they were recorded.
+#[derive(Clone, Debug, Default)]
+pub struct Transcript {
+    /// The entries of the transcript.
+    entries: Arc<Mutex<Vec<TranscriptEntry>>>,
+    /// The number of sponges initialized for the transcript.
+    num_sponges: Arc<AtomicUsize>,
+}
+
+impl Transcript {
+    /// Initializes a new, empty transcript.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the entries of the transcript.
+    pub fn entries(&self) -> Vec<TranscriptEntry> {
+        self.entries.lock().clone()
+    }
+
+    /// Returns the number of sponges initialized for the transcript.
+    pub fn num_sponges(&self) -> usize {
+        self.num_sponges.load(Ordering::SeqCst)
+    }
+
+    /// Removes the entries of the transcript, and resets the sponge indices.
+    pub fn clear(&self) {
+        self.entries.lock().clear();
+        self.num_sponges.store(0, Ordering::SeqCst);
+    }
+
+    /// Records the given entry.
+    fn record(&self, sponge: usize, operation: TranscriptOperation, elements: Vec<String>) {
+        self.entries.lock().push(TranscriptEntry { sponge, operation, elements });
+    }
+}
+
+impl Serialize for Transcript {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.entries.lock().serialize(serializer)
+    }
+}
+
+/// The parameters of a `TranscriptSponge`, which are the parameters of the inner sponge,
+/// and the transcript to record into.
+#[derive(Clone, Debug)]
+pub struct TranscriptParameters<P> {
+    /// The parameters of the inner sponge.
+    pub parameters: P,
+    /// The transcript to record into.
+    pub transcript: Transcript,
+}
+
+impl<P> TranscriptParameters<P> {
+    /// Initializes new parameters, which record into a new transcript.
+    pub fn new(parameters: P) -> Self {
+        Self { parameters, transcript: Transcript::new() }
+    }
+}
+
+/// A sponge that records every absorbed and squeezed element into a transcript,
+/// and otherwise behaves as the inner sponge.
+///
+/// As such, `VarunaSNARK` instantiated with a `TranscriptSponge` produces and accepts the same proofs
+/// as with the inner sponge, while exporting the full Fiat-Shamir transcript of each proof and verification.
+#[derive(Clone, Debug)]
+pub struct TranscriptSponge<S> {
+    /// The inner sponge.
+    sponge: S,
+    /// The index of the sponge in the transcript.
+    index: usize,
+    /// The transcript to record into.
+    transcript: Transcript,
+}
+
+impl<S> TranscriptSponge<S> {
+    /// Records the given operation on the given elements.
+    fn record<T: ToString>(&self, operation: TranscriptOperation, elements: impl IntoIterator<Item = T>) {
+        self.transcript.record(self.index, operation, elements.into_iter().map(|element| element.to_string()).collect())
+    }
+}
+
+impl<F: PrimeField, const RATE: usize, S: AlgebraicSponge<F, RATE>> AlgebraicSponge<F, RATE> for TranscriptSponge<S> {
+    type Parameters = TranscriptParameters<S::Parameters>;
+
+    fn sample_parameters() -> Self::Parameters {
+        TranscriptParameters::new(S::sample_parameters())
+    }
+
+    fn new_with_parameters(parameters: &Self::Parameters) -> Self {
+        let index = parameters.transcript.num_sponges.fetch_add(1, Ordering::SeqCst);
+        Self {
+            sponge: S::new_with_parameters(&parameters.parameters),
+            index,
+            transcript: parameters.transcript.clone(),
+        }
+    }
+
+    fn absorb_native_field_elements<T: ToConstraintField<F>>(&mut self, elements: &[T]) {
+        // Note: The elements are recorded as they are absorbed by the inner sponge.
+        let native = elements.iter().flat_map(|element| element.to_field_elements().unwrap_or_default());
+        self.record(TranscriptOperation::AbsorbNative, native);
+        self.sponge.absorb_native_field_elements(elements)
+    }
+
+    fn absorb_nonnative_field_elements<Target: PrimeField>(&mut self, elements: impl IntoIterator<Item = Target>) {
+        let elements = elements.into_iter().collect::<Vec<_>>();
+        self.record(TranscriptOperation::AbsorbNonnative, &elements);
+        self.sponge.absorb_nonnative_field_elements(elements)
+    }
+
+    fn absorb_bytes(&mut self, elements: &[u8]) {
+        self.record(TranscriptOperation::AbsorbBytes, [hex::encode(elements)]);
+        self.sponge.absorb_bytes(elements)
+    }
+
+    fn squeeze_native_field_elements(&mut self, num: usize) -> SmallVec<[F; 10]> {
+        let elements = self.sponge.squeeze_native_field_elements(num);
+        self.record(TranscriptOperation::SqueezeNative, &elements);
+        elements
+    }
+
+    fn squeeze_nonnative_field_elements<Target: PrimeField>(&mut self, num: usize) -> SmallVec<[Target; 10]> {
+        let elements = self.sponge.squeeze_nonnative_field_elements(num);
+        self.record(TranscriptOperation::SqueezeNonnative, &elements);
+        elements
+    }
+
+    fn squeeze_short_nonnative_field_elements<Target: PrimeField>(&mut self, num: usize) -> SmallVec<[Target; 10]> {
+        let elements = self.sponge.squeeze_short_nonnative_field_elements(num);
+        self.record(TranscriptOperation::SqueezeShortNonnative, &elements);
+        elements
+    }
+}
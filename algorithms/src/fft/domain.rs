@@ -24,6 +24,8 @@
 //! This allows us to perform polynomial operations in O(n)
 //! by performing an O(n log n) FFT over such a domain.
 
+#[cfg(not(feature = "serial"))]
+use crate::fft::six_step;
 use crate::{
     cfg_chunks_mut,
     cfg_into_iter,
@@ -542,6 +544,12 @@ impl<F: FftField> EvaluationDomain<F> {
         use FFTOrder::*;
         let pc = pre_comp.precomputation_for_subdomain(self).unwrap();
 
+        // Use the six-step FFT on large domains, as it parallelizes better than the radix-2 FFT.
+        #[cfg(not(feature = "serial"))]
+        if ord == II && x_s.len() == self.size() && six_step::is_enabled(x_s.len()) {
+            return self.six_step_fft_in_place_with_pc(x_s, &pc);
+        }
+
         let log_len = log2(x_s.len());
 
         if ord == OI {
@@ -567,6 +575,12 @@ impl<F: FftField> EvaluationDomain<F> {
         use FFTOrder::*;
         let pc = pre_comp.precomputation_for_subdomain(self).unwrap();
 
+        // Use the six-step FFT on large domains, as it parallelizes better than the radix-2 FFT.
+        #[cfg(not(feature = "serial"))]
+        if ord == II && x_s.len() == self.size() && six_step::is_enabled(x_s.len()) {
+            return self.six_step_ifft_in_place_with_pc(x_s, &pc);
+        }
+
         let log_len = log2(x_s.len());
 
         if ord == II {
@@ -580,6 +594,37 @@ impl<F: FftField> EvaluationDomain<F> {
         }
     }
 
+    /// Computes the in-order FFT of `x_s` with the six-step algorithm, where `x_s` has the size of the domain.
+    #[cfg(not(feature = "serial"))]
+    fn six_step_fft_in_place_with_pc<T: DomainCoeff<F>>(&self, x_s: &mut [T], pc: &FFTPrecomputation<F>) {
+        let (n1, n2) = six_step::split(x_s.len());
+        let (first, second) = (Self::new(n2).unwrap(), Self::new(n1).unwrap());
+        let first_pc = pc.precomputation_for_subdomain(&first).unwrap();
+        let second_pc = pc.precomputation_for_subdomain(&second).unwrap();
+        six_step::fft_in_place(
+            x_s,
+            &pc.roots,
+            |row| first.fft_helper_in_place_with_pc(row, FFTOrder::II, &first_pc),
+            |row| second.fft_helper_in_place_with_pc(row, FFTOrder::II, &second_pc),
+        );
+    }
+
+    /// Computes the in-order IFFT of `x_s` with the six-step algorithm, where `x_s` has the size of the domain.
+    /// The results here must all be divided by |x_s|, which is left up to the caller to do.
+    #[cfg(not(feature = "serial"))]
+    fn six_step_ifft_in_place_with_pc<T: DomainCoeff<F>>(&self, x_s: &mut [T], pc: &IFFTPrecomputation<F>) {
+        let (n1, n2) = six_step::split(x_s.len());
+        let (first, second) = (Self::new(n2).unwrap(), Self::new(n1).unwrap());
+        let first_pc = pc.precomputation_for_subdomain(&first).unwrap();
+        let second_pc = pc.precomputation_for_subdomain(&second).unwrap();
+        six_step::fft_in_place(
+            x_s,
+            &pc.inverse_roots,
+            |row| first.ifft_helper_in_place_with_pc(row, FFTOrder::II, &first_pc),
+            |row| second.ifft_helper_in_place_with_pc(row, FFTOrder::II, &second_pc),
+        );
+    }
+
     /// Computes the first `self.size / 2` roots of unity for the entire domain.
     /// e.g. for the domain [1, g, g^2, ..., g^{n - 1}], it computes
     // [1, g, g^2, ..., g^{(n/2) - 1}]
@@ -933,6 +978,8 @@ impl<F: FftField> IFFTPrecomputation<F> {
 
 #[cfg(test)]
 mod tests {
+    #[cfg(not(feature = "serial"))]
+    use crate::fft::domain::derange;
     #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
     use crate::fft::domain::FFTOrder;
     use crate::fft::{DensePolynomial, EvaluationDomain};
@@ -1095,6 +1142,34 @@ mod tests {
         }
     }
 
+    /// Tests that the six-step FFTs match the radix-2 FFTs.
+    #[cfg(not(feature = "serial"))]
+    #[test]
+    fn test_six_step_fft_correctness() {
+        let mut rng = TestRng::default();
+
+        for log_domain_size in 2..12 {
+            let domain = EvaluationDomain::<Fr>::new(1 << log_domain_size).unwrap();
+            let (fft_pc, ifft_pc) = (domain.precompute_fft(), domain.precompute_ifft());
+            let coeffs = (0..domain.size()).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+
+            // Compute the radix-2 FFT.
+            let mut expected = coeffs.clone();
+            domain.io_helper_with_roots(&mut expected, &fft_pc.roots);
+            derange(&mut expected);
+
+            // Ensure the six-step FFT matches.
+            let mut candidate = coeffs.clone();
+            domain.six_step_fft_in_place_with_pc(&mut candidate, &fft_pc);
+            assert_eq!(expected, candidate, "domain size = {}", domain.size());
+
+            // Ensure the six-step IFFT inverts the FFT.
+            domain.six_step_ifft_in_place_with_pc(&mut candidate, &ifft_pc);
+            candidate.iter_mut().for_each(|value| *value *= domain.size_inv);
+            assert_eq!(coeffs, candidate, "domain size = {}", domain.size());
+        }
+    }
+
     /// Tests that FFT precomputation is correctly subdomained
     #[test]
     fn test_fft_precomputation() {
@@ -24,6 +24,11 @@ pub use evaluations::Evaluations;
 pub mod polynomial;
 pub use polynomial::{DensePolynomial, Polynomial, SparsePolynomial};
 
+#[cfg(not(feature = "serial"))]
+pub mod six_step;
+#[cfg(not(feature = "serial"))]
+pub use six_step::FFTParallelism;
+
 #[cfg(test)]
 mod tests;
 
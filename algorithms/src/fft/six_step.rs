@@ -0,0 +1,194 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! This module implements the six-step FFT, which splits an FFT of size `n = n1 * n2`
+//! into `n1` independent FFTs of size `n2` and `n2` independent FFTs of size `n1`,
+//! with transposes in between. Unlike the butterflies of the radix-2 FFT, whose
+//! parallelism vanishes in the rounds with a large gap, each of these smaller FFTs
+//! fits in cache and runs on its own thread, which makes better use of the cores
+//! on large evaluation domains.
+
+use crate::fft::{domain::log2, DomainCoeff};
+use snarkvm_fields::FftField;
+
+use core::ops::Range;
+use parking_lot::RwLock;
+use rayon::prelude::*;
+
+/// The configuration of the six-step FFT.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct FFTParallelism {
+    /// The minimum log-size of a domain at which the six-step FFT is used instead of the radix-2 FFT.
+    pub min_log_size: u32,
+    /// The number of threads used by the six-step FFT, or `0` to use the current thread pool.
+    pub num_threads: usize,
+}
+
+impl FFTParallelism {
+    /// The default configuration, which uses the six-step FFT on domains of size `2^20` and above.
+    pub const DEFAULT: Self = Self { min_log_size: 20, num_threads: 0 };
+}
+
+impl Default for FFTParallelism {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// The selected configuration of the six-step FFT.
+static PARALLELISM: RwLock<FFTParallelism> = parking_lot::const_rwlock(FFTParallelism::DEFAULT);
+
+/// Returns the selected configuration of the six-step FFT.
+pub fn parallelism() -> FFTParallelism {
+    *PARALLELISM.read()
+}
+
+/// Selects the given configuration of the six-step FFT.
+pub fn set_parallelism(parallelism: FFTParallelism) {
+    *PARALLELISM.write() = parallelism;
+}
+
+/// The number of output rows that are transposed by each thread.
+const TRANSPOSE_BAND_SIZE: usize = 32;
+
+/// The maximum number of elements in a block that is transposed directly, instead of being split.
+const TRANSPOSE_BLOCK_SIZE: usize = 64;
+
+/// Returns `true` if the six-step FFT is used for an FFT of the given size.
+pub(super) fn is_enabled(size: usize) -> bool {
+    // Note: The six-step FFT requires `n1, n2 >= 2`, so that it recurses on strictly smaller FFTs.
+    size.is_power_of_two() && log2(size) >= parallelism().min_log_size.max(2)
+}
+
+/// Returns `(n1, n2)`, where `n1 * n2` is the given size.
+/// The six-step FFT performs `n1` FFTs of size `n2`, followed by `n2` FFTs of size `n1`.
+pub(super) fn split(size: usize) -> (usize, usize) {
+    let log_n1 = log2(size) / 2;
+    (1 << log_n1, size >> log_n1)
+}
+
+/// Computes the FFT of `x_s` in place, where `roots` are the first `x_s.len() / 2` powers of the root of unity.
+/// The `first_fft` is an in-order FFT of size `n2`, and the `second_fft` is an in-order FFT of size `n1`,
+/// over the subdomains of the given root of unity.
+pub(super) fn fft_in_place<F: FftField, T: DomainCoeff<F>>(
+    x_s: &mut [T],
+    roots: &[F],
+    first_fft: impl Fn(&mut [T]) + Send + Sync,
+    second_fft: impl Fn(&mut [T]) + Send + Sync,
+) {
+    let (n1, n2) = split(x_s.len());
+    let half = x_s.len() / 2;
+    debug_assert_eq!(roots.len(), half);
+
+    execute(parallelism().num_threads, || {
+        let mut scratch = vec![T::zero(); x_s.len()];
+
+        // 1. View the input as an `n2 x n1` matrix, and transpose it,
+        // so that row `j1` holds the input elements `j1 + n1 * j2`.
+        transpose(x_s, &mut scratch, n2, n1);
+
+        // 2. Perform the FFT of each row, and multiply entry `k2` of row `j1` by the twiddle factor `w^(j1 * k2)`.
+        scratch.par_chunks_mut(n2).enumerate().for_each(|(j1, row)| {
+            first_fft(row);
+            for (k2, x) in row.iter_mut().enumerate().skip(1) {
+                // Note: `w^(n / 2) = -1`, so the powers above `half` are the negated powers below `half`.
+                let index = j1 * k2;
+                *x *= if index < half { roots[index] } else { -roots[index - half] };
+            }
+        });
+
+        // 3. Transpose the `n1 x n2` matrix, so that row `k2` holds the `k2`-th entry of each FFT.
+        transpose(&scratch, x_s, n1, n2);
+
+        // 4. Perform the FFT of each row.
+        x_s.par_chunks_mut(n1).for_each(&second_fft);
+
+        // 5. Transpose the `n2 x n1` matrix, so that the output element `k2 + n2 * k1` is in order.
+        transpose(x_s, &mut scratch, n2, n1);
+        x_s.copy_from_slice(&scratch);
+    })
+}
+
+/// Runs the given function on the given number of threads, or on the current thread pool if `num_threads` is `0`.
+fn execute<R: Send>(num_threads: usize, f: impl FnOnce() -> R + Send) -> R {
+    match num_threads {
+        0 => f(),
+        num_threads => match rayon::ThreadPoolBuilder::new().num_threads(num_threads).build() {
+            Ok(pool) => pool.install(f),
+            Err(_) => f(),
+        },
+    }
+}
+
+/// Transposes the `rows x cols` row-major matrix in `src` into the `cols x rows` row-major matrix in `dst`.
+///
+/// The output is split into bands of rows, which are transposed in parallel. Each band is transposed
+/// by recursively halving the larger dimension, so that the blocks fit in every level of the cache.
+pub(super) fn transpose<T: Copy + Send + Sync>(src: &[T], dst: &mut [T], rows: usize, cols: usize) {
+    assert_eq!(src.len(), rows * cols);
+    assert_eq!(dst.len(), rows * cols);
+
+    dst.par_chunks_mut(rows * TRANSPOSE_BAND_SIZE).enumerate().for_each(|(band, dst)| {
+        let start = band * TRANSPOSE_BAND_SIZE;
+        let end = core::cmp::min(start + TRANSPOSE_BAND_SIZE, cols);
+        transpose_block(src, dst, rows, cols, 0..rows, start..end, start);
+    });
+}
+
+/// Transposes the block of `src` with the given rows and columns into `dst`,
+/// which holds the output rows starting at `offset`.
+fn transpose_block<T: Copy>(
+    src: &[T],
+    dst: &mut [T],
+    rows: usize,
+    cols: usize,
+    block_rows: Range<usize>,
+    block_cols: Range<usize>,
+    offset: usize,
+) {
+    if block_rows.len() * block_cols.len() <= TRANSPOSE_BLOCK_SIZE {
+        for col in block_cols {
+            for row in block_rows.clone() {
+                dst[(col - offset) * rows + row] = src[row * cols + col];
+            }
+        }
+    } else if block_rows.len() >= block_cols.len() {
+        let mid = block_rows.start + block_rows.len() / 2;
+        transpose_block(src, dst, rows, cols, block_rows.start..mid, block_cols.clone(), offset);
+        transpose_block(src, dst, rows, cols, mid..block_rows.end, block_cols, offset);
+    } else {
+        let mid = block_cols.start + block_cols.len() / 2;
+        transpose_block(src, dst, rows, cols, block_rows.clone(), block_cols.start..mid, offset);
+        transpose_block(src, dst, rows, cols, block_rows, mid..block_cols.end, offset);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transpose() {
+        for (rows, cols) in [(1, 1), (1, 7), (7, 1), (3, 5), (64, 32), (33, 100), (128, 256)] {
+            let src = (0..rows * cols).collect::<Vec<_>>();
+            let mut dst = vec![0; rows * cols];
+            transpose(&src, &mut dst, rows, cols);
+            for row in 0..rows {
+                for col in 0..cols {
+                    assert_eq!(dst[col * rows + row], src[row * cols + col]);
+                }
+            }
+        }
+    }
+}
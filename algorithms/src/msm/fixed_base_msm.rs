@@ -0,0 +1,207 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkvm_curves::{AffineCurve, ProjectiveCurve};
+use snarkvm_fields::{PrimeField, Zero};
+use snarkvm_utilities::{cfg_into_iter, cfg_iter, cfg_iter_mut, BigInteger};
+
+use core::ops::Range;
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+/// The maximum window size, which bounds the number of buckets of each pass to `2^16 - 1`.
+const MAX_WINDOW_SIZE: usize = 16;
+
+/// The configuration of a `FixedBaseMsm`, which trades the memory of the tables for the speed of each MSM.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct FixedBaseMsmConfig {
+    /// The number of precomputed multiples of each base, i.e. the size of the tables relative to the bases.
+    /// Each MSM performs one bucket pass for every `num_windows / num_tables` windows of the scalars.
+    pub num_tables: usize,
+    /// The window size in bits, or `None` to select the window size with the lowest estimated cost.
+    pub window_size: Option<usize>,
+}
+
+impl FixedBaseMsmConfig {
+    /// The default configuration, which stores four multiples of each base.
+    pub const DEFAULT: Self = Self { num_tables: 4, window_size: None };
+}
+
+impl Default for FixedBaseMsmConfig {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// A multi-scalar multiplication over a fixed list of bases, such as a slice of the committer key.
+///
+/// The bases are precomputed once, as the tables `2^(t * s * c) * bases[i]`, where `c` is the window size
+/// and `s` is the number of windows covered by each table. The digits of the scalars in the windows
+/// `t * s + k` of every table are then summed in a single bucket pass `k`, which saves the bucket sums
+/// and doublings of all but `s` windows, and allows larger windows than the variable-base MSM.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FixedBaseMsm<G: AffineCurve> {
+    /// The window size in bits.
+    window_size: usize,
+    /// The number of windows covered by each table.
+    windows_per_table: usize,
+    /// The number of bases.
+    num_bases: usize,
+    /// The tables, where `tables[t * num_bases + i] = 2^(t * windows_per_table * window_size) * bases[i]`.
+    tables: Vec<G>,
+}
+
+impl<G: AffineCurve> FixedBaseMsm<G> {
+    /// Initializes the tables of the given bases, with the given configuration.
+    pub fn new(bases: &[G], config: FixedBaseMsmConfig) -> Self {
+        let num_bits = G::ScalarField::size_in_bits();
+        let num_tables = config.num_tables.clamp(1, num_bits);
+        let window_size = config
+            .window_size
+            .unwrap_or_else(|| Self::optimal_window_size(bases.len(), num_tables))
+            .clamp(1, MAX_WINDOW_SIZE);
+
+        // Note: The number of tables is reduced to the number needed to cover the windows.
+        let num_windows = (num_bits + window_size - 1) / window_size;
+        let windows_per_table = (num_windows + num_tables - 1) / num_tables;
+        let num_tables = (num_windows + windows_per_table - 1) / windows_per_table;
+        let shift = windows_per_table * window_size;
+
+        let mut table = cfg_iter!(bases).map(|base| base.to_projective()).collect::<Vec<_>>();
+        let mut tables = Vec::with_capacity(num_tables * bases.len());
+        for index in 0..num_tables {
+            // Shift the previous table by `2^shift`.
+            if index > 0 {
+                cfg_iter_mut!(table).for_each(|point| {
+                    for _ in 0..shift {
+                        point.double_in_place();
+                    }
+                });
+            }
+            tables.extend(G::Projective::batch_normalization_into_affine(table.clone()));
+        }
+
+        Self { window_size, windows_per_table, num_bases: bases.len(), tables }
+    }
+
+    /// Returns the window size that minimizes the estimated cost of an MSM over the given number of bases,
+    /// with the given number of tables. Each pass adds one point per base and table to a bucket,
+    /// and then sums its `2^c - 1` buckets with two additions each.
+    pub fn optimal_window_size(num_bases: usize, num_tables: usize) -> usize {
+        let num_bits = G::ScalarField::size_in_bits();
+        let num_tables = num_tables.max(1);
+        (1..=MAX_WINDOW_SIZE)
+            .min_by_key(|window_size| {
+                let num_windows = (num_bits + window_size - 1) / window_size;
+                let num_passes = (num_windows + num_tables - 1) / num_tables;
+                num_bases * num_windows + num_passes * (2 << window_size)
+            })
+            .unwrap_or(1)
+    }
+
+    /// Returns the window size in bits.
+    pub const fn window_size(&self) -> usize {
+        self.window_size
+    }
+
+    /// Returns the number of bases.
+    pub const fn num_bases(&self) -> usize {
+        self.num_bases
+    }
+
+    /// Returns the number of tables.
+    pub fn num_tables(&self) -> usize {
+        match self.num_bases {
+            0 => 0,
+            num_bases => self.tables.len() / num_bases,
+        }
+    }
+
+    /// Returns the size in bytes of the tables.
+    pub fn memory_size(&self) -> usize {
+        self.tables.len() * core::mem::size_of::<G>()
+    }
+
+    /// Returns the MSM of the bases with the given scalars.
+    /// If there are fewer scalars than bases, the MSM is over the leading bases.
+    pub fn msm(&self, scalars: &[<G::ScalarField as PrimeField>::BigInteger]) -> G::Projective {
+        let num_scalars = core::cmp::min(scalars.len(), self.num_bases);
+        if num_scalars == 0 {
+            return G::Projective::zero();
+        }
+
+        // Split the bases into chunks, so that every thread is busy when there are only a few passes.
+        #[cfg(not(feature = "serial"))]
+        let num_chunks = (rayon::current_num_threads() / self.windows_per_table).clamp(1, num_scalars);
+        #[cfg(feature = "serial")]
+        let num_chunks = 1;
+        let chunk_size = (num_scalars + num_chunks - 1) / num_chunks;
+
+        // Compute the sum of each pass over each chunk.
+        let sums = cfg_into_iter!(0..self.windows_per_table * num_chunks)
+            .map(|job| {
+                let (pass, chunk) = (job / num_chunks, job % num_chunks);
+                let start = core::cmp::min(chunk * chunk_size, num_scalars);
+                self.pass(scalars, pass, start..core::cmp::min(start + chunk_size, num_scalars))
+            })
+            .collect::<Vec<_>>();
+
+        // Combine the passes, from the highest window to the lowest.
+        sums.chunks(num_chunks).rev().fold(G::Projective::zero(), |mut total, sums| {
+            for _ in 0..self.window_size {
+                total.double_in_place();
+            }
+            sums.iter().fold(total, |total, sum| total + *sum)
+        })
+    }
+
+    /// Returns the sum of the `k`-th window of each table, over the bases in the given range,
+    /// where `k` is the given pass.
+    fn pass(
+        &self,
+        scalars: &[<G::ScalarField as PrimeField>::BigInteger],
+        pass: usize,
+        range: Range<usize>,
+    ) -> G::Projective {
+        let num_bits = G::ScalarField::size_in_bits();
+        let c = self.window_size;
+
+        // We don't need the "zero" bucket, so we only have 2^c - 1 buckets.
+        let mut buckets = vec![G::Projective::zero(); (1 << c) - 1];
+        for (index, table) in self.tables.chunks(self.num_bases).enumerate() {
+            let w_start = (index * self.windows_per_table + pass) * c;
+            if w_start >= num_bits {
+                break;
+            }
+            for (base, scalar) in table[range.clone()].iter().zip(&scalars[range.clone()]) {
+                // We right-shift by w_start, and mod the remaining bits by the window size.
+                let mut scalar = *scalar;
+                scalar.divn(w_start as u32);
+                let digit = scalar.as_ref()[0] % (1 << c);
+                if digit != 0 {
+                    buckets[(digit - 1) as usize].add_assign_mixed(base);
+                }
+            }
+        }
+
+        // Sum the buckets, where the bucket at index `j` is weighted by `j + 1`.
+        let mut sum = G::Projective::zero();
+        let mut running_sum = G::Projective::zero();
+        for bucket in buckets.into_iter().rev() {
+            running_sum += bucket;
+            sum += running_sum;
+        }
+        sum
+    }
+}
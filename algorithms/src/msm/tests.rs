@@ -64,3 +64,34 @@ fn variable_base_test_with_bls12_unequal_numbers() {
 
     assert_eq!(naive.to_affine(), fast.to_affine());
 }
+
+#[test]
+fn fixed_base_msm_test_with_bls12() {
+    const SAMPLES: usize = 1 << 8;
+
+    let mut rng = TestRng::default();
+
+    let g = (0..SAMPLES).map(|_| G1Projective::rand(&mut rng).to_affine()).collect::<Vec<_>>();
+
+    for config in [
+        FixedBaseMsmConfig::DEFAULT,
+        FixedBaseMsmConfig { num_tables: 1, window_size: Some(5) },
+        FixedBaseMsmConfig { num_tables: 3, window_size: Some(7) },
+        FixedBaseMsmConfig { num_tables: 253, window_size: Some(1) },
+        FixedBaseMsmConfig { num_tables: 100, window_size: None },
+    ] {
+        let fixed_base = FixedBaseMsm::new(&g, config);
+        assert_eq!(fixed_base.num_bases(), SAMPLES);
+        assert_eq!(fixed_base.memory_size(), fixed_base.num_tables() * SAMPLES * core::mem::size_of_val(&g[0]));
+
+        // Ensure repeated MSMs, including over the leading bases, match the naive MSM.
+        for num_scalars in [0, 1, SAMPLES - 17, SAMPLES] {
+            let v = (0..num_scalars).map(|_| Fr::rand(&mut rng).to_bigint()).collect::<Vec<_>>();
+
+            let naive = naive_variable_base_msm(g.as_slice(), v.as_slice());
+            let fast = fixed_base.msm(v.as_slice());
+
+            assert_eq!(naive.to_affine(), fast.to_affine());
+        }
+    }
+}
@@ -0,0 +1,120 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::batched;
+use snarkvm_curves::bls12_377::{G1Affine, G1Projective};
+use snarkvm_utilities::biginteger::BigInteger256;
+
+use anyhow::Result;
+use core::any::{Any, TypeId};
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+/// A backend for the variable-base MSM over BLS12-377 G1, as used by the KZG commitments and the Varuna prover.
+///
+/// The backend is selected at runtime with `VariableBase::set_backend`. If the backend fails,
+/// or the MSM is not larger than its threshold, then the MSM is computed on the CPU instead.
+pub trait MsmBackend: Send + Sync {
+    /// Returns the name of the backend.
+    fn name(&self) -> &'static str;
+
+    /// Returns the number of scalars above which the backend is used.
+    fn threshold(&self) -> usize {
+        0
+    }
+
+    /// Returns the MSM of the given bases and scalars.
+    fn msm(&self, bases: &[G1Affine], scalars: &[BigInteger256]) -> Result<G1Projective>;
+}
+
+/// The CPU backend, which computes the MSM using a batched addition technique.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct CpuBackend;
+
+impl MsmBackend for CpuBackend {
+    /// Returns the name of the backend.
+    fn name(&self) -> &'static str {
+        "cpu"
+    }
+
+    /// Returns the MSM of the given bases and scalars.
+    fn msm(&self, bases: &[G1Affine], scalars: &[BigInteger256]) -> Result<G1Projective> {
+        Ok(batched::msm(bases, scalars))
+    }
+}
+
+/// The CUDA backend, which computes the MSM on an NVIDIA GPU.
+#[cfg(all(feature = "cuda", target_arch = "x86_64"))]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct CudaBackend;
+
+#[cfg(all(feature = "cuda", target_arch = "x86_64"))]
+impl MsmBackend for CudaBackend {
+    /// Returns the name of the backend.
+    fn name(&self) -> &'static str {
+        "cuda"
+    }
+
+    /// Returns the number of scalars above which the backend is used.
+    fn threshold(&self) -> usize {
+        // TODO SNP: where to set the threshold
+        1024
+    }
+
+    /// Returns the MSM of the given bases and scalars.
+    fn msm(&self, bases: &[G1Affine], scalars: &[BigInteger256]) -> Result<G1Projective> {
+        snarkvm_algorithms_cuda::msm::<G1Affine, G1Projective, BigInteger256>(bases, scalars)
+            .map_err(|error| anyhow::anyhow!("The CUDA MSM failed with error code {}", error.code))
+    }
+}
+
+/// The selected backend, or `None` if the default backend is selected.
+static BACKEND: RwLock<Option<Arc<dyn MsmBackend>>> = parking_lot::const_rwlock(None);
+
+/// Returns the default backend, which is the CUDA backend if the `cuda` feature is enabled.
+#[cfg(all(feature = "cuda", target_arch = "x86_64"))]
+fn default_backend() -> Option<Arc<dyn MsmBackend>> {
+    Some(Arc::new(CudaBackend))
+}
+
+/// Returns the default backend, which is the CPU if the `cuda` feature is not enabled.
+#[cfg(not(all(feature = "cuda", target_arch = "x86_64")))]
+fn default_backend() -> Option<Arc<dyn MsmBackend>> {
+    None
+}
+
+/// Returns the selected backend, or `None` if the default backend is the CPU.
+pub(super) fn selected() -> Option<Arc<dyn MsmBackend>> {
+    BACKEND.read().clone().or_else(default_backend)
+}
+
+/// Selects the given backend, or the default backend if `None` is given.
+pub(super) fn select(backend: Option<Arc<dyn MsmBackend>>) {
+    *BACKEND.write() = backend;
+}
+
+/// Returns the given slice as a slice of `T`, if `S` is `T`.
+#[allow(unsafe_code)]
+pub(super) fn cast_slice<S: 'static, T: 'static>(slice: &[S]) -> Option<&[T]> {
+    match TypeId::of::<S>() == TypeId::of::<T>() {
+        // SAFETY: `S` and `T` are the same type, so the slices have the same layout.
+        true => Some(unsafe { &*(slice as *const [S] as *const [T]) }),
+        false => None,
+    }
+}
+
+/// Returns the given value as a `T`, if `S` is `T`.
+pub(super) fn cast_value<S: 'static, T: 'static>(value: S) -> Option<T> {
+    (Box::new(value) as Box<dyn Any>).downcast::<T>().ok().map(|value| *value)
+}
@@ -0,0 +1,241 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::MsmBackend;
+use snarkvm_curves::{
+    bls12_377::{Fq, Fr, G1Affine, G1Projective},
+    ProjectiveCurve,
+};
+use snarkvm_fields::{PrimeField, Zero};
+use snarkvm_utilities::{
+    biginteger::{BigInteger256, BigInteger384},
+    cfg_into_iter,
+};
+
+use ::metal::{Buffer, CommandQueue, CompileOptions, ComputePipelineState, Device, MTLResourceOptions, MTLSize};
+use anyhow::{anyhow, ensure, Result};
+use core::{ffi::c_void, marker::PhantomData, mem::size_of};
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+/// The Metal shader, which accumulates the buckets of Pippenger's algorithm.
+const SHADER: &str = include_str!("msm.metal");
+
+/// The number of 32-bit limbs in a base field element.
+const FQ_LIMBS: usize = 12;
+
+/// The Metal backend, which computes the MSM on an Apple GPU.
+///
+/// The scalars are split into windows and the bases are sorted into buckets on the CPU,
+/// the buckets are accumulated on the GPU, and the buckets are combined on the CPU.
+/// The backend is never selected by default, and must be selected with `VariableBase::set_backend`.
+pub struct MetalBackend {
+    device: Device,
+    queue: CommandQueue,
+    pipeline: ComputePipelineState,
+}
+
+impl MetalBackend {
+    /// Initializes the Metal backend on the default system GPU.
+    pub fn new() -> Result<Self> {
+        let device = Device::system_default().ok_or_else(|| anyhow!("No Metal device is available"))?;
+        let library = device
+            .new_library_with_source(SHADER, &CompileOptions::new())
+            .map_err(|error| anyhow!("Failed to compile the Metal MSM shader: {error}"))?;
+        let function = library
+            .get_function("bucket_accumulate", None)
+            .map_err(|error| anyhow!("Failed to load the Metal MSM kernel: {error}"))?;
+        let pipeline = device
+            .new_compute_pipeline_state_with_function(&function)
+            .map_err(|error| anyhow!("Failed to create the Metal MSM pipeline: {error}"))?;
+        let queue = device.new_command_queue();
+        Ok(Self { device, queue, pipeline })
+    }
+
+    /// Returns the sums of the given buckets, which are computed on the GPU.
+    fn accumulate_buckets(
+        &self,
+        bases: &[G1Affine],
+        point_indices: &[u32],
+        bucket_offsets: &[u32],
+    ) -> Result<Vec<G1Projective>> {
+        let num_buckets = bucket_offsets.len() - 1;
+
+        // Encode the bases as the Montgomery limbs of their coordinates.
+        let mut encoded_bases = Vec::with_capacity(bases.len() * 2 * FQ_LIMBS);
+        for base in bases {
+            encode_fq(&base.x, &mut encoded_bases);
+            encode_fq(&base.y, &mut encoded_bases);
+        }
+
+        let options = MTLResourceOptions::StorageModeShared;
+        let bases_buffer = self.new_buffer(&encoded_bases, options);
+        let indices_buffer = self.new_buffer(point_indices, options);
+        let offsets_buffer = self.new_buffer(bucket_offsets, options);
+        let buckets_buffer = self.device.new_buffer((num_buckets * 3 * FQ_LIMBS * size_of::<u32>()) as u64, options);
+        let num_buckets_u32 = u32::try_from(num_buckets)?;
+
+        let command_buffer = self.queue.new_command_buffer();
+        let encoder = command_buffer.new_compute_command_encoder();
+        encoder.set_compute_pipeline_state(&self.pipeline);
+        encoder.set_buffer(0, Some(&bases_buffer), 0);
+        encoder.set_buffer(1, Some(&indices_buffer), 0);
+        encoder.set_buffer(2, Some(&offsets_buffer), 0);
+        encoder.set_buffer(3, Some(&buckets_buffer), 0);
+        encoder.set_bytes(4, size_of::<u32>() as u64, &num_buckets_u32 as *const u32 as *const c_void);
+        let width = self.pipeline.thread_execution_width().min(self.pipeline.max_total_threads_per_threadgroup());
+        encoder.dispatch_threads(MTLSize::new(num_buckets as u64, 1, 1), MTLSize::new(width, 1, 1));
+        encoder.end_encoding();
+        command_buffer.commit();
+        command_buffer.wait_until_completed();
+
+        // Decode the buckets from the Montgomery limbs of their coordinates.
+        // SAFETY: The buffer is shared with the CPU, and holds `num_buckets` points of `3 * FQ_LIMBS` limbs each.
+        #[allow(unsafe_code)]
+        let limbs =
+            unsafe { core::slice::from_raw_parts(buckets_buffer.contents() as *const u32, num_buckets * 3 * FQ_LIMBS) };
+        Ok(limbs
+            .chunks_exact(3 * FQ_LIMBS)
+            .map(|point| {
+                let (x, rest) = point.split_at(FQ_LIMBS);
+                let (y, z) = rest.split_at(FQ_LIMBS);
+                G1Projective::new(decode_fq(x), decode_fq(y), decode_fq(z))
+            })
+            .collect())
+    }
+
+    /// Returns a new GPU buffer holding a copy of the given limbs.
+    fn new_buffer(&self, limbs: &[u32], options: MTLResourceOptions) -> Buffer {
+        // Metal does not allow empty buffers, so an empty slice is padded to a single limb.
+        let limbs = if limbs.is_empty() { &[0u32][..] } else { limbs };
+        self.device.new_buffer_with_data(limbs.as_ptr() as *const c_void, core::mem::size_of_val(limbs) as u64, options)
+    }
+}
+
+impl MsmBackend for MetalBackend {
+    /// Returns the name of the backend.
+    fn name(&self) -> &'static str {
+        "metal"
+    }
+
+    /// Returns the number of scalars above which the backend is used.
+    fn threshold(&self) -> usize {
+        // Below this size, there are too few buckets to occupy the GPU.
+        1 << 12
+    }
+
+    /// Returns the MSM of the given bases and scalars.
+    fn msm(&self, bases: &[G1Affine], scalars: &[BigInteger256]) -> Result<G1Projective> {
+        let size = bases.len().min(scalars.len());
+        ensure!(size <= u32::MAX as usize, "The Metal MSM supports at most 2^32 - 1 bases");
+        let (bases, scalars) = (&bases[..size], &scalars[..size]);
+
+        let c = match size < 32 {
+            true => 3,
+            false => crate::msm::ln_without_floats(size) + 2,
+        };
+        let num_bits = Fr::size_in_bits();
+        let num_windows = (num_bits + c - 1) / c;
+        // Note: The zero digit has no bucket.
+        let buckets_per_window = (1 << c) - 1;
+        let num_buckets = num_windows * buckets_per_window;
+
+        // Sort the indices of the bases into the buckets of each window, using a counting sort.
+        let bucket_of = |window: usize, digit: usize| window * buckets_per_window + digit - 1;
+        let mut bucket_offsets = vec![0u32; num_buckets + 1];
+        for (base, scalar) in bases.iter().zip(scalars) {
+            if base.infinity {
+                continue;
+            }
+            for window in 0..num_windows {
+                let digit = window_digit(scalar, window * c, c);
+                if digit != 0 {
+                    bucket_offsets[bucket_of(window, digit) + 1] += 1;
+                }
+            }
+        }
+        for i in 1..bucket_offsets.len() {
+            bucket_offsets[i] += bucket_offsets[i - 1];
+        }
+        if bucket_offsets[num_buckets] == 0 {
+            return Ok(G1Projective::zero());
+        }
+        let mut cursors = bucket_offsets[..num_buckets].to_vec();
+        let mut point_indices = vec![0u32; bucket_offsets[num_buckets] as usize];
+        for (index, (base, scalar)) in bases.iter().zip(scalars).enumerate() {
+            if base.infinity {
+                continue;
+            }
+            for window in 0..num_windows {
+                let digit = window_digit(scalar, window * c, c);
+                if digit != 0 {
+                    let cursor = &mut cursors[bucket_of(window, digit)];
+                    point_indices[*cursor as usize] = index as u32;
+                    *cursor += 1;
+                }
+            }
+        }
+
+        let buckets = self.accumulate_buckets(bases, &point_indices, &bucket_offsets)?;
+
+        // Compute the sum of each window, where the `i`-th bucket is weighted by `i + 1`.
+        let window_sums: Vec<_> = cfg_into_iter!(0..num_windows)
+            .map(|window| {
+                let mut running_sum = G1Projective::zero();
+                let mut sum = G1Projective::zero();
+                for bucket in buckets[window * buckets_per_window..(window + 1) * buckets_per_window].iter().rev() {
+                    running_sum += bucket;
+                    sum += &running_sum;
+                }
+                sum
+            })
+            .collect();
+
+        // Combine the window sums, starting from the highest window.
+        Ok(window_sums.iter().rev().fold(G1Projective::zero(), |mut total, sum| {
+            for _ in 0..c {
+                total.double_in_place();
+            }
+            total + sum
+        }))
+    }
+}
+
+/// Returns the `c` bits of the given scalar starting from bit `start`.
+fn window_digit(scalar: &BigInteger256, start: usize, c: usize) -> usize {
+    let (limb, shift) = (start / 64, start % 64);
+    let mut digit = scalar.0[limb] >> shift;
+    if shift + c > 64 && limb + 1 < scalar.0.len() {
+        digit |= scalar.0[limb + 1] << (64 - shift);
+    }
+    (digit & ((1 << c) - 1)) as usize
+}
+
+/// Appends the Montgomery limbs of the given field element, as little-endian 32-bit limbs.
+fn encode_fq(element: &Fq, limbs: &mut Vec<u32>) {
+    for limb in (element.0).0 {
+        limbs.push(limb as u32);
+        limbs.push((limb >> 32) as u32);
+    }
+}
+
+/// Returns the field element with the given Montgomery limbs, as little-endian 32-bit limbs.
+fn decode_fq(limbs: &[u32]) -> Fq {
+    let mut element = [0u64; 6];
+    for (i, limb) in element.iter_mut().enumerate() {
+        *limb = limbs[2 * i] as u64 | ((limbs[2 * i + 1] as u64) << 32);
+    }
+    Fq(BigInteger384::new(element), PhantomData)
+}
@@ -0,0 +1,261 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// The bucket accumulation step of Pippenger's algorithm over BLS12-377 G1.
+//
+// Field elements of Fq are stored in Montgomery form (R = 2^384), as twelve little-endian
+// 32-bit limbs, which matches the in-memory representation of `Fq` on the host.
+
+#include <metal_stdlib>
+using namespace metal;
+
+#define LIMBS 12
+
+struct Fq {
+    uint l[LIMBS];
+};
+
+// An affine point, where the point at infinity is never uploaded.
+struct Affine {
+    Fq x;
+    Fq y;
+};
+
+// A point in Jacobian coordinates, where the point at infinity has `z == 0`.
+struct Jacobian {
+    Fq x;
+    Fq y;
+    Fq z;
+};
+
+// The base field modulus.
+constant uint MODULUS[LIMBS] = {
+    0x00000001, 0x8508c000, 0x30000000, 0x170b5d44, 0xba094800, 0x1ef3622f,
+    0x00f5138f, 0x1a22d9f3, 0x6ca1493b, 0xc63b05c0, 0x17c510ea, 0x01ae3a46
+};
+
+// The Montgomery form of one, i.e. 2^384 mod p.
+constant uint ONE[LIMBS] = {
+    0xffffff68, 0x02cdffff, 0x7fffffb1, 0x51409f83, 0x8a7d3ff2, 0x9f7db3a9,
+    0x6e7c6305, 0x7b4e97b7, 0x803c84e8, 0x4cf495bf, 0xe2fdf49a, 0x008d6661
+};
+
+// -p^{-1} mod 2^32.
+constant uint INV = 0xffffffff;
+
+Fq fq_zero() {
+    Fq r;
+    for (int i = 0; i < LIMBS; i++) { r.l[i] = 0; }
+    return r;
+}
+
+Fq fq_one() {
+    Fq r;
+    for (int i = 0; i < LIMBS; i++) { r.l[i] = ONE[i]; }
+    return r;
+}
+
+bool fq_is_zero(Fq a) {
+    uint acc = 0;
+    for (int i = 0; i < LIMBS; i++) { acc |= a.l[i]; }
+    return acc == 0;
+}
+
+bool fq_eq(Fq a, Fq b) {
+    uint acc = 0;
+    for (int i = 0; i < LIMBS; i++) { acc |= a.l[i] ^ b.l[i]; }
+    return acc == 0;
+}
+
+// Returns `true` if `a >= p`.
+bool fq_geq_modulus(Fq a) {
+    for (int i = LIMBS - 1; i >= 0; i--) {
+        if (a.l[i] != MODULUS[i]) { return a.l[i] > MODULUS[i]; }
+    }
+    return true;
+}
+
+Fq fq_sub_modulus(Fq a) {
+    ulong borrow = 0;
+    for (int i = 0; i < LIMBS; i++) {
+        ulong t = (ulong)a.l[i] - (ulong)MODULUS[i] - borrow;
+        a.l[i] = (uint)t;
+        borrow = (t >> 32) & 1;
+    }
+    return a;
+}
+
+Fq fq_add(Fq a, Fq b) {
+    // As p < 2^377, the sum does not overflow 384 bits.
+    ulong carry = 0;
+    for (int i = 0; i < LIMBS; i++) {
+        ulong t = (ulong)a.l[i] + (ulong)b.l[i] + carry;
+        a.l[i] = (uint)t;
+        carry = t >> 32;
+    }
+    return fq_geq_modulus(a) ? fq_sub_modulus(a) : a;
+}
+
+Fq fq_sub(Fq a, Fq b) {
+    ulong borrow = 0;
+    for (int i = 0; i < LIMBS; i++) {
+        ulong t = (ulong)a.l[i] - (ulong)b.l[i] - borrow;
+        a.l[i] = (uint)t;
+        borrow = (t >> 32) & 1;
+    }
+    if (borrow != 0) {
+        ulong carry = 0;
+        for (int i = 0; i < LIMBS; i++) {
+            ulong t = (ulong)a.l[i] + (ulong)MODULUS[i] + carry;
+            a.l[i] = (uint)t;
+            carry = t >> 32;
+        }
+    }
+    return a;
+}
+
+Fq fq_double(Fq a) {
+    return fq_add(a, a);
+}
+
+// Montgomery multiplication, using the CIOS method.
+Fq fq_mul(Fq a, Fq b) {
+    uint t[LIMBS + 2];
+    for (int i = 0; i < LIMBS + 2; i++) { t[i] = 0; }
+
+    for (int i = 0; i < LIMBS; i++) {
+        ulong carry = 0;
+        for (int j = 0; j < LIMBS; j++) {
+            ulong s = (ulong)t[j] + (ulong)a.l[j] * (ulong)b.l[i] + carry;
+            t[j] = (uint)s;
+            carry = s >> 32;
+        }
+        ulong s = (ulong)t[LIMBS] + carry;
+        t[LIMBS] = (uint)s;
+        t[LIMBS + 1] = (uint)(s >> 32);
+
+        uint m = t[0] * INV;
+        carry = ((ulong)t[0] + (ulong)m * (ulong)MODULUS[0]) >> 32;
+        for (int j = 1; j < LIMBS; j++) {
+            s = (ulong)t[j] + (ulong)m * (ulong)MODULUS[j] + carry;
+            t[j - 1] = (uint)s;
+            carry = s >> 32;
+        }
+        s = (ulong)t[LIMBS] + carry;
+        t[LIMBS - 1] = (uint)s;
+        t[LIMBS] = t[LIMBS + 1] + (uint)(s >> 32);
+    }
+
+    Fq r;
+    for (int i = 0; i < LIMBS; i++) { r.l[i] = t[i]; }
+    return (t[LIMBS] != 0 || fq_geq_modulus(r)) ? fq_sub_modulus(r) : r;
+}
+
+Fq fq_square(Fq a) {
+    return fq_mul(a, a);
+}
+
+// Doubles the given point, using the formulas for `a = 0` (dbl-2009-l).
+Jacobian jacobian_double(Jacobian p) {
+    if (fq_is_zero(p.z)) { return p; }
+
+    Fq a = fq_square(p.x);
+    Fq b = fq_square(p.y);
+    Fq c = fq_square(b);
+    // D = 2*((X1+B)^2-A-C)
+    Fq d = fq_double(fq_sub(fq_sub(fq_square(fq_add(p.x, b)), a), c));
+    // E = 3*A
+    Fq e = fq_add(fq_double(a), a);
+    Fq f = fq_square(e);
+
+    Jacobian r;
+    // Z3 = 2*Y1*Z1
+    r.z = fq_double(fq_mul(p.y, p.z));
+    // X3 = F-2*D
+    r.x = fq_sub(f, fq_double(d));
+    // Y3 = E*(D-X3)-8*C
+    Fq c8 = fq_double(fq_double(fq_double(c)));
+    r.y = fq_sub(fq_mul(e, fq_sub(d, r.x)), c8);
+    return r;
+}
+
+// Adds the given affine point to the given point (madd-2007-bl).
+Jacobian jacobian_add_mixed(Jacobian p, Affine q) {
+    if (fq_is_zero(p.z)) {
+        Jacobian r;
+        r.x = q.x;
+        r.y = q.y;
+        r.z = fq_one();
+        return r;
+    }
+
+    Fq z1z1 = fq_square(p.z);
+    Fq u2 = fq_mul(q.x, z1z1);
+    Fq s2 = fq_mul(fq_mul(q.y, p.z), z1z1);
+
+    if (fq_eq(p.x, u2)) {
+        if (fq_eq(p.y, s2)) {
+            return jacobian_double(p);
+        }
+        // The points are inverses of each other.
+        Jacobian r;
+        r.x = fq_one();
+        r.y = fq_one();
+        r.z = fq_zero();
+        return r;
+    }
+
+    // H = U2-X1
+    Fq h = fq_sub(u2, p.x);
+    Fq hh = fq_square(h);
+    // I = 4*HH
+    Fq i = fq_double(fq_double(hh));
+    Fq j = fq_mul(h, i);
+    // r = 2*(S2-Y1)
+    Fq r = fq_double(fq_sub(s2, p.y));
+    Fq v = fq_mul(p.x, i);
+
+    Jacobian out;
+    // X3 = r^2-J-2*V
+    out.x = fq_sub(fq_sub(fq_square(r), j), fq_double(v));
+    // Y3 = r*(V-X3)-2*Y1*J
+    out.y = fq_sub(fq_mul(r, fq_sub(v, out.x)), fq_double(fq_mul(p.y, j)));
+    // Z3 = (Z1+H)^2-Z1Z1-HH
+    out.z = fq_sub(fq_sub(fq_square(fq_add(p.z, h)), z1z1), hh);
+    return out;
+}
+
+// Sums the bases assigned to each bucket, where the bases of bucket `i` are
+// `bases[point_indices[k]]` for `k` in `bucket_offsets[i]..bucket_offsets[i + 1]`.
+kernel void bucket_accumulate(
+    device const Affine* bases [[buffer(0)]],
+    device const uint* point_indices [[buffer(1)]],
+    device const uint* bucket_offsets [[buffer(2)]],
+    device Jacobian* buckets [[buffer(3)]],
+    constant uint& num_buckets [[buffer(4)]],
+    uint gid [[thread_position_in_grid]]
+) {
+    if (gid >= num_buckets) { return; }
+
+    Jacobian acc;
+    acc.x = fq_one();
+    acc.y = fq_one();
+    acc.z = fq_zero();
+
+    uint end = bucket_offsets[gid + 1];
+    for (uint k = bucket_offsets[gid]; k < end; k++) {
+        acc = jacobian_add_mixed(acc, bases[point_indices[k]]);
+    }
+    buckets[gid] = acc;
+}
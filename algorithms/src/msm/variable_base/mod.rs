@@ -12,6 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod backend;
+pub use backend::*;
+
+#[cfg(all(feature = "metal", target_os = "macos"))]
+mod metal;
+#[cfg(all(feature = "metal", target_os = "macos"))]
+pub use self::metal::*;
+
 pub mod batched;
 pub mod standard;
 
@@ -22,22 +30,17 @@ use snarkvm_curves::{bls12_377::G1Affine, traits::AffineCurve};
 use snarkvm_fields::PrimeField;
 
 use core::any::TypeId;
+use std::sync::Arc;
 
 pub struct VariableBase;
 
 impl VariableBase {
     pub fn msm<G: AffineCurve>(bases: &[G], scalars: &[<G::ScalarField as PrimeField>::BigInteger]) -> G::Projective {
-        // For BLS12-377, we perform variable base MSM using a batched addition technique.
+        // For BLS12-377, we perform variable base MSM using the selected backend,
+        // or using a batched addition technique if the backend is not used.
         if TypeId::of::<G>() == TypeId::of::<G1Affine>() {
-            #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
-            // TODO SNP: where to set the threshold
-            if scalars.len() > 1024 {
-                let result = snarkvm_algorithms_cuda::msm::<G, G::Projective, <G::ScalarField as PrimeField>::BigInteger>(
-                    bases, scalars,
-                );
-                if let Ok(result) = result {
-                    return result;
-                }
+            if let Some(result) = Self::msm_with_backend(bases, scalars) {
+                return result;
             }
             batched::msm(bases, scalars)
         }
@@ -47,6 +50,30 @@ impl VariableBase {
         }
     }
 
+    /// Selects the backend for the variable base MSM over BLS12-377 G1.
+    /// If `None` is given, the default backend is selected, which is CUDA if the `cuda` feature is enabled.
+    pub fn set_backend(backend: Option<Arc<dyn MsmBackend>>) {
+        backend::select(backend)
+    }
+
+    /// Returns the name of the selected backend for the variable base MSM over BLS12-377 G1.
+    pub fn backend_name() -> &'static str {
+        backend::selected().map_or_else(|| CpuBackend.name(), |backend| backend.name())
+    }
+
+    /// Returns the MSM from the selected backend, or `None` if the backend is not used or fails.
+    fn msm_with_backend<G: AffineCurve>(
+        bases: &[G],
+        scalars: &[<G::ScalarField as PrimeField>::BigInteger],
+    ) -> Option<G::Projective> {
+        let backend = backend::selected()?;
+        if scalars.len() <= backend.threshold() {
+            return None;
+        }
+        let result = backend.msm(backend::cast_slice(bases)?, backend::cast_slice(scalars)?).ok()?;
+        backend::cast_value(result)
+    }
+
     #[cfg(test)]
     fn msm_naive<G: AffineCurve>(bases: &[G], scalars: &[<G::ScalarField as PrimeField>::BigInteger]) -> G::Projective {
         use itertools::Itertools;
@@ -105,6 +132,60 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_msm_backend() {
+        use parking_lot::Mutex;
+        use snarkvm_curves::{bls12_377::G1Projective, ProjectiveCurve};
+        use snarkvm_utilities::biginteger::BigInteger256;
+
+        /// A backend that records the size of each MSM, and fails if `fail` is set.
+        struct TestBackend {
+            sizes: Mutex<Vec<usize>>,
+            fail: bool,
+        }
+
+        impl MsmBackend for TestBackend {
+            fn name(&self) -> &'static str {
+                "test"
+            }
+
+            fn threshold(&self) -> usize {
+                76
+            }
+
+            fn msm(&self, bases: &[G1Affine], scalars: &[BigInteger256]) -> anyhow::Result<G1Projective> {
+                self.sizes.lock().push(scalars.len());
+                match self.fail {
+                    true => anyhow::bail!("The test backend failed"),
+                    false => Ok(standard::msm(bases, scalars)),
+                }
+            }
+        }
+
+        let mut rng = TestRng::default();
+        for fail in [false, true] {
+            let backend = Arc::new(TestBackend { sizes: Mutex::new(Vec::new()), fail });
+            VariableBase::set_backend(Some(backend.clone()));
+            assert_eq!(VariableBase::backend_name(), "test");
+
+            for msm_size in [7, 77] {
+                let (bases, scalars) = create_scalar_bases::<G1Affine, Fr>(&mut rng, msm_size);
+                let expected = standard::msm(bases.as_slice(), scalars.as_slice()).to_affine();
+                // Ensure the result is correct, including when the backend fails and the CPU is used instead.
+                let candidate = VariableBase::msm(bases.as_slice(), scalars.as_slice()).to_affine();
+                assert_eq!(expected, candidate, "MSM size: {msm_size}");
+            }
+            // Ensure the backend is only used above its threshold.
+            // Note: Other tests may use the backend concurrently, so only the sizes are checked.
+            let sizes = backend.sizes.lock();
+            assert!(sizes.contains(&77));
+            assert!(sizes.iter().all(|size| *size > 76));
+        }
+
+        // Restore the default backend.
+        VariableBase::set_backend(None);
+    }
+
     #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
     #[test]
     fn test_msm_cuda() {
@@ -116,4 +197,22 @@ mod tests {
             assert_eq!(rust.to_affine(), cuda.to_affine());
         }
     }
+
+    #[cfg(all(feature = "metal", target_os = "macos"))]
+    #[test]
+    fn test_msm_metal() {
+        use snarkvm_curves::ProjectiveCurve;
+        use snarkvm_fields::Zero;
+
+        let backend = MetalBackend::new().unwrap();
+        let mut rng = TestRng::default();
+        for msm_size in [1, 2, 5, 100, 1 << 10, 1 << 13, 1 << 16] {
+            let (mut bases, scalars) = create_scalar_bases::<G1Affine, Fr>(&mut rng, msm_size);
+            // Ensure the point at infinity is skipped.
+            bases[0] = G1Affine::zero();
+            let expected = batched::msm(bases.as_slice(), scalars.as_slice());
+            let candidate = backend.msm(bases.as_slice(), scalars.as_slice()).unwrap();
+            assert_eq!(expected.to_affine(), candidate.to_affine(), "MSM size: {msm_size}");
+        }
+    }
 }
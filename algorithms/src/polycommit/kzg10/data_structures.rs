@@ -29,7 +29,7 @@ use snarkvm_utilities::{
 };
 
 use crate::srs::{UniversalProver, UniversalVerifier};
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use core::ops::{Add, AddAssign};
 use rand_core::RngCore;
 use std::{collections::BTreeMap, io, ops::Range, sync::Arc};
@@ -60,10 +60,29 @@ impl<E: PairingEngine> UniversalParams<E> {
         Ok(Self { powers, h, prepared_h, prepared_beta_h })
     }
 
+    /// Loads the universal parameters, with the powers of beta G for polynomials of up to the given degree.
+    /// Only these powers are read from the parameter files, and further powers are read on demand.
+    pub fn load_up_to(degree: usize) -> Result<Self> {
+        let params = Self::load()?;
+        params.extend_degree(degree)?;
+        Ok(params)
+    }
+
     pub fn download_powers_for(&self, range: Range<usize>) -> Result<()> {
         self.powers.download_powers_for(range)
     }
 
+    /// Extends the loaded powers of beta G to support polynomials of up to the given degree.
+    pub fn extend_degree(&self, degree: usize) -> Result<()> {
+        let num_powers = degree.checked_add(1).ok_or_else(|| anyhow!("The degree is too large"))?;
+        self.powers.extend_powers_to(num_powers)
+    }
+
+    /// Returns the maximum degree supported by the loaded powers of beta G.
+    pub fn loaded_degree(&self) -> usize {
+        self.powers.num_powers().saturating_sub(1)
+    }
+
     pub fn lagrange_basis(&self, domain: EvaluationDomain<E::Fr>) -> Result<Vec<E::G1Affine>> {
         let basis = domain
             .ifft(&self.powers_of_beta_g(0, domain.size())?.iter().map(|e| (*e).to_projective()).collect::<Vec<_>>());
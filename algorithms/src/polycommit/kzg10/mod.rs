@@ -19,26 +19,32 @@
 //! proposed by Kate, Zaverucha, and Goldberg ([KZG11](http://cacr.uwaterloo.ca/techreports/2010/cacr2010-10.pdf)).
 //! This construction achieves extractability in the algebraic group model (AGM).
 
+use crate::polycommit::PCError;
+#[cfg(feature = "prover")]
 use crate::{
     fft::{DensePolynomial, Polynomial},
     msm::VariableBase,
-    polycommit::PCError,
 };
 use snarkvm_curves::traits::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
 use snarkvm_fields::{One, PrimeField, Zero};
-use snarkvm_utilities::{cfg_iter, cfg_iter_mut, rand::Uniform, BitIteratorBE};
+use snarkvm_utilities::rand::Uniform;
+#[cfg(feature = "prover")]
+use snarkvm_utilities::{cfg_iter, cfg_iter_mut, BitIteratorBE};
 
-use anyhow::{anyhow, ensure, Result};
+#[cfg(feature = "prover")]
+use anyhow::anyhow;
+use anyhow::{ensure, Result};
 use core::{marker::PhantomData, ops::Mul};
 use itertools::Itertools;
 use rand_core::RngCore;
 
-#[cfg(not(feature = "serial"))]
+#[cfg(all(feature = "prover", not(feature = "serial")))]
 use rayon::prelude::*;
 
 mod data_structures;
 pub use data_structures::*;
 
+#[cfg(feature = "prover")]
 use super::sonic_pc::LabeledPolynomialWithBasis;
 
 #[derive(Debug, PartialEq, Eq)]
@@ -88,12 +94,11 @@ impl<E: PairingEngine> KZG10<E> {
     /// Constructs public parameters when given as input the maximum degree `degree`
     /// for the polynomial commitment scheme.
     pub fn load_srs(max_degree: usize) -> Result<UniversalParams<E>, PCError> {
-        let params = UniversalParams::load()?;
-        params.download_powers_for(0..(max_degree + 1))?;
-        Ok(params)
+        Ok(UniversalParams::load_up_to(max_degree)?)
     }
 
     /// Outputs a commitment to `polynomial`.
+    #[cfg(feature = "prover")]
     pub fn commit(
         powers: &Powers<E>,
         polynomial: &Polynomial<'_, E::Fr>,
@@ -154,7 +159,32 @@ impl<E: PairingEngine> KZG10<E> {
         Ok((KZGCommitment(commitment.into()), randomness))
     }
 
+    /// Outputs a commitment to `polynomial`, hidden by the given `randomness`.
+    #[cfg(feature = "prover")]
+    pub(crate) fn commit_with_randomness(
+        powers: &Powers<E>,
+        polynomial: &DensePolynomial<E::Fr>,
+        randomness: &KZGRandomness<E>,
+    ) -> Result<KZGCommitment<E>, PCError> {
+        Self::check_degree_is_too_large(polynomial.degree(), powers.size())?;
+        Self::check_degree_is_too_large(
+            randomness.blinding_polynomial.degree(),
+            powers.powers_of_beta_times_gamma_g.len(),
+        )?;
+
+        let (num_leading_zeros, plain_coeffs) = skip_leading_zeros_and_convert_to_bigints(polynomial);
+        let bases = &powers.powers_of_beta_g[num_leading_zeros..(num_leading_zeros + plain_coeffs.len())];
+        let mut commitment = VariableBase::msm(bases, &plain_coeffs);
+
+        if randomness.is_hiding() {
+            let random_ints = convert_to_bigints(&randomness.blinding_polynomial.coeffs);
+            commitment += VariableBase::msm(&powers.powers_of_beta_times_gamma_g, &random_ints);
+        }
+        Ok(KZGCommitment(commitment.to_affine()))
+    }
+
     /// Outputs a commitment to `polynomial`.
+    #[cfg(feature = "prover")]
     pub fn commit_lagrange(
         lagrange_basis: &LagrangeBasis<E>,
         evaluations: &[E::Fr],
@@ -209,6 +239,7 @@ impl<E: PairingEngine> KZG10<E> {
     /// The witness polynomial w(x) the quotient of the division (p(x) - p(z)) / (x - z)
     /// Observe that this quotient does not change with z because
     /// p(z) is the remainder term. We can therefore omit p(z) when computing the quotient.
+    #[cfg(feature = "prover")]
     pub fn compute_witness_polynomial(
         polynomial: &DensePolynomial<E::Fr>,
         point: E::Fr,
@@ -234,6 +265,7 @@ impl<E: PairingEngine> KZG10<E> {
         Ok((witness_polynomial, random_witness_polynomial))
     }
 
+    #[cfg(feature = "prover")]
     pub(crate) fn open_with_witness_polynomial(
         powers: &Powers<E>,
         point: E::Fr,
@@ -270,6 +302,7 @@ impl<E: PairingEngine> KZG10<E> {
 
     /// On input a polynomial `p` in Lagrange basis, and a point `point`,
     /// outputs an evaluation proof for the same.
+    #[cfg(feature = "prover")]
     pub fn open_lagrange(
         lagrange_basis: &LagrangeBasis<E>,
         domain_elements: &[E::Fr],
@@ -300,6 +333,7 @@ impl<E: PairingEngine> KZG10<E> {
     }
 
     /// On input a polynomial `p` and a point `point`, outputs a proof for the same.
+    #[cfg(feature = "prover")]
     pub fn open(
         powers: &Powers<E>,
         polynomial: &DensePolynomial<E::Fr>,
@@ -352,6 +386,30 @@ impl<E: PairingEngine> KZG10<E> {
         values: &[E::Fr],
         proofs: &[KZGProof<E>],
         rng: &mut R,
+    ) -> Result<bool> {
+        // We don't need to sample randomizers from the full field,
+        // only from 128-bit strings.
+        let randomizers = (0..commitments.len())
+            .map(|i| match i {
+                0 => E::Fr::one(),
+                _ => u128::rand(rng).into(),
+            })
+            .collect::<Vec<_>>();
+        Self::batch_check_with_randomizers(vk, commitments, points, values, proofs, &randomizers)
+    }
+
+    /// Check that each `proof_i` in `proofs` is a valid proof of evaluation for
+    /// `commitment_i` at `point_i`, with a single pairing check over the combination
+    /// of the proofs with the given `randomizers`.
+    ///
+    /// The randomizers must be unpredictable to the provers, e.g. derived from a hash of the proofs.
+    pub fn batch_check_with_randomizers(
+        vk: &VerifierKey<E>,
+        commitments: &[KZGCommitment<E>],
+        points: &[E::Fr],
+        values: &[E::Fr],
+        proofs: &[KZGProof<E>],
+        randomizers: &[E::Fr],
     ) -> Result<bool> {
         let check_time = start_timer!(|| format!("Checking {} evaluation proofs", commitments.len()));
         let g = vk.g.to_projective();
@@ -361,7 +419,6 @@ impl<E: PairingEngine> KZG10<E> {
         let mut total_w = <E::G1Projective>::zero();
 
         let combination_time = start_timer!(|| "Combining commitments and proofs");
-        let mut randomizer = E::Fr::one();
         // Instead of multiplying g and gamma_g in each turn, we simply accumulate
         // their coefficients and perform a final multiplication at the end.
         let mut g_multiplier = E::Fr::zero();
@@ -369,7 +426,10 @@ impl<E: PairingEngine> KZG10<E> {
         ensure!(commitments.len() == points.len());
         ensure!(commitments.len() == values.len());
         ensure!(commitments.len() == proofs.len());
-        for (((c, z), v), proof) in commitments.iter().zip_eq(points).zip_eq(values).zip_eq(proofs) {
+        ensure!(commitments.len() == randomizers.len());
+        for ((((c, z), v), proof), &randomizer) in
+            commitments.iter().zip_eq(points).zip_eq(values).zip_eq(proofs).zip_eq(randomizers)
+        {
             let w = proof.w;
             let mut temp = w.mul(*z);
             temp.add_assign_mixed(&c.0);
@@ -380,9 +440,6 @@ impl<E: PairingEngine> KZG10<E> {
             }
             total_c += &c.mul(randomizer);
             total_w += &w.mul(randomizer);
-            // We don't need to sample randomizers from the full field,
-            // only from 128-bit strings.
-            randomizer = u128::rand(rng).into();
         }
         total_c -= &g.mul(g_multiplier);
         total_c -= &gamma_g.mul(gamma_g_multiplier);
@@ -403,6 +460,7 @@ impl<E: PairingEngine> KZG10<E> {
         Ok(result)
     }
 
+    #[cfg(feature = "prover")]
     pub(crate) fn check_degree_is_too_large(degree: usize, num_powers: usize) -> Result<(), PCError> {
         let num_coefficients = degree + 1;
         if num_coefficients > num_powers {
@@ -412,6 +470,7 @@ impl<E: PairingEngine> KZG10<E> {
         }
     }
 
+    #[cfg(feature = "prover")]
     pub(crate) fn check_hiding_bound(hiding_poly_degree: usize, num_powers: usize) -> Result<(), PCError> {
         if hiding_poly_degree == 0 {
             Err(PCError::HidingBoundIsZero)
@@ -424,6 +483,7 @@ impl<E: PairingEngine> KZG10<E> {
         }
     }
 
+    #[cfg(feature = "prover")]
     pub(crate) fn check_degrees_and_bounds<'a>(
         max_degree: usize,
         enforced_degree_bounds: Option<&[usize]>,
@@ -451,6 +511,7 @@ impl<E: PairingEngine> KZG10<E> {
     }
 }
 
+#[cfg(feature = "prover")]
 fn skip_leading_zeros_and_convert_to_bigints<F: PrimeField>(p: &DensePolynomial<F>) -> (usize, Vec<F::BigInteger>) {
     if p.coeffs.is_empty() {
         (0, vec![])
@@ -465,6 +526,7 @@ fn skip_leading_zeros_and_convert_to_bigints<F: PrimeField>(p: &DensePolynomial<
     }
 }
 
+#[cfg(feature = "prover")]
 fn convert_to_bigints<F: PrimeField>(p: &[F]) -> Vec<F::BigInteger> {
     let to_bigint_time = start_timer!(|| "Converting polynomial coeffs to bigints");
     let coeffs = cfg_iter!(p).map(|s| s.to_bigint()).collect::<Vec<_>>();
@@ -531,6 +593,34 @@ mod tests {
         assert_eq!(&pp_bytes, &pp_recovered_bytes);
     }
 
+    #[test]
+    fn test_kzg10_universal_params_load_up_to() {
+        use snarkvm_utilities::CanonicalDeserialize;
+
+        // Load the powers for a degree within the hard-coded powers.
+        let pp = UniversalParams::<Bls12_377>::load_up_to(100).unwrap();
+        assert_eq!(pp.loaded_degree(), (1 << 15) - 1);
+
+        // Extend the powers to a degree that is not a power of two, and then on demand to the next power of two.
+        pp.extend_degree(40_000).unwrap();
+        assert_eq!(pp.loaded_degree(), 40_000);
+        let powers = pp.powers_of_beta_g(0, 50_000).unwrap();
+        assert_eq!(pp.loaded_degree(), (1 << 16) - 1);
+
+        // Ensure the powers match the parameter files, when loaded fully into memory.
+        let mut expected = Vec::<<Bls12_377 as PairingEngine>::G1Affine>::deserialize_uncompressed_unchecked(
+            &*snarkvm_parameters::mainnet::Degree15::load_bytes().unwrap(),
+        )
+        .unwrap();
+        expected.extend(
+            Vec::<<Bls12_377 as PairingEngine>::G1Affine>::deserialize_uncompressed_unchecked(
+                &*snarkvm_parameters::mainnet::Degree16::load_bytes().unwrap(),
+            )
+            .unwrap(),
+        );
+        assert_eq!(powers, expected[..50_000]);
+    }
+
     fn end_to_end_test_template<E: PairingEngine>() -> Result<(), PCError> {
         let rng = &mut TestRng::default();
         for _ in 0..100 {
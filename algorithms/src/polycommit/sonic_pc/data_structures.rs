@@ -14,8 +14,8 @@
 
 use super::{LabeledPolynomial, PolynomialInfo};
 use crate::{crypto_hash::sha256::sha256, fft::EvaluationDomain, polycommit::kzg10};
-use snarkvm_curves::PairingEngine;
-use snarkvm_fields::{ConstraintFieldError, Field, PrimeField, ToConstraintField};
+use snarkvm_curves::{PairingEngine, ProjectiveCurve};
+use snarkvm_fields::{ConstraintFieldError, Field, PrimeField, ToConstraintField, Zero};
 use snarkvm_utilities::{error, serialize::*, FromBytes, ToBytes};
 
 use hashbrown::HashMap;
@@ -395,6 +395,33 @@ impl<E: PairingEngine> BatchProof<E> {
     }
 }
 
+/// Evaluation proof at a query set, aggregated into a single proof that is checked with a single pairing check.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize)]
+pub struct AggregateProof<E: PairingEngine> {
+    /// The commitment to the combined witness polynomial of the queries.
+    pub(crate) w: Commitment<E>,
+    /// The evaluation proof of the combined polynomial at the aggregation challenge.
+    pub(crate) proof: kzg10::KZGProof<E>,
+}
+
+impl<E: PairingEngine> AggregateProof<E> {
+    pub fn is_hiding(&self) -> bool {
+        self.proof.is_hiding()
+    }
+}
+
+impl<E: PairingEngine> FromBytes for AggregateProof<E> {
+    fn read_le<R: Read>(mut reader: R) -> io::Result<Self> {
+        CanonicalDeserialize::deserialize_compressed(&mut reader).map_err(|_| error("could not deserialize struct"))
+    }
+}
+
+impl<E: PairingEngine> ToBytes for AggregateProof<E> {
+    fn write_le<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        CanonicalSerialize::serialize_compressed(self, &mut writer).map_err(|_| error("could not serialize struct"))
+    }
+}
+
 /// Labels a `LabeledPolynomial` or a `LabeledCommitment`.
 pub type PolynomialLabel = String;
 
@@ -679,3 +706,36 @@ impl<E: PairingEngine> ToBytes for BatchLCProof<E> {
         CanonicalSerialize::serialize_compressed(self, &mut writer).map_err(|_| error("could not serialize struct"))
     }
 }
+
+/// The pairing check of a batch opening, deferred so that it may be combined with the pairing checks of other batch openings.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PairingCheck<E: PairingEngine> {
+    /// The combined commitments, for each degree bound.
+    pub(super) combined_comms: BTreeMap<Option<usize>, E::G1Projective>,
+    /// The combined witness.
+    pub(super) combined_witness: E::G1Projective,
+    /// The combined adjusted witness.
+    pub(super) combined_adjusted_witness: E::G1Projective,
+}
+
+impl<E: PairingEngine> PairingCheck<E> {
+    /// Adds the given pairing check, scaled by the given randomizer, to this pairing check.
+    pub fn accumulate(&mut self, other: &Self, randomizer: E::Fr) {
+        for (degree_bound, comm) in &other.combined_comms {
+            *self.combined_comms.entry(*degree_bound).or_insert_with(E::G1Projective::zero) += *comm * randomizer;
+        }
+        self.combined_witness += other.combined_witness * randomizer;
+        self.combined_adjusted_witness += other.combined_adjusted_witness * randomizer;
+    }
+
+    /// Returns the group elements of the pairing check, i.e. to absorb them into a sponge.
+    pub fn to_commitments(&self) -> Vec<Commitment<E>> {
+        let elements = self
+            .combined_comms
+            .values()
+            .copied()
+            .chain([self.combined_witness, self.combined_adjusted_witness])
+            .collect::<Vec<_>>();
+        E::G1Projective::batch_normalization_into_affine(elements).into_iter().map(kzg10::KZGCommitment).collect()
+    }
+}
@@ -12,25 +12,29 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[cfg(feature = "prover")]
+use crate::{fft::DensePolynomial, polycommit::optional_rng::OptionalRng, srs::UniversalProver};
 use crate::{
-    fft::DensePolynomial,
     msm::variable_base::VariableBase,
-    polycommit::{kzg10, optional_rng::OptionalRng, PCError},
-    srs::{UniversalProver, UniversalVerifier},
+    polycommit::{kzg10, PCError},
+    srs::UniversalVerifier,
     AlgebraicSponge,
 };
+#[cfg(feature = "prover")]
 use hashbrown::HashMap;
 use itertools::Itertools;
 use snarkvm_curves::traits::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
-use snarkvm_fields::{One, Zero};
+use snarkvm_fields::{Field, One, Zero};
 
+#[cfg(feature = "prover")]
+use anyhow::anyhow;
 use anyhow::{bail, ensure, Result};
 use core::{convert::TryInto, marker::PhantomData, ops::Mul};
+#[cfg(feature = "prover")]
 use rand_core::{RngCore, SeedableRng};
-use std::{
-    borrow::Borrow,
-    collections::{BTreeMap, BTreeSet},
-};
+#[cfg(feature = "prover")]
+use std::borrow::Borrow;
+use std::collections::{BTreeMap, BTreeSet};
 
 mod data_structures;
 pub use data_structures::*;
@@ -58,6 +62,7 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         kzg10::KZG10::load_srs(max_degree).map_err(Into::into)
     }
 
+    #[cfg(feature = "prover")]
     pub fn trim(
         pp: &UniversalParams<E>,
         supported_degree: usize,
@@ -172,6 +177,7 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
     ///
     /// If for some `i`, `polynomials[i].degree_bound().is_some()`, then that
     /// polynomial will have the corresponding degree bound enforced.
+    #[cfg(feature = "prover")]
     #[allow(clippy::format_push_string)]
     pub fn commit<'b>(
         universal_prover: &UniversalProver<E>,
@@ -255,6 +261,7 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         Ok((labeled_comms, randomness))
     }
 
+    #[cfg(feature = "prover")]
     pub fn combine_for_open<'a>(
         universal_prover: &UniversalProver<E>,
         ck: &CommitterUnionKey<E>,
@@ -282,6 +289,7 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
 
     /// On input a list of labeled polynomials and a query set, `open` outputs a proof of evaluation
     /// of the polynomials at the points in the query set.
+    #[cfg(feature = "prover")]
     pub fn batch_open<'a>(
         universal_prover: &UniversalProver<E>,
         ck: &CommitterUnionKey<E>,
@@ -348,6 +356,22 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         proof: &BatchProof<E>,
         fs_rng: &mut S,
     ) -> Result<bool>
+    where
+        Commitment<E>: 'a,
+    {
+        let pairing_check = Self::batch_check_deferred(vk, commitments, query_set, values, proof, fs_rng)?;
+        Self::check_pairing(vk, pairing_check)
+    }
+
+    /// Returns the pairing check for `batch_check`, without computing the pairings.
+    pub fn batch_check_deferred<'a>(
+        vk: &UniversalVerifier<E>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
+        query_set: &QuerySet<E::Fr>,
+        values: &Evaluations<E::Fr>,
+        proof: &BatchProof<E>,
+        fs_rng: &mut S,
+    ) -> Result<PairingCheck<E>>
     where
         Commitment<E>: 'a,
     {
@@ -404,11 +428,226 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
             randomizer = fs_rng.squeeze_short_nonnative_field_element::<E::Fr>();
         }
 
-        let result = Self::check_elems(vk, combined_comms, combined_witness, combined_adjusted_witness);
         end_timer!(batch_check_time);
-        result.map_err(Into::into)
+        Ok(PairingCheck { combined_comms, combined_witness, combined_adjusted_witness })
+    }
+
+    /// Returns `true` if the given pairing check holds.
+    pub fn check_pairing(vk: &UniversalVerifier<E>, pairing_check: PairingCheck<E>) -> Result<bool> {
+        let PairingCheck { combined_comms, combined_witness, combined_adjusted_witness } = pairing_check;
+        Self::check_elems(vk, combined_comms, combined_witness, combined_adjusted_witness)
+    }
+
+    /// Returns `true` if all of the given pairing checks hold, using a single product of pairings.
+    ///
+    /// The pairing checks are combined with random coefficients squeezed from the given sponge,
+    /// after absorbing every pairing check, so that a failing pairing check cannot be cancelled out by another.
+    pub fn check_pairings(
+        vk: &UniversalVerifier<E>,
+        pairing_checks: Vec<PairingCheck<E>>,
+        fs_rng: &mut S,
+    ) -> Result<bool> {
+        let mut pairing_checks = pairing_checks.into_iter();
+        let Some(mut combined) = pairing_checks.next() else { bail!("There are no pairing checks to combine") };
+        let pairing_checks = pairing_checks.collect::<Vec<_>>();
+        if pairing_checks.is_empty() {
+            return Self::check_pairing(vk, combined);
+        }
+
+        let combine_time = start_timer!(|| format!("Combining {} pairing checks", pairing_checks.len() + 1));
+        // Absorb every pairing check, before squeezing the random coefficients.
+        fs_rng.absorb_native_field_elements(&combined.to_commitments());
+        for pairing_check in &pairing_checks {
+            fs_rng.absorb_native_field_elements(&pairing_check.to_commitments());
+        }
+        let randomizers = fs_rng.squeeze_short_nonnative_field_elements::<E::Fr>(pairing_checks.len());
+        // Combine the pairing checks.
+        for (pairing_check, randomizer) in pairing_checks.iter().zip_eq(randomizers) {
+            combined.accumulate(pairing_check, randomizer);
+        }
+        end_timer!(combine_time);
+
+        Self::check_pairing(vk, combined)
+    }
+
+    /// On input a list of labeled polynomials and a query set, `aggregate_open` outputs a single proof
+    /// of evaluation of the polynomials at the points in the query set.
+    ///
+    /// For the challenges `c_i` of the queries `(p_i, z_i)`, the proof commits to the combined witness
+    /// `q(X) = sum_i c_i (p_i(X) - p_i(z_i)) / (X - z_i)`, and opens the polynomial
+    /// `L(X) = sum_i c_i (p_i(X) - p_i(z_i)) / (r - z_i) - q(X)` to zero at the challenge `r`.
+    /// Polynomials with degree bounds are not supported.
+    #[cfg(feature = "prover")]
+    pub fn aggregate_open<'a>(
+        universal_prover: &UniversalProver<E>,
+        ck: &CommitterUnionKey<E>,
+        labeled_polynomials: impl ExactSizeIterator<Item = &'a LabeledPolynomial<E::Fr>>,
+        query_set: &QuerySet<E::Fr>,
+        rands: impl ExactSizeIterator<Item = &'a Randomness<E>>,
+        fs_rng: &mut S,
+    ) -> Result<AggregateProof<E>>
+    where
+        Randomness<E>: 'a,
+        Commitment<E>: 'a,
+    {
+        ensure!(labeled_polynomials.len() == rands.len());
+        let poly_rand: HashMap<_, _> =
+            labeled_polynomials.into_iter().zip_eq(rands).map(|(poly, r)| (poly.label(), (poly, r))).collect();
+
+        let open_time = start_timer!(|| format!(
+            "Aggregating the openings of {} polynomials at query set of size {}",
+            poly_rand.len(),
+            query_set.len(),
+        ));
+
+        let mut query_to_labels_map = BTreeMap::new();
+
+        for (label, (point_name, point)) in query_set.iter() {
+            let labels = query_to_labels_map.entry(point_name).or_insert((point, BTreeSet::new()));
+            labels.1.insert(label);
+        }
+
+        // Combine the polynomials at each point, and accumulate the witness polynomials of each point.
+        let mut combined_queries = Vec::with_capacity(query_to_labels_map.len());
+        let mut witness = DensePolynomial::zero();
+        let mut witness_rand = Randomness::empty();
+        for (_point_name, (&query, labels)) in query_to_labels_map.into_iter() {
+            let mut to_combine = Vec::with_capacity(labels.len());
+            for label in labels {
+                let (polynomial, rand) =
+                    poly_rand.get(label as &str).ok_or(PCError::MissingPolynomial { label: label.to_string() })?;
+                if polynomial.degree_bound().is_some() {
+                    bail!(PCError::EquationHasDegreeBounds(label.to_string()));
+                }
+                kzg10::KZG10::<E>::check_degrees_and_bounds(universal_prover.max_degree, None, *polynomial)?;
+
+                let challenge = fs_rng.squeeze_short_nonnative_field_element::<E::Fr>();
+                to_combine.push((challenge, polynomial.polynomial().to_dense(), *rand));
+            }
+            let (polynomial, rand) = Self::combine_polynomials(to_combine);
+
+            let (query_witness, query_hiding_witness) =
+                kzg10::KZG10::<E>::compute_witness_polynomial(&polynomial, query, &rand)?;
+            witness += &query_witness;
+            if let Some(query_hiding_witness) = query_hiding_witness {
+                witness_rand.blinding_polynomial += &query_hiding_witness;
+            }
+            combined_queries.push((query, polynomial, rand));
+        }
+
+        // Commit to the combined witness polynomial, and sample the aggregation challenge.
+        let powers = ck.powers();
+        let w = kzg10::KZG10::commit_with_randomness(&powers, &witness, &witness_rand)?;
+        fs_rng.absorb_native_field_elements(&[w]);
+        let challenge = fs_rng.squeeze_nonnative_field_elements::<E::Fr>(1)[0];
+
+        // Compute the polynomial `L`, which evaluates to zero at the aggregation challenge.
+        let mut polynomial = -witness;
+        let mut rand = Randomness::empty();
+        rand += (-E::Fr::one(), &witness_rand);
+        for (query, query_polynomial, query_rand) in &combined_queries {
+            let coeff = (challenge - query).inverse().ok_or_else(|| anyhow!("The challenge is a query point"))?;
+            polynomial += (coeff, query_polynomial);
+            rand += (coeff, query_rand);
+        }
+
+        let proof_time = start_timer!(|| "Creating proof");
+        let proof = kzg10::KZG10::open(&powers, &polynomial, challenge, &rand)?;
+        end_timer!(proof_time);
+
+        end_timer!(open_time);
+        Ok(AggregateProof { w, proof })
+    }
+
+    /// Checks the aggregated proof that `values` are the true evaluations at `query_set`
+    /// of the polynomials committed in `commitments`, with a single pairing check.
+    pub fn aggregate_check<'a>(
+        vk: &UniversalVerifier<E>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
+        query_set: &QuerySet<E::Fr>,
+        values: &Evaluations<E::Fr>,
+        proof: &AggregateProof<E>,
+        fs_rng: &mut S,
+    ) -> Result<bool>
+    where
+        Commitment<E>: 'a,
+    {
+        let commitments: BTreeMap<_, _> = commitments.into_iter().map(|c| (c.label().to_owned(), c)).collect();
+        let check_time = start_timer!(|| format!(
+            "Checking the aggregated openings of {} commitments at query set of size {}",
+            commitments.len(),
+            query_set.len(),
+        ));
+        let mut query_to_labels_map = BTreeMap::new();
+
+        for (label, (point_name, point)) in query_set.iter() {
+            let labels = query_to_labels_map.entry(point_name).or_insert((point, BTreeSet::new()));
+            labels.1.insert(label);
+        }
+
+        // Sample the challenges of the queries, in the same order as the prover.
+        let mut combined_queries = Vec::with_capacity(query_to_labels_map.len());
+        for (_point_name, (&query, labels)) in query_to_labels_map.into_iter() {
+            let mut to_combine = Vec::with_capacity(labels.len());
+            for label in labels {
+                let commitment =
+                    commitments.get(label).ok_or(PCError::MissingPolynomial { label: label.to_string() })?;
+                if commitment.degree_bound().is_some() {
+                    bail!(PCError::EquationHasDegreeBounds(label.to_string()));
+                }
+                let value = values
+                    .get(&(label.clone(), query))
+                    .ok_or(PCError::MissingEvaluation { label: label.to_string() })?;
+
+                let challenge = fs_rng.squeeze_short_nonnative_field_element::<E::Fr>();
+                to_combine.push((challenge, commitment.commitment().0, *value));
+            }
+            combined_queries.push((query, to_combine));
+        }
+
+        // Sample the aggregation challenge.
+        fs_rng.absorb_native_field_elements(&[proof.w]);
+        let challenge = fs_rng.squeeze_nonnative_field_elements::<E::Fr>(1)[0];
+
+        // Compute the commitment to `L - L(challenge)`, shifted by `challenge * proof`, so that the check is
+        // `e(L - L(challenge) + challenge * proof, H) = e(proof, beta * H)`.
+        let mut bases = Vec::with_capacity(query_set.len() + 4);
+        let mut coeffs = Vec::with_capacity(query_set.len() + 4);
+        let mut combined_value = E::Fr::zero();
+        for (query, to_combine) in combined_queries {
+            let Some(coeff) = (challenge - query).inverse() else {
+                return Ok(false);
+            };
+            for (query_challenge, commitment, value) in to_combine {
+                let coeff = coeff * query_challenge;
+                combined_value += coeff * value;
+                bases.push(commitment);
+                coeffs.push(coeff);
+            }
+        }
+        bases.extend([vk.vk.g, proof.w.0, proof.proof.w]);
+        coeffs.extend([-combined_value, -E::Fr::one(), challenge]);
+        if let Some(random_v) = proof.proof.random_v {
+            bases.push(vk.vk.gamma_g);
+            coeffs.push(-random_v);
+        }
+        let coeffs = coeffs.into_iter().map(|c| c.into()).collect::<Vec<_>>();
+        let combined_commitment = VariableBase::msm(&bases, &coeffs).to_affine();
+
+        let result = E::product_of_pairings(
+            [
+                (&combined_commitment.prepare(), &vk.vk.prepared_h),
+                (&(-proof.proof.w).prepare(), &vk.vk.prepared_beta_h),
+            ]
+            .iter()
+            .copied(),
+        )
+        .is_one();
+        end_timer!(check_time, || format!("Result: {result}"));
+        Ok(result)
     }
 
+    #[cfg(feature = "prover")]
     pub fn open_combinations<'a>(
         universal_prover: &UniversalProver<E>,
         ck: &CommitterUnionKey<E>,
@@ -482,6 +721,31 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         proof: &BatchLCProof<E>,
         fs_rng: &mut S,
     ) -> Result<bool>
+    where
+        Commitment<E>: 'a,
+    {
+        let pairing_check = Self::check_combinations_deferred(
+            vk,
+            linear_combinations,
+            commitments,
+            query_set,
+            evaluations,
+            proof,
+            fs_rng,
+        )?;
+        Self::check_pairing(vk, pairing_check)
+    }
+
+    /// Returns the pairing check for `check_combinations`, without computing the pairings.
+    pub fn check_combinations_deferred<'a>(
+        vk: &UniversalVerifier<E>,
+        linear_combinations: impl IntoIterator<Item = &'a LinearCombination<E::Fr>>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
+        query_set: &QuerySet<E::Fr>,
+        evaluations: &Evaluations<E::Fr>,
+        proof: &BatchLCProof<E>,
+        fs_rng: &mut S,
+    ) -> Result<PairingCheck<E>>
     where
         Commitment<E>: 'a,
     {
@@ -539,11 +803,12 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
             .collect::<Vec<_>>();
         end_timer!(combined_comms_norm_time);
 
-        Self::batch_check(vk, &lc_commitments, query_set, &evaluations, proof, fs_rng)
+        Self::batch_check_deferred(vk, &lc_commitments, query_set, &evaluations, proof, fs_rng)
     }
 }
 
 impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
+    #[cfg(feature = "prover")]
     fn combine_polynomials<'a, B: Borrow<DensePolynomial<E::Fr>>>(
         coeffs_polys_rands: impl IntoIterator<Item = (E::Fr, B, &'a Randomness<E>)>,
     ) -> (DensePolynomial<E::Fr>, Randomness<E>) {
@@ -777,6 +1042,13 @@ mod tests {
         println!("Finished bls12-377");
     }
 
+    #[test]
+    fn test_aggregate_open() {
+        crate::polycommit::test_templates::aggregate_test_template::<Bls12_377, Sponge>()
+            .expect("test failed for bls12-377");
+        println!("Finished bls12-377");
+    }
+
     #[test]
     fn test_lagrange_commitment() {
         crate::polycommit::test_templates::lagrange_test_template::<Bls12_377, Sponge>()
@@ -128,8 +128,8 @@ pub fn bad_degree_bound_test<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>>() -
     Ok(())
 }
 
-pub fn lagrange_test_template<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>>()
--> Result<Vec<TestComponents<E, S>>, PCError> {
+pub fn lagrange_test_template<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>>(
+) -> Result<Vec<TestComponents<E, S>>, PCError> {
     let num_iters = 10usize;
     let max_degree = 256usize;
     let supported_degree = 127usize;
@@ -242,6 +242,66 @@ pub fn lagrange_test_template<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>>()
     Ok(test_components)
 }
 
+pub fn aggregate_test_template<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>>() -> Result<(), PCError> {
+    let rng = &mut TestRng::default();
+    let max_degree = 64;
+    let pp = SonicKZG10::<E, S>::load_srs(max_degree)?;
+    let universal_prover = &pp.to_universal_prover().unwrap();
+
+    for num_polynomials in 1..5 {
+        let supported_degree = distributions::Uniform::from(4..=max_degree).sample(rng);
+        let mut polynomials = Vec::new();
+        let mut labels = Vec::new();
+        for i in 0..num_polynomials {
+            let label = format!("Test{i}");
+            labels.push(label.clone());
+            let degree = distributions::Uniform::from(1..=supported_degree).sample(rng);
+            let poly = DensePolynomial::rand(degree, rng);
+            let hiding_bound = if rng.gen() { Some(1) } else { None };
+            polynomials.push(LabeledPolynomial::new(label, poly, None, hiding_bound))
+        }
+        let (ck, vk) = SonicKZG10::<E, S>::trim(&pp, supported_degree, None, 1, None).unwrap();
+        let ck = CommitterUnionKey::union(std::iter::once(&ck));
+
+        let (comms, rands) =
+            SonicKZG10::<E, S>::commit(universal_prover, &ck, polynomials.iter().map(Into::into), Some(rng))?;
+
+        // Construct a query set, where each polynomial is queried at a subset of the points.
+        let mut query_set = QuerySet::new();
+        let mut values = Evaluations::new();
+        for point_id in 0..distributions::Uniform::from(1..=5).sample(rng) {
+            let point = E::Fr::rand(rng);
+            for (polynomial, label) in polynomials.iter().zip_eq(labels.iter()) {
+                if point_id == 0 || rng.gen() {
+                    query_set.insert((label.clone(), (format!("rand_{point_id}"), point)));
+                    values.insert((label.clone(), point), polynomial.evaluate(point));
+                }
+            }
+        }
+
+        let mut sponge_for_open = S::new();
+        let proof = SonicKZG10::aggregate_open(
+            universal_prover,
+            &ck,
+            polynomials.iter(),
+            &query_set,
+            rands.iter(),
+            &mut sponge_for_open,
+        )?;
+        let mut sponge_for_check = S::new();
+        let result = SonicKZG10::aggregate_check(&vk, &comms, &query_set, &values, &proof, &mut sponge_for_check)?;
+        assert!(result, "proof was incorrect, Query set: {query_set:#?}");
+
+        // Ensure an incorrect evaluation is rejected.
+        let (key, value) = values.iter().next().map(|(key, value)| (key.clone(), *value)).unwrap();
+        values.insert(key, value + E::Fr::one());
+        let mut sponge_for_check = S::new();
+        let result = SonicKZG10::aggregate_check(&vk, &comms, &query_set, &values, &proof, &mut sponge_for_check)?;
+        assert!(!result, "incorrect evaluation was accepted");
+    }
+    Ok(())
+}
+
 fn test_template<E, S>(info: TestInfo) -> Result<Vec<TestComponents<E, S>>, PCError>
 where
     E: PairingEngine,
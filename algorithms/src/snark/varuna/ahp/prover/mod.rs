@@ -26,4 +26,4 @@ pub(crate) use oracles::*;
 mod round_functions;
 
 mod state;
-use state::*;
+pub(crate) use state::*;
@@ -23,6 +23,12 @@ pub use data_structures::*;
 mod varuna;
 pub use varuna::*;
 
+/// Implements a resumable Varuna prover, which is driven one step at a time.
+#[cfg(feature = "prover")]
+mod resumable;
+#[cfg(feature = "prover")]
+pub use resumable::*;
+
 /// Specifies the SNARK mode.
 mod mode;
 pub use mode::*;
@@ -0,0 +1,568 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    polycommit::sonic_pc::{
+        Commitment,
+        CommitterUnionKey,
+        LabeledCommitment,
+        LabeledPolynomial,
+        LabeledPolynomialWithBasis,
+        Randomness,
+        SonicKZG10,
+    },
+    r1cs::ConstraintSynthesizer,
+    snark::varuna::{
+        ahp::{indexer::CircuitInfo, verifier, AHPError, AHPForR1CS, CircuitId, EvaluationsProvider},
+        proof,
+        prover,
+        CircuitProvingKey,
+        Proof,
+        SNARKMode,
+        VarunaSNARK,
+    },
+    srs::UniversalProver,
+    AlgebraicSponge,
+    SNARKError,
+};
+use snarkvm_curves::PairingEngine;
+
+use anyhow::{anyhow, bail, ensure, Result};
+use itertools::Itertools;
+use rand::{CryptoRng, Rng};
+use std::{collections::BTreeMap, ops::Deref};
+
+/// The progress of a resumable Varuna prover.
+#[derive(Clone, Debug)]
+pub enum ProverStep<E: PairingEngine> {
+    /// The prover has completed `completed` of its `total` steps, and must be stepped again.
+    Pending { completed: usize, total: usize },
+    /// The prover has completed all of its steps, and produced the given proof.
+    Done(Proof<E>),
+}
+
+/// A Varuna prover that is driven incrementally, one step at a time.
+///
+/// Each call to `Prover::step` runs one stage of the prover (the witness synthesis, one of the five AHP rounds,
+/// or the opening of the polynomial commitments), and then returns control to the caller.
+/// This allows single-threaded environments, such as WASM in the browser, to yield to their event loop
+/// and report progress between steps. Stepping the prover to completion is equivalent to `VarunaSNARK::prove_batch`.
+pub struct Prover<'a, E, FS, SM, C, R>
+where
+    E: PairingEngine,
+    FS: AlgebraicSponge<E::Fq, 2>,
+    SM: SNARKMode,
+    C: ConstraintSynthesizer<E::Fr>,
+    R: Rng + CryptoRng,
+{
+    /// The universal prover.
+    universal_prover: &'a UniversalProver<E>,
+    /// The Fiat-Shamir parameters.
+    fs_parameters: &'a FS::Parameters,
+    /// The proving keys, and the constraints to prove for each key.
+    keys_to_constraints: &'a BTreeMap<&'a CircuitProvingKey<E, SM>, &'a [C]>,
+    /// The union of the committer keys of the proving keys.
+    committer_key: CommitterUnionKey<'a, E>,
+    /// The RNG used for zero-knowledge.
+    zk_rng: &'a mut R,
+    /// The number of completed steps.
+    num_completed_steps: usize,
+
+    /// The Fiat-Shamir sponge.
+    sponge: Option<FS>,
+    /// The batch size of each circuit.
+    batch_sizes: BTreeMap<CircuitId, usize>,
+    /// The circuit info of each circuit.
+    circuit_infos: BTreeMap<CircuitId, &'a CircuitInfo>,
+    /// The public inputs of each circuit.
+    public_inputs: BTreeMap<CircuitId, Vec<Vec<E::Fr>>>,
+    /// The total number of instances in the batch.
+    total_instances: usize,
+
+    /// The AHP prover state.
+    prover_state: Option<prover::State<'a, E::Fr, SM>>,
+    /// The AHP verifier state.
+    verifier_state: Option<verifier::State<E::Fr, SM>>,
+    /// The first verifier message.
+    verifier_first_message: Option<verifier::FirstMessage<E::Fr>>,
+    /// The second verifier message.
+    verifier_second_message: Option<verifier::SecondMessage<E::Fr>>,
+    /// The third verifier message.
+    verifier_third_message: Option<verifier::ThirdMessage<E::Fr>>,
+    /// The fourth verifier message.
+    verifier_fourth_message: Option<verifier::FourthMessage<E::Fr>>,
+    /// The third prover message.
+    prover_third_message: Option<prover::ThirdMessage<E::Fr>>,
+    /// The fourth prover message.
+    prover_fourth_message: Option<prover::FourthMessage<E::Fr>>,
+    /// The second round oracles.
+    second_oracles: Option<prover::SecondOracles<E::Fr>>,
+    /// The third round oracles.
+    third_oracles: Option<prover::ThirdOracles<E::Fr>>,
+    /// The fourth round oracles.
+    fourth_oracles: Option<prover::FourthOracles<E::Fr>>,
+    /// The prover polynomials, gathered after the fifth round.
+    polynomials: Option<Vec<LabeledPolynomial<E::Fr>>>,
+    /// The commitments of each round.
+    commitments: Vec<Vec<LabeledCommitment<Commitment<E>>>>,
+    /// The commitment randomness of each round.
+    commitment_randomnesses: Vec<Vec<Randomness<E>>>,
+}
+
+impl<'a, E, FS, SM, C, R> Prover<'a, E, FS, SM, C, R>
+where
+    E: PairingEngine,
+    FS: AlgebraicSponge<E::Fq, 2>,
+    SM: SNARKMode,
+    C: ConstraintSynthesizer<E::Fr>,
+    R: Rng + CryptoRng,
+{
+    /// The number of steps of the prover.
+    pub const NUM_STEPS: usize = 7;
+
+    /// Initializes a new prover for the given proving keys and constraints.
+    pub fn new(
+        universal_prover: &'a UniversalProver<E>,
+        fs_parameters: &'a FS::Parameters,
+        keys_to_constraints: &'a BTreeMap<&'a CircuitProvingKey<E, SM>, &'a [C]>,
+        zk_rng: &'a mut R,
+    ) -> Result<Self> {
+        if keys_to_constraints.is_empty() {
+            bail!(SNARKError::EmptyBatch);
+        }
+
+        let committer_key = CommitterUnionKey::union(keys_to_constraints.keys().map(|pk| pk.committer_key.deref()));
+
+        Ok(Self {
+            universal_prover,
+            fs_parameters,
+            keys_to_constraints,
+            committer_key,
+            zk_rng,
+            num_completed_steps: 0,
+            sponge: None,
+            batch_sizes: BTreeMap::new(),
+            circuit_infos: BTreeMap::new(),
+            public_inputs: BTreeMap::new(),
+            total_instances: 0,
+            prover_state: None,
+            verifier_state: None,
+            verifier_first_message: None,
+            verifier_second_message: None,
+            verifier_third_message: None,
+            verifier_fourth_message: None,
+            prover_third_message: None,
+            prover_fourth_message: None,
+            second_oracles: None,
+            third_oracles: None,
+            fourth_oracles: None,
+            polynomials: None,
+            commitments: Vec::with_capacity(5),
+            commitment_randomnesses: Vec::with_capacity(5),
+        })
+    }
+
+    /// Returns the number of completed steps.
+    pub const fn num_completed_steps(&self) -> usize {
+        self.num_completed_steps
+    }
+
+    /// Runs the next step of the prover, and returns the proof once all steps are completed.
+    /// If a step fails, the prover cannot be stepped again.
+    pub fn step(&mut self) -> Result<ProverStep<E>> {
+        let result = match self.num_completed_steps {
+            0 => self.initialize().map(|_| None),
+            1 => self.first_round().map(|_| None),
+            2 => self.second_round().map(|_| None),
+            3 => self.third_round().map(|_| None),
+            4 => self.fourth_round().map(|_| None),
+            5 => self.fifth_round().map(|_| None),
+            6 => self.open().map(Some),
+            _ => bail!("The Varuna prover cannot be stepped further"),
+        };
+        match result {
+            Ok(proof) => {
+                self.num_completed_steps += 1;
+                match proof {
+                    Some(proof) => Ok(ProverStep::Done(proof)),
+                    None => Ok(ProverStep::Pending { completed: self.num_completed_steps, total: Self::NUM_STEPS }),
+                }
+            }
+            Err(error) => {
+                // Ensure the prover is not stepped again.
+                self.num_completed_steps = usize::MAX;
+                Err(error)
+            }
+        }
+    }
+
+    /// Runs the remaining steps of the prover, and returns the proof.
+    pub fn prove(mut self) -> Result<Proof<E>> {
+        loop {
+            if let ProverStep::Done(proof) = self.step()? {
+                return Ok(proof);
+            }
+        }
+    }
+}
+
+impl<'a, E, FS, SM, C, R> Prover<'a, E, FS, SM, C, R>
+where
+    E: PairingEngine,
+    FS: AlgebraicSponge<E::Fq, 2>,
+    SM: SNARKMode,
+    C: ConstraintSynthesizer<E::Fr>,
+    R: Rng + CryptoRng,
+{
+    /// Synthesizes the witnesses, and initializes the sponge with the public inputs and circuit commitments.
+    fn initialize(&mut self) -> Result<()> {
+        let keys_to_constraints = self.keys_to_constraints;
+
+        let mut circuits_to_constraints = BTreeMap::new();
+        for (pk, constraints) in keys_to_constraints {
+            circuits_to_constraints.insert(pk.circuit.deref(), *constraints);
+        }
+        let prover_state = AHPForR1CS::<_, SM>::init_prover(&circuits_to_constraints, &mut *self.zk_rng)?;
+
+        // extract information from the prover key and state to consume in further calculations
+        let mut inputs_and_batch_sizes = BTreeMap::new();
+        for pk in keys_to_constraints.keys() {
+            let batch_size = prover_state.batch_size(&pk.circuit).ok_or(SNARKError::CircuitNotFound)?;
+            let public_input = prover_state.public_inputs(&pk.circuit).ok_or(SNARKError::CircuitNotFound)?;
+            let padded_public_input =
+                prover_state.padded_public_inputs(&pk.circuit).ok_or(SNARKError::CircuitNotFound)?;
+            let circuit_id = pk.circuit.id;
+            self.batch_sizes.insert(circuit_id, batch_size);
+            self.circuit_infos.insert(circuit_id, &pk.circuit_verifying_key.circuit_info);
+            inputs_and_batch_sizes.insert(circuit_id, (batch_size, padded_public_input));
+            self.public_inputs.insert(circuit_id, public_input);
+            self.total_instances = self.total_instances.saturating_add(batch_size);
+        }
+        ensure!(prover_state.total_instances == self.total_instances);
+
+        let circuit_commitments =
+            keys_to_constraints.keys().map(|pk| pk.circuit_verifying_key.circuit_commitments.as_slice());
+
+        self.sponge = Some(VarunaSNARK::<E, FS, SM>::init_sponge(
+            self.fs_parameters,
+            &inputs_and_batch_sizes,
+            circuit_commitments,
+        ));
+        self.prover_state = Some(prover_state);
+        Ok(())
+    }
+
+    /// Runs the first round of the prover and verifier.
+    fn first_round(&mut self) -> Result<()> {
+        let prover_state = self.prover_state.take().ok_or_else(missing_state)?;
+        let prover_state = AHPForR1CS::<_, SM>::prover_first_round(prover_state, &mut *self.zk_rng)?;
+
+        let first_round_comm_time = start_timer!(|| "Committing to first round polys");
+        let first_round_oracles = prover_state.first_round_oracles.as_ref().ok_or_else(missing_state)?;
+        self.commit(first_round_oracles.iter().map(Into::into))?;
+        end_timer!(first_round_comm_time);
+
+        self.absorb(&[])?;
+
+        let (verifier_first_message, verifier_state) = AHPForR1CS::<_, SM>::verifier_first_round(
+            &self.batch_sizes,
+            &self.circuit_infos,
+            prover_state.max_constraint_domain,
+            prover_state.max_variable_domain,
+            prover_state.max_non_zero_domain,
+            self.sponge.as_mut().ok_or_else(missing_state)?,
+        )?;
+
+        self.prover_state = Some(prover_state);
+        self.verifier_first_message = Some(verifier_first_message);
+        self.verifier_state = Some(verifier_state);
+        Ok(())
+    }
+
+    /// Runs the second round of the prover and verifier.
+    fn second_round(&mut self) -> Result<()> {
+        let prover_state = self.prover_state.take().ok_or_else(missing_state)?;
+        let verifier_first_message = self.verifier_first_message.as_ref().ok_or_else(missing_state)?;
+        let (second_oracles, prover_state) =
+            AHPForR1CS::<_, SM>::prover_second_round(verifier_first_message, prover_state, &mut *self.zk_rng)?;
+
+        let second_round_comm_time = start_timer!(|| "Committing to second round polys");
+        self.commit(second_oracles.iter().map(Into::into))?;
+        end_timer!(second_round_comm_time);
+
+        self.absorb(&[])?;
+
+        let verifier_state = self.verifier_state.take().ok_or_else(missing_state)?;
+        let (verifier_second_message, verifier_state) = AHPForR1CS::<_, SM>::verifier_second_round(
+            verifier_state,
+            self.sponge.as_mut().ok_or_else(missing_state)?,
+        )?;
+
+        self.prover_state = Some(prover_state);
+        self.second_oracles = Some(second_oracles);
+        self.verifier_second_message = Some(verifier_second_message);
+        self.verifier_state = Some(verifier_state);
+        Ok(())
+    }
+
+    /// Runs the third round of the prover and verifier.
+    fn third_round(&mut self) -> Result<()> {
+        let prover_state = self.prover_state.take().ok_or_else(missing_state)?;
+        let verifier_first_message = self.verifier_first_message.as_ref().ok_or_else(missing_state)?;
+        let verifier_second_message = self.verifier_second_message.as_ref().ok_or_else(missing_state)?;
+        let (prover_third_message, third_oracles, prover_state) = AHPForR1CS::<_, SM>::prover_third_round(
+            verifier_first_message,
+            verifier_second_message,
+            prover_state,
+            &mut *self.zk_rng,
+        )?;
+
+        let third_round_comm_time = start_timer!(|| "Committing to third round polys");
+        self.commit(third_oracles.iter().map(Into::into))?;
+        end_timer!(third_round_comm_time);
+
+        self.absorb(&prover_third_message.sums.clone().into_iter().flatten().collect_vec())?;
+
+        let verifier_state = self.verifier_state.take().ok_or_else(missing_state)?;
+        let (verifier_third_message, verifier_state) =
+            AHPForR1CS::<_, SM>::verifier_third_round(verifier_state, self.sponge.as_mut().ok_or_else(missing_state)?)?;
+
+        self.prover_state = Some(prover_state);
+        self.prover_third_message = Some(prover_third_message);
+        self.third_oracles = Some(third_oracles);
+        self.verifier_third_message = Some(verifier_third_message);
+        self.verifier_state = Some(verifier_state);
+        Ok(())
+    }
+
+    /// Runs the fourth round of the prover and verifier.
+    fn fourth_round(&mut self) -> Result<()> {
+        let prover_state = self.prover_state.take().ok_or_else(missing_state)?;
+        let verifier_second_message = self.verifier_second_message.as_ref().ok_or_else(missing_state)?;
+        let verifier_third_message = self.verifier_third_message.as_ref().ok_or_else(missing_state)?;
+        let (prover_fourth_message, fourth_oracles, prover_state) = AHPForR1CS::<_, SM>::prover_fourth_round(
+            verifier_second_message,
+            verifier_third_message,
+            prover_state,
+            &mut *self.zk_rng,
+        )?;
+
+        let fourth_round_comm_time = start_timer!(|| "Committing to fourth round polys");
+        self.commit(fourth_oracles.iter().map(Into::into))?;
+        end_timer!(fourth_round_comm_time);
+
+        self.absorb(&prover_fourth_message.sums)?;
+
+        let verifier_state = self.verifier_state.take().ok_or_else(missing_state)?;
+        let (verifier_fourth_message, verifier_state) = AHPForR1CS::<_, SM>::verifier_fourth_round(
+            verifier_state,
+            self.sponge.as_mut().ok_or_else(missing_state)?,
+        )?;
+
+        self.prover_state = Some(prover_state);
+        self.prover_fourth_message = Some(prover_fourth_message);
+        self.fourth_oracles = Some(fourth_oracles);
+        self.verifier_fourth_message = Some(verifier_fourth_message);
+        self.verifier_state = Some(verifier_state);
+        Ok(())
+    }
+
+    /// Runs the fifth round of the prover and verifier, and gathers the prover polynomials.
+    fn fifth_round(&mut self) -> Result<()> {
+        let mut prover_state = self.prover_state.take().ok_or_else(missing_state)?;
+
+        // We take out values from state before they are consumed.
+        let first_round_oracles = prover_state.first_round_oracles.take().ok_or_else(missing_state)?;
+        let index_a_polys = prover_state
+            .circuit_specific_states
+            .values_mut()
+            .map(|s| s.a_polys.take().ok_or_else(missing_state))
+            .flatten_ok()
+            .collect::<Result<Vec<_>>>()?;
+        let index_b_polys = prover_state
+            .circuit_specific_states
+            .values_mut()
+            .map(|s| s.b_polys.take().ok_or_else(missing_state))
+            .flatten_ok()
+            .collect::<Result<Vec<_>>>()?;
+
+        let verifier_fourth_message = self.verifier_fourth_message.take().ok_or_else(missing_state)?;
+        let fifth_oracles =
+            AHPForR1CS::<_, SM>::prover_fifth_round(verifier_fourth_message, prover_state, &mut *self.zk_rng)?;
+
+        let fifth_round_comm_time = start_timer!(|| "Committing to fifth round polys");
+        self.commit(fifth_oracles.iter().map(Into::into))?;
+        end_timer!(fifth_round_comm_time);
+
+        self.absorb(&[])?;
+
+        let verifier_state = self.verifier_state.take().ok_or_else(missing_state)?;
+        let verifier_state =
+            AHPForR1CS::<_, SM>::verifier_fifth_round(verifier_state, self.sponge.as_mut().ok_or_else(missing_state)?)?;
+
+        // Gather prover polynomials in one vector.
+        let polynomials: Vec<_> = index_a_polys
+            .into_iter()
+            .chain(index_b_polys)
+            .chain(first_round_oracles.into_iter())
+            .chain(self.second_oracles.take().ok_or_else(missing_state)?.into_iter())
+            .chain(self.third_oracles.take().ok_or_else(missing_state)?.into_iter())
+            .chain(self.fourth_oracles.take().ok_or_else(missing_state)?.into_iter())
+            .chain(fifth_oracles.into_iter())
+            .collect();
+        let num_unique_circuits = self.keys_to_constraints.len();
+        ensure!(
+            polynomials.len()
+                == num_unique_circuits * 6 + // numerator and denominator for each matrix sumcheck
+            AHPForR1CS::<E::Fr, SM>::num_first_round_oracles(self.total_instances) +
+            AHPForR1CS::<E::Fr, SM>::num_second_round_oracles() +
+            AHPForR1CS::<E::Fr, SM>::num_third_round_oracles() +
+            AHPForR1CS::<E::Fr, SM>::num_fourth_round_oracles(num_unique_circuits) +
+            AHPForR1CS::<E::Fr, SM>::num_fifth_round_oracles()
+        );
+
+        self.polynomials = Some(polynomials);
+        self.verifier_state = Some(verifier_state);
+        Ok(())
+    }
+
+    /// Evaluates the prover polynomials over the query set, opens their commitments, and returns the proof.
+    fn open(&mut self) -> Result<Proof<E>> {
+        let num_unique_circuits = self.keys_to_constraints.len();
+        let polynomials = self.polynomials.take().ok_or_else(missing_state)?;
+        let [first_commitments, second_commitments, third_commitments, fourth_commitments, fifth_commitments]: [_; 5] =
+            core::mem::take(&mut self.commitments).try_into().map_err(|_| missing_state())?;
+
+        // Gather commitments in one vector.
+        let witness_comm_len = if SM::ZK { first_commitments.len() - 1 } else { first_commitments.len() };
+        let mask_poly = SM::ZK.then(|| *first_commitments[witness_comm_len].commitment());
+        let witness_commitments = first_commitments[..witness_comm_len]
+            .iter()
+            .map(|c| proof::WitnessCommitments { w: *c.commitment() })
+            .collect_vec();
+        let fourth_commitments_chunked = fourth_commitments.chunks_exact(3);
+        let (g_a_commitments, g_b_commitments, g_c_commitments) = fourth_commitments_chunked
+            .map(|c| (*c[0].commitment(), *c[1].commitment(), *c[2].commitment()))
+            .multiunzip();
+
+        #[rustfmt::skip]
+        let commitments = proof::Commitments {
+            witness_commitments,
+            mask_poly,
+            h_0: *second_commitments[0].commitment(),
+            g_1: *third_commitments[0].commitment(),
+            h_1: *third_commitments[1].commitment(),
+            g_a_commitments,
+            g_b_commitments,
+            g_c_commitments,
+            h_2: *fifth_commitments[0].commitment(),
+        };
+
+        // Gather commitment randomness together.
+        let indexer_randomness = vec![Randomness::<E>::empty(); 6 * num_unique_circuits];
+        let commitment_randomnesses: Vec<Randomness<E>> = indexer_randomness
+            .into_iter()
+            .chain(core::mem::take(&mut self.commitment_randomnesses).into_iter().flatten())
+            .collect();
+
+        let empty_randomness = Randomness::<E>::empty();
+        if SM::ZK {
+            ensure!(commitment_randomnesses.iter().any(|r| r != &empty_randomness));
+        } else {
+            ensure!(commitment_randomnesses.iter().all(|r| r == &empty_randomness));
+        }
+
+        // Compute the AHP verifier's query set.
+        let prover_third_message = self.prover_third_message.take().ok_or_else(missing_state)?;
+        let prover_fourth_message = self.prover_fourth_message.take().ok_or_else(missing_state)?;
+        let verifier_state = self.verifier_state.take().ok_or_else(missing_state)?;
+        let (query_set, verifier_state) = AHPForR1CS::<_, SM>::verifier_query_set(verifier_state);
+        let lc_s = AHPForR1CS::<_, SM>::construct_linear_combinations(
+            &self.public_inputs,
+            &polynomials,
+            &prover_third_message,
+            &prover_fourth_message,
+            &verifier_state,
+        )?;
+
+        let eval_time = start_timer!(|| "Evaluating linear combinations over query set");
+        let mut evaluations = BTreeMap::new();
+        for (label, (_, point)) in query_set.to_set() {
+            if !AHPForR1CS::<E::Fr, SM>::LC_WITH_ZERO_EVAL.contains(&label.as_str()) {
+                let lc = lc_s.get(&label).ok_or_else(|| AHPError::MissingEval(label.to_string()))?;
+                let evaluation = polynomials.get_lc_eval(lc, point)?;
+                evaluations.insert(label, evaluation);
+            }
+        }
+
+        let batch_sizes = core::mem::take(&mut self.batch_sizes);
+        let evaluations = proof::Evaluations::from_map(&evaluations, batch_sizes.clone());
+        end_timer!(eval_time);
+
+        let sponge = self.sponge.as_mut().ok_or_else(missing_state)?;
+        sponge.absorb_nonnative_field_elements(evaluations.to_field_elements());
+
+        let pc_proof = SonicKZG10::<E, FS>::open_combinations(
+            self.universal_prover,
+            &self.committer_key,
+            lc_s.values(),
+            polynomials,
+            &commitment_randomnesses,
+            &query_set.to_set(),
+            sponge,
+        )?;
+
+        let proof = Proof::<E>::new(
+            batch_sizes,
+            commitments,
+            evaluations,
+            prover_third_message,
+            prover_fourth_message,
+            pc_proof,
+        )?;
+        proof.check_batch_sizes()?;
+        ensure!(proof.pc_proof.is_hiding() == SM::ZK);
+
+        Ok(proof)
+    }
+
+    /// Commits to the given oracles, and records their commitments and commitment randomness.
+    fn commit<'b>(&mut self, oracles: impl IntoIterator<Item = LabeledPolynomialWithBasis<'b, E::Fr>>) -> Result<()> {
+        let (commitments, commitment_randomnesses) = SonicKZG10::<E, FS>::commit(
+            self.universal_prover,
+            &self.committer_key,
+            oracles,
+            SM::ZK.then_some(&mut *self.zk_rng),
+        )?;
+        self.commitments.push(commitments);
+        self.commitment_randomnesses.push(commitment_randomnesses);
+        Ok(())
+    }
+
+    /// Absorbs the latest commitments, and the given matrix sums, into the sponge.
+    fn absorb(&mut self, sums: &[prover::MatrixSums<E::Fr>]) -> Result<()> {
+        let commitments = self.commitments.last().ok_or_else(missing_state)?;
+        let sponge = self.sponge.as_mut().ok_or_else(missing_state)?;
+        match sums.is_empty() {
+            true => VarunaSNARK::<E, FS, SM>::absorb_labeled(commitments, sponge),
+            false => VarunaSNARK::<E, FS, SM>::absorb_labeled_with_sums(commitments, sums, sponge),
+        }
+        Ok(())
+    }
+}
+
+/// Returns the error for a prover that is missing the state of a previous step.
+fn missing_state() -> anyhow::Error {
+    anyhow!("The Varuna prover is missing the state of a previous step")
+}
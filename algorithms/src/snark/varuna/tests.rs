@@ -302,11 +302,13 @@ mod varuna {
 #[cfg(any(test, feature = "test"))]
 mod varuna_hiding {
     use crate::{
-        crypto_hash::PoseidonSponge,
+        crypto_hash::{PoseidonSponge, TranscriptEntry, TranscriptOperation, TranscriptParameters, TranscriptSponge},
         snark::varuna::{
             ahp::AHPForR1CS,
             test_circuit::TestCircuit,
             CircuitVerifyingKey,
+            Prover,
+            ProverStep,
             VarunaHidingMode,
             VarunaSNARK,
         },
@@ -319,10 +321,11 @@ mod varuna_hiding {
         ToBytes,
     };
 
-    use std::str::FromStr;
+    use std::{collections::BTreeMap, str::FromStr};
 
     type VarunaInst = VarunaSNARK<Bls12_377, FS, VarunaHidingMode>;
     type FS = PoseidonSponge<Fq, 2, 1>;
+    type ResumableProver<'a> = Prover<'a, Bls12_377, FS, VarunaHidingMode, TestCircuit<Fr>, TestRng>;
 
     fn test_circuit_n_times(num_constraints: usize, num_variables: usize, num_times: usize) {
         let rng = &mut TestRng::default();
@@ -459,6 +462,156 @@ mod varuna_hiding {
         test_circuit_n_times(num_constraints, num_variables, 1);
     }
 
+    #[test]
+    fn prove_and_verify_many() {
+        let rng = &mut TestRng::default();
+
+        let max_degree = AHPForR1CS::<Fr, VarunaHidingMode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = VarunaInst::universal_setup(max_degree).unwrap();
+        let universal_prover = &universal_srs.to_universal_prover().unwrap();
+        let universal_verifier = &universal_srs.to_universal_verifier().unwrap();
+        let fs_parameters = FS::sample_parameters();
+
+        // Prove several circuits, each with its own proof.
+        let mut index_vks = Vec::new();
+        let mut inputs = Vec::new();
+        let mut proofs = Vec::new();
+        for _ in 0..3 {
+            let (circuit, public_inputs) = TestCircuit::gen_rand(2, 25, 25, rng);
+            let (index_pk, index_vk) = VarunaInst::circuit_setup(&universal_srs, &circuit).unwrap();
+            let proof = VarunaInst::prove(universal_prover, &fs_parameters, &index_pk, &circuit, rng).unwrap();
+            index_vks.push(index_vk);
+            inputs.push(vec![public_inputs]);
+            proofs.push(proof);
+        }
+
+        // Check the proofs are valid together.
+        let instances = index_vks
+            .iter()
+            .zip(&inputs)
+            .zip(&proofs)
+            .map(|((index_vk, inputs), proof)| (BTreeMap::from([(index_vk, inputs.as_slice())]), proof))
+            .collect::<Vec<_>>();
+        assert!(VarunaInst::verify_many(universal_verifier, &fs_parameters, &instances).unwrap());
+
+        // Check a single invalid public input fails the check for all of the proofs.
+        let mut fake_inputs = inputs.clone();
+        let last = fake_inputs[1][0].len() - 1;
+        fake_inputs[1][0][last] = Fr::rand(rng);
+        let instances = index_vks
+            .iter()
+            .zip(&fake_inputs)
+            .zip(&proofs)
+            .map(|((index_vk, inputs), proof)| (BTreeMap::from([(index_vk, inputs.as_slice())]), proof))
+            .collect::<Vec<_>>();
+        assert!(!VarunaInst::verify_many(universal_verifier, &fs_parameters, &instances).unwrap());
+
+        // Check a proof for the wrong circuit fails the check for all of the proofs.
+        let instances = index_vks
+            .iter()
+            .zip(&inputs)
+            .zip(proofs.iter().rev())
+            .map(|((index_vk, inputs), proof)| (BTreeMap::from([(index_vk, inputs.as_slice())]), proof))
+            .collect::<Vec<_>>();
+        assert!(!VarunaInst::verify_many(universal_verifier, &fs_parameters, &instances).unwrap_or(false));
+    }
+
+    #[test]
+    fn prove_and_verify_with_resumable_prover() {
+        let rng = &mut TestRng::default();
+
+        let max_degree = AHPForR1CS::<Fr, VarunaHidingMode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = VarunaInst::universal_setup(max_degree).unwrap();
+        let universal_prover = &universal_srs.to_universal_prover().unwrap();
+        let universal_verifier = &universal_srs.to_universal_verifier().unwrap();
+        let fs_parameters = FS::sample_parameters();
+
+        let mul_depth = 2;
+        let (circuit, public_inputs) = TestCircuit::gen_rand(mul_depth, 100, 25, rng);
+        let (index_pk, index_vk) = VarunaInst::circuit_setup(&universal_srs, &circuit).unwrap();
+
+        let circuits = [circuit];
+        let keys_to_constraints = BTreeMap::from([(&index_pk, circuits.as_slice())]);
+        let mut prover = ResumableProver::new(universal_prover, &fs_parameters, &keys_to_constraints, rng).unwrap();
+
+        // Step the prover, and ensure it reports its progress.
+        let mut num_steps = 0;
+        let proof = loop {
+            num_steps += 1;
+            match prover.step().unwrap() {
+                ProverStep::Pending { completed, total } => {
+                    assert_eq!(completed, num_steps);
+                    assert_eq!(total, ResumableProver::NUM_STEPS);
+                }
+                ProverStep::Done(proof) => break proof,
+            }
+        };
+        assert_eq!(num_steps, ResumableProver::NUM_STEPS);
+
+        // Ensure the prover cannot be stepped after it is done.
+        assert!(prover.step().is_err());
+
+        assert!(VarunaInst::verify(universal_verifier, &fs_parameters, &index_vk, public_inputs, &proof).unwrap());
+    }
+
+    #[test]
+    fn prove_and_verify_with_transcript() {
+        type TranscriptInst = VarunaSNARK<Bls12_377, TranscriptSponge<FS>, VarunaHidingMode>;
+
+        let rng = &mut TestRng::default();
+
+        let max_degree = AHPForR1CS::<Fr, VarunaHidingMode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = VarunaInst::universal_setup(max_degree).unwrap();
+        let universal_prover = &universal_srs.to_universal_prover().unwrap();
+        let universal_verifier = &universal_srs.to_universal_verifier().unwrap();
+        let fs_parameters = FS::sample_parameters();
+
+        let mul_depth = 2;
+        let (circuit, public_inputs) = TestCircuit::gen_rand(mul_depth, 100, 25, rng);
+        let (index_pk, index_vk) = VarunaInst::circuit_setup(&universal_srs, &circuit).unwrap();
+
+        // Prove while recording the transcript, and ensure the proof verifies without the transcript.
+        let prover_parameters = TranscriptParameters::new(fs_parameters.clone());
+        let proof = TranscriptInst::prove(universal_prover, &prover_parameters, &index_pk, &circuit, rng).unwrap();
+        let inputs = public_inputs.as_slice();
+        assert!(VarunaInst::verify(universal_verifier, &fs_parameters, &index_vk, inputs, &proof).unwrap());
+
+        // Verify while recording the transcript.
+        let verifier_parameters = TranscriptParameters::new(fs_parameters);
+        assert!(TranscriptInst::verify(universal_verifier, &verifier_parameters, &index_vk, inputs, &proof).unwrap());
+
+        // Ensure both transcripts start with the protocol name.
+        let prover_transcript = prover_parameters.transcript.entries();
+        let verifier_transcript = verifier_parameters.transcript.entries();
+        for transcript in [&prover_transcript, &verifier_transcript] {
+            assert_eq!(transcript[0].operation, TranscriptOperation::AbsorbBytes);
+            assert_eq!(transcript[0].elements, vec![hex::encode(TranscriptInst::PROTOCOL_NAME)]);
+        }
+
+        // Ensure the prover and the verifier derive the same challenges.
+        let challenges = |transcript: &[TranscriptEntry]| {
+            transcript
+                .iter()
+                .filter(|entry| {
+                    matches!(
+                        entry.operation,
+                        TranscriptOperation::SqueezeNative
+                            | TranscriptOperation::SqueezeNonnative
+                            | TranscriptOperation::SqueezeShortNonnative
+                    )
+                })
+                .map(|entry| (entry.operation, entry.elements.clone()))
+                .collect::<Vec<_>>()
+        };
+        assert!(!challenges(&prover_transcript).is_empty());
+        assert_eq!(challenges(&prover_transcript), challenges(&verifier_transcript));
+
+        // Ensure the transcript serializes as the list of its entries.
+        let json = serde_json::to_value(&verifier_parameters.transcript).unwrap();
+        assert_eq!(json.as_array().unwrap().len(), verifier_transcript.len());
+        assert_eq!(json[0]["operation"], "absorb_bytes");
+    }
+
     #[test]
     fn check_indexing() {
         let rng = &mut TestRng::default();
@@ -15,19 +15,10 @@
 use super::Certificate;
 use crate::{
     fft::EvaluationDomain,
-    polycommit::sonic_pc::{
-        Commitment,
-        CommitterUnionKey,
-        Evaluations,
-        LabeledCommitment,
-        QuerySet,
-        Randomness,
-        SonicKZG10,
-    },
+    polycommit::sonic_pc::{Commitment, Evaluations, LabeledCommitment, PairingCheck, QuerySet, SonicKZG10},
     r1cs::{ConstraintSynthesizer, SynthesisError},
     snark::varuna::{
-        ahp::{AHPError, AHPForR1CS, CircuitId, EvaluationsProvider},
-        proof,
+        ahp::{AHPError, AHPForR1CS, CircuitId},
         prover,
         witness_label,
         CircuitProvingKey,
@@ -36,26 +27,36 @@ use crate::{
         SNARKMode,
         UniversalSRS,
     },
-    srs::UniversalVerifier,
+    srs::{UniversalProver, UniversalVerifier},
     AlgebraicSponge,
     SNARKError,
     SNARK,
 };
-use rand::RngCore;
+#[cfg(feature = "prover")]
+use crate::{
+    polycommit::sonic_pc::{CommitterUnionKey, Randomness},
+    snark::varuna::Prover,
+};
 use snarkvm_curves::PairingEngine;
 use snarkvm_fields::{One, PrimeField, ToConstraintField, Zero};
-use snarkvm_utilities::{to_bytes_le, ToBytes};
+use snarkvm_utilities::{cfg_iter, to_bytes_le, ToBytes};
 
 use anyhow::{anyhow, bail, ensure, Result};
 use core::marker::PhantomData;
 use itertools::Itertools;
-use rand::{CryptoRng, Rng};
-use std::{borrow::Borrow, collections::BTreeMap, ops::Deref, sync::Arc};
+use std::{borrow::Borrow, collections::BTreeMap};
+
+#[cfg(feature = "prover")]
+use rand::{CryptoRng, Rng, RngCore};
+#[cfg(feature = "prover")]
+use std::sync::Arc;
 
-use crate::srs::UniversalProver;
 #[cfg(not(feature = "std"))]
 use snarkvm_utilities::println;
 
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
 /// The Varuna proof system.
 #[derive(Clone, Debug)]
 pub struct VarunaSNARK<E: PairingEngine, FS: AlgebraicSponge<E::Fq, 2>, SM: SNARKMode>(
@@ -69,6 +70,7 @@ impl<E: PairingEngine, FS: AlgebraicSponge<E::Fq, 2>, SM: SNARKMode> VarunaSNARK
 
     // TODO: implement optimizations resulting from batching
     //       (e.g. computing a common set of Lagrange powers, FFT precomputations, etc)
+    #[cfg(feature = "prover")]
     pub fn batch_circuit_setup<C: ConstraintSynthesizer<E::Fr>>(
         universal_srs: &UniversalSRS<E>,
         circuits: &[&C],
@@ -133,7 +135,7 @@ impl<E: PairingEngine, FS: AlgebraicSponge<E::Fq, 2>, SM: SNARKMode> VarunaSNARK
         Ok(circuit_keys)
     }
 
-    fn init_sponge<'a>(
+    pub(super) fn init_sponge<'a>(
         fs_parameters: &FS::Parameters,
         inputs_and_batch_sizes: &BTreeMap<CircuitId, (usize, &[Vec<E::Fr>])>,
         circuit_commitments: impl Iterator<Item = &'a [crate::polycommit::sonic_pc::Commitment<E>]>,
@@ -164,7 +166,7 @@ impl<E: PairingEngine, FS: AlgebraicSponge<E::Fq, 2>, SM: SNARKMode> VarunaSNARK
         Ok(sponge)
     }
 
-    fn absorb_labeled_with_sums(
+    pub(super) fn absorb_labeled_with_sums(
         comms: &[LabeledCommitment<Commitment<E>>],
         sums: &[prover::MatrixSums<E::Fr>],
         sponge: &mut FS,
@@ -173,7 +175,7 @@ impl<E: PairingEngine, FS: AlgebraicSponge<E::Fq, 2>, SM: SNARKMode> VarunaSNARK
         Self::absorb_with_sums(&commitments, sums, sponge)
     }
 
-    fn absorb_labeled(comms: &[LabeledCommitment<Commitment<E>>], sponge: &mut FS) {
+    pub(super) fn absorb_labeled(comms: &[LabeledCommitment<Commitment<E>>], sponge: &mut FS) {
         let commitments: Vec<_> = comms.iter().map(|c| *c.commitment()).collect();
         Self::absorb(&commitments, sponge);
     }
@@ -214,6 +216,7 @@ where
     type VerifierInput = [E::Fr];
     type VerifyingKey = CircuitVerifyingKey<E>;
 
+    #[cfg(feature = "prover")]
     fn universal_setup(max_degree: usize) -> Result<Self::UniversalSRS> {
         let setup_time = start_timer!(|| { format!("Varuna::UniversalSetup with max_degree {max_degree}",) });
         let srs = SonicKZG10::<E, FS>::load_srs(max_degree).map_err(Into::into);
@@ -223,6 +226,7 @@ where
 
     /// Generates the circuit proving and verifying keys.
     /// This is a deterministic algorithm that anyone can rerun.
+    #[cfg(feature = "prover")]
     fn circuit_setup<C: ConstraintSynthesizer<E::Fr>>(
         universal_srs: &Self::UniversalSRS,
         circuit: &C,
@@ -233,6 +237,7 @@ where
     }
 
     /// Prove that the verifying key commitments commit to the indexed circuit's polynomials
+    #[cfg(feature = "prover")]
     fn prove_vk(
         universal_prover: &Self::UniversalProver,
         fs_parameters: &Self::FSParameters,
@@ -333,6 +338,7 @@ where
     /// This is the main entrypoint for creating proofs.
     /// You can find a specification of the prover algorithm in:
     /// https://github.com/AleoHQ/protocol-docs
+    #[cfg(feature = "prover")]
     fn prove_batch<C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
         universal_prover: &Self::UniversalProver,
         fs_parameters: &Self::FSParameters,
@@ -340,281 +346,8 @@ where
         zk_rng: &mut R,
     ) -> Result<Self::Proof> {
         let prover_time = start_timer!(|| "Varuna::Prover");
-        if keys_to_constraints.is_empty() {
-            bail!(SNARKError::EmptyBatch);
-        }
-
-        let mut circuits_to_constraints = BTreeMap::new();
-        for (pk, constraints) in keys_to_constraints {
-            circuits_to_constraints.insert(pk.circuit.deref(), *constraints);
-        }
-        let prover_state = AHPForR1CS::<_, SM>::init_prover(&circuits_to_constraints, zk_rng)?;
-
-        // extract information from the prover key and state to consume in further calculations
-        let mut batch_sizes = BTreeMap::new();
-        let mut circuit_infos = BTreeMap::new();
-        let mut inputs_and_batch_sizes = BTreeMap::new();
-        let mut total_instances = 0usize;
-        let mut public_inputs = BTreeMap::new(); // inputs need to live longer than the rest of prover_state
-        let num_unique_circuits = keys_to_constraints.len();
-        let mut circuit_ids = Vec::with_capacity(num_unique_circuits);
-        for pk in keys_to_constraints.keys() {
-            let batch_size = prover_state.batch_size(&pk.circuit).ok_or(SNARKError::CircuitNotFound)?;
-            let public_input = prover_state.public_inputs(&pk.circuit).ok_or(SNARKError::CircuitNotFound)?;
-            let padded_public_input =
-                prover_state.padded_public_inputs(&pk.circuit).ok_or(SNARKError::CircuitNotFound)?;
-            let circuit_id = pk.circuit.id;
-            batch_sizes.insert(circuit_id, batch_size);
-            circuit_infos.insert(circuit_id, &pk.circuit_verifying_key.circuit_info);
-            inputs_and_batch_sizes.insert(circuit_id, (batch_size, padded_public_input));
-            public_inputs.insert(circuit_id, public_input);
-            total_instances = total_instances.saturating_add(batch_size);
-
-            circuit_ids.push(circuit_id);
-        }
-        ensure!(prover_state.total_instances == total_instances);
-
-        let committer_key = CommitterUnionKey::union(keys_to_constraints.keys().map(|pk| pk.committer_key.deref()));
-
-        let circuit_commitments =
-            keys_to_constraints.keys().map(|pk| pk.circuit_verifying_key.circuit_commitments.as_slice());
-
-        let mut sponge = Self::init_sponge(fs_parameters, &inputs_and_batch_sizes, circuit_commitments.clone());
-
-        // --------------------------------------------------------------------
-        // First round
-
-        let prover_state = AHPForR1CS::<_, SM>::prover_first_round(prover_state, zk_rng)?;
-
-        let first_round_comm_time = start_timer!(|| "Committing to first round polys");
-        let (first_commitments, first_commitment_randomnesses) = {
-            let first_round_oracles = prover_state.first_round_oracles.as_ref().unwrap();
-            SonicKZG10::<E, FS>::commit(
-                universal_prover,
-                &committer_key,
-                first_round_oracles.iter().map(Into::into),
-                SM::ZK.then_some(zk_rng),
-            )?
-        };
-        end_timer!(first_round_comm_time);
-
-        Self::absorb_labeled(&first_commitments, &mut sponge);
-
-        let (verifier_first_message, verifier_state) = AHPForR1CS::<_, SM>::verifier_first_round(
-            &batch_sizes,
-            &circuit_infos,
-            prover_state.max_constraint_domain,
-            prover_state.max_variable_domain,
-            prover_state.max_non_zero_domain,
-            &mut sponge,
-        )?;
-        // --------------------------------------------------------------------
-
-        // --------------------------------------------------------------------
-        // Second round
-
-        let (second_oracles, prover_state) =
-            AHPForR1CS::<_, SM>::prover_second_round(&verifier_first_message, prover_state, zk_rng)?;
-
-        let second_round_comm_time = start_timer!(|| "Committing to second round polys");
-        let (second_commitments, second_commitment_randomnesses) = SonicKZG10::<E, FS>::commit(
-            universal_prover,
-            &committer_key,
-            second_oracles.iter().map(Into::into),
-            SM::ZK.then_some(zk_rng),
-        )?;
-        end_timer!(second_round_comm_time);
-
-        Self::absorb_labeled(&second_commitments, &mut sponge);
-
-        let (verifier_second_msg, verifier_state) =
-            AHPForR1CS::<_, SM>::verifier_second_round(verifier_state, &mut sponge)?;
-        // --------------------------------------------------------------------
-
-        // --------------------------------------------------------------------
-        // Third round
-
-        let (prover_third_message, third_oracles, prover_state) = AHPForR1CS::<_, SM>::prover_third_round(
-            &verifier_first_message,
-            &verifier_second_msg,
-            prover_state,
-            zk_rng,
-        )?;
-
-        let third_round_comm_time = start_timer!(|| "Committing to third round polys");
-        let (third_commitments, third_commitment_randomnesses) = SonicKZG10::<E, FS>::commit(
-            universal_prover,
-            &committer_key,
-            third_oracles.iter().map(Into::into),
-            SM::ZK.then_some(zk_rng),
-        )?;
-        end_timer!(third_round_comm_time);
-
-        Self::absorb_labeled_with_sums(
-            &third_commitments,
-            &prover_third_message.sums.clone().into_iter().flatten().collect_vec(),
-            &mut sponge,
-        );
-
-        let (verifier_third_msg, verifier_state) =
-            AHPForR1CS::<_, SM>::verifier_third_round(verifier_state, &mut sponge)?;
-        // --------------------------------------------------------------------
-
-        // --------------------------------------------------------------------
-        // Fourth round
-
-        let (prover_fourth_message, fourth_oracles, mut prover_state) =
-            AHPForR1CS::<_, SM>::prover_fourth_round(&verifier_second_msg, &verifier_third_msg, prover_state, zk_rng)?;
-
-        let fourth_round_comm_time = start_timer!(|| "Committing to fourth round polys");
-        let (fourth_commitments, fourth_commitment_randomnesses) = SonicKZG10::<E, FS>::commit(
-            universal_prover,
-            &committer_key,
-            fourth_oracles.iter().map(Into::into),
-            SM::ZK.then_some(zk_rng),
-        )?;
-        end_timer!(fourth_round_comm_time);
-
-        Self::absorb_labeled_with_sums(&fourth_commitments, &prover_fourth_message.sums, &mut sponge);
-
-        let (verifier_fourth_msg, verifier_state) =
-            AHPForR1CS::<_, SM>::verifier_fourth_round(verifier_state, &mut sponge)?;
-        // --------------------------------------------------------------------
-
-        // We take out values from state before they are consumed.
-        let first_round_oracles = prover_state.first_round_oracles.take().unwrap();
-        let index_a_polys =
-            prover_state.circuit_specific_states.values_mut().flat_map(|s| s.a_polys.take().unwrap()).collect_vec();
-        let index_b_polys =
-            prover_state.circuit_specific_states.values_mut().flat_map(|s| s.b_polys.take().unwrap()).collect_vec();
-
-        // --------------------------------------------------------------------
-        // Fifth round
-        let fifth_oracles = AHPForR1CS::<_, SM>::prover_fifth_round(verifier_fourth_msg, prover_state, zk_rng)?;
-
-        let fifth_round_comm_time = start_timer!(|| "Committing to fifth round polys");
-        let (fifth_commitments, fifth_commitment_randomnesses) = SonicKZG10::<E, FS>::commit(
-            universal_prover,
-            &committer_key,
-            fifth_oracles.iter().map(Into::into),
-            SM::ZK.then_some(zk_rng),
-        )?;
-        end_timer!(fifth_round_comm_time);
-
-        Self::absorb_labeled(&fifth_commitments, &mut sponge);
-
-        let verifier_state = AHPForR1CS::<_, SM>::verifier_fifth_round(verifier_state, &mut sponge)?;
-        // --------------------------------------------------------------------
-
-        // Gather prover polynomials in one vector.
-        let polynomials: Vec<_> = index_a_polys
-            .into_iter()
-            .chain(index_b_polys)
-            .chain(first_round_oracles.into_iter())
-            .chain(second_oracles.into_iter())
-            .chain(third_oracles.into_iter())
-            .chain(fourth_oracles.into_iter())
-            .chain(fifth_oracles.into_iter())
-            .collect();
-        ensure!(
-            polynomials.len()
-                == num_unique_circuits * 6 + // numerator and denominator for each matrix sumcheck
-            AHPForR1CS::<E::Fr, SM>::num_first_round_oracles(total_instances) +
-            AHPForR1CS::<E::Fr, SM>::num_second_round_oracles() +
-            AHPForR1CS::<E::Fr, SM>::num_third_round_oracles() +
-            AHPForR1CS::<E::Fr, SM>::num_fourth_round_oracles(num_unique_circuits) +
-            AHPForR1CS::<E::Fr, SM>::num_fifth_round_oracles()
-        );
-
-        // Gather commitments in one vector.
-        let witness_comm_len = if SM::ZK { first_commitments.len() - 1 } else { first_commitments.len() };
-        let mask_poly = SM::ZK.then(|| *first_commitments[witness_comm_len].commitment());
-        let witness_commitments = first_commitments[..witness_comm_len]
-            .iter()
-            .map(|c| proof::WitnessCommitments { w: *c.commitment() })
-            .collect_vec();
-        let fourth_commitments_chunked = fourth_commitments.chunks_exact(3);
-        let (g_a_commitments, g_b_commitments, g_c_commitments) = fourth_commitments_chunked
-            .map(|c| (*c[0].commitment(), *c[1].commitment(), *c[2].commitment()))
-            .multiunzip();
-
-        #[rustfmt::skip]
-        let commitments = proof::Commitments {
-            witness_commitments,
-            mask_poly,
-            h_0: *second_commitments[0].commitment(),
-            g_1: *third_commitments[0].commitment(),
-            h_1: *third_commitments[1].commitment(),
-            g_a_commitments,
-            g_b_commitments,
-            g_c_commitments,
-            h_2: *fifth_commitments[0].commitment(),
-        };
-
-        // Gather commitment randomness together.
-        let indexer_randomness = vec![Randomness::<E>::empty(); 6 * num_unique_circuits];
-        let commitment_randomnesses: Vec<Randomness<E>> = indexer_randomness
-            .into_iter()
-            .chain(first_commitment_randomnesses)
-            .chain(second_commitment_randomnesses)
-            .chain(third_commitment_randomnesses)
-            .chain(fourth_commitment_randomnesses)
-            .chain(fifth_commitment_randomnesses)
-            .collect();
-
-        let empty_randomness = Randomness::<E>::empty();
-        if SM::ZK {
-            ensure!(commitment_randomnesses.iter().any(|r| r != &empty_randomness));
-        } else {
-            ensure!(commitment_randomnesses.iter().all(|r| r == &empty_randomness));
-        }
-
-        // Compute the AHP verifier's query set.
-        let (query_set, verifier_state) = AHPForR1CS::<_, SM>::verifier_query_set(verifier_state);
-        let lc_s = AHPForR1CS::<_, SM>::construct_linear_combinations(
-            &public_inputs,
-            &polynomials,
-            &prover_third_message,
-            &prover_fourth_message,
-            &verifier_state,
-        )?;
-
-        let eval_time = start_timer!(|| "Evaluating linear combinations over query set");
-        let mut evaluations = std::collections::BTreeMap::new();
-        for (label, (_, point)) in query_set.to_set() {
-            if !AHPForR1CS::<E::Fr, SM>::LC_WITH_ZERO_EVAL.contains(&label.as_str()) {
-                let lc = lc_s.get(&label).ok_or_else(|| AHPError::MissingEval(label.to_string()))?;
-                let evaluation = polynomials.get_lc_eval(lc, point)?;
-                evaluations.insert(label, evaluation);
-            }
-        }
-
-        let evaluations = proof::Evaluations::from_map(&evaluations, batch_sizes.clone());
-        end_timer!(eval_time);
-
-        sponge.absorb_nonnative_field_elements(evaluations.to_field_elements());
-
-        let pc_proof = SonicKZG10::<E, FS>::open_combinations(
-            universal_prover,
-            &committer_key,
-            lc_s.values(),
-            polynomials,
-            &commitment_randomnesses,
-            &query_set.to_set(),
-            &mut sponge,
-        )?;
-
-        let proof = Proof::<E>::new(
-            batch_sizes,
-            commitments,
-            evaluations,
-            prover_third_message,
-            prover_fourth_message,
-            pc_proof,
-        )?;
-        proof.check_batch_sizes()?;
-        ensure!(proof.pc_proof.is_hiding() == SM::ZK);
-
+        let proof =
+            Prover::<E, FS, SM, C, R>::new(universal_prover, fs_parameters, keys_to_constraints, zk_rng)?.prove()?;
         end_timer!(prover_time);
         Ok(proof)
     }
@@ -628,6 +361,75 @@ where
         keys_to_inputs: &BTreeMap<&Self::VerifyingKey, &[B]>,
         proof: &Self::Proof,
     ) -> Result<bool> {
+        // Run the verifier, up to the final pairing check.
+        let Some(pairing_check) =
+            Self::verify_batch_deferred(universal_verifier, fs_parameters, keys_to_inputs, proof)?
+        else {
+            return Ok(false);
+        };
+        // Check the pairings.
+        let evaluations_are_correct = SonicKZG10::<E, FS>::check_pairing(universal_verifier, pairing_check)?;
+        if !evaluations_are_correct {
+            #[cfg(debug_assertions)]
+            eprintln!("SonicKZG10::Check failed");
+        }
+        Ok(evaluations_are_correct)
+    }
+}
+
+impl<E: PairingEngine, FS, SM> VarunaSNARK<E, FS, SM>
+where
+    E::Fr: PrimeField,
+    E::Fq: PrimeField,
+    FS: AlgebraicSponge<E::Fq, 2>,
+    SM: SNARKMode,
+{
+    /// Verifies the given batch proofs, each for its own map of verifying keys to public inputs.
+    ///
+    /// Each proof is checked up to its final pairing check, as in `verify_batch`.
+    /// The pairing checks of all of the proofs are then combined with random coefficients,
+    /// and checked with a single product of pairings.
+    pub fn verify_many<B: Borrow<[E::Fr]>>(
+        universal_verifier: &UniversalVerifier<E>,
+        fs_parameters: &FS::Parameters,
+        instances: &[(BTreeMap<&CircuitVerifyingKey<E>, &[B]>, &Proof<E>)],
+    ) -> Result<bool>
+    where
+        B: Sync,
+        FS::Parameters: Sync,
+    {
+        if instances.is_empty() {
+            bail!(SNARKError::EmptyBatch);
+        }
+
+        let verifier_time = start_timer!(|| format!("Varuna::VerifyMany with {} proofs", instances.len()));
+        // Run the verifier for each proof, up to the final pairing check.
+        let pairing_checks = cfg_iter!(instances)
+            .map(|(keys_to_inputs, proof)| {
+                Self::verify_batch_deferred(universal_verifier, fs_parameters, keys_to_inputs, proof)
+            })
+            .collect::<Result<Option<Vec<_>>>>()?;
+        let Some(pairing_checks) = pairing_checks else {
+            end_timer!(verifier_time);
+            return Ok(false);
+        };
+
+        // Check the pairings of all of the proofs at once.
+        let mut sponge = FS::new_with_parameters(fs_parameters);
+        sponge.absorb_bytes(Self::PROTOCOL_NAME);
+        let result = SonicKZG10::<E, FS>::check_pairings(universal_verifier, pairing_checks, &mut sponge);
+        end_timer!(verifier_time);
+        result
+    }
+
+    /// Runs the verifier for the given batch proof, up to the final pairing check, and returns the pairing check.
+    /// Returns `None` if the proof has the incorrect hiding mode.
+    fn verify_batch_deferred<B: Borrow<[E::Fr]>>(
+        universal_verifier: &UniversalVerifier<E>,
+        fs_parameters: &FS::Parameters,
+        keys_to_inputs: &BTreeMap<&CircuitVerifyingKey<E>, &[B]>,
+        proof: &Proof<E>,
+    ) -> Result<Option<PairingCheck<E>>> {
         if keys_to_inputs.is_empty() {
             bail!(SNARKError::EmptyBatch);
         }
@@ -723,7 +525,7 @@ where
                 "Found `mask_poly` in the first round when not expected, or proof has incorrect hiding mode ({})",
                 proof.pc_proof.is_hiding()
             );
-            return Ok(false);
+            return Ok(None);
         }
 
         let verifier_time = start_timer!(|| format!("Varuna::Verify with batch sizes: {:?}", batch_sizes));
@@ -897,7 +699,7 @@ where
         end_timer!(lc_time);
 
         let pc_time = start_timer!(|| "Checking linear combinations with PC");
-        let evaluations_are_correct = SonicKZG10::<E, FS>::check_combinations(
+        let pairing_check = SonicKZG10::<E, FS>::check_combinations_deferred(
             universal_verifier,
             lc_s.values(),
             &commitments,
@@ -908,15 +710,7 @@ where
         )?;
         end_timer!(pc_time);
 
-        if !evaluations_are_correct {
-            #[cfg(debug_assertions)]
-            eprintln!("SonicKZG10::Check failed using final challenge: {:?}", verifier_state.gamma);
-        }
-
-        end_timer!(verifier_time, || format!(
-            " SonicKZG10::Check for AHP Verifier linear equations: {}",
-            evaluations_are_correct & proof_has_correct_zk_mode
-        ));
-        Ok(evaluations_are_correct & proof_has_correct_zk_mode)
+        end_timer!(verifier_time);
+        Ok(Some(pairing_check))
     }
 }
@@ -17,6 +17,7 @@ use snarkvm_fields::PrimeField;
 use snarkvm_utilities::{CanonicalDeserialize, CanonicalSerialize, FromBytes, ToBytes};
 
 use anyhow::Result;
+#[cfg(feature = "prover")]
 use rand::{CryptoRng, Rng};
 use std::{borrow::Borrow, collections::BTreeMap, fmt::Debug};
 
@@ -54,13 +55,16 @@ pub trait SNARK {
     type FiatShamirRng: AlgebraicSponge<Self::BaseField, 2, Parameters = Self::FSParameters>;
     type FSParameters;
 
+    #[cfg(feature = "prover")]
     fn universal_setup(config: usize) -> Result<Self::UniversalSRS>;
 
+    #[cfg(feature = "prover")]
     fn circuit_setup<C: ConstraintSynthesizer<Self::ScalarField>>(
         srs: &Self::UniversalSRS,
         circuit: &C,
     ) -> Result<(Self::ProvingKey, Self::VerifyingKey)>;
 
+    #[cfg(feature = "prover")]
     fn prove_vk(
         universal_prover: &Self::UniversalProver,
         fs_parameters: &Self::FSParameters,
@@ -68,6 +72,7 @@ pub trait SNARK {
         proving_key: &Self::ProvingKey,
     ) -> Result<Self::Certificate>;
 
+    #[cfg(feature = "prover")]
     fn prove<C: ConstraintSynthesizer<Self::ScalarField>, R: Rng + CryptoRng>(
         universal_prover: &Self::UniversalProver,
         fs_parameters: &Self::FSParameters,
@@ -80,6 +85,7 @@ pub trait SNARK {
         Self::prove_batch(universal_prover, fs_parameters, &keys_to_constraints, rng)
     }
 
+    #[cfg(feature = "prover")]
     fn prove_batch<C: ConstraintSynthesizer<Self::ScalarField>, R: Rng + CryptoRng>(
         universal_prover: &Self::UniversalProver,
         fs_parameters: &Self::FSParameters,
@@ -0,0 +1,71 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{
+    account::PrivateKey,
+    prelude::{Network, TestRng},
+};
+
+use anyhow::Result;
+
+/// A deterministic source of synthetic workloads.
+/// The same seed always produces the same accounts, records, and programs, so results are comparable across runs.
+pub struct Dataset {
+    /// The seed of the dataset.
+    seed: u64,
+    /// The RNG, initialized from the seed.
+    rng: TestRng,
+}
+
+impl Dataset {
+    /// Initializes a new dataset from the given seed.
+    pub fn new(seed: u64) -> Self {
+        Self { seed, rng: TestRng::fixed(seed) }
+    }
+
+    /// Returns the seed of the dataset.
+    pub const fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Returns the RNG of the dataset.
+    pub fn rng(&mut self) -> &mut TestRng {
+        &mut self.rng
+    }
+
+    /// Samples the given number of accounts.
+    pub fn sample_accounts<N: Network>(&mut self, num_accounts: usize) -> Result<Vec<PrivateKey<N>>> {
+        (0..num_accounts).map(|_| PrivateKey::new(&mut self.rng)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_dataset_is_deterministic() {
+        // Ensure the same seed produces the same accounts.
+        let first = Dataset::new(7).sample_accounts::<CurrentNetwork>(3).unwrap();
+        let second = Dataset::new(7).sample_accounts::<CurrentNetwork>(3).unwrap();
+        assert_eq!(first, second);
+
+        // Ensure a different seed produces different accounts.
+        let third = Dataset::new(8).sample_accounts::<CurrentNetwork>(3).unwrap();
+        assert_ne!(first, third);
+    }
+}
@@ -0,0 +1,107 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkvm_benchmarks::{workloads::*, Dataset, Report};
+
+use anyhow::{bail, Result};
+use console::network::MainnetV0;
+
+type CurrentNetwork = MainnetV0;
+#[cfg(feature = "proving")]
+type CurrentAleo = circuit::AleoV0;
+
+/// The configuration of a benchmark run.
+struct Config {
+    /// The seed of the dataset.
+    seed: u64,
+    /// The number of iterations of each measurement.
+    iterations: u32,
+    /// The number of transfers in the transfer workload.
+    transfers: usize,
+    /// The number of accounts in the transfer workload.
+    accounts: usize,
+    /// The number of functions in the deployment workload.
+    functions: usize,
+    /// The number of solutions in the puzzle workload.
+    #[cfg_attr(not(feature = "puzzle"), allow(dead_code))]
+    solutions: usize,
+    /// The path to write the report to. If `None`, the report is printed to stdout.
+    output: Option<String>,
+}
+
+impl Config {
+    /// Parses the configuration from the command-line arguments.
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self> {
+        let mut config = Self {
+            seed: 0,
+            iterations: 10,
+            transfers: 1_000,
+            accounts: 10,
+            functions: 16,
+            solutions: 8,
+            output: None,
+        };
+        while let Some(flag) = args.next() {
+            let Some(value) = args.next() else { bail!("Missing a value for '{flag}'") };
+            match flag.as_str() {
+                "--seed" => config.seed = value.parse()?,
+                "--iterations" => config.iterations = value.parse()?,
+                "--transfers" => config.transfers = value.parse()?,
+                "--accounts" => config.accounts = value.parse()?,
+                "--functions" => config.functions = value.parse()?,
+                "--solutions" => config.solutions = value.parse()?,
+                "--output" => config.output = Some(value),
+                _ => bail!("Unknown argument '{flag}'"),
+            }
+        }
+        Ok(config)
+    }
+}
+
+fn main() -> Result<()> {
+    let config = Config::parse(std::env::args().skip(1))?;
+
+    // Initialize the dataset and the report.
+    let mut dataset = Dataset::new(config.seed);
+    let mut report = Report::new(config.seed);
+
+    // Run the transfer workload.
+    let transfers = TransferWorkload::<CurrentNetwork>::sample(&mut dataset, config.transfers, config.accounts)?;
+    report.extend(transfers.run(config.iterations)?);
+
+    // Run the deployment workload.
+    let deployments = DeploymentWorkload::<CurrentNetwork>::sample(config.functions)?;
+    report.extend(deployments.run(config.iterations)?);
+
+    // Run the puzzle workload.
+    #[cfg(feature = "puzzle")]
+    {
+        let puzzle = PuzzleWorkload::<CurrentNetwork>::sample(&mut dataset, config.solutions)?;
+        report.extend(puzzle.run(config.iterations)?);
+    }
+
+    // Run the proving workload.
+    #[cfg(feature = "proving")]
+    {
+        let proving = ProvingWorkload::<CurrentNetwork>::sample::<CurrentAleo>(&mut dataset)?;
+        report.extend(proving.run::<CurrentAleo>(&mut dataset, config.iterations)?);
+    }
+
+    // Output the report.
+    match config.output {
+        Some(path) => std::fs::write(path, report.to_json()?)?,
+        None => println!("{}", report.to_json()?),
+    }
+    Ok(())
+}
@@ -0,0 +1,101 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use anyhow::Result;
+use serde::Serialize;
+use std::time::Instant;
+
+/// The result of measuring a single operation of a workload.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Measurement {
+    /// The name of the measured operation, e.g. `transfers/insert`.
+    pub name: String,
+    /// The number of items processed by each iteration, e.g. the number of transfers.
+    pub size: u64,
+    /// The number of iterations.
+    pub iterations: u32,
+    /// The total elapsed time over all iterations, in nanoseconds.
+    pub total_nanos: u64,
+    /// The mean elapsed time of an iteration, in nanoseconds.
+    pub mean_nanos: u64,
+    /// The fastest iteration, in nanoseconds.
+    pub min_nanos: u64,
+    /// The slowest iteration, in nanoseconds.
+    pub max_nanos: u64,
+}
+
+impl Measurement {
+    /// Measures the given operation over the given number of iterations.
+    /// Note: The `setup` closure is run before each iteration, and is excluded from the measurement.
+    pub fn run<S, T>(
+        name: &str,
+        size: u64,
+        iterations: u32,
+        mut setup: impl FnMut() -> Result<S>,
+        mut operation: impl FnMut(S) -> Result<T>,
+    ) -> Result<Self> {
+        anyhow::ensure!(iterations > 0, "Benchmark '{name}' must run at least one iteration");
+
+        let mut elapsed = Vec::with_capacity(iterations as usize);
+        for _ in 0..iterations {
+            // Prepare the input for the iteration.
+            let input = setup()?;
+            // Time the operation.
+            let timer = Instant::now();
+            let output = operation(input)?;
+            elapsed.push(u64::try_from(timer.elapsed().as_nanos())?);
+            // Drop the output outside of the timed region.
+            drop(output);
+        }
+
+        let total_nanos = elapsed.iter().sum::<u64>();
+        Ok(Self {
+            name: name.to_string(),
+            size,
+            iterations,
+            total_nanos,
+            mean_nanos: total_nanos / iterations as u64,
+            min_nanos: elapsed.iter().copied().min().unwrap_or_default(),
+            max_nanos: elapsed.iter().copied().max().unwrap_or_default(),
+        })
+    }
+}
+
+/// A machine-readable report of a benchmark run.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Report {
+    /// The version of snarkVM that was measured.
+    pub version: String,
+    /// The seed of the dataset.
+    pub seed: u64,
+    /// The measurements.
+    pub measurements: Vec<Measurement>,
+}
+
+impl Report {
+    /// Initializes a new report for the given seed.
+    pub fn new(seed: u64) -> Self {
+        Self { version: env!("CARGO_PKG_VERSION").to_string(), seed, measurements: Vec::new() }
+    }
+
+    /// Adds the given measurements to the report.
+    pub fn extend(&mut self, measurements: impl IntoIterator<Item = Measurement>) {
+        self.measurements.extend(measurements)
+    }
+
+    /// Returns the report as a JSON string.
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+}
@@ -0,0 +1,104 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::Measurement;
+use console::prelude::*;
+use synthesizer_program::Program;
+
+use anyhow::Result;
+
+/// A workload of a program with `num_functions` functions, each with a finalize scope that updates a mapping.
+/// Measures the parsing and (de)serialization of the program, as performed when a deployment is received.
+pub struct DeploymentWorkload<N: Network> {
+    /// The number of functions in the program.
+    num_functions: usize,
+    /// The program source.
+    source: String,
+    /// The program.
+    program: Program<N>,
+}
+
+impl<N: Network> DeploymentWorkload<N> {
+    /// Samples a program with the given number of functions.
+    pub fn sample(num_functions: usize) -> Result<Self> {
+        // Construct the program source.
+        let mut source = r"program bench.aleo;
+
+mapping counts:
+    key as u32.public;
+    value as u64.public;
+"
+        .to_string();
+        for index in 0..num_functions {
+            source.push_str(&format!(
+                r"
+function f{index}:
+    input r0 as u64.public;
+    input r1 as u64.public;
+    add r0 r1 into r2;
+    async f{index} r2 into r3;
+    output r3 as bench.aleo/f{index}.future;
+
+finalize f{index}:
+    input r0 as u64.public;
+    get.or_use counts[{index}u32] 0u64 into r1;
+    add r1 r0 into r2;
+    set r2 into counts[{index}u32];
+"
+            ));
+        }
+        // Parse the program.
+        let program = Program::from_str(&source)?;
+        Ok(Self { num_functions, source, program })
+    }
+
+    /// Measures parsing the program from its source.
+    pub fn bench_parse(&self, iterations: u32) -> Result<Measurement> {
+        Measurement::run(
+            "deployments/parse",
+            self.num_functions as u64,
+            iterations,
+            || Ok(()),
+            |_| Program::<N>::from_str(&self.source),
+        )
+    }
+
+    /// Measures serializing the program to bytes.
+    pub fn bench_serialize(&self, iterations: u32) -> Result<Measurement> {
+        Measurement::run(
+            "deployments/serialize",
+            self.num_functions as u64,
+            iterations,
+            || Ok(()),
+            |_| self.program.to_bytes_le(),
+        )
+    }
+
+    /// Measures deserializing the program from bytes.
+    pub fn bench_deserialize(&self, iterations: u32) -> Result<Measurement> {
+        let bytes = self.program.to_bytes_le()?;
+        Measurement::run(
+            "deployments/deserialize",
+            self.num_functions as u64,
+            iterations,
+            || Ok(()),
+            |_| Program::<N>::from_bytes_le(&bytes),
+        )
+    }
+
+    /// Runs all of the measurements of the workload.
+    pub fn run(&self, iterations: u32) -> Result<Vec<Measurement>> {
+        Ok(vec![self.bench_parse(iterations)?, self.bench_serialize(iterations)?, self.bench_deserialize(iterations)?])
+    }
+}
@@ -0,0 +1,98 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{Dataset, Measurement};
+use circuit::Aleo;
+use console::{
+    account::{Address, PrivateKey},
+    prelude::*,
+    program::{Identifier, ProgramID, Value},
+};
+use ledger_block::Execution;
+use ledger_query::Query;
+use ledger_store::{helpers::memory::BlockMemory, BlockStore};
+use synthesizer_process::Process;
+
+use anyhow::Result;
+
+/// A workload of `credits.aleo/transfer_public` executions.
+/// Measures proving and verifying an execution.
+/// Note: The process loads the `credits.aleo` proving keys, which may be downloaded on first use.
+pub struct ProvingWorkload<N: Network> {
+    /// The process.
+    process: Process<N>,
+    /// The block store, used to prepare the executions.
+    block_store: BlockStore<N, BlockMemory<N>>,
+    /// The caller of the executions.
+    private_key: PrivateKey<N>,
+    /// The inputs of the executions.
+    inputs: Vec<Value<N>>,
+    /// A proven execution, used to measure verification.
+    execution: Execution<N>,
+}
+
+impl<N: Network> ProvingWorkload<N> {
+    /// Samples a `transfer_public` execution.
+    pub fn sample<A: Aleo<Network = N>>(dataset: &mut Dataset) -> Result<Self> {
+        // Initialize the process.
+        let process = Process::load()?;
+        // Initialize the block store.
+        let block_store = BlockStore::<N, BlockMemory<N>>::open(None)?;
+
+        // Sample the caller and the recipient.
+        let accounts = dataset.sample_accounts::<N>(2)?;
+        let recipient = Address::try_from(&accounts[1])?;
+        // Prepare the inputs.
+        let inputs = vec![Value::from_str(&recipient.to_string())?, Value::from_str("1u64")?];
+
+        let mut workload =
+            Self { process, block_store, private_key: accounts[0], inputs, execution: Execution::new() };
+        // Prove an execution, to measure verification.
+        workload.execution = workload.prove::<A>(dataset.rng())?;
+        Ok(workload)
+    }
+
+    /// Authorizes, executes, and proves a `transfer_public` execution.
+    fn prove<A: Aleo<Network = N>>(&self, rng: &mut TestRng) -> Result<Execution<N>> {
+        // Authorize the function call.
+        let authorization = self.process.authorize::<A, _>(
+            &self.private_key,
+            ProgramID::from_str("credits.aleo")?,
+            Identifier::from_str("transfer_public")?,
+            self.inputs.iter(),
+            rng,
+        )?;
+        // Execute the request.
+        let (_, mut trace) = self.process.execute::<A, _>(authorization, rng)?;
+        // Prepare the trace.
+        trace.prepare(Query::from(&self.block_store))?;
+        // Prove the execution.
+        trace.prove_execution::<A, _>("credits.aleo/transfer_public", rng)
+    }
+
+    /// Measures proving an execution.
+    pub fn bench_prove<A: Aleo<Network = N>>(&self, dataset: &mut Dataset, iterations: u32) -> Result<Measurement> {
+        Measurement::run("proving/prove", 1, iterations, || Ok(()), |_| self.prove::<A>(dataset.rng()))
+    }
+
+    /// Measures verifying an execution.
+    pub fn bench_verify(&self, iterations: u32) -> Result<Measurement> {
+        Measurement::run("proving/verify", 1, iterations, || Ok(()), |_| self.process.verify_execution(&self.execution))
+    }
+
+    /// Runs all of the measurements of the workload.
+    pub fn run<A: Aleo<Network = N>>(&self, dataset: &mut Dataset, iterations: u32) -> Result<Vec<Measurement>> {
+        Ok(vec![self.bench_prove::<A>(dataset, iterations)?, self.bench_verify(iterations)?])
+    }
+}
@@ -0,0 +1,75 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{Dataset, Measurement};
+use console::{account::Address, prelude::*};
+use ledger_coinbase::{CoinbasePuzzle, CoinbaseSolution, EpochChallenge, PuzzleConfig};
+
+use anyhow::Result;
+
+/// The degree of the coinbase puzzle used by the workload.
+const PUZZLE_DEGREE: u32 = (1 << 13) - 1;
+
+/// A workload of a batch of coinbase puzzle solutions from distinct provers.
+/// Measures the verification of the batch, as performed when a block is received.
+/// Note: The puzzle setup loads the universal SRS, which may be downloaded on first use.
+pub struct PuzzleWorkload<N: Network> {
+    /// The coinbase puzzle.
+    puzzle: CoinbasePuzzle<N>,
+    /// The epoch challenge.
+    epoch_challenge: EpochChallenge<N>,
+    /// The batch of solutions.
+    solutions: CoinbaseSolution<N>,
+}
+
+impl<N: Network> PuzzleWorkload<N> {
+    /// Samples a batch of `num_solutions` solutions.
+    pub fn sample(dataset: &mut Dataset, num_solutions: usize) -> Result<Self> {
+        ensure!(num_solutions <= N::MAX_SOLUTIONS, "The puzzle workload exceeds {} solutions", N::MAX_SOLUTIONS);
+
+        // Initialize the coinbase puzzle.
+        let srs = CoinbasePuzzle::<N>::setup(PuzzleConfig { degree: PUZZLE_DEGREE })?;
+        let puzzle = CoinbasePuzzle::<N>::trim(&srs, PuzzleConfig { degree: PUZZLE_DEGREE })?;
+        // Sample the epoch challenge.
+        let epoch_challenge = EpochChallenge::new(dataset.rng().gen(), Default::default(), PUZZLE_DEGREE)?;
+
+        // Sample the solutions.
+        let solutions = dataset
+            .sample_accounts::<N>(num_solutions)?
+            .iter()
+            .map(|private_key| {
+                let address = Address::try_from(private_key)?;
+                puzzle.prove(&epoch_challenge, address, dataset.rng().gen(), None)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self { puzzle, epoch_challenge, solutions: CoinbaseSolution::new(solutions)? })
+    }
+
+    /// Measures the verification of the batch of solutions.
+    pub fn bench_verify(&self, iterations: u32) -> Result<Measurement> {
+        Measurement::run(
+            "puzzle/verify",
+            self.solutions.len() as u64,
+            iterations,
+            || Ok(()),
+            |_| self.puzzle.check_solutions(&self.solutions, &self.epoch_challenge, 0),
+        )
+    }
+
+    /// Runs all of the measurements of the workload.
+    pub fn run(&self, iterations: u32) -> Result<Vec<Measurement>> {
+        Ok(vec![self.bench_verify(iterations)?])
+    }
+}
@@ -0,0 +1,154 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{Dataset, Measurement};
+use console::{
+    account::{Address, ViewKey},
+    prelude::*,
+    program::{Ciphertext, Identifier, Plaintext, ProgramID, Record},
+    types::Scalar,
+};
+use ledger_block::Output;
+use ledger_store::{helpers::memory::OutputMemory, OutputStore};
+
+use anyhow::Result;
+
+/// The number of record outputs in each transfer.
+const OUTPUTS_PER_TRANSFER: usize = 2;
+
+/// A workload of transfers, each producing two `credits` record outputs owned by a fixed set of accounts.
+/// Measures the insertion of the outputs into storage, and the scanning of the outputs by a single account.
+pub struct TransferWorkload<N: Network> {
+    /// The view keys of the accounts.
+    view_keys: Vec<ViewKey<N>>,
+    /// The transition IDs and record outputs of the transfers.
+    transfers: Vec<(N::TransitionID, Vec<Output<N>>)>,
+}
+
+impl<N: Network> TransferWorkload<N> {
+    /// Samples a workload of `num_transfers` transfers between `num_accounts` accounts.
+    pub fn sample(dataset: &mut Dataset, num_transfers: usize, num_accounts: usize) -> Result<Self> {
+        ensure!(num_accounts > 0, "The transfer workload requires at least one account");
+
+        // Sample the accounts.
+        let private_keys = dataset.sample_accounts::<N>(num_accounts)?;
+        let view_keys = private_keys.iter().map(ViewKey::try_from).collect::<Result<Vec<_>>>()?;
+        let addresses = private_keys.iter().map(Address::try_from).collect::<Result<Vec<_>>>()?;
+
+        // Prepare the program ID and record name of a `credits` record.
+        let program_id = ProgramID::<N>::from_str("credits.aleo")?;
+        let record_name = Identifier::<N>::from_str("credits")?;
+
+        // Sample the transfers.
+        let mut transfers = Vec::with_capacity(num_transfers);
+        for index in 0..num_transfers {
+            let mut outputs = Vec::with_capacity(OUTPUTS_PER_TRANSFER);
+            for offset in 0..OUTPUTS_PER_TRANSFER {
+                // Select the owner of the record.
+                let owner = addresses[(index * OUTPUTS_PER_TRANSFER + offset) % num_accounts];
+                // Sample the record.
+                let randomizer = Scalar::rand(dataset.rng());
+                let record = Record::<N, Plaintext<N>>::from_str(&format!(
+                    "{{ owner: {owner}.private, microcredits: {}u64.private, _nonce: {}.public }}",
+                    dataset.rng().gen_range(1..1_000_000u64),
+                    N::g_scalar_multiply(&randomizer)
+                ))?;
+                // Compute the record output, as it appears in a transition.
+                let commitment = record.to_commitment(&program_id, &record_name)?;
+                let (record_ciphertext, view_tag) = record.encrypt_with_view_tag(randomizer)?;
+                let checksum = record_ciphertext.to_checksum(view_tag)?;
+                outputs.push(Output::Record(commitment, checksum, Some(record_ciphertext), Some(view_tag)));
+            }
+            transfers.push((Uniform::rand(dataset.rng()), outputs));
+        }
+
+        Ok(Self { view_keys, transfers })
+    }
+
+    /// Returns the number of transfers in the workload.
+    pub fn len(&self) -> usize {
+        self.transfers.len()
+    }
+
+    /// Returns `true` if the workload contains no transfers.
+    pub fn is_empty(&self) -> bool {
+        self.transfers.is_empty()
+    }
+
+    /// Measures the insertion of the transfers into a fresh output store.
+    pub fn bench_insert(&self, iterations: u32) -> Result<Measurement> {
+        Measurement::run(
+            "transfers/insert",
+            self.len() as u64,
+            iterations,
+            || OutputStore::<N, OutputMemory<N>>::open(None),
+            |store| {
+                for (transition_id, outputs) in &self.transfers {
+                    store.insert(*transition_id, outputs)?;
+                }
+                Ok(store)
+            },
+        )
+    }
+
+    /// Measures the scanning of the transfers by the first account, by decrypting the owner of every record.
+    pub fn bench_scan(&self, iterations: u32) -> Result<Measurement> {
+        let view_key = self.view_keys[0];
+        Measurement::run(
+            "transfers/scan",
+            self.len() as u64,
+            iterations,
+            || Ok(()),
+            |_| Ok(self.records().filter(|(_, record)| record.is_owner(&view_key)).count()),
+        )
+    }
+
+    /// Measures the scanning of the transfers by the first account, by first discarding records with a mismatched view tag.
+    pub fn bench_scan_with_view_tags(&self, iterations: u32) -> Result<Measurement> {
+        let view_key = self.view_keys[0];
+        Measurement::run(
+            "transfers/scan_with_view_tags",
+            self.len() as u64,
+            iterations,
+            || Ok(()),
+            |_| {
+                Ok(self
+                    .records()
+                    .filter(|(view_tag, record)| match view_tag {
+                        Some(view_tag) => record.is_view_tag_match(&view_key, *view_tag),
+                        None => true,
+                    })
+                    .filter(|(_, record)| record.is_owner(&view_key))
+                    .count())
+            },
+        )
+    }
+
+    /// Runs all of the measurements of the workload.
+    pub fn run(&self, iterations: u32) -> Result<Vec<Measurement>> {
+        Ok(vec![
+            self.bench_insert(iterations)?,
+            self.bench_scan(iterations)?,
+            self.bench_scan_with_view_tags(iterations)?,
+        ])
+    }
+
+    /// Returns an iterator over the view tags and record ciphertexts of the transfers.
+    fn records(&self) -> impl '_ + Iterator<Item = (Option<u8>, &Record<N, Ciphertext<N>>)> {
+        self.transfers
+            .iter()
+            .flat_map(|(_, outputs)| outputs.iter())
+            .filter_map(|output| output.record().map(|(_, record)| (output.view_tag(), record)))
+    }
+}
@@ -34,6 +34,9 @@ pub trait Aleo: Environment {
     /// Returns the serial number domain as a constant field element.
     fn serial_number_domain() -> Field<Self>;
 
+    /// Returns the view tag domain as a constant field element.
+    fn view_tag_domain() -> Field<Self>;
+
     /// Returns the scalar multiplication on the generator `G`.
     fn g_scalar_multiply(scalar: &Scalar<Self>) -> Group<Self>;
 
@@ -60,6 +60,8 @@ thread_local! {
     static GRAPH_KEY_DOMAIN: Field<AleoV0> = Field::constant(<console::MainnetV0 as console::Network>::graph_key_domain());
     /// The serial number domain as a constant field element.
     static SERIAL_NUMBER_DOMAIN: Field<AleoV0> = Field::constant(<console::MainnetV0 as console::Network>::serial_number_domain());
+    /// The view tag domain as a constant field element.
+    static VIEW_TAG_DOMAIN: Field<AleoV0> = Field::constant(<console::MainnetV0 as console::Network>::view_tag_domain());
 
     /// The BHP hash function, which can take an input of up to 256 bits.
     static BHP_256: BHP256<AleoV0> = BHP256::<AleoV0>::constant(console::BHP_256.clone());
@@ -116,6 +118,11 @@ impl Aleo for AleoV0 {
         SERIAL_NUMBER_DOMAIN.with(|domain| domain.clone())
     }
 
+    /// Returns the view tag domain as a constant field element.
+    fn view_tag_domain() -> Field<Self> {
+        VIEW_TAG_DOMAIN.with(|domain| domain.clone())
+    }
+
     /// Returns the scalar multiplication on the generator `G`.
     #[inline]
     fn g_scalar_multiply(scalar: &Scalar<Self>) -> Group<Self> {
@@ -0,0 +1,79 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<A: Aleo> Record<A, Plaintext<A>> {
+    /// Encrypts `self` for the given auditor under the given randomizer.
+    /// Note: Together with `Self::encrypt` under the same randomizer, this proves that the auditor ciphertext
+    /// and the ciphertext of the record owner encrypt the same record.
+    pub fn encrypt_for_auditor(&self, randomizer: &Scalar<A>, auditor: &Address<A>) -> Record<A, Ciphertext<A>> {
+        // Ensure the randomizer corresponds to the record nonce.
+        A::assert_eq(&self.nonce, A::g_scalar_multiply(randomizer));
+        // Compute the record view key of the auditor.
+        let record_view_key = (auditor.to_group() * randomizer).to_x_coordinate();
+        // Encrypt the record.
+        self.encrypt_symmetric_unchecked(record_view_key)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const ITERATIONS: u64 = 10;
+
+    #[test]
+    fn test_encrypt_for_auditor() -> Result<()> {
+        use console::Network;
+
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample the owner and the auditor.
+            let private_key = snarkvm_console_account::PrivateKey::<<Circuit as Environment>::Network>::new(&mut rng)?;
+            let address = snarkvm_console_account::Address::try_from(private_key)?;
+            let auditor_private_key =
+                snarkvm_console_account::PrivateKey::<<Circuit as Environment>::Network>::new(&mut rng)?;
+            let auditor = snarkvm_console_account::Address::try_from(auditor_private_key)?;
+
+            // Construct the record.
+            let randomizer = Uniform::rand(&mut rng);
+            let record =
+                console::Record::<<Circuit as Environment>::Network, console::Plaintext<_>>::from_str(&format!(
+                    "{{ owner: {address}.private, amount: 100u64.private, _nonce: {}.public }}",
+                    <Circuit as Environment>::Network::g_scalar_multiply(&randomizer)
+                ))?;
+            // Compute the expected ciphertexts.
+            let expected = record.encrypt(randomizer)?;
+            let expected_auditor = record.encrypt_for_auditor(randomizer, &auditor)?;
+
+            // Compute both ciphertexts in the circuit, from the same plaintext and randomizer.
+            let circuit_record = Record::<Circuit, Plaintext<Circuit>>::new(Mode::Private, record);
+            let circuit_randomizer = Scalar::new(Mode::Private, randomizer);
+            let candidate = circuit_record.encrypt(&circuit_randomizer);
+            let candidate_auditor =
+                circuit_record.encrypt_for_auditor(&circuit_randomizer, &Address::new(Mode::Private, auditor));
+            assert_eq!(expected, candidate.eject_value());
+            assert_eq!(expected_auditor, candidate_auditor.eject_value());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+}
@@ -21,6 +21,7 @@ pub use entry::Entry;
 mod helpers;
 pub use helpers::Owner;
 
+mod audit;
 mod decrypt;
 mod encrypt;
 mod equal;
@@ -31,11 +32,12 @@ mod tag;
 mod to_bits;
 mod to_commitment;
 mod to_fields;
+mod view_tag;
 
 use crate::{Access, Ciphertext, Identifier, Plaintext, ProgramID, Visibility};
 use snarkvm_circuit_account::{PrivateKey, ViewKey};
 use snarkvm_circuit_network::Aleo;
-use snarkvm_circuit_types::{environment::prelude::*, Boolean, Field, Group, Scalar, U32};
+use snarkvm_circuit_types::{environment::prelude::*, Address, Boolean, Field, Group, Scalar, U32, U8};
 
 #[derive(Clone)]
 pub struct Record<A: Aleo, Private: Visibility<A>> {
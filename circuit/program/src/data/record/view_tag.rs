@@ -0,0 +1,95 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<A: Aleo> Record<A, Plaintext<A>> {
+    /// Encrypts `self` for the record owner under the given randomizer, and returns the record ciphertext and its view tag.
+    /// Note: The view tag is derived from the record view key that is already computed for encryption,
+    /// so it only costs one additional hash.
+    pub fn encrypt_with_view_tag(&self, randomizer: &Scalar<A>) -> (Record<A, Ciphertext<A>>, U8<A>) {
+        // Ensure the randomizer corresponds to the record nonce.
+        A::assert_eq(&self.nonce, A::g_scalar_multiply(randomizer));
+        // Compute the record view key.
+        let record_view_key = ((*self.owner).to_group() * randomizer).to_x_coordinate();
+        // Compute the view tag.
+        let view_tag = Self::view_tag_from_record_view_key(record_view_key.clone());
+        // Encrypt the record, and return the record ciphertext and view tag.
+        (self.encrypt_symmetric_unchecked(record_view_key), view_tag)
+    }
+}
+
+impl<A: Aleo> Record<A, Ciphertext<A>> {
+    /// Returns the record checksum, as `Hash(record ciphertext || view tag)`.
+    pub fn to_checksum(&self, view_tag: &U8<A>) -> Field<A> {
+        A::hash_bhp1024(&[self.to_bits_le(), view_tag.to_bits_le()].concat())
+    }
+}
+
+impl<A: Aleo, Private: Visibility<A>> Record<A, Private> {
+    /// Returns the view tag for the given record view key, as the first byte of `Hash(domain || record_view_key)`.
+    pub fn view_tag_from_record_view_key(record_view_key: Field<A>) -> U8<A> {
+        // Hash the record view key.
+        let hash = A::hash_psd2(&[A::view_tag_domain(), record_view_key]);
+        // Return the first byte of the hash.
+        U8::from_bits_le(&hash.to_bits_le()[0..8])
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const ITERATIONS: u64 = 10;
+
+    #[test]
+    fn test_encrypt_with_view_tag() -> Result<()> {
+        use console::Network;
+
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample an address.
+            let private_key = snarkvm_console_account::PrivateKey::<<Circuit as Environment>::Network>::new(&mut rng)?;
+            let address = snarkvm_console_account::Address::try_from(private_key)?;
+
+            // Construct the record.
+            let randomizer = Uniform::rand(&mut rng);
+            let record =
+                console::Record::<<Circuit as Environment>::Network, console::Plaintext<_>>::from_str(&format!(
+                    "{{ owner: {address}.private, amount: 100u64.private, _nonce: {}.public }}",
+                    <Circuit as Environment>::Network::g_scalar_multiply(&randomizer)
+                ))?;
+            // Compute the expected record ciphertext, view tag, and checksum.
+            let (expected_ciphertext, expected_view_tag) = record.encrypt_with_view_tag(randomizer)?;
+            let expected_checksum = expected_ciphertext.to_checksum(expected_view_tag)?;
+
+            // Compute the record ciphertext, view tag, and checksum in the circuit.
+            let (candidate_ciphertext, candidate_view_tag) =
+                Record::<Circuit, Plaintext<Circuit>>::new(Mode::Private, record)
+                    .encrypt_with_view_tag(&Scalar::new(Mode::Private, randomizer));
+            let candidate_checksum = candidate_ciphertext.to_checksum(&candidate_view_tag);
+            assert_eq!(expected_ciphertext, candidate_ciphertext.eject_value());
+            assert_eq!(expected_view_tag, *candidate_view_tag.eject_value());
+            assert_eq!(expected_checksum, candidate_checksum.eject_value());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,256 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{Entry, Identifier, Literal, Plaintext, ProgramID, Record};
+use snarkvm_circuit_account::ViewKey;
+use snarkvm_circuit_network::Aleo;
+use snarkvm_circuit_types::{environment::prelude::*, Boolean, Field};
+
+/// The predicate that a disclosed record entry satisfies against the disclosed value.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DisclosureOperator {
+    /// The entry is equal to the value.
+    Eq,
+    /// The entry is not equal to the value.
+    Neq,
+    /// The entry is less than the value.
+    Lt,
+    /// The entry is less than or equal to the value.
+    Lte,
+    /// The entry is greater than the value.
+    Gt,
+    /// The entry is greater than or equal to the value.
+    Gte,
+}
+
+impl DisclosureOperator {
+    /// Returns `true` if `entry` and `value` satisfy the operator.
+    pub fn evaluate<A: Aleo, T>(&self, entry: &T, value: &T) -> Boolean<A>
+    where
+        T: Equal<T, Output = Boolean<A>> + Compare<T, Output = Boolean<A>>,
+    {
+        match self {
+            Self::Eq => entry.is_equal(value),
+            Self::Neq => entry.is_not_equal(value),
+            Self::Lt => entry.is_less_than(value),
+            Self::Lte => entry.is_less_than_or_equal(value),
+            Self::Gt => entry.is_greater_than(value),
+            Self::Gte => entry.is_greater_than_or_equal(value),
+        }
+    }
+}
+
+#[cfg(console)]
+impl From<console::DisclosureOperator> for DisclosureOperator {
+    /// Initializes a circuit operator from a console operator.
+    fn from(operator: console::DisclosureOperator) -> Self {
+        match operator {
+            console::DisclosureOperator::Eq => Self::Eq,
+            console::DisclosureOperator::Neq => Self::Neq,
+            console::DisclosureOperator::Lt => Self::Lt,
+            console::DisclosureOperator::Lte => Self::Lte,
+            console::DisclosureOperator::Gt => Self::Gt,
+            console::DisclosureOperator::Gte => Self::Gte,
+        }
+    }
+}
+
+/// A statement that a top-level entry of the record behind a commitment satisfies a predicate.
+pub struct Disclosure<A: Aleo> {
+    /// The program ID of the record.
+    program_id: ProgramID<A>,
+    /// The name of the record.
+    record_name: Identifier<A>,
+    /// The commitment of the record.
+    commitment: Field<A>,
+    /// The hash of the value.
+    value_hash: Field<A>,
+    /// The name of the disclosed entry.
+    entry_name: Identifier<A>,
+    /// The predicate operator.
+    operator: DisclosureOperator,
+    /// The value the entry is compared against.
+    value: Literal<A>,
+}
+
+#[cfg(console)]
+impl<A: Aleo> Inject for Disclosure<A> {
+    type Primitive = console::Disclosure<A::Network>;
+
+    /// Initializes a disclosure from a primitive.
+    /// Note: The commitment and the value hash are injected as `Mode::Public`, in that order,
+    /// matching `console::Disclosure::to_verifier_inputs`.
+    fn new(_: Mode, disclosure: Self::Primitive) -> Self {
+        // Compute the value hash.
+        let value_hash = match disclosure.to_value_hash() {
+            Ok(value_hash) => value_hash,
+            Err(error) => A::halt(format!("Failed to compute the disclosure value hash: {error}")),
+        };
+        Self {
+            program_id: ProgramID::new(Mode::Constant, *disclosure.program_id()),
+            record_name: Identifier::new(Mode::Constant, *disclosure.record_name()),
+            commitment: Field::new(Mode::Public, *disclosure.commitment()),
+            value_hash: Field::new(Mode::Public, value_hash),
+            entry_name: Identifier::new(Mode::Constant, *disclosure.entry_name()),
+            operator: disclosure.operator().into(),
+            value: Literal::new(Mode::Private, disclosure.value().clone()),
+        }
+    }
+}
+
+impl<A: Aleo> Disclosure<A> {
+    /// Returns `true` if the given record opens the commitment, is owned by the given view key,
+    /// and its entry satisfies the predicate.
+    pub fn verify(&self, view_key: &ViewKey<A>, record: &Record<A, Plaintext<A>>) -> Boolean<A> {
+        // Retrieve the disclosed entry, which must be a literal.
+        let entry = match record.data().get(&self.entry_name) {
+            Some(Entry::Constant(Plaintext::Literal(literal, _)))
+            | Some(Entry::Public(Plaintext::Literal(literal, _)))
+            | Some(Entry::Private(Plaintext::Literal(literal, _))) => literal,
+            _ => return Boolean::constant(false),
+        };
+
+        // Ensure the view key corresponds to the record owner.
+        let is_owner = view_key.to_address().is_equal(record.owner().deref());
+
+        // Ensure the record opens the commitment.
+        let candidate_commitment = record.to_commitment(&self.program_id, &self.record_name);
+        let is_commitment_valid = candidate_commitment.is_equal(&self.commitment);
+
+        // Ensure the value opens the value hash.
+        let candidate_value_hash = A::hash_psd8(&Plaintext::from(self.value.clone()).to_fields());
+        let is_value_valid = candidate_value_hash.is_equal(&self.value_hash);
+
+        // Evaluate the predicate.
+        is_owner & is_commitment_valid & is_value_valid & evaluate(self.operator, entry, &self.value)
+    }
+}
+
+/// Returns `true` if `entry` and `value` satisfy the operator.
+/// Note: Literals of different types never satisfy the predicate.
+fn evaluate<A: Aleo>(operator: DisclosureOperator, entry: &Literal<A>, value: &Literal<A>) -> Boolean<A> {
+    match (operator, entry, value) {
+        (DisclosureOperator::Eq, a, b) => a.is_equal(b),
+        (DisclosureOperator::Neq, a, b) => a.is_not_equal(b),
+        (operator, Literal::Field(a), Literal::Field(b)) => operator.evaluate(a, b),
+        (operator, Literal::Scalar(a), Literal::Scalar(b)) => operator.evaluate(a, b),
+        (operator, Literal::I8(a), Literal::I8(b)) => operator.evaluate(a, b),
+        (operator, Literal::I16(a), Literal::I16(b)) => operator.evaluate(a, b),
+        (operator, Literal::I32(a), Literal::I32(b)) => operator.evaluate(a, b),
+        (operator, Literal::I64(a), Literal::I64(b)) => operator.evaluate(a, b),
+        (operator, Literal::I128(a), Literal::I128(b)) => operator.evaluate(a, b),
+        (operator, Literal::U8(a), Literal::U8(b)) => operator.evaluate(a, b),
+        (operator, Literal::U16(a), Literal::U16(b)) => operator.evaluate(a, b),
+        (operator, Literal::U32(a), Literal::U32(b)) => operator.evaluate(a, b),
+        (operator, Literal::U64(a), Literal::U64(b)) => operator.evaluate(a, b),
+        (operator, Literal::U128(a), Literal::U128(b)) => operator.evaluate(a, b),
+        _ => Boolean::constant(false),
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    #[test]
+    fn test_disclosure() -> Result<()> {
+        use console::Network;
+
+        let mut rng = TestRng::default();
+
+        let program_id = console::ProgramID::from_str("token.aleo")?;
+        let record_name = console::Identifier::from_str("token")?;
+        let amount = console::Identifier::from_str("amount")?;
+
+        // Construct the record.
+        let private_key = snarkvm_console_account::PrivateKey::<<Circuit as Environment>::Network>::new(&mut rng)?;
+        let view_key = snarkvm_console_account::ViewKey::try_from(&private_key)?;
+        let address = snarkvm_console_account::Address::try_from(private_key)?;
+        let record = console::Record::<<Circuit as Environment>::Network, console::Plaintext<_>>::from_str(&format!(
+            "{{ owner: {address}.private, amount: 150u64.private, _nonce: {}.public }}",
+            <Circuit as Environment>::Network::g_scalar_multiply(&Uniform::rand(&mut rng))
+        ))?;
+        let commitment = record.to_commitment(&program_id, &record_name)?;
+
+        for (operator, value) in [
+            (console::DisclosureOperator::Gte, "100u64"),
+            (console::DisclosureOperator::Gt, "150u64"),
+            (console::DisclosureOperator::Lt, "151u64"),
+            (console::DisclosureOperator::Lte, "149u64"),
+            (console::DisclosureOperator::Eq, "150u64"),
+            (console::DisclosureOperator::Neq, "150u64"),
+            (console::DisclosureOperator::Gte, "100u128"),
+        ] {
+            let disclosure = console::Disclosure::new(
+                program_id,
+                record_name,
+                commitment,
+                amount,
+                operator,
+                console::Literal::from_str(value)?,
+            )?;
+            // Compute the expected result.
+            let expected = disclosure.holds_for(&view_key, &record)?;
+
+            // Verify the disclosure in the circuit.
+            let circuit_view_key = ViewKey::<Circuit>::new(Mode::Private, view_key);
+            let circuit_record = Record::<Circuit, Plaintext<Circuit>>::new(Mode::Private, record.clone());
+            let circuit_disclosure = Disclosure::<Circuit>::new(Mode::Private, disclosure.clone());
+            let candidate = circuit_disclosure.verify(&circuit_view_key, &circuit_record);
+            assert_eq!(expected, candidate.eject_value());
+            // Ensure the constant one, the commitment, and the value hash are the only public variables.
+            assert_eq!(3, Circuit::num_public());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+
+        // Ensure the disclosure does not hold against another commitment.
+        let disclosure = console::Disclosure::new(
+            program_id,
+            record_name,
+            Uniform::rand(&mut rng),
+            amount,
+            console::DisclosureOperator::Gte,
+            console::Literal::from_str("100u64")?,
+        )?;
+        let circuit_view_key = ViewKey::<Circuit>::new(Mode::Private, view_key);
+        let circuit_record = Record::<Circuit, Plaintext<Circuit>>::new(Mode::Private, record.clone());
+        let circuit_disclosure = Disclosure::<Circuit>::new(Mode::Private, disclosure);
+        assert!(!circuit_disclosure.verify(&circuit_view_key, &circuit_record).eject_value());
+        Circuit::reset();
+
+        // Ensure the disclosure does not hold for a view key of another account.
+        let disclosure = console::Disclosure::new(
+            program_id,
+            record_name,
+            commitment,
+            amount,
+            console::DisclosureOperator::Gte,
+            console::Literal::from_str("100u64")?,
+        )?;
+        let other_private_key =
+            snarkvm_console_account::PrivateKey::<<Circuit as Environment>::Network>::new(&mut rng)?;
+        let other_view_key = snarkvm_console_account::ViewKey::try_from(&other_private_key)?;
+        let circuit_view_key = ViewKey::<Circuit>::new(Mode::Private, other_view_key);
+        let circuit_record = Record::<Circuit, Plaintext<Circuit>>::new(Mode::Private, record);
+        let circuit_disclosure = Disclosure::<Circuit>::new(Mode::Private, disclosure);
+        assert!(!circuit_disclosure.verify(&circuit_view_key, &circuit_record).eject_value());
+        Circuit::reset();
+        Ok(())
+    }
+}
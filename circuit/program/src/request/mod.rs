@@ -30,8 +30,8 @@ pub enum InputID<A: Aleo> {
     Public(Field<A>),
     /// The ciphertext hash of the private input.
     Private(Field<A>),
-    /// The `(commitment, gamma, serial_number, tag)` tuple of the record input.
-    Record(Field<A>, Box<Group<A>>, Field<A>, Field<A>),
+    /// The `(commitment, gamma, serial_number, tag, lock_height)` tuple of the record input.
+    Record(Field<A>, Box<Group<A>>, Field<A>, Field<A>, Option<Field<A>>),
     /// The hash of the external record input.
     ExternalRecord(Field<A>),
 }
@@ -49,12 +49,14 @@ impl<A: Aleo> Inject for InputID<A> {
             console::InputID::Public(field) => Self::Public(Field::new(Mode::Public, field)),
             // Inject the ciphertext hash as `Mode::Public`.
             console::InputID::Private(field) => Self::Private(Field::new(Mode::Public, field)),
-            // Inject commitment and gamma as `Mode::Private`, and the expected serial number and tag as `Mode::Public`.
-            console::InputID::Record(commitment, gamma, serial_number, tag) => Self::Record(
+            // Inject commitment and gamma as `Mode::Private`, and the expected serial number, tag,
+            // and (optional) lock height as `Mode::Public`.
+            console::InputID::Record(commitment, gamma, serial_number, tag, lock_height) => Self::Record(
                 Field::new(Mode::Private, commitment),
                 Box::new(Group::new(Mode::Private, gamma)),
                 Field::new(Mode::Public, serial_number),
                 Field::new(Mode::Public, tag),
+                lock_height.map(|lock_height| Field::new(Mode::Public, console::Field::from_u32(*lock_height))),
             ),
             // Inject the commitment as `Mode::Public`.
             console::InputID::ExternalRecord(field) => Self::ExternalRecord(Field::new(Mode::Public, field)),
@@ -72,11 +74,12 @@ impl<A: Aleo> Eject for InputID<A> {
             Self::Constant(field) => field.eject_mode(),
             Self::Public(field) => field.eject_mode(),
             Self::Private(field) => field.eject_mode(),
-            Self::Record(commitment, gamma, serial_number, tag) => Mode::combine(commitment.eject_mode(), [
-                gamma.eject_mode(),
-                serial_number.eject_mode(),
-                tag.eject_mode(),
-            ]),
+            Self::Record(commitment, gamma, serial_number, tag, lock_height) => Mode::combine(
+                commitment.eject_mode(),
+                [gamma.eject_mode(), serial_number.eject_mode(), tag.eject_mode()]
+                    .into_iter()
+                    .chain(lock_height.as_ref().map(|lock_height| lock_height.eject_mode())),
+            ),
             Self::ExternalRecord(field) => field.eject_mode(),
         }
     }
@@ -87,11 +90,12 @@ impl<A: Aleo> Eject for InputID<A> {
             Self::Constant(field) => console::InputID::Constant(field.eject_value()),
             Self::Public(field) => console::InputID::Public(field.eject_value()),
             Self::Private(field) => console::InputID::Private(field.eject_value()),
-            Self::Record(commitment, gamma, serial_number, tag) => console::InputID::Record(
+            Self::Record(commitment, gamma, serial_number, tag, lock_height) => console::InputID::Record(
                 commitment.eject_value(),
                 gamma.eject_value(),
                 serial_number.eject_value(),
                 tag.eject_value(),
+                lock_height.as_ref().map(|lock_height| console::U32::from_field_lossy(&lock_height.eject_value())),
             ),
             Self::ExternalRecord(field) => console::InputID::ExternalRecord(field.eject_value()),
         }
@@ -107,8 +111,10 @@ impl<A: Aleo> ToFields for InputID<A> {
             InputID::Constant(field) => vec![field.clone()],
             InputID::Public(field) => vec![field.clone()],
             InputID::Private(field) => vec![field.clone()],
-            InputID::Record(commitment, gamma, serial_number, tag) => {
-                vec![commitment.clone(), gamma.to_x_coordinate(), serial_number.clone(), tag.clone()]
+            InputID::Record(commitment, gamma, serial_number, tag, lock_height) => {
+                let mut fields = vec![commitment.clone(), gamma.to_x_coordinate(), serial_number.clone(), tag.clone()];
+                fields.extend(lock_height.clone());
+                fields
             }
             InputID::ExternalRecord(field) => vec![field.clone()],
         }
@@ -209,8 +209,9 @@ impl<A: Aleo> Request<A> {
                         // Ensure the expected hash matches the computed hash.
                         input_hash.is_equal(&A::hash_psd8(&ciphertext.to_fields()))
                     }
-                    // A record input is computed to its serial number.
-                    InputID::Record(commitment, gamma, serial_number, tag) => {
+                    // A record input, or a consumed external record input, is computed to its serial number.
+                    // Note: The lock height of a time-locked record is checked against its record type by the stack.
+                    InputID::Record(commitment, gamma, serial_number, tag, _) => {
                         // Retrieve the record.
                         let record = match &input {
                             Value::Record(record) => record,
@@ -218,14 +219,21 @@ impl<A: Aleo> Request<A> {
                             Value::Plaintext(..) => A::halt("Expected a record input, found a plaintext input"),
                             Value::Future(..) => A::halt("Expected a record input, found a future input"),
                         };
-                        // Retrieve the record name as a `Mode::Constant`.
-                        let record_name = match input_type {
-                            console::ValueType::Record(record_name) => Identifier::constant(*record_name),
+                        // Retrieve the program ID and record name of the record as `Mode::Constant`.
+                        let (record_program_id, record_name) = match input_type {
+                            console::ValueType::Record(record_name) => {
+                                (program_id.clone(), Identifier::constant(*record_name))
+                            }
+                            // An external record is committed under the program that produced it.
+                            console::ValueType::ExternalRecord(locator) => (
+                                ProgramID::new(Mode::Constant, *locator.program_id()),
+                                Identifier::constant(*locator.resource()),
+                            ),
                             // Ensure the input is a record.
                             _ => A::halt(format!("Expected a record input at input {index}")),
                         };
                         // Compute the record commitment.
-                        let candidate_commitment = record.to_commitment(program_id, &record_name);
+                        let candidate_commitment = record.to_commitment(&record_program_id, &record_name);
                         // Compute the `candidate_serial_number` from `gamma`.
                         let candidate_serial_number =
                             Record::<A, Plaintext<A>>::serial_number_from_gamma(gamma, candidate_commitment.clone());
@@ -118,10 +118,10 @@ impl<A: Aleo> Response<A> {
                         // Compute the encryption randomizer as `HashToScalar(tvk || index)`.
                         let randomizer = A::hash_to_scalar_psd2(&[tvk.clone(), output_index]);
 
-                        // Encrypt the record, using the randomizer.
-                        let encrypted_record = record.encrypt(&randomizer);
-                        // Compute the record checksum, as the hash of the encrypted record.
-                        let checksum = A::hash_bhp1024(&encrypted_record.to_bits_le());
+                        // Encrypt the record and compute its view tag, using the randomizer.
+                        let (encrypted_record, view_tag) = record.encrypt_with_view_tag(&randomizer);
+                        // Compute the record checksum, as the hash of the encrypted record and view tag.
+                        let checksum = encrypted_record.to_checksum(&view_tag);
 
                         // Return the output ID.
                         OutputID::record(commitment, checksum)
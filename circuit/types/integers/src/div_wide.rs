@@ -0,0 +1,255 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> DivWide<Self> for Integer<E, I> {
+    type Output = Self;
+
+    #[inline]
+    fn div_wide(&self, other: &Integer<E, I>) -> Self::Output {
+        match (self.is_constant(), other.is_constant()) {
+            // If `other` is a constant and is zero, then halt.
+            (_, true) if other.eject_value().is_zero() => E::halt("Attempted to divide by zero."),
+            // If `self` and `other` are constants, and other is not zero, then directly return the value of the division.
+            (true, true) => self.div_checked(other),
+            // Handle the remaining cases.
+            // Note that `other` is either a constant and non-zero, or not a constant.
+            _ => {
+                if I::is_signed() {
+                    // Ensure that overflow cannot occur in this division.
+                    // Signed integer division wraps when the dividend is Integer::MIN and the divisor is -1.
+                    let min = Integer::constant(console::Integer::MIN);
+                    let neg_one = Integer::constant(-console::Integer::one());
+                    let overflows = self.is_equal(&min) & other.is_equal(&neg_one);
+                    E::assert(!overflows);
+
+                    // Divide the absolute value of `self` and `other` in the base field.
+                    // Note that it is safe to use `abs_wrapped`, since the case for console::Integer::MIN is handled above.
+                    let unsigned_dividend = self.abs_wrapped().cast_as_dual();
+                    // Note that `unsigned_divisor` is zero iff `other` is zero.
+                    let unsigned_divisor = other.abs_wrapped().cast_as_dual();
+                    // Note that this call to `div_wide` checks that `unsigned_divisor` is not zero.
+                    let unsigned_quotient = unsigned_dividend.div_wide(&unsigned_divisor);
+
+                    // Note that quotient <= |console::Integer::MIN|, since the dividend <= |console::Integer::MIN| and 0 <= quotient <= dividend.
+                    let signed_quotient = Integer { bits_le: unsigned_quotient.bits_le, phantom: Default::default() };
+                    let operands_same_sign = &self.msb().is_equal(other.msb());
+
+                    Self::ternary(operands_same_sign, &signed_quotient, &Self::zero().sub_wrapped(&signed_quotient))
+                } else {
+                    // Return the quotient of `self` and `other`.
+                    // Note that this call checks that `other` is not zero.
+                    self.unsigned_division_via_halves(other).0
+                }
+            }
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> Integer<E, I> {
+    /// Divides `self` by `other`, via witnesses, returning the quotient and remainder.
+    /// This method does not check that `other` is non-zero.
+    /// If 2 * I::BITS >= E::BaseField::size_in_data_bits(), the division is checked over the halves of the integers.
+    /// Otherwise, this method is equivalent to `unsigned_division_via_witness`.
+    /// This method assumes the `self` and `other` are unsigned integers.
+    pub(super) fn unsigned_division_via_halves(&self, other: &Self) -> (Self, Self) {
+        // If the division fits in the base field, check it as in `unsigned_division_via_witness`.
+        if 2 * I::BITS < E::BaseField::size_in_data_bits() as u64 {
+            return self.unsigned_division_via_witness(other);
+        }
+
+        // Eject the dividend and divisor, to compute the quotient as a witness.
+        let dividend_value = self.eject_value();
+        // Note: This band-aid was added to prevent a panic when the divisor is 0.
+        let divisor_value = match other.eject_value().is_zero() {
+            true => console::Integer::one(),
+            false => other.eject_value(),
+        };
+
+        // Overflow is not possible for unsigned integers so we use wrapping operations.
+        let quotient = Integer::new(Mode::Private, console::Integer::new(dividend_value.wrapping_div(&divisor_value)));
+        let remainder = Integer::new(Mode::Private, console::Integer::new(dividend_value.wrapping_rem(&divisor_value)));
+
+        // Ensure that Euclidean division holds for these values as integers, by decomposing the quotient
+        // and divisor into their upper and lower halves, as in `karatsuba_multiply`:
+        //   quotient * other + remainder = z_0 + z_1 * 2^(I::BITS/2) + z_2 * 2^I::BITS + remainder
+        // As `self` is less than 2^I::BITS, the product of the upper halves `z_2` must be zero.
+        // The remaining terms are less than 2^(1.5 * I::BITS + 2), and do not wrap around the field modulus.
+        let x_1 = Field::from_bits_le(&quotient.bits_le[(I::BITS as usize / 2)..]);
+        let x_0 = Field::from_bits_le(&quotient.bits_le[..(I::BITS as usize / 2)]);
+        let y_1 = Field::from_bits_le(&other.bits_le[(I::BITS as usize / 2)..]);
+        let y_0 = Field::from_bits_le(&other.bits_le[..(I::BITS as usize / 2)]);
+
+        // Ensure that the product of the upper halves is zero.
+        E::assert_eq(&x_1 * &y_1, E::zero());
+
+        let z_0 = &x_0 * &y_0;
+        // Note that `z_1` omits `z_2`, since `z_2` is zero.
+        let z_1 = (&x_1 + &x_0) * (&y_1 + &y_0) - &z_0;
+
+        let mut b_m_bits = vec![Boolean::constant(false); I::BITS as usize / 2];
+        b_m_bits.push(Boolean::constant(true));
+        let b_m = Field::from_bits_le(&b_m_bits);
+
+        E::assert_eq(self.to_field(), z_0 + (z_1 * b_m) + remainder.to_field());
+
+        // Ensure that the remainder is less than the divisor.
+        // Note that if this check is satisfied and `other` is an unsigned integer, then `other` is not zero.
+        E::assert(remainder.is_less_than(other));
+
+        // Return the quotient and remainder of `self` and `other`.
+        (quotient, remainder)
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn DivWide<Integer<E, I>, Output = Integer<E, I>>> for Integer<E, I> {
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        // If the division fits in the base field, the circuit is the same as `div_checked`.
+        if 2 * I::BITS < E::BaseField::size_in_data_bits() as u64 {
+            return <Self as Metrics<dyn DivChecked<Integer<E, I>, Output = Integer<E, I>>>>::count(case);
+        }
+        match (case.0, case.1) {
+            (Mode::Constant, Mode::Constant) => Count::is(I::BITS, 0, 0, 0),
+            (Mode::Constant, _) | (_, Mode::Constant) => match I::is_signed() {
+                true => Count::less_than(7 * I::BITS + 1, 0, 1164, 1172),
+                false => Count::less_than(I::BITS + 1, 0, 387, 391),
+            },
+            (_, _) => match I::is_signed() {
+                true => Count::is(6 * I::BITS, 0, 1164, 1172),
+                false => Count::is(I::BITS, 0, 387, 391),
+            },
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn DivWide<Integer<E, I>, Output = Integer<E, I>>> for Integer<E, I> {
+    type Case = (Mode, Mode);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        <Self as OutputMode<dyn DivChecked<Integer<E, I>, Output = Integer<E, I>>>>::output_mode(case)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    use std::panic::RefUnwindSafe;
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_div_wide<I: IntegerType + RefUnwindSafe>(
+        name: &str,
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        second: console::Integer<<Circuit as Environment>::Network, I>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::<Circuit, I>::new(mode_b, second);
+        if second == console::Integer::zero() {
+            match mode_b {
+                Mode::Constant => check_operation_halts(&a, &b, Integer::div_wide),
+                _ => Circuit::scope(name, || {
+                    let _candidate = a.div_wide(&b);
+                    assert!(!Circuit::is_satisfied_in_scope(), "(!is_satisfied_in_scope)");
+                }),
+            }
+        } else {
+            match first.checked_div(&second) {
+                Some(expected) => Circuit::scope(name, || {
+                    let candidate = a.div_wide(&b);
+                    assert_eq!(console::Integer::new(expected), candidate.eject_value());
+                    assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+                }),
+                None => match (mode_a, mode_b) {
+                    (Mode::Constant, Mode::Constant) => check_operation_halts(&a, &b, Integer::div_wide),
+                    _ => Circuit::scope(name, || {
+                        let _candidate = a.div_wide(&b);
+                        assert!(!Circuit::is_satisfied_in_scope(), "(!is_satisfied_in_scope)");
+                    }),
+                },
+            }
+        }
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + RefUnwindSafe>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+
+            let name = format!("DivWide: {first} / {second}");
+            check_div_wide::<I>(&name, first, second, mode_a, mode_b);
+
+            let name = format!("DivWide by One: {first} / 1");
+            check_div_wide::<I>(&name, first, console::Integer::one(), mode_a, mode_b);
+
+            let name = format!("DivWide by Self: {first} / {first}");
+            check_div_wide::<I>(&name, first, first, mode_a, mode_b);
+
+            let name = format!("DivWide by Zero: {first} / 0");
+            check_div_wide::<I>(&name, first, console::Integer::zero(), mode_a, mode_b);
+        }
+
+        // Check standard division properties and corner cases.
+        check_div_wide::<I>("MAX / 1", console::Integer::MAX, console::Integer::one(), mode_a, mode_b);
+        check_div_wide::<I>("MIN / 1", console::Integer::MIN, console::Integer::one(), mode_a, mode_b);
+        check_div_wide::<I>("MAX / MAX", console::Integer::MAX, console::Integer::MAX, mode_a, mode_b);
+        check_div_wide::<I>("0 / 1", console::Integer::zero(), console::Integer::one(), mode_a, mode_b);
+        check_div_wide::<I>("0 / 0", console::Integer::zero(), console::Integer::zero(), mode_a, mode_b);
+
+        // Check some additional corner cases for signed integer division.
+        if I::is_signed() {
+            check_div_wide::<I>("MAX / -1", console::Integer::MAX, -console::Integer::one(), mode_a, mode_b);
+            check_div_wide::<I>("MIN / -1", console::Integer::MIN, -console::Integer::one(), mode_a, mode_b);
+        }
+    }
+
+    #[test]
+    fn test_unsigned_division_via_halves_u128() {
+        let mut rng = TestRng::default();
+
+        for (mode_a, mode_b) in
+            [(Mode::Public, Mode::Private), (Mode::Private, Mode::Public), (Mode::Private, Mode::Private)]
+        {
+            let first: console::Integer<<Circuit as Environment>::Network, u128> = Uniform::rand(&mut rng);
+            let second: console::Integer<<Circuit as Environment>::Network, u128> = Uniform::rand(&mut rng);
+            let a = Integer::<Circuit, u128>::new(mode_a, first);
+            let b = Integer::<Circuit, u128>::new(mode_b, second);
+
+            Circuit::scope("u128 division via halves", || {
+                let (quotient, remainder) = a.unsigned_division_via_halves(&b);
+                assert_eq!(first.wrapping_div(&second), quotient.eject_value());
+                assert_eq!(first.wrapping_rem(&second), remainder.eject_value());
+                assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+            });
+            Circuit::reset();
+        }
+    }
+
+    test_integer_binary!(run_test, i64, div_wide);
+    test_integer_binary!(run_test, i128, div_wide);
+
+    test_integer_binary!(run_test, u64, div_wide);
+    test_integer_binary!(run_test, u128, div_wide);
+}
@@ -0,0 +1,161 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> RemWide<Self> for Integer<E, I> {
+    type Output = Self;
+
+    #[inline]
+    fn rem_wide(&self, other: &Integer<E, I>) -> Self::Output {
+        match (self.is_constant(), other.is_constant()) {
+            // If `other` is a constant and is zero, then halt.
+            (_, true) if other.eject_value().is_zero() => E::halt("Attempted to divide by zero."),
+            // If `self` and `other` are constants, and other is not zero, then directly return the remainder.
+            (true, true) => self.rem_checked(other),
+            // Handle the remaining cases.
+            // Note that `other` is either a constant and non-zero, or not a constant.
+            _ => {
+                if I::is_signed() {
+                    // Ensure that overflow cannot occur when computing the associated division operations.
+                    // Signed integer division overflows when the dividend is Integer::MIN and the divisor is -1.
+                    let min = Integer::constant(console::Integer::MIN);
+                    let neg_one = Integer::constant(-console::Integer::one());
+                    let overflows = self.is_equal(&min) & other.is_equal(&neg_one);
+                    E::assert(!overflows);
+
+                    // Divide the absolute value of `self` and `other` in the base field.
+                    let unsigned_dividend = self.abs_wrapped().cast_as_dual();
+                    // Note that `unsigned_divisor` is zero iff `other` is zero.
+                    let unsigned_divisor = other.abs_wrapped().cast_as_dual();
+                    // Note that this call to `rem_wide` checks that `unsigned_divisor` is not zero.
+                    let unsigned_remainder = unsigned_dividend.rem_wide(&unsigned_divisor);
+
+                    let signed_remainder = Self { bits_le: unsigned_remainder.bits_le, phantom: Default::default() };
+
+                    // The remainder takes on the same sign as `self` because the division operation rounds towards zero.
+                    Self::ternary(&!self.msb(), &signed_remainder, &Self::zero().sub_wrapped(&signed_remainder))
+                } else {
+                    // Return the remainder of `self` and `other`.
+                    // Note that this call checks that `other` is not zero.
+                    self.unsigned_division_via_halves(other).1
+                }
+            }
+        }
+    }
+}
+
+impl<E: Environment, I: IntegerType> Metrics<dyn RemWide<Integer<E, I>, Output = Integer<E, I>>> for Integer<E, I> {
+    type Case = (Mode, Mode);
+
+    fn count(case: &Self::Case) -> Count {
+        <Self as Metrics<dyn RemChecked<Integer<E, I>, Output = Integer<E, I>>>>::count(case)
+    }
+}
+
+impl<E: Environment, I: IntegerType> OutputMode<dyn RemWide<Integer<E, I>, Output = Integer<E, I>>> for Integer<E, I> {
+    type Case = (Mode, Mode);
+
+    fn output_mode(case: &Self::Case) -> Mode {
+        <Self as OutputMode<dyn RemChecked<Integer<E, I>, Output = Integer<E, I>>>>::output_mode(case)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    use test_utilities::*;
+
+    use std::panic::RefUnwindSafe;
+
+    const ITERATIONS: u64 = 32;
+
+    fn check_rem_wide<I: IntegerType + RefUnwindSafe>(
+        name: &str,
+        first: console::Integer<<Circuit as Environment>::Network, I>,
+        second: console::Integer<<Circuit as Environment>::Network, I>,
+        mode_a: Mode,
+        mode_b: Mode,
+    ) {
+        let a = Integer::<Circuit, I>::new(mode_a, first);
+        let b = Integer::<Circuit, I>::new(mode_b, second);
+        if second == console::Integer::zero() {
+            match mode_b {
+                Mode::Constant => check_operation_halts(&a, &b, Integer::rem_wide),
+                _ => Circuit::scope(name, || {
+                    let _candidate = a.rem_wide(&b);
+                    assert!(!Circuit::is_satisfied_in_scope(), "(!is_satisfied_in_scope)");
+                }),
+            }
+        } else {
+            match first.checked_rem(&second) {
+                Some(expected) => Circuit::scope(name, || {
+                    let candidate = a.rem_wide(&b);
+                    assert_eq!(console::Integer::new(expected), candidate.eject_value());
+                    assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+                }),
+                None => match (mode_a, mode_b) {
+                    (Mode::Constant, Mode::Constant) => check_operation_halts(&a, &b, Integer::rem_wide),
+                    _ => Circuit::scope(name, || {
+                        let _candidate = a.rem_wide(&b);
+                        assert!(!Circuit::is_satisfied_in_scope(), "(!is_satisfied_in_scope)");
+                    }),
+                },
+            }
+        }
+        Circuit::reset();
+    }
+
+    fn run_test<I: IntegerType + RefUnwindSafe>(mode_a: Mode, mode_b: Mode) {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let first = Uniform::rand(&mut rng);
+            let second = Uniform::rand(&mut rng);
+
+            let name = format!("RemWide: {first} % {second}");
+            check_rem_wide::<I>(&name, first, second, mode_a, mode_b);
+
+            let name = format!("RemWide by One: {first} % 1");
+            check_rem_wide::<I>(&name, first, console::Integer::one(), mode_a, mode_b);
+
+            let name = format!("RemWide by Self: {first} % {first}");
+            check_rem_wide::<I>(&name, first, first, mode_a, mode_b);
+
+            let name = format!("RemWide by Zero: {first} % 0");
+            check_rem_wide::<I>(&name, first, console::Integer::zero(), mode_a, mode_b);
+        }
+
+        // Check standard division properties and corner cases.
+        check_rem_wide::<I>("MAX % 1", console::Integer::MAX, console::Integer::one(), mode_a, mode_b);
+        check_rem_wide::<I>("MIN % 1", console::Integer::MIN, console::Integer::one(), mode_a, mode_b);
+        check_rem_wide::<I>("MAX % MAX", console::Integer::MAX, console::Integer::MAX, mode_a, mode_b);
+        check_rem_wide::<I>("0 % 1", console::Integer::zero(), console::Integer::one(), mode_a, mode_b);
+        check_rem_wide::<I>("0 % 0", console::Integer::zero(), console::Integer::zero(), mode_a, mode_b);
+
+        // Check some additional corner cases for signed integer division.
+        if I::is_signed() {
+            check_rem_wide::<I>("MAX % -1", console::Integer::MAX, -console::Integer::one(), mode_a, mode_b);
+            check_rem_wide::<I>("MIN % -1", console::Integer::MIN, -console::Integer::one(), mode_a, mode_b);
+        }
+    }
+
+    test_integer_binary!(run_test, i64, rem_wide);
+    test_integer_binary!(run_test, i128, rem_wide);
+
+    test_integer_binary!(run_test, u64, rem_wide);
+    test_integer_binary!(run_test, u128, rem_wide);
+}
@@ -12,11 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#![cfg_attr(not(feature = "std"), no_std)]
 #![forbid(unsafe_code)]
 #![allow(clippy::too_many_arguments)]
 #![warn(clippy::cast_possible_truncation)]
 #![cfg_attr(test, allow(clippy::assertions_on_result_states))]
 
+#[cfg(all(test, not(feature = "std")))]
+#[macro_use]
+extern crate std;
+
 pub use snarkvm_console_types::{environment::prelude::*, Address, Field, Group, Scalar};
 
 mod address;
@@ -31,6 +36,11 @@ pub mod graph_key;
 #[cfg(feature = "graph_key")]
 pub use graph_key::*;
 
+#[cfg(feature = "mnemonic")]
+pub mod mnemonic;
+#[cfg(feature = "mnemonic")]
+pub use mnemonic::Mnemonic;
+
 #[cfg(feature = "private_key")]
 pub mod private_key;
 #[cfg(feature = "private_key")]
@@ -41,6 +51,14 @@ pub mod signature;
 #[cfg(feature = "signature")]
 pub use signature::*;
 
+#[cfg(feature = "stealth")]
+mod stealth;
+#[cfg(feature = "stealth")]
+pub use stealth::*;
+
+#[cfg(feature = "threshold")]
+pub mod threshold;
+
 #[cfg(feature = "view_key")]
 pub mod view_key;
 #[cfg(feature = "view_key")]
@@ -0,0 +1,62 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use hmac::Hmac;
+use sha2::Sha512;
+use unicode_normalization::UnicodeNormalization;
+
+/// The number of PBKDF2 rounds used to derive a BIP-39 seed.
+const PBKDF2_ROUNDS: u32 = 2048;
+
+impl Mnemonic {
+    /// Returns the 64-byte BIP-39 seed of the mnemonic and the given passphrase.
+    pub fn to_seed(&self, passphrase: &str) -> [u8; 64] {
+        // Normalize the passphrase, and prepend the BIP-39 salt prefix.
+        let salt = format!("mnemonic{}", passphrase.nfkd().collect::<String>());
+        // Derive the seed with PBKDF2-HMAC-SHA512.
+        let mut phrase = self.to_string();
+        let mut seed = [0u8; 64];
+        pbkdf2::pbkdf2::<Hmac<Sha512>>(phrase.as_bytes(), salt.as_bytes(), PBKDF2_ROUNDS, &mut seed)
+            .expect("HMAC accepts keys of any length");
+        phrase.zeroize();
+        seed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mnemonic::tests::TEST_VECTORS;
+
+    #[test]
+    fn test_seed_vectors() -> Result<()> {
+        for (_, phrase, seed) in TEST_VECTORS {
+            let mnemonic = Mnemonic::from_str(phrase)?;
+            assert_eq!(seed, hex::encode(mnemonic.to_seed("TREZOR")));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_seed_passphrase() -> Result<()> {
+        let mnemonic = Mnemonic::from_str(TEST_VECTORS[0].1)?;
+        // Ensure the passphrase changes the seed.
+        assert_ne!(mnemonic.to_seed(""), mnemonic.to_seed("TREZOR"));
+        // Ensure the passphrase is normalized, by comparing a composed and a decomposed "é".
+        assert_eq!(mnemonic.to_seed("caf\u{e9}"), mnemonic.to_seed("cafe\u{301}"));
+        Ok(())
+    }
+}
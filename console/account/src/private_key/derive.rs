@@ -0,0 +1,191 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+static ACCOUNT_CHILD_KEY_DOMAIN: &str = "AleoAccountChildKey0";
+
+/// The BIP-44 purpose of an Aleo derivation path.
+pub const ALEO_PURPOSE: u32 = 44;
+/// The SLIP-44 coin type of Aleo.
+pub const ALEO_COIN_TYPE: u32 = 683;
+
+/// A derivation path for account private keys, such as `m/44'/683'/0'/0'`.
+///
+/// Child keys are derived from the secret account seed, so every index in the path is hardened,
+/// and is written with a `'` (or `h`) suffix. The path convention for Aleo accounts is
+/// `m/44'/683'/{account}'/{address_index}'`, see `DerivationPath::aleo`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct DerivationPath {
+    /// The (unhardened) indices of the path.
+    indices: Vec<u32>,
+}
+
+impl DerivationPath {
+    /// The offset of hardened indices.
+    pub const HARDENED_OFFSET: u32 = 1 << 31;
+    /// The maximum depth of a derivation path.
+    pub const MAX_DEPTH: usize = 255;
+
+    /// Initializes a derivation path from the given indices, where each index is hardened.
+    pub fn new(indices: Vec<u32>) -> Result<Self> {
+        ensure!(indices.len() <= Self::MAX_DEPTH, "A derivation path cannot exceed a depth of {}", Self::MAX_DEPTH);
+        for index in &indices {
+            ensure!(*index < Self::HARDENED_OFFSET, "Derivation path index {index} is out of range");
+        }
+        Ok(Self { indices })
+    }
+
+    /// Returns the derivation path `m/44'/683'/{account}'/{address_index}'` of an Aleo account.
+    pub fn aleo(account: u32, address_index: u32) -> Result<Self> {
+        Self::new(vec![ALEO_PURPOSE, ALEO_COIN_TYPE, account, address_index])
+    }
+
+    /// Returns the (unhardened) indices of the path.
+    pub fn indices(&self) -> &[u32] {
+        &self.indices
+    }
+
+    /// Returns the path with the given child path appended.
+    pub fn join(&self, child: &DerivationPath) -> Result<Self> {
+        Self::new(self.indices.iter().chain(&child.indices).copied().collect())
+    }
+}
+
+impl FromStr for DerivationPath {
+    type Err = Error;
+
+    /// Parses a derivation path, such as `m/44'/683'/0'/0'`.
+    fn from_str(path: &str) -> Result<Self> {
+        let mut components = path.split('/');
+        // Ensure the path starts at the master key.
+        ensure!(components.next() == Some("m"), "A derivation path must start with 'm', found '{path}'");
+        // Parse the hardened indices.
+        let indices = components
+            .map(|component| {
+                let index = component
+                    .strip_suffix('\'')
+                    .or_else(|| component.strip_suffix('h'))
+                    .ok_or_else(|| anyhow!("Derivation path index '{component}' must be hardened"))?;
+                ensure!(
+                    !index.is_empty() && index.bytes().all(|byte| byte.is_ascii_digit()),
+                    "Invalid derivation path index '{component}'"
+                );
+                index.parse::<u32>().map_err(|_| anyhow!("Derivation path index '{component}' is out of range"))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Self::new(indices)
+    }
+}
+
+impl Display for DerivationPath {
+    /// Writes the derivation path as a string, such as `m/44'/683'/0'/0'`.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "m")?;
+        for index in &self.indices {
+            write!(f, "/{index}'")?;
+        }
+        Ok(())
+    }
+}
+
+impl<N: Network> PrivateKey<N> {
+    /// Returns the child private key at the given derivation path, where for each index in the path:
+    ///     child_seed := Hash(domain || parent_seed || (index + 2^31))
+    ///
+    /// The child private key is then derived from the child seed, as for any account seed.
+    pub fn derive_child(&self, path: &DerivationPath) -> Result<Self> {
+        // Construct the child key domain separator.
+        let domain = Field::<N>::new_domain_separator(ACCOUNT_CHILD_KEY_DOMAIN);
+        // Derive the child seed.
+        let seed = path.indices().iter().try_fold(self.seed, |seed, index| {
+            let index = Field::from_u64(u64::from(*index) + u64::from(DerivationPath::HARDENED_OFFSET));
+            N::hash_psd4(&[domain, seed, index])
+        })?;
+        // Derive the child private key.
+        Self::try_from(seed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Address;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    const ALEO_PRIVATE_KEY: &str = "APrivateKey1zkp8cC4jgHEBnbtu3xxs1Ndja2EMizcvTRDq5Nikdkukg1p";
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_derivation_path_string() -> Result<()> {
+        // Ensure valid paths round trip.
+        for path in ["m", "m/0'", "m/44'/683'/0'/0'", "m/2147483647'"] {
+            assert_eq!(path, DerivationPath::from_str(path)?.to_string());
+        }
+        // Ensure the `h` suffix is accepted.
+        assert_eq!(DerivationPath::from_str("m/44h/683h/1h/2h")?, DerivationPath::aleo(1, 2)?);
+
+        // Ensure invalid paths are rejected.
+        for path in ["", "0'", "M/0'", "m/", "m/0", "m/-1'", "m/+1'", "m/2147483648'", "m/0'/", "m//0'", "m/a'"] {
+            assert!(DerivationPath::from_str(path).is_err(), "Path '{path}' should be rejected");
+        }
+        assert!(DerivationPath::new(vec![0; DerivationPath::MAX_DEPTH + 1]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_derive_child() -> Result<()> {
+        let private_key = PrivateKey::<CurrentNetwork>::from_str(ALEO_PRIVATE_KEY)?;
+
+        // Ensure the empty path derives the key itself.
+        assert_eq!(private_key, private_key.derive_child(&DerivationPath::default())?);
+
+        // Ensure derivation is deterministic, and follows the path convention.
+        let path = DerivationPath::from_str("m/44'/683'/0'/0'")?;
+        let child = private_key.derive_child(&path)?;
+        assert_eq!(child, private_key.derive_child(&DerivationPath::aleo(0, 0)?)?);
+        assert_ne!(child, private_key);
+
+        // Ensure derivation composes along the path.
+        let account = private_key.derive_child(&DerivationPath::from_str("m/44'/683'/0'")?)?;
+        assert_eq!(child, account.derive_child(&DerivationPath::from_str("m/0'")?)?);
+        let joined = DerivationPath::from_str("m/44'/683'")?.join(&DerivationPath::from_str("m/0'/0'")?)?;
+        assert_eq!(path, joined);
+
+        // Ensure a child key is a valid account.
+        let address = Address::try_from(&child)?;
+        assert_eq!(address, Address::try_from(PrivateKey::from_str(&child.to_string())?)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_derive_child_distinct() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let account = rng.gen_range(0..DerivationPath::HARDENED_OFFSET);
+            let address_index = rng.gen_range(0..DerivationPath::HARDENED_OFFSET - 1);
+
+            // Ensure neighboring paths and different parents derive distinct keys.
+            let child = private_key.derive_child(&DerivationPath::aleo(account, address_index)?)?;
+            assert_ne!(child, private_key.derive_child(&DerivationPath::aleo(account, address_index + 1)?)?);
+            assert_ne!(child, PrivateKey::new(&mut rng)?.derive_child(&DerivationPath::aleo(account, address_index)?)?);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,219 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::Address;
+
+use chacha20poly1305::{
+    aead::{Aead, KeyInit, Payload},
+    ChaCha20Poly1305,
+    Key,
+    Nonce,
+};
+use serde_json::{json, Value};
+
+/// The version of the keystore format.
+const KEYSTORE_VERSION: u64 = 1;
+/// The scrypt cost parameter, as `log2(N)`, of new keystores.
+const KEYSTORE_LOG_N: u8 = 17;
+/// The maximum scrypt cost parameter, as `log2(N)`, of a keystore that will be decrypted.
+const KEYSTORE_MAX_LOG_N: u8 = 20;
+/// The scrypt block size parameter.
+const KEYSTORE_R: u32 = 8;
+/// The scrypt parallelization parameter.
+const KEYSTORE_P: u32 = 1;
+
+impl<N: Network> PrivateKey<N> {
+    /// Returns the private key encrypted under the given password, as a JSON keystore.
+    ///
+    /// The encryption key is derived from the password with scrypt, and the account seed is encrypted
+    /// with ChaCha20-Poly1305. The version, network, address, and scrypt parameters of the keystore
+    /// are authenticated along with the account seed.
+    pub fn to_keystore<R: Rng + CryptoRng>(&self, password: &str, rng: &mut R) -> Result<String> {
+        self.to_keystore_with_log_n(password, KEYSTORE_LOG_N, rng)
+    }
+
+    /// Returns the private key from the given JSON keystore and password.
+    ///
+    /// The password is checked by the (constant-time) authentication of the ciphertext,
+    /// so a wrong password and a tampered keystore are rejected alike.
+    pub fn from_keystore(keystore: &str, password: &str) -> Result<Self> {
+        let keystore: Value = serde_json::from_str(keystore).map_err(|_| anyhow!("Invalid keystore JSON"))?;
+
+        // Ensure the version and network of the keystore are supported.
+        let version = read_u64(&keystore, "version")?;
+        ensure!(version == KEYSTORE_VERSION, "Unsupported keystore version {version}");
+        let network = read_u64(&keystore, "network")?;
+        ensure!(network == u64::from(N::ID), "The keystore is for network {network}, expected {}", N::ID);
+        let Some(address) = keystore["address"].as_str() else { bail!("Missing keystore field 'address'") };
+
+        // Ensure the key derivation and cipher are supported.
+        let kdf = &keystore["kdf"];
+        ensure!(kdf["name"] == "scrypt", "Unsupported keystore key derivation function");
+        let log_n = u8::try_from(read_u64(kdf, "log_n")?).map_err(|_| anyhow!("Invalid scrypt parameters"))?;
+        let (r, p) = (read_u64(kdf, "r")?, read_u64(kdf, "p")?);
+        ensure!(
+            log_n <= KEYSTORE_MAX_LOG_N && r == u64::from(KEYSTORE_R) && p == u64::from(KEYSTORE_P),
+            "Unsupported scrypt parameters"
+        );
+        let cipher = &keystore["cipher"];
+        ensure!(cipher["name"] == "chacha20-poly1305", "Unsupported keystore cipher");
+
+        // Derive the encryption key.
+        let mut key = derive_key(password, &read_hex(kdf, "salt")?, log_n)?;
+        // Decrypt the account seed.
+        let nonce = read_hex(cipher, "nonce")?;
+        ensure!(nonce.len() == 12, "Invalid keystore nonce");
+        let ciphertext = read_hex(&keystore, "ciphertext")?;
+        let aad = associated_data::<N>(address, log_n);
+        let plaintext = ChaCha20Poly1305::new(Key::from_slice(&key))
+            .decrypt(Nonce::from_slice(&nonce), Payload { msg: &ciphertext, aad: &aad })
+            .map_err(|_| anyhow!("Failed to decrypt the keystore: incorrect password, or the keystore is corrupted"));
+        key.zeroize();
+        let mut plaintext = plaintext?;
+
+        // Recover the private key.
+        let seed = Field::from_bytes_le(&plaintext);
+        plaintext.zeroize();
+        let private_key = Self::try_from(seed?)?;
+        // Ensure the private key matches the address of the keystore.
+        ensure!(Address::try_from(&private_key)?.to_string() == address, "The keystore address does not match");
+        Ok(private_key)
+    }
+
+    /// Returns the private key encrypted under the given password, with the given scrypt cost parameter.
+    fn to_keystore_with_log_n<R: Rng + CryptoRng>(&self, password: &str, log_n: u8, rng: &mut R) -> Result<String> {
+        let address = Address::try_from(self)?.to_string();
+        // Sample the salt and nonce.
+        let salt: [u8; 32] = rng.gen();
+        let nonce: [u8; 12] = rng.gen();
+
+        // Derive the encryption key.
+        let mut key = derive_key(password, &salt, log_n)?;
+        // Encrypt the account seed.
+        let mut plaintext = self.seed.to_bytes_le()?;
+        let aad = associated_data::<N>(&address, log_n);
+        let ciphertext = ChaCha20Poly1305::new(Key::from_slice(&key))
+            .encrypt(Nonce::from_slice(&nonce), Payload { msg: &plaintext, aad: &aad })
+            .map_err(|_| anyhow!("Failed to encrypt the private key"));
+        key.zeroize();
+        plaintext.zeroize();
+        let ciphertext = ciphertext?;
+
+        Ok(json!({
+            "version": KEYSTORE_VERSION,
+            "network": N::ID,
+            "address": address,
+            "kdf": { "name": "scrypt", "log_n": log_n, "r": KEYSTORE_R, "p": KEYSTORE_P, "salt": hex::encode(salt) },
+            "cipher": { "name": "chacha20-poly1305", "nonce": hex::encode(nonce) },
+            "ciphertext": hex::encode(ciphertext),
+        })
+        .to_string())
+    }
+}
+
+/// Returns the 32-byte encryption key for the given password, salt, and scrypt cost parameter.
+fn derive_key(password: &str, salt: &[u8], log_n: u8) -> Result<[u8; 32]> {
+    let params =
+        scrypt::Params::new(log_n, KEYSTORE_R, KEYSTORE_P, 32).map_err(|_| anyhow!("Invalid scrypt parameters"))?;
+    let mut key = [0u8; 32];
+    scrypt::scrypt(password.as_bytes(), salt, &params, &mut key)
+        .map_err(|_| anyhow!("Failed to derive the keystore key"))?;
+    Ok(key)
+}
+
+/// Returns the associated data that authenticates the keystore header.
+fn associated_data<N: Network>(address: &str, log_n: u8) -> Vec<u8> {
+    format!("{KEYSTORE_VERSION}:{}:{address}:scrypt:{log_n}:{KEYSTORE_R}:{KEYSTORE_P}:chacha20-poly1305", N::ID)
+        .into_bytes()
+}
+
+/// Returns the unsigned integer field of the given JSON object.
+fn read_u64(object: &Value, field: &str) -> Result<u64> {
+    object[field].as_u64().ok_or_else(|| anyhow!("Missing keystore field '{field}'"))
+}
+
+/// Returns the hex-encoded field of the given JSON object.
+fn read_hex(object: &Value, field: &str) -> Result<Vec<u8>> {
+    let Some(string) = object[field].as_str() else { bail!("Missing keystore field '{field}'") };
+    hex::decode(string).map_err(|_| anyhow!("Invalid hex in keystore field '{field}'"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    /// The scrypt cost parameter of the test keystores, which is lowered to keep the tests fast.
+    const TEST_LOG_N: u8 = 10;
+
+    #[test]
+    fn test_keystore_round_trip() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..10 {
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let keystore = private_key.to_keystore_with_log_n("password", TEST_LOG_N, &mut rng)?;
+            assert_eq!(private_key, PrivateKey::from_keystore(&keystore, "password")?);
+            // Ensure the keystore does not contain the private key.
+            assert!(!keystore.contains(&private_key.to_string()));
+            assert!(!keystore.contains(&hex::encode(private_key.seed().to_bytes_le()?)));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_keystore_wrong_password() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+        let keystore = private_key.to_keystore_with_log_n("password", TEST_LOG_N, &mut rng)?;
+        assert!(PrivateKey::<CurrentNetwork>::from_keystore(&keystore, "Password").is_err());
+        assert!(PrivateKey::<CurrentNetwork>::from_keystore(&keystore, "").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_keystore_tampering() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+        let keystore: Value =
+            serde_json::from_str(&private_key.to_keystore_with_log_n("password", TEST_LOG_N, &mut rng)?)?;
+
+        // A helper to modify a field of the keystore, and attempt to decrypt it.
+        let decrypt_with = |modify: &dyn Fn(&mut Value)| {
+            let mut candidate = keystore.clone();
+            modify(&mut candidate);
+            PrivateKey::<CurrentNetwork>::from_keystore(&candidate.to_string(), "password")
+        };
+        assert_eq!(private_key, decrypt_with(&|_| ())?);
+
+        // Ensure the header fields are authenticated.
+        let other_address = Address::try_from(PrivateKey::<CurrentNetwork>::new(&mut TestRng::fixed(1))?)?;
+        assert!(decrypt_with(&|keystore| keystore["address"] = json!(other_address.to_string())).is_err());
+        assert!(decrypt_with(&|keystore| keystore["version"] = json!(2)).is_err());
+        assert!(decrypt_with(&|keystore| keystore["network"] = json!(1)).is_err());
+        assert!(decrypt_with(&|keystore| keystore["kdf"]["log_n"] = json!(TEST_LOG_N + 1)).is_err());
+        // Ensure the scrypt cost parameter is bounded.
+        assert!(decrypt_with(&|keystore| keystore["kdf"]["log_n"] = json!(KEYSTORE_MAX_LOG_N + 1)).is_err());
+        // Ensure the salt, nonce, and ciphertext are authenticated.
+        assert!(decrypt_with(&|keystore| keystore["kdf"]["salt"] = json!(hex::encode([0u8; 32]))).is_err());
+        assert!(decrypt_with(&|keystore| keystore["cipher"]["nonce"] = json!(hex::encode([0u8; 12]))).is_err());
+        assert!(decrypt_with(&|keystore| keystore["ciphertext"] = json!(hex::encode([0u8; 48]))).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,149 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::Mnemonic;
+
+impl<N: Network> PrivateKey<N> {
+    /// Returns the private key for the given BIP-39 mnemonic phrase and passphrase.
+    ///
+    /// The account seed is derived from the standard BIP-39 seed of the phrase and passphrase,
+    /// for every passphrase including the empty one, so a phrase from any BIP-39 wallet is accepted.
+    ///
+    /// Note: The account is not derived using BIP-32, so a phrase does not recover the same account
+    /// in other BIP-39 wallets, and vice versa. To back up an existing private key, see `PrivateKey::to_seed_mnemonic`.
+    pub fn from_mnemonic(phrase: &str, passphrase: &str) -> Result<Self> {
+        // Parse the mnemonic, and check its checksum.
+        let mut mnemonic = Mnemonic::from_str(phrase)?;
+        // Derive the account seed from the BIP-39 seed.
+        let mut bip39_seed = mnemonic.to_seed(passphrase);
+        let seed = Field::new(N::Field::from_bytes_le_mod_order(&bip39_seed));
+        bip39_seed.zeroize();
+        mnemonic.zeroize();
+        // Output the private key.
+        Self::try_from(seed)
+    }
+
+    /// Returns the private key for the given 24-word mnemonic of the raw account seed.
+    /// This recovers the private key exported by `PrivateKey::to_seed_mnemonic`.
+    pub fn from_seed_mnemonic(phrase: &str) -> Result<Self> {
+        // Parse the mnemonic, and check its checksum.
+        let mut mnemonic = Mnemonic::from_str(phrase)?;
+        // Ensure the phrase encodes an account seed.
+        ensure!(mnemonic.num_words() == 24, "A seed mnemonic must have 24 words");
+        let seed = Field::new(N::Field::from_bytes_le_mod_order(mnemonic.entropy()));
+        let mut bytes = seed.to_bytes_le()?;
+        let is_canonical = bytes == mnemonic.entropy();
+        bytes.zeroize();
+        mnemonic.zeroize();
+        ensure!(is_canonical, "The mnemonic does not encode a valid account seed");
+        // Output the private key.
+        Self::try_from(seed)
+    }
+
+    /// Returns the 24-word mnemonic of the raw account seed.
+    ///
+    /// Note: The words and checksum follow BIP-39, but the phrase encodes the account seed itself,
+    /// so it must be imported with `PrivateKey::from_seed_mnemonic`, not `PrivateKey::from_mnemonic`.
+    pub fn to_seed_mnemonic(&self) -> Result<Mnemonic> {
+        let mut entropy = self.seed.to_bytes_le()?;
+        let mnemonic = Mnemonic::from_entropy(&entropy);
+        entropy.zeroize();
+        mnemonic
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    const ITERATIONS: u64 = 1000;
+
+    /// The mnemonic of the account seed `0`.
+    const ZERO_MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon art";
+    /// The mnemonic of the account seed `1`.
+    const ONE_MNEMONIC: &str = "absurd abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon ability";
+
+    #[test]
+    fn test_seed_mnemonic_vectors() -> Result<()> {
+        let zero = PrivateKey::<CurrentNetwork>::try_from(Field::zero())?;
+        assert_eq!(ZERO_MNEMONIC, zero.to_seed_mnemonic()?.to_string());
+        assert_eq!(zero, PrivateKey::from_seed_mnemonic(ZERO_MNEMONIC)?);
+
+        let one = PrivateKey::<CurrentNetwork>::try_from(Field::one())?;
+        assert_eq!(ONE_MNEMONIC, one.to_seed_mnemonic()?.to_string());
+        assert_eq!(one, PrivateKey::from_seed_mnemonic(ONE_MNEMONIC)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_seed_mnemonic_round_trip() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let mnemonic = private_key.to_seed_mnemonic()?;
+            assert_eq!(24, mnemonic.num_words());
+            assert_eq!(private_key, PrivateKey::from_seed_mnemonic(&mnemonic.to_string())?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_mnemonic_passphrase() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let phrase = PrivateKey::<CurrentNetwork>::new(&mut rng)?.to_seed_mnemonic()?.to_string();
+        // Ensure every passphrase, including the empty one, derives the account seed from the BIP-39 seed.
+        for passphrase in ["", "passphrase"] {
+            let candidate = PrivateKey::<CurrentNetwork>::from_mnemonic(&phrase, passphrase)?;
+            assert_eq!(candidate, PrivateKey::from_mnemonic(&phrase, passphrase)?);
+            let bip39_seed = Mnemonic::from_str(&phrase)?.to_seed(passphrase);
+            assert_eq!(
+                candidate.seed(),
+                Field::new(<CurrentNetwork as Environment>::Field::from_bytes_le_mod_order(&bip39_seed))
+            );
+        }
+        // Ensure the passphrase selects a distinct account.
+        assert_ne!(
+            PrivateKey::<CurrentNetwork>::from_mnemonic(&phrase, "")?,
+            PrivateKey::from_mnemonic(&phrase, "passphrase")?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_mnemonic_from_other_wallets() -> Result<()> {
+        // Ensure phrases of every length from other BIP-39 wallets are accepted, with or without a passphrase,
+        // and rejected as seed mnemonics, as they do not encode an account seed.
+        for phrase in [
+            "legal winner thank year wave sausage worth useful legal winner thank yellow",
+            "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo when",
+            "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo vote",
+        ] {
+            assert!(PrivateKey::<CurrentNetwork>::from_mnemonic(phrase, "").is_ok());
+            assert!(PrivateKey::<CurrentNetwork>::from_mnemonic(phrase, "TREZOR").is_ok());
+            assert!(PrivateKey::<CurrentNetwork>::from_seed_mnemonic(phrase).is_err());
+        }
+        // Ensure an invalid checksum is rejected.
+        let phrase = "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo";
+        assert!(PrivateKey::<CurrentNetwork>::from_mnemonic(phrase, "").is_err());
+        assert!(PrivateKey::<CurrentNetwork>::from_mnemonic(phrase, "TREZOR").is_err());
+        assert!(PrivateKey::<CurrentNetwork>::from_seed_mnemonic(phrase).is_err());
+        Ok(())
+    }
+}
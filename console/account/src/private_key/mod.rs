@@ -13,13 +13,22 @@
 // limitations under the License.
 
 mod bytes;
+mod derive;
 mod serialize;
 mod string;
 mod try_from;
 
+#[cfg(feature = "keystore")]
+mod keystore;
+
+#[cfg(feature = "mnemonic")]
+mod mnemonic;
+
 #[cfg(feature = "signature")]
 mod sign;
 
+pub use derive::{DerivationPath, ALEO_COIN_TYPE, ALEO_PURPOSE};
+
 use snarkvm_console_network::prelude::*;
 use snarkvm_console_types::{Field, Scalar};
 
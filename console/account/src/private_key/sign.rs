@@ -30,6 +30,12 @@ impl<N: Network> PrivateKey<N> {
     pub fn sign_bits<R: Rng + CryptoRng>(&self, message: &[bool], rng: &mut R) -> Result<Signature<N>> {
         Signature::sign_bits(self, message, rng)
     }
+
+    /// Returns a deterministic signature for the given offchain message, under the given application domain.
+    /// The signature cannot be used as a signature of a transaction, see `Signature::sign_message`.
+    pub fn sign_message(&self, domain: &str, message: &[u8]) -> Result<Signature<N>> {
+        Signature::sign_message(self, domain, message)
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,134 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The domain separator of offchain messages.
+static MESSAGE_DOMAIN: &str = "AleoOffchainMessage0";
+/// The domain separator of the deterministic nonce of offchain messages.
+static MESSAGE_NONCE_DOMAIN: &str = "AleoOffchainMessageNonce0";
+
+/// Verifies signatures of offchain messages, as produced by `PrivateKey::sign_message`.
+pub trait VerifyMessage<N: Network> {
+    /// Returns `true` if the signature is valid for the given domain and message under this address.
+    fn verify_message(&self, domain: &str, message: &[u8], signature: &Signature<N>) -> bool;
+}
+
+impl<N: Network> VerifyMessage<N> for Address<N> {
+    /// Returns `true` if the signature is valid for the given domain and message under this address.
+    fn verify_message(&self, domain: &str, message: &[u8], signature: &Signature<N>) -> bool {
+        signature.verify_message(self, domain, message)
+    }
+}
+
+impl<N: Network> Signature<N> {
+    /// Returns a deterministic signature for the given offchain message, under the given application domain.
+    ///
+    /// The signed message is `(MESSAGE_DOMAIN, Hash(domain), Hash(message))`, where each hash is over the
+    /// byte length followed by the packed bytes. As `MESSAGE_DOMAIN` is a constant, an offchain message can
+    /// never be the message of a `Request` (which starts with the function ID), nor a message in another domain.
+    /// The nonce is derived as `HashToScalar(MESSAGE_NONCE_DOMAIN, seed, Hash(domain), Hash(message))`.
+    #[cfg(feature = "private_key")]
+    pub fn sign_message(private_key: &PrivateKey<N>, domain: &str, message: &[u8]) -> Result<Self> {
+        // Construct the signed message.
+        let fields = message_fields::<N>(domain, message)?;
+        // Derive the nonce from the account seed and the signed message.
+        let nonce = N::hash_to_scalar_psd4(&[
+            Field::new_domain_separator(MESSAGE_NONCE_DOMAIN),
+            private_key.seed(),
+            fields[1],
+            fields[2],
+        ])?;
+        // Sign the message.
+        Self::sign_with_nonce(private_key, &fields, nonce)
+    }
+
+    /// Returns `true` if the signature is valid for the given offchain message, under the given application domain.
+    pub fn verify_message(&self, address: &Address<N>, domain: &str, message: &[u8]) -> bool {
+        match message_fields::<N>(domain, message) {
+            Ok(fields) => self.verify(address, &fields),
+            Err(_) => false,
+        }
+    }
+}
+
+/// Returns the signed message `(MESSAGE_DOMAIN, Hash(domain), Hash(message))` of an offchain message.
+fn message_fields<N: Network>(domain: &str, message: &[u8]) -> Result<[Field<N>; 3]> {
+    // Ensure the application domain is not empty.
+    ensure!(!domain.is_empty(), "The domain of an offchain message cannot be empty");
+    Ok([Field::new_domain_separator(MESSAGE_DOMAIN), hash_bytes::<N>(domain.as_bytes())?, hash_bytes::<N>(message)?])
+}
+
+/// Returns the hash of the given bytes, prefixed with their length.
+fn hash_bytes<N: Network>(bytes: &[u8]) -> Result<Field<N>> {
+    // Prefix the length, so that trailing zero bytes are not lost in packing.
+    let mut preimage = vec![Field::from_u64(bytes.len() as u64)];
+    // Pack the bytes into field elements.
+    for chunk in bytes.to_bits_le().chunks(Field::<N>::size_in_data_bits()) {
+        preimage.push(Field::from_bits_le(chunk)?);
+    }
+    N::hash_psd8(&preimage)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_sign_and_verify_message() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let address = Address::try_from(&private_key)?;
+
+            let message = (0..i).map(|_| rng.gen()).collect::<Vec<u8>>();
+            let signature = private_key.sign_message("example.com", &message)?;
+            assert!(address.verify_message("example.com", &message, &signature));
+
+            // Ensure the signature is deterministic.
+            assert_eq!(signature, private_key.sign_message("example.com", &message)?);
+            // Ensure the signature is invalid for a different domain, message, or address.
+            assert!(!address.verify_message("example.org", &message, &signature));
+            assert!(!address.verify_message("example.com", &[&message[..], &[0u8]].concat(), &signature));
+            let other_address = Address::try_from(PrivateKey::<CurrentNetwork>::new(rng)?)?;
+            assert!(!other_address.verify_message("example.com", &message, &signature));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_message_domain_separation() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let address = Address::try_from(&private_key)?;
+        let signature = private_key.sign_message("example.com", b"login")?;
+
+        // Ensure the signature does not verify as a plain signature over the same bytes.
+        assert!(!signature.verify_bytes(&address, b"login"));
+        // Ensure a plain signature does not verify as an offchain message.
+        let plain_signature = private_key.sign_bytes(b"login", rng)?;
+        assert!(!address.verify_message("example.com", b"login", &plain_signature));
+        // Ensure the domain cannot be empty.
+        assert!(private_key.sign_message("", b"login").is_err());
+        assert!(!address.verify_message("", b"login", &signature));
+        Ok(())
+    }
+}
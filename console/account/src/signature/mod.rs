@@ -15,6 +15,7 @@
 mod bitwise;
 mod bytes;
 mod from_bits;
+mod message;
 mod parse;
 mod serialize;
 mod size_in_bits;
@@ -31,6 +32,8 @@ use crate::ComputeKey;
 #[cfg(feature = "private_key")]
 use crate::PrivateKey;
 
+pub use message::VerifyMessage;
+
 use crate::address::Address;
 use snarkvm_console_network::prelude::*;
 use snarkvm_console_types::{Boolean, Field, Scalar};
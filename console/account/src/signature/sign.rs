@@ -19,13 +19,18 @@ impl<N: Network> Signature<N> {
     ///     challenge := HashToScalar(nonce * G, pk_sig, pr_sig, address, message)
     ///     response := nonce - challenge * private_key.sk_sig()
     pub fn sign<R: Rng + CryptoRng>(private_key: &PrivateKey<N>, message: &[Field<N>], rng: &mut R) -> Result<Self> {
+        // Sample a random nonce from the scalar field, and sign the message.
+        Self::sign_with_nonce(private_key, message, Scalar::rand(rng))
+    }
+
+    /// Returns a signature for a given message, using the given nonce.
+    /// Note: The nonce must be secret, and must never be reused for a different message.
+    pub(super) fn sign_with_nonce(private_key: &PrivateKey<N>, message: &[Field<N>], nonce: Scalar<N>) -> Result<Self> {
         // Ensure the number of field elements does not exceed the maximum allowed size.
         if message.len() > N::MAX_DATA_SIZE_IN_FIELDS as usize {
             bail!("Cannot sign the message: the message exceeds maximum allowed size")
         }
 
-        // Sample a random nonce from the scalar field.
-        let nonce = Scalar::rand(rng);
         // Compute `g_r` as `nonce * G`.
         let g_r = N::g_scalar_multiply(&nonce);
 
@@ -20,6 +20,7 @@ impl<N: Network> Signature<N> {
     pub fn verify(&self, address: &Address<N>, message: &[Field<N>]) -> bool {
         // Ensure the number of field elements does not exceed the maximum allowed size.
         if message.len() > N::MAX_DATA_SIZE_IN_FIELDS as usize {
+            #[cfg(feature = "std")]
             eprintln!("Cannot sign the signature: the signed message exceeds maximum allowed size");
             return false;
         }
@@ -68,8 +69,9 @@ impl<N: Network> Signature<N> {
         // Pack the bits into field elements.
         match message.chunks(Field::<N>::size_in_data_bits()).map(Field::from_bits_le).collect::<Result<Vec<_>>>() {
             Ok(fields) => self.verify(address, &fields),
-            Err(error) => {
-                eprintln!("Failed to verify signature: {error}");
+            Err(_error) => {
+                #[cfg(feature = "std")]
+                eprintln!("Failed to verify signature: {_error}");
                 false
             }
         }
@@ -0,0 +1,54 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for OneTimeAddress<N> {
+    /// Reads a one-time address from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let address = FromBytes::read_le(&mut reader)?;
+        let ephemeral_key = FromBytes::read_le(&mut reader)?;
+        Ok(Self { address, ephemeral_key })
+    }
+}
+
+impl<N: Network> ToBytes for OneTimeAddress<N> {
+    /// Writes a one-time address to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.address.write_le(&mut writer)?;
+        self.ephemeral_key.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PrivateKey;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let compute_key = ComputeKey::try_from(&PrivateKey::<CurrentNetwork>::new(rng)?)?;
+        let expected = OneTimeAddress::new(&compute_key, rng)?;
+
+        // Check the byte representation.
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, OneTimeAddress::read_le(&expected_bytes[..])?);
+        Ok(())
+    }
+}
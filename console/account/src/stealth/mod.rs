@@ -0,0 +1,142 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+
+use crate::{ComputeKey, ViewKey};
+use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::{Address, Field, Group, Scalar};
+
+static ONE_TIME_ADDRESS_DOMAIN: &str = "AleoOneTimeAddress0";
+
+/// A one-time address, derived by a sender from the compute key of the recipient, which serves as its public scan key.
+///
+/// For an ephemeral key `R := r * G` and the recipient address `A := view_key * G`, the sender and the recipient
+/// share the tweak `t := HashToScalar(r * A) = HashToScalar(view_key * R)`. The one-time address is the address of
+/// the compute key `(pk_sig + t * G, pr_sig)`, so it is unlinkable to the recipient address without the view key.
+/// The sender publishes the ephemeral key alongside the payment, for the recipient to detect it.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct OneTimeAddress<N: Network> {
+    /// The one-time address.
+    address: Address<N>,
+    /// The ephemeral key `R := r * G`.
+    ephemeral_key: Group<N>,
+}
+
+impl<N: Network> OneTimeAddress<N> {
+    /// Samples a new one-time address for the given recipient compute key.
+    pub fn new<R: Rng + CryptoRng>(recipient: &ComputeKey<N>, rng: &mut R) -> Result<Self> {
+        Self::from_randomizer(recipient, Scalar::rand(rng))
+    }
+
+    /// Returns the one-time address for the given recipient compute key, under the given randomizer.
+    /// Note: The randomizer must be secret, and must never be reused.
+    pub fn from_randomizer(recipient: &ComputeKey<N>, randomizer: Scalar<N>) -> Result<Self> {
+        // Compute the ephemeral key R := r * G.
+        let ephemeral_key = N::g_scalar_multiply(&randomizer);
+        // Compute the tweak from the shared secret r * A.
+        let tweak = to_tweak::<N>(*recipient.to_address() * randomizer)?;
+        // Derive the one-time address.
+        let address = to_one_time_compute_key(recipient, tweak)?.to_address();
+        Ok(Self { address, ephemeral_key })
+    }
+
+    /// Returns the one-time address.
+    pub const fn address(&self) -> &Address<N> {
+        &self.address
+    }
+
+    /// Returns the ephemeral key.
+    pub const fn ephemeral_key(&self) -> &Group<N> {
+        &self.ephemeral_key
+    }
+
+    /// Returns the view key of the one-time address, if the one-time address belongs to the given account.
+    /// The compute key must belong to the same account as the view key.
+    ///
+    /// The one-time view key decrypts the records owned by the one-time address,
+    /// without revealing the view key of the account.
+    pub fn to_view_key(&self, view_key: &ViewKey<N>, compute_key: &ComputeKey<N>) -> Result<Option<ViewKey<N>>> {
+        // Ensure the compute key belongs to the view key.
+        ensure!(view_key.to_address() == compute_key.to_address(), "The compute key does not belong to the view key");
+
+        // Compute the tweak from the shared secret view_key * R.
+        let tweak = to_tweak::<N>(self.ephemeral_key * **view_key)?;
+        // Derive the one-time compute key.
+        let one_time_compute_key = to_one_time_compute_key(compute_key, tweak)?;
+        // Ensure the one-time address belongs to the account.
+        if one_time_compute_key.to_address() != self.address {
+            return Ok(None);
+        }
+
+        // Compute the one-time view key, as `(sk_sig + t) + r_sig + sk_prf'`.
+        // Note: As `view_key := sk_sig + r_sig + sk_prf`, this does not require the private key.
+        let one_time_view_key = **view_key - compute_key.sk_prf() + tweak + one_time_compute_key.sk_prf();
+        Ok(Some(ViewKey::from_scalar(one_time_view_key)))
+    }
+}
+
+/// Returns the tweak `t := HashToScalar(shared_secret)`.
+fn to_tweak<N: Network>(shared_secret: Group<N>) -> Result<Scalar<N>> {
+    let domain = Field::<N>::new_domain_separator(ONE_TIME_ADDRESS_DOMAIN);
+    N::hash_to_scalar_psd2(&[domain, shared_secret.to_x_coordinate()])
+}
+
+/// Returns the one-time compute key `(pk_sig + t * G, pr_sig)` for the given tweak.
+fn to_one_time_compute_key<N: Network>(compute_key: &ComputeKey<N>, tweak: Scalar<N>) -> Result<ComputeKey<N>> {
+    ComputeKey::try_from((compute_key.pk_sig() + N::g_scalar_multiply(&tweak), compute_key.pr_sig()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PrivateKey;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_one_time_address() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample the recipient and another account.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let view_key = ViewKey::try_from(&private_key)?;
+            let compute_key = ComputeKey::try_from(&private_key)?;
+            let address = Address::try_from(&private_key)?;
+            let other_private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let other_view_key = ViewKey::try_from(&other_private_key)?;
+            let other_compute_key = ComputeKey::try_from(&other_private_key)?;
+
+            // Derive two one-time addresses for the recipient.
+            let first = OneTimeAddress::new(&compute_key, rng)?;
+            let second = OneTimeAddress::new(&compute_key, rng)?;
+            // Ensure the one-time addresses are fresh, and unlinked to the address of the recipient.
+            assert_ne!(first.address(), second.address());
+            assert_ne!(*first.address(), address);
+
+            // Ensure the recipient detects the one-time address, and derives its view key.
+            let one_time_view_key = first.to_view_key(&view_key, &compute_key)?.unwrap();
+            assert_eq!(one_time_view_key.to_address(), *first.address());
+            // Ensure another account does not detect the one-time address.
+            assert!(first.to_view_key(&other_view_key, &other_compute_key)?.is_none());
+            // Ensure the compute key must belong to the view key.
+            assert!(first.to_view_key(&view_key, &other_compute_key).is_err());
+        }
+        Ok(())
+    }
+}
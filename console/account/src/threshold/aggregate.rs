@@ -0,0 +1,67 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> KeyPackage<N> {
+    /// Returns the account signature for the given message, by aggregating the signature shares of all signers, where:
+    ///     challenge := HashToScalar(R, pk_sig, pr_sig, address, message)
+    ///     response := sum(z_i)
+    ///
+    /// Each signature share is checked against the verifying share of its signer, to identify misbehaving signers.
+    pub fn aggregate(
+        &self,
+        commitments: &[SigningCommitment<N>],
+        message: &[Field<N>],
+        shares: &[SignatureShare<N>],
+    ) -> Result<Signature<N>> {
+        // Prepare the signing session.
+        let session = SigningSession::new(self, commitments, message)?;
+        // Ensure there is one share from each signer.
+        ensure!(
+            shares.len() == commitments.len(),
+            "Expected {} signature shares, found {}",
+            commitments.len(),
+            shares.len()
+        );
+
+        let mut response = Scalar::zero();
+        for share in shares {
+            // Retrieve the position of the signer in the session.
+            let position = session.position(share.index())?;
+            ensure!(
+                shares.iter().filter(|other| other.index() == share.index()).count() == 1,
+                "Duplicate signature share from participant {}",
+                share.index()
+            );
+            let commitment = &commitments[position];
+            // Ensure `G^z_i == D_i + E_i^rho_i - Y_i^(challenge * lambda_i)`.
+            let expected = commitment.hiding() + (commitment.binding() * session.binding_factors[position])
+                - (self.verifying_share(share.index())?
+                    * (session.challenge * session.lagrange_coefficients[position]));
+            ensure!(
+                N::g_scalar_multiply(&share.response()) == expected,
+                "Invalid signature share from participant {}",
+                share.index()
+            );
+            response += share.response();
+        }
+
+        // Construct the signature.
+        let signature = Signature::from((session.challenge, response, self.compute_key));
+        // Ensure the signature is valid for the account address.
+        ensure!(signature.verify(&self.address(), message), "The aggregated signature is invalid");
+        Ok(signature)
+    }
+}
@@ -0,0 +1,159 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for KeyPackage<N> {
+    /// Reads a key package from a buffer.
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let index = u16::read_le(&mut reader)?;
+        let threshold = u16::read_le(&mut reader)?;
+        let secret_share = Scalar::read_le(&mut reader)?;
+        let num_participants = u16::read_le(&mut reader)?;
+        let verifying_shares =
+            (0..num_participants).map(|_| Group::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        let compute_key = ComputeKey::read_le(&mut reader)?;
+        // Ensure the key package is well-formed.
+        if !(1..=num_participants).contains(&index) || !(1..=num_participants).contains(&threshold) {
+            return Err(error("Invalid key package parameters"));
+        }
+        Ok(Self { index, threshold, secret_share, verifying_shares, compute_key })
+    }
+}
+
+impl<N: Network> ToBytes for KeyPackage<N> {
+    /// Writes a key package to a buffer.
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.index.write_le(&mut writer)?;
+        self.threshold.write_le(&mut writer)?;
+        self.secret_share.write_le(&mut writer)?;
+        u16::try_from(self.verifying_shares.len())
+            .map_err(|_| error("Too many participants"))?
+            .write_le(&mut writer)?;
+        self.verifying_shares.write_le(&mut writer)?;
+        self.compute_key.write_le(&mut writer)
+    }
+}
+
+impl<N: Network> FromBytes for DkgCommitment<N> {
+    /// Reads a distributed key generation commitment from a buffer.
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let index = u16::read_le(&mut reader)?;
+        let num_commitments = u16::read_le(&mut reader)?;
+        if num_commitments == 0 {
+            return Err(error("A commitment must contain at least one coefficient"));
+        }
+        let commitments = (0..num_commitments).map(|_| Group::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        let pr_sig = Group::read_le(&mut reader)?;
+        let challenge = Scalar::read_le(&mut reader)?;
+        let response = Scalar::read_le(&mut reader)?;
+        Ok(Self { index, commitments, pr_sig, proof: (challenge, response) })
+    }
+}
+
+impl<N: Network> ToBytes for DkgCommitment<N> {
+    /// Writes a distributed key generation commitment to a buffer.
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.index.write_le(&mut writer)?;
+        u16::try_from(self.commitments.len()).map_err(|_| error("Too many coefficients"))?.write_le(&mut writer)?;
+        self.commitments.write_le(&mut writer)?;
+        self.pr_sig.write_le(&mut writer)?;
+        self.proof.0.write_le(&mut writer)?;
+        self.proof.1.write_le(&mut writer)
+    }
+}
+
+impl<N: Network> FromBytes for SigningCommitment<N> {
+    /// Reads a signing commitment from a buffer.
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let index = u16::read_le(&mut reader)?;
+        let hiding = Group::read_le(&mut reader)?;
+        let binding = Group::read_le(&mut reader)?;
+        Ok(Self::new(index, hiding, binding))
+    }
+}
+
+impl<N: Network> ToBytes for SigningCommitment<N> {
+    /// Writes a signing commitment to a buffer.
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.index().write_le(&mut writer)?;
+        self.hiding().write_le(&mut writer)?;
+        self.binding().write_le(&mut writer)
+    }
+}
+
+impl<N: Network> FromBytes for SignatureShare<N> {
+    /// Reads a signature share from a buffer.
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let index = u16::read_le(&mut reader)?;
+        let response = Scalar::read_le(&mut reader)?;
+        Ok(Self::new(index, response))
+    }
+}
+
+impl<N: Network> ToBytes for SignatureShare<N> {
+    /// Writes a signature share to a buffer.
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.index().write_le(&mut writer)?;
+        self.response().write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Check the byte representation of a key package.
+        let key_packages = test_helpers::sample_key_packages(2, 3, &mut rng);
+        for key_package in &key_packages {
+            let bytes = key_package.to_bytes_le()?;
+            assert!(*key_package == KeyPackage::read_le(&bytes[..])?);
+            assert!(KeyPackage::<CurrentNetwork>::read_le(&bytes[1..]).is_err());
+        }
+
+        // Check the byte representation of a distributed key generation commitment.
+        let (_, commitment) = DkgSecret::<CurrentNetwork>::new(1, 2, 3, &mut rng)?;
+        let bytes = commitment.to_bytes_le()?;
+        assert_eq!(commitment, DkgCommitment::read_le(&bytes[..])?);
+        assert!(DkgCommitment::<CurrentNetwork>::read_le(&bytes[1..]).is_err());
+
+        // Check the byte representation of a signing commitment and signature share.
+        let (nonces, commitment) = key_packages[0].commit(&mut rng);
+        let bytes = commitment.to_bytes_le()?;
+        assert_eq!(commitment, SigningCommitment::read_le(&bytes[..])?);
+        assert!(SigningCommitment::<CurrentNetwork>::read_le(&bytes[1..]).is_err());
+
+        let (_, other_commitment) = key_packages[1].commit(&mut rng);
+        let share = key_packages[0].sign_share(nonces, &[commitment, other_commitment], &[Field::one()])?;
+        let bytes = share.to_bytes_le()?;
+        assert_eq!(share, SignatureShare::read_le(&bytes[..])?);
+        assert!(SignatureShare::<CurrentNetwork>::read_le(&bytes[1..]).is_err());
+        Ok(())
+    }
+}
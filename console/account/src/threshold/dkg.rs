@@ -0,0 +1,206 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The secret state of a participant in distributed key generation.
+///
+/// Distributed key generation runs in two rounds:
+///  1. Each participant calls `DkgSecret::new`, and broadcasts the returned `DkgCommitment`.
+///  2. Each participant privately sends `share_for(recipient)` to every other participant.
+///
+/// Finally, each participant calls `finalize` with all commitments and the shares it received.
+pub struct DkgSecret<N: Network> {
+    /// The index of the participant, in `1..=n`.
+    index: u16,
+    /// The number of participants required to sign.
+    threshold: u16,
+    /// The number of participants.
+    num_participants: u16,
+    /// The coefficients of the secret polynomial, of degree `threshold - 1`.
+    coefficients: Vec<Scalar<N>>,
+}
+
+/// The commitment broadcast by a participant in the first round of distributed key generation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DkgCommitment<N: Network> {
+    /// The index of the participant.
+    index: u16,
+    /// The commitments `G^a_k` to the coefficients of the secret polynomial.
+    commitments: Vec<Group<N>>,
+    /// The contribution to the signature public randomizer `pr_sig`.
+    pr_sig: Group<N>,
+    /// The proof of knowledge of the constant coefficient, as `(challenge, response)`.
+    proof: (Scalar<N>, Scalar<N>),
+}
+
+impl<N: Network> DkgSecret<N> {
+    /// Starts distributed key generation for the participant with the given index, in a `threshold`-of-`num_participants` account.
+    pub fn new<R: Rng + CryptoRng>(
+        index: u16,
+        threshold: u16,
+        num_participants: u16,
+        rng: &mut R,
+    ) -> Result<(Self, DkgCommitment<N>)> {
+        // Ensure the parameters are well-formed.
+        ensure!((1..=num_participants).contains(&threshold), "The threshold must be in 1..={num_participants}");
+        ensure!((1..=num_participants).contains(&index), "The participant index must be in 1..={num_participants}");
+
+        // Sample the coefficients of the secret polynomial.
+        let coefficients = (0..threshold).map(|_| Scalar::rand(rng)).collect::<Vec<_>>();
+        // Commit to the coefficients.
+        let commitments = coefficients.iter().map(N::g_scalar_multiply).collect::<Vec<_>>();
+        // Sample the contribution to `pr_sig`. Note: Its discrete logarithm is discarded.
+        let pr_sig = N::g_scalar_multiply(&Scalar::rand(rng));
+
+        // Prove knowledge of the constant coefficient, to prevent rogue-key attacks on `pk_sig`.
+        let nonce = Scalar::rand(rng);
+        let challenge = DkgCommitment::challenge(index, commitments[0], pr_sig, N::g_scalar_multiply(&nonce))?;
+        let response = nonce + challenge * coefficients[0];
+
+        let commitment = DkgCommitment { index, commitments, pr_sig, proof: (challenge, response) };
+        Ok((Self { index, threshold, num_participants, coefficients }, commitment))
+    }
+
+    /// Returns the index of the participant.
+    pub const fn index(&self) -> u16 {
+        self.index
+    }
+
+    /// Returns the secret share for the participant with the given index.
+    /// Note: The share must be sent to the recipient over a private, authenticated channel.
+    pub fn share_for(&self, recipient: u16) -> Result<Scalar<N>> {
+        ensure!((1..=self.num_participants).contains(&recipient), "Unknown participant {recipient}");
+        // Evaluate the secret polynomial at the recipient index.
+        let x = to_scalar::<N>(recipient);
+        Ok(self.coefficients.iter().rev().fold(Scalar::zero(), |acc, coefficient| acc * x + *coefficient))
+    }
+
+    /// Returns the key package of the participant, given the commitments of all participants,
+    /// and the secret shares `(sender index, share)` received from all other participants.
+    pub fn finalize(&self, commitments: &[DkgCommitment<N>], shares: &[(u16, Scalar<N>)]) -> Result<KeyPackage<N>> {
+        // Ensure there is one commitment from each participant, ordered by index.
+        ensure!(
+            commitments.len() == usize::from(self.num_participants),
+            "Expected {} commitments, found {}",
+            self.num_participants,
+            commitments.len()
+        );
+        for (expected_index, commitment) in (1..=self.num_participants).zip_eq(commitments) {
+            ensure!(commitment.index == expected_index, "Expected the commitment of participant {expected_index}");
+            ensure!(
+                commitment.commitments.len() == usize::from(self.threshold),
+                "The commitment of participant {expected_index} is for a different threshold"
+            );
+            ensure!(commitment.verify()?, "Invalid proof of knowledge from participant {expected_index}");
+        }
+        // Ensure the commitment of this participant is its own.
+        let own_commitment = &commitments[usize::from(self.index) - 1];
+        ensure!(
+            own_commitment.commitments[0] == N::g_scalar_multiply(&self.coefficients[0]),
+            "The commitment of participant {} does not match the secret polynomial",
+            self.index
+        );
+
+        // Ensure there is one share from each other participant.
+        ensure!(
+            shares.len() == usize::from(self.num_participants) - 1,
+            "Expected {} shares, found {}",
+            self.num_participants - 1,
+            shares.len()
+        );
+        // Compute the secret share, starting with the share of this participant.
+        let mut secret_share = self.share_for(self.index)?;
+        let mut senders = Vec::with_capacity(shares.len());
+        for (sender, share) in shares {
+            ensure!(*sender != self.index && !senders.contains(sender), "Duplicate share from participant {sender}");
+            let commitment = commitments
+                .get(usize::from(*sender).wrapping_sub(1))
+                .ok_or_else(|| anyhow!("Unknown participant {sender}"))?;
+            // Ensure the share is consistent with the commitment of the sender.
+            ensure!(
+                N::g_scalar_multiply(share) == commitment.evaluate(self.index),
+                "Invalid share from participant {sender}"
+            );
+            senders.push(*sender);
+            secret_share += *share;
+        }
+
+        // Compute the verifying shares of all participants.
+        let verifying_shares = (1..=self.num_participants)
+            .map(|index| commitments.iter().fold(Group::zero(), |acc, commitment| acc + commitment.evaluate(index)))
+            .collect::<Vec<_>>();
+        // Ensure the secret share matches the verifying share.
+        ensure!(
+            N::g_scalar_multiply(&secret_share) == verifying_shares[usize::from(self.index) - 1],
+            "The secret share does not match the verifying share"
+        );
+
+        // Compute `pk_sig` as the sum of the constant coefficient commitments.
+        let pk_sig = commitments.iter().fold(Group::zero(), |acc, commitment| acc + commitment.commitments[0]);
+        // Compute `pr_sig` as the sum of the contributions.
+        let pr_sig = commitments.iter().fold(Group::zero(), |acc, commitment| acc + commitment.pr_sig);
+        ensure!(!pk_sig.is_zero() && !pr_sig.is_zero(), "The threshold account keys are degenerate");
+
+        Ok(KeyPackage {
+            index: self.index,
+            threshold: self.threshold,
+            secret_share,
+            verifying_shares,
+            compute_key: ComputeKey::try_from((pk_sig, pr_sig))?,
+        })
+    }
+}
+
+impl<N: Network> DkgCommitment<N> {
+    /// Returns the index of the participant.
+    pub const fn index(&self) -> u16 {
+        self.index
+    }
+
+    /// Returns the commitments to the coefficients of the secret polynomial.
+    pub fn commitments(&self) -> &[Group<N>] {
+        &self.commitments
+    }
+
+    /// Returns the contribution to the signature public randomizer `pr_sig`.
+    pub const fn pr_sig(&self) -> Group<N> {
+        self.pr_sig
+    }
+
+    /// Returns `true` if the proof of knowledge of the constant coefficient is valid.
+    fn verify(&self) -> Result<bool> {
+        let (challenge, response) = self.proof;
+        // Compute `g_r` as `(response * G) - (challenge * G^a_0)`.
+        let g_r = N::g_scalar_multiply(&response) - (self.commitments[0] * challenge);
+        Ok(challenge == Self::challenge(self.index, self.commitments[0], self.pr_sig, g_r)?)
+    }
+
+    /// Returns the commitment to the secret polynomial evaluated at the given index.
+    fn evaluate(&self, index: u16) -> Group<N> {
+        let x = to_scalar::<N>(index);
+        self.commitments.iter().rev().fold(Group::zero(), |acc, commitment| acc * x + *commitment)
+    }
+
+    /// Returns the challenge for the proof of knowledge, as `HashToScalar(index, G^a_0, pr_sig, g_r)`.
+    fn challenge(index: u16, constant: Group<N>, pr_sig: Group<N>, g_r: Group<N>) -> Result<Scalar<N>> {
+        N::hash_to_scalar_psd4(&[
+            Field::new_domain_separator("AleoThresholdDKG0"),
+            Field::from_u16(index),
+            constant.to_x_coordinate(),
+            pr_sig.to_x_coordinate(),
+            g_r.to_x_coordinate(),
+        ])
+    }
+}
@@ -0,0 +1,240 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod aggregate;
+mod bytes;
+mod dkg;
+mod sign;
+
+pub use dkg::*;
+pub use sign::*;
+
+use crate::{ComputeKey, Signature};
+use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::{Address, Field, Group, Scalar};
+
+/// The key package of a participant in a `t`-of-`n` threshold account.
+///
+/// The signature secret key `sk_sig` of the account is shared among `n` participants, such that any `t`
+/// of them can jointly sign for the account address, using a FROST-style two-round protocol.
+/// The resulting signatures are regular account signatures, and verify with `Signature::verify`.
+///
+/// The signature randomizer `r_sig` is not known to any participant, as such, a threshold account
+/// has no private key or view key.
+#[derive(Clone, PartialEq, Eq)]
+pub struct KeyPackage<N: Network> {
+    /// The index of the participant, in `1..=n`.
+    index: u16,
+    /// The number of participants required to sign.
+    threshold: u16,
+    /// The participant's share of `sk_sig`.
+    secret_share: Scalar<N>,
+    /// The verifying shares `G^share` of all participants, ordered by index.
+    verifying_shares: Vec<Group<N>>,
+    /// The compute key of the threshold account.
+    compute_key: ComputeKey<N>,
+}
+
+impl<N: Network> KeyPackage<N> {
+    /// Returns the index of the participant.
+    pub const fn index(&self) -> u16 {
+        self.index
+    }
+
+    /// Returns the number of participants required to sign.
+    pub const fn threshold(&self) -> u16 {
+        self.threshold
+    }
+
+    /// Returns the number of participants.
+    pub fn num_participants(&self) -> usize {
+        self.verifying_shares.len()
+    }
+
+    /// Returns the verifying share of the participant with the given index.
+    pub fn verifying_share(&self, index: u16) -> Result<Group<N>> {
+        match index {
+            0 => bail!("Participant indices start at 1"),
+            _ => self
+                .verifying_shares
+                .get(usize::from(index) - 1)
+                .copied()
+                .ok_or_else(|| anyhow!("Unknown participant {index}")),
+        }
+    }
+
+    /// Returns the compute key of the threshold account.
+    pub const fn compute_key(&self) -> ComputeKey<N> {
+        self.compute_key
+    }
+
+    /// Returns the address of the threshold account.
+    pub fn address(&self) -> Address<N> {
+        self.compute_key.to_address()
+    }
+}
+
+/// Returns the given participant index as a scalar.
+fn to_scalar<N: Network>(index: u16) -> Scalar<N> {
+    Scalar::new(N::Scalar::from(u128::from(index)))
+}
+
+/// Returns the Lagrange coefficient at zero of the participant with the given index, for the given set of participants.
+fn lagrange_coefficient<N: Network>(index: u16, indices: &[u16]) -> Result<Scalar<N>> {
+    let mut numerator = Scalar::<N>::one();
+    let mut denominator = Scalar::<N>::one();
+    for other in indices.iter().filter(|other| **other != index) {
+        numerator = numerator * to_scalar::<N>(*other);
+        denominator = denominator * (to_scalar::<N>(*other) - to_scalar::<N>(index));
+    }
+    Ok(numerator * denominator.inverse()?)
+}
+
+#[cfg(test)]
+mod test_helpers {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    /// Runs distributed key generation for a `threshold`-of-`num_participants` account.
+    pub(super) fn sample_key_packages(
+        threshold: u16,
+        num_participants: u16,
+        rng: &mut TestRng,
+    ) -> Vec<KeyPackage<CurrentNetwork>> {
+        // Round 1: each participant broadcasts a commitment to their secret polynomial.
+        let (secrets, commitments): (Vec<_>, Vec<_>) = (1..=num_participants)
+            .map(|index| DkgSecret::new(index, threshold, num_participants, rng).unwrap())
+            .unzip();
+        // Round 2: each participant privately sends a secret share to every other participant.
+        secrets
+            .iter()
+            .map(|secret| {
+                let shares = secrets
+                    .iter()
+                    .filter(|sender| sender.index() != secret.index())
+                    .map(|sender| (sender.index(), sender.share_for(secret.index()).unwrap()))
+                    .collect::<Vec<_>>();
+                secret.finalize(&commitments, &shares).unwrap()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    const ITERATIONS: u64 = 10;
+
+    /// Signs the message with the given signers, returning the aggregated signature.
+    fn threshold_sign(
+        signers: &[&KeyPackage<CurrentNetwork>],
+        message: &[Field<CurrentNetwork>],
+        rng: &mut TestRng,
+    ) -> Result<Signature<CurrentNetwork>> {
+        // Round 1: each signer broadcasts a commitment to their signing nonces.
+        let (nonces, commitments): (Vec<_>, Vec<_>) = signers.iter().map(|signer| signer.commit(rng)).unzip();
+        // Round 2: each signer produces a signature share.
+        let shares = signers
+            .iter()
+            .zip_eq(nonces)
+            .map(|(signer, nonces)| signer.sign_share(nonces, &commitments, message))
+            .collect::<Result<Vec<_>>>()?;
+        // Aggregate the signature shares.
+        signers[0].aggregate(&commitments, message, &shares)
+    }
+
+    #[test]
+    fn test_threshold_sign_and_verify() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for (threshold, num_participants) in [(1, 1), (1, 3), (2, 3), (3, 5), (5, 5)] {
+            let key_packages = test_helpers::sample_key_packages(threshold, num_participants, &mut rng);
+
+            // Ensure all participants agree on the account.
+            let address = key_packages[0].address();
+            assert!(key_packages.iter().all(|package| package.address() == address));
+
+            for i in 0..ITERATIONS {
+                let message: Vec<_> = (0..i).map(|_| Uniform::rand(&mut rng)).collect();
+
+                // Select a random subset of `threshold` signers.
+                let mut signers = key_packages.iter().collect::<Vec<_>>();
+                while signers.len() > threshold as usize {
+                    signers.remove(rng.gen_range(0..signers.len()));
+                }
+
+                // Ensure the signature is a valid account signature.
+                let signature = threshold_sign(&signers, &message, &mut rng)?;
+                assert_eq!(signature.to_address(), address);
+                assert!(signature.verify(&address, &message));
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_threshold_sign_below_threshold() {
+        let mut rng = TestRng::default();
+
+        let key_packages = test_helpers::sample_key_packages(3, 5, &mut rng);
+        let message: Vec<_> = (0..4).map(|_| Uniform::rand(&mut rng)).collect();
+
+        // Ensure fewer than `threshold` signers cannot sign.
+        let signers = key_packages.iter().take(2).collect::<Vec<_>>();
+        assert!(threshold_sign(&signers, &message, &mut rng).is_err());
+    }
+
+    #[test]
+    fn test_threshold_sign_invalid_share() {
+        let mut rng = TestRng::default();
+
+        let key_packages = test_helpers::sample_key_packages(2, 3, &mut rng);
+        let message: Vec<_> = (0..4).map(|_| Uniform::rand(&mut rng)).collect();
+
+        let signers = [&key_packages[0], &key_packages[2]];
+        let (nonces, commitments): (Vec<_>, Vec<_>) = signers.iter().map(|signer| signer.commit(&mut rng)).unzip();
+        let mut shares = signers
+            .iter()
+            .zip_eq(nonces)
+            .map(|(signer, nonces)| signer.sign_share(nonces, &commitments, &message))
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+
+        // Tamper with the signature share of the second signer.
+        shares[1] = SignatureShare::new(shares[1].index(), shares[1].response() + Scalar::one());
+        let error = key_packages[1].aggregate(&commitments, &message, &shares).unwrap_err();
+        assert!(error.to_string().contains("participant 3"));
+    }
+
+    #[test]
+    fn test_lagrange_coefficient() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Ensure interpolation at zero recovers the constant term of a random polynomial.
+        let coefficients: Vec<Scalar<CurrentNetwork>> = (0..3).map(|_| Uniform::rand(&mut rng)).collect();
+        let indices = [2u16, 4, 5];
+        let secret = indices.iter().try_fold(Scalar::zero(), |acc, index| {
+            let evaluation = coefficients.iter().rev().fold(Scalar::zero(), |acc, c| acc * to_scalar(*index) + *c);
+            Ok::<_, Error>(acc + evaluation * lagrange_coefficient::<CurrentNetwork>(*index, &indices)?)
+        })?;
+        assert_eq!(secret, coefficients[0]);
+        Ok(())
+    }
+}
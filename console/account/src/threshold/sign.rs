@@ -0,0 +1,217 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The secret nonces of a signer for a single signing session.
+/// Note: The nonces are consumed when signing, and must never be reused.
+pub struct SigningNonces<N: Network> {
+    /// The hiding nonce `d`.
+    hiding: Scalar<N>,
+    /// The binding nonce `e`.
+    binding: Scalar<N>,
+}
+
+/// The commitment broadcast by a signer in the first round of signing.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SigningCommitment<N: Network> {
+    /// The index of the signer.
+    index: u16,
+    /// The hiding nonce commitment `D := G^d`.
+    hiding: Group<N>,
+    /// The binding nonce commitment `E := G^e`.
+    binding: Group<N>,
+}
+
+/// The signature share produced by a signer in the second round of signing.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SignatureShare<N: Network> {
+    /// The index of the signer.
+    index: u16,
+    /// The response share `z_i`.
+    response: Scalar<N>,
+}
+
+impl<N: Network> SigningCommitment<N> {
+    /// Initializes a new signing commitment.
+    pub const fn new(index: u16, hiding: Group<N>, binding: Group<N>) -> Self {
+        Self { index, hiding, binding }
+    }
+
+    /// Returns the index of the signer.
+    pub const fn index(&self) -> u16 {
+        self.index
+    }
+
+    /// Returns the hiding nonce commitment.
+    pub const fn hiding(&self) -> Group<N> {
+        self.hiding
+    }
+
+    /// Returns the binding nonce commitment.
+    pub const fn binding(&self) -> Group<N> {
+        self.binding
+    }
+}
+
+impl<N: Network> SignatureShare<N> {
+    /// Initializes a new signature share.
+    pub const fn new(index: u16, response: Scalar<N>) -> Self {
+        Self { index, response }
+    }
+
+    /// Returns the index of the signer.
+    pub const fn index(&self) -> u16 {
+        self.index
+    }
+
+    /// Returns the response share.
+    pub const fn response(&self) -> Scalar<N> {
+        self.response
+    }
+}
+
+impl<N: Network> KeyPackage<N> {
+    /// Returns fresh signing nonces, and the commitment to broadcast to the other signers.
+    pub fn commit<R: Rng + CryptoRng>(&self, rng: &mut R) -> (SigningNonces<N>, SigningCommitment<N>) {
+        let nonces = SigningNonces { hiding: Scalar::rand(rng), binding: Scalar::rand(rng) };
+        let commitment = SigningCommitment {
+            index: self.index,
+            hiding: N::g_scalar_multiply(&nonces.hiding),
+            binding: N::g_scalar_multiply(&nonces.binding),
+        };
+        (nonces, commitment)
+    }
+
+    /// Returns the signature share of this participant for the given message, where:
+    ///     z_i := d_i + (e_i * rho_i) - (challenge * lambda_i * share_i)
+    ///
+    /// The commitments are those of all signers in this session, ordered by index.
+    pub fn sign_share(
+        &self,
+        nonces: SigningNonces<N>,
+        commitments: &[SigningCommitment<N>],
+        message: &[Field<N>],
+    ) -> Result<SignatureShare<N>> {
+        // Prepare the signing session.
+        let session = SigningSession::new(self, commitments, message)?;
+        // Retrieve the position of this participant in the session.
+        let position = session.position(self.index)?;
+        // Ensure the commitment of this participant matches the nonces.
+        let commitment = &commitments[position];
+        ensure!(
+            commitment.hiding == N::g_scalar_multiply(&nonces.hiding)
+                && commitment.binding == N::g_scalar_multiply(&nonces.binding),
+            "The signing commitment of participant {} does not match the nonces",
+            self.index
+        );
+
+        // Compute the response share.
+        let response = nonces.hiding + (nonces.binding * session.binding_factors[position])
+            - (session.challenge * session.lagrange_coefficients[position] * self.secret_share);
+        Ok(SignatureShare { index: self.index, response })
+    }
+}
+
+/// The public state of a signing session, which is derived identically by all signers.
+pub(super) struct SigningSession<N: Network> {
+    /// The indices of the signers, in increasing order.
+    pub(super) indices: Vec<u16>,
+    /// The binding factors `rho_i` of the signers.
+    pub(super) binding_factors: Vec<Scalar<N>>,
+    /// The Lagrange coefficients `lambda_i` of the signers.
+    pub(super) lagrange_coefficients: Vec<Scalar<N>>,
+    /// The signature challenge.
+    pub(super) challenge: Scalar<N>,
+}
+
+impl<N: Network> SigningSession<N> {
+    /// Initializes the signing session for the given commitments and message.
+    pub(super) fn new(
+        key_package: &KeyPackage<N>,
+        commitments: &[SigningCommitment<N>],
+        message: &[Field<N>],
+    ) -> Result<Self> {
+        // Ensure the number of field elements does not exceed the maximum allowed size.
+        if message.len() > N::MAX_DATA_SIZE_IN_FIELDS as usize {
+            bail!("Cannot sign the message: the message exceeds maximum allowed size")
+        }
+        // Ensure there are enough signers.
+        ensure!(
+            commitments.len() >= usize::from(key_package.threshold),
+            "Expected at least {} signers, found {}",
+            key_package.threshold,
+            commitments.len()
+        );
+        // Ensure the signers are known, and ordered by index without duplicates.
+        let indices = commitments.iter().map(|commitment| commitment.index).collect::<Vec<_>>();
+        ensure!(indices.windows(2).all(|pair| pair[0] < pair[1]), "The signers must be in increasing order of index");
+        for index in &indices {
+            key_package.verifying_share(*index)?;
+        }
+
+        // Encode the commitments as `[index, D, E]` for each signer.
+        let mut encoded = Vec::with_capacity(5 * commitments.len());
+        for commitment in commitments {
+            encoded.push(Field::from_u16(commitment.index));
+            for point in [commitment.hiding, commitment.binding] {
+                encoded.extend([point.to_x_coordinate(), point.to_y_coordinate()]);
+            }
+        }
+        let commitments_hash = N::hash_psd8(&encoded)?;
+        let message_hash = N::hash_psd8(message)?;
+
+        // Compute the binding factor of each signer as `HashToScalar(index, message, commitments)`.
+        let binding_factors = indices
+            .iter()
+            .map(|index| {
+                N::hash_to_scalar_psd4(&[
+                    Field::new_domain_separator("AleoThresholdBinding0"),
+                    Field::from_u16(*index),
+                    message_hash,
+                    commitments_hash,
+                ])
+            })
+            .collect::<Result<Vec<_>>>()?;
+        // Compute the Lagrange coefficient of each signer.
+        let lagrange_coefficients =
+            indices.iter().map(|index| lagrange_coefficient::<N>(*index, &indices)).collect::<Result<Vec<_>>>()?;
+        // Compute the group commitment as `sum(D_i + E_i^rho_i)`.
+        let group_commitment = commitments
+            .iter()
+            .zip_eq(&binding_factors)
+            .fold(Group::zero(), |acc, (commitment, rho)| acc + commitment.hiding + (commitment.binding * rho));
+
+        // Compute the challenge as `HashToScalar(R, pk_sig, pr_sig, address, message)`,
+        // which matches the challenge of an account signature.
+        let compute_key = key_package.compute_key;
+        let mut preimage = Vec::with_capacity(4 + message.len());
+        preimage.extend(
+            [group_commitment, compute_key.pk_sig(), compute_key.pr_sig(), *compute_key.to_address()]
+                .map(|point| point.to_x_coordinate()),
+        );
+        preimage.extend(message);
+        let challenge = N::hash_to_scalar_psd8(&preimage)?;
+
+        Ok(Self { indices, binding_factors, lagrange_coefficients, challenge })
+    }
+
+    /// Returns the position of the signer with the given index in the session.
+    pub(super) fn position(&self, index: u16) -> Result<usize> {
+        self.indices
+            .iter()
+            .position(|candidate| *candidate == index)
+            .ok_or_else(|| anyhow!("Participant {index} is not a signer"))
+    }
+}
@@ -0,0 +1,64 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate criterion;
+
+use snarkvm_console_algorithms::{Ed25519, Secp256k1};
+use snarkvm_console_types::prelude::*;
+use snarkvm_utilities::{TestRng, Uniform};
+
+use criterion::Criterion;
+
+/// Note: The finalize costs of `ecdsa.verify` and `ed25519.verify` are scaled from the cost of `mul` on groups,
+/// by the ratio of their running times to this benchmark.
+fn group_mul(c: &mut Criterion) {
+    let rng = &mut TestRng::default();
+
+    let group = Group::<Console>::rand(rng);
+    let scalar = Scalar::<Console>::rand(rng);
+    c.bench_function("Group Mul - variable base", |b| b.iter(|| group * scalar));
+}
+
+fn secp256k1(c: &mut Criterion) {
+    let rng = &mut TestRng::default();
+
+    let secret_key: [u8; 32] = rng.gen();
+    let digest: [u8; 32] = rng.gen();
+    let public_key = Secp256k1::public_key(&secret_key).unwrap();
+    let signature = Secp256k1::sign(&secret_key, &digest, rng).unwrap();
+    c.bench_function("Secp256k1 Verify", |b| b.iter(|| Secp256k1::verify(&public_key, &digest, &signature)));
+}
+
+fn ed25519(c: &mut Criterion) {
+    let rng = &mut TestRng::default();
+
+    let seed: [u8; 32] = rng.gen();
+    let public_key = Ed25519::public_key(&seed);
+    for size in [32, 1024] {
+        let message = (0..size).map(|_| rng.gen()).collect::<Vec<u8>>();
+        let signature = Ed25519::sign(&seed, &message);
+        c.bench_function(&format!("Ed25519 Verify - message size {size}"), |b| {
+            b.iter(|| Ed25519::verify(&public_key, &message, &signature))
+        });
+    }
+}
+
+criterion_group! {
+    name = signature;
+    config = Criterion::default().sample_size(100);
+    targets = group_mul, secp256k1, ed25519
+}
+
+criterion_main!(signature);
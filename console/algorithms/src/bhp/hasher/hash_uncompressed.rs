@@ -14,7 +14,7 @@
 
 use super::*;
 
-use std::borrow::Cow;
+use alloc::borrow::Cow;
 
 impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> HashUncompressed
     for BHPHasher<E, NUM_WINDOWS, WINDOW_SIZE>
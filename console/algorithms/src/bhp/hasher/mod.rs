@@ -18,7 +18,7 @@ use crate::Blake2Xs;
 use snarkvm_console_types::prelude::*;
 use snarkvm_utilities::BigInteger;
 
-use std::sync::Arc;
+use alloc::sync::Arc;
 
 /// The BHP chunk size (this implementation is for a 3-bit BHP).
 pub(super) const BHP_CHUNK_SIZE: usize = 3;
@@ -22,7 +22,7 @@ mod hash_uncompressed;
 
 use snarkvm_console_types::prelude::*;
 
-use std::sync::Arc;
+use alloc::sync::Arc;
 
 const BHP_CHUNK_SIZE: usize = 3;
 
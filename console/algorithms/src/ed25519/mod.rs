@@ -0,0 +1,112 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkvm_console_types::environment::prelude::*;
+
+use ed25519_dalek::{Signature, Signer, SigningKey, VerifyingKey};
+
+/// The Ed25519 signature scheme, as specified in RFC 8032.
+///
+/// Public keys are the 32-byte encoding of a point, and signatures are the 64-byte encoding of `R || S`.
+/// Verification is strict: it uses the cofactorless equation `[S]B = R + [k]A`,
+/// and rejects non-canonical encodings and small-order public keys and `R`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Ed25519;
+
+impl Ed25519 {
+    /// Returns `true` if the given signature is valid for the given public key and message.
+    pub fn verify(public_key: &[u8; 32], message: &[u8], signature: &[u8; 64]) -> bool {
+        // Decode the public key.
+        let Ok(public_key) = VerifyingKey::from_bytes(public_key) else {
+            return false;
+        };
+        public_key.verify_strict(message, &Signature::from_bytes(signature)).is_ok()
+    }
+
+    /// Returns the public key for the given secret key seed.
+    pub fn public_key(seed: &[u8; 32]) -> [u8; 32] {
+        SigningKey::from_bytes(seed).verifying_key().to_bytes()
+    }
+
+    /// Returns the deterministic signature on the given message for the given secret key seed.
+    pub fn sign(seed: &[u8; 32], message: &[u8]) -> [u8; 64] {
+        SigningKey::from_bytes(seed).sign(message).to_bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ITERATIONS: usize = 10;
+
+    /// The order `L` of the prime-order subgroup of edwards25519, in little-endian.
+    const L: [u8; 32] = [
+        0xed, 0xd3, 0xf5, 0x5c, 0x1a, 0x63, 0x12, 0x58, 0xd6, 0x9c, 0xf7, 0xa2, 0xde, 0xf9, 0xde, 0x14, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10,
+    ];
+
+    #[test]
+    fn test_rfc8032_vector() {
+        // The first test vector of RFC 8032, Section 7.1.
+        let seed = hex::decode("9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60").unwrap();
+        let seed: [u8; 32] = seed.try_into().unwrap();
+        let public_key = Ed25519::public_key(&seed);
+        assert_eq!(hex::encode(public_key), "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a");
+        let signature = Ed25519::sign(&seed, &[]);
+        assert_eq!(
+            hex::encode(signature),
+            "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b"
+        );
+        assert!(Ed25519::verify(&public_key, &[], &signature));
+    }
+
+    #[test]
+    fn test_sign_and_verify() {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let seed: [u8; 32] = rng.gen();
+            let message = (0..i * 10).map(|_| rng.gen()).collect::<Vec<u8>>();
+            let public_key = Ed25519::public_key(&seed);
+            let signature = Ed25519::sign(&seed, &message);
+            assert!(Ed25519::verify(&public_key, &message, &signature));
+
+            // Check a different message is rejected.
+            assert!(!Ed25519::verify(&public_key, &[&message[..], &[0u8]].concat(), &signature));
+            // Check a different public key is rejected.
+            assert!(!Ed25519::verify(&Ed25519::public_key(&rng.gen()), &message, &signature));
+            // Check a non-canonical `S`, i.e. `S + L`, is rejected.
+            let mut malleated = signature;
+            let mut carry = 0u16;
+            for (byte, l) in malleated[32..].iter_mut().zip(L) {
+                let [low, high] = (*byte as u16 + l as u16 + carry).to_le_bytes();
+                *byte = low;
+                carry = high as u16;
+            }
+            assert!(!Ed25519::verify(&public_key, &message, &malleated));
+        }
+    }
+
+    #[test]
+    fn test_small_order_public_key_is_rejected() {
+        // The identity point is a small-order point, for which any `S = 0` signature with `R` as the identity
+        // satisfies the cofactorless equation.
+        let mut identity = [0u8; 32];
+        identity[0] = 1;
+        let mut signature = [0u8; 64];
+        signature[..32].copy_from_slice(&identity);
+        assert!(!Ed25519::verify(&identity, b"message", &signature));
+    }
+}
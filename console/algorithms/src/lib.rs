@@ -12,11 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#![cfg_attr(not(feature = "std"), no_std)]
 #![forbid(unsafe_code)]
 #![allow(clippy::too_many_arguments)]
 #![warn(clippy::cast_possible_truncation)]
 #![cfg_attr(test, allow(clippy::assertions_on_result_states))]
 
+extern crate alloc;
+
+#[cfg(all(test, not(feature = "std")))]
+#[macro_use]
+extern crate std;
+
 pub use snarkvm_console_types::prelude::*;
 
 pub mod bhp;
@@ -25,6 +32,9 @@ pub use bhp::{BHP, BHP1024, BHP256, BHP512, BHP768};
 mod blake2xs;
 pub use blake2xs::Blake2Xs;
 
+mod ed25519;
+pub use ed25519::Ed25519;
+
 mod elligator2;
 pub use elligator2::Elligator2;
 
@@ -36,3 +46,6 @@ pub use pedersen::{Pedersen, Pedersen128, Pedersen64};
 
 mod poseidon;
 pub use poseidon::{Poseidon, Poseidon2, Poseidon4, Poseidon8};
+
+mod secp256k1;
+pub use secp256k1::Secp256k1;
@@ -19,8 +19,9 @@ use crate::poseidon::{
 use snarkvm_console_types::{prelude::*, Field};
 use snarkvm_fields::PoseidonParameters;
 
+use alloc::sync::Arc;
+use core::ops::DerefMut;
 use smallvec::SmallVec;
-use std::{ops::DerefMut, sync::Arc};
 
 /// A duplex sponge based using the Poseidon permutation.
 ///
@@ -152,7 +153,7 @@ impl<E: Environment, const RATE: usize, const CAPACITY: usize> PoseidonSponge<E,
     #[inline]
     fn absorb_internal(&mut self, mut rate_start: usize, input: &[Field<E>]) {
         if !input.is_empty() {
-            let first_chunk_size = std::cmp::min(RATE - rate_start, input.len());
+            let first_chunk_size = core::cmp::min(RATE - rate_start, input.len());
             let num_elements_remaining = input.len() - first_chunk_size;
             let (first_chunk, rest_chunk) = input.split_at(first_chunk_size);
             let rest_chunks = rest_chunk.chunks(RATE);
@@ -167,7 +168,7 @@ impl<E: Environment, const RATE: usize, const CAPACITY: usize> PoseidonSponge<E,
 
             // Absorb the input elements, `RATE` elements at a time, except for the first chunk, which
             // is of size `RATE - rate_start`.
-            for (i, chunk) in std::iter::once(first_chunk).chain(rest_chunks).enumerate() {
+            for (i, chunk) in core::iter::once(first_chunk).chain(rest_chunks).enumerate() {
                 for (element, state_elem) in chunk.iter().zip(&mut self.state.rate_state_mut()[rate_start..]) {
                     *state_elem += element;
                 }
@@ -189,7 +190,7 @@ impl<E: Environment, const RATE: usize, const CAPACITY: usize> PoseidonSponge<E,
     fn squeeze_internal(&mut self, mut rate_start: usize, output: &mut [Field<E>]) {
         let output_size = output.len();
         if output_size != 0 {
-            let first_chunk_size = std::cmp::min(RATE - rate_start, output.len());
+            let first_chunk_size = core::cmp::min(RATE - rate_start, output.len());
             let num_output_remaining = output.len() - first_chunk_size;
             let (first_chunk, rest_chunk) = output.split_at_mut(first_chunk_size);
             assert_eq!(rest_chunk.len(), num_output_remaining);
@@ -205,7 +206,7 @@ impl<E: Environment, const RATE: usize, const CAPACITY: usize> PoseidonSponge<E,
 
             // Absorb the input output, `RATE` output at a time, except for the first chunk, which
             // is of size `RATE - rate_start`.
-            for (i, chunk) in std::iter::once(first_chunk).chain(rest_chunks).enumerate() {
+            for (i, chunk) in core::iter::once(first_chunk).chain(rest_chunks).enumerate() {
                 let range = rate_start..(rate_start + chunk.len());
                 debug_assert_eq!(
                     chunk.len(),
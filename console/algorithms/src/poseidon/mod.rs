@@ -24,7 +24,7 @@ use crate::{poseidon::helpers::*, Elligator2};
 use snarkvm_console_types::prelude::*;
 use snarkvm_fields::{PoseidonDefaultField, PoseidonParameters};
 
-use std::sync::Arc;
+use alloc::sync::Arc;
 
 const CAPACITY: usize = 1;
 
@@ -0,0 +1,136 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkvm_console_types::environment::prelude::*;
+
+use k256::ecdsa::{
+    signature::hazmat::{PrehashVerifier, RandomizedPrehashSigner},
+    Signature,
+    SigningKey,
+    VerifyingKey,
+};
+
+/// ECDSA over the secp256k1 curve, as used by Bitcoin and Ethereum.
+///
+/// Public keys are SEC1-encoded, in compressed (33 bytes) or uncompressed (65 bytes) form,
+/// or are the raw encoding of `x || y` (64 bytes), as used by Ethereum.
+/// Signatures are the big-endian encoding of `r || s` (64 bytes), and are computed over a 32-byte message digest.
+/// As in Bitcoin and Ethereum, signatures must be low-S normalized.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Secp256k1;
+
+impl Secp256k1 {
+    /// Returns `true` if the given signature is valid for the given public key and message digest.
+    pub fn verify(public_key: &[u8], digest: &[u8; 32], signature: &[u8; 64]) -> bool {
+        // Decode the public key.
+        let public_key = match public_key.len() {
+            // Decode a raw point, by prepending the SEC1 tag of an uncompressed point.
+            64 => VerifyingKey::from_sec1_bytes(&[&[0x04][..], public_key].concat()),
+            _ => VerifyingKey::from_sec1_bytes(public_key),
+        };
+        let Ok(public_key) = public_key else {
+            return false;
+        };
+        // Decode the signature, which ensures `r` and `s` are in `[1, n - 1]`.
+        let Ok(signature) = Signature::from_slice(signature) else {
+            return false;
+        };
+        public_key.verify_prehash(digest, &signature).is_ok()
+    }
+
+    /// Returns the compressed public key for the given secret key.
+    pub fn public_key(secret_key: &[u8; 32]) -> Result<[u8; 33]> {
+        let secret_key = SigningKey::from_slice(secret_key).map_err(|_| anyhow!("Invalid secp256k1 secret key"))?;
+        let mut public_key = [0u8; 33];
+        public_key.copy_from_slice(secret_key.verifying_key().to_encoded_point(true).as_bytes());
+        Ok(public_key)
+    }
+
+    /// Returns a low-S signature on the given message digest, using a nonce sampled from the given RNG.
+    pub fn sign<R: Rng + CryptoRng>(secret_key: &[u8; 32], digest: &[u8; 32], rng: &mut R) -> Result<[u8; 64]> {
+        let secret_key = SigningKey::from_slice(secret_key).map_err(|_| anyhow!("Invalid secp256k1 secret key"))?;
+        let signature: Signature =
+            secret_key.sign_prehash_with_rng(rng, digest).map_err(|_| anyhow!("Failed to sign with secp256k1"))?;
+        let mut output = [0u8; 64];
+        output.copy_from_slice(&signature.to_bytes());
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ITERATIONS: usize = 10;
+
+    /// The x-coordinate of the generator of secp256k1.
+    const GX: &str = "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+
+    #[test]
+    fn test_public_key() -> Result<()> {
+        // The public key of the secret key `1` is the generator.
+        let mut secret_key = [0u8; 32];
+        secret_key[31] = 1;
+        assert_eq!(hex::encode(Secp256k1::public_key(&secret_key)?), format!("02{GX}"));
+        // The public key of the secret key `2` is twice the generator.
+        secret_key[31] = 2;
+        assert_eq!(
+            hex::encode(Secp256k1::public_key(&secret_key)?),
+            "02c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5"
+        );
+        // Ensure zero and out-of-range secret keys are rejected.
+        assert!(Secp256k1::public_key(&[0u8; 32]).is_err());
+        assert!(Secp256k1::public_key(&[0xff; 32]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_sign_and_verify() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let secret_key: [u8; 32] = rng.gen();
+            let digest: [u8; 32] = rng.gen();
+            let public_key = Secp256k1::public_key(&secret_key)?;
+            let signature = Secp256k1::sign(&secret_key, &digest, &mut rng)?;
+            assert!(Secp256k1::verify(&public_key, &digest, &signature));
+
+            // Check the uncompressed public key is accepted.
+            let verifying_key = VerifyingKey::from_sec1_bytes(&public_key).unwrap();
+            let uncompressed = verifying_key.to_encoded_point(false).as_bytes().to_vec();
+            assert!(Secp256k1::verify(&uncompressed, &digest, &signature));
+            // Check the raw public key is accepted.
+            assert!(Secp256k1::verify(&uncompressed[1..], &digest, &signature));
+
+            // Check a different digest is rejected.
+            let other_digest: [u8; 32] = rng.gen();
+            assert!(!Secp256k1::verify(&public_key, &other_digest, &signature));
+            // Check a different public key is rejected.
+            let other_public_key = Secp256k1::public_key(&rng.gen())?;
+            assert!(!Secp256k1::verify(&other_public_key, &digest, &signature));
+            // Check a malformed public key is rejected.
+            assert!(!Secp256k1::verify(&public_key[1..], &digest, &signature));
+            assert!(!Secp256k1::verify(&[&[0x05][..], &uncompressed[1..]].concat(), &digest, &signature));
+            // Check a zero signature is rejected.
+            assert!(!Secp256k1::verify(&public_key, &digest, &[0u8; 64]));
+            // Check the high-S form of the signature is rejected.
+            let signature = Signature::from_slice(&signature).unwrap();
+            let high_s = Signature::from_scalars(signature.r(), -*signature.s()).unwrap();
+            let mut malleated = [0u8; 64];
+            malleated.copy_from_slice(&high_s.to_bytes());
+            assert!(!Secp256k1::verify(&public_key, &digest, &malleated));
+        }
+        Ok(())
+    }
+}
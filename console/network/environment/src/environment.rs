@@ -28,6 +28,9 @@ use snarkvm_utilities::BigInteger;
 use core::{fmt::Debug, hash::Hash};
 use zeroize::Zeroize;
 
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+
 pub trait Environment:
     'static + Copy + Clone + Debug + PartialEq + Eq + Hash + Serialize + DeserializeOwned + Send + Sync
 {
@@ -14,6 +14,9 @@
 
 use crate::Environment;
 
+#[cfg(not(feature = "std"))]
+use alloc::string::ToString;
+
 /// A trait to unwrap a `Result` or `Halt`.
 pub trait OrHalt<T> {
     /// Returns the result if it is successful, otherwise halt.
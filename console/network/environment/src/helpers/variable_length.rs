@@ -18,6 +18,9 @@ use snarkvm_utilities::{
     FromBytes,
 };
 
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
 /// Returns the variable length integer of the given value.
 /// https://en.bitcoin.it/wiki/Protocol_documentation#Variable_length_integer
 pub fn variable_length_integer(value: &u64) -> Vec<u8> {
@@ -12,9 +12,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#![cfg_attr(not(feature = "std"), no_std)]
 #![forbid(unsafe_code)]
 #![allow(clippy::too_many_arguments)]
 
+#[cfg_attr(not(feature = "std"), macro_use)]
+extern crate alloc;
+
+#[cfg(all(test, not(feature = "std")))]
+#[macro_use]
+extern crate std;
+
 mod environment;
 pub use environment::*;
 
@@ -75,10 +83,12 @@ pub mod prelude {
         FromBits as _,
         FromBytes,
         FromBytesDeserializer,
+        FromCbor,
         TestRng,
         ToBits as _,
         ToBytes,
         ToBytesSerializer,
+        ToCbor,
         Uniform,
     };
 
@@ -116,6 +126,15 @@ pub mod prelude {
         str::{self, FromStr},
     };
 
+    pub use alloc::{
+        borrow::ToOwned,
+        boxed::Box,
+        format,
+        string::{String, ToString},
+        vec,
+        vec::Vec,
+    };
+
     pub use anyhow::{anyhow, bail, ensure, Error, Result};
     pub use bech32::{self, FromBase32, ToBase32};
     pub use itertools::Itertools;
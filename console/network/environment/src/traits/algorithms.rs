@@ -14,6 +14,9 @@
 
 use anyhow::Result;
 
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
 /// A trait for a commitment scheme.
 pub trait Commit {
     type Input;
@@ -63,6 +63,14 @@ pub trait DivWrapped<Rhs: ?Sized = Self> {
     fn div_wrapped(&self, rhs: &Rhs) -> Self::Output;
 }
 
+/// Binary operator for dividing two values, enforcing an overflow never occurs,
+/// where the circuit checks the division over the halves of the operands.
+pub trait DivWide<Rhs: ?Sized = Self> {
+    type Output;
+
+    fn div_wide(&self, rhs: &Rhs) -> Self::Output;
+}
+
 /// Binary operator for modding two values.
 pub trait Modulo<Rhs: ?Sized = Self> {
     type Output;
@@ -112,6 +120,14 @@ pub trait RemChecked<Rhs: ?Sized = Self> {
     fn rem_checked(&self, rhs: &Rhs) -> Self::Output;
 }
 
+/// Binary operator for dividing two values and returning the remainder, enforcing an overflow never occurs,
+/// where the circuit checks the division over the halves of the operands.
+pub trait RemWide<Rhs: ?Sized = Self> {
+    type Output;
+
+    fn rem_wide(&self, rhs: &Rhs) -> Self::Output;
+}
+
 /// Binary operator for dividing two values, bounding the remainder to `MAX` or `MIN` if an overflow occurs.
 pub trait RemSaturating<Rhs: ?Sized = Self> {
     type Output;
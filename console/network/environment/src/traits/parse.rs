@@ -18,6 +18,9 @@ use nom::{
     IResult,
 };
 
+#[cfg(not(feature = "std"))]
+use alloc::string::{String, ToString};
+
 /// The `nom`-compatible parser return type.
 pub type ParserResult<'a, O> = IResult<&'a str, O, VerboseError<&'a str>>;
 
@@ -16,6 +16,9 @@ use super::FieldTrait;
 
 use anyhow::Result;
 
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
 /// Unary operator for converting to a base field.
 pub trait ToField {
     type Field: FieldTrait;
@@ -0,0 +1,69 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::environment::prelude::*;
+
+/// The number of consensus versions.
+pub const NUM_CONSENSUS_VERSIONS: usize = 3;
+
+/// The version of the consensus rules, which changes at fixed block heights of the network.
+/// See `Network::CONSENSUS_VERSION_HEIGHTS` for the height at which each version activates.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ConsensusVersion {
+    /// The initial consensus rules.
+    V1 = 1,
+    /// Allows deployments to upgrade an existing program.
+    V2 = 2,
+    /// Allows validators to rotate their signing keys, through the `credits.aleo/signer` mapping.
+    V3 = 3,
+}
+
+impl ConsensusVersion {
+    /// Returns the consensus version at the given block height, using the given activation heights.
+    pub fn from_height(heights: &[(ConsensusVersion, u32); NUM_CONSENSUS_VERSIONS], height: u32) -> Result<Self> {
+        // Find the latest version that has activated at the given height.
+        match heights.iter().rev().find(|(_, activation_height)| *activation_height <= height) {
+            Some((version, _)) => Ok(*version),
+            None => bail!("No consensus version is active at block height {height}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{MainnetV0, Network};
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_consensus_version_heights() {
+        let heights = CurrentNetwork::CONSENSUS_VERSION_HEIGHTS;
+        // Ensure the first version activates at genesis.
+        assert_eq!(heights[0], (ConsensusVersion::V1, 0));
+        // Ensure the versions and their heights are strictly increasing.
+        for window in heights.windows(2) {
+            assert!(window[0].0 < window[1].0);
+            assert!(window[0].1 < window[1].1);
+        }
+        // Ensure each version is active from its height, until the next version activates.
+        for (version, height) in heights {
+            assert_eq!(CurrentNetwork::consensus_version(height).unwrap(), version);
+        }
+        for window in heights.windows(2) {
+            assert_eq!(CurrentNetwork::consensus_version(window[1].1 - 1).unwrap(), window[0].0);
+        }
+        assert_eq!(CurrentNetwork::consensus_version(u32::MAX).unwrap(), ConsensusVersion::V3);
+    }
+}
@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod consensus_version;
+pub use consensus_version::*;
+
 mod id;
 pub use id::*;
 
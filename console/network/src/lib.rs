@@ -33,6 +33,7 @@ pub mod prelude {
 }
 
 use crate::environment::prelude::*;
+#[cfg(feature = "snark")]
 use snarkvm_algorithms::{
     crypto_hash::PoseidonSponge,
     snark::varuna::{CircuitProvingKey, CircuitVerifyingKey, VarunaHidingMode},
@@ -41,11 +42,15 @@ use snarkvm_algorithms::{
 };
 use snarkvm_console_algorithms::{Poseidon2, Poseidon4, BHP1024, BHP512};
 use snarkvm_console_collections::merkle_tree::{MerklePath, MerkleTree};
-use snarkvm_console_types::{Field, Group, Scalar};
+use snarkvm_console_types::{Address, Field, Group, Scalar};
+#[cfg(feature = "snark")]
 use snarkvm_curves::PairingEngine;
 
+#[cfg(feature = "snark")]
 use indexmap::IndexMap;
+#[cfg(feature = "snark")]
 use once_cell::sync::OnceCell;
+#[cfg(feature = "snark")]
 use std::sync::Arc;
 
 /// A helper type for the BHP Merkle tree.
@@ -54,12 +59,17 @@ pub type BHPMerkleTree<N, const DEPTH: u8> = MerkleTree<N, BHP1024<N>, BHP512<N>
 pub type PoseidonMerkleTree<N, const DEPTH: u8> = MerkleTree<N, Poseidon4<N>, Poseidon2<N>, DEPTH>;
 
 /// Helper types for the Varuna parameters.
+#[cfg(feature = "snark")]
 type Fq<N> = <<N as Environment>::PairingCurve as PairingEngine>::Fq;
+#[cfg(feature = "snark")]
 pub type FiatShamir<N> = PoseidonSponge<Fq<N>, 2, 1>;
+#[cfg(feature = "snark")]
 pub type FiatShamirParameters<N> = <FiatShamir<N> as AlgebraicSponge<Fq<N>, 2>>::Parameters;
 
 /// Helper types for the Varuna proving and verifying key.
+#[cfg(feature = "snark")]
 pub(crate) type VarunaProvingKey<N> = CircuitProvingKey<<N as Environment>::PairingCurve, VarunaHidingMode>;
+#[cfg(feature = "snark")]
 pub(crate) type VarunaVerifyingKey<N> = CircuitVerifyingKey<<N as Environment>::PairingCurve>;
 
 pub trait Network:
@@ -81,12 +91,18 @@ pub trait Network:
     const ID: u16;
     /// The network name.
     const NAME: &'static str;
+    /// The short network name, used in the REST API paths of a node.
+    const SHORT_NAME: &'static str;
     /// The network edition.
     const EDITION: u16;
 
     /// The function name for the inclusion circuit.
+    #[cfg(feature = "snark")]
     const INCLUSION_FUNCTION_NAME: &'static str;
 
+    /// The block heights at which each consensus version activates, in increasing order.
+    const CONSENSUS_VERSION_HEIGHTS: [(ConsensusVersion, u32); NUM_CONSENSUS_VERSIONS];
+
     /// The fixed timestamp of the genesis block.
     const GENESIS_TIMESTAMP: i64 = 1696118400; // 2023-10-01 00:00:00 UTC
     /// The genesis block coinbase target.
@@ -154,6 +170,8 @@ pub trait Network:
     const MAX_COMMANDS: usize = u16::MAX as usize;
     /// The maximum number of write commands in finalize.
     const MAX_WRITES: u16 = 16;
+    /// The maximum number of iterations of a loop in finalize.
+    const MAX_ITERATIONS: u32 = 256;
 
     /// The maximum number of inputs per transition.
     const MAX_INPUTS: usize = 16;
@@ -176,19 +194,29 @@ pub trait Network:
     /// The transition ID type.
     type TransitionID: Bech32ID<Field<Self>>;
 
+    /// Returns the consensus version at the given block height.
+    fn consensus_version(height: u32) -> Result<ConsensusVersion> {
+        ConsensusVersion::from_height(&Self::CONSENSUS_VERSION_HEIGHTS, height)
+    }
+
     /// Returns the genesis block bytes.
+    #[cfg(feature = "snark")]
     fn genesis_bytes() -> &'static [u8];
 
     /// Returns the proving key for the given function name in `credits.aleo`.
+    #[cfg(feature = "snark")]
     fn get_credits_proving_key(function_name: String) -> Result<&'static Arc<VarunaProvingKey<Self>>>;
 
     /// Returns the verifying key for the given function name in `credits.aleo`.
+    #[cfg(feature = "snark")]
     fn get_credits_verifying_key(function_name: String) -> Result<&'static Arc<VarunaVerifyingKey<Self>>>;
 
     /// Returns the `proving key` for the inclusion circuit.
+    #[cfg(feature = "snark")]
     fn inclusion_proving_key() -> &'static Arc<VarunaProvingKey<Self>>;
 
     /// Returns the `verifying key` for the inclusion circuit.
+    #[cfg(feature = "snark")]
     fn inclusion_verifying_key() -> &'static Arc<VarunaVerifyingKey<Self>>;
 
     /// Returns the powers of `G`.
@@ -198,12 +226,15 @@ pub trait Network:
     fn g_scalar_multiply(scalar: &Scalar<Self>) -> Group<Self>;
 
     /// Returns the Varuna universal prover.
+    #[cfg(feature = "snark")]
     fn varuna_universal_prover() -> &'static UniversalProver<Self::PairingCurve>;
 
     /// Returns the Varuna universal verifier.
+    #[cfg(feature = "snark")]
     fn varuna_universal_verifier() -> &'static UniversalVerifier<Self::PairingCurve>;
 
     /// Returns the sponge parameters for Varuna.
+    #[cfg(feature = "snark")]
     fn varuna_fs_parameters() -> &'static FiatShamirParameters<Self>;
 
     /// Returns the encryption domain as a constant field element.
@@ -215,6 +246,16 @@ pub trait Network:
     /// Returns the serial number domain as a constant field element.
     fn serial_number_domain() -> Field<Self>;
 
+    /// Returns the view tag domain as a constant field element.
+    fn view_tag_domain() -> Field<Self>;
+
+    /// Returns the address of the external randomness beacon, if one is designated for this network.
+    ///
+    /// Randomness ratifications are only accepted if they are signed by this address.
+    fn randomness_beacon() -> Option<Address<Self>> {
+        None
+    }
+
     /// Returns a BHP commitment with an input hasher of 256-bits and randomizer.
     fn commit_bhp256(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>>;
 
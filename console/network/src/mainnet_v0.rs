@@ -36,15 +36,14 @@ lazy_static! {
     /// The group bases for the Aleo signature and encryption schemes.
     pub static ref GENERATOR_G: Vec<Group<MainnetV0 >> = MainnetV0::new_bases("AleoAccountEncryptionAndSignatureScheme0");
 
-    /// The Varuna sponge parameters.
-    pub static ref VARUNA_FS_PARAMETERS: FiatShamirParameters<MainnetV0> = FiatShamir::<MainnetV0>::sample_parameters();
-
     /// The encryption domain as a constant field element.
     pub static ref ENCRYPTION_DOMAIN: Field<MainnetV0> = Field::<MainnetV0>::new_domain_separator("AleoSymmetricEncryption0");
     /// The graph key domain as a constant field element.
     pub static ref GRAPH_KEY_DOMAIN: Field<MainnetV0> = Field::<MainnetV0>::new_domain_separator("AleoGraphKey0");
     /// The serial number domain as a constant field element.
     pub static ref SERIAL_NUMBER_DOMAIN: Field<MainnetV0> = Field::<MainnetV0>::new_domain_separator("AleoSerialNumber0");
+    /// The view tag domain as a constant field element.
+    pub static ref VIEW_TAG_DOMAIN: Field<MainnetV0> = Field::<MainnetV0>::new_domain_separator("AleoViewTag0");
 
     /// The BHP hash function, which can take an input of up to 256 bits.
     pub static ref BHP_256: BHP256<MainnetV0> = BHP256::<MainnetV0>::setup("AleoBHP256").expect("Failed to setup BHP256");
@@ -66,6 +65,12 @@ lazy_static! {
     pub static ref POSEIDON_4: Poseidon4<MainnetV0> = Poseidon4::<MainnetV0>::setup("AleoPoseidon4").expect("Failed to setup Poseidon4");
     /// The Poseidon hash function, using a rate of 8.
     pub static ref POSEIDON_8: Poseidon8<MainnetV0> = Poseidon8::<MainnetV0>::setup("AleoPoseidon8").expect("Failed to setup Poseidon8");
+}
+
+#[cfg(feature = "snark")]
+lazy_static! {
+    /// The Varuna sponge parameters.
+    pub static ref VARUNA_FS_PARAMETERS: FiatShamirParameters<MainnetV0> = FiatShamir::<MainnetV0>::sample_parameters();
 
     pub static ref CREDITS_PROVING_KEYS: IndexMap<String, Arc<VarunaProvingKey<Console>>> = {
         let mut map = IndexMap::new();
@@ -131,21 +136,34 @@ impl Network for MainnetV0 {
     /// The transition ID type.
     type TransitionID = AleoID<Field<Self>, { hrp2!("au") }>;
 
+    /// The block heights at which each consensus version activates.
+    #[cfg(not(any(test, feature = "test")))]
+    const CONSENSUS_VERSION_HEIGHTS: [(ConsensusVersion, u32); NUM_CONSENSUS_VERSIONS] =
+        [(ConsensusVersion::V1, 0), (ConsensusVersion::V2, 2_000_000), (ConsensusVersion::V3, 2_100_000)];
+    /// The block heights at which each consensus version activates, lowered for tests.
+    #[cfg(any(test, feature = "test"))]
+    const CONSENSUS_VERSION_HEIGHTS: [(ConsensusVersion, u32); NUM_CONSENSUS_VERSIONS] =
+        [(ConsensusVersion::V1, 0), (ConsensusVersion::V2, 3), (ConsensusVersion::V3, 5)];
     /// The network edition.
     const EDITION: u16 = 0;
     /// The network ID.
     const ID: u16 = 0;
     /// The function name for the inclusion circuit.
+    #[cfg(feature = "snark")]
     const INCLUSION_FUNCTION_NAME: &'static str = snarkvm_parameters::mainnet::NETWORK_INCLUSION_FUNCTION_NAME;
     /// The network name.
     const NAME: &'static str = "Aleo Mainnet (v0)";
+    /// The short network name.
+    const SHORT_NAME: &'static str = "mainnet";
 
     /// Returns the genesis block bytes.
+    #[cfg(feature = "snark")]
     fn genesis_bytes() -> &'static [u8] {
         snarkvm_parameters::mainnet::GenesisBytes::load_bytes()
     }
 
     /// Returns the proving key for the given function name in `credits.aleo`.
+    #[cfg(feature = "snark")]
     fn get_credits_proving_key(function_name: String) -> Result<&'static Arc<VarunaProvingKey<Self>>> {
         CREDITS_PROVING_KEYS
             .get(&function_name)
@@ -153,6 +171,7 @@ impl Network for MainnetV0 {
     }
 
     /// Returns the verifying key for the given function name in `credits.aleo`.
+    #[cfg(feature = "snark")]
     fn get_credits_verifying_key(function_name: String) -> Result<&'static Arc<VarunaVerifyingKey<Self>>> {
         CREDITS_VERIFYING_KEYS
             .get(&function_name)
@@ -160,6 +179,7 @@ impl Network for MainnetV0 {
     }
 
     /// Returns the `proving key` for the inclusion circuit.
+    #[cfg(feature = "snark")]
     fn inclusion_proving_key() -> &'static Arc<VarunaProvingKey<Self>> {
         static INSTANCE: OnceCell<Arc<VarunaProvingKey<Console>>> = OnceCell::new();
         INSTANCE.get_or_init(|| {
@@ -172,6 +192,7 @@ impl Network for MainnetV0 {
     }
 
     /// Returns the `verifying key` for the inclusion circuit.
+    #[cfg(feature = "snark")]
     fn inclusion_verifying_key() -> &'static Arc<VarunaVerifyingKey<Self>> {
         static INSTANCE: OnceCell<Arc<VarunaVerifyingKey<Console>>> = OnceCell::new();
         INSTANCE.get_or_init(|| {
@@ -201,6 +222,7 @@ impl Network for MainnetV0 {
     }
 
     /// Returns the Varuna universal prover.
+    #[cfg(feature = "snark")]
     fn varuna_universal_prover() -> &'static UniversalProver<Self::PairingCurve> {
         static INSTANCE: OnceCell<UniversalProver<<Console as Environment>::PairingCurve>> = OnceCell::new();
         INSTANCE.get_or_init(|| {
@@ -212,6 +234,7 @@ impl Network for MainnetV0 {
     }
 
     /// Returns the Varuna universal verifier.
+    #[cfg(feature = "snark")]
     fn varuna_universal_verifier() -> &'static UniversalVerifier<Self::PairingCurve> {
         static INSTANCE: OnceCell<UniversalVerifier<<Console as Environment>::PairingCurve>> = OnceCell::new();
         INSTANCE.get_or_init(|| {
@@ -223,6 +246,7 @@ impl Network for MainnetV0 {
     }
 
     /// Returns the sponge parameters used for the sponge in the Varuna SNARK.
+    #[cfg(feature = "snark")]
     fn varuna_fs_parameters() -> &'static FiatShamirParameters<Self> {
         &VARUNA_FS_PARAMETERS
     }
@@ -242,6 +266,11 @@ impl Network for MainnetV0 {
         *SERIAL_NUMBER_DOMAIN
     }
 
+    /// Returns the view tag domain as a constant field element.
+    fn view_tag_domain() -> Field<Self> {
+        *VIEW_TAG_DOMAIN
+    }
+
     /// Returns a BHP commitment with an input hasher of 256-bits and randomizer.
     fn commit_bhp256(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>> {
         BHP_256.commit(input, randomizer)
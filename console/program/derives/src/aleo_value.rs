@@ -0,0 +1,124 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{ext::IdentExt, parse_quote, Data, DeriveInput, Error, Fields, GenericParam, LitStr, Path, Result};
+
+/// Returns the path to the `snarkvm_console_program` crate, as given by the `#[aleo(crate = "path")]` attribute.
+fn crate_path(ast: &DeriveInput) -> Result<Path> {
+    let mut path = parse_quote!(snarkvm_console_program);
+    for attribute in ast.attrs.iter().filter(|attribute| attribute.path().is_ident("aleo")) {
+        attribute.parse_nested_meta(|meta| match meta.path.is_ident("crate") {
+            true => {
+                path = meta.value()?.parse::<LitStr>()?.parse()?;
+                Ok(())
+            }
+            false => Err(meta.error("unsupported `aleo` attribute, expected `crate`")),
+        })?;
+    }
+    Ok(path)
+}
+
+/// Returns `true` if the given name is a valid Aleo identifier.
+fn is_identifier(name: &str) -> bool {
+    name.starts_with(|character: char| character.is_ascii_alphabetic())
+        && name.chars().all(|character| character.is_ascii_alphanumeric() || character == '_')
+}
+
+pub(super) fn impl_aleo_value(ast: &DeriveInput) -> Result<TokenStream> {
+    let name = &ast.ident;
+    let krate = crate_path(ast)?;
+
+    // Retrieve the named fields of the struct.
+    let fields = match &ast.data {
+        Data::Struct(data_struct) => match &data_struct.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => return Err(Error::new_spanned(name, "`AleoValue` can only be derived for structs with named fields")),
+        },
+        _ => return Err(Error::new_spanned(name, "`AleoValue` can only be derived for structs")),
+    };
+    // Ensure the struct has at least one field, as Aleo structs may not be empty.
+    if fields.is_empty() {
+        return Err(Error::new_spanned(name, "`AleoValue` can only be derived for structs with at least one field"));
+    }
+
+    // Retrieve the field identifiers and the member names.
+    let idents = fields.iter().filter_map(|field| field.ident.as_ref()).collect::<Vec<_>>();
+    let members = idents.iter().map(|ident| ident.unraw().to_string()).collect::<Vec<_>>();
+    // Ensure each member name is a valid Aleo identifier.
+    for (ident, member) in idents.iter().zip(&members) {
+        if !is_identifier(member) {
+            return Err(Error::new_spanned(ident, format!("`{member}` is not a valid Aleo identifier")));
+        }
+    }
+    let num_members = members.len();
+
+    // Use the first type parameter as the network, or introduce one if there is none.
+    let mut generics = ast.generics.clone();
+    let network = match ast.generics.type_params().next() {
+        Some(type_param) => type_param.ident.clone(),
+        None => {
+            let network = format_ident!("__N");
+            generics.params.push(GenericParam::Type(parse_quote!(#network: #krate::Network)));
+            network
+        }
+    };
+    // Require each field type to be an Aleo value.
+    let where_clause = generics.make_where_clause();
+    for field in fields {
+        let ty = &field.ty;
+        where_clause.predicates.push(parse_quote!(#ty: #krate::AleoValue<#network>));
+    }
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let (_, ty_generics, _) = ast.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #krate::AleoValue<#network> for #name #ty_generics #where_clause {
+            fn to_plaintext(&self) -> #krate::Plaintext<#network> {
+                #krate::aleo_value_to_struct([
+                    #( (#members, #krate::AleoValue::<#network>::to_plaintext(&self.#idents)) ),*
+                ])
+            }
+
+            fn from_plaintext(plaintext: &#krate::Plaintext<#network>) -> #krate::Result<Self> {
+                Ok(Self {
+                    #( #idents: #krate::AleoValue::<#network>::from_plaintext(
+                        #krate::aleo_value_member(plaintext, #members, #num_members)?
+                    )? ),*
+                })
+            }
+        }
+
+        impl #impl_generics ::core::convert::TryFrom<#krate::Plaintext<#network>> for #name #ty_generics #where_clause {
+            type Error = #krate::Error;
+
+            fn try_from(plaintext: #krate::Plaintext<#network>) -> #krate::Result<Self> {
+                #krate::AleoValue::<#network>::from_plaintext(&plaintext)
+            }
+        }
+
+        impl #impl_generics ::core::convert::From<#name #ty_generics> for #krate::Plaintext<#network> #where_clause {
+            fn from(value: #name #ty_generics) -> Self {
+                #krate::AleoValue::<#network>::to_plaintext(&value)
+            }
+        }
+
+        impl #impl_generics ::core::convert::From<#name #ty_generics> for #krate::Value<#network> #where_clause {
+            fn from(value: #name #ty_generics) -> Self {
+                #krate::Value::Plaintext(#krate::AleoValue::<#network>::to_plaintext(&value))
+            }
+        }
+    })
+}
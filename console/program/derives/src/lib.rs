@@ -0,0 +1,28 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod aleo_value;
+
+use syn::*;
+
+/// Derives `AleoValue` for a struct with named fields, mapping each field onto the member of the same name
+/// in an Aleo struct. This also derives `TryFrom<Plaintext<N>>`, and `From<Self>` for `Plaintext<N>` and `Value<N>`.
+///
+/// The network is the first type parameter of the struct, or any network if the struct has no type parameters.
+/// The path to the `snarkvm_console_program` crate may be set with `#[aleo(crate = "path")]`.
+#[proc_macro_derive(AleoValue, attributes(aleo))]
+pub fn derive_aleo_value(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    proc_macro::TokenStream::from(aleo_value::impl_aleo_value(&ast).unwrap_or_else(Error::into_compile_error))
+}
@@ -0,0 +1,182 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{Identifier, Literal, LiteralType, Plaintext};
+use snarkvm_console_network::Network;
+use snarkvm_console_types::{prelude::*, Boolean};
+
+pub use snarkvm_console_program_derives::AleoValue;
+
+/// A Rust type that maps onto an Aleo plaintext.
+///
+/// This is implemented for `bool`, the primitive integers, the field, group, scalar, and address types, and arrays,
+/// and may be derived for structs with `#[derive(AleoValue)]`, whose fields map onto the members of an Aleo struct.
+pub trait AleoValue<N: Network>: Sized {
+    /// Returns the value as a plaintext.
+    fn to_plaintext(&self) -> Plaintext<N>;
+
+    /// Returns the value from the given plaintext.
+    fn from_plaintext(plaintext: &Plaintext<N>) -> Result<Self>;
+}
+
+macro_rules! impl_aleo_value {
+    ($type:ty, $variant:ident, |$value:ident| $to_literal:expr, |$literal:ident| $from_literal:expr) => {
+        impl<N: Network> AleoValue<N> for $type {
+            fn to_plaintext(&self) -> Plaintext<N> {
+                let $value = self;
+                Plaintext::from(Literal::$variant($to_literal))
+            }
+
+            fn from_plaintext(plaintext: &Plaintext<N>) -> Result<Self> {
+                match plaintext {
+                    Plaintext::Literal(Literal::$variant($literal), ..) => Ok($from_literal),
+                    _ => bail!("Expected a literal of type '{}', found '{plaintext}'", LiteralType::$variant),
+                }
+            }
+        }
+    };
+}
+
+impl_aleo_value!(bool, Boolean, |value| Boolean::new(*value), |literal| **literal);
+impl_aleo_value!(i8, I8, |value| I8::new(*value), |literal| **literal);
+impl_aleo_value!(i16, I16, |value| I16::new(*value), |literal| **literal);
+impl_aleo_value!(i32, I32, |value| I32::new(*value), |literal| **literal);
+impl_aleo_value!(i64, I64, |value| I64::new(*value), |literal| **literal);
+impl_aleo_value!(i128, I128, |value| I128::new(*value), |literal| **literal);
+impl_aleo_value!(u8, U8, |value| U8::new(*value), |literal| **literal);
+impl_aleo_value!(u16, U16, |value| U16::new(*value), |literal| **literal);
+impl_aleo_value!(u32, U32, |value| U32::new(*value), |literal| **literal);
+impl_aleo_value!(u64, U64, |value| U64::new(*value), |literal| **literal);
+impl_aleo_value!(u128, U128, |value| U128::new(*value), |literal| **literal);
+impl_aleo_value!(Address<N>, Address, |value| *value, |literal| *literal);
+impl_aleo_value!(Boolean<N>, Boolean, |value| *value, |literal| *literal);
+impl_aleo_value!(Field<N>, Field, |value| *value, |literal| *literal);
+impl_aleo_value!(Group<N>, Group, |value| *value, |literal| *literal);
+impl_aleo_value!(Scalar<N>, Scalar, |value| *value, |literal| *literal);
+
+impl<N: Network, T: AleoValue<N>, const LENGTH: usize> AleoValue<N> for [T; LENGTH] {
+    /// Returns the array as a plaintext.
+    fn to_plaintext(&self) -> Plaintext<N> {
+        Plaintext::Array(self.iter().map(T::to_plaintext).collect(), Default::default())
+    }
+
+    /// Returns the array from the given plaintext.
+    fn from_plaintext(plaintext: &Plaintext<N>) -> Result<Self> {
+        let Plaintext::Array(elements, ..) = plaintext else { bail!("Expected an array, found '{plaintext}'") };
+        // Ensure the number of elements matches.
+        ensure!(elements.len() == LENGTH, "Expected an array of {LENGTH} elements, found {}", elements.len());
+        let elements = elements.iter().map(T::from_plaintext).collect::<Result<Vec<_>>>()?;
+        elements.try_into().map_err(|_| anyhow!("Expected an array of {LENGTH} elements"))
+    }
+}
+
+impl<N: Network> AleoValue<N> for Plaintext<N> {
+    /// Returns the plaintext.
+    fn to_plaintext(&self) -> Plaintext<N> {
+        self.clone()
+    }
+
+    /// Returns the given plaintext.
+    fn from_plaintext(plaintext: &Plaintext<N>) -> Result<Self> {
+        Ok(plaintext.clone())
+    }
+}
+
+/// Returns the plaintext struct with the given members.
+/// Note: This is used by `#[derive(AleoValue)]`, which ensures each member name is an identifier.
+#[doc(hidden)]
+pub fn aleo_value_to_struct<N: Network>(
+    members: impl IntoIterator<Item = (&'static str, Plaintext<N>)>,
+) -> Plaintext<N> {
+    let members = members.into_iter().map(|(name, member)| match Identifier::from_str(name) {
+        Ok(name) => (name, member),
+        Err(error) => panic!("Member '{name}' is not a valid Aleo identifier: {error}"),
+    });
+    Plaintext::Struct(members.collect(), Default::default())
+}
+
+/// Returns the member of the given name, in the given plaintext struct of the given number of members.
+/// Note: This is used by `#[derive(AleoValue)]`.
+#[doc(hidden)]
+pub fn aleo_value_member<'a, N: Network>(
+    plaintext: &'a Plaintext<N>,
+    name: &str,
+    num_members: usize,
+) -> Result<&'a Plaintext<N>> {
+    let Plaintext::Struct(members, ..) = plaintext else { bail!("Expected a struct, found '{plaintext}'") };
+    // Ensure the number of members matches.
+    ensure!(members.len() == num_members, "Expected a struct of {num_members} members, found {}", members.len());
+    members.get(&Identifier::from_str(name)?).ok_or_else(|| anyhow!("Missing member '{name}' in '{plaintext}'"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Value;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[derive(AleoValue, Clone, Debug, PartialEq, Eq)]
+    #[aleo(crate = "crate")]
+    struct Balances {
+        spendable: u64,
+        locked: [u128; 2],
+    }
+
+    #[derive(AleoValue, Clone, Debug, PartialEq, Eq)]
+    #[aleo(crate = "crate")]
+    struct Account<N: Network> {
+        owner: Address<N>,
+        id: Field<N>,
+        active: bool,
+        balances: Balances,
+    }
+
+    #[test]
+    fn test_derive_aleo_value() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let expected = Account::<CurrentNetwork> {
+            owner: Address::rand(rng),
+            id: Field::rand(rng),
+            active: true,
+            balances: Balances { spendable: 5, locked: [1, 2] },
+        };
+
+        // Check the plaintext of the struct.
+        let plaintext = Plaintext::from(expected.clone());
+        let string = format!(
+            "{{ owner: {}, id: {}, active: true, balances: {{ spendable: 5u64, locked: [1u128, 2u128] }} }}",
+            expected.owner, expected.id
+        );
+        assert_eq!(plaintext, Plaintext::from_str(&string)?);
+        assert_eq!(Account::try_from(plaintext.clone())?, expected);
+        assert_eq!(Value::from(expected), Value::Plaintext(plaintext));
+
+        // Check a struct without a network.
+        let balances = Balances { spendable: 5, locked: [1, 2] };
+        let plaintext: Plaintext<CurrentNetwork> = balances.clone().into();
+        assert_eq!(Balances::try_from(plaintext)?, balances);
+
+        // Ensure mismatched plaintexts are rejected.
+        let check = |string: &str| Balances::try_from(Plaintext::<CurrentNetwork>::from_str(string).unwrap()).is_err();
+        assert!(check("5u64"));
+        assert!(check("{ spendable: 5u32, locked: [1u128, 2u128] }"));
+        assert!(check("{ spendable: 5u64, locked: [1u128, 2u128, 3u128] }"));
+        assert!(check("{ spendable: 5u64, locked: [1u128, 2u128], extra: 1u8 }"));
+        assert!(check("{ spendable: 5u64, unlocked: [1u128, 2u128] }"));
+        Ok(())
+    }
+}
@@ -0,0 +1,153 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use serde_json::Value as Json;
+
+impl<N: Network> Literal<N> {
+    /// Returns the literal as JSON.
+    ///
+    /// Booleans are JSON booleans, strings are JSON strings, and integers of up to 64 bits are JSON numbers.
+    /// All other literals are JSON strings without their type suffix, e.g. `"5"` for `5field` or `5u128`,
+    /// as JSON numbers may not hold them without a loss of precision.
+    pub fn to_json(&self) -> Json {
+        match self {
+            Self::Boolean(boolean) => Json::from(**boolean),
+            Self::I8(integer) => Json::from(**integer),
+            Self::I16(integer) => Json::from(**integer),
+            Self::I32(integer) => Json::from(**integer),
+            Self::I64(integer) => Json::from(**integer),
+            Self::U8(integer) => Json::from(**integer),
+            Self::U16(integer) => Json::from(**integer),
+            Self::U32(integer) => Json::from(**integer),
+            Self::U64(integer) => Json::from(**integer),
+            Self::String(string) => Json::from(&**string),
+            _ => {
+                let string = self.to_string();
+                Json::from(string.strip_suffix(self.to_type().type_name()).unwrap_or(&string))
+            }
+        }
+    }
+
+    /// Returns the literal of the given type from JSON, in the layout of `Literal::to_json`.
+    /// Note: Integers are also accepted as JSON strings.
+    pub fn from_json(json: &Json, literal_type: &LiteralType) -> Result<Self> {
+        let literal = match (json, literal_type) {
+            (Json::Bool(boolean), LiteralType::Boolean) => Self::Boolean(Boolean::new(*boolean)),
+            (Json::String(string), LiteralType::String) => {
+                // Ensure the string is within the allowed capacity.
+                ensure!(string.len() <= N::MAX_STRING_BYTES as usize, "Found a string that exceeds the maximum size");
+                Self::String(StringType::new(string))
+            }
+            (Json::String(string), LiteralType::Address | LiteralType::Signature) => Self::from_str(string)?,
+            (Json::Number(number), literal_type) if is_integer_type(literal_type) => {
+                Self::from_str(&format!("{number}{}", literal_type.type_name()))?
+            }
+            (Json::String(string), LiteralType::Field | LiteralType::Group | LiteralType::Scalar) => {
+                Self::from_str(&format!("{string}{}", literal_type.type_name()))?
+            }
+            (Json::String(string), literal_type) if is_integer_type(literal_type) => {
+                Self::from_str(&format!("{string}{}", literal_type.type_name()))?
+            }
+            _ => bail!("Expected a JSON value of type '{literal_type}', found '{json}'"),
+        };
+        // Ensure the literal is of the given type.
+        ensure!(literal.to_type() == *literal_type, "Expected a literal of type '{literal_type}', found '{literal}'");
+        Ok(literal)
+    }
+}
+
+/// Returns `true` if the given literal type is an integer type.
+const fn is_integer_type(literal_type: &LiteralType) -> bool {
+    matches!(
+        literal_type,
+        LiteralType::I8
+            | LiteralType::I16
+            | LiteralType::I32
+            | LiteralType::I64
+            | LiteralType::I128
+            | LiteralType::U8
+            | LiteralType::U16
+            | LiteralType::U32
+            | LiteralType::U64
+            | LiteralType::U128
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_json() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let literal_types = [
+            LiteralType::Address,
+            LiteralType::Boolean,
+            LiteralType::Field,
+            LiteralType::Group,
+            LiteralType::I8,
+            LiteralType::I16,
+            LiteralType::I32,
+            LiteralType::I64,
+            LiteralType::I128,
+            LiteralType::U8,
+            LiteralType::U16,
+            LiteralType::U32,
+            LiteralType::U64,
+            LiteralType::U128,
+            LiteralType::Scalar,
+            LiteralType::Signature,
+            LiteralType::String,
+        ];
+
+        for _ in 0..ITERATIONS {
+            for literal_type in &literal_types {
+                // Sample a literal, and check its JSON representation.
+                let expected = Literal::<CurrentNetwork>::sample(*literal_type, rng);
+                let candidate = Literal::from_json(&expected.to_json(), literal_type)?;
+                assert_eq!(expected, candidate);
+            }
+        }
+
+        // Check the representation of each kind of literal.
+        let check = |literal: &str, json: Json| {
+            let literal = Literal::<CurrentNetwork>::from_str(literal).unwrap();
+            assert_eq!(literal.to_json(), json);
+            assert_eq!(Literal::from_json(&json, &literal.to_type()).unwrap(), literal);
+        };
+        check("true", Json::from(true));
+        check("-5i8", Json::from(-5));
+        check("18446744073709551615u64", Json::from(u64::MAX));
+        check("18446744073709551616u128", Json::from("18446744073709551616"));
+        check("5field", Json::from("5"));
+        check("\"hello\"", Json::from("hello"));
+
+        // Ensure integers are accepted as strings.
+        assert_eq!(Literal::<CurrentNetwork>::from_json(&Json::from("5"), &LiteralType::U8)?.to_string(), "5u8");
+        // Ensure mismatched and out-of-range values are rejected.
+        assert!(Literal::<CurrentNetwork>::from_json(&Json::from(5), &LiteralType::Field).is_err());
+        assert!(Literal::<CurrentNetwork>::from_json(&Json::from(256), &LiteralType::U8).is_err());
+        assert!(Literal::<CurrentNetwork>::from_json(&Json::from("5u8"), &LiteralType::U8).is_err());
+        assert!(Literal::<CurrentNetwork>::from_json(&Json::from(true), &LiteralType::String).is_err());
+        Ok(())
+    }
+}
@@ -20,6 +20,7 @@ mod cast;
 mod cast_lossy;
 mod equal;
 mod from_bits;
+mod json;
 mod parse;
 mod sample;
 mod serialize;
@@ -0,0 +1,191 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{PlaintextType, StructType};
+
+use serde_json::{Map, Value as Json};
+
+impl<N: Network> Plaintext<N> {
+    /// Returns the plaintext as structured JSON, in the layout of the given plaintext type.
+    ///
+    /// Structs are JSON objects, arrays are JSON arrays, and literals are as given by `Literal::to_json`.
+    /// The definitions of the structs are resolved with `get_struct`.
+    pub fn to_json_typed<'a>(
+        &self,
+        plaintext_type: &PlaintextType<N>,
+        get_struct: &impl Fn(&Identifier<N>) -> Result<&'a StructType<N>>,
+    ) -> Result<Json>
+    where
+        N: 'a,
+    {
+        match (self, plaintext_type) {
+            (Self::Literal(literal, ..), PlaintextType::Literal(literal_type)) => {
+                // Ensure the literal is of the given type.
+                ensure!(
+                    literal.to_type() == *literal_type,
+                    "Expected a literal of type '{literal_type}', found '{literal}'"
+                );
+                Ok(literal.to_json())
+            }
+            (Self::Struct(members, ..), PlaintextType::Struct(struct_name)) => {
+                let struct_ = get_struct(struct_name)?;
+                // Ensure the number of members matches.
+                ensure!(
+                    members.len() == struct_.members().len(),
+                    "Expected {} members in struct '{struct_name}', found {}",
+                    struct_.members().len(),
+                    members.len()
+                );
+                // Convert the members, in the order of the struct definition.
+                let mut object = Map::with_capacity(members.len());
+                for (member_name, member_type) in struct_.members() {
+                    let member = members
+                        .get(member_name)
+                        .ok_or_else(|| anyhow!("Missing member '{member_name}' in struct '{struct_name}'"))?;
+                    object.insert(member_name.to_string(), member.to_json_typed(member_type, get_struct)?);
+                }
+                Ok(Json::Object(object))
+            }
+            (Self::Array(elements, ..), PlaintextType::Array(array_type)) => {
+                // Ensure the number of elements matches.
+                ensure!(
+                    elements.len() == **array_type.length() as usize,
+                    "Expected {} elements in array '{plaintext_type}', found {}",
+                    **array_type.length(),
+                    elements.len()
+                );
+                let element_type = array_type.next_element_type();
+                elements
+                    .iter()
+                    .map(|element| element.to_json_typed(element_type, get_struct))
+                    .collect::<Result<Vec<_>>>()
+                    .map(Json::Array)
+            }
+            _ => bail!("Expected a plaintext of type '{plaintext_type}', found '{self}'"),
+        }
+    }
+
+    /// Returns the plaintext of the given plaintext type from structured JSON, in the layout of `Plaintext::to_json_typed`.
+    /// The definitions of the structs are resolved with `get_struct`.
+    pub fn from_json_typed<'a>(
+        json: &Json,
+        plaintext_type: &PlaintextType<N>,
+        get_struct: &impl Fn(&Identifier<N>) -> Result<&'a StructType<N>>,
+    ) -> Result<Self>
+    where
+        N: 'a,
+    {
+        match plaintext_type {
+            PlaintextType::Literal(literal_type) => Ok(Self::from(Literal::from_json(json, literal_type)?)),
+            PlaintextType::Struct(struct_name) => {
+                let struct_ = get_struct(struct_name)?;
+                let Json::Object(object) = json else { bail!("Expected a JSON object for struct '{struct_name}'") };
+                // Ensure the number of members matches.
+                ensure!(
+                    object.len() == struct_.members().len(),
+                    "Expected {} members in struct '{struct_name}', found {}",
+                    struct_.members().len(),
+                    object.len()
+                );
+                // Convert the members, in the order of the struct definition.
+                let members = struct_
+                    .members()
+                    .iter()
+                    .map(|(member_name, member_type)| {
+                        let member = object
+                            .get(&member_name.to_string())
+                            .ok_or_else(|| anyhow!("Missing member '{member_name}' in struct '{struct_name}'"))?;
+                        Ok((*member_name, Self::from_json_typed(member, member_type, get_struct)?))
+                    })
+                    .collect::<Result<IndexMap<_, _>>>()?;
+                Ok(Self::Struct(members, Default::default()))
+            }
+            PlaintextType::Array(array_type) => {
+                let Json::Array(elements) = json else { bail!("Expected a JSON array for array '{plaintext_type}'") };
+                // Ensure the number of elements matches.
+                ensure!(
+                    elements.len() == **array_type.length() as usize,
+                    "Expected {} elements in array '{plaintext_type}', found {}",
+                    **array_type.length(),
+                    elements.len()
+                );
+                let element_type = array_type.next_element_type();
+                let elements = elements
+                    .iter()
+                    .map(|element| Self::from_json_typed(element, element_type, get_struct))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(Self::Array(elements, Default::default()))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_json_typed() -> Result<()> {
+        let struct_ = StructType::<CurrentNetwork>::from_str(
+            r"
+struct message:
+    sender as address;
+    amounts as [u64; 2u32];
+    flag as boolean;",
+        )?;
+        let get_struct = |name: &Identifier<CurrentNetwork>| match name == struct_.name() {
+            true => Ok(&struct_),
+            false => bail!("Struct '{name}' is not defined"),
+        };
+
+        // Check a struct.
+        let plaintext_type = PlaintextType::<CurrentNetwork>::from_str("message")?;
+        let expected = Plaintext::<CurrentNetwork>::from_str(
+            "{ sender: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah, amounts: [1u64, 2u64], flag: true }",
+        )?;
+        let json = expected.to_json_typed(&plaintext_type, &get_struct)?;
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "sender": "aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah",
+                "amounts": [1, 2],
+                "flag": true
+            })
+        );
+        assert_eq!(Plaintext::from_json_typed(&json, &plaintext_type, &get_struct)?, expected);
+
+        // Check an array of structs.
+        let plaintext_type = PlaintextType::<CurrentNetwork>::from_str("[message; 2u32]")?;
+        let expected = Plaintext::<CurrentNetwork>::from_str(&format!("[{expected}, {expected}]"))?;
+        let json = expected.to_json_typed(&plaintext_type, &get_struct)?;
+        assert_eq!(Plaintext::from_json_typed(&json, &plaintext_type, &get_struct)?, expected);
+
+        // Ensure mismatched plaintexts and JSON values are rejected.
+        let plaintext_type = PlaintextType::<CurrentNetwork>::from_str("message")?;
+        assert!(Plaintext::<CurrentNetwork>::from_str("1u64")?.to_json_typed(&plaintext_type, &get_struct).is_err());
+        let json = serde_json::json!({ "sender": "aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah" });
+        assert!(Plaintext::from_json_typed(&json, &plaintext_type, &get_struct).is_err());
+        let json = serde_json::json!([1, 2, 3]);
+        let plaintext_type = PlaintextType::<CurrentNetwork>::from_str("[u64; 2u32]")?;
+        assert!(Plaintext::from_json_typed(&json, &plaintext_type, &get_struct).is_err());
+        // Ensure undefined structs are rejected.
+        let plaintext_type = PlaintextType::<CurrentNetwork>::from_str("token")?;
+        assert!(Plaintext::from_json_typed(&serde_json::json!({}), &plaintext_type, &get_struct).is_err());
+        Ok(())
+    }
+}
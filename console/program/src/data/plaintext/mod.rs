@@ -18,9 +18,11 @@ mod equal;
 mod find;
 mod from_bits;
 mod from_fields;
+mod json;
 mod num_randomizers;
 mod parse;
 mod serialize;
+mod size_in_bytes;
 mod size_in_fields;
 mod to_bits;
 mod to_fields;
@@ -0,0 +1,127 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_console_account::Signature;
+
+impl<N: Network> Plaintext<N> {
+    /// Returns the number of bytes in the byte encoding of `self`, without serializing it.
+    /// This method errors if `self` cannot be encoded (i.e. a struct member exceeds `u16::MAX` bytes).
+    pub fn size_in_bytes(&self) -> Result<usize> {
+        match self {
+            // The variant, and the literal.
+            Self::Literal(literal, ..) => Ok(1 + literal_size_in_bytes(literal)),
+            Self::Struct(members, ..) => {
+                // Ensure the number of members fits in a `u8`.
+                ensure!(members.len() <= u8::MAX as usize, "Plaintext struct exceeds u8::MAX members");
+                // The variant, and the number of members.
+                let mut num_bytes = 1 + 1;
+                for (identifier, member) in members {
+                    // The identifier (with its length), the number of bytes in the member, and the member.
+                    num_bytes += 1 + identifier.size_in_bits() as usize / 8 + 2 + size_in_u16_bytes(member)?;
+                }
+                Ok(num_bytes)
+            }
+            Self::Array(elements, ..) => {
+                // The variant, and the number of elements.
+                let mut num_bytes = 1 + 4;
+                for element in elements {
+                    // The number of bytes in the element, and the element.
+                    num_bytes += 2 + size_in_u16_bytes(element)?;
+                }
+                Ok(num_bytes)
+            }
+        }
+    }
+}
+
+/// Returns the number of bytes in the byte encoding of the given nested plaintext,
+/// ensuring it is small enough for its length to be encoded as a `u16`.
+fn size_in_u16_bytes<N: Network>(plaintext: &Plaintext<N>) -> Result<usize> {
+    let num_bytes = plaintext.size_in_bytes()?;
+    ensure!(num_bytes <= u16::MAX as usize, "Plaintext member exceeds u16::MAX bytes");
+    Ok(num_bytes)
+}
+
+/// Returns the number of bytes in the byte encoding of the given literal.
+fn literal_size_in_bytes<N: Network>(literal: &Literal<N>) -> usize {
+    // The variant of the literal is encoded as a `u16`.
+    2 + match literal {
+        Literal::Address(..) => Address::<N>::size_in_bytes(),
+        Literal::Boolean(..) => Boolean::<N>::size_in_bytes(),
+        Literal::Field(..) => Field::<N>::size_in_bytes(),
+        Literal::Group(..) => Group::<N>::size_in_bytes(),
+        Literal::I8(..) => I8::<N>::size_in_bytes(),
+        Literal::I16(..) => I16::<N>::size_in_bytes(),
+        Literal::I32(..) => I32::<N>::size_in_bytes(),
+        Literal::I64(..) => I64::<N>::size_in_bytes(),
+        Literal::I128(..) => I128::<N>::size_in_bytes(),
+        Literal::U8(..) => U8::<N>::size_in_bytes(),
+        Literal::U16(..) => U16::<N>::size_in_bytes(),
+        Literal::U32(..) => U32::<N>::size_in_bytes(),
+        Literal::U64(..) => U64::<N>::size_in_bytes(),
+        Literal::U128(..) => U128::<N>::size_in_bytes(),
+        Literal::Scalar(..) => Scalar::<N>::size_in_bytes(),
+        Literal::Signature(..) => Signature::<N>::size_in_bytes(),
+        // The length of the string is encoded as a `u16`.
+        Literal::String(string) => 2 + string.len(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    const ITERATIONS: u32 = 100;
+
+    fn check_size_in_bytes(plaintext: &Plaintext<CurrentNetwork>) -> Result<()> {
+        assert_eq!(plaintext.size_in_bytes()?, plaintext.to_bytes_le()?.len());
+        Ok(())
+    }
+
+    #[test]
+    fn test_size_in_bytes() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let private_key = snarkvm_console_account::PrivateKey::<CurrentNetwork>::new(rng)?;
+            let signature = private_key.sign(&[Uniform::rand(rng)], rng)?;
+
+            for literal in [
+                Literal::<CurrentNetwork>::Address(Address::try_from(private_key)?),
+                Literal::Boolean(Boolean::new(Uniform::rand(rng))),
+                Literal::Field(Uniform::rand(rng)),
+                Literal::Group(Uniform::rand(rng)),
+                Literal::I8(I8::new(Uniform::rand(rng))),
+                Literal::I128(I128::new(Uniform::rand(rng))),
+                Literal::U64(U64::new(Uniform::rand(rng))),
+                Literal::Scalar(Uniform::rand(rng)),
+                Literal::Signature(Box::new(signature)),
+                Literal::String(StringType::rand(rng)),
+            ] {
+                check_size_in_bytes(&Plaintext::from(literal))?;
+            }
+        }
+
+        // Check a nested struct and array.
+        check_size_in_bytes(&Plaintext::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah, amounts: [ 1u64, 2u64 ], inner: { flag: true, label: \"hello\" } }",
+        )?)?;
+        check_size_in_bytes(&Plaintext::from_str("[ [ 1u8, 2u8 ], [ 3u8, 4u8 ], [ 5u8, 6u8 ] ]")?)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,125 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Record<N, Plaintext<N>> {
+    /// Encrypts `self` for the given auditor under the given randomizer.
+    ///
+    /// The auditor ciphertext shares the nonce of the record, so the auditor decrypts it with their
+    /// own view key, without learning the view key of the record owner.
+    pub fn encrypt_for_auditor(&self, randomizer: Scalar<N>, auditor: &Address<N>) -> Result<Record<N, Ciphertext<N>>> {
+        // Ensure the randomizer corresponds to the record nonce.
+        if self.nonce == N::g_scalar_multiply(&randomizer) {
+            // Compute the record view key of the auditor.
+            let record_view_key = (**auditor * randomizer).to_x_coordinate();
+            // Encrypt the record.
+            self.encrypt_symmetric_unchecked(&record_view_key)
+        } else {
+            bail!(
+                "Illegal operation: Record::encrypt_for_auditor() randomizer does not correspond to the record nonce."
+            )
+        }
+    }
+}
+
+impl<N: Network> Record<N, Ciphertext<N>> {
+    /// Decrypts `self` as the auditor ciphertext of a record, using the view key of the auditor,
+    /// and checks that the plaintext opens the given record commitment.
+    pub fn decrypt_as_auditor(
+        &self,
+        view_key: &ViewKey<N>,
+        program_id: &ProgramID<N>,
+        record_name: &Identifier<N>,
+        commitment: &Field<N>,
+    ) -> Result<Record<N, Plaintext<N>>> {
+        // Compute the record view key of the auditor.
+        let record_view_key = (self.nonce * **view_key).to_x_coordinate();
+        // Decrypt the record.
+        let record = self.decrypt_symmetric_unchecked(&record_view_key)?;
+        // Ensure the plaintext opens the record commitment.
+        match record.to_commitment(program_id, record_name)? == *commitment {
+            true => Ok(record),
+            false => {
+                bail!("Illegal operation: Record::decrypt_as_auditor() plaintext does not match the record commitment.")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_encrypt_for_auditor() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let program_id = ProgramID::<CurrentNetwork>::from_str("token.aleo")?;
+        let record_name = Identifier::from_str("token")?;
+
+        for _ in 0..ITERATIONS {
+            // Sample the owner and the auditor.
+            let owner_private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let owner_view_key = ViewKey::try_from(&owner_private_key)?;
+            let owner = Address::try_from(&owner_private_key)?;
+            let auditor_private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let auditor_view_key = ViewKey::try_from(&auditor_private_key)?;
+            let auditor = Address::try_from(&auditor_private_key)?;
+
+            // Prepare the record.
+            let randomizer = Scalar::rand(&mut rng);
+            let record = Record {
+                owner: Owner::Private(Plaintext::from(Literal::Address(owner))),
+                data: IndexMap::from_iter(vec![(
+                    Identifier::from_str("amount")?,
+                    Entry::Private(Plaintext::from(Literal::Field(Field::rand(&mut rng)))),
+                )]),
+                nonce: CurrentNetwork::g_scalar_multiply(&randomizer),
+            };
+            let commitment = record.to_commitment(&program_id, &record_name)?;
+
+            // Encrypt the record for the owner and the auditor.
+            let ciphertext = record.encrypt(randomizer)?;
+            let auditor_ciphertext = record.encrypt_for_auditor(randomizer, &auditor)?;
+            assert_eq!(ciphertext.nonce(), auditor_ciphertext.nonce());
+
+            // Ensure the auditor decrypts the record, and the owner does not need to share their view key.
+            assert_eq!(
+                record,
+                auditor_ciphertext.decrypt_as_auditor(&auditor_view_key, &program_id, &record_name, &commitment)?
+            );
+            assert_eq!(record, ciphertext.decrypt(&owner_view_key)?);
+
+            // Ensure the auditor ciphertext is rejected with another view key.
+            assert!(auditor_ciphertext
+                .decrypt_as_auditor(&owner_view_key, &program_id, &record_name, &commitment)
+                .is_err());
+            // Ensure the auditor ciphertext is rejected against another commitment.
+            let other_commitment = Field::rand(&mut rng);
+            assert!(auditor_ciphertext
+                .decrypt_as_auditor(&auditor_view_key, &program_id, &record_name, &other_commitment)
+                .is_err());
+
+            // Ensure an incorrect randomizer is rejected.
+            assert!(record.encrypt_for_auditor(Scalar::rand(&mut rng), &auditor).is_err());
+        }
+        Ok(())
+    }
+}
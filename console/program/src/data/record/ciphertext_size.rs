@@ -0,0 +1,75 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network, Private: Visibility> Record<N, Private> {
+    /// Returns the number of bytes in the byte encoding of the encrypted record, without encrypting or serializing it.
+    /// For a `Record<N, Ciphertext<N>>`, this is the number of bytes in the byte encoding of `self`.
+    pub fn ciphertext_size(&self) -> Result<usize> {
+        // The owner, which is an address if public, and a ciphertext of one field element if private.
+        let mut num_bytes = 1 + match self.owner.is_public() {
+            true => Address::<N>::size_in_bytes(),
+            false => 2 + Field::<N>::size_in_bytes(),
+        };
+        // The number of entries.
+        num_bytes += 1;
+        for (identifier, entry) in &self.data {
+            // The entry, where private entries are encrypted to one field element per randomizer.
+            let entry_size = 1 + match entry {
+                Entry::Constant(plaintext) | Entry::Public(plaintext) => plaintext.size_in_bytes()?,
+                Entry::Private(..) => 2 + entry.num_randomizers()? as usize * Field::<N>::size_in_bytes(),
+            };
+            ensure!(entry_size <= u16::MAX as usize, "Record entry '{identifier}' exceeds u16::MAX bytes");
+            // The identifier (with its length), the number of bytes in the entry, and the entry.
+            num_bytes += 1 + identifier.size_in_bits() as usize / 8 + 2 + entry_size;
+        }
+        // The nonce.
+        Ok(num_bytes + Group::<N>::size_in_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_ciphertext_size() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for (owner, entries) in [
+            ("private", "token_amount: 100u64.private"),
+            ("public", "token_amount: 100u64.public, memo: [ 1u8.constant, 2u8.constant, 3u8.constant ]"),
+            ("private", "data: { a: 1field.private, b: [ true.private, false.private ] }"),
+        ] {
+            // Sample a record with a valid nonce.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let address = Address::try_from(&private_key)?;
+            let randomizer = Uniform::rand(rng);
+            let nonce = CurrentNetwork::g_scalar_multiply(&randomizer);
+            let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(&format!(
+                "{{ owner: {address}.{owner}, {entries}, _nonce: {nonce}.public }}"
+            ))?;
+
+            // Check the size matches the encrypted record.
+            let ciphertext = record.encrypt(randomizer)?;
+            assert_eq!(record.ciphertext_size()?, ciphertext.to_bytes_le()?.len());
+            assert_eq!(ciphertext.ciphertext_size()?, ciphertext.to_bytes_le()?.len());
+        }
+        Ok(())
+    }
+}
@@ -42,4 +42,17 @@ impl<N: Network> Record<N, Plaintext<N>> {
             bail!("Attempted to find record entry with an empty path.")
         }
     }
+
+    /// Returns the amount of microcredits in the record.
+    /// Note: This method only checks that the record contains a `microcredits` entry of type `u64`.
+    pub fn microcredits(&self) -> Result<u64> {
+        match self.data.get(&Identifier::from_str("microcredits")?) {
+            Some(
+                Entry::Constant(Plaintext::Literal(Literal::U64(amount), _))
+                | Entry::Public(Plaintext::Literal(Literal::U64(amount), _))
+                | Entry::Private(Plaintext::Literal(Literal::U64(amount), _)),
+            ) => Ok(**amount),
+            _ => bail!("The record does not contain a 'microcredits' entry of type 'u64'"),
+        }
+    }
 }
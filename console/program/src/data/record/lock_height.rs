@@ -0,0 +1,50 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use snarkvm_console_types::U32;
+
+impl<N: Network> Record<N, Plaintext<N>> {
+    /// Returns the lock height of the record, from the given entry of a time-locked record type.
+    /// Note: This method only checks that the record contains the entry as a public `u32`.
+    pub fn lock_height(&self, entry_name: &Identifier<N>) -> Result<U32<N>> {
+        match self.data.get(entry_name) {
+            Some(Entry::Public(Plaintext::Literal(Literal::U32(lock_height), _))) => Ok(*lock_height),
+            _ => bail!("The record does not contain a '{entry_name}' entry of type 'u32.public'"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_lock_height() -> Result<()> {
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, amount: 100u64.private, release: 10u32.public, hidden: 10u32.private, _nonce: 0group.public }",
+        )?;
+        // Ensure the lock height is read from a public 'u32' entry.
+        assert_eq!(*record.lock_height(&Identifier::from_str("release")?)?, 10);
+        // Ensure the lock height is not read from a private entry, another type, or a missing entry.
+        assert!(record.lock_height(&Identifier::from_str("hidden")?).is_err());
+        assert!(record.lock_height(&Identifier::from_str("amount")?).is_err());
+        assert!(record.lock_height(&Identifier::from_str("missing")?).is_err());
+        Ok(())
+    }
+}
@@ -18,12 +18,15 @@ pub use entry::Entry;
 mod helpers;
 pub use helpers::Owner;
 
+mod audit;
 mod bytes;
+mod ciphertext_size;
 mod decrypt;
 mod encrypt;
 mod equal;
 mod find;
 mod is_owner;
+mod lock_height;
 mod num_randomizers;
 mod parse_ciphertext;
 mod parse_plaintext;
@@ -33,6 +36,7 @@ mod tag;
 mod to_bits;
 mod to_commitment;
 mod to_fields;
+mod view_tag;
 
 use crate::{Access, Ciphertext, Identifier, Literal, Plaintext, ProgramID};
 use snarkvm_console_account::{Address, PrivateKey, ViewKey};
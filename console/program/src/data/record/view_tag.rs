@@ -0,0 +1,114 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Record<N, Plaintext<N>> {
+    /// Encrypts `self` for the record owner under the given randomizer, and returns the record ciphertext and its view tag.
+    /// Note: The view tag is derived from the record view key that is already computed for encryption,
+    /// so it only costs one additional hash.
+    pub fn encrypt_with_view_tag(&self, randomizer: Scalar<N>) -> Result<(Record<N, Ciphertext<N>>, u8)> {
+        // Ensure the randomizer corresponds to the record nonce.
+        ensure!(
+            self.nonce == N::g_scalar_multiply(&randomizer),
+            "Illegal operation: Record::encrypt_with_view_tag() randomizer does not correspond to the record nonce."
+        );
+        // Compute the record view key.
+        let record_view_key = (**self.owner * randomizer).to_x_coordinate();
+        // Encrypt the record.
+        let record = self.encrypt_symmetric_unchecked(&record_view_key)?;
+        // Compute the view tag.
+        let view_tag = Self::view_tag_from_record_view_key(&record_view_key)?;
+        // Return the record ciphertext and view tag.
+        Ok((record, view_tag))
+    }
+}
+
+impl<N: Network> Record<N, Ciphertext<N>> {
+    /// Returns `true` if the given view tag matches the view tag of the record for the given view key.
+    ///
+    /// A scanner compares view tags before checking ownership, to discard ~99.6% of records
+    /// that are not owned by the view key without decrypting them.
+    /// A match does **not** imply ownership, which must still be checked with `Self::is_owner`.
+    pub fn is_view_tag_match(&self, view_key: &ViewKey<N>, view_tag: u8) -> bool {
+        // Compute the record view key.
+        let record_view_key = (self.nonce * **view_key).to_x_coordinate();
+        // Compare the view tags.
+        match Self::view_tag_from_record_view_key(&record_view_key) {
+            Ok(candidate) => candidate == view_tag,
+            Err(_) => false,
+        }
+    }
+
+    /// Returns the record checksum, as `Hash(record ciphertext || view tag)`.
+    /// Note: This binds the view tag to the record output, so it can not be altered or stripped.
+    pub fn to_checksum(&self, view_tag: u8) -> Result<Field<N>> {
+        N::hash_bhp1024(&to_bits_le![self, view_tag])
+    }
+}
+
+impl<N: Network, Private: Visibility> Record<N, Private> {
+    /// Returns the view tag for the given record view key, as the first byte of `Hash(domain || record_view_key)`.
+    pub fn view_tag_from_record_view_key(record_view_key: &Field<N>) -> Result<u8> {
+        // Hash the record view key.
+        let hash = N::hash_psd2(&[N::view_tag_domain(), *record_view_key])?;
+        // Return the first byte of the hash.
+        Ok(hash.to_bytes_le()?[0])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_view_tag() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample an owner and an unrelated account.
+            let view_key = ViewKey::try_from(PrivateKey::<CurrentNetwork>::new(&mut rng)?)?;
+            let other_view_key = ViewKey::try_from(PrivateKey::<CurrentNetwork>::new(&mut rng)?)?;
+
+            // Construct the record.
+            let randomizer = Scalar::rand(&mut rng);
+            let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(&format!(
+                "{{ owner: {}.private, amount: 100u64.private, _nonce: {}.public }}",
+                view_key.to_address(),
+                CurrentNetwork::g_scalar_multiply(&randomizer)
+            ))?;
+            let (ciphertext, view_tag) = record.encrypt_with_view_tag(randomizer)?;
+            // Ensure the record ciphertext matches the one from `Record::encrypt`.
+            assert_eq!(ciphertext, record.encrypt(randomizer)?);
+
+            // Ensure the view tag matches for the owner.
+            assert!(ciphertext.is_view_tag_match(&view_key, view_tag));
+            assert!(!ciphertext.is_view_tag_match(&view_key, view_tag.wrapping_add(1)));
+            // Ensure an unrelated account does not own the record, regardless of its view tag.
+            assert!(!ciphertext.is_owner(&other_view_key));
+
+            // Ensure the checksum binds the view tag.
+            assert_ne!(ciphertext.to_checksum(view_tag)?, ciphertext.to_checksum(view_tag.wrapping_add(1))?);
+
+            // Ensure a randomizer that does not correspond to the nonce is rejected.
+            assert!(record.encrypt_with_view_tag(Scalar::rand(&mut rng)).is_err());
+        }
+        Ok(())
+    }
+}
@@ -17,6 +17,7 @@ mod equal;
 mod find;
 mod parse;
 mod serialize;
+mod size_in_fields;
 mod to_bits;
 mod to_fields;
 
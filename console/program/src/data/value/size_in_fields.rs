@@ -0,0 +1,56 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Value<N> {
+    /// Returns the number of field elements in `self.to_fields()`, without packing the fields.
+    pub fn size_in_fields(&self) -> Result<u16> {
+        // Compute the number of bits.
+        let num_bits = match self {
+            Self::Plaintext(plaintext) => return plaintext.size_in_fields(),
+            Self::Record(record) => record.to_bits_le().len(),
+            Self::Future(future) => future.to_bits_le().len(),
+        } + 1; // 1 extra bit for the terminus indicator.
+        // Compute the ceiling division of the number of bits by the number of bits in a field element.
+        let num_fields = (num_bits + Field::<N>::size_in_data_bits() - 1) / Field::<N>::size_in_data_bits();
+        // Ensure the number of field elements does not exceed the maximum allowed size.
+        match num_fields <= N::MAX_DATA_SIZE_IN_FIELDS as usize {
+            // Return the number of field elements.
+            true => Ok(u16::try_from(num_fields).or_halt_with::<N>("Value exceeds u16::MAX field elements.")),
+            false => bail!("Value cannot exceed {} field elements.", N::MAX_DATA_SIZE_IN_FIELDS),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_size_in_fields() -> Result<()> {
+        for string in [
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah, token_amount: 100u64 }",
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, token_amount: 100u64.private, _nonce: 0group.public }",
+            "{ program_id: credits.aleo, function_name: transfer_public_to_private, arguments: [ aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah, 100u64 ] }",
+        ] {
+            let value = Value::<CurrentNetwork>::from_str(string)?;
+            assert_eq!(value.size_in_fields()? as usize, value.to_fields()?.len());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,98 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> PlaintextType<N> {
+    /// Returns the number of bits of a plaintext of this type, as packed by `Plaintext::to_bits_le`.
+    /// This packing is the canonical input of the `commit` and `hash` instructions.
+    ///
+    /// For string literals, this method returns the maximum number of bits that can be stored in the string.
+    pub fn size_in_bits<'a>(&self, get_struct: &impl Fn(&Identifier<N>) -> Result<&'a StructType<N>>) -> Result<usize>
+    where
+        N: 'a,
+    {
+        match self {
+            // The variant bits, the literal variant (u8), the literal size (u16), and the literal.
+            Self::Literal(literal_type) => Ok(2 + 8 + 16 + literal_type.size_in_bits::<N>() as usize),
+            Self::Struct(struct_name) => {
+                // The variant bits and the struct length (u8).
+                let mut size = 2 + 8usize;
+                for (member_name, member_type) in get_struct(struct_name)?.members() {
+                    let member_size = member_type.size_in_bits(get_struct)?;
+                    // The identifier size (u8), the identifier, the member size (u16), and the member.
+                    size = [8 + member_name.size_in_bits() as usize + 16, member_size]
+                        .into_iter()
+                        .try_fold(size, usize::checked_add)
+                        .ok_or_else(|| anyhow!("The size of struct '{struct_name}' overflows"))?;
+                }
+                Ok(size)
+            }
+            Self::Array(array_type) => {
+                // The element size (u16) and the element.
+                let element_size = 16 + array_type.next_element_type().size_in_bits(get_struct)?;
+                // The variant bits, the array length (u32), and the elements.
+                (**array_type.length() as usize)
+                    .checked_mul(element_size)
+                    .and_then(|size| size.checked_add(2 + 32))
+                    .ok_or_else(|| anyhow!("The size of array '{self}' overflows"))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Plaintext;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_size_in_bits() -> Result<()> {
+        let struct_ = StructType::<CurrentNetwork>::from_str(
+            r"
+struct message:
+    sender as address;
+    amounts as [u64; 2u32];
+    flag as boolean;",
+        )?;
+        let get_struct = |name: &Identifier<CurrentNetwork>| match name == struct_.name() {
+            true => Ok(&struct_),
+            false => bail!("Struct '{name}' is not defined"),
+        };
+
+        // Check a literal.
+        let plaintext = Plaintext::<CurrentNetwork>::from_str("1u64")?;
+        let size = PlaintextType::<CurrentNetwork>::from_str("u64")?.size_in_bits(&get_struct)?;
+        assert_eq!(plaintext.to_bits_le().len(), size);
+
+        // Check a struct.
+        let plaintext = Plaintext::<CurrentNetwork>::from_str(
+            "{ sender: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah, amounts: [1u64, 2u64], flag: true }",
+        )?;
+        let size = PlaintextType::<CurrentNetwork>::from_str("message")?.size_in_bits(&get_struct)?;
+        assert_eq!(plaintext.to_bits_le().len(), size);
+
+        // Check an array of structs.
+        let plaintext = Plaintext::<CurrentNetwork>::from_str(&format!("[{plaintext}, {plaintext}, {plaintext}]"))?;
+        let size = PlaintextType::<CurrentNetwork>::from_str("[message; 3u32]")?.size_in_bits(&get_struct)?;
+        assert_eq!(plaintext.to_bits_le().len(), size);
+
+        // Check an undefined struct.
+        assert!(PlaintextType::<CurrentNetwork>::from_str("token")?.size_in_bits(&get_struct).is_err());
+        Ok(())
+    }
+}
@@ -14,11 +14,26 @@
 
 use super::*;
 
+/// The version of a time-locked record type.
+///
+/// Record types without a lock height retain the original encoding, which begins with the (non-zero) length
+/// of the record name, so that existing programs are unchanged. Time-locked record types are prefixed with
+/// a zero byte and this version, and are followed by the name of the lock height entry.
+const TIME_LOCKED_RECORD_TYPE_VERSION: u8 = 1;
+
 impl<N: Network> FromBytes for RecordType<N> {
     /// Reads a record type from a buffer.
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the length of the record name, or the zero byte of a time-locked record type.
+        let prefix = u8::read_le(&mut reader)?;
         // Read the name of the record type.
-        let name = Identifier::read_le(&mut reader)?;
+        let (name, is_time_locked) = match prefix {
+            0 => match u8::read_le(&mut reader)? {
+                TIME_LOCKED_RECORD_TYPE_VERSION => (Identifier::read_le(&mut reader)?, true),
+                _ => return Err(error("Invalid record type version")),
+            },
+            _ => (Identifier::read_le(&mut [prefix].as_slice().chain(&mut reader))?, false),
+        };
         // Read the visibility for the owner.
         let owner = PublicOrPrivate::read_le(&mut reader)?;
 
@@ -55,7 +70,15 @@ impl<N: Network> FromBytes for RecordType<N> {
             return Err(error("Failed to parse record: too many entries"));
         }
 
-        Ok(Self { name, owner, entries })
+        // Read the name of the lock height entry, if the record type is time-locked.
+        let lock_height = match is_time_locked {
+            true => Some(Identifier::read_le(&mut reader)?),
+            false => None,
+        };
+        // Ensure the lock height names a 'u32.public' entry.
+        check_lock_height(&entries, lock_height.as_ref()).map_err(|e| error(e.to_string()))?;
+
+        Ok(Self { name, owner, entries, lock_height })
     }
 }
 
@@ -67,6 +90,11 @@ impl<N: Network> ToBytes for RecordType<N> {
             return Err(error("Failed to serialize record: too many entries"));
         }
 
+        // Write the zero byte and version, if the record type is time-locked.
+        if self.lock_height.is_some() {
+            0u8.write_le(&mut writer)?;
+            TIME_LOCKED_RECORD_TYPE_VERSION.write_le(&mut writer)?;
+        }
         // Write the name of the record type.
         self.name.write_le(&mut writer)?;
         // Write the visibility for the owner.
@@ -81,7 +109,11 @@ impl<N: Network> ToBytes for RecordType<N> {
             // Write the value type to the buffer.
             value_type.write_le(&mut writer)?;
         }
-        Ok(())
+        // Write the name of the lock height entry, if the record type is time-locked.
+        match &self.lock_height {
+            Some(lock_height) => lock_height.write_le(&mut writer),
+            None => Ok(()),
+        }
     }
 }
 
@@ -101,4 +133,27 @@ mod tests {
         assert_eq!(expected, candidate);
         Ok(())
     }
+
+    #[test]
+    fn test_time_locked_bytes() -> Result<()> {
+        let expected = RecordType::<CurrentNetwork>::from_str(
+            "record vesting:\n    owner as address.private;\n    release as u32.public;\n    lock release;",
+        )?;
+        // Ensure a time-locked record type is prefixed with a zero byte and the version.
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(&expected_bytes[..2], &[0, TIME_LOCKED_RECORD_TYPE_VERSION]);
+        assert_eq!(expected, RecordType::from_bytes_le(&expected_bytes)?);
+
+        // Ensure a record type without a lock height retains the original encoding.
+        let unlocked = RecordType::<CurrentNetwork>::from_str(
+            "record vesting:\n    owner as address.private;\n    release as u32.public;",
+        )?;
+        assert_eq!(&expected_bytes[2..expected_bytes.len() - 8], &unlocked.to_bytes_le()?[..]);
+
+        // Ensure an unknown version is rejected.
+        let mut candidate_bytes = expected_bytes;
+        candidate_bytes[1] = TIME_LOCKED_RECORD_TYPE_VERSION + 1;
+        assert!(RecordType::<CurrentNetwork>::from_bytes_le(&candidate_bytes).is_err());
+        Ok(())
+    }
 }
@@ -22,7 +22,7 @@ mod bytes;
 mod parse;
 mod serialize;
 
-use crate::Identifier;
+use crate::{Identifier, LiteralType, PlaintextType};
 use snarkvm_console_network::prelude::*;
 
 use indexmap::IndexMap;
@@ -36,6 +36,8 @@ pub struct RecordType<N: Network> {
     owner: PublicOrPrivate,
     /// The name and value type for the entries in data.
     entries: IndexMap<Identifier<N>, EntryType<N>>,
+    /// The name of the entry that holds the lock height, if the record type is time-locked.
+    lock_height: Option<Identifier<N>>,
 }
 
 impl<N: Network> RecordType<N> {
@@ -53,6 +55,28 @@ impl<N: Network> RecordType<N> {
     pub const fn entries(&self) -> &IndexMap<Identifier<N>, EntryType<N>> {
         &self.entries
     }
+
+    /// Returns the name of the entry that holds the lock height, if the record type is time-locked.
+    ///
+    /// A record of a time-locked record type may only be spent once the latest block height is at least
+    /// its lock height. To allow the verifier to enforce this, the lock height is revealed when the record is spent.
+    pub const fn lock_height(&self) -> Option<&Identifier<N>> {
+        self.lock_height.as_ref()
+    }
+}
+
+/// Ensures the lock height of a time-locked record type names a `u32.public` entry.
+fn check_lock_height<N: Network>(
+    entries: &IndexMap<Identifier<N>, EntryType<N>>,
+    lock_height: Option<&Identifier<N>>,
+) -> Result<()> {
+    if let Some(lock_height) = lock_height {
+        match entries.get(lock_height) {
+            Some(EntryType::Public(PlaintextType::Literal(LiteralType::U32))) => (),
+            _ => bail!("The lock height '{lock_height}' must be an entry of type 'u32.public'"),
+        }
+    }
+    Ok(())
 }
 
 impl<N: Network> TypeName for RecordType<N> {
@@ -21,6 +21,13 @@ impl<N: Network> Parser for RecordType<N> {
     ///       owner as address.private;
     ///       user_defined as u64.public;
     /// ```
+    /// A time-locked record type names the `u32.public` entry that holds its lock height as:
+    /// ```text
+    ///   record vesting:
+    ///       owner as address.private;
+    ///       release as u32.public;
+    ///       lock release;
+    /// ```
     #[inline]
     fn parse(string: &str) -> ParserResult<Self> {
         /// Parses a string into a tuple.
@@ -45,6 +52,24 @@ impl<N: Network> Parser for RecordType<N> {
             Ok((string, (identifier, value_type)))
         }
 
+        /// Parses a string into the name of the lock height entry.
+        fn parse_lock_height<N: Network>(string: &str) -> ParserResult<Identifier<N>> {
+            // Parse the whitespace and comments from the string.
+            let (string, _) = Sanitizer::parse(string)?;
+            // Parse the "lock" keyword from the string.
+            let (string, _) = tag("lock")(string)?;
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the identifier from the string.
+            let (string, identifier) = Identifier::parse(string)?;
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the semicolon ';' keyword from the string.
+            let (string, _) = tag(";")(string)?;
+            // Return the identifier.
+            Ok((string, identifier))
+        }
+
         // Parse the whitespace and comments from the string.
         let (string, _) = Sanitizer::parse(string)?;
         // Parse the type name from the string.
@@ -90,11 +115,16 @@ impl<N: Network> Parser for RecordType<N> {
             if entries.len() > N::MAX_DATA_ENTRIES {
                 return Err(error("Failed to parse record: too many entries"));
             }
-            Ok(entries)
+            Ok(IndexMap::from_iter(entries))
+        })(string)?;
+
+        // Parse the name of the lock height entry from the string, if the record type is time-locked.
+        let (string, lock_height) = map_res(opt(parse_lock_height), |lock_height| {
+            check_lock_height(&entries, lock_height.as_ref()).map(|_| lock_height)
         })(string)?;
 
         // Return the record type.
-        Ok((string, Self { name, owner, entries: IndexMap::from_iter(entries) }))
+        Ok((string, Self { name, owner, entries, lock_height }))
     }
 }
 
@@ -127,7 +157,13 @@ impl<N: Network> Display for RecordType<N> {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         write!(f, "{} {}:", Self::type_name(), self.name)?;
         write!(f, "\n    owner as address.{};", self.owner)?;
-        self.entries.iter().try_for_each(|(entry_name, entry_type)| write!(f, "\n    {entry_name} as {entry_type};"))
+        self.entries
+            .iter()
+            .try_for_each(|(entry_name, entry_type)| write!(f, "\n    {entry_name} as {entry_type};"))?;
+        match &self.lock_height {
+            Some(lock_height) => write!(f, "\n    lock {lock_height};"),
+            None => Ok(()),
+        }
     }
 }
 
@@ -147,6 +183,7 @@ mod tests {
                 Identifier::from_str("first")?,
                 EntryType::from_str("field.constant")?,
             )]),
+            lock_height: None,
         };
 
         let (remainder, candidate) = RecordType::<CurrentNetwork>::parse(
@@ -161,6 +198,34 @@ record message:
         Ok(())
     }
 
+    #[test]
+    fn test_parse_lock_height() -> Result<()> {
+        // Ensure a time-locked record type names its lock height entry.
+        let candidate = RecordType::<CurrentNetwork>::from_str(
+            "record vesting:\n    owner as address.private;\n    lock as u64.private;\n    release as u32.public;\n    lock release;",
+        )?;
+        assert_eq!(candidate.lock_height(), Some(&Identifier::from_str("release")?));
+        assert_eq!(candidate.entries().len(), 2);
+        assert_eq!(
+            "record vesting:\n    owner as address.private;\n    lock as u64.private;\n    release as u32.public;\n    lock release;",
+            candidate.to_string()
+        );
+
+        // Ensure the lock height must name an existing 'u32.public' entry.
+        for entry in ["release as u32.private;", "release as u64.public;", "other as u32.public;"] {
+            let candidate = RecordType::<CurrentNetwork>::from_str(&format!(
+                "record vesting:\n    owner as address.private;\n    {entry}\n    lock release;"
+            ));
+            assert!(candidate.is_err());
+        }
+        // Ensure the lock height is declared after the entries.
+        let candidate = RecordType::<CurrentNetwork>::from_str(
+            "record vesting:\n    owner as address.private;\n    lock release;\n    release as u32.public;",
+        );
+        assert!(candidate.is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_parse_fails() {
         // Must be non-empty.
@@ -0,0 +1,291 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{Entry, Identifier, Literal, Plaintext, ProgramID, Record};
+use snarkvm_console_account::ViewKey;
+use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::Field;
+
+use core::ops::Deref;
+
+/// The predicate that a disclosed record entry satisfies against the disclosed value.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DisclosureOperator {
+    /// The entry is equal to the value.
+    Eq,
+    /// The entry is not equal to the value.
+    Neq,
+    /// The entry is less than the value.
+    Lt,
+    /// The entry is less than or equal to the value.
+    Lte,
+    /// The entry is greater than the value.
+    Gt,
+    /// The entry is greater than or equal to the value.
+    Gte,
+}
+
+impl DisclosureOperator {
+    /// Returns `true` if `entry` and `value` satisfy the operator.
+    pub fn evaluate<T, B>(&self, entry: &T, value: &T) -> bool
+    where
+        T: Equal<Output = B> + Compare<Output = B>,
+        B: Deref<Target = bool>,
+    {
+        match self {
+            Self::Eq => *entry.is_equal(value),
+            Self::Neq => *entry.is_not_equal(value),
+            Self::Lt => *entry.is_less_than(value),
+            Self::Lte => *entry.is_less_than_or_equal(value),
+            Self::Gt => *entry.is_greater_than(value),
+            Self::Gte => *entry.is_greater_than_or_equal(value),
+        }
+    }
+}
+
+impl Display for DisclosureOperator {
+    /// Prints the operator as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Eq => write!(f, "=="),
+            Self::Neq => write!(f, "!="),
+            Self::Lt => write!(f, "<"),
+            Self::Lte => write!(f, "<="),
+            Self::Gt => write!(f, ">"),
+            Self::Gte => write!(f, ">="),
+        }
+    }
+}
+
+/// A statement that a top-level entry of the record behind a commitment satisfies a predicate,
+/// e.g. `amount >= 100u64`, without revealing the record or the entry itself.
+///
+/// The prover also shows knowledge of the view key of the record owner, so only the owner
+/// (or a holder of their view key) can make a disclosure about the record.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Disclosure<N: Network> {
+    /// The program ID of the record.
+    program_id: ProgramID<N>,
+    /// The name of the record.
+    record_name: Identifier<N>,
+    /// The commitment of the record.
+    commitment: Field<N>,
+    /// The name of the disclosed entry.
+    entry_name: Identifier<N>,
+    /// The predicate operator.
+    operator: DisclosureOperator,
+    /// The value the entry is compared against.
+    value: Literal<N>,
+}
+
+impl<N: Network> Disclosure<N> {
+    /// Initializes a new disclosure.
+    pub fn new(
+        program_id: ProgramID<N>,
+        record_name: Identifier<N>,
+        commitment: Field<N>,
+        entry_name: Identifier<N>,
+        operator: DisclosureOperator,
+        value: Literal<N>,
+    ) -> Result<Self> {
+        // Ensure the operator is defined for the value type.
+        match (operator, &value) {
+            (DisclosureOperator::Eq | DisclosureOperator::Neq, _) => (),
+            (_, Literal::Field(..) | Literal::Scalar(..)) => (),
+            (_, Literal::I8(..) | Literal::I16(..) | Literal::I32(..) | Literal::I64(..) | Literal::I128(..)) => (),
+            (_, Literal::U8(..) | Literal::U16(..) | Literal::U32(..) | Literal::U64(..) | Literal::U128(..)) => (),
+            (operator, value) => bail!("The '{operator}' operator is not defined for '{value}'"),
+        }
+        Ok(Self { program_id, record_name, commitment, entry_name, operator, value })
+    }
+
+    /// Returns the program ID of the record.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the name of the record.
+    pub const fn record_name(&self) -> &Identifier<N> {
+        &self.record_name
+    }
+
+    /// Returns the commitment of the record.
+    pub const fn commitment(&self) -> &Field<N> {
+        &self.commitment
+    }
+
+    /// Returns the name of the disclosed entry.
+    pub const fn entry_name(&self) -> &Identifier<N> {
+        &self.entry_name
+    }
+
+    /// Returns the predicate operator.
+    pub const fn operator(&self) -> DisclosureOperator {
+        self.operator
+    }
+
+    /// Returns the value the entry is compared against.
+    pub const fn value(&self) -> &Literal<N> {
+        &self.value
+    }
+
+    /// Returns the hash of the value, which binds the value to the proof.
+    pub fn to_value_hash(&self) -> Result<Field<N>> {
+        N::hash_psd8(&Plaintext::from(self.value.clone()).to_fields()?)
+    }
+
+    /// Returns the public inputs of the disclosure proof.
+    pub fn to_verifier_inputs(&self) -> Result<Vec<N::Field>> {
+        Ok(vec![N::Field::one(), *self.commitment, *self.to_value_hash()?])
+    }
+
+    /// Returns `true` if the given record opens the commitment, is owned by the given view key,
+    /// and its entry satisfies the predicate.
+    pub fn holds_for(&self, view_key: &ViewKey<N>, record: &Record<N, Plaintext<N>>) -> Result<bool> {
+        // Ensure the view key corresponds to the record owner.
+        if view_key.to_address() != **record.owner() {
+            return Ok(false);
+        }
+        // Ensure the record opens the commitment.
+        if record.to_commitment(&self.program_id, &self.record_name)? != self.commitment {
+            return Ok(false);
+        }
+        // Retrieve the disclosed entry, which must be a literal.
+        let entry = match record.data().get(&self.entry_name) {
+            Some(Entry::Constant(Plaintext::Literal(literal, _)))
+            | Some(Entry::Public(Plaintext::Literal(literal, _)))
+            | Some(Entry::Private(Plaintext::Literal(literal, _))) => literal,
+            Some(_) => bail!("Record entry '{}' is not a literal", self.entry_name),
+            None => bail!("Record entry '{}' not found", self.entry_name),
+        };
+        // Evaluate the predicate.
+        Ok(evaluate(self.operator, entry, &self.value))
+    }
+}
+
+/// Returns `true` if `entry` and `value` satisfy the operator.
+/// Note: Literals of different types never satisfy the predicate.
+fn evaluate<N: Network>(operator: DisclosureOperator, entry: &Literal<N>, value: &Literal<N>) -> bool {
+    match (operator, entry, value) {
+        (DisclosureOperator::Eq, a, b) => *a.is_equal(b),
+        (DisclosureOperator::Neq, a, b) => *a.is_not_equal(b),
+        (operator, Literal::Field(a), Literal::Field(b)) => operator.evaluate(a, b),
+        (operator, Literal::Scalar(a), Literal::Scalar(b)) => operator.evaluate(a, b),
+        (operator, Literal::I8(a), Literal::I8(b)) => operator.evaluate(a, b),
+        (operator, Literal::I16(a), Literal::I16(b)) => operator.evaluate(a, b),
+        (operator, Literal::I32(a), Literal::I32(b)) => operator.evaluate(a, b),
+        (operator, Literal::I64(a), Literal::I64(b)) => operator.evaluate(a, b),
+        (operator, Literal::I128(a), Literal::I128(b)) => operator.evaluate(a, b),
+        (operator, Literal::U8(a), Literal::U8(b)) => operator.evaluate(a, b),
+        (operator, Literal::U16(a), Literal::U16(b)) => operator.evaluate(a, b),
+        (operator, Literal::U32(a), Literal::U32(b)) => operator.evaluate(a, b),
+        (operator, Literal::U64(a), Literal::U64(b)) => operator.evaluate(a, b),
+        (operator, Literal::U128(a), Literal::U128(b)) => operator.evaluate(a, b),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_disclosure() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let program_id = ProgramID::<CurrentNetwork>::from_str("token.aleo")?;
+        let record_name = Identifier::from_str("token")?;
+        let amount = Identifier::from_str("amount")?;
+
+        // Prepare the record.
+        let private_key = snarkvm_console_account::PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+        let view_key = ViewKey::try_from(&private_key)?;
+        let address = snarkvm_console_account::Address::try_from(&private_key)?;
+        let nonce = CurrentNetwork::g_scalar_multiply(&Uniform::rand(&mut rng));
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(&format!(
+            "{{ owner: {address}.private, amount: 150u64.private, memo: {{ id: 1field.private }}, _nonce: {nonce}.public }}"
+        ))?;
+        let commitment = record.to_commitment(&program_id, &record_name)?;
+
+        let check = |operator, value: &str| -> Result<bool> {
+            Disclosure::new(program_id, record_name, commitment, amount, operator, Literal::from_str(value)?)?
+                .holds_for(&view_key, &record)
+        };
+
+        // Ensure the predicates are evaluated on the entry.
+        assert!(check(DisclosureOperator::Gte, "100u64")?);
+        assert!(check(DisclosureOperator::Gte, "150u64")?);
+        assert!(!check(DisclosureOperator::Gt, "150u64")?);
+        assert!(check(DisclosureOperator::Lt, "151u64")?);
+        assert!(!check(DisclosureOperator::Lte, "149u64")?);
+        assert!(check(DisclosureOperator::Eq, "150u64")?);
+        assert!(check(DisclosureOperator::Neq, "100u64")?);
+        // Ensure a value of another type never satisfies the predicate.
+        assert!(!check(DisclosureOperator::Gte, "100u128")?);
+        assert!(!check(DisclosureOperator::Eq, "150u32")?);
+
+        // Ensure the operator must be defined for the value type.
+        assert!(check(DisclosureOperator::Gte, "true").is_err());
+
+        // Ensure the disclosure does not hold for another commitment.
+        let disclosure = Disclosure::new(
+            program_id,
+            record_name,
+            Uniform::rand(&mut rng),
+            amount,
+            DisclosureOperator::Gte,
+            Literal::from_str("100u64")?,
+        )?;
+        assert!(!disclosure.holds_for(&view_key, &record)?);
+
+        // Ensure the disclosure does not hold for another view key.
+        let disclosure = Disclosure::new(
+            program_id,
+            record_name,
+            commitment,
+            amount,
+            DisclosureOperator::Gte,
+            Literal::from_str("100u64")?,
+        )?;
+        let other_view_key = ViewKey::try_from(&snarkvm_console_account::PrivateKey::<CurrentNetwork>::new(&mut rng)?)?;
+        assert!(disclosure.holds_for(&view_key, &record)?);
+        assert!(!disclosure.holds_for(&other_view_key, &record)?);
+
+        // Ensure only top-level literal entries can be disclosed.
+        let memo = Identifier::from_str("memo")?;
+        let disclosure = Disclosure::new(
+            program_id,
+            record_name,
+            commitment,
+            memo,
+            DisclosureOperator::Eq,
+            Literal::from_str("1field")?,
+        )?;
+        assert!(disclosure.holds_for(&view_key, &record).is_err());
+        let missing = Identifier::from_str("missing")?;
+        let disclosure = Disclosure::new(
+            program_id,
+            record_name,
+            commitment,
+            missing,
+            DisclosureOperator::Eq,
+            Literal::from_str("1field")?,
+        )?;
+        assert!(disclosure.holds_for(&view_key, &record).is_err());
+        Ok(())
+    }
+}
@@ -24,12 +24,18 @@ pub use snarkvm_console_account::Signature;
 pub use snarkvm_console_network::Network;
 pub use snarkvm_console_types::prelude::*;
 
+mod aleo_value;
+pub use aleo_value::*;
+
 mod data;
 pub use data::*;
 
 mod data_types;
 pub use data_types::*;
 
+mod disclosure;
+pub use disclosure::*;
+
 mod function_id;
 pub use function_id::*;
 
@@ -48,5 +54,8 @@ pub use request::*;
 mod response;
 pub use response::*;
 
+mod schema;
+pub use schema::*;
+
 pub mod state_path;
 pub use state_path::*;
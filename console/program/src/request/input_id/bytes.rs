@@ -38,10 +38,25 @@ impl<N: Network> FromBytes for InputID<N> {
                 // Read the tag value.
                 let tag = Field::read_le(&mut reader)?;
                 // Return the record input.
-                Ok(Self::Record(commitment, gamma, serial_number, tag))
+                Ok(Self::Record(commitment, gamma, serial_number, tag, None))
             }
             // External record input.
             4 => Ok(Self::ExternalRecord(Field::read_le(&mut reader)?)),
+            // Time-locked record input.
+            5 => {
+                // Read the commitment.
+                let commitment = Field::read_le(&mut reader)?;
+                // Read the gamma value.
+                let gamma = Group::read_le(&mut reader)?;
+                // Read the serial number.
+                let serial_number = Field::read_le(&mut reader)?;
+                // Read the tag value.
+                let tag = Field::read_le(&mut reader)?;
+                // Read the lock height.
+                let lock_height = U32::read_le(&mut reader)?;
+                // Return the record input.
+                Ok(Self::Record(commitment, gamma, serial_number, tag, Some(lock_height)))
+            }
             // Invalid input.
             _ => Err(error("Invalid input ID variant")),
         }
@@ -74,9 +89,12 @@ impl<N: Network> ToBytes for InputID<N> {
                 value.write_le(&mut writer)
             }
             // Record input.
-            Self::Record(commitment, gamma, serial_number, tag) => {
+            Self::Record(commitment, gamma, serial_number, tag, lock_height) => {
                 // Write the variant.
-                3u8.write_le(&mut writer)?;
+                match lock_height {
+                    None => 3u8.write_le(&mut writer)?,
+                    Some(_) => 5u8.write_le(&mut writer)?,
+                }
                 // Write the commitment.
                 commitment.write_le(&mut writer)?;
                 // Write the gamma value.
@@ -84,7 +102,12 @@ impl<N: Network> ToBytes for InputID<N> {
                 // Write the serial number.
                 serial_number.write_le(&mut writer)?;
                 // Write the tag value.
-                tag.write_le(&mut writer)
+                tag.write_le(&mut writer)?;
+                // Write the lock height, if the record is time-locked.
+                match lock_height {
+                    Some(lock_height) => lock_height.write_le(&mut writer),
+                    None => Ok(()),
+                }
             }
             // External record input.
             Self::ExternalRecord(value) => {
@@ -27,8 +27,8 @@ pub enum InputID<N: Network> {
     Public(Field<N>),
     /// The ciphertext hash of the private input.
     Private(Field<N>),
-    /// The commitment, gamma, serial number, and tag of the record input.
-    Record(Field<N>, Group<N>, Field<N>, Field<N>),
+    /// The commitment, gamma, serial number, tag, and (optional) lock height of the record input.
+    Record(Field<N>, Group<N>, Field<N>, Field<N>, Option<U32<N>>),
     /// The hash of the external record input.
     ExternalRecord(Field<N>),
 }
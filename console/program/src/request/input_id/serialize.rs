@@ -39,13 +39,16 @@ impl<N: Network> Serialize for InputID<N> {
                     input.serialize_field("id", &id)?;
                     input.end()
                 }
-                Self::Record(commitment, gamma, serial_number, tag) => {
-                    let mut input = serializer.serialize_struct("InputID", 5)?;
+                Self::Record(commitment, gamma, serial_number, tag, lock_height) => {
+                    let mut input = serializer.serialize_struct("InputID", 5 + lock_height.is_some() as usize)?;
                     input.serialize_field("type", "record")?;
                     input.serialize_field("commitment", &commitment)?;
                     input.serialize_field("gamma", &gamma)?;
                     input.serialize_field("serial_number", &serial_number)?;
                     input.serialize_field("tag", &tag)?;
+                    if let Some(lock_height) = lock_height {
+                        input.serialize_field("lock_height", &lock_height)?;
+                    }
                     input.end()
                 }
                 Self::ExternalRecord(id) => {
@@ -77,6 +80,10 @@ impl<'de, N: Network> Deserialize<'de> for InputID<N> {
                         DeserializeExt::take_from_value::<D>(&mut input, "gamma")?,
                         DeserializeExt::take_from_value::<D>(&mut input, "serial_number")?,
                         DeserializeExt::take_from_value::<D>(&mut input, "tag")?,
+                        match input.get("lock_height") {
+                            Some(_) => Some(DeserializeExt::take_from_value::<D>(&mut input, "lock_height")?),
+                            None => None,
+                        },
                     ),
                     Some("external_record") => {
                         InputID::ExternalRecord(DeserializeExt::take_from_value::<D>(&mut input, "id")?)
@@ -104,6 +111,7 @@ mod tests {
         "{\"type\":\"private\",\"id\":\"123field\"}",
         "{\"type\":\"record\",\"commitment\":\"123123field\",\"tag\":\"0field\",\"serial_number\":\"123456789field\",\"gamma\":\"0group\"}",
         "{\"type\":\"external_record\",\"id\":\"123456789field\"}",
+        "{\"type\":\"record\",\"commitment\":\"123123field\",\"gamma\":\"0group\",\"serial_number\":\"123456789field\",\"tag\":\"0field\",\"lock_height\":\"10u32\"}",
     ];
 
     fn check_serde_json<
@@ -27,6 +27,39 @@ impl<N: Network> Request<N> {
         root_tvk: Option<Field<N>>,
         is_root: bool,
         rng: &mut R,
+    ) -> Result<Self> {
+        Self::sign_with_consumed_records(
+            private_key,
+            program_id,
+            function_name,
+            inputs,
+            input_types,
+            &[],
+            &[],
+            root_tvk,
+            is_root,
+            rng,
+        )
+    }
+
+    /// Returns the request for a given private key, program ID, function name, inputs, input types, and RNG,
+    /// where the external record inputs at the given indices are consumed by this request,
+    /// and the record inputs at the given indices are time-locked by the given entry.
+    ///
+    /// A consumed external record input is computed to its serial number, using the commitment under
+    /// the program that produced the record, instead of being hashed (using `tvk`) to a field element.
+    /// A time-locked record input reveals its lock height, so that the verifier can enforce it.
+    pub fn sign_with_consumed_records<R: Rng + CryptoRng>(
+        private_key: &PrivateKey<N>,
+        program_id: ProgramID<N>,
+        function_name: Identifier<N>,
+        inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
+        input_types: &[ValueType<N>],
+        consumed_external_records: &[usize],
+        time_locked_records: &[(usize, Identifier<N>)],
+        root_tvk: Option<Field<N>>,
+        is_root: bool,
+        rng: &mut R,
     ) -> Result<Self> {
         // Ensure the number of inputs matches the number of input types.
         if input_types.len() != inputs.len() {
@@ -36,6 +69,21 @@ impl<N: Network> Request<N> {
                 inputs.len()
             )
         }
+        // Ensure the consumed inputs are external record inputs.
+        for index in consumed_external_records {
+            ensure!(
+                matches!(input_types.get(*index), Some(ValueType::ExternalRecord(..))),
+                "Input #{index} of '{program_id}/{function_name}' is not an external record, and cannot be consumed"
+            );
+        }
+        // Ensure the time-locked inputs are record inputs that are spent by this request.
+        for (index, _) in time_locked_records {
+            ensure!(
+                matches!(input_types.get(*index), Some(ValueType::Record(..)))
+                    || consumed_external_records.contains(index),
+                "Input #{index} of '{program_id}/{function_name}' is not a spent record, and cannot be time-locked"
+            );
+        }
 
         // Retrieve `sk_sig`.
         let sk_sig = private_key.sk_sig();
@@ -162,8 +210,17 @@ impl<N: Network> Request<N> {
                     // Add the input hash to the inputs.
                     input_ids.push(InputID::Private(input_hash));
                 }
-                // A record input is computed to its serial number.
-                ValueType::Record(record_name) => {
+                // A record input, or a consumed external record input, is computed to its serial number.
+                ValueType::Record(..) | ValueType::ExternalRecord(..)
+                    if matches!(input_type, ValueType::Record(..)) || consumed_external_records.contains(&index) =>
+                {
+                    // Retrieve the program ID and record name of the record.
+                    let (record_program_id, record_name) = match input_type {
+                        ValueType::Record(record_name) => (&program_id, record_name),
+                        // An external record is committed under the program that produced it.
+                        ValueType::ExternalRecord(locator) => (locator.program_id(), locator.resource()),
+                        _ => bail!("Expected a record type at input {index}"),
+                    };
                     // Retrieve the record.
                     let record = match &input {
                         Value::Record(record) => record,
@@ -175,7 +232,7 @@ impl<N: Network> Request<N> {
                     ensure!(**record.owner() == signer, "Input record for '{program_id}' must belong to the signer");
 
                     // Compute the record commitment.
-                    let commitment = record.to_commitment(&program_id, record_name)?;
+                    let commitment = record.to_commitment(record_program_id, record_name)?;
 
                     // Compute the generator `H` as `HashToGroup(commitment)`.
                     let h = N::hash_to_group_psd2(&[N::serial_number_domain(), commitment])?;
@@ -193,8 +250,14 @@ impl<N: Network> Request<N> {
                     message.extend([h, h_r, gamma].iter().map(|point| point.to_x_coordinate()));
                     message.push(tag);
 
+                    // Retrieve the lock height, if the record is time-locked.
+                    let lock_height = match time_locked_records.iter().find(|(locked, _)| *locked == index) {
+                        Some((_, entry_name)) => Some(record.lock_height(entry_name)?),
+                        None => None,
+                    };
+
                     // Add the input ID.
-                    input_ids.push(InputID::Record(commitment, gamma, serial_number, tag));
+                    input_ids.push(InputID::Record(commitment, gamma, serial_number, tag, lock_height));
                 }
                 // An external record input is hashed (using `tvk`) to a field element.
                 ValueType::ExternalRecord(..) => {
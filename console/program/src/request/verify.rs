@@ -131,8 +131,9 @@ impl<N: Network> Request<N> {
                         // Add the input hash to the message.
                         message.push(candidate_hash);
                     }
-                    // A record input is computed to its serial number.
-                    InputID::Record(commitment, gamma, serial_number, tag) => {
+                    // A record input, or a consumed external record input, is computed to its serial number.
+                    // Note: The lock height of a time-locked record is checked against its record type by the stack.
+                    InputID::Record(commitment, gamma, serial_number, tag, _) => {
                         // Retrieve the record.
                         let record = match &input {
                             Value::Record(record) => record,
@@ -140,9 +141,11 @@ impl<N: Network> Request<N> {
                             Value::Plaintext(..) => bail!("Expected a record input, found a plaintext input"),
                             Value::Future(..) => bail!("Expected a record input, found a future input"),
                         };
-                        // Retrieve the record name.
-                        let record_name = match input_type {
-                            ValueType::Record(record_name) => record_name,
+                        // Retrieve the program ID and record name of the record.
+                        let (record_program_id, record_name) = match input_type {
+                            ValueType::Record(record_name) => (&self.program_id, record_name),
+                            // An external record is committed under the program that produced it.
+                            ValueType::ExternalRecord(locator) => (locator.program_id(), locator.resource()),
                             // Ensure the input type is a record.
                             _ => bail!("Expected a record type at input {index}"),
                         };
@@ -150,7 +153,7 @@ impl<N: Network> Request<N> {
                         ensure!(**record.owner() == self.signer, "Input record does not belong to the signer");
 
                         // Compute the record commitment.
-                        let candidate_cm = record.to_commitment(&self.program_id, record_name)?;
+                        let candidate_cm = record.to_commitment(record_program_id, record_name)?;
                         // Ensure the commitment matches.
                         ensure!(*commitment == candidate_cm, "Expected a record input with the same commitment");
 
@@ -272,4 +275,124 @@ mod tests {
             assert!(request.verify(&input_types, is_root));
         }
     }
+
+    #[test]
+    fn test_sign_and_verify_consumed_external_record() {
+        let rng = &mut TestRng::default();
+
+        // Sample a random private key and address.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let address = Address::try_from(&private_key).unwrap();
+
+        // Construct a program ID and function name.
+        let program_id = ProgramID::from_str("wallet.aleo").unwrap();
+        let function_name = Identifier::from_str("burn").unwrap();
+
+        // Prepare a record belonging to the address.
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(&format!(
+            "{{ owner: {address}.private, token_amount: 100u64.private, _nonce: 2293253577170800572742339369209137467208538700597121244293392265726446806023group.public }}"
+        ))
+        .unwrap();
+        let inputs = [Value::Record(record.clone())];
+
+        // Construct the input types.
+        let input_types = vec![ValueType::from_str("token.aleo/token.record").unwrap()];
+
+        // Compute the signed request, consuming the external record.
+        let request = Request::sign_with_consumed_records(
+            &private_key,
+            program_id,
+            function_name,
+            inputs.into_iter(),
+            &input_types,
+            &[0],
+            &[],
+            None,
+            true,
+            rng,
+        )
+        .unwrap();
+        assert!(request.verify(&input_types, true));
+
+        // Ensure the commitment is computed under the program that produced the record.
+        let expected_commitment =
+            record.to_commitment(&ProgramID::from_str("token.aleo").unwrap(), &Identifier::from_str("token").unwrap());
+        match &request.input_ids()[0] {
+            InputID::Record(commitment, ..) => assert_eq!(*commitment, expected_commitment.unwrap()),
+            _ => panic!("Expected the external record to be consumed"),
+        }
+        // Ensure the record is not time-locked.
+        assert!(matches!(&request.input_ids()[0], InputID::Record(.., None)));
+
+        // Ensure a non-external record input cannot be consumed.
+        let input_types = vec![ValueType::from_str("token.record").unwrap()];
+        let inputs = [Value::Record(record)];
+        assert!(Request::sign_with_consumed_records(
+            &private_key,
+            program_id,
+            function_name,
+            inputs.into_iter(),
+            &input_types,
+            &[0],
+            &[],
+            None,
+            true,
+            rng,
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_sign_and_verify_time_locked_record() {
+        let rng = &mut TestRng::default();
+
+        // Sample a random private key and address.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let address = Address::try_from(&private_key).unwrap();
+
+        // Construct a program ID and function name.
+        let program_id = ProgramID::from_str("vesting.aleo").unwrap();
+        let function_name = Identifier::from_str("unlock").unwrap();
+
+        // Prepare a time-locked record belonging to the address.
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(&format!(
+            "{{ owner: {address}.private, amount: 100u64.private, release: 10u32.public, _nonce: 2293253577170800572742339369209137467208538700597121244293392265726446806023group.public }}"
+        ))
+        .unwrap();
+        let input_types = vec![ValueType::from_str("vesting.record").unwrap()];
+        let release = Identifier::from_str("release").unwrap();
+
+        // Compute the signed request, revealing the lock height of the record.
+        let request = Request::sign_with_consumed_records(
+            &private_key,
+            program_id,
+            function_name,
+            [Value::Record(record.clone())].into_iter(),
+            &input_types,
+            &[],
+            &[(0, release)],
+            None,
+            true,
+            rng,
+        )
+        .unwrap();
+        assert!(request.verify(&input_types, true));
+        assert!(matches!(&request.input_ids()[0], InputID::Record(.., Some(lock_height)) if **lock_height == 10));
+
+        // Ensure a record without the lock height entry cannot be time-locked.
+        let amount = Identifier::from_str("amount").unwrap();
+        assert!(Request::sign_with_consumed_records(
+            &private_key,
+            program_id,
+            function_name,
+            [Value::Record(record)].into_iter(),
+            &input_types,
+            &[],
+            &[(0, amount)],
+            None,
+            true,
+            rng,
+        )
+        .is_err());
+    }
 }
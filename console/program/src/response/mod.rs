@@ -16,6 +16,11 @@ use crate::{compute_function_id, Identifier, ProgramID, Register, Value, ValueTy
 use snarkvm_console_network::Network;
 use snarkvm_console_types::prelude::*;
 
+#[cfg(feature = "trace")]
+mod trace;
+#[cfg(feature = "trace")]
+pub use trace::*;
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum OutputID<N: Network> {
     /// The hash of the constant output.
@@ -159,10 +164,10 @@ impl<N: Network> Response<N> {
                         // Compute the encryption randomizer as `HashToScalar(tvk || index)`.
                         let randomizer = N::hash_to_scalar_psd2(&[*tvk, index])?;
 
-                        // Encrypt the record, using the randomizer.
-                        let encrypted_record = record.encrypt(randomizer)?;
-                        // Compute the record checksum, as the hash of the encrypted record.
-                        let checksum = N::hash_bhp1024(&encrypted_record.to_bits_le())?;
+                        // Encrypt the record and compute its view tag, using the randomizer.
+                        let (encrypted_record, view_tag) = record.encrypt_with_view_tag(randomizer)?;
+                        // Compute the record checksum, as the hash of the encrypted record and view tag.
+                        let checksum = encrypted_record.to_checksum(view_tag)?;
 
                         // Return the output ID.
                         Ok(OutputID::Record(commitment, checksum))
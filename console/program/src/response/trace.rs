@@ -0,0 +1,92 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A structured record of the outputs of a response, for consumption by debuggers and playgrounds.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ResponseTrace<N: Network> {
+    /// The output kinds and values, in order.
+    outputs: Vec<(&'static str, Value<N>)>,
+}
+
+impl<N: Network> ResponseTrace<N> {
+    /// Returns the output kinds and values, in order.
+    pub fn outputs(&self) -> &[(&'static str, Value<N>)] {
+        &self.outputs
+    }
+}
+
+impl<N: Network> OutputID<N> {
+    /// Returns the kind of the output, as a string.
+    pub const fn kind(&self) -> &'static str {
+        match self {
+            Self::Constant(..) => "constant",
+            Self::Public(..) => "public",
+            Self::Private(..) => "private",
+            Self::Record(..) => "record",
+            Self::ExternalRecord(..) => "external_record",
+            Self::Future(..) => "future",
+        }
+    }
+}
+
+impl<N: Network> Response<N> {
+    /// Returns a structured record of the outputs of the response.
+    pub fn to_trace(&self) -> ResponseTrace<N> {
+        ResponseTrace {
+            outputs: self
+                .output_ids
+                .iter()
+                .zip_eq(&self.outputs)
+                .map(|(id, value)| (id.kind(), value.clone()))
+                .collect(),
+        }
+    }
+}
+
+impl<N: Network> Serialize for ResponseTrace<N> {
+    /// Serializes the response trace into a string.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut outputs = serializer.serialize_seq(Some(self.outputs.len()))?;
+        for (kind, value) in &self.outputs {
+            outputs.serialize_element(&serde_json::json!({ "type": kind, "value": value.to_string() }))?;
+        }
+        outputs.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_response_to_trace() {
+        let outputs =
+            vec![Value::<CurrentNetwork>::from_str("1u8").unwrap(), Value::<CurrentNetwork>::from_str("2u8").unwrap()];
+        let output_ids = vec![OutputID::Public(Field::from_u8(1)), OutputID::Constant(Field::from_u8(2))];
+        let response = Response::from((output_ids, outputs.clone()));
+
+        // Ensure the trace records the kind and value of each output.
+        let trace = response.to_trace();
+        assert_eq!(trace.outputs(), &[("public", outputs[0].clone()), ("constant", outputs[1].clone())]);
+
+        // Ensure the trace serializes.
+        let string = serde_json::to_string(&trace).unwrap();
+        assert_eq!(string, r#"[{"type":"public","value":"1u8"},{"type":"constant","value":"2u8"}]"#);
+    }
+}
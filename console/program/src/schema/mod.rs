@@ -0,0 +1,311 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    Entry,
+    EntryType,
+    Identifier,
+    Literal,
+    LiteralType,
+    Owner,
+    Plaintext,
+    PlaintextType,
+    Record,
+    RecordType,
+    StructType,
+    Value,
+    ValueType,
+};
+use snarkvm_console_network::prelude::*;
+
+use indexmap::IndexMap;
+use serde_json::{Map, Value as Json};
+
+/// The name of the JSON member that holds the nonce of a record.
+const RECORD_NONCE: &str = "_nonce";
+
+/// A schema of struct and record definitions, for converting values to and from structured JSON,
+/// and to and from serde-compatible Rust types.
+///
+/// Plaintexts are converted as given by `Plaintext::to_json_typed`. Records are JSON objects with their
+/// `owner`, their entries, and their nonce in `_nonce`; the visibility of each entry follows the record definition.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ValueSchema<N: Network> {
+    /// The struct definitions.
+    structs: IndexMap<Identifier<N>, StructType<N>>,
+    /// The record definitions.
+    records: IndexMap<Identifier<N>, RecordType<N>>,
+}
+
+impl<N: Network> ValueSchema<N> {
+    /// Initializes a new schema from the given struct and record definitions.
+    pub fn new(
+        structs: impl IntoIterator<Item = StructType<N>>,
+        records: impl IntoIterator<Item = RecordType<N>>,
+    ) -> Self {
+        Self {
+            structs: structs.into_iter().map(|struct_| (*struct_.name(), struct_)).collect(),
+            records: records.into_iter().map(|record| (*record.name(), record)).collect(),
+        }
+    }
+
+    /// Returns the struct definitions.
+    pub const fn structs(&self) -> &IndexMap<Identifier<N>, StructType<N>> {
+        &self.structs
+    }
+
+    /// Returns the record definitions.
+    pub const fn records(&self) -> &IndexMap<Identifier<N>, RecordType<N>> {
+        &self.records
+    }
+
+    /// Returns the struct definition for the given name.
+    pub fn get_struct(&self, name: &Identifier<N>) -> Result<&StructType<N>> {
+        self.structs.get(name).ok_or_else(|| anyhow!("Struct '{name}' is not defined in the schema"))
+    }
+
+    /// Returns the record definition for the given name.
+    pub fn get_record(&self, name: &Identifier<N>) -> Result<&RecordType<N>> {
+        self.records.get(name).ok_or_else(|| anyhow!("Record '{name}' is not defined in the schema"))
+    }
+}
+
+impl<N: Network> ValueSchema<N> {
+    /// Returns the given plaintext as structured JSON.
+    pub fn plaintext_to_json(&self, plaintext: &Plaintext<N>, plaintext_type: &PlaintextType<N>) -> Result<Json> {
+        plaintext.to_json_typed(plaintext_type, &|name| self.get_struct(name))
+    }
+
+    /// Returns the plaintext of the given type from structured JSON.
+    pub fn plaintext_from_json(&self, json: &Json, plaintext_type: &PlaintextType<N>) -> Result<Plaintext<N>> {
+        Plaintext::from_json_typed(json, plaintext_type, &|name| self.get_struct(name))
+    }
+
+    /// Returns the given record as structured JSON.
+    pub fn record_to_json(&self, record: &Record<N, Plaintext<N>>, record_name: &Identifier<N>) -> Result<Json> {
+        let record_type = self.get_record(record_name)?;
+        // Ensure the number of entries matches.
+        ensure!(
+            record.data().len() == record_type.entries().len(),
+            "Expected {} entries in record '{record_name}', found {}",
+            record_type.entries().len(),
+            record.data().len()
+        );
+
+        let mut object = Map::with_capacity(record_type.entries().len() + 2);
+        object.insert("owner".to_string(), Literal::Address(**record.owner()).to_json());
+        // Convert the entries, in the order of the record definition.
+        for (entry_name, entry_type) in record_type.entries() {
+            let plaintext = match record.data().get(entry_name) {
+                Some(Entry::Constant(plaintext) | Entry::Public(plaintext) | Entry::Private(plaintext)) => plaintext,
+                None => bail!("Missing entry '{entry_name}' in record '{record_name}'"),
+            };
+            object.insert(entry_name.to_string(), self.plaintext_to_json(plaintext, entry_type.plaintext_type())?);
+        }
+        object.insert(RECORD_NONCE.to_string(), Literal::Group(*record.nonce()).to_json());
+        Ok(Json::Object(object))
+    }
+
+    /// Returns the record of the given name from structured JSON.
+    pub fn record_from_json(&self, json: &Json, record_name: &Identifier<N>) -> Result<Record<N, Plaintext<N>>> {
+        let record_type = self.get_record(record_name)?;
+        let Json::Object(object) = json else { bail!("Expected a JSON object for record '{record_name}'") };
+        // Ensure the number of entries matches, including the owner and the nonce.
+        ensure!(
+            object.len() == record_type.entries().len() + 2,
+            "Expected {} entries in record '{record_name}', found {}",
+            record_type.entries().len(),
+            object.len().saturating_sub(2)
+        );
+
+        // Retrieve the given member of the JSON object.
+        let get = |name: &str| object.get(name).ok_or_else(|| anyhow!("Missing '{name}' in record '{record_name}'"));
+
+        // Convert the owner, with the visibility of the record definition.
+        let owner = match Literal::<N>::from_json(get("owner")?, &LiteralType::Address)? {
+            Literal::Address(address) => address,
+            _ => unreachable!("The literal is an address"),
+        };
+        let owner = match record_type.owner().is_public() {
+            true => Owner::Public(owner),
+            false => Owner::Private(Plaintext::from(Literal::Address(owner))),
+        };
+        // Convert the entries, with the visibility of the record definition.
+        let data = record_type
+            .entries()
+            .iter()
+            .map(|(entry_name, entry_type)| {
+                let plaintext = self.plaintext_from_json(get(&entry_name.to_string())?, entry_type.plaintext_type())?;
+                let entry = match entry_type {
+                    EntryType::Constant(..) => Entry::Constant(plaintext),
+                    EntryType::Public(..) => Entry::Public(plaintext),
+                    EntryType::Private(..) => Entry::Private(plaintext),
+                };
+                Ok((*entry_name, entry))
+            })
+            .collect::<Result<IndexMap<_, _>>>()?;
+        // Convert the nonce.
+        let nonce = match Literal::<N>::from_json(get(RECORD_NONCE)?, &LiteralType::Group)? {
+            Literal::Group(nonce) => nonce,
+            _ => unreachable!("The literal is a group element"),
+        };
+        Record::<N, Plaintext<N>>::from_plaintext(owner, data, nonce)
+    }
+
+    /// Returns the given value as structured JSON.
+    /// Note: Futures and external records are not supported, as their definitions are not in the schema.
+    pub fn value_to_json(&self, value: &Value<N>, value_type: &ValueType<N>) -> Result<Json> {
+        match (value, value_type) {
+            (
+                Value::Plaintext(plaintext),
+                ValueType::Constant(plaintext_type)
+                | ValueType::Public(plaintext_type)
+                | ValueType::Private(plaintext_type),
+            ) => self.plaintext_to_json(plaintext, plaintext_type),
+            (Value::Record(record), ValueType::Record(record_name)) => self.record_to_json(record, record_name),
+            _ => bail!("Cannot convert a value of type '{value_type}' with the schema"),
+        }
+    }
+
+    /// Returns the value of the given type from structured JSON.
+    /// Note: Futures and external records are not supported, as their definitions are not in the schema.
+    pub fn value_from_json(&self, json: &Json, value_type: &ValueType<N>) -> Result<Value<N>> {
+        match value_type {
+            ValueType::Constant(plaintext_type)
+            | ValueType::Public(plaintext_type)
+            | ValueType::Private(plaintext_type) => {
+                Ok(Value::Plaintext(self.plaintext_from_json(json, plaintext_type)?))
+            }
+            ValueType::Record(record_name) => Ok(Value::Record(self.record_from_json(json, record_name)?)),
+            _ => bail!("Cannot convert a value of type '{value_type}' with the schema"),
+        }
+    }
+
+    /// Returns the given plaintext as a serde-compatible Rust type, through its structured JSON.
+    pub fn to_typed<T: DeserializeOwned>(
+        &self,
+        plaintext: &Plaintext<N>,
+        plaintext_type: &PlaintextType<N>,
+    ) -> Result<T> {
+        Ok(serde_json::from_value(self.plaintext_to_json(plaintext, plaintext_type)?)?)
+    }
+
+    /// Returns the plaintext of the given type from a serde-compatible Rust type, through its structured JSON.
+    pub fn from_typed<T: Serialize>(&self, value: &T, plaintext_type: &PlaintextType<N>) -> Result<Plaintext<N>> {
+        self.plaintext_from_json(&serde_json::to_value(value)?, plaintext_type)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    use std::collections::BTreeMap;
+
+    type CurrentNetwork = MainnetV0;
+
+    /// Returns a schema with a sample struct and record definition.
+    fn sample_schema() -> ValueSchema<CurrentNetwork> {
+        let struct_ = StructType::from_str(
+            r"
+struct balances:
+    spendable as u64;
+    locked as u64;",
+        )
+        .unwrap();
+        let record = RecordType::from_str(
+            r"
+record token:
+    owner as address.private;
+    amount as u128.private;
+    issuer as address.public;
+    balances as balances.private;",
+        )
+        .unwrap();
+        ValueSchema::new([struct_], [record])
+    }
+
+    #[test]
+    fn test_plaintext() -> Result<()> {
+        let schema = sample_schema();
+
+        // Check a struct.
+        let plaintext_type = PlaintextType::from_str("balances")?;
+        let expected = Plaintext::from_str("{ spendable: 5u64, locked: 1u64 }")?;
+        let json = schema.plaintext_to_json(&expected, &plaintext_type)?;
+        assert_eq!(json, serde_json::json!({ "spendable": 5, "locked": 1 }));
+        assert_eq!(schema.plaintext_from_json(&json, &plaintext_type)?, expected);
+
+        // Check the same struct, as a Rust type.
+        let typed: BTreeMap<String, u64> = schema.to_typed(&expected, &plaintext_type)?;
+        assert_eq!(typed, BTreeMap::from([("spendable".to_string(), 5), ("locked".to_string(), 1)]));
+        assert_eq!(schema.from_typed(&typed, &plaintext_type)?, expected);
+
+        // Check an array, as a Rust type.
+        let plaintext_type = PlaintextType::from_str("[u8; 3u32]")?;
+        let expected = Plaintext::from_str("[1u8, 2u8, 3u8]")?;
+        assert_eq!(schema.to_typed::<Vec<u8>>(&expected, &plaintext_type)?, vec![1, 2, 3]);
+        assert_eq!(schema.from_typed(&[1u8, 2, 3], &plaintext_type)?, expected);
+        assert!(schema.from_typed(&[1u8, 2], &plaintext_type).is_err());
+
+        // Ensure undefined structs are rejected.
+        assert!(schema.plaintext_from_json(&serde_json::json!({}), &PlaintextType::from_str("message")?).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_record() -> Result<()> {
+        let schema = sample_schema();
+        let record_name = Identifier::from_str("token")?;
+
+        let expected = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(
+            r"{
+    owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private,
+    amount: 100u128.private,
+    issuer: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.public,
+    balances: {
+        spendable: 5u64.private,
+        locked: 1u64.private
+    },
+    _nonce: 0group.public
+}",
+        )?;
+
+        // Check the record.
+        let json = schema.record_to_json(&expected, &record_name)?;
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "owner": "aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah",
+                "amount": "100",
+                "issuer": "aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah",
+                "balances": { "spendable": 5, "locked": 1 },
+                "_nonce": "0"
+            })
+        );
+        assert_eq!(schema.record_from_json(&json, &record_name)?, expected);
+
+        // Check the record, as a value.
+        let value_type = ValueType::from_str("token.record")?;
+        let value = Value::Record(expected.clone());
+        assert_eq!(schema.value_from_json(&schema.value_to_json(&value, &value_type)?, &value_type)?, value);
+
+        // Ensure a record with a missing entry is rejected.
+        let mut json = json;
+        json.as_object_mut().unwrap().remove("issuer");
+        assert!(schema.record_from_json(&json, &record_name).is_err());
+        Ok(())
+    }
+}
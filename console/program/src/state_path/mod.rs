@@ -24,6 +24,9 @@ pub use transaction_leaf::*;
 pub mod transition_leaf;
 pub use transition_leaf::*;
 
+mod state_root_path;
+pub use state_root_path::*;
+
 mod bytes;
 mod parse;
 mod serialize;
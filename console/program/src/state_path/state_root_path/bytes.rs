@@ -0,0 +1,89 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for StateRootPath<N> {
+    /// Reads the path from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid state root path version"));
+        }
+
+        // Read the state root path.
+        let global_state_root = N::StateRoot::read_le(&mut reader)?;
+        let block_path = BlockPath::read_le(&mut reader)?;
+        let block_hash = N::BlockHash::read_le(&mut reader)?;
+        let previous_block_hash = N::BlockHash::read_le(&mut reader)?;
+        let header_root = Field::read_le(&mut reader)?;
+        let header_path = HeaderPath::read_le(&mut reader)?;
+        let header_leaf = HeaderLeaf::read_le(&mut reader)?;
+
+        // Construct the state root path.
+        Ok(Self::from(
+            global_state_root,
+            block_path,
+            block_hash,
+            previous_block_hash,
+            header_root,
+            header_path,
+            header_leaf,
+        ))
+    }
+}
+
+impl<N: Network> ToBytes for StateRootPath<N> {
+    /// Writes the path to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+
+        // Write the state root path.
+        self.global_state_root.write_le(&mut writer)?;
+        self.block_path.write_le(&mut writer)?;
+        self.block_hash.write_le(&mut writer)?;
+        self.previous_block_hash.write_le(&mut writer)?;
+        self.header_root.write_le(&mut writer)?;
+        self.header_path.write_le(&mut writer)?;
+        self.header_leaf.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    const ITERATIONS: usize = 10;
+
+    #[test]
+    fn test_bytes() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample the state root path.
+            let (_, expected) = test_helpers::sample_state_root_path::<CurrentNetwork>(&mut rng).unwrap();
+
+            // Check the byte representation.
+            let expected_bytes = expected.to_bytes_le().unwrap();
+            assert_eq!(expected, StateRootPath::read_le(&expected_bytes[..]).unwrap());
+            assert!(StateRootPath::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+        }
+    }
+}
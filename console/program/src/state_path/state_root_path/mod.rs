@@ -0,0 +1,255 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+
+use super::*;
+
+/// The state root path proves that a historical state root was committed to by a given global state root.
+///
+/// The historical state root is the `previous_state_root` in the header of the block that follows it,
+/// and the hash of that block is a leaf in the block tree of the global state root.
+#[derive(Clone, PartialEq, Eq)]
+pub struct StateRootPath<N: Network> {
+    /// The global state root.
+    global_state_root: N::StateRoot,
+    /// The Merkle path for the block hash.
+    block_path: BlockPath<N>,
+    /// The block hash.
+    block_hash: N::BlockHash,
+    /// The previous block hash.
+    previous_block_hash: N::BlockHash,
+    /// The block header root.
+    header_root: Field<N>,
+    /// The Merkle path for the block header leaf.
+    header_path: HeaderPath<N>,
+    /// The block header leaf, containing the historical state root.
+    header_leaf: HeaderLeaf<N>,
+}
+
+impl<N: Network> StateRootPath<N> {
+    /// Initializes a new instance of `StateRootPath`.
+    pub fn from(
+        global_state_root: N::StateRoot,
+        block_path: BlockPath<N>,
+        block_hash: N::BlockHash,
+        previous_block_hash: N::BlockHash,
+        header_root: Field<N>,
+        header_path: HeaderPath<N>,
+        header_leaf: HeaderLeaf<N>,
+    ) -> Self {
+        Self { global_state_root, block_path, block_hash, previous_block_hash, header_root, header_path, header_leaf }
+    }
+
+    /// Returns the global state root.
+    pub const fn global_state_root(&self) -> N::StateRoot {
+        self.global_state_root
+    }
+
+    /// Returns the historical state root.
+    pub fn state_root(&self) -> N::StateRoot {
+        self.header_leaf.id().into()
+    }
+
+    /// Returns the block path.
+    pub const fn block_path(&self) -> &BlockPath<N> {
+        &self.block_path
+    }
+
+    /// Returns the block hash.
+    pub const fn block_hash(&self) -> N::BlockHash {
+        self.block_hash
+    }
+
+    /// Returns the previous block hash.
+    pub const fn previous_block_hash(&self) -> N::BlockHash {
+        self.previous_block_hash
+    }
+
+    /// Returns the block header root.
+    pub const fn header_root(&self) -> &Field<N> {
+        &self.header_root
+    }
+
+    /// Returns the header path.
+    pub const fn header_path(&self) -> &HeaderPath<N> {
+        &self.header_path
+    }
+
+    /// Returns the header leaf.
+    pub const fn header_leaf(&self) -> &HeaderLeaf<N> {
+        &self.header_leaf
+    }
+}
+
+impl<N: Network> Debug for StateRootPath<N> {
+    /// Prints the state root path.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("StateRootPath")
+            .field("global_state_root", &self.global_state_root)
+            .field("block_path", &self.block_path)
+            .field("block_hash", &self.block_hash)
+            .field("previous_block_hash", &self.previous_block_hash)
+            .field("header_root", &self.header_root)
+            .field("header_path", &self.header_path)
+            .field("header_leaf", &self.header_leaf)
+            .finish()
+    }
+}
+
+impl<N: Network> StateRootPath<N> {
+    /// Checks if the state root path is valid for the given historical state root.
+    ///
+    /// # Diagram
+    /// The `[[ ]]` notation is used to denote public inputs.
+    /// ```ignore
+    ///
+    ///  [[ global_state_root ]]
+    ///           |
+    ///      block_path
+    ///          |
+    ///     block_hash := Hash( previous_block_hash || header_root )
+    ///                                                     |
+    ///                                                header_path
+    ///                                                    |
+    ///                                               header_leaf := (0, [[ state_root ]])
+    /// ```
+    pub fn verify(&self, state_root: &N::StateRoot) -> Result<()> {
+        // Ensure the header leaf is the previous state root.
+        ensure!(self.header_leaf.index() == 0, "Header leaf index must be 0 (the previous state root)");
+        ensure!(
+            self.header_leaf.id() == **state_root,
+            "The header leaf '{}' does not match the state root '{state_root}'",
+            self.header_leaf.id()
+        );
+        // Ensure the header path is valid.
+        ensure!(
+            N::verify_merkle_path_bhp(&self.header_path, &self.header_root, &self.header_leaf.to_bits_le()),
+            "The state root '{state_root}' does not belong to the block header '{}'",
+            self.header_root
+        );
+        // Ensure the block hash is correct.
+        ensure!(
+            *self.block_hash == N::hash_bhp1024(&to_bits_le![(*self.previous_block_hash), self.header_root])?,
+            "Block hash '{}' is incorrect. Double-check the previous block hash and block header root.",
+            self.block_hash
+        );
+        // Ensure the global state root is correct.
+        ensure!(
+            N::verify_merkle_path_bhp(&self.block_path, &self.global_state_root, &self.block_hash.to_bits_le()),
+            "The block hash '{}' does not belong to the global state root '{}'",
+            self.block_hash,
+            self.global_state_root
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use super::*;
+
+    /// Samples a state root path, returning the historical state root and its path.
+    pub(crate) fn sample_state_root_path<N: Network>(
+        rng: &mut TestRng,
+    ) -> Result<(N::StateRoot, StateRootPath<N>)> {
+        // Sample the historical state root.
+        let state_root: N::StateRoot = Field::<N>::rand(rng).into();
+
+        // Construct the block header tree.
+        let header_leaf = HeaderLeaf::<N>::new(0, *state_root);
+        let mut header_leaves = vec![header_leaf.to_bits_le()];
+        header_leaves.extend((1..8).map(|index| HeaderLeaf::<N>::new(index, Field::rand(rng)).to_bits_le()));
+        let header_tree: HeaderTree<N> = N::merkle_tree_bhp(&header_leaves)?;
+        let header_root = *header_tree.root();
+        let header_path = header_tree.prove(0, &header_leaf.to_bits_le())?;
+
+        // Construct the block hash.
+        let previous_block_hash: N::BlockHash = Field::<N>::rand(rng).into();
+        let block_hash: N::BlockHash = N::hash_bhp1024(&to_bits_le![(*previous_block_hash), header_root])?.into();
+
+        // Construct the block tree.
+        let mut block_leaves = (0..rng.gen_range(1..16)).map(|_| Field::<N>::rand(rng).to_bits_le()).collect::<Vec<_>>();
+        let block_height = block_leaves.len();
+        block_leaves.push(block_hash.to_bits_le());
+        let block_tree: BlockTree<N> = N::merkle_tree_bhp(&block_leaves)?;
+        let global_state_root = (*block_tree.root()).into();
+        let block_path = block_tree.prove(block_height, &block_hash.to_bits_le())?;
+
+        // Return the state root and its path.
+        Ok((
+            state_root,
+            StateRootPath::from(
+                global_state_root,
+                block_path,
+                block_hash,
+                previous_block_hash,
+                header_root,
+                header_path,
+                header_leaf,
+            ),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    const ITERATIONS: usize = 10;
+
+    #[test]
+    fn test_verify() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample the state root path.
+            let (state_root, path) = test_helpers::sample_state_root_path::<CurrentNetwork>(&mut rng).unwrap();
+            assert_eq!(path.state_root(), state_root);
+            // Ensure the path is valid.
+            path.verify(&state_root).unwrap();
+
+            // Ensure the path is invalid for a different state root.
+            let other: <CurrentNetwork as Network>::StateRoot = Field::<CurrentNetwork>::rand(&mut rng).into();
+            assert!(path.verify(&other).is_err());
+
+            // Ensure the path is invalid for a different global state root.
+            let invalid = StateRootPath::from(
+                Field::<CurrentNetwork>::rand(&mut rng).into(),
+                path.block_path().clone(),
+                path.block_hash(),
+                path.previous_block_hash(),
+                *path.header_root(),
+                path.header_path().clone(),
+                *path.header_leaf(),
+            );
+            assert!(invalid.verify(&state_root).is_err());
+
+            // Ensure the path is invalid for a different previous block hash.
+            let invalid = StateRootPath::from(
+                path.global_state_root(),
+                path.block_path().clone(),
+                path.block_hash(),
+                Field::<CurrentNetwork>::rand(&mut rng).into(),
+                *path.header_root(),
+                path.header_path().clone(),
+                *path.header_leaf(),
+            );
+            assert!(invalid.verify(&state_root).is_err());
+        }
+    }
+}
@@ -272,6 +272,19 @@ impl<E: Environment, I: IntegerType> DivWrapped<Integer<E, I>> for Integer<E, I>
     }
 }
 
+impl<E: Environment, I: IntegerType> DivWide<Integer<E, I>> for Integer<E, I> {
+    type Output = Integer<E, I>;
+
+    /// Returns the `quotient` of `self` and `other`.
+    #[inline]
+    fn div_wide(&self, other: &Integer<E, I>) -> Self::Output {
+        match self.integer.checked_div(&other.integer) {
+            Some(integer) => Integer::new(integer),
+            None => E::halt(format!("Integer division failed on: {self} and {other}")),
+        }
+    }
+}
+
 impl<E: Environment, I: IntegerType> DivAssign<Integer<E, I>> for Integer<E, I> {
     /// Divides `self` by `other`.
     #[inline]
@@ -336,6 +349,19 @@ impl<E: Environment, I: IntegerType> Rem<&Integer<E, I>> for Integer<E, I> {
     }
 }
 
+impl<E: Environment, I: IntegerType> RemWide<Integer<E, I>> for Integer<E, I> {
+    type Output = Integer<E, I>;
+
+    /// Returns the `remainder` of `self` divided by `other`.
+    #[inline]
+    fn rem_wide(&self, other: &Integer<E, I>) -> Self::Output {
+        match self.integer.checked_rem(&other.integer) {
+            Some(integer) => Integer::new(integer),
+            None => E::halt(format!("Integer remainder failed on: {self} and {other}")),
+        }
+    }
+}
+
 impl<E: Environment, I: IntegerType> RemWrapped<Integer<E, I>> for Integer<E, I> {
     type Output = Integer<E, I>;
 
@@ -0,0 +1,70 @@
+/*
+ * Copyright (C) 2019-2023 Aleo Systems Inc.
+ * This file is part of the snarkVM library.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at:
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#ifndef SNARKVM_FFI_H
+#define SNARKVM_FFI_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* The version of the C ABI declared in this header. Compare against `snarkvm_abi_version()`. */
+#define SNARKVM_FFI_ABI_VERSION 1
+
+/* The status returned by every function. */
+typedef enum SnarkvmStatus {
+  SNARKVM_STATUS_OK = 0,
+  SNARKVM_STATUS_NULL_POINTER = 1,
+  SNARKVM_STATUS_INVALID_UTF8 = 2,
+  SNARKVM_STATUS_INVALID_INPUT = 3,
+  SNARKVM_STATUS_PANIC = 4,
+} SnarkvmStatus;
+
+/* Helpers */
+
+uint32_t snarkvm_abi_version(void);
+/* Owned by the library; valid until the next call on the same thread. Null if the last call succeeded. */
+const char *snarkvm_last_error(void);
+void snarkvm_string_free(char *string);
+void snarkvm_bytes_free(uint8_t *bytes, size_t length);
+
+/* Account */
+
+SnarkvmStatus snarkvm_private_key_new(char **private_key);
+SnarkvmStatus snarkvm_private_key_to_view_key(const char *private_key, char **view_key);
+SnarkvmStatus snarkvm_private_key_to_address(const char *private_key, char **address);
+SnarkvmStatus snarkvm_view_key_to_address(const char *view_key, char **address);
+
+/* Record */
+
+SnarkvmStatus snarkvm_record_is_owner(const char *view_key, const char *ciphertext, bool *is_owner);
+SnarkvmStatus snarkvm_record_decrypt(const char *view_key, const char *ciphertext, char **plaintext);
+
+/* Transaction */
+
+SnarkvmStatus snarkvm_transaction_from_bytes(const uint8_t *bytes, size_t length, char **transaction);
+SnarkvmStatus snarkvm_transaction_to_bytes(const char *transaction, uint8_t **bytes, size_t *length);
+SnarkvmStatus snarkvm_transaction_id(const char *transaction, char **transaction_id);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* SNARKVM_FFI_H */
@@ -0,0 +1,82 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{ffi_call, read_str, write_string, CurrentNetwork, SnarkvmStatus};
+use snarkvm_console::account::{Address, PrivateKey, ViewKey};
+
+use std::{ffi::c_char, str::FromStr};
+
+/// Samples a new private key, and writes it to `private_key`.
+///
+/// # Safety
+///
+/// `private_key` must be a valid out-pointer. The written string must be released with `snarkvm_string_free`.
+#[no_mangle]
+pub unsafe extern "C" fn snarkvm_private_key_new(private_key: *mut *mut c_char) -> SnarkvmStatus {
+    ffi_call(|| {
+        let key = PrivateKey::<CurrentNetwork>::new(&mut rand::thread_rng())?;
+        write_string(private_key, key.to_string())
+    })
+}
+
+/// Derives the view key of the given private key, and writes it to `view_key`.
+///
+/// # Safety
+///
+/// `private_key` must be a NUL-terminated string, and `view_key` must be a valid out-pointer.
+/// The written string must be released with `snarkvm_string_free`.
+#[no_mangle]
+pub unsafe extern "C" fn snarkvm_private_key_to_view_key(
+    private_key: *const c_char,
+    view_key: *mut *mut c_char,
+) -> SnarkvmStatus {
+    ffi_call(|| {
+        let private_key = PrivateKey::<CurrentNetwork>::from_str(read_str(private_key)?)?;
+        write_string(view_key, ViewKey::try_from(&private_key)?.to_string())
+    })
+}
+
+/// Derives the address of the given private key, and writes it to `address`.
+///
+/// # Safety
+///
+/// `private_key` must be a NUL-terminated string, and `address` must be a valid out-pointer.
+/// The written string must be released with `snarkvm_string_free`.
+#[no_mangle]
+pub unsafe extern "C" fn snarkvm_private_key_to_address(
+    private_key: *const c_char,
+    address: *mut *mut c_char,
+) -> SnarkvmStatus {
+    ffi_call(|| {
+        let private_key = PrivateKey::<CurrentNetwork>::from_str(read_str(private_key)?)?;
+        write_string(address, Address::try_from(&private_key)?.to_string())
+    })
+}
+
+/// Derives the address of the given view key, and writes it to `address`.
+///
+/// # Safety
+///
+/// `view_key` must be a NUL-terminated string, and `address` must be a valid out-pointer.
+/// The written string must be released with `snarkvm_string_free`.
+#[no_mangle]
+pub unsafe extern "C" fn snarkvm_view_key_to_address(
+    view_key: *const c_char,
+    address: *mut *mut c_char,
+) -> SnarkvmStatus {
+    ffi_call(|| {
+        let view_key = ViewKey::<CurrentNetwork>::from_str(read_str(view_key)?)?;
+        write_string(address, Address::try_from(&view_key)?.to_string())
+    })
+}
@@ -0,0 +1,167 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::{
+    cell::RefCell,
+    ffi::{c_char, CStr, CString},
+    panic::{catch_unwind, AssertUnwindSafe},
+    ptr,
+};
+
+/// The version of the C ABI exposed by this library.
+///
+/// This is incremented on any breaking change to a function signature, a status code,
+/// or the ownership of a returned value.
+pub const SNARKVM_FFI_ABI_VERSION: u32 = 1;
+
+/// The status returned by every exported function.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SnarkvmStatus {
+    /// The call succeeded, and its result was written to the out-pointer.
+    Ok = 0,
+    /// A required pointer was null.
+    NullPointer = 1,
+    /// An input string is not valid UTF-8.
+    InvalidUtf8 = 2,
+    /// An input could not be parsed, or the operation on it failed.
+    InvalidInput = 3,
+    /// The call panicked. The library remains usable.
+    Panic = 4,
+}
+
+thread_local! {
+    /// The message of the last error on this thread.
+    static LAST_ERROR: RefCell<Option<CString>> = RefCell::new(None);
+}
+
+/// An error raised by an exported function, before it is reported as a status.
+pub(crate) struct FfiError {
+    status: SnarkvmStatus,
+    message: String,
+}
+
+impl FfiError {
+    /// Initializes a new error with the given status and message.
+    pub(crate) fn new(status: SnarkvmStatus, message: impl Into<String>) -> Self {
+        Self { status, message: message.into() }
+    }
+}
+
+impl From<anyhow::Error> for FfiError {
+    /// Reports an error from snarkVM as invalid input.
+    fn from(error: anyhow::Error) -> Self {
+        Self::new(SnarkvmStatus::InvalidInput, error.to_string())
+    }
+}
+
+/// Runs the body of an exported function, recording its error message and catching any panic.
+pub(crate) fn ffi_call(function: impl FnOnce() -> Result<(), FfiError>) -> SnarkvmStatus {
+    let (status, message) = match catch_unwind(AssertUnwindSafe(function)) {
+        Ok(Ok(())) => (SnarkvmStatus::Ok, None),
+        Ok(Err(error)) => (error.status, Some(error.message)),
+        Err(_) => (SnarkvmStatus::Panic, Some("snarkVM panicked during the call".to_string())),
+    };
+    // Note: Interior NUL bytes cannot appear in a C string, so they are stripped from the message.
+    let message = message.map(|message| CString::new(message.replace('\0', "")).unwrap_or_default());
+    LAST_ERROR.with(|last_error| *last_error.borrow_mut() = message);
+    status
+}
+
+/// Returns the given C string as a `&str`.
+pub(crate) unsafe fn read_str<'a>(string: *const c_char) -> Result<&'a str, FfiError> {
+    if string.is_null() {
+        return Err(FfiError::new(SnarkvmStatus::NullPointer, "Received a null string"));
+    }
+    CStr::from_ptr(string).to_str().map_err(|error| FfiError::new(SnarkvmStatus::InvalidUtf8, error.to_string()))
+}
+
+/// Returns the given buffer as a byte slice.
+pub(crate) unsafe fn read_bytes<'a>(bytes: *const u8, length: usize) -> Result<&'a [u8], FfiError> {
+    match bytes.is_null() {
+        true => Err(FfiError::new(SnarkvmStatus::NullPointer, "Received a null buffer")),
+        false => Ok(std::slice::from_raw_parts(bytes, length)),
+    }
+}
+
+/// Writes the given string to the out-pointer, transferring its ownership to the caller.
+pub(crate) unsafe fn write_string(out: *mut *mut c_char, string: String) -> Result<(), FfiError> {
+    if out.is_null() {
+        return Err(FfiError::new(SnarkvmStatus::NullPointer, "Received a null out-pointer"));
+    }
+    let string = CString::new(string).map_err(|error| FfiError::new(SnarkvmStatus::InvalidInput, error.to_string()))?;
+    *out = string.into_raw();
+    Ok(())
+}
+
+/// Writes the given bytes to the out-pointers, transferring their ownership to the caller.
+pub(crate) unsafe fn write_bytes(out: *mut *mut u8, out_length: *mut usize, bytes: Vec<u8>) -> Result<(), FfiError> {
+    if out.is_null() || out_length.is_null() {
+        return Err(FfiError::new(SnarkvmStatus::NullPointer, "Received a null out-pointer"));
+    }
+    let bytes = bytes.into_boxed_slice();
+    *out_length = bytes.len();
+    *out = Box::into_raw(bytes) as *mut u8;
+    Ok(())
+}
+
+/// Writes the given value to the out-pointer.
+pub(crate) unsafe fn write_value<T>(out: *mut T, value: T) -> Result<(), FfiError> {
+    match out.is_null() {
+        true => Err(FfiError::new(SnarkvmStatus::NullPointer, "Received a null out-pointer")),
+        false => {
+            *out = value;
+            Ok(())
+        }
+    }
+}
+
+/// Returns the version of the C ABI exposed by this library.
+#[no_mangle]
+pub extern "C" fn snarkvm_abi_version() -> u32 {
+    SNARKVM_FFI_ABI_VERSION
+}
+
+/// Returns the message of the last error on the calling thread, or null if the last call succeeded.
+///
+/// The message is owned by the library, and remains valid until the next call on the same thread.
+#[no_mangle]
+pub extern "C" fn snarkvm_last_error() -> *const c_char {
+    LAST_ERROR.with(|last_error| last_error.borrow().as_ref().map_or(ptr::null(), |message| message.as_ptr()))
+}
+
+/// Releases a string returned by this library.
+///
+/// # Safety
+///
+/// `string` must be null, or a string returned by this library that has not been released yet.
+#[no_mangle]
+pub unsafe extern "C" fn snarkvm_string_free(string: *mut c_char) {
+    if !string.is_null() {
+        drop(CString::from_raw(string));
+    }
+}
+
+/// Releases a byte buffer returned by this library.
+///
+/// # Safety
+///
+/// `bytes` must be null, or a buffer returned by this library that has not been released yet,
+/// and `length` must be the length returned alongside it.
+#[no_mangle]
+pub unsafe extern "C" fn snarkvm_bytes_free(bytes: *mut u8, length: usize) {
+    if !bytes.is_null() {
+        drop(Box::from_raw(ptr::slice_from_raw_parts_mut(bytes, length)));
+    }
+}
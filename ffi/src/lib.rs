@@ -0,0 +1,37 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A C ABI over the account, record, and transaction types of snarkVM.
+//!
+//! Every exported function returns a [`SnarkvmStatus`] and writes its result through an out-pointer.
+//! Strings and byte buffers returned by this library must be released with [`snarkvm_string_free`]
+//! and [`snarkvm_bytes_free`]. The pointer requirements of each function are listed in its documentation.
+
+mod helpers;
+pub use helpers::*;
+
+mod account;
+pub use account::*;
+
+mod record;
+pub use record::*;
+
+mod transaction;
+pub use transaction::*;
+
+#[cfg(test)]
+mod tests;
+
+/// The network the exported functions operate on.
+pub(crate) type CurrentNetwork = snarkvm_console::network::MainnetV0;
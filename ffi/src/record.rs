@@ -0,0 +1,58 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{ffi_call, read_str, write_string, write_value, CurrentNetwork, SnarkvmStatus};
+use snarkvm_console::{
+    account::ViewKey,
+    program::{Ciphertext, Record},
+};
+
+use std::{ffi::c_char, str::FromStr};
+
+/// Writes `true` to `is_owner` if the given record ciphertext is owned by the given view key.
+///
+/// # Safety
+///
+/// `view_key` and `ciphertext` must be NUL-terminated strings, and `is_owner` must be a valid out-pointer.
+#[no_mangle]
+pub unsafe extern "C" fn snarkvm_record_is_owner(
+    view_key: *const c_char,
+    ciphertext: *const c_char,
+    is_owner: *mut bool,
+) -> SnarkvmStatus {
+    ffi_call(|| {
+        let view_key = ViewKey::<CurrentNetwork>::from_str(read_str(view_key)?)?;
+        let record = Record::<CurrentNetwork, Ciphertext<CurrentNetwork>>::from_str(read_str(ciphertext)?)?;
+        write_value(is_owner, record.is_owner(&view_key))
+    })
+}
+
+/// Decrypts the given record ciphertext with the given view key, and writes the plaintext record to `plaintext`.
+///
+/// # Safety
+///
+/// `view_key` and `ciphertext` must be NUL-terminated strings, and `plaintext` must be a valid out-pointer.
+/// The written string must be released with `snarkvm_string_free`.
+#[no_mangle]
+pub unsafe extern "C" fn snarkvm_record_decrypt(
+    view_key: *const c_char,
+    ciphertext: *const c_char,
+    plaintext: *mut *mut c_char,
+) -> SnarkvmStatus {
+    ffi_call(|| {
+        let view_key = ViewKey::<CurrentNetwork>::from_str(read_str(view_key)?)?;
+        let record = Record::<CurrentNetwork, Ciphertext<CurrentNetwork>>::from_str(read_str(ciphertext)?)?;
+        write_string(plaintext, record.decrypt(&view_key)?.to_string())
+    })
+}
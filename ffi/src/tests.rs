@@ -0,0 +1,141 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::*;
+use snarkvm_console::{
+    account::{Address, PrivateKey, ViewKey},
+    network::prelude::*,
+    program::{Ciphertext, Plaintext, Record},
+    types::Scalar,
+};
+
+use std::ffi::{c_char, CStr, CString};
+
+/// Runs the given call, and returns the string it writes to its out-pointer.
+fn call_with_string(call: impl FnOnce(*mut *mut c_char) -> SnarkvmStatus) -> Result<String, String> {
+    let mut out = std::ptr::null_mut();
+    match call(&mut out) {
+        SnarkvmStatus::Ok => unsafe {
+            let string = CStr::from_ptr(out).to_str().unwrap().to_string();
+            snarkvm_string_free(out);
+            Ok(string)
+        },
+        _ => Err(unsafe { CStr::from_ptr(snarkvm_last_error()) }.to_str().unwrap().to_string()),
+    }
+}
+
+#[test]
+fn test_abi_version() {
+    assert_eq!(snarkvm_abi_version(), SNARKVM_FFI_ABI_VERSION);
+}
+
+#[test]
+fn test_account() {
+    // Sample a private key.
+    let private_key = call_with_string(|out| unsafe { snarkvm_private_key_new(out) }).unwrap();
+    let expected = PrivateKey::<CurrentNetwork>::from_str(&private_key).unwrap();
+    let private_key = CString::new(private_key).unwrap();
+
+    // Derive the view key and address.
+    let view_key = call_with_string(|out| unsafe { snarkvm_private_key_to_view_key(private_key.as_ptr(), out) });
+    assert_eq!(view_key.unwrap(), ViewKey::try_from(&expected).unwrap().to_string());
+    let address = call_with_string(|out| unsafe { snarkvm_private_key_to_address(private_key.as_ptr(), out) });
+    assert_eq!(address.unwrap(), Address::try_from(&expected).unwrap().to_string());
+
+    // Ensure the view key derives the same address.
+    let view_key = CString::new(ViewKey::try_from(&expected).unwrap().to_string()).unwrap();
+    let address = call_with_string(|out| unsafe { snarkvm_view_key_to_address(view_key.as_ptr(), out) });
+    assert_eq!(address.unwrap(), Address::try_from(&expected).unwrap().to_string());
+}
+
+#[test]
+fn test_errors() {
+    // Ensure an invalid input is reported with a message.
+    let invalid = CString::new("APrivateKey1invalid").unwrap();
+    let error = call_with_string(|out| unsafe { snarkvm_private_key_to_address(invalid.as_ptr(), out) });
+    assert!(!error.unwrap_err().is_empty());
+    let mut out = std::ptr::null_mut::<c_char>();
+    assert_eq!(unsafe { snarkvm_private_key_to_address(invalid.as_ptr(), &mut out) }, SnarkvmStatus::InvalidInput);
+
+    // Ensure null pointers are rejected.
+    assert_eq!(unsafe { snarkvm_private_key_to_address(std::ptr::null(), &mut out) }, SnarkvmStatus::NullPointer);
+    assert_eq!(unsafe { snarkvm_private_key_new(std::ptr::null_mut()) }, SnarkvmStatus::NullPointer);
+
+    // Ensure the last error is cleared on success.
+    call_with_string(|out| unsafe { snarkvm_private_key_new(out) }).unwrap();
+    assert!(snarkvm_last_error().is_null());
+}
+
+#[test]
+fn test_record() {
+    let rng = &mut TestRng::default();
+
+    let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let view_key = ViewKey::try_from(&private_key).unwrap();
+    let address = Address::try_from(&private_key).unwrap();
+
+    // Encrypt a record for the address.
+    let randomizer = Scalar::rand(rng);
+    let nonce = CurrentNetwork::g_scalar_multiply(&randomizer);
+    let expected = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(&format!(
+        "{{ owner: {address}.private, microcredits: 100u64.private, _nonce: {nonce}.public }}"
+    ))
+    .unwrap();
+    let ciphertext: Record<CurrentNetwork, Ciphertext<CurrentNetwork>> = expected.encrypt(randomizer).unwrap();
+
+    let view_key = CString::new(view_key.to_string()).unwrap();
+    let ciphertext = CString::new(ciphertext.to_string()).unwrap();
+
+    // Ensure the record is owned by the view key.
+    let mut is_owner = false;
+    assert_eq!(
+        unsafe { snarkvm_record_is_owner(view_key.as_ptr(), ciphertext.as_ptr(), &mut is_owner) },
+        SnarkvmStatus::Ok
+    );
+    assert!(is_owner);
+
+    // Ensure the record decrypts to the original plaintext.
+    let plaintext =
+        call_with_string(|out| unsafe { snarkvm_record_decrypt(view_key.as_ptr(), ciphertext.as_ptr(), out) });
+    assert_eq!(Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(&plaintext.unwrap()).unwrap(), expected);
+}
+
+#[test]
+fn test_transaction() {
+    let rng = &mut TestRng::default();
+
+    let expected = ledger_test_helpers::sample_execution_transaction_with_fee(false, rng);
+    let expected_bytes = expected.to_bytes_le().unwrap();
+
+    // Deserialize the transaction from bytes.
+    let transaction = call_with_string(|out| unsafe {
+        snarkvm_transaction_from_bytes(expected_bytes.as_ptr(), expected_bytes.len(), out)
+    })
+    .unwrap();
+    assert_eq!(transaction, expected.to_string());
+    let transaction = CString::new(transaction).unwrap();
+
+    // Serialize the transaction back into bytes.
+    let (mut bytes, mut length) = (std::ptr::null_mut(), 0);
+    assert_eq!(
+        unsafe { snarkvm_transaction_to_bytes(transaction.as_ptr(), &mut bytes, &mut length) },
+        SnarkvmStatus::Ok
+    );
+    assert_eq!(unsafe { std::slice::from_raw_parts(bytes, length) }, expected_bytes.as_slice());
+    unsafe { snarkvm_bytes_free(bytes, length) };
+
+    // Ensure the transaction ID matches.
+    let transaction_id = call_with_string(|out| unsafe { snarkvm_transaction_id(transaction.as_ptr(), out) });
+    assert_eq!(transaction_id.unwrap(), expected.id().to_string());
+}
@@ -0,0 +1,72 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{ffi_call, read_bytes, read_str, write_bytes, write_string, CurrentNetwork, SnarkvmStatus};
+use snarkvm_console::network::prelude::{FromBytes, ToBytes};
+use snarkvm_ledger_block::Transaction;
+
+use std::{ffi::c_char, str::FromStr};
+
+/// Deserializes a transaction from its byte representation, and writes it to `transaction` as JSON.
+///
+/// # Safety
+///
+/// `bytes` must point to `length` readable bytes, and `transaction` must be a valid out-pointer.
+/// The written string must be released with `snarkvm_string_free`.
+#[no_mangle]
+pub unsafe extern "C" fn snarkvm_transaction_from_bytes(
+    bytes: *const u8,
+    length: usize,
+    transaction: *mut *mut c_char,
+) -> SnarkvmStatus {
+    ffi_call(|| {
+        let candidate = Transaction::<CurrentNetwork>::from_bytes_le(read_bytes(bytes, length)?)?;
+        write_string(transaction, candidate.to_string())
+    })
+}
+
+/// Serializes the given JSON transaction into its byte representation, and writes it to `bytes` and `length`.
+///
+/// # Safety
+///
+/// `transaction` must be a NUL-terminated string, and `bytes` and `length` must be valid out-pointers.
+/// The written buffer must be released with `snarkvm_bytes_free`.
+#[no_mangle]
+pub unsafe extern "C" fn snarkvm_transaction_to_bytes(
+    transaction: *const c_char,
+    bytes: *mut *mut u8,
+    length: *mut usize,
+) -> SnarkvmStatus {
+    ffi_call(|| {
+        let candidate = Transaction::<CurrentNetwork>::from_str(read_str(transaction)?)?;
+        write_bytes(bytes, length, candidate.to_bytes_le()?)
+    })
+}
+
+/// Parses the given JSON transaction, and writes its transaction ID to `transaction_id`.
+///
+/// # Safety
+///
+/// `transaction` must be a NUL-terminated string, and `transaction_id` must be a valid out-pointer.
+/// The written string must be released with `snarkvm_string_free`.
+#[no_mangle]
+pub unsafe extern "C" fn snarkvm_transaction_id(
+    transaction: *const c_char,
+    transaction_id: *mut *mut c_char,
+) -> SnarkvmStatus {
+    ffi_call(|| {
+        let candidate = Transaction::<CurrentNetwork>::from_str(read_str(transaction)?)?;
+        write_string(transaction_id, candidate.id().to_string())
+    })
+}
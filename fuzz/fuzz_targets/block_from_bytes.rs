@@ -0,0 +1,29 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![no_main]
+
+use console::network::{prelude::*, MainnetV0};
+use ledger_block::Block;
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    // Deserialize the block.
+    if let Ok(block) = Block::<MainnetV0>::from_bytes_le(data) {
+        // Ensure the block round-trips through its byte representation.
+        let candidate = Block::<MainnetV0>::from_bytes_le(&block.to_bytes_le().unwrap()).unwrap();
+        assert_eq!(block, candidate);
+    }
+});
@@ -0,0 +1,33 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![no_main]
+
+use console::{
+    network::{prelude::*, MainnetV0},
+    program::Plaintext,
+};
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(string) = std::str::from_utf8(data) {
+        // Parse the plaintext.
+        if let Ok(plaintext) = Plaintext::<MainnetV0>::from_str(string) {
+            // Ensure the plaintext round-trips through its string representation.
+            let candidate = Plaintext::<MainnetV0>::from_str(&plaintext.to_string()).unwrap();
+            assert_eq!(plaintext, candidate);
+        }
+    }
+});
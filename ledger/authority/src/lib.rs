@@ -112,6 +112,15 @@ impl<N: Network> Authority<N> {
             Self::Quorum(subdag) => subdag.leader_address(),
         }
     }
+
+    /// Returns the subdag of the authority, if the authority is a quorum.
+    /// Note: Use `Subdag::statistics` to compute the leader schedule and batch statistics of the subdag.
+    pub const fn subdag(&self) -> Option<&Subdag<N>> {
+        match self {
+            Self::Beacon(_) => None,
+            Self::Quorum(subdag) => Some(subdag),
+        }
+    }
 }
 
 #[cfg(any(test, feature = "test-helpers"))]
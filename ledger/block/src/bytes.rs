@@ -14,15 +14,20 @@
 
 use super::*;
 
+impl<N: Network> Migrate for Block<N> {
+    const NAME: &'static str = "block";
+    const VERSION: u8 = 1;
+}
+
 impl<N: Network> FromBytes for Block<N> {
     /// Reads the block from the buffer.
     #[inline]
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
         // Read the version.
-        let version = u8::read_le(&mut reader)?;
-        // Ensure the version is valid.
-        if version != 1 {
-            return Err(error("Invalid block version"));
+        let version = Self::read_version(&mut reader)?;
+        // Read a value written in an older byte format.
+        if version < Self::VERSION {
+            return Self::read_legacy(version, reader);
         }
 
         // Read the block hash.
@@ -94,7 +99,7 @@ impl<N: Network> ToBytes for Block<N> {
     #[inline]
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
         // Write the version.
-        1u8.write_le(&mut writer)?;
+        Self::VERSION.write_le(&mut writer)?;
 
         // Write the block hash.
         self.block_hash.write_le(&mut writer)?;
@@ -155,4 +160,21 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_migrate() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let block = crate::test_helpers::sample_genesis_block(rng);
+        let mut bytes = block.to_bytes_le()?;
+        // Check the current bytes do not need to be migrated.
+        assert_eq!(Block::<CurrentNetwork>::version_of(&bytes)?, Block::<CurrentNetwork>::VERSION);
+        assert!(Block::<CurrentNetwork>::migrate(&bytes)?.is_none());
+
+        // Check an unsupported version is rejected.
+        bytes[0] = Block::<CurrentNetwork>::VERSION + 1;
+        assert!(Block::<CurrentNetwork>::read_le(&bytes[..]).is_err());
+        assert!(Block::<CurrentNetwork>::migrate(&bytes).is_err());
+        Ok(())
+    }
 }
@@ -14,15 +14,20 @@
 
 use super::*;
 
+impl<N: Network> Migrate for Header<N> {
+    const NAME: &'static str = "header";
+    const VERSION: u8 = 1;
+}
+
 impl<N: Network> FromBytes for Header<N> {
     /// Reads the block header from the buffer.
     #[inline]
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
         // Read the version.
-        let version = u8::read_le(&mut reader)?;
-        // Ensure the version is valid.
-        if version != 1 {
-            return Err(error("Invalid header version"));
+        let version = Self::read_version(&mut reader)?;
+        // Read a value written in an older byte format.
+        if version < Self::VERSION {
+            return Self::read_legacy(version, reader);
         }
 
         // Read from the buffer.
@@ -53,7 +58,7 @@ impl<N: Network> ToBytes for Header<N> {
     #[inline]
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
         // Write the version.
-        1u8.write_le(&mut writer)?;
+        Self::VERSION.write_le(&mut writer)?;
 
         // Write to the buffer.
         self.previous_state_root.write_le(&mut writer)?;
@@ -14,15 +14,20 @@
 
 use super::*;
 
+impl<N: Network> Migrate for Metadata<N> {
+    const NAME: &'static str = "metadata";
+    const VERSION: u8 = 1;
+}
+
 impl<N: Network> FromBytes for Metadata<N> {
     /// Reads the metadata from the buffer.
     #[inline]
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
         // Read the version.
-        let version = u8::read_le(&mut reader)?;
-        // Ensure the version is valid.
-        if version != 1 {
-            return Err(error("Invalid metadata version"));
+        let version = Self::read_version(&mut reader)?;
+        // Read a value written in an older byte format.
+        if version < Self::VERSION {
+            return Self::read_legacy(version, reader);
         }
 
         // Read from the buffer.
@@ -59,7 +64,7 @@ impl<N: Network> ToBytes for Metadata<N> {
     #[inline]
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
         // Write the version.
-        1u8.write_le(&mut writer)?;
+        Self::VERSION.write_le(&mut writer)?;
 
         // Write to the buffer.
         self.network.write_le(&mut writer)?;
@@ -20,6 +20,7 @@ mod to_bits;
 mod to_hash;
 mod verify;
 
+use crate::Migrate;
 use console::{network::prelude::*, types::Field};
 
 use core::marker::PhantomData;
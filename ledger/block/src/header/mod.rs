@@ -22,7 +22,7 @@ mod serialize;
 mod string;
 mod verify;
 
-use crate::{Ratifications, Transactions};
+use crate::{Migrate, Ratifications, Transactions};
 use console::{
     network::prelude::*,
     program::{HeaderLeaf, HeaderPath, HeaderTree, HEADER_DEPTH},
@@ -0,0 +1,123 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::prelude::{error, FromBytes, IoResult, Read, Result, ToBytes};
+
+/// A byte format with an explicit version header, which can read and upgrade values
+/// written by older releases.
+///
+/// Each versioned type writes `VERSION` as its first byte. When a layout changes, bump
+/// `VERSION` and read the previous layouts in `read_legacy`, so existing stores remain
+/// readable and are rewritten in the current layout the next time they are stored.
+pub trait Migrate: FromBytes + ToBytes {
+    /// The name of the type, used in error messages.
+    const NAME: &'static str;
+    /// The byte format version written by this release.
+    const VERSION: u8;
+    /// The oldest byte format version this release can read.
+    const MIN_VERSION: u8 = 1;
+
+    /// Reads a value written in the given older byte format version.
+    /// Note: The version header has already been read from the reader.
+    fn read_legacy<R: Read>(version: u8, _reader: R) -> IoResult<Self> {
+        Err(error(format!("Unsupported {} version '{version}'", Self::NAME)))
+    }
+
+    /// Reads the version header, ensuring it is within the supported range.
+    fn read_version<R: Read>(reader: R) -> IoResult<u8> {
+        let version = u8::read_le(reader)?;
+        match (Self::MIN_VERSION..=Self::VERSION).contains(&version) {
+            true => Ok(version),
+            false => Err(error(format!("Invalid {} version '{version}'", Self::NAME))),
+        }
+    }
+
+    /// Returns the byte format version of the given bytes.
+    fn version_of(bytes: &[u8]) -> Result<u8> {
+        Ok(Self::read_version(bytes)?)
+    }
+
+    /// Returns the given bytes rewritten in the current byte format, or `None` if they are already current.
+    /// Note: This also upgrades any nested versioned values, not only the outer version header.
+    fn migrate(bytes: &[u8]) -> Result<Option<Vec<u8>>> {
+        let migrated = Self::from_bytes_le(bytes)?.to_bytes_le()?;
+        match migrated == bytes {
+            true => Ok(None),
+            false => Ok(Some(migrated)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::prelude::Write;
+
+    /// A value whose second version added the `b` field.
+    #[derive(Debug, PartialEq)]
+    struct Sample {
+        a: u8,
+        b: u16,
+    }
+
+    impl Migrate for Sample {
+        const NAME: &'static str = "sample";
+        const VERSION: u8 = 2;
+
+        fn read_legacy<R: Read>(version: u8, mut reader: R) -> IoResult<Self> {
+            match version {
+                1 => Ok(Self { a: u8::read_le(&mut reader)?, b: 0 }),
+                _ => Err(error(format!("Unsupported sample version '{version}'"))),
+            }
+        }
+    }
+
+    impl FromBytes for Sample {
+        fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+            let version = Self::read_version(&mut reader)?;
+            if version < Self::VERSION {
+                return Self::read_legacy(version, reader);
+            }
+            Ok(Self { a: u8::read_le(&mut reader)?, b: u16::read_le(&mut reader)? })
+        }
+    }
+
+    impl ToBytes for Sample {
+        fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+            Self::VERSION.write_le(&mut writer)?;
+            self.a.write_le(&mut writer)?;
+            self.b.write_le(&mut writer)
+        }
+    }
+
+    #[test]
+    fn test_migrate() -> Result<()> {
+        // Check the legacy bytes are read, and upgraded to the current version.
+        let legacy = [1u8, 7];
+        assert_eq!(Sample::version_of(&legacy)?, 1);
+        assert_eq!(Sample::from_bytes_le(&legacy)?, Sample { a: 7, b: 0 });
+        let migrated = Sample::migrate(&legacy)?.unwrap();
+        assert_eq!(migrated, [2u8, 7, 0, 0]);
+
+        // Check the current bytes are left unchanged.
+        assert_eq!(Sample::version_of(&migrated)?, 2);
+        assert!(Sample::migrate(&migrated)?.is_none());
+
+        // Check unsupported versions are rejected.
+        assert!(Sample::version_of(&[0u8, 7]).is_err());
+        assert!(Sample::version_of(&[3u8, 7, 0, 0]).is_err());
+        assert!(Sample::from_bytes_le(&[3u8, 7, 0, 0]).is_err());
+        Ok(())
+    }
+}
@@ -12,5 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod migrate;
+pub use migrate::*;
+
 mod target;
 pub use target::*;
@@ -29,6 +29,9 @@ pub use ratifications::*;
 pub mod ratify;
 pub use ratify::*;
 
+pub mod slashing;
+pub use slashing::*;
+
 pub mod solutions;
 pub use solutions::*;
 
@@ -41,8 +44,15 @@ pub use transactions::*;
 pub mod transition;
 pub use transition::*;
 
+#[cfg(feature = "protobuf")]
+pub mod proto;
+
+mod scan;
+pub use scan::*;
+
 mod bytes;
 mod genesis;
+mod schema;
 mod serialize;
 mod string;
 mod verify;
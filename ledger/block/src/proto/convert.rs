@@ -0,0 +1,513 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super as proto;
+use crate::{
+    Block,
+    ConfirmedTransaction,
+    Execution,
+    Fee,
+    Header,
+    Input,
+    MappingPolicy,
+    Metadata,
+    Output,
+    Ratifications,
+    Ratify,
+    Rejected,
+    Transaction,
+    Transactions,
+    Transition,
+};
+use console::{
+    network::prelude::*,
+    program::{Identifier, ProgramID},
+};
+use proto::{
+    confirmed_transaction::Status,
+    transaction::{Deploy, Deprecate, Execute, FeeOnly, Kind},
+};
+
+use prost::Message;
+
+/// Returns the native byte encoding of the given value.
+fn to_bytes<T: ToBytes>(value: &T) -> Result<Vec<u8>> {
+    value.to_bytes_le()
+}
+
+/// Returns the value read from the given native byte encoding, ensuring no trailing bytes remain.
+fn from_bytes<T: FromBytes>(mut bytes: &[u8], name: &str) -> Result<T> {
+    let value = T::read_le(&mut bytes).map_err(|e| anyhow!("Invalid {name} - {e}"))?;
+    ensure!(bytes.is_empty(), "Invalid {name} - found {} trailing bytes", bytes.len());
+    Ok(value)
+}
+
+/// Returns the given message field, or an error if it is missing.
+fn required<T>(value: Option<T>, name: &str) -> Result<T> {
+    value.ok_or_else(|| anyhow!("Missing {name} in protobuf message"))
+}
+
+impl<N: Network> From<&Metadata<N>> for proto::Metadata {
+    fn from(metadata: &Metadata<N>) -> Self {
+        Self {
+            network: u32::from(metadata.network()),
+            round: metadata.round(),
+            height: metadata.height(),
+            cumulative_weight: metadata.cumulative_weight().to_le_bytes().to_vec(),
+            cumulative_proof_target: metadata.cumulative_proof_target().to_le_bytes().to_vec(),
+            coinbase_target: metadata.coinbase_target(),
+            proof_target: metadata.proof_target(),
+            last_coinbase_target: metadata.last_coinbase_target(),
+            last_coinbase_timestamp: metadata.last_coinbase_timestamp(),
+            timestamp: metadata.timestamp(),
+        }
+    }
+}
+
+impl<N: Network> TryFrom<proto::Metadata> for Metadata<N> {
+    type Error = Error;
+
+    fn try_from(metadata: proto::Metadata) -> Result<Self> {
+        Metadata::new(
+            u16::try_from(metadata.network)?,
+            metadata.round,
+            metadata.height,
+            from_bytes(&metadata.cumulative_weight, "cumulative weight")?,
+            from_bytes(&metadata.cumulative_proof_target, "cumulative proof target")?,
+            metadata.coinbase_target,
+            metadata.proof_target,
+            metadata.last_coinbase_target,
+            metadata.last_coinbase_timestamp,
+            metadata.timestamp,
+        )
+    }
+}
+
+impl<N: Network> TryFrom<&Header<N>> for proto::Header {
+    type Error = Error;
+
+    fn try_from(header: &Header<N>) -> Result<Self> {
+        Ok(Self {
+            previous_state_root: to_bytes(&header.previous_state_root())?,
+            transactions_root: to_bytes(&header.transactions_root())?,
+            finalize_root: to_bytes(&header.finalize_root())?,
+            ratifications_root: to_bytes(&header.ratifications_root())?,
+            solutions_root: to_bytes(&header.solutions_root())?,
+            subdag_root: to_bytes(&header.subdag_root())?,
+            metadata: Some(header.metadata().into()),
+        })
+    }
+}
+
+impl<N: Network> TryFrom<proto::Header> for Header<N> {
+    type Error = Error;
+
+    fn try_from(header: proto::Header) -> Result<Self> {
+        Header::from(
+            from_bytes(&header.previous_state_root, "previous state root")?,
+            from_bytes(&header.transactions_root, "transactions root")?,
+            from_bytes(&header.finalize_root, "finalize root")?,
+            from_bytes(&header.ratifications_root, "ratifications root")?,
+            from_bytes(&header.solutions_root, "solutions root")?,
+            from_bytes(&header.subdag_root, "subdag root")?,
+            required(header.metadata, "header metadata")?.try_into()?,
+        )
+    }
+}
+
+impl<N: Network> TryFrom<&Transition<N>> for proto::Transition {
+    type Error = Error;
+
+    fn try_from(transition: &Transition<N>) -> Result<Self> {
+        Ok(Self {
+            id: to_bytes(transition.id())?,
+            program_id: transition.program_id().to_string(),
+            function_name: transition.function_name().to_string(),
+            inputs: transition.inputs().iter().map(to_bytes).collect::<Result<_>>()?,
+            outputs: transition.outputs().iter().map(to_bytes).collect::<Result<_>>()?,
+            tpk: to_bytes(transition.tpk())?,
+            tcm: to_bytes(transition.tcm())?,
+            scm: to_bytes(transition.scm())?,
+        })
+    }
+}
+
+impl<N: Network> TryFrom<proto::Transition> for Transition<N> {
+    type Error = Error;
+
+    fn try_from(transition: proto::Transition) -> Result<Self> {
+        // Read the inputs and outputs.
+        let inputs = transition
+            .inputs
+            .iter()
+            .map(|input| from_bytes::<Input<N>>(input, "transition input"))
+            .collect::<Result<Vec<_>>>()?;
+        let outputs = transition
+            .outputs
+            .iter()
+            .map(|output| from_bytes::<Output<N>>(output, "transition output"))
+            .collect::<Result<Vec<_>>>()?;
+        // Construct the transition.
+        let candidate = Transition::new(
+            ProgramID::from_str(&transition.program_id)?,
+            Identifier::from_str(&transition.function_name)?,
+            inputs,
+            outputs,
+            from_bytes(&transition.tpk, "transition public key")?,
+            from_bytes(&transition.tcm, "transition commitment")?,
+            from_bytes(&transition.scm, "signer commitment")?,
+        )?;
+        // Ensure the transition ID matches.
+        ensure!(to_bytes(candidate.id())? == transition.id, "Mismatching transition ID in protobuf message");
+        Ok(candidate)
+    }
+}
+
+impl<N: Network> TryFrom<&Fee<N>> for proto::Fee {
+    type Error = Error;
+
+    fn try_from(fee: &Fee<N>) -> Result<Self> {
+        Ok(Self {
+            transition: Some(fee.transition().try_into()?),
+            global_state_root: to_bytes(&fee.global_state_root())?,
+            proof: fee.proof().map(to_bytes).transpose()?,
+        })
+    }
+}
+
+impl<N: Network> TryFrom<proto::Fee> for Fee<N> {
+    type Error = Error;
+
+    fn try_from(fee: proto::Fee) -> Result<Self> {
+        Fee::from(
+            required(fee.transition, "fee transition")?.try_into()?,
+            from_bytes(&fee.global_state_root, "fee global state root")?,
+            fee.proof.map(|proof| from_bytes(&proof, "fee proof")).transpose()?,
+        )
+    }
+}
+
+impl<N: Network> TryFrom<&Execution<N>> for proto::Execution {
+    type Error = Error;
+
+    fn try_from(execution: &Execution<N>) -> Result<Self> {
+        Ok(Self {
+            transitions: execution.transitions().map(proto::Transition::try_from).collect::<Result<_>>()?,
+            global_state_root: to_bytes(&execution.global_state_root())?,
+            proof: execution.proof().map(to_bytes).transpose()?,
+            sequence: execution.sequence(),
+        })
+    }
+}
+
+impl<N: Network> TryFrom<proto::Execution> for Execution<N> {
+    type Error = Error;
+
+    fn try_from(execution: proto::Execution) -> Result<Self> {
+        let transitions =
+            execution.transitions.into_iter().map(Transition::try_from).collect::<Result<Vec<Transition<N>>>>()?;
+        Execution::from(
+            transitions.into_iter(),
+            from_bytes(&execution.global_state_root, "execution global state root")?,
+            execution.proof.map(|proof| from_bytes(&proof, "execution proof")).transpose()?,
+        )?
+        .with_sequence(execution.sequence)
+    }
+}
+
+impl<N: Network> TryFrom<&Transaction<N>> for proto::Transaction {
+    type Error = Error;
+
+    fn try_from(transaction: &Transaction<N>) -> Result<Self> {
+        let kind = match transaction {
+            Transaction::Deploy(_, owner, deployment, fee) => Kind::Deploy(Deploy {
+                owner: to_bytes(owner)?,
+                deployment: to_bytes(&**deployment)?,
+                fee: Some(fee.try_into()?),
+            }),
+            Transaction::Execute(_, execution, fee) => Kind::Execute(Execute {
+                execution: Some(execution.try_into()?),
+                fee: fee.as_ref().map(proto::Fee::try_from).transpose()?,
+            }),
+            Transaction::Fee(_, fee) => Kind::Fee(FeeOnly { fee: Some(fee.try_into()?) }),
+            Transaction::Deprecate(_, owner, program_id, mapping_policy, fee) => Kind::Deprecate(Deprecate {
+                owner: to_bytes(owner)?,
+                program_id: program_id.to_string(),
+                freeze_mappings: mapping_policy.is_freeze(),
+                fee: Some(fee.try_into()?),
+            }),
+        };
+        Ok(Self { id: to_bytes(&transaction.id())?, kind: Some(kind) })
+    }
+}
+
+impl<N: Network> TryFrom<proto::Transaction> for Transaction<N> {
+    type Error = Error;
+
+    fn try_from(transaction: proto::Transaction) -> Result<Self> {
+        // Construct the transaction.
+        let candidate = match required(transaction.kind, "transaction kind")? {
+            Kind::Deploy(deploy) => Transaction::from_deployment(
+                from_bytes(&deploy.owner, "program owner")?,
+                from_bytes(&deploy.deployment, "deployment")?,
+                required(deploy.fee, "deployment fee")?.try_into()?,
+            )?,
+            Kind::Execute(execute) => Transaction::from_execution(
+                required(execute.execution, "execution")?.try_into()?,
+                execute.fee.map(Fee::try_from).transpose()?,
+            )?,
+            Kind::Fee(fee) => Transaction::from_fee(required(fee.fee, "fee")?.try_into()?)?,
+            Kind::Deprecate(deprecate) => Transaction::from_deprecation(
+                from_bytes(&deprecate.owner, "program owner")?,
+                ProgramID::from_str(&deprecate.program_id)?,
+                match deprecate.freeze_mappings {
+                    true => MappingPolicy::Freeze,
+                    false => MappingPolicy::Remove,
+                },
+                required(deprecate.fee, "deprecation fee")?.try_into()?,
+            )?,
+        };
+        // Ensure the transaction ID matches.
+        ensure!(to_bytes(&candidate.id())? == transaction.id, "Mismatching transaction ID in protobuf message");
+        Ok(candidate)
+    }
+}
+
+impl<N: Network> TryFrom<&ConfirmedTransaction<N>> for proto::ConfirmedTransaction {
+    type Error = Error;
+
+    fn try_from(confirmed: &ConfirmedTransaction<N>) -> Result<Self> {
+        let status = match confirmed {
+            ConfirmedTransaction::AcceptedDeploy(..) => Status::AcceptedDeploy,
+            ConfirmedTransaction::AcceptedExecute(..) => Status::AcceptedExecute,
+            ConfirmedTransaction::RejectedDeploy(..) => Status::RejectedDeploy,
+            ConfirmedTransaction::RejectedExecute(..) => Status::RejectedExecute,
+            ConfirmedTransaction::AcceptedDeprecate(..) => Status::AcceptedDeprecate,
+        };
+        Ok(Self {
+            status: status as i32,
+            index: confirmed.index(),
+            transaction: Some(confirmed.transaction().try_into()?),
+            rejected: confirmed.to_rejected().map(to_bytes).transpose()?,
+            finalize_operations: confirmed.finalize_operations().iter().map(to_bytes).collect::<Result<_>>()?,
+        })
+    }
+}
+
+impl<N: Network> TryFrom<proto::ConfirmedTransaction> for ConfirmedTransaction<N> {
+    type Error = Error;
+
+    fn try_from(confirmed: proto::ConfirmedTransaction) -> Result<Self> {
+        let index = confirmed.index;
+        let transaction = Transaction::try_from(required(confirmed.transaction, "confirmed transaction")?)?;
+        let finalize_operations = confirmed
+            .finalize_operations
+            .iter()
+            .map(|operation| from_bytes(operation, "finalize operation"))
+            .collect::<Result<Vec<_>>>()?;
+        let rejected = confirmed
+            .rejected
+            .map(|rejected| from_bytes::<Rejected<N>>(&rejected, "rejected transaction"))
+            .transpose()?;
+        // Construct the confirmed transaction.
+        match (Status::try_from(confirmed.status)?, rejected) {
+            (Status::AcceptedDeploy, None) => Self::accepted_deploy(index, transaction, finalize_operations),
+            (Status::AcceptedExecute, None) => Self::accepted_execute(index, transaction, finalize_operations),
+            (Status::AcceptedDeprecate, None) => Self::accepted_deprecate(index, transaction, finalize_operations),
+            (Status::RejectedDeploy, Some(rejected)) => {
+                Self::rejected_deploy(index, transaction, rejected, finalize_operations)
+            }
+            (Status::RejectedExecute, Some(rejected)) => {
+                Self::rejected_execute(index, transaction, rejected, finalize_operations)
+            }
+            (status, _) => bail!("Invalid confirmed transaction status '{status:?}' in protobuf message"),
+        }
+    }
+}
+
+impl<N: Network> TryFrom<&Block<N>> for proto::Block {
+    type Error = Error;
+
+    fn try_from(block: &Block<N>) -> Result<Self> {
+        Ok(Self {
+            block_hash: to_bytes(&block.hash())?,
+            previous_hash: to_bytes(&block.previous_hash())?,
+            header: Some(block.header().try_into()?),
+            authority: to_bytes(block.authority())?,
+            ratifications: block.ratifications().iter().map(to_bytes).collect::<Result<_>>()?,
+            solutions: to_bytes(block.solutions())?,
+            aborted_solution_ids: block.aborted_solution_ids().iter().map(to_bytes).collect::<Result<_>>()?,
+            transactions: block
+                .transactions()
+                .iter()
+                .map(proto::ConfirmedTransaction::try_from)
+                .collect::<Result<_>>()?,
+            aborted_transaction_ids: block.aborted_transaction_ids().iter().map(to_bytes).collect::<Result<_>>()?,
+        })
+    }
+}
+
+impl<N: Network> TryFrom<proto::Block> for Block<N> {
+    type Error = Error;
+
+    fn try_from(block: proto::Block) -> Result<Self> {
+        // Read the ratifications and transactions.
+        let ratifications = Ratifications::try_from_iter(
+            block
+                .ratifications
+                .iter()
+                .map(|ratify| from_bytes::<Ratify<N>>(ratify, "ratification"))
+                .collect::<Result<Vec<_>>>()?,
+        )?;
+        let transactions =
+            block.transactions.into_iter().map(ConfirmedTransaction::try_from).collect::<Result<Transactions<N>>>()?;
+        // Read the aborted IDs.
+        let aborted_solution_ids =
+            block.aborted_solution_ids.iter().map(|id| from_bytes(id, "aborted solution ID")).collect::<Result<_>>()?;
+        let aborted_transaction_ids = block
+            .aborted_transaction_ids
+            .iter()
+            .map(|id| from_bytes(id, "aborted transaction ID"))
+            .collect::<Result<_>>()?;
+        // Construct the block.
+        let candidate = Block::from(
+            from_bytes(&block.previous_hash, "previous block hash")?,
+            required(block.header, "block header")?.try_into()?,
+            from_bytes(&block.authority, "block authority")?,
+            ratifications,
+            from_bytes(&block.solutions, "block solutions")?,
+            aborted_solution_ids,
+            transactions,
+            aborted_transaction_ids,
+        )?;
+        // Ensure the block hash matches.
+        ensure!(to_bytes(&candidate.hash())? == block.block_hash, "Mismatching block hash in protobuf message");
+        Ok(candidate)
+    }
+}
+
+impl<N: Network> Block<N> {
+    /// Returns the block encoded as a protobuf `Block` message.
+    pub fn to_protobuf(&self) -> Result<Vec<u8>> {
+        Ok(proto::Block::try_from(self)?.encode_to_vec())
+    }
+
+    /// Returns the block decoded from a protobuf `Block` message.
+    pub fn from_protobuf(bytes: &[u8]) -> Result<Self> {
+        proto::Block::decode(bytes)?.try_into()
+    }
+}
+
+impl<N: Network> Header<N> {
+    /// Returns the header encoded as a protobuf `Header` message.
+    pub fn to_protobuf(&self) -> Result<Vec<u8>> {
+        Ok(proto::Header::try_from(self)?.encode_to_vec())
+    }
+
+    /// Returns the header decoded from a protobuf `Header` message.
+    pub fn from_protobuf(bytes: &[u8]) -> Result<Self> {
+        proto::Header::decode(bytes)?.try_into()
+    }
+}
+
+impl<N: Network> Transaction<N> {
+    /// Returns the transaction encoded as a protobuf `Transaction` message.
+    pub fn to_protobuf(&self) -> Result<Vec<u8>> {
+        Ok(proto::Transaction::try_from(self)?.encode_to_vec())
+    }
+
+    /// Returns the transaction decoded from a protobuf `Transaction` message.
+    pub fn from_protobuf(bytes: &[u8]) -> Result<Self> {
+        proto::Transaction::decode(bytes)?.try_into()
+    }
+}
+
+impl<N: Network> Transition<N> {
+    /// Returns the transition encoded as a protobuf `Transition` message.
+    pub fn to_protobuf(&self) -> Result<Vec<u8>> {
+        Ok(proto::Transition::try_from(self)?.encode_to_vec())
+    }
+
+    /// Returns the transition decoded from a protobuf `Transition` message.
+    pub fn from_protobuf(bytes: &[u8]) -> Result<Self> {
+        proto::Transition::decode(bytes)?.try_into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    #[test]
+    fn test_block_protobuf() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let expected = crate::test_helpers::sample_genesis_block(rng);
+        // Check the block round trip, and that the native byte format is unchanged.
+        let candidate = Block::<CurrentNetwork>::from_protobuf(&expected.to_protobuf()?)?;
+        assert_eq!(expected, candidate);
+        assert_eq!(expected.to_bytes_le()?, candidate.to_bytes_le()?);
+
+        // Check the header round trip.
+        let candidate = Header::<CurrentNetwork>::from_protobuf(&expected.header().to_protobuf()?)?;
+        assert_eq!(expected.header(), &candidate);
+        assert_eq!(expected.header().to_bytes_le()?, candidate.to_bytes_le()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_transaction_protobuf() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for expected in [
+            crate::transaction::test_helpers::sample_deployment_transaction(true, rng),
+            crate::transaction::test_helpers::sample_deployment_transaction(false, rng),
+            crate::transaction::test_helpers::sample_execution_transaction_with_fee(true, rng),
+            crate::transaction::test_helpers::sample_execution_transaction_with_fee(false, rng),
+            crate::transaction::test_helpers::sample_deprecation_transaction(true, rng),
+            crate::transaction::test_helpers::sample_deprecation_transaction(false, rng),
+            crate::transaction::test_helpers::sample_private_fee_transaction(rng),
+            crate::transaction::test_helpers::sample_fee_public_transaction(rng),
+        ]
+        .into_iter()
+        {
+            // Check the transaction round trip, and that the native byte format is unchanged.
+            let candidate = Transaction::<CurrentNetwork>::from_protobuf(&expected.to_protobuf()?)?;
+            assert_eq!(expected, candidate);
+            assert_eq!(expected.to_bytes_le()?, candidate.to_bytes_le()?);
+
+            // Check the transition round trip.
+            for transition in expected.transitions() {
+                let candidate = Transition::<CurrentNetwork>::from_protobuf(&transition.to_protobuf()?)?;
+                assert_eq!(transition, &candidate);
+                assert_eq!(transition.to_bytes_le()?, candidate.to_bytes_le()?);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_protobuf_mismatched_id_fails() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let transaction = crate::transaction::test_helpers::sample_fee_public_transaction(rng);
+        // Corrupt the transaction ID.
+        let mut message = proto::Transaction::try_from(&transaction)?;
+        message.id = vec![0u8; message.id.len()];
+        assert!(Transaction::<CurrentNetwork>::try_from(message).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,227 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The protobuf wire types for blocks, transactions, and transitions.
+//!
+//! These messages mirror `ledger/block/proto/block.proto`, which is the schema
+//! other languages generate their decoders from.
+
+mod convert;
+
+/// The block metadata.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Metadata {
+    #[prost(uint32, tag = "1")]
+    pub network: u32,
+    #[prost(uint64, tag = "2")]
+    pub round: u64,
+    #[prost(uint32, tag = "3")]
+    pub height: u32,
+    #[prost(bytes = "vec", tag = "4")]
+    pub cumulative_weight: Vec<u8>,
+    #[prost(bytes = "vec", tag = "5")]
+    pub cumulative_proof_target: Vec<u8>,
+    #[prost(uint64, tag = "6")]
+    pub coinbase_target: u64,
+    #[prost(uint64, tag = "7")]
+    pub proof_target: u64,
+    #[prost(uint64, tag = "8")]
+    pub last_coinbase_target: u64,
+    #[prost(int64, tag = "9")]
+    pub last_coinbase_timestamp: i64,
+    #[prost(int64, tag = "10")]
+    pub timestamp: i64,
+}
+
+/// The block header.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Header {
+    #[prost(bytes = "vec", tag = "1")]
+    pub previous_state_root: Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub transactions_root: Vec<u8>,
+    #[prost(bytes = "vec", tag = "3")]
+    pub finalize_root: Vec<u8>,
+    #[prost(bytes = "vec", tag = "4")]
+    pub ratifications_root: Vec<u8>,
+    #[prost(bytes = "vec", tag = "5")]
+    pub solutions_root: Vec<u8>,
+    #[prost(bytes = "vec", tag = "6")]
+    pub subdag_root: Vec<u8>,
+    #[prost(message, optional, tag = "7")]
+    pub metadata: Option<Metadata>,
+}
+
+/// The transition.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Transition {
+    #[prost(bytes = "vec", tag = "1")]
+    pub id: Vec<u8>,
+    #[prost(string, tag = "2")]
+    pub program_id: String,
+    #[prost(string, tag = "3")]
+    pub function_name: String,
+    #[prost(bytes = "vec", repeated, tag = "4")]
+    pub inputs: Vec<Vec<u8>>,
+    #[prost(bytes = "vec", repeated, tag = "5")]
+    pub outputs: Vec<Vec<u8>>,
+    #[prost(bytes = "vec", tag = "6")]
+    pub tpk: Vec<u8>,
+    #[prost(bytes = "vec", tag = "7")]
+    pub tcm: Vec<u8>,
+    #[prost(bytes = "vec", tag = "8")]
+    pub scm: Vec<u8>,
+}
+
+/// The fee.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Fee {
+    #[prost(message, optional, tag = "1")]
+    pub transition: Option<Transition>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub global_state_root: Vec<u8>,
+    #[prost(bytes = "vec", optional, tag = "3")]
+    pub proof: Option<Vec<u8>>,
+}
+
+/// The execution.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Execution {
+    #[prost(message, repeated, tag = "1")]
+    pub transitions: Vec<Transition>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub global_state_root: Vec<u8>,
+    #[prost(bytes = "vec", optional, tag = "3")]
+    pub proof: Option<Vec<u8>>,
+    #[prost(uint64, optional, tag = "4")]
+    pub sequence: Option<u64>,
+}
+
+/// The transaction.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Transaction {
+    #[prost(bytes = "vec", tag = "1")]
+    pub id: Vec<u8>,
+    #[prost(oneof = "transaction::Kind", tags = "2, 3, 4, 5")]
+    pub kind: Option<transaction::Kind>,
+}
+
+/// The nested messages of the transaction.
+pub mod transaction {
+    /// The kind of transaction.
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Kind {
+        #[prost(message, tag = "2")]
+        Deploy(Deploy),
+        #[prost(message, tag = "3")]
+        Execute(Execute),
+        #[prost(message, tag = "4")]
+        Fee(FeeOnly),
+        #[prost(message, tag = "5")]
+        Deprecate(Deprecate),
+    }
+
+    /// The deploy transaction.
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Deploy {
+        #[prost(bytes = "vec", tag = "1")]
+        pub owner: Vec<u8>,
+        #[prost(bytes = "vec", tag = "2")]
+        pub deployment: Vec<u8>,
+        #[prost(message, optional, tag = "3")]
+        pub fee: Option<super::Fee>,
+    }
+
+    /// The execute transaction.
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Execute {
+        #[prost(message, optional, tag = "1")]
+        pub execution: Option<super::Execution>,
+        #[prost(message, optional, tag = "2")]
+        pub fee: Option<super::Fee>,
+    }
+
+    /// The fee transaction.
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct FeeOnly {
+        #[prost(message, optional, tag = "1")]
+        pub fee: Option<super::Fee>,
+    }
+
+    /// The deprecate transaction.
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Deprecate {
+        #[prost(bytes = "vec", tag = "1")]
+        pub owner: Vec<u8>,
+        #[prost(string, tag = "2")]
+        pub program_id: String,
+        #[prost(bool, tag = "3")]
+        pub freeze_mappings: bool,
+        #[prost(message, optional, tag = "4")]
+        pub fee: Option<super::Fee>,
+    }
+}
+
+/// The confirmed transaction.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ConfirmedTransaction {
+    #[prost(enumeration = "confirmed_transaction::Status", tag = "1")]
+    pub status: i32,
+    #[prost(uint32, tag = "2")]
+    pub index: u32,
+    #[prost(message, optional, tag = "3")]
+    pub transaction: Option<Transaction>,
+    #[prost(bytes = "vec", optional, tag = "4")]
+    pub rejected: Option<Vec<u8>>,
+    #[prost(bytes = "vec", repeated, tag = "5")]
+    pub finalize_operations: Vec<Vec<u8>>,
+}
+
+/// The nested types of the confirmed transaction.
+pub mod confirmed_transaction {
+    /// The status of the confirmed transaction.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum Status {
+        Unspecified = 0,
+        AcceptedDeploy = 1,
+        AcceptedExecute = 2,
+        RejectedDeploy = 3,
+        RejectedExecute = 4,
+        AcceptedDeprecate = 5,
+    }
+}
+
+/// The block.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Block {
+    #[prost(bytes = "vec", tag = "1")]
+    pub block_hash: Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub previous_hash: Vec<u8>,
+    #[prost(message, optional, tag = "3")]
+    pub header: Option<Header>,
+    #[prost(bytes = "vec", tag = "4")]
+    pub authority: Vec<u8>,
+    #[prost(bytes = "vec", repeated, tag = "5")]
+    pub ratifications: Vec<Vec<u8>>,
+    #[prost(bytes = "vec", tag = "6")]
+    pub solutions: Vec<u8>,
+    #[prost(bytes = "vec", repeated, tag = "7")]
+    pub aborted_solution_ids: Vec<Vec<u8>>,
+    #[prost(message, repeated, tag = "8")]
+    pub transactions: Vec<ConfirmedTransaction>,
+    #[prost(bytes = "vec", repeated, tag = "9")]
+    pub aborted_transaction_ids: Vec<Vec<u8>>,
+}
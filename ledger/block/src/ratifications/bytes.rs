@@ -14,15 +14,20 @@
 
 use super::*;
 
+impl<N: Network> Migrate for Ratifications<N> {
+    const NAME: &'static str = "ratifications";
+    const VERSION: u8 = 1;
+}
+
 impl<N: Network> FromBytes for Ratifications<N> {
     /// Reads the ratifications from buffer.
     #[inline]
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
         // Read the version.
-        let version = u8::read_le(&mut reader)?;
-        // Ensure the version is valid.
-        if version != 1 {
-            return Err(error("Invalid ratifications version"));
+        let version = Self::read_version(&mut reader)?;
+        // Read a value written in an older byte format.
+        if version < Self::VERSION {
+            return Self::read_legacy(version, reader);
         }
         // Read the number of ratifications.
         let num_ratify: u32 = FromBytes::read_le(&mut reader)?;
@@ -42,7 +47,7 @@ impl<N: Network> ToBytes for Ratifications<N> {
     #[inline]
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
         // Write the version.
-        1u8.write_le(&mut writer)?;
+        Self::VERSION.write_le(&mut writer)?;
         // Write the number of ratifications.
         u32::try_from(self.ratifications.len()).map_err(error)?.write_le(&mut writer)?;
         // Write the ratifications.
@@ -16,7 +16,7 @@ mod merkle;
 mod serialize;
 mod string;
 
-use crate::Ratify;
+use crate::{Migrate, Ratify};
 use console::{
     network::prelude::*,
     program::{RatificationsPath, RatificationsTree, RATIFICATIONS_DEPTH},
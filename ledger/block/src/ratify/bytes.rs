@@ -70,7 +70,27 @@ impl<N: Network> FromBytes for Ratify<N> {
                 // Return the ratify object.
                 Self::PuzzleReward(amount)
             }
-            3.. => return Err(error(format!("Failed to decode ratify object variant {variant}"))),
+            3 => {
+                // Read the validator address.
+                let validator: Address<N> = FromBytes::read_le(&mut reader)?;
+                // Read the signer address.
+                let signer: Address<N> = FromBytes::read_le(&mut reader)?;
+                // Read the signature.
+                let signature: Signature<N> = FromBytes::read_le(&mut reader)?;
+                // Return the ratify object.
+                Self::SigningKey(Box::new(validator), Box::new(signer), Box::new(signature))
+            }
+            4 => {
+                // Read the beacon round.
+                let round: u64 = FromBytes::read_le(&mut reader)?;
+                // Read the randomness.
+                let randomness: Field<N> = FromBytes::read_le(&mut reader)?;
+                // Read the signature.
+                let signature: Signature<N> = FromBytes::read_le(&mut reader)?;
+                // Return the ratify object.
+                Self::Randomness(round, Box::new(randomness), Box::new(signature))
+            }
+            5.. => return Err(error(format!("Failed to decode ratify object variant {variant}"))),
         };
         Ok(ratify)
     }
@@ -107,6 +127,18 @@ impl<N: Network> ToBytes for Ratify<N> {
                 (2 as Variant).write_le(&mut writer)?;
                 amount.write_le(&mut writer)
             }
+            Self::SigningKey(validator, signer, signature) => {
+                (3 as Variant).write_le(&mut writer)?;
+                validator.write_le(&mut writer)?;
+                signer.write_le(&mut writer)?;
+                signature.write_le(&mut writer)
+            }
+            Self::Randomness(round, randomness, signature) => {
+                (4 as Variant).write_le(&mut writer)?;
+                round.write_le(&mut writer)?;
+                randomness.write_le(&mut writer)?;
+                signature.write_le(&mut writer)
+            }
         }
     }
 }
@@ -16,7 +16,11 @@ mod bytes;
 mod serialize;
 mod string;
 
-use console::{network::prelude::*, types::Address};
+use console::{
+    account::{PrivateKey, Signature},
+    network::prelude::*,
+    types::{Address, Field},
+};
 use ledger_committee::Committee;
 
 use indexmap::IndexMap;
@@ -36,9 +40,82 @@ pub enum Ratify<N: Network> {
     BlockReward(u64),
     /// The puzzle reward.
     PuzzleReward(u64),
+    /// The rotation of a validator's signing key, as `(validator, signer, signature)`.
+    /// Note: The signature is produced by the validator's private key, over the current and next signer,
+    /// and the number of prior rotations of the validator, which prevents the rotation from being replayed.
+    SigningKey(Box<Address<N>>, Box<Address<N>>, Box<Signature<N>>),
+    /// The external randomness from the network's randomness beacon, as `(beacon round, randomness, signature)`.
+    /// Note: The signature is produced by the randomness beacon, over the beacon round and randomness.
+    Randomness(u64, Box<Field<N>>, Box<Signature<N>>),
 }
 
 impl<N: Network> Ratify<N> {
+    /// Initializes a new signing key ratification, rotating the validator's signing key to the given `signer`.
+    /// The `nonce` is the number of prior signing key rotations of the validator.
+    pub fn new_signing_key<R: Rng + CryptoRng>(
+        private_key: &PrivateKey<N>,
+        current_signer: Address<N>,
+        signer: Address<N>,
+        nonce: u64,
+        rng: &mut R,
+    ) -> Result<Self> {
+        // Derive the validator address.
+        let validator = Address::try_from(private_key)?;
+        // Sign the signing key message.
+        let signature = private_key.sign(&Self::signing_key_message(current_signer, signer, nonce)?, rng)?;
+        // Return the ratify object.
+        Ok(Self::SigningKey(Box::new(validator), Box::new(signer), Box::new(signature)))
+    }
+
+    /// Returns the message signed by a validator to rotate its signing key from `current_signer` to `signer`,
+    /// where `nonce` is the number of prior signing key rotations of the validator.
+    pub fn signing_key_message(current_signer: Address<N>, signer: Address<N>, nonce: u64) -> Result<Vec<Field<N>>> {
+        Ok(vec![
+            Field::new_domain_separator("AleoSigningKey0"),
+            Field::from_u16(N::ID),
+            current_signer.to_field()?,
+            signer.to_field()?,
+            Field::from_u64(nonce),
+        ])
+    }
+
+    /// Initializes a new randomness ratification, signing the given beacon `round` and `randomness`.
+    pub fn new_randomness<R: Rng + CryptoRng>(
+        beacon_private_key: &PrivateKey<N>,
+        round: u64,
+        randomness: Field<N>,
+        rng: &mut R,
+    ) -> Result<Self> {
+        // Sign the randomness message.
+        let signature = beacon_private_key.sign(&Self::randomness_message(round, randomness), rng)?;
+        // Return the ratify object.
+        Ok(Self::Randomness(round, Box::new(randomness), Box::new(signature)))
+    }
+
+    /// Returns the message signed by the randomness beacon for the given beacon `round` and `randomness`.
+    pub fn randomness_message(round: u64, randomness: Field<N>) -> Vec<Field<N>> {
+        vec![Field::from_u64(round), randomness]
+    }
+
+    /// Returns `true` if the randomness ratification is signed by the given randomness beacon.
+    /// Note: This method returns `false` if the ratification is not a randomness ratification.
+    pub fn verify_randomness(&self, beacon: &Address<N>) -> bool {
+        match self {
+            Self::Randomness(round, randomness, signature) => {
+                signature.verify(beacon, &Self::randomness_message(*round, **randomness))
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the external randomness, if this is a randomness ratification.
+    pub fn randomness(&self) -> Option<Field<N>> {
+        match self {
+            Self::Randomness(_, randomness, _) => Some(**randomness),
+            _ => None,
+        }
+    }
+
     /// Returns the ratification ID.
     pub fn to_id(&self) -> Result<N::RatificationID> {
         Ok(N::hash_bhp1024(&self.to_bytes_le()?.to_bits_le())?.into())
@@ -53,6 +130,17 @@ pub(crate) mod test_helpers {
     type CurrentNetwork = MainnetV0;
 
     pub(crate) fn sample_ratifications(rng: &mut TestRng) -> Vec<Ratify<CurrentNetwork>> {
+        let private_key = PrivateKey::new(rng).unwrap();
+        let signing_key = Ratify::new_signing_key(
+            &private_key,
+            Address::try_from(&private_key).unwrap(),
+            Address::new(rng.gen()),
+            rng.gen(),
+            rng,
+        )
+        .unwrap();
+        let randomness = Ratify::new_randomness(&private_key, rng.gen(), Field::rand(rng), rng).unwrap();
+
         let committee = ledger_committee::test_helpers::sample_committee(rng);
         let mut public_balances = PublicBalances::new();
         for (address, _) in committee.members().iter() {
@@ -65,6 +153,8 @@ pub(crate) mod test_helpers {
             Ratify::Genesis(Box::new(committee), Box::new(public_balances), Box::new(bonded_balances)),
             Ratify::BlockReward(rng.gen()),
             Ratify::PuzzleReward(rng.gen()),
+            signing_key,
+            randomness,
         ]
     }
 }
@@ -77,4 +167,31 @@ mod tests {
     fn check_ratify_size() {
         assert_eq!(std::mem::size_of::<Ratify<console::network::MainnetV0>>(), 32);
     }
+
+    #[test]
+    fn test_verify_randomness() {
+        let rng = &mut TestRng::default();
+
+        // Sample the randomness beacon.
+        let beacon_private_key = PrivateKey::<console::network::MainnetV0>::new(rng).unwrap();
+        let beacon = Address::try_from(&beacon_private_key).unwrap();
+
+        // Ensure a randomness ratification signed by the beacon is valid.
+        let randomness = Field::rand(rng);
+        let ratify = Ratify::new_randomness(&beacon_private_key, 7, randomness, rng).unwrap();
+        assert!(ratify.verify_randomness(&beacon));
+        assert_eq!(ratify.randomness(), Some(randomness));
+
+        // Ensure a randomness ratification signed by another account is invalid.
+        let other = Ratify::new_randomness(&PrivateKey::new(rng).unwrap(), 7, randomness, rng).unwrap();
+        assert!(!other.verify_randomness(&beacon));
+
+        // Ensure the signature is bound to the beacon round.
+        let Ratify::Randomness(_, randomness, signature) = ratify else { unreachable!() };
+        assert!(!Ratify::Randomness(8, randomness, signature).verify_randomness(&beacon));
+
+        // Ensure other ratifications are not randomness ratifications.
+        assert!(!Ratify::BlockReward(1).verify_randomness(&beacon));
+        assert_eq!(Ratify::<console::network::MainnetV0>::BlockReward(1).randomness(), None);
+    }
 }
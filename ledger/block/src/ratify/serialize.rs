@@ -39,6 +39,22 @@ impl<N: Network> Serialize for Ratify<N> {
                     input.serialize_field("amount", &amount)?;
                     input.end()
                 }
+                Self::SigningKey(validator, signer, signature) => {
+                    let mut input = serializer.serialize_struct("Ratify", 4)?;
+                    input.serialize_field("type", "signing_key")?;
+                    input.serialize_field("validator", &validator)?;
+                    input.serialize_field("signer", &signer)?;
+                    input.serialize_field("signature", &signature)?;
+                    input.end()
+                }
+                Self::Randomness(round, randomness, signature) => {
+                    let mut input = serializer.serialize_struct("Ratify", 4)?;
+                    input.serialize_field("type", "randomness")?;
+                    input.serialize_field("round", &round)?;
+                    input.serialize_field("randomness", &randomness)?;
+                    input.serialize_field("signature", &signature)?;
+                    input.end()
+                }
             },
             false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
         }
@@ -79,6 +95,26 @@ impl<'de, N: Network> Deserialize<'de> for Ratify<N> {
                         // Construct the ratify object.
                         Ratify::PuzzleReward(amount)
                     }
+                    Some("signing_key") => {
+                        // Retrieve the validator address.
+                        let validator: Address<N> = DeserializeExt::take_from_value::<D>(&mut object, "validator")?;
+                        // Retrieve the signer address.
+                        let signer: Address<N> = DeserializeExt::take_from_value::<D>(&mut object, "signer")?;
+                        // Retrieve the signature.
+                        let signature: Signature<N> = DeserializeExt::take_from_value::<D>(&mut object, "signature")?;
+                        // Construct the ratify object.
+                        Ratify::SigningKey(Box::new(validator), Box::new(signer), Box::new(signature))
+                    }
+                    Some("randomness") => {
+                        // Retrieve the beacon round.
+                        let round: u64 = DeserializeExt::take_from_value::<D>(&mut object, "round")?;
+                        // Retrieve the randomness.
+                        let randomness: Field<N> = DeserializeExt::take_from_value::<D>(&mut object, "randomness")?;
+                        // Retrieve the signature.
+                        let signature: Signature<N> = DeserializeExt::take_from_value::<D>(&mut object, "signature")?;
+                        // Construct the ratify object.
+                        Ratify::Randomness(round, Box::new(randomness), Box::new(signature))
+                    }
                     _ => return Err(de::Error::custom("Invalid ratify object type")),
                 };
                 // Return the ratify object.
@@ -0,0 +1,109 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use console::{
+    account::{GraphKey, ViewKey},
+    program::Plaintext,
+};
+
+/// A record owned by a view key, as found by a scan.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScannedRecord<N: Network> {
+    /// The height of the block that contains the record.
+    pub height: u32,
+    /// The ID of the transaction that contains the record.
+    pub transaction_id: N::TransactionID,
+    /// The ID of the transition that output the record.
+    pub transition_id: N::TransitionID,
+    /// The commitment of the record.
+    pub commitment: Field<N>,
+    /// The tag of the record, which is revealed when the record is spent.
+    pub tag: Field<N>,
+    /// The decrypted record.
+    pub record: Record<N, Plaintext<N>>,
+    /// Whether the record is spent, if known.
+    /// Note: A block does not know if its records are spent in later blocks, so `Block::scan` sets this to `None`.
+    pub is_spent: Option<bool>,
+}
+
+impl<N: Network> Block<N> {
+    /// Returns the records in the block that are owned by the given view key, in the order they were created.
+    /// Only the records whose owner matches the view key are decrypted.
+    pub fn scan(&self, view_key: &ViewKey<N>) -> Result<Vec<ScannedRecord<N>>> {
+        // Derive the x-coordinate of the address, to check the record owners.
+        let address_x_coordinate = view_key.to_address().to_x_coordinate();
+        // Derive the tag secret key, to compute the record tags.
+        let sk_tag = GraphKey::try_from(view_key)?.sk_tag();
+
+        let mut records = Vec::new();
+        for confirmed in self.transactions.iter() {
+            // Note: For a rejected transaction, this is the fee transaction that was confirmed in its place.
+            let transaction = confirmed.transaction();
+            for transition in transaction.transitions() {
+                for (commitment, record) in transition.records() {
+                    if record.is_owner_with_address_x_coordinate(view_key, &address_x_coordinate) {
+                        records.push(ScannedRecord {
+                            height: self.height(),
+                            transaction_id: transaction.id(),
+                            transition_id: *transition.id(),
+                            commitment: *commitment,
+                            tag: Record::<N, Plaintext<N>>::tag(sk_tag, *commitment)?,
+                            record: record.decrypt(view_key)?,
+                            is_spent: None,
+                        });
+                    }
+                }
+            }
+        }
+        Ok(records)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_scan() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis block and its private key.
+        let (block, _, private_key) = crate::test_helpers::sample_genesis_block_and_components(rng);
+        let view_key = ViewKey::<CurrentNetwork>::try_from(&private_key).unwrap();
+
+        // Ensure the scan finds every record of the genesis block, as they are owned by the genesis account.
+        let records = block.scan(&view_key).unwrap();
+        assert_eq!(records.len(), block.records().count());
+        let sk_tag = GraphKey::try_from(&view_key).unwrap().sk_tag();
+        for (scanned, (commitment, ciphertext)) in records.iter().zip(block.records()) {
+            assert_eq!(scanned.height, block.height());
+            assert_eq!(&scanned.commitment, commitment);
+            assert_eq!(
+                scanned.tag,
+                Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::tag(sk_tag, *commitment).unwrap()
+            );
+            assert_eq!(scanned.record, ciphertext.decrypt(&view_key).unwrap());
+            assert_eq!(scanned.is_spent, None);
+        }
+
+        // Ensure the scan of another view key is empty.
+        let other_view_key = ViewKey::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+        assert!(block.scan(&other_view_key).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,124 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use snarkvm_utilities::ByteLayout;
+
+use indexmap::IndexMap;
+
+impl<N: Network> Block<N> {
+    /// Returns the byte layouts of the consensus types contained in this block.
+    ///
+    /// Each layout is recorded from the `ToBytes` implementation of the first value of its kind,
+    /// so variants that do not appear in the block are not included in the schema.
+    pub fn to_layout_schema(&self) -> Result<Vec<ByteLayout>> {
+        // Initialize the layouts, keyed by name.
+        let mut layouts = IndexMap::new();
+
+        // Record the block and its components.
+        record(&mut layouts, "Block", self)?;
+        record(&mut layouts, "Header", self.header())?;
+        record(&mut layouts, "Metadata", self.metadata())?;
+        record(&mut layouts, "Authority", self.authority())?;
+        record(&mut layouts, "Ratifications", self.ratifications())?;
+        record(&mut layouts, "Solutions", self.solutions())?;
+        record(&mut layouts, "Transactions", self.transactions())?;
+
+        // Record the ratifications.
+        for ratify in self.ratifications().iter() {
+            let name = match ratify {
+                Ratify::Genesis(..) => "Ratify::Genesis",
+                Ratify::BlockReward(..) => "Ratify::BlockReward",
+                Ratify::PuzzleReward(..) => "Ratify::PuzzleReward",
+                Ratify::SigningKey(..) => "Ratify::SigningKey",
+                Ratify::Randomness(..) => "Ratify::Randomness",
+            };
+            record(&mut layouts, name, ratify)?;
+        }
+
+        // Record the transactions.
+        for confirmed in self.transactions().iter() {
+            let name = match confirmed {
+                ConfirmedTransaction::AcceptedDeploy(..) => "ConfirmedTransaction::AcceptedDeploy",
+                ConfirmedTransaction::AcceptedExecute(..) => "ConfirmedTransaction::AcceptedExecute",
+                ConfirmedTransaction::RejectedDeploy(..) => "ConfirmedTransaction::RejectedDeploy",
+                ConfirmedTransaction::RejectedExecute(..) => "ConfirmedTransaction::RejectedExecute",
+                ConfirmedTransaction::AcceptedDeprecate(..) => "ConfirmedTransaction::AcceptedDeprecate",
+            };
+            record(&mut layouts, name, confirmed)?;
+
+            let transaction = confirmed.transaction();
+            let name = match transaction {
+                Transaction::Deploy(..) => "Transaction::Deploy",
+                Transaction::Execute(..) => "Transaction::Execute",
+                Transaction::Fee(..) => "Transaction::Fee",
+                Transaction::Deprecate(..) => "Transaction::Deprecate",
+            };
+            record(&mut layouts, name, transaction)?;
+
+            // Record the fee.
+            if let Some(fee) = transaction.fee_transition() {
+                record(&mut layouts, "Fee", &fee)?;
+            }
+            // Record the transitions.
+            for transition in transaction.transitions() {
+                record(&mut layouts, "Transition", transition)?;
+            }
+        }
+
+        Ok(layouts.into_values().collect())
+    }
+}
+
+/// Records the byte layout of the given value, if a layout with the same name has not been recorded.
+fn record<T: ToBytes>(layouts: &mut IndexMap<String, ByteLayout>, name: &str, value: &T) -> Result<()> {
+    if !layouts.contains_key(name) {
+        layouts.insert(name.to_string(), ByteLayout::record(name, value)?);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_genesis_layout_schema() -> Result<()> {
+        // Load the genesis block.
+        let genesis_block = Block::<CurrentNetwork>::read_le(CurrentNetwork::genesis_bytes())?;
+
+        // Record the layout schema.
+        let schema = genesis_block.to_layout_schema()?;
+        let names = schema.iter().map(|layout| layout.name.as_str()).collect::<Vec<_>>();
+        for expected in
+            ["Block", "Header", "Metadata", "Authority", "Ratify::Genesis", "Transaction::Execute", "Transition"]
+        {
+            assert!(names.contains(&expected), "Missing the layout for '{expected}'");
+        }
+
+        // Ensure the layouts cover every byte of the values.
+        let block = &schema[0];
+        assert_eq!(block.size, genesis_block.to_bytes_le()?.len());
+        assert_eq!(block.segments.iter().map(|segment| segment.width).sum::<usize>(), block.size);
+        assert_eq!(block.version(), Some(1));
+
+        // Ensure the schema is deterministic.
+        assert_eq!(schema, genesis_block.to_layout_schema()?);
+        Ok(())
+    }
+}
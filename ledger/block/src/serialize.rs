@@ -108,6 +108,22 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_cbor() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for expected in [crate::test_helpers::sample_genesis_block(rng)].into_iter() {
+            // Serialize
+            let expected_cbor = expected.to_cbor()?;
+            assert_eq!(expected_cbor, expected.to_cbor()?);
+            assert!(expected_cbor.len() < expected.to_string().len());
+
+            // Deserialize
+            assert_eq!(expected, Block::from_cbor(&expected_cbor)?);
+        }
+        Ok(())
+    }
+
     #[test]
     fn test_genesis_serde_json() -> Result<()> {
         // Load the genesis block.
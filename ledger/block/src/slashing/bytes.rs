@@ -0,0 +1,96 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for SlashingEvidence<N> {
+    /// Reads the slashing evidence from the buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid slashing evidence version"));
+        }
+        // Read the variant.
+        let variant = u8::read_le(&mut reader)?;
+        // Read the evidence.
+        let evidence = match variant {
+            0 => Self::DoubleBatch(Box::new((BatchHeader::read_le(&mut reader)?, BatchHeader::read_le(&mut reader)?))),
+            1 => {
+                let mut read_signed_header = || -> IoResult<SignedHeader<N>> {
+                    Ok((
+                        FromBytes::read_le(&mut reader)?,
+                        FromBytes::read_le(&mut reader)?,
+                        FromBytes::read_le(&mut reader)?,
+                    ))
+                };
+                Self::ConflictingBeacon(Box::new((read_signed_header()?, read_signed_header()?)))
+            }
+            2.. => return Err(error("Invalid slashing evidence variant")),
+        };
+        // Ensure the evidence is well-formed.
+        evidence.to_signer().map_err(error)?;
+        Ok(evidence)
+    }
+}
+
+impl<N: Network> ToBytes for SlashingEvidence<N> {
+    /// Writes the slashing evidence to the buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        match self {
+            Self::DoubleBatch(headers) => {
+                // Write the variant.
+                0u8.write_le(&mut writer)?;
+                // Write the batch headers.
+                headers.0.write_le(&mut writer)?;
+                headers.1.write_le(&mut writer)
+            }
+            Self::ConflictingBeacon(headers) => {
+                // Write the variant.
+                1u8.write_le(&mut writer)?;
+                // Write the signed headers.
+                for (previous_hash, header, signature) in [&headers.0, &headers.1] {
+                    previous_hash.write_le(&mut writer)?;
+                    header.write_le(&mut writer)?;
+                    signature.write_le(&mut writer)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::account::PrivateKey;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        for expected in crate::slashing::test_helpers::sample_slashing_evidence(&private_key, rng) {
+            // Check the byte representation.
+            let expected_bytes = expected.to_bytes_le()?;
+            assert_eq!(expected, SlashingEvidence::read_le(&expected_bytes[..])?);
+        }
+        Ok(())
+    }
+}
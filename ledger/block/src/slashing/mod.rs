@@ -0,0 +1,254 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod serialize;
+mod string;
+
+use crate::Header;
+use console::{
+    account::{Address, Signature},
+    network::prelude::*,
+    types::Field,
+};
+use ledger_committee::Committee;
+use ledger_narwhal_batch_header::BatchHeader;
+
+/// A signed block header from a beacon authority, as `(previous_hash, header, signature)`.
+pub type SignedHeader<N> = (<N as Network>::BlockHash, Header<N>, Signature<N>);
+
+/// Evidence that a validator equivocated, by signing two conflicting messages.
+///
+/// The conflicting messages are stored in increasing order of their ID, so that each
+/// equivocation has exactly one form of evidence, and one evidence ID.
+#[derive(Clone, PartialEq, Eq)]
+pub enum SlashingEvidence<N: Network> {
+    /// Two batch headers signed by the same author for the same round.
+    /// Note: A batch certificate carries the signed batch header, as `BatchCertificate::batch_header`.
+    DoubleBatch(Box<(BatchHeader<N>, BatchHeader<N>)>),
+    /// Two beacon signatures by the same signer, for different blocks at the same height.
+    ConflictingBeacon(Box<(SignedHeader<N>, SignedHeader<N>)>),
+}
+
+impl<N: Network> SlashingEvidence<N> {
+    /// Initializes evidence of two batch headers signed by the same author for the same round.
+    pub fn double_batch(first: BatchHeader<N>, second: BatchHeader<N>) -> Result<Self> {
+        // Order the batch headers by batch ID.
+        let headers = match first.batch_id() <= second.batch_id() {
+            true => (first, second),
+            false => (second, first),
+        };
+        let evidence = Self::DoubleBatch(Box::new(headers));
+        // Ensure the evidence is well-formed.
+        evidence.to_signer()?;
+        Ok(evidence)
+    }
+
+    /// Initializes evidence of two beacon signatures by the same signer, for different blocks at the same height.
+    pub fn conflicting_beacon(first: SignedHeader<N>, second: SignedHeader<N>) -> Result<Self> {
+        // Order the signed headers by block hash.
+        let headers = match to_block_hash(&first.0, &first.1)? <= to_block_hash(&second.0, &second.1)? {
+            true => (first, second),
+            false => (second, first),
+        };
+        let evidence = Self::ConflictingBeacon(Box::new(headers));
+        // Ensure the evidence is well-formed.
+        evidence.to_signer()?;
+        Ok(evidence)
+    }
+
+    /// Returns the evidence ID, as the hash of the evidence bytes.
+    pub fn to_id(&self) -> Result<Field<N>> {
+        N::hash_bhp1024(&self.to_bytes_le()?.to_bits_le())
+    }
+
+    /// Ensures the evidence is valid for the given committee, and returns the equivocating member.
+    ///
+    /// This is the check to run before acting on the evidence, i.e. when verifying a block or in finalize.
+    pub fn verify(&self, committee: &Committee<N>) -> Result<Address<N>> {
+        // Retrieve the address that signed the conflicting messages.
+        let signer = self.to_signer()?;
+        // Retrieve the member that signs with the address.
+        committee.get_validator(signer).ok_or_else(|| anyhow!("Signer '{signer}' is not in the committee"))
+    }
+
+    /// Ensures the conflicting messages are signed by the same address, and returns the address.
+    fn to_signer(&self) -> Result<Address<N>> {
+        match self {
+            Self::DoubleBatch(headers) => {
+                let (first, second) = &**headers;
+                // Ensure the batch headers are distinct, and in order.
+                ensure!(first.batch_id() < second.batch_id(), "The batch headers must be distinct, and ordered");
+                // Ensure the batch headers have the same author and round.
+                ensure!(first.author() == second.author(), "The batch headers have different authors");
+                ensure!(first.round() == second.round(), "The batch headers are for different rounds");
+                // Ensure the signatures are valid.
+                for header in [first, second] {
+                    ensure!(
+                        header.signature().verify(&header.author(), &[header.batch_id()]),
+                        "Invalid signature for batch '{}'",
+                        header.batch_id()
+                    );
+                }
+                Ok(first.author())
+            }
+            Self::ConflictingBeacon(headers) => {
+                let (first, second) = &**headers;
+                let first_hash = to_block_hash(&first.0, &first.1)?;
+                let second_hash = to_block_hash(&second.0, &second.1)?;
+                // Ensure the blocks are distinct, and in order.
+                ensure!(first_hash < second_hash, "The block headers must be distinct, and ordered");
+                // Ensure the blocks are for the same height.
+                ensure!(first.1.height() == second.1.height(), "The block headers are for different heights");
+                // Ensure the blocks have the same signer.
+                let signer = first.2.to_address();
+                ensure!(second.2.to_address() == signer, "The block headers have different signers");
+                // Ensure the signatures are valid.
+                for (hash, (_, header, signature)) in [(first_hash, first), (second_hash, second)] {
+                    ensure!(signature.verify(&signer, &[hash]), "Invalid signature for block {}", header.height());
+                }
+                Ok(signer)
+            }
+        }
+    }
+}
+
+/// Returns the block hash for the given previous block hash and block header.
+fn to_block_hash<N: Network>(previous_hash: &N::BlockHash, header: &Header<N>) -> Result<Field<N>> {
+    N::hash_bhp1024(&to_bits_le![previous_hash, header.to_root()?])
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use super::*;
+    use console::account::PrivateKey;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    /// Samples evidence of two batch headers signed by the given private key.
+    pub(crate) fn sample_double_batch(
+        private_key: &PrivateKey<CurrentNetwork>,
+        rng: &mut TestRng,
+    ) -> SlashingEvidence<CurrentNetwork> {
+        let mut sample = |timestamp| {
+            BatchHeader::new(private_key, 1, timestamp, Default::default(), Default::default(), rng).unwrap()
+        };
+        let (first, second) = (sample(1), sample(2));
+        SlashingEvidence::double_batch(first, second).unwrap()
+    }
+
+    /// Samples evidence of two beacon signatures by the given private key.
+    pub(crate) fn sample_conflicting_beacon(
+        private_key: &PrivateKey<CurrentNetwork>,
+        rng: &mut TestRng,
+    ) -> SlashingEvidence<CurrentNetwork> {
+        let header = crate::header::test_helpers::sample_block_header(rng);
+        let mut sample = || {
+            let previous_hash = <CurrentNetwork as Network>::BlockHash::from(Field::rand(rng));
+            let hash = to_block_hash(&previous_hash, &header).unwrap();
+            (previous_hash, header, private_key.sign(&[hash], rng).unwrap())
+        };
+        let (first, second) = (sample(), sample());
+        SlashingEvidence::conflicting_beacon(first, second).unwrap()
+    }
+
+    /// Samples a list of slashing evidence signed by the given private key.
+    pub(crate) fn sample_slashing_evidence(
+        private_key: &PrivateKey<CurrentNetwork>,
+        rng: &mut TestRng,
+    ) -> Vec<SlashingEvidence<CurrentNetwork>> {
+        vec![sample_double_batch(private_key, rng), sample_conflicting_beacon(private_key, rng)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::account::PrivateKey;
+    use indexmap::IndexMap;
+    use ledger_committee::MIN_VALIDATOR_STAKE;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    #[test]
+    fn test_verify() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample a committee, with a member of known private key.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let address = Address::try_from(&private_key)?;
+        let mut members = IndexMap::new();
+        members.insert(address, (MIN_VALIDATOR_STAKE, true));
+        for _ in 0..3 {
+            members.insert(Address::new(rng.gen()), (MIN_VALIDATOR_STAKE, true));
+        }
+        let committee = Committee::new(1, members)?;
+        // Sample a committee without the member.
+        let other_committee = ledger_committee::test_helpers::sample_committee(rng);
+
+        for evidence in test_helpers::sample_slashing_evidence(&private_key, rng) {
+            // Check the evidence identifies the member.
+            assert_eq!(evidence.verify(&committee)?, address);
+            // Check the evidence is rejected for a committee without the member.
+            assert!(evidence.verify(&other_committee).is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_double_batch_is_canonical() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample the batch headers.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let SlashingEvidence::DoubleBatch(headers) = test_helpers::sample_double_batch(&private_key, rng) else {
+            unreachable!()
+        };
+        let (first, second) = *headers;
+
+        // Check the evidence is the same in either order.
+        let evidence = SlashingEvidence::double_batch(first.clone(), second.clone())?;
+        assert_eq!(evidence, SlashingEvidence::double_batch(second.clone(), first.clone())?);
+        assert_eq!(evidence.to_id()?, SlashingEvidence::double_batch(second.clone(), first.clone())?.to_id()?);
+
+        // Check the same batch header is not evidence.
+        assert!(SlashingEvidence::double_batch(first.clone(), first.clone()).is_err());
+        // Check batch headers from different authors are not evidence.
+        let other_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let other = BatchHeader::new(&other_key, 1, 1, Default::default(), Default::default(), rng)?;
+        assert!(SlashingEvidence::double_batch(first, other).is_err());
+        // Check batch headers from different rounds are not evidence.
+        let next = BatchHeader::new(&private_key, 2, 1, Default::default(), [Field::rand(rng)].into(), rng)?;
+        assert!(SlashingEvidence::double_batch(second, next).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_conflicting_beacon_rejects_unsigned_header() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample the signed headers.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let SlashingEvidence::ConflictingBeacon(headers) = test_helpers::sample_conflicting_beacon(&private_key, rng)
+        else {
+            unreachable!()
+        };
+        let (first, second) = *headers;
+
+        // Check a signature over a different block is not evidence.
+        let forged = (second.0, second.1, first.2);
+        assert!(SlashingEvidence::conflicting_beacon(first, forged).is_err());
+        Ok(())
+    }
+}
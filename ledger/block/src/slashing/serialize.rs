@@ -0,0 +1,111 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Serialize for SlashingEvidence<N> {
+    /// Serializes the slashing evidence into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let mut evidence = serializer.serialize_struct("SlashingEvidence", 3)?;
+                match self {
+                    Self::DoubleBatch(headers) => {
+                        evidence.serialize_field("type", "double_batch")?;
+                        evidence.serialize_field("first", &headers.0)?;
+                        evidence.serialize_field("second", &headers.1)?;
+                    }
+                    Self::ConflictingBeacon(headers) => {
+                        evidence.serialize_field("type", "conflicting_beacon")?;
+                        evidence.serialize_field("first", &headers.0)?;
+                        evidence.serialize_field("second", &headers.1)?;
+                    }
+                }
+                evidence.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for SlashingEvidence<N> {
+    /// Deserializes the slashing evidence from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                let mut evidence = serde_json::Value::deserialize(deserializer)?;
+                let type_: String = DeserializeExt::take_from_value::<D>(&mut evidence, "type")?;
+
+                // Recover the slashing evidence.
+                match type_.as_str() {
+                    "double_batch" => Self::double_batch(
+                        DeserializeExt::take_from_value::<D>(&mut evidence, "first")?,
+                        DeserializeExt::take_from_value::<D>(&mut evidence, "second")?,
+                    ),
+                    "conflicting_beacon" => Self::conflicting_beacon(
+                        DeserializeExt::take_from_value::<D>(&mut evidence, "first")?,
+                        DeserializeExt::take_from_value::<D>(&mut evidence, "second")?,
+                    ),
+                    _ => Err(anyhow!("Invalid slashing evidence type")),
+                }
+                .map_err(de::Error::custom)
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "slashing evidence"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::account::PrivateKey;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    #[test]
+    fn test_serde_json() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        for expected in crate::slashing::test_helpers::sample_slashing_evidence(&private_key, rng) {
+            // Serialize
+            let expected_string = &expected.to_string();
+            let candidate_string = serde_json::to_string(&expected)?;
+            assert_eq!(expected, serde_json::from_str(&candidate_string)?);
+
+            // Deserialize
+            assert_eq!(expected, SlashingEvidence::from_str(expected_string)?);
+            assert_eq!(expected, serde_json::from_str(expected_string)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_bincode() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        for expected in crate::slashing::test_helpers::sample_slashing_evidence(&private_key, rng) {
+            // Serialize
+            let expected_bytes = expected.to_bytes_le()?;
+            let expected_bytes_with_size_encoding = bincode::serialize(&expected)?;
+            assert_eq!(&expected_bytes[..], &expected_bytes_with_size_encoding[8..]);
+
+            // Deserialize
+            assert_eq!(expected, SlashingEvidence::read_le(&expected_bytes[..])?);
+            assert_eq!(expected, bincode::deserialize(&expected_bytes_with_size_encoding[..])?);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,244 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The payload of the transaction under construction.
+#[derive(Clone)]
+enum Payload<N: Network> {
+    /// A program deployment, with the program owner.
+    Deployment(ProgramOwner<N>, Box<Deployment<N>>),
+    /// A program execution.
+    Execution(Execution<N>),
+    /// A program deprecation, with the program owner and mapping policy.
+    Deprecation(ProgramOwner<N>, ProgramID<N>, MappingPolicy),
+}
+
+/// A builder that assembles a transaction step by step, validating each step as it is added.
+///
+/// The payload and the fee may be added in either order. A transaction with only a fee
+/// builds a fee transaction.
+#[derive(Clone)]
+pub struct TransactionBuilder<N: Network> {
+    /// The deployment, execution, or deprecation.
+    payload: Option<Payload<N>>,
+    /// The fee.
+    fee: Option<Fee<N>>,
+    /// The expected priority fee (in microcredits).
+    priority_fee: Option<u64>,
+}
+
+impl<N: Network> Default for TransactionBuilder<N> {
+    /// Initializes an empty transaction builder.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: Network> TransactionBuilder<N> {
+    /// Initializes an empty transaction builder.
+    pub const fn new() -> Self {
+        Self { payload: None, fee: None, priority_fee: None }
+    }
+
+    /// Adds a deployment, signed by the given program owner.
+    pub fn deployment(self, owner: ProgramOwner<N>, deployment: Deployment<N>) -> Result<Self> {
+        // Ensure the deployment is not empty.
+        ensure!(!deployment.program().functions().is_empty(), "Attempted to build an empty deployment transaction");
+        // Ensure the owner signed the deployment ID.
+        ensure!(owner.verify(deployment.to_deployment_id()?), "Attempted to build a deployment with an invalid owner");
+        self.with_payload(Payload::Deployment(owner, Box::new(deployment)))
+    }
+
+    /// Adds an execution.
+    pub fn execution(self, execution: Execution<N>) -> Result<Self> {
+        // Ensure the execution is not empty.
+        ensure!(!execution.is_empty(), "Attempted to build an empty execution transaction");
+        self.with_payload(Payload::Execution(execution))
+    }
+
+    /// Adds a deprecation of the given program, signed by the given program owner.
+    pub fn deprecation(
+        self,
+        owner: ProgramOwner<N>,
+        program_id: ProgramID<N>,
+        mapping_policy: MappingPolicy,
+    ) -> Result<Self> {
+        // Ensure the owner signed the deprecation ID.
+        let deprecation_id = Transaction::deprecation_id(&program_id, mapping_policy)?;
+        ensure!(owner.verify(deprecation_id), "Attempted to build a deprecation with an invalid owner");
+        self.with_payload(Payload::Deprecation(owner, program_id, mapping_policy))
+    }
+
+    /// Attaches the fee.
+    pub fn fee(mut self, fee: Fee<N>) -> Result<Self> {
+        // Ensure the fee is not already attached.
+        ensure!(self.fee.is_none(), "Attempted to attach a second fee to the transaction");
+        self.fee = Some(fee);
+        self.validate()?;
+        Ok(self)
+    }
+
+    /// Sets the expected priority fee (in microcredits), which the attached fee must pay.
+    pub fn priority_fee(mut self, priority_fee: u64) -> Result<Self> {
+        self.priority_fee = Some(priority_fee);
+        self.validate()?;
+        Ok(self)
+    }
+
+    /// Returns the deployment or execution ID that the fee must commit to, if a payload is added.
+    pub fn deployment_or_execution_id(&self) -> Result<Option<Field<N>>> {
+        match &self.payload {
+            Some(Payload::Deployment(_, deployment)) => Ok(Some(deployment.to_deployment_id()?)),
+            Some(Payload::Execution(execution)) => Ok(Some(execution.to_execution_id()?)),
+            Some(Payload::Deprecation(_, program_id, mapping_policy)) => {
+                Ok(Some(Transaction::deprecation_id(program_id, *mapping_policy)?))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the transaction, ensuring every invariant holds.
+    pub fn build(self) -> Result<Transaction<N>> {
+        self.validate()?;
+        match (self.payload, self.fee) {
+            (Some(Payload::Deployment(owner, deployment)), Some(fee)) => {
+                Transaction::from_deployment(owner, *deployment, fee)
+            }
+            (Some(Payload::Deployment(..)), None) => bail!("A deployment transaction requires a fee"),
+            (Some(Payload::Execution(execution)), fee) => Transaction::from_execution(execution, fee),
+            (Some(Payload::Deprecation(owner, program_id, mapping_policy)), Some(fee)) => {
+                Transaction::from_deprecation(owner, program_id, mapping_policy, fee)
+            }
+            (Some(Payload::Deprecation(..)), None) => bail!("A deprecation transaction requires a fee"),
+            (None, Some(fee)) => Transaction::from_fee(fee),
+            (None, None) => bail!("Attempted to build a transaction without a payload or a fee"),
+        }
+    }
+}
+
+impl<N: Network> TransactionBuilder<N> {
+    /// Adds the given payload, ensuring no payload is already added.
+    fn with_payload(mut self, payload: Payload<N>) -> Result<Self> {
+        ensure!(self.payload.is_none(), "Attempted to add a second payload to the transaction");
+        self.payload = Some(payload);
+        self.validate()?;
+        Ok(self)
+    }
+
+    /// Ensures the attached fee is consistent with the payload and the expected priority fee.
+    fn validate(&self) -> Result<()> {
+        // If there is no fee, there is nothing to check.
+        let Some(fee) = &self.fee else {
+            return Ok(());
+        };
+        // Ensure the fee commits to the payload.
+        if let Some(id) = self.deployment_or_execution_id()? {
+            ensure!(fee.deployment_or_execution_id()? == id, "The fee does not commit to the transaction payload");
+        }
+        // Ensure the fee pays the expected priority fee.
+        if let Some(priority_fee) = self.priority_fee {
+            let amount = *fee.priority_amount()?;
+            ensure!(amount == priority_fee, "The fee pays a priority fee of {amount}, expected {priority_fee}");
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    #[test]
+    fn test_build() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for expected in [
+            crate::transaction::test_helpers::sample_deployment_transaction(true, rng),
+            crate::transaction::test_helpers::sample_deployment_transaction(false, rng),
+            crate::transaction::test_helpers::sample_execution_transaction_with_fee(true, rng),
+            crate::transaction::test_helpers::sample_execution_transaction_with_fee(false, rng),
+            crate::transaction::test_helpers::sample_deprecation_transaction(true, rng),
+            crate::transaction::test_helpers::sample_fee_public_transaction(rng),
+        ]
+        .into_iter()
+        {
+            // Rebuild the transaction, attaching the fee last.
+            let builder = match expected.clone() {
+                Transaction::Deploy(_, owner, deployment, fee) => {
+                    TransactionBuilder::new().deployment(owner, *deployment)?.fee(fee)?
+                }
+                Transaction::Execute(_, execution, fee) => {
+                    let builder = TransactionBuilder::new().execution(execution)?;
+                    match fee {
+                        Some(fee) => builder.fee(fee)?,
+                        None => builder,
+                    }
+                }
+                Transaction::Deprecate(_, owner, program_id, mapping_policy, fee) => {
+                    TransactionBuilder::new().deprecation(owner, program_id, mapping_policy)?.fee(fee)?
+                }
+                Transaction::Fee(_, fee) => TransactionBuilder::new().fee(fee)?,
+            };
+            // Check the priority fee is accepted.
+            let builder = match expected.fee_transition() {
+                Some(fee) => builder.priority_fee(*fee.priority_amount()?)?,
+                None => builder,
+            };
+            assert_eq!(expected, builder.build()?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_fails() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let Transaction::Deploy(_, owner, deployment, deployment_fee) =
+            crate::transaction::test_helpers::sample_deployment_transaction(true, rng)
+        else {
+            unreachable!()
+        };
+        let Transaction::Execute(_, execution, Some(execution_fee)) =
+            crate::transaction::test_helpers::sample_execution_transaction_with_fee(true, rng)
+        else {
+            unreachable!()
+        };
+
+        // Check a fee for a different payload is rejected, in either order.
+        let builder = TransactionBuilder::<CurrentNetwork>::new().deployment(owner, *deployment.clone())?;
+        assert!(builder.clone().fee(execution_fee.clone()).is_err());
+        let builder = TransactionBuilder::<CurrentNetwork>::new().fee(execution_fee.clone())?;
+        assert!(builder.deployment(owner, *deployment.clone()).is_err());
+
+        // Check a second payload is rejected.
+        let builder = TransactionBuilder::<CurrentNetwork>::new().execution(execution)?;
+        assert!(builder.deployment(owner, *deployment.clone()).is_err());
+
+        // Check a mismatched priority fee is rejected.
+        let priority_fee = *deployment_fee.priority_amount()?;
+        let builder = TransactionBuilder::<CurrentNetwork>::new().deployment(owner, *deployment.clone())?;
+        assert!(builder.clone().fee(deployment_fee.clone())?.priority_fee(priority_fee + 1).is_err());
+        assert!(builder.priority_fee(priority_fee + 1)?.fee(deployment_fee).is_err());
+
+        // Check a deployment without a fee is rejected.
+        let builder = TransactionBuilder::<CurrentNetwork>::new().deployment(owner, *deployment)?;
+        assert!(builder.build().is_err());
+        // Check an empty builder is rejected.
+        assert!(TransactionBuilder::<CurrentNetwork>::new().build().is_err());
+        Ok(())
+    }
+}
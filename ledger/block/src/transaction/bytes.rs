@@ -14,15 +14,20 @@
 
 use super::*;
 
+impl<N: Network> Migrate for Transaction<N> {
+    const NAME: &'static str = "transaction";
+    const VERSION: u8 = 1;
+}
+
 impl<N: Network> FromBytes for Transaction<N> {
     /// Reads the transaction from the buffer.
     #[inline]
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
         // Read the version.
-        let version = u8::read_le(&mut reader)?;
-        // Ensure the version is valid.
-        if version != 1 {
-            return Err(error("Invalid transaction version"));
+        let version = Self::read_version(&mut reader)?;
+        // Read a value written in an older byte format.
+        if version < Self::VERSION {
+            return Self::read_legacy(version, reader);
         }
 
         // Read the variant.
@@ -75,7 +80,25 @@ impl<N: Network> FromBytes for Transaction<N> {
                 // Return the ID and the transaction.
                 (id, transaction)
             }
-            3.. => return Err(error("Invalid transaction variant")),
+            3 => {
+                // Read the ID.
+                let id = N::TransactionID::read_le(&mut reader)?;
+                // Read the owner.
+                let owner = ProgramOwner::read_le(&mut reader)?;
+                // Read the program ID.
+                let program_id = ProgramID::read_le(&mut reader)?;
+                // Read the mapping policy.
+                let mapping_policy = MappingPolicy::read_le(&mut reader)?;
+                // Read the fee.
+                let fee = Fee::read_le(&mut reader)?;
+
+                // Initialize the transaction.
+                let transaction =
+                    Self::from_deprecation(owner, program_id, mapping_policy, fee).map_err(|e| error(e.to_string()))?;
+                // Return the ID and the transaction.
+                (id, transaction)
+            }
+            4.. => return Err(error("Invalid transaction variant")),
         };
 
         // Ensure the transaction ID matches.
@@ -92,7 +115,7 @@ impl<N: Network> ToBytes for Transaction<N> {
     #[inline]
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
         // Write the version.
-        1u8.write_le(&mut writer)?;
+        Self::VERSION.write_le(&mut writer)?;
 
         // Write the transaction.
         match self {
@@ -132,6 +155,20 @@ impl<N: Network> ToBytes for Transaction<N> {
                 // Write the fee.
                 fee.write_le(&mut writer)
             }
+            Self::Deprecate(id, owner, program_id, mapping_policy, fee) => {
+                // Write the variant.
+                3u8.write_le(&mut writer)?;
+                // Write the ID.
+                id.write_le(&mut writer)?;
+                // Write the owner.
+                owner.write_le(&mut writer)?;
+                // Write the program ID.
+                program_id.write_le(&mut writer)?;
+                // Write the mapping policy.
+                mapping_policy.write_le(&mut writer)?;
+                // Write the fee.
+                fee.write_le(&mut writer)
+            }
         }
     }
 }
@@ -149,6 +186,8 @@ mod tests {
             crate::transaction::test_helpers::sample_deployment_transaction(false, rng),
             crate::transaction::test_helpers::sample_execution_transaction_with_fee(true, rng),
             crate::transaction::test_helpers::sample_execution_transaction_with_fee(false, rng),
+            crate::transaction::test_helpers::sample_deprecation_transaction(true, rng),
+            crate::transaction::test_helpers::sample_deprecation_transaction(false, rng),
         ]
         .into_iter()
         {
@@ -0,0 +1,194 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A component of a transaction that is not committed to by the transaction ID.
+///
+/// These components may differ between two transactions with the same ID, for example,
+/// when a transaction is proven again, or when the program owner signs again.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MalleableComponent {
+    /// The program owner of a deployment or deprecation.
+    ProgramOwner,
+    /// The edition of a deployment.
+    DeploymentEdition,
+    /// The program declarations of a deployment, other than its functions.
+    DeploymentDeclarations,
+    /// The verifying keys and certificates of a deployment.
+    DeploymentVerifyingKeys,
+    /// The global state root of an execution.
+    ExecutionGlobalStateRoot,
+    /// The proof of an execution.
+    ExecutionProof,
+    /// The global state root of a fee.
+    FeeGlobalStateRoot,
+    /// The proof of a fee.
+    FeeProof,
+}
+
+impl Display for MalleableComponent {
+    /// Prints the malleable component as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::ProgramOwner => write!(f, "program owner"),
+            Self::DeploymentEdition => write!(f, "deployment edition"),
+            Self::DeploymentDeclarations => write!(f, "deployment declarations"),
+            Self::DeploymentVerifyingKeys => write!(f, "deployment verifying keys"),
+            Self::ExecutionGlobalStateRoot => write!(f, "execution global state root"),
+            Self::ExecutionProof => write!(f, "execution proof"),
+            Self::FeeGlobalStateRoot => write!(f, "fee global state root"),
+            Self::FeeProof => write!(f, "fee proof"),
+        }
+    }
+}
+
+impl<N: Network> Transaction<N> {
+    /// Returns the components of the transaction that are not committed to by the transaction ID.
+    ///
+    /// The transaction ID commits to the transitions (or deployed functions) of the transaction,
+    /// so two transactions with the same ID may still differ in any of these components.
+    pub fn malleable_components(&self) -> Vec<MalleableComponent> {
+        let mut components = match self {
+            Self::Deploy(..) => vec![
+                MalleableComponent::ProgramOwner,
+                MalleableComponent::DeploymentEdition,
+                MalleableComponent::DeploymentDeclarations,
+                MalleableComponent::DeploymentVerifyingKeys,
+            ],
+            Self::Execute(_, execution, _) => match execution.proof() {
+                Some(_) => vec![MalleableComponent::ExecutionGlobalStateRoot, MalleableComponent::ExecutionProof],
+                None => vec![MalleableComponent::ExecutionGlobalStateRoot],
+            },
+            Self::Fee(..) => vec![],
+            Self::Deprecate(..) => vec![MalleableComponent::ProgramOwner],
+        };
+        if let Some(fee) = self.fee_transition() {
+            components.push(MalleableComponent::FeeGlobalStateRoot);
+            if fee.proof().is_some() {
+                components.push(MalleableComponent::FeeProof);
+            }
+        }
+        components
+    }
+
+    /// Returns the transaction from the given bytes, ensuring the bytes are its canonical encoding.
+    ///
+    /// Unlike `Transaction::read_le`, this rejects trailing bytes, and any component that
+    /// does not re-encode to the exact bytes it was decoded from.
+    pub fn from_bytes_le_strict(bytes: &[u8]) -> Result<Self> {
+        let mut reader = bytes;
+        // Decode the transaction.
+        let transaction = Self::read_le(&mut reader)?;
+        // Ensure there are no trailing bytes.
+        ensure!(reader.is_empty(), "Found {} trailing bytes after transaction '{}'", reader.len(), transaction.id());
+        // Ensure the transaction re-encodes to the given bytes.
+        ensure!(transaction.to_bytes_le()? == bytes, "Transaction '{}' is not canonically encoded", transaction.id());
+        Ok(transaction)
+    }
+
+    /// Returns the canonical form of the transaction.
+    ///
+    /// The canonical form recomputes the transaction ID from the components of the transaction,
+    /// and is guaranteed to round trip through `Transaction::from_bytes_le_strict`.
+    pub fn canonicalize(&self) -> Result<Self> {
+        // Reconstruct the transaction from its components.
+        let transaction = match self.clone() {
+            Self::Deploy(_, owner, deployment, fee) => Self::from_deployment(owner, *deployment, fee)?,
+            Self::Execute(_, execution, fee) => Self::from_execution(execution, fee)?,
+            Self::Fee(_, fee) => Self::from_fee(fee)?,
+            Self::Deprecate(_, owner, program_id, mapping_policy, fee) => {
+                Self::from_deprecation(owner, program_id, mapping_policy, fee)?
+            }
+        };
+        // Ensure the transaction round trips through its canonical encoding.
+        Self::from_bytes_le_strict(&transaction.to_bytes_le()?)
+    }
+
+    /// Returns `true` if the transaction is in canonical form.
+    pub fn is_canonical(&self) -> bool {
+        matches!(self.canonicalize(), Ok(transaction) if transaction == *self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_canonical() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for transaction in [
+            crate::transaction::test_helpers::sample_deployment_transaction(true, rng),
+            crate::transaction::test_helpers::sample_execution_transaction_with_fee(false, rng),
+            crate::transaction::test_helpers::sample_deprecation_transaction(true, rng),
+            crate::transaction::test_helpers::sample_fee_public_transaction(rng),
+        ] {
+            // Ensure the transaction is canonical.
+            assert!(transaction.is_canonical());
+            assert_eq!(transaction, transaction.canonicalize()?);
+
+            // Ensure the strict decoder accepts the canonical encoding.
+            let bytes = transaction.to_bytes_le()?;
+            assert_eq!(transaction, Transaction::from_bytes_le_strict(&bytes)?);
+
+            // Ensure the strict decoder rejects trailing bytes, which the default decoder ignores.
+            let mut redundant_bytes = bytes.clone();
+            redundant_bytes.push(0u8);
+            assert_eq!(transaction, Transaction::read_le(&redundant_bytes[..])?);
+            assert!(Transaction::<CurrentNetwork>::from_bytes_le_strict(&redundant_bytes).is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_canonicalize_id() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Construct a transaction with an incorrect ID.
+        let transaction = crate::transaction::test_helpers::sample_execution_transaction_with_fee(true, rng);
+        let (execution, fee) = match transaction.clone() {
+            Transaction::Execute(_, execution, fee) => (execution, fee),
+            _ => unreachable!(),
+        };
+        let malformed = Transaction::Execute(Field::rand(rng).into(), execution, fee);
+
+        // Ensure the transaction is not canonical, and canonicalizes to the correct ID.
+        assert!(!malformed.is_canonical());
+        assert_eq!(transaction, malformed.canonicalize()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_malleable_components() {
+        let rng = &mut TestRng::default();
+
+        // Ensure the program owner of a deprecation is reported.
+        let transaction = crate::transaction::test_helpers::sample_deprecation_transaction(false, rng);
+        let components = transaction.malleable_components();
+        assert_eq!(components[0], MalleableComponent::ProgramOwner);
+        assert!(components.contains(&MalleableComponent::FeeGlobalStateRoot));
+
+        // Ensure the execution components are reported before the fee components.
+        let transaction = crate::transaction::test_helpers::sample_execution_transaction_with_fee(true, rng);
+        let components = transaction.malleable_components();
+        assert_eq!(components[0], MalleableComponent::ExecutionGlobalStateRoot);
+        assert!(components.contains(&MalleableComponent::FeeGlobalStateRoot));
+        assert!(!components.contains(&MalleableComponent::ProgramOwner));
+    }
+}
@@ -0,0 +1,287 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The synthesis cost of a single function in a deployment.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct FunctionCost<N: Network> {
+    /// The function name.
+    function_name: Identifier<N>,
+    /// The number of variables in the function circuit.
+    num_variables: u64,
+    /// The number of constraints in the function circuit.
+    num_constraints: u64,
+    /// The synthesis cost in microcredits.
+    synthesis_cost: u64,
+}
+
+impl<N: Network> FunctionCost<N> {
+    /// Returns the function name.
+    pub const fn function_name(&self) -> &Identifier<N> {
+        &self.function_name
+    }
+
+    /// Returns the number of variables in the function circuit.
+    pub const fn num_variables(&self) -> u64 {
+        self.num_variables
+    }
+
+    /// Returns the number of constraints in the function circuit.
+    pub const fn num_constraints(&self) -> u64 {
+        self.num_constraints
+    }
+
+    /// Returns the synthesis cost in microcredits.
+    pub const fn synthesis_cost(&self) -> u64 {
+        self.synthesis_cost
+    }
+}
+
+/// The itemized cost of a deployment, in microcredits.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DeploymentCost<N: Network> {
+    /// The size of the deployment in bytes.
+    size_in_bytes: u64,
+    /// The storage cost in microcredits.
+    storage_cost: u64,
+    /// The namespace cost in microcredits.
+    namespace_cost: u64,
+    /// The synthesis cost of each function, in the order of the deployment.
+    functions: Vec<FunctionCost<N>>,
+    /// The synthesis cost in microcredits.
+    synthesis_cost: u64,
+    /// The total cost in microcredits.
+    total_cost: u64,
+}
+
+impl<N: Network> DeploymentCost<N> {
+    /// Returns the size of the deployment in bytes.
+    pub const fn size_in_bytes(&self) -> u64 {
+        self.size_in_bytes
+    }
+
+    /// Returns the storage cost in microcredits.
+    pub const fn storage_cost(&self) -> u64 {
+        self.storage_cost
+    }
+
+    /// Returns the namespace cost in microcredits.
+    pub const fn namespace_cost(&self) -> u64 {
+        self.namespace_cost
+    }
+
+    /// Returns the synthesis cost of each function, in the order of the deployment.
+    pub fn functions(&self) -> &[FunctionCost<N>] {
+        &self.functions
+    }
+
+    /// Returns the synthesis cost in microcredits.
+    pub const fn synthesis_cost(&self) -> u64 {
+        self.synthesis_cost
+    }
+
+    /// Returns the total cost in microcredits.
+    /// This is the *minimum* fee required to publish the deployment.
+    pub const fn total_cost(&self) -> u64 {
+        self.total_cost
+    }
+}
+
+/// The limits to check a deployment against, before it is published.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DeploymentLimits {
+    /// The maximum number of constraints in a single function.
+    pub max_function_constraints: u64,
+    /// The maximum number of variables in a single function.
+    pub max_function_variables: u64,
+    /// The maximum number of combined constraints in the deployment.
+    pub max_combined_constraints: u64,
+    /// The maximum size of the deployment in bytes.
+    pub max_size_in_bytes: u64,
+    /// The maximum total cost of the deployment in microcredits.
+    pub max_total_cost: u64,
+}
+
+impl DeploymentLimits {
+    /// Returns the limits enforced by consensus for the given network.
+    pub const fn consensus<N: Network>() -> Self {
+        Self {
+            max_function_constraints: N::MAX_DEPLOYMENT_LIMIT,
+            max_function_variables: u64::MAX,
+            max_combined_constraints: N::MAX_DEPLOYMENT_LIMIT,
+            max_size_in_bytes: u64::MAX,
+            max_total_cost: N::MAX_FEE,
+        }
+    }
+}
+
+impl<N: Network> Deployment<N> {
+    /// Returns the itemized cost of the deployment, with the synthesis cost of each function.
+    pub fn cost_breakdown(&self) -> Result<DeploymentCost<N>> {
+        // Determine the number of bytes in the deployment.
+        let size_in_bytes = self.size_in_bytes()?;
+        // Determine the number of characters in the program ID.
+        let num_characters = u32::try_from(self.program_id().name().to_string().len())?;
+
+        // Compute the storage cost in microcredits.
+        let storage_cost = size_in_bytes
+            .checked_mul(N::DEPLOYMENT_FEE_MULTIPLIER)
+            .ok_or_else(|| anyhow!("The storage cost computation overflowed for '{}'", self.program_id()))?;
+
+        // Compute the synthesis cost of each function in microcredits.
+        let functions = self
+            .verifying_keys
+            .iter()
+            .map(|(function_name, (verifying_key, _))| {
+                let num_variables = verifying_key.circuit_info.num_variables as u64;
+                let num_constraints = verifying_key.circuit_info.num_constraints as u64;
+                let synthesis_cost = num_constraints.checked_mul(N::SYNTHESIS_FEE_MULTIPLIER).ok_or_else(|| {
+                    anyhow!("The synthesis cost computation overflowed for '{}/{function_name}'", self.program_id())
+                })?;
+                Ok(FunctionCost { function_name: *function_name, num_variables, num_constraints, synthesis_cost })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Compute the synthesis cost in microcredits.
+        let synthesis_cost = functions
+            .iter()
+            .try_fold(0u64, |acc, function| acc.checked_add(function.synthesis_cost))
+            .ok_or_else(|| anyhow!("The synthesis cost computation overflowed for '{}'", self.program_id()))?;
+
+        // Compute the namespace cost in credits: 10^(10 - num_characters).
+        let namespace_cost = 10u64
+            .checked_pow(10u32.saturating_sub(num_characters))
+            .ok_or_else(|| anyhow!("The namespace cost computation overflowed for '{}'", self.program_id()))?
+            .saturating_mul(1_000_000); // 1 microcredit = 1e-6 credits.
+
+        // Compute the total cost in microcredits.
+        let total_cost = storage_cost
+            .checked_add(synthesis_cost)
+            .and_then(|x| x.checked_add(namespace_cost))
+            .ok_or_else(|| anyhow!("The total cost computation overflowed for '{}'", self.program_id()))?;
+
+        Ok(DeploymentCost { size_in_bytes, storage_cost, namespace_cost, functions, synthesis_cost, total_cost })
+    }
+
+    /// Checks that the deployment is within the given limits.
+    /// On failure, the error message names the first function or quantity that exceeds its limit.
+    pub fn check_limits(&self, limits: &DeploymentLimits) -> Result<()> {
+        // Compute the itemized cost of the deployment.
+        let cost = self.cost_breakdown()?;
+        let program_id = self.program_id();
+
+        // Ensure each function is within the limits.
+        for function in cost.functions() {
+            let function_name = function.function_name();
+            ensure!(
+                function.num_constraints() <= limits.max_function_constraints,
+                "Function '{program_id}/{function_name}' has {} constraints, which exceeds the limit of {}",
+                function.num_constraints(),
+                limits.max_function_constraints
+            );
+            ensure!(
+                function.num_variables() <= limits.max_function_variables,
+                "Function '{program_id}/{function_name}' has {} variables, which exceeds the limit of {}",
+                function.num_variables(),
+                limits.max_function_variables
+            );
+        }
+
+        // Ensure the combined number of constraints is within the limit.
+        let num_combined_constraints = self.num_combined_constraints()?;
+        ensure!(
+            num_combined_constraints <= limits.max_combined_constraints,
+            "Program '{program_id}' has {num_combined_constraints} combined constraints, which exceeds the limit of {}",
+            limits.max_combined_constraints
+        );
+        // Ensure the size of the deployment is within the limit.
+        ensure!(
+            cost.size_in_bytes() <= limits.max_size_in_bytes,
+            "Deployment for '{program_id}' is {} bytes, which exceeds the limit of {}",
+            cost.size_in_bytes(),
+            limits.max_size_in_bytes
+        );
+        // Ensure the total cost is within the limit.
+        ensure!(
+            cost.total_cost() <= limits.max_total_cost,
+            "Deployment for '{program_id}' costs {} microcredits, which exceeds the limit of {}",
+            cost.total_cost(),
+            limits.max_total_cost
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_cost_breakdown() {
+        let rng = &mut TestRng::default();
+
+        // Sample the deployment.
+        let deployment = test_helpers::sample_deployment(rng);
+        // Compute the itemized cost.
+        let cost = deployment.cost_breakdown().unwrap();
+
+        // Ensure there is an entry for each function, in order.
+        assert_eq!(cost.functions().len(), deployment.verifying_keys().len());
+        for (function, (function_name, (verifying_key, _))) in cost.functions().iter().zip_eq(deployment.verifying_keys())
+        {
+            assert_eq!(function.function_name(), function_name);
+            assert_eq!(function.num_constraints(), verifying_key.circuit_info.num_constraints as u64);
+            assert_eq!(function.num_variables(), verifying_key.circuit_info.num_variables as u64);
+        }
+
+        // Ensure the costs are consistent.
+        assert_eq!(cost.size_in_bytes(), deployment.size_in_bytes().unwrap());
+        assert_eq!(cost.storage_cost(), cost.size_in_bytes() * CurrentNetwork::DEPLOYMENT_FEE_MULTIPLIER);
+        assert_eq!(
+            cost.synthesis_cost(),
+            deployment.num_combined_constraints().unwrap() * CurrentNetwork::SYNTHESIS_FEE_MULTIPLIER
+        );
+        assert_eq!(cost.total_cost(), cost.storage_cost() + cost.synthesis_cost() + cost.namespace_cost());
+    }
+
+    #[test]
+    fn test_check_limits() {
+        let rng = &mut TestRng::default();
+
+        // Sample the deployment.
+        let deployment = test_helpers::sample_deployment(rng);
+        let cost = deployment.cost_breakdown().unwrap();
+        let num_constraints = cost.functions()[0].num_constraints();
+
+        // Ensure the deployment is within the consensus limits.
+        deployment.check_limits(&DeploymentLimits::consensus::<CurrentNetwork>()).unwrap();
+
+        // Ensure the deployment fails when a function exceeds the constraint limit.
+        let limits = DeploymentLimits {
+            max_function_constraints: num_constraints - 1,
+            ..DeploymentLimits::consensus::<CurrentNetwork>()
+        };
+        let error = deployment.check_limits(&limits).unwrap_err();
+        assert!(error.to_string().contains("testing.aleo/compute"));
+
+        // Ensure the deployment fails when the total cost exceeds the limit.
+        let limits =
+            DeploymentLimits { max_total_cost: cost.total_cost() - 1, ..DeploymentLimits::consensus::<CurrentNetwork>() };
+        assert!(deployment.check_limits(&limits).is_err());
+    }
+}
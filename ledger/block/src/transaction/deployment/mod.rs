@@ -18,6 +18,9 @@ mod bytes;
 mod serialize;
 mod string;
 
+mod cost;
+pub use cost::*;
+
 use crate::Transaction;
 use console::{
     network::prelude::*,
@@ -27,12 +30,14 @@ use console::{
 use synthesizer_program::Program;
 use synthesizer_snark::{Certificate, VerifyingKey};
 
+use std::sync::Arc;
+
 #[derive(Clone, PartialEq, Eq)]
 pub struct Deployment<N: Network> {
     /// The edition.
     edition: u16,
-    /// The program.
-    program: Program<N>,
+    /// The program, shared with the stack of the program once it is deployed.
+    program: Arc<Program<N>>,
     /// The mapping of function names to their verifying key and certificate.
     verifying_keys: Vec<(Identifier<N>, (VerifyingKey<N>, Certificate<N>))>,
 }
@@ -41,11 +46,11 @@ impl<N: Network> Deployment<N> {
     /// Initializes a new deployment.
     pub fn new(
         edition: u16,
-        program: Program<N>,
+        program: impl Into<Arc<Program<N>>>,
         verifying_keys: Vec<(Identifier<N>, (VerifyingKey<N>, Certificate<N>))>,
     ) -> Result<Self> {
         // Construct the deployment.
-        let deployment = Self { edition, program, verifying_keys };
+        let deployment = Self { edition, program: program.into(), verifying_keys };
         // Ensure the deployment is ordered.
         deployment.check_is_ordered()?;
         // Return the deployment.
@@ -56,10 +61,11 @@ impl<N: Network> Deployment<N> {
     pub fn check_is_ordered(&self) -> Result<()> {
         let program_id = self.program.id();
 
-        // Ensure the edition matches.
+        // Ensure the edition is at least the network edition.
+        // Note: An edition greater than the network edition is an upgrade of an existing program.
         ensure!(
-            self.edition == N::EDITION,
-            "Deployed the wrong edition (expected '{}', found '{}').",
+            self.edition >= N::EDITION,
+            "Deployed the wrong edition (expected at least '{}', found '{}').",
             N::EDITION,
             self.edition
         );
@@ -109,13 +115,23 @@ impl<N: Network> Deployment<N> {
         self.edition
     }
 
+    /// Returns `true` if the deployment is an upgrade of an existing program.
+    pub const fn is_upgrade(&self) -> bool {
+        self.edition > N::EDITION
+    }
+
     /// Returns the program.
-    pub const fn program(&self) -> &Program<N> {
+    pub fn program(&self) -> &Program<N> {
+        &self.program
+    }
+
+    /// Returns the shared program, which is cloned without copying the program.
+    pub const fn shared_program(&self) -> &Arc<Program<N>> {
         &self.program
     }
 
     /// Returns the program.
-    pub const fn program_id(&self) -> &ProgramID<N> {
+    pub fn program_id(&self) -> &ProgramID<N> {
         self.program.id()
     }
 
@@ -21,7 +21,7 @@ impl<N: Network> Serialize for Deployment<N> {
             true => {
                 let mut deployment = serializer.serialize_struct("Deployment", 3)?;
                 deployment.serialize_field("edition", &self.edition)?;
-                deployment.serialize_field("program", &self.program)?;
+                deployment.serialize_field("program", self.program())?;
                 deployment.serialize_field("verifying_keys", &self.verifying_keys)?;
                 deployment.end()
             }
@@ -43,7 +43,7 @@ impl<'de, N: Network> Deserialize<'de> for Deployment<N> {
                     // Retrieve the edition.
                     DeserializeExt::take_from_value::<D>(&mut deployment, "edition")?,
                     // Retrieve the program.
-                    DeserializeExt::take_from_value::<D>(&mut deployment, "program")?,
+                    Program::<N>::take_from_value::<D>(&mut deployment, "program")?,
                     // Retrieve the verifying keys.
                     DeserializeExt::take_from_value::<D>(&mut deployment, "verifying_keys")?,
                 )
@@ -20,7 +20,8 @@ impl<N: Network> FromBytes for Execution<N> {
         // Read the version.
         let version = u8::read_le(&mut reader)?;
         // Ensure the version is valid.
-        if version != 1 {
+        // Note: Version 2 is a sequenced execution.
+        if version != 1 && version != 2 {
             return Err(error("Invalid execution version"));
         }
         // Read the number of transitions.
@@ -42,8 +43,15 @@ impl<N: Network> FromBytes for Execution<N> {
             1 => Some(Proof::read_le(&mut reader)?),
             _ => return Err(error(format!("Invalid proof variant '{proof_variant}'"))),
         };
+        // Read the sequence number.
+        let sequence = match version {
+            2 => Some(u64::read_le(&mut reader)?),
+            _ => None,
+        };
         // Return the new `Execution` instance.
-        Self::from(transitions.into_iter(), global_state_root, proof).map_err(|e| error(e.to_string()))
+        Self::from(transitions.into_iter(), global_state_root, proof)
+            .and_then(|execution| execution.with_sequence(sequence))
+            .map_err(|e| error(e.to_string()))
     }
 }
 
@@ -51,7 +59,10 @@ impl<N: Network> ToBytes for Execution<N> {
     /// Writes the execution to a buffer.
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
         // Write the version.
-        1u8.write_le(&mut writer)?;
+        match self.sequence {
+            None => 1u8.write_le(&mut writer)?,
+            Some(_) => 2u8.write_le(&mut writer)?,
+        }
         // Write the number of transitions.
         (u8::try_from(self.transitions.len()).map_err(|e| error(e.to_string()))?).write_le(&mut writer)?;
         // Write the transitions.
@@ -68,6 +79,10 @@ impl<N: Network> ToBytes for Execution<N> {
                 proof.write_le(&mut writer)?;
             }
         }
+        // Write the sequence number.
+        if let Some(sequence) = self.sequence {
+            sequence.write_le(&mut writer)?;
+        }
         Ok(())
     }
 }
@@ -86,6 +101,11 @@ mod tests {
         // Check the byte representation.
         let expected_bytes = expected.to_bytes_le()?;
         assert_eq!(expected, Execution::read_le(&expected_bytes[..])?);
+
+        // Check the byte representation of a sequenced execution.
+        let expected = expected.with_sequence(Some(rng.gen()))?;
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, Execution::read_le(&expected_bytes[..])?);
         Ok(())
     }
 }
@@ -0,0 +1,187 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::Fee;
+
+use std::collections::HashMap;
+
+impl<N: Network> Execution<N> {
+    /// Returns the call graph of the execution, in the Graphviz DOT format.
+    ///
+    /// The `call_graph` maps each transition ID to the IDs of the transitions it calls,
+    /// as constructed by `Process::construct_call_graph`. If a `fee` is given,
+    /// it is attached to the root transition of the execution.
+    pub fn to_graphviz(
+        &self,
+        call_graph: &HashMap<N::TransitionID, Vec<N::TransitionID>>,
+        fee: Option<&Fee<N>>,
+    ) -> Result<String> {
+        // Retrieve the root transition and the calls.
+        let root = self.peek()?;
+        let calls = self.graph_calls(call_graph)?;
+
+        let mut graph = String::from("digraph execution {\n");
+        // Write a node for each transition.
+        for transition in self.transitions() {
+            graph.push_str(&format!("    \"{}\" [label=\"{}\"];\n", transition.id(), Self::graph_label(transition)));
+        }
+        // Write an edge for each call.
+        for (caller, callee) in calls {
+            graph.push_str(&format!("    \"{caller}\" -> \"{callee}\";\n"));
+        }
+        // Write the fee, if it exists.
+        if let Some(fee) = fee {
+            let label = format!("{}\\nfee: {} microcredits", Self::graph_label(fee.transition()), *fee.amount()?);
+            graph.push_str(&format!("    \"{}\" [label=\"{label}\", style=dashed];\n", fee.transition_id()));
+            graph.push_str(&format!(
+                "    \"{}\" -> \"{}\" [style=dashed, label=\"fee\"];\n",
+                root.id(),
+                fee.transition_id()
+            ));
+        }
+        graph.push_str("}\n");
+        Ok(graph)
+    }
+
+    /// Returns the call graph of the execution, as a JSON object.
+    ///
+    /// The `call_graph` maps each transition ID to the IDs of the transitions it calls,
+    /// as constructed by `Process::construct_call_graph`. If a `fee` is given,
+    /// it is attached to the root transition of the execution.
+    pub fn to_json_graph(
+        &self,
+        call_graph: &HashMap<N::TransitionID, Vec<N::TransitionID>>,
+        fee: Option<&Fee<N>>,
+    ) -> Result<serde_json::Value> {
+        // Ensure the call graph is consistent with the execution.
+        self.graph_calls(call_graph)?;
+
+        // Construct a node for each transition.
+        let transitions = self
+            .transitions()
+            .map(|transition| {
+                let mut node = Self::graph_node(transition);
+                node["calls"] = serde_json::json!(
+                    call_graph.get(transition.id()).into_iter().flatten().map(ToString::to_string).collect::<Vec<_>>()
+                );
+                node
+            })
+            .collect::<Vec<_>>();
+        // Construct a node for the fee, if it exists.
+        let fee = match fee {
+            Some(fee) => {
+                let mut node = Self::graph_node(fee.transition());
+                node["amount"] = serde_json::json!(*fee.amount()?);
+                node
+            }
+            None => serde_json::Value::Null,
+        };
+
+        Ok(serde_json::json!({
+            "root": self.peek()?.id().to_string(),
+            "transitions": transitions,
+            "fee": fee,
+        }))
+    }
+}
+
+impl<N: Network> Execution<N> {
+    /// Returns the `(caller, callee)` pairs of the given call graph, in the order of the transitions.
+    fn graph_calls<'a>(
+        &self,
+        call_graph: &'a HashMap<N::TransitionID, Vec<N::TransitionID>>,
+    ) -> Result<Vec<(&'a N::TransitionID, &'a N::TransitionID)>> {
+        // Ensure every transition in the call graph is in the execution.
+        for (caller, callees) in call_graph {
+            for transition_id in std::iter::once(caller).chain(callees) {
+                ensure!(
+                    self.contains_transition(transition_id),
+                    "Transition '{transition_id}' in the call graph is not in the execution"
+                );
+            }
+        }
+        // Collect the calls, in the order of the transitions.
+        Ok(self
+            .transitions
+            .keys()
+            .filter_map(|transition_id| call_graph.get_key_value(transition_id))
+            .flat_map(|(caller, callees)| callees.iter().map(move |callee| (caller, callee)))
+            .collect())
+    }
+
+    /// Returns the label of the given transition, for the Graphviz DOT format.
+    fn graph_label(transition: &Transition<N>) -> String {
+        format!(
+            "{}/{}\\ninputs: [{}]\\noutputs: [{}]",
+            transition.program_id(),
+            transition.function_name(),
+            transition.input_kinds().map(|kind| kind.as_str()).collect::<Vec<_>>().join(", "),
+            transition.output_kinds().map(|kind| kind.as_str()).collect::<Vec<_>>().join(", "),
+        )
+    }
+
+    /// Returns the node of the given transition, as a JSON object.
+    fn graph_node(transition: &Transition<N>) -> serde_json::Value {
+        serde_json::json!({
+            "id": transition.id().to_string(),
+            "program_id": transition.program_id().to_string(),
+            "function_name": transition.function_name().to_string(),
+            "inputs": transition.input_kinds().map(|kind| kind.as_str()).collect::<Vec<_>>(),
+            "outputs": transition.output_kinds().map(|kind| kind.as_str()).collect::<Vec<_>>(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_graph() {
+        let rng = &mut TestRng::default();
+
+        // Sample the execution.
+        let execution = test_helpers::sample_execution(rng);
+        // Construct a call graph with no calls.
+        let call_graph: HashMap<_, _> = execution.transitions().map(|transition| (*transition.id(), vec![])).collect();
+
+        // Ensure the Graphviz graph contains every transition.
+        let graphviz = execution.to_graphviz(&call_graph, None).unwrap();
+        assert!(graphviz.starts_with("digraph execution {"));
+        for transition in execution.transitions() {
+            assert!(graphviz.contains(&format!("\"{}\" [label=\"{}", transition.id(), transition.program_id())));
+        }
+
+        // Ensure the JSON graph contains every transition.
+        let json = execution.to_json_graph(&call_graph, None).unwrap();
+        assert_eq!(json["root"], execution.peek().unwrap().id().to_string());
+        assert_eq!(json["transitions"].as_array().unwrap().len(), execution.len());
+        assert_eq!(json["fee"], serde_json::Value::Null);
+        for (node, transition) in json["transitions"].as_array().unwrap().iter().zip_eq(execution.transitions()) {
+            assert_eq!(node["id"], transition.id().to_string());
+            assert_eq!(node["function_name"], transition.function_name().to_string());
+            assert_eq!(node["inputs"].as_array().unwrap().len(), transition.inputs().len());
+            assert_eq!(node["outputs"].as_array().unwrap().len(), transition.outputs().len());
+            assert!(node["calls"].as_array().unwrap().is_empty());
+        }
+
+        // Ensure a call graph with an unknown transition is rejected.
+        let unknown = rng.gen();
+        let mut invalid_call_graph = call_graph.clone();
+        invalid_call_graph.insert(*execution.peek().unwrap().id(), vec![unknown]);
+        assert!(execution.to_graphviz(&invalid_call_graph, None).is_err());
+        assert!(execution.to_json_graph(&invalid_call_graph, None).is_err());
+    }
+}
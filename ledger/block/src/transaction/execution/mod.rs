@@ -13,9 +13,15 @@
 // limitations under the License.
 
 mod bytes;
+mod graph;
 mod serialize;
 mod string;
 
+#[cfg(feature = "trace")]
+mod trace;
+#[cfg(feature = "trace")]
+pub use trace::*;
+
 use crate::{Transaction, Transition};
 use console::{account::Field, network::prelude::*, program::ProgramID};
 use synthesizer_snark::Proof;
@@ -30,12 +36,14 @@ pub struct Execution<N: Network> {
     global_state_root: N::StateRoot,
     /// The proof.
     proof: Option<Proof<N>>,
+    /// The sequence number of the fee payer, if the execution is sequenced.
+    sequence: Option<u64>,
 }
 
 impl<N: Network> Execution<N> {
     /// Initialize a new `Execution` instance.
     pub fn new() -> Self {
-        Self { transitions: Default::default(), global_state_root: Default::default(), proof: None }
+        Self { transitions: Default::default(), global_state_root: Default::default(), proof: None, sequence: None }
     }
 
     /// Initializes a new `Execution` instance with the given transitions.
@@ -45,13 +53,27 @@ impl<N: Network> Execution<N> {
         proof: Option<Proof<N>>,
     ) -> Result<Self> {
         // Construct the execution.
-        let execution = Self { transitions: transitions.map(|t| (*t.id(), t)).collect(), global_state_root, proof };
+        let execution =
+            Self { transitions: transitions.map(|t| (*t.id(), t)).collect(), global_state_root, proof, sequence: None };
         // Ensure the transitions are not empty.
         ensure!(!execution.transitions.is_empty(), "Execution cannot initialize from empty list of transitions");
         // Return the new `Execution` instance.
         Ok(execution)
     }
 
+    /// Returns the execution with the given sequence number of the fee payer.
+    ///
+    /// A sequenced execution is committed to by its execution ID, and is only accepted
+    /// if its public fee payer has not used the sequence number, and has used all lower ones.
+    pub fn with_sequence(mut self, sequence: Option<u64>) -> Result<Self> {
+        // Ensure the number of leaves, including the sequence number, is within the Merkle tree size.
+        if sequence.is_some() {
+            Transaction::<N>::check_execution_size(self.transitions.len().saturating_add(1))?;
+        }
+        self.sequence = sequence;
+        Ok(self)
+    }
+
     /// Returns the size in bytes.
     pub fn size_in_bytes(&self) -> Result<u64> {
         Ok(u64::try_from(self.to_bytes_le()?.len())?)
@@ -67,6 +89,18 @@ impl<N: Network> Execution<N> {
         self.proof.as_ref()
     }
 
+    /// Returns the sequence number of the fee payer, if the execution is sequenced.
+    pub const fn sequence(&self) -> Option<u64> {
+        self.sequence
+    }
+
+    /// Returns `true` if the execution does not consume or produce any records.
+    pub fn is_public_only(&self) -> bool {
+        self.transitions
+            .values()
+            .all(|transition| transition.serial_numbers().next().is_none() && transition.commitments().next().is_none())
+    }
+
     /// Returns the execution ID.
     pub fn to_execution_id(&self) -> Result<Field<N>> {
         Ok(*Transaction::execution_tree(self, &None)?.root())
@@ -19,13 +19,19 @@ impl<N: Network> Serialize for Execution<N> {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         match serializer.is_human_readable() {
             true => {
-                let mut execution = serializer.serialize_struct("Execution", 2 + self.proof.is_some() as usize)?;
+                let mut execution = serializer.serialize_struct(
+                    "Execution",
+                    2 + self.proof.is_some() as usize + self.sequence.is_some() as usize,
+                )?;
                 execution
                     .serialize_field("transitions", &self.transitions.values().collect::<Vec<&Transition<N>>>())?;
                 execution.serialize_field("global_state_root", &self.global_state_root)?;
                 if let Some(proof) = &self.proof {
                     execution.serialize_field("proof", proof)?;
                 }
+                if let Some(sequence) = &self.sequence {
+                    execution.serialize_field("sequence", sequence)?;
+                }
                 execution.end()
             }
             false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
@@ -48,8 +54,15 @@ impl<'de, N: Network> Deserialize<'de> for Execution<N> {
                 let proof =
                     serde_json::from_value(execution.get_mut("proof").unwrap_or(&mut serde_json::Value::Null).take())
                         .map_err(de::Error::custom)?;
+                // Retrieve the sequence number.
+                let sequence = serde_json::from_value(
+                    execution.get_mut("sequence").unwrap_or(&mut serde_json::Value::Null).take(),
+                )
+                .map_err(de::Error::custom)?;
                 // Recover the execution.
-                Self::from(transitions.into_iter(), global_state_root, proof).map_err(de::Error::custom)
+                Self::from(transitions.into_iter(), global_state_root, proof)
+                    .and_then(|execution| execution.with_sequence(sequence))
+                    .map_err(de::Error::custom)
             }
             false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "execution"),
         }
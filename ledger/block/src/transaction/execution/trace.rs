@@ -0,0 +1,194 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use crate::{Input, Output};
+use console::program::{Identifier, Locator, Value};
+
+/// A structured record of a single transition, as seen by a debugger.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransitionTrace<N: Network> {
+    /// The transition ID.
+    id: N::TransitionID,
+    /// The program ID.
+    program_id: ProgramID<N>,
+    /// The function name.
+    function_name: Identifier<N>,
+    /// The transition inputs.
+    inputs: Vec<Input<N>>,
+    /// The transition outputs.
+    outputs: Vec<Output<N>>,
+}
+
+impl<N: Network> TransitionTrace<N> {
+    /// Returns the transition ID.
+    pub const fn id(&self) -> &N::TransitionID {
+        &self.id
+    }
+
+    /// Returns the program ID.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the function name.
+    pub const fn function_name(&self) -> &Identifier<N> {
+        &self.function_name
+    }
+
+    /// Returns the transition inputs.
+    pub fn inputs(&self) -> &[Input<N>] {
+        &self.inputs
+    }
+
+    /// Returns the transition outputs.
+    pub fn outputs(&self) -> &[Output<N>] {
+        &self.outputs
+    }
+}
+
+impl<N: Network> From<&Transition<N>> for TransitionTrace<N> {
+    /// Initializes a transition trace from the given transition.
+    fn from(transition: &Transition<N>) -> Self {
+        Self {
+            id: *transition.id(),
+            program_id: *transition.program_id(),
+            function_name: *transition.function_name(),
+            inputs: transition.inputs().to_vec(),
+            outputs: transition.outputs().to_vec(),
+        }
+    }
+}
+
+/// The intermediate register values of a function or closure, in the order they were assigned.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RegisterFrame<N: Network> {
+    /// The function or closure.
+    locator: Locator<N>,
+    /// The register values, keyed by register locator.
+    registers: IndexMap<u64, Value<N>>,
+}
+
+impl<N: Network> RegisterFrame<N> {
+    /// Initializes a new register frame.
+    pub const fn new(locator: Locator<N>, registers: IndexMap<u64, Value<N>>) -> Self {
+        Self { locator, registers }
+    }
+
+    /// Returns the function or closure.
+    pub const fn locator(&self) -> &Locator<N> {
+        &self.locator
+    }
+
+    /// Returns the register values, keyed by register locator.
+    pub const fn registers(&self) -> &IndexMap<u64, Value<N>> {
+        &self.registers
+    }
+}
+
+/// A structured record of an execution, for consumption by debuggers and playgrounds.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExecutionTrace<N: Network> {
+    /// The transition traces, in execution order.
+    transitions: Vec<TransitionTrace<N>>,
+    /// The register frames, in the order they completed.
+    /// Note: This is only populated when the execution was evaluated without proving.
+    timeline: Vec<RegisterFrame<N>>,
+}
+
+impl<N: Network> ExecutionTrace<N> {
+    /// Initializes a new execution trace.
+    pub const fn new(transitions: Vec<TransitionTrace<N>>, timeline: Vec<RegisterFrame<N>>) -> Self {
+        Self { transitions, timeline }
+    }
+
+    /// Returns the transition traces, in execution order.
+    pub fn transitions(&self) -> &[TransitionTrace<N>] {
+        &self.transitions
+    }
+
+    /// Returns the register frames, in the order they completed.
+    pub fn timeline(&self) -> &[RegisterFrame<N>] {
+        &self.timeline
+    }
+}
+
+impl<N: Network> Execution<N> {
+    /// Returns a structured record of the inputs and outputs of each transition.
+    /// Note: The timeline is empty, as intermediate register values are not retained in a proven execution.
+    pub fn to_trace(&self) -> ExecutionTrace<N> {
+        ExecutionTrace::new(self.transitions.values().map(TransitionTrace::from).collect(), vec![])
+    }
+}
+
+impl<N: Network> Serialize for TransitionTrace<N> {
+    /// Serializes the transition trace into a string.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut transition = serializer.serialize_struct("TransitionTrace", 5)?;
+        transition.serialize_field("id", &self.id)?;
+        transition.serialize_field("program", &self.program_id)?;
+        transition.serialize_field("function", &self.function_name)?;
+        transition.serialize_field("inputs", &self.inputs)?;
+        transition.serialize_field("outputs", &self.outputs)?;
+        transition.end()
+    }
+}
+
+impl<N: Network> Serialize for RegisterFrame<N> {
+    /// Serializes the register frame into a string.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut frame = serializer.serialize_struct("RegisterFrame", 2)?;
+        frame.serialize_field("locator", &self.locator)?;
+        frame.serialize_field("registers", &self.registers)?;
+        frame.end()
+    }
+}
+
+impl<N: Network> Serialize for ExecutionTrace<N> {
+    /// Serializes the execution trace into a string.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut trace = serializer.serialize_struct("ExecutionTrace", 2)?;
+        trace.serialize_field("transitions", &self.transitions)?;
+        trace.serialize_field("timeline", &self.timeline)?;
+        trace.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_execution_to_trace() {
+        let rng = &mut TestRng::default();
+
+        // Sample the execution.
+        let execution = crate::transaction::execution::test_helpers::sample_execution(rng);
+        let trace = execution.to_trace();
+
+        // Ensure the trace matches the transitions.
+        assert_eq!(trace.transitions().len(), execution.len());
+        for (transition, expected) in trace.transitions().iter().zip_eq(execution.transitions()) {
+            assert_eq!(transition.id(), expected.id());
+            assert_eq!(transition.inputs(), expected.inputs());
+            assert_eq!(transition.outputs(), expected.outputs());
+        }
+        assert!(trace.timeline().is_empty());
+
+        // Ensure the trace serializes.
+        let string = serde_json::to_string(&trace).unwrap();
+        assert!(string.contains("\"transitions\""));
+    }
+}
@@ -0,0 +1,187 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use console::{program::compute_function_id, types::U16};
+
+impl<N: Network> Transaction<N> {
+    /// Ensures the IDs in the transaction are consistent with its contents.
+    ///
+    /// This recomputes the transaction ID, the transition IDs, the input and output IDs, and the
+    /// deployment or execution ID that the fee commits to. On a mismatch, the error names the path
+    /// of the offending field, i.e. `execution.transitions[1].outputs[0]`.
+    ///
+    /// Note: This does not verify the proofs, which requires the program verifying keys.
+    pub fn check_integrity(&self) -> Result<()> {
+        // Ensure the transaction ID matches the transaction tree.
+        let root = self.to_root().map_err(|e| integrity_error("id", e))?;
+        if *self.id() != root {
+            return Err(integrity_error("id", format!("expected '{root}', found '{}'", self.id())));
+        }
+
+        match self {
+            Self::Deploy(_, owner, deployment, fee) => {
+                // Compute the deployment ID.
+                let deployment_id = deployment.to_deployment_id().map_err(|e| integrity_error("deployment", e))?;
+                // Ensure the owner signed the deployment ID.
+                if !owner.verify(deployment_id) {
+                    return Err(integrity_error("owner.signature", "the signature does not match the deployment ID"));
+                }
+                // Ensure the fee commits to the deployment ID.
+                check_fee_integrity(fee, Some(deployment_id))
+            }
+            Self::Execute(_, execution, fee) => {
+                // Ensure each transition is well-formed.
+                for (index, transition) in execution.transitions().enumerate() {
+                    check_transition_integrity(&format!("execution.transitions[{index}]"), transition)?;
+                }
+                match fee {
+                    // Ensure the fee commits to the execution ID.
+                    Some(fee) => {
+                        let execution_id = execution.to_execution_id().map_err(|e| integrity_error("execution", e))?;
+                        check_fee_integrity(fee, Some(execution_id))
+                    }
+                    None => Ok(()),
+                }
+            }
+            // Note: A fee transaction commits to the ID of a rejected transaction, which is not included.
+            Self::Fee(_, fee) => check_fee_integrity(fee, None),
+            Self::Deprecate(_, owner, program_id, mapping_policy, fee) => {
+                // Compute the deprecation ID.
+                let deprecation_id =
+                    Self::deprecation_id(program_id, *mapping_policy).map_err(|e| integrity_error("program_id", e))?;
+                // Ensure the owner signed the deprecation ID.
+                if !owner.verify(deprecation_id) {
+                    return Err(integrity_error("owner.signature", "the signature does not match the deprecation ID"));
+                }
+                // Ensure the fee commits to the deprecation ID.
+                check_fee_integrity(fee, Some(deprecation_id))
+            }
+        }
+    }
+}
+
+/// Ensures the fee transition is well-formed, and commits to the expected ID, if one is given.
+fn check_fee_integrity<N: Network>(fee: &Fee<N>, expected_id: Option<Field<N>>) -> Result<()> {
+    // Ensure the fee transition is well-formed.
+    check_transition_integrity("fee.transition", fee.transition())?;
+    // Ensure the fee commits to the expected ID.
+    if let Some(expected_id) = expected_id {
+        let candidate_id =
+            fee.deployment_or_execution_id().map_err(|e| integrity_error("fee.deployment_or_execution_id", e))?;
+        if candidate_id != expected_id {
+            return Err(integrity_error(
+                "fee.deployment_or_execution_id",
+                format!("expected '{expected_id}', found '{candidate_id}'"),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Ensures the transition ID, and the input and output IDs, are consistent with the transition.
+fn check_transition_integrity<N: Network>(path: &str, transition: &Transition<N>) -> Result<()> {
+    // Compute the function ID.
+    let function_id = compute_function_id(&U16::new(N::ID), transition.program_id(), transition.function_name())
+        .map_err(|e| integrity_error(path, e))?;
+
+    // Ensure each input ID matches the input.
+    for (index, input) in transition.inputs().iter().enumerate() {
+        if !input.verify(function_id, transition.tcm(), index) {
+            return Err(integrity_error(&format!("{path}.inputs[{index}]"), "the input ID does not match the input"));
+        }
+    }
+    // Ensure each output ID matches the output.
+    let num_inputs = transition.inputs().len();
+    for (index, output) in transition.outputs().iter().enumerate() {
+        if !output.verify(function_id, transition.tcm(), num_inputs + index) {
+            return Err(integrity_error(
+                &format!("{path}.outputs[{index}]"),
+                "the output ID does not match the output",
+            ));
+        }
+    }
+
+    // Ensure the transition ID matches the transition tree.
+    let root = transition.to_root().map_err(|e| integrity_error(path, e))?;
+    let candidate_id = N::hash_bhp512(&(root, *transition.tcm()).to_bits_le()).map_err(|e| integrity_error(path, e))?;
+    if **transition.id() != candidate_id {
+        return Err(integrity_error(
+            &format!("{path}.id"),
+            format!("expected '{candidate_id}', found '{}'", transition.id()),
+        ));
+    }
+    Ok(())
+}
+
+/// Returns an integrity error for the field at the given path.
+fn integrity_error(path: &str, reason: impl Display) -> Error {
+    anyhow!("Transaction integrity check failed at '{path}' - {reason}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_check_integrity() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for transaction in [
+            crate::transaction::test_helpers::sample_deployment_transaction(true, rng),
+            crate::transaction::test_helpers::sample_deployment_transaction(false, rng),
+            crate::transaction::test_helpers::sample_execution_transaction_with_fee(true, rng),
+            crate::transaction::test_helpers::sample_execution_transaction_with_fee(false, rng),
+            crate::transaction::test_helpers::sample_private_fee_transaction(rng),
+            crate::transaction::test_helpers::sample_fee_public_transaction(rng),
+            crate::transaction::test_helpers::sample_deprecation_transaction(true, rng),
+            crate::transaction::test_helpers::sample_deprecation_transaction(false, rng),
+        ] {
+            transaction.check_integrity()?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_check_integrity_with_tampered_id() {
+        let rng = &mut TestRng::default();
+
+        // Replace the transaction ID.
+        let transaction = crate::transaction::test_helpers::sample_execution_transaction_with_fee(false, rng);
+        let Transaction::Execute(_, execution, fee) = transaction else { unreachable!() };
+        let tampered = Transaction::<CurrentNetwork>::Execute(Field::rand(rng).into(), execution, fee);
+
+        let error = tampered.check_integrity().unwrap_err().to_string();
+        assert!(error.contains("'id'"), "{error}");
+    }
+
+    #[test]
+    fn test_check_integrity_with_tampered_fee() {
+        let rng = &mut TestRng::default();
+
+        // Replace the fee with a fee for a different execution, and recompute the transaction ID.
+        let transaction = crate::transaction::test_helpers::sample_execution_transaction_with_fee(false, rng);
+        let Transaction::Execute(_, execution, _) = transaction else { unreachable!() };
+        let fee = crate::transaction::fee::test_helpers::sample_fee_public(Field::rand(rng), rng);
+        let id = *Transaction::execution_tree(&execution, &Some(fee.clone())).unwrap().root();
+        let tampered = Transaction::<CurrentNetwork>::Execute(id.into(), execution, Some(fee));
+
+        let error = tampered.check_integrity().unwrap_err().to_string();
+        assert!(error.contains("'fee.deployment_or_execution_id'"), "{error}");
+    }
+}
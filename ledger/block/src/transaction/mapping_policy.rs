@@ -0,0 +1,120 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The treatment of the mappings of a program, when the program is deprecated.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MappingPolicy {
+    /// The mappings of the program are preserved as read-only state.
+    Freeze,
+    /// The mappings of the program are removed.
+    Remove,
+}
+
+impl MappingPolicy {
+    /// Returns `true` if the mappings of the program are preserved.
+    pub const fn is_freeze(&self) -> bool {
+        matches!(self, Self::Freeze)
+    }
+
+    /// Returns `true` if the mappings of the program are removed.
+    pub const fn is_remove(&self) -> bool {
+        matches!(self, Self::Remove)
+    }
+}
+
+impl FromBytes for MappingPolicy {
+    /// Reads the mapping policy from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the freeze flag.
+        match bool::read_le(&mut reader)? {
+            true => Ok(Self::Freeze),
+            false => Ok(Self::Remove),
+        }
+    }
+}
+
+impl ToBytes for MappingPolicy {
+    /// Writes the mapping policy to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the freeze flag.
+        self.is_freeze().write_le(&mut writer)
+    }
+}
+
+impl FromStr for MappingPolicy {
+    type Err = Error;
+
+    /// Initializes the mapping policy from a string.
+    fn from_str(policy: &str) -> Result<Self> {
+        match policy {
+            "freeze" => Ok(Self::Freeze),
+            "remove" => Ok(Self::Remove),
+            _ => bail!("Invalid mapping policy '{policy}'"),
+        }
+    }
+}
+
+impl Display for MappingPolicy {
+    /// Displays the mapping policy as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Freeze => write!(f, "freeze"),
+            Self::Remove => write!(f, "remove"),
+        }
+    }
+}
+
+impl Serialize for MappingPolicy {
+    /// Serializes the mapping policy into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => serializer.collect_str(self),
+            false => ToBytesSerializer::serialize(self, serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for MappingPolicy {
+    /// Deserializes the mapping policy from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => FromStr::from_str(&String::deserialize(deserializer)?).map_err(de::Error::custom),
+            false => FromBytesDeserializer::<Self>::deserialize(deserializer, "mapping policy", 1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mapping_policy() -> Result<()> {
+        for expected in [MappingPolicy::Freeze, MappingPolicy::Remove] {
+            // Check the string representation.
+            assert_eq!(expected, MappingPolicy::from_str(&expected.to_string())?);
+            // Check the byte representation.
+            assert_eq!(expected, MappingPolicy::read_le(&expected.to_bytes_le()?[..])?);
+            // Check the serde representations.
+            assert_eq!(expected, serde_json::from_str(&serde_json::to_string(&expected)?)?);
+            assert_eq!(expected, bincode::deserialize(&bincode::serialize(&expected)?)?);
+        }
+        // Ensure the byte representation matches the freeze flag.
+        assert_eq!(MappingPolicy::Freeze.to_bytes_le()?, true.to_bytes_le()?);
+        assert_eq!(MappingPolicy::Remove.to_bytes_le()?, false.to_bytes_le()?);
+        Ok(())
+    }
+}
@@ -53,12 +53,20 @@ impl<N: Network> Transaction<N> {
                     if *id == **fee.id() {
                         // Return the transaction leaf.
                         return Ok(TransactionLeaf::new_execution(
-                            u16::try_from(execution.len())?, // The last index.
+                            u16::try_from(execution.len() + execution.sequence().is_some() as usize)?, // The last index.
                             *id,
                         ));
                     }
                 }
 
+                // Check if the ID is the hash of the sequence number.
+                if let Some(sequence) = execution.sequence() {
+                    if *id == Self::sequence_leaf_id(sequence)? {
+                        // Return the transaction leaf.
+                        return Ok(TransactionLeaf::new_execution(u16::try_from(execution.len())?, *id));
+                    }
+                }
+
                 // Iterate through the transitions in the execution.
                 for (index, transition) in execution.transitions().enumerate() {
                     // Check if the transition ID matches the given ID.
@@ -78,6 +86,20 @@ impl<N: Network> Transaction<N> {
                 // Error if the transition ID was not found.
                 bail!("Transition ID not found in fee transaction");
             }
+            Self::Deprecate(_, _, program_id, mapping_policy, fee) => {
+                // Check if the ID is the transition ID for the fee.
+                if *id == **fee.id() {
+                    // Return the transaction leaf.
+                    return Ok(TransactionLeaf::new_fee(1, *id));
+                }
+                // Check if the ID is the hash of the program ID and the mapping policy.
+                if *id == Self::deprecation_leaf_id(program_id, *mapping_policy)? {
+                    // Return the transaction leaf.
+                    return Ok(TransactionLeaf::new_deployment(0, *id));
+                }
+                // Error if the ID was not found.
+                bail!("ID not found in deprecation transaction");
+            }
         }
     }
 
@@ -96,6 +118,10 @@ impl<N: Network> Transaction<N> {
             Transaction::Execute(_, execution, fee) => Self::execution_tree(execution, fee),
             // Compute the fee tree.
             Transaction::Fee(_, fee) => Self::fee_tree(fee),
+            // Compute the deprecation tree.
+            Transaction::Deprecate(_, _, program_id, mapping_policy, fee) => {
+                Self::deprecation_tree(program_id, *mapping_policy, Some(fee))
+            }
         }
     }
 }
@@ -136,29 +162,48 @@ impl<N: Network> Transaction<N> {
 
     /// Returns the Merkle tree for the given execution.
     pub fn execution_tree(execution: &Execution<N>, fee: &Option<Fee<N>>) -> Result<TransactionTree<N>> {
-        Self::transitions_tree(execution.transitions(), fee)
+        Self::sequenced_transitions_tree(execution.transitions(), execution.sequence(), fee)
     }
 
     /// Returns the Merkle tree for the given transitions.
     pub fn transitions_tree<'a>(
         transitions: impl ExactSizeIterator<Item = &'a Transition<N>>,
         fee: &Option<Fee<N>>,
+    ) -> Result<TransactionTree<N>> {
+        Self::sequenced_transitions_tree(transitions, None, fee)
+    }
+
+    /// Returns the Merkle tree for the given transitions and (optional) sequence number.
+    /// Note: The sequence number is the leaf after the transitions, so that the fee is bound to it.
+    fn sequenced_transitions_tree<'a>(
+        transitions: impl ExactSizeIterator<Item = &'a Transition<N>>,
+        sequence: Option<u64>,
+        fee: &Option<Fee<N>>,
     ) -> Result<TransactionTree<N>> {
         // Retrieve the number of transitions.
         let num_transitions = transitions.len();
-        // Ensure the number of leaves is within the Merkle tree size.
+        // Retrieve the number of leaves before the fee.
+        let num_leaves = num_transitions + sequence.is_some() as usize;
+        // Ensure there are transitions, and the number of leaves is within the Merkle tree size.
         Self::check_execution_size(num_transitions)?;
+        Self::check_execution_size(num_leaves)?;
         // Prepare the leaves.
         let leaves = transitions.enumerate().map(|(index, transition)| {
             // Construct the transaction leaf.
             Ok::<_, Error>(TransactionLeaf::new_execution(u16::try_from(index)?, **transition.id()).to_bits_le())
         });
+        // If the sequence number is present, add it to the leaves.
+        let leaves = leaves.chain(sequence.map(|sequence| {
+            // Construct the transaction leaf.
+            let id = Self::sequence_leaf_id(sequence)?;
+            Ok::<_, Error>(TransactionLeaf::new_execution(u16::try_from(num_transitions)?, id).to_bits_le())
+        }));
         // If the fee is present, add it to the leaves.
         let leaves = match fee {
             Some(fee) => {
                 // Construct the transaction leaf.
                 let leaf = TransactionLeaf::new_fee(
-                    u16::try_from(num_transitions)?, // The last index.
+                    u16::try_from(num_leaves)?, // The last index.
                     **fee.transition_id(),
                 )
                 .to_bits_le();
@@ -180,6 +225,44 @@ impl<N: Network> Transaction<N> {
         N::merkle_tree_bhp::<TRANSACTION_DEPTH>(&[leaf])
     }
 
+    /// Returns the Merkle tree for the deprecation of the given program ID.
+    pub fn deprecation_tree(
+        program_id: &ProgramID<N>,
+        mapping_policy: MappingPolicy,
+        fee: Option<&Fee<N>>,
+    ) -> Result<TransactionTree<N>> {
+        // Construct the transaction leaf for the program ID and the mapping policy.
+        let leaf =
+            TransactionLeaf::new_deployment(0u16, Self::deprecation_leaf_id(program_id, mapping_policy)?).to_bits_le();
+        // If the fee is present, add it to the leaves.
+        let leaves = match fee {
+            Some(fee) => vec![leaf, TransactionLeaf::new_fee(1u16, **fee.transition_id()).to_bits_le()],
+            None => vec![leaf],
+        };
+        // Compute the deprecation tree.
+        N::merkle_tree_bhp::<TRANSACTION_DEPTH>(&leaves)
+    }
+
+    /// Returns the deprecation ID for the given program ID and mapping policy.
+    /// This is the ID that the program owner signs, and that the fee is bound to.
+    pub fn deprecation_id(program_id: &ProgramID<N>, mapping_policy: MappingPolicy) -> Result<Field<N>> {
+        Ok(*Self::deprecation_tree(program_id, mapping_policy, None)?.root())
+    }
+
+    /// Returns the ID of the deprecation leaf, as the hash of the program ID and the mapping policy.
+    fn deprecation_leaf_id(program_id: &ProgramID<N>, mapping_policy: MappingPolicy) -> Result<Field<N>> {
+        // Construct the preimage.
+        let mut preimage = program_id.to_bits_le();
+        preimage.push(mapping_policy.is_freeze());
+        // Hash the preimage.
+        N::hash_bhp1024(&preimage)
+    }
+
+    /// Returns the ID of the sequence leaf, as the hash of the sequence number.
+    fn sequence_leaf_id(sequence: u64) -> Result<Field<N>> {
+        N::hash_bhp1024(&sequence.to_bits_le())
+    }
+
     /// Returns `true` if the deployment is within the size bounds.
     pub fn check_deployment_size(deployment: &Deployment<N>) -> Result<()> {
         // Retrieve the program.
@@ -12,6 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod builder;
+pub use builder::*;
+
+mod canonical;
+pub use canonical::*;
+
 mod deployment;
 pub use deployment::*;
 
@@ -21,15 +27,28 @@ pub use execution::*;
 mod fee;
 pub use fee::*;
 
+mod mapping_policy;
+pub use mapping_policy::*;
+
 mod bytes;
+mod integrity;
 mod merkle;
 mod serialize;
 mod string;
 
-use crate::Transition;
+use crate::{Migrate, Transition};
 use console::{
     network::prelude::*,
-    program::{Ciphertext, ProgramOwner, Record, TransactionLeaf, TransactionPath, TransactionTree, TRANSACTION_DEPTH},
+    program::{
+        Ciphertext,
+        ProgramID,
+        ProgramOwner,
+        Record,
+        TransactionLeaf,
+        TransactionPath,
+        TransactionTree,
+        TRANSACTION_DEPTH,
+    },
     types::{Field, Group, U64},
 };
 
@@ -41,6 +60,9 @@ pub enum Transaction<N: Network> {
     Execute(N::TransactionID, Execution<N>, Option<Fee<N>>),
     /// The fee transaction represents a fee paid to the network, used for rejected transactions.
     Fee(N::TransactionID, Fee<N>),
+    /// The deprecate transaction marks a program as deprecated, rejecting new executions of the program.
+    /// The mapping policy determines whether the mappings of the program are preserved or removed.
+    Deprecate(N::TransactionID, ProgramOwner<N>, ProgramID<N>, MappingPolicy, Fee<N>),
 }
 
 impl<N: Network> Transaction<N> {
@@ -77,6 +99,23 @@ impl<N: Network> Transaction<N> {
         // Construct the execution transaction.
         Ok(Self::Fee(id.into(), fee))
     }
+
+    /// Initializes a new deprecation transaction.
+    pub fn from_deprecation(
+        owner: ProgramOwner<N>,
+        program_id: ProgramID<N>,
+        mapping_policy: MappingPolicy,
+        fee: Fee<N>,
+    ) -> Result<Self> {
+        // Compute the transaction ID.
+        let id = *Self::deprecation_tree(&program_id, mapping_policy, Some(&fee))?.root();
+        // Compute the deprecation ID.
+        let deprecation_id = Self::deprecation_id(&program_id, mapping_policy)?;
+        // Ensure the owner signed the correct transaction ID.
+        ensure!(owner.verify(deprecation_id), "Attempted to create a deprecation transaction with an invalid owner");
+        // Construct the deprecation transaction.
+        Ok(Self::Deprecate(id.into(), owner, program_id, mapping_policy, fee))
+    }
 }
 
 impl<N: Network> Transaction<N> {
@@ -97,6 +136,12 @@ impl<N: Network> Transaction<N> {
     pub const fn is_fee(&self) -> bool {
         matches!(self, Self::Fee(..))
     }
+
+    /// Returns `true` if the transaction is a deprecate transaction.
+    #[inline]
+    pub const fn is_deprecate(&self) -> bool {
+        matches!(self, Self::Deprecate(..))
+    }
 }
 
 impl<N: Network> Transaction<N> {
@@ -113,11 +158,12 @@ impl<N: Network> Transaction<N> {
 }
 
 impl<N: Network> Transaction<N> {
-    /// Returns `Some(owner)` if the transaction is a deployment. Otherwise, returns `None`.
+    /// Returns `Some(owner)` if the transaction is a deployment or deprecation. Otherwise, returns `None`.
     #[inline]
     pub fn owner(&self) -> Option<&ProgramOwner<N>> {
         match self {
             Self::Deploy(_, owner, _, _) => Some(owner),
+            Self::Deprecate(_, owner, _, _, _) => Some(owner),
             _ => None,
         }
     }
@@ -139,6 +185,24 @@ impl<N: Network> Transaction<N> {
             _ => None,
         }
     }
+
+    /// Returns `Some(program_id)` if the transaction is a deprecation. Otherwise, returns `None`.
+    #[inline]
+    pub fn deprecated_program_id(&self) -> Option<&ProgramID<N>> {
+        match self {
+            Self::Deprecate(_, _, program_id, _, _) => Some(program_id),
+            _ => None,
+        }
+    }
+
+    /// Returns `Some(mapping_policy)` if the transaction is a deprecation. Otherwise, returns `None`.
+    #[inline]
+    pub fn mapping_policy(&self) -> Option<MappingPolicy> {
+        match self {
+            Self::Deprecate(_, _, _, mapping_policy, _) => Some(*mapping_policy),
+            _ => None,
+        }
+    }
 }
 
 /// A helper enum for iterators and consuming iterators over a transaction.
@@ -179,6 +243,7 @@ impl<N: Network> Transaction<N> {
             Self::Deploy(id, ..) => *id,
             Self::Execute(id, ..) => *id,
             Self::Fee(id, ..) => *id,
+            Self::Deprecate(id, ..) => *id,
         }
     }
 
@@ -189,6 +254,7 @@ impl<N: Network> Transaction<N> {
             Self::Execute(_, _, Some(fee)) => fee.amount(),
             Self::Execute(_, _, None) => Ok(U64::zero()),
             Self::Fee(_, fee) => fee.amount(),
+            Self::Deprecate(_, _, _, _, fee) => fee.amount(),
         }
     }
 
@@ -199,6 +265,7 @@ impl<N: Network> Transaction<N> {
             Self::Execute(_, _, Some(fee)) => fee.base_amount(),
             Self::Execute(_, _, None) => Ok(U64::zero()),
             Self::Fee(_, fee) => fee.base_amount(),
+            Self::Deprecate(_, _, _, _, fee) => fee.base_amount(),
         }
     }
 
@@ -209,6 +276,7 @@ impl<N: Network> Transaction<N> {
             Self::Execute(_, _, Some(fee)) => fee.priority_amount(),
             Self::Execute(_, _, None) => Ok(U64::zero()),
             Self::Fee(_, fee) => fee.priority_amount(),
+            Self::Deprecate(_, _, _, _, fee) => fee.priority_amount(),
         }
     }
 
@@ -218,6 +286,7 @@ impl<N: Network> Transaction<N> {
             Self::Deploy(_, _, _, fee) => Some(fee.clone()),
             Self::Execute(_, _, fee) => fee.clone(),
             Self::Fee(_, fee) => Some(fee.clone()),
+            Self::Deprecate(_, _, _, _, fee) => Some(fee.clone()),
         }
     }
 }
@@ -235,6 +304,8 @@ impl<N: Network> Transaction<N> {
             }
             // Check the fee.
             Self::Fee(_, fee) => fee.id() == transition_id,
+            // Check the fee.
+            Self::Deprecate(_, _, _, _, fee) => fee.id() == transition_id,
         }
     }
 
@@ -270,6 +341,11 @@ impl<N: Network> Transaction<N> {
                 true => Some(fee.transition()),
                 false => None,
             },
+            // Check the fee.
+            Self::Deprecate(_, _, _, _, fee) => match fee.id() == transition_id {
+                true => Some(fee.transition()),
+                false => None,
+            },
         }
     }
 
@@ -303,6 +379,7 @@ impl<N: Network> Transaction<N> {
                 IterWrap::Execute(execution.transitions().chain(fee.as_ref().map(|fee| fee.transition())))
             }
             Self::Fee(_, fee) => IterWrap::Fee(Some(fee.transition()).into_iter()),
+            Self::Deprecate(_, _, _, _, fee) => IterWrap::Fee(Some(fee.transition()).into_iter()),
         }
     }
 
@@ -370,6 +447,7 @@ impl<N: Network> Transaction<N> {
                 IterWrap::Execute(execution.into_transitions().chain(fee.map(|fee| fee.into_transition())))
             }
             Self::Fee(_, fee) => IterWrap::Fee(Some(fee.into_transition()).into_iter()),
+            Self::Deprecate(_, _, _, _, fee) => IterWrap::Fee(Some(fee.into_transition()).into_iter()),
         }
     }
 
@@ -461,6 +539,28 @@ pub mod test_helpers {
         Transaction::from_fee(fee).unwrap()
     }
 
+    /// Samples a random deprecation transaction with a private or public fee.
+    pub fn sample_deprecation_transaction(is_fee_private: bool, rng: &mut TestRng) -> Transaction<CurrentNetwork> {
+        // Sample a private key.
+        let private_key = PrivateKey::new(rng).unwrap();
+        // Sample a program ID.
+        let program_id = ProgramID::from_str("testing.aleo").unwrap();
+
+        // Compute the deprecation ID.
+        let deprecation_id = Transaction::deprecation_id(&program_id, MappingPolicy::Freeze).unwrap();
+        // Construct a program owner.
+        let owner = ProgramOwner::new(&private_key, deprecation_id, rng).unwrap();
+
+        // Sample the fee.
+        let fee = match is_fee_private {
+            true => crate::transaction::fee::test_helpers::sample_fee_private(deprecation_id, rng),
+            false => crate::transaction::fee::test_helpers::sample_fee_public(deprecation_id, rng),
+        };
+
+        // Construct a deprecation transaction.
+        Transaction::from_deprecation(owner, program_id, MappingPolicy::Freeze, fee).unwrap()
+    }
+
     /// Samples a random fee transaction.
     pub fn sample_fee_public_transaction(rng: &mut TestRng) -> Transaction<CurrentNetwork> {
         // Sample a fee.
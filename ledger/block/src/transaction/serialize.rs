@@ -45,6 +45,16 @@ impl<N: Network> Serialize for Transaction<N> {
                     transaction.serialize_field("fee", &fee)?;
                     transaction.end()
                 }
+                Self::Deprecate(id, owner, program_id, mapping_policy, fee) => {
+                    let mut transaction = serializer.serialize_struct("Transaction", 6)?;
+                    transaction.serialize_field("type", "deprecate")?;
+                    transaction.serialize_field("id", &id)?;
+                    transaction.serialize_field("owner", &owner)?;
+                    transaction.serialize_field("program_id", &program_id)?;
+                    transaction.serialize_field("mapping_policy", &mapping_policy)?;
+                    transaction.serialize_field("fee", &fee)?;
+                    transaction.end()
+                }
             },
             false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
         }
@@ -94,6 +104,19 @@ impl<'de, N: Network> Deserialize<'de> for Transaction<N> {
                         // Construct the transaction.
                         Transaction::from_fee(fee).map_err(de::Error::custom)?
                     }
+                    Some("deprecate") => {
+                        // Retrieve the owner.
+                        let owner = DeserializeExt::take_from_value::<D>(&mut transaction, "owner")?;
+                        // Retrieve the program ID.
+                        let program_id = DeserializeExt::take_from_value::<D>(&mut transaction, "program_id")?;
+                        // Retrieve the mapping policy.
+                        let mapping_policy = DeserializeExt::take_from_value::<D>(&mut transaction, "mapping_policy")?;
+                        // Retrieve the fee.
+                        let fee = DeserializeExt::take_from_value::<D>(&mut transaction, "fee")?;
+                        // Construct the transaction.
+                        Transaction::from_deprecation(owner, program_id, mapping_policy, fee)
+                            .map_err(de::Error::custom)?
+                    }
                     _ => return Err(de::Error::custom("Invalid transaction type")),
                 };
 
@@ -123,6 +146,8 @@ mod tests {
             crate::transaction::test_helpers::sample_deployment_transaction(false, rng),
             crate::transaction::test_helpers::sample_execution_transaction_with_fee(true, rng),
             crate::transaction::test_helpers::sample_execution_transaction_with_fee(false, rng),
+            crate::transaction::test_helpers::sample_deprecation_transaction(true, rng),
+            crate::transaction::test_helpers::sample_deprecation_transaction(false, rng),
         ]
         .into_iter()
         {
@@ -146,6 +171,8 @@ mod tests {
             crate::transaction::test_helpers::sample_deployment_transaction(false, rng),
             crate::transaction::test_helpers::sample_execution_transaction_with_fee(true, rng),
             crate::transaction::test_helpers::sample_execution_transaction_with_fee(false, rng),
+            crate::transaction::test_helpers::sample_deprecation_transaction(true, rng),
+            crate::transaction::test_helpers::sample_deprecation_transaction(false, rng),
         ]
         .into_iter()
         {
@@ -160,4 +187,26 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_cbor() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for expected in [
+            crate::transaction::test_helpers::sample_deployment_transaction(true, rng),
+            crate::transaction::test_helpers::sample_execution_transaction_with_fee(true, rng),
+            crate::transaction::test_helpers::sample_deprecation_transaction(true, rng),
+        ]
+        .into_iter()
+        {
+            // Serialize
+            let expected_cbor = expected.to_cbor()?;
+            assert_eq!(expected_cbor, expected.to_cbor()?);
+            assert!(expected_cbor.len() < expected.to_string().len());
+
+            // Deserialize
+            assert_eq!(expected, Transaction::from_cbor(&expected_cbor)?);
+        }
+        Ok(())
+    }
 }
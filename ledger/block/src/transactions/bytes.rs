@@ -14,15 +14,20 @@
 
 use super::*;
 
+impl<N: Network> Migrate for Transactions<N> {
+    const NAME: &'static str = "transactions";
+    const VERSION: u8 = 1;
+}
+
 impl<N: Network> FromBytes for Transactions<N> {
     /// Reads the transactions from buffer.
     #[inline]
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
         // Read the version.
-        let version = u8::read_le(&mut reader)?;
-        // Ensure the version is valid.
-        if version != 1 {
-            return Err(error("Invalid transactions version"));
+        let version = Self::read_version(&mut reader)?;
+        // Read a value written in an older byte format.
+        if version < Self::VERSION {
+            return Self::read_legacy(version, reader);
         }
         // Read the number of transactions.
         let num_txs: u32 = FromBytes::read_le(&mut reader)?;
@@ -42,7 +47,7 @@ impl<N: Network> ToBytes for Transactions<N> {
     #[inline]
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
         // Write the version.
-        1u8.write_le(&mut writer)?;
+        Self::VERSION.write_le(&mut writer)?;
         // Write the number of transactions.
         u32::try_from(self.transactions.len()).map_err(error)?.write_le(&mut writer)?;
         // Write the transactions.
@@ -75,7 +75,20 @@ impl<N: Network> FromBytes for ConfirmedTransaction<N> {
                 // Return the confirmed transaction.
                 Self::rejected_execute(index, transaction, rejected, finalize).map_err(error)
             }
-            4.. => Err(error(format!("Failed to decode confirmed transaction variant {variant}"))),
+            4 => {
+                // Read the index.
+                let index = u32::read_le(&mut reader)?;
+                // Read the transaction.
+                let transaction = Transaction::<N>::read_le(&mut reader)?;
+                // Read the number of finalize operations.
+                let num_finalize = NumFinalizeSize::read_le(&mut reader)?;
+                // Read the finalize operations.
+                let finalize =
+                    (0..num_finalize).map(|_| FromBytes::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
+                // Return the confirmed transaction.
+                Self::accepted_deprecate(index, transaction, finalize).map_err(error)
+            }
+            5.. => Err(error(format!("Failed to decode confirmed transaction variant {variant}"))),
         }
     }
 }
@@ -136,6 +149,18 @@ impl<N: Network> ToBytes for ConfirmedTransaction<N> {
                 // Write the finalize operations.
                 finalize.iter().try_for_each(|finalize| finalize.write_le(&mut writer))
             }
+            Self::AcceptedDeprecate(index, transaction, finalize) => {
+                // Write the variant.
+                4u8.write_le(&mut writer)?;
+                // Write the index.
+                index.write_le(&mut writer)?;
+                // Write the transaction.
+                transaction.write_le(&mut writer)?;
+                // Write the number of finalize operations.
+                NumFinalizeSize::try_from(finalize.len()).map_err(error)?.write_le(&mut writer)?;
+                // Write the finalize operations.
+                finalize.iter().try_for_each(|finalize| finalize.write_le(&mut writer))
+            }
         }
     }
 }
@@ -17,7 +17,11 @@ mod serialize;
 mod string;
 
 use crate::{rejected::Rejected, Transaction};
-use console::{network::prelude::*, program::FINALIZE_ID_DEPTH, types::Field};
+use console::{
+    network::prelude::*,
+    program::{Plaintext, FINALIZE_ID_DEPTH},
+    types::Field,
+};
 use synthesizer_program::FinalizeOperation;
 
 pub type NumFinalizeSize = u16;
@@ -33,6 +37,8 @@ pub enum ConfirmedTransaction<N: Network> {
     RejectedDeploy(u32, Transaction<N>, Rejected<N>, Vec<FinalizeOperation<N>>),
     /// The rejected execute transaction is composed of `(index, fee_transaction, rejected_execution, finalize_operations)`.
     RejectedExecute(u32, Transaction<N>, Rejected<N>, Vec<FinalizeOperation<N>>),
+    /// The accepted deprecate transaction is composed of `(index, deprecate_transaction, finalize_operations)`.
+    AcceptedDeprecate(u32, Transaction<N>, Vec<FinalizeOperation<N>>),
 }
 
 impl<N: Network> ConfirmedTransaction<N> {
@@ -42,10 +48,10 @@ impl<N: Network> ConfirmedTransaction<N> {
         transaction: Transaction<N>,
         finalize_operations: Vec<FinalizeOperation<N>>,
     ) -> Result<Self> {
-        // Retrieve the program and fee from the deployment transaction, and ensure the transaction is a deploy transaction.
-        let (program, fee) = match &transaction {
-            Transaction::Deploy(_, _, deployment, fee) => (deployment.program(), fee),
-            Transaction::Execute(..) | Transaction::Fee(..) => {
+        // Retrieve the deployment and fee from the deployment transaction, and ensure the transaction is a deploy transaction.
+        let (deployment, fee) = match &transaction {
+            Transaction::Deploy(_, _, deployment, fee) => (deployment, fee),
+            Transaction::Execute(..) | Transaction::Fee(..) | Transaction::Deprecate(..) => {
                 bail!("Transaction '{}' is not a deploy transaction", transaction.id())
             }
         };
@@ -71,11 +77,17 @@ impl<N: Network> ConfirmedTransaction<N> {
                 );
             }
             // Ensure the number of program mappings matches the number of 'InitializeMapping' finalize operations.
-            if num_initialize_mappings != program.mappings().len() {
+            // Note: An upgrade only initializes the mappings that were not present in the previous edition.
+            let num_mappings = deployment.program().mappings().len();
+            let is_valid_num_mappings = match deployment.is_upgrade() {
+                true => num_initialize_mappings <= num_mappings,
+                false => num_initialize_mappings == num_mappings,
+            };
+            if !is_valid_num_mappings {
                 bail!(
                     "Transaction '{}' (deploy) must contain '{}' 'InitializeMapping' operations (found '{num_initialize_mappings}')",
                     transaction.id(),
-                    program.mappings().len(),
+                    num_mappings,
                 )
             }
             // Ensure the number of finalize operations matches the number of 'UpdateKeyValue' finalize operations.
@@ -100,14 +112,16 @@ impl<N: Network> ConfirmedTransaction<N> {
     ) -> Result<Self> {
         // Ensure the finalize operations contain the correct types.
         for operation in finalize_operations.iter() {
-            // Ensure the finalize operation is an insert or update key-value operation.
+            // Ensure the finalize operation is a key-value or event operation.
             match operation {
                 FinalizeOperation::InsertKeyValue(..)
                 | FinalizeOperation::UpdateKeyValue(..)
-                | FinalizeOperation::RemoveKeyValue(..) => (),
+                | FinalizeOperation::RemoveKeyValue(..)
+                | FinalizeOperation::Event(..) => (),
                 FinalizeOperation::InitializeMapping(..)
                 | FinalizeOperation::ReplaceMapping(..)
-                | FinalizeOperation::RemoveMapping(..) => {
+                | FinalizeOperation::RemoveMapping(..)
+                | FinalizeOperation::DeprecateProgram(..) => {
                     bail!("Transaction '{}' (execute) contains an invalid finalize operation type", transaction.id())
                 }
             }
@@ -119,6 +133,59 @@ impl<N: Network> ConfirmedTransaction<N> {
         }
     }
 
+    /// Returns a new instance of an accepted deprecate transaction.
+    pub fn accepted_deprecate(
+        index: u32,
+        transaction: Transaction<N>,
+        finalize_operations: Vec<FinalizeOperation<N>>,
+    ) -> Result<Self> {
+        // Retrieve the fee from the deprecation transaction, and ensure the transaction is a deprecate transaction.
+        let (mapping_policy, fee) = match &transaction {
+            Transaction::Deprecate(_, _, _, mapping_policy, fee) => (*mapping_policy, fee),
+            Transaction::Deploy(..) | Transaction::Execute(..) | Transaction::Fee(..) => {
+                bail!("Transaction '{}' is not a deprecate transaction", transaction.id())
+            }
+        };
+
+        // Count the number of `DeprecateProgram`, `RemoveMapping`, and `UpdateKeyValue` finalize operations.
+        let (num_deprecate_programs, num_remove_mappings, num_update_key_values) = finalize_operations
+            .iter()
+            .try_fold((0, 0, 0), |(deprecate, remove, update), operation| match operation {
+                FinalizeOperation::DeprecateProgram(..) => Ok((deprecate + 1, remove, update)),
+                FinalizeOperation::RemoveMapping(..) => Ok((deprecate, remove + 1, update)),
+                FinalizeOperation::UpdateKeyValue(..) => Ok((deprecate, remove, update + 1)),
+                op => {
+                    bail!("Transaction '{}' (deprecate) contains an invalid finalize operation ({op})", transaction.id())
+                }
+            })?;
+
+        // Ensure there is exactly one 'DeprecateProgram' finalize operation.
+        if num_deprecate_programs != 1 {
+            bail!(
+                "Transaction '{}' (deprecate) must contain one 'DeprecateProgram' operation (found '{num_deprecate_programs}')",
+                transaction.id()
+            )
+        }
+        // Ensure the mappings of the program are not removed, if they are frozen.
+        if mapping_policy.is_freeze() && num_remove_mappings != 0 {
+            bail!(
+                "Transaction '{}' (deprecate) freezes the program mappings, but contains '{num_remove_mappings}' 'RemoveMapping' operations",
+                transaction.id()
+            )
+        }
+        // Ensure the number of finalize operations matches the number of 'UpdateKeyValue' finalize operations.
+        if num_update_key_values != fee.num_finalize_operations() {
+            bail!(
+                "Transaction '{}' (deprecate) must contain {} 'UpdateKeyValue' operations (found '{num_update_key_values}')",
+                transaction.id(),
+                fee.num_finalize_operations()
+            );
+        }
+
+        // Return the accepted deprecate transaction.
+        Ok(Self::AcceptedDeprecate(index, transaction, finalize_operations))
+    }
+
     /// Returns a new instance of a rejected deploy transaction.
     pub fn rejected_deploy(
         index: u32,
@@ -137,7 +204,9 @@ impl<N: Network> ConfirmedTransaction<N> {
                 | FinalizeOperation::RemoveKeyValue(..) => (),
                 FinalizeOperation::InitializeMapping(..)
                 | FinalizeOperation::ReplaceMapping(..)
-                | FinalizeOperation::RemoveMapping(..) => {
+                | FinalizeOperation::RemoveMapping(..)
+                | FinalizeOperation::DeprecateProgram(..)
+                | FinalizeOperation::Event(..) => {
                     bail!("Transaction '{}' (fee) contains an invalid finalize operation type", transaction.id())
                 }
             }
@@ -167,7 +236,9 @@ impl<N: Network> ConfirmedTransaction<N> {
                 | FinalizeOperation::RemoveKeyValue(..) => (),
                 FinalizeOperation::InitializeMapping(..)
                 | FinalizeOperation::ReplaceMapping(..)
-                | FinalizeOperation::RemoveMapping(..) => {
+                | FinalizeOperation::RemoveMapping(..)
+                | FinalizeOperation::DeprecateProgram(..)
+                | FinalizeOperation::Event(..) => {
                     bail!("Transaction '{}' (fee) contains an invalid finalize operation type", transaction.id())
                 }
             }
@@ -184,7 +255,7 @@ impl<N: Network> ConfirmedTransaction<N> {
     /// Returns 'true' if the confirmed transaction is accepted.
     pub const fn is_accepted(&self) -> bool {
         match self {
-            Self::AcceptedDeploy(..) | Self::AcceptedExecute(..) => true,
+            Self::AcceptedDeploy(..) | Self::AcceptedExecute(..) | Self::AcceptedDeprecate(..) => true,
             Self::RejectedDeploy(..) | Self::RejectedExecute(..) => false,
         }
     }
@@ -208,6 +279,7 @@ impl<N: Network> ConfirmedTransaction<N> {
             Self::AcceptedExecute(index, ..) => *index,
             Self::RejectedDeploy(index, ..) => *index,
             Self::RejectedExecute(index, ..) => *index,
+            Self::AcceptedDeprecate(index, ..) => *index,
         }
     }
 
@@ -218,6 +290,7 @@ impl<N: Network> ConfirmedTransaction<N> {
             Self::AcceptedExecute(..) => "accepted execute",
             Self::RejectedDeploy(..) => "rejected deploy",
             Self::RejectedExecute(..) => "rejected execute",
+            Self::AcceptedDeprecate(..) => "accepted deprecate",
         }
     }
 
@@ -228,6 +301,7 @@ impl<N: Network> ConfirmedTransaction<N> {
             Self::AcceptedExecute(_, transaction, _) => transaction,
             Self::RejectedDeploy(_, transaction, _, _) => transaction,
             Self::RejectedExecute(_, transaction, _, _) => transaction,
+            Self::AcceptedDeprecate(_, transaction, _) => transaction,
         }
     }
 
@@ -238,6 +312,7 @@ impl<N: Network> ConfirmedTransaction<N> {
             Self::AcceptedExecute(_, transaction, _) => transaction,
             Self::RejectedDeploy(_, transaction, _, _) => transaction,
             Self::RejectedExecute(_, transaction, _, _) => transaction,
+            Self::AcceptedDeprecate(_, transaction, _) => transaction,
         }
     }
 
@@ -248,6 +323,7 @@ impl<N: Network> ConfirmedTransaction<N> {
             Self::AcceptedExecute(_, _, finalize) => finalize.len(),
             Self::RejectedDeploy(_, _, _, finalize) => finalize.len(),
             Self::RejectedExecute(_, _, _, finalize) => finalize.len(),
+            Self::AcceptedDeprecate(_, _, finalize) => finalize.len(),
         }
     }
 
@@ -258,9 +334,18 @@ impl<N: Network> ConfirmedTransaction<N> {
             Self::AcceptedExecute(_, _, finalize) => finalize,
             Self::RejectedDeploy(_, _, _, finalize) => finalize,
             Self::RejectedExecute(_, _, _, finalize) => finalize,
+            Self::AcceptedDeprecate(_, _, finalize) => finalize,
         }
     }
 
+    /// Returns an iterator over the events emitted by the confirmed transaction, as (`program ID`, `event`).
+    pub fn events(&self) -> impl '_ + Iterator<Item = (&Field<N>, &Plaintext<N>)> {
+        self.finalize_operations().iter().filter_map(|operation| match operation {
+            FinalizeOperation::Event(program_id, event) => Some((program_id, event)),
+            _ => None,
+        })
+    }
+
     /// Returns the finalize ID, by computing the root of a (small) Merkle tree comprised of
     /// the ordered finalize operations for the transaction.
     pub fn to_finalize_id(&self) -> Result<Field<N>> {
@@ -274,7 +359,9 @@ impl<N: Network> ConfirmedTransaction<N> {
     /// Returns the rejected ID, if the confirmed transaction is rejected.
     pub fn to_rejected_id(&self) -> Result<Option<Field<N>>> {
         match self {
-            ConfirmedTransaction::AcceptedDeploy(..) | ConfirmedTransaction::AcceptedExecute(..) => Ok(None),
+            ConfirmedTransaction::AcceptedDeploy(..)
+            | ConfirmedTransaction::AcceptedExecute(..)
+            | ConfirmedTransaction::AcceptedDeprecate(..) => Ok(None),
             ConfirmedTransaction::RejectedDeploy(_, _, rejected, _) => Ok(Some(rejected.to_id()?)),
             ConfirmedTransaction::RejectedExecute(_, _, rejected, _) => Ok(Some(rejected.to_id()?)),
         }
@@ -283,7 +370,9 @@ impl<N: Network> ConfirmedTransaction<N> {
     /// Returns the rejected object, if the confirmed transaction is rejected.
     pub fn to_rejected(&self) -> Option<&Rejected<N>> {
         match self {
-            ConfirmedTransaction::AcceptedDeploy(..) | ConfirmedTransaction::AcceptedExecute(..) => None,
+            ConfirmedTransaction::AcceptedDeploy(..)
+            | ConfirmedTransaction::AcceptedExecute(..)
+            | ConfirmedTransaction::AcceptedDeprecate(..) => None,
             ConfirmedTransaction::RejectedDeploy(_, _, rejected, _) => Some(rejected),
             ConfirmedTransaction::RejectedExecute(_, _, rejected, _) => Some(rejected),
         }
@@ -296,6 +385,7 @@ impl<N: Network> ConfirmedTransaction<N> {
         match self {
             Self::AcceptedDeploy(_, transaction, _) => Ok(transaction.id()),
             Self::AcceptedExecute(_, transaction, _) => Ok(transaction.id()),
+            Self::AcceptedDeprecate(_, transaction, _) => Ok(transaction.id()),
             Self::RejectedDeploy(_, fee_transaction, rejected, _)
             | Self::RejectedExecute(_, fee_transaction, rejected, _) => {
                 Ok(rejected.to_unconfirmed_id(&fee_transaction.fee_transition())?.into())
@@ -310,6 +400,7 @@ impl<N: Network> ConfirmedTransaction<N> {
         match self {
             Self::AcceptedDeploy(_, transaction, _) => Ok(transaction.clone()),
             Self::AcceptedExecute(_, transaction, _) => Ok(transaction.clone()),
+            Self::AcceptedDeprecate(_, transaction, _) => Ok(transaction.clone()),
             Self::RejectedDeploy(_, fee_transaction, rejected, _) => Transaction::from_deployment(
                 rejected
                     .program_owner()
@@ -376,6 +467,28 @@ pub mod test_helpers {
         ConfirmedTransaction::accepted_execute(index, tx, vec![]).unwrap()
     }
 
+    /// Samples an accepted deprecate transaction at the given index.
+    pub(crate) fn sample_accepted_deprecate(
+        index: u32,
+        is_fee_private: bool,
+        rng: &mut TestRng,
+    ) -> ConfirmedTransaction<CurrentNetwork> {
+        // Sample a deprecate transaction.
+        let tx = crate::transaction::test_helpers::sample_deprecation_transaction(is_fee_private, rng);
+
+        // Construct the finalize operations based on if the fee is public or private.
+        let finalize_operations = match is_fee_private {
+            true => vec![FinalizeOperation::DeprecateProgram(Uniform::rand(rng))],
+            false => vec![
+                FinalizeOperation::UpdateKeyValue(Uniform::rand(rng), Uniform::rand(rng), Uniform::rand(rng)),
+                FinalizeOperation::DeprecateProgram(Uniform::rand(rng)),
+            ],
+        };
+
+        // Return the confirmed transaction.
+        ConfirmedTransaction::accepted_deprecate(index, tx, finalize_operations).unwrap()
+    }
+
     /// Samples a rejected deploy transaction at the given index.
     pub(crate) fn sample_rejected_deploy(
         index: u32,
@@ -427,6 +540,8 @@ pub mod test_helpers {
             sample_rejected_deploy(2, false, rng),
             sample_rejected_execute(3, true, rng),
             sample_rejected_execute(3, false, rng),
+            sample_accepted_deprecate(4, true, rng),
+            sample_accepted_deprecate(4, false, rng),
             sample_accepted_deploy(Uniform::rand(rng), true, rng),
             sample_accepted_deploy(Uniform::rand(rng), false, rng),
             sample_accepted_execute(Uniform::rand(rng), true, rng),
@@ -435,6 +550,8 @@ pub mod test_helpers {
             sample_rejected_deploy(Uniform::rand(rng), false, rng),
             sample_rejected_execute(Uniform::rand(rng), true, rng),
             sample_rejected_execute(Uniform::rand(rng), false, rng),
+            sample_accepted_deprecate(Uniform::rand(rng), true, rng),
+            sample_accepted_deprecate(Uniform::rand(rng), false, rng),
         ]
     }
 }
@@ -458,6 +575,7 @@ mod test {
             FinalizeOperation::InsertKeyValue(Uniform::rand(rng), Uniform::rand(rng), Uniform::rand(rng)),
             FinalizeOperation::UpdateKeyValue(Uniform::rand(rng), Uniform::rand(rng), Uniform::rand(rng)),
             FinalizeOperation::RemoveKeyValue(Uniform::rand(rng), Uniform::rand(rng)),
+            FinalizeOperation::Event(Uniform::rand(rng), Plaintext::from_str("{ amount: 1u64 }").unwrap()),
         ];
         let confirmed = ConfirmedTransaction::accepted_execute(index, tx.clone(), finalize_operations.clone()).unwrap();
 
@@ -465,6 +583,7 @@ mod test {
         assert_eq!(confirmed.transaction(), &tx);
         assert_eq!(confirmed.num_finalize(), finalize_operations.len());
         assert_eq!(confirmed.finalize_operations(), &finalize_operations);
+        assert_eq!(confirmed.events().count(), 1);
 
         // Attempt to create an `AcceptedExecution` with invalid `FinalizeOperation`s.
         let finalize_operations = vec![FinalizeOperation::InitializeMapping(Uniform::rand(rng))];
@@ -57,6 +57,15 @@ impl<N: Network> Serialize for ConfirmedTransaction<N> {
                     object.serialize_field("finalize", finalize_operations)?;
                     object.end()
                 }
+                Self::AcceptedDeprecate(index, transaction, finalize_operations) => {
+                    let mut object = serializer.serialize_struct("ConfirmedTransaction", 5)?;
+                    object.serialize_field("status", "accepted")?;
+                    object.serialize_field("type", "deprecate")?;
+                    object.serialize_field("index", index)?;
+                    object.serialize_field("transaction", transaction)?;
+                    object.serialize_field("finalize", finalize_operations)?;
+                    object.end()
+                }
             },
             false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
         }
@@ -110,6 +119,12 @@ impl<'de, N: Network> Deserialize<'de> for ConfirmedTransaction<N> {
                         // Return the rejected execute transaction.
                         Self::rejected_execute(index, transaction, rejected, finalize).map_err(de::Error::custom)
                     }
+                    (Some("accepted"), Some("deprecate")) => {
+                        // Parse the finalize operations.
+                        let finalize: Vec<_> = DeserializeExt::take_from_value::<D>(&mut object, "finalize")?;
+                        // Return the accepted deprecate transaction.
+                        Self::accepted_deprecate(index, transaction, finalize).map_err(de::Error::custom)
+                    }
                     _ => Err(de::Error::custom("Invalid confirmed transaction type")),
                 }
             }
@@ -23,11 +23,12 @@ mod merkle;
 mod serialize;
 mod string;
 
-use crate::{Transaction, Transition};
+use crate::{Migrate, Transaction, Transition};
 use console::{
     network::prelude::*,
     program::{
         Ciphertext,
+        Plaintext,
         ProgramOwner,
         Record,
         TransactionsPath,
@@ -202,6 +203,11 @@ impl<N: Network> Transactions<N> {
         self.iter().filter(|tx| tx.is_accepted() && tx.is_execute())
     }
 
+    /// Returns an iterator over all transactions in `self` that are accepted deprecate transactions.
+    pub fn deprecations(&self) -> impl '_ + Iterator<Item = &ConfirmedTransaction<N>> {
+        self.iter().filter(|tx| tx.is_accepted() && tx.is_deprecate())
+    }
+
     /// Returns an iterator over all transitions.
     pub fn transitions(&self) -> impl '_ + Iterator<Item = &Transition<N>> {
         self.iter().flat_map(|tx| tx.transitions())
@@ -266,6 +272,11 @@ impl<N: Network> Transactions<N> {
     pub fn finalize_operations(&self) -> impl '_ + Iterator<Item = &FinalizeOperation<N>> {
         self.iter().flat_map(|tx| tx.finalize_operations())
     }
+
+    /// Returns an iterator over the emitted events, as (`program ID`, `event`), for all transactions.
+    pub fn events(&self) -> impl '_ + Iterator<Item = (&Field<N>, &Plaintext<N>)> {
+        self.iter().flat_map(|tx| tx.events())
+    }
 }
 
 impl<N: Network> IntoIterator for Transactions<N> {
@@ -294,6 +305,11 @@ impl<N: Network> Transactions<N> {
         self.into_iter().filter(|tx| tx.is_accepted() && tx.is_execute())
     }
 
+    /// Returns a consuming iterator over all transactions in `self` that are accepted deprecate transactions.
+    pub fn into_deprecations(self) -> impl Iterator<Item = ConfirmedTransaction<N>> {
+        self.into_iter().filter(|tx| tx.is_accepted() && tx.is_deprecate())
+    }
+
     /// Returns a consuming iterator over all transitions.
     pub fn into_transitions(self) -> impl Iterator<Item = Transition<N>> {
         self.into_iter().flat_map(|tx| tx.into_transaction().into_transitions())
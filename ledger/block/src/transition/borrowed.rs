@@ -0,0 +1,404 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use console::types::Scalar;
+
+/// The record version written for time-locked records.
+const TIME_LOCKED_RECORD_VERSION: u8 = 2;
+
+/// A borrowed view of a transition in its byte encoding.
+///
+/// Parsing only locates each input and output within the buffer, so the serial numbers,
+/// tags, and commitments can be read without decoding or cloning any plaintexts,
+/// ciphertexts, records, or futures.
+#[derive(Clone, Debug)]
+pub struct TransitionRef<'a, N: Network> {
+    /// The byte encoding of the transition.
+    bytes: &'a [u8],
+    /// The transition ID.
+    id: N::TransitionID,
+    /// The program ID.
+    program_id: ProgramID<N>,
+    /// The function name.
+    function_name: Identifier<N>,
+    /// The byte encoding of each input.
+    inputs: Vec<&'a [u8]>,
+    /// The byte encoding of each output.
+    outputs: Vec<&'a [u8]>,
+    /// The byte encoding of the transition public key.
+    tpk: &'a [u8],
+    /// The transition commitment.
+    tcm: Field<N>,
+    /// The signer commitment.
+    scm: Field<N>,
+}
+
+impl<'a, N: Network> TransitionRef<'a, N> {
+    /// Returns a view of the transition in the given bytes, ensuring no trailing bytes remain.
+    pub fn new(mut bytes: &'a [u8]) -> Result<Self> {
+        let transition = Self::read(&mut bytes)?;
+        ensure!(bytes.is_empty(), "Found {} trailing bytes after the transition", bytes.len());
+        Ok(transition)
+    }
+
+    /// Returns a view of the transition at the start of the given buffer, advancing the buffer past it.
+    pub fn read(buffer: &mut &'a [u8]) -> Result<Self> {
+        let start = *buffer;
+        let bytes = &mut *buffer;
+
+        // Read the version.
+        let version = Transition::<N>::read_version(&mut *bytes)?;
+        ensure!(version == Transition::<N>::VERSION, "Borrowed transitions must use the current byte format");
+        // Read the transition ID.
+        let id = N::TransitionID::read_le(&mut *bytes)?;
+        // Read the program ID.
+        let program_id = ProgramID::read_le(&mut *bytes)?;
+        // Read the function name.
+        let function_name = Identifier::read_le(&mut *bytes)?;
+
+        // Locate the inputs.
+        let num_inputs = u8::read_le(&mut *bytes)?;
+        let inputs = (0..num_inputs).map(|_| span(bytes, skip_input::<N>)).collect::<Result<Vec<_>>>()?;
+        // Locate the outputs.
+        let num_outputs = u8::read_le(&mut *bytes)?;
+        let outputs = (0..num_outputs).map(|_| span(bytes, skip_output::<N>)).collect::<Result<Vec<_>>>()?;
+
+        // Locate the transition public key.
+        let tpk = take(bytes, Field::<N>::size_in_bytes())?;
+        // Read the transition commitment.
+        let tcm = Field::read_le(&mut *bytes)?;
+        // Read the signer commitment.
+        let scm = Field::read_le(&mut *bytes)?;
+
+        // Retrieve the bytes of the transition.
+        let bytes = &start[..start.len() - buffer.len()];
+        Ok(Self { bytes, id, program_id, function_name, inputs, outputs, tpk, tcm, scm })
+    }
+
+    /// Returns the byte encoding of the transition.
+    pub const fn bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Returns the transition ID.
+    pub const fn id(&self) -> &N::TransitionID {
+        &self.id
+    }
+
+    /// Returns the program ID.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the function name.
+    pub const fn function_name(&self) -> &Identifier<N> {
+        &self.function_name
+    }
+
+    /// Returns the byte encoding of each input.
+    pub fn input_bytes(&self) -> &[&'a [u8]] {
+        &self.inputs
+    }
+
+    /// Returns the byte encoding of each output.
+    pub fn output_bytes(&self) -> &[&'a [u8]] {
+        &self.outputs
+    }
+
+    /// Returns the transition public key.
+    pub fn tpk(&self) -> Result<Group<N>> {
+        Ok(Group::read_le(self.tpk)?)
+    }
+
+    /// Returns the transition commitment.
+    pub const fn tcm(&self) -> &Field<N> {
+        &self.tcm
+    }
+
+    /// Returns the signer commitment.
+    pub const fn scm(&self) -> &Field<N> {
+        &self.scm
+    }
+
+    /// Returns the serial numbers of the record inputs.
+    pub fn serial_numbers(&self) -> impl '_ + Iterator<Item = Result<Field<N>>> {
+        self.inputs
+            .iter()
+            .filter(|input| input[0] == 3)
+            .map(|input| -> Result<Field<N>> { Ok(Field::read_le(&input[1..])?) })
+    }
+
+    /// Returns the tags of the record inputs.
+    pub fn tags(&self) -> impl '_ + Iterator<Item = Result<Field<N>>> {
+        let offset = 1 + Field::<N>::size_in_bytes();
+        self.inputs
+            .iter()
+            .filter(|input| input[0] == 3)
+            .map(move |input| -> Result<Field<N>> { Ok(Field::read_le(&input[offset..])?) })
+    }
+
+    /// Returns the commitments of the record outputs.
+    pub fn commitments(&self) -> impl '_ + Iterator<Item = Result<Field<N>>> {
+        self.outputs
+            .iter()
+            .filter(|output| output[0] == 3 || output[0] == 6)
+            .map(|output| -> Result<Field<N>> { Ok(Field::read_le(&output[1..])?) })
+    }
+
+    /// Returns the decoded transition.
+    pub fn to_transition(&self) -> Result<Transition<N>> {
+        Transition::from_bytes_le(self.bytes)
+    }
+}
+
+/// Returns the bytes consumed by `skip`, advancing the buffer past them.
+fn span<'a>(bytes: &mut &'a [u8], skip: fn(&mut &'a [u8]) -> Result<()>) -> Result<&'a [u8]> {
+    let start = *bytes;
+    skip(bytes)?;
+    Ok(&start[..start.len() - bytes.len()])
+}
+
+/// Returns the next `length` bytes, advancing the buffer past them.
+fn take<'a>(bytes: &mut &'a [u8], length: usize) -> Result<&'a [u8]> {
+    ensure!(bytes.len() >= length, "Unexpected end of the transition bytes");
+    let (head, tail) = bytes.split_at(length);
+    *bytes = tail;
+    Ok(head)
+}
+
+/// Skips an optional value, which is prefixed with a boolean.
+fn skip_option(bytes: &mut &[u8], skip: fn(&mut &[u8]) -> Result<()>) -> Result<()> {
+    match bool::read_le(&mut *bytes)? {
+        true => skip(bytes),
+        false => Ok(()),
+    }
+}
+
+/// Skips a value prefixed with its length as a `u16`.
+fn skip_sized(bytes: &mut &[u8]) -> Result<()> {
+    let length = u16::read_le(&mut *bytes)?;
+    take(bytes, length as usize).map(|_| ())
+}
+
+/// Skips an identifier.
+fn skip_identifier(bytes: &mut &[u8]) -> Result<()> {
+    let length = u8::read_le(&mut *bytes)?;
+    take(bytes, length as usize).map(|_| ())
+}
+
+/// Skips a ciphertext.
+fn skip_ciphertext<N: Network>(bytes: &mut &[u8]) -> Result<()> {
+    let num_fields = u16::read_le(&mut *bytes)?;
+    take(bytes, num_fields as usize * Field::<N>::size_in_bytes()).map(|_| ())
+}
+
+/// Skips a literal.
+fn skip_literal<N: Network>(bytes: &mut &[u8]) -> Result<()> {
+    let (field, scalar) = (Field::<N>::size_in_bytes(), Scalar::<N>::size_in_bytes());
+    // Determine the size of the literal from its variant.
+    let length = match u16::read_le(&mut *bytes)? {
+        // Address, Field, Group
+        0 | 2 | 3 => field,
+        // Boolean, I8, U8
+        1 | 4 | 9 => 1,
+        // I16, U16
+        5 | 10 => 2,
+        // I32, U32
+        6 | 11 => 4,
+        // I64, U64
+        7 | 12 => 8,
+        // I128, U128
+        8 | 13 => 16,
+        // Scalar
+        14 => scalar,
+        // Signature
+        15 => 2 * scalar + 2 * field,
+        // String
+        16 => u16::read_le(&mut *bytes)? as usize,
+        variant => bail!("Invalid literal variant '{variant}'"),
+    };
+    take(bytes, length).map(|_| ())
+}
+
+/// Skips a plaintext.
+fn skip_plaintext<N: Network>(bytes: &mut &[u8]) -> Result<()> {
+    match u8::read_le(&mut *bytes)? {
+        0 => skip_literal::<N>(bytes),
+        1 => (0..u8::read_le(&mut *bytes)?).try_for_each(|_| {
+            skip_identifier(bytes)?;
+            skip_sized(bytes)
+        }),
+        2 => (0..u32::read_le(&mut *bytes)?).try_for_each(|_| skip_sized(bytes)),
+        variant => bail!("Invalid plaintext variant '{variant}'"),
+    }
+}
+
+/// Skips a record ciphertext.
+fn skip_record<N: Network>(bytes: &mut &[u8]) -> Result<()> {
+    // Read the owner variant, which follows the version of a time-locked record.
+    let owner = match u8::read_le(&mut *bytes)? {
+        TIME_LOCKED_RECORD_VERSION => u8::read_le(&mut *bytes)?,
+        owner => owner,
+    };
+    match owner {
+        0 => take(bytes, Field::<N>::size_in_bytes()).map(|_| ())?,
+        1 => skip_ciphertext::<N>(bytes)?,
+        variant => bail!("Invalid record owner variant '{variant}'"),
+    }
+    // Skip the entries.
+    (0..u8::read_le(&mut *bytes)?).try_for_each(|_| {
+        skip_identifier(bytes)?;
+        skip_sized(bytes)
+    })?;
+    // Skip the nonce.
+    take(bytes, Field::<N>::size_in_bytes()).map(|_| ())
+}
+
+/// Skips a future.
+fn skip_future(bytes: &mut &[u8]) -> Result<()> {
+    // Skip the program ID and function name.
+    (0..3).try_for_each(|_| skip_identifier(bytes))?;
+    // Skip the arguments.
+    (0..u8::read_le(&mut *bytes)?).try_for_each(|_| skip_sized(bytes))
+}
+
+/// Skips a transition input.
+fn skip_input<N: Network>(bytes: &mut &[u8]) -> Result<()> {
+    let field = Field::<N>::size_in_bytes();
+    match u8::read_le(&mut *bytes)? {
+        0 | 1 => {
+            take(bytes, field)?;
+            skip_option(bytes, skip_plaintext::<N>)
+        }
+        2 => {
+            take(bytes, field)?;
+            skip_option(bytes, skip_ciphertext::<N>)
+        }
+        3 => take(bytes, 2 * field).map(|_| ()),
+        4 => take(bytes, field).map(|_| ()),
+        variant => bail!("Invalid transition input variant '{variant}'"),
+    }
+}
+
+/// Skips a transition output.
+fn skip_output<N: Network>(bytes: &mut &[u8]) -> Result<()> {
+    let field = Field::<N>::size_in_bytes();
+    match u8::read_le(&mut *bytes)? {
+        0 | 1 => {
+            take(bytes, field)?;
+            skip_option(bytes, skip_plaintext::<N>)
+        }
+        2 => {
+            take(bytes, field)?;
+            skip_option(bytes, skip_ciphertext::<N>)
+        }
+        3 => {
+            take(bytes, 2 * field)?;
+            skip_option(bytes, skip_record::<N>)
+        }
+        4 => take(bytes, field).map(|_| ()),
+        5 => {
+            take(bytes, field)?;
+            skip_option(bytes, skip_future)
+        }
+        6 => {
+            take(bytes, 2 * field)?;
+            skip_record::<N>(bytes)?;
+            // Skip the view tag.
+            take(bytes, 1).map(|_| ())
+        }
+        variant => bail!("Invalid transition output variant '{variant}'"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    #[test]
+    fn test_transition_ref() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for expected in [
+            crate::transaction::test_helpers::sample_deployment_transaction(true, rng),
+            crate::transaction::test_helpers::sample_execution_transaction_with_fee(true, rng),
+            crate::transaction::test_helpers::sample_execution_transaction_with_fee(false, rng),
+            crate::transaction::test_helpers::sample_fee_public_transaction(rng),
+        ]
+        .iter()
+        .flat_map(|transaction| transaction.transitions())
+        {
+            let bytes = expected.to_bytes_le()?;
+            let candidate = TransitionRef::<CurrentNetwork>::new(&bytes)?;
+
+            // Check the borrowed view matches the transition.
+            assert_eq!(expected.id(), candidate.id());
+            assert_eq!(expected.program_id(), candidate.program_id());
+            assert_eq!(expected.function_name(), candidate.function_name());
+            assert_eq!(expected.inputs().len(), candidate.input_bytes().len());
+            assert_eq!(expected.outputs().len(), candidate.output_bytes().len());
+            for (input, input_bytes) in expected.inputs().iter().zip_eq(candidate.input_bytes()) {
+                assert_eq!(input.to_bytes_le()?, *input_bytes);
+            }
+            for (output, output_bytes) in expected.outputs().iter().zip_eq(candidate.output_bytes()) {
+                assert_eq!(output.to_bytes_le()?, *output_bytes);
+            }
+            assert_eq!(expected.tpk(), &candidate.tpk()?);
+            assert_eq!(expected.tcm(), candidate.tcm());
+            assert_eq!(expected.scm(), candidate.scm());
+
+            // Check the serial numbers, tags, and commitments.
+            assert_eq!(
+                expected.serial_numbers().copied().collect::<Vec<_>>(),
+                candidate.serial_numbers().collect::<Result<Vec<_>>>()?
+            );
+            assert_eq!(expected.tags().copied().collect::<Vec<_>>(), candidate.tags().collect::<Result<Vec<_>>>()?);
+            assert_eq!(
+                expected.commitments().copied().collect::<Vec<_>>(),
+                candidate.commitments().collect::<Result<Vec<_>>>()?
+            );
+
+            // Check the transition is recovered.
+            assert_eq!(expected, &candidate.to_transition()?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_transition_ref_in_buffer() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Write two transitions into a single buffer.
+        let first = test_helpers::sample_transition(rng);
+        let second = test_helpers::sample_transition(rng);
+        let first_bytes = first.to_bytes_le()?;
+        let bytes = [first_bytes.clone(), second.to_bytes_le()?].concat();
+
+        // Check each transition is read in turn.
+        let mut buffer = &bytes[..];
+        assert_eq!(first.id(), TransitionRef::<CurrentNetwork>::read(&mut buffer)?.id());
+        assert_eq!(second.id(), TransitionRef::<CurrentNetwork>::read(&mut buffer)?.id());
+        assert!(buffer.is_empty());
+
+        // Check a truncated buffer fails.
+        assert!(TransitionRef::<CurrentNetwork>::new(&first_bytes[..first_bytes.len() - 1]).is_err());
+        // Check trailing bytes fail.
+        assert!(TransitionRef::<CurrentNetwork>::new(&bytes).is_err());
+        Ok(())
+    }
+}
@@ -53,10 +53,20 @@ impl<N: Network> FromBytes for Input<N> {
                 // Read the tag.
                 let tag: Field<N> = FromBytes::read_le(&mut reader)?;
                 // Return the record.
-                Self::Record(serial_number, tag)
+                Self::Record(serial_number, tag, None)
             }
             4 => Self::ExternalRecord(FromBytes::read_le(&mut reader)?),
-            5.. => return Err(error(format!("Failed to decode transition input variant {variant}"))),
+            5 => {
+                // Read the serial number.
+                let serial_number: Field<N> = FromBytes::read_le(&mut reader)?;
+                // Read the tag.
+                let tag: Field<N> = FromBytes::read_le(&mut reader)?;
+                // Read the lock height.
+                let lock_height: U32<N> = FromBytes::read_le(&mut reader)?;
+                // Return the time-locked record.
+                Self::Record(serial_number, tag, Some(lock_height))
+            }
+            6.. => return Err(error(format!("Failed to decode transition input variant {variant}"))),
         };
         Ok(literal)
     }
@@ -99,11 +109,17 @@ impl<N: Network> ToBytes for Input<N> {
                     None => false.write_le(&mut writer),
                 }
             }
-            Self::Record(serial_number, tag) => {
+            Self::Record(serial_number, tag, None) => {
                 (3 as Variant).write_le(&mut writer)?;
                 serial_number.write_le(&mut writer)?;
                 tag.write_le(&mut writer)
             }
+            Self::Record(serial_number, tag, Some(lock_height)) => {
+                (5 as Variant).write_le(&mut writer)?;
+                serial_number.write_le(&mut writer)?;
+                tag.write_le(&mut writer)?;
+                lock_height.write_le(&mut writer)
+            }
             Self::ExternalRecord(input_commitment) => {
                 (4 as Variant).write_le(&mut writer)?;
                 input_commitment.write_le(&mut writer)
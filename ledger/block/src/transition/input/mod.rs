@@ -19,11 +19,51 @@ mod string;
 use console::{
     network::prelude::*,
     program::{Ciphertext, Plaintext, TransitionLeaf},
-    types::Field,
+    types::{Field, U32},
 };
 
 type Variant = u8;
 
+/// The kind of a transition input.
+///
+/// The discriminants are stable across releases, and do not depend on the layout of [`Input`].
+/// New kinds may be added in future releases.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(u8)]
+#[non_exhaustive]
+pub enum InputKind {
+    /// A constant input.
+    Constant = 0,
+    /// A public input.
+    Public = 1,
+    /// A private input.
+    Private = 2,
+    /// A record input, which is spent by the transition.
+    Record = 3,
+    /// An external record input, which is spent by a transition in another program.
+    ExternalRecord = 4,
+}
+
+impl InputKind {
+    /// Returns the name of the input kind.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Constant => "constant",
+            Self::Public => "public",
+            Self::Private => "private",
+            Self::Record => "record",
+            Self::ExternalRecord => "external_record",
+        }
+    }
+}
+
+impl Display for InputKind {
+    /// Prints the input kind as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
 /// The transition input.
 #[derive(Clone, PartialEq, Eq)]
 pub enum Input<N: Network> {
@@ -33,8 +73,8 @@ pub enum Input<N: Network> {
     Public(Field<N>, Option<Plaintext<N>>),
     /// The ciphertext hash and (optional) ciphertext.
     Private(Field<N>, Option<Ciphertext<N>>),
-    /// The serial number and tag of the record.
-    Record(Field<N>, Field<N>),
+    /// The serial number, tag, and (optional) lock height of the record.
+    Record(Field<N>, Field<N>, Option<U32<N>>),
     /// The input commitment to the external record. Note: This is **not** the record commitment.
     ExternalRecord(Field<N>),
 }
@@ -51,6 +91,42 @@ impl<N: Network> Input<N> {
         }
     }
 
+    /// Returns the kind of the input.
+    pub const fn kind(&self) -> InputKind {
+        match self {
+            Input::Constant(..) => InputKind::Constant,
+            Input::Public(..) => InputKind::Public,
+            Input::Private(..) => InputKind::Private,
+            Input::Record(..) => InputKind::Record,
+            Input::ExternalRecord(..) => InputKind::ExternalRecord,
+        }
+    }
+
+    /// Returns `true` if the input is a constant.
+    pub const fn is_constant(&self) -> bool {
+        matches!(self, Input::Constant(..))
+    }
+
+    /// Returns `true` if the input is public.
+    pub const fn is_public(&self) -> bool {
+        matches!(self, Input::Public(..))
+    }
+
+    /// Returns `true` if the input is private.
+    pub const fn is_private(&self) -> bool {
+        matches!(self, Input::Private(..))
+    }
+
+    /// Returns `true` if the input is a record.
+    pub const fn is_record(&self) -> bool {
+        matches!(self, Input::Record(..))
+    }
+
+    /// Returns `true` if the input is an external record.
+    pub const fn is_external_record(&self) -> bool {
+        matches!(self, Input::ExternalRecord(..))
+    }
+
     /// Returns the ID of the input.
     pub const fn id(&self) -> &Field<N> {
         match self {
@@ -70,7 +146,7 @@ impl<N: Network> Input<N> {
     /// Returns the tag, if the input is a record.
     pub const fn tag(&self) -> Option<&Field<N>> {
         match self {
-            Input::Record(_, tag) => Some(tag),
+            Input::Record(_, tag, _) => Some(tag),
             _ => None,
         }
     }
@@ -78,7 +154,15 @@ impl<N: Network> Input<N> {
     /// Returns the tag, if the input is a record, and consumes `self`.
     pub fn into_tag(self) -> Option<Field<N>> {
         match self {
-            Input::Record(_, tag) => Some(tag),
+            Input::Record(_, tag, _) => Some(tag),
+            _ => None,
+        }
+    }
+
+    /// Returns the lock height, if the input is a time-locked record.
+    pub const fn lock_height(&self) -> Option<&U32<N>> {
+        match self {
+            Input::Record(_, _, Some(lock_height)) => Some(lock_height),
             _ => None,
         }
     }
@@ -99,9 +183,18 @@ impl<N: Network> Input<N> {
         }
     }
 
+    /// Returns the plaintext value, if the input is a constant or public and the value is present.
+    pub const fn plaintext(&self) -> Option<&Plaintext<N>> {
+        match self {
+            Input::Constant(_, Some(plaintext)) | Input::Public(_, Some(plaintext)) => Some(plaintext),
+            _ => None,
+        }
+    }
+
     /// Returns the public verifier inputs for the proof.
     pub fn verifier_inputs(&self) -> impl '_ + Iterator<Item = N::Field> {
-        [Some(self.id()), self.tag()].into_iter().flatten().map(|id| **id)
+        let lock_height = self.lock_height().map(|lock_height| *Field::<N>::from_u32(**lock_height));
+        [Some(self.id()), self.tag()].into_iter().flatten().map(|id| **id).chain(lock_height)
     }
 
     /// Returns `true` if the input is well-formed.
@@ -164,7 +257,7 @@ impl<N: Network> Input<N> {
                 // A similar rule is enforced for the transition output.
                 bail!("A transition input value is missing")
             }
-            Input::Record(_, _) | Input::ExternalRecord(_) => Ok(true),
+            Input::Record(..) | Input::ExternalRecord(_) => Ok(true),
         };
 
         match result() {
@@ -212,8 +305,53 @@ pub(crate) mod test_helpers {
             (Uniform::rand(rng), Input::Public(plaintext_hash, Some(plaintext))),
             (Uniform::rand(rng), Input::Private(Uniform::rand(rng), None)),
             (Uniform::rand(rng), Input::Private(ciphertext_hash, Some(ciphertext))),
-            (Uniform::rand(rng), Input::Record(Uniform::rand(rng), Uniform::rand(rng))),
+            (Uniform::rand(rng), Input::Record(Uniform::rand(rng), Uniform::rand(rng), None)),
+            (Uniform::rand(rng), Input::Record(Uniform::rand(rng), Uniform::rand(rng), Some(Uniform::rand(rng)))),
             (Uniform::rand(rng), Input::ExternalRecord(Uniform::rand(rng))),
         ]
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kind_discriminants() {
+        assert_eq!(InputKind::Constant as u8, 0);
+        assert_eq!(InputKind::Public as u8, 1);
+        assert_eq!(InputKind::Private as u8, 2);
+        assert_eq!(InputKind::Record as u8, 3);
+        assert_eq!(InputKind::ExternalRecord as u8, 4);
+    }
+
+    #[test]
+    fn test_kind() {
+        for (_, input) in test_helpers::sample_inputs() {
+            // Ensure the kind matches the input.
+            let kind = input.kind();
+            let expected = match input {
+                Input::Constant(..) => InputKind::Constant,
+                Input::Public(..) => InputKind::Public,
+                Input::Private(..) => InputKind::Private,
+                Input::Record(..) => InputKind::Record,
+                Input::ExternalRecord(..) => InputKind::ExternalRecord,
+            };
+            assert_eq!(kind, expected);
+            // Ensure exactly one predicate holds, and it matches the kind.
+            let predicates = [
+                (input.is_constant(), InputKind::Constant),
+                (input.is_public(), InputKind::Public),
+                (input.is_private(), InputKind::Private),
+                (input.is_record(), InputKind::Record),
+                (input.is_external_record(), InputKind::ExternalRecord),
+            ];
+            assert_eq!(predicates.iter().filter(|(is, _)| *is).count(), 1);
+            assert!(predicates.iter().any(|(is, predicate_kind)| *is && *predicate_kind == kind));
+            // Ensure the plaintext is only present for constant and public inputs.
+            if input.plaintext().is_some() {
+                assert!(matches!(kind, InputKind::Constant | InputKind::Public));
+            }
+        }
+    }
+}
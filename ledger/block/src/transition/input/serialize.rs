@@ -46,11 +46,14 @@ impl<N: Network> Serialize for Input<N> {
                     }
                     input.end()
                 }
-                Self::Record(id, tag) => {
-                    let mut input = serializer.serialize_struct("Input", 3)?;
+                Self::Record(id, tag, lock_height) => {
+                    let mut input = serializer.serialize_struct("Input", 3 + lock_height.is_some() as usize)?;
                     input.serialize_field("type", "record")?;
                     input.serialize_field("id", &id)?;
                     input.serialize_field("tag", &tag)?;
+                    if let Some(lock_height) = lock_height {
+                        input.serialize_field("lock_height", &lock_height)?;
+                    }
                     input.end()
                 }
                 Self::ExternalRecord(id) => {
@@ -89,7 +92,19 @@ impl<'de, N: Network> Deserialize<'de> for Input<N> {
                         Some(value) => Some(Ciphertext::<N>::from_str(value).map_err(de::Error::custom)?),
                         None => None,
                     }),
-                    Some("record") => Input::Record(id, DeserializeExt::take_from_value::<D>(&mut input, "tag")?),
+                    Some("record") => {
+                        // Retrieve the tag.
+                        let tag = DeserializeExt::take_from_value::<D>(&mut input, "tag")?;
+                        // Retrieve the lock height, if it exists.
+                        let lock_height = match input.get("lock_height") {
+                            Some(lock_height) => {
+                                Some(serde_json::from_value::<U32<N>>(lock_height.clone()).map_err(de::Error::custom)?)
+                            }
+                            None => None,
+                        };
+                        // Return the record.
+                        Input::Record(id, tag, lock_height)
+                    }
                     Some("external_record") => Input::ExternalRecord(id),
                     _ => return Err(de::Error::custom("Invalid transition input type")),
                 };
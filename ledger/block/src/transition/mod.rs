@@ -13,16 +13,20 @@
 // limitations under the License.
 
 pub mod input;
-pub use input::Input;
+pub use input::{Input, InputKind};
 
 pub mod output;
-pub use output::Output;
+pub use output::{Output, OutputKind};
+
+mod borrowed;
+pub use borrowed::TransitionRef;
 
 mod bytes;
 mod merkle;
 mod serialize;
 mod string;
 
+use crate::Migrate;
 use console::{
     network::prelude::*,
     program::{
@@ -31,6 +35,7 @@ use console::{
         Identifier,
         InputID,
         OutputID,
+        Plaintext,
         ProgramID,
         Record,
         Register,
@@ -139,9 +144,9 @@ impl<N: Network> Transition<N> {
                         // Return the private input.
                         Ok(Input::Private(*input_hash, Some(ciphertext)))
                     }
-                    (InputID::Record(_, _, serial_number, tag), Value::Record(..)) => {
+                    (InputID::Record(_, _, serial_number, tag, lock_height), Value::Record(..)) => {
                         // Return the input record.
-                        Ok(Input::Record(*serial_number, *tag))
+                        Ok(Input::Record(*serial_number, *tag, *lock_height))
                     }
                     (InputID::ExternalRecord(input_hash), Value::Record(..)) => Ok(Input::ExternalRecord(*input_hash)),
                     _ => bail!("Malformed request input: {:?}, {input}", input_id),
@@ -214,15 +219,15 @@ impl<N: Network> Transition<N> {
                         // Compute the encryption randomizer as `HashToScalar(tvk || index)`.
                         let randomizer = N::hash_to_scalar_psd2(&[*request.tvk(), index])?;
 
-                        // Encrypt the record, using the randomizer.
-                        let record_ciphertext = record.encrypt(randomizer)?;
-                        // Compute the record checksum, as the hash of the encrypted record.
-                        let ciphertext_checksum = N::hash_bhp1024(&record_ciphertext.to_bits_le())?;
+                        // Encrypt the record and compute its view tag, using the randomizer.
+                        let (record_ciphertext, view_tag) = record.encrypt_with_view_tag(randomizer)?;
+                        // Compute the record checksum, as the hash of the encrypted record and view tag.
+                        let ciphertext_checksum = record_ciphertext.to_checksum(view_tag)?;
                         // Ensure the checksum matches.
                         ensure!(*checksum == ciphertext_checksum, "The output record ciphertext checksum is incorrect");
 
                         // Return the record output.
-                        Ok(Output::Record(*commitment, *checksum, Some(record_ciphertext)))
+                        Ok(Output::Record(*commitment, *checksum, Some(record_ciphertext), Some(view_tag)))
                     }
                     (OutputID::ExternalRecord(hash), Value::Record(record)) => {
                         // Construct the (console) output index as a field element.
@@ -361,7 +366,7 @@ impl<N: Network> Transition<N> {
             Input::Constant(_, _) => false,
             Input::Public(_, _) => false,
             Input::Private(_, _) => false,
-            Input::Record(input_sn, _) => input_sn == serial_number,
+            Input::Record(input_sn, ..) => input_sn == serial_number,
             Input::ExternalRecord(_) => false,
         })
     }
@@ -372,7 +377,7 @@ impl<N: Network> Transition<N> {
             Output::Constant(_, _) => false,
             Output::Public(_, _) => false,
             Output::Private(_, _) => false,
-            Output::Record(output_cm, _, _) => output_cm == commitment,
+            Output::Record(output_cm, ..) => output_cm == commitment,
             Output::ExternalRecord(_) => false,
             Output::Future(_, _) => false,
         })
@@ -386,8 +391,8 @@ impl<N: Network> Transition<N> {
             Output::Constant(_, _) => None,
             Output::Public(_, _) => None,
             Output::Private(_, _) => None,
-            Output::Record(output_cm, _, Some(record)) if output_cm == commitment => Some(record),
-            Output::Record(_, _, _) => None,
+            Output::Record(output_cm, _, Some(record), _) if output_cm == commitment => Some(record),
+            Output::Record(..) => None,
             Output::ExternalRecord(_) => None,
             Output::Future(_, _) => None,
         })
@@ -412,6 +417,33 @@ impl<N: Network> Transition<N> {
         self.inputs.iter().flat_map(Input::tag)
     }
 
+    /// Returns an iterator over the input kinds, in the order of the inputs.
+    pub fn input_kinds(&self) -> impl '_ + ExactSizeIterator<Item = InputKind> {
+        self.inputs.iter().map(Input::kind)
+    }
+
+    /// Returns an iterator over the output kinds, in the order of the outputs.
+    pub fn output_kinds(&self) -> impl '_ + ExactSizeIterator<Item = OutputKind> {
+        self.outputs.iter().map(Output::kind)
+    }
+
+    /// Returns an iterator over the records spent by this transition, as a tuple of `(serial number, tag)`.
+    pub fn spent_tags(&self) -> impl '_ + Iterator<Item = (&Field<N>, &Field<N>)> {
+        self.inputs.iter().filter_map(|input| match input {
+            Input::Record(serial_number, tag, _) => Some((serial_number, tag)),
+            _ => None,
+        })
+    }
+
+    /// Returns an iterator over the decoded public inputs, as a tuple of `(input index, plaintext)`.
+    /// Note: Public inputs without a value are skipped.
+    pub fn public_inputs_decoded(&self) -> impl '_ + Iterator<Item = (usize, &Plaintext<N>)> {
+        self.inputs.iter().enumerate().filter_map(|(index, input)| match input {
+            Input::Public(_, Some(plaintext)) => Some((index, plaintext)),
+            _ => None,
+        })
+    }
+
     /* Output */
 
     /// Returns the output IDs.
@@ -494,3 +526,34 @@ pub mod test_helpers {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_classification() {
+        let rng = &mut TestRng::default();
+
+        // Sample a transition.
+        let transition = test_helpers::sample_transition(rng);
+
+        // Ensure the input kinds match the inputs.
+        assert_eq!(transition.input_kinds().len(), transition.inputs().len());
+        for (kind, input) in transition.input_kinds().zip_eq(transition.inputs()) {
+            assert_eq!(kind, input.kind());
+        }
+
+        // Ensure the spent tags match the serial numbers and tags.
+        let (serial_numbers, tags): (Vec<&Field<_>>, Vec<&Field<_>>) = transition.spent_tags().unzip();
+        assert_eq!(serial_numbers, transition.serial_numbers().collect::<Vec<_>>());
+        assert_eq!(tags, transition.tags().collect::<Vec<_>>());
+
+        // Ensure the decoded public inputs are the public inputs with values.
+        for (index, plaintext) in transition.public_inputs_decoded() {
+            let input = &transition.inputs()[index];
+            assert_eq!(input.kind(), InputKind::Public);
+            assert_eq!(input.plaintext(), Some(plaintext));
+        }
+    }
+}
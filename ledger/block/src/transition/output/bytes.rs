@@ -56,7 +56,7 @@ impl<N: Network> FromBytes for Output<N> {
                     false => None,
                 };
 
-                Self::Record(commitment, checksum, record_ciphertext)
+                Self::Record(commitment, checksum, record_ciphertext, None)
             }
             4 => {
                 let commitment = FromBytes::read_le(&mut reader)?;
@@ -71,7 +71,14 @@ impl<N: Network> FromBytes for Output<N> {
                 };
                 Self::Future(future_hash, future)
             }
-            6.. => return Err(error(format!("Failed to decode output variant {index}"))),
+            6 => {
+                let commitment = FromBytes::read_le(&mut reader)?;
+                let checksum = FromBytes::read_le(&mut reader)?;
+                let record_ciphertext = FromBytes::read_le(&mut reader)?;
+                let view_tag: u8 = FromBytes::read_le(&mut reader)?;
+                Self::Record(commitment, checksum, Some(record_ciphertext), Some(view_tag))
+            }
+            7.. => return Err(error(format!("Failed to decode output variant {index}"))),
         };
         Ok(literal)
     }
@@ -114,7 +121,15 @@ impl<N: Network> ToBytes for Output<N> {
                     None => false.write_le(&mut writer),
                 }
             }
-            Self::Record(commitment, checksum, record_ciphertext) => {
+            Self::Record(commitment, checksum, Some(record), Some(view_tag)) => {
+                (6 as Variant).write_le(&mut writer)?;
+                commitment.write_le(&mut writer)?;
+                checksum.write_le(&mut writer)?;
+                record.write_le(&mut writer)?;
+                view_tag.write_le(&mut writer)
+            }
+            Self::Record(_, _, None, Some(_)) => Err(error("Failed to encode a view tag without a record output")),
+            Self::Record(commitment, checksum, record_ciphertext, None) => {
                 (3 as Variant).write_le(&mut writer)?;
                 commitment.write_le(&mut writer)?;
                 checksum.write_le(&mut writer)?;
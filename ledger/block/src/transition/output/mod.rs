@@ -24,6 +24,46 @@ use console::{
 
 type Variant = u8;
 
+/// The kind of a transition output.
+///
+/// This classification is stable across releases, and does not depend on the layout of [`Output`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum OutputKind {
+    /// A constant output.
+    Constant,
+    /// A public output.
+    Public,
+    /// A private output.
+    Private,
+    /// A record output, which is created by the transition.
+    Record,
+    /// An external record output, which is created by a transition in another program.
+    ExternalRecord,
+    /// A future output, which is executed in finalize.
+    Future,
+}
+
+impl OutputKind {
+    /// Returns the name of the output kind.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Constant => "constant",
+            Self::Public => "public",
+            Self::Private => "private",
+            Self::Record => "record",
+            Self::ExternalRecord => "external_record",
+            Self::Future => "future",
+        }
+    }
+}
+
+impl Display for OutputKind {
+    /// Prints the output kind as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
 /// The transition output.
 #[derive(Clone, PartialEq, Eq)]
 pub enum Output<N: Network> {
@@ -33,8 +73,8 @@ pub enum Output<N: Network> {
     Public(Field<N>, Option<Plaintext<N>>),
     /// The ciphertext hash and (optional) ciphertext.
     Private(Field<N>, Option<Ciphertext<N>>),
-    /// The commitment, checksum, and (optional) record ciphertext.
-    Record(Field<N>, Field<N>, Option<Record<N, Ciphertext<N>>>),
+    /// The commitment, checksum, (optional) record ciphertext, and (optional) view tag.
+    Record(Field<N>, Field<N>, Option<Record<N, Ciphertext<N>>>, Option<u8>),
     /// The output commitment of the external record. Note: This is **not** the record commitment.
     ExternalRecord(Field<N>),
     /// The future hash and (optional) future.
@@ -48,12 +88,24 @@ impl<N: Network> Output<N> {
             Output::Constant(_, _) => 0,
             Output::Public(_, _) => 1,
             Output::Private(_, _) => 2,
-            Output::Record(_, _, _) => 3,
+            Output::Record(..) => 3,
             Output::ExternalRecord(_) => 4,
             Output::Future(_, _) => 5,
         }
     }
 
+    /// Returns the kind of the output.
+    pub const fn kind(&self) -> OutputKind {
+        match self {
+            Output::Constant(..) => OutputKind::Constant,
+            Output::Public(..) => OutputKind::Public,
+            Output::Private(..) => OutputKind::Private,
+            Output::Record(..) => OutputKind::Record,
+            Output::ExternalRecord(..) => OutputKind::ExternalRecord,
+            Output::Future(..) => OutputKind::Future,
+        }
+    }
+
     /// Returns the ID of the output.
     pub const fn id(&self) -> &Field<N> {
         match self {
@@ -75,7 +127,7 @@ impl<N: Network> Output<N> {
     #[allow(clippy::type_complexity)]
     pub const fn record(&self) -> Option<(&Field<N>, &Record<N, Ciphertext<N>>)> {
         match self {
-            Output::Record(commitment, _, Some(record)) => Some((commitment, record)),
+            Output::Record(commitment, _, Some(record), _) => Some((commitment, record)),
             _ => None,
         }
     }
@@ -84,7 +136,7 @@ impl<N: Network> Output<N> {
     #[allow(clippy::type_complexity)]
     pub fn into_record(self) -> Option<(Field<N>, Record<N, Ciphertext<N>>)> {
         match self {
-            Output::Record(commitment, _, Some(record)) => Some((commitment, record)),
+            Output::Record(commitment, _, Some(record), _) => Some((commitment, record)),
             _ => None,
         }
     }
@@ -108,7 +160,7 @@ impl<N: Network> Output<N> {
     /// Returns the nonce, if the output is a record.
     pub const fn nonce(&self) -> Option<&Group<N>> {
         match self {
-            Output::Record(_, _, Some(record)) => Some(record.nonce()),
+            Output::Record(_, _, Some(record), _) => Some(record.nonce()),
             _ => None,
         }
     }
@@ -116,7 +168,7 @@ impl<N: Network> Output<N> {
     /// Returns the nonce, if the output is a record, and consumes `self`.
     pub fn into_nonce(self) -> Option<Group<N>> {
         match self {
-            Output::Record(_, _, Some(record)) => Some(record.into_nonce()),
+            Output::Record(_, _, Some(record), _) => Some(record.into_nonce()),
             _ => None,
         }
     }
@@ -137,6 +189,15 @@ impl<N: Network> Output<N> {
         }
     }
 
+    /// Returns the view tag, if the output is a record with a view tag.
+    /// Note: The view tag is bound to the transition ID through the record checksum.
+    pub const fn view_tag(&self) -> Option<u8> {
+        match self {
+            Output::Record(_, _, _, view_tag) => *view_tag,
+            _ => None,
+        }
+    }
+
     /// Returns the future, if the output is a future.
     pub const fn future(&self) -> Option<&Future<N>> {
         match self {
@@ -208,7 +269,12 @@ impl<N: Network> Output<N> {
                     Err(error) => Err(error),
                 }
             }
-            Output::Record(_, checksum, Some(value)) => match N::hash_bhp1024(&value.to_bits_le()) {
+            Output::Record(_, checksum, Some(value), Some(view_tag)) => match value.to_checksum(*view_tag) {
+                Ok(candidate_hash) => Ok(checksum == &candidate_hash),
+                Err(error) => Err(error),
+            },
+            // Note: Record outputs without a view tag were created before view tags, and are checksummed without one.
+            Output::Record(_, checksum, Some(value), None) => match N::hash_bhp1024(&value.to_bits_le()) {
                 Ok(candidate_hash) => Ok(checksum == &candidate_hash),
                 Err(error) => Err(error),
             },
@@ -235,7 +301,7 @@ impl<N: Network> Output<N> {
             Output::Constant(_, None)
             | Output::Public(_, None)
             | Output::Private(_, None)
-            | Output::Record(_, _, None)
+            | Output::Record(_, _, None, _)
             | Output::Future(_, None) => {
                 // This enforces that the transition *must* contain the value for this transition output.
                 // A similar rule is enforced for the transition input.
@@ -286,8 +352,9 @@ pub(crate) mod test_helpers {
         let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(
             &format!("{{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, token_amount: 100u64.private, _nonce: {nonce}.public }}"),
         ).unwrap();
-        let record_ciphertext = record.encrypt(randomizer).unwrap();
+        let (record_ciphertext, record_view_tag) = record.encrypt_with_view_tag(randomizer).unwrap();
         let record_checksum = CurrentNetwork::hash_bhp1024(&record_ciphertext.to_bits_le()).unwrap();
+        let record_tagged_checksum = record_ciphertext.to_checksum(record_view_tag).unwrap();
 
         vec![
             (transition_id, input),
@@ -297,8 +364,20 @@ pub(crate) mod test_helpers {
             (Uniform::rand(rng), Output::Public(plaintext_hash, Some(plaintext))),
             (Uniform::rand(rng), Output::Private(Uniform::rand(rng), None)),
             (Uniform::rand(rng), Output::Private(ciphertext_hash, Some(ciphertext))),
-            (Uniform::rand(rng), Output::Record(Uniform::rand(rng), Uniform::rand(rng), None)),
-            (Uniform::rand(rng), Output::Record(Uniform::rand(rng), record_checksum, Some(record_ciphertext))),
+            (Uniform::rand(rng), Output::Record(Uniform::rand(rng), Uniform::rand(rng), None, None)),
+            (
+                Uniform::rand(rng),
+                Output::Record(Uniform::rand(rng), record_checksum, Some(record_ciphertext.clone()), None),
+            ),
+            (
+                Uniform::rand(rng),
+                Output::Record(
+                    Uniform::rand(rng),
+                    record_tagged_checksum,
+                    Some(record_ciphertext),
+                    Some(record_view_tag),
+                ),
+            ),
             (Uniform::rand(rng), Output::ExternalRecord(Uniform::rand(rng))),
         ]
     }
@@ -46,14 +46,18 @@ impl<N: Network> Serialize for Output<N> {
                     }
                     output.end()
                 }
-                Self::Record(id, checksum, value) => {
-                    let mut output = serializer.serialize_struct("Output", 3 + value.is_some() as usize)?;
+                Self::Record(id, checksum, value, view_tag) => {
+                    let num_fields = 3 + value.is_some() as usize + view_tag.is_some() as usize;
+                    let mut output = serializer.serialize_struct("Output", num_fields)?;
                     output.serialize_field("type", "record")?;
                     output.serialize_field("id", &id)?;
                     output.serialize_field("checksum", &checksum)?;
                     if let Some(value) = value {
                         output.serialize_field("value", &value)?;
                     }
+                    if let Some(view_tag) = view_tag {
+                        output.serialize_field("view_tag", &view_tag)?;
+                    }
                     output.end()
                 }
                 Self::ExternalRecord(id) => {
@@ -104,13 +108,22 @@ impl<'de, N: Network> Deserialize<'de> for Output<N> {
                     Some("record") => {
                         // Retrieve the checksum.
                         let checksum: Field<N> = DeserializeExt::take_from_value::<D>(&mut output, "checksum")?;
-                        // Return the record.
-                        Output::Record(id, checksum, match output.get("value").and_then(|v| v.as_str()) {
+                        // Retrieve the record.
+                        let record = match output.get("value").and_then(|v| v.as_str()) {
                             Some(value) => {
                                 Some(Record::<N, Ciphertext<N>>::from_str(value).map_err(de::Error::custom)?)
                             }
                             None => None,
-                        })
+                        };
+                        // Retrieve the view tag, if it exists.
+                        let view_tag = match output.get("view_tag") {
+                            Some(view_tag) => Some(
+                                serde_json::from_value::<u8>(view_tag.clone()).map_err(de::Error::custom)?,
+                            ),
+                            None => None,
+                        };
+                        // Return the record.
+                        Output::Record(id, checksum, record, view_tag)
                     }
                     Some("external_record") => Output::ExternalRecord(id),
                     Some("future") => Output::Future(id, match output.get("value").and_then(|v| v.as_str()) {
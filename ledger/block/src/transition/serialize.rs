@@ -116,4 +116,22 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_cbor() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample the transition.
+        let expected = crate::transition::test_helpers::sample_transition(rng);
+
+        // Serialize
+        let expected_cbor = expected.to_cbor()?;
+        assert_eq!(expected_cbor, expected.to_cbor()?);
+        assert!(expected_cbor.len() < expected.to_string().len());
+
+        // Deserialize
+        assert_eq!(expected, Transition::from_cbor(&expected_cbor)?);
+
+        Ok(())
+    }
 }
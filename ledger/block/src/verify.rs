@@ -192,9 +192,9 @@ impl<N: Network> Block<N> {
             Authority::Beacon(signature) => {
                 // Retrieve the signer.
                 let signer = signature.to_address();
-                // Ensure the block is signed by a committee member.
+                // Ensure the block is signed by a committee member, or by the signing key it has rotated to.
                 ensure!(
-                    current_committee_lookback.members().contains_key(&signer),
+                    current_committee_lookback.get_validator(signer).is_some(),
                     "Beacon block {expected_height} has a signer not in the committee (found '{signer}')",
                 );
                 // Ensure the signature is valid.
@@ -208,9 +208,9 @@ impl<N: Network> Block<N> {
             Authority::Quorum(subdag) => {
                 // Compute the expected leader.
                 let expected_leader = current_committee_lookback.get_leader(expected_round)?;
-                // Ensure the block is authored by the expected leader.
+                // Ensure the block is authored by the expected leader, or by the signing key it has rotated to.
                 ensure!(
-                    subdag.leader_address() == expected_leader,
+                    current_committee_lookback.get_validator(subdag.leader_address()) == Some(expected_leader),
                     "Quorum block {expected_height} is authored by an unexpected leader (found: {}, expected: {expected_leader})",
                     subdag.leader_address()
                 );
@@ -451,12 +451,30 @@ impl<N: Network> Block<N> {
         }
 
         // Ensure there are no duplicate program IDs.
-        if has_duplicates(
-            self.transactions().iter().filter_map(|tx| tx.transaction().deployment().map(|d| d.program_id())),
-        ) {
+        // Note: A program may not be deployed and deprecated in the same block.
+        if has_duplicates(self.transactions().iter().filter_map(|tx| {
+            tx.transaction().deployment().map(|d| d.program_id()).or_else(|| tx.transaction().deprecated_program_id())
+        })) {
             bail!("Found a duplicate program ID in block {height}");
         }
 
+        // Ensure no program is executed after it is deprecated in this block.
+        let mut deprecated_program_ids = HashSet::new();
+        for tx in self.transactions().iter() {
+            // Ensure the accepted transitions do not call a deprecated program.
+            if tx.is_accepted() {
+                if let Some(transition) =
+                    tx.transitions().find(|transition| deprecated_program_ids.contains(transition.program_id()))
+                {
+                    bail!("Found an execution of deprecated program '{}' in block {height}", transition.program_id());
+                }
+            }
+            // Add the deprecated program ID, if the transaction is a deprecation.
+            if let Some(program_id) = tx.transaction().deprecated_program_id() {
+                deprecated_program_ids.insert(*program_id);
+            }
+        }
+
         /* Input */
 
         // Ensure there are no duplicate input IDs.
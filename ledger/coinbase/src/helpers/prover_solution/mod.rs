@@ -40,9 +40,25 @@ impl<N: Network> ProverSolution<N> {
         epoch_challenge: &EpochChallenge<N>,
         proof_target: u64,
     ) -> Result<bool> {
+        // Compute the challenge point and the claimed value.
+        let Some((challenge_point, claimed_value)) = self.to_claimed_evaluation(epoch_challenge, proof_target)? else {
+            return Ok(false);
+        };
+
+        // Check the KZG proof.
+        Ok(KZG10::check(verifying_key, &self.commitment(), challenge_point, claimed_value, self.proof())?)
+    }
+
+    /// Returns the challenge point and the claimed value that are opened by the KZG proof,
+    /// or `None` if the proof is hiding.
+    pub(crate) fn to_claimed_evaluation(
+        &self,
+        epoch_challenge: &EpochChallenge<N>,
+        proof_target: u64,
+    ) -> Result<Option<(<N::PairingCurve as PairingEngine>::Fr, <N::PairingCurve as PairingEngine>::Fr)>> {
         // Ensure the proof is non-hiding.
         if self.proof.is_hiding() {
-            return Ok(false);
+            return Ok(None);
         }
 
         // Ensure that the prover solution is greater than the proof target.
@@ -63,8 +79,7 @@ impl<N: Network> ProverSolution<N> {
         // Compute the claimed value by multiplying the evaluations.
         let claimed_value = epoch_evaluation * prover_evaluation;
 
-        // Check the KZG proof.
-        Ok(KZG10::check(verifying_key, &self.commitment(), challenge_point, claimed_value, self.proof())?)
+        Ok(Some((challenge_point, claimed_value)))
     }
 
     /// Returns the address of the prover.
@@ -63,14 +63,21 @@ impl<N: Network> CoinbasePuzzle<N> {
     }
 
     /// Load the coinbase puzzle proving and verifying keys.
+    ///
+    /// Without the `prover` feature, only the coinbase puzzle verifying key is loaded.
     pub fn load() -> Result<Self> {
-        let max_degree = N::COINBASE_PUZZLE_DEGREE;
         // Load the universal SRS.
         let universal_srs = UniversalSRS::<N>::load()?;
         // Trim the universal SRS to the maximum degree.
-        Self::trim(&*universal_srs, PuzzleConfig { degree: max_degree })
+        #[cfg(feature = "prover")]
+        let puzzle = Self::trim(&*universal_srs, PuzzleConfig { degree: N::COINBASE_PUZZLE_DEGREE })?;
+        // Extract the verifying key from the universal SRS.
+        #[cfg(not(feature = "prover"))]
+        let puzzle = Self::Verifier(Arc::new(Self::verifying_key(&universal_srs)?));
+        Ok(puzzle)
     }
 
+    #[cfg(feature = "prover")]
     pub fn trim(srs: &SRS<N::PairingCurve>, config: PuzzleConfig) -> Result<Self> {
         // As above, we must support committing to the product of two degree `n` polynomials.
         // Thus, the SRS must support committing to a polynomial of degree `2n - 1`.
@@ -84,14 +91,7 @@ impl<N: Network> CoinbasePuzzle<N> {
         let fft_precomputation = product_domain.precompute_fft();
         let product_domain_elements = product_domain.elements().collect();
 
-        let vk = CoinbaseVerifyingKey::<N> {
-            g: srs.power_of_beta_g(0)?,
-            gamma_g: <N::PairingCurve as PairingEngine>::G1Affine::zero(), // We don't use gamma_g later on since we are not hiding.
-            h: srs.h,
-            beta_h: srs.beta_h(),
-            prepared_h: srs.prepared_h.clone(),
-            prepared_beta_h: srs.prepared_beta_h.clone(),
-        };
+        let vk = Self::verifying_key(srs)?;
 
         let pk = CoinbaseProvingKey {
             product_domain,
@@ -105,6 +105,7 @@ impl<N: Network> CoinbasePuzzle<N> {
     }
 
     /// Returns a prover solution to the coinbase puzzle.
+    #[cfg(feature = "prover")]
     pub fn prove(
         &self,
         epoch_challenge: &EpochChallenge<N>,
@@ -184,13 +185,37 @@ impl<N: Network> CoinbasePuzzle<N> {
         }
         lap!(timer, "Perform initial checks");
 
-        // Verify each prover solution.
-        if !cfg_iter!(solutions).all(|(_, solution)| {
-            solution.verify(self.coinbase_verifying_key(), epoch_challenge, proof_target).unwrap_or(false)
-        }) {
+        // Compute the challenge point and the claimed value of each prover solution.
+        let claims = cfg_iter!(solutions)
+            .map(|(_, solution)| solution.to_claimed_evaluation(epoch_challenge, proof_target).ok().flatten())
+            .collect::<Option<Vec<_>>>();
+        let Some(claims) = claims else {
             bail!("The solutions contain an invalid prover solution");
+        };
+        lap!(timer, "Compute the claimed evaluations");
+
+        // Derive the randomizers of the batch check from the solutions, so that they are unpredictable to the provers.
+        let mut bytes = epoch_challenge.to_bytes_le()?;
+        for solution in solutions.values() {
+            solution.write_le(&mut bytes)?;
         }
-        finish!(timer, "Verify each solution");
+        let randomizers = hash_to_coefficients(&bytes, u32::try_from(solutions.len())?);
+
+        // Verify the KZG proofs of all prover solutions with a single pairing check.
+        let commitments = solutions.values().map(|solution| *solution.commitment()).collect::<Vec<_>>();
+        let proofs = solutions.values().map(|solution| *solution.proof()).collect::<Vec<_>>();
+        let (points, values): (Vec<_>, Vec<_>) = claims.into_iter().unzip();
+        if !KZG10::batch_check_with_randomizers(
+            self.coinbase_verifying_key(),
+            &commitments,
+            &points,
+            &values,
+            &proofs,
+            &randomizers,
+        )? {
+            bail!("The solutions contain an invalid prover solution");
+        }
+        finish!(timer, "Verify the solutions");
 
         Ok(())
     }
@@ -229,6 +254,18 @@ impl<N: Network> CoinbasePuzzle<N> {
         Ok(product_domain)
     }
 
+    /// Returns the coinbase puzzle verifying key for the given SRS.
+    fn verifying_key(srs: &SRS<N::PairingCurve>) -> Result<CoinbaseVerifyingKey<N>> {
+        Ok(CoinbaseVerifyingKey::<N> {
+            g: srs.power_of_beta_g(0)?,
+            gamma_g: <N::PairingCurve as PairingEngine>::G1Affine::zero(), // We don't use gamma_g later on since we are not hiding.
+            h: srs.h,
+            beta_h: srs.beta_h(),
+            prepared_h: srs.prepared_h.clone(),
+            prepared_beta_h: srs.prepared_beta_h.clone(),
+        })
+    }
+
     /// Returns the prover polynomial for the coinbase puzzle.
     fn prover_polynomial(
         epoch_challenge: &EpochChallenge<N>,
@@ -20,7 +20,7 @@ impl<N: Network> FromBytes for Committee<N> {
         // Read the version.
         let version = u8::read_le(&mut reader)?;
         // Ensure the version is valid.
-        if version != 1 {
+        if version != 1 && version != 2 {
             return Err(error("Invalid committee version"));
         }
 
@@ -49,8 +49,28 @@ impl<N: Network> FromBytes for Committee<N> {
         }
         // Read the total stake.
         let total_stake = u64::read_le(&mut reader)?;
+        // Read the signers.
+        let mut signers = IndexMap::new();
+        if version == 2 {
+            // Read the number of signers.
+            let num_signers = u16::read_le(&mut reader)?;
+            // Ensure the number of signers does not exceed the number of members.
+            if num_signers > num_members {
+                return Err(error(format!("Committee cannot have more than {num_members} signers")));
+            }
+            for _ in 0..num_signers {
+                // Read the member.
+                let member = Address::read_le(&mut reader)?;
+                // Read the signer.
+                let signer = Address::read_le(&mut reader)?;
+                // Insert the member and signer.
+                signers.insert(member, signer);
+            }
+        }
         // Construct the committee.
-        let committee = Self::new(starting_round, members).map_err(|e| error(e.to_string()))?;
+        let committee = Self::new(starting_round, members)
+            .and_then(|committee| committee.with_signers(signers))
+            .map_err(|e| error(e.to_string()))?;
         // Ensure the total stake matches.
         match committee.total_stake() == total_stake {
             true => Ok(committee),
@@ -63,7 +83,12 @@ impl<N: Network> ToBytes for Committee<N> {
     /// Writes the committee to the buffer.
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
         // Write the version.
-        1u8.write_le(&mut writer)?;
+        // Note: Committees without rotated signers are written with version 1, to preserve their encoding.
+        let version = match self.signers.is_empty() {
+            true => 1u8,
+            false => 2u8,
+        };
+        version.write_le(&mut writer)?;
         // Write the starting round.
         self.starting_round.write_le(&mut writer)?;
         // Write the number of members.
@@ -78,7 +103,19 @@ impl<N: Network> ToBytes for Committee<N> {
             is_open.write_le(&mut writer)?;
         }
         // Write the total stake.
-        self.total_stake.write_le(&mut writer)
+        self.total_stake.write_le(&mut writer)?;
+        // Write the signers.
+        if version == 2 {
+            // Write the number of signers.
+            u16::try_from(self.signers.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+            for (member, signer) in &self.signers {
+                // Write the member.
+                member.write_le(&mut writer)?;
+                // Write the signer.
+                signer.write_le(&mut writer)?;
+            }
+        }
+        Ok(())
     }
 }
 
@@ -96,4 +133,15 @@ mod tests {
             assert_eq!(expected, Committee::read_le(&expected_bytes[..]).unwrap());
         }
     }
+
+    #[test]
+    fn test_bytes_with_signers() {
+        let rng = &mut TestRng::default();
+
+        let expected = crate::test_helpers::sample_committee_with_signers(rng);
+        // Check the byte representation.
+        let expected_bytes = expected.to_bytes_le().unwrap();
+        assert_eq!(expected_bytes[0], 2);
+        assert_eq!(expected, Committee::read_le(&expected_bytes[..]).unwrap());
+    }
 }
@@ -0,0 +1,225 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A change to a committee member, between a previous committee and the current committee.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CommitteeChange<N: Network> {
+    /// The member joined the committee, with the given `(stake, is_open)` state.
+    Join(Address<N>, u64, bool),
+    /// The member left the committee, with the given stake in the previous committee.
+    Leave(Address<N>, u64),
+    /// The stake of the member changed, from the previous stake to the current stake.
+    StakeChange(Address<N>, u64, u64),
+}
+
+impl<N: Network> CommitteeChange<N> {
+    /// Returns the address of the member.
+    pub const fn address(&self) -> &Address<N> {
+        match self {
+            Self::Join(address, ..) | Self::Leave(address, ..) | Self::StakeChange(address, ..) => address,
+        }
+    }
+}
+
+/// The changes between a previous committee and the current committee.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CommitteeDiff<N: Network> {
+    /// The starting round of the previous committee.
+    previous_round: u64,
+    /// The starting round of the current committee.
+    starting_round: u64,
+    /// The changes, sorted by the x-coordinate of the member address in increasing order.
+    changes: Vec<CommitteeChange<N>>,
+}
+
+impl<N: Network> CommitteeDiff<N> {
+    /// Returns the starting round of the previous committee.
+    pub const fn previous_round(&self) -> u64 {
+        self.previous_round
+    }
+
+    /// Returns the starting round of the current committee.
+    pub const fn starting_round(&self) -> u64 {
+        self.starting_round
+    }
+
+    /// Returns the changes between the committees.
+    pub fn changes(&self) -> &[CommitteeChange<N>] {
+        &self.changes
+    }
+
+    /// Returns `true` if the committees have the same members and stakes.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+impl<N: Network> Committee<N> {
+    /// Returns the members that joined, left, or changed stake since the given previous committee.
+    /// Note: The changes are sorted by member address, to ensure the method returns a deterministic result.
+    pub fn diff(&self, previous: &Committee<N>) -> CommitteeDiff<N> {
+        // Compute the members that left or changed stake.
+        let mut changes = previous
+            .members
+            .iter()
+            .filter_map(|(address, (previous_stake, _))| match self.members.get(address) {
+                None => Some(CommitteeChange::Leave(*address, *previous_stake)),
+                Some((stake, _)) if stake != previous_stake => {
+                    Some(CommitteeChange::StakeChange(*address, *previous_stake, *stake))
+                }
+                Some(_) => None,
+            })
+            .collect::<Vec<_>>();
+        // Compute the members that joined.
+        changes.extend(
+            self.members
+                .iter()
+                .filter(|(address, _)| !previous.members.contains_key(*address))
+                .map(|(address, (stake, is_open))| CommitteeChange::Join(*address, *stake, *is_open)),
+        );
+        // Sort the changes by the x-coordinate of the member address.
+        // Note: The use of 'sort_unstable_by_key' is safe here because the addresses are guaranteed to be unique.
+        changes.sort_unstable_by_key(|change| change.address().to_x_coordinate());
+
+        CommitteeDiff { previous_round: previous.starting_round, starting_round: self.starting_round, changes }
+    }
+}
+
+impl<N: Network> FromBytes for CommitteeDiff<N> {
+    /// Reads the committee diff from the buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid committee diff version"));
+        }
+        // Read the starting rounds.
+        let previous_round = u64::read_le(&mut reader)?;
+        let starting_round = u64::read_le(&mut reader)?;
+        // Read the number of changes.
+        let num_changes = u16::read_le(&mut reader)?;
+        // Ensure the number of changes is within the allowed limit.
+        if num_changes > Committee::<N>::MAX_COMMITTEE_SIZE.saturating_mul(2) {
+            return Err(error(format!(
+                "Committee diff cannot exceed {} changes",
+                Committee::<N>::MAX_COMMITTEE_SIZE * 2
+            )));
+        }
+        // Read the changes.
+        let mut changes = Vec::with_capacity(num_changes as usize);
+        for _ in 0..num_changes {
+            // Read the variant.
+            let variant = u8::read_le(&mut reader)?;
+            // Read the address.
+            let address = Address::read_le(&mut reader)?;
+            // Read the change.
+            let change = match variant {
+                0 => CommitteeChange::Join(address, u64::read_le(&mut reader)?, bool::read_le(&mut reader)?),
+                1 => CommitteeChange::Leave(address, u64::read_le(&mut reader)?),
+                2 => CommitteeChange::StakeChange(address, u64::read_le(&mut reader)?, u64::read_le(&mut reader)?),
+                _ => return Err(error(format!("Invalid committee change variant '{variant}'"))),
+            };
+            changes.push(change);
+        }
+        // Return the committee diff.
+        Ok(Self { previous_round, starting_round, changes })
+    }
+}
+
+impl<N: Network> ToBytes for CommitteeDiff<N> {
+    /// Writes the committee diff to the buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the starting rounds.
+        self.previous_round.write_le(&mut writer)?;
+        self.starting_round.write_le(&mut writer)?;
+        // Write the number of changes.
+        u16::try_from(self.changes.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        // Write the changes.
+        for change in &self.changes {
+            match change {
+                CommitteeChange::Join(address, stake, is_open) => {
+                    0u8.write_le(&mut writer)?;
+                    address.write_le(&mut writer)?;
+                    stake.write_le(&mut writer)?;
+                    is_open.write_le(&mut writer)?;
+                }
+                CommitteeChange::Leave(address, stake) => {
+                    1u8.write_le(&mut writer)?;
+                    address.write_le(&mut writer)?;
+                    stake.write_le(&mut writer)?;
+                }
+                CommitteeChange::StakeChange(address, previous_stake, stake) => {
+                    2u8.write_le(&mut writer)?;
+                    address.write_le(&mut writer)?;
+                    previous_stake.write_le(&mut writer)?;
+                    stake.write_le(&mut writer)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::prelude::TestRng;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    #[test]
+    fn test_diff() {
+        let rng = &mut TestRng::default();
+
+        // Sample a committee.
+        let previous = crate::test_helpers::sample_committee_for_round_and_size(1, 5, rng);
+        // Ensure the diff with itself is empty.
+        assert!(previous.diff(&previous).is_empty());
+
+        // Remove the first member, change the stake of the second member, and add a new member.
+        let mut members = previous.members().clone();
+        let (left, (left_stake, _)) = members.shift_remove_index(0).unwrap();
+        let (changed, (changed_stake, is_open)) = members.get_index(0).map(|(a, s)| (*a, *s)).unwrap();
+        members.insert(changed, (changed_stake + 1, is_open));
+        let joined = Address::<CurrentNetwork>::new(rng.gen());
+        members.insert(joined, (MIN_VALIDATOR_STAKE, true));
+        let current = Committee::new(10, members).unwrap();
+
+        // Compute the diff.
+        let diff = current.diff(&previous);
+        assert_eq!(diff.previous_round(), 1);
+        assert_eq!(diff.starting_round(), 10);
+        assert_eq!(diff.changes().len(), 3);
+        assert!(diff.changes().contains(&CommitteeChange::Leave(left, left_stake)));
+        assert!(diff.changes().contains(&CommitteeChange::StakeChange(changed, changed_stake, changed_stake + 1)));
+        assert!(diff.changes().contains(&CommitteeChange::Join(joined, MIN_VALIDATOR_STAKE, true)));
+        // Ensure the changes are sorted by address.
+        assert!(diff.changes().windows(2).all(|w| w[0].address().to_x_coordinate() < w[1].address().to_x_coordinate()));
+
+        // Ensure the reverse diff inverts the changes.
+        let reverse = previous.diff(&current);
+        assert!(reverse.changes().contains(&CommitteeChange::Join(left, left_stake, previous.members()[&left].1)));
+        assert!(reverse.changes().contains(&CommitteeChange::StakeChange(changed, changed_stake + 1, changed_stake)));
+        assert!(reverse.changes().contains(&CommitteeChange::Leave(joined, MIN_VALIDATOR_STAKE)));
+
+        // Check the byte representation.
+        let bytes = diff.to_bytes_le().unwrap();
+        assert_eq!(diff, CommitteeDiff::read_le(&bytes[..]).unwrap());
+    }
+}
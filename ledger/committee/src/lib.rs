@@ -15,6 +15,18 @@
 #![forbid(unsafe_code)]
 #![warn(clippy::cast_possible_truncation)]
 
+mod diff;
+pub use diff::*;
+
+mod signer_set;
+pub use signer_set::*;
+
+mod staking;
+pub use staking::*;
+
+mod transition_proof;
+pub use transition_proof::*;
+
 mod bytes;
 mod serialize;
 mod string;
@@ -47,6 +59,10 @@ pub struct Committee<N: Network> {
     members: IndexMap<Address<N>, (u64, bool)>,
     /// The total stake of all `members`.
     total_stake: u64,
+    /// A map of member `address` to the rotated `signer` address it signs with.
+    signers: IndexMap<Address<N>, Address<N>>,
+    /// A map of rotated `signer` address to the member `address` that signs with it.
+    validators: IndexMap<Address<N>, Address<N>>,
 }
 
 impl<N: Network> Committee<N> {
@@ -81,7 +97,25 @@ impl<N: Network> Committee<N> {
         #[cfg(feature = "metrics")]
         metrics::gauge(metrics::committee::TOTAL_STAKE, total_stake as f64);
         // Return the new committee.
-        Ok(Self { starting_round, members, total_stake })
+        Ok(Self { starting_round, members, total_stake, signers: Default::default(), validators: Default::default() })
+    }
+
+    /// Returns the committee with the given map of member `address` to rotated `signer` address.
+    pub fn with_signers(mut self, signers: IndexMap<Address<N>, Address<N>>) -> Result<Self> {
+        // Ensure there are no more signers than members.
+        ensure!(signers.len() <= self.members.len(), "Committee cannot have more signers than members");
+        // Ensure each signer is unique.
+        let validators = signers.iter().map(|(member, signer)| (*signer, *member)).collect::<IndexMap<_, _>>();
+        ensure!(validators.len() == signers.len(), "Committee signers must be unique");
+        for (member, signer) in &signers {
+            // Ensure the validator is a member.
+            ensure!(self.is_committee_member(*member), "Validator '{member}' is not in the committee");
+            // Ensure the signer is not a member.
+            ensure!(!self.is_committee_member(*signer), "Signer '{signer}' is already a committee member");
+        }
+        self.signers = signers;
+        self.validators = validators;
+        Ok(self)
     }
 }
 
@@ -96,6 +130,31 @@ impl<N: Network> Committee<N> {
         &self.members
     }
 
+    /// Returns the map of member `address` to rotated `signer` address.
+    pub const fn signers(&self) -> &IndexMap<Address<N>, Address<N>> {
+        &self.signers
+    }
+
+    /// Returns the address the given member signs with.
+    /// Note: A member that has not rotated its signing key signs with its own address.
+    pub fn get_signer(&self, member: Address<N>) -> Option<Address<N>> {
+        match self.is_committee_member(member) {
+            true => Some(self.signers.get(&member).copied().unwrap_or(member)),
+            false => None,
+        }
+    }
+
+    /// Returns the member that signs with the given address, if one exists.
+    pub fn get_validator(&self, signer: Address<N>) -> Option<Address<N>> {
+        match self.validators.get(&signer) {
+            Some(member) => Some(*member),
+            None => match self.is_committee_member(signer) && !self.signers.contains_key(&signer) {
+                true => Some(signer),
+                false => None,
+            },
+        }
+    }
+
     /// Returns the number of validators in the committee.
     pub fn num_members(&self) -> usize {
         self.members.len()
@@ -259,6 +318,21 @@ pub mod test_helpers {
         sample_committee_for_round_and_size(round, 4, rng)
     }
 
+    /// Samples a random committee, where half of the members have rotated their signing keys.
+    pub fn sample_committee_with_signers(rng: &mut TestRng) -> Committee<CurrentNetwork> {
+        // Sample the committee.
+        let committee = sample_committee(rng);
+        // Sample the signers.
+        let signers = committee
+            .members()
+            .keys()
+            .step_by(2)
+            .map(|member| (*member, Address::<CurrentNetwork>::new(rng.gen())))
+            .collect::<IndexMap<_, _>>();
+        // Return the committee.
+        committee.with_signers(signers).unwrap()
+    }
+
     /// Samples a random committee for a given round and number of members.
     pub fn sample_committee_for_round_and_size(
         round: u64,
@@ -458,4 +532,33 @@ mod tests {
     fn test_maximum_committee_size() {
         assert_eq!(Committee::<CurrentNetwork>::MAX_COMMITTEE_SIZE, BatchHeader::<CurrentNetwork>::MAX_CERTIFICATES);
     }
+
+    #[test]
+    fn test_signers() {
+        let rng = &mut TestRng::default();
+
+        // Sample a committee, and rotate the signing key of its first member.
+        let committee = crate::test_helpers::sample_committee(rng);
+        let (rotated, unrotated) =
+            (*committee.members().get_index(0).unwrap().0, *committee.members().get_index(1).unwrap().0);
+        let signer = Address::new(rng.gen());
+        let committee = committee.with_signers(IndexMap::from([(rotated, signer)])).unwrap();
+
+        // Ensure the rotated member signs with its new key, and no longer with its own address.
+        assert_eq!(committee.get_signer(rotated), Some(signer));
+        assert_eq!(committee.get_validator(signer), Some(rotated));
+        assert_eq!(committee.get_validator(rotated), None);
+        // Ensure the unrotated member signs with its own address.
+        assert_eq!(committee.get_signer(unrotated), Some(unrotated));
+        assert_eq!(committee.get_validator(unrotated), Some(unrotated));
+        // Ensure an unknown address does not resolve.
+        assert_eq!(committee.get_signer(signer), None);
+        assert_eq!(committee.get_validator(Address::new(rng.gen())), None);
+
+        // Ensure the signers must be unique, and must not be members.
+        assert!(committee.clone().with_signers(IndexMap::from([(rotated, signer), (unrotated, signer)])).is_err());
+        assert!(committee.clone().with_signers(IndexMap::from([(rotated, unrotated)])).is_err());
+        // Ensure the rotated address must be a member.
+        assert!(committee.with_signers(IndexMap::from([(signer, Address::new(rng.gen()))])).is_err());
+    }
 }
@@ -19,10 +19,15 @@ impl<N: Network> Serialize for Committee<N> {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         match serializer.is_human_readable() {
             true => {
-                let mut certificate = serializer.serialize_struct("Committee", 3)?;
+                let mut certificate =
+                    serializer.serialize_struct("Committee", 3 + usize::from(!self.signers.is_empty()))?;
                 certificate.serialize_field("starting_round", &self.starting_round)?;
                 certificate.serialize_field("members", &self.members)?;
                 certificate.serialize_field("total_stake", &self.total_stake)?;
+                // Note: The signers are only included if a member has rotated its signing key.
+                if !self.signers.is_empty() {
+                    certificate.serialize_field("signers", &self.signers)?;
+                }
                 certificate.end()
             }
             false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
@@ -37,10 +42,16 @@ impl<'de, N: Network> Deserialize<'de> for Committee<N> {
             true => {
                 let mut value = serde_json::Value::deserialize(deserializer)?;
                 let total_stake: u64 = DeserializeExt::take_from_value::<D>(&mut value, "total_stake")?;
+                // Retrieve the signers, if they are present.
+                let signers = match value.get("signers") {
+                    Some(_) => DeserializeExt::take_from_value::<D>(&mut value, "signers")?,
+                    None => IndexMap::new(),
+                };
                 let committee = Self::new(
                     DeserializeExt::take_from_value::<D>(&mut value, "starting_round")?,
                     DeserializeExt::take_from_value::<D>(&mut value, "members")?,
                 )
+                .and_then(|committee| committee.with_signers(signers))
                 .map_err(de::Error::custom)?;
                 match committee.total_stake == total_stake {
                     true => Ok(committee),
@@ -96,6 +107,7 @@ mod tests {
         for expected in crate::test_helpers::sample_committees(rng) {
             check_serde_json(expected);
         }
+        check_serde_json(crate::test_helpers::sample_committee_with_signers(rng));
     }
 
     #[test]
@@ -105,5 +117,6 @@ mod tests {
         for expected in crate::test_helpers::sample_committees(rng) {
             check_bincode(expected);
         }
+        check_bincode(crate::test_helpers::sample_committee_with_signers(rng));
     }
 }
@@ -0,0 +1,114 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use std::collections::HashMap;
+
+/// A lookup from the address a committee member signs with, to the index of the member in the committee.
+///
+/// This is computed once per committee, so that checking the signers of many certificates
+/// does not scan the rotated signers of the committee for every signature.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SignerSet<N: Network> {
+    /// The starting round number of the committee.
+    starting_round: u64,
+    /// A map of `signer` address to the index of its member in the committee.
+    indices: HashMap<Address<N>, usize>,
+}
+
+impl<N: Network> SignerSet<N> {
+    /// Returns the starting round number of the committee.
+    pub const fn starting_round(&self) -> u64 {
+        self.starting_round
+    }
+
+    /// Returns the number of signers in the set.
+    pub fn len(&self) -> usize {
+        self.indices.len()
+    }
+
+    /// Returns `true` if the set has no signers.
+    pub fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+
+    /// Returns `true` if the given address is a signer of the committee.
+    pub fn contains(&self, signer: &Address<N>) -> bool {
+        self.indices.contains_key(signer)
+    }
+
+    /// Returns the index of the member that signs with the given address, if one exists.
+    pub fn get_index(&self, signer: &Address<N>) -> Option<usize> {
+        self.indices.get(signer).copied()
+    }
+}
+
+impl<N: Network> Committee<N> {
+    /// Returns the lookup from the address each member signs with, to the index of the member.
+    pub fn to_signer_set(&self) -> SignerSet<N> {
+        let indices = self
+            .members
+            .keys()
+            .enumerate()
+            .map(|(index, member)| (self.signers.get(member).copied().unwrap_or(*member), index))
+            .collect();
+        SignerSet { starting_round: self.starting_round, indices }
+    }
+
+    /// Returns the member that signs with the given address, using the given signer set of this committee.
+    pub fn get_validator_with(&self, signer_set: &SignerSet<N>, signer: &Address<N>) -> Option<Address<N>> {
+        signer_set.get_index(signer).and_then(|index| self.members.get_index(index)).map(|(member, _)| *member)
+    }
+
+    /// Returns the stake of the member that signs with the given address, using the given signer set of this committee.
+    pub fn get_signer_stake_with(&self, signer_set: &SignerSet<N>, signer: &Address<N>) -> u64 {
+        signer_set.get_index(signer).and_then(|index| self.members.get_index(index)).map_or(0, |(_, (stake, _))| *stake)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::prelude::TestRng;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    #[test]
+    fn test_signer_set() {
+        let rng = &mut TestRng::default();
+
+        for committee in
+            [crate::test_helpers::sample_committee(rng), crate::test_helpers::sample_committee_with_signers(rng)]
+        {
+            // Compute the signer set.
+            let signer_set = committee.to_signer_set();
+            assert_eq!(signer_set.starting_round(), committee.starting_round());
+            assert_eq!(signer_set.len(), committee.num_members());
+
+            // Check the lookups match the committee.
+            for (member, (stake, _)) in committee.members() {
+                let signer = committee.get_signer(*member).unwrap();
+                assert_eq!(committee.get_validator_with(&signer_set, &signer), committee.get_validator(signer));
+                assert_eq!(committee.get_signer_stake_with(&signer_set, &signer), *stake);
+            }
+
+            // Check an unknown address is not a signer.
+            let address = Address::<CurrentNetwork>::new(rng.gen());
+            assert!(!signer_set.contains(&address));
+            assert_eq!(committee.get_validator_with(&signer_set, &address), None);
+            assert_eq!(committee.get_signer_stake_with(&signer_set, &address), 0);
+        }
+    }
+}
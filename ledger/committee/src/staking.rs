@@ -0,0 +1,217 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The minimum amount of microcredits that may be bonded in a single `bond_public` call.
+pub const MIN_BOND_AMOUNT: u64 = 1_000_000u64; // microcredits
+/// The number of blocks an unbond must wait before it can be claimed with `claim_unbond_public`.
+pub const UNBONDING_PERIOD: u32 = 360u32; // blocks
+/// The commission withheld by a validator from its delegators' staking rewards, as a percentage.
+/// Note: `credits.aleo` pays each staker in proportion to its own stake, and does not withhold a commission.
+pub const VALIDATOR_COMMISSION_PERCENT: u8 = 0u8;
+
+/// Returns the height at which an unbond made at the given height can be claimed.
+///
+/// Note: Unbonding again before the claim resets the release height, as in `credits.aleo`.
+pub fn unbond_release_height(unbond_height: u32) -> Result<u32> {
+    unbond_height
+        .checked_add(UNBONDING_PERIOD)
+        .ok_or_else(|| anyhow!("The unbond release height overflows for an unbond at block {unbond_height}"))
+}
+
+/// Returns the number of blocks until the unbond with the given release height can be claimed.
+/// If the unbond can be claimed at the current height, this returns `0`.
+pub fn blocks_until_unbond_release(release_height: u32, current_height: u32) -> u32 {
+    release_height.saturating_sub(current_height)
+}
+
+/// Returns `(validator_share, staker_share)` of the staking reward, for the given commission percentage.
+pub fn split_commission(staking_reward: u64, commission_percent: u8) -> Result<(u64, u64)> {
+    // Ensure the commission is a valid percentage.
+    ensure!(commission_percent <= 100, "The commission must be at most 100%, found {commission_percent}%");
+    // Compute the validator share, rounding down in favor of the staker.
+    let validator_share = (staking_reward as u128).saturating_mul(commission_percent as u128).saturating_div(100);
+    // Note: The validator share is at most the staking reward, so this conversion is infallible.
+    let validator_share = u64::try_from(validator_share)?;
+    Ok((validator_share, staking_reward.saturating_sub(validator_share)))
+}
+
+impl<N: Network> Committee<N> {
+    /// Ensures a delegator may bond the given amount to the validator, following the rules of `bond_public`.
+    /// The `current_bond` is the `(validator, microcredits)` already bonded by the delegator, if any.
+    ///
+    /// Note: The committee may lag behind the `committee` mapping, so the bond may still fail on-chain.
+    pub fn check_delegation(
+        &self,
+        delegator: Address<N>,
+        validator: Address<N>,
+        current_bond: Option<(Address<N>, u64)>,
+        amount: u64,
+    ) -> Result<()> {
+        // Ensure the amount is at least the minimum bond amount.
+        ensure!(amount >= MIN_BOND_AMOUNT, "The bond amount must be at least {MIN_BOND_AMOUNT} microcredits");
+        // Ensure the delegator is not a validator.
+        ensure!(!self.is_committee_member(delegator), "Validator '{delegator}' cannot bond as a delegator");
+        // Ensure the validator is open to stakers.
+        ensure!(self.is_committee_member(validator), "Validator '{validator}' is not in the committee");
+        ensure!(self.is_committee_member_open(validator), "Validator '{validator}' is not open to stakers");
+        // Compute the bonded microcredits after the bond.
+        let bonded = match current_bond {
+            Some((current_validator, bonded)) => {
+                // Ensure the delegator is bonding to the same validator.
+                ensure!(
+                    current_validator == validator,
+                    "Delegator '{delegator}' is already bonded to validator '{current_validator}'"
+                );
+                bonded.checked_add(amount).ok_or_else(|| anyhow!("The bonded microcredits overflow"))?
+            }
+            None => amount,
+        };
+        // Ensure the bonded microcredits are at least the minimum delegator stake.
+        ensure!(
+            bonded >= MIN_DELEGATOR_STAKE,
+            "Delegators must bond at least {MIN_DELEGATOR_STAKE} microcredits, found {bonded} microcredits"
+        );
+        Ok(())
+    }
+
+    /// Returns the staking reward for one block, for a staker with the given stake bonded to the validator.
+    /// The staking reward is defined as: `block_reward * stake / total_stake`.
+    ///
+    /// As in the ledger, stakers bonded to a validator with more than 25% of the total stake,
+    /// and stakers with less than the minimum delegator stake, do not receive a staking reward.
+    pub fn staking_reward(&self, validator: Address<N>, stake: u64, block_reward: u64) -> u64 {
+        // If there is no stake, return 0.
+        if self.total_stake == 0 {
+            return 0;
+        }
+        // If the validator has more than 25% of the total stake, return 0.
+        if self.get_stake(validator) > self.total_stake.saturating_div(4) {
+            return 0;
+        }
+        // If the staker has less than the minimum required stake, return 0.
+        if stake < MIN_DELEGATOR_STAKE {
+            return 0;
+        }
+        // Compute the staking reward.
+        let reward = (block_reward as u128).saturating_mul(stake as u128).saturating_div(self.total_stake as u128);
+        // Note: The reward is at most the block reward, as the stake of a staker is at most the total stake.
+        u64::try_from(reward).unwrap_or(0)
+    }
+
+    /// Returns the projected staking reward over the given number of blocks, for a staker with
+    /// the given stake bonded to the validator, assuming the current stake distribution.
+    ///
+    /// Note: As rewards are bonded each block, the projection does not compound the stake,
+    /// and slightly underestimates the reward over long periods.
+    pub fn projected_staking_reward(
+        &self,
+        validator: Address<N>,
+        stake: u64,
+        block_reward: u64,
+        num_blocks: u32,
+    ) -> u64 {
+        self.staking_reward(validator, stake, block_reward).saturating_mul(num_blocks as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::prelude::TestRng;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    #[test]
+    fn test_unbond_release_height() -> Result<()> {
+        assert_eq!(unbond_release_height(0)?, UNBONDING_PERIOD);
+        assert_eq!(unbond_release_height(1_000)?, 1_000 + UNBONDING_PERIOD);
+        assert!(unbond_release_height(u32::MAX).is_err());
+
+        let release_height = unbond_release_height(1_000)?;
+        assert_eq!(blocks_until_unbond_release(release_height, 1_000), UNBONDING_PERIOD);
+        assert_eq!(blocks_until_unbond_release(release_height, release_height), 0);
+        assert_eq!(blocks_until_unbond_release(release_height, release_height + 1), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_split_commission() -> Result<()> {
+        assert_eq!(split_commission(1_000, VALIDATOR_COMMISSION_PERCENT)?, (0, 1_000));
+        assert_eq!(split_commission(1_000, 10)?, (100, 900));
+        assert_eq!(split_commission(999, 10)?, (99, 900));
+        assert_eq!(split_commission(u64::MAX, 100)?, (u64::MAX, 0));
+        assert!(split_commission(1_000, 101).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_check_delegation() {
+        let rng = &mut TestRng::default();
+
+        // Sample a committee, and find an open and a closed validator.
+        let committee = crate::test_helpers::sample_committee(rng);
+        let mut members = committee.members().keys().copied();
+        let open = members.clone().find(|member| committee.is_committee_member_open(*member));
+        let closed = members.find(|member| !committee.is_committee_member_open(*member));
+        let delegator = Address::<CurrentNetwork>::new(rng.gen());
+
+        if let Some(validator) = open {
+            // Check a new delegation.
+            assert!(committee.check_delegation(delegator, validator, None, MIN_DELEGATOR_STAKE).is_ok());
+            assert!(committee.check_delegation(delegator, validator, None, MIN_DELEGATOR_STAKE - 1).is_err());
+            // Check a top-up of an existing delegation.
+            let bond = Some((validator, MIN_DELEGATOR_STAKE));
+            assert!(committee.check_delegation(delegator, validator, bond, MIN_BOND_AMOUNT).is_ok());
+            assert!(committee.check_delegation(delegator, validator, bond, MIN_BOND_AMOUNT - 1).is_err());
+            // Check a validator cannot delegate.
+            assert!(committee.check_delegation(validator, validator, None, MIN_DELEGATOR_STAKE).is_err());
+            // Check a delegator cannot bond to a second validator.
+            let other = Address::<CurrentNetwork>::new(rng.gen());
+            assert!(committee
+                .check_delegation(delegator, validator, Some((other, MIN_DELEGATOR_STAKE)), MIN_BOND_AMOUNT)
+                .is_err());
+        }
+        if let Some(validator) = closed {
+            // Check a closed validator rejects delegations.
+            assert!(committee.check_delegation(delegator, validator, None, MIN_DELEGATOR_STAKE).is_err());
+        }
+        // Check a non-member rejects delegations.
+        let validator = Address::<CurrentNetwork>::new(rng.gen());
+        assert!(committee.check_delegation(delegator, validator, None, MIN_DELEGATOR_STAKE).is_err());
+    }
+
+    #[test]
+    fn test_staking_reward() {
+        let rng = &mut TestRng::default();
+
+        // Sample a committee where each validator has less than 25% of the total stake.
+        let committee = crate::test_helpers::sample_committee_for_round_and_size(1, 10, rng);
+        let validator = *committee.members().keys().next().unwrap();
+        let block_reward = 1_000_000_000u64;
+
+        // Check the staking reward is proportional to the stake.
+        let stake = committee.total_stake() / 10;
+        assert_eq!(committee.staking_reward(validator, stake, block_reward), block_reward / 10);
+        assert_eq!(committee.projected_staking_reward(validator, stake, block_reward, 360), 360 * block_reward / 10);
+        // Check a staker below the minimum delegator stake is not rewarded.
+        assert_eq!(committee.staking_reward(validator, MIN_DELEGATOR_STAKE - 1, block_reward), 0);
+
+        // Check a staker bonded to a validator with more than 25% of the total stake is not rewarded.
+        let committee = crate::test_helpers::sample_committee_for_round_and_size(1, 3, rng);
+        let validator = *committee.members().keys().next().unwrap();
+        assert_eq!(committee.staking_reward(validator, MIN_DELEGATOR_STAKE, block_reward), 0);
+    }
+}
@@ -0,0 +1,255 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use console::account::{PrivateKey, Signature};
+
+/// A proof that a committee was derived from the previous committee, at the given block.
+///
+/// The members of the previous committee sign the transition, so a light client that trusts the
+/// previous committee can follow the committee changes without executing the bond and unbond
+/// transactions in each block. The transition is valid once signed by a quorum of the previous committee.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CommitteeTransitionProof<N: Network> {
+    /// The hash of the block that derived the next committee.
+    block_hash: N::BlockHash,
+    /// The height of the block that derived the next committee.
+    block_height: u32,
+    /// The ID of the previous committee.
+    previous_id: Field<N>,
+    /// The ID of the next committee.
+    next_id: Field<N>,
+    /// The changes from the previous committee to the next committee.
+    diff: CommitteeDiff<N>,
+    /// The signatures of the previous committee, keyed by the signing address.
+    signatures: IndexMap<Address<N>, Signature<N>>,
+}
+
+impl<N: Network> Committee<N> {
+    /// Returns the committee ID, as the hash of the committee bytes.
+    pub fn to_id(&self) -> Result<Field<N>> {
+        N::hash_bhp1024(&self.to_bytes_le()?.to_bits_le())
+    }
+
+    /// Returns an unsigned proof that the next committee was derived from the previous committee,
+    /// at the block with the given hash and height.
+    pub fn prove_transition(
+        previous: &Committee<N>,
+        next: &Committee<N>,
+        block_hash: N::BlockHash,
+        block_height: u32,
+    ) -> Result<CommitteeTransitionProof<N>> {
+        // Ensure the next committee starts after the previous committee.
+        ensure!(
+            next.starting_round > previous.starting_round,
+            "The next committee must start after round {}, found round {}",
+            previous.starting_round,
+            next.starting_round
+        );
+        Ok(CommitteeTransitionProof {
+            block_hash,
+            block_height,
+            previous_id: previous.to_id()?,
+            next_id: next.to_id()?,
+            diff: next.diff(previous),
+            signatures: Default::default(),
+        })
+    }
+}
+
+impl<N: Network> CommitteeTransitionProof<N> {
+    /// Returns the hash of the block that derived the next committee.
+    pub const fn block_hash(&self) -> N::BlockHash {
+        self.block_hash
+    }
+
+    /// Returns the height of the block that derived the next committee.
+    pub const fn block_height(&self) -> u32 {
+        self.block_height
+    }
+
+    /// Returns the ID of the previous committee.
+    pub const fn previous_id(&self) -> Field<N> {
+        self.previous_id
+    }
+
+    /// Returns the ID of the next committee.
+    pub const fn next_id(&self) -> Field<N> {
+        self.next_id
+    }
+
+    /// Returns the changes from the previous committee to the next committee.
+    pub const fn diff(&self) -> &CommitteeDiff<N> {
+        &self.diff
+    }
+
+    /// Returns the signatures of the previous committee, keyed by the signing address.
+    pub const fn signatures(&self) -> &IndexMap<Address<N>, Signature<N>> {
+        &self.signatures
+    }
+
+    /// Returns the message signed by the previous committee.
+    fn to_message(&self) -> [Field<N>; 4] {
+        [*self.block_hash, Field::from_u32(self.block_height), self.previous_id, self.next_id]
+    }
+
+    /// Signs the proof with the given private key, which must sign for a member of the previous committee.
+    pub fn sign<R: Rng + CryptoRng>(&mut self, private_key: &PrivateKey<N>, rng: &mut R) -> Result<()> {
+        // Sign the message.
+        let signature = Signature::sign(private_key, &self.to_message(), rng)?;
+        // Insert the signature.
+        self.signatures.insert(Address::try_from(private_key)?, signature);
+        Ok(())
+    }
+
+    /// Ensures the proof is signed by a quorum of the previous committee,
+    /// and that the next committee is derived from the previous committee.
+    pub fn verify(&self, previous: &Committee<N>, next: &Committee<N>) -> Result<()> {
+        // Ensure the committees match the proof.
+        ensure!(previous.to_id()? == self.previous_id, "The previous committee does not match the proof");
+        ensure!(next.to_id()? == self.next_id, "The next committee does not match the proof");
+        ensure!(next.diff(previous) == self.diff, "The committee changes do not match the proof");
+
+        // Ensure each signature is valid, and collect the signing members.
+        let message = self.to_message();
+        let mut members = HashSet::with_capacity(self.signatures.len());
+        for (signer, signature) in &self.signatures {
+            // Retrieve the member that signs with the address.
+            let Some(member) = previous.get_validator(*signer) else {
+                bail!("Signer '{signer}' is not in the previous committee");
+            };
+            // Ensure the signature is valid.
+            ensure!(signature.verify(signer, &message), "Invalid signature from member '{member}'");
+            members.insert(member);
+        }
+        // Ensure the signing members reach the quorum threshold.
+        ensure!(
+            previous.is_quorum_threshold_reached(&members),
+            "The committee transition is not signed by a quorum of the previous committee"
+        );
+        Ok(())
+    }
+}
+
+impl<N: Network> FromBytes for CommitteeTransitionProof<N> {
+    /// Reads the committee transition proof from the buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid committee transition proof version"));
+        }
+        // Read the block hash and height.
+        let block_hash = N::BlockHash::read_le(&mut reader)?;
+        let block_height = u32::read_le(&mut reader)?;
+        // Read the committee IDs.
+        let previous_id = Field::read_le(&mut reader)?;
+        let next_id = Field::read_le(&mut reader)?;
+        // Read the diff.
+        let diff = CommitteeDiff::read_le(&mut reader)?;
+        // Read the number of signatures.
+        let num_signatures = u16::read_le(&mut reader)?;
+        // Ensure the number of signatures is within the allowed limit.
+        if num_signatures > Committee::<N>::MAX_COMMITTEE_SIZE {
+            return Err(error(format!("Cannot exceed {} signatures", Committee::<N>::MAX_COMMITTEE_SIZE)));
+        }
+        // Read the signatures.
+        let mut signatures = IndexMap::with_capacity(num_signatures as usize);
+        for _ in 0..num_signatures {
+            signatures.insert(Address::read_le(&mut reader)?, Signature::read_le(&mut reader)?);
+        }
+        // Return the proof.
+        Ok(Self { block_hash, block_height, previous_id, next_id, diff, signatures })
+    }
+}
+
+impl<N: Network> ToBytes for CommitteeTransitionProof<N> {
+    /// Writes the committee transition proof to the buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the block hash and height.
+        self.block_hash.write_le(&mut writer)?;
+        self.block_height.write_le(&mut writer)?;
+        // Write the committee IDs.
+        self.previous_id.write_le(&mut writer)?;
+        self.next_id.write_le(&mut writer)?;
+        // Write the diff.
+        self.diff.write_le(&mut writer)?;
+        // Write the number of signatures.
+        u16::try_from(self.signatures.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        // Write the signatures.
+        for (signer, signature) in &self.signatures {
+            signer.write_le(&mut writer)?;
+            signature.write_le(&mut writer)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::prelude::TestRng;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    /// Samples a committee of members with known private keys.
+    fn sample_committee(round: u64, private_keys: &[PrivateKey<CurrentNetwork>]) -> Committee<CurrentNetwork> {
+        let members = private_keys
+            .iter()
+            .map(|private_key| (Address::try_from(private_key).unwrap(), (MIN_VALIDATOR_STAKE, true)))
+            .collect();
+        Committee::new(round, members).unwrap()
+    }
+
+    #[test]
+    fn test_prove_transition() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample the previous committee, and a next committee with an additional member.
+        let private_keys = (0..5).map(|_| PrivateKey::new(rng)).collect::<Result<Vec<_>>>()?;
+        let previous = sample_committee(1, &private_keys[..4]);
+        let next = sample_committee(10, &private_keys);
+
+        // Prove the transition.
+        let block_hash = <CurrentNetwork as Network>::BlockHash::from(Field::rand(rng));
+        let mut proof = Committee::prove_transition(&previous, &next, block_hash, 5)?;
+        assert_eq!(proof.diff().changes().len(), 1);
+
+        // Check the proof is rejected until a quorum of the previous committee signs it.
+        for private_key in &private_keys[..2] {
+            proof.sign(private_key, rng)?;
+        }
+        assert!(proof.verify(&previous, &next).is_err());
+        proof.sign(&private_keys[2], rng)?;
+        proof.verify(&previous, &next)?;
+
+        // Check the proof is rejected for a different next committee.
+        let other = sample_committee(10, &private_keys[..4]);
+        assert!(proof.verify(&previous, &other).is_err());
+
+        // Check a signature from outside the previous committee is rejected.
+        let mut invalid = proof.clone();
+        invalid.sign(&private_keys[4], rng)?;
+        assert!(invalid.verify(&previous, &next).is_err());
+
+        // Check the byte representation.
+        let bytes = proof.to_bytes_le()?;
+        assert_eq!(proof, CommitteeTransitionProof::read_le(&bytes[..])?);
+        Ok(())
+    }
+}
@@ -16,7 +16,19 @@ use super::*;
 
 impl<N: Network> FromBytes for BatchCertificate<N> {
     /// Reads the batch certificate from the buffer.
-    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+    fn read_le<R: Read>(reader: R) -> IoResult<Self> {
+        // Read the batch certificate.
+        let certificate = Self::read_le_with_deferred_signatures(reader)?;
+        // Verify the signatures are valid.
+        Self::verify_signatures([&certificate]).map_err(error)?;
+        Ok(certificate)
+    }
+}
+
+impl<N: Network> BatchCertificate<N> {
+    /// Reads the batch certificate from the buffer, deferring the signature verification.
+    /// The caller must verify the signatures afterwards with `BatchCertificate::verify_signatures`.
+    pub fn read_le_with_deferred_signatures<R: Read>(mut reader: R) -> IoResult<Self> {
         // Read the version.
         let version = u8::read_le(&mut reader)?;
         // Ensure the version is valid.
@@ -43,7 +55,7 @@ impl<N: Network> FromBytes for BatchCertificate<N> {
             .map(Signature::read_le)
             .collect::<Result<IndexSet<_>, _>>()?;
         // Return the batch certificate.
-        Self::from(batch_header, signatures).map_err(error)
+        Self::from_with_deferred_signatures(batch_header, signatures).map_err(error)
     }
 }
 
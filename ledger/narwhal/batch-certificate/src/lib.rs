@@ -50,6 +50,21 @@ impl<N: Network> BatchCertificate<N> {
 impl<N: Network> BatchCertificate<N> {
     /// Initializes a new batch certificate.
     pub fn from(batch_header: BatchHeader<N>, signatures: IndexSet<Signature<N>>) -> Result<Self> {
+        // Initialize the batch certificate.
+        let certificate = Self::from_with_deferred_signatures(batch_header, signatures)?;
+        // Verify the signatures are valid.
+        Self::verify_signatures([&certificate])?;
+        // Return the batch certificate.
+        Ok(certificate)
+    }
+
+    /// Initializes a new batch certificate, checking the signers but deferring the signature verification.
+    /// Unlike `from_unchecked`, this still enforces the signature count and signer uniqueness checks.
+    /// The caller must verify the signatures afterwards with `BatchCertificate::verify_signatures`.
+    pub fn from_with_deferred_signatures(
+        batch_header: BatchHeader<N>,
+        signatures: IndexSet<Signature<N>>,
+    ) -> Result<Self> {
         // Ensure that the number of signatures is within bounds.
         ensure!(signatures.len() <= Self::MAX_SIGNATURES as usize, "Invalid number of signatures");
 
@@ -61,13 +76,6 @@ impl<N: Network> BatchCertificate<N> {
         );
         ensure!(signature_authors.len() == signatures.len(), "A duplicate author was found in the set of signatures");
 
-        // Verify the signatures are valid.
-        cfg_iter!(signatures).try_for_each(|signature| {
-            if !signature.verify(&signature.to_address(), &[batch_header.batch_id()]) {
-                bail!("Invalid batch certificate signature")
-            }
-            Ok(())
-        })?;
         // Return the batch certificate.
         Self::from_unchecked(batch_header, signatures)
     }
@@ -142,6 +150,31 @@ impl<N: Network> BatchCertificate<N> {
     }
 }
 
+impl<N: Network> BatchCertificate<N> {
+    /// Ensures the signatures of the given batch certificates are valid.
+    ///
+    /// Note: This is not batch Schnorr verification; each signature is still verified on its own.
+    /// The signatures of all certificates are flattened into one parallel iterator, rather than
+    /// verified one certificate at a time, so that small certificates do not leave threads idle.
+    pub fn verify_signatures<'a>(certificates: impl IntoIterator<Item = &'a Self>) -> Result<()>
+    where
+        N: 'a,
+    {
+        // Collect the signatures and the batch ID they sign.
+        let signatures = certificates
+            .into_iter()
+            .flat_map(|certificate| certificate.signatures.iter().map(|signature| (signature, certificate.batch_id())))
+            .collect::<Vec<_>>();
+        // Verify the signatures.
+        cfg_into_iter!(signatures).try_for_each(|(signature, batch_id)| {
+            if !signature.verify(&signature.to_address(), &[batch_id]) {
+                bail!("Invalid batch certificate signature for batch '{batch_id}'")
+            }
+            Ok(())
+        })
+    }
+}
+
 #[cfg(any(test, feature = "test-helpers"))]
 pub mod test_helpers {
     use super::*;
@@ -235,6 +268,7 @@ pub mod test_helpers {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use console::{account::PrivateKey, prelude::TestRng};
 
     type CurrentNetwork = console::network::MainnetV0;
 
@@ -242,4 +276,28 @@ mod tests {
     fn test_maximum_signatures() {
         assert_eq!(BatchHeader::<CurrentNetwork>::MAX_CERTIFICATES, BatchCertificate::<CurrentNetwork>::MAX_SIGNATURES);
     }
+
+    #[test]
+    fn test_verify_signatures() {
+        let rng = &mut TestRng::default();
+
+        // Check the signatures of many certificates are verified at once.
+        let certificates = crate::test_helpers::sample_batch_certificates(rng);
+        assert!(BatchCertificate::verify_signatures(&certificates).is_ok());
+
+        // Add a signature over a different batch ID.
+        let certificate = certificates.last().unwrap();
+        let mut signatures = certificate.signatures().copied().collect::<IndexSet<_>>();
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        signatures.insert(private_key.sign(&[Field::rand(rng)], rng).unwrap());
+        let forged =
+            BatchCertificate::from_with_deferred_signatures(certificate.batch_header().clone(), signatures.clone())
+                .unwrap();
+        assert!(BatchCertificate::from(certificate.batch_header().clone(), signatures).is_err());
+
+        // Check a forged signature in any certificate is rejected.
+        let mut certificates = certificates.into_iter().collect::<Vec<_>>();
+        certificates.push(forged);
+        assert!(BatchCertificate::verify_signatures(&certificates).is_err());
+    }
 }
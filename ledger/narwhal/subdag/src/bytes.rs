@@ -48,12 +48,15 @@ impl<N: Network> FromBytes for Subdag<N> {
             let mut certificates = IndexSet::new();
             for _ in 0..num_certificates {
                 // Read the certificate.
-                certificates.insert(BatchCertificate::read_le(&mut reader)?);
+                // Note: The signatures are verified for all certificates at once, below.
+                certificates.insert(BatchCertificate::read_le_with_deferred_signatures(&mut reader)?);
             }
             // Insert the round and certificates.
             subdag.insert(round, certificates);
         }
 
+        // Verify the signatures of the certificates.
+        BatchCertificate::verify_signatures(subdag.values().flatten()).map_err(error)?;
         // Return the subdag.
         Self::from(subdag).map_err(error)
     }
@@ -15,6 +15,9 @@
 #![forbid(unsafe_code)]
 #![warn(clippy::cast_possible_truncation)]
 
+mod statistics;
+pub use statistics::*;
+
 mod bytes;
 mod serialize;
 mod string;
@@ -188,18 +191,8 @@ impl<N: Network> Subdag<N> {
 
     /// Returns the timestamp of the anchor round, defined as the weighted median timestamp of the subdag.
     pub fn timestamp(&self, committee: &Committee<N>) -> i64 {
-        // Retrieve the anchor round.
-        let anchor_round = self.anchor_round();
-        // Retrieve the timestamps and stakes of the certificates for `anchor_round` - 1.
-        let timestamps_and_stakes = self
-            .values()
-            .flatten()
-            .filter(|certificate| certificate.round() == anchor_round.saturating_sub(1))
-            .map(|certificate| (certificate.timestamp(), committee.get_stake(certificate.author())))
-            .collect::<Vec<_>>();
-
-        // Return the weighted median timestamp.
-        weighted_median(timestamps_and_stakes)
+        // Return the weighted median timestamp of the certificates for `anchor_round` - 1.
+        self.round_timestamp(self.anchor_round().saturating_sub(1), committee)
     }
 
     /// Returns the subdag root of the certificates.
@@ -216,6 +209,34 @@ impl<N: Network> Subdag<N> {
     }
 }
 
+impl<N: Network> Subdag<N> {
+    /// Ensures the author and signers of every certificate in the subdag sign for a member of the given committee.
+    /// Note: This method does not verify the signatures, see `BatchCertificate::verify_signatures`.
+    pub fn verify_signers(&self, committee: &Committee<N>) -> Result<()> {
+        // Compute the signer set once, rather than scanning the committee for every signature.
+        let signer_set = committee.to_signer_set();
+        for certificate in self.values().flatten() {
+            // Ensure the author is in the committee.
+            let author = certificate.author();
+            ensure!(
+                signer_set.contains(&author),
+                "Author '{author}' of certificate '{}' is not in the committee",
+                certificate.id()
+            );
+            // Ensure the signers are in the committee.
+            for signature in certificate.signatures() {
+                let signer = signature.to_address();
+                ensure!(
+                    signer_set.contains(&signer),
+                    "Signer '{signer}' of certificate '{}' is not in the committee",
+                    certificate.id()
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
 impl<N: Network> Deref for Subdag<N> {
     type Target = BTreeMap<u64, IndexSet<BatchCertificate<N>>>;
 
@@ -302,10 +323,35 @@ mod tests {
     use super::*;
     use narwhal_batch_header::BatchHeader;
 
+    use indexmap::IndexMap;
+
     type CurrentNetwork = console::network::MainnetV0;
 
     const ITERATIONS: u64 = 100;
 
+    #[test]
+    fn test_verify_signers() {
+        let rng = &mut TestRng::default();
+
+        // Sample a subdag, and a committee of its authors and signers.
+        let subdag = crate::test_helpers::sample_subdag(rng);
+        let mut members = IndexMap::new();
+        for certificate in subdag.values().flatten() {
+            members.insert(certificate.author(), (ledger_committee::MIN_VALIDATOR_STAKE, true));
+            for signature in certificate.signatures() {
+                members.insert(signature.to_address(), (ledger_committee::MIN_VALIDATOR_STAKE, true));
+            }
+        }
+        let committee = Committee::new(subdag.anchor_round() - 2, members.clone()).unwrap();
+        assert!(subdag.verify_signers(&committee).is_ok());
+
+        // Check a committee without one of the signers is rejected.
+        let signer = subdag.leader_certificate().signatures().next().unwrap().to_address();
+        members.shift_remove(&signer);
+        let committee = Committee::new(subdag.anchor_round() - 2, members).unwrap();
+        assert!(subdag.verify_signers(&committee).is_err());
+    }
+
     #[test]
     fn test_max_certificates() {
         // Determine the maximum number of certificates in a block.
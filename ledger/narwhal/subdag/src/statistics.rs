@@ -0,0 +1,193 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use indexmap::IndexMap;
+
+/// The statistics of a validator in a subdag.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ValidatorStatistics {
+    /// The number of certificates authored by the validator.
+    num_certificates: usize,
+    /// The number of transmissions in the certificates authored by the validator.
+    num_transmissions: usize,
+    /// The number of certificates signed by the validator, excluding its own.
+    num_endorsements: usize,
+}
+
+impl ValidatorStatistics {
+    /// Returns the number of certificates authored by the validator.
+    pub const fn num_certificates(&self) -> usize {
+        self.num_certificates
+    }
+
+    /// Returns the number of transmissions in the certificates authored by the validator.
+    pub const fn num_transmissions(&self) -> usize {
+        self.num_transmissions
+    }
+
+    /// Returns the number of certificates signed by the validator, excluding its own.
+    pub const fn num_endorsements(&self) -> usize {
+        self.num_endorsements
+    }
+}
+
+/// The statistics of a subdag, for monitoring validator liveness.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SubdagStatistics<N: Network> {
+    /// The anchor round.
+    anchor_round: u64,
+    /// The expected leader of each even round in the subdag.
+    leaders: BTreeMap<u64, Address<N>>,
+    /// The weighted median timestamp of each round in the subdag.
+    timestamps: BTreeMap<u64, i64>,
+    /// The statistics of each committee member, including members without certificates in the subdag.
+    validators: IndexMap<Address<N>, ValidatorStatistics>,
+}
+
+impl<N: Network> SubdagStatistics<N> {
+    /// Returns the anchor round.
+    pub const fn anchor_round(&self) -> u64 {
+        self.anchor_round
+    }
+
+    /// Returns the expected leader of each even round in the subdag.
+    pub const fn leaders(&self) -> &BTreeMap<u64, Address<N>> {
+        &self.leaders
+    }
+
+    /// Returns the weighted median timestamp of each round in the subdag.
+    pub const fn timestamps(&self) -> &BTreeMap<u64, i64> {
+        &self.timestamps
+    }
+
+    /// Returns the statistics of each committee member.
+    pub const fn validators(&self) -> &IndexMap<Address<N>, ValidatorStatistics> {
+        &self.validators
+    }
+
+    /// Returns the statistics of the given committee member, if it is in the committee.
+    pub fn get_validator(&self, address: &Address<N>) -> Option<&ValidatorStatistics> {
+        self.validators.get(address)
+    }
+}
+
+impl<N: Network> Subdag<N> {
+    /// Returns the weighted median timestamp of the certificates in the given round.
+    pub fn round_timestamp(&self, round: u64, committee: &Committee<N>) -> i64 {
+        // Retrieve the timestamps and stakes of the certificates for the round.
+        let timestamps_and_stakes = self
+            .values()
+            .flatten()
+            .filter(|certificate| certificate.round() == round)
+            .map(|certificate| {
+                // Resolve the validator of the author, as the author may sign with a rotated signing key.
+                let stake = committee.get_validator(certificate.author()).map_or(0, |v| committee.get_stake(v));
+                (certificate.timestamp(), stake)
+            })
+            .collect::<Vec<_>>();
+
+        // Return the weighted median timestamp.
+        weighted_median(timestamps_and_stakes)
+    }
+
+    /// Returns the statistics of the subdag, for the committee that certified it.
+    ///
+    /// Authors and signers are resolved to the committee member they sign for,
+    /// and signatures from addresses outside the committee are ignored.
+    pub fn statistics(&self, committee: &Committee<N>) -> Result<SubdagStatistics<N>> {
+        // Compute the expected leader of each even round.
+        let leaders = self
+            .keys()
+            .filter(|round| *round % 2 == 0)
+            .map(|round| Ok((*round, committee.get_leader(*round)?)))
+            .collect::<Result<BTreeMap<_, _>>>()?;
+        // Compute the weighted median timestamp of each round.
+        let timestamps = self.keys().map(|round| (*round, self.round_timestamp(*round, committee))).collect();
+
+        // Initialize the statistics of each committee member.
+        let mut validators = committee
+            .members()
+            .keys()
+            .map(|member| (*member, ValidatorStatistics::default()))
+            .collect::<IndexMap<_, _>>();
+        for certificate in self.values().flatten() {
+            // Resolve the author to its committee member.
+            let author = committee.get_validator(certificate.author());
+            // Update the statistics of the author.
+            if let Some(statistics) = author.and_then(|author| validators.get_mut(&author)) {
+                statistics.num_certificates += 1;
+                statistics.num_transmissions += certificate.transmission_ids().len();
+            }
+            // Update the statistics of the signers.
+            for signature in certificate.signatures() {
+                // Resolve the signer to its committee member, skipping the author.
+                let signer = committee.get_validator(signature.to_address()).filter(|signer| Some(*signer) != author);
+                if let Some(statistics) = signer.and_then(|signer| validators.get_mut(&signer)) {
+                    statistics.num_endorsements += 1;
+                }
+            }
+        }
+
+        Ok(SubdagStatistics { anchor_round: self.anchor_round(), leaders, timestamps, validators })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::prelude::TestRng;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    #[test]
+    fn test_statistics() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample a subdag, and a committee of its authors and signers.
+        let subdag = crate::test_helpers::sample_subdag(rng);
+        let mut members = IndexMap::new();
+        for certificate in subdag.values().flatten() {
+            members.insert(certificate.author(), (ledger_committee::MIN_VALIDATOR_STAKE, true));
+            for signature in certificate.signatures() {
+                members.insert(signature.to_address(), (ledger_committee::MIN_VALIDATOR_STAKE, true));
+            }
+        }
+        // Add a member without certificates in the subdag.
+        let absent = Address::<CurrentNetwork>::new(rng.gen());
+        members.insert(absent, (ledger_committee::MIN_VALIDATOR_STAKE, true));
+        let committee = Committee::new(subdag.anchor_round() - 2, members)?;
+
+        // Compute the statistics.
+        let statistics = subdag.statistics(&committee)?;
+        assert_eq!(statistics.anchor_round(), subdag.anchor_round());
+        assert_eq!(statistics.leaders().len(), subdag.keys().filter(|round| *round % 2 == 0).count());
+        assert_eq!(statistics.leaders()[&subdag.anchor_round()], committee.get_leader(subdag.anchor_round())?);
+        assert_eq!(statistics.timestamps().len(), subdag.len());
+        assert_eq!(statistics.timestamps()[&(subdag.anchor_round() - 1)], subdag.timestamp(&committee));
+
+        // Check the certificate counts.
+        let num_certificates =
+            statistics.validators().values().map(ValidatorStatistics::num_certificates).sum::<usize>();
+        assert_eq!(num_certificates, subdag.values().flatten().count());
+        let num_transmissions =
+            statistics.validators().values().map(ValidatorStatistics::num_transmissions).sum::<usize>();
+        assert_eq!(num_transmissions, subdag.transmission_ids().count());
+        let leader = statistics.get_validator(&subdag.leader_address()).unwrap();
+        assert!(leader.num_certificates() >= 1);
+        assert_eq!(statistics.get_validator(&absent), Some(&ValidatorStatistics::default()));
+        Ok(())
+    }
+}
@@ -88,6 +88,25 @@ impl<N: Network, B: BlockStorage<N>> QueryTrait<N> for Query<N, B> {
         }
     }
 
+    /// Returns the current block height.
+    fn current_block_height(&self) -> Result<u32> {
+        match self {
+            Self::VM(block_store) => Ok(block_store.current_block_height()),
+            Self::REST(url) => Ok(Self::get_request(&format!("{url}/{}/latest/height", N::SHORT_NAME))?.into_json()?),
+        }
+    }
+
+    /// Returns the current block height.
+    #[cfg(feature = "async")]
+    async fn current_block_height_async(&self) -> Result<u32> {
+        match self {
+            Self::VM(block_store) => Ok(block_store.current_block_height()),
+            Self::REST(url) => {
+                Ok(Self::get_request_async(&format!("{url}/{}/latest/height", N::SHORT_NAME)).await?.json().await?)
+            }
+        }
+    }
+
     /// Returns a state path for the given `commitment`.
     fn get_state_path_for_commitment(&self, commitment: &Field<N>) -> Result<StatePath<N>> {
         match self {
@@ -12,7 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use console::{network::Network, prelude::Result, program::StatePath, types::Field};
+use console::{
+    network::Network,
+    prelude::{bail, Result},
+    program::StatePath,
+    types::Field,
+};
 
 #[cfg_attr(feature = "async", async_trait(?Send))]
 pub trait QueryTrait<N: Network> {
@@ -23,6 +28,17 @@ pub trait QueryTrait<N: Network> {
     #[cfg(feature = "async")]
     async fn current_state_root_async(&self) -> Result<N::StateRoot>;
 
+    /// Returns the current block height.
+    fn current_block_height(&self) -> Result<u32> {
+        bail!("This query does not support retrieving the current block height")
+    }
+
+    /// Returns the current block height.
+    #[cfg(feature = "async")]
+    async fn current_block_height_async(&self) -> Result<u32> {
+        self.current_block_height()
+    }
+
     /// Returns a state path for the given `commitment`.
     fn get_state_path_for_commitment(&self, commitment: &Field<N>) -> Result<StatePath<N>>;
 
@@ -95,6 +95,9 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         // Drop the write lock on the current block.
         drop(current_block);
 
+        // Notify the subscriptions of the updates in the block.
+        self.subscriptions.write().notify(block);
+
         // Update the cached committee from storage.
         if let Ok(current_committee) = self.vm.finalize_store().committee_store().current_committee() {
             *self.current_committee.write() = Some(current_committee);
@@ -306,10 +309,12 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         let state = FinalizeGlobalState::new::<N>(
             next_round,
             next_height,
+            next_timestamp,
             next_cumulative_weight,
             next_cumulative_proof_target,
             previous_block.hash(),
-        )?;
+        )?
+        .with_block_randomness(candidate_ratifications.iter().find_map(Ratify::randomness))?;
         // Speculate over the ratifications, solutions, and transactions.
         let (ratifications, transactions, aborted_transaction_ids, ratified_finalize_operations) = self.vm.speculate(
             state,
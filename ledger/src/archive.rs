@@ -0,0 +1,41 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Writes the blocks of the given era to the given writer, and returns the manifest of the era,
+    /// chained to the given `previous` manifest. The first era has no previous manifest.
+    ///
+    /// An era covers the block heights `[era * era_length, (era + 1) * era_length)`, where the canonical
+    /// era length is `BLOCKS_PER_ERA`. An era is only written once every block in it is in the ledger.
+    pub fn write_era<W: Write>(
+        &self,
+        era: u32,
+        era_length: u32,
+        previous: Option<&EraManifest<N>>,
+        writer: W,
+    ) -> Result<EraManifest<N>> {
+        let mut era_writer = EraWriter::new(writer, era, era_length)?;
+        let heights = era_writer.heights().clone();
+        // Ensure the era is complete.
+        ensure!(heights.end <= self.latest_height().saturating_add(1), "Era {era} is not complete in the ledger");
+
+        // Write the blocks of the era.
+        for height in heights {
+            era_writer.write_block(&self.get_block(height)?)?;
+        }
+        era_writer.finish(previous)
+    }
+}
@@ -73,10 +73,12 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         let state = FinalizeGlobalState::new::<N>(
             block.round(),
             block.height(),
+            block.timestamp(),
             block.cumulative_weight(),
             block.cumulative_proof_target(),
             block.previous_hash(),
-        )?;
+        )?
+        .with_block_randomness(block.ratifications().iter().find_map(Ratify::randomness))?;
 
         // Ensure speculation over the unconfirmed transactions is correct.
         let ratified_finalize_operations =
@@ -0,0 +1,302 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use console::program::Value;
+
+/// The visibility of an input of a generated transaction.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GeneratedInput {
+    /// A constant `field` input.
+    Constant,
+    /// A public `field` input.
+    Public,
+    /// A private `field` input.
+    Private,
+}
+
+impl GeneratedInput {
+    /// Returns the visibility of the input, as declared in the generated programs.
+    const fn visibility(&self) -> &'static str {
+        match self {
+            Self::Constant => "constant",
+            Self::Public => "public",
+            Self::Private => "private",
+        }
+    }
+
+    /// Returns the character encoding the input in the names of the generated programs.
+    const fn symbol(&self) -> char {
+        match self {
+            Self::Constant => 'c',
+            Self::Public => 'p',
+            Self::Private => 's',
+        }
+    }
+}
+
+/// The fee of a generated transaction.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GeneratedFee {
+    /// A public fee, paid from the public balance of the generator account.
+    Public,
+    /// A private fee, paid from an unspent `credits.aleo` record of the generator account.
+    Private,
+}
+
+/// The shape of a generated transaction.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TransactionShape {
+    /// The number of transitions in the execution, excluding the fee transition.
+    num_transitions: usize,
+    /// The inputs of each transition.
+    inputs: Vec<GeneratedInput>,
+    /// The fee of the transaction.
+    fee: GeneratedFee,
+    /// The number of mapping writes in the finalize scope of each transition.
+    finalize_writes: u16,
+}
+
+impl TransactionShape {
+    /// Initializes a new transaction shape.
+    pub fn new<N: Network>(
+        num_transitions: usize,
+        inputs: Vec<GeneratedInput>,
+        fee: GeneratedFee,
+        finalize_writes: u16,
+    ) -> Result<Self> {
+        // Ensure the number of transitions is within bounds.
+        // Note: Observe we hold back 1 for the fee.
+        ensure!(
+            (1..Transaction::<N>::MAX_TRANSITIONS).contains(&num_transitions),
+            "The number of transitions must be between 1 and {}, found {num_transitions}",
+            Transaction::<N>::MAX_TRANSITIONS - 1
+        );
+        // Ensure the number of inputs is within bounds.
+        ensure!(
+            inputs.len() <= N::MAX_INPUTS,
+            "The number of inputs must be at most {}, found {}",
+            N::MAX_INPUTS,
+            inputs.len()
+        );
+        // Ensure the number of finalize writes is within bounds.
+        ensure!(
+            finalize_writes <= N::MAX_WRITES,
+            "The number of finalize writes must be at most {}, found {finalize_writes}",
+            N::MAX_WRITES
+        );
+        Ok(Self { num_transitions, inputs, fee, finalize_writes })
+    }
+
+    /// Returns the number of transitions in the execution, excluding the fee transition.
+    pub const fn num_transitions(&self) -> usize {
+        self.num_transitions
+    }
+
+    /// Returns the inputs of each transition.
+    pub fn inputs(&self) -> &[GeneratedInput] {
+        &self.inputs
+    }
+
+    /// Returns the fee of the transaction.
+    pub const fn fee(&self) -> GeneratedFee {
+        self.fee
+    }
+
+    /// Returns the number of mapping writes in the finalize scope of each transition.
+    pub const fn finalize_writes(&self) -> u16 {
+        self.finalize_writes
+    }
+
+    /// Returns the ID of the generated program for the given layer.
+    fn program_id<N: Network>(&self, layer: usize) -> Result<ProgramID<N>> {
+        let signature = self.inputs.iter().map(GeneratedInput::symbol).collect::<String>();
+        ProgramID::from_str(&format!("gen_{layer}_{}_{signature}.aleo", self.finalize_writes))
+    }
+}
+
+impl Default for TransactionShape {
+    /// Returns the shape of a transaction with one transition, one public input, and a public fee.
+    fn default() -> Self {
+        Self { num_transitions: 1, inputs: vec![GeneratedInput::Public], fee: GeneratedFee::Public, finalize_writes: 0 }
+    }
+}
+
+/// A generator of valid, provable transactions of a given shape, for load testing.
+///
+/// Each shape is executed by a chain of generated programs, where the program of each layer calls
+/// the program of the layer below, so that an execution of the top layer contains one transition
+/// per layer. The programs must be deployed to the ledger with `TransactionGenerator::deploy`,
+/// before transactions of the shape are generated with `TransactionGenerator::generate`.
+pub struct TransactionGenerator<N: Network> {
+    /// The private key of the generator account.
+    private_key: PrivateKey<N>,
+    /// The view key of the generator account.
+    view_key: ViewKey<N>,
+    /// The commitments of the fee records spent by the generated transactions.
+    spent_fee_records: Vec<Field<N>>,
+}
+
+impl<N: Network> TransactionGenerator<N> {
+    /// The name of the function of the generated programs.
+    const FUNCTION_NAME: &'static str = "run";
+
+    /// Initializes a new transaction generator for the given account.
+    pub fn new(private_key: PrivateKey<N>) -> Result<Self> {
+        let view_key = ViewKey::try_from(&private_key)?;
+        Ok(Self { private_key, view_key, spent_fee_records: Default::default() })
+    }
+
+    /// Returns the generated programs of the given shape, in deployment order.
+    pub fn programs(shape: &TransactionShape) -> Result<Vec<Program<N>>> {
+        (0..shape.num_transitions).map(|layer| Self::program(shape, layer)).collect()
+    }
+
+    /// Returns the generated program of the given shape, for the given layer.
+    fn program(shape: &TransactionShape, layer: usize) -> Result<Program<N>> {
+        let program_id = shape.program_id::<N>(layer)?;
+        let function = Self::FUNCTION_NAME;
+        let num_inputs = shape.inputs.len();
+        let has_finalize = shape.finalize_writes > 0;
+
+        let mut program = String::new();
+        // Add the imports of the layers below.
+        for lower_layer in 0..layer {
+            program.push_str(&format!("import {};\n", shape.program_id::<N>(lower_layer)?));
+        }
+        program.push_str(&format!("program {program_id};\n"));
+        // Add the mapping.
+        if has_finalize {
+            program.push_str("mapping writes:\n    key as u8.public;\n    value as u32.public;\n");
+        }
+
+        // Add the function.
+        program.push_str(&format!("function {function}:\n"));
+        for (index, input) in shape.inputs.iter().enumerate() {
+            program.push_str(&format!("    input r{index} as field.{};\n", input.visibility()));
+        }
+        let operands = (0..num_inputs).map(|index| format!(" r{index}")).collect::<String>();
+        // Call the layer below, with the same inputs.
+        if layer > 0 {
+            let callee = shape.program_id::<N>(layer - 1)?;
+            match has_finalize {
+                true => program.push_str(&format!("    call {callee}/{function}{operands} into r{num_inputs};\n")),
+                false => program.push_str(&format!("    call {callee}/{function}{operands};\n")),
+            }
+        }
+        if has_finalize {
+            // Pass the future of the layer below to the finalize scope.
+            let (future, output) = match layer > 0 {
+                true => (format!(" r{num_inputs}"), num_inputs + 1),
+                false => (String::new(), num_inputs),
+            };
+            program.push_str(&format!("    async {function}{future} into r{output};\n"));
+            program.push_str(&format!("    output r{output} as {program_id}/{function}.future;\n"));
+
+            // Add the finalize scope.
+            program.push_str(&format!("finalize {function}:\n"));
+            if layer > 0 {
+                let callee = shape.program_id::<N>(layer - 1)?;
+                program.push_str(&format!("    input r0 as {callee}/{function}.future;\n    await r0;\n"));
+            }
+            for key in 0..shape.finalize_writes {
+                program.push_str(&format!("    set block.height into writes[{key}u8];\n"));
+            }
+        }
+        Program::from_str(&program)
+    }
+
+    /// Deploys the generated programs of the given shape that are not yet in the ledger,
+    /// advancing the ledger by one block per deployment.
+    ///
+    /// The generator account must be able to produce the next blocks of the ledger,
+    /// and must have a public balance to pay for the deployments.
+    pub fn deploy<C: ConsensusStorage<N>, R: Rng + CryptoRng>(
+        &self,
+        ledger: &Ledger<N, C>,
+        shape: &TransactionShape,
+        rng: &mut R,
+    ) -> Result<()> {
+        for program in Self::programs(shape)? {
+            // Skip the programs that are already deployed.
+            if ledger.vm().contains_program(program.id()) {
+                continue;
+            }
+            // Deploy the program, with a public fee.
+            let transaction = ledger.vm().deploy(&self.private_key, &program, None, 0, None, rng)?;
+            // Add the deployment to the next block.
+            let block = ledger.prepare_advance_to_next_beacon_block(
+                &self.private_key,
+                vec![],
+                vec![],
+                vec![transaction],
+                rng,
+            )?;
+            ledger.advance_to_next_block(&block)?;
+            // Ensure the deployment was accepted.
+            ensure!(ledger.vm().contains_program(program.id()), "Failed to deploy '{}'", program.id());
+        }
+        Ok(())
+    }
+
+    /// Returns a new transaction of the given shape, with random inputs.
+    ///
+    /// The generated programs of the shape must be deployed with `TransactionGenerator::deploy`.
+    /// The fee records spent by the generated transactions are not reused by later transactions.
+    pub fn generate<C: ConsensusStorage<N>, R: Rng + CryptoRng>(
+        &mut self,
+        ledger: &Ledger<N, C>,
+        shape: &TransactionShape,
+        rng: &mut R,
+    ) -> Result<Transaction<N>> {
+        // Ensure the top layer is deployed.
+        let program_id = shape.program_id::<N>(shape.num_transitions - 1)?;
+        ensure!(ledger.vm().contains_program(&program_id), "'{program_id}' is not deployed, call `deploy` first");
+
+        // Sample the inputs.
+        let inputs =
+            shape.inputs.iter().map(|_| Value::from(Literal::Field(Uniform::rand(rng)))).collect::<Vec<Value<N>>>();
+
+        // Select the fee record, which is the unspent record with the most microcredits.
+        let (fee_commitment, fee_record) = match shape.fee {
+            GeneratedFee::Public => (None, None),
+            GeneratedFee::Private => {
+                let Some((commitment, record)) = ledger
+                    .find_unspent_credits_records(&self.view_key)?
+                    .into_iter()
+                    .filter(|(commitment, _)| !self.spent_fee_records.contains(commitment))
+                    .max_by_key(|(_, record)| record.microcredits().unwrap_or_default())
+                else {
+                    bail!("The Aleo account has no records to spend for the fee.")
+                };
+                (Some(commitment), Some(record))
+            }
+        };
+
+        // Execute the top layer.
+        let transaction = ledger.vm().execute(
+            &self.private_key,
+            (program_id, Self::FUNCTION_NAME),
+            inputs.into_iter(),
+            fee_record,
+            0,
+            None,
+            rng,
+        )?;
+        // Mark the fee record as spent.
+        self.spent_fee_records.extend(fee_commitment);
+        Ok(transaction)
+    }
+}
@@ -25,11 +25,48 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         self.vm.finalize_store().committee_store().get_committee_for_round(round)
     }
 
+    /// Returns the changes to the committee made by the block at the given `block height`,
+    /// relative to the committee of the previous block. Returns `None` for the genesis block.
+    pub fn get_committee_diff(&self, block_height: u32) -> Result<Option<CommitteeDiff<N>>> {
+        // The genesis block has no previous committee.
+        let Some(previous_height) = block_height.checked_sub(1) else {
+            return Ok(None);
+        };
+        // Retrieve the committee for the given block height.
+        let Some(committee) = self.get_committee(block_height)? else {
+            bail!("Committee for block {block_height} does not exist in storage")
+        };
+        // Retrieve the committee for the previous block height.
+        let Some(previous_committee) = self.get_committee(previous_height)? else {
+            bail!("Committee for block {previous_height} does not exist in storage")
+        };
+        // Return the committee diff.
+        Ok(Some(committee.diff(&previous_committee)))
+    }
+
     /// Returns the state root that contains the given `block height`.
     pub fn get_state_root(&self, block_height: u32) -> Result<Option<N::StateRoot>> {
         self.vm.block_store().get_state_root(block_height)
     }
 
+    /// Returns the state root at the given `block height`, along with a state root path that proves
+    /// the state root is committed to by the current state root.
+    ///
+    /// If the given `block height` is the latest block height, the state root is the current state root,
+    /// and no state root path is returned.
+    pub fn state_root_at(&self, block_height: u32) -> Result<(N::StateRoot, Option<StateRootPath<N>>)> {
+        // Retrieve the state root.
+        let Some(state_root) = self.get_state_root(block_height)? else {
+            bail!("The state root for block {block_height} does not exist in storage")
+        };
+        // If the block height is the latest block height, return the state root without a path.
+        if block_height == self.latest_height() {
+            return Ok((state_root, None));
+        }
+        // Retrieve the state root path.
+        Ok((state_root, Some(self.vm.block_store().get_state_root_path(block_height)?)))
+    }
+
     /// Returns a state path for the given commitment.
     pub fn get_state_path_for_commitment(&self, commitment: &Field<N>) -> Result<StatePath<N>> {
         self.vm.block_store().get_state_path_for_commitment(commitment)
@@ -199,6 +236,58 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         }
     }
 
+    /// Returns the program for the given program ID, if it matches the owner, height, and import criteria of the `filter`.
+    pub(crate) fn get_program_if_matches(
+        &self,
+        program_id: &ProgramID<N>,
+        filter: &ProgramFilter<N>,
+    ) -> Result<Option<Program<N>>> {
+        let deployment_store = self.vm.transaction_store().deployment_store();
+        // Ensure the program is owned by the given address.
+        if let Some(owner) = filter.owner() {
+            if deployment_store.get_owner(program_id)?.map(|owner| owner.address()).as_ref() != Some(owner) {
+                return Ok(None);
+            }
+        }
+        // Ensure the program was deployed in the given range of heights.
+        if filter.heights().is_some() {
+            // Retrieve the deployment transaction ID.
+            let Some(transaction_id) = deployment_store.find_transaction_id_from_program_id(program_id)? else {
+                return Ok(None);
+            };
+            // Retrieve the deployment height.
+            let height = match self.vm.block_store().find_block_hash(&transaction_id)? {
+                Some(block_hash) => self.get_height(&block_hash)?,
+                None => bail!("Missing block for deployment transaction '{transaction_id}'"),
+            };
+            if !filter.matches_height(height) {
+                return Ok(None);
+            }
+        }
+        // Retrieve the program.
+        let Some(program) = deployment_store.get_program(program_id)? else {
+            return Ok(None);
+        };
+        // Ensure the program imports the given program ID.
+        if let Some(import) = filter.import() {
+            if !program.contains_import(import) {
+                return Ok(None);
+            }
+        }
+        Ok(Some(program))
+    }
+
+    /// Returns the program IDs that import the given program ID, as of their latest edition.
+    pub fn dependents_of(&self, program_id: ProgramID<N>) -> Result<Vec<ProgramID<N>>> {
+        self.vm.transaction_store().deployment_store().get_dependents(&program_id)
+    }
+
+    /// Returns the program IDs that import the given program ID directly or through other programs,
+    /// as of their latest edition.
+    pub fn transitive_dependents_of(&self, program_id: ProgramID<N>) -> Result<Vec<ProgramID<N>>> {
+        self.vm.transaction_store().deployment_store().get_transitive_dependents(&program_id)
+    }
+
     /// Returns the block solutions for the given block height.
     pub fn get_solutions(&self, height: u32) -> Result<Solutions<N>> {
         // If the height is 0, return the genesis block solutions.
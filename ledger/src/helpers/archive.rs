@@ -0,0 +1,418 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::block::Block;
+use console::{
+    account::{Address, PrivateKey, Signature},
+    network::prelude::*,
+    types::Field,
+};
+
+use core::ops::Range;
+use sha2::{Digest, Sha256};
+
+/// The default number of blocks in each era of an archive.
+pub const BLOCKS_PER_ERA: u32 = 8192;
+
+/// The magic bytes at the start of an era file.
+const ERA_MAGIC: [u8; 4] = *b"AERA";
+/// The version of the era file and manifest format.
+const ERA_VERSION: u8 = 1;
+
+/// The manifest of an era, i.e. a file with the blocks in the height range `[era * era_length, (era + 1) * era_length)`.
+///
+/// The manifests of an archive form an integrity chain: the cumulative hash of each manifest commits to
+/// the cumulative hash of the previous era, so verifying the latest manifest against a trusted (e.g. signed)
+/// cumulative hash covers every era before it.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct EraManifest<N: Network> {
+    /// The era number.
+    era: u32,
+    /// The number of blocks in each era.
+    era_length: u32,
+    /// The previous block hash of the first block in the era.
+    previous_block_hash: N::BlockHash,
+    /// The block hash of the last block in the era.
+    last_block_hash: N::BlockHash,
+    /// The SHA-256 checksum of the era file.
+    checksum: [u8; 32],
+    /// The cumulative hash of the era, as `SHA-256( previous cumulative hash || manifest )`.
+    cumulative_hash: [u8; 32],
+}
+
+impl<N: Network> EraManifest<N> {
+    /// Initializes a new manifest, chained to the given `previous` manifest.
+    pub fn new(
+        era: u32,
+        era_length: u32,
+        previous_block_hash: N::BlockHash,
+        last_block_hash: N::BlockHash,
+        checksum: [u8; 32],
+        previous: Option<&Self>,
+    ) -> Result<Self> {
+        let mut manifest =
+            Self { era, era_length, previous_block_hash, last_block_hash, checksum, cumulative_hash: [0u8; 32] };
+        manifest.check_previous(previous)?;
+        manifest.cumulative_hash = manifest.to_cumulative_hash(previous)?;
+        Ok(manifest)
+    }
+
+    /// Returns the era number.
+    pub const fn era(&self) -> u32 {
+        self.era
+    }
+
+    /// Returns the number of blocks in each era.
+    pub const fn era_length(&self) -> u32 {
+        self.era_length
+    }
+
+    /// Returns the range of block heights in the era.
+    pub fn heights(&self) -> Result<Range<u32>> {
+        let start =
+            self.era.checked_mul(self.era_length).ok_or_else(|| anyhow!("Era {} is out of bounds", self.era))?;
+        let end = start.checked_add(self.era_length).ok_or_else(|| anyhow!("Era {} is out of bounds", self.era))?;
+        Ok(start..end)
+    }
+
+    /// Returns the previous block hash of the first block in the era.
+    pub const fn previous_block_hash(&self) -> N::BlockHash {
+        self.previous_block_hash
+    }
+
+    /// Returns the block hash of the last block in the era.
+    pub const fn last_block_hash(&self) -> N::BlockHash {
+        self.last_block_hash
+    }
+
+    /// Returns the SHA-256 checksum of the era file.
+    pub const fn checksum(&self) -> [u8; 32] {
+        self.checksum
+    }
+
+    /// Returns the cumulative hash of the era.
+    pub const fn cumulative_hash(&self) -> [u8; 32] {
+        self.cumulative_hash
+    }
+
+    /// Ensures the manifest follows the given `previous` manifest, and that its cumulative hash is correct.
+    /// The first era has no previous manifest.
+    pub fn verify_chain(&self, previous: Option<&Self>) -> Result<()> {
+        self.check_previous(previous)?;
+        ensure!(
+            self.cumulative_hash == self.to_cumulative_hash(previous)?,
+            "Invalid cumulative hash for era {}",
+            self.era
+        );
+        Ok(())
+    }
+
+    /// Returns a detached signature of the manifest with the given private key.
+    pub fn sign<R: Rng + CryptoRng>(&self, private_key: &PrivateKey<N>, rng: &mut R) -> Result<Signature<N>> {
+        Signature::sign(private_key, &self.to_message()?, rng)
+    }
+
+    /// Returns `true` if the given detached signature of the manifest is valid for the given address.
+    pub fn verify_signature(&self, address: &Address<N>, signature: &Signature<N>) -> bool {
+        match self.to_message() {
+            Ok(message) => signature.verify(address, &message),
+            Err(_) => false,
+        }
+    }
+
+    /// Ensures the era of the manifest is a valid successor of the given `previous` manifest.
+    fn check_previous(&self, previous: Option<&Self>) -> Result<()> {
+        ensure!(self.era_length > 0, "The era length must be greater than zero");
+        // Ensure the range of block heights is valid.
+        self.heights()?;
+        match previous {
+            None => ensure!(self.era == 0, "Era {} requires the manifest of the previous era", self.era),
+            Some(previous) => {
+                ensure!(
+                    previous.era.checked_add(1) == Some(self.era),
+                    "Era {} does not follow era {}",
+                    self.era,
+                    previous.era
+                );
+                ensure!(previous.era_length == self.era_length, "Mismatching era length in era {}", self.era);
+                ensure!(
+                    previous.last_block_hash == self.previous_block_hash,
+                    "Era {} does not extend the last block of era {}",
+                    self.era,
+                    previous.era
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the cumulative hash `SHA-256( previous cumulative hash || manifest )`.
+    fn to_cumulative_hash(&self, previous: Option<&Self>) -> Result<[u8; 32]> {
+        let mut hasher = Sha256::new();
+        hasher.update(previous.map_or([0u8; 32], |previous| previous.cumulative_hash));
+        hasher.update(self.to_preimage()?);
+        Ok(hasher.finalize().into())
+    }
+
+    /// Returns the bytes of the manifest, without the cumulative hash.
+    fn to_preimage(&self) -> Result<Vec<u8>> {
+        let mut preimage = Vec::new();
+        ERA_VERSION.write_le(&mut preimage)?;
+        N::ID.write_le(&mut preimage)?;
+        self.era.write_le(&mut preimage)?;
+        self.era_length.write_le(&mut preimage)?;
+        self.previous_block_hash.write_le(&mut preimage)?;
+        self.last_block_hash.write_le(&mut preimage)?;
+        self.checksum.write_le(&mut preimage)?;
+        Ok(preimage)
+    }
+
+    /// Returns the signed message for the manifest, as the two 128-bit halves of the SHA-256 hash of the manifest.
+    fn to_message(&self) -> Result<Vec<Field<N>>> {
+        let digest: [u8; 32] = Sha256::digest(self.to_bytes_le()?).into();
+        Ok(digest.chunks(16).map(|chunk| Field::new(N::Field::from_bytes_le_mod_order(chunk))).collect())
+    }
+}
+
+impl<N: Network> FromBytes for EraManifest<N> {
+    /// Reads the manifest from the buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != ERA_VERSION {
+            return Err(error("Invalid era manifest version"));
+        }
+        // Read the network ID.
+        let network = u16::read_le(&mut reader)?;
+        // Ensure the network ID is valid.
+        if network != N::ID {
+            return Err(error("Invalid network ID in the era manifest"));
+        }
+        // Read the manifest.
+        let era = u32::read_le(&mut reader)?;
+        let era_length = u32::read_le(&mut reader)?;
+        let previous_block_hash = FromBytes::read_le(&mut reader)?;
+        let last_block_hash = FromBytes::read_le(&mut reader)?;
+        let checksum = FromBytes::read_le(&mut reader)?;
+        let cumulative_hash = FromBytes::read_le(&mut reader)?;
+        Ok(Self { era, era_length, previous_block_hash, last_block_hash, checksum, cumulative_hash })
+    }
+}
+
+impl<N: Network> ToBytes for EraManifest<N> {
+    /// Writes the manifest to the buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        ERA_VERSION.write_le(&mut writer)?;
+        N::ID.write_le(&mut writer)?;
+        self.era.write_le(&mut writer)?;
+        self.era_length.write_le(&mut writer)?;
+        self.previous_block_hash.write_le(&mut writer)?;
+        self.last_block_hash.write_le(&mut writer)?;
+        self.checksum.write_le(&mut writer)?;
+        self.cumulative_hash.write_le(&mut writer)
+    }
+}
+
+/// A writer of an era file, which computes the checksum of the file as the blocks are written.
+pub struct EraWriter<N: Network, W: Write> {
+    /// The underlying writer.
+    writer: HashingWriter<W>,
+    /// The era number.
+    era: u32,
+    /// The number of blocks in each era.
+    era_length: u32,
+    /// The range of block heights in the era.
+    heights: Range<u32>,
+    /// The height of the next block.
+    next_height: u32,
+    /// The previous block hash of the first block in the era.
+    previous_block_hash: Option<N::BlockHash>,
+    /// The block hash of the last written block.
+    last_block_hash: Option<N::BlockHash>,
+}
+
+impl<N: Network, W: Write> EraWriter<N, W> {
+    /// Initializes a new writer for the given era, and writes the header of the era file.
+    pub fn new(writer: W, era: u32, era_length: u32) -> Result<Self> {
+        ensure!(era_length > 0, "The era length must be greater than zero");
+        let start = era.checked_mul(era_length).ok_or_else(|| anyhow!("Era {era} is out of bounds"))?;
+        let end = start.checked_add(era_length).ok_or_else(|| anyhow!("Era {era} is out of bounds"))?;
+
+        let mut writer = HashingWriter { inner: writer, hasher: Sha256::new() };
+        // Write the header.
+        ERA_MAGIC.write_le(&mut writer)?;
+        ERA_VERSION.write_le(&mut writer)?;
+        N::ID.write_le(&mut writer)?;
+        era.write_le(&mut writer)?;
+        era_length.write_le(&mut writer)?;
+
+        Ok(Self {
+            writer,
+            era,
+            era_length,
+            heights: start..end,
+            next_height: start,
+            previous_block_hash: None,
+            last_block_hash: None,
+        })
+    }
+
+    /// Returns the range of block heights in the era.
+    pub const fn heights(&self) -> &Range<u32> {
+        &self.heights
+    }
+
+    /// Writes the given block, which must be the next block of the era.
+    pub fn write_block(&mut self, block: &Block<N>) -> Result<()> {
+        ensure!(self.heights.contains(&self.next_height), "Era {} is already complete", self.era);
+        ensure!(block.height() == self.next_height, "Expected block {} in era {}", self.next_height, self.era);
+        if let Some(last_block_hash) = self.last_block_hash {
+            ensure!(
+                block.previous_hash() == last_block_hash,
+                "Block {} does not extend the previous block",
+                block.height()
+            );
+        }
+        block.write_le(&mut self.writer)?;
+
+        self.previous_block_hash.get_or_insert(block.previous_hash());
+        self.last_block_hash = Some(block.hash());
+        self.next_height += 1;
+        Ok(())
+    }
+
+    /// Finishes the era file, and returns its manifest, chained to the given `previous` manifest.
+    pub fn finish(mut self, previous: Option<&EraManifest<N>>) -> Result<EraManifest<N>> {
+        ensure!(self.next_height == self.heights.end, "Era {} is incomplete", self.era);
+        self.writer.flush()?;
+
+        let (Some(previous_block_hash), Some(last_block_hash)) = (self.previous_block_hash, self.last_block_hash)
+        else {
+            bail!("Era {} is empty", self.era)
+        };
+        let checksum = self.writer.hasher.finalize().into();
+        EraManifest::new(self.era, self.era_length, previous_block_hash, last_block_hash, checksum, previous)
+    }
+}
+
+/// A reader of an era file, which verifies the blocks against the manifest as they are read.
+pub struct EraReader<'a, N: Network, R: Read> {
+    /// The underlying reader.
+    reader: HashingReader<R>,
+    /// The manifest of the era.
+    manifest: &'a EraManifest<N>,
+    /// The range of block heights in the era.
+    heights: Range<u32>,
+    /// The height of the next block.
+    next_height: u32,
+    /// The expected previous block hash of the next block.
+    previous_block_hash: N::BlockHash,
+}
+
+impl<'a, N: Network, R: Read> EraReader<'a, N, R> {
+    /// Initializes a new reader for the era of the given manifest, and reads the header of the era file.
+    pub fn new(reader: R, manifest: &'a EraManifest<N>) -> Result<Self> {
+        let heights = manifest.heights()?;
+        let mut reader = HashingReader { inner: reader, hasher: Sha256::new() };
+        // Read the header.
+        ensure!(<[u8; 4]>::read_le(&mut reader)? == ERA_MAGIC, "Invalid era file");
+        ensure!(u8::read_le(&mut reader)? == ERA_VERSION, "Invalid era file version");
+        ensure!(u16::read_le(&mut reader)? == N::ID, "Invalid network ID in the era file");
+        ensure!(u32::read_le(&mut reader)? == manifest.era, "Mismatching era in the era file");
+        ensure!(u32::read_le(&mut reader)? == manifest.era_length, "Mismatching era length in the era file");
+
+        Ok(Self {
+            reader,
+            manifest,
+            next_height: heights.start,
+            heights,
+            previous_block_hash: manifest.previous_block_hash,
+        })
+    }
+
+    /// Reads the next block of the era, or returns `None` if every block has been read.
+    pub fn read_block(&mut self) -> Result<Option<Block<N>>> {
+        if !self.heights.contains(&self.next_height) {
+            return Ok(None);
+        }
+        let block = Block::<N>::read_le(&mut self.reader)?;
+        ensure!(block.height() == self.next_height, "Expected block {} in era {}", self.next_height, self.manifest.era);
+        ensure!(
+            block.previous_hash() == self.previous_block_hash,
+            "Block {} does not extend the previous block",
+            block.height()
+        );
+
+        self.previous_block_hash = block.hash();
+        self.next_height += 1;
+        Ok(Some(block))
+    }
+
+    /// Finishes reading the era file, and ensures it matches the checksum and last block hash of the manifest.
+    pub fn finish(mut self) -> Result<()> {
+        ensure!(self.next_height == self.heights.end, "Era {} has not been fully read", self.manifest.era);
+        ensure!(
+            self.previous_block_hash == self.manifest.last_block_hash,
+            "Mismatching last block in era {}",
+            self.manifest.era
+        );
+        // Ensure there is no trailing data.
+        ensure!(self.reader.read(&mut [0u8; 1])? == 0, "Trailing data in the era file");
+        let checksum: [u8; 32] = self.reader.hasher.finalize().into();
+        ensure!(checksum == self.manifest.checksum, "Mismatching checksum for era {}", self.manifest.era);
+        Ok(())
+    }
+}
+
+impl<N: Network, R: Read> Iterator for EraReader<'_, N, R> {
+    type Item = Result<Block<N>>;
+
+    /// Returns the next block of the era.
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_block().transpose()
+    }
+}
+
+/// A writer that computes the SHA-256 hash of the written bytes.
+struct HashingWriter<W: Write> {
+    inner: W,
+    hasher: Sha256,
+}
+
+impl<W: Write> Write for HashingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> IoResult<usize> {
+        let num_bytes = self.inner.write(buf)?;
+        self.hasher.update(&buf[..num_bytes]);
+        Ok(num_bytes)
+    }
+
+    fn flush(&mut self) -> IoResult<()> {
+        self.inner.flush()
+    }
+}
+
+/// A reader that computes the SHA-256 hash of the read bytes.
+struct HashingReader<R: Read> {
+    inner: R,
+    hasher: Sha256,
+}
+
+impl<R: Read> Read for HashingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> IoResult<usize> {
+        let num_bytes = self.inner.read(buf)?;
+        self.hasher.update(&buf[..num_bytes]);
+        Ok(num_bytes)
+    }
+}
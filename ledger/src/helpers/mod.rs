@@ -12,8 +12,20 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod archive;
+pub use archive::*;
+
 mod bft;
 pub use bft::*;
 
+mod program_filter;
+pub use program_filter::*;
+
+mod scan;
+pub use scan::*;
+
+mod subscription;
+pub use subscription::*;
+
 mod supply;
 pub use supply::*;
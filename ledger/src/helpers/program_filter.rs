@@ -0,0 +1,105 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{account::Address, network::Network, program::ProgramID};
+
+use core::ops::Range;
+
+/// A filter over the deployed programs, as used by `Ledger::programs`.
+/// The criteria apply to the latest edition of each program, and a program must match every criterion that is set.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProgramFilter<N: Network> {
+    /// The address that owns the program.
+    owner: Option<Address<N>>,
+    /// The range of block heights in which the program was deployed.
+    heights: Option<Range<u32>>,
+    /// The program ID imported by the program.
+    import: Option<ProgramID<N>>,
+    /// The prefix of the program name.
+    name_prefix: Option<String>,
+}
+
+impl<N: Network> Default for ProgramFilter<N> {
+    /// Returns a filter that matches every deployed program.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: Network> ProgramFilter<N> {
+    /// Initializes a new filter that matches every deployed program.
+    pub const fn new() -> Self {
+        Self { owner: None, heights: None, import: None, name_prefix: None }
+    }
+
+    /// Returns the filter, restricted to the programs owned by the given `address`.
+    pub fn with_owner(mut self, address: Address<N>) -> Self {
+        self.owner = Some(address);
+        self
+    }
+
+    /// Returns the filter, restricted to the programs deployed in the given range of block `heights`.
+    pub fn with_heights(mut self, heights: Range<u32>) -> Self {
+        self.heights = Some(heights);
+        self
+    }
+
+    /// Returns the filter, restricted to the programs that import the given `program ID`.
+    pub fn with_import(mut self, program_id: ProgramID<N>) -> Self {
+        self.import = Some(program_id);
+        self
+    }
+
+    /// Returns the filter, restricted to the programs whose name starts with the given `prefix`.
+    pub fn with_name_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.name_prefix = Some(prefix.into());
+        self
+    }
+
+    /// Returns the owner address, if set.
+    pub const fn owner(&self) -> Option<&Address<N>> {
+        self.owner.as_ref()
+    }
+
+    /// Returns the range of deployment heights, if set.
+    pub const fn heights(&self) -> Option<&Range<u32>> {
+        self.heights.as_ref()
+    }
+
+    /// Returns the imported program ID, if set.
+    pub const fn import(&self) -> Option<&ProgramID<N>> {
+        self.import.as_ref()
+    }
+
+    /// Returns the name prefix, if set.
+    pub fn name_prefix(&self) -> Option<&str> {
+        self.name_prefix.as_deref()
+    }
+
+    /// Returns `true` if the given `program ID` has the name prefix of the filter.
+    pub fn matches_name(&self, program_id: &ProgramID<N>) -> bool {
+        match &self.name_prefix {
+            Some(prefix) => program_id.name().to_string().starts_with(prefix.as_str()),
+            None => true,
+        }
+    }
+
+    /// Returns `true` if the given deployment `height` is in the range of the filter.
+    pub fn matches_height(&self, height: u32) -> bool {
+        match &self.heights {
+            Some(heights) => heights.contains(&height),
+            None => true,
+        }
+    }
+}
@@ -0,0 +1,170 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::block::{Output, ScannedRecord, Transition};
+use console::{
+    account::Address,
+    network::Network,
+    program::{Argument, Identifier, Literal, Plaintext, ProgramID},
+};
+
+use core::ops::Range;
+
+/// The names of the `credits.aleo` functions that transfer public credits.
+const PUBLIC_TRANSFER_FUNCTIONS: [&str; 3] =
+    ["transfer_public", "transfer_private_to_public", "transfer_public_to_private"];
+
+/// The kind of a public interaction of an address with a transition.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ActivityKind {
+    /// A public transfer of credits, to or from the address.
+    Transfer,
+    /// A public fee, paid by the address.
+    Fee,
+    /// A finalize argument that contains the address.
+    Finalize,
+    /// A public input or output that contains the address.
+    Public,
+}
+
+/// A public interaction of an address, as found by a scan.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PublicActivity<N: Network> {
+    /// The height of the block that contains the transition.
+    pub height: u32,
+    /// The ID of the transaction that contains the transition.
+    pub transaction_id: N::TransactionID,
+    /// The ID of the transition.
+    pub transition_id: N::TransitionID,
+    /// The program ID of the transition.
+    pub program_id: ProgramID<N>,
+    /// The function name of the transition.
+    pub function_name: Identifier<N>,
+    /// The kind of interaction.
+    pub kind: ActivityKind,
+}
+
+impl<N: Network> PublicActivity<N> {
+    /// Returns the public interaction of the given `address` with the given transition, if any.
+    /// Note: Only the plaintext arguments of the transition's own future are checked,
+    /// as the futures of the functions it calls belong to their own transitions.
+    pub fn from_transition(
+        height: u32,
+        transaction_id: N::TransactionID,
+        transition: &Transition<N>,
+        address: &Address<N>,
+    ) -> Option<Self> {
+        // Determine if the address is in the finalize arguments, or in the public inputs or outputs.
+        let in_finalize = transition.outputs().iter().filter_map(Output::future).any(|future| {
+            future.arguments().iter().any(|argument| match argument {
+                Argument::Plaintext(plaintext) => contains_address(plaintext, address),
+                Argument::Future(_) => false,
+            })
+        });
+        let in_public = transition.public_inputs_decoded().any(|(_, plaintext)| contains_address(plaintext, address))
+            || transition.outputs().iter().any(|output| match output {
+                Output::Public(_, Some(plaintext)) => contains_address(plaintext, address),
+                _ => false,
+            });
+
+        // Determine the kind of interaction.
+        let is_credits = transition.program_id().to_string() == "credits.aleo";
+        let function_name = transition.function_name().to_string();
+        let kind = match (in_finalize, in_public) {
+            // The payer of a public fee is the finalize argument of the fee.
+            (true, _) if transition.is_fee_public() => ActivityKind::Fee,
+            (true, _) | (_, true) if is_credits && PUBLIC_TRANSFER_FUNCTIONS.contains(&function_name.as_str()) => {
+                ActivityKind::Transfer
+            }
+            (true, _) => ActivityKind::Finalize,
+            (false, true) => ActivityKind::Public,
+            (false, false) => return None,
+        };
+
+        Some(Self {
+            height,
+            transaction_id,
+            transition_id: *transition.id(),
+            program_id: *transition.program_id(),
+            function_name: *transition.function_name(),
+            kind,
+        })
+    }
+}
+
+/// The report of a scan of an account over a range of blocks, as produced by `Ledger::scan` and `Ledger::scan_address`.
+/// A scan with a view key reports the records owned by the account, while a watch-only scan with an address does not.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScanReport<N: Network> {
+    /// The scanned address.
+    address: Address<N>,
+    /// The scanned range of block heights.
+    heights: Range<u32>,
+    /// The records owned by the address.
+    records: Vec<ScannedRecord<N>>,
+    /// The public interactions of the address.
+    activity: Vec<PublicActivity<N>>,
+}
+
+impl<N: Network> ScanReport<N> {
+    /// Initializes a new, empty report for the given `address` and range of block `heights`.
+    pub const fn new(address: Address<N>, heights: Range<u32>) -> Self {
+        Self { address, heights, records: Vec::new(), activity: Vec::new() }
+    }
+
+    /// Returns the scanned address.
+    pub const fn address(&self) -> &Address<N> {
+        &self.address
+    }
+
+    /// Returns the scanned range of block heights.
+    pub const fn heights(&self) -> &Range<u32> {
+        &self.heights
+    }
+
+    /// Returns the records owned by the address, in the order they were created.
+    pub fn records(&self) -> &[ScannedRecord<N>] {
+        &self.records
+    }
+
+    /// Returns the public interactions of the address, in the order they occurred.
+    pub fn activity(&self) -> &[PublicActivity<N>] {
+        &self.activity
+    }
+
+    /// Returns the public interactions of the given `kind`.
+    pub fn activity_of(&self, kind: ActivityKind) -> impl '_ + Iterator<Item = &PublicActivity<N>> {
+        self.activity.iter().filter(move |activity| activity.kind == kind)
+    }
+
+    /// Adds the given record to the report.
+    pub(crate) fn add_record(&mut self, record: ScannedRecord<N>) {
+        self.records.push(record);
+    }
+
+    /// Adds the given public interaction to the report.
+    pub(crate) fn add_activity(&mut self, activity: PublicActivity<N>) {
+        self.activity.push(activity);
+    }
+}
+
+/// Returns `true` if the given plaintext contains the given address.
+fn contains_address<N: Network>(plaintext: &Plaintext<N>, address: &Address<N>) -> bool {
+    match plaintext {
+        Plaintext::Literal(Literal::Address(candidate), _) => candidate == address,
+        Plaintext::Literal(..) => false,
+        Plaintext::Struct(members, _) => members.values().any(|member| contains_address(member, address)),
+        Plaintext::Array(elements, _) => elements.iter().any(|element| contains_address(element, address)),
+    }
+}
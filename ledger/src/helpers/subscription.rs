@@ -0,0 +1,264 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    block::{Block, ConfirmedTransaction},
+    PublicActivity,
+};
+use console::{
+    account::Address,
+    network::Network,
+    program::{Identifier, Plaintext, ProgramID},
+    types::Field,
+};
+use synthesizer::program::FinalizeOperation;
+
+use indexmap::{IndexMap, IndexSet};
+use std::sync::mpsc::Sender;
+
+/// The ID of a subscription, as returned by `Ledger::subscribe`.
+pub type SubscriptionID = u64;
+
+/// A predicate over the keys of a mapping, as used by `SubscriptionFilter`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum KeyPredicate<N: Network> {
+    /// Matches every key.
+    Any,
+    /// Matches the given key.
+    Equals(Plaintext<N>),
+    /// Matches any of the given keys.
+    OneOf(Vec<Plaintext<N>>),
+}
+
+impl<N: Network> KeyPredicate<N> {
+    /// Returns the keys of the predicate, or `None` if the predicate matches every key.
+    pub fn keys(&self) -> Option<&[Plaintext<N>]> {
+        match self {
+            Self::Any => None,
+            Self::Equals(key) => Some(core::slice::from_ref(key)),
+            Self::OneOf(keys) => Some(keys),
+        }
+    }
+}
+
+/// A filter over the updates of each new block, as used by `Ledger::subscribe`.
+/// A subscription receives only the updates that match every criterion that is set:
+///  - A program, mapping, or key filter matches the finalize operations (and events) of the program.
+///  - An address filter matches the public activity of the address, optionally restricted to a program.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SubscriptionFilter<N: Network> {
+    /// The program ID.
+    program: Option<ProgramID<N>>,
+    /// The mapping name.
+    mapping: Option<Identifier<N>>,
+    /// The predicate over the mapping keys.
+    keys: KeyPredicate<N>,
+    /// The address involved in the transitions.
+    address: Option<Address<N>>,
+}
+
+impl<N: Network> Default for SubscriptionFilter<N> {
+    /// Returns a filter that matches every update.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: Network> SubscriptionFilter<N> {
+    /// Initializes a new filter that matches every update.
+    pub const fn new() -> Self {
+        Self { program: None, mapping: None, keys: KeyPredicate::Any, address: None }
+    }
+
+    /// Returns the filter, restricted to the given `program ID`.
+    pub fn with_program(mut self, program_id: ProgramID<N>) -> Self {
+        self.program = Some(program_id);
+        self
+    }
+
+    /// Returns the filter, restricted to the given `mapping` of the program.
+    pub fn with_mapping(mut self, mapping: Identifier<N>) -> Self {
+        self.mapping = Some(mapping);
+        self
+    }
+
+    /// Returns the filter, restricted to the mapping keys that match the given `predicate`.
+    pub fn with_keys(mut self, predicate: KeyPredicate<N>) -> Self {
+        self.keys = predicate;
+        self
+    }
+
+    /// Returns the filter, restricted to the transitions that involve the given `address`.
+    pub fn with_address(mut self, address: Address<N>) -> Self {
+        self.address = Some(address);
+        self
+    }
+
+    /// Returns the program ID, if set.
+    pub const fn program(&self) -> Option<&ProgramID<N>> {
+        self.program.as_ref()
+    }
+
+    /// Returns the mapping name, if set.
+    pub const fn mapping(&self) -> Option<&Identifier<N>> {
+        self.mapping.as_ref()
+    }
+
+    /// Returns the predicate over the mapping keys.
+    pub const fn keys(&self) -> &KeyPredicate<N> {
+        &self.keys
+    }
+
+    /// Returns the address, if set.
+    pub const fn address(&self) -> Option<&Address<N>> {
+        self.address.as_ref()
+    }
+}
+
+/// An update of a new block that matches a subscription.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SubscriptionUpdate<N: Network> {
+    /// A finalize operation of a confirmed transaction, such as a mapping update or an event.
+    Operation { height: u32, transaction_id: N::TransactionID, operation: FinalizeOperation<N> },
+    /// A public interaction of the subscribed address.
+    Activity(PublicActivity<N>),
+}
+
+/// A subscription filter, compiled into the mapping and key IDs of the finalize operations it matches.
+#[derive(Clone, Debug)]
+pub(crate) struct CompiledFilter<N: Network> {
+    /// The program ID and its field, as found in the `DeprecateProgram` and `Event` operations.
+    program: Option<(ProgramID<N>, Field<N>)>,
+    /// The IDs of the matching mappings, or `None` if every mapping matches.
+    mapping_ids: Option<IndexSet<Field<N>>>,
+    /// The IDs of the matching keys, or `None` if every key matches.
+    key_ids: Option<IndexSet<Field<N>>>,
+    /// Determines if the filter is restricted to a mapping.
+    is_mapping: bool,
+    /// The address involved in the transitions.
+    address: Option<Address<N>>,
+}
+
+impl<N: Network> CompiledFilter<N> {
+    /// Initializes a new compiled filter.
+    pub(crate) const fn new(
+        program: Option<(ProgramID<N>, Field<N>)>,
+        mapping_ids: Option<IndexSet<Field<N>>>,
+        key_ids: Option<IndexSet<Field<N>>>,
+        is_mapping: bool,
+        address: Option<Address<N>>,
+    ) -> Self {
+        Self { program, mapping_ids, key_ids, is_mapping, address }
+    }
+
+    /// Returns the updates of the given confirmed transaction that match the filter.
+    fn updates(&self, height: u32, confirmed: &ConfirmedTransaction<N>) -> Vec<SubscriptionUpdate<N>> {
+        let transaction = confirmed.transaction();
+        // If the filter has an address, match the public activity of the address.
+        if let Some(address) = &self.address {
+            return transaction
+                .transitions()
+                .filter(|transition| {
+                    self.program.map_or(true, |(program_id, _)| transition.program_id() == &program_id)
+                })
+                .filter_map(|transition| PublicActivity::from_transition(height, transaction.id(), transition, address))
+                .map(SubscriptionUpdate::Activity)
+                .collect();
+        }
+        // Otherwise, match the finalize operations.
+        confirmed
+            .finalize_operations()
+            .iter()
+            .filter(|operation| self.matches_operation(operation))
+            .map(|operation| SubscriptionUpdate::Operation {
+                height,
+                transaction_id: transaction.id(),
+                operation: operation.clone(),
+            })
+            .collect()
+    }
+
+    /// Returns `true` if the given finalize operation matches the filter.
+    fn matches_operation(&self, operation: &FinalizeOperation<N>) -> bool {
+        let matches_mapping = |mapping_id| self.mapping_ids.as_ref().map_or(true, |ids| ids.contains(mapping_id));
+        let matches_key = |key_id| self.key_ids.as_ref().map_or(true, |ids| ids.contains(key_id));
+        match operation {
+            FinalizeOperation::InsertKeyValue(mapping_id, key_id, _)
+            | FinalizeOperation::UpdateKeyValue(mapping_id, key_id, _)
+            | FinalizeOperation::RemoveKeyValue(mapping_id, key_id) => {
+                matches_mapping(mapping_id) && matches_key(key_id)
+            }
+            FinalizeOperation::InitializeMapping(mapping_id)
+            | FinalizeOperation::ReplaceMapping(mapping_id)
+            | FinalizeOperation::RemoveMapping(mapping_id) => self.key_ids.is_none() && matches_mapping(mapping_id),
+            FinalizeOperation::DeprecateProgram(program_id) | FinalizeOperation::Event(program_id, _) => {
+                !self.is_mapping && self.program.map_or(true, |(_, field)| &field == program_id)
+            }
+        }
+    }
+}
+
+/// The registry of subscriptions, notified of the matching updates of each new block.
+pub(crate) struct Subscriptions<N: Network> {
+    /// The ID of the next subscription.
+    next_id: SubscriptionID,
+    /// The map of `subscription ID` to `(filter, sender)`.
+    entries: IndexMap<SubscriptionID, (CompiledFilter<N>, Sender<SubscriptionUpdate<N>>)>,
+}
+
+impl<N: Network> Default for Subscriptions<N> {
+    /// Initializes an empty registry.
+    fn default() -> Self {
+        Self { next_id: 0, entries: IndexMap::new() }
+    }
+}
+
+impl<N: Network> Subscriptions<N> {
+    /// Returns the number of subscriptions.
+    pub(crate) fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Registers the given filter, and returns its subscription ID.
+    pub(crate) fn insert(
+        &mut self,
+        filter: CompiledFilter<N>,
+        sender: Sender<SubscriptionUpdate<N>>,
+    ) -> SubscriptionID {
+        let id = self.next_id;
+        self.next_id = self.next_id.saturating_add(1);
+        self.entries.insert(id, (filter, sender));
+        id
+    }
+
+    /// Deregisters the given subscription, and returns `true` if it was registered.
+    pub(crate) fn remove(&mut self, id: SubscriptionID) -> bool {
+        self.entries.shift_remove(&id).is_some()
+    }
+
+    /// Sends the matching updates of the given block to each subscription.
+    /// Note: A subscription is deregistered once an update can no longer be sent, as its receiver has been dropped.
+    pub(crate) fn notify(&mut self, block: &Block<N>) {
+        if self.entries.is_empty() {
+            return;
+        }
+        self.entries.retain(|_, (filter, sender)| {
+            block
+                .transactions()
+                .iter()
+                .flat_map(|confirmed| filter.updates(block.height(), confirmed))
+                .all(|update| sender.send(update).is_ok())
+        });
+    }
+}
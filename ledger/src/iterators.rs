@@ -25,6 +25,12 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         self.vm.block_store().solution_ids()
     }
 
+    /// Returns an iterator over the committee diffs, for the blocks in the given range of `heights`.
+    /// Note: The genesis block is skipped, as it has no previous committee.
+    pub fn committee_diffs(&self, heights: Range<u32>) -> impl '_ + Iterator<Item = Result<(u32, CommitteeDiff<N>)>> {
+        heights.filter_map(|height| self.get_committee_diff(height).transpose().map(|diff| Ok((height, diff?))))
+    }
+
     /* Transaction */
 
     /// Returns an iterator over the program IDs, for all transactions in `self`.
@@ -32,9 +38,28 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         self.vm.transaction_store().program_ids()
     }
 
-    /// Returns an iterator over the programs, for all transactions in `self`.
-    pub fn programs(&self) -> impl '_ + Iterator<Item = Cow<'_, Program<N>>> {
-        self.vm.transaction_store().programs()
+    /// Returns an iterator over the latest edition of the deployed programs that match the given `filter`.
+    /// Note: The owner and import criteria select the programs from their store indices,
+    /// so the remaining criteria are only checked for the selected programs.
+    pub fn programs<'a>(&'a self, filter: &'a ProgramFilter<N>) -> impl 'a + Iterator<Item = Result<Program<N>>> {
+        let deployment_store = self.vm.transaction_store().deployment_store();
+        // Select the candidate program IDs, using the owned programs or dependents index if possible.
+        let candidates = match (filter.owner(), filter.import()) {
+            (Some(owner), _) => deployment_store.get_owned_programs(owner),
+            (None, Some(import)) => deployment_store.get_dependents(import),
+            (None, None) => Ok(deployment_store.program_editions().map(|(program_id, _)| *program_id).collect()),
+        };
+        // Return the candidate programs that match the filter.
+        let (candidates, error) = match candidates {
+            Ok(candidates) => (candidates, None),
+            Err(error) => (Vec::new(), Some(Err(error))),
+        };
+        error.into_iter().chain(
+            candidates
+                .into_iter()
+                .filter(|program_id| filter.matches_name(program_id))
+                .filter_map(|program_id| self.get_program_if_matches(&program_id, filter).transpose()),
+        )
     }
 
     /// Returns an iterator over the transaction IDs, for all transactions in `self`.
@@ -35,28 +35,41 @@ mod helpers;
 pub use helpers::*;
 
 mod advance;
+mod archive;
 mod check_next_block;
 mod check_transaction_basic;
 mod contains;
 mod find;
+#[cfg(any(test, feature = "test-helpers"))]
+mod generator;
+#[cfg(any(test, feature = "test-helpers"))]
+pub use generator::*;
 mod get;
 mod iterators;
+mod scan;
+mod snapshot;
+pub use snapshot::*;
+mod stats;
+mod subscribe;
+
+#[cfg(feature = "rpc")]
+pub mod rpc;
 
 #[cfg(test)]
 mod tests;
 
 use console::{
-    account::{Address, GraphKey, PrivateKey, ViewKey},
+    account::{Address, ComputeKey, GraphKey, OneTimeAddress, PrivateKey, ViewKey},
     network::prelude::*,
-    program::{Ciphertext, Entry, Identifier, Literal, Plaintext, ProgramID, Record, StatePath, Value},
+    program::{Ciphertext, Entry, Identifier, Literal, Plaintext, ProgramID, Record, StatePath, StateRootPath},
     types::{Field, Group},
 };
 use ledger_authority::Authority;
 use ledger_coinbase::{CoinbasePuzzle, CoinbaseSolution, EpochChallenge, ProverSolution, PuzzleCommitment};
-use ledger_committee::Committee;
+use ledger_committee::{Committee, CommitteeDiff};
 use ledger_narwhal::{BatchCertificate, Subdag, Transmission, TransmissionID};
 use ledger_query::Query;
-use ledger_store::{ConsensusStorage, ConsensusStore};
+use ledger_store::{BlockStats, ConsensusStorage, ConsensusStore};
 use synthesizer::{
     program::{FinalizeGlobalState, Program},
     vm::VM,
@@ -107,6 +120,8 @@ pub struct Ledger<N: Network, C: ConsensusStorage<N>> {
     current_committee: Arc<RwLock<Option<Committee<N>>>>,
     /// The current block.
     current_block: Arc<RwLock<Block<N>>>,
+    /// The subscriptions to the updates of new blocks.
+    subscriptions: Arc<RwLock<Subscriptions<N>>>,
 }
 
 impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
@@ -169,6 +184,7 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             current_epoch_challenge: Default::default(),
             current_committee: Arc::new(RwLock::new(current_committee)),
             current_block: Arc::new(RwLock::new(genesis_block.clone())),
+            subscriptions: Default::default(),
         };
 
         // If the block store is empty, initialize the genesis block.
@@ -320,9 +336,42 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             .collect::<IndexMap<_, _>>())
     }
 
+    /// Returns the unspent `credits.aleo` record with the fewest microcredits that contains at least
+    /// the given amount of microcredits, skipping the records with the given commitments.
+    #[allow(clippy::type_complexity)]
+    pub fn select_credits_record(
+        &self,
+        view_key: &ViewKey<N>,
+        amount_in_microcredits: u64,
+        excluded_commitments: &[Field<N>],
+    ) -> Result<Option<(Field<N>, Record<N, Plaintext<N>>)>> {
+        let records = self.find_unspent_credits_records(view_key)?;
+        Ok(Self::select_credits_record_from(&records, amount_in_microcredits, excluded_commitments))
+    }
+
+    /// Returns the record with the fewest microcredits that contains at least the given amount of microcredits,
+    /// from the given records, skipping the records with the given commitments.
+    #[allow(clippy::type_complexity)]
+    fn select_credits_record_from(
+        records: &RecordMap<N>,
+        amount_in_microcredits: u64,
+        excluded_commitments: &[Field<N>],
+    ) -> Option<(Field<N>, Record<N, Plaintext<N>>)> {
+        records
+            .iter()
+            .filter(|(commitment, _)| !excluded_commitments.contains(commitment))
+            .filter_map(|(commitment, record)| match record.microcredits() {
+                Ok(balance) if balance >= amount_in_microcredits => Some((balance, commitment, record)),
+                _ => None,
+            })
+            .min_by_key(|(balance, _, _)| *balance)
+            .map(|(_, commitment, record)| (*commitment, record.clone()))
+    }
+
     /// Creates a deploy transaction.
     ///
     /// The `priority_fee_in_microcredits` is an additional fee **on top** of the deployment fee.
+    #[cfg(feature = "prover")]
     pub fn create_deploy<R: Rng + CryptoRng>(
         &self,
         private_key: &PrivateKey<N>,
@@ -346,6 +395,7 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
     /// Creates a transfer transaction.
     ///
     /// The `priority_fee_in_microcredits` is an additional fee **on top** of the execution fee.
+    #[cfg(feature = "prover")]
     pub fn create_transfer<R: Rng + CryptoRng>(
         &self,
         private_key: &PrivateKey<N>,
@@ -355,26 +405,29 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         query: Option<Query<N, C::BlockStorage>>,
         rng: &mut R,
     ) -> Result<Transaction<N>> {
-        // Fetch the unspent records.
-        let records = self.find_unspent_credits_records(&ViewKey::try_from(private_key)?)?;
-        ensure!(!records.len().is_zero(), "The Aleo account has no records to spend.");
-        let mut records = records.values();
+        // Compute the view key.
+        let view_key = ViewKey::try_from(private_key)?;
 
-        // Prepare the inputs.
-        let inputs = [
-            Value::Record(records.next().unwrap().clone()),
-            Value::from_str(&format!("{to}"))?,
-            Value::from_str(&format!("{amount_in_microcredits}u64"))?,
-        ];
-
-        // Prepare the fee.
-        let fee_record = Some(records.next().unwrap().clone());
+        // Fetch the unspent records.
+        let records = self.find_unspent_credits_records(&view_key)?;
+        // Select the record to transfer from.
+        let Some((commitment, record)) = Self::select_credits_record_from(&records, amount_in_microcredits, &[]) else {
+            bail!("The Aleo account has no record with at least {amount_in_microcredits} microcredits to spend.")
+        };
+        // Select the fee record, which is the remaining record with the most microcredits.
+        let fee_record = records
+            .into_iter()
+            .filter(|(fee_commitment, _)| *fee_commitment != commitment)
+            .max_by_key(|(_, fee_record)| fee_record.microcredits().unwrap_or_default())
+            .map(|(_, fee_record)| fee_record);
+        ensure!(fee_record.is_some(), "The Aleo account has no records to spend for the fee.");
 
         // Create a new execute transaction.
-        self.vm.execute(
+        self.vm.credits().transfer_private(
             private_key,
-            ("credits.aleo", "transfer_private"),
-            inputs.iter(),
+            record,
+            to,
+            amount_in_microcredits,
             fee_record,
             priority_fee_in_microcredits,
             query,
@@ -0,0 +1,313 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A JSON-RPC 2.0 service over a ledger, for lightweight tools and tests that serve chain data.
+//!
+//! The service is transport-agnostic: `RpcService::handle` maps a request string to a response string,
+//! and may be bound to any HTTP, WebSocket, or IPC server. The requests of a batch are served from one
+//! snapshot of the ledger, so they observe the same latest block.
+//!
+//! | Method               | Params                                  | Result                                 |
+//! |----------------------|-----------------------------------------|----------------------------------------|
+//! | `latest_height`      | `[]`                                    | The latest block height.               |
+//! | `latest_hash`        | `[]`                                    | The latest block hash.                 |
+//! | `get_block`          | `[height]` or `[block_hash]`            | The block.                             |
+//! | `get_transaction`    | `[transaction_id]`                      | The transaction.                       |
+//! | `get_mapping_value`  | `[program_id, mapping_name, key]`       | The mapping value, or `null`.          |
+//! | `simulate_broadcast` | `[transaction]`                         | `{ "valid": bool, "error": string? }`  |
+
+use super::*;
+
+use serde_json::{json, Value as Json};
+
+/// The JSON-RPC version.
+const JSONRPC_VERSION: &str = "2.0";
+
+/// The error code of an invalid JSON request.
+pub const PARSE_ERROR: i64 = -32700;
+/// The error code of a request that is not a valid JSON-RPC request.
+pub const INVALID_REQUEST: i64 = -32600;
+/// The error code of a request for an unknown method.
+pub const METHOD_NOT_FOUND: i64 = -32601;
+/// The error code of a request with invalid method parameters.
+pub const INVALID_PARAMS: i64 = -32602;
+/// The error code of a request that failed in the ledger, e.g. for a block that does not exist.
+pub const LEDGER_ERROR: i64 = -32000;
+
+/// A JSON-RPC 2.0 error.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RpcError {
+    /// The error code.
+    pub code: i64,
+    /// The error message.
+    pub message: String,
+}
+
+impl RpcError {
+    /// Initializes a new error with the given code and message.
+    pub fn new(code: i64, message: impl Display) -> Self {
+        Self { code, message: message.to_string() }
+    }
+}
+
+/// A JSON-RPC 2.0 service, with handlers bound to a ledger.
+#[derive(Clone)]
+pub struct RpcService<N: Network, C: ConsensusStorage<N>> {
+    /// The ledger.
+    ledger: Ledger<N, C>,
+}
+
+impl<N: Network, C: ConsensusStorage<N>> RpcService<N, C> {
+    /// Initializes a new service for the given ledger.
+    pub const fn new(ledger: Ledger<N, C>) -> Self {
+        Self { ledger }
+    }
+
+    /// Returns the ledger of the service.
+    pub const fn ledger(&self) -> &Ledger<N, C> {
+        &self.ledger
+    }
+
+    /// Handles the given JSON-RPC request, or batch of requests.
+    /// Returns the response, or `None` if the request consists only of notifications.
+    pub fn handle(&self, request: &str) -> Option<String> {
+        // Pin the ledger for the duration of the request, or batch of requests.
+        let snapshot = self.ledger.read_snapshot();
+        let response = match serde_json::from_str::<Json>(request) {
+            // Handle a batch of requests.
+            Ok(Json::Array(requests)) => match requests.is_empty() {
+                true => Some(error_response(Json::Null, RpcError::new(INVALID_REQUEST, "Empty batch"))),
+                false => {
+                    let responses = requests
+                        .into_iter()
+                        .filter_map(|request| self.handle_request(&snapshot, request))
+                        .collect_vec();
+                    (!responses.is_empty()).then_some(Json::Array(responses))
+                }
+            },
+            // Handle a single request.
+            Ok(request) => self.handle_request(&snapshot, request),
+            Err(error) => Some(error_response(Json::Null, RpcError::new(PARSE_ERROR, error))),
+        };
+        response.map(|response| response.to_string())
+    }
+
+    /// Handles the given JSON-RPC request.
+    /// Returns the response, or `None` if the request is a notification.
+    fn handle_request(&self, snapshot: &LedgerSnapshot<N, C>, request: Json) -> Option<Json> {
+        // Ensure the request is well-formed.
+        let Json::Object(request) = request else {
+            return Some(error_response(Json::Null, RpcError::new(INVALID_REQUEST, "Expected a request object")));
+        };
+        // Note: A request without an ID is a notification, which receives no response.
+        let id = request.get("id").cloned();
+        let (Some(JSONRPC_VERSION), Some(method)) =
+            (request.get("jsonrpc").and_then(Json::as_str), request.get("method").and_then(Json::as_str))
+        else {
+            return Some(error_response(id.unwrap_or(Json::Null), RpcError::new(INVALID_REQUEST, "Invalid request")));
+        };
+        let params = request.get("params").cloned().unwrap_or(Json::Array(vec![]));
+
+        // Dispatch the request.
+        let result = self.dispatch(snapshot, method, &params);
+        let id = id?;
+        Some(match result {
+            Ok(result) => json!({ "jsonrpc": JSONRPC_VERSION, "id": id, "result": result }),
+            Err(error) => error_response(id, error),
+        })
+    }
+
+    /// Returns the result of the given method and parameters, as read from the given snapshot.
+    fn dispatch(&self, snapshot: &LedgerSnapshot<N, C>, method: &str, params: &Json) -> Result<Json, RpcError> {
+        match method {
+            "latest_height" => Ok(json!(snapshot.latest_height())),
+            "latest_hash" => to_json(&snapshot.latest_hash()),
+            "get_block" => {
+                let block = match param(params, 0)? {
+                    Json::Number(height) => {
+                        let height = height.as_u64().and_then(|height| u32::try_from(height).ok());
+                        let height = height.ok_or_else(|| RpcError::new(INVALID_PARAMS, "Invalid block height"))?;
+                        snapshot.get_block(height)
+                    }
+                    hash => snapshot.get_block_by_hash(&parse_param(hash)?),
+                };
+                to_json(&block.map_err(ledger_error)?)
+            }
+            "get_transaction" => {
+                let transaction_id = parse_param(param(params, 0)?)?;
+                to_json(&snapshot.get_transaction(transaction_id).map_err(ledger_error)?)
+            }
+            "get_mapping_value" => {
+                let program_id = parse_param(param(params, 0)?)?;
+                let mapping_name = parse_param(param(params, 1)?)?;
+                let key: Plaintext<N> = parse_param(param(params, 2)?)?;
+                match snapshot.get_mapping_value(program_id, mapping_name, &key).map_err(ledger_error)? {
+                    Some(value) => to_json(&value),
+                    None => Ok(Json::Null),
+                }
+            }
+            "simulate_broadcast" => {
+                // Accept the transaction as a JSON object, or as a string.
+                let transaction: Transaction<N> = match param(params, 0)? {
+                    Json::String(transaction) => Transaction::from_str(transaction),
+                    transaction => serde_json::from_value(transaction.clone()).map_err(|error| anyhow!("{error}")),
+                }
+                .map_err(|error| RpcError::new(INVALID_PARAMS, format!("Invalid transaction: {error}")))?;
+                // Check the transaction, without adding it to the ledger.
+                match self.ledger.check_transaction_basic(&transaction, None, &mut OsRng) {
+                    Ok(()) => Ok(json!({ "valid": true })),
+                    Err(error) => Ok(json!({ "valid": false, "error": error.to_string() })),
+                }
+            }
+            _ => Err(RpcError::new(METHOD_NOT_FOUND, format!("Unknown method '{method}'"))),
+        }
+    }
+}
+
+/// Returns the error response for the given request ID.
+fn error_response(id: Json, error: RpcError) -> Json {
+    json!({ "jsonrpc": JSONRPC_VERSION, "id": id, "error": { "code": error.code, "message": error.message } })
+}
+
+/// Returns the positional parameter at the given index.
+fn param(params: &Json, index: usize) -> Result<&Json, RpcError> {
+    params.get(index).ok_or_else(|| RpcError::new(INVALID_PARAMS, format!("Missing parameter {index}")))
+}
+
+/// Returns the given string parameter, parsed as `T`.
+fn parse_param<T: FromStr>(param: &Json) -> Result<T, RpcError>
+where
+    T::Err: Display,
+{
+    let Some(param) = param.as_str() else { return Err(RpcError::new(INVALID_PARAMS, "Expected a string parameter")) };
+    T::from_str(param).map_err(|error| RpcError::new(INVALID_PARAMS, format!("Invalid parameter '{param}': {error}")))
+}
+
+/// Returns the given value as JSON.
+fn to_json<T: Serialize>(value: &T) -> Result<Json, RpcError> {
+    serde_json::to_value(value).map_err(|error| RpcError::new(LEDGER_ERROR, error))
+}
+
+/// Returns the RPC error for the given ledger error.
+fn ledger_error(error: Error) -> RpcError {
+    RpcError::new(LEDGER_ERROR, error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::CurrentNetwork;
+    use console::program::Value;
+
+    /// Returns the response to the given request.
+    fn call(service: &RpcService<CurrentNetwork, impl ConsensusStorage<CurrentNetwork>>, request: Json) -> Json {
+        serde_json::from_str(&service.handle(&request.to_string()).unwrap()).unwrap()
+    }
+
+    /// Returns the request for the given method and parameters.
+    fn request(method: &str, params: Json) -> Json {
+        json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params })
+    }
+
+    #[test]
+    fn test_rpc_queries() {
+        let rng = &mut TestRng::default();
+        let crate::test_helpers::TestEnv { ledger, private_key, address, .. } =
+            crate::test_helpers::sample_test_env(rng);
+        let service = RpcService::new(ledger.clone());
+
+        // Ensure the latest height and hash are served.
+        assert_eq!(call(&service, request("latest_height", json!([])))["result"], json!(0));
+        assert_eq!(
+            call(&service, request("latest_hash", json!([])))["result"],
+            json!(ledger.latest_hash().to_string())
+        );
+
+        // Ensure the genesis block is served by height and by hash.
+        let genesis = ledger.get_block(0).unwrap();
+        let expected = serde_json::to_value(&genesis).unwrap();
+        assert_eq!(call(&service, request("get_block", json!([0])))["result"], expected);
+        assert_eq!(call(&service, request("get_block", json!([genesis.hash().to_string()])))["result"], expected);
+        assert_eq!(call(&service, request("get_block", json!([1])))["error"]["code"], json!(LEDGER_ERROR));
+
+        // Ensure a transaction is served.
+        let transaction = genesis.transactions().iter().next().unwrap().transaction().clone();
+        let response = call(&service, request("get_transaction", json!([transaction.id().to_string()])));
+        assert_eq!(response["result"], serde_json::to_value(&transaction).unwrap());
+
+        // Ensure a mapping value is served.
+        let params = json!(["credits.aleo", "account", address.to_string()]);
+        let expected = ledger
+            .vm()
+            .finalize_store()
+            .get_value_confirmed(
+                ProgramID::from_str("credits.aleo").unwrap(),
+                Identifier::from_str("account").unwrap(),
+                &Plaintext::from_str(&address.to_string()).unwrap(),
+            )
+            .unwrap()
+            .unwrap();
+        assert_eq!(call(&service, request("get_mapping_value", params))["result"], json!(expected.to_string()));
+        let params = json!(["credits.aleo", "account", Address::<CurrentNetwork>::zero().to_string()]);
+        assert_eq!(call(&service, request("get_mapping_value", params))["result"], Json::Null);
+
+        // Ensure a broadcast is simulated, without adding the transaction to the ledger.
+        let inputs = [Value::from_str(&format!("{address}")).unwrap(), Value::from_str("1u64").unwrap()];
+        let transaction = ledger
+            .vm()
+            .execute(&private_key, ("credits.aleo", "transfer_public"), inputs.iter(), None, 0, None, rng)
+            .unwrap();
+        let response = call(&service, request("simulate_broadcast", json!([transaction.to_string()])));
+        assert_eq!(response["result"], json!({ "valid": true }));
+        let response = call(
+            &service,
+            request("simulate_broadcast", json!([genesis.transactions().iter().next().unwrap().transaction()])),
+        );
+        assert_eq!(response["result"]["valid"], json!(false));
+        assert_eq!(ledger.latest_height(), 0);
+    }
+
+    #[test]
+    fn test_rpc_errors() {
+        let rng = &mut TestRng::default();
+        let crate::test_helpers::TestEnv { ledger, .. } = crate::test_helpers::sample_test_env(rng);
+        let service = RpcService::new(ledger);
+
+        // Ensure invalid JSON is rejected.
+        let response: Json = serde_json::from_str(&service.handle("{").unwrap()).unwrap();
+        assert_eq!(response["error"]["code"], json!(PARSE_ERROR));
+        // Ensure invalid requests are rejected.
+        assert_eq!(
+            call(&service, json!({ "id": 1, "method": "latest_height" }))["error"]["code"],
+            json!(INVALID_REQUEST)
+        );
+        assert_eq!(call(&service, json!([]))["error"]["code"], json!(INVALID_REQUEST));
+        // Ensure unknown methods and invalid parameters are rejected.
+        assert_eq!(call(&service, request("get_balance", json!([])))["error"]["code"], json!(METHOD_NOT_FOUND));
+        assert_eq!(call(&service, request("get_block", json!([])))["error"]["code"], json!(INVALID_PARAMS));
+        assert_eq!(call(&service, request("get_block", json!(["hash"])))["error"]["code"], json!(INVALID_PARAMS));
+        assert_eq!(call(&service, request("get_transaction", json!([1])))["error"]["code"], json!(INVALID_PARAMS));
+
+        // Ensure notifications receive no response.
+        let notification = json!({ "jsonrpc": "2.0", "method": "latest_height" });
+        assert_eq!(service.handle(&notification.to_string()), None);
+
+        // Ensure a batch receives a response for each request.
+        let batch = json!([request("latest_height", json!([])), notification, request("get_balance", json!([]))]);
+        let response = call(&service, batch);
+        assert_eq!(response.as_array().unwrap().len(), 2);
+        assert_eq!(response[0]["result"], json!(0));
+        assert_eq!(response[1]["error"]["code"], json!(METHOD_NOT_FOUND));
+    }
+}
@@ -0,0 +1,129 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Returns the records and public activity of the given view key in the given block range.
+    /// The range is inclusive of the start and exclusive of the end.
+    pub fn scan(&self, view_key: &ViewKey<N>, heights: Range<u32>) -> Result<ScanReport<N>> {
+        self.scan_blocks(&view_key.to_address(), Some(view_key), heights)
+    }
+
+    /// Returns the public activity of the given address in the given block range, without any key material.
+    /// The range is inclusive of the start and exclusive of the end.
+    ///
+    /// This is a watch-only scan: the public transfers, public fees, and finalize arguments of the address
+    /// are reported as in `Ledger::scan`, while its records remain encrypted and are not reported.
+    pub fn scan_address(&self, address: &Address<N>, heights: Range<u32>) -> Result<ScanReport<N>> {
+        self.scan_blocks(address, None, heights)
+    }
+
+    /// Returns the reports of the given one-time addresses that belong to the given account, in the given block range.
+    /// The range is inclusive of the start and exclusive of the end.
+    ///
+    /// Each one-time address is detected with the view key and compute key of the account, and its records
+    /// are decrypted with its one-time view key. One-time addresses of other accounts are skipped.
+    pub fn scan_one_time(
+        &self,
+        view_key: &ViewKey<N>,
+        compute_key: &ComputeKey<N>,
+        one_time_addresses: &[OneTimeAddress<N>],
+        heights: Range<u32>,
+    ) -> Result<Vec<ScanReport<N>>> {
+        let mut reports = Vec::new();
+        for one_time_address in one_time_addresses {
+            // Derive the view key of the one-time address, if it belongs to the account.
+            if let Some(one_time_view_key) = one_time_address.to_view_key(view_key, compute_key)? {
+                reports.push(self.scan_blocks(
+                    one_time_address.address(),
+                    Some(&one_time_view_key),
+                    heights.clone(),
+                )?);
+            }
+        }
+        Ok(reports)
+    }
+
+    /// Returns the records owned by the given view key in the given block range, with their spent status.
+    /// The range is inclusive of the `start` and exclusive of the `end`.
+    ///
+    /// Unlike `Ledger::scan`, the public activity of the account is not reported.
+    pub fn scan_range(&self, view_key: &ViewKey<N>, start: u32, end: u32) -> Result<Vec<ScannedRecord<N>>> {
+        // Ensure the range is valid.
+        self.ensure_scan_range(&(start..end))?;
+
+        let mut records = Vec::new();
+        for height in start..end {
+            records.extend(self.scan_block(&self.get_block(height)?, view_key)?);
+        }
+        Ok(records)
+    }
+
+    /// Returns the report of the given address in the given block range,
+    /// including its records if the corresponding view key is given.
+    fn scan_blocks(
+        &self,
+        address: &Address<N>,
+        view_key: Option<&ViewKey<N>>,
+        heights: Range<u32>,
+    ) -> Result<ScanReport<N>> {
+        // Ensure the range is valid.
+        self.ensure_scan_range(&heights)?;
+
+        let mut report = ScanReport::new(*address, heights.clone());
+        for height in heights {
+            let block = self.get_block(height)?;
+            for confirmed in block.transactions().iter() {
+                // Note: For a rejected transaction, this is the fee transaction that was confirmed in its place.
+                let transaction = confirmed.transaction();
+                for transition in transaction.transitions() {
+                    // Record the public interaction of the address, if any.
+                    if let Some(activity) =
+                        PublicActivity::from_transition(height, transaction.id(), transition, address)
+                    {
+                        report.add_activity(activity);
+                    }
+                }
+            }
+            // Record the records owned by the address, if the view key is given.
+            if let Some(view_key) = view_key {
+                self.scan_block(&block, view_key)?.into_iter().for_each(|record| report.add_record(record));
+            }
+        }
+        Ok(report)
+    }
+
+    /// Returns the records in the given block that are owned by the given view key, with their spent status.
+    fn scan_block(&self, block: &Block<N>, view_key: &ViewKey<N>) -> Result<Vec<ScannedRecord<N>>> {
+        let mut records = block.scan(view_key)?;
+        // Determine if each record is spent, by checking if its tag is in the ledger.
+        for scanned in records.iter_mut() {
+            scanned.is_spent = Some(self.contains_tag(&scanned.tag)?);
+        }
+        Ok(records)
+    }
+
+    /// Ensures the given block range is not empty, and does not exceed the latest block height.
+    fn ensure_scan_range(&self, heights: &Range<u32>) -> Result<()> {
+        // Ensure the range is not empty.
+        ensure!(heights.start < heights.end, "The block range {heights:?} is empty");
+        // Ensure the range does not exceed the latest block height.
+        ensure!(
+            heights.end <= self.latest_height().saturating_add(1),
+            "The block range {heights:?} exceeds the ledger"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,103 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use console::program::Value;
+use parking_lot::RwLockReadGuard;
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Returns a snapshot of the ledger at the latest block, for composing several consistent reads.
+    ///
+    /// While the snapshot is held, no block is added to the ledger, so the reads of the snapshot
+    /// do not observe a block that is inserted in between them.
+    /// Note: The snapshot must not be held across a call to `Ledger::advance_to_next_block`, or to any of
+    /// the `Ledger::latest_*` methods on the same thread, as these wait on the insertion of a block.
+    pub fn read_snapshot(&self) -> LedgerSnapshot<'_, N, C> {
+        LedgerSnapshot { ledger: self, latest_block: self.current_block.read() }
+    }
+}
+
+/// A consistent view of the ledger, pinned at its latest block when the snapshot was taken.
+pub struct LedgerSnapshot<'a, N: Network, C: ConsensusStorage<N>> {
+    /// The ledger.
+    ledger: &'a Ledger<N, C>,
+    /// The latest block, whose read lock blocks the insertion of new blocks.
+    latest_block: RwLockReadGuard<'a, Block<N>>,
+}
+
+impl<N: Network, C: ConsensusStorage<N>> LedgerSnapshot<'_, N, C> {
+    /// Returns the latest block of the snapshot.
+    pub fn latest_block(&self) -> &Block<N> {
+        &self.latest_block
+    }
+
+    /// Returns the latest block height of the snapshot.
+    pub fn latest_height(&self) -> u32 {
+        self.latest_block.height()
+    }
+
+    /// Returns the latest block hash of the snapshot.
+    pub fn latest_hash(&self) -> N::BlockHash {
+        self.latest_block.hash()
+    }
+
+    /// Returns the latest state root of the snapshot.
+    pub fn latest_state_root(&self) -> N::StateRoot {
+        self.ledger.vm.block_store().current_state_root()
+    }
+
+    /// Returns the block for the given block height.
+    pub fn get_block(&self, height: u32) -> Result<Block<N>> {
+        match height == self.latest_height() {
+            true => Ok(self.latest_block.clone()),
+            false => self.ledger.get_block(height),
+        }
+    }
+
+    /// Returns the block for the given block hash.
+    pub fn get_block_by_hash(&self, block_hash: &N::BlockHash) -> Result<Block<N>> {
+        self.ledger.get_block_by_hash(block_hash)
+    }
+
+    /// Returns the block transactions for the given block height.
+    pub fn get_transactions(&self, height: u32) -> Result<Transactions<N>> {
+        self.ledger.get_transactions(height)
+    }
+
+    /// Returns the transaction for the given transaction ID.
+    pub fn get_transaction(&self, transaction_id: N::TransactionID) -> Result<Transaction<N>> {
+        self.ledger.get_transaction(transaction_id)
+    }
+
+    /// Returns the confirmed transaction for the given transaction ID.
+    pub fn get_confirmed_transaction(&self, transaction_id: N::TransactionID) -> Result<ConfirmedTransaction<N>> {
+        self.ledger.get_confirmed_transaction(transaction_id)
+    }
+
+    /// Returns the program for the given program ID.
+    pub fn get_program(&self, program_id: ProgramID<N>) -> Result<Program<N>> {
+        self.ledger.get_program(program_id)
+    }
+
+    /// Returns the confirmed value of the given key in the given mapping, if it exists.
+    pub fn get_mapping_value(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: &Plaintext<N>,
+    ) -> Result<Option<Value<N>>> {
+        self.ledger.vm.finalize_store().get_value_confirmed(program_id, mapping_name, key)
+    }
+}
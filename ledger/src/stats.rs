@@ -0,0 +1,65 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Returns the statistics of the blocks in the given block range.
+    /// The range is inclusive of the start and exclusive of the end.
+    pub fn stats(&self, heights: Range<u32>) -> Result<BlockStats> {
+        // Ensure the range is not empty.
+        ensure!(heights.start < heights.end, "The block range {heights:?} is empty");
+        // Ensure the range does not exceed the latest block height.
+        ensure!(
+            heights.end <= self.latest_height().saturating_add(1),
+            "The block range {heights:?} exceeds the ledger"
+        );
+
+        // Retrieve the cumulative stats up to the end of the range.
+        let end_stats = self.get_cumulative_stats(heights.end - 1)?;
+        // Subtract the cumulative stats before the start of the range.
+        match heights.start {
+            0 => Ok(end_stats),
+            start => end_stats
+                .checked_sub(&self.get_cumulative_stats(start - 1)?)
+                .ok_or_else(|| anyhow!("The block stats for the block range {heights:?} underflow")),
+        }
+    }
+
+    /// Returns the average number of transactions per second in the given block range.
+    /// The range is inclusive of the start and exclusive of the end.
+    ///
+    /// The elapsed time is measured from the block preceding the range (or the genesis block)
+    /// to the last block in the range.
+    pub fn transactions_per_second(&self, heights: Range<u32>) -> Result<f64> {
+        // Retrieve the stats for the range.
+        let stats = self.stats(heights.clone())?;
+        // Compute the elapsed time in seconds.
+        let start_timestamp = self.get_header(heights.start.saturating_sub(1))?.timestamp();
+        let end_timestamp = self.get_header(heights.end - 1)?.timestamp();
+        let elapsed = end_timestamp.saturating_sub(start_timestamp);
+        // Ensure time has elapsed over the range.
+        ensure!(elapsed > 0, "No time has elapsed in the block range {heights:?}");
+        // Return the number of transactions per second.
+        Ok(stats.num_transactions() as f64 / elapsed as f64)
+    }
+
+    /// Returns the cumulative block stats, up to and including the given block height.
+    fn get_cumulative_stats(&self, height: u32) -> Result<BlockStats> {
+        match self.vm.block_store().get_block_stats(height)? {
+            Some(stats) => Ok(stats),
+            None => bail!("Missing block stats for block {height}"),
+        }
+    }
+}
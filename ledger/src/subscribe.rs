@@ -0,0 +1,117 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use ledger_store::{to_key_id, to_mapping_id};
+use synthesizer::program::{StackMatches, StackProgram};
+
+use indexmap::IndexSet;
+use std::sync::mpsc::{channel, Receiver};
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Registers a subscription with the given filter, and returns its ID and the receiver of its updates.
+    /// The matching updates of each new block are sent as the block is added to the ledger.
+    ///
+    /// The filter is compiled against the deployed program, so the mapping must exist in the program,
+    /// and the keys must match the key type of the mapping.
+    pub fn subscribe(
+        &self,
+        filter: SubscriptionFilter<N>,
+    ) -> Result<(SubscriptionID, Receiver<SubscriptionUpdate<N>>)> {
+        // Compile the filter.
+        let filter = self.compile_filter(&filter)?;
+        // Register the subscription.
+        let (sender, receiver) = channel();
+        let id = self.subscriptions.write().insert(filter, sender);
+        Ok((id, receiver))
+    }
+
+    /// Deregisters the given subscription, and returns `true` if it was registered.
+    pub fn unsubscribe(&self, id: SubscriptionID) -> bool {
+        self.subscriptions.write().remove(id)
+    }
+
+    /// Returns the number of registered subscriptions.
+    pub fn num_subscriptions(&self) -> usize {
+        self.subscriptions.read().len()
+    }
+}
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Compiles the given filter into the mapping and key IDs of the finalize operations it matches.
+    fn compile_filter(&self, filter: &SubscriptionFilter<N>) -> Result<CompiledFilter<N>> {
+        // Ensure the filter is well-formed.
+        if filter.mapping().is_some() && filter.program().is_none() {
+            bail!("A subscription to a mapping must specify the program")
+        }
+        if filter.keys().keys().is_some() && filter.mapping().is_none() {
+            bail!("A subscription to mapping keys must specify the mapping")
+        }
+        if filter.address().is_some() && filter.mapping().is_some() {
+            bail!("A subscription to an address cannot be restricted to a mapping")
+        }
+
+        // If the filter is not restricted to a program, it matches every mapping.
+        let Some(program_id) = filter.program() else {
+            return Ok(CompiledFilter::new(None, None, None, false, filter.address().copied()));
+        };
+
+        // Retrieve the stack of the program.
+        let process = self.vm.process();
+        let process = process.read();
+        let stack = process.get_stack(*program_id)?;
+        // Compute the program ID field, as in the `DeprecateProgram` and `Event` operations.
+        let program_field = N::hash_bhp1024(&program_id.to_bits_le())?;
+
+        let (mapping_ids, key_ids) = match filter.mapping() {
+            Some(mapping_name) => {
+                // Ensure the mapping exists.
+                let mapping = stack.program().get_mapping(mapping_name)?;
+                // Compute the IDs of the keys, ensuring each key matches the key type of the mapping.
+                let key_ids = filter
+                    .keys()
+                    .keys()
+                    .map(|keys| {
+                        keys.iter()
+                            .map(|key| {
+                                stack.matches_plaintext(key, mapping.key().plaintext_type())?;
+                                to_key_id(program_id, mapping_name, key)
+                            })
+                            .collect::<Result<IndexSet<_>>>()
+                    })
+                    .transpose()?;
+                (IndexSet::from([to_mapping_id(program_id, mapping_name)?]), key_ids)
+            }
+            // Otherwise, match every mapping of the program.
+            None => {
+                let mapping_ids = stack
+                    .program()
+                    .mappings()
+                    .keys()
+                    .map(|mapping_name| to_mapping_id(program_id, mapping_name))
+                    .collect::<Result<IndexSet<_>>>()?;
+                (mapping_ids, None)
+            }
+        };
+
+        Ok(CompiledFilter::new(
+            Some((*program_id, program_field)),
+            Some(mapping_ids),
+            key_ids,
+            filter.mapping().is_some(),
+            filter.address().copied(),
+        ))
+    }
+}
@@ -15,20 +15,34 @@
 use crate::{
     advance::split_candidate_solutions,
     test_helpers::{CurrentLedger, CurrentNetwork},
+    ActivityKind,
+    EraManifest,
+    EraReader,
+    GeneratedFee,
+    GeneratedInput,
+    KeyPredicate,
     Ledger,
+    ProgramFilter,
     RecordsFilter,
+    SubscriptionFilter,
+    SubscriptionUpdate,
+    TransactionGenerator,
+    TransactionShape,
 };
 use aleo_std::StorageMode;
 use console::{
-    account::{Address, PrivateKey},
+    account::{Address, ComputeKey, OneTimeAddress, PrivateKey, ViewKey},
     network::prelude::*,
     program::{Entry, Identifier, Literal, Plaintext, ProgramID, Value},
 };
 use indexmap::IndexMap;
 use ledger_block::{ConfirmedTransaction, Rejected, Transaction};
-use ledger_committee::{Committee, MIN_VALIDATOR_STAKE};
-use ledger_store::{helpers::memory::ConsensusMemory, ConsensusStore};
-use synthesizer::{program::Program, vm::VM};
+use ledger_committee::{Committee, CommitteeChange, MIN_VALIDATOR_STAKE};
+use ledger_store::{helpers::memory::ConsensusMemory, BlockStats, ConsensusStore};
+use synthesizer::{
+    program::{FinalizeOperation, Program},
+    vm::VM,
+};
 
 #[test]
 fn test_load() {
@@ -69,6 +83,349 @@ fn test_load_unchecked() {
     assert_eq!(ledger.latest_block(), genesis);
 }
 
+#[test]
+fn test_stats() {
+    // Load the genesis block.
+    let genesis = crate::test_helpers::sample_genesis_block();
+
+    // Initialize the ledger with the genesis block.
+    let ledger = CurrentLedger::load(genesis.clone(), StorageMode::Production).unwrap();
+
+    // Ensure the stats for the genesis block match the block.
+    let stats = ledger.stats(0..1).unwrap();
+    assert_eq!(stats, BlockStats::from_block(&genesis).unwrap());
+    assert_eq!(stats.num_blocks(), 1);
+    assert_eq!(stats.num_transactions(), genesis.transactions().len() as u64);
+    assert_eq!(stats.num_transitions(), genesis.transitions().count() as u64);
+    assert_eq!(stats.num_rejected_transactions(), 0);
+
+    // Ensure empty ranges and ranges beyond the latest block are rejected.
+    assert!(ledger.stats(0..0).is_err());
+    assert!(ledger.stats(0..2).is_err());
+    assert!(ledger.stats(1..2).is_err());
+}
+
+#[test]
+fn test_scan_address() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, view_key, address } =
+        crate::test_helpers::sample_test_env(rng);
+
+    // Sample a recipient account.
+    let recipient_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let recipient_address = Address::try_from(&recipient_private_key).unwrap();
+
+    // Transfer public credits to the recipient.
+    let inputs = [Value::from_str(&format!("{recipient_address}")).unwrap(), Value::from_str("185000u64").unwrap()];
+    let transaction = ledger
+        .vm
+        .execute(&private_key, ("credits.aleo", "transfer_public"), inputs.iter(), None, 0, None, rng)
+        .unwrap();
+    let transaction_id = transaction.id();
+    let block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+    ledger.check_next_block(&block, rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Ensure the watch-only scan of the recipient reports the transfer, and no records.
+    let report = ledger.scan_address(&recipient_address, 1..2).unwrap();
+    assert_eq!(report.address(), &recipient_address);
+    assert!(report.records().is_empty());
+    assert_eq!(report.activity().len(), 1);
+    assert_eq!(report.activity()[0].kind, ActivityKind::Transfer);
+    assert_eq!(report.activity()[0].transaction_id, transaction_id);
+    assert_eq!(report.activity()[0].function_name, Identifier::from_str("transfer_public").unwrap());
+
+    // Ensure the watch-only scan of the sender reports the transfer and the public fee.
+    let report = ledger.scan_address(&address, 1..2).unwrap();
+    assert_eq!(report.activity_of(ActivityKind::Transfer).count(), 1);
+    assert_eq!(report.activity_of(ActivityKind::Fee).count(), 1);
+    assert!(report.activity().iter().all(|activity| activity.transaction_id == transaction_id));
+
+    // Ensure the view key scan of the sender reports the same activity.
+    let view_key_report = ledger.scan(&view_key, 1..2).unwrap();
+    assert_eq!(view_key_report.activity(), report.activity());
+
+    // Ensure the view key scan of the sender reports the same records as `find_records`.
+    let records = ledger.find_records(&view_key, RecordsFilter::All).unwrap().collect::<Vec<_>>();
+    let view_key_report = ledger.scan(&view_key, 0..2).unwrap();
+    assert_eq!(view_key_report.records().len(), records.len());
+    for scanned in view_key_report.records() {
+        assert!(records.contains(&(scanned.commitment, scanned.record.clone())));
+    }
+
+    // Ensure the scan of an account without any activity is empty.
+    let other_address = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+    let report = ledger.scan_address(&other_address, 0..2).unwrap();
+    assert!(report.activity().is_empty());
+    assert!(report.records().is_empty());
+
+    // Ensure empty ranges and ranges beyond the latest block are rejected.
+    assert!(ledger.scan_address(&address, 1..1).is_err());
+    assert!(ledger.scan_address(&address, 1..3).is_err());
+}
+
+#[test]
+fn test_scan_range() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, view_key, address } =
+        crate::test_helpers::sample_test_env(rng);
+
+    // Ensure the genesis records are found, and are unspent.
+    let records = ledger.scan_range(&view_key, 0, 1).unwrap();
+    assert_eq!(records, ledger.scan(&view_key, 0..1).unwrap().records());
+    assert!(records.iter().all(|scanned| scanned.is_spent == Some(false)));
+
+    // Select a genesis record with a balance.
+    let microcredits = Identifier::from_str("microcredits").unwrap();
+    let spent = records
+        .iter()
+        .find(|scanned| match scanned.record.data().get(&microcredits) {
+            Some(Entry::Private(Plaintext::Literal(Literal::U64(amount), _))) => !amount.is_zero(),
+            _ => false,
+        })
+        .unwrap()
+        .clone();
+
+    // Spend the record.
+    let inputs = [
+        Value::Record(spent.record.clone()),
+        Value::from_str(&format!("{address}")).unwrap(),
+        Value::from_str("100u64").unwrap(),
+    ];
+    let transaction = ledger
+        .vm
+        .execute(&private_key, ("credits.aleo", "transfer_private"), inputs.into_iter(), None, 0, None, rng)
+        .unwrap();
+    let transaction_id = transaction.id();
+    let block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+    ledger.check_next_block(&block, rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Ensure the spent record is now reported as spent.
+    let records = ledger.scan_range(&view_key, 0, 1).unwrap();
+    let scanned = records.iter().find(|scanned| scanned.commitment == spent.commitment).unwrap();
+    assert_eq!(scanned.is_spent, Some(true));
+    assert_eq!(records.iter().filter(|scanned| scanned.is_spent == Some(true)).count(), 1);
+
+    // Ensure the outputs of the transfer are reported as unspent, with their transition context.
+    let records = ledger.scan_range(&view_key, 1, 2).unwrap();
+    assert_eq!(records.len(), 2);
+    assert!(records.iter().all(|scanned| scanned.height == 1 && scanned.transaction_id == transaction_id));
+    assert!(records.iter().all(|scanned| scanned.is_spent == Some(false)));
+    assert_eq!(
+        records,
+        block
+            .scan(&view_key)
+            .unwrap()
+            .into_iter()
+            .map(|scanned| crate::ScannedRecord { is_spent: Some(false), ..scanned })
+            .collect::<Vec<_>>()
+    );
+
+    // Ensure empty ranges and ranges beyond the latest block are rejected.
+    assert!(ledger.scan_range(&view_key, 1, 1).is_err());
+    assert!(ledger.scan_range(&view_key, 1, 3).is_err());
+}
+
+#[test]
+fn test_scan_one_time() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Sample a recipient account.
+    let recipient_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let recipient_view_key = ViewKey::try_from(&recipient_private_key).unwrap();
+    let recipient_compute_key = ComputeKey::try_from(&recipient_private_key).unwrap();
+
+    // Derive a one-time address for the recipient, and a one-time address for another account.
+    let one_time_address = OneTimeAddress::new(&recipient_compute_key, rng).unwrap();
+    let other_compute_key = ComputeKey::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+    let other_one_time_address = OneTimeAddress::new(&other_compute_key, rng).unwrap();
+
+    // Transfer a private record to the one-time address of the recipient.
+    let inputs =
+        [Value::from_str(&format!("{}", one_time_address.address())).unwrap(), Value::from_str("185000u64").unwrap()];
+    let transaction = ledger
+        .vm
+        .execute(&private_key, ("credits.aleo", "transfer_public_to_private"), inputs.iter(), None, 0, None, rng)
+        .unwrap();
+    let block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+    ledger.check_next_block(&block, rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Ensure the recipient detects its one-time address, and decrypts the record.
+    let reports = ledger
+        .scan_one_time(&recipient_view_key, &recipient_compute_key, &[one_time_address, other_one_time_address], 0..2)
+        .unwrap();
+    assert_eq!(reports.len(), 1);
+    assert_eq!(reports[0].address(), one_time_address.address());
+    assert_eq!(reports[0].records().len(), 1);
+    assert_eq!(**reports[0].records()[0].record.owner(), *one_time_address.address());
+
+    // Ensure the record is not visible to the view key of the recipient address.
+    assert!(ledger.scan(&recipient_view_key, 0..2).unwrap().records().is_empty());
+}
+
+#[test]
+fn test_read_snapshot() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, address, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Prepare the next block, with a public transfer.
+    let inputs = [Value::from_str(&format!("{address}")).unwrap(), Value::from_str("1u64").unwrap()];
+    let transaction = ledger
+        .vm
+        .execute(&private_key, ("credits.aleo", "transfer_public"), inputs.iter(), None, 0, None, rng)
+        .unwrap();
+    let transaction_id = transaction.id();
+    let block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+    ledger.check_next_block(&block, rng).unwrap();
+
+    // Take a snapshot, and read the balance of the account.
+    let program_id = ProgramID::from_str("credits.aleo").unwrap();
+    let mapping_name = Identifier::from_str("account").unwrap();
+    let key = Plaintext::from_str(&address.to_string()).unwrap();
+    let snapshot = ledger.read_snapshot();
+    let balance = snapshot.get_mapping_value(program_id, mapping_name, &key).unwrap();
+    assert!(balance.is_some());
+
+    // Advance the ledger on another thread, while the snapshot is held.
+    let handle = {
+        let (ledger, block) = (ledger.clone(), block.clone());
+        std::thread::spawn(move || ledger.advance_to_next_block(&block).unwrap())
+    };
+    std::thread::sleep(std::time::Duration::from_millis(100));
+
+    // Ensure the snapshot does not observe the new block.
+    assert_eq!(snapshot.latest_height(), 0);
+    assert_eq!(snapshot.get_block(0).unwrap().hash(), snapshot.latest_hash());
+    assert!(snapshot.get_block(1).is_err());
+    assert!(snapshot.get_transaction(transaction_id).is_err());
+    assert_eq!(snapshot.get_mapping_value(program_id, mapping_name, &key).unwrap(), balance);
+
+    // Release the snapshot, and wait for the block to be added.
+    drop(snapshot);
+    handle.join().unwrap();
+
+    // Ensure a new snapshot observes the new block.
+    let snapshot = ledger.read_snapshot();
+    assert_eq!(snapshot.latest_height(), 1);
+    assert_eq!(snapshot.get_block(1).unwrap(), block);
+    assert!(snapshot.get_transaction(transaction_id).is_ok());
+    assert_ne!(snapshot.get_mapping_value(program_id, mapping_name, &key).unwrap(), balance);
+}
+
+#[test]
+fn test_subscribe() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, address, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Sample a recipient account.
+    let recipient_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let recipient_address = Address::try_from(&recipient_private_key).unwrap();
+    let recipient_key = Plaintext::from_str(&recipient_address.to_string()).unwrap();
+
+    let credits = ProgramID::from_str("credits.aleo").unwrap();
+    let account = Identifier::from_str("account").unwrap();
+
+    // Ensure malformed filters are rejected.
+    let filter = SubscriptionFilter::new().with_mapping(account);
+    assert!(ledger.subscribe(filter).is_err());
+    let filter = SubscriptionFilter::new().with_program(credits).with_mapping(Identifier::from_str("missing").unwrap());
+    assert!(ledger.subscribe(filter).is_err());
+    let filter = SubscriptionFilter::new()
+        .with_program(credits)
+        .with_mapping(account)
+        .with_keys(KeyPredicate::Equals(Plaintext::from_str("1u64").unwrap()));
+    assert!(ledger.subscribe(filter).is_err());
+    let filter = SubscriptionFilter::new().with_program(credits).with_mapping(account).with_address(address);
+    assert!(ledger.subscribe(filter).is_err());
+    assert_eq!(ledger.num_subscriptions(), 0);
+
+    // Subscribe to the account of the recipient, to the account mapping, and to the activity of the recipient.
+    let filter = SubscriptionFilter::new()
+        .with_program(credits)
+        .with_mapping(account)
+        .with_keys(KeyPredicate::Equals(recipient_key.clone()));
+    let (key_subscription, key_updates) = ledger.subscribe(filter).unwrap();
+    let (_, mapping_updates) =
+        ledger.subscribe(SubscriptionFilter::new().with_program(credits).with_mapping(account)).unwrap();
+    let (_, activity_updates) = ledger.subscribe(SubscriptionFilter::new().with_address(recipient_address)).unwrap();
+    // Subscribe to the program, and drop the receiver.
+    let (_, dropped) = ledger.subscribe(SubscriptionFilter::new().with_program(credits)).unwrap();
+    drop(dropped);
+    assert_eq!(ledger.num_subscriptions(), 4);
+
+    // Transfer public credits to the recipient.
+    let inputs = [Value::from_str(&format!("{recipient_address}")).unwrap(), Value::from_str("185000u64").unwrap()];
+    let transaction = ledger
+        .vm
+        .execute(&private_key, ("credits.aleo", "transfer_public"), inputs.iter(), None, 0, None, rng)
+        .unwrap();
+    let transaction_id = transaction.id();
+    let block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+    ledger.check_next_block(&block, rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Ensure the key subscription only receives the update of the recipient account.
+    let updates = key_updates.try_iter().collect::<Vec<_>>();
+    assert_eq!(updates.len(), 1);
+    let key_update = updates[0].clone();
+    let expected_key_id = ledger_store::to_key_id(&credits, &account, &recipient_key).unwrap();
+    match &key_update {
+        SubscriptionUpdate::Operation { height, transaction_id: id, operation } => {
+            assert_eq!(*height, 1);
+            assert_eq!(*id, transaction_id);
+            match operation {
+                FinalizeOperation::InsertKeyValue(_, key_id, _) | FinalizeOperation::UpdateKeyValue(_, key_id, _) => {
+                    assert_eq!(*key_id, expected_key_id)
+                }
+                operation => panic!("Unexpected operation: {operation:?}"),
+            }
+        }
+        update => panic!("Unexpected update: {update:?}"),
+    }
+
+    // Ensure the mapping subscription receives the updates of both accounts.
+    let updates = mapping_updates.try_iter().collect::<Vec<_>>();
+    assert!(updates.len() > 1);
+    assert!(updates.contains(&key_update));
+
+    // Ensure the activity subscription receives the transfer to the recipient.
+    let updates = activity_updates.try_iter().collect::<Vec<_>>();
+    assert_eq!(updates.len(), 1);
+    match &updates[0] {
+        SubscriptionUpdate::Activity(activity) => {
+            assert_eq!(activity.kind, ActivityKind::Transfer);
+            assert_eq!(activity.transaction_id, transaction_id);
+        }
+        update => panic!("Unexpected update: {update:?}"),
+    }
+
+    // Ensure the subscription with the dropped receiver is deregistered.
+    assert_eq!(ledger.num_subscriptions(), 3);
+
+    // Ensure subscriptions can be deregistered.
+    assert!(ledger.unsubscribe(key_subscription));
+    assert!(!ledger.unsubscribe(key_subscription));
+    assert_eq!(ledger.num_subscriptions(), 2);
+}
+
 #[test]
 fn test_state_path() {
     let rng = &mut TestRng::default();
@@ -85,6 +442,116 @@ fn test_state_path() {
     let _state_path = ledger.get_state_path_for_commitment(commitment).unwrap();
 }
 
+#[test]
+fn test_era_archive() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the ledger.
+    let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let address = Address::try_from(&private_key).unwrap();
+    let ledger = crate::test_helpers::sample_ledger(private_key, rng);
+
+    // Advance the ledger by four blocks.
+    for _ in 0..4 {
+        let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+        ledger.advance_to_next_block(&block).unwrap();
+    }
+
+    // Write the first two eras, of two blocks each.
+    let mut era_0 = Vec::new();
+    let manifest_0 = ledger.write_era(0, 2, None, &mut era_0).unwrap();
+    let mut era_1 = Vec::new();
+    let manifest_1 = ledger.write_era(1, 2, Some(&manifest_0), &mut era_1).unwrap();
+    assert_eq!(manifest_1.heights().unwrap(), 2..4);
+    assert_eq!(manifest_1.previous_block_hash(), manifest_0.last_block_hash());
+
+    // Ensure an era must be complete, and must be chained to the previous era.
+    assert!(ledger.write_era(2, 2, Some(&manifest_1), &mut Vec::new()).is_err());
+    assert!(ledger.write_era(1, 2, None, &mut Vec::new()).is_err());
+
+    // Ensure the manifests form an integrity chain.
+    manifest_0.verify_chain(None).unwrap();
+    manifest_1.verify_chain(Some(&manifest_0)).unwrap();
+    assert!(manifest_1.verify_chain(None).is_err());
+    assert_eq!(EraManifest::<CurrentNetwork>::read_le(&manifest_1.to_bytes_le().unwrap()[..]).unwrap(), manifest_1);
+
+    // Ensure the blocks are read back, and verified against the manifest.
+    let mut reader = EraReader::new(&era_1[..], &manifest_1).unwrap();
+    let blocks = reader.by_ref().collect::<Result<Vec<_>>>().unwrap();
+    reader.finish().unwrap();
+    assert_eq!(blocks, vec![ledger.get_block(2).unwrap(), ledger.get_block(3).unwrap()]);
+
+    // Ensure an era file is rejected against the manifest of another era.
+    assert!(EraReader::new(&era_0[..], &manifest_1).is_err());
+    // Ensure trailing data is rejected.
+    let mut trailing = era_1.clone();
+    trailing.push(0);
+    let mut reader = EraReader::new(&trailing[..], &manifest_1).unwrap();
+    while reader.read_block().unwrap().is_some() {}
+    assert!(reader.finish().is_err());
+
+    // Ensure the detached signature of a manifest is verified.
+    let signature = manifest_1.sign(&private_key, rng).unwrap();
+    assert!(manifest_1.verify_signature(&address, &signature));
+    assert!(!manifest_0.verify_signature(&address, &signature));
+}
+
+#[test]
+fn test_state_root_at() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the ledger.
+    let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let ledger = crate::test_helpers::sample_ledger(private_key, rng);
+
+    // Ensure the state root at the latest height has no path.
+    let (state_root, path) = ledger.state_root_at(0).unwrap();
+    assert_eq!(state_root, ledger.latest_state_root());
+    assert!(path.is_none());
+
+    // Advance the ledger by two blocks.
+    for _ in 0..2 {
+        let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+        ledger.advance_to_next_block(&block).unwrap();
+    }
+
+    // Ensure the historical state roots are proven against the current state root.
+    for height in 0..2 {
+        let (state_root, path) = ledger.state_root_at(height).unwrap();
+        assert_eq!(Some(state_root), ledger.get_state_root(height).unwrap());
+        let path = path.unwrap();
+        assert_eq!(path.state_root(), state_root);
+        assert_eq!(path.global_state_root(), ledger.latest_state_root());
+        path.verify(&state_root).unwrap();
+    }
+
+    // Ensure the state root at a non-existent height fails.
+    assert!(ledger.state_root_at(3).is_err());
+}
+
+#[test]
+fn test_select_credits_record() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, view_key, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Fetch the unspent records.
+    let records = ledger.find_unspent_credits_records(&view_key).unwrap();
+    assert!(!records.is_empty());
+    // Determine the smallest balance.
+    let smallest = records.values().map(|record| record.microcredits().unwrap()).min().unwrap();
+
+    // Ensure the smallest sufficient record is selected.
+    let (commitment, record) = ledger.select_credits_record(&view_key, 1, &[]).unwrap().unwrap();
+    assert_eq!(record.microcredits().unwrap(), smallest);
+    // Ensure an excluded record is not selected.
+    let selected = ledger.select_credits_record(&view_key, 1, &[commitment]).unwrap();
+    assert!(selected.map_or(true, |(candidate, _)| candidate != commitment));
+    // Ensure no record is selected if the amount is too large.
+    assert!(ledger.select_credits_record(&view_key, u64::MAX, &[]).unwrap().is_none());
+}
+
 #[test]
 fn test_insufficient_private_fees() {
     let rng = &mut TestRng::default();
@@ -485,6 +952,66 @@ finalize foo:
     assert_eq!(program, ledger.get_program(program_id).unwrap())
 }
 
+#[test]
+fn test_programs_with_filter() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, .. } = crate::test_helpers::sample_test_env(rng);
+    let address = Address::try_from(&private_key).unwrap();
+
+    // Deploy a program in block 1.
+    let first = Program::<CurrentNetwork>::from_str(
+        "
+program filter_first.aleo;
+function foo:
+    input r0 as u8.private;
+    output r0 as u8.private;",
+    )
+    .unwrap();
+    let transaction = ledger.vm.deploy(&private_key, &first, None, 0, None, rng).unwrap();
+    let block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Deploy a program that imports the first program in block 2.
+    let second = Program::<CurrentNetwork>::from_str(
+        "
+import filter_first.aleo;
+program other_second.aleo;
+function bar:
+    input r0 as u8.private;
+    output r0 as u8.private;",
+    )
+    .unwrap();
+    let transaction = ledger.vm.deploy(&private_key, &second, None, 0, None, rng).unwrap();
+    let block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Returns the programs that match the given filter.
+    let programs = |filter: ProgramFilter<CurrentNetwork>| -> Vec<Program<CurrentNetwork>> {
+        ledger.programs(&filter).collect::<Result<_>>().unwrap()
+    };
+
+    // Ensure every deployed program is returned without criteria.
+    assert_eq!(programs(ProgramFilter::new()), vec![first.clone(), second.clone()]);
+    // Ensure the programs are filtered by owner.
+    assert_eq!(programs(ProgramFilter::new().with_owner(address)), vec![first.clone(), second.clone()]);
+    let other = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+    assert!(programs(ProgramFilter::new().with_owner(other)).is_empty());
+    // Ensure the programs are filtered by deployment height.
+    assert_eq!(programs(ProgramFilter::new().with_heights(2..3)), vec![second.clone()]);
+    assert!(programs(ProgramFilter::new().with_heights(3..10)).is_empty());
+    // Ensure the programs are filtered by import.
+    assert_eq!(programs(ProgramFilter::new().with_import(*first.id())), vec![second.clone()]);
+    // Ensure the programs are filtered by name prefix.
+    assert_eq!(programs(ProgramFilter::new().with_name_prefix("filter")), vec![first.clone()]);
+    // Ensure the criteria are combined.
+    let filter = ProgramFilter::new().with_owner(address).with_heights(0..2).with_name_prefix("other");
+    assert!(programs(filter).is_empty());
+}
+
 #[test]
 fn test_bond_and_unbond_validator() {
     let rng = &mut TestRng::default();
@@ -547,6 +1074,11 @@ fn test_bond_and_unbond_validator() {
     let committee = ledger.latest_committee().unwrap();
     assert!(committee.is_committee_member(new_member_address));
 
+    // Check that the committee diff contains the new member.
+    let diff = ledger.get_committee_diff(ledger.latest_height()).unwrap().unwrap();
+    let change = diff.changes().iter().find(|change| *change.address() == new_member_address).unwrap();
+    assert!(matches!(change, CommitteeChange::Join(_, stake, true) if *stake >= bond_amount));
+
     // Check that number of validators in the `metadata` mapping in `credtis.aleo` is updated.
     let program_id = ProgramID::<CurrentNetwork>::from_str("credits.aleo").unwrap();
     let metadata_mapping_name = Identifier::from_str("metadata").unwrap();
@@ -587,6 +1119,13 @@ fn test_bond_and_unbond_validator() {
     let committee = ledger.latest_committee().unwrap();
     assert!(!committee.is_committee_member(new_member_address));
 
+    // Check that the committee diffs contain the new member joining and leaving.
+    let diffs = ledger.committee_diffs(0..ledger.latest_height() + 1).collect::<Result<Vec<_>>>().unwrap();
+    assert_eq!(diffs.len(), ledger.latest_height() as usize);
+    let (_, diff) = diffs.last().unwrap();
+    let change = diff.changes().iter().find(|change| *change.address() == new_member_address).unwrap();
+    assert!(matches!(change, CommitteeChange::Leave(..)));
+
     // Check that number of validators in the `metadata` mapping in `credtis.aleo` is updated.
     let program_id = ProgramID::<CurrentNetwork>::from_str("credits.aleo").unwrap();
     let metadata_mapping_name = Identifier::from_str("metadata").unwrap();
@@ -1497,3 +2036,57 @@ fn test_max_committee_limit_with_bonds() {
     let committee = ledger.latest_committee().unwrap();
     assert!(!committee.is_committee_member(second_address));
 }
+
+#[test]
+fn test_transaction_generator() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Initialize the generator.
+    let mut generator = TransactionGenerator::new(private_key).unwrap();
+
+    // Prepare a shape with two transitions, all input kinds, a private fee, and finalize writes.
+    let inputs = vec![GeneratedInput::Constant, GeneratedInput::Public, GeneratedInput::Private];
+    let shape = TransactionShape::new::<CurrentNetwork>(2, inputs, GeneratedFee::Private, 2).unwrap();
+
+    // Ensure a transaction cannot be generated before the programs are deployed.
+    assert!(generator.generate(&ledger, &shape, rng).is_err());
+
+    // Deploy the programs, and ensure deploying again is a no-op.
+    generator.deploy(&ledger, &shape, rng).unwrap();
+    let height = ledger.latest_height();
+    generator.deploy(&ledger, &shape, rng).unwrap();
+    assert_eq!(ledger.latest_height(), height);
+
+    // Generate two transactions, which must spend different fee records.
+    let transaction_1 = generator.generate(&ledger, &shape, rng).unwrap();
+    let transaction_2 = generator.generate(&ledger, &shape, rng).unwrap();
+    for transaction in [&transaction_1, &transaction_2] {
+        assert_eq!(transaction.execution().unwrap().len(), shape.num_transitions());
+        assert!(matches!(transaction.fee_transition(), Some(fee) if fee.is_fee_private()));
+    }
+    assert!(transaction_1.input_ids().all(|id| !transaction_2.input_ids().any(|other| other == id)));
+
+    // Add the transactions to the next block.
+    let block = ledger
+        .prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction_1, transaction_2], rng)
+        .unwrap();
+    ledger.check_next_block(&block, rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+    assert_eq!(block.transactions().num_accepted(), 2);
+
+    // Ensure the finalize writes were applied.
+    let program_id = ProgramID::from_str("gen_1_2_cps.aleo").unwrap();
+    let value = ledger
+        .vm()
+        .finalize_store()
+        .get_value_confirmed(program_id, Identifier::from_str("writes").unwrap(), &Plaintext::from_str("1u8").unwrap())
+        .unwrap();
+    assert_eq!(value, Some(Value::from_str(&format!("{}u32", block.height())).unwrap()));
+
+    // Ensure a shape with too many transitions is rejected.
+    let inputs = vec![GeneratedInput::Public];
+    assert!(TransactionShape::new::<CurrentNetwork>(32, inputs, GeneratedFee::Public, 0).is_err());
+}
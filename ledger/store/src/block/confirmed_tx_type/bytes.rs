@@ -25,7 +25,8 @@ impl<N: Network> FromBytes for ConfirmedTxType<N> {
             1 => Ok(Self::AcceptedExecute(u32::read_le(&mut reader)?)),
             2 => Ok(Self::RejectedDeploy(u32::read_le(&mut reader)?, Rejected::read_le(&mut reader)?)),
             3 => Ok(Self::RejectedExecute(u32::read_le(&mut reader)?, Rejected::read_le(&mut reader)?)),
-            4.. => Err(error("Invalid confirmed transaction type variant")),
+            4 => Ok(Self::AcceptedDeprecate(u32::read_le(&mut reader)?)),
+            5.. => Err(error("Invalid confirmed transaction type variant")),
         }
     }
 }
@@ -63,6 +64,12 @@ impl<N: Network> ToBytes for ConfirmedTxType<N> {
                 // Write the rejected transaction.
                 rejected.write_le(&mut writer)
             }
+            Self::AcceptedDeprecate(index) => {
+                // Write the variant.
+                4u8.write_le(&mut writer)?;
+                // Write the index.
+                index.write_le(&mut writer)
+            }
         }
     }
 }
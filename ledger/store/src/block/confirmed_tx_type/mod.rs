@@ -28,6 +28,8 @@ pub enum ConfirmedTxType<N: Network> {
     RejectedDeploy(u32, Rejected<N>),
     /// An execute transaction that was rejected.
     RejectedExecute(u32, Rejected<N>),
+    /// A deprecate transaction that was accepted.
+    AcceptedDeprecate(u32),
 }
 
 #[cfg(test)]
@@ -65,6 +67,12 @@ pub mod test_helpers {
         ConfirmedTxType::RejectedExecute(rng.gen(), rejected)
     }
 
+    /// Samples an accepted deprecate.
+    pub(crate) fn sample_accepted_deprecate(rng: &mut TestRng) -> ConfirmedTxType<CurrentNetwork> {
+        // Return the accepted deprecate.
+        ConfirmedTxType::AcceptedDeprecate(rng.gen())
+    }
+
     /// Sample a list of randomly rejected transactions.
     pub(crate) fn sample_confirmed_tx_types() -> Vec<ConfirmedTxType<CurrentNetwork>> {
         let rng = &mut TestRng::default();
@@ -74,6 +82,7 @@ pub mod test_helpers {
             sample_accepted_execution(rng),
             sample_rejected_deploy(rng),
             sample_rejected_execute(rng),
+            sample_accepted_deprecate(rng),
         ]
     }
 }
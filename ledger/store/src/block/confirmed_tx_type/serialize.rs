@@ -45,6 +45,12 @@ impl<N: Network> Serialize for ConfirmedTxType<N> {
                     confirmed_tx_type.serialize_field("rejected", rejected)?;
                     confirmed_tx_type.end()
                 }
+                Self::AcceptedDeprecate(index) => {
+                    let mut confirmed_tx_type = serializer.serialize_struct("ConfirmedTxType", 2)?;
+                    confirmed_tx_type.serialize_field("type", "AcceptedDeprecate")?;
+                    confirmed_tx_type.serialize_field("index", index)?;
+                    confirmed_tx_type.end()
+                }
             },
             false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
         }
@@ -83,6 +89,10 @@ impl<'de, N: Network> Deserialize<'de> for ConfirmedTxType<N> {
                             .map_err(de::Error::custom)?;
                         Ok(Self::RejectedExecute(index, rejected))
                     }
+                    "AcceptedDeprecate" => Ok(Self::AcceptedDeprecate(
+                        DeserializeExt::take_from_value::<D>(&mut confirmed_tx_type, "index")
+                            .map_err(de::Error::custom)?,
+                    )),
                     _ => Err(error("Invalid confirmed transaction type")).map_err(de::Error::custom),
                 }
             }
@@ -0,0 +1,318 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    atomic_batch_scope,
+    cow_to_cloned,
+    helpers::{Map, MapRead, NestedMap, NestedMapRead},
+};
+use console::{network::prelude::*, program::Identifier};
+
+use aleo_std_storage::StorageMode;
+use anyhow::Result;
+use core::marker::PhantomData;
+
+/// A trait for extension storage.
+///
+/// The extension storage is a namespaced keyspace for data that is not part of consensus,
+/// such as node-specific metadata about blocks. Its maps participate in the atomic batches
+/// of the block storage, so its entries can be written and removed together with blocks.
+pub trait ExtensionStorage<N: Network>: 'static + Clone + Send + Sync {
+    /// The mapping of `namespace` to `[(key, value)]`.
+    type ExtensionMap: for<'a> NestedMap<'a, Identifier<N>, Vec<u8>, Vec<u8>>;
+    /// The mapping of `block height` to `[(namespace, key)]`, for the entries stored with the block.
+    type BlockExtensionsMap: for<'a> Map<'a, u32, Vec<(Identifier<N>, Vec<u8>)>>;
+
+    /// Initializes the extension storage.
+    fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self>;
+
+    /// Returns the extension map.
+    fn extension_map(&self) -> &Self::ExtensionMap;
+    /// Returns the block extensions map.
+    fn block_extensions_map(&self) -> &Self::BlockExtensionsMap;
+
+    /// Returns the storage mode.
+    fn storage_mode(&self) -> &StorageMode;
+
+    /// Starts an atomic batch write operation.
+    fn start_atomic(&self) {
+        self.extension_map().start_atomic();
+        self.block_extensions_map().start_atomic();
+    }
+
+    /// Checks if an atomic batch is in progress.
+    fn is_atomic_in_progress(&self) -> bool {
+        self.extension_map().is_atomic_in_progress() || self.block_extensions_map().is_atomic_in_progress()
+    }
+
+    /// Checkpoints the atomic batch.
+    fn atomic_checkpoint(&self) {
+        self.extension_map().atomic_checkpoint();
+        self.block_extensions_map().atomic_checkpoint();
+    }
+
+    /// Clears the latest atomic batch checkpoint.
+    fn clear_latest_checkpoint(&self) {
+        self.extension_map().clear_latest_checkpoint();
+        self.block_extensions_map().clear_latest_checkpoint();
+    }
+
+    /// Rewinds the atomic batch to the previous checkpoint.
+    fn atomic_rewind(&self) {
+        self.extension_map().atomic_rewind();
+        self.block_extensions_map().atomic_rewind();
+    }
+
+    /// Aborts an atomic batch write operation.
+    fn abort_atomic(&self) {
+        self.extension_map().abort_atomic();
+        self.block_extensions_map().abort_atomic();
+    }
+
+    /// Finishes an atomic batch write operation.
+    fn finish_atomic(&self) -> Result<()> {
+        self.extension_map().finish_atomic()?;
+        self.block_extensions_map().finish_atomic()
+    }
+
+    /// Stores the given `(namespace, key, value)` entry into storage.
+    fn insert(&self, namespace: Identifier<N>, key: Vec<u8>, value: Vec<u8>) -> Result<()> {
+        self.extension_map().insert(namespace, key, value)
+    }
+
+    /// Stores the given `(namespace, key, value)` entries into storage, and associates them with the given block height.
+    /// The entries are removed from storage when the block is removed.
+    fn insert_for_block(&self, height: u32, entries: Vec<(Identifier<N>, Vec<u8>, Vec<u8>)>) -> Result<()> {
+        // Retrieve the entries already associated with the block.
+        let mut keys = match self.block_extensions_map().get_speculative(&height)? {
+            Some(keys) => cow_to_cloned!(keys),
+            None => Vec::new(),
+        };
+
+        atomic_batch_scope!(self, {
+            for (namespace, key, value) in entries {
+                // Associate the entry with the block, if it is not already.
+                if !keys.iter().any(|(n, k)| *n == namespace && *k == key) {
+                    keys.push((namespace, key.clone()));
+                }
+                // Store the entry.
+                self.extension_map().insert(namespace, key, value)?;
+            }
+            // Store the entries associated with the block.
+            self.block_extensions_map().insert(height, keys)?;
+
+            Ok(())
+        })
+    }
+
+    /// Removes the entry for the given `namespace` and `key`.
+    fn remove(&self, namespace: &Identifier<N>, key: &[u8]) -> Result<()> {
+        self.extension_map().remove_key(namespace, &key.to_vec())
+    }
+
+    /// Removes the entries associated with the given block height.
+    fn remove_for_block(&self, height: u32) -> Result<()> {
+        // Retrieve the entries associated with the block.
+        let keys = match self.block_extensions_map().get_speculative(&height)? {
+            Some(keys) => cow_to_cloned!(keys),
+            None => return Ok(()),
+        };
+
+        atomic_batch_scope!(self, {
+            // Remove the entries.
+            for (namespace, key) in keys.iter() {
+                self.extension_map().remove_key(namespace, key)?;
+            }
+            // Remove the entries associated with the block.
+            self.block_extensions_map().remove(&height)?;
+
+            Ok(())
+        })
+    }
+
+    /// Returns `true` if the given `namespace` contains the given `key`.
+    fn contains_key(&self, namespace: &Identifier<N>, key: &[u8]) -> Result<bool> {
+        self.extension_map().contains_key_speculative(namespace, &key.to_vec())
+    }
+
+    /// Returns the value for the given `namespace` and `key`.
+    fn get_value(&self, namespace: &Identifier<N>, key: &[u8]) -> Result<Option<Vec<u8>>> {
+        match self.extension_map().get_value_speculative(namespace, &key.to_vec())? {
+            Some(value) => Ok(Some(cow_to_cloned!(value))),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the `(key, value)` entries in the given `namespace`.
+    fn get_entries(&self, namespace: &Identifier<N>) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+        self.extension_map().get_map_speculative(namespace)
+    }
+
+    /// Returns the `(namespace, key)` pairs associated with the given block height.
+    fn get_block_keys(&self, height: u32) -> Result<Vec<(Identifier<N>, Vec<u8>)>> {
+        match self.block_extensions_map().get_speculative(&height)? {
+            Some(keys) => Ok(cow_to_cloned!(keys)),
+            None => Ok(Vec::new()),
+        }
+    }
+}
+
+/// The extension store.
+#[derive(Clone)]
+pub struct ExtensionStore<N: Network, E: ExtensionStorage<N>> {
+    /// The extension storage.
+    storage: E,
+    /// PhantomData.
+    _phantom: PhantomData<N>,
+}
+
+impl<N: Network, E: ExtensionStorage<N>> ExtensionStore<N, E> {
+    /// Initializes the extension store.
+    pub fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
+        // Initialize the extension storage.
+        let storage = E::open(storage)?;
+        // Return the extension store.
+        Ok(Self { storage, _phantom: PhantomData })
+    }
+
+    /// Initializes an extension store from storage.
+    pub fn from(storage: E) -> Self {
+        Self { storage, _phantom: PhantomData }
+    }
+
+    /// Starts an atomic batch write operation.
+    pub fn start_atomic(&self) {
+        self.storage.start_atomic();
+    }
+
+    /// Checks if an atomic batch is in progress.
+    pub fn is_atomic_in_progress(&self) -> bool {
+        self.storage.is_atomic_in_progress()
+    }
+
+    /// Checkpoints the atomic batch.
+    pub fn atomic_checkpoint(&self) {
+        self.storage.atomic_checkpoint();
+    }
+
+    /// Clears the latest atomic batch checkpoint.
+    pub fn clear_latest_checkpoint(&self) {
+        self.storage.clear_latest_checkpoint();
+    }
+
+    /// Rewinds the atomic batch to the previous checkpoint.
+    pub fn atomic_rewind(&self) {
+        self.storage.atomic_rewind();
+    }
+
+    /// Aborts an atomic batch write operation.
+    pub fn abort_atomic(&self) {
+        self.storage.abort_atomic();
+    }
+
+    /// Finishes an atomic batch write operation.
+    pub fn finish_atomic(&self) -> Result<()> {
+        self.storage.finish_atomic()
+    }
+
+    /// Returns the storage mode.
+    pub fn storage_mode(&self) -> &StorageMode {
+        self.storage.storage_mode()
+    }
+}
+
+impl<N: Network, E: ExtensionStorage<N>> ExtensionStore<N, E> {
+    /// Stores the given `(namespace, key, value)` entry into storage.
+    pub fn insert(&self, namespace: Identifier<N>, key: Vec<u8>, value: Vec<u8>) -> Result<()> {
+        self.storage.insert(namespace, key, value)
+    }
+
+    /// Stores the given `(namespace, key, value)` entries into storage, and associates them with the given block height.
+    /// The entries are removed from storage when the block is removed.
+    pub fn insert_for_block(&self, height: u32, entries: Vec<(Identifier<N>, Vec<u8>, Vec<u8>)>) -> Result<()> {
+        self.storage.insert_for_block(height, entries)
+    }
+
+    /// Removes the entry for the given `namespace` and `key`.
+    pub fn remove(&self, namespace: &Identifier<N>, key: &[u8]) -> Result<()> {
+        self.storage.remove(namespace, key)
+    }
+
+    /// Removes the entries associated with the given block height.
+    pub fn remove_for_block(&self, height: u32) -> Result<()> {
+        self.storage.remove_for_block(height)
+    }
+}
+
+impl<N: Network, E: ExtensionStorage<N>> ExtensionStore<N, E> {
+    /// Returns `true` if the given `namespace` contains the given `key`.
+    pub fn contains_key(&self, namespace: &Identifier<N>, key: &[u8]) -> Result<bool> {
+        self.storage.contains_key(namespace, key)
+    }
+
+    /// Returns the value for the given `namespace` and `key`.
+    pub fn get_value(&self, namespace: &Identifier<N>, key: &[u8]) -> Result<Option<Vec<u8>>> {
+        self.storage.get_value(namespace, key)
+    }
+
+    /// Returns the `(key, value)` entries in the given `namespace`.
+    pub fn get_entries(&self, namespace: &Identifier<N>) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+        self.storage.get_entries(namespace)
+    }
+
+    /// Returns the `(namespace, key)` pairs associated with the given block height.
+    pub fn get_block_keys(&self, height: u32) -> Result<Vec<(Identifier<N>, Vec<u8>)>> {
+        self.storage.get_block_keys(height)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::memory::ExtensionMemory;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    #[test]
+    fn test_insert_get_remove() {
+        // Initialize a new extension store.
+        let store = ExtensionStore::<CurrentNetwork, ExtensionMemory<_>>::open(None).unwrap();
+
+        let timestamps = Identifier::from_str("timestamps").unwrap();
+        let peers = Identifier::from_str("peers").unwrap();
+
+        // Insert an entry that is not associated with a block.
+        store.insert(peers, vec![0], vec![1, 2, 3]).unwrap();
+        assert!(store.contains_key(&peers, &[0]).unwrap());
+        assert!(!store.contains_key(&timestamps, &[0]).unwrap());
+        assert_eq!(store.get_value(&peers, &[0]).unwrap(), Some(vec![1, 2, 3]));
+
+        // Insert the entries of a block.
+        store.insert_for_block(0, vec![(timestamps, vec![0], vec![4]), (peers, vec![1], vec![5])]).unwrap();
+        assert_eq!(store.get_entries(&timestamps).unwrap(), vec![(vec![0], vec![4])]);
+        assert_eq!(store.get_entries(&peers).unwrap().len(), 2);
+        assert_eq!(store.get_block_keys(0).unwrap(), vec![(timestamps, vec![0]), (peers, vec![1])]);
+        assert!(store.get_block_keys(1).unwrap().is_empty());
+
+        // Remove the entries of the block.
+        store.remove_for_block(0).unwrap();
+        assert!(store.get_entries(&timestamps).unwrap().is_empty());
+        assert_eq!(store.get_entries(&peers).unwrap(), vec![(vec![0], vec![1, 2, 3])]);
+        assert!(store.get_block_keys(0).unwrap().is_empty());
+
+        // Remove the remaining entry.
+        store.remove(&peers, &[0]).unwrap();
+        assert_eq!(store.get_value(&peers, &[0]).unwrap(), None);
+    }
+}
@@ -15,6 +15,12 @@
 pub mod confirmed_tx_type;
 pub use confirmed_tx_type::*;
 
+mod extension;
+pub use extension::*;
+
+mod stats;
+pub use stats::*;
+
 use crate::{
     atomic_batch_scope,
     cow_to_cloned,
@@ -27,7 +33,7 @@ use crate::{
 };
 use console::{
     network::prelude::*,
-    program::{BlockTree, HeaderLeaf, ProgramID, StatePath},
+    program::{BlockTree, HeaderLeaf, Identifier, ProgramID, StatePath, StateRootPath},
     types::Field,
 };
 use ledger_authority::Authority;
@@ -75,6 +81,10 @@ fn to_confirmed_tuple<N: Network>(
             // Return the confirmed tuple.
             Ok((ConfirmedTxType::RejectedExecute(index, rejected), tx, finalize_operations))
         }
+        ConfirmedTransaction::AcceptedDeprecate(index, tx, finalize_operations) => {
+            // Return the confirmed tuple.
+            Ok((ConfirmedTxType::AcceptedDeprecate(index), tx, finalize_operations))
+        }
     }
 }
 
@@ -100,6 +110,10 @@ fn to_confirmed_transaction<N: Network>(
             // Return the confirmed transaction.
             ConfirmedTransaction::rejected_execute(index, transaction, rejected, finalize_operations)
         }
+        ConfirmedTxType::AcceptedDeprecate(index) => {
+            // Return the confirmed transaction.
+            ConfirmedTransaction::accepted_deprecate(index, transaction, finalize_operations)
+        }
     }
 }
 
@@ -139,6 +153,10 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
     type ConfirmedTransactionsMap: for<'a> Map<'a, N::TransactionID, (N::BlockHash, ConfirmedTxType<N>, Vec<FinalizeOperation<N>>)>;
     /// The rejected deployment or execution map.
     type RejectedDeploymentOrExecutionMap: for<'a> Map<'a, Field<N>, Rejected<N>>;
+    /// The mapping of `block height` to the cumulative `block stats` up to and including the block.
+    type StatsMap: for<'a> Map<'a, u32, BlockStats>;
+    /// The extension storage.
+    type ExtensionStorage: ExtensionStorage<N>;
     /// The transaction storage.
     type TransactionStorage: TransactionStorage<N, TransitionStorage = Self::TransitionStorage>;
     /// The transition storage.
@@ -181,6 +199,10 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
     fn confirmed_transactions_map(&self) -> &Self::ConfirmedTransactionsMap;
     /// Returns the rejected deployment or execution map.
     fn rejected_deployment_or_execution_map(&self) -> &Self::RejectedDeploymentOrExecutionMap;
+    /// Returns the stats map.
+    fn stats_map(&self) -> &Self::StatsMap;
+    /// Returns the extension store.
+    fn extension_store(&self) -> &ExtensionStore<N, Self::ExtensionStorage>;
     /// Returns the transaction store.
     fn transaction_store(&self) -> &TransactionStore<N, Self::TransactionStorage>;
 
@@ -213,6 +235,8 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().start_atomic();
         self.confirmed_transactions_map().start_atomic();
         self.rejected_deployment_or_execution_map().start_atomic();
+        self.stats_map().start_atomic();
+        self.extension_store().start_atomic();
         self.transaction_store().start_atomic();
     }
 
@@ -235,6 +259,8 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             || self.rejected_or_aborted_transaction_id_map().is_atomic_in_progress()
             || self.confirmed_transactions_map().is_atomic_in_progress()
             || self.rejected_deployment_or_execution_map().is_atomic_in_progress()
+            || self.stats_map().is_atomic_in_progress()
+            || self.extension_store().is_atomic_in_progress()
             || self.transaction_store().is_atomic_in_progress()
     }
 
@@ -257,6 +283,8 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().atomic_checkpoint();
         self.confirmed_transactions_map().atomic_checkpoint();
         self.rejected_deployment_or_execution_map().atomic_checkpoint();
+        self.stats_map().atomic_checkpoint();
+        self.extension_store().atomic_checkpoint();
         self.transaction_store().atomic_checkpoint();
     }
 
@@ -279,6 +307,8 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().clear_latest_checkpoint();
         self.confirmed_transactions_map().clear_latest_checkpoint();
         self.rejected_deployment_or_execution_map().clear_latest_checkpoint();
+        self.stats_map().clear_latest_checkpoint();
+        self.extension_store().clear_latest_checkpoint();
         self.transaction_store().clear_latest_checkpoint();
     }
 
@@ -301,6 +331,8 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().atomic_rewind();
         self.confirmed_transactions_map().atomic_rewind();
         self.rejected_deployment_or_execution_map().atomic_rewind();
+        self.stats_map().atomic_rewind();
+        self.extension_store().atomic_rewind();
         self.transaction_store().atomic_rewind();
     }
 
@@ -323,6 +355,8 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().abort_atomic();
         self.confirmed_transactions_map().abort_atomic();
         self.rejected_deployment_or_execution_map().abort_atomic();
+        self.stats_map().abort_atomic();
+        self.extension_store().abort_atomic();
         self.transaction_store().abort_atomic();
     }
 
@@ -345,6 +379,8 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().finish_atomic()?;
         self.confirmed_transactions_map().finish_atomic()?;
         self.rejected_deployment_or_execution_map().finish_atomic()?;
+        self.stats_map().finish_atomic()?;
+        self.extension_store().finish_atomic()?;
         self.transaction_store().finish_atomic()
     }
 
@@ -374,6 +410,17 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             .map(|tx| tx.to_unconfirmed_transaction_id())
             .collect::<Result<Vec<_>>>()?;
 
+        // Prepare the cumulative block stats, by adding the stats of this block to those of the previous block.
+        let stats = match block.height() {
+            0 => BlockStats::from_block(block)?,
+            height => match self.stats_map().get_confirmed(&(height - 1))? {
+                Some(previous_stats) => cow_to_copied!(previous_stats)
+                    .checked_add(&BlockStats::from_block(block)?)
+                    .ok_or_else(|| anyhow!("The block stats overflow at block {height}"))?,
+                None => bail!("Missing block stats for block {}", height - 1),
+            },
+        };
+
         atomic_batch_scope!(self, {
             // Store the (block height, state root) pair.
             self.state_root_map().insert(block.height(), state_root)?;
@@ -443,6 +490,9 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
                 self.transaction_store().insert(&transaction)?;
             }
 
+            // Store the cumulative block stats.
+            self.stats_map().insert(block.height(), stats)?;
+
             Ok(())
         })
     }
@@ -572,6 +622,12 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
                 self.transaction_store().remove(transaction_id)?;
             }
 
+            // Remove the cumulative block stats.
+            self.stats_map().remove(&block_height)?;
+
+            // Remove the extension entries stored with the block.
+            self.extension_store().remove_for_block(block_height)?;
+
             Ok(())
         })
     }
@@ -926,6 +982,14 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         }
     }
 
+    /// Returns the cumulative block stats, up to and including the given `block height`.
+    fn get_block_stats(&self, height: u32) -> Result<Option<BlockStats>> {
+        match self.stats_map().get_confirmed(&height)? {
+            Some(stats) => Ok(Some(cow_to_copied!(stats))),
+            None => Ok(None),
+        }
+    }
+
     /// Returns the block for the given `block hash`.
     fn get_block(&self, block_hash: &N::BlockHash) -> Result<Option<Block<N>>> {
         // Retrieve the block height.
@@ -1022,6 +1086,16 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
 
     /// Stores the given block into storage.
     pub fn insert(&self, block: &Block<N>) -> Result<()> {
+        self.insert_with_extensions(block, Vec::new())
+    }
+
+    /// Stores the given block into storage, along with the given `(namespace, key, value)` extension entries.
+    /// The block and the entries are written in the same atomic batch, and the entries are removed with the block.
+    pub fn insert_with_extensions(
+        &self,
+        block: &Block<N>,
+        extensions: Vec<(Identifier<N>, Vec<u8>, Vec<u8>)>,
+    ) -> Result<()> {
         // Acquire the write lock on the block tree.
         let mut tree = self.tree.write();
         // Prepare an updated Merkle tree containing the new block hash.
@@ -1030,8 +1104,15 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         if block.height() != u32::try_from(updated_tree.number_of_leaves())? - 1 {
             bail!("Attempted to insert a block at the incorrect height into storage")
         }
-        // Insert the (state root, block height) pair.
-        self.storage.insert((*updated_tree.root()).into(), block)?;
+        atomic_batch_scope!(self, {
+            // Insert the (state root, block height) pair.
+            self.storage.insert((*updated_tree.root()).into(), block)?;
+            // Insert the extension entries, if any.
+            if !extensions.is_empty() {
+                self.storage.extension_store().insert_for_block(block.height(), extensions)?;
+            }
+            Ok(())
+        })?;
         // Update the block tree.
         *tree = updated_tree;
         // Return success.
@@ -1088,6 +1169,11 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         Ok(())
     }
 
+    /// Returns the extension store.
+    pub fn extension_store(&self) -> &ExtensionStore<N, B::ExtensionStorage> {
+        self.storage.extension_store()
+    }
+
     /// Returns the transaction store.
     pub fn transaction_store(&self) -> &TransactionStore<N, B::TransactionStorage> {
         self.storage.transaction_store()
@@ -1165,6 +1251,12 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         (*self.tree.read().root()).into()
     }
 
+    /// Returns the current block height.
+    pub fn current_block_height(&self) -> u32 {
+        // Note: The block tree contains one leaf for each block, starting from the genesis block.
+        u32::try_from(self.tree.read().number_of_leaves()).unwrap_or(u32::MAX).saturating_sub(1)
+    }
+
     /// Returns the state root that contains the given `block height`.
     pub fn get_state_root(&self, block_height: u32) -> Result<Option<N::StateRoot>> {
         self.storage.get_state_root(block_height)
@@ -1175,6 +1267,56 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         self.storage.get_state_path_for_commitment(commitment, &self.tree.read())
     }
 
+    /// Returns the state root path for the given `block height`, which proves that the state root
+    /// at the given `block height` is committed to by the current state root.
+    ///
+    /// Note: The state root at the latest block height is the current state root, and is only
+    /// committed to by the block tree once the next block is inserted.
+    pub fn get_state_root_path(&self, block_height: u32) -> Result<StateRootPath<N>> {
+        // Acquire the read lock on the block tree.
+        let tree = self.tree.read();
+
+        // Retrieve the state root.
+        let Some(state_root) = self.get_state_root(block_height)? else {
+            bail!("The state root for block {block_height} is missing in storage")
+        };
+        // Retrieve the next block hash, which commits to the state root in its block header.
+        let next_height = block_height.checked_add(1).ok_or_else(|| anyhow!("Block height {block_height} overflowed"))?;
+        let Some(block_hash) = self.get_block_hash(next_height)? else {
+            bail!("The state root for block {block_height} is not yet committed to by a subsequent block")
+        };
+        // Retrieve the block hash at the given block height, which is the previous block hash of the next block.
+        let Some(previous_block_hash) = self.get_block_hash(block_height)? else {
+            bail!("Missing block hash for block {block_height}")
+        };
+        // Retrieve the next block header.
+        let Some(header) = self.get_block_header(&block_hash)? else {
+            bail!("Missing block header for block {next_height} ('{block_hash}')")
+        };
+        // Ensure the block header commits to the state root.
+        ensure!(
+            header.previous_state_root() == state_root,
+            "The block header for block {next_height} does not commit to the state root for block {block_height}"
+        );
+
+        // Construct the block header path.
+        let header_root = header.to_root()?;
+        let header_leaf = HeaderLeaf::<N>::new(0, *state_root);
+        let header_path = header.to_path(&header_leaf)?;
+        // Construct the block path.
+        let block_path = tree.prove(next_height as usize, &block_hash.to_bits_le())?;
+
+        Ok(StateRootPath::from(
+            (*tree.root()).into(),
+            block_path,
+            block_hash,
+            previous_block_hash,
+            header_root,
+            header_path,
+            header_leaf,
+        ))
+    }
+
     /// Returns the previous block hash of the given `block height`.
     pub fn get_previous_block_hash(&self, height: u32) -> Result<Option<N::BlockHash>> {
         self.storage.get_previous_block_hash(height)
@@ -1251,6 +1393,11 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         self.storage.get_block(block_hash)
     }
 
+    /// Returns the cumulative block stats, up to and including the given `block height`.
+    pub fn get_block_stats(&self, height: u32) -> Result<Option<BlockStats>> {
+        self.storage.get_block_stats(height)
+    }
+
     /// Returns the program for the given `program ID`.
     pub fn get_program(&self, program_id: &ProgramID<N>) -> Result<Option<Program<N>>> {
         self.storage.transaction_store().get_program(program_id)
@@ -1369,6 +1516,30 @@ mod tests {
         assert_eq!(None, candidate);
     }
 
+    #[test]
+    fn test_insert_remove_with_extensions() {
+        let rng = &mut TestRng::default();
+
+        // Sample the block.
+        let block = ledger_test_helpers::sample_genesis_block(rng);
+
+        // Initialize a new block store.
+        let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+        let namespace = Identifier::from_str("timestamps").unwrap();
+
+        // Insert the block with an extension entry.
+        block_store.insert_with_extensions(&block, vec![(namespace, vec![0], vec![1, 2, 3])]).unwrap();
+        assert_eq!(block_store.extension_store().get_value(&namespace, &[0]).unwrap(), Some(vec![1, 2, 3]));
+        assert_eq!(block_store.extension_store().get_block_keys(0).unwrap(), vec![(namespace, vec![0])]);
+
+        // Remove the block.
+        block_store.remove_last_n(1).unwrap();
+
+        // Ensure the extension entry was removed with the block.
+        assert_eq!(block_store.extension_store().get_value(&namespace, &[0]).unwrap(), None);
+        assert!(block_store.extension_store().get_block_keys(0).unwrap().is_empty());
+    }
+
     #[test]
     fn test_find_block_hash() {
         let rng = &mut TestRng::default();
@@ -0,0 +1,138 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use serde::{Deserialize, Serialize};
+
+/// The statistics of a sequence of blocks.
+///
+/// The block store maintains these statistics cumulatively, i.e. the statistics stored for a
+/// block height cover every block from genesis up to and including that height. This allows the
+/// statistics for any range of blocks to be computed from two lookups, without scanning the blocks.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct BlockStats {
+    /// The number of blocks.
+    num_blocks: u64,
+    /// The number of confirmed transactions, including rejected transactions.
+    num_transactions: u64,
+    /// The number of rejected transactions.
+    num_rejected_transactions: u64,
+    /// The number of aborted transactions.
+    num_aborted_transactions: u64,
+    /// The number of transitions in the confirmed transactions.
+    num_transitions: u64,
+    /// The number of finalize operations.
+    num_finalize_operations: u64,
+    /// The number of prover solutions.
+    num_solutions: u64,
+    /// The number of aborted prover solutions.
+    num_aborted_solutions: u64,
+    /// The total fees paid, in microcredits.
+    total_fees: u64,
+}
+
+impl BlockStats {
+    /// Returns the statistics of the given block.
+    pub fn from_block<N: Network>(block: &Block<N>) -> Result<Self> {
+        // Compute the total fees paid in the block.
+        let total_fees = block.transactions().iter().try_fold(0u64, |total, confirmed| {
+            total
+                .checked_add(*confirmed.fee_amount()?)
+                .ok_or_else(|| anyhow!("The total fees in block {} overflow", block.height()))
+        })?;
+
+        Ok(Self {
+            num_blocks: 1,
+            num_transactions: block.transactions().len() as u64,
+            num_rejected_transactions: block.transactions().num_rejected() as u64,
+            num_aborted_transactions: block.aborted_transaction_ids().len() as u64,
+            num_transitions: block.transitions().count() as u64,
+            num_finalize_operations: block.transactions().num_finalize() as u64,
+            num_solutions: block.solutions().len() as u64,
+            num_aborted_solutions: block.aborted_solution_ids().len() as u64,
+            total_fees,
+        })
+    }
+
+    /// Returns the number of blocks.
+    pub const fn num_blocks(&self) -> u64 {
+        self.num_blocks
+    }
+
+    /// Returns the number of confirmed transactions, including rejected transactions.
+    pub const fn num_transactions(&self) -> u64 {
+        self.num_transactions
+    }
+
+    /// Returns the number of rejected transactions.
+    pub const fn num_rejected_transactions(&self) -> u64 {
+        self.num_rejected_transactions
+    }
+
+    /// Returns the number of aborted transactions.
+    pub const fn num_aborted_transactions(&self) -> u64 {
+        self.num_aborted_transactions
+    }
+
+    /// Returns the number of transitions in the confirmed transactions.
+    pub const fn num_transitions(&self) -> u64 {
+        self.num_transitions
+    }
+
+    /// Returns the number of finalize operations.
+    pub const fn num_finalize_operations(&self) -> u64 {
+        self.num_finalize_operations
+    }
+
+    /// Returns the number of prover solutions.
+    pub const fn num_solutions(&self) -> u64 {
+        self.num_solutions
+    }
+
+    /// Returns the number of aborted prover solutions.
+    pub const fn num_aborted_solutions(&self) -> u64 {
+        self.num_aborted_solutions
+    }
+
+    /// Returns the total fees paid, in microcredits.
+    pub const fn total_fees(&self) -> u64 {
+        self.total_fees
+    }
+
+    /// Returns the sum of `self` and `other`, or `None` if any statistic overflows.
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        self.checked_combine(other, u64::checked_add)
+    }
+
+    /// Returns the difference of `self` and `other`, or `None` if any statistic underflows.
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        self.checked_combine(other, u64::checked_sub)
+    }
+
+    /// Combines each statistic of `self` and `other` with the given operation.
+    fn checked_combine(&self, other: &Self, op: fn(u64, u64) -> Option<u64>) -> Option<Self> {
+        Some(Self {
+            num_blocks: op(self.num_blocks, other.num_blocks)?,
+            num_transactions: op(self.num_transactions, other.num_transactions)?,
+            num_rejected_transactions: op(self.num_rejected_transactions, other.num_rejected_transactions)?,
+            num_aborted_transactions: op(self.num_aborted_transactions, other.num_aborted_transactions)?,
+            num_transitions: op(self.num_transitions, other.num_transitions)?,
+            num_finalize_operations: op(self.num_finalize_operations, other.num_finalize_operations)?,
+            num_solutions: op(self.num_solutions, other.num_solutions)?,
+            num_aborted_solutions: op(self.num_aborted_solutions, other.num_aborted_solutions)?,
+            total_fees: op(self.total_fees, other.total_fees)?,
+        })
+    }
+}
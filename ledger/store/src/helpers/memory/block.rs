@@ -13,13 +13,16 @@
 // limitations under the License.
 
 use crate::{
-    helpers::memory::{MemoryMap, TransactionMemory, TransitionMemory},
+    helpers::memory::{MemoryMap, NestedMemoryMap, TransactionMemory, TransitionMemory},
+    BlockStats,
     BlockStorage,
     ConfirmedTxType,
+    ExtensionStorage,
+    ExtensionStore,
     TransactionStore,
     TransitionStore,
 };
-use console::{prelude::*, types::Field};
+use console::{prelude::*, program::Identifier, types::Field};
 use ledger_authority::Authority;
 use ledger_block::{Header, Ratifications, Rejected, Solutions};
 use ledger_coinbase::PuzzleCommitment;
@@ -66,6 +69,10 @@ pub struct BlockMemory<N: Network> {
         MemoryMap<N::TransactionID, (N::BlockHash, ConfirmedTxType<N>, Vec<FinalizeOperation<N>>)>,
     /// The rejected deployment or execution map.
     rejected_deployment_or_execution_map: MemoryMap<Field<N>, Rejected<N>>,
+    /// The mapping of `block height` to cumulative `block stats`.
+    stats_map: MemoryMap<u32, BlockStats>,
+    /// The extension store.
+    extension_store: ExtensionStore<N, ExtensionMemory<N>>,
     /// The transaction store.
     transaction_store: TransactionStore<N, TransactionMemory<N>>,
 }
@@ -89,15 +96,19 @@ impl<N: Network> BlockStorage<N> for BlockMemory<N> {
     type RejectedOrAbortedTransactionIDMap = MemoryMap<N::TransactionID, N::BlockHash>;
     type ConfirmedTransactionsMap = MemoryMap<N::TransactionID, (N::BlockHash, ConfirmedTxType<N>, Vec<FinalizeOperation<N>>)>;
     type RejectedDeploymentOrExecutionMap = MemoryMap<Field<N>, Rejected<N>>;
+    type StatsMap = MemoryMap<u32, BlockStats>;
+    type ExtensionStorage = ExtensionMemory<N>;
     type TransactionStorage = TransactionMemory<N>;
     type TransitionStorage = TransitionMemory<N>;
 
     /// Initializes the block storage.
     fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
         // Initialize the transition store.
-        let transition_store = TransitionStore::<N, TransitionMemory<N>>::open(storage)?;
+        let transition_store = TransitionStore::<N, TransitionMemory<N>>::open(storage.clone())?;
         // Initialize the transaction store.
         let transaction_store = TransactionStore::<N, TransactionMemory<N>>::open(transition_store)?;
+        // Initialize the extension store.
+        let extension_store = ExtensionStore::<N, ExtensionMemory<N>>::open(storage)?;
         // Return the block storage.
         Ok(Self {
             state_root_map: MemoryMap::default(),
@@ -117,6 +128,8 @@ impl<N: Network> BlockStorage<N> for BlockMemory<N> {
             rejected_or_aborted_transaction_id_map: MemoryMap::default(),
             confirmed_transactions_map: MemoryMap::default(),
             rejected_deployment_or_execution_map: MemoryMap::default(),
+            stats_map: MemoryMap::default(),
+            extension_store,
             transaction_store,
         })
     }
@@ -206,8 +219,60 @@ impl<N: Network> BlockStorage<N> for BlockMemory<N> {
         &self.rejected_deployment_or_execution_map
     }
 
+    /// Returns the stats map.
+    fn stats_map(&self) -> &Self::StatsMap {
+        &self.stats_map
+    }
+
+    /// Returns the extension store.
+    fn extension_store(&self) -> &ExtensionStore<N, Self::ExtensionStorage> {
+        &self.extension_store
+    }
+
     /// Returns the transaction store.
     fn transaction_store(&self) -> &TransactionStore<N, Self::TransactionStorage> {
         &self.transaction_store
     }
 }
+
+/// An in-memory extension storage.
+#[derive(Clone)]
+#[allow(clippy::type_complexity)]
+pub struct ExtensionMemory<N: Network> {
+    /// The extension map.
+    extension_map: NestedMemoryMap<Identifier<N>, Vec<u8>, Vec<u8>>,
+    /// The block extensions map.
+    block_extensions_map: MemoryMap<u32, Vec<(Identifier<N>, Vec<u8>)>>,
+    /// The storage mode.
+    storage_mode: StorageMode,
+}
+
+#[rustfmt::skip]
+impl<N: Network> ExtensionStorage<N> for ExtensionMemory<N> {
+    type ExtensionMap = NestedMemoryMap<Identifier<N>, Vec<u8>, Vec<u8>>;
+    type BlockExtensionsMap = MemoryMap<u32, Vec<(Identifier<N>, Vec<u8>)>>;
+
+    /// Initializes the extension storage.
+    fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
+        Ok(Self {
+            extension_map: NestedMemoryMap::default(),
+            block_extensions_map: MemoryMap::default(),
+            storage_mode: storage.into(),
+        })
+    }
+
+    /// Returns the extension map.
+    fn extension_map(&self) -> &Self::ExtensionMap {
+        &self.extension_map
+    }
+
+    /// Returns the block extensions map.
+    fn block_extensions_map(&self) -> &Self::BlockExtensionsMap {
+        &self.block_extensions_map
+    }
+
+    /// Returns the storage mode.
+    fn storage_mode(&self) -> &StorageMode {
+        &self.storage_mode
+    }
+}
@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use crate::{
-    helpers::memory::{MemoryMap, TransitionMemory},
+    helpers::memory::{MemoryMap, NestedMemoryMap, TransitionMemory},
     DeploymentStorage,
     DeploymentStore,
     ExecutionStorage,
@@ -25,12 +25,16 @@ use crate::{
     TransitionStore,
 };
 use console::{
+    account::Address,
     prelude::*,
     program::{Identifier, ProgramID, ProgramOwner},
 };
+use ledger_block::MappingPolicy;
 use synthesizer_program::Program;
 use synthesizer_snark::{Certificate, Proof, VerifyingKey};
 
+use indexmap::IndexSet;
+
 /// An in-memory transaction storage.
 #[derive(Clone)]
 pub struct TransactionMemory<N: Network> {
@@ -103,6 +107,14 @@ pub struct DeploymentMemory<N: Network> {
     verifying_key_map: MemoryMap<(ProgramID<N>, Identifier<N>, u16), VerifyingKey<N>>,
     /// The certificate map.
     certificate_map: MemoryMap<(ProgramID<N>, Identifier<N>, u16), Certificate<N>>,
+    /// The deprecation map.
+    deprecation_map: MemoryMap<N::TransactionID, (ProgramID<N>, ProgramOwner<N>, MappingPolicy)>,
+    /// The reverse deprecation map.
+    reverse_deprecation_map: MemoryMap<ProgramID<N>, N::TransactionID>,
+    /// The dependents map.
+    dependents_map: NestedMemoryMap<ProgramID<N>, ProgramID<N>, ()>,
+    /// The owned programs map.
+    owned_programs_map: MemoryMap<Address<N>, IndexSet<ProgramID<N>>>,
     /// The fee store.
     fee_store: FeeStore<N, FeeMemory<N>>,
 }
@@ -116,6 +128,10 @@ impl<N: Network> DeploymentStorage<N> for DeploymentMemory<N> {
     type ProgramMap = MemoryMap<(ProgramID<N>, u16), Program<N>>;
     type VerifyingKeyMap = MemoryMap<(ProgramID<N>, Identifier<N>, u16), VerifyingKey<N>>;
     type CertificateMap = MemoryMap<(ProgramID<N>, Identifier<N>, u16), Certificate<N>>;
+    type DeprecationMap = MemoryMap<N::TransactionID, (ProgramID<N>, ProgramOwner<N>, MappingPolicy)>;
+    type ReverseDeprecationMap = MemoryMap<ProgramID<N>, N::TransactionID>;
+    type DependentsMap = NestedMemoryMap<ProgramID<N>, ProgramID<N>, ()>;
+    type OwnedProgramsMap = MemoryMap<Address<N>, IndexSet<ProgramID<N>>>;
     type FeeStorage = FeeMemory<N>;
 
     /// Initializes the deployment storage.
@@ -128,6 +144,10 @@ impl<N: Network> DeploymentStorage<N> for DeploymentMemory<N> {
             program_map: MemoryMap::default(),
             verifying_key_map: MemoryMap::default(),
             certificate_map: MemoryMap::default(),
+            deprecation_map: MemoryMap::default(),
+            reverse_deprecation_map: MemoryMap::default(),
+            dependents_map: NestedMemoryMap::default(),
+            owned_programs_map: MemoryMap::default(),
             fee_store,
         })
     }
@@ -167,6 +187,26 @@ impl<N: Network> DeploymentStorage<N> for DeploymentMemory<N> {
         &self.certificate_map
     }
 
+    /// Returns the deprecation map.
+    fn deprecation_map(&self) -> &Self::DeprecationMap {
+        &self.deprecation_map
+    }
+
+    /// Returns the reverse deprecation map.
+    fn reverse_deprecation_map(&self) -> &Self::ReverseDeprecationMap {
+        &self.reverse_deprecation_map
+    }
+
+    /// Returns the dependents map.
+    fn dependents_map(&self) -> &Self::DependentsMap {
+        &self.dependents_map
+    }
+
+    /// Returns the owned programs map.
+    fn owned_programs_map(&self) -> &Self::OwnedProgramsMap {
+        &self.owned_programs_map
+    }
+
     /// Returns the fee store.
     fn fee_store(&self) -> &FeeStore<N, Self::FeeStorage> {
         &self.fee_store
@@ -183,6 +223,8 @@ pub struct ExecutionMemory<N: Network> {
     reverse_id_map: MemoryMap<N::TransitionID, N::TransactionID>,
     /// The inclusion map.
     inclusion_map: MemoryMap<N::TransactionID, (N::StateRoot, Option<Proof<N>>)>,
+    /// The sequence map.
+    sequence_map: MemoryMap<N::TransactionID, u64>,
     /// The fee store.
     fee_store: FeeStore<N, FeeMemory<N>>,
 }
@@ -192,6 +234,7 @@ impl<N: Network> ExecutionStorage<N> for ExecutionMemory<N> {
     type IDMap = MemoryMap<N::TransactionID, (Vec<N::TransitionID>, bool)>;
     type ReverseIDMap = MemoryMap<N::TransitionID, N::TransactionID>;
     type InclusionMap = MemoryMap<N::TransactionID, (N::StateRoot, Option<Proof<N>>)>;
+    type SequenceMap = MemoryMap<N::TransactionID, u64>;
     type FeeStorage = FeeMemory<N>;
 
     /// Initializes the execution storage.
@@ -200,6 +243,7 @@ impl<N: Network> ExecutionStorage<N> for ExecutionMemory<N> {
             id_map: MemoryMap::default(),
             reverse_id_map: MemoryMap::default(),
             inclusion_map: MemoryMap::default(),
+            sequence_map: MemoryMap::default(),
             fee_store
         })
     }
@@ -219,6 +263,11 @@ impl<N: Network> ExecutionStorage<N> for ExecutionMemory<N> {
         &self.inclusion_map
     }
 
+    /// Returns the sequence map.
+    fn sequence_map(&self) -> &Self::SequenceMap {
+        &self.sequence_map
+    }
+
     /// Returns the fee store.
     fn fee_store(&self) -> &FeeStore<N, Self::FeeStorage> {
         &self.fee_store
@@ -16,7 +16,7 @@ use crate::{helpers::memory::MemoryMap, InputStorage, InputStore, OutputStorage,
 use console::{
     prelude::*,
     program::{Ciphertext, Future, Identifier, Plaintext, ProgramID, Record},
-    types::{Field, Group},
+    types::{Field, Group, U32},
 };
 
 use aleo_std_storage::StorageMode;
@@ -125,6 +125,8 @@ pub struct InputMemory<N: Network> {
     record: MemoryMap<Field<N>, Field<N>>,
     /// The mapping of `record tag` to `serial number`.
     record_tag: MemoryMap<Field<N>, Field<N>>,
+    /// The mapping of `serial number` to `lock height`, for time-locked records.
+    record_lock_height: MemoryMap<Field<N>, U32<N>>,
     /// The mapping of `external hash` to `()`. Note: This is **not** the record commitment.
     external_record: MemoryMap<Field<N>, ()>,
     /// The storage mode.
@@ -140,6 +142,7 @@ impl<N: Network> InputStorage<N> for InputMemory<N> {
     type PrivateMap = MemoryMap<Field<N>, Option<Ciphertext<N>>>;
     type RecordMap = MemoryMap<Field<N>, Field<N>>;
     type RecordTagMap = MemoryMap<Field<N>, Field<N>>;
+    type RecordLockHeightMap = MemoryMap<Field<N>, U32<N>>;
     type ExternalRecordMap = MemoryMap<Field<N>, ()>;
 
     /// Initializes the transition input storage.
@@ -152,6 +155,7 @@ impl<N: Network> InputStorage<N> for InputMemory<N> {
             private: MemoryMap::default(),
             record: MemoryMap::default(),
             record_tag: MemoryMap::default(),
+            record_lock_height: MemoryMap::default(),
             external_record: MemoryMap::default(),
             storage_mode: storage.into(),
         })
@@ -192,6 +196,11 @@ impl<N: Network> InputStorage<N> for InputMemory<N> {
         &self.record_tag
     }
 
+    /// Returns the record lock height map.
+    fn record_lock_height_map(&self) -> &Self::RecordLockHeightMap {
+        &self.record_lock_height
+    }
+
     /// Returns the external record map.
     fn external_record_map(&self) -> &Self::ExternalRecordMap {
         &self.external_record
@@ -221,6 +230,8 @@ pub struct OutputMemory<N: Network> {
     record: MemoryMap<Field<N>, (Field<N>, Option<Record<N, Ciphertext<N>>>)>,
     /// The mapping of `record nonce` to `commitment`.
     record_nonce: MemoryMap<Group<N>, Field<N>>,
+    /// The mapping of `commitment` to `view tag`.
+    record_view_tag: MemoryMap<Field<N>, u8>,
     /// The mapping of `external hash` to `()`. Note: This is **not** the record commitment.
     external_record: MemoryMap<Field<N>, ()>,
     /// The mapping of `future hash` to `(optional) future`.
@@ -238,6 +249,7 @@ impl<N: Network> OutputStorage<N> for OutputMemory<N> {
     type PrivateMap = MemoryMap<Field<N>, Option<Ciphertext<N>>>;
     type RecordMap = MemoryMap<Field<N>, (Field<N>, Option<Record<N, Ciphertext<N>>>)>;
     type RecordNonceMap = MemoryMap<Group<N>, Field<N>>;
+    type RecordViewTagMap = MemoryMap<Field<N>, u8>;
     type ExternalRecordMap = MemoryMap<Field<N>, ()>;
     type FutureMap = MemoryMap<Field<N>, Option<Future<N>>>;
 
@@ -251,6 +263,7 @@ impl<N: Network> OutputStorage<N> for OutputMemory<N> {
             private: Default::default(),
             record: Default::default(),
             record_nonce: Default::default(),
+            record_view_tag: Default::default(),
             external_record: Default::default(),
             future: Default::default(),
             storage_mode: storage.into(),
@@ -292,6 +305,11 @@ impl<N: Network> OutputStorage<N> for OutputMemory<N> {
         &self.record_nonce
     }
 
+    /// Returns the record view tag map.
+    fn record_view_tag_map(&self) -> &Self::RecordViewTagMap {
+        &self.record_view_tag
+    }
+
     /// Returns the external record map.
     fn external_record_map(&self) -> &Self::ExternalRecordMap {
         &self.external_record
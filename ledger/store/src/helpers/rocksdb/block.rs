@@ -14,18 +14,22 @@
 
 use crate::{
     helpers::rocksdb::{
-        internal::{self, DataMap, Database},
+        internal::{self, DataMap, Database, NestedDataMap},
         BlockMap,
+        ExtensionMap,
         MapID,
         TransactionDB,
         TransitionDB,
     },
+    BlockStats,
     BlockStorage,
     ConfirmedTxType,
+    ExtensionStorage,
+    ExtensionStore,
     TransactionStore,
     TransitionStore,
 };
-use console::{prelude::*, types::Field};
+use console::{prelude::*, program::Identifier, types::Field};
 use ledger_authority::Authority;
 use ledger_block::{Header, Ratifications, Rejected, Solutions};
 use ledger_coinbase::PuzzleCommitment;
@@ -72,6 +76,10 @@ pub struct BlockDB<N: Network> {
         DataMap<N::TransactionID, (N::BlockHash, ConfirmedTxType<N>, Vec<FinalizeOperation<N>>)>,
     /// The rejected deployment or execution map.
     rejected_deployment_or_execution_map: DataMap<Field<N>, Rejected<N>>,
+    /// The mapping of `block height` to cumulative `block stats`.
+    stats_map: DataMap<u32, BlockStats>,
+    /// The extension store.
+    extension_store: ExtensionStore<N, ExtensionDB<N>>,
     /// The transaction store.
     transaction_store: TransactionStore<N, TransactionDB<N>>,
 }
@@ -95,6 +103,8 @@ impl<N: Network> BlockStorage<N> for BlockDB<N> {
     type RejectedOrAbortedTransactionIDMap = DataMap<N::TransactionID, N::BlockHash>;
     type ConfirmedTransactionsMap = DataMap<N::TransactionID, (N::BlockHash, ConfirmedTxType<N>, Vec<FinalizeOperation<N>>)>;
     type RejectedDeploymentOrExecutionMap = DataMap<Field<N>, Rejected<N>>;
+    type StatsMap = DataMap<u32, BlockStats>;
+    type ExtensionStorage = ExtensionDB<N>;
     type TransactionStorage = TransactionDB<N>;
     type TransitionStorage = TransitionDB<N>;
 
@@ -104,6 +114,8 @@ impl<N: Network> BlockStorage<N> for BlockDB<N> {
         let transition_store = TransitionStore::<N, TransitionDB<N>>::open(storage.clone())?;
         // Initialize the transaction store.
         let transaction_store = TransactionStore::<N, TransactionDB<N>>::open(transition_store)?;
+        // Initialize the extension store.
+        let extension_store = ExtensionStore::<N, ExtensionDB<N>>::open(storage.clone())?;
         // Return the block storage.
         Ok(Self {
             state_root_map: internal::RocksDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::StateRoot))?,
@@ -122,7 +134,9 @@ impl<N: Network> BlockStorage<N> for BlockDB<N> {
             aborted_transaction_ids_map: internal::RocksDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::AbortedTransactionIDs))?,
             rejected_or_aborted_transaction_id_map: internal::RocksDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::RejectedOrAbortedTransactionID))?,
             confirmed_transactions_map: internal::RocksDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::ConfirmedTransactions))?,
-            rejected_deployment_or_execution_map: internal::RocksDB::open_map(N::ID, storage, MapID::Block(BlockMap::RejectedDeploymentOrExecution))?,
+            rejected_deployment_or_execution_map: internal::RocksDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::RejectedDeploymentOrExecution))?,
+            stats_map: internal::RocksDB::open_map(N::ID, storage, MapID::Block(BlockMap::Stats))?,
+            extension_store,
             transaction_store,
         })
     }
@@ -212,8 +226,60 @@ impl<N: Network> BlockStorage<N> for BlockDB<N> {
         &self.rejected_deployment_or_execution_map
     }
 
+    /// Returns the stats map.
+    fn stats_map(&self) -> &Self::StatsMap {
+        &self.stats_map
+    }
+
+    /// Returns the extension store.
+    fn extension_store(&self) -> &ExtensionStore<N, Self::ExtensionStorage> {
+        &self.extension_store
+    }
+
     /// Returns the transaction store.
     fn transaction_store(&self) -> &TransactionStore<N, Self::TransactionStorage> {
         &self.transaction_store
     }
 }
+
+/// A RocksDB extension storage.
+#[derive(Clone)]
+#[allow(clippy::type_complexity)]
+pub struct ExtensionDB<N: Network> {
+    /// The extension map.
+    extension_map: NestedDataMap<Identifier<N>, Vec<u8>, Vec<u8>>,
+    /// The block extensions map.
+    block_extensions_map: DataMap<u32, Vec<(Identifier<N>, Vec<u8>)>>,
+    /// The storage mode.
+    storage_mode: StorageMode,
+}
+
+#[rustfmt::skip]
+impl<N: Network> ExtensionStorage<N> for ExtensionDB<N> {
+    type ExtensionMap = NestedDataMap<Identifier<N>, Vec<u8>, Vec<u8>>;
+    type BlockExtensionsMap = DataMap<u32, Vec<(Identifier<N>, Vec<u8>)>>;
+
+    /// Initializes the extension storage.
+    fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
+        Ok(Self {
+            extension_map: internal::RocksDB::open_nested_map(N::ID, storage.clone(), MapID::Extension(ExtensionMap::Extension))?,
+            block_extensions_map: internal::RocksDB::open_map(N::ID, storage.clone(), MapID::Extension(ExtensionMap::BlockExtensions))?,
+            storage_mode: storage.into(),
+        })
+    }
+
+    /// Returns the extension map.
+    fn extension_map(&self) -> &Self::ExtensionMap {
+        &self.extension_map
+    }
+
+    /// Returns the block extensions map.
+    fn block_extensions_map(&self) -> &Self::BlockExtensionsMap {
+        &self.block_extensions_map
+    }
+
+    /// Returns the storage mode.
+    fn storage_mode(&self) -> &StorageMode {
+        &self.storage_mode
+    }
+}
@@ -22,6 +22,7 @@ pub enum MapID {
     Committee(CommitteeMap),
     Deployment(DeploymentMap),
     Execution(ExecutionMap),
+    Extension(ExtensionMap),
     Fee(FeeMap),
     Transaction(TransactionMap),
     Transition(TransitionMap),
@@ -40,6 +41,7 @@ impl From<MapID> for u16 {
             MapID::Committee(id) => id as u16,
             MapID::Deployment(id) => id as u16,
             MapID::Execution(id) => id as u16,
+            MapID::Extension(id) => id as u16,
             MapID::Fee(id) => id as u16,
             MapID::Transaction(id) => id as u16,
             MapID::Transition(id) => id as u16,
@@ -84,6 +86,7 @@ pub enum BlockMap {
     RejectedOrAbortedTransactionID = DataID::BlockRejectedOrAbortedTransactionIDMap as u16,
     ConfirmedTransactions = DataID::BlockConfirmedTransactionsMap as u16,
     RejectedDeploymentOrExecution = DataID::BlockRejectedDeploymentOrExecutionMap as u16,
+    Stats = DataID::BlockStatsMap as u16,
 }
 
 /// The RocksDB map prefix for committee-related entries.
@@ -110,6 +113,10 @@ pub enum DeploymentMap {
     Program = DataID::DeploymentProgramMap as u16,
     VerifyingKey = DataID::DeploymentVerifyingKeyMap as u16,
     Certificate = DataID::DeploymentCertificateMap as u16,
+    Deprecation = DataID::DeploymentDeprecationMap as u16,
+    ReverseDeprecation = DataID::DeploymentReverseDeprecationMap as u16,
+    Dependents = DataID::DeploymentDependentsMap as u16,
+    OwnedPrograms = DataID::DeploymentOwnedProgramsMap as u16,
 }
 
 /// The RocksDB map prefix for execution-related entries.
@@ -121,6 +128,17 @@ pub enum ExecutionMap {
     ID = DataID::ExecutionIDMap as u16,
     ReverseID = DataID::ExecutionReverseIDMap as u16,
     Inclusion = DataID::ExecutionInclusionMap as u16,
+    Sequence = DataID::ExecutionSequenceMap as u16,
+}
+
+/// The RocksDB map prefix for extension-related entries.
+// Note: the order of these variants can be changed at any point in time,
+// as long as the corresponding DataID values remain the same.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u16)]
+pub enum ExtensionMap {
+    Extension = DataID::ExtensionMap as u16,
+    BlockExtensions = DataID::ExtensionBlockExtensionsMap as u16,
 }
 
 /// The RocksDB map prefix for fee-related entries.
@@ -146,6 +164,7 @@ pub enum TransitionInputMap {
     Private = DataID::InputPrivateMap as u16,
     Record = DataID::InputRecordMap as u16,
     RecordTag = DataID::InputRecordTagMap as u16,
+    RecordLockHeight = DataID::InputRecordLockHeightMap as u16,
     ExternalRecord = DataID::InputExternalRecordMap as u16,
 }
 
@@ -162,6 +181,7 @@ pub enum TransitionOutputMap {
     Private = DataID::OutputPrivateMap as u16,
     Record = DataID::OutputRecordMap as u16,
     RecordNonce = DataID::OutputRecordNonceMap as u16,
+    RecordViewTag = DataID::OutputRecordViewTagMap as u16,
     ExternalRecord = DataID::OutputExternalRecordMap as u16,
     Future = DataID::OutputFutureMap as u16,
 }
@@ -290,6 +310,24 @@ enum DataID {
     // Program
     ProgramIDMap,
     KeyValueMap,
+    // Deployment
+    DeploymentDeprecationMap,
+    DeploymentReverseDeprecationMap,
+    // Output
+    OutputRecordViewTagMap,
+    // Block
+    BlockStatsMap,
+    // Deployment
+    DeploymentDependentsMap,
+    // Extension
+    ExtensionMap,
+    ExtensionBlockExtensionsMap,
+    // Deployment
+    DeploymentOwnedProgramsMap,
+    // Execution
+    ExecutionSequenceMap,
+    // Input
+    InputRecordLockHeightMap,
 
     // Testing
     #[cfg(test)]
@@ -21,6 +21,7 @@ use crate::{
         ExecutionMap,
         FeeMap,
         MapID,
+        NestedDataMap,
         TransactionMap,
         TransitionDB,
     },
@@ -35,12 +36,16 @@ use crate::{
     TransitionStore,
 };
 use console::{
+    account::Address,
     prelude::*,
     program::{Identifier, ProgramID, ProgramOwner},
 };
+use ledger_block::MappingPolicy;
 use synthesizer_program::Program;
 use synthesizer_snark::{Certificate, Proof, VerifyingKey};
 
+use indexmap::IndexSet;
+
 /// A database transaction storage.
 #[derive(Clone)]
 pub struct TransactionDB<N: Network> {
@@ -113,6 +118,14 @@ pub struct DeploymentDB<N: Network> {
     verifying_key_map: DataMap<(ProgramID<N>, Identifier<N>, u16), VerifyingKey<N>>,
     /// The certificate map.
     certificate_map: DataMap<(ProgramID<N>, Identifier<N>, u16), Certificate<N>>,
+    /// The deprecation map.
+    deprecation_map: DataMap<N::TransactionID, (ProgramID<N>, ProgramOwner<N>, MappingPolicy)>,
+    /// The reverse deprecation map.
+    reverse_deprecation_map: DataMap<ProgramID<N>, N::TransactionID>,
+    /// The dependents map.
+    dependents_map: NestedDataMap<ProgramID<N>, ProgramID<N>, ()>,
+    /// The owned programs map.
+    owned_programs_map: DataMap<Address<N>, IndexSet<ProgramID<N>>>,
     /// The fee store.
     fee_store: FeeStore<N, FeeDB<N>>,
 }
@@ -126,6 +139,10 @@ impl<N: Network> DeploymentStorage<N> for DeploymentDB<N> {
     type ProgramMap = DataMap<(ProgramID<N>, u16), Program<N>>;
     type VerifyingKeyMap = DataMap<(ProgramID<N>, Identifier<N>, u16), VerifyingKey<N>>;
     type CertificateMap = DataMap<(ProgramID<N>, Identifier<N>, u16), Certificate<N>>;
+    type DeprecationMap = DataMap<N::TransactionID, (ProgramID<N>, ProgramOwner<N>, MappingPolicy)>;
+    type ReverseDeprecationMap = DataMap<ProgramID<N>, N::TransactionID>;
+    type DependentsMap = NestedDataMap<ProgramID<N>, ProgramID<N>, ()>;
+    type OwnedProgramsMap = DataMap<Address<N>, IndexSet<ProgramID<N>>>;
     type FeeStorage = FeeDB<N>;
 
     /// Initializes the deployment storage.
@@ -140,6 +157,10 @@ impl<N: Network> DeploymentStorage<N> for DeploymentDB<N> {
             program_map: rocksdb::RocksDB::open_map(N::ID, storage_mode.clone(), MapID::Deployment(DeploymentMap::Program))?,
             verifying_key_map: rocksdb::RocksDB::open_map(N::ID, storage_mode.clone(), MapID::Deployment(DeploymentMap::VerifyingKey))?,
             certificate_map: rocksdb::RocksDB::open_map(N::ID, storage_mode.clone(), MapID::Deployment(DeploymentMap::Certificate))?,
+            deprecation_map: rocksdb::RocksDB::open_map(N::ID, storage_mode.clone(), MapID::Deployment(DeploymentMap::Deprecation))?,
+            reverse_deprecation_map: rocksdb::RocksDB::open_map(N::ID, storage_mode.clone(), MapID::Deployment(DeploymentMap::ReverseDeprecation))?,
+            dependents_map: rocksdb::RocksDB::open_nested_map(N::ID, storage_mode.clone(), MapID::Deployment(DeploymentMap::Dependents))?,
+            owned_programs_map: rocksdb::RocksDB::open_map(N::ID, storage_mode.clone(), MapID::Deployment(DeploymentMap::OwnedPrograms))?,
             fee_store,
         })
     }
@@ -179,6 +200,26 @@ impl<N: Network> DeploymentStorage<N> for DeploymentDB<N> {
         &self.certificate_map
     }
 
+    /// Returns the deprecation map.
+    fn deprecation_map(&self) -> &Self::DeprecationMap {
+        &self.deprecation_map
+    }
+
+    /// Returns the reverse deprecation map.
+    fn reverse_deprecation_map(&self) -> &Self::ReverseDeprecationMap {
+        &self.reverse_deprecation_map
+    }
+
+    /// Returns the dependents map.
+    fn dependents_map(&self) -> &Self::DependentsMap {
+        &self.dependents_map
+    }
+
+    /// Returns the owned programs map.
+    fn owned_programs_map(&self) -> &Self::OwnedProgramsMap {
+        &self.owned_programs_map
+    }
+
     /// Returns the fee store.
     fn fee_store(&self) -> &FeeStore<N, Self::FeeStorage> {
         &self.fee_store
@@ -195,6 +236,8 @@ pub struct ExecutionDB<N: Network> {
     reverse_id_map: DataMap<N::TransitionID, N::TransactionID>,
     /// The inclusion map.
     inclusion_map: DataMap<N::TransactionID, (N::StateRoot, Option<Proof<N>>)>,
+    /// The sequence map.
+    sequence_map: DataMap<N::TransactionID, u64>,
     /// The fee store.
     fee_store: FeeStore<N, FeeDB<N>>,
 }
@@ -204,6 +247,7 @@ impl<N: Network> ExecutionStorage<N> for ExecutionDB<N> {
     type IDMap = DataMap<N::TransactionID, (Vec<N::TransitionID>, bool)>;
     type ReverseIDMap = DataMap<N::TransitionID, N::TransactionID>;
     type InclusionMap = DataMap<N::TransactionID, (N::StateRoot, Option<Proof<N>>)>;
+    type SequenceMap = DataMap<N::TransactionID, u64>;
     type FeeStorage = FeeDB<N>;
 
     /// Initializes the execution storage.
@@ -214,6 +258,7 @@ impl<N: Network> ExecutionStorage<N> for ExecutionDB<N> {
             id_map: rocksdb::RocksDB::open_map(N::ID, storage_mode.clone(), MapID::Execution(ExecutionMap::ID))?,
             reverse_id_map: rocksdb::RocksDB::open_map(N::ID, storage_mode.clone(), MapID::Execution(ExecutionMap::ReverseID))?,
             inclusion_map: rocksdb::RocksDB::open_map(N::ID, storage_mode.clone(), MapID::Execution(ExecutionMap::Inclusion))?,
+            sequence_map: rocksdb::RocksDB::open_map(N::ID, storage_mode.clone(), MapID::Execution(ExecutionMap::Sequence))?,
             fee_store,
         })
     }
@@ -233,6 +278,11 @@ impl<N: Network> ExecutionStorage<N> for ExecutionDB<N> {
         &self.inclusion_map
     }
 
+    /// Returns the sequence map.
+    fn sequence_map(&self) -> &Self::SequenceMap {
+        &self.sequence_map
+    }
+
     /// Returns the fee store.
     fn fee_store(&self) -> &FeeStore<N, Self::FeeStorage> {
         &self.fee_store
@@ -23,7 +23,7 @@ use crate::{
 use console::{
     prelude::*,
     program::{Ciphertext, Future, Identifier, Plaintext, ProgramID, Record},
-    types::{Field, Group},
+    types::{Field, Group, U32},
 };
 
 use aleo_std_storage::StorageMode;
@@ -132,6 +132,8 @@ pub struct InputDB<N: Network> {
     record: DataMap<Field<N>, Field<N>>,
     /// The mapping of `record tag` to `serial number`.
     record_tag: DataMap<Field<N>, Field<N>>,
+    /// The mapping of `serial number` to `lock height`, for time-locked records.
+    record_lock_height: DataMap<Field<N>, U32<N>>,
     /// The mapping of `external commitment` to `()`. Note: This is **not** the record commitment.
     external_record: DataMap<Field<N>, ()>,
     /// The storage mode.
@@ -147,6 +149,7 @@ impl<N: Network> InputStorage<N> for InputDB<N> {
     type PrivateMap = DataMap<Field<N>, Option<Ciphertext<N>>>;
     type RecordMap = DataMap<Field<N>, Field<N>>;
     type RecordTagMap = DataMap<Field<N>, Field<N>>;
+    type RecordLockHeightMap = DataMap<Field<N>, U32<N>>;
     type ExternalRecordMap = DataMap<Field<N>, ()>;
 
     /// Initializes the transition input storage.
@@ -159,6 +162,7 @@ impl<N: Network> InputStorage<N> for InputDB<N> {
             private: rocksdb::RocksDB::open_map(N::ID, storage.clone(), MapID::TransitionInput(TransitionInputMap::Private))?,
             record: rocksdb::RocksDB::open_map(N::ID, storage.clone(), MapID::TransitionInput(TransitionInputMap::Record))?,
             record_tag: rocksdb::RocksDB::open_map(N::ID, storage.clone(), MapID::TransitionInput(TransitionInputMap::RecordTag))?,
+            record_lock_height: rocksdb::RocksDB::open_map(N::ID, storage.clone(), MapID::TransitionInput(TransitionInputMap::RecordLockHeight))?,
             external_record: rocksdb::RocksDB::open_map(N::ID, storage.clone(), MapID::TransitionInput(TransitionInputMap::ExternalRecord))?,
             storage_mode: storage.into(),
         })
@@ -199,6 +203,11 @@ impl<N: Network> InputStorage<N> for InputDB<N> {
         &self.record_tag
     }
 
+    /// Returns the record lock height map.
+    fn record_lock_height_map(&self) -> &Self::RecordLockHeightMap {
+        &self.record_lock_height
+    }
+
     /// Returns the external record map.
     fn external_record_map(&self) -> &Self::ExternalRecordMap {
         &self.external_record
@@ -228,6 +237,8 @@ pub struct OutputDB<N: Network> {
     record: DataMap<Field<N>, (Field<N>, Option<Record<N, Ciphertext<N>>>)>,
     /// The mapping of `record nonce` to `commitment`.
     record_nonce: DataMap<Group<N>, Field<N>>,
+    /// The mapping of `commitment` to `view tag`.
+    record_view_tag: DataMap<Field<N>, u8>,
     /// The mapping of `external commitment` to `()`. Note: This is **not** the record commitment.
     external_record: DataMap<Field<N>, ()>,
     /// The mapping of `future hash` to `(optional) future`.
@@ -245,6 +256,7 @@ impl<N: Network> OutputStorage<N> for OutputDB<N> {
     type PrivateMap = DataMap<Field<N>, Option<Ciphertext<N>>>;
     type RecordMap = DataMap<Field<N>, (Field<N>, Option<Record<N, Ciphertext<N>>>)>;
     type RecordNonceMap = DataMap<Group<N>, Field<N>>;
+    type RecordViewTagMap = DataMap<Field<N>, u8>;
     type ExternalRecordMap = DataMap<Field<N>, ()>;
     type FutureMap = DataMap<Field<N>, Option<Future<N>>>;
 
@@ -258,6 +270,7 @@ impl<N: Network> OutputStorage<N> for OutputDB<N> {
             private: rocksdb::RocksDB::open_map(N::ID, storage.clone(), MapID::TransitionOutput(TransitionOutputMap::Private))?,
             record: rocksdb::RocksDB::open_map(N::ID, storage.clone(), MapID::TransitionOutput(TransitionOutputMap::Record))?,
             record_nonce: rocksdb::RocksDB::open_map(N::ID, storage.clone(), MapID::TransitionOutput(TransitionOutputMap::RecordNonce))?,
+            record_view_tag: rocksdb::RocksDB::open_map(N::ID, storage.clone(), MapID::TransitionOutput(TransitionOutputMap::RecordViewTag))?,
             external_record: rocksdb::RocksDB::open_map(N::ID, storage.clone(), MapID::TransitionOutput(TransitionOutputMap::ExternalRecord))?,
             future: rocksdb::RocksDB::open_map(N::ID, storage.clone(), MapID::TransitionOutput(TransitionOutputMap::Future))?,
             storage_mode: storage.into(),
@@ -299,6 +312,11 @@ impl<N: Network> OutputStorage<N> for OutputDB<N> {
         &self.record_nonce
     }
 
+    /// Returns the record view tag map.
+    fn record_view_tag_map(&self) -> &Self::RecordViewTagMap {
+        &self.record_view_tag
+    }
+
     /// Returns the external record map.
     fn external_record_map(&self) -> &Self::ExternalRecordMap {
         &self.external_record
@@ -33,7 +33,7 @@ use indexmap::IndexSet;
 
 /// TODO (howardwu): Remove this.
 /// Returns the mapping ID for the given `program ID` and `mapping name`.
-fn to_mapping_id<N: Network>(program_id: &ProgramID<N>, mapping_name: &Identifier<N>) -> Result<Field<N>> {
+pub fn to_mapping_id<N: Network>(program_id: &ProgramID<N>, mapping_name: &Identifier<N>) -> Result<Field<N>> {
     // Construct the preimage.
     let mut preimage = Vec::new();
     program_id.write_bits_le(&mut preimage);
@@ -44,7 +44,7 @@ fn to_mapping_id<N: Network>(program_id: &ProgramID<N>, mapping_name: &Identifie
 }
 
 /// Returns the key ID for the given `program ID`, `mapping name`, and `key`.
-fn to_key_id<N: Network>(
+pub fn to_key_id<N: Network>(
     program_id: &ProgramID<N>,
     mapping_name: &Identifier<N>,
     key: &Plaintext<N>,
@@ -60,6 +60,44 @@ fn to_key_id<N: Network>(
     N::hash_bhp1024(&preimage)
 }
 
+/// Returns the entry checksum `Hash( m || k || v )` for the given mapping entry, as computed in the finalize checksum.
+fn to_entry_checksum<N: Network>(
+    mapping: &(ProgramID<N>, Identifier<N>),
+    key: &Plaintext<N>,
+    value: &Value<N>,
+) -> Result<Field<N>> {
+    // Construct the preimage.
+    let mut preimage = Vec::new();
+    mapping.write_bits_le(&mut preimage);
+    false.write_bits_le(&mut preimage); // Separator.
+    key.write_bits_le(&mut preimage);
+    false.write_bits_le(&mut preimage); // Separator.
+    value.write_bits_le(&mut preimage);
+    false.write_bits_le(&mut preimage); // Separator.
+
+    // Compute the entry checksum.
+    N::hash_bhp1024(&preimage)
+}
+
+/// The first divergence between two finalize stores, as found by `FinalizeStore::diff`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FinalizeDivergence<N: Network> {
+    /// The mapping is initialized in only one of the two stores.
+    Mapping { program_id: ProgramID<N>, mapping_name: Identifier<N>, in_self: bool },
+    /// The entry for the key differs between the two stores. A missing entry is `None`.
+    Entry {
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: Plaintext<N>,
+        self_value: Option<Value<N>>,
+        other_value: Option<Value<N>>,
+        /// The entry checksum `Hash( m || k || v )` in this store, as computed in the finalize checksum.
+        self_checksum: Option<Field<N>>,
+        /// The entry checksum `Hash( m || k || v )` in the other store, as computed in the finalize checksum.
+        other_checksum: Option<Field<N>>,
+    },
+}
+
 /// A trait for program state storage. Note: For the program logic, see `DeploymentStorage`.
 ///
 /// We define the `key ID := Hash ( program ID || mapping name || Hash(key) )`
@@ -707,6 +745,15 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
         self.storage.contains_program_confirmed(program_id)
     }
 
+    /// Returns `true` if the given `program ID` and `mapping name` exist.
+    pub fn contains_mapping_speculative(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+    ) -> Result<bool> {
+        self.storage.contains_mapping_speculative(program_id, mapping_name)
+    }
+
     /// Returns `true` if the given `program ID`, `mapping name`, and `key` exist.
     pub fn contains_key_confirmed(
         &self,
@@ -768,6 +815,74 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
     }
 }
 
+impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
+    /// Returns the first divergence between the confirmed state of this store and the given store,
+    /// or `None` if both stores hold the same mappings and entries.
+    ///
+    /// This is intended for diagnosing a state mismatch between two nodes, e.g. one restored from
+    /// a snapshot and one replayed from genesis. The stores are walked entry by entry, with each
+    /// entry looked up in the other store, so no more than one entry is held in memory at a time.
+    pub fn diff<Q: FinalizeStorage<N>>(&self, other: &FinalizeStore<N, Q>) -> Result<Option<FinalizeDivergence<N>>> {
+        // Ensure both stores have the same mappings.
+        for (program_id, mapping_names) in self.storage.program_id_map().iter_confirmed() {
+            let other_names = other.get_mapping_names_confirmed(&program_id)?.unwrap_or_default();
+            if let Some(mapping_name) = mapping_names.iter().find(|name| !other_names.contains(*name)) {
+                let (program_id, mapping_name) = (cow_to_copied!(program_id), *mapping_name);
+                return Ok(Some(FinalizeDivergence::Mapping { program_id, mapping_name, in_self: true }));
+            }
+        }
+        for (program_id, mapping_names) in other.storage.program_id_map().iter_confirmed() {
+            let self_names = self.get_mapping_names_confirmed(&program_id)?.unwrap_or_default();
+            if let Some(mapping_name) = mapping_names.iter().find(|name| !self_names.contains(*name)) {
+                let (program_id, mapping_name) = (cow_to_copied!(program_id), *mapping_name);
+                return Ok(Some(FinalizeDivergence::Mapping { program_id, mapping_name, in_self: false }));
+            }
+        }
+
+        // Ensure every entry in this store matches the entry in the other store.
+        for (mapping, key, value) in self.storage.key_value_map().iter_confirmed() {
+            let ((program_id, mapping_name), key, value) =
+                (cow_to_copied!(mapping), cow_to_cloned!(key), cow_to_cloned!(value));
+            let other_value = other.get_value_confirmed(program_id, mapping_name, &key)?;
+            if other_value.as_ref() != Some(&value) {
+                let self_checksum = Some(to_entry_checksum(&(program_id, mapping_name), &key, &value)?);
+                let other_checksum = match &other_value {
+                    Some(other_value) => Some(to_entry_checksum(&(program_id, mapping_name), &key, other_value)?),
+                    None => None,
+                };
+                return Ok(Some(FinalizeDivergence::Entry {
+                    program_id,
+                    mapping_name,
+                    key,
+                    self_value: Some(value),
+                    other_value,
+                    self_checksum,
+                    other_checksum,
+                }));
+            }
+        }
+        // Ensure the other store has no entries that are missing from this store.
+        // Note: Entries present in both stores were compared above.
+        for (mapping, key, value) in other.storage.key_value_map().iter_confirmed() {
+            let ((program_id, mapping_name), key) = (cow_to_copied!(mapping), cow_to_cloned!(key));
+            if !self.contains_key_confirmed(program_id, mapping_name, &key)? {
+                let other_checksum = Some(to_entry_checksum(&(program_id, mapping_name), &key, &value)?);
+                return Ok(Some(FinalizeDivergence::Entry {
+                    program_id,
+                    mapping_name,
+                    key,
+                    self_value: None,
+                    other_value: Some(cow_to_cloned!(value)),
+                    self_checksum: None,
+                    other_checksum,
+                }));
+            }
+        }
+
+        Ok(None)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1208,6 +1323,64 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_diff() {
+        // Initialize a program ID and mapping names.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let other_mapping_name = Identifier::from_str("bonded").unwrap();
+
+        // Initialize two finalize stores with the same entries.
+        let (key_a, key_b) = (Plaintext::from_str("1field").unwrap(), Plaintext::from_str("2field").unwrap());
+        let value = Value::from_str("100u64").unwrap();
+        let stores = (0..2)
+            .map(|_| {
+                let finalize_store = FinalizeStore::from(FinalizeMemory::open(None).unwrap()).unwrap();
+                finalize_store.initialize_mapping(program_id, mapping_name).unwrap();
+                finalize_store.insert_key_value(program_id, mapping_name, key_a.clone(), value.clone()).unwrap();
+                finalize_store
+            })
+            .collect::<Vec<_>>();
+        let (store, other) = (&stores[0], &stores[1]);
+
+        // Ensure the stores do not diverge.
+        assert_eq!(store.diff(other).unwrap(), None);
+
+        // Ensure a changed value is reported, along with its entry checksums.
+        let changed = Value::from_str("200u64").unwrap();
+        other.update_key_value(program_id, mapping_name, key_a.clone(), changed.clone()).unwrap();
+        let Some(FinalizeDivergence::Entry { key, self_value, other_value, self_checksum, other_checksum, .. }) =
+            store.diff(other).unwrap()
+        else {
+            panic!("Expected an entry divergence")
+        };
+        assert_eq!(key, key_a);
+        assert_eq!(self_value, Some(value.clone()));
+        assert_eq!(other_value, Some(changed));
+        assert_ne!(self_checksum, other_checksum);
+        other.update_key_value(program_id, mapping_name, key_a.clone(), value.clone()).unwrap();
+
+        // Ensure an entry missing from this store is reported.
+        other.insert_key_value(program_id, mapping_name, key_b.clone(), value.clone()).unwrap();
+        let Some(FinalizeDivergence::Entry { key, self_value, other_value, .. }) = store.diff(other).unwrap() else {
+            panic!("Expected an entry divergence")
+        };
+        assert_eq!((key, self_value, other_value), (key_b.clone(), None, Some(value)));
+        other.remove_key_value(program_id, mapping_name, &key_b).unwrap();
+        assert_eq!(store.diff(other).unwrap(), None);
+
+        // Ensure a mapping missing from the other store is reported.
+        store.initialize_mapping(program_id, other_mapping_name).unwrap();
+        assert_eq!(
+            store.diff(other).unwrap(),
+            Some(FinalizeDivergence::Mapping { program_id, mapping_name: other_mapping_name, in_self: true })
+        );
+        assert_eq!(
+            other.diff(store).unwrap(),
+            Some(FinalizeDivergence::Mapping { program_id, mapping_name: other_mapping_name, in_self: false })
+        );
+    }
+
     #[test]
     fn test_must_initialize_first() {
         // Initialize a program ID and mapping name.
@@ -16,21 +16,23 @@ use crate::{
     atomic_batch_scope,
     cow_to_cloned,
     cow_to_copied,
-    helpers::{Map, MapRead},
+    helpers::{Map, MapRead, NestedMap, NestedMapRead},
     FeeStorage,
     FeeStore,
 };
 use console::{
+    account::Address,
     network::prelude::*,
     program::{Identifier, ProgramID, ProgramOwner},
 };
-use ledger_block::{Deployment, Fee, Transaction};
+use ledger_block::{Deployment, Fee, MappingPolicy, Transaction};
 use synthesizer_program::Program;
 use synthesizer_snark::{Certificate, VerifyingKey};
 
 use aleo_std_storage::StorageMode;
 use anyhow::Result;
 use core::marker::PhantomData;
+use indexmap::IndexSet;
 use std::borrow::Cow;
 
 /// A trait for deployment storage.
@@ -49,6 +51,14 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
     type VerifyingKeyMap: for<'a> Map<'a, (ProgramID<N>, Identifier<N>, u16), VerifyingKey<N>>;
     /// The mapping of `(program ID, function name, edition)` to `certificate`.
     type CertificateMap: for<'a> Map<'a, (ProgramID<N>, Identifier<N>, u16), Certificate<N>>;
+    /// The mapping of `transaction ID` to `(program ID, program owner, mapping policy)`, for deprecations.
+    type DeprecationMap: for<'a> Map<'a, N::TransactionID, (ProgramID<N>, ProgramOwner<N>, MappingPolicy)>;
+    /// The mapping of `program ID` to `transaction ID`, for deprecations.
+    type ReverseDeprecationMap: for<'a> Map<'a, ProgramID<N>, N::TransactionID>;
+    /// The mapping of `(imported program ID, dependent program ID)` to `()`, as of the latest edition of the dependent.
+    type DependentsMap: for<'a> NestedMap<'a, ProgramID<N>, ProgramID<N>, ()>;
+    /// The mapping of `owner address` to the `program IDs` it owns, as of their latest edition.
+    type OwnedProgramsMap: for<'a> Map<'a, Address<N>, IndexSet<ProgramID<N>>>;
     /// The fee storage.
     type FeeStorage: FeeStorage<N>;
 
@@ -69,6 +79,14 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
     fn verifying_key_map(&self) -> &Self::VerifyingKeyMap;
     /// Returns the certificate map.
     fn certificate_map(&self) -> &Self::CertificateMap;
+    /// Returns the deprecation map.
+    fn deprecation_map(&self) -> &Self::DeprecationMap;
+    /// Returns the reverse deprecation map.
+    fn reverse_deprecation_map(&self) -> &Self::ReverseDeprecationMap;
+    /// Returns the dependents map.
+    fn dependents_map(&self) -> &Self::DependentsMap;
+    /// Returns the owned programs map.
+    fn owned_programs_map(&self) -> &Self::OwnedProgramsMap;
     /// Returns the fee storage.
     fn fee_store(&self) -> &FeeStore<N, Self::FeeStorage>;
 
@@ -86,6 +104,10 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         self.program_map().start_atomic();
         self.verifying_key_map().start_atomic();
         self.certificate_map().start_atomic();
+        self.deprecation_map().start_atomic();
+        self.reverse_deprecation_map().start_atomic();
+        self.dependents_map().start_atomic();
+        self.owned_programs_map().start_atomic();
         self.fee_store().start_atomic();
     }
 
@@ -98,6 +120,10 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
             || self.program_map().is_atomic_in_progress()
             || self.verifying_key_map().is_atomic_in_progress()
             || self.certificate_map().is_atomic_in_progress()
+            || self.deprecation_map().is_atomic_in_progress()
+            || self.reverse_deprecation_map().is_atomic_in_progress()
+            || self.dependents_map().is_atomic_in_progress()
+            || self.owned_programs_map().is_atomic_in_progress()
             || self.fee_store().is_atomic_in_progress()
     }
 
@@ -110,6 +136,10 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         self.program_map().atomic_checkpoint();
         self.verifying_key_map().atomic_checkpoint();
         self.certificate_map().atomic_checkpoint();
+        self.deprecation_map().atomic_checkpoint();
+        self.reverse_deprecation_map().atomic_checkpoint();
+        self.dependents_map().atomic_checkpoint();
+        self.owned_programs_map().atomic_checkpoint();
         self.fee_store().atomic_checkpoint();
     }
 
@@ -122,6 +152,10 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         self.program_map().clear_latest_checkpoint();
         self.verifying_key_map().clear_latest_checkpoint();
         self.certificate_map().clear_latest_checkpoint();
+        self.deprecation_map().clear_latest_checkpoint();
+        self.reverse_deprecation_map().clear_latest_checkpoint();
+        self.dependents_map().clear_latest_checkpoint();
+        self.owned_programs_map().clear_latest_checkpoint();
         self.fee_store().clear_latest_checkpoint();
     }
 
@@ -134,6 +168,10 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         self.program_map().atomic_rewind();
         self.verifying_key_map().atomic_rewind();
         self.certificate_map().atomic_rewind();
+        self.deprecation_map().atomic_rewind();
+        self.reverse_deprecation_map().atomic_rewind();
+        self.dependents_map().atomic_rewind();
+        self.owned_programs_map().atomic_rewind();
         self.fee_store().atomic_rewind();
     }
 
@@ -146,6 +184,10 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         self.program_map().abort_atomic();
         self.verifying_key_map().abort_atomic();
         self.certificate_map().abort_atomic();
+        self.deprecation_map().abort_atomic();
+        self.reverse_deprecation_map().abort_atomic();
+        self.dependents_map().abort_atomic();
+        self.owned_programs_map().abort_atomic();
         self.fee_store().abort_atomic();
     }
 
@@ -158,6 +200,10 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         self.program_map().finish_atomic()?;
         self.verifying_key_map().finish_atomic()?;
         self.certificate_map().finish_atomic()?;
+        self.deprecation_map().finish_atomic()?;
+        self.reverse_deprecation_map().finish_atomic()?;
+        self.dependents_map().finish_atomic()?;
+        self.owned_programs_map().finish_atomic()?;
         self.fee_store().finish_atomic()
     }
 
@@ -168,6 +214,7 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
             Transaction::Deploy(transaction_id, owner, deployment, fee) => (transaction_id, owner, deployment, fee),
             Transaction::Execute(..) => bail!("Attempted to insert an execute transaction into deployment storage."),
             Transaction::Fee(..) => bail!("Attempted to insert fee transaction into deployment storage."),
+            Transaction::Deprecate(..) => bail!("Attempted to insert a deprecate transaction into deployment storage."),
         };
 
         // Ensure the deployment is ordered.
@@ -182,6 +229,25 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         // Retrieve the program ID.
         let program_id = *program.id();
 
+        // Retrieve the previous edition of the program, if this is an upgrade.
+        let previous_program = match edition > N::EDITION {
+            true => match self.program_map().get_speculative(&(program_id, edition - 1))? {
+                Some(program) => Some(cow_to_cloned!(program)),
+                None => bail!("Failed to locate edition {} of program '{program_id}'", edition - 1),
+            },
+            false => None,
+        };
+        // Retrieve the owner of the previous edition of the program, if this is an upgrade.
+        let previous_owner = match edition > N::EDITION {
+            true => match self.owner_map().get_speculative(&(program_id, edition - 1))? {
+                Some(owner) => Some(owner.address()),
+                None => bail!("Failed to locate the owner of edition {} of program '{program_id}'", edition - 1),
+            },
+            false => None,
+        };
+        // Prepare the programs of the previous and next owners.
+        let owned_programs = self.prepare_owned_programs(&program_id, previous_owner, Some(owner.address()))?;
+
         atomic_batch_scope!(self, {
             // Store the program ID.
             self.id_map().insert(*transaction_id, program_id)?;
@@ -203,6 +269,24 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
                 self.certificate_map().insert((program_id, *function_name, edition), certificate.clone())?;
             }
 
+            // Remove the program as a dependent of the imports of the previous edition.
+            if let Some(previous_program) = &previous_program {
+                for import_id in previous_program.imports().keys() {
+                    self.dependents_map().remove_key(import_id, &program_id)?;
+                }
+            }
+            // Store the program as a dependent of its imports.
+            for import_id in program.imports().keys() {
+                self.dependents_map().insert(*import_id, program_id, ())?;
+            }
+            // Store the programs of the owners.
+            for (address, programs) in owned_programs {
+                match programs.is_empty() {
+                    true => self.owned_programs_map().remove(&address)?,
+                    false => self.owned_programs_map().insert(address, programs)?,
+                }
+            }
+
             // Store the fee transition.
             self.fee_store().insert(*transaction_id, fee)?;
 
@@ -218,21 +302,51 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
             None => bail!("Failed to get the program ID for transaction '{transaction_id}'"),
         };
         // Retrieve the edition.
-        let edition = match self.get_edition(&program_id)? {
+        let edition = match self.get_transaction_edition(transaction_id)? {
             Some(edition) => edition,
             None => bail!("Failed to locate the edition for program '{program_id}'"),
         };
+        // Ensure the edition is the latest edition of the program.
+        if self.get_edition(&program_id)? != Some(edition) {
+            bail!("Cannot remove edition {edition} of program '{program_id}', as it has been upgraded")
+        }
         // Retrieve the program.
         let program = match self.program_map().get_confirmed(&(program_id, edition))? {
             Some(program) => cow_to_cloned!(program),
             None => bail!("Failed to locate program '{program_id}' for transaction '{transaction_id}'"),
         };
+        // Retrieve the previous edition of the program, if this was an upgrade.
+        let previous_program = match edition > N::EDITION {
+            true => match self.program_map().get_confirmed(&(program_id, edition - 1))? {
+                Some(program) => Some(cow_to_cloned!(program)),
+                None => bail!("Failed to locate edition {} of program '{program_id}'", edition - 1),
+            },
+            false => None,
+        };
+        // Retrieve the owner.
+        let owner = match self.owner_map().get_confirmed(&(program_id, edition))? {
+            Some(owner) => owner.address(),
+            None => bail!("Failed to locate the owner of program '{program_id}' for transaction '{transaction_id}'"),
+        };
+        // Retrieve the owner of the previous edition of the program, if this was an upgrade.
+        let previous_owner = match edition > N::EDITION {
+            true => match self.owner_map().get_confirmed(&(program_id, edition - 1))? {
+                Some(owner) => Some(owner.address()),
+                None => bail!("Failed to locate the owner of edition {} of program '{program_id}'", edition - 1),
+            },
+            false => None,
+        };
+        // Prepare the programs of the owners, restoring the owner of the previous edition.
+        let owned_programs = self.prepare_owned_programs(&program_id, Some(owner), previous_owner)?;
 
         atomic_batch_scope!(self, {
             // Remove the program ID.
             self.id_map().remove(transaction_id)?;
-            // Remove the edition.
-            self.edition_map().remove(&program_id)?;
+            // Remove the edition, restoring the previous edition if this was an upgrade.
+            match edition > N::EDITION {
+                true => self.edition_map().insert(program_id, edition - 1)?,
+                false => self.edition_map().remove(&program_id)?,
+            }
 
             // Remove the reverse program ID.
             self.reverse_id_map().remove(&(program_id, edition))?;
@@ -249,6 +363,137 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
                 self.certificate_map().remove(&(program_id, *function_name, edition))?;
             }
 
+            // Remove the program as a dependent of its imports.
+            for import_id in program.imports().keys() {
+                self.dependents_map().remove_key(import_id, &program_id)?;
+            }
+            // Restore the program as a dependent of the imports of the previous edition.
+            if let Some(previous_program) = &previous_program {
+                for import_id in previous_program.imports().keys() {
+                    self.dependents_map().insert(*import_id, program_id, ())?;
+                }
+            }
+            // Update the programs of the owners.
+            for (address, programs) in owned_programs {
+                match programs.is_empty() {
+                    true => self.owned_programs_map().remove(&address)?,
+                    false => self.owned_programs_map().insert(address, programs)?,
+                }
+            }
+
+            // Remove the fee transition.
+            self.fee_store().remove(transaction_id)?;
+
+            Ok(())
+        })
+    }
+
+    /// Indexes the dependents of the latest edition of every program, if the dependents map is empty.
+    /// This populates the dependents map for storage that was written before it existed.
+    fn backfill_dependents(&self) -> Result<()> {
+        // Skip the backfill if the dependents map is already populated.
+        if self.dependents_map().keys_confirmed().next().is_some() {
+            return Ok(());
+        }
+
+        // Collect the `(imported program ID, dependent program ID)` pairs.
+        let mut dependents = Vec::new();
+        for (program_id, edition) in self.edition_map().iter_confirmed() {
+            // Retrieve the latest edition of the program.
+            let program = match self.program_map().get_confirmed(&(*program_id, *edition))? {
+                Some(program) => cow_to_cloned!(program),
+                None => bail!("Failed to locate edition {edition} of program '{program_id}'"),
+            };
+            dependents.extend(program.imports().keys().map(|import_id| (*import_id, *program_id)));
+        }
+        // If no program imports another, there is nothing to backfill.
+        if dependents.is_empty() {
+            return Ok(());
+        }
+
+        atomic_batch_scope!(self, {
+            // Store the dependents of the imported programs.
+            for (import_id, program_id) in dependents {
+                self.dependents_map().insert(import_id, program_id, ())?;
+            }
+            Ok(())
+        })
+    }
+
+    /// Returns the updated programs of the `previous` and `next` owners of the given program,
+    /// such that the given program is owned by exactly the `next` owner.
+    #[allow(clippy::type_complexity)]
+    fn prepare_owned_programs(
+        &self,
+        program_id: &ProgramID<N>,
+        previous: Option<Address<N>>,
+        next: Option<Address<N>>,
+    ) -> Result<Vec<(Address<N>, IndexSet<ProgramID<N>>)>> {
+        // Retrieve the addresses of either owner.
+        let addresses: IndexSet<_> = previous.into_iter().chain(next).collect();
+
+        addresses
+            .into_iter()
+            .map(|address| {
+                // Retrieve the current programs of the owner.
+                let mut programs = match self.owned_programs_map().get_speculative(&address)? {
+                    Some(programs) => cow_to_cloned!(programs),
+                    None => IndexSet::new(),
+                };
+                // Update the given program as owned, based on the owner of the next edition.
+                match next == Some(address) {
+                    true => programs.insert(*program_id),
+                    false => programs.shift_remove(program_id),
+                };
+                Ok((address, programs))
+            })
+            .collect()
+    }
+
+    /// Stores the given `deprecation transaction` into storage.
+    fn insert_deprecation(&self, transaction: &Transaction<N>) -> Result<()> {
+        // Ensure the transaction is a deprecation.
+        let (transaction_id, owner, program_id, mapping_policy, fee) = match transaction {
+            Transaction::Deprecate(transaction_id, owner, program_id, mapping_policy, fee) => {
+                (transaction_id, owner, program_id, mapping_policy, fee)
+            }
+            _ => bail!("Attempted to insert a non-deprecate transaction as a deprecation."),
+        };
+
+        // Ensure the program exists.
+        if self.get_edition(program_id)?.is_none() {
+            bail!("Failed to insert the deprecation of program '{program_id}', as it does not exist")
+        }
+        // Ensure the program is not already deprecated.
+        if self.reverse_deprecation_map().contains_key_confirmed(program_id)? {
+            bail!("Failed to insert the deprecation of program '{program_id}', as it is already deprecated")
+        }
+
+        atomic_batch_scope!(self, {
+            // Store the program ID, owner, and mapping policy.
+            self.deprecation_map().insert(*transaction_id, (*program_id, *owner, *mapping_policy))?;
+            // Store the reverse program ID.
+            self.reverse_deprecation_map().insert(*program_id, *transaction_id)?;
+            // Store the fee transition.
+            self.fee_store().insert(*transaction_id, fee)?;
+
+            Ok(())
+        })
+    }
+
+    /// Removes the deprecation transaction for the given `transaction ID`.
+    fn remove_deprecation(&self, transaction_id: &N::TransactionID) -> Result<()> {
+        // Retrieve the program ID.
+        let program_id = match self.deprecation_map().get_confirmed(transaction_id)? {
+            Some(deprecation) => deprecation.0,
+            None => bail!("Failed to get the deprecated program ID for transaction '{transaction_id}'"),
+        };
+
+        atomic_batch_scope!(self, {
+            // Remove the program ID, owner, and mapping policy.
+            self.deprecation_map().remove(transaction_id)?;
+            // Remove the reverse program ID.
+            self.reverse_deprecation_map().remove(&program_id)?;
             // Remove the fee transition.
             self.fee_store().remove(transaction_id)?;
 
@@ -309,6 +554,30 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         }
     }
 
+    /// Returns the edition deployed by the given `transaction ID`.
+    /// Note: This is the latest edition of the program, unless the program has since been upgraded.
+    fn get_transaction_edition(&self, transaction_id: &N::TransactionID) -> Result<Option<u16>> {
+        // Retrieve the program ID.
+        let program_id = match self.get_program_id(transaction_id)? {
+            Some(program_id) => program_id,
+            None => return Ok(None),
+        };
+        // Retrieve the latest edition.
+        let latest_edition = match self.get_edition(&program_id)? {
+            Some(edition) => edition,
+            None => bail!("Failed to locate the edition for program '{program_id}'"),
+        };
+        // Find the edition that was deployed by the transaction, starting from the latest edition.
+        for edition in (N::EDITION..=latest_edition).rev() {
+            if let Some(candidate_id) = self.reverse_id_map().get_confirmed(&(program_id, edition))? {
+                if cow_to_copied!(candidate_id) == *transaction_id {
+                    return Ok(Some(edition));
+                }
+            }
+        }
+        bail!("Failed to locate the edition of program '{program_id}' for transaction '{transaction_id}'")
+    }
+
     /// Returns the program for the given `program ID`.
     fn get_program(&self, program_id: &ProgramID<N>) -> Result<Option<Program<N>>> {
         // Check if the program ID is for 'credits.aleo'.
@@ -390,7 +659,7 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
             None => return Ok(None),
         };
         // Retrieve the edition.
-        let edition = match self.get_edition(&program_id)? {
+        let edition = match self.get_transaction_edition(transaction_id)? {
             Some(edition) => edition,
             None => bail!("Failed to get the edition for program '{program_id}'"),
         };
@@ -437,8 +706,7 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
             return Ok(None);
         }
 
-        // TODO (raychu86): Consider program upgrades and edition changes.
-        // Retrieve the edition.
+        // Retrieve the latest edition.
         let edition = match self.get_edition(program_id)? {
             Some(edition) => edition,
             None => return Ok(None),
@@ -451,6 +719,63 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         }
     }
 
+    /// Returns the program IDs that import the given `program ID`, as of their latest edition.
+    fn get_dependents(&self, program_id: &ProgramID<N>) -> Result<Vec<ProgramID<N>>> {
+        Ok(self.dependents_map().get_map_confirmed(program_id)?.into_iter().map(|(dependent, _)| dependent).collect())
+    }
+
+    /// Returns the program IDs that import the given `program ID` directly or through other programs,
+    /// as of their latest edition, in breadth-first order.
+    fn get_transitive_dependents(&self, program_id: &ProgramID<N>) -> Result<Vec<ProgramID<N>>> {
+        // Initialize the dependents with the direct dependents of the program.
+        let mut dependents: IndexSet<_> = self.get_dependents(program_id)?.into_iter().collect();
+        // Expand each dependent in the order it was found.
+        let mut index = 0;
+        while let Some(dependent) = dependents.get_index(index).copied() {
+            dependents.extend(self.get_dependents(&dependent)?);
+            index += 1;
+        }
+        Ok(dependents.into_iter().collect())
+    }
+
+    /// Returns the program IDs owned by the given `address`, as of their latest edition.
+    fn get_owned_programs(&self, address: &Address<N>) -> Result<Vec<ProgramID<N>>> {
+        match self.owned_programs_map().get_confirmed(address)? {
+            Some(programs) => Ok(programs.iter().copied().collect()),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Returns the transaction ID that deprecated the given `program ID`, if the program is deprecated.
+    fn get_deprecation_transaction_id(&self, program_id: &ProgramID<N>) -> Result<Option<N::TransactionID>> {
+        match self.reverse_deprecation_map().get_confirmed(program_id)? {
+            Some(transaction_id) => Ok(Some(cow_to_copied!(transaction_id))),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the deprecation transaction for the given `transaction ID`.
+    fn get_deprecation_transaction(&self, transaction_id: &N::TransactionID) -> Result<Option<Transaction<N>>> {
+        // Retrieve the program ID, owner, and mapping policy.
+        let (program_id, owner, mapping_policy) = match self.deprecation_map().get_confirmed(transaction_id)? {
+            Some(deprecation) => cow_to_copied!(deprecation),
+            None => return Ok(None),
+        };
+        // Retrieve the fee.
+        let fee = match self.get_fee(transaction_id)? {
+            Some(fee) => fee,
+            None => bail!("Failed to get the fee for transaction '{transaction_id}'"),
+        };
+
+        // Construct the deprecation transaction.
+        let deprecation_transaction = Transaction::from_deprecation(owner, program_id, mapping_policy, fee)?;
+        // Ensure the transaction ID matches.
+        match *transaction_id == deprecation_transaction.id() {
+            true => Ok(Some(deprecation_transaction)),
+            false => bail!("The deprecation transaction ID does not match '{transaction_id}'"),
+        }
+    }
+
     /// Returns the transaction for the given `transaction ID`.
     fn get_transaction(&self, transaction_id: &N::TransactionID) -> Result<Option<Transaction<N>>> {
         // Retrieve the deployment.
@@ -465,8 +790,8 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         };
 
         // Retrieve the owner.
-        let owner = match self.get_owner(deployment.program_id())? {
-            Some(owner) => owner,
+        let owner = match self.owner_map().get_confirmed(&(*deployment.program_id(), deployment.edition()))? {
+            Some(owner) => cow_to_copied!(owner),
             None => bail!("Failed to get the owner for transaction '{transaction_id}'"),
         };
 
@@ -494,6 +819,8 @@ impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
     pub fn open(fee_store: FeeStore<N, D::FeeStorage>) -> Result<Self> {
         // Initialize the deployment storage.
         let storage = D::open(fee_store)?;
+        // Backfill the dependents map, for storage that was written before it existed.
+        storage.backfill_dependents()?;
         // Return the deployment store.
         Ok(Self { storage, _phantom: PhantomData })
     }
@@ -513,6 +840,16 @@ impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
         self.storage.remove(transaction_id)
     }
 
+    /// Stores the given `deprecation transaction` into storage.
+    pub fn insert_deprecation(&self, transaction: &Transaction<N>) -> Result<()> {
+        self.storage.insert_deprecation(transaction)
+    }
+
+    /// Removes the deprecation transaction for the given `transaction ID`.
+    pub fn remove_deprecation(&self, transaction_id: &N::TransactionID) -> Result<()> {
+        self.storage.remove_deprecation(transaction_id)
+    }
+
     /// Starts an atomic batch write operation.
     pub fn start_atomic(&self) {
         self.storage.start_atomic();
@@ -570,6 +907,11 @@ impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
         self.storage.get_edition(program_id)
     }
 
+    /// Returns the edition deployed by the given `transaction ID`.
+    pub fn get_transaction_edition(&self, transaction_id: &N::TransactionID) -> Result<Option<u16>> {
+        self.storage.get_transaction_edition(transaction_id)
+    }
+
     /// Returns the program ID for the given `transaction ID`.
     pub fn get_program_id(&self, transaction_id: &N::TransactionID) -> Result<Option<ProgramID<N>>> {
         self.storage.get_program_id(transaction_id)
@@ -580,6 +922,11 @@ impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
         self.storage.get_program(program_id)
     }
 
+    /// Returns the owner of the latest edition for the given `program ID`.
+    pub fn get_owner(&self, program_id: &ProgramID<N>) -> Result<Option<ProgramOwner<N>>> {
+        self.storage.get_owner(program_id)
+    }
+
     /// Returns the verifying key for the given `(program ID, function name)`.
     pub fn get_verifying_key(
         &self,
@@ -602,6 +949,32 @@ impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
     pub fn get_fee(&self, transaction_id: &N::TransactionID) -> Result<Option<Fee<N>>> {
         self.storage.get_fee(transaction_id)
     }
+
+    /// Returns the deprecation transaction for the given `transaction ID`.
+    pub fn get_deprecation_transaction(&self, transaction_id: &N::TransactionID) -> Result<Option<Transaction<N>>> {
+        self.storage.get_deprecation_transaction(transaction_id)
+    }
+
+    /// Returns the transaction ID that deprecated the given `program ID`, if the program is deprecated.
+    pub fn get_deprecation_transaction_id(&self, program_id: &ProgramID<N>) -> Result<Option<N::TransactionID>> {
+        self.storage.get_deprecation_transaction_id(program_id)
+    }
+
+    /// Returns the program IDs that import the given `program ID`, as of their latest edition.
+    pub fn get_dependents(&self, program_id: &ProgramID<N>) -> Result<Vec<ProgramID<N>>> {
+        self.storage.get_dependents(program_id)
+    }
+
+    /// Returns the program IDs that import the given `program ID` directly or through other programs,
+    /// as of their latest edition, in breadth-first order.
+    pub fn get_transitive_dependents(&self, program_id: &ProgramID<N>) -> Result<Vec<ProgramID<N>>> {
+        self.storage.get_transitive_dependents(program_id)
+    }
+
+    /// Returns the program IDs owned by the given `address`, as of their latest edition.
+    pub fn get_owned_programs(&self, address: &Address<N>) -> Result<Vec<ProgramID<N>>> {
+        self.storage.get_owned_programs(address)
+    }
 }
 
 impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
@@ -624,6 +997,11 @@ impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
     pub fn contains_program_id(&self, program_id: &ProgramID<N>) -> Result<bool> {
         self.storage.edition_map().contains_key_confirmed(program_id)
     }
+
+    /// Returns `true` if the given program ID is deprecated.
+    pub fn is_deprecated(&self, program_id: &ProgramID<N>) -> Result<bool> {
+        self.storage.reverse_deprecation_map().contains_key_confirmed(program_id)
+    }
 }
 
 impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
@@ -640,6 +1018,11 @@ impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
         })
     }
 
+    /// Returns an iterator over the `(program ID, edition)`, for the latest edition of every deployed program.
+    pub fn program_editions(&self) -> impl '_ + Iterator<Item = (Cow<'_, ProgramID<N>>, Cow<'_, u16>)> {
+        self.storage.edition_map().iter_confirmed()
+    }
+
     /// Returns an iterator over the programs, for all deployments.
     pub fn programs(&self) -> impl '_ + Iterator<Item = Cow<'_, Program<N>>> {
         self.storage.program_map().values_confirmed().map(|program| match program {
@@ -696,7 +1079,14 @@ mod tests {
 
             // Retrieve the deployment transaction.
             let candidate = deployment_store.get_transaction(&transaction_id).unwrap();
-            assert_eq!(Some(transaction), candidate);
+            assert_eq!(Some(transaction.clone()), candidate);
+
+            // Ensure the program is owned by the deployer.
+            let (owner, program_id) = match &transaction {
+                Transaction::Deploy(_, owner, deployment, _) => (owner.address(), *deployment.program_id()),
+                _ => unreachable!("Expected a deployment transaction"),
+            };
+            assert_eq!(deployment_store.get_owned_programs(&owner).unwrap(), vec![program_id]);
 
             // Remove the deployment.
             deployment_store.remove(&transaction_id).unwrap();
@@ -704,6 +1094,60 @@ mod tests {
             // Ensure the deployment transaction does not exist.
             let candidate = deployment_store.get_transaction(&transaction_id).unwrap();
             assert_eq!(None, candidate);
+            // Ensure the program is no longer owned by the deployer.
+            assert!(deployment_store.get_owned_programs(&owner).unwrap().is_empty());
+        }
+    }
+
+    #[test]
+    fn test_insert_get_remove_deprecation() {
+        let rng = &mut TestRng::default();
+
+        // Sample the deployment.
+        let deployment_transaction = ledger_test_helpers::sample_deployment_transaction(true, rng);
+        let program_id = *ledger_test_helpers::sample_deployment(rng).program_id();
+
+        for is_fee_private in [true, false] {
+            // Sample the deprecation.
+            let transaction = ledger_test_helpers::sample_deprecation_transaction(is_fee_private, rng);
+            let transaction_id = transaction.id();
+
+            // Initialize a new transition store.
+            let transition_store = TransitionStore::open(None).unwrap();
+            // Initialize a new fee store.
+            let fee_store = FeeStore::open(transition_store).unwrap();
+            // Initialize a new deployment store.
+            let deployment_store = DeploymentStore::<_, DeploymentMemory<_>>::open(fee_store).unwrap();
+
+            // Ensure the deprecation of a program that does not exist fails.
+            assert!(deployment_store.insert_deprecation(&transaction).is_err());
+
+            // Insert the deployment transaction.
+            deployment_store.insert(&deployment_transaction).unwrap();
+            assert!(!deployment_store.is_deprecated(&program_id).unwrap());
+
+            // Insert the deprecation transaction.
+            deployment_store.insert_deprecation(&transaction).unwrap();
+            assert!(deployment_store.is_deprecated(&program_id).unwrap());
+            assert_eq!(Some(transaction_id), deployment_store.get_deprecation_transaction_id(&program_id).unwrap());
+
+            // Ensure the program remains readable.
+            assert!(deployment_store.get_program(&program_id).unwrap().is_some());
+
+            // Ensure the program cannot be deprecated twice.
+            assert!(deployment_store.insert_deprecation(&transaction).is_err());
+
+            // Retrieve the deprecation transaction.
+            let candidate = deployment_store.get_deprecation_transaction(&transaction_id).unwrap();
+            assert_eq!(Some(transaction), candidate);
+
+            // Remove the deprecation.
+            deployment_store.remove_deprecation(&transaction_id).unwrap();
+            assert!(!deployment_store.is_deprecated(&program_id).unwrap());
+
+            // Ensure the deprecation transaction does not exist.
+            let candidate = deployment_store.get_deprecation_transaction(&transaction_id).unwrap();
+            assert_eq!(None, candidate);
         }
     }
 
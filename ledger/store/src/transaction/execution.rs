@@ -38,6 +38,8 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
     type ReverseIDMap: for<'a> Map<'a, N::TransitionID, N::TransactionID>;
     /// The mapping of `transaction ID` to `(global state root, (optional) proof)`.
     type InclusionMap: for<'a> Map<'a, N::TransactionID, (N::StateRoot, Option<Proof<N>>)>;
+    /// The mapping of `transaction ID` to `sequence number`, for sequenced executions.
+    type SequenceMap: for<'a> Map<'a, N::TransactionID, u64>;
     /// The fee storage.
     type FeeStorage: FeeStorage<N>;
 
@@ -50,6 +52,8 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
     fn reverse_id_map(&self) -> &Self::ReverseIDMap;
     /// Returns the inclusion map.
     fn inclusion_map(&self) -> &Self::InclusionMap;
+    /// Returns the sequence map.
+    fn sequence_map(&self) -> &Self::SequenceMap;
     /// Returns the fee store.
     fn fee_store(&self) -> &FeeStore<N, Self::FeeStorage>;
     /// Returns the transition store.
@@ -67,6 +71,7 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         self.id_map().start_atomic();
         self.reverse_id_map().start_atomic();
         self.inclusion_map().start_atomic();
+        self.sequence_map().start_atomic();
         self.fee_store().start_atomic();
     }
 
@@ -75,6 +80,7 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         self.id_map().is_atomic_in_progress()
             || self.reverse_id_map().is_atomic_in_progress()
             || self.inclusion_map().is_atomic_in_progress()
+            || self.sequence_map().is_atomic_in_progress()
             || self.fee_store().is_atomic_in_progress()
     }
 
@@ -83,6 +89,7 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         self.id_map().atomic_checkpoint();
         self.reverse_id_map().atomic_checkpoint();
         self.inclusion_map().atomic_checkpoint();
+        self.sequence_map().atomic_checkpoint();
         self.fee_store().atomic_checkpoint();
     }
 
@@ -91,6 +98,7 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         self.id_map().clear_latest_checkpoint();
         self.reverse_id_map().clear_latest_checkpoint();
         self.inclusion_map().clear_latest_checkpoint();
+        self.sequence_map().clear_latest_checkpoint();
         self.fee_store().clear_latest_checkpoint();
     }
 
@@ -99,6 +107,7 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         self.id_map().atomic_rewind();
         self.reverse_id_map().atomic_rewind();
         self.inclusion_map().atomic_rewind();
+        self.sequence_map().atomic_rewind();
         self.fee_store().atomic_rewind();
     }
 
@@ -107,6 +116,7 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         self.id_map().abort_atomic();
         self.reverse_id_map().abort_atomic();
         self.inclusion_map().abort_atomic();
+        self.sequence_map().abort_atomic();
         self.fee_store().abort_atomic();
     }
 
@@ -115,6 +125,7 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         self.id_map().finish_atomic()?;
         self.reverse_id_map().finish_atomic()?;
         self.inclusion_map().finish_atomic()?;
+        self.sequence_map().finish_atomic()?;
         self.fee_store().finish_atomic()
     }
 
@@ -125,6 +136,7 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
             Transaction::Deploy(..) => bail!("Attempted to insert a deploy transaction into execution storage."),
             Transaction::Execute(transaction_id, execution, fee) => (transaction_id, execution, fee),
             Transaction::Fee(..) => bail!("Attempted to insert a fee transaction into execution storage."),
+            Transaction::Deprecate(..) => bail!("Attempted to insert a deprecate transaction into execution storage."),
         };
 
         // Retrieve the transitions.
@@ -135,6 +147,8 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         let global_state_root = execution.global_state_root();
         // Retrieve the proof.
         let proof = execution.proof().cloned();
+        // Retrieve the sequence number.
+        let sequence = execution.sequence();
 
         atomic_batch_scope!(self, {
             // Store the transition IDs.
@@ -151,6 +165,11 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
             // Store the global state root and proof.
             self.inclusion_map().insert(*transaction_id, (global_state_root, proof))?;
 
+            // Store the sequence number.
+            if let Some(sequence) = sequence {
+                self.sequence_map().insert(*transaction_id, sequence)?;
+            }
+
             // Store the fee.
             if let Some(fee) = fee {
                 // Store the fee.
@@ -183,6 +202,8 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
 
             // Remove the global state root and proof.
             self.inclusion_map().remove(transaction_id)?;
+            // Remove the sequence number.
+            self.sequence_map().remove(transaction_id)?;
 
             // Remove the fee.
             if has_fee {
@@ -223,6 +244,8 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
             Some(inclusion) => cow_to_cloned!(inclusion),
             None => bail!("Failed to get the proof for the transaction '{transaction_id}'"),
         };
+        // Retrieve the sequence number.
+        let sequence = self.sequence_map().get_confirmed(transaction_id)?.map(|sequence| cow_to_copied!(sequence));
 
         // Initialize a vector for the transitions.
         let mut transitions = Vec::new();
@@ -236,7 +259,7 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         }
 
         // Return the execution.
-        Ok(Some(Execution::from(transitions.into_iter(), global_state_root, proof)?))
+        Ok(Some(Execution::from(transitions.into_iter(), global_state_root, proof)?.with_sequence(sequence)?))
     }
 
     /// Returns the transaction for the given `transaction ID`.
@@ -252,6 +275,8 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
             Some(inclusion) => cow_to_cloned!(inclusion),
             None => bail!("Failed to get the proof for the transaction '{transaction_id}'"),
         };
+        // Retrieve the sequence number.
+        let sequence = self.sequence_map().get_confirmed(transaction_id)?.map(|sequence| cow_to_copied!(sequence));
 
         // Initialize a vector for the transitions.
         let mut transitions = Vec::new();
@@ -265,7 +290,7 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         }
 
         // Construct the execution.
-        let execution = Execution::from(transitions.into_iter(), global_state_root, proof)?;
+        let execution = Execution::from(transitions.into_iter(), global_state_root, proof)?.with_sequence(sequence)?;
 
         // Construct the transaction.
         let transaction = match has_fee {
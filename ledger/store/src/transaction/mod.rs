@@ -49,6 +49,8 @@ pub enum TransactionType {
     Execute,
     /// A transaction that is a fee.
     Fee,
+    /// A transaction that is a deprecation.
+    Deprecate,
 }
 
 /// A trait for transaction storage.
@@ -165,6 +167,12 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
                     // Store the fee transaction.
                     self.fee_store().insert(transaction.id(), fee)?;
                 }
+                Transaction::Deprecate(..) => {
+                    // Store the transaction type.
+                    self.id_map().insert(transaction.id(), TransactionType::Deprecate)?;
+                    // Store the deprecation transaction.
+                    self.deployment_store().insert_deprecation(transaction)?;
+                }
             }
             Ok(())
         })
@@ -189,6 +197,8 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
                 TransactionType::Execute => self.execution_store().remove(transaction_id)?,
                 // Remove the fee transaction.
                 TransactionType::Fee => self.fee_store().remove(transaction_id)?,
+                // Remove the deprecation transaction.
+                TransactionType::Deprecate => self.deployment_store().remove_deprecation(transaction_id)?,
             }
             Ok(())
         })
@@ -225,6 +235,8 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
                 Some(fee) => Ok(Some(Transaction::Fee(*transaction_id, fee))),
                 None => bail!("Failed to get fee for transaction '{transaction_id}'"),
             },
+            // Return the deprecation transaction.
+            TransactionType::Deprecate => self.deployment_store().get_deprecation_transaction(transaction_id),
         }
     }
 }
@@ -334,6 +346,10 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
             TransactionType::Execute => bail!("Tried to get a deployment for execution transaction '{transaction_id}'"),
             // Throw an error.
             TransactionType::Fee => bail!("Tried to get a deployment for fee transaction '{transaction_id}'"),
+            // Throw an error.
+            TransactionType::Deprecate => {
+                bail!("Tried to get a deployment for deprecation transaction '{transaction_id}'")
+            }
         }
     }
 
@@ -352,6 +368,10 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
             TransactionType::Execute => self.storage.execution_store().get_execution(transaction_id),
             // Throw an error.
             TransactionType::Fee => bail!("Tried to get an execution for fee transaction '{transaction_id}'"),
+            // Throw an error.
+            TransactionType::Deprecate => {
+                bail!("Tried to get an execution for deprecation transaction '{transaction_id}'")
+            }
         }
     }
 
@@ -365,11 +385,9 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
         // Retrieve the edition.
         match transaction_type {
             TransactionType::Deploy => {
-                // Retrieve the program ID.
-                let program_id = self.storage.deployment_store().get_program_id(transaction_id)?;
-                // Return the edition.
-                match program_id {
-                    Some(program_id) => self.storage.deployment_store().get_edition(&program_id),
+                // Return the edition deployed by the transaction.
+                match self.storage.deployment_store().get_transaction_edition(transaction_id)? {
+                    Some(edition) => Ok(Some(edition)),
                     None => bail!("Failed to get the program ID for deployment transaction '{transaction_id}'"),
                 }
             }
@@ -377,6 +395,8 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
             TransactionType::Execute => Ok(None),
             // Return 'None'.
             TransactionType::Fee => Ok(None),
+            // Return 'None'.
+            TransactionType::Deprecate => Ok(None),
         }
     }
 
@@ -390,6 +410,11 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
         self.storage.deployment_store().get_program(program_id)
     }
 
+    /// Returns `true` if the given program ID is deprecated.
+    pub fn is_deprecated(&self, program_id: &ProgramID<N>) -> Result<bool> {
+        self.storage.deployment_store().is_deprecated(program_id)
+    }
+
     /// Returns the verifying key for the given `(program ID, function name)`.
     pub fn get_verifying_key(
         &self,
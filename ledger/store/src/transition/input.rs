@@ -14,12 +14,13 @@
 
 use crate::{
     atomic_batch_scope,
+    cow_to_copied,
     helpers::{Map, MapRead},
 };
 use console::{
     network::prelude::*,
     program::{Ciphertext, Plaintext},
-    types::Field,
+    types::{Field, U32},
 };
 use ledger_block::Input;
 
@@ -43,6 +44,8 @@ pub trait InputStorage<N: Network>: Clone + Send + Sync {
     type RecordMap: for<'a> Map<'a, Field<N>, Field<N>>;
     /// The mapping of `tag` to `serial number`.
     type RecordTagMap: for<'a> Map<'a, Field<N>, Field<N>>;
+    /// The mapping of `serial number` to `lock height`, for time-locked records.
+    type RecordLockHeightMap: for<'a> Map<'a, Field<N>, U32<N>>;
     /// The mapping of `external hash` to `()`. Note: This is **not** the record commitment.
     type ExternalRecordMap: for<'a> Map<'a, Field<N>, ()>;
 
@@ -63,6 +66,8 @@ pub trait InputStorage<N: Network>: Clone + Send + Sync {
     fn record_map(&self) -> &Self::RecordMap;
     /// Returns the record tag map.
     fn record_tag_map(&self) -> &Self::RecordTagMap;
+    /// Returns the record lock height map.
+    fn record_lock_height_map(&self) -> &Self::RecordLockHeightMap;
     /// Returns the external record map.
     fn external_record_map(&self) -> &Self::ExternalRecordMap;
 
@@ -78,6 +83,7 @@ pub trait InputStorage<N: Network>: Clone + Send + Sync {
         self.private_map().start_atomic();
         self.record_map().start_atomic();
         self.record_tag_map().start_atomic();
+        self.record_lock_height_map().start_atomic();
         self.external_record_map().start_atomic();
     }
 
@@ -90,6 +96,7 @@ pub trait InputStorage<N: Network>: Clone + Send + Sync {
             || self.private_map().is_atomic_in_progress()
             || self.record_map().is_atomic_in_progress()
             || self.record_tag_map().is_atomic_in_progress()
+            || self.record_lock_height_map().is_atomic_in_progress()
             || self.external_record_map().is_atomic_in_progress()
     }
 
@@ -102,6 +109,7 @@ pub trait InputStorage<N: Network>: Clone + Send + Sync {
         self.private_map().atomic_checkpoint();
         self.record_map().atomic_checkpoint();
         self.record_tag_map().atomic_checkpoint();
+        self.record_lock_height_map().atomic_checkpoint();
         self.external_record_map().atomic_checkpoint();
     }
 
@@ -114,6 +122,7 @@ pub trait InputStorage<N: Network>: Clone + Send + Sync {
         self.private_map().clear_latest_checkpoint();
         self.record_map().clear_latest_checkpoint();
         self.record_tag_map().clear_latest_checkpoint();
+        self.record_lock_height_map().clear_latest_checkpoint();
         self.external_record_map().clear_latest_checkpoint();
     }
 
@@ -126,6 +135,7 @@ pub trait InputStorage<N: Network>: Clone + Send + Sync {
         self.private_map().atomic_rewind();
         self.record_map().atomic_rewind();
         self.record_tag_map().atomic_rewind();
+        self.record_lock_height_map().atomic_rewind();
         self.external_record_map().atomic_rewind();
     }
 
@@ -138,6 +148,7 @@ pub trait InputStorage<N: Network>: Clone + Send + Sync {
         self.private_map().abort_atomic();
         self.record_map().abort_atomic();
         self.record_tag_map().abort_atomic();
+        self.record_lock_height_map().abort_atomic();
         self.external_record_map().abort_atomic();
     }
 
@@ -150,6 +161,7 @@ pub trait InputStorage<N: Network>: Clone + Send + Sync {
         self.private_map().finish_atomic()?;
         self.record_map().finish_atomic()?;
         self.record_tag_map().finish_atomic()?;
+        self.record_lock_height_map().finish_atomic()?;
         self.external_record_map().finish_atomic()
     }
 
@@ -168,9 +180,13 @@ pub trait InputStorage<N: Network>: Clone + Send + Sync {
                     Input::Constant(input_id, constant) => self.constant_map().insert(input_id, constant)?,
                     Input::Public(input_id, public) => self.public_map().insert(input_id, public)?,
                     Input::Private(input_id, private) => self.private_map().insert(input_id, private)?,
-                    Input::Record(serial_number, tag) => {
+                    Input::Record(serial_number, tag, lock_height) => {
                         // Store the record tag.
                         self.record_tag_map().insert(tag, serial_number)?;
+                        // Store the lock height, if the record is time-locked.
+                        if let Some(lock_height) = lock_height {
+                            self.record_lock_height_map().insert(serial_number, lock_height)?;
+                        }
                         // Store the record.
                         self.record_map().insert(serial_number, tag)?
                     }
@@ -210,6 +226,7 @@ pub trait InputStorage<N: Network>: Clone + Send + Sync {
                 self.public_map().remove(&input_id)?;
                 self.private_map().remove(&input_id)?;
                 self.record_map().remove(&input_id)?;
+                self.record_lock_height_map().remove(&input_id)?;
                 self.external_record_map().remove(&input_id)?;
             }
 
@@ -241,9 +258,14 @@ pub trait InputStorage<N: Network>: Clone + Send + Sync {
         // Constructs the input given the input ID and input value.
         macro_rules! into_input {
             (Input::Record($input_id:ident, $input:expr)) => {
+                // Retrieve the lock height, if the record is time-locked.
+                let lock_height = self
+                    .record_lock_height_map()
+                    .get_confirmed(&$input_id)?
+                    .map(|lock_height| cow_to_copied!(lock_height));
                 match $input {
-                    Cow::Borrowed(tag) => Input::Record($input_id, *tag),
-                    Cow::Owned(tag) => Input::Record($input_id, tag),
+                    Cow::Borrowed(tag) => Input::Record($input_id, *tag, lock_height),
+                    Cow::Owned(tag) => Input::Record($input_id, tag, lock_height),
                 }
             };
             (Input::$Variant:ident($input_id:ident, $input:expr)) => {
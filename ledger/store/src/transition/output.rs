@@ -14,6 +14,7 @@
 
 use crate::{
     atomic_batch_scope,
+    cow_to_copied,
     helpers::{Map, MapRead},
 };
 use console::{
@@ -43,6 +44,8 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
     type RecordMap: for<'a> Map<'a, Field<N>, (Field<N>, Option<Record<N, Ciphertext<N>>>)>;
     /// The mapping of `record nonce` to `commitment`.
     type RecordNonceMap: for<'a> Map<'a, Group<N>, Field<N>>;
+    /// The mapping of `commitment` to `view tag`.
+    type RecordViewTagMap: for<'a> Map<'a, Field<N>, u8>;
     /// The mapping of `external hash` to `()`. Note: This is **not** the record commitment.
     type ExternalRecordMap: for<'a> Map<'a, Field<N>, ()>;
     /// The mapping of `future hash` to `(optional) future`.
@@ -65,6 +68,8 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
     fn record_map(&self) -> &Self::RecordMap;
     /// Returns the record nonce map.
     fn record_nonce_map(&self) -> &Self::RecordNonceMap;
+    /// Returns the record view tag map.
+    fn record_view_tag_map(&self) -> &Self::RecordViewTagMap;
     /// Returns the external record map.
     fn external_record_map(&self) -> &Self::ExternalRecordMap;
     /// Returns the future map.
@@ -82,6 +87,7 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
         self.private_map().start_atomic();
         self.record_map().start_atomic();
         self.record_nonce_map().start_atomic();
+        self.record_view_tag_map().start_atomic();
         self.external_record_map().start_atomic();
         self.future_map().start_atomic();
     }
@@ -95,6 +101,7 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
             || self.private_map().is_atomic_in_progress()
             || self.record_map().is_atomic_in_progress()
             || self.record_nonce_map().is_atomic_in_progress()
+            || self.record_view_tag_map().is_atomic_in_progress()
             || self.external_record_map().is_atomic_in_progress()
             || self.future_map().is_atomic_in_progress()
     }
@@ -108,6 +115,7 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
         self.private_map().atomic_checkpoint();
         self.record_map().atomic_checkpoint();
         self.record_nonce_map().atomic_checkpoint();
+        self.record_view_tag_map().atomic_checkpoint();
         self.external_record_map().atomic_checkpoint();
         self.future_map().atomic_checkpoint();
     }
@@ -121,6 +129,7 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
         self.private_map().clear_latest_checkpoint();
         self.record_map().clear_latest_checkpoint();
         self.record_nonce_map().clear_latest_checkpoint();
+        self.record_view_tag_map().clear_latest_checkpoint();
         self.external_record_map().clear_latest_checkpoint();
         self.future_map().clear_latest_checkpoint();
     }
@@ -134,6 +143,7 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
         self.private_map().atomic_rewind();
         self.record_map().atomic_rewind();
         self.record_nonce_map().atomic_rewind();
+        self.record_view_tag_map().atomic_rewind();
         self.external_record_map().atomic_rewind();
         self.future_map().atomic_rewind();
     }
@@ -147,6 +157,7 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
         self.private_map().abort_atomic();
         self.record_map().abort_atomic();
         self.record_nonce_map().abort_atomic();
+        self.record_view_tag_map().abort_atomic();
         self.external_record_map().abort_atomic();
         self.future_map().abort_atomic();
     }
@@ -160,6 +171,7 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
         self.private_map().finish_atomic()?;
         self.record_map().finish_atomic()?;
         self.record_nonce_map().finish_atomic()?;
+        self.record_view_tag_map().finish_atomic()?;
         self.external_record_map().finish_atomic()?;
         self.future_map().finish_atomic()
     }
@@ -179,11 +191,15 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
                     Output::Constant(output_id, constant) => self.constant_map().insert(output_id, constant)?,
                     Output::Public(output_id, public) => self.public_map().insert(output_id, public)?,
                     Output::Private(output_id, private) => self.private_map().insert(output_id, private)?,
-                    Output::Record(commitment, checksum, optional_record) => {
+                    Output::Record(commitment, checksum, optional_record, optional_view_tag) => {
                         // If the optional record exists, insert the record nonce.
                         if let Some(record) = &optional_record {
                             self.record_nonce_map().insert(*record.nonce(), commitment)?;
                         }
+                        // If the optional view tag exists, insert the view tag.
+                        if let Some(view_tag) = optional_view_tag {
+                            self.record_view_tag_map().insert(commitment, view_tag)?;
+                        }
                         // Insert the record entry.
                         self.record_map().insert(commitment, (checksum, optional_record))?
                     }
@@ -226,6 +242,7 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
                 self.public_map().remove(&output_id)?;
                 self.private_map().remove(&output_id)?;
                 self.record_map().remove(&output_id)?;
+                self.record_view_tag_map().remove(&output_id)?;
                 self.external_record_map().remove(&output_id)?;
                 self.future_map().remove(&output_id)?;
             }
@@ -257,10 +274,12 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
     fn get(&self, transition_id: &N::TransitionID) -> Result<Vec<Output<N>>> {
         // Constructs the output given the output ID and output value.
         macro_rules! into_output {
-            (Output::Record($output_id:ident, $output:expr)) => {
+            (Output::Record($output_id:ident, $output:expr, $view_tag:expr)) => {
                 match $output {
-                    Cow::Borrowed((checksum, opt_record)) => Output::Record($output_id, *checksum, opt_record.clone()),
-                    Cow::Owned((checksum, opt_record)) => Output::Record($output_id, checksum, opt_record),
+                    Cow::Borrowed((checksum, opt_record)) => {
+                        Output::Record($output_id, *checksum, opt_record.clone(), $view_tag)
+                    }
+                    Cow::Owned((checksum, opt_record)) => Output::Record($output_id, checksum, opt_record, $view_tag),
                 }
             };
             (Output::$Variant:ident($output_id:ident, $output:expr)) => {
@@ -283,7 +302,10 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
                 return Ok(into_output!(Output::Private(output_id, private)));
             }
             if let Some(record) = self.record_map().get_confirmed(&output_id)? {
-                return Ok(into_output!(Output::Record(output_id, record)));
+                // Retrieve the view tag, if it exists.
+                let view_tag =
+                    self.record_view_tag_map().get_confirmed(&output_id)?.map(|view_tag| cow_to_copied!(view_tag));
+                return Ok(into_output!(Output::Record(output_id, record, view_tag)));
             }
             if self.external_record_map().get_confirmed(&output_id)?.is_some() {
                 return Ok(Output::ExternalRecord(output_id));
@@ -436,6 +458,16 @@ impl<N: Network, O: OutputStorage<N>> OutputStore<N, O> {
     }
 }
 
+impl<N: Network, O: OutputStorage<N>> OutputStore<N, O> {
+    /// Returns the view tag for the given `commitment`, if the record output has a view tag.
+    pub fn get_record_view_tag(&self, commitment: &Field<N>) -> Result<Option<u8>> {
+        match self.storage.record_view_tag_map().get_confirmed(commitment)? {
+            Some(view_tag) => Ok(Some(cow_to_copied!(view_tag))),
+            None => Ok(None),
+        }
+    }
+}
+
 impl<N: Network, O: OutputStorage<N>> OutputStore<N, O> {
     /// Returns the transition ID that contains the given `output ID`.
     pub fn find_transition_id(&self, output_id: &Field<N>) -> Result<Option<N::TransitionID>> {
@@ -26,6 +26,7 @@ use ledger_block::{
     Fee,
     Header,
     Input,
+    MappingPolicy,
     Output,
     Ratifications,
     Rejected,
@@ -78,7 +79,7 @@ pub fn sample_inputs() -> Vec<(<CurrentNetwork as Network>::TransitionID, Input<
         (Uniform::rand(rng), Input::Public(plaintext_hash, Some(plaintext))),
         (Uniform::rand(rng), Input::Private(Uniform::rand(rng), None)),
         (Uniform::rand(rng), Input::Private(ciphertext_hash, Some(ciphertext))),
-        (Uniform::rand(rng), Input::Record(Uniform::rand(rng), Uniform::rand(rng))),
+        (Uniform::rand(rng), Input::Record(Uniform::rand(rng), Uniform::rand(rng), None)),
         (Uniform::rand(rng), Input::ExternalRecord(Uniform::rand(rng))),
     ]
 }
@@ -119,8 +120,8 @@ pub fn sample_outputs() -> Vec<(<CurrentNetwork as Network>::TransitionID, Outpu
         (Uniform::rand(rng), Output::Public(plaintext_hash, Some(plaintext))),
         (Uniform::rand(rng), Output::Private(Uniform::rand(rng), None)),
         (Uniform::rand(rng), Output::Private(ciphertext_hash, Some(ciphertext))),
-        (Uniform::rand(rng), Output::Record(Uniform::rand(rng), Uniform::rand(rng), None)),
-        (Uniform::rand(rng), Output::Record(Uniform::rand(rng), record_checksum, Some(record_ciphertext))),
+        (Uniform::rand(rng), Output::Record(Uniform::rand(rng), Uniform::rand(rng), None, None)),
+        (Uniform::rand(rng), Output::Record(Uniform::rand(rng), record_checksum, Some(record_ciphertext), None)),
         (Uniform::rand(rng), Output::ExternalRecord(Uniform::rand(rng))),
     ]
 }
@@ -337,6 +338,28 @@ pub fn sample_deployment_transaction(is_fee_private: bool, rng: &mut TestRng) ->
     Transaction::from_deployment(owner, deployment, fee).unwrap()
 }
 
+/// Samples a random deprecation transaction, for the program of `sample_deployment`, with a private or public fee.
+pub fn sample_deprecation_transaction(is_fee_private: bool, rng: &mut TestRng) -> Transaction<CurrentNetwork> {
+    // Sample a private key.
+    let private_key = PrivateKey::new(rng).unwrap();
+    // Retrieve the program ID.
+    let program_id = *crate::sample_deployment(rng).program_id();
+
+    // Compute the deprecation ID.
+    let deprecation_id = Transaction::deprecation_id(&program_id, MappingPolicy::Freeze).unwrap();
+    // Construct a program owner.
+    let owner = ProgramOwner::new(&private_key, deprecation_id, rng).unwrap();
+
+    // Sample the fee.
+    let fee = match is_fee_private {
+        true => crate::sample_fee_private(deprecation_id, rng),
+        false => crate::sample_fee_public(deprecation_id, rng),
+    };
+
+    // Construct a deprecation transaction.
+    Transaction::from_deprecation(owner, program_id, MappingPolicy::Freeze, fee).unwrap()
+}
+
 /// Samples a random execution transaction with a private or public fee.
 pub fn sample_execution_transaction_with_fee(is_fee_private: bool, rng: &mut TestRng) -> Transaction<CurrentNetwork> {
     // Sample an execution.
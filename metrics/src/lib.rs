@@ -20,6 +20,11 @@ pub mod committee {
     pub const TOTAL_STAKE: &str = "snarkvm_ledger_committee_total_stake";
 }
 
+pub mod finalize {
+    pub const COMMAND_COUNT: &str = "snarkvm_synthesizer_finalize_command_count";
+    pub const COMMAND_MICROS: &str = "snarkvm_synthesizer_finalize_command_micros";
+}
+
 /// Registers all snarkVM metrics.
 pub fn register_metrics() {
     for name in GAUGE_NAMES {
@@ -43,6 +48,15 @@ pub fn counter<V: Into<u64>>(name: &'static str, value: V) {
     counter.absolute(value.into());
 }
 
+/// Updates a counter with the given name and labels to the given value.
+///
+/// Counters represent a single monotonic value, which means the value can only be incremented,
+/// not decremented, and always starts out with an initial value of zero.
+pub fn counter_label<V: Into<u64>>(name: &'static str, labels: &[(&'static str, String)], value: V) {
+    let counter = ::metrics::counter!(name, labels);
+    counter.absolute(value.into());
+}
+
 /// Increments a counter with the given name by one.
 ///
 /// Counters represent a single monotonic value, which means the value can only be incremented,
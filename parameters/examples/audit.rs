@@ -0,0 +1,58 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkvm_console::{
+    account::{Address, PrivateKey},
+    network::MainnetV0,
+};
+use snarkvm_curves::bls12_377::Bls12_377;
+use snarkvm_parameters::mainnet::audit_parameters;
+
+use anyhow::Result;
+use serde_json::json;
+use std::{path::Path, str::FromStr};
+
+/// Run the following command to audit a directory of parameter files, and sign the report.
+/// `cargo run --release --example audit [directory] [private_key]`
+///
+/// If no private key is given, the report is signed with a new private key.
+pub fn main() -> Result<()> {
+    let args: Vec<String> = std::env::args().collect();
+    if args.len() < 2 {
+        eprintln!("Invalid number of arguments. Given: {} - Required: 1 or 2", args.len() - 1);
+        return Ok(());
+    }
+
+    let rng = &mut rand::thread_rng();
+    let private_key = match args.get(2) {
+        Some(private_key) => PrivateKey::<MainnetV0>::from_str(private_key)?,
+        None => PrivateKey::<MainnetV0>::new(rng)?,
+    };
+    let address = Address::try_from(&private_key)?;
+
+    // Audit the parameter files.
+    let report = audit_parameters::<Bls12_377, _>(Path::new(&args[1]), rng)?;
+    for check in report.checks() {
+        println!("{} - {}: {}", check.filename, check.check, check.status);
+    }
+
+    // Sign the report, and ensure the signature verifies.
+    let attestation = report.sign(|bytes| private_key.sign_bytes(bytes, rng))?;
+    assert!(attestation.verify(|bytes, signature| signature.verify_bytes(&address, bytes)));
+
+    let mut attestation = attestation.to_json();
+    attestation["signer"] = json!(address.to_string());
+    println!("\n{}", serde_json::to_string_pretty(&attestation)?);
+    Ok(())
+}
@@ -15,6 +15,7 @@
 use snarkvm_algorithms::crypto_hash::sha256::sha256;
 use snarkvm_circuit::Aleo;
 use snarkvm_console::network::{prelude::ToBytes, MainnetV0, Network};
+use snarkvm_parameters::{Manifest, CHUNK_SIZE};
 use snarkvm_synthesizer::{Process, Program};
 
 use anyhow::Result;
@@ -37,10 +38,20 @@ fn versioned_filename(filename: &str, checksum: &str) -> String {
     }
 }
 
-/// Writes the given bytes to the given versioned filename.
+/// Writes the given bytes to the given versioned filename, along with its manifest and chunks.
 fn write_remote(filename: &str, version: &str, bytes: &[u8]) -> Result<()> {
-    let mut file = BufWriter::new(File::create(PathBuf::from(&versioned_filename(filename, version)))?);
+    let remote_filename = versioned_filename(filename, version);
+    let mut file = BufWriter::new(File::create(PathBuf::from(&remote_filename))?);
     file.write_all(bytes)?;
+
+    // Write the manifest, and the chunks by their checksum, so provers can download only the changed chunks.
+    let manifest = Manifest::new(bytes, CHUNK_SIZE)?;
+    write_metadata(&format!("{remote_filename}.manifest"), &manifest.to_json())?;
+    let chunks_directory = PathBuf::from(&remote_filename).with_file_name("chunks");
+    fs::create_dir_all(&chunks_directory)?;
+    for (checksum, chunk) in manifest.split(bytes) {
+        write_local(chunks_directory.join(checksum).to_str().unwrap(), chunk)?;
+    }
     Ok(())
 }
 
@@ -122,10 +133,9 @@ pub fn credits_program<N: Network, A: Aleo<Network = N>>() -> Result<()> {
         write_remote(&format!("{function_name}.prover"), &proving_key_checksum, &proving_key_bytes)?;
         write_local(&format!("{function_name}.verifier"), &verifying_key_bytes)?;
 
-        commands.push(format!(
-            "upload \"{}\"",
-            versioned_filename(&format!("{function_name}.prover"), &proving_key_checksum)
-        ));
+        let prover_filename = versioned_filename(&format!("{function_name}.prover"), &proving_key_checksum);
+        commands.push(format!("upload \"{prover_filename}\""));
+        commands.push(format!("upload \"{prover_filename}.manifest\""));
     }
 
     // Print the commands.
@@ -133,6 +143,7 @@ pub fn credits_program<N: Network, A: Aleo<Network = N>>() -> Result<()> {
     for command in commands {
         println!("{command}");
     }
+    println!("upload the new files in \"chunks\"");
     println!();
 
     Ok(())
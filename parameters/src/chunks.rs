@@ -0,0 +1,308 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::errors::ParameterError;
+
+use serde_json::{json, Value};
+use std::collections::{HashMap, HashSet};
+
+/// The size in bytes of a parameter chunk.
+pub const CHUNK_SIZE: usize = 4 * 1024 * 1024;
+
+/// The manifest of a parameter file, which is split into fixed-size chunks that are addressed by their checksum.
+///
+/// A parameter file is published alongside its manifest (as `{filename}.manifest`) and its chunks
+/// (as `chunks/{checksum}`). As unchanged chunks keep their checksum across parameter updates,
+/// a prover only needs to download the chunks that are not found in a previous version of the file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Manifest {
+    /// The checksum of the parameter file.
+    checksum: String,
+    /// The size in bytes of the parameter file.
+    size: usize,
+    /// The size in bytes of each chunk, except the last chunk.
+    chunk_size: usize,
+    /// The checksums of the chunks, in order.
+    chunks: Vec<String>,
+}
+
+impl Manifest {
+    /// Initializes the manifest of the given parameter bytes, with the given chunk size.
+    pub fn new(bytes: &[u8], chunk_size: usize) -> Result<Self, ParameterError> {
+        // Ensure the chunk size is nonzero.
+        if chunk_size == 0 {
+            return Err(ParameterError::Message("The chunk size must be nonzero".to_string()));
+        }
+        Ok(Self {
+            checksum: checksum!(bytes),
+            size: bytes.len(),
+            chunk_size,
+            chunks: bytes.chunks(chunk_size).map(|chunk| checksum!(chunk)).collect(),
+        })
+    }
+
+    /// Returns the checksum of the parameter file.
+    pub fn checksum(&self) -> &str {
+        &self.checksum
+    }
+
+    /// Returns the size in bytes of the parameter file.
+    pub const fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns the size in bytes of each chunk, except the last chunk.
+    pub const fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+
+    /// Returns the checksums of the chunks, in order.
+    pub fn chunks(&self) -> &[String] {
+        &self.chunks
+    }
+
+    /// Returns the URL of the chunk with the given checksum.
+    pub fn chunk_url(remote_url: &str, checksum: &str) -> String {
+        format!("{remote_url}/chunks/{checksum}")
+    }
+
+    /// Returns the checksums of the chunks that are not in the given previous manifest.
+    /// These are the only chunks that are downloaded when updating from the previous version.
+    pub fn diff(&self, previous: &Manifest) -> Vec<&str> {
+        let previous = previous.chunks.iter().collect::<HashSet<_>>();
+        let mut seen = HashSet::new();
+        self.chunks
+            .iter()
+            .filter(|checksum| !previous.contains(checksum) && seen.insert(*checksum))
+            .map(String::as_str)
+            .collect()
+    }
+
+    /// Returns the chunks of the given parameter bytes, along with their checksums.
+    pub fn split<'a>(&'a self, bytes: &'a [u8]) -> impl Iterator<Item = (&'a str, &'a [u8])> {
+        self.chunks.iter().map(String::as_str).zip(bytes.chunks(self.chunk_size))
+    }
+
+    /// Returns the chunks of the given previous versions of the parameter file that are in this manifest.
+    pub fn reusable_chunks<'a>(&self, previous: impl IntoIterator<Item = &'a [u8]>) -> HashMap<String, Vec<u8>> {
+        let needed = self.chunks.iter().collect::<HashSet<_>>();
+        let mut reusable = HashMap::new();
+        for bytes in previous {
+            for chunk in bytes.chunks(self.chunk_size) {
+                let checksum = checksum!(chunk);
+                if needed.contains(&checksum) {
+                    reusable.entry(checksum).or_insert_with(|| chunk.to_vec());
+                }
+            }
+        }
+        reusable
+    }
+
+    /// Returns the parameter bytes, assembled from the given reusable chunks,
+    /// and from the chunks downloaded with the given `fetch` function.
+    /// Each downloaded chunk is verified against its checksum, as is the assembled file.
+    pub fn assemble(
+        &self,
+        reusable: &HashMap<String, Vec<u8>>,
+        mut fetch: impl FnMut(&str) -> Result<Vec<u8>, ParameterError>,
+    ) -> Result<Vec<u8>, ParameterError> {
+        let mut buffer = Vec::with_capacity(self.size);
+        for (index, checksum) in self.chunks.iter().enumerate() {
+            match reusable.get(checksum) {
+                Some(chunk) => buffer.extend_from_slice(chunk),
+                None => {
+                    let chunk = fetch(checksum)?;
+                    self.verify_chunk(index, &chunk)?;
+                    buffer.extend_from_slice(&chunk);
+                }
+            }
+        }
+
+        // Ensure the size matches.
+        if self.size != buffer.len() {
+            return Err(ParameterError::SizeMismatch(self.size, buffer.len()));
+        }
+        // Ensure the checksum matches.
+        let candidate_checksum = checksum!(&buffer);
+        if self.checksum != candidate_checksum {
+            return checksum_error!(self.checksum.clone(), candidate_checksum);
+        }
+        Ok(buffer)
+    }
+
+    /// Ensures the given bytes are the chunk at the given index.
+    pub fn verify_chunk(&self, index: usize, chunk: &[u8]) -> Result<(), ParameterError> {
+        // Retrieve the expected checksum.
+        let Some(expected_checksum) = self.chunks.get(index) else {
+            return Err(ParameterError::Message(format!("Chunk index {index} is out of bounds")));
+        };
+        // Ensure the size matches.
+        let expected_size = match index + 1 == self.chunks.len() {
+            true => self.size - index * self.chunk_size,
+            false => self.chunk_size,
+        };
+        if expected_size != chunk.len() {
+            return Err(ParameterError::SizeMismatch(expected_size, chunk.len()));
+        }
+        // Ensure the checksum matches.
+        let candidate_checksum = checksum!(chunk);
+        if *expected_checksum != candidate_checksum {
+            return checksum_error!(expected_checksum.clone(), candidate_checksum);
+        }
+        Ok(())
+    }
+
+    /// Returns the manifest as JSON.
+    pub fn to_json(&self) -> Value {
+        json!({
+            "checksum": self.checksum,
+            "size": self.size,
+            "chunk_size": self.chunk_size,
+            "chunks": self.chunks,
+        })
+    }
+
+    /// Initializes the manifest from the given JSON bytes.
+    pub fn from_json(bytes: &[u8]) -> Result<Self, ParameterError> {
+        let error = |field: &str| ParameterError::Message(format!("The manifest has an invalid '{field}'"));
+
+        let manifest: Value = serde_json::from_slice(bytes).map_err(|e| ParameterError::Message(e.to_string()))?;
+        let checksum = manifest["checksum"].as_str().ok_or_else(|| error("checksum"))?.to_string();
+        let size =
+            manifest["size"].as_u64().and_then(|size| usize::try_from(size).ok()).ok_or_else(|| error("size"))?;
+        let chunk_size = manifest["chunk_size"]
+            .as_u64()
+            .and_then(|chunk_size| usize::try_from(chunk_size).ok())
+            .filter(|chunk_size| *chunk_size > 0)
+            .ok_or_else(|| error("chunk_size"))?;
+        let chunks = manifest["chunks"]
+            .as_array()
+            .ok_or_else(|| error("chunks"))?
+            .iter()
+            .map(|checksum| checksum.as_str().map(str::to_string).ok_or_else(|| error("chunks")))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // Ensure the number of chunks matches the size.
+        if chunks.len() != size / chunk_size + usize::from(size % chunk_size != 0) {
+            return Err(error("chunks"));
+        }
+        Ok(Self { checksum, size, chunk_size, chunks })
+    }
+}
+
+/// Returns the paths of the other versions of the given versioned parameter file, in the same directory.
+/// The versions of a parameter file are named `{filename}.{version}`.
+#[cfg(not(feature = "wasm"))]
+pub fn previous_versions(file_path: &std::path::Path) -> Vec<std::path::PathBuf> {
+    // Retrieve the unversioned filename, including the trailing period.
+    let Some(filename) = file_path.file_name().and_then(|filename| filename.to_str()) else { return vec![] };
+    let Some(prefix) = filename.rfind('.').map(|index| &filename[..=index]) else { return vec![] };
+    // Retrieve the other versions in the directory.
+    let Some(Ok(entries)) = file_path.parent().map(std::fs::read_dir) else { return vec![] };
+    entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            let name = path.file_name().and_then(|name| name.to_str()).unwrap_or_default();
+            path != file_path && name.starts_with(prefix) && !name[prefix.len()..].contains('.')
+        })
+        .collect()
+}
+
+/// Returns the size in bytes and the checksum of the given file.
+/// The file is read in chunks, so that it is never loaded fully into memory.
+#[cfg(not(feature = "wasm"))]
+pub fn checksum_file(file_path: &std::path::Path) -> Result<(usize, String), ParameterError> {
+    use sha2::Digest;
+    use std::io::Read;
+
+    let mut file = std::fs::File::open(file_path)?;
+    let mut hasher = sha2::Sha256::new();
+    let mut buffer = vec![0u8; CHUNK_SIZE];
+    let mut size = 0;
+    loop {
+        let num_bytes = file.read(&mut buffer)?;
+        if num_bytes == 0 {
+            break;
+        }
+        hasher.update(&buffer[..num_bytes]);
+        size += num_bytes;
+    }
+    Ok((size, hex::encode(hasher.finalize())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rand::{Rng, SeedableRng};
+
+    /// Samples the given number of random bytes.
+    fn sample_bytes(num_bytes: usize) -> Vec<u8> {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(1234567890);
+        (0..num_bytes).map(|_| rng.gen()).collect()
+    }
+
+    #[test]
+    fn test_manifest_json() {
+        let bytes = sample_bytes(1000);
+        for chunk_size in [1, 7, 100, 1000, 1024] {
+            let manifest = Manifest::new(&bytes, chunk_size).unwrap();
+            assert_eq!(manifest.chunks().len(), (bytes.len() + chunk_size - 1) / chunk_size);
+            assert_eq!(manifest.checksum(), checksum!(&bytes));
+            // Ensure the manifest round-trips through JSON.
+            let json = serde_json::to_vec(&manifest.to_json()).unwrap();
+            assert_eq!(Manifest::from_json(&json).unwrap(), manifest);
+        }
+        // Ensure a manifest with a missing chunk is rejected.
+        let mut json = Manifest::new(&bytes, 100).unwrap().to_json();
+        json["chunks"].as_array_mut().unwrap().pop();
+        assert!(Manifest::from_json(&serde_json::to_vec(&json).unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_assemble_delta() {
+        let chunk_size = 64;
+        let previous = sample_bytes(1000);
+        // Update a single chunk, and append to the last chunk.
+        let mut bytes = previous.clone();
+        bytes[200] ^= 1;
+        bytes.extend_from_slice(&[7u8; 10]);
+
+        let previous_manifest = Manifest::new(&previous, chunk_size).unwrap();
+        let manifest = Manifest::new(&bytes, chunk_size).unwrap();
+        // Ensure only the updated chunk and the last chunk changed.
+        assert_eq!(manifest.diff(&previous_manifest).len(), 2);
+
+        // Assemble the file, downloading only the chunks that are not in the previous version.
+        let chunks = manifest.split(&bytes).collect::<HashMap<_, _>>();
+        let reusable = manifest.reusable_chunks([previous.as_slice()]);
+        let mut downloaded = vec![];
+        let candidate = manifest
+            .assemble(&reusable, |checksum| {
+                downloaded.push(checksum.to_string());
+                Ok(chunks[checksum].to_vec())
+            })
+            .unwrap();
+        assert_eq!(candidate, bytes);
+        assert_eq!(downloaded, manifest.diff(&previous_manifest));
+
+        // Ensure a corrupted chunk is rejected.
+        let result = manifest.assemble(&HashMap::new(), |checksum| {
+            let mut chunk = chunks[checksum].to_vec();
+            chunk[0] ^= 1;
+            Ok(chunk)
+        });
+        assert!(matches!(result, Err(ParameterError::ChecksumMismatch(..))));
+    }
+}
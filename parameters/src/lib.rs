@@ -27,6 +27,9 @@ extern crate thiserror;
 #[macro_use]
 pub mod macros;
 
+pub mod chunks;
+pub use chunks::*;
+
 pub mod errors;
 pub use errors::*;
 
@@ -101,6 +101,41 @@ macro_rules! impl_store_and_remote_fetch {
             Ok(transfer.perform()?)
         }
 
+        #[cfg(not(feature = "wasm"))]
+        fn remote_fetch_with_manifest(
+            buffer: &mut Vec<u8>,
+            remote_url: &str,
+            filename: &str,
+            file_path: &std::path::Path,
+            expected_checksum: &str,
+        ) -> Result<(), $crate::errors::ParameterError> {
+            // Fetch the manifest of the parameter file, if the remote provides one.
+            let mut manifest_bytes = vec![];
+            let manifest = Self::remote_fetch(&mut manifest_bytes, &format!("{}/{}.manifest", remote_url, filename))
+                .ok()
+                .and_then(|()| $crate::Manifest::from_json(&manifest_bytes).ok())
+                .filter(|manifest| manifest.checksum() == expected_checksum);
+
+            match manifest {
+                // Download only the chunks that are not found in a previous version of the parameter file.
+                Some(manifest) => {
+                    let previous = $crate::previous_versions(file_path)
+                        .into_iter()
+                        .filter_map(|path| std::fs::read(path).ok())
+                        .collect::<Vec<_>>();
+                    let reusable = manifest.reusable_chunks(previous.iter().map(Vec::as_slice));
+                    *buffer = manifest.assemble(&reusable, |checksum| {
+                        let mut chunk = vec![];
+                        Self::remote_fetch(&mut chunk, &$crate::Manifest::chunk_url(remote_url, checksum))?;
+                        Ok(chunk)
+                    })?;
+                    Ok(())
+                }
+                // Otherwise, download the entire parameter file.
+                None => Self::remote_fetch(buffer, &format!("{}/{}", remote_url, filename)),
+            }
+        }
+
         #[cfg(feature = "wasm")]
         fn remote_fetch(url: &str) -> Result<Vec<u8>, $crate::errors::ParameterError> {
             // Use the browser's XmlHttpRequest object to download the parameter file synchronously.
@@ -188,14 +223,12 @@ macro_rules! impl_load_bytes_logic_remote {
                 );
             }
 
-            // Construct the URL.
-            let url = format!("{}/{}", $remote_url, $filename);
-
             // Load remote file
             cfg_if::cfg_if! {
                 if #[cfg(not(feature = "wasm"))] {
+                    // Download the parameter file, or only its changed chunks if the remote provides a manifest.
                     let mut buffer = vec![];
-                    Self::remote_fetch(&mut buffer, &url)?;
+                    Self::remote_fetch_with_manifest(&mut buffer, $remote_url, $filename, &file_path, &$expected_checksum)?;
 
                     // Ensure the checksum matches.
                     let candidate_checksum = checksum!(&buffer);
@@ -214,6 +247,8 @@ macro_rules! impl_load_bytes_logic_remote {
                         }
                     }
                 } else if #[cfg(feature = "wasm")] {
+                    // Construct the URL.
+                    let url = format!("{}/{}", $remote_url, $filename);
                     let buffer = Self::remote_fetch(&url)?;
 
                     // Ensure the checksum matches.
@@ -342,6 +377,54 @@ macro_rules! impl_remote {
                     expected_size
                 );
             }
+
+            /// Returns the path of the parameter file on disk, after downloading it if it does not exist.
+            /// The file is verified in chunks, so that it can be read lazily instead of loaded fully into memory.
+            #[cfg(not(feature = "wasm"))]
+            pub fn load_path() -> Result<std::path::PathBuf, $crate::errors::ParameterError> {
+                // The path of the parameter file, once it is verified.
+                static FILE_PATH: std::sync::OnceLock<std::path::PathBuf> = std::sync::OnceLock::new();
+                if let Some(file_path) = FILE_PATH.get() {
+                    return Ok(file_path.clone());
+                }
+
+                let metadata: serde_json::Value =
+                    serde_json::from_str(Self::METADATA).expect("Metadata was not well-formatted");
+                let expected_checksum: String =
+                    metadata["checksum"].as_str().expect("Failed to parse checksum").to_string();
+                let expected_size: usize =
+                    metadata["size"].to_string().parse().expect("Failed to retrieve the file size");
+
+                // Compose the versioned file path.
+                let mut file_path = aleo_std::aleo_dir();
+                file_path.push($local_dir);
+                match expected_checksum.get(0..7) {
+                    Some(sum) => file_path.push(format!("{}.{}.{}", $fname, "usrs", sum)),
+                    _ => file_path.push(format!("{}.{}", $fname, "usrs")),
+                };
+
+                // Download and store the parameter file, if it does not exist.
+                if !file_path.exists() {
+                    Self::load_bytes()?;
+                    if !file_path.exists() {
+                        return Err($crate::errors::ParameterError::Message(format!(
+                            "Failed to store the parameter file in {file_path:?}"
+                        )));
+                    }
+                }
+
+                // Ensure the size and checksum match.
+                let (size, candidate_checksum) = $crate::checksum_file(&file_path)?;
+                if expected_size != size {
+                    remove_file!(file_path);
+                    return Err($crate::errors::ParameterError::SizeMismatch(expected_size, size));
+                }
+                if expected_checksum != candidate_checksum {
+                    return checksum_error!(expected_checksum, candidate_checksum);
+                }
+
+                Ok(FILE_PATH.get_or_init(|| file_path).clone())
+            }
         }
         paste::item! {
             #[cfg(test)]
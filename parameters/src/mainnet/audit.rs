@@ -0,0 +1,515 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_curves::traits::{AffineCurve, PairingEngine};
+use snarkvm_utilities::CanonicalDeserialize;
+
+use anyhow::{anyhow, ensure, Result};
+use rand::Rng;
+use serde_json::{json, Value};
+use std::{
+    collections::BTreeMap,
+    fmt,
+    path::{Path, PathBuf},
+};
+
+/// The parameter files that are audited, as `(filename, file type, metadata)`.
+const PARAMETER_FILES: &[(&str, &str, &str)] = &[
+    ("powers-of-beta-15", "usrs", Degree15::METADATA),
+    ("powers-of-beta-16", "usrs", Degree16::METADATA),
+    ("powers-of-beta-17", "usrs", Degree17::METADATA),
+    ("powers-of-beta-18", "usrs", Degree18::METADATA),
+    ("powers-of-beta-19", "usrs", Degree19::METADATA),
+    ("powers-of-beta-20", "usrs", Degree20::METADATA),
+    ("powers-of-beta-21", "usrs", Degree21::METADATA),
+    ("powers-of-beta-22", "usrs", Degree22::METADATA),
+    ("powers-of-beta-23", "usrs", Degree23::METADATA),
+    ("powers-of-beta-24", "usrs", Degree24::METADATA),
+    ("powers-of-beta-25", "usrs", Degree25::METADATA),
+    ("powers-of-beta-26", "usrs", Degree26::METADATA),
+    ("powers-of-beta-27", "usrs", Degree27::METADATA),
+    ("powers-of-beta-28", "usrs", Degree28::METADATA),
+    ("shifted-powers-of-beta-15", "usrs", ShiftedDegree15::METADATA),
+    ("shifted-powers-of-beta-16", "usrs", ShiftedDegree16::METADATA),
+    ("shifted-powers-of-beta-17", "usrs", ShiftedDegree17::METADATA),
+    ("shifted-powers-of-beta-18", "usrs", ShiftedDegree18::METADATA),
+    ("shifted-powers-of-beta-19", "usrs", ShiftedDegree19::METADATA),
+    ("shifted-powers-of-beta-20", "usrs", ShiftedDegree20::METADATA),
+    ("shifted-powers-of-beta-21", "usrs", ShiftedDegree21::METADATA),
+    ("shifted-powers-of-beta-22", "usrs", ShiftedDegree22::METADATA),
+    ("shifted-powers-of-beta-23", "usrs", ShiftedDegree23::METADATA),
+    ("shifted-powers-of-beta-24", "usrs", ShiftedDegree24::METADATA),
+    ("shifted-powers-of-beta-25", "usrs", ShiftedDegree25::METADATA),
+    ("shifted-powers-of-beta-26", "usrs", ShiftedDegree26::METADATA),
+    ("shifted-powers-of-beta-27", "usrs", ShiftedDegree27::METADATA),
+    ("powers-of-beta-gamma", "usrs", Gamma::METADATA),
+    ("neg-powers-of-beta", "usrs", NegBeta::METADATA),
+    ("beta-h", "usrs", BetaH::METADATA),
+    ("bond_public", "prover", BondPublicProver::METADATA),
+    ("bond_public", "verifier", BondPublicVerifier::METADATA),
+    ("unbond_public", "prover", UnbondPublicProver::METADATA),
+    ("unbond_public", "verifier", UnbondPublicVerifier::METADATA),
+    ("unbond_delegator_as_validator", "prover", UnbondDelegatorAsValidatorProver::METADATA),
+    ("unbond_delegator_as_validator", "verifier", UnbondDelegatorAsValidatorVerifier::METADATA),
+    ("claim_unbond_public", "prover", ClaimUnbondPublicProver::METADATA),
+    ("claim_unbond_public", "verifier", ClaimUnbondPublicVerifier::METADATA),
+    ("set_validator_state", "prover", SetValidatorStateProver::METADATA),
+    ("set_validator_state", "verifier", SetValidatorStateVerifier::METADATA),
+    ("transfer_private", "prover", TransferPrivateProver::METADATA),
+    ("transfer_private", "verifier", TransferPrivateVerifier::METADATA),
+    ("transfer_public", "prover", TransferPublicProver::METADATA),
+    ("transfer_public", "verifier", TransferPublicVerifier::METADATA),
+    ("transfer_private_to_public", "prover", TransferPrivateToPublicProver::METADATA),
+    ("transfer_private_to_public", "verifier", TransferPrivateToPublicVerifier::METADATA),
+    ("transfer_public_to_private", "prover", TransferPublicToPrivateProver::METADATA),
+    ("transfer_public_to_private", "verifier", TransferPublicToPrivateVerifier::METADATA),
+    ("join", "prover", JoinProver::METADATA),
+    ("join", "verifier", JoinVerifier::METADATA),
+    ("split", "prover", SplitProver::METADATA),
+    ("split", "verifier", SplitVerifier::METADATA),
+    ("fee_private", "prover", FeePrivateProver::METADATA),
+    ("fee_private", "verifier", FeePrivateVerifier::METADATA),
+    ("fee_public", "prover", FeePublicProver::METADATA),
+    ("fee_public", "verifier", FeePublicVerifier::METADATA),
+    ("inclusion", "prover", InclusionProver::METADATA),
+    ("inclusion", "verifier", InclusionVerifier::METADATA),
+];
+
+/// The status of a check in a parameter audit.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AuditStatus {
+    /// The check passed.
+    Passed,
+    /// The check failed, for the given reason.
+    Failed(String),
+    /// The file is not in the audited directory.
+    Missing,
+    /// The check was not performed, for the given reason.
+    Skipped(String),
+}
+
+impl fmt::Display for AuditStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Passed => write!(f, "passed"),
+            Self::Failed(reason) => write!(f, "failed ({reason})"),
+            Self::Missing => write!(f, "missing"),
+            Self::Skipped(reason) => write!(f, "skipped ({reason})"),
+        }
+    }
+}
+
+/// A check of a parameter file in a parameter audit.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AuditCheck {
+    /// The name of the parameter file.
+    pub filename: String,
+    /// The name of the check, which is one of `checksum`, `generator`, `degree_bound`, or `pairing`.
+    pub check: &'static str,
+    /// The status of the check.
+    pub status: AuditStatus,
+}
+
+/// The report of a parameter audit, which lists the checks of each parameter file in a directory.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AuditReport {
+    /// The audited directory.
+    directory: String,
+    /// The checks of the parameter files.
+    checks: Vec<AuditCheck>,
+}
+
+impl AuditReport {
+    /// Returns the audited directory.
+    pub fn directory(&self) -> &str {
+        &self.directory
+    }
+
+    /// Returns the checks of the parameter files.
+    pub fn checks(&self) -> &[AuditCheck] {
+        &self.checks
+    }
+
+    /// Returns `true` if no check failed.
+    /// Note: Missing files are not a failure, as a directory may only hold the parameters it uses.
+    pub fn is_valid(&self) -> bool {
+        !self.checks.iter().any(|check| matches!(check.status, AuditStatus::Failed(_)))
+    }
+
+    /// Returns the report as JSON.
+    pub fn to_json(&self) -> Value {
+        let checks = self
+            .checks
+            .iter()
+            .map(|check| {
+                let (status, reason) = match &check.status {
+                    AuditStatus::Passed => ("passed", None),
+                    AuditStatus::Failed(reason) => ("failed", Some(reason)),
+                    AuditStatus::Missing => ("missing", None),
+                    AuditStatus::Skipped(reason) => ("skipped", Some(reason)),
+                };
+                json!({ "file": check.filename, "check": check.check, "status": status, "reason": reason })
+            })
+            .collect::<Vec<_>>();
+        json!({ "directory": self.directory, "valid": self.is_valid(), "checks": checks })
+    }
+
+    /// Returns the report as JSON bytes, which are the message of an attestation.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.to_json().to_string().into_bytes()
+    }
+
+    /// Returns the checksum of the report.
+    pub fn checksum(&self) -> String {
+        checksum!(&self.to_bytes())
+    }
+
+    /// Returns an attestation of the report, signed by the given `sign` function over the report bytes.
+    pub fn sign<S>(self, sign: impl FnOnce(&[u8]) -> Result<S>) -> Result<Attestation<S>> {
+        let signature = sign(&self.to_bytes())?;
+        Ok(Attestation { report: self, signature })
+    }
+
+    /// Adds a check with the given status.
+    fn push(&mut self, filename: &str, check: &'static str, status: AuditStatus) {
+        self.checks.push(AuditCheck { filename: filename.to_string(), check, status });
+    }
+}
+
+/// A signed report of a parameter audit.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Attestation<S> {
+    /// The report.
+    report: AuditReport,
+    /// The signature over the report bytes.
+    signature: S,
+}
+
+impl<S> Attestation<S> {
+    /// Returns the report.
+    pub const fn report(&self) -> &AuditReport {
+        &self.report
+    }
+
+    /// Returns the signature over the report bytes.
+    pub const fn signature(&self) -> &S {
+        &self.signature
+    }
+
+    /// Returns `true` if the given `verify` function accepts the signature over the report bytes.
+    pub fn verify(&self, verify: impl FnOnce(&[u8], &S) -> bool) -> bool {
+        verify(&self.report.to_bytes(), &self.signature)
+    }
+}
+
+impl<S: fmt::Display> Attestation<S> {
+    /// Returns the attestation as JSON.
+    pub fn to_json(&self) -> Value {
+        json!({
+            "report": self.report.to_json(),
+            "checksum": self.report.checksum(),
+            "signature": self.signature.to_string(),
+        })
+    }
+}
+
+/// Audits the parameter files in the given directory, without downloading any file.
+///
+/// For each parameter file, this checks the checksum and size against the metadata of this build.
+/// For the universal SRS, this also checks the number of powers in each file, and checks with pairings
+/// that each file holds consecutive powers of beta, which are consistent with `beta * H`.
+/// The parameter files may be named as in the resources directory, or with their versioned remote names.
+pub fn audit_parameters<E: PairingEngine, R: Rng>(directory: &Path, rng: &mut R) -> Result<AuditReport> {
+    ensure!(directory.is_dir(), "'{}' is not a directory", directory.display());
+
+    let mut report = AuditReport { directory: directory.display().to_string(), checks: vec![] };
+    let mut paths = BTreeMap::new();
+
+    // Check the checksum and size of each parameter file.
+    for (name, file_type, metadata) in PARAMETER_FILES {
+        let filename = format!("{name}.{file_type}");
+        let (expected_checksum, expected_size) = expected_checksum_and_size(file_type, metadata)?;
+        let Some(path) = locate(directory, &filename, &expected_checksum) else {
+            report.push(&filename, "checksum", AuditStatus::Missing);
+            continue;
+        };
+        let bytes = std::fs::read(&path)?;
+        let candidate_checksum = checksum!(&bytes);
+        let status = if bytes.len() != expected_size {
+            AuditStatus::Failed(format!("expected {expected_size} bytes, found {} bytes", bytes.len()))
+        } else if candidate_checksum != expected_checksum {
+            AuditStatus::Failed(format!("expected checksum {expected_checksum}, found {candidate_checksum}"))
+        } else {
+            AuditStatus::Passed
+        };
+        report.push(&filename, "checksum", status);
+        paths.insert(filename, path);
+    }
+
+    // Check the universal SRS.
+    audit_srs::<E, R>(&mut report, &paths, rng);
+
+    Ok(report)
+}
+
+/// Checks the degree bounds and the pairing consistency of the universal SRS files in the given paths.
+fn audit_srs<E: PairingEngine, R: Rng>(report: &mut AuditReport, paths: &BTreeMap<String, PathBuf>, rng: &mut R) {
+    let generator = E::G1Affine::prime_subgroup_generator();
+    let beta_h = read::<E::G2Affine>(paths, "beta-h.usrs").and_then(|beta_h| beta_h.ok());
+
+    // Returns the pairing status of the given consecutive powers of beta.
+    let mut pairing_status = |powers: &[E::G1Affine], link: Option<[E::G1Affine; 2]>| match beta_h {
+        None => AuditStatus::Skipped("'beta-h.usrs' is missing or invalid".to_string()),
+        Some(beta_h) => match is_consecutive::<E, R>(powers, beta_h, rng)
+            && link.iter().all(|link| is_consecutive::<E, R>(link, beta_h, rng))
+        {
+            true => AuditStatus::Passed,
+            false => AuditStatus::Failed("the powers are not consecutive powers of beta".to_string()),
+        },
+    };
+
+    // Check the powers of beta G, where each file continues the powers of the previous file.
+    let mut first_powers = None;
+    let mut last_power = None;
+    for degree in 15..=28 {
+        let filename = format!("powers-of-beta-{degree}.usrs");
+        let Some(powers) = read_powers::<E>(report, paths, &filename, num_powers(degree)) else {
+            last_power = None;
+            continue;
+        };
+        if degree == 15 {
+            let status = match powers.first() == Some(&generator) {
+                true => AuditStatus::Passed,
+                false => AuditStatus::Failed("the first power is not the generator".to_string()),
+            };
+            report.push(&filename, "generator", status);
+        }
+        let link = last_power.zip(powers.first().copied()).map(|(last, first)| [last, first]);
+        report.push(&filename, "pairing", pairing_status(&powers, link));
+        last_power = powers.last().copied();
+        if degree == 15 {
+            first_powers = Some(powers);
+        }
+    }
+
+    // Check the shifted powers of beta G, where each file precedes the powers of the previous file.
+    let mut first_power = None;
+    for degree in 15..=27 {
+        let filename = format!("shifted-powers-of-beta-{degree}.usrs");
+        let Some(powers) = read_powers::<E>(report, paths, &filename, num_powers(degree)) else {
+            first_power = None;
+            continue;
+        };
+        let link = powers.last().copied().zip(first_power).map(|(last, first)| [last, first]);
+        report.push(&filename, "pairing", pairing_status(&powers, link));
+        first_power = powers.first().copied();
+    }
+
+    // Check the powers of beta times gamma G, which are stored in runs of consecutive powers.
+    let filename = "powers-of-beta-gamma.usrs";
+    match read::<BTreeMap<usize, E::G1Affine>>(paths, filename) {
+        None => (),
+        Some(Err(error)) => report.push(filename, "pairing", AuditStatus::Failed(error)),
+        Some(Ok(powers)) => {
+            // Split the powers into runs of consecutive powers.
+            let mut runs: Vec<Vec<E::G1Affine>> = vec![];
+            let mut previous_index = None;
+            for (index, power) in powers {
+                match previous_index.map(|previous| previous + 1) == Some(index) {
+                    true => runs.last_mut().unwrap().push(power),
+                    false => runs.push(vec![power]),
+                }
+                previous_index = Some(index);
+            }
+            let status = runs.iter().fold(AuditStatus::Passed, |status, run| match status {
+                AuditStatus::Passed => pairing_status(run, None),
+                status => status,
+            });
+            report.push(filename, "pairing", status);
+        }
+    }
+
+    // Check the negative powers of beta H, which are `beta^-(max_degree - d) H` for each degree bound `d`,
+    // so that `beta^-(max_degree - d2) H = beta^(d2 - d1) * beta^-(max_degree - d1) H`.
+    let filename = "neg-powers-of-beta.usrs";
+    match read::<BTreeMap<usize, E::G2Affine>>(paths, filename) {
+        None => (),
+        Some(Err(error)) => report.push(filename, "degree_bound", AuditStatus::Failed(error)),
+        Some(Ok(neg_powers)) => {
+            let status = match neg_powers.keys().all(|degree_bound| *degree_bound < MAX_NUM_POWERS) {
+                true => AuditStatus::Passed,
+                false => AuditStatus::Failed(format!("a degree bound exceeds {MAX_NUM_POWERS}")),
+            };
+            report.push(filename, "degree_bound", status);
+
+            let status = match &first_powers {
+                None => AuditStatus::Skipped("'powers-of-beta-15.usrs' is missing or invalid".to_string()),
+                Some(powers) => {
+                    let neg_powers = neg_powers.into_iter().collect::<Vec<_>>();
+                    let consistent = neg_powers.windows(2).all(|window| {
+                        let ((d1, neg_power_1), (d2, neg_power_2)) = (window[0], window[1]);
+                        match powers.get(d2 - d1) {
+                            Some(power) => E::pairing(generator, neg_power_2) == E::pairing(*power, neg_power_1),
+                            None => true,
+                        }
+                    });
+                    match consistent {
+                        true => AuditStatus::Passed,
+                        false => AuditStatus::Failed("the powers are not consistent powers of beta".to_string()),
+                    }
+                }
+            };
+            report.push(filename, "pairing", status);
+        }
+    }
+
+    // Check that `beta * H` is consistent with `beta * G`.
+    let filename = "beta-h.usrs";
+    match (read::<E::G2Affine>(paths, filename), &first_powers) {
+        (None, _) => (),
+        (Some(Err(error)), _) => report.push(filename, "pairing", AuditStatus::Failed(error)),
+        (Some(Ok(_)), None) => report.push(
+            filename,
+            "pairing",
+            AuditStatus::Skipped("'powers-of-beta-15.usrs' is missing or invalid".into()),
+        ),
+        (Some(Ok(beta_h)), Some(powers)) => {
+            let h = E::G2Affine::prime_subgroup_generator();
+            let status = match E::pairing(powers[1], h) == E::pairing(generator, beta_h) {
+                true => AuditStatus::Passed,
+                false => AuditStatus::Failed("beta * H is not consistent with beta * G".to_string()),
+            };
+            report.push(filename, "pairing", status);
+        }
+    }
+}
+
+/// Returns the number of powers in the SRS file of the given degree.
+/// The file of degree `15` holds the first `2^15` powers, and each following file doubles the number of powers.
+const fn num_powers(degree: u32) -> usize {
+    match degree {
+        15 => 1 << 15,
+        _ => 1 << (degree - 1),
+    }
+}
+
+/// Returns the expected checksum and size of the given file type, from the given metadata.
+fn expected_checksum_and_size(file_type: &str, metadata: &str) -> Result<(String, usize)> {
+    let metadata: Value = serde_json::from_str(metadata)?;
+    let (checksum_key, size_key) = match file_type {
+        "usrs" => ("checksum".to_string(), "size".to_string()),
+        _ => (format!("{file_type}_checksum"), format!("{file_type}_size")),
+    };
+    let checksum = metadata[checksum_key.as_str()].as_str().ok_or_else(|| anyhow!("Invalid checksum metadata"))?;
+    let size = metadata[size_key.as_str()].as_u64().ok_or_else(|| anyhow!("Invalid size metadata"))?;
+    Ok((checksum.to_string(), usize::try_from(size)?))
+}
+
+/// Returns the path of the given parameter file in the given directory, if it exists.
+/// The file may be named `{filename}`, or `{filename}.{version}` as downloaded from the remote.
+fn locate(directory: &Path, filename: &str, checksum: &str) -> Option<PathBuf> {
+    let versioned = checksum.get(0..7).map(|version| format!("{filename}.{version}"));
+    [Some(filename.to_string()), versioned]
+        .into_iter()
+        .flatten()
+        .map(|name| directory.join(name))
+        .find(|path| path.is_file())
+}
+
+/// Reads and deserializes the given parameter file, with validation.
+/// Returns `None` if the file is missing.
+fn read<T: CanonicalDeserialize>(paths: &BTreeMap<String, PathBuf>, filename: &str) -> Option<Result<T, String>> {
+    let path = paths.get(filename)?;
+    Some(
+        std::fs::read(path)
+            .map_err(|error| error.to_string())
+            .and_then(|bytes| T::deserialize_uncompressed(&*bytes).map_err(|error| error.to_string())),
+    )
+}
+
+/// Reads the given SRS file, and checks that it holds the given number of powers.
+/// Returns `None` if the file is missing or invalid.
+fn read_powers<E: PairingEngine>(
+    report: &mut AuditReport,
+    paths: &BTreeMap<String, PathBuf>,
+    filename: &str,
+    num_powers: usize,
+) -> Option<Vec<E::G1Affine>> {
+    match read::<Vec<E::G1Affine>>(paths, filename)? {
+        Ok(powers) if powers.len() == num_powers => {
+            report.push(filename, "degree_bound", AuditStatus::Passed);
+            Some(powers)
+        }
+        Ok(powers) => {
+            let reason = format!("expected {num_powers} powers, found {} powers", powers.len());
+            report.push(filename, "degree_bound", AuditStatus::Failed(reason));
+            None
+        }
+        Err(error) => {
+            report.push(filename, "degree_bound", AuditStatus::Failed(error));
+            None
+        }
+    }
+}
+
+/// Returns `true` if `powers[i + 1] = beta * powers[i]` for each `i`, where `beta_h = beta * H`.
+/// This checks `e(sum_i r_i * powers[i + 1], H) = e(sum_i r_i * powers[i], beta * H)` for random 64-bit `r_i`.
+fn is_consecutive<E: PairingEngine, R: Rng>(powers: &[E::G1Affine], beta_h: E::G2Affine, rng: &mut R) -> bool {
+    if powers.len() < 2 {
+        return true;
+    }
+    let randomizers = (1..powers.len()).map(|_| rng.gen::<u64>()).collect::<Vec<_>>();
+    let bits = |r: u64| (0..64).rev().map(move |i| (r >> i) & 1 == 1);
+
+    let lhs: E::G1Projective = powers[1..].iter().zip(&randomizers).map(|(power, r)| power.mul_bits(bits(*r))).sum();
+    let rhs: E::G1Projective = powers.iter().zip(&randomizers).map(|(power, r)| power.mul_bits(bits(*r))).sum();
+    E::pairing(lhs, E::G2Affine::prime_subgroup_generator()) == E::pairing(rhs, beta_h)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::Bls12_377;
+
+    #[test]
+    fn test_audit_parameters() {
+        let rng = &mut rand::thread_rng();
+
+        // Audit the local parameter files.
+        let directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/mainnet/resources");
+        let report = audit_parameters::<Bls12_377, _>(&directory, rng).unwrap();
+        assert!(report.is_valid(), "{}", report.to_json());
+
+        // Ensure the local SRS files passed their checks.
+        let passed = |filename: &str, check: &str| {
+            report
+                .checks()
+                .iter()
+                .any(|c| c.filename == filename && c.check == check && c.status == AuditStatus::Passed)
+        };
+        assert!(passed("powers-of-beta-15.usrs", "checksum"));
+        assert!(passed("powers-of-beta-15.usrs", "degree_bound"));
+        assert!(passed("powers-of-beta-15.usrs", "generator"));
+        assert!(passed("powers-of-beta-15.usrs", "pairing"));
+        assert!(passed("powers-of-beta-16.usrs", "pairing"));
+        assert!(passed("beta-h.usrs", "pairing"));
+        assert!(passed("neg-powers-of-beta.usrs", "pairing"));
+
+        // Ensure the attestation verifies over the report.
+        let attestation = report.clone().sign(|bytes| Ok(checksum!(bytes))).unwrap();
+        assert!(attestation.verify(|bytes, signature| checksum!(bytes) == *signature));
+        assert_eq!(attestation.report(), &report);
+    }
+}
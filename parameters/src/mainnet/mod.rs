@@ -12,6 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[cfg(not(feature = "wasm"))]
+pub mod audit;
+#[cfg(not(feature = "wasm"))]
+pub use audit::*;
+
 pub mod genesis;
 pub use genesis::*;
 
@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use super::*;
-use snarkvm_curves::traits::{PairingCurve, PairingEngine};
+use snarkvm_curves::traits::{AffineCurve, PairingCurve, PairingEngine};
 use snarkvm_utilities::{
     CanonicalDeserialize,
     CanonicalSerialize,
@@ -47,7 +47,7 @@ const NUM_POWERS_27: usize = 1 << 27;
 const NUM_POWERS_28: usize = 1 << 28;
 
 /// The maximum degree supported by the SRS.
-const MAX_NUM_POWERS: usize = NUM_POWERS_28;
+pub(super) const MAX_NUM_POWERS: usize = NUM_POWERS_28;
 
 lazy_static::lazy_static! {
     static ref POWERS_OF_BETA_G_15: Vec<u8> = Degree15::load_bytes().expect("Failed to load powers of beta in universal SRS");
@@ -104,11 +104,24 @@ impl<E: PairingEngine> PowersOfG<E> {
         })
     }
 
+    /// Initializes the hard-coded instance of the powers, with the first `num_powers` powers of beta G.
+    /// Only the needed powers are read from the parameter files, and further powers are read on demand.
+    pub fn load_up_to(num_powers: usize) -> Result<Self> {
+        let powers = Self::load()?;
+        powers.extend_powers_to(num_powers)?;
+        Ok(powers)
+    }
+
     /// Download the powers of beta G specified by `range`.
     pub fn download_powers_for(&self, range: Range<usize>) -> Result<()> {
         self.powers_of_beta_g.write().download_powers_for(&range)
     }
 
+    /// Extends the contiguous powers of beta G to the first `num_powers` powers, reading only the missing powers.
+    pub fn extend_powers_to(&self, num_powers: usize) -> Result<()> {
+        self.powers_of_beta_g.write().extend_powers_to(num_powers)
+    }
+
     /// Returns the number of contiguous powers of beta G starting from the 0-th power.
     pub fn num_powers(&self) -> usize {
         self.powers_of_beta_g.read().num_powers()
@@ -366,64 +379,25 @@ impl<E: PairingEngine> PowersOfBetaG<E> {
         // Determine the new power of two.
         let final_power_of_two =
             end.checked_next_power_of_two().ok_or_else(|| anyhow!("Requesting too many powers"))?;
-        // Ensure the total number of powers is less than the maximum number of powers.
-        ensure!(final_power_of_two <= MAX_NUM_POWERS, "Requesting more powers than exist in the SRS");
-
-        // Retrieve the current power of two.
-        let current_power_of_two = self
-            .powers_of_beta_g
-            .len()
-            .checked_next_power_of_two()
-            .ok_or_else(|| anyhow!("The current degree is too large"))?;
-
-        // Initialize a vector for the powers of two to be downloaded.
-        let mut download_queue = Vec::with_capacity(14);
-
-        // Initialize the first degree to download.
-        let mut accumulator = current_power_of_two * 2;
-        // Determine the powers of two to download.
-        while accumulator <= final_power_of_two {
-            download_queue.push(accumulator);
-            accumulator =
-                accumulator.checked_mul(2).ok_or_else(|| anyhow!("Overflowed while requesting a larger degree"))?;
-        }
-        ensure!(final_power_of_two * 2 == accumulator, "Ensure the loop terminates at the right power of two");
+        // Extend the powers up to the new power of two.
+        self.extend_powers_to(final_power_of_two)
+    }
 
-        // Reserve capacity for the new powers of two.
-        let additional_size = final_power_of_two
-            .checked_sub(self.powers_of_beta_g.len())
-            .ok_or_else(|| anyhow!("final_power_of_two is smaller than existing powers"))?;
-        self.powers_of_beta_g.reserve(additional_size);
+    /// This method reads the universal SRS powers from `self.num_powers()` up to `end`,
+    /// and updates `Self` in place with the new powers.
+    fn extend_powers_to(&mut self, end: usize) -> Result<()> {
+        // Ensure the total number of powers is less than the maximum number of powers.
+        ensure!(end <= MAX_NUM_POWERS, "Requesting more powers than exist in the SRS");
 
-        // Download the powers of two.
-        for num_powers in &download_queue {
+        let start = self.powers_of_beta_g.len();
+        if end > start {
             #[cfg(debug_assertions)]
-            println!("Loading {num_powers} powers");
-
-            // Download the universal SRS powers if they're not already on disk.
-            let additional_bytes = match *num_powers {
-                NUM_POWERS_16 => Degree16::load_bytes()?,
-                NUM_POWERS_17 => Degree17::load_bytes()?,
-                NUM_POWERS_18 => Degree18::load_bytes()?,
-                NUM_POWERS_19 => Degree19::load_bytes()?,
-                NUM_POWERS_20 => Degree20::load_bytes()?,
-                NUM_POWERS_21 => Degree21::load_bytes()?,
-                NUM_POWERS_22 => Degree22::load_bytes()?,
-                NUM_POWERS_23 => Degree23::load_bytes()?,
-                NUM_POWERS_24 => Degree24::load_bytes()?,
-                NUM_POWERS_25 => Degree25::load_bytes()?,
-                NUM_POWERS_26 => Degree26::load_bytes()?,
-                NUM_POWERS_27 => Degree27::load_bytes()?,
-                NUM_POWERS_28 => Degree28::load_bytes()?,
-                _ => bail!("Cannot download an invalid degree of '{num_powers}'"),
-            };
+            println!("Loading powers {start}..{end}");
 
-            // Deserialize the group elements.
-            let additional_powers = Vec::deserialize_uncompressed_unchecked(&*additional_bytes)?;
-            // Extend the powers.
-            self.powers_of_beta_g.extend(&additional_powers);
+            // Read the missing powers.
+            let additional_powers = read_powers_of_beta_g::<E>(start..end)?;
+            self.powers_of_beta_g.extend(additional_powers);
         }
-        ensure!(self.powers_of_beta_g.len() == final_power_of_two, "Loaded an incorrect number of powers");
         Ok(())
     }
 
@@ -511,6 +485,93 @@ impl<E: PairingEngine> PowersOfBetaG<E> {
     }
 }
 
+/// Returns the powers at the given offsets of a remote parameter file.
+/// On disk, only the bytes of the requested powers are read, instead of the entire file.
+macro_rules! read_remote_powers {
+    ($engine:ty, $name:ident, $offsets:expr) => {{
+        #[cfg(not(feature = "wasm"))]
+        let powers = read_powers_from_file::<$engine>(&$name::load_path()?, $offsets)?;
+        #[cfg(feature = "wasm")]
+        let powers = deserialize_powers::<$engine>(&$name::load_bytes()?, $offsets)?;
+        powers
+    }};
+}
+
+/// Returns the powers of beta G in `range`, which are read from only the parameter files that contain them.
+///
+/// The file of degree `15` contains the powers `0..2^15`, and the file of each degree `k > 15`
+/// contains the powers `2^(k-1)..2^k`. On disk, only the bytes of the requested powers are read.
+fn read_powers_of_beta_g<E: PairingEngine>(range: Range<usize>) -> Result<Vec<E::G1Affine>> {
+    ensure!(range.end <= MAX_NUM_POWERS, "Requesting more powers than exist in the SRS");
+
+    let mut powers = Vec::with_capacity(range.len());
+    let (mut file_start, mut file_end) = (0, NUM_POWERS_15);
+    while file_start < range.end {
+        // Read the powers in the intersection of the range and the file.
+        let (start, end) = (range.start.max(file_start), range.end.min(file_end));
+        if start < end {
+            let offsets = (start - file_start)..(end - file_start);
+            let additional_powers = match file_end {
+                NUM_POWERS_15 => deserialize_powers::<E>(&**POWERS_OF_BETA_G_15, offsets)?,
+                NUM_POWERS_16 => deserialize_powers::<E>(&Degree16::load_bytes()?, offsets)?,
+                NUM_POWERS_17 => read_remote_powers!(E, Degree17, offsets),
+                NUM_POWERS_18 => read_remote_powers!(E, Degree18, offsets),
+                NUM_POWERS_19 => read_remote_powers!(E, Degree19, offsets),
+                NUM_POWERS_20 => read_remote_powers!(E, Degree20, offsets),
+                NUM_POWERS_21 => read_remote_powers!(E, Degree21, offsets),
+                NUM_POWERS_22 => read_remote_powers!(E, Degree22, offsets),
+                NUM_POWERS_23 => read_remote_powers!(E, Degree23, offsets),
+                NUM_POWERS_24 => read_remote_powers!(E, Degree24, offsets),
+                NUM_POWERS_25 => read_remote_powers!(E, Degree25, offsets),
+                NUM_POWERS_26 => read_remote_powers!(E, Degree26, offsets),
+                NUM_POWERS_27 => read_remote_powers!(E, Degree27, offsets),
+                NUM_POWERS_28 => read_remote_powers!(E, Degree28, offsets),
+                _ => bail!("Cannot read an invalid degree of '{file_end}'"),
+            };
+            powers.extend(additional_powers);
+        }
+        file_start = file_end;
+        file_end *= 2;
+    }
+    ensure!(powers.len() == range.len(), "Loaded an incorrect number of powers");
+    Ok(powers)
+}
+
+/// Returns the powers at the given offsets of the parameter file at `file_path`,
+/// by reading only the bytes of the requested powers.
+#[cfg(not(feature = "wasm"))]
+fn read_powers_from_file<E: PairingEngine>(
+    file_path: &std::path::Path,
+    offsets: Range<usize>,
+) -> Result<Vec<E::G1Affine>> {
+    use std::io::{Read, Seek, SeekFrom};
+
+    let mut file = std::fs::File::open(file_path)?;
+    // Read the number of powers in the file.
+    let mut header = [0u8; 8];
+    file.read_exact(&mut header)?;
+    ensure!(u64::from_le_bytes(header) as usize >= offsets.end, "The parameter file has too few powers");
+
+    // Read the bytes of the requested powers.
+    let power_size = E::G1Affine::prime_subgroup_generator().uncompressed_size();
+    let mut bytes = header.to_vec();
+    bytes.resize(8 + offsets.len() * power_size, 0);
+    file.seek(SeekFrom::Start((8 + offsets.start * power_size) as u64))?;
+    file.read_exact(&mut bytes[8..])?;
+
+    deserialize_powers::<E>(&bytes, 0..offsets.len())
+}
+
+/// Returns the powers at the given offsets of the serialized vector of powers.
+fn deserialize_powers<E: PairingEngine>(bytes: &[u8], offsets: Range<usize>) -> Result<Vec<E::G1Affine>> {
+    let power_size = E::G1Affine::prime_subgroup_generator().uncompressed_size();
+    // Retrieve the bytes of the requested powers, skipping the length of the vector.
+    let bytes = bytes
+        .get(8 + offsets.start * power_size..8 + offsets.end * power_size)
+        .ok_or_else(|| anyhow!("The parameter file has too few powers"))?;
+    bytes.chunks(power_size).map(|bytes| Ok(E::G1Affine::deserialize_uncompressed_unchecked(bytes)?)).collect()
+}
+
 impl<E: PairingEngine> FromBytes for PowersOfBetaG<E> {
     /// Reads the powers from the buffer.
     fn read_le<R: Read>(reader: R) -> std::io::Result<Self> {
@@ -0,0 +1,245 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use console::program::{FinalizeType, Register, ValueType};
+use synthesizer_program::{CallOperator, Operand};
+
+use indexmap::IndexSet;
+use std::collections::VecDeque;
+
+/// The call graph of a program, mapping each function and closure to the resources it calls.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CallGraph<N: Network> {
+    /// The mapping of each caller to its callees, in the order they are first called.
+    calls: IndexMap<Locator<N>, IndexSet<Locator<N>>>,
+}
+
+impl<N: Network> CallGraph<N> {
+    /// Returns the mapping of each caller to its callees.
+    pub const fn calls(&self) -> &IndexMap<Locator<N>, IndexSet<Locator<N>>> {
+        &self.calls
+    }
+
+    /// Returns the callees of the given function or closure, if it is in the call graph.
+    pub fn callees(&self, locator: &Locator<N>) -> Option<&IndexSet<Locator<N>>> {
+        self.calls.get(locator)
+    }
+
+    /// Returns the callers of the given function or closure.
+    pub fn callers(&self, locator: &Locator<N>) -> Vec<&Locator<N>> {
+        self.calls.iter().filter(|(_, callees)| callees.contains(locator)).map(|(caller, _)| caller).collect()
+    }
+}
+
+/// An issue with the usage of futures in a function, found by `Process::analyze_futures`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FutureIssue<N: Network> {
+    /// A future produced by a call is not passed to the `async` instruction.
+    Unconsumed { function: Locator<N>, register: Register<N>, future: Locator<N> },
+    /// A future is passed to the `async` instruction out of the order in which it was produced.
+    ConsumedOutOfOrder { function: Locator<N>, register: Register<N>, future: Locator<N> },
+    /// A future input of the finalize is never awaited.
+    Unawaited { function: Locator<N>, register: Register<N>, future: Locator<N> },
+    /// A future input of the finalize is awaited out of the order in which it was passed in.
+    AwaitedOutOfOrder { function: Locator<N>, register: Register<N>, future: Locator<N> },
+}
+
+impl<N: Network> Display for FutureIssue<N> {
+    /// Prints the issue as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Unconsumed { function, register, future } => {
+                write!(f, "'{function}' does not pass the future '{future}' in '{register}' to its 'async' instruction")
+            }
+            Self::ConsumedOutOfOrder { function, register, future } => {
+                write!(f, "'{function}' passes the future '{future}' in '{register}' out of the order it was produced")
+            }
+            Self::Unawaited { function, register, future } => {
+                write!(f, "The finalize of '{function}' does not await the future '{future}' in '{register}'")
+            }
+            Self::AwaitedOutOfOrder { function, register, future } => {
+                write!(f, "The finalize of '{function}' awaits the future '{future}' in '{register}' out of order")
+            }
+        }
+    }
+}
+
+/// The report of `Process::analyze_futures`, listing the issues found in each function.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FutureReport<N: Network> {
+    /// The issues, in the order of the functions in the program.
+    issues: Vec<FutureIssue<N>>,
+}
+
+impl<N: Network> FutureReport<N> {
+    /// Returns `true` if no issues were found.
+    pub fn is_valid(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// Returns the issues.
+    pub fn issues(&self) -> &[FutureIssue<N>] {
+        &self.issues
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Returns the call graph of the given program, including the resources it calls in its imports.
+    #[inline]
+    pub fn call_graph(&self, program_id: impl TryInto<ProgramID<N>>) -> Result<CallGraph<N>> {
+        // Retrieve the program.
+        let program = self.get_program(program_id)?;
+        // Initialize the queue with the closures and functions of the program.
+        let mut queue = program
+            .closures()
+            .keys()
+            .chain(program.functions().keys())
+            .map(|name| Locator::new(*program.id(), *name))
+            .collect::<VecDeque<_>>();
+
+        // Initialize the calls.
+        let mut calls = IndexMap::new();
+        while let Some(caller) = queue.pop_front() {
+            // Skip the caller if it was already visited.
+            if calls.contains_key(&caller) {
+                continue;
+            }
+            // Retrieve the instructions of the caller.
+            let program = self.get_program(*caller.program_id())?;
+            let instructions = match program.closures().get(caller.resource()) {
+                Some(closure) => closure.instructions(),
+                None => program.get_function_ref(caller.resource())?.instructions(),
+            };
+            // Collect the callees, resolving local calls to the caller's program.
+            let callees = instructions
+                .iter()
+                .filter_map(|instruction| match instruction {
+                    Instruction::Call(call) => match call.operator() {
+                        CallOperator::Locator(locator) => Some(*locator),
+                        CallOperator::Resource(resource) => Some(Locator::new(*caller.program_id(), *resource)),
+                    },
+                    _ => None,
+                })
+                .collect::<IndexSet<_>>();
+            // Visit the callees.
+            queue.extend(callees.iter().copied());
+            calls.insert(caller, callees);
+        }
+        Ok(CallGraph { calls })
+    }
+
+    /// Analyzes the usage of futures in the given program, using the process to resolve its imports.
+    /// Unlike `Process::add_program`, this reports every issue found, instead of the first one.
+    #[inline]
+    pub fn analyze_futures(&self, program: &Program<N>) -> Result<FutureReport<N>> {
+        // Initialize the issues.
+        let mut issues = Vec::new();
+
+        for function in program.functions().values() {
+            // Compute the locator of the function.
+            let function_locator = Locator::new(*program.id(), *function.name());
+
+            // Collect the futures produced by calls to external functions, in order.
+            let mut produced = Vec::new();
+            for instruction in function.instructions() {
+                if let Instruction::Call(call) = instruction {
+                    if let CallOperator::Locator(locator) = call.operator() {
+                        // Retrieve the output types of the external function, if it exists.
+                        let program = self.get_program(*locator.program_id())?;
+                        let output_types =
+                            program.functions().get(locator.resource()).map(|f| f.output_types()).unwrap_or_default();
+                        for (register, output_type) in call.destinations().into_iter().zip(output_types) {
+                            if let ValueType::Future(future) = output_type {
+                                produced.push((register, future));
+                            }
+                        }
+                    }
+                }
+            }
+
+            // Collect the produced futures passed to the `async` instruction, in order.
+            let consumed = function
+                .instructions()
+                .iter()
+                .filter_map(|instruction| match instruction {
+                    Instruction::Async(async_) => Some(async_.operands()),
+                    _ => None,
+                })
+                .flatten()
+                .filter_map(|operand| match operand {
+                    Operand::Register(register) => produced.iter().find(|(produced, _)| produced == register),
+                    _ => None,
+                })
+                .cloned()
+                .collect::<Vec<_>>();
+            Self::check_futures(
+                &produced,
+                &consumed,
+                |register, future| FutureIssue::Unconsumed { function: function_locator, register, future },
+                |register, future| FutureIssue::ConsumedOutOfOrder { function: function_locator, register, future },
+                &mut issues,
+            );
+
+            if let Some(finalize) = function.finalize_logic() {
+                // Collect the future inputs of the finalize, in order.
+                let inputs = finalize
+                    .inputs()
+                    .iter()
+                    .filter_map(|input| match input.finalize_type() {
+                        FinalizeType::Future(future) => Some((input.register().clone(), *future)),
+                        FinalizeType::Plaintext(..) => None,
+                    })
+                    .collect::<Vec<_>>();
+                // Collect the future inputs awaited by the finalize, in order.
+                let awaited = finalize
+                    .commands()
+                    .iter()
+                    .filter_map(|command| match command {
+                        Command::Await(await_) => inputs.iter().find(|(input, _)| input == await_.register()),
+                        _ => None,
+                    })
+                    .cloned()
+                    .collect::<Vec<_>>();
+                Self::check_futures(
+                    &inputs,
+                    &awaited,
+                    |register, future| FutureIssue::Unawaited { function: function_locator, register, future },
+                    |register, future| FutureIssue::AwaitedOutOfOrder { function: function_locator, register, future },
+                    &mut issues,
+                );
+            }
+        }
+        Ok(FutureReport { issues })
+    }
+
+    /// Reports the `expected` futures that are missing from `found`, and the futures in `found` that are out of order.
+    fn check_futures(
+        expected: &[(Register<N>, Locator<N>)],
+        found: &[(Register<N>, Locator<N>)],
+        missing: impl Fn(Register<N>, Locator<N>) -> FutureIssue<N>,
+        out_of_order: impl Fn(Register<N>, Locator<N>) -> FutureIssue<N>,
+        issues: &mut Vec<FutureIssue<N>>,
+    ) {
+        // Report the futures that are never found.
+        for (register, future) in expected.iter().filter(|future| !found.contains(future)) {
+            issues.push(missing(register.clone(), *future));
+        }
+        // Report the futures that are found out of order, relative to the other found futures.
+        let expected = expected.iter().filter(|future| found.contains(future));
+        for (found, _) in found.iter().zip(expected).filter(|(found, expected)| found != expected) {
+            issues.push(out_of_order(found.0.clone(), found.1));
+        }
+    }
+}
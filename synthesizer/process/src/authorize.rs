@@ -25,8 +25,72 @@ impl<N: Network> Process<N> {
         inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
         rng: &mut R,
     ) -> Result<Authorization<N>> {
+        // Retrieve the stack.
+        let stack = self.get_stack(program_id)?;
         // Authorize the call.
-        self.get_stack(program_id)?.authorize::<A, R>(private_key, function_name, inputs, rng)
+        // Note: If the circuit environment halts, the halt is returned as an error.
+        catch_halt(|| stack.authorize::<A, R>(private_key, function_name, inputs, rng)).map_err(|error| {
+            // Reset the circuit environment, as the authorization may have halted midway.
+            A::reset();
+            error
+        })
+    }
+
+    /// Prepares an unsigned authorization of a call to the program function for the given inputs.
+    /// The unsigned authorization is signed separately, with `Authorization::sign`.
+    #[inline]
+    pub fn authorize_unsigned(
+        &self,
+        program_id: impl TryInto<ProgramID<N>>,
+        function_name: impl TryInto<Identifier<N>>,
+        inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
+    ) -> Result<UnsignedAuthorization<N>> {
+        // Prepare the program ID.
+        let program_id = program_id.try_into().map_err(|_| anyhow!("Invalid program ID"))?;
+        // Prepare the function name.
+        let function_name = function_name.try_into().map_err(|_| anyhow!("Invalid function name"))?;
+        // Ensure the number of inputs matches the function.
+        let function = self.get_program(program_id)?.get_function(&function_name)?;
+        ensure!(
+            function.inputs().len() == inputs.len(),
+            "Function '{program_id}/{function_name}' expects {} inputs, found {}",
+            function.inputs().len(),
+            inputs.len()
+        );
+        // Prepare the inputs.
+        let inputs = inputs
+            .enumerate()
+            .map(|(index, input)| {
+                input
+                    .try_into()
+                    .map_err(|_| anyhow!("Failed to parse input #{index} for '{program_id}/{function_name}'"))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Collect the programs in the call graph, with each program after its imports.
+        let mut programs = IndexMap::new();
+        self.collect_programs(&program_id, &mut programs)?;
+
+        UnsignedAuthorization::new(program_id, function_name, inputs, programs.into_values().collect())
+    }
+
+    /// Collects the given program and its imports, with each program after its imports, excluding 'credits.aleo'.
+    fn collect_programs(
+        &self,
+        program_id: &ProgramID<N>,
+        programs: &mut IndexMap<ProgramID<N>, Program<N>>,
+    ) -> Result<()> {
+        // Skip 'credits.aleo', and any program that is already collected.
+        if program_id == &ProgramID::from_str("credits.aleo")? || programs.contains_key(program_id) {
+            return Ok(());
+        }
+        // Collect the imports, followed by the program.
+        let program = self.get_program(*program_id)?;
+        for import in program.imports().keys() {
+            self.collect_programs(import, programs)?;
+        }
+        programs.insert(*program_id, program.clone());
+        Ok(())
     }
 
     /// Authorizes the fee given the credits record, the fee amount (in microcredits),
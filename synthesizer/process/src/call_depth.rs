@@ -0,0 +1,115 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The error returned when a function call exceeds the maximum call depth of the process.
+///
+/// Callers may distinguish this error from other errors with `error.downcast_ref::<CallDepthExceeded>()`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CallDepthExceeded {
+    /// The locator of the called function.
+    locator: String,
+    /// The maximum call depth.
+    max_depth: usize,
+}
+
+impl CallDepthExceeded {
+    /// Returns the locator of the called function.
+    pub fn locator(&self) -> &str {
+        &self.locator
+    }
+
+    /// Returns the maximum call depth.
+    pub const fn max_depth(&self) -> usize {
+        self.max_depth
+    }
+}
+
+impl Display for CallDepthExceeded {
+    /// Prints the error as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "Calling '{}' exceeds the maximum call depth of {}", self.locator, self.max_depth)
+    }
+}
+
+impl std::error::Error for CallDepthExceeded {}
+
+/// Ensures a call to the given function, at the given depth, does not exceed the maximum call depth.
+///
+/// The top-level function is at depth 0, and each nested function call increments the depth by one.
+pub(crate) fn ensure_call_depth<N: Network>(locator: &Locator<N>, depth: usize, max_depth: usize) -> Result<()> {
+    match depth <= max_depth {
+        true => Ok(()),
+        false => Err(CallDepthExceeded { locator: locator.to_string(), max_depth }.into()),
+    }
+}
+
+/// Returns the error of the given instruction, passing a `CallDepthExceeded` error through as-is.
+pub(crate) fn instruction_error<N: Network>(action: &str, instruction: &Instruction<N>, error: Error) -> Error {
+    match error.is::<CallDepthExceeded>() {
+        true => error,
+        false => anyhow!("Failed to {action} instruction ({instruction}): {error}"),
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// The default maximum depth of nested function calls.
+    pub const DEFAULT_MAX_CALL_DEPTH: usize = N::MAX_PROGRAM_DEPTH;
+
+    /// Returns the process, with the given maximum depth of nested function calls.
+    pub fn with_max_call_depth(mut self, max_call_depth: usize) -> Self {
+        // Apply the maximum call depth to the existing stacks.
+        for stack in self.stacks.values() {
+            stack.set_max_call_depth(max_call_depth);
+        }
+        self.max_call_depth = max_call_depth;
+        self
+    }
+
+    /// Returns the maximum depth of nested function calls.
+    pub const fn max_call_depth(&self) -> usize {
+        self.max_call_depth
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_ensure_call_depth() {
+        let locator = Locator::<CurrentNetwork>::from_str("credits.aleo/transfer_public").unwrap();
+
+        // Ensure calls within the maximum call depth are allowed.
+        assert!(ensure_call_depth(&locator, 0, 0).is_ok());
+        assert!(ensure_call_depth(&locator, 2, 2).is_ok());
+
+        // Ensure calls beyond the maximum call depth return a `CallDepthExceeded` error.
+        let error = ensure_call_depth(&locator, 3, 2).unwrap_err();
+        let error = error.downcast_ref::<CallDepthExceeded>().unwrap();
+        assert_eq!(error.locator(), "credits.aleo/transfer_public");
+        assert_eq!(error.max_depth(), 2);
+
+        // Ensure the error is passed through the instruction errors of the callers.
+        let instruction = Instruction::<CurrentNetwork>::from_str("call foo.aleo/bar r0 into r1;").unwrap();
+        let error = instruction_error("execute", &instruction, ensure_call_depth(&locator, 3, 2).unwrap_err());
+        assert!(error.downcast_ref::<CallDepthExceeded>().is_some());
+        let error = instruction_error("execute", &instruction, anyhow!("error"));
+        assert_eq!(error.to_string(), "Failed to execute instruction (call foo.aleo/bar r0 into r1;): error");
+    }
+}
@@ -0,0 +1,377 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{Stack, StackProgramTypes};
+use console::{
+    prelude::*,
+    program::{FinalizeType, Identifier, LiteralType, PlaintextType},
+};
+use synthesizer_program::{CallDynamic, CastType, Command, Finalize, Instruction, Operand, StackProgram};
+
+/// The cost of a finalize command that scales with the size of its operands,
+/// derived as `base + (per_byte * size_in_bytes)` microcredits.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SizeCost {
+    /// The base cost in microcredits.
+    pub base: u64,
+    /// The cost in microcredits per byte of the operands.
+    pub per_byte: u64,
+}
+
+impl SizeCost {
+    /// Returns the cost in microcredits for operands of the given size in bytes.
+    pub const fn cost(&self, size_in_bytes: u64) -> u64 {
+        self.base.saturating_add(self.per_byte.saturating_mul(size_in_bytes))
+    }
+}
+
+/// The cost model for finalize, which assigns a cost in microcredits to each finalize command.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct CostModel {
+    /// The cost of an arithmetic, logical, or comparison instruction, `await`, `branch`, `iter`,
+    /// and a `cast` to a literal, group coordinate, or record.
+    pub basic: u64,
+    /// The cost of `div` and `pow` on fields.
+    pub field_div_pow: u64,
+    /// The cost of `mul` on groups and scalars.
+    pub group_mul: u64,
+    /// The cost of `inv` and `sqrt`.
+    pub inv_sqrt: u64,
+    /// The cost of `position`.
+    pub position: u64,
+    /// The cost of `rand.chacha`.
+    pub rand_chacha: u64,
+    /// The cost of `array.get` and `array.set`, by the size of the array.
+    pub array: SizeCost,
+    /// The cost of `call.dynamic`, by the size of its operands.
+    pub call_dynamic: SizeCost,
+    /// The prepaid cost of the finalize scope of a `call.dynamic` callee, which is only known at runtime.
+    /// A callee whose finalize cost exceeds the prepaid cost is rejected when it is called.
+    pub call_dynamic_callee: u64,
+    /// The cost of `cast` and `cast.lossy` to a struct or array, by the size of the destination type.
+    pub cast: SizeCost,
+    /// The cost of `emit`, by the size of the event.
+    pub emit: SizeCost,
+    /// The cost of the Keccak, SHA-3, and Pedersen hashes and commitments, by the size of the operands.
+    pub hash: SizeCost,
+    /// The cost of the BHP hashes and commitments, by the size of the operands.
+    pub hash_bhp: SizeCost,
+    /// The cost of the Poseidon hashes and `sign.verify`, by the size of the operands.
+    pub hash_psd: SizeCost,
+    /// The cost of `contains`, `get`, and `get.or_use`, by the size of the key.
+    /// The cost of `remove` is the base cost.
+    pub mapping: SizeCost,
+    /// The cost of `set`, by the size of the key and value.
+    pub set: SizeCost,
+    /// The cost of `ecdsa.verify`, by the size of the operands.
+    pub ecdsa_verify: SizeCost,
+    /// The cost of `ed25519.verify`, by the size of the operands.
+    pub ed25519_verify: SizeCost,
+    /// The maximum total finalize cost of the executions in a block.
+    pub max_block_finalize_cost: u64,
+}
+
+impl CostModel {
+    /// Returns the cost model for the network `N`.
+    pub const fn for_network<N: Network>() -> Self {
+        // The highest base cost of a finalize command (the BHP hashes and commitments).
+        // Note: The prepaid cost of a `call.dynamic` callee covers this base cost for each of its commands,
+        // and callees whose finalize cost exceeds it, including the per-byte costs, are rejected.
+        const MAX_BASE_COST: u64 = 50_000;
+
+        Self {
+            basic: 500,
+            field_div_pow: 1_500,
+            group_mul: 10_000,
+            inv_sqrt: 2_500,
+            position: 100,
+            rand_chacha: 25_000,
+            array: SizeCost { base: 500, per_byte: 30 },
+            call_dynamic: SizeCost { base: 10_000, per_byte: 10 },
+            call_dynamic_callee: MAX_BASE_COST * CallDynamic::<N>::MAX_CALLEE_COMMANDS as u64,
+            cast: SizeCost { base: 500, per_byte: 30 },
+            emit: SizeCost { base: 10_000, per_byte: 100 },
+            hash: SizeCost { base: 10_000, per_byte: 30 },
+            hash_bhp: SizeCost { base: 50_000, per_byte: 300 },
+            hash_psd: SizeCost { base: 40_000, per_byte: 75 },
+            mapping: SizeCost { base: 10_000, per_byte: 10 },
+            set: SizeCost { base: 10_000, per_byte: 100 },
+            // Note: The base costs of signature verification are scaled from `group_mul`, by the ratio of their
+            // running times in the `signature` benchmark of `snarkvm-console-algorithms`. A secp256k1 verification
+            // runs in about 1x the time of a group scalar multiplication, and an Ed25519 verification in about 0.4x.
+            ecdsa_verify: SizeCost { base: 10_000, per_byte: 30 },
+            ed25519_verify: SizeCost { base: 5_000, per_byte: 30 },
+            max_block_finalize_cost: 100_000_000_000,
+        }
+    }
+
+    /// Returns the minimum number of microcredits required to run the finalize of the given function.
+    pub fn finalize_cost<N: Network>(&self, stack: &Stack<N>, function_name: &Identifier<N>) -> Result<u64> {
+        // Retrieve the finalize logic.
+        let Some(finalize) = stack.get_function_ref(function_name)?.finalize_logic() else {
+            // Return a finalize cost of 0, if the function does not have a finalize scope.
+            return Ok(0);
+        };
+
+        // Aggregate the cost of all commands in the program.
+        // Note: The commands in the body of a loop, including the position that ends it, are counted once per iteration.
+        let mut total_cost = 0u64;
+        // Initialize the active loop, as the position that ends the loop body and the number of iterations.
+        let mut active_loop: Option<(&Identifier<N>, u64)> = None;
+        for command in finalize.commands() {
+            // Determine the number of times the command is executed.
+            let num_executions = active_loop.map_or(1, |(_, num_iterations)| num_iterations);
+            // Compute the cost of the command.
+            let command_cost = self
+                .command_cost(stack, finalize, command)?
+                .checked_mul(num_executions)
+                .ok_or(anyhow!("Finalize cost overflowed"))?;
+            total_cost = total_cost.checked_add(command_cost).ok_or(anyhow!("Finalize cost overflowed"))?;
+            // Update the active loop.
+            match command {
+                Command::Iter(iter) => active_loop = Some((iter.position(), iter.num_iterations() as u64)),
+                Command::Position(position) if active_loop.map_or(false, |(end, _)| end == position.name()) => {
+                    active_loop = None
+                }
+                _ => (),
+            }
+        }
+        Ok(total_cost)
+    }
+
+    /// Returns the cost in microcredits of a single execution of the given command in the given finalize scope.
+    pub fn command_cost<N: Network>(
+        &self,
+        stack: &Stack<N>,
+        finalize: &Finalize<N>,
+        command: &Command<N>,
+    ) -> Result<u64> {
+        // A helper function to compute the cost of a command, by the size of its operands.
+        let cost_in_size = |operands: &[Operand<N>], size_cost: SizeCost| -> Result<u64> {
+            Ok(size_cost.cost(operands_size_in_bytes(stack, finalize, operands)?))
+        };
+        // A helper function to compute the cost of a cast, by its cast type.
+        let cast_cost = |cast_type: &CastType<N>| -> Result<u64> {
+            match cast_type {
+                CastType::Plaintext(PlaintextType::Literal(_)) => Ok(self.basic),
+                CastType::Plaintext(plaintext_type) => {
+                    Ok(self.cast.cost(plaintext_size_in_bytes(stack, plaintext_type)?))
+                }
+                CastType::GroupXCoordinate
+                | CastType::GroupYCoordinate
+                | CastType::Record(_)
+                | CastType::ExternalRecord(_) => Ok(self.basic),
+            }
+        };
+
+        match command {
+            Command::Instruction(Instruction::Abs(_)) => Ok(self.basic),
+            Command::Instruction(Instruction::AbsWrapped(_)) => Ok(self.basic),
+            Command::Instruction(Instruction::Add(_)) => Ok(self.basic),
+            Command::Instruction(Instruction::AddWrapped(_)) => Ok(self.basic),
+            Command::Instruction(Instruction::And(_)) => Ok(self.basic),
+            Command::Instruction(Instruction::AssertEq(_)) => Ok(self.basic),
+            Command::Instruction(Instruction::AssertNeq(_)) => Ok(self.basic),
+            Command::Instruction(Instruction::Async(_)) => bail!("'async' is not supported in finalize"),
+            Command::Instruction(Instruction::Call(_)) => bail!("'call' is not supported in finalize"),
+            Command::Instruction(Instruction::Cast(cast)) => cast_cost(cast.cast_type()),
+            Command::Instruction(Instruction::CastLossy(cast_lossy)) => cast_cost(cast_lossy.cast_type()),
+            Command::Instruction(Instruction::CommitBHP256(commit)) => cost_in_size(commit.operands(), self.hash_bhp),
+            Command::Instruction(Instruction::CommitBHP512(commit)) => cost_in_size(commit.operands(), self.hash_bhp),
+            Command::Instruction(Instruction::CommitBHP768(commit)) => cost_in_size(commit.operands(), self.hash_bhp),
+            Command::Instruction(Instruction::CommitBHP1024(commit)) => cost_in_size(commit.operands(), self.hash_bhp),
+            Command::Instruction(Instruction::CommitPED64(commit)) => cost_in_size(commit.operands(), self.hash),
+            Command::Instruction(Instruction::CommitPED128(commit)) => cost_in_size(commit.operands(), self.hash),
+            Command::Instruction(Instruction::Div(div)) => {
+                // Ensure `div` has exactly two operands.
+                ensure!(div.operands().len() == 2, "'div' must contain exactly 2 operands");
+                // Retrieve the finalize types.
+                let finalize_types = stack.get_finalize_types(finalize.name())?;
+                // Retrieve the price by the operand type.
+                match finalize_types.get_type_from_operand(stack, &div.operands()[0])? {
+                    FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::Field)) => Ok(self.field_div_pow),
+                    FinalizeType::Plaintext(PlaintextType::Literal(_)) => Ok(self.basic),
+                    FinalizeType::Plaintext(PlaintextType::Array(_)) => bail!("'div' does not support arrays"),
+                    FinalizeType::Plaintext(PlaintextType::Struct(_)) => bail!("'div' does not support structs"),
+                    FinalizeType::Future(_) => bail!("'div' does not support futures"),
+                }
+            }
+            Command::Instruction(Instruction::DivWrapped(_)) => Ok(self.basic),
+            Command::Instruction(Instruction::Double(_)) => Ok(self.basic),
+            Command::Instruction(Instruction::GreaterThan(_)) => Ok(self.basic),
+            Command::Instruction(Instruction::GreaterThanOrEqual(_)) => Ok(self.basic),
+            Command::Instruction(Instruction::HashBHP256(hash)) => cost_in_size(hash.operands(), self.hash_bhp),
+            Command::Instruction(Instruction::HashBHP512(hash)) => cost_in_size(hash.operands(), self.hash_bhp),
+            Command::Instruction(Instruction::HashBHP768(hash)) => cost_in_size(hash.operands(), self.hash_bhp),
+            Command::Instruction(Instruction::HashBHP1024(hash)) => cost_in_size(hash.operands(), self.hash_bhp),
+            Command::Instruction(Instruction::HashKeccak256(hash)) => cost_in_size(hash.operands(), self.hash),
+            Command::Instruction(Instruction::HashKeccak384(hash)) => cost_in_size(hash.operands(), self.hash),
+            Command::Instruction(Instruction::HashKeccak512(hash)) => cost_in_size(hash.operands(), self.hash),
+            Command::Instruction(Instruction::HashPED64(hash)) => cost_in_size(hash.operands(), self.hash),
+            Command::Instruction(Instruction::HashPED128(hash)) => cost_in_size(hash.operands(), self.hash),
+            Command::Instruction(Instruction::HashPSD2(hash)) => cost_in_size(hash.operands(), self.hash_psd),
+            Command::Instruction(Instruction::HashPSD4(hash)) => cost_in_size(hash.operands(), self.hash_psd),
+            Command::Instruction(Instruction::HashPSD8(hash)) => cost_in_size(hash.operands(), self.hash_psd),
+            Command::Instruction(Instruction::HashSha3_256(hash)) => cost_in_size(hash.operands(), self.hash),
+            Command::Instruction(Instruction::HashSha3_384(hash)) => cost_in_size(hash.operands(), self.hash),
+            Command::Instruction(Instruction::HashSha3_512(hash)) => cost_in_size(hash.operands(), self.hash),
+            Command::Instruction(Instruction::HashManyPSD2(_)) => {
+                bail!("`hash_many.psd2` is not supported in finalize")
+            }
+            Command::Instruction(Instruction::HashManyPSD4(_)) => {
+                bail!("`hash_many.psd4` is not supported in finalize")
+            }
+            Command::Instruction(Instruction::HashManyPSD8(_)) => {
+                bail!("`hash_many.psd8` is not supported in finalize")
+            }
+            Command::Instruction(Instruction::HashBytesKeccak256(hash)) => cost_in_size(hash.operands(), self.hash),
+            Command::Instruction(Instruction::HashBytesSha3_256(hash)) => cost_in_size(hash.operands(), self.hash),
+            Command::Instruction(Instruction::Inv(_)) => Ok(self.inv_sqrt),
+            Command::Instruction(Instruction::IsEq(_)) => Ok(self.basic),
+            Command::Instruction(Instruction::IsNeq(_)) => Ok(self.basic),
+            Command::Instruction(Instruction::LessThan(_)) => Ok(self.basic),
+            Command::Instruction(Instruction::LessThanOrEqual(_)) => Ok(self.basic),
+            Command::Instruction(Instruction::Modulo(_)) => Ok(self.basic),
+            Command::Instruction(Instruction::Mul(mul)) => {
+                // Ensure `mul` has exactly two operands.
+                ensure!(mul.operands().len() == 2, "'mul' must contain exactly 2 operands");
+                // Retrieve the finalize types.
+                let finalize_types = stack.get_finalize_types(finalize.name())?;
+                // Retrieve the price by operand type.
+                match finalize_types.get_type_from_operand(stack, &mul.operands()[0])? {
+                    FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::Group)) => Ok(self.group_mul),
+                    FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::Scalar)) => Ok(self.group_mul),
+                    FinalizeType::Plaintext(PlaintextType::Literal(_)) => Ok(self.basic),
+                    FinalizeType::Plaintext(PlaintextType::Array(_)) => bail!("'mul' does not support arrays"),
+                    FinalizeType::Plaintext(PlaintextType::Struct(_)) => bail!("'mul' does not support structs"),
+                    FinalizeType::Future(_) => bail!("'mul' does not support futures"),
+                }
+            }
+            Command::Instruction(Instruction::MulWrapped(_)) => Ok(self.basic),
+            Command::Instruction(Instruction::Nand(_)) => Ok(self.basic),
+            Command::Instruction(Instruction::Neg(_)) => Ok(self.basic),
+            Command::Instruction(Instruction::Nor(_)) => Ok(self.basic),
+            Command::Instruction(Instruction::Not(_)) => Ok(self.basic),
+            Command::Instruction(Instruction::Or(_)) => Ok(self.basic),
+            Command::Instruction(Instruction::Pow(pow)) => {
+                // Ensure `pow` has at least one operand.
+                ensure!(!pow.operands().is_empty(), "'pow' must contain at least 1 operand");
+                // Retrieve the finalize types.
+                let finalize_types = stack.get_finalize_types(finalize.name())?;
+                // Retrieve the price by operand type.
+                match finalize_types.get_type_from_operand(stack, &pow.operands()[0])? {
+                    FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::Field)) => Ok(self.field_div_pow),
+                    FinalizeType::Plaintext(PlaintextType::Literal(_)) => Ok(self.basic),
+                    FinalizeType::Plaintext(PlaintextType::Array(_)) => bail!("'pow' does not support arrays"),
+                    FinalizeType::Plaintext(PlaintextType::Struct(_)) => bail!("'pow' does not support structs"),
+                    FinalizeType::Future(_) => bail!("'pow' does not support futures"),
+                }
+            }
+            Command::Instruction(Instruction::PowWrapped(_)) => Ok(self.basic),
+            Command::Instruction(Instruction::Rem(_)) => Ok(self.basic),
+            Command::Instruction(Instruction::RemWrapped(_)) => Ok(self.basic),
+            Command::Instruction(Instruction::SignVerify(sign)) => cost_in_size(sign.operands(), self.hash_psd),
+            Command::Instruction(Instruction::Shl(_)) => Ok(self.basic),
+            Command::Instruction(Instruction::ShlWrapped(_)) => Ok(self.basic),
+            Command::Instruction(Instruction::Shr(_)) => Ok(self.basic),
+            Command::Instruction(Instruction::ShrWrapped(_)) => Ok(self.basic),
+            Command::Instruction(Instruction::Square(_)) => Ok(self.basic),
+            Command::Instruction(Instruction::SquareRoot(_)) => Ok(self.inv_sqrt),
+            Command::Instruction(Instruction::Sub(_)) => Ok(self.basic),
+            Command::Instruction(Instruction::SubWrapped(_)) => Ok(self.basic),
+            Command::Instruction(Instruction::Ternary(_)) => Ok(self.basic),
+            Command::Instruction(Instruction::Xor(_)) => Ok(self.basic),
+            Command::Instruction(Instruction::EcdsaVerify(verify)) => {
+                cost_in_size(verify.operands(), self.ecdsa_verify)
+            }
+            Command::Instruction(Instruction::Ed25519Verify(verify)) => {
+                cost_in_size(verify.operands(), self.ed25519_verify)
+            }
+            Command::Instruction(Instruction::DivWide(_)) => Ok(self.basic),
+            Command::Instruction(Instruction::RemWide(_)) => Ok(self.basic),
+            Command::Await(_) => Ok(self.basic),
+            Command::Contains(command) => cost_in_size(std::slice::from_ref(command.key()), self.mapping),
+            Command::Get(command) => cost_in_size(std::slice::from_ref(command.key()), self.mapping),
+            Command::GetOrUse(command) => cost_in_size(std::slice::from_ref(command.key()), self.mapping),
+            Command::RandChaCha(_) => Ok(self.rand_chacha),
+            Command::Remove(_) => Ok(self.mapping.base),
+            Command::Set(command) => cost_in_size(&[command.key().clone(), command.value().clone()], self.set),
+            Command::BranchEq(_) | Command::BranchNeq(_) => Ok(self.basic),
+            Command::Position(_) => Ok(self.position),
+            Command::ArrayGet(command) => cost_in_size(std::slice::from_ref(command.array()), self.array),
+            Command::ArraySet(command) => cost_in_size(std::slice::from_ref(command.array()), self.array),
+            Command::Iter(_) => Ok(self.basic),
+            Command::CallDynamic(command) => {
+                Ok(cost_in_size(command.operands(), self.call_dynamic)?.saturating_add(self.call_dynamic_callee))
+            }
+            Command::Emit(command) => cost_in_size(std::slice::from_ref(command.event()), self.emit),
+        }
+    }
+}
+
+/// Returns the size in bytes of the given plaintext type.
+fn plaintext_size_in_bytes<N: Network>(stack: &Stack<N>, plaintext_type: &PlaintextType<N>) -> Result<u64> {
+    match plaintext_type {
+        PlaintextType::Literal(literal_type) => Ok(literal_type.size_in_bytes::<N>() as u64),
+        PlaintextType::Struct(struct_name) => {
+            // Retrieve the struct from the stack.
+            let struct_ = stack.program().get_struct(struct_name)?;
+            // Retrieve the size of the struct name.
+            let size_of_name = struct_.name().to_bytes_le()?.len() as u64;
+            // Retrieve the size of all the members of the struct.
+            let size_of_members = struct_.members().iter().try_fold(0u64, |acc, (_, member_type)| {
+                acc.checked_add(plaintext_size_in_bytes(stack, member_type)?).ok_or(anyhow!(
+                    "Overflowed while computing the size of the struct '{}/{struct_name}' - {member_type}",
+                    stack.program_id()
+                ))
+            })?;
+            // Return the size of the struct.
+            Ok(size_of_name.saturating_add(size_of_members))
+        }
+        PlaintextType::Array(array_type) => {
+            // Retrieve the number of elements in the array.
+            let num_elements = **array_type.length() as u64;
+            // Compute the size of an array element.
+            let size_of_element = plaintext_size_in_bytes(stack, array_type.next_element_type())?;
+            // Return the size of the array.
+            Ok(num_elements.saturating_mul(size_of_element))
+        }
+    }
+}
+
+/// Returns the combined size in bytes of the given operands in the given finalize scope.
+fn operands_size_in_bytes<N: Network>(
+    stack: &Stack<N>,
+    finalize: &Finalize<N>,
+    operands: &[Operand<N>],
+) -> Result<u64> {
+    // Retrieve the finalize types.
+    let finalize_types = stack.get_finalize_types(finalize.name())?;
+    // Compute the size of the operands.
+    operands.iter().try_fold(0u64, |acc, operand| {
+        // Determine the size of the operand.
+        let operand_size = match finalize_types.get_type_from_operand(stack, operand)? {
+            FinalizeType::Plaintext(plaintext_type) => plaintext_size_in_bytes(stack, &plaintext_type)?,
+            FinalizeType::Future(future) => {
+                bail!("Future '{future}' is not a valid operand in the finalize scope");
+            }
+        };
+        // Safely add the size to the accumulator.
+        acc.checked_add(operand_size).ok_or(anyhow!(
+            "Overflowed while computing the size of the operand '{operand}' in '{}/{}' (finalize)",
+            stack.program_id(),
+            finalize.name()
+        ))
+    })
+}
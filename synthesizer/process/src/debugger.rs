@@ -0,0 +1,86 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use synthesizer_program::{MappingLocator, Operand};
+
+/// A debugger for the finalize scopes evaluated by `Process::finalize_execution`.
+///
+/// Each callback receives the locator of the finalize scope and the index of the command in the scope.
+/// The callbacks are invoked in the order of evaluation, and do not affect the result of finalize.
+pub trait FinalizeDebugger<N: Network>: Send + Sync {
+    /// Invoked before a command is evaluated, with the registers assigned so far.
+    fn on_command(
+        &self,
+        _locator: &Locator<N>,
+        _index: usize,
+        _command: &Command<N>,
+        _registers: &IndexMap<u64, Value<N>>,
+    ) {
+    }
+
+    /// Invoked after a command reads the given `key` from a mapping.
+    /// The `value` is the value read, or the boolean result for `contains`.
+    fn on_mapping_read(
+        &self,
+        _locator: &Locator<N>,
+        _index: usize,
+        _mapping: &MappingLocator<N>,
+        _key: &Value<N>,
+        _value: &Value<N>,
+    ) {
+    }
+
+    /// Invoked after a command writes to a mapping.
+    fn on_mapping_write(&self, _locator: &Locator<N>, _index: usize, _operation: &FinalizeOperation<N>) {}
+
+    /// Invoked after a branch command is evaluated, with whether the branch is taken.
+    fn on_branch(&self, _locator: &Locator<N>, _index: usize, _taken: bool) {}
+}
+
+impl<N: Network> Process<N> {
+    /// Returns the process, with the given debugger invoked by `Process::finalize_execution`.
+    pub fn with_finalize_debugger(mut self, debugger: Arc<dyn FinalizeDebugger<N>>) -> Self {
+        self.finalize_debugger = Some(debugger);
+        self
+    }
+
+    /// Returns the finalize debugger, if the process is in debug mode.
+    pub fn finalize_debugger(&self) -> Option<&Arc<dyn FinalizeDebugger<N>>> {
+        self.finalize_debugger.as_ref()
+    }
+}
+
+/// Notifies the debugger of the mapping read by the given command, if it reads a mapping.
+pub(crate) fn debug_mapping_read<N: Network>(
+    debugger: &dyn FinalizeDebugger<N>,
+    locator: &Locator<N>,
+    index: usize,
+    command: &Command<N>,
+    stack: &Stack<N>,
+    registers: &FinalizeRegisters<N>,
+) -> Result<()> {
+    // Retrieve the mapping, key, and destination of the command.
+    let (mapping, key, destination) = match command {
+        Command::Get(get) => (get.mapping(), get.key(), get.destination()),
+        Command::GetOrUse(get_or_use) => (get_or_use.mapping(), get_or_use.key(), get_or_use.destination()),
+        Command::Contains(contains) => (contains.mapping(), contains.key(), contains.destination()),
+        _ => return Ok(()),
+    };
+    // Load the key and the value read into the destination.
+    let key = registers.load(stack, key)?;
+    let value = registers.load(stack, &Operand::Register(destination.clone()))?;
+    debugger.on_mapping_read(locator, index, mapping, &key, &value);
+    Ok(())
+}
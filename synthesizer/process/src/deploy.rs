@@ -16,6 +16,7 @@ use super::*;
 
 impl<N: Network> Process<N> {
     /// Deploys the given program ID, if it does not exist.
+    #[cfg(feature = "prover")]
     #[inline]
     pub fn deploy<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
         &self,
@@ -44,7 +45,7 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process::load_deployment");
 
         // Compute the program stack.
-        let stack = Stack::new(self, deployment.program())?;
+        let stack = Stack::new(self, deployment.shared_program())?;
         lap!(timer, "Compute the stack");
 
         // Insert the verifying keys.
@@ -0,0 +1,117 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use console::{
+    account::{Address, ViewKey},
+    program::{Disclosure, Entry},
+    types::Group,
+};
+use synthesizer_program::StackMatches;
+use synthesizer_snark::Proof;
+
+/// The name of the disclosure circuit.
+const DISCLOSURE_CIRCUIT: &str = "disclosure";
+
+impl<N: Network> Process<N> {
+    /// Returns a proof that the given record opens the commitment of the disclosure, that the given
+    /// view key belongs to the record owner, and that its disclosed entry satisfies the predicate.
+    #[inline]
+    pub fn prove_disclosure<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        disclosure: &Disclosure<N>,
+        view_key: &ViewKey<N>,
+        record: &Record<N, Plaintext<N>>,
+        rng: &mut R,
+    ) -> Result<Proof<N>> {
+        // Ensure the record matches its record type.
+        self.get_stack(disclosure.program_id())?.matches_record(record, disclosure.record_name())?;
+        // Ensure the disclosure holds for the record.
+        ensure!(disclosure.holds_for(view_key, record)?, "The record does not satisfy the disclosure");
+
+        // Synthesize the disclosure circuit.
+        let assignment = Self::synthesize_disclosure::<A>(disclosure, view_key, record);
+        // Derive the circuit key.
+        let (proving_key, _) = self.universal_srs.to_circuit_key(DISCLOSURE_CIRCUIT, &assignment)?;
+        // Prove the disclosure.
+        proving_key.prove(DISCLOSURE_CIRCUIT, &assignment, rng)
+    }
+
+    /// Verifies the given disclosure proof, using only the commitment of the record.
+    /// Note: The verifying key is derived from a record sampled from the record type and owned by a burner
+    /// account, so neither the record nor the view key of its owner is needed.
+    #[inline]
+    pub fn verify_disclosure<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        disclosure: &Disclosure<N>,
+        proof: &Proof<N>,
+        rng: &mut R,
+    ) -> Result<()> {
+        // Sample a record of the same record type.
+        let burner_private_key = PrivateKey::new(rng)?;
+        let burner_view_key = ViewKey::try_from(&burner_private_key)?;
+        let burner_address = Address::try_from(&burner_private_key)?;
+        let record = self.get_stack(disclosure.program_id())?.sample_record(
+            &burner_address,
+            disclosure.record_name(),
+            Group::rand(rng),
+            rng,
+        )?;
+        // Ensure the disclosed entry is a literal of the same type as the value.
+        match record.find(&[*disclosure.entry_name()])? {
+            Entry::Constant(Plaintext::Literal(literal, _))
+            | Entry::Public(Plaintext::Literal(literal, _))
+            | Entry::Private(Plaintext::Literal(literal, _)) => ensure!(
+                literal.variant() == disclosure.value().variant(),
+                "The record entry '{}' is not of type '{}'",
+                disclosure.entry_name(),
+                disclosure.value().to_type()
+            ),
+            _ => bail!("The record entry '{}' is not a literal", disclosure.entry_name()),
+        }
+
+        // Synthesize the disclosure circuit.
+        let assignment = Self::synthesize_disclosure::<A>(disclosure, &burner_view_key, &record);
+        // Derive the circuit key.
+        let (_, verifying_key) = self.universal_srs.to_circuit_key(DISCLOSURE_CIRCUIT, &assignment)?;
+        // Verify the disclosure proof.
+        ensure!(
+            verifying_key.verify(DISCLOSURE_CIRCUIT, &disclosure.to_verifier_inputs()?, proof),
+            "Disclosure proof is invalid"
+        );
+        Ok(())
+    }
+
+    /// Synthesizes the disclosure circuit for the given disclosure, view key, and record.
+    fn synthesize_disclosure<A: circuit::Aleo<Network = N>>(
+        disclosure: &Disclosure<N>,
+        view_key: &ViewKey<N>,
+        record: &Record<N, Plaintext<N>>,
+    ) -> circuit::Assignment<N::Field> {
+        // Ensure the circuit environment is clean.
+        A::reset();
+
+        // Inject the disclosure, with the commitment and the value hash as `Mode::Public`.
+        let disclosure = circuit::Disclosure::<A>::new(circuit::Mode::Private, disclosure.clone());
+        // Inject the view key as `Mode::Private`.
+        let view_key = circuit::ViewKey::<A>::new(circuit::Mode::Private, *view_key);
+        // Inject the record as `Mode::Private`.
+        let record = circuit::Record::<A, circuit::Plaintext<A>>::new(circuit::Mode::Private, record.clone());
+        // Enforce the record is owned by the view key and satisfies the disclosure.
+        A::assert(disclosure.verify(&view_key, &record));
+
+        // Eject the assignment and reset the circuit environment.
+        A::eject_assignment_and_reset()
+    }
+}
@@ -13,8 +13,129 @@
 // limitations under the License.
 
 use super::*;
+use ledger_store::{atomic_finalize, FinalizeMode};
+
+/// The values assigned to the registers of an evaluated function or closure.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RegisterTrace<N: Network> {
+    /// The function or closure.
+    locator: Locator<N>,
+    /// The mapping of register locators to their values, in the order they were assigned.
+    registers: IndexMap<u64, Value<N>>,
+}
+
+impl<N: Network> RegisterTrace<N> {
+    /// Initializes a new register trace.
+    pub const fn new(locator: Locator<N>, registers: IndexMap<u64, Value<N>>) -> Self {
+        Self { locator, registers }
+    }
+
+    /// Returns the function or closure.
+    pub const fn locator(&self) -> &Locator<N> {
+        &self.locator
+    }
+
+    /// Returns the mapping of register locators to their values, in the order they were assigned.
+    pub const fn registers(&self) -> &IndexMap<u64, Value<N>> {
+        &self.registers
+    }
+}
+
+/// The result of `Process::evaluate_only`.
+#[derive(Clone, Debug)]
+pub struct Evaluation<N: Network> {
+    /// The response of the top-level function.
+    response: Response<N>,
+    /// The register traces of the evaluated functions and closures, in the order they completed.
+    traces: Vec<RegisterTrace<N>>,
+    /// The transitions of the authorization, without proofs.
+    transitions: Vec<Transition<N>>,
+}
+
+impl<N: Network> Evaluation<N> {
+    /// Returns the response of the top-level function.
+    pub const fn response(&self) -> &Response<N> {
+        &self.response
+    }
+
+    /// Returns the outputs of the top-level function.
+    pub fn outputs(&self) -> &[Value<N>] {
+        self.response.outputs()
+    }
+
+    /// Returns the register traces of the evaluated functions and closures, in the order they completed.
+    pub fn traces(&self) -> &[RegisterTrace<N>] {
+        &self.traces
+    }
+
+    /// Returns the transitions of the authorization, without proofs.
+    pub fn transitions(&self) -> &[Transition<N>] {
+        &self.transitions
+    }
+
+    /// Returns a structured record of each transition's inputs and outputs,
+    /// along with the intermediate register values of every evaluated function and closure.
+    #[cfg(feature = "trace")]
+    pub fn to_trace(&self) -> ledger_block::ExecutionTrace<N> {
+        use ledger_block::{ExecutionTrace, RegisterFrame, TransitionTrace};
+
+        ExecutionTrace::new(
+            self.transitions.iter().map(TransitionTrace::from).collect(),
+            self.traces.iter().map(|trace| RegisterFrame::new(trace.locator, trace.registers.clone())).collect(),
+        )
+    }
+}
 
 impl<N: Network> Process<N> {
+    /// Evaluates a program function on the given authorization, without synthesizing or proving any circuits.
+    /// The result contains the outputs, and the register traces of every evaluated function and closure.
+    ///
+    /// This method is intended for testing programs, and does not produce a valid execution.
+    #[inline]
+    pub fn evaluate_only<A: circuit::Aleo<Network = N>>(
+        &self,
+        authorization: Authorization<N>,
+    ) -> Result<Evaluation<N>> {
+        let timer = timer!("Process::evaluate_only");
+
+        // Retrieve the transitions, as evaluating consumes the requests of the authorization.
+        let transitions = authorization.transitions().into_values().collect();
+        // Retrieve the top-level request (without popping it).
+        let request = authorization.peek_next()?;
+        // Retrieve the stack.
+        let stack = self.get_stack(request.program_id())?;
+
+        // Initialize the register traces.
+        let traces = RegisterTraces::default();
+        // Evaluate the function.
+        let call_stack = CallStack::evaluate_with_traces(authorization, traces.clone())?;
+        let response = stack.evaluate_function::<A>(call_stack, None)?;
+        lap!(timer, "Evaluate the function");
+
+        // Retrieve the register traces.
+        let traces = traces.read().clone();
+
+        finish!(timer);
+        Ok(Evaluation { response, traces, transitions })
+    }
+
+    /// Returns the finalize operations that the given evaluation would produce on the given store,
+    /// without committing them to the store.
+    #[inline]
+    pub fn project_finalize<P: FinalizeStorage<N>>(
+        &self,
+        state: FinalizeGlobalState,
+        store: &FinalizeStore<N, P>,
+        evaluation: &Evaluation<N>,
+    ) -> Result<Vec<FinalizeOperation<N>>> {
+        // Construct an execution from the transitions, without a proof.
+        let execution = Execution::from(evaluation.transitions().iter().cloned(), N::StateRoot::default(), None)?;
+        // Finalize the execution in a dry run.
+        atomic_finalize!(store, FinalizeMode::DryRun, {
+            self.finalize_execution(state, store, &execution, None).map_err(|error| error.to_string())
+        })
+    }
+
     /// Evaluates a program function on the given request.
     #[inline]
     pub fn evaluate<A: circuit::Aleo<Network = N>>(&self, authorization: Authorization<N>) -> Result<Response<N>> {
@@ -45,11 +45,19 @@ impl<N: Network> Process<N> {
         // Retrieve the stack.
         let stack = self.get_stack(request.program_id())?;
         // Execute the circuit.
-        let response = stack.execute_function::<A, R>(call_stack, caller, root_tvk, rng)?;
+        // Note: If the circuit environment halts, the halt is returned as an error.
+        let response =
+            catch_halt(|| stack.execute_function::<A, R>(call_stack, caller, root_tvk, rng)).map_err(|error| {
+                // Reset the circuit environment, as the execution may have halted midway.
+                A::reset();
+                error
+            })?;
         lap!(timer, "Execute the function");
 
         // Extract the trace.
-        let trace = Arc::try_unwrap(trace).unwrap().into_inner();
+        let mut trace = Arc::try_unwrap(trace).unwrap().into_inner();
+        // Attach the profiler to the trace, so that proving the trace is profiled.
+        trace.set_profiler(self.profiler().cloned());
         // Ensure the trace is not empty.
         ensure!(!trace.transitions().is_empty(), "Execution of '{locator}' is empty");
 
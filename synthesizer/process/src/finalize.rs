@@ -13,10 +13,15 @@
 // limitations under the License.
 
 use super::*;
-use console::program::{Future, Register};
-use synthesizer_program::{Await, FinalizeRegistersState, Operand};
+use console::{
+    program::{Future, Register, ValueType},
+    types::U32,
+};
+use synthesizer_program::{Await, CallDynamic, FinalizeRegistersState, FinalizeStoreTrait, Function, Iter, Operand};
 use utilities::handle_halting;
 
+use std::time::Instant;
+
 impl<N: Network> Process<N> {
     /// Finalizes the deployment and fee.
     /// This method assumes the given deployment **is valid**.
@@ -32,7 +37,22 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process::finalize_deployment");
 
         // Compute the program stack.
-        let stack = Stack::new(self, deployment.program())?;
+        let stack = match deployment.is_upgrade() {
+            true => {
+                // Ensure program upgrades are enabled at this block height.
+                ensure!(
+                    N::consensus_version(state.block_height())? >= ConsensusVersion::V2,
+                    "Program upgrades are not enabled at block height {}",
+                    state.block_height()
+                );
+                // Ensure the program has not been deprecated since the upgrade was verified.
+                ensure!(!self.is_deprecated(deployment.program_id()), "Cannot upgrade a deprecated program");
+                Stack::new_upgrade(self, deployment.shared_program())?
+            }
+            false => Stack::new(self, deployment.shared_program())?,
+        };
+        // Ensure the program only reads the 'credits.aleo' mappings that are enabled at this block height.
+        self.check_credits_mappings(deployment.program(), state.block_height())?;
         lap!(timer, "Compute the stack");
 
         // Insert the verifying keys.
@@ -42,6 +62,7 @@ impl<N: Network> Process<N> {
         lap!(timer, "Insert the verifying keys");
 
         // Initialize the mappings, and store their finalize operations.
+        // Note: The mappings of a previous edition are preserved, so only new mappings are initialized.
         atomic_batch_scope!(store, {
             // Initialize a list for the finalize operations.
             let mut finalize_operations = Vec::with_capacity(deployment.program().mappings().len());
@@ -51,7 +72,7 @@ impl<N: Network> Process<N> {
             // Retrieve the fee stack.
             let fee_stack = self.get_stack(fee.program_id())?;
             // Finalize the fee transition.
-            finalize_operations.extend(finalize_fee_transition(state, store, fee_stack, fee)?);
+            finalize_operations.extend(finalize_fee_transition(self, state, store, fee_stack, fee)?);
             lap!(timer, "Finalize transition for '{}/{}'", fee.program_id(), fee.function_name());
 
             /* Finalize the deployment. */
@@ -60,6 +81,10 @@ impl<N: Network> Process<N> {
             let program_id = deployment.program_id();
             // Iterate over the mappings.
             for mapping in deployment.program().mappings().values() {
+                // Skip the mapping if it was initialized by a previous edition.
+                if deployment.is_upgrade() && store.contains_mapping_confirmed(program_id, mapping.name())? {
+                    continue;
+                }
                 // Initialize the mapping.
                 finalize_operations.push(store.initialize_mapping(*program_id, *mapping.name())?);
             }
@@ -70,6 +95,52 @@ impl<N: Network> Process<N> {
         })
     }
 
+    /// Finalizes the deprecation and fee.
+    /// This method assumes the given deprecation **is valid**.
+    /// This method should **only** be called by `VM::finalize()`.
+    ///
+    /// Note: If the `mapping_policy` is `MappingPolicy::Freeze`, the mappings of the deprecated program are preserved,
+    /// so its state remains readable. Otherwise, the mappings of the deprecated program are removed.
+    #[inline]
+    pub fn finalize_deprecation<P: FinalizeStorage<N>>(
+        &self,
+        state: FinalizeGlobalState,
+        store: &FinalizeStore<N, P>,
+        program_id: &ProgramID<N>,
+        mapping_policy: MappingPolicy,
+        fee: &Fee<N>,
+    ) -> Result<Vec<FinalizeOperation<N>>> {
+        let timer = timer!("Process::finalize_deprecation");
+
+        // Ensure the program is not 'credits.aleo'.
+        ensure!(program_id != &ProgramID::from_str("credits.aleo")?, "Cannot deprecate 'credits.aleo'");
+        // Ensure the program exists.
+        ensure!(self.contains_program(program_id), "Program '{program_id}' does not exist");
+
+        atomic_batch_scope!(store, {
+            // Retrieve the fee stack.
+            let fee_stack = self.get_stack(fee.program_id())?;
+            // Finalize the fee transition.
+            let mut finalize_operations = finalize_fee_transition(self, state, store, fee_stack, fee)?;
+            lap!(timer, "Finalize transition for '{}/{}'", fee.program_id(), fee.function_name());
+
+            // If the mappings are not frozen, remove the mappings of the program.
+            if mapping_policy.is_remove() {
+                for mapping_name in self.get_program(program_id)?.mappings().keys() {
+                    finalize_operations.push(store.remove_mapping(*program_id, *mapping_name)?);
+                }
+                lap!(timer, "Remove the mappings of the program");
+            }
+
+            // Mark the program as deprecated.
+            finalize_operations.push(FinalizeOperation::DeprecateProgram(N::hash_bhp1024(&program_id.to_bits_le())?));
+            finish!(timer, "Deprecate the program");
+
+            // Return the finalize operations.
+            Ok(finalize_operations)
+        })
+    }
+
     /// Finalizes the execution and fee.
     /// This method assumes the given execution **is valid**.
     /// This method should **only** be called by `VM::finalize()`.
@@ -80,6 +151,21 @@ impl<N: Network> Process<N> {
         store: &FinalizeStore<N, P>,
         execution: &Execution<N>,
         fee: Option<&Fee<N>>,
+    ) -> Result<Vec<FinalizeOperation<N>>> {
+        self.finalize_execution_with_profiler(state, store, execution, fee, None)
+    }
+
+    /// Finalizes the execution and fee, recording each command of the execution to the given profiler, if any.
+    /// This method assumes the given execution **is valid**.
+    /// This method should **only** be called by `VM::finalize()`.
+    #[inline]
+    pub fn finalize_execution_with_profiler<P: FinalizeStorage<N>>(
+        &self,
+        state: FinalizeGlobalState,
+        store: &FinalizeStore<N, P>,
+        execution: &Execution<N>,
+        fee: Option<&Fee<N>>,
+        profiler: Option<&FinalizeProfiler<N>>,
     ) -> Result<Vec<FinalizeOperation<N>>> {
         let timer = timer!("Program::finalize_execution");
 
@@ -107,7 +193,9 @@ impl<N: Network> Process<N> {
             // Finalize the root transition.
             // Note that this will result in all the remaining transitions being finalized, since the number
             // of calls matches the number of transitions.
-            let mut finalize_operations = finalize_transition(state, store, stack, transition, call_graph)?;
+            let debugger = self.finalize_debugger().map(|debugger| debugger.as_ref());
+            let mut finalize_operations =
+                finalize_transition(self, state, store, stack, transition, call_graph, debugger, profiler)?;
 
             /* Finalize the fee. */
 
@@ -115,10 +203,18 @@ impl<N: Network> Process<N> {
                 // Retrieve the fee stack.
                 let fee_stack = self.get_stack(fee.program_id())?;
                 // Finalize the fee transition.
-                finalize_operations.extend(finalize_fee_transition(state, store, fee_stack, fee)?);
+                finalize_operations.extend(finalize_fee_transition(self, state, store, fee_stack, fee)?);
                 lap!(timer, "Finalize transition for '{}/{}'", fee.program_id(), fee.function_name());
             }
 
+            /* Finalize the sequence number. */
+
+            if execution.sequence().is_some() {
+                // Increment the next sequence number of the fee payer.
+                finalize_operations.extend(Self::finalize_sequence(store, execution, fee)?);
+                lap!(timer, "Finalize the sequence number");
+            }
+
             finish!(timer);
             // Return the finalize operations.
             Ok(finalize_operations)
@@ -141,7 +237,7 @@ impl<N: Network> Process<N> {
             // Retrieve the stack.
             let stack = self.get_stack(fee.program_id())?;
             // Finalize the fee transition.
-            let result = finalize_fee_transition(state, store, stack, fee);
+            let result = finalize_fee_transition(self, state, store, stack, fee);
             finish!(timer, "Finalize transition for '{}/{}'", fee.program_id(), fee.function_name());
             // Return the result.
             result
@@ -151,6 +247,7 @@ impl<N: Network> Process<N> {
 
 /// Finalizes the given fee transition.
 fn finalize_fee_transition<N: Network, P: FinalizeStorage<N>>(
+    process: &Process<N>,
     state: FinalizeGlobalState,
     store: &FinalizeStore<N, P>,
     stack: &Stack<N>,
@@ -162,7 +259,7 @@ fn finalize_fee_transition<N: Network, P: FinalizeStorage<N>>(
     call_graph.insert(*fee.transition_id(), Vec::new());
 
     // Finalize the transition.
-    match finalize_transition(state, store, stack, fee, call_graph) {
+    match finalize_transition(process, state, store, stack, fee, call_graph, None, None) {
         // If the evaluation succeeds, return the finalize operations.
         Ok(finalize_operations) => Ok(finalize_operations),
         // If the evaluation fails, bail and return the error.
@@ -170,13 +267,17 @@ fn finalize_fee_transition<N: Network, P: FinalizeStorage<N>>(
     }
 }
 
-/// Finalizes the given transition.
+/// Finalizes the given transition, notifying the given debugger of each command, if any,
+/// and recording each command to the given profiler, if any.
 fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
+    process: &Process<N>,
     state: FinalizeGlobalState,
     store: &FinalizeStore<N, P>,
     stack: &Stack<N>,
     transition: &Transition<N>,
     call_graph: HashMap<N::TransitionID, Vec<N::TransitionID>>,
+    debugger: Option<&dyn FinalizeDebugger<N>>,
+    profiler: Option<&FinalizeProfiler<N>>,
 ) -> Result<Vec<FinalizeOperation<N>>> {
     // Retrieve the program ID.
     let program_id = transition.program_id();
@@ -218,10 +319,22 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
         mut recent_call_locator,
     }) = states.pop()
     {
+        // Initialize the active loop, as (iter index, end position index, `iter` command, loop counter).
+        // Note: Loops do not contain `await` commands, so a loop is never active when a finalize state is pushed.
+        let mut active_loop = None;
+        // Retrieve the locator of the finalize scope.
+        let locator = Locator::new(*stack.program_id(), *finalize.name());
+
         // Evaluate the commands.
         while counter < finalize.commands().len() {
             // Retrieve the command.
             let command = &finalize.commands()[counter];
+            // Notify the debugger of the command.
+            if let Some(debugger) = debugger {
+                debugger.on_command(&locator, counter, command, registers.registers());
+            }
+            // Start the profile of the command, if the finalize scope is profiled.
+            let profile = profiler.map(|profiler| (profiler, locator, Instant::now()));
             // Finalize the command.
             match &command {
                 Command::BranchEq(branch_eq) => {
@@ -230,6 +343,10 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
                     }));
                     match result {
                         Ok(Ok(new_counter)) => {
+                            // Notify the debugger of the branch decision.
+                            if let Some(debugger) = debugger {
+                                debugger.on_branch(&locator, counter, new_counter != counter + 1);
+                            }
                             counter = new_counter;
                         }
                         // If the evaluation fails, bail and return the error.
@@ -244,6 +361,10 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
                     }));
                     match result {
                         Ok(Ok(new_counter)) => {
+                            // Notify the debugger of the branch decision.
+                            if let Some(debugger) = debugger {
+                                debugger.on_branch(&locator, counter, new_counter != counter + 1);
+                            }
                             counter = new_counter;
                         }
                         // If the evaluation fails, bail and return the error.
@@ -252,6 +373,52 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
                         Err(_) => bail!("'finalize' failed to evaluate command ({command})"),
                     }
                 }
+                Command::Iter(iter) => {
+                    let result = handle_halting!(panic::AssertUnwindSafe(|| {
+                        enter_loop(counter, iter, finalize, stack, &mut registers)
+                    }));
+                    match result {
+                        Ok(Ok(end)) => {
+                            // Set the active loop, and proceed to the loop body.
+                            active_loop = Some((counter, end, iter, **iter.start()));
+                            counter += 1;
+                        }
+                        // If the evaluation fails, bail and return the error.
+                        Ok(Err(error)) => bail!("'finalize' failed to evaluate command ({command}): {error}"),
+                        // If the evaluation fails, bail and return the error.
+                        Err(_) => bail!("'finalize' failed to evaluate command ({command})"),
+                    }
+                }
+                Command::Position(_) => match active_loop {
+                    // If the position ends the active loop, proceed to the next iteration or exit the loop.
+                    Some((start, end, iter, value)) if end == counter => match value + 1 < **iter.end() {
+                        true => {
+                            let result = handle_halting!(panic::AssertUnwindSafe(|| {
+                                next_iteration(iter, value + 1, stack, &mut registers)
+                            }));
+                            match result {
+                                Ok(Ok(())) => {
+                                    // Update the active loop, and return to the start of the loop body.
+                                    active_loop = Some((start, end, iter, value + 1));
+                                    counter = start + 1;
+                                }
+                                // If the evaluation fails, bail and return the error.
+                                Ok(Err(error)) => {
+                                    bail!("'finalize' failed to evaluate command ({command}): {error}")
+                                }
+                                // If the evaluation fails, bail and return the error.
+                                Err(_) => bail!("'finalize' failed to evaluate command ({command})"),
+                            }
+                        }
+                        false => {
+                            // Exit the loop.
+                            active_loop = None;
+                            counter += 1;
+                        }
+                    },
+                    // Otherwise, the `position` command is a no-op.
+                    _ => counter += 1,
+                },
                 Command::Await(await_) => {
                     // Check that the `await` register's locator is greater than the last seen call locator.
                     // This ensures that futures are invoked in the order they are called.
@@ -304,6 +471,40 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
                     // Push the callee state onto the stack.
                     states.push(callee_state);
 
+                    // Record the command to the profiler, if any.
+                    profile_command(profile, command);
+                    break;
+                }
+                Command::CallDynamic(call_dynamic) => {
+                    // Retrieve the programs with an active finalize state, including the current program.
+                    let active_programs =
+                        states.iter().map(|state| state.stack.program_id()).chain([stack.program_id()]);
+
+                    let callee_state = match handle_halting!(panic::AssertUnwindSafe(|| {
+                        // Set up the finalize state for the dynamic call.
+                        setup_call_dynamic(process, state, call_dynamic, stack, &registers, active_programs)
+                    })) {
+                        Ok(Ok(callee_state)) => callee_state,
+                        // If the evaluation fails, bail and return the error.
+                        Ok(Err(error)) => bail!("'finalize' failed to evaluate command ({command}): {error}"),
+                        // If the evaluation fails, bail and return the error.
+                        Err(_) => bail!("'finalize' failed to evaluate command ({command})"),
+                    };
+
+                    // Increment the counter.
+                    counter += 1;
+
+                    // Aggregate the caller state.
+                    let caller_state =
+                        FinalizeState { counter, finalize, registers, stack, call_counter, recent_call_locator };
+
+                    // Push the caller state onto the stack.
+                    states.push(caller_state);
+                    // Push the callee state onto the stack.
+                    states.push(callee_state);
+
+                    // Record the command to the profiler, if any.
+                    profile_command(profile, command);
                     break;
                 }
                 _ => {
@@ -311,9 +512,20 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
                         handle_halting!(panic::AssertUnwindSafe(|| { command.finalize(stack, store, &mut registers) }));
                     match result {
                         // If the evaluation succeeds with an operation, add it to the list.
-                        Ok(Ok(Some(finalize_operation))) => finalize_operations.push(finalize_operation),
+                        Ok(Ok(Some(finalize_operation))) => {
+                            // Notify the debugger of the mapping write.
+                            if let Some(debugger) = debugger {
+                                debugger.on_mapping_write(&locator, counter, &finalize_operation);
+                            }
+                            finalize_operations.push(finalize_operation)
+                        }
                         // If the evaluation succeeds with no operation, continue.
-                        Ok(Ok(None)) => {}
+                        Ok(Ok(None)) => {
+                            // Notify the debugger of the mapping read, if any.
+                            if let Some(debugger) = debugger {
+                                debug_mapping_read(debugger, &locator, counter, command, stack, &registers)?;
+                            }
+                        }
                         // If the evaluation fails, bail and return the error.
                         Ok(Err(error)) => bail!("'finalize' failed to evaluate command ({command}): {error}"),
                         // If the evaluation fails, bail and return the error.
@@ -322,6 +534,8 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
                     counter += 1;
                 }
             };
+            // Record the command to the profiler, if any.
+            profile_command(profile, command);
         }
     }
 
@@ -406,6 +620,147 @@ fn setup_await<'a, N: Network>(
     initialize_finalize_state(state, &future, stack, transition_id)
 }
 
+// A helper function that sets up the dynamic call operation.
+#[inline]
+fn setup_call_dynamic<'a, 'b, N: Network>(
+    process: &'a Process<N>,
+    state: FinalizeGlobalState,
+    call_dynamic: &CallDynamic<N>,
+    stack: &Stack<N>,
+    registers: &FinalizeRegisters<N>,
+    mut active_programs: impl Iterator<Item = &'b ProgramID<N>>,
+) -> Result<FinalizeState<'a, N>> {
+    // Load the callee program ID and the arguments.
+    let (program_id, arguments) = call_dynamic.load(stack, registers)?;
+    // Ensure the callee program does not have an active finalize state, to prevent reentrancy.
+    if active_programs.any(|active_program| active_program == &program_id) {
+        bail!("Reentrant call to '{program_id}' is not allowed");
+    }
+
+    // Ensure the callee program is not deprecated.
+    ensure!(!process.is_deprecated(&program_id), "Cannot call '{program_id}', as it is deprecated");
+
+    // Retrieve the callee stack and function.
+    let stack = process.get_stack(program_id)?.as_ref();
+    let function = stack.get_function_ref(call_dynamic.function_name())?;
+    // Ensure the callee is a finalize-only function.
+    let finalize = check_finalize_only(stack, function)?;
+    // Ensure the number of arguments matches the number of finalize inputs.
+    ensure!(
+        finalize.inputs().len() == arguments.len(),
+        "'{program_id}/{}' expects {} arguments, found {}",
+        function.name(),
+        finalize.inputs().len(),
+        arguments.len()
+    );
+
+    // Initialize the registers, under the transition ID of the caller.
+    let mut registers = FinalizeRegisters::new(
+        state,
+        *registers.transition_id(),
+        *function.name(),
+        stack.get_finalize_types(function.name())?.clone(),
+    );
+    // Store the inputs.
+    finalize
+        .inputs()
+        .iter()
+        .zip_eq(arguments)
+        .try_for_each(|(input, argument)| registers.store(stack, input.register(), argument))?;
+
+    Ok(FinalizeState { counter: 0, finalize, registers, stack, call_counter: 0, recent_call_locator: None })
+}
+
+// A helper function that checks the given function may be invoked by `call.dynamic`, and returns its finalize logic.
+// A finalize-only function takes public inputs, and only forwards them in order to its finalize scope,
+// which must be bounded, must be covered by the prepaid callee cost, and must not invoke other finalize scopes.
+fn check_finalize_only<'a, N: Network>(stack: &Stack<N>, function: &'a Function<N>) -> Result<&'a Finalize<N>> {
+    let name = function.name();
+    // Ensure the function has a finalize scope.
+    let Some(finalize) = function.finalize_logic() else {
+        bail!("Function '{name}' does not have an associated finalize block")
+    };
+    // Ensure the inputs are public.
+    ensure!(
+        function.inputs().iter().all(|input| matches!(input.value_type(), ValueType::Public(..))),
+        "Function '{name}' must only take public inputs to be called dynamically"
+    );
+    // Ensure the body only forwards the inputs to the finalize scope.
+    let forwards_inputs = match function.instructions() {
+        [Instruction::Async(async_)] => async_
+            .operands()
+            .iter()
+            .map(|operand| match operand {
+                Operand::Register(register) => Some(register),
+                _ => None,
+            })
+            .eq(function.inputs().iter().map(|input| Some(input.register()))),
+        _ => false,
+    };
+    ensure!(forwards_inputs, "Function '{name}' must only forward its inputs to 'async' to be called dynamically");
+    // Ensure the finalize scope is bounded and does not invoke other finalize scopes.
+    ensure!(
+        finalize.commands().len() <= CallDynamic::<N>::MAX_CALLEE_COMMANDS,
+        "Function '{name}' exceeds {} finalize commands, and cannot be called dynamically",
+        CallDynamic::<N>::MAX_CALLEE_COMMANDS
+    );
+    ensure!(
+        !finalize
+            .commands()
+            .iter()
+            .any(|command| matches!(command, Command::Await(_) | Command::Iter(_) | Command::CallDynamic(_))),
+        "Function '{name}' cannot contain 'await', 'iter', or 'call.dynamic' to be called dynamically"
+    );
+    // Ensure the finalize cost is covered by the prepaid cost of a `call.dynamic` callee.
+    let cost_model = CostModel::for_network::<N>();
+    let finalize_cost = cost_model.finalize_cost(stack, name)?;
+    ensure!(
+        finalize_cost <= cost_model.call_dynamic_callee,
+        "Function '{name}' costs {finalize_cost} microcredits, which exceeds the prepaid {} microcredits of a dynamic call",
+        cost_model.call_dynamic_callee
+    );
+    Ok(finalize)
+}
+
+// A helper function that enters the given loop, and returns the index of the position that ends the loop body.
+#[inline]
+fn enter_loop<N: Network>(
+    counter: usize,
+    iter: &Iter<N>,
+    finalize: &Finalize<N>,
+    stack: &Stack<N>,
+    registers: &mut FinalizeRegisters<N>,
+) -> Result<usize> {
+    // Retrieve the index of the position that ends the loop body.
+    let end = match finalize.positions().get(iter.position()) {
+        Some(index) if *index > counter => *index,
+        Some(_) => bail!("Cannot loop until an earlier position '{}' in the program", iter.position()),
+        None => bail!("The position '{}' does not exist.", iter.position()),
+    };
+    // Assign the start of the range to the loop counter.
+    iter.finalize(stack, registers, *iter.start())?;
+    Ok(end)
+}
+
+// A helper function that prepares the registers for the next iteration of the given loop.
+#[inline]
+fn next_iteration<N: Network>(
+    iter: &Iter<N>,
+    value: u32,
+    stack: &Stack<N>,
+    registers: &mut FinalizeRegisters<N>,
+) -> Result<()> {
+    // Retrieve the locator of the loop counter.
+    let locator = match iter.destination() {
+        Register::Locator(locator) => *locator,
+        Register::Access(..) => bail!("The 'iter' destination register must be a locator"),
+    };
+    // Clear the loop counter and the registers assigned in the loop body.
+    registers.clear_from(locator);
+    // Assign the next value to the loop counter.
+    iter.finalize(stack, registers, U32::new(value))
+}
+
 // A helper function that returns the index to branch to.
 #[inline]
 fn branch_to<N: Network, const VARIANT: u8>(
@@ -0,0 +1,124 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use synthesizer_program::Opcode;
+
+use std::time::{Duration, Instant};
+
+/// The number of times an opcode was evaluated in a finalize scope, and the cumulative time spent evaluating it.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct CommandProfile {
+    /// The number of times the opcode was evaluated.
+    pub count: u64,
+    /// The cumulative time spent evaluating the opcode.
+    pub elapsed: Duration,
+}
+
+/// A profiler for the finalize scopes evaluated by `Process::finalize_execution_with_profiler`.
+///
+/// The profiler aggregates the evaluations of each opcode per finalize scope, i.e. per program and function.
+/// Note: Commands that fail are not recorded. The time of an `await` or `call.dynamic` command
+/// excludes the finalize scope it invokes, which is recorded under its own locator.
+#[derive(Debug)]
+pub struct FinalizeProfiler<N: Network> {
+    /// The mapping of finalize scopes to the profile of each opcode.
+    profiles: RwLock<IndexMap<Locator<N>, IndexMap<Opcode, CommandProfile>>>,
+}
+
+impl<N: Network> FinalizeProfiler<N> {
+    /// Initializes a new finalize profiler.
+    pub fn new() -> Self {
+        Self { profiles: Default::default() }
+    }
+
+    /// Records an evaluation of the given command in the given finalize scope.
+    pub fn record(&self, locator: &Locator<N>, command: &Command<N>, elapsed: Duration) {
+        let mut profiles = self.profiles.write();
+        let profile = profiles.entry(*locator).or_default().entry(command.opcode()).or_default();
+        profile.count = profile.count.saturating_add(1);
+        profile.elapsed = profile.elapsed.saturating_add(elapsed);
+    }
+
+    /// Returns the profile of each opcode in the given finalize scope.
+    pub fn get(&self, locator: &Locator<N>) -> Option<IndexMap<Opcode, CommandProfile>> {
+        self.profiles.read().get(locator).cloned()
+    }
+
+    /// Returns the cumulative time spent in each finalize scope, sorted from the most to the least time spent.
+    pub fn to_scope_timings(&self) -> Vec<(Locator<N>, Duration)> {
+        let mut timings = self
+            .profiles
+            .read()
+            .iter()
+            .map(|(locator, profiles)| (*locator, profiles.values().map(|profile| profile.elapsed).sum()))
+            .collect::<Vec<(_, Duration)>>();
+        timings.sort_by(|(_, a), (_, b)| b.cmp(a));
+        timings
+    }
+
+    /// Returns the cumulative time spent in each program, sorted from the most to the least time spent.
+    pub fn to_program_timings(&self) -> Vec<(ProgramID<N>, Duration)> {
+        let mut timings = IndexMap::<ProgramID<N>, Duration>::new();
+        for (locator, duration) in self.to_scope_timings() {
+            let timing = timings.entry(*locator.program_id()).or_default();
+            *timing = timing.saturating_add(duration);
+        }
+        timings.sort_by(|_, a, _, b| b.cmp(a));
+        timings.into_iter().collect()
+    }
+
+    /// Clears the recorded profiles.
+    pub fn clear(&self) {
+        self.profiles.write().clear();
+    }
+
+    /// Publishes the recorded profiles to the metrics registry.
+    /// Each opcode of a finalize scope is reported as a pair of counters, labelled by program, function, and opcode.
+    #[cfg(feature = "metrics")]
+    pub fn publish_metrics(&self) {
+        for (locator, profiles) in self.profiles.read().iter() {
+            for (opcode, profile) in profiles {
+                let labels = [
+                    ("program", locator.program_id().to_string()),
+                    ("function", locator.resource().to_string()),
+                    ("opcode", opcode.to_string()),
+                ];
+                metrics::counter_label(metrics::finalize::COMMAND_COUNT, &labels, profile.count);
+                metrics::counter_label(
+                    metrics::finalize::COMMAND_MICROS,
+                    &labels,
+                    u64::try_from(profile.elapsed.as_micros()).unwrap_or(u64::MAX),
+                );
+            }
+        }
+    }
+}
+
+impl<N: Network> Default for FinalizeProfiler<N> {
+    /// Initializes a new finalize profiler.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Records the time elapsed since the given instant for the command, if the finalize scope is profiled.
+pub(crate) fn profile_command<N: Network>(
+    profile: Option<(&FinalizeProfiler<N>, Locator<N>, Instant)>,
+    command: &Command<N>,
+) {
+    if let Some((profiler, locator, start)) = profile {
+        profiler.record(&locator, command, start.elapsed());
+    }
+}
@@ -0,0 +1,81 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use std::{any::Any, panic::AssertUnwindSafe};
+use utilities::handle_halting;
+
+/// The error returned when the process halts, e.g. when the circuit environment halts on a malformed input.
+///
+/// Callers may distinguish a halt from other errors with `error.downcast_ref::<Halt>()`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Halt {
+    /// The halt message.
+    message: String,
+}
+
+impl Halt {
+    /// Returns the halt message.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Initializes a new halt from the given panic payload.
+    fn from_payload(payload: Box<dyn Any + Send>) -> Self {
+        let message = match payload.downcast::<String>() {
+            Ok(message) => *message,
+            Err(payload) => match payload.downcast::<&'static str>() {
+                Ok(message) => message.to_string(),
+                Err(_) => "An unknown halt occurred".to_string(),
+            },
+        };
+        Self { message }
+    }
+}
+
+impl Display for Halt {
+    /// Prints the halt as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "Halted: {}", self.message)
+    }
+}
+
+impl std::error::Error for Halt {}
+
+/// Runs the given operation, converting a halt into a `Halt` error.
+pub(crate) fn catch_halt<T>(operation: impl FnOnce() -> Result<T>) -> Result<T> {
+    match handle_halting!(AssertUnwindSafe(operation)) {
+        Ok(result) => result,
+        Err(payload) => Err(Halt::from_payload(payload).into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_catch_halt() {
+        // Ensure the result is returned if the operation does not halt.
+        assert_eq!(catch_halt(|| Ok(1u8)).unwrap(), 1u8);
+        assert!(catch_halt(|| -> Result<()> { bail!("error") }).unwrap_err().downcast_ref::<Halt>().is_none());
+
+        // Ensure a halt is converted into a `Halt` error.
+        let error = catch_halt(|| -> Result<()> { panic!("halted on {}", 1u8) }).unwrap_err();
+        assert_eq!(error.downcast_ref::<Halt>().unwrap().message(), "halted on 1");
+        let error = catch_halt(|| -> Result<()> { panic!("halted") }).unwrap_err();
+        assert_eq!(error.downcast_ref::<Halt>().unwrap().message(), "halted");
+    }
+}
@@ -27,11 +27,44 @@ pub use trace::*;
 mod traits;
 pub use traits::*;
 
+mod analyze;
+pub use analyze::*;
+
+mod call_depth;
+pub use call_depth::*;
+
+mod cost;
+pub use cost::*;
+
+mod debugger;
+pub use debugger::*;
+
+mod finalize_profiler;
+pub use finalize_profiler::*;
+
+mod halt;
+pub use halt::*;
+
+mod profiler;
+pub use profiler::*;
+
+mod evaluate;
+pub use evaluate::*;
+
+mod proving;
+pub use proving::*;
+
+mod template;
+pub use template::*;
+
 mod authorize;
 mod deploy;
-mod evaluate;
+#[cfg(feature = "prover")]
+mod disclose;
 mod execute;
 mod finalize;
+mod sequence;
+mod upgrade;
 mod verify_deployment;
 mod verify_execution;
 mod verify_fee;
@@ -41,11 +74,11 @@ mod tests;
 
 use console::{
     account::PrivateKey,
-    network::prelude::*,
+    network::{prelude::*, ConsensusVersion},
     program::{compute_function_id, Identifier, Literal, Locator, Plaintext, ProgramID, Record, Response, Value},
     types::{Field, U16, U64},
 };
-use ledger_block::{Deployment, Execution, Fee, Input, Transition};
+use ledger_block::{Deployment, Execution, Fee, Input, MappingPolicy, Transition};
 use ledger_store::{atomic_batch_scope, FinalizeStorage, FinalizeStore};
 use synthesizer_program::{
     Branch,
@@ -55,6 +88,7 @@ use synthesizer_program::{
     FinalizeGlobalState,
     FinalizeOperation,
     Instruction,
+    MappingLocator,
     Program,
     RegistersLoad,
     RegistersStore,
@@ -63,7 +97,7 @@ use synthesizer_program::{
 use synthesizer_snark::{ProvingKey, UniversalSRS, VerifyingKey};
 
 use aleo_std::prelude::{finish, lap, timer};
-use indexmap::IndexMap;
+use indexmap::{IndexMap, IndexSet};
 use parking_lot::RwLock;
 use std::{collections::HashMap, sync::Arc};
 
@@ -76,16 +110,32 @@ pub struct Process<N: Network> {
     universal_srs: Arc<UniversalSRS<N>>,
     /// The mapping of program IDs to stacks.
     stacks: IndexMap<ProgramID<N>, Arc<Stack<N>>>,
+    /// The set of deprecated program IDs.
+    deprecated_programs: IndexSet<ProgramID<N>>,
+    /// The finalize debugger, if the process is in debug mode.
+    finalize_debugger: Option<Arc<dyn FinalizeDebugger<N>>>,
+    /// The profiler, if the process is profiled.
+    profiler: Option<Arc<dyn Profiler<N>>>,
+    /// The maximum depth of nested function calls.
+    max_call_depth: usize,
 }
 
 impl<N: Network> Process<N> {
     /// Initializes a new process.
+    #[cfg(feature = "prover")]
     #[inline]
     pub fn setup<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(rng: &mut R) -> Result<Self> {
         let timer = timer!("Process:setup");
 
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: IndexMap::new(),
+            deprecated_programs: IndexSet::new(),
+            finalize_debugger: None,
+            profiler: None,
+            max_call_depth: Self::DEFAULT_MAX_CALL_DEPTH,
+        };
         lap!(timer, "Initialize process");
 
         // Initialize the 'credits.aleo' program.
@@ -140,7 +190,14 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process::load");
 
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: IndexMap::new(),
+            deprecated_programs: IndexSet::new(),
+            finalize_debugger: None,
+            profiler: None,
+            max_call_depth: Self::DEFAULT_MAX_CALL_DEPTH,
+        };
         lap!(timer, "Initialize process");
 
         // Initialize the 'credits.aleo' program.
@@ -173,7 +230,14 @@ impl<N: Network> Process<N> {
     #[cfg(feature = "wasm")]
     pub fn load_web() -> Result<Self> {
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: IndexMap::new(),
+            deprecated_programs: IndexSet::new(),
+            finalize_debugger: None,
+            profiler: None,
+            max_call_depth: Self::DEFAULT_MAX_CALL_DEPTH,
+        };
 
         // Initialize the 'credits.aleo' program.
         let program = Program::credits()?;
@@ -200,6 +264,19 @@ impl<N: Network> Process<N> {
         self.stacks.contains_key(program_id)
     }
 
+    /// Returns `true` if the program with the given ID is deprecated.
+    #[inline]
+    pub fn is_deprecated(&self, program_id: &ProgramID<N>) -> bool {
+        self.deprecated_programs.contains(program_id)
+    }
+
+    /// Marks the program with the given ID as deprecated, so it can no longer be called dynamically.
+    /// This method should **only** be called once the deprecation is finalized.
+    #[inline]
+    pub fn mark_deprecated(&mut self, program_id: ProgramID<N>) {
+        self.deprecated_programs.insert(program_id);
+    }
+
     /// Returns the stack for the given program ID.
     #[inline]
     pub fn get_stack(&self, program_id: impl TryInto<ProgramID<N>>) -> Result<&Arc<Stack<N>>> {
@@ -245,6 +322,20 @@ impl<N: Network> Process<N> {
         self.get_stack(program_id)?.get_verifying_key(&function_name)
     }
 
+    /// Returns the indices of the external record inputs that are consumed by the given program ID and function name.
+    /// The serial numbers of these records are published in the transition of the function itself.
+    #[inline]
+    pub fn get_consumed_external_records(
+        &self,
+        program_id: impl TryInto<ProgramID<N>>,
+        function_name: impl TryInto<Identifier<N>>,
+    ) -> Result<Vec<usize>> {
+        // Prepare the function name.
+        let function_name = function_name.try_into().map_err(|_| anyhow!("Invalid function name"))?;
+        // Return the indices of the consumed external record inputs.
+        self.get_stack(program_id)?.get_consumed_external_records(&function_name)
+    }
+
     /// Inserts the given proving key, for the given program ID and function name.
     #[inline]
     pub fn insert_proving_key(
@@ -268,6 +359,7 @@ impl<N: Network> Process<N> {
     }
 
     /// Synthesizes the proving and verifying key for the given program ID and function name.
+    #[cfg(feature = "prover")]
     #[inline]
     pub fn synthesize_key<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
         &self,
@@ -0,0 +1,219 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use std::time::{Duration, Instant};
+
+/// A phase of constructing a proof for a function.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ProfilePhase {
+    /// Synthesizing the function (its request, instructions, and response) into a circuit.
+    Synthesis,
+    /// Ejecting the witness assignment from the circuit.
+    Witness,
+    /// Indexing and committing to the circuit, to produce its proving and verifying key.
+    Commit,
+    /// Proving the circuit, or for a deployment, certifying the circuit key.
+    Prove,
+}
+
+impl Display for ProfilePhase {
+    /// Prints the phase as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Synthesis => write!(f, "synthesis"),
+            Self::Witness => write!(f, "witness"),
+            Self::Commit => write!(f, "commit"),
+            Self::Prove => write!(f, "prove"),
+        }
+    }
+}
+
+/// A profiler for the circuits synthesized and proven by `Process::deploy` and `Process::execute`.
+///
+/// Each callback receives the locator of the function being profiled.
+/// The callbacks are invoked in the order of synthesis, and do not affect the deployment or execution.
+/// Note: The synthesis phase of a function includes the time spent synthesizing the functions it calls.
+pub trait Profiler<N: Network>: Send + Sync {
+    /// Invoked after an instruction is synthesized, with the number of constraints it added to the circuit.
+    /// For a `call` to a closure, this includes the constraints of the closure,
+    /// while a called function is profiled under its own locator.
+    fn on_instruction(
+        &self,
+        _locator: &Locator<N>,
+        _index: usize,
+        _instruction: &Instruction<N>,
+        _num_constraints: u64,
+    ) {
+    }
+
+    /// Invoked after a phase is completed, with the time elapsed in the phase.
+    fn on_phase(&self, _locator: &Locator<N>, _phase: ProfilePhase, _elapsed: Duration) {}
+}
+
+impl<N: Network> Debug for dyn Profiler<N> {
+    /// Prints the profiler as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "Profiler")
+    }
+}
+
+/// The number of constraints added to a circuit by an instruction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InstructionProfile<N: Network> {
+    /// The locator of the function.
+    pub locator: Locator<N>,
+    /// The index of the instruction in the function.
+    pub index: usize,
+    /// The opcode of the instruction.
+    pub opcode: String,
+    /// The number of constraints added by the instruction.
+    pub num_constraints: u64,
+}
+
+/// The time elapsed in a phase of constructing a proof for a function.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PhaseProfile<N: Network> {
+    /// The locator of the function.
+    pub locator: Locator<N>,
+    /// The phase.
+    pub phase: ProfilePhase,
+    /// The time elapsed in the phase.
+    pub elapsed: Duration,
+}
+
+/// A profiler that records every callback, and exports the records in the folded stack format of flamegraphs.
+#[derive(Debug)]
+pub struct ProfileRecorder<N: Network> {
+    /// The recorded instruction profiles.
+    instructions: RwLock<Vec<InstructionProfile<N>>>,
+    /// The recorded phase profiles.
+    phases: RwLock<Vec<PhaseProfile<N>>>,
+}
+
+impl<N: Network> ProfileRecorder<N> {
+    /// Initializes a new profile recorder.
+    pub fn new() -> Self {
+        Self { instructions: Default::default(), phases: Default::default() }
+    }
+
+    /// Returns the recorded instruction profiles.
+    pub fn instructions(&self) -> Vec<InstructionProfile<N>> {
+        self.instructions.read().clone()
+    }
+
+    /// Returns the recorded phase profiles.
+    pub fn phases(&self) -> Vec<PhaseProfile<N>> {
+        self.phases.read().clone()
+    }
+
+    /// Clears the recorded profiles.
+    pub fn clear(&self) {
+        self.instructions.write().clear();
+        self.phases.write().clear();
+    }
+
+    /// Returns the number of constraints of each instruction, in the folded stack format.
+    /// Each line is of the form `program.aleo/function;index:opcode num_constraints`.
+    /// Instructions that are synthesized more than once are summed into a single line.
+    pub fn to_folded_constraints(&self) -> String {
+        // Sum the constraints of each stack.
+        let mut stacks = IndexMap::<String, u64>::new();
+        for profile in self.instructions.read().iter() {
+            let stack = format!("{};{}:{}", profile.locator, profile.index, profile.opcode);
+            let count = stacks.entry(stack).or_default();
+            *count = count.saturating_add(profile.num_constraints);
+        }
+        Self::to_folded(stacks)
+    }
+
+    /// Returns the time elapsed in each phase, in microseconds, in the folded stack format.
+    /// Each line is of the form `program.aleo/function;phase microseconds`.
+    /// Phases that are completed more than once are summed into a single line.
+    pub fn to_folded_timings(&self) -> String {
+        // Sum the elapsed time of each stack.
+        let mut stacks = IndexMap::<String, u64>::new();
+        for profile in self.phases.read().iter() {
+            let stack = format!("{};{}", profile.locator, profile.phase);
+            let count = stacks.entry(stack).or_default();
+            *count = count.saturating_add(u64::try_from(profile.elapsed.as_micros()).unwrap_or(u64::MAX));
+        }
+        Self::to_folded(stacks)
+    }
+
+    /// Returns the given stacks and counts as lines in the folded stack format.
+    fn to_folded(stacks: IndexMap<String, u64>) -> String {
+        stacks.into_iter().map(|(stack, count)| format!("{stack} {count}\n")).collect()
+    }
+}
+
+impl<N: Network> Default for ProfileRecorder<N> {
+    /// Initializes a new profile recorder.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: Network> Profiler<N> for ProfileRecorder<N> {
+    /// Records the number of constraints added by the instruction.
+    fn on_instruction(&self, locator: &Locator<N>, index: usize, instruction: &Instruction<N>, num_constraints: u64) {
+        self.instructions.write().push(InstructionProfile {
+            locator: *locator,
+            index,
+            opcode: instruction.opcode().to_string(),
+            num_constraints,
+        });
+    }
+
+    /// Records the time elapsed in the phase.
+    fn on_phase(&self, locator: &Locator<N>, phase: ProfilePhase, elapsed: Duration) {
+        self.phases.write().push(PhaseProfile { locator: *locator, phase, elapsed });
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Returns the process, with the given profiler invoked by `Process::deploy` and `Process::execute`.
+    pub fn with_profiler(mut self, profiler: Arc<dyn Profiler<N>>) -> Self {
+        // Attach the profiler to the existing stacks.
+        for stack in self.stacks.values() {
+            stack.set_profiler(Some(profiler.clone()));
+        }
+        self.profiler = Some(profiler);
+        self
+    }
+
+    /// Returns the profiler, if the process is profiled.
+    pub fn profiler(&self) -> Option<&Arc<dyn Profiler<N>>> {
+        self.profiler.as_ref()
+    }
+}
+
+/// Runs the given closure, and notifies the profiler (if any) of the time elapsed in the given phase.
+pub(crate) fn profile_phase<N: Network, T>(
+    profiler: Option<&Arc<dyn Profiler<N>>>,
+    locator: &Locator<N>,
+    phase: ProfilePhase,
+    f: impl FnOnce() -> T,
+) -> T {
+    match profiler {
+        Some(profiler) => {
+            let start = Instant::now();
+            let output = f();
+            profiler.on_phase(locator, phase, start.elapsed());
+            output
+        }
+        None => f(),
+    }
+}
@@ -0,0 +1,90 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for ProvingRequest<N> {
+    /// Reads the proving request from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid proving request version"));
+        }
+        // Read the authorization.
+        let authorization = Authorization::read_le(&mut reader)?;
+        // Return the new `ProvingRequest` instance.
+        Self::new(authorization).map_err(error)
+    }
+}
+
+impl<N: Network> ToBytes for ProvingRequest<N> {
+    /// Writes the proving request to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the authorization.
+        self.authorization.write_le(&mut writer)
+    }
+}
+
+impl<N: Network> FromBytes for ProvingResponse<N> {
+    /// Reads the proving response from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid proving response version"));
+        }
+        // Read the execution.
+        let execution = Execution::read_le(&mut reader)?;
+        // Return the new `ProvingResponse` instance.
+        Ok(Self::new(execution))
+    }
+}
+
+impl<N: Network> ToBytes for ProvingResponse<N> {
+    /// Writes the proving response to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the execution.
+        self.execution.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Construct a new proving request.
+        let expected = crate::proving::test_helpers::sample_proving_request(rng);
+        // Check the byte representation.
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, ProvingRequest::read_le(&expected_bytes[..])?);
+
+        // Construct a new proving response.
+        let expected = crate::proving::test_helpers::sample_proving_response();
+        // Check the byte representation.
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, ProvingResponse::read_le(&expected_bytes[..])?);
+        Ok(())
+    }
+}
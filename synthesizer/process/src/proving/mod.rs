@@ -0,0 +1,202 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod serialize;
+mod string;
+
+use crate::{Authorization, Process};
+use console::{
+    network::prelude::*,
+    program::{Identifier, ProgramID},
+    types::Field,
+};
+use ledger_block::Execution;
+
+/// A request for an untrusted prover to prove an authorization.
+///
+/// The authorization is already signed, so the prover never learns the private key.
+/// The request does not include a fee: once the client checks the returned execution with
+/// `ProvingRequest::verify_response`, it authorizes a fee for `ProvingRequest::execution_id`.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ProvingRequest<N: Network> {
+    /// The program ID of the main function.
+    program_id: ProgramID<N>,
+    /// The name of the main function.
+    function_name: Identifier<N>,
+    /// The execution ID, which the fee commits to.
+    execution_id: Field<N>,
+    /// The authorization to prove.
+    authorization: Authorization<N>,
+}
+
+/// The response of a prover to a `ProvingRequest`.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ProvingResponse<N: Network> {
+    /// The proven execution.
+    execution: Execution<N>,
+}
+
+impl<N: Network> ProvingRequest<N> {
+    /// Initializes a new proving request for the given authorization.
+    pub fn new(authorization: Authorization<N>) -> Result<Self> {
+        // Ensure the authorization is not for a fee.
+        ensure!(
+            !authorization.is_fee_private() && !authorization.is_fee_public(),
+            "A proving request must not be for a fee, as the fee is authorized after proving"
+        );
+        // Retrieve the main function.
+        let request = authorization.peek_next()?;
+        // Compute the execution ID.
+        let execution_id = authorization.to_execution_id()?;
+        Ok(Self {
+            program_id: *request.program_id(),
+            function_name: *request.function_name(),
+            execution_id,
+            authorization,
+        })
+    }
+
+    /// Returns the program ID of the main function.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the name of the main function.
+    pub const fn function_name(&self) -> &Identifier<N> {
+        &self.function_name
+    }
+
+    /// Returns the execution ID, which the fee commits to.
+    pub const fn execution_id(&self) -> Field<N> {
+        self.execution_id
+    }
+
+    /// Returns the authorization to prove.
+    pub const fn authorization(&self) -> &Authorization<N> {
+        &self.authorization
+    }
+
+    /// Returns the authorization to prove.
+    pub fn into_authorization(self) -> Authorization<N> {
+        self.authorization
+    }
+
+    /// Ensures the response is a valid execution of this request.
+    ///
+    /// The client runs this check before authorizing a fee for the execution, so an untrusted
+    /// prover cannot substitute a different execution, or return an invalid proof.
+    pub fn verify_response(&self, process: &Process<N>, response: &ProvingResponse<N>) -> Result<()> {
+        let execution = response.execution();
+        // Ensure the execution is for the requested transitions.
+        ensure!(
+            execution.to_execution_id()? == self.execution_id,
+            "The proven execution does not match the execution ID '{}'",
+            self.execution_id
+        );
+        // Ensure the execution is for the requested main function.
+        let main = execution.transitions().last().ok_or_else(|| anyhow!("The proven execution is empty"))?;
+        ensure!(
+            main.program_id() == &self.program_id && main.function_name() == &self.function_name,
+            "The proven execution is not for '{}/{}'",
+            self.program_id,
+            self.function_name
+        );
+        // Ensure the proof is valid.
+        process.verify_execution(execution)
+    }
+}
+
+impl<N: Network> ProvingResponse<N> {
+    /// Initializes a new proving response with the given execution.
+    pub const fn new(execution: Execution<N>) -> Self {
+        Self { execution }
+    }
+
+    /// Returns the proven execution.
+    pub const fn execution(&self) -> &Execution<N> {
+        &self.execution
+    }
+
+    /// Returns the proven execution.
+    pub fn into_execution(self) -> Execution<N> {
+        self.execution
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use super::*;
+    use console::{account::PrivateKey, program::Value};
+
+    type CurrentNetwork = console::network::MainnetV0;
+    type CurrentAleo = circuit::AleoV0;
+
+    /// Samples a proving request for a call to 'credits.aleo/transfer_public'.
+    pub(crate) fn sample_proving_request(rng: &mut TestRng) -> ProvingRequest<CurrentNetwork> {
+        // Initialize the process.
+        let process = Process::<CurrentNetwork>::load().unwrap();
+
+        // Sample a private key.
+        let private_key = PrivateKey::new(rng).unwrap();
+        // Prepare the inputs.
+        let recipient = console::account::Address::<CurrentNetwork>::new(rng.gen());
+        let inputs = [Value::from_str(&recipient.to_string()).unwrap(), Value::from_str("1u64").unwrap()];
+
+        // Compute the authorization.
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&private_key, "credits.aleo", "transfer_public", inputs.iter(), rng)
+            .unwrap();
+        ProvingRequest::new(authorization).unwrap()
+    }
+
+    /// Samples a proving response.
+    pub(crate) fn sample_proving_response() -> ProvingResponse<CurrentNetwork> {
+        ProvingResponse::new(crate::test_helpers::sample_execution())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Check the request is for the main function.
+        let request = test_helpers::sample_proving_request(rng);
+        let main = request.authorization().peek_next()?;
+        assert_eq!(request.program_id(), main.program_id());
+        assert_eq!(request.function_name(), main.function_name());
+        assert_eq!(request.execution_id(), request.authorization().to_execution_id()?);
+
+        // Check a fee authorization is rejected.
+        let fee = crate::stack::authorization::test_helpers::sample_authorization(rng);
+        assert!(ProvingRequest::new(fee).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_response_with_mismatched_execution() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Check an execution of a different authorization is rejected.
+        let request = test_helpers::sample_proving_request(rng);
+        let response = test_helpers::sample_proving_response();
+        let process = Process::load()?;
+        assert!(request.verify_response(&process, &response).is_err());
+        Ok(())
+    }
+}
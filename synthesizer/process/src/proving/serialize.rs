@@ -0,0 +1,155 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Serialize for ProvingRequest<N> {
+    /// Serializes the proving request into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let mut request = serializer.serialize_struct("ProvingRequest", 4)?;
+                request.serialize_field("program_id", &self.program_id)?;
+                request.serialize_field("function_name", &self.function_name)?;
+                request.serialize_field("execution_id", &self.execution_id)?;
+                request.serialize_field("authorization", &self.authorization)?;
+                request.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for ProvingRequest<N> {
+    /// Deserializes the proving request from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                // Parse the proving request from a string into a value.
+                let mut request = serde_json::Value::deserialize(deserializer)?;
+                // Recover the proving request.
+                let candidate = Self::new(DeserializeExt::take_from_value::<D>(&mut request, "authorization")?)
+                    .map_err(de::Error::custom)?;
+                // Ensure the metadata matches the authorization.
+                let program_id: ProgramID<N> = DeserializeExt::take_from_value::<D>(&mut request, "program_id")?;
+                let function_name: Identifier<N> = DeserializeExt::take_from_value::<D>(&mut request, "function_name")?;
+                let execution_id: Field<N> = DeserializeExt::take_from_value::<D>(&mut request, "execution_id")?;
+                match program_id == candidate.program_id
+                    && function_name == candidate.function_name
+                    && execution_id == candidate.execution_id
+                {
+                    true => Ok(candidate),
+                    false => Err(de::Error::custom("The proving request metadata does not match the authorization")),
+                }
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "proving request"),
+        }
+    }
+}
+
+impl<N: Network> Serialize for ProvingResponse<N> {
+    /// Serializes the proving response into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let mut response = serializer.serialize_struct("ProvingResponse", 1)?;
+                response.serialize_field("execution", &self.execution)?;
+                response.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for ProvingResponse<N> {
+    /// Deserializes the proving response from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                // Parse the proving response from a string into a value.
+                let mut response = serde_json::Value::deserialize(deserializer)?;
+                // Recover the proving response.
+                Ok(Self::new(DeserializeExt::take_from_value::<D>(&mut response, "execution")?))
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "proving response"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serde_json() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample the proving request.
+        let expected = crate::proving::test_helpers::sample_proving_request(rng);
+
+        // Serialize
+        let expected_string = &expected.to_string();
+        let candidate_string = serde_json::to_string(&expected)?;
+        assert_eq!(expected, serde_json::from_str(&candidate_string)?);
+
+        // Deserialize
+        assert_eq!(expected, ProvingRequest::from_str(expected_string)?);
+        assert_eq!(expected, serde_json::from_str(expected_string)?);
+
+        // Sample the proving response.
+        let expected = crate::proving::test_helpers::sample_proving_response();
+
+        // Serialize
+        let expected_string = &expected.to_string();
+        let candidate_string = serde_json::to_string(&expected)?;
+        assert_eq!(expected, serde_json::from_str(&candidate_string)?);
+
+        // Deserialize
+        assert_eq!(expected, ProvingResponse::from_str(expected_string)?);
+        assert_eq!(expected, serde_json::from_str(expected_string)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bincode() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample the proving request.
+        let expected = crate::proving::test_helpers::sample_proving_request(rng);
+
+        // Serialize
+        let expected_bytes = expected.to_bytes_le()?;
+        let expected_bytes_with_size_encoding = bincode::serialize(&expected)?;
+        assert_eq!(&expected_bytes[..], &expected_bytes_with_size_encoding[8..]);
+
+        // Deserialize
+        assert_eq!(expected, ProvingRequest::read_le(&expected_bytes[..])?);
+        assert_eq!(expected, bincode::deserialize(&expected_bytes_with_size_encoding[..])?);
+
+        // Sample the proving response.
+        let expected = crate::proving::test_helpers::sample_proving_response();
+
+        // Serialize
+        let expected_bytes = expected.to_bytes_le()?;
+        let expected_bytes_with_size_encoding = bincode::serialize(&expected)?;
+        assert_eq!(&expected_bytes[..], &expected_bytes_with_size_encoding[8..]);
+
+        // Deserialize
+        assert_eq!(expected, ProvingResponse::read_le(&expected_bytes[..])?);
+        assert_eq!(expected, bincode::deserialize(&expected_bytes_with_size_encoding[..])?);
+
+        Ok(())
+    }
+}
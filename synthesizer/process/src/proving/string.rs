@@ -0,0 +1,61 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromStr for ProvingRequest<N> {
+    type Err = Error;
+
+    /// Initializes the proving request from a JSON-string.
+    fn from_str(request: &str) -> Result<Self, Self::Err> {
+        Ok(serde_json::from_str(request)?)
+    }
+}
+
+impl<N: Network> Debug for ProvingRequest<N> {
+    /// Prints the proving request as a JSON-string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for ProvingRequest<N> {
+    /// Displays the proving request as a JSON-string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", serde_json::to_string(self).map_err::<fmt::Error, _>(ser::Error::custom)?)
+    }
+}
+
+impl<N: Network> FromStr for ProvingResponse<N> {
+    type Err = Error;
+
+    /// Initializes the proving response from a JSON-string.
+    fn from_str(response: &str) -> Result<Self, Self::Err> {
+        Ok(serde_json::from_str(response)?)
+    }
+}
+
+impl<N: Network> Debug for ProvingResponse<N> {
+    /// Prints the proving response as a JSON-string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for ProvingResponse<N> {
+    /// Displays the proving response as a JSON-string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", serde_json::to_string(self).map_err::<fmt::Error, _>(ser::Error::custom)?)
+    }
+}
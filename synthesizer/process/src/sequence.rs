@@ -0,0 +1,120 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use console::account::Address;
+use synthesizer_program::FinalizeStoreTrait;
+
+impl<N: Network> Process<N> {
+    /// Returns the program ID and mapping name that store the next sequence number of each address.
+    /// Note: The mapping is reserved under 'credits.aleo', and is initialized by the first sequenced execution.
+    pub fn sequence_mapping() -> Result<(ProgramID<N>, Identifier<N>)> {
+        Ok((ProgramID::from_str("credits.aleo")?, Identifier::from_str("sequence")?))
+    }
+
+    /// Returns the confirmed next sequence number of the given address,
+    /// which is `0` if the address has no sequenced executions.
+    pub fn next_sequence_confirmed<P: FinalizeStorage<N>>(
+        store: &FinalizeStore<N, P>,
+        address: &Address<N>,
+    ) -> Result<u64> {
+        // Retrieve the sequence mapping.
+        let (program_id, mapping_name) = Self::sequence_mapping()?;
+        // Return `0` if the mapping has not been initialized.
+        if !store.contains_mapping_confirmed(&program_id, &mapping_name)? {
+            return Ok(0);
+        }
+        // Retrieve the next sequence number.
+        let key = Plaintext::from(Literal::Address(*address));
+        to_sequence(address, store.get_value_confirmed(program_id, mapping_name, &key)?)
+    }
+
+    /// Returns the speculative next sequence number of the given address,
+    /// which is `0` if the address has no sequenced executions.
+    pub fn next_sequence_speculative<P: FinalizeStorage<N>>(
+        store: &FinalizeStore<N, P>,
+        address: &Address<N>,
+    ) -> Result<u64> {
+        // Retrieve the sequence mapping.
+        let (program_id, mapping_name) = Self::sequence_mapping()?;
+        // Return `0` if the mapping has not been initialized.
+        if !store.contains_mapping_speculative(&program_id, &mapping_name)? {
+            return Ok(0);
+        }
+        // Retrieve the next sequence number.
+        let key = Plaintext::from(Literal::Address(*address));
+        to_sequence(address, store.get_value_speculative(program_id, mapping_name, &key)?)
+    }
+
+    /// Checks that the given execution is sequenced, and carries the next sequence number of its fee payer.
+    /// On success, returns the fee payer.
+    pub fn check_sequence<P: FinalizeStorage<N>>(
+        store: &FinalizeStore<N, P>,
+        execution: &Execution<N>,
+        fee: Option<&Fee<N>>,
+    ) -> Result<Address<N>> {
+        // Retrieve the sequence number.
+        let Some(sequence) = execution.sequence() else { bail!("The execution is not sequenced") };
+        // Retrieve the fee payer, which owns the sequence number.
+        let Some(payer) = fee.and_then(Fee::payer) else { bail!("A sequenced execution must have a public fee") };
+        // Ensure the sequence number is the next sequence number of the payer.
+        let next_sequence = Self::next_sequence_speculative(store, &payer)?;
+        ensure!(
+            sequence == next_sequence,
+            "Invalid sequence number for '{payer}' - expected {next_sequence}, found {sequence}"
+        );
+        Ok(payer)
+    }
+
+    /// Finalizes the sequence number of the given execution, by incrementing the next sequence number of its fee payer.
+    pub(crate) fn finalize_sequence<P: FinalizeStorage<N>>(
+        store: &FinalizeStore<N, P>,
+        execution: &Execution<N>,
+        fee: Option<&Fee<N>>,
+    ) -> Result<Vec<FinalizeOperation<N>>> {
+        // Ensure the execution carries the next sequence number of its fee payer.
+        let payer = Self::check_sequence(store, execution, fee)?;
+        // Compute the next sequence number of the payer.
+        let next_sequence = match execution.sequence().and_then(|sequence| sequence.checked_add(1)) {
+            Some(next_sequence) => next_sequence,
+            None => bail!("The sequence number for '{payer}' overflowed"),
+        };
+
+        // Retrieve the sequence mapping.
+        let (program_id, mapping_name) = Self::sequence_mapping()?;
+        // Initialize a list for the finalize operations.
+        let mut finalize_operations = Vec::with_capacity(2);
+        // Initialize the mapping, if this is the first sequenced execution.
+        if !store.contains_mapping_speculative(&program_id, &mapping_name)? {
+            finalize_operations.push(store.initialize_mapping(program_id, mapping_name)?);
+        }
+        // Store the next sequence number of the payer.
+        finalize_operations.push(store.update_key_value(
+            program_id,
+            mapping_name,
+            Plaintext::from(Literal::Address(payer)),
+            Value::from(Literal::U64(U64::new(next_sequence))),
+        )?);
+        Ok(finalize_operations)
+    }
+}
+
+/// Returns the sequence number in the given mapping value, which is `0` if there is no value.
+fn to_sequence<N: Network>(address: &Address<N>, value: Option<Value<N>>) -> Result<u64> {
+    match value {
+        Some(Value::Plaintext(Plaintext::Literal(Literal::U64(sequence), _))) => Ok(*sequence),
+        Some(value) => bail!("Invalid sequence number '{value}' for '{address}'"),
+        None => Ok(0),
+    }
+}
@@ -0,0 +1,87 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for UnsignedAuthorization<N> {
+    /// Reads the unsigned authorization from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid unsigned authorization version"));
+        }
+
+        // Read the program ID.
+        let program_id = ProgramID::read_le(&mut reader)?;
+        // Read the function name.
+        let function_name = Identifier::read_le(&mut reader)?;
+
+        // Read the number of inputs.
+        let num_inputs = u8::read_le(&mut reader)?;
+        // Ensure the number of inputs is within the allowed range.
+        if num_inputs as usize > N::MAX_INPUTS {
+            return Err(error("Unsigned authorization (from 'read_le') has too many inputs"));
+        }
+        // Read the inputs.
+        let inputs = (0..num_inputs).map(|_| Value::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+
+        // Read the number of programs.
+        let num_programs = u16::read_le(&mut reader)?;
+        // Read the programs.
+        let programs = (0..num_programs).map(|_| Program::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+
+        // Return the new `UnsignedAuthorization` instance.
+        Self::new(program_id, function_name, inputs, programs).map_err(error)
+    }
+}
+
+impl<N: Network> ToBytes for UnsignedAuthorization<N> {
+    /// Writes the unsigned authorization to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the program ID.
+        self.program_id.write_le(&mut writer)?;
+        // Write the function name.
+        self.function_name.write_le(&mut writer)?;
+        // Write the number of inputs.
+        u8::try_from(self.inputs.len()).map_err(error)?.write_le(&mut writer)?;
+        // Write the inputs.
+        self.inputs.iter().try_for_each(|input| input.write_le(&mut writer))?;
+        // Write the number of programs.
+        u16::try_from(self.programs.len()).map_err(error)?.write_le(&mut writer)?;
+        // Write the programs.
+        self.programs.iter().try_for_each(|program| program.write_le(&mut writer))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Construct a new unsigned authorization.
+        let expected = crate::stack::authorization::unsigned::test_helpers::sample_unsigned_authorization(rng);
+
+        // Check the byte representation.
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, UnsignedAuthorization::read_le(&expected_bytes[..])?);
+        Ok(())
+    }
+}
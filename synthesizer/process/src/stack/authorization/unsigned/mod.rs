@@ -0,0 +1,166 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod serialize;
+mod sign;
+mod string;
+
+use crate::{Authorization, Process};
+use console::{
+    account::PrivateKey,
+    network::prelude::*,
+    program::{Identifier, ProgramID, Value},
+    types::Field,
+};
+use synthesizer_program::Program;
+
+/// A call to a program function that is not yet authorized by a private key.
+///
+/// An online machine prepares the unsigned authorization with `VM::authorize_unsigned`, and a
+/// machine holding the private key signs it with `Authorization::sign`. As the unsigned
+/// authorization carries the programs in the call graph, the signer does not need network access.
+#[derive(Clone, PartialEq, Eq)]
+pub struct UnsignedAuthorization<N: Network> {
+    /// The program ID.
+    program_id: ProgramID<N>,
+    /// The function name.
+    function_name: Identifier<N>,
+    /// The function inputs.
+    inputs: Vec<Value<N>>,
+    /// The programs in the call graph, each after its imports, excluding 'credits.aleo'.
+    programs: Vec<Program<N>>,
+}
+
+impl<N: Network> UnsignedAuthorization<N> {
+    /// Initializes a new unsigned authorization.
+    pub fn new(
+        program_id: ProgramID<N>,
+        function_name: Identifier<N>,
+        inputs: Vec<Value<N>>,
+        programs: Vec<Program<N>>,
+    ) -> Result<Self> {
+        // Ensure the number of inputs is within the allowed range.
+        ensure!(inputs.len() <= N::MAX_INPUTS, "Found too many inputs in the unsigned authorization");
+        // Ensure the number of programs is within the allowed range.
+        ensure!(programs.len() <= u16::MAX as usize, "Found too many programs in the unsigned authorization");
+
+        // Initialize the 'credits.aleo' program ID.
+        let credits_program_id = ProgramID::<N>::from_str("credits.aleo")?;
+        // Ensure each program follows its imports.
+        let mut program_ids = vec![credits_program_id];
+        for program in &programs {
+            ensure!(!program_ids.contains(program.id()), "Found a duplicate program '{}'", program.id());
+            for import in program.imports().keys() {
+                ensure!(program_ids.contains(import), "Program '{}' must follow its import '{import}'", program.id());
+            }
+            program_ids.push(*program.id());
+        }
+        // Ensure the function is in one of the programs.
+        ensure!(program_ids.contains(&program_id), "Program '{program_id}' is missing from the unsigned authorization");
+
+        Ok(Self { program_id, function_name, inputs, programs })
+    }
+
+    /// Returns the program ID.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the function name.
+    pub const fn function_name(&self) -> &Identifier<N> {
+        &self.function_name
+    }
+
+    /// Returns the function inputs.
+    pub fn inputs(&self) -> &[Value<N>] {
+        &self.inputs
+    }
+
+    /// Returns the programs in the call graph, each after its imports, excluding 'credits.aleo'.
+    pub fn programs(&self) -> &[Program<N>] {
+        &self.programs
+    }
+
+    /// Returns a process with the programs in the call graph.
+    pub fn to_process(&self) -> Result<Process<N>> {
+        let mut process = Process::load()?;
+        for program in &self.programs {
+            process.add_program(program)?;
+        }
+        Ok(process)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use super::*;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    /// Samples an unsigned authorization for a call that imports 'credits.aleo'.
+    pub(crate) fn sample_unsigned_authorization(rng: &mut TestRng) -> UnsignedAuthorization<CurrentNetwork> {
+        // Initialize the program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+import credits.aleo;
+
+program unsigned_authorization.aleo;
+
+function transfer:
+    input r0 as address.public;
+    input r1 as u64.public;
+    call credits.aleo/transfer_public r0 r1 into r2;
+    async transfer r2 into r3;
+    output r3 as unsigned_authorization.aleo/transfer.future;
+
+finalize transfer:
+    input r0 as credits.aleo/transfer_public.future;
+    await r0;",
+        )
+        .unwrap();
+
+        // Prepare the inputs.
+        let address = console::account::Address::<CurrentNetwork>::new(rng.gen());
+        let inputs = vec![Value::from_str(&address.to_string()).unwrap(), Value::from_str("1u64").unwrap()];
+
+        UnsignedAuthorization::new(*program.id(), Identifier::from_str("transfer").unwrap(), inputs, vec![program])
+            .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    #[test]
+    fn test_new() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let unsigned = test_helpers::sample_unsigned_authorization(rng);
+        let (program_id, function_name) = (*unsigned.program_id(), *unsigned.function_name());
+
+        // Check a missing program is rejected.
+        let result =
+            UnsignedAuthorization::<CurrentNetwork>::new(program_id, function_name, unsigned.inputs().to_vec(), vec![]);
+        assert!(result.is_err());
+        // Check a duplicate program is rejected.
+        let programs = vec![unsigned.programs()[0].clone(), unsigned.programs()[0].clone()];
+        let result = UnsignedAuthorization::new(program_id, function_name, unsigned.inputs().to_vec(), programs);
+        assert!(result.is_err());
+        Ok(())
+    }
+}
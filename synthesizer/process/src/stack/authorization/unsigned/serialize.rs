@@ -0,0 +1,98 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Serialize for UnsignedAuthorization<N> {
+    /// Serializes the unsigned authorization into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let mut authorization = serializer.serialize_struct("UnsignedAuthorization", 4)?;
+                authorization.serialize_field("program_id", &self.program_id)?;
+                authorization.serialize_field("function_name", &self.function_name)?;
+                authorization.serialize_field("inputs", &self.inputs)?;
+                authorization.serialize_field("programs", &self.programs)?;
+                authorization.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for UnsignedAuthorization<N> {
+    /// Deserializes the unsigned authorization from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                // Parse the unsigned authorization from a string into a value.
+                let mut authorization = serde_json::Value::deserialize(deserializer)?;
+                // Recover the unsigned authorization.
+                Self::new(
+                    DeserializeExt::take_from_value::<D>(&mut authorization, "program_id")?,
+                    DeserializeExt::take_from_value::<D>(&mut authorization, "function_name")?,
+                    DeserializeExt::take_from_value::<D>(&mut authorization, "inputs")?,
+                    DeserializeExt::take_from_value::<D>(&mut authorization, "programs")?,
+                )
+                .map_err(de::Error::custom)
+            }
+            false => {
+                FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "unsigned authorization")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serde_json() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample the unsigned authorization.
+        let expected = crate::stack::authorization::unsigned::test_helpers::sample_unsigned_authorization(rng);
+
+        // Serialize
+        let expected_string = &expected.to_string();
+        let candidate_string = serde_json::to_string(&expected)?;
+        assert_eq!(expected, serde_json::from_str(&candidate_string)?);
+
+        // Deserialize
+        assert_eq!(expected, UnsignedAuthorization::from_str(expected_string)?);
+        assert_eq!(expected, serde_json::from_str(expected_string)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bincode() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample the unsigned authorization.
+        let expected = crate::stack::authorization::unsigned::test_helpers::sample_unsigned_authorization(rng);
+
+        // Serialize
+        let expected_bytes = expected.to_bytes_le()?;
+        let expected_bytes_with_size_encoding = bincode::serialize(&expected)?;
+        assert_eq!(&expected_bytes[..], &expected_bytes_with_size_encoding[8..]);
+
+        // Deserialize
+        assert_eq!(expected, UnsignedAuthorization::read_le(&expected_bytes[..])?);
+        assert_eq!(expected, bincode::deserialize(&expected_bytes_with_size_encoding[..])?);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,91 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Authorization<N> {
+    /// Signs the unsigned authorization with the given private key.
+    ///
+    /// This only requires the programs carried by the unsigned authorization, so it may run on an
+    /// air-gapped machine. The authorization is then proven with `VM::execute_authorization`.
+    pub fn sign<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        unsigned: &UnsignedAuthorization<N>,
+        private_key: &PrivateKey<N>,
+        rng: &mut R,
+    ) -> Result<Self> {
+        unsigned.to_process()?.authorize::<A, R>(
+            private_key,
+            *unsigned.program_id(),
+            *unsigned.function_name(),
+            unsigned.inputs().iter(),
+            rng,
+        )
+    }
+
+    /// Signs a public fee for the given deployment or execution ID with the given private key.
+    ///
+    /// The execution ID of a signed authorization is given by `Authorization::to_execution_id`.
+    pub fn sign_fee_public<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        private_key: &PrivateKey<N>,
+        base_fee_in_microcredits: u64,
+        priority_fee_in_microcredits: u64,
+        deployment_or_execution_id: Field<N>,
+        rng: &mut R,
+    ) -> Result<Self> {
+        Process::load()?.authorize_fee_public::<A, R>(
+            private_key,
+            base_fee_in_microcredits,
+            priority_fee_in_microcredits,
+            deployment_or_execution_id,
+            rng,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::account::Address;
+
+    type CurrentNetwork = console::network::MainnetV0;
+    type CurrentAleo = circuit::network::AleoV0;
+
+    #[test]
+    fn test_sign() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let unsigned = crate::stack::authorization::unsigned::test_helpers::sample_unsigned_authorization(rng);
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+
+        // Sign the unsigned authorization.
+        let authorization = Authorization::sign::<CurrentAleo, _>(&unsigned, &private_key, rng)?;
+        // Check the call to the program, and the call to 'credits.aleo', are authorized.
+        assert_eq!(authorization.len(), 2);
+        let request = authorization.peek_next()?;
+        assert_eq!(request.program_id(), unsigned.program_id());
+        assert_eq!(request.function_name(), unsigned.function_name());
+        assert_eq!(request.signer(), &Address::try_from(&private_key)?);
+
+        // Sign the fee.
+        let fee = Authorization::sign_fee_public::<CurrentAleo, _>(
+            &private_key,
+            1_000,
+            0,
+            authorization.to_execution_id()?,
+            rng,
+        )?;
+        assert!(fee.is_fee_public());
+        Ok(())
+    }
+}
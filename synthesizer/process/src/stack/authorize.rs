@@ -32,6 +32,10 @@ impl<N: Network> Stack<N> {
         let function_name = function_name.try_into().map_err(|_| anyhow!("Invalid function name"))?;
         // Retrieve the input types.
         let input_types = self.get_function(&function_name)?.input_types();
+        // Retrieve the indices of the consumed external record inputs.
+        let consumed = self.get_consumed_external_records(&function_name)?;
+        // Retrieve the lock entries of the time-locked record inputs.
+        let time_locked = self.get_time_locked_records(&function_name)?;
         lap!(timer, "Retrieve the input types");
         // Set is_root to true.
         let is_root = true;
@@ -41,8 +45,18 @@ impl<N: Network> Stack<N> {
         // This is the root request and we do not have a root_tvk to pass on.
         let root_tvk = None;
         // Compute the request.
-        let request =
-            Request::sign(private_key, program_id, function_name, inputs, &input_types, root_tvk, is_root, rng)?;
+        let request = Request::sign_with_consumed_records(
+            private_key,
+            program_id,
+            function_name,
+            inputs,
+            &input_types,
+            &consumed,
+            &time_locked,
+            root_tvk,
+            is_root,
+            rng,
+        )?;
         lap!(timer, "Compute the request");
         // Initialize the authorization.
         let authorization = Authorization::new(request.clone());
@@ -17,11 +17,12 @@ use aleo_std::prelude::{finish, lap, timer};
 use console::{
     account::Field,
     network::prelude::*,
-    program::{Register, Request, Value, ValueType},
+    program::{Register, Request, Response, Value, ValueType},
 };
 use synthesizer_program::{
     Call,
     CallOperator,
+    Function,
     Operand,
     RegistersLoad,
     RegistersLoadCircuit,
@@ -33,18 +34,64 @@ use synthesizer_program::{
     StackProgram,
 };
 
+/// A function call that awaits the response of its callee.
+pub struct PendingCall<'a, N: Network, A: circuit::Aleo<Network = N>, S> {
+    /// The stack of the callee.
+    substack: &'a S,
+    /// The function of the callee.
+    function: Function<N>,
+    /// The request to the callee.
+    request: Request<N>,
+    /// The circuit inputs to the callee.
+    inputs: Vec<circuit::Value<A>>,
+    /// The number of public variables in the circuit of the caller.
+    num_public: u64,
+    /// The circuit of the caller, which is injected back once the callee responds.
+    r1cs: circuit::environment::R1CS<A::BaseField>,
+    /// The evaluated outputs of the callee, if the call is in execute mode.
+    expected_outputs: Option<Vec<Value<N>>>,
+}
+
+impl<'a, N: Network, A: circuit::Aleo<Network = N>, S> PendingCall<'a, N, A, S> {
+    /// Returns the stack of the callee.
+    pub const fn substack(&self) -> &'a S {
+        self.substack
+    }
+}
+
 pub trait CallTrait<N: Network> {
     /// Evaluates the instruction.
-    fn evaluate<A: circuit::Aleo<Network = N>>(
+    ///
+    /// If the instruction calls a function, the function is not evaluated, and the stack of the callee is returned.
+    /// The caller is expected to evaluate the function, and to resume with `CallTrait::resume_evaluate`.
+    fn evaluate<'a, A: circuit::Aleo<Network = N>, S: StackEvaluate<N> + StackMatches<N> + StackProgram<N>>(
+        &self,
+        stack: &'a S,
+        registers: &mut Registers<N, A>,
+    ) -> Result<Option<&'a S>>;
+
+    /// Assigns the outputs of an evaluated function call to the destination registers.
+    fn resume_evaluate<A: circuit::Aleo<Network = N>>(
         &self,
-        stack: &(impl StackEvaluate<N> + StackMatches<N> + StackProgram<N>),
+        stack: &(impl StackMatches<N> + StackProgram<N>),
         registers: &mut Registers<N, A>,
+        response: Response<N>,
     ) -> Result<()>;
 
     /// Executes the instruction.
-    fn execute<A: circuit::Aleo<Network = N>, R: CryptoRng + Rng>(
+    ///
+    /// If the instruction calls a function, the circuit of the caller is ejected, and the pending call is
+    /// returned with the call stack of the callee. The caller is expected to execute the function,
+    /// and to resume with `CallTrait::resume_execute`.
+    #[allow(clippy::type_complexity)]
+    fn execute<
+        'a,
+        A: circuit::Aleo<Network = N>,
+        R: CryptoRng + Rng,
+        S: StackEvaluate<N> + StackExecute<N> + StackMatches<N> + StackProgram<N>,
+    >(
         &self,
-        stack: &(impl StackEvaluate<N> + StackExecute<N> + StackMatches<N> + StackProgram<N>),
+        stack: &'a S,
         registers: &mut (
                  impl RegistersCall<N>
                  + RegistersSigner<N>
@@ -53,17 +100,29 @@ pub trait CallTrait<N: Network> {
                  + RegistersStoreCircuit<N, A>
              ),
         rng: &mut R,
+    ) -> Result<Option<(PendingCall<'a, N, A, S>, CallStack<N>)>>;
+
+    /// Checks the response of an executed function call, and assigns its outputs to the destination registers.
+    fn resume_execute<A: circuit::Aleo<Network = N>, S: StackMatches<N> + StackProgram<N>>(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut impl RegistersStoreCircuit<N, A>,
+        pending_call: PendingCall<'_, N, A, S>,
+        response: Response<N>,
     ) -> Result<()>;
 }
 
 impl<N: Network> CallTrait<N> for Call<N> {
     /// Evaluates the instruction.
+    ///
+    /// If the instruction calls a function, the function is not evaluated, and the stack of the callee is returned.
+    /// The caller is expected to evaluate the function, and to resume with `CallTrait::resume_evaluate`.
     #[inline]
-    fn evaluate<A: circuit::Aleo<Network = N>>(
+    fn evaluate<'a, A: circuit::Aleo<Network = N>, S: StackEvaluate<N> + StackMatches<N> + StackProgram<N>>(
         &self,
-        stack: &(impl StackEvaluate<N> + StackMatches<N> + StackProgram<N>),
+        stack: &'a S,
         registers: &mut Registers<N, A>,
-    ) -> Result<()> {
+    ) -> Result<Option<&'a S>> {
         let timer = timer!("Call::evaluate");
 
         // Load the operands values.
@@ -104,18 +163,14 @@ impl<N: Network> CallTrait<N> for Call<N> {
                 registers.tvk()?,
             )?
         }
-        // If the operator is a function, retrieve the function and compute the output.
-        else if let Ok(function) = substack.program().get_function(resource) {
+        // If the operator is a function, return the substack, to evaluate the function.
+        else if let Ok(function) = substack.program().get_function_ref(resource) {
             // Ensure the number of inputs matches the number of input statements.
             if function.inputs().len() != inputs.len() {
                 bail!("Expected {} inputs, found {}", function.inputs().len(), inputs.len())
             }
-            // Set the (console) caller.
-            let console_caller = Some(*stack.program_id());
-            // Evaluate the function.
-            let response = substack.evaluate_function::<A>(registers.call_stack(), console_caller)?;
-            // Load the outputs.
-            response.outputs().to_vec()
+            finish!(timer);
+            return Ok(Some(substack));
         }
         // Else, throw an error.
         else {
@@ -130,14 +185,39 @@ impl<N: Network> CallTrait<N> for Call<N> {
         }
         finish!(timer);
 
+        Ok(None)
+    }
+
+    /// Assigns the outputs of an evaluated function call to the destination registers.
+    #[inline]
+    fn resume_evaluate<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut Registers<N, A>,
+        response: Response<N>,
+    ) -> Result<()> {
+        // Assign the outputs to the destination registers.
+        for (output, register) in response.outputs().iter().zip_eq(&self.destinations()) {
+            // Assign the output to the register.
+            registers.store(stack, register, output.clone())?;
+        }
         Ok(())
     }
 
     /// Executes the instruction.
+    ///
+    /// If the instruction calls a function, the circuit of the caller is ejected, and the pending call is
+    /// returned with the call stack of the callee. The caller is expected to execute the function,
+    /// and to resume with `CallTrait::resume_execute`.
     #[inline]
-    fn execute<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+    fn execute<
+        'a,
+        A: circuit::Aleo<Network = N>,
+        R: CryptoRng + Rng,
+        S: StackEvaluate<N> + StackExecute<N> + StackMatches<N> + StackProgram<N>,
+    >(
         &self,
-        stack: &(impl StackEvaluate<N> + StackExecute<N> + StackMatches<N> + StackProgram<N>),
+        stack: &'a S,
         registers: &mut (
                  impl RegistersCall<N>
                  + RegistersSigner<N>
@@ -146,7 +226,7 @@ impl<N: Network> CallTrait<N> for Call<N> {
                  + RegistersStoreCircuit<N, A>
              ),
         rng: &mut R,
-    ) -> Result<()> {
+    ) -> Result<Option<(PendingCall<'a, N, A, S>, CallStack<N>)>> {
         let timer = timer!("Call::execute");
 
         // Load the operands values.
@@ -187,21 +267,31 @@ impl<N: Network> CallTrait<N> for Call<N> {
         let root_tvk = registers.root_tvk().ok();
 
         // If the operator is a closure, retrieve the closure and compute the output.
-        let outputs = if let Ok(closure) = substack.program().get_closure(resource) {
+        if let Ok(closure) = substack.program().get_closure(resource) {
             lap!(timer, "Execute the closure");
             // Execute the closure, and load the outputs.
-            substack.execute_closure(
+            let outputs = substack.execute_closure(
                 &closure,
                 &inputs,
                 registers.call_stack(),
                 registers.signer_circuit()?,
                 registers.caller_circuit()?,
                 registers.tvk_circuit()?,
-            )?
+            )?;
+
+            // Assign the outputs to the destination registers.
+            for (output, register) in outputs.into_iter().zip_eq(&self.destinations()) {
+                // Assign the output to the register.
+                registers.store_circuit(stack, register, output)?;
+            }
+            lap!(timer, "Assigned the outputs to registers");
+
+            finish!(timer);
+            Ok(None)
         }
-        // If the operator is a function, retrieve the function and compute the output.
+        // If the operator is a function, retrieve the function and prepare the call.
         else if let Ok(function) = substack.program().get_function(resource) {
-            lap!(timer, "Execute the function");
+            lap!(timer, "Prepare the function call");
             // Retrieve the number of inputs.
             let num_inputs = function.inputs().len();
             // Ensure the number of inputs matches the number of input statements.
@@ -218,236 +308,253 @@ impl<N: Network> CallTrait<N> for Call<N> {
             use circuit::Eject;
             // Eject the existing circuit.
             let r1cs = A::eject_r1cs_and_reset();
-            let (request, response) = {
-                // Eject the circuit inputs.
-                let inputs = inputs.eject_value();
-
-                // Set the (console) caller.
-                let console_caller = Some(*stack.program_id());
-
-                match registers.call_stack() {
-                    // If the circuit is in authorize or synthesize mode, then add any external calls to the stack.
-                    CallStack::Authorize(_, private_key, authorization)
-                    | CallStack::Synthesize(_, private_key, authorization) => {
-                        // Compute the request.
-                        let request = Request::sign(
-                            &private_key,
-                            *substack.program_id(),
-                            *function.name(),
-                            inputs.iter(),
-                            &function.input_types(),
-                            root_tvk,
-                            is_root,
-                            rng,
-                        )?;
-
-                        // Retrieve the call stack.
-                        let mut call_stack = registers.call_stack();
-                        // Push the request onto the call stack.
-                        call_stack.push(request.clone())?;
-
-                        // Add the request to the authorization.
-                        authorization.push(request.clone());
-
-                        // Execute the request.
-                        let response = substack.execute_function::<A, R>(call_stack, console_caller, root_tvk, rng)?;
-
-                        // Return the request and response.
-                        (request, response)
-                    }
-                    CallStack::PackageRun(_, private_key, ..) => {
-                        // Compute the request.
-                        let request = Request::sign(
-                            &private_key,
-                            *substack.program_id(),
-                            *function.name(),
-                            inputs.iter(),
-                            &function.input_types(),
-                            root_tvk,
-                            is_root,
-                            rng,
-                        )?;
-
-                        // Retrieve the call stack.
-                        let mut call_stack = registers.call_stack();
-                        // Push the request onto the call stack.
-                        call_stack.push(request.clone())?;
-
-                        // Evaluate the request.
-                        let response = substack.execute_function::<A, _>(call_stack, console_caller, root_tvk, rng)?;
-
-                        // Return the request and response.
-                        (request, response)
-                    }
-                    CallStack::CheckDeployment(_, private_key, ..) => {
-                        // Compute the request.
-                        let request = Request::sign(
-                            &private_key,
-                            *substack.program_id(),
-                            *function.name(),
-                            inputs.iter(),
-                            &function.input_types(),
-                            root_tvk,
-                            is_root,
-                            rng,
-                        )?;
-
-                        // Compute the address.
-                        let address = Address::try_from(&private_key)?;
-                        // Sample dummy outputs
-                        let outputs = function
-                            .outputs()
-                            .iter()
-                            .map(|output| match output.value_type() {
-                                ValueType::Record(record_name) => {
-                                    // Get the register index containing the record.
-                                    let index = match output.operand() {
-                                        Operand::Register(Register::Locator(index)) => Field::from_u64(*index),
-                                        _ => bail!("Expected a `Register::Locator` operand for a record output."),
-                                    };
-                                    // Compute the encryption randomizer as `HashToScalar(tvk || index)`.
-                                    let randomizer = N::hash_to_scalar_psd2(&[*request.tvk(), index])?;
-                                    // Construct the record nonce.
-                                    let record_nonce = N::g_scalar_multiply(&randomizer);
-                                    Ok(Value::Record(substack.sample_record(
-                                        &address,
-                                        record_name,
-                                        record_nonce,
-                                        rng,
-                                    )?))
-                                }
-                                _ => substack.sample_value(&address, output.value_type(), rng),
-                            })
-                            .collect::<Result<Vec<_>>>()?;
-                        // Map the output operands to registers.
-                        let output_registers = function
-                            .outputs()
-                            .iter()
-                            .map(|output| match output.operand() {
-                                Operand::Register(register) => Some(register.clone()),
-                                _ => None,
-                            })
-                            .collect::<Vec<_>>();
-
-                        // Compute the response.
-                        let response = crate::Response::new(
-                            request.network_id(),
-                            substack.program().id(),
-                            function.name(),
-                            request.inputs().len(),
-                            request.tvk(),
-                            request.tcm(),
-                            outputs,
-                            &function.output_types(),
-                            &output_registers,
-                        )?;
-
-                        // Return the request and response.
-                        (request, response)
-                    }
-                    // If the circuit is in evaluate mode, then throw an error.
-                    CallStack::Evaluate(..) => {
-                        bail!("Cannot 'execute' a function in 'evaluate' mode.")
-                    }
-                    // If the circuit is in execute mode, then evaluate and execute the instructions.
-                    CallStack::Execute(authorization, ..) => {
-                        // Retrieve the next request (without popping it).
-                        let request = authorization.peek_next()?;
-                        // Ensure the inputs match the original inputs.
-                        request.inputs().iter().zip_eq(&inputs).try_for_each(|(request_input, input)| {
-                            ensure!(request_input == input, "Inputs do not match in a 'call' instruction.");
-                            Ok(())
-                        })?;
-
-                        // Evaluate the function, and load the outputs.
-                        let console_response =
-                            substack.evaluate_function::<A>(registers.call_stack().replicate(), console_caller)?;
-                        // Execute the request.
-                        let response =
-                            substack.execute_function::<A, R>(registers.call_stack(), console_caller, root_tvk, rng)?;
-                        // Ensure the values are equal.
-                        if console_response.outputs() != response.outputs() {
-                            #[cfg(debug_assertions)]
-                            eprintln!("\n{:#?} != {:#?}\n", console_response.outputs(), response.outputs());
-                            bail!("Function '{}' outputs do not match in a 'call' instruction.", function.name())
-                        }
-                        // Return the request and response.
-                        (request, response)
-                    }
+            // Eject the circuit inputs.
+            let console_inputs = inputs.eject_value();
+
+            let (request, call_stack, expected_outputs) = match registers.call_stack() {
+                // If the circuit is in authorize or synthesize mode, then add any external calls to the stack.
+                CallStack::Authorize(_, private_key, authorization)
+                | CallStack::Synthesize(_, private_key, authorization) => {
+                    // Compute the request.
+                    let request = Request::sign_with_consumed_records(
+                        &private_key,
+                        *substack.program_id(),
+                        *function.name(),
+                        console_inputs.iter(),
+                        &function.input_types(),
+                        &substack.get_consumed_external_records(function.name())?,
+                        &substack.get_time_locked_records(function.name())?,
+                        root_tvk,
+                        is_root,
+                        rng,
+                    )?;
+
+                    // Retrieve the call stack.
+                    let mut call_stack = registers.call_stack();
+                    // Push the request onto the call stack.
+                    call_stack.push(request.clone())?;
+
+                    // Add the request to the authorization.
+                    authorization.push(request.clone());
+
+                    // Return the request and the call stack of the callee.
+                    (request, call_stack, None)
+                }
+                CallStack::PackageRun(_, private_key, ..) => {
+                    // Compute the request.
+                    let request = Request::sign_with_consumed_records(
+                        &private_key,
+                        *substack.program_id(),
+                        *function.name(),
+                        console_inputs.iter(),
+                        &function.input_types(),
+                        &substack.get_consumed_external_records(function.name())?,
+                        &substack.get_time_locked_records(function.name())?,
+                        root_tvk,
+                        is_root,
+                        rng,
+                    )?;
+
+                    // Retrieve the call stack.
+                    let mut call_stack = registers.call_stack();
+                    // Push the request onto the call stack.
+                    call_stack.push(request.clone())?;
+
+                    // Return the request and the call stack of the callee.
+                    (request, call_stack, None)
+                }
+                CallStack::CheckDeployment(_, private_key, ..) => {
+                    // Compute the request.
+                    let request = Request::sign_with_consumed_records(
+                        &private_key,
+                        *substack.program_id(),
+                        *function.name(),
+                        console_inputs.iter(),
+                        &function.input_types(),
+                        &substack.get_consumed_external_records(function.name())?,
+                        &substack.get_time_locked_records(function.name())?,
+                        root_tvk,
+                        is_root,
+                        rng,
+                    )?;
+
+                    // Compute the address.
+                    let address = Address::try_from(&private_key)?;
+                    // Sample dummy outputs
+                    let outputs = function
+                        .outputs()
+                        .iter()
+                        .map(|output| match output.value_type() {
+                            ValueType::Record(record_name) => {
+                                // Get the register index containing the record.
+                                let index = match output.operand() {
+                                    Operand::Register(Register::Locator(index)) => Field::from_u64(*index),
+                                    _ => bail!("Expected a `Register::Locator` operand for a record output."),
+                                };
+                                // Compute the encryption randomizer as `HashToScalar(tvk || index)`.
+                                let randomizer = N::hash_to_scalar_psd2(&[*request.tvk(), index])?;
+                                // Construct the record nonce.
+                                let record_nonce = N::g_scalar_multiply(&randomizer);
+                                Ok(Value::Record(substack.sample_record(&address, record_name, record_nonce, rng)?))
+                            }
+                            _ => substack.sample_value(&address, output.value_type(), rng),
+                        })
+                        .collect::<Result<Vec<_>>>()?;
+                    // Map the output operands to registers.
+                    let output_registers = function
+                        .outputs()
+                        .iter()
+                        .map(|output| match output.operand() {
+                            Operand::Register(register) => Some(register.clone()),
+                            _ => None,
+                        })
+                        .collect::<Vec<_>>();
+
+                    // Compute the response.
+                    let response = crate::Response::new(
+                        request.network_id(),
+                        substack.program().id(),
+                        function.name(),
+                        request.inputs().len(),
+                        request.tvk(),
+                        request.tcm(),
+                        outputs,
+                        &function.output_types(),
+                        &output_registers,
+                    )?;
+                    lap!(timer, "Sampled the response");
+
+                    // Resume the call with the sampled response, as the callee is not executed.
+                    let pending_call =
+                        PendingCall { substack, function, request, inputs, num_public, r1cs, expected_outputs: None };
+                    self.resume_execute(stack, registers, pending_call, response)?;
+
+                    finish!(timer);
+                    return Ok(None);
+                }
+                // If the circuit is in evaluate mode, then throw an error.
+                CallStack::Evaluate(..) => {
+                    bail!("Cannot 'execute' a function in 'evaluate' mode.")
+                }
+                // If the circuit is in execute mode, then evaluate and execute the instructions.
+                CallStack::Execute(authorization, ..) => {
+                    // Retrieve the next request (without popping it).
+                    let request = authorization.peek_next()?;
+                    // Ensure the inputs match the original inputs.
+                    request.inputs().iter().zip_eq(&console_inputs).try_for_each(|(request_input, input)| {
+                        ensure!(request_input == input, "Inputs do not match in a 'call' instruction.");
+                        Ok(())
+                    })?;
+
+                    // Set the (console) caller.
+                    let console_caller = Some(*stack.program_id());
+                    // Evaluate the function, and load the outputs.
+                    let console_response =
+                        substack.evaluate_function::<A>(registers.call_stack().replicate(), console_caller)?;
+
+                    // Return the request, the call stack of the callee, and the expected outputs.
+                    (request, registers.call_stack(), Some(console_response.outputs().to_vec()))
                 }
             };
-            lap!(timer, "Computed the request and response");
-
-            // Inject the existing circuit.
-            A::inject_r1cs(r1cs);
-
-            use circuit::Inject;
-
-            // Inject the network ID as `Mode::Constant`.
-            let network_id = circuit::U16::constant(*request.network_id());
-            // Inject the program ID as `Mode::Constant`.
-            let program_id = circuit::ProgramID::constant(*substack.program_id());
-            // Inject the function name as `Mode::Constant`.
-            let function_name = circuit::Identifier::constant(*function.name());
-
-            // Ensure the number of public variables remains the same.
-            ensure!(A::num_public() == num_public, "Forbidden: 'call' injected excess public variables");
-
-            // Inject the `signer` (from the request) as `Mode::Private`.
-            let signer = circuit::Address::new(circuit::Mode::Private, *request.signer());
-            // Inject the `sk_tag` (from the request) as `Mode::Private`.
-            let sk_tag = circuit::Field::new(circuit::Mode::Private, *request.sk_tag());
-            // Inject the `tvk` (from the request) as `Mode::Private`.
-            let tvk = circuit::Field::new(circuit::Mode::Private, *request.tvk());
-            // Inject the `tcm` (from the request) as `Mode::Public`.
-            let tcm = circuit::Field::new(circuit::Mode::Public, *request.tcm());
-            // Compute the transition commitment as `Hash(tvk)`.
-            let candidate_tcm = A::hash_psd2(&[tvk.clone()]);
-            // Ensure the transition commitment matches the computed transition commitment.
-            A::assert_eq(&tcm, &candidate_tcm);
-            // Inject the input IDs (from the request) as `Mode::Public`.
-            let input_ids = request
-                .input_ids()
-                .iter()
-                .map(|input_id| circuit::InputID::new(circuit::Mode::Public, *input_id))
-                .collect::<Vec<_>>();
-
-            // Ensure the candidate input IDs match their computed inputs.
-            let (check_input_ids, _) = circuit::Request::check_input_ids::<false>(
-                &network_id,
-                &program_id,
-                &function_name,
-                &input_ids,
-                &inputs,
-                &function.input_types(),
-                &signer,
-                &sk_tag,
-                &tvk,
-                &tcm,
-                None,
-            );
-            A::assert(check_input_ids);
-            lap!(timer, "Checked the input ids");
-
-            // Inject the outputs as `Mode::Private` (with the 'tcm' and output IDs as `Mode::Public`).
-            let outputs = circuit::Response::process_outputs_from_callback(
-                &network_id,
-                &program_id,
-                &function_name,
-                num_inputs,
-                &tvk,
-                &tcm,
-                response.outputs().to_vec(),
-                &function.output_types(),
-            );
-            lap!(timer, "Checked the outputs");
-            // Return the circuit outputs.
-            outputs
+            lap!(timer, "Computed the request");
+
+            finish!(timer);
+            Ok(Some((
+                PendingCall { substack, function, request, inputs, num_public, r1cs, expected_outputs },
+                call_stack,
+            )))
         }
         // Else, throw an error.
         else {
             bail!("Call operator '{}' is invalid or unsupported.", self.operator())
-        };
+        }
+    }
+
+    /// Checks the response of an executed function call, and assigns its outputs to the destination registers.
+    #[inline]
+    fn resume_execute<A: circuit::Aleo<Network = N>, S: StackMatches<N> + StackProgram<N>>(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut impl RegistersStoreCircuit<N, A>,
+        pending_call: PendingCall<'_, N, A, S>,
+        response: Response<N>,
+    ) -> Result<()> {
+        let timer = timer!("Call::resume_execute");
+
+        let PendingCall { substack, function, request, inputs, num_public, r1cs, expected_outputs } = pending_call;
+
+        // If the call is in execute mode, ensure the outputs match the evaluated outputs.
+        if let Some(expected_outputs) = expected_outputs {
+            if expected_outputs.as_slice() != response.outputs() {
+                #[cfg(debug_assertions)]
+                eprintln!("\n{:#?} != {:#?}\n", expected_outputs, response.outputs());
+                bail!("Function '{}' outputs do not match in a 'call' instruction.", function.name())
+            }
+        }
+
+        // Inject the existing circuit.
+        A::inject_r1cs(r1cs);
+
+        use circuit::Inject;
+
+        // Inject the network ID as `Mode::Constant`.
+        let network_id = circuit::U16::constant(*request.network_id());
+        // Inject the program ID as `Mode::Constant`.
+        let program_id = circuit::ProgramID::constant(*substack.program_id());
+        // Inject the function name as `Mode::Constant`.
+        let function_name = circuit::Identifier::constant(*function.name());
+
+        // Ensure the number of public variables remains the same.
+        ensure!(A::num_public() == num_public, "Forbidden: 'call' injected excess public variables");
+
+        // Inject the `signer` (from the request) as `Mode::Private`.
+        let signer = circuit::Address::new(circuit::Mode::Private, *request.signer());
+        // Inject the `sk_tag` (from the request) as `Mode::Private`.
+        let sk_tag = circuit::Field::new(circuit::Mode::Private, *request.sk_tag());
+        // Inject the `tvk` (from the request) as `Mode::Private`.
+        let tvk = circuit::Field::new(circuit::Mode::Private, *request.tvk());
+        // Inject the `tcm` (from the request) as `Mode::Public`.
+        let tcm = circuit::Field::new(circuit::Mode::Public, *request.tcm());
+        // Compute the transition commitment as `Hash(tvk)`.
+        let candidate_tcm = A::hash_psd2(&[tvk.clone()]);
+        // Ensure the transition commitment matches the computed transition commitment.
+        A::assert_eq(&tcm, &candidate_tcm);
+        // Inject the input IDs (from the request) as `Mode::Public`.
+        let input_ids = request
+            .input_ids()
+            .iter()
+            .map(|input_id| circuit::InputID::new(circuit::Mode::Public, *input_id))
+            .collect::<Vec<_>>();
+
+        // Ensure the candidate input IDs match their computed inputs.
+        let (check_input_ids, _) = circuit::Request::check_input_ids::<false>(
+            &network_id,
+            &program_id,
+            &function_name,
+            &input_ids,
+            &inputs,
+            &function.input_types(),
+            &signer,
+            &sk_tag,
+            &tvk,
+            &tcm,
+            None,
+        );
+        A::assert(check_input_ids);
+        lap!(timer, "Checked the input ids");
+
+        // Inject the outputs as `Mode::Private` (with the 'tcm' and output IDs as `Mode::Public`).
+        let outputs = circuit::Response::process_outputs_from_callback(
+            &network_id,
+            &program_id,
+            &function_name,
+            inputs.len(),
+            &tvk,
+            &tcm,
+            response.outputs().to_vec(),
+            &function.output_types(),
+        );
+        lap!(timer, "Checked the outputs");
 
         // Assign the outputs to the destination registers.
         for (output, register) in outputs.into_iter().zip_eq(&self.destinations()) {
@@ -18,6 +18,7 @@ use rand::{rngs::StdRng, SeedableRng};
 
 impl<N: Network> Stack<N> {
     /// Deploys the given program ID, if it does not exist.
+    #[cfg(feature = "prover")]
     #[inline]
     pub fn deploy<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(&self, rng: &mut R) -> Result<Deployment<N>> {
         let timer = timer!("Stack::deploy");
@@ -40,7 +41,10 @@ impl<N: Network> Stack<N> {
             lap!(timer, "Retrieve the keys for {function_name}");
 
             // Certify the circuit.
-            let certificate = Certificate::certify(&function_name.to_string(), &proving_key, &verifying_key)?;
+            let locator = Locator::new(*self.program_id(), *function_name);
+            let certificate = profile_phase(self.profiler().as_ref(), &locator, ProfilePhase::Prove, || {
+                Certificate::certify(&function_name.to_string(), &proving_key, &verifying_key)
+            })?;
             lap!(timer, "Certify the circuit");
 
             // Add the verifying key and certificate to the bundle.
@@ -67,7 +71,7 @@ impl<N: Network> Stack<N> {
         // Ensure the deployment is ordered.
         deployment.check_is_ordered()?;
         // Ensure the program in the stack and deployment matches.
-        ensure!(&self.program == deployment.program(), "The stack program does not match the deployment program");
+        ensure!(*self.program == *deployment.program(), "The stack program does not match the deployment program");
 
         // Check Verifying Keys //
 
@@ -119,12 +123,14 @@ impl<N: Network> Stack<N> {
             let is_root = true;
 
             // Compute the request, with a burner private key.
-            let request = Request::sign(
+            let request = Request::sign_with_consumed_records(
                 &burner_private_key,
                 *program_id,
                 *function.name(),
                 inputs.into_iter(),
                 &input_types,
+                &self.get_consumed_external_records(function.name())?,
+                &self.get_time_locked_records(function.name())?,
                 root_tvk,
                 is_root,
                 rng,
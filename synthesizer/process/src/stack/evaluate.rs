@@ -36,6 +36,12 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
             bail!("Expected {} inputs, found {}", closure.inputs().len(), inputs.len())
         }
 
+        // Retrieve the register traces, if they are recorded.
+        let traces = match &call_stack {
+            CallStack::Evaluate(_, traces) => traces.clone(),
+            _ => None,
+        };
+
         // Initialize the registers.
         let mut registers = Registers::<N, A>::new(call_stack, self.get_register_types(closure.name())?.clone());
         // Set the transition signer.
@@ -62,6 +68,12 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
         }
         lap!(timer, "Evaluate the instructions");
 
+        // Record the register trace, if the traces are recorded.
+        if let Some(traces) = traces {
+            let locator = Locator::new(*self.program_id(), *closure.name());
+            traces.write().push(RegisterTrace::new(locator, registers.console_registers().clone()));
+        }
+
         // Load the outputs.
         let outputs = closure
             .outputs()
@@ -82,6 +94,14 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
                     Operand::Caller => Ok(Value::Plaintext(Plaintext::from(Literal::Address(registers.caller()?)))),
                     // If the operand is the block height, throw an error.
                     Operand::BlockHeight => bail!("Cannot retrieve the block height from a closure scope."),
+                    // If the operand is the block timestamp, throw an error.
+                    Operand::BlockTimestamp => bail!("Cannot retrieve the block timestamp from a closure scope."),
+                    // If the operand is the block round, throw an error.
+                    Operand::BlockRound => bail!("Cannot retrieve the block round from a closure scope."),
+                    // If the operand is the block randomness, throw an error.
+                    Operand::BlockRandomness => bail!("Cannot retrieve the block randomness from a closure scope."),
+                    // If the operand is the network ID, throw an error.
+                    Operand::NetworkID => bail!("Cannot retrieve the network ID from a closure scope."),
                 }
             })
             .collect();
@@ -93,6 +113,9 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
 
     /// Evaluates a program function on the given inputs.
     ///
+    /// The nested function calls are evaluated on a work stack, instead of the Rust call stack,
+    /// and are limited to the maximum call depth of the stack.
+    ///
     /// # Errors
     /// This method will halt if the given inputs are not the same length as the input statements.
     #[inline]
@@ -103,9 +126,84 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
     ) -> Result<Response<N>> {
         let timer = timer!("Stack::evaluate_function");
 
+        // Retrieve the maximum call depth.
+        let max_call_depth = self.max_call_depth();
+
+        // Initialize the work stack, with the state of the top-level function.
+        // Note: Each state below the top of the work stack awaits the response of the state above it.
+        let mut states = vec![self.initialize_evaluate_state::<A>(call_stack, caller)?];
+        lap!(timer, "Initialize the top-level function");
+
+        while let Some(mut state) = states.pop() {
+            match state.evaluate_instructions() {
+                // If the function calls a function, evaluate the callee.
+                Ok(Some(substack)) => {
+                    // Initialize the state of the callee, and ensure it is within the maximum call depth.
+                    let depth = states.len() + 1;
+                    let callee = substack
+                        .initialize_evaluate_state::<A>(state.registers.call_stack(), Some(*state.stack.program_id()))
+                        .and_then(|callee| {
+                            ensure_call_depth(&callee.locator(), depth, max_call_depth)?;
+                            Ok(callee)
+                        });
+                    // Suspend the caller.
+                    states.push(state);
+                    match callee {
+                        Ok(callee) => states.push(callee),
+                        Err(error) => return Err(unwind_evaluate(&states, error)),
+                    }
+                }
+                // If the function returns, resume its caller with the response.
+                Ok(None) => {
+                    let response = match state.into_response() {
+                        Ok(response) => response,
+                        Err(error) => return Err(unwind_evaluate(&states, error)),
+                    };
+                    match states.last_mut() {
+                        Some(caller) => {
+                            if let Err(error) = caller.resume(response) {
+                                return Err(unwind_evaluate(&states, error));
+                            }
+                        }
+                        None => {
+                            finish!(timer);
+                            return Ok(response);
+                        }
+                    }
+                }
+                Err(error) => return Err(unwind_evaluate(&states, error)),
+            }
+        }
+        bail!("The evaluation of a function in '{}' did not return a response", self.program.id())
+    }
+}
+
+/// The state of a function evaluation, on the work stack of `Stack::evaluate_function`.
+struct EvaluateState<'a, N: Network, A: circuit::Aleo<Network = N>> {
+    /// The stack of the function.
+    stack: &'a Stack<N>,
+    /// The function.
+    function: &'a Function<N>,
+    /// The request.
+    request: Request<N>,
+    /// The registers.
+    registers: Registers<N, A>,
+    /// The register traces, if they are recorded.
+    traces: Option<RegisterTraces<N>>,
+    /// The index of the next instruction, or of the `call` instruction awaiting its callee.
+    counter: usize,
+}
+
+impl<N: Network> Stack<N> {
+    /// Initializes the state of a function evaluation, for the next request in the given call stack.
+    fn initialize_evaluate_state<A: circuit::Aleo<Network = N>>(
+        &self,
+        call_stack: CallStack<N>,
+        caller: Option<ProgramID<N>>,
+    ) -> Result<EvaluateState<'_, N, A>> {
         // Retrieve the next request, based on the call stack mode.
         let (request, call_stack) = match &call_stack {
-            CallStack::Evaluate(authorization) => (authorization.next()?, call_stack),
+            CallStack::Evaluate(authorization, _) => (authorization.next()?, call_stack),
             // If the evaluation is performed in the `Execute` mode, create a new `Evaluate` mode.
             // This is done to ensure that evaluation during execution is performed consistently.
             CallStack::Execute(authorization, _) => {
@@ -113,12 +211,11 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
                 // This way, the authorization remains unmodified in this 'evaluate' scope.
                 let authorization = authorization.replicate();
                 let request = authorization.next()?;
-                let call_stack = CallStack::Evaluate(authorization);
+                let call_stack = CallStack::Evaluate(authorization, None);
                 (request, call_stack)
             }
             _ => bail!("Illegal operation: call stack must be `Evaluate` or `Execute` in `evaluate_function`."),
         };
-        lap!(timer, "Retrieve the next request");
 
         // Ensure the network ID matches.
         ensure!(
@@ -129,7 +226,7 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
         );
 
         // Retrieve the function, inputs, and transition view key.
-        let function = self.get_function(request.function_name())?;
+        let function = self.get_function_ref(request.function_name())?;
         let inputs = request.inputs();
         let signer = *request.signer();
         let (is_root, caller) = match caller {
@@ -150,7 +247,12 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
                 inputs.len()
             )
         }
-        lap!(timer, "Perform input checks");
+
+        // Retrieve the register traces, if they are recorded.
+        let traces = match &call_stack {
+            CallStack::Evaluate(_, traces) => traces.clone(),
+            _ => None,
+        };
 
         // Initialize the registers.
         let mut registers = Registers::<N, A>::new(call_stack, self.get_register_types(function.name())?.clone());
@@ -160,39 +262,86 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
         registers.set_caller(caller);
         // Set the transition view key.
         registers.set_tvk(tvk);
-        lap!(timer, "Initialize the registers");
 
         // Ensure the request is well-formed.
         ensure!(request.verify(&function.input_types(), is_root), "Request is invalid");
-        lap!(timer, "Verify the request");
+        // Ensure the request consumes the expected external record inputs.
+        self.check_consumed_external_records(&request)?;
+        // Ensure the request reveals the lock heights of the time-locked record inputs.
+        self.check_time_locked_records(&request)?;
 
         // Store the inputs.
         function.inputs().iter().map(|i| i.register()).zip_eq(inputs).try_for_each(|(register, input)| {
             // Assign the input value to the register.
             registers.store(self, register, input.clone())
         })?;
-        lap!(timer, "Store the inputs");
+
+        Ok(EvaluateState { stack: self, function, request, registers, traces, counter: 0 })
+    }
+}
+
+impl<'a, N: Network, A: circuit::Aleo<Network = N>> EvaluateState<'a, N, A> {
+    /// Returns the locator of the function.
+    fn locator(&self) -> Locator<N> {
+        Locator::new(*self.stack.program_id(), *self.function.name())
+    }
+
+    /// Evaluates the instructions, until the function returns or calls a function.
+    /// If the function calls a function, the stack of the callee is returned.
+    fn evaluate_instructions(&mut self) -> Result<Option<&'a Stack<N>>> {
+        let (stack, function) = (self.stack, self.function);
 
         // Evaluate the instructions.
         // Note: We handle the `call` instruction separately, as it requires special handling.
-        for instruction in function.instructions() {
+        while let Some(instruction) = function.instructions().get(self.counter) {
             // Evaluate the instruction.
             let result = match instruction {
                 // If the instruction is a `call` instruction, we need to handle it separately.
-                Instruction::Call(call) => CallTrait::evaluate(call, self, &mut registers),
+                Instruction::Call(call) => CallTrait::evaluate(call, stack, &mut self.registers),
                 // Otherwise, evaluate the instruction normally.
-                _ => instruction.evaluate(self, &mut registers),
+                _ => instruction.evaluate(stack, &mut self.registers).map(|()| None),
             };
-            // If the evaluation fails, bail and return the error.
-            if let Err(error) = result {
-                bail!("Failed to evaluate instruction ({instruction}): {error}");
+            match result {
+                // If the instruction calls a function, suspend the evaluation on the `call` instruction.
+                Ok(Some(substack)) => return Ok(Some(substack)),
+                Ok(None) => self.counter += 1,
+                // If the evaluation fails, bail and return the error.
+                Err(error) => return Err(instruction_error("evaluate", instruction, error)),
             }
         }
-        lap!(timer, "Evaluate the instructions");
+        Ok(None)
+    }
+
+    /// Resumes the evaluation with the response of the callee.
+    fn resume(&mut self, response: Response<N>) -> Result<()> {
+        let Some(Instruction::Call(call)) = self.function.instructions().get(self.counter) else {
+            bail!("Function '{}' is not awaiting a function call", self.locator())
+        };
+        CallTrait::resume_evaluate(call, self.stack, &mut self.registers, response)?;
+        self.counter += 1;
+        Ok(())
+    }
+
+    /// Returns the given error of a callee, as the error of the `call` instruction awaiting it.
+    fn call_error(&self, error: Error) -> Error {
+        match self.function.instructions().get(self.counter) {
+            Some(instruction) => instruction_error("evaluate", instruction, error),
+            None => error,
+        }
+    }
+
+    /// Returns the response of the evaluated function.
+    fn into_response(self) -> Result<Response<N>> {
+        let EvaluateState { stack, function, request, registers, traces, .. } = self;
+
+        // Record the register trace, if the traces are recorded.
+        if let Some(traces) = traces {
+            let locator = Locator::new(*stack.program_id(), *function.name());
+            traces.write().push(RegisterTrace::new(locator, registers.console_registers().clone()));
+        }
 
         // Retrieve the output operands.
         let output_operands = &function.outputs().iter().map(|output| output.operand()).collect::<Vec<_>>();
-        lap!(timer, "Retrieve the output operands");
 
         // Load the outputs.
         let outputs = output_operands
@@ -202,7 +351,7 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
                     // If the operand is a literal, use the literal directly.
                     Operand::Literal(literal) => Ok(Value::Plaintext(Plaintext::from(literal))),
                     // If the operand is a register, retrieve the stack value from the register.
-                    Operand::Register(register) => registers.load(self, &Operand::Register(register.clone())),
+                    Operand::Register(register) => registers.load(stack, &Operand::Register(register.clone())),
                     // If the operand is the program ID, convert the program ID into an address.
                     Operand::ProgramID(program_id) => {
                         Ok(Value::Plaintext(Plaintext::from(Literal::Address(program_id.to_address()?))))
@@ -213,10 +362,17 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
                     Operand::Caller => Ok(Value::Plaintext(Plaintext::from(Literal::Address(registers.caller()?)))),
                     // If the operand is the block height, throw an error.
                     Operand::BlockHeight => bail!("Cannot retrieve the block height from a function scope."),
+                    // If the operand is the block timestamp, throw an error.
+                    Operand::BlockTimestamp => bail!("Cannot retrieve the block timestamp from a function scope."),
+                    // If the operand is the block round, throw an error.
+                    Operand::BlockRound => bail!("Cannot retrieve the block round from a function scope."),
+                    // If the operand is the block randomness, throw an error.
+                    Operand::BlockRandomness => bail!("Cannot retrieve the block randomness from a function scope."),
+                    // If the operand is the network ID, throw an error.
+                    Operand::NetworkID => bail!("Cannot retrieve the network ID from a function scope."),
                 }
             })
             .collect::<Result<Vec<_>>>()?;
-        lap!(timer, "Load the outputs");
 
         // Map the output operands to registers.
         let output_registers = output_operands
@@ -226,12 +382,11 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
                 _ => None,
             })
             .collect::<Vec<_>>();
-        lap!(timer, "Loaded the output registers");
 
         // Compute the response.
-        let response = Response::new(
+        Response::new(
             request.network_id(),
-            self.program.id(),
+            stack.program.id(),
             function.name(),
             request.inputs().len(),
             request.tvk(),
@@ -239,9 +394,11 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
             outputs,
             &function.output_types(),
             &output_registers,
-        );
-        finish!(timer);
-
-        response
+        )
     }
 }
+
+/// Returns the given error of a callee, as the error of the `call` instructions of its callers.
+fn unwind_evaluate<N: Network, A: circuit::Aleo<Network = N>>(states: &[EvaluateState<N, A>], error: Error) -> Error {
+    states.iter().rev().fold(error, |error, state| state.call_error(error))
+}
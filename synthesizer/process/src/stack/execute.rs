@@ -115,6 +115,22 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                     Operand::BlockHeight => {
                         bail!("Illegal operation: cannot retrieve the block height in a closure scope")
                     }
+                    // If the operand is the block timestamp, throw an error.
+                    Operand::BlockTimestamp => {
+                        bail!("Illegal operation: cannot retrieve the block timestamp in a closure scope")
+                    }
+                    // If the operand is the block round, throw an error.
+                    Operand::BlockRound => {
+                        bail!("Illegal operation: cannot retrieve the block round in a closure scope")
+                    }
+                    // If the operand is the block randomness, throw an error.
+                    Operand::BlockRandomness => {
+                        bail!("Illegal operation: cannot retrieve the block randomness in a closure scope")
+                    }
+                    // If the operand is the network ID, throw an error.
+                    Operand::NetworkID => {
+                        bail!("Illegal operation: cannot retrieve the network ID in a closure scope")
+                    }
                 }
             })
             .collect();
@@ -126,6 +142,9 @@ impl<N: Network> StackExecute<N> for Stack<N> {
 
     /// Executes a program function on the given inputs.
     ///
+    /// The nested function calls are executed on a work stack, instead of the Rust call stack,
+    /// and are limited to the maximum call depth of the stack.
+    ///
     /// Note: To execute a transition, do **not** call this method. Instead, call `Process::execute`.
     ///
     /// # Errors
@@ -133,13 +152,103 @@ impl<N: Network> StackExecute<N> for Stack<N> {
     #[inline]
     fn execute_function<A: circuit::Aleo<Network = N>, R: CryptoRng + Rng>(
         &self,
-        mut call_stack: CallStack<N>,
+        call_stack: CallStack<N>,
         console_caller: Option<ProgramID<N>>,
         root_tvk: Option<Field<N>>,
         rng: &mut R,
     ) -> Result<Response<N>> {
         let timer = timer!("Stack::execute_function");
 
+        // Retrieve the maximum call depth.
+        let max_call_depth = self.max_call_depth();
+
+        // Initialize the work stack, with the state of the top-level function.
+        // Note: Each state below the top of the work stack awaits the response of the state above it.
+        let mut states = vec![self.initialize_execute_state::<A>(call_stack, console_caller, root_tvk)?];
+        lap!(timer, "Initialize the top-level function");
+
+        while let Some(mut state) = states.pop() {
+            match state.execute_instructions(rng) {
+                // If the function calls a function, execute the callee.
+                Ok(Some(call_stack)) => {
+                    // Initialize the state of the callee, and ensure it is within the maximum call depth.
+                    let depth = states.len() + 1;
+                    let callee = state.initialize_callee(call_stack).and_then(|callee| {
+                        ensure_call_depth(&callee.locator, depth, max_call_depth)?;
+                        Ok(callee)
+                    });
+                    // Suspend the caller.
+                    states.push(state);
+                    match callee {
+                        Ok(callee) => states.push(callee),
+                        Err(error) => return Err(unwind_execute(&states, error)),
+                    }
+                }
+                // If the function returns, resume its caller with the response.
+                Ok(None) => {
+                    let response = match state.into_response() {
+                        Ok(response) => response,
+                        Err(error) => return Err(unwind_execute(&states, error)),
+                    };
+                    match states.last_mut() {
+                        Some(caller) => {
+                            if let Err(error) = caller.resume(response) {
+                                return Err(unwind_execute(&states, error));
+                            }
+                        }
+                        None => {
+                            finish!(timer);
+                            return Ok(response);
+                        }
+                    }
+                }
+                Err(error) => return Err(unwind_execute(&states, error)),
+            }
+        }
+        bail!("The execution of a function in '{}' did not return a response", self.program.id())
+    }
+}
+
+/// The state of a function execution, on the work stack of `Stack::execute_function`.
+struct ExecuteState<'a, N: Network, A: circuit::Aleo<Network = N>> {
+    /// The stack of the function.
+    stack: &'a Stack<N>,
+    /// The function.
+    function: &'a Function<N>,
+    /// The locator of the function.
+    locator: Locator<N>,
+    /// The console request.
+    console_request: Request<N>,
+    /// The circuit request.
+    request: circuit::Request<A>,
+    /// The registers.
+    registers: Registers<N, A>,
+    /// The profiler, if the function is being synthesized or executed.
+    profiler: Option<Arc<dyn Profiler<N>>>,
+    /// The start time of the synthesis.
+    synthesis_start: std::time::Instant,
+    /// The number of constraints for verifying the request in the circuit.
+    num_request_constraints: u64,
+    /// The number of public variables in the circuit, after verifying the request.
+    num_public: u64,
+    /// Whether the function contains a function call.
+    contains_function_call: bool,
+    /// The index of the next instruction, or of the `call` instruction awaiting its callee.
+    counter: usize,
+    /// The pending call, with the number of constraints before the `call` instruction, if the function awaits its callee.
+    pending_call: Option<(PendingCall<'a, N, A, Stack<N>>, u64)>,
+}
+
+impl<N: Network> Stack<N> {
+    /// Initializes the state of a function execution, for the next request in the given call stack.
+    fn initialize_execute_state<A: circuit::Aleo<Network = N>>(
+        &self,
+        mut call_stack: CallStack<N>,
+        console_caller: Option<ProgramID<N>>,
+        root_tvk: Option<Field<N>>,
+    ) -> Result<ExecuteState<'_, N, A>> {
+        let timer = timer!("Stack::initialize_execute_state");
+
         // Ensure the circuit environment is clean.
         A::reset();
 
@@ -149,8 +258,18 @@ impl<N: Network> StackExecute<N> for Stack<N> {
             A::set_constraint_limit(*constraint_limit);
         }
 
+        // Retrieve the profiler, if the function is being synthesized or executed.
+        let profiler = match call_stack {
+            CallStack::Synthesize(..) | CallStack::Execute(..) => self.profiler(),
+            _ => None,
+        };
+        // Start the synthesis timer.
+        let synthesis_start = std::time::Instant::now();
+
         // Retrieve the next request.
         let console_request = call_stack.pop()?;
+        // Construct the locator of the function.
+        let locator = Locator::new(*self.program_id(), *console_request.function_name());
 
         // Ensure the network ID matches.
         ensure!(
@@ -176,7 +295,7 @@ impl<N: Network> StackExecute<N> for Stack<N> {
         };
 
         // Retrieve the function from the program.
-        let function = self.get_function(console_request.function_name())?;
+        let function = self.get_function_ref(console_request.function_name())?;
         // Retrieve the number of inputs.
         let num_inputs = function.inputs().len();
         // Ensure the number of inputs matches the number of input statements.
@@ -185,9 +304,7 @@ impl<N: Network> StackExecute<N> for Stack<N> {
         }
         // Retrieve the input types.
         let input_types = function.input_types();
-        // Retrieve the output types.
-        let output_types = function.output_types();
-        lap!(timer, "Retrieve the input and output types");
+        lap!(timer, "Retrieve the input types");
 
         // Ensure the inputs match their expected types.
         console_request.inputs().iter().zip_eq(&input_types).try_for_each(|(input, input_type)| {
@@ -198,10 +315,14 @@ impl<N: Network> StackExecute<N> for Stack<N> {
 
         // Ensure the request is well-formed.
         ensure!(console_request.verify(&input_types, console_is_root), "Request is invalid");
+        // Ensure the request consumes the expected external record inputs.
+        self.check_consumed_external_records(&console_request)?;
+        // Ensure the request reveals the lock heights of the time-locked record inputs.
+        self.check_time_locked_records(&console_request)?;
         lap!(timer, "Verify the console request");
 
         // Initialize the registers.
-        let mut registers = Registers::new(call_stack, self.get_register_types(function.name())?.clone());
+        let mut registers = Registers::<N, A>::new(call_stack, self.get_register_types(function.name())?.clone());
 
         // Set the root tvk, from a parent request or the current request.
         // inject the `root_tvk` as `Mode::Private`.
@@ -231,6 +352,21 @@ impl<N: Network> StackExecute<N> for Stack<N> {
 
         // Ensure the request has a valid signature, inputs, and transition view key.
         A::assert(request.verify(&input_types, &tpk, root_tvk, is_root));
+        // Ensure the revealed lock heights match the lock entries of the time-locked record inputs.
+        for (index, entry_name) in self.get_time_locked_records(function.name())? {
+            match (request.inputs().get(index), request.input_ids().get(index)) {
+                (
+                    Some(circuit::Value::Record(record)),
+                    Some(circuit::InputID::Record(_, _, _, _, Some(lock_height))),
+                ) => match record.data().get(&circuit::Identifier::constant(entry_name)) {
+                    Some(circuit::Entry::Public(circuit::Plaintext::Literal(circuit::Literal::U32(entry), _))) => {
+                        A::assert_eq(lock_height, circuit::traits::ToField::to_field(entry))
+                    }
+                    _ => bail!("Input at index {index} does not contain the lock entry '{entry_name}'"),
+                },
+                _ => bail!("Input at index {index} is not a time-locked record"),
+            }
+        }
         lap!(timer, "Verify the circuit request");
 
         // Set the transition signer.
@@ -271,47 +407,162 @@ impl<N: Network> StackExecute<N> for Stack<N> {
         })?;
         lap!(timer, "Store the inputs");
 
-        // Initialize a tracker to determine if there are any function calls.
-        let mut contains_function_call = false;
+        finish!(timer);
+
+        Ok(ExecuteState {
+            stack: self,
+            function,
+            locator,
+            console_request,
+            request,
+            registers,
+            profiler,
+            synthesis_start,
+            num_request_constraints,
+            num_public,
+            contains_function_call: false,
+            counter: 0,
+            pending_call: None,
+        })
+    }
+}
+
+impl<'a, N: Network, A: circuit::Aleo<Network = N>> ExecuteState<'a, N, A> {
+    /// Executes the instructions, until the function returns or calls a function.
+    /// If the function calls a function, the call stack of the callee is returned.
+    fn execute_instructions<R: CryptoRng + Rng>(&mut self, rng: &mut R) -> Result<Option<CallStack<N>>> {
+        let (stack, function) = (self.stack, self.function);
 
         // Execute the instructions.
-        for instruction in function.instructions() {
+        while let Some(instruction) = function.instructions().get(self.counter) {
+            // Retrieve the number of constraints before executing the instruction.
+            let num_constraints = A::num_constraints();
+
             // If the circuit is in execute mode, then evaluate the instructions.
-            if let CallStack::Execute(..) = registers.call_stack() {
+            if let CallStack::Execute(..) = self.registers.call_stack() {
                 // Evaluate the instruction.
                 let result = match instruction {
                     // If the instruction is a `call` instruction, we need to handle it separately.
-                    Instruction::Call(call) => CallTrait::evaluate(call, self, &mut registers),
+                    Instruction::Call(call) => match CallTrait::evaluate(call, stack, &mut self.registers) {
+                        // If the instruction calls a function, evaluate the callee, and assign its outputs.
+                        Ok(Some(substack)) => substack
+                            .evaluate_function::<A>(self.registers.call_stack(), Some(*stack.program_id()))
+                            .and_then(|response| {
+                                CallTrait::resume_evaluate(call, stack, &mut self.registers, response)
+                            }),
+                        result => result.map(|_| ()),
+                    },
                     // Otherwise, evaluate the instruction normally.
-                    _ => instruction.evaluate(self, &mut registers),
+                    _ => instruction.evaluate(stack, &mut self.registers),
                 };
                 // If the evaluation fails, bail and return the error.
                 if let Err(error) = result {
-                    bail!("Failed to evaluate instruction ({instruction}): {error}");
+                    return Err(instruction_error("evaluate", instruction, error));
                 }
             }
 
             // Execute the instruction.
             let result = match instruction {
                 // If the instruction is a `call` instruction, we need to handle it separately.
-                Instruction::Call(call) => CallTrait::execute(call, self, &mut registers, rng),
+                Instruction::Call(call) => CallTrait::execute(call, stack, &mut self.registers, rng),
                 // Otherwise, execute the instruction normally.
-                _ => instruction.execute(self, &mut registers),
+                _ => instruction.execute(stack, &mut self.registers).map(|()| None),
             };
-            // If the execution fails, bail and return the error.
-            if let Err(error) = result {
-                bail!("Failed to execute instruction ({instruction}): {error}");
+            match result {
+                // If the instruction calls a function, suspend the execution on the `call` instruction.
+                Ok(Some((pending_call, call_stack))) => {
+                    self.pending_call = Some((pending_call, num_constraints));
+                    return Ok(Some(call_stack));
+                }
+                Ok(None) => self.complete_instruction(instruction, num_constraints)?,
+                // If the execution fails, bail and return the error.
+                Err(error) => return Err(instruction_error("execute", instruction, error)),
             }
+        }
+        Ok(None)
+    }
 
-            // If the instruction was a function call, then set the tracker to `true`.
-            if let Instruction::Call(call) = instruction {
-                // Check if the call is a function call.
-                if call.is_function_call(self)? {
-                    contains_function_call = true;
-                }
+    /// Initializes the state of the callee of the pending call, for the given call stack.
+    fn initialize_callee(&self, call_stack: CallStack<N>) -> Result<ExecuteState<'a, N, A>> {
+        let Some((pending_call, _)) = &self.pending_call else {
+            bail!("Function '{}' is not awaiting a function call", self.locator)
+        };
+        // Set the (console) caller.
+        let console_caller = Some(*self.stack.program_id());
+        // If we are not handling the root request, retrieve the root request's tvk
+        let root_tvk = self.registers.root_tvk().ok();
+        // Initialize the state of the callee.
+        pending_call.substack().initialize_execute_state::<A>(call_stack, console_caller, root_tvk)
+    }
+
+    /// Resumes the execution with the response of the callee.
+    fn resume(&mut self, response: Response<N>) -> Result<()> {
+        let function = self.function;
+        let (Some((pending_call, num_constraints)), Some(instruction)) =
+            (self.pending_call.take(), function.instructions().get(self.counter))
+        else {
+            bail!("Function '{}' is not awaiting a function call", self.locator)
+        };
+        let Instruction::Call(call) = instruction else {
+            bail!("Function '{}' is not awaiting a function call", self.locator)
+        };
+        CallTrait::resume_execute(call, self.stack, &mut self.registers, pending_call, response)?;
+        self.complete_instruction(instruction, num_constraints)
+    }
+
+    /// Completes the current instruction, given the number of constraints before the instruction.
+    fn complete_instruction(&mut self, instruction: &Instruction<N>, num_constraints: u64) -> Result<()> {
+        // If the function is profiled, then report the number of constraints added by the instruction.
+        if let Some(profiler) = &self.profiler {
+            let num_constraints = A::num_constraints().saturating_sub(num_constraints);
+            profiler.on_instruction(&self.locator, self.counter, instruction, num_constraints);
+        }
+
+        // If the instruction was a function call, then set the tracker to `true`.
+        if let Instruction::Call(call) = instruction {
+            // Check if the call is a function call.
+            if call.is_function_call(self.stack)? {
+                self.contains_function_call = true;
             }
         }
-        lap!(timer, "Execute the instructions");
+
+        self.counter += 1;
+        Ok(())
+    }
+
+    /// Returns the given error of a callee, as the error of the `call` instruction awaiting it.
+    fn call_error(&self, error: Error) -> Error {
+        match self.function.instructions().get(self.counter) {
+            Some(instruction) => instruction_error("execute", instruction, error),
+            None => error,
+        }
+    }
+
+    /// Returns the response of the executed function.
+    fn into_response(self) -> Result<Response<N>> {
+        let timer = timer!("Stack::execute_function::into_response");
+
+        let ExecuteState {
+            stack,
+            function,
+            locator,
+            console_request,
+            request,
+            registers,
+            profiler,
+            synthesis_start,
+            num_request_constraints,
+            num_public,
+            contains_function_call,
+            ..
+        } = self;
+
+        use circuit::{Eject, Inject};
+
+        // Retrieve the number of inputs.
+        let num_inputs = function.inputs().len();
+        // Retrieve the output types.
+        let output_types = function.output_types();
 
         // Load the outputs.
         let output_operands = &function.outputs().iter().map(|output| output.operand()).collect::<Vec<_>>();
@@ -324,7 +575,7 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                         circuit::Literal::new(circuit::Mode::Constant, literal.clone()),
                     ))),
                     // If the operand is a register, retrieve the stack value from the register.
-                    Operand::Register(register) => registers.load_circuit(self, &Operand::Register(register.clone())),
+                    Operand::Register(register) => registers.load_circuit(stack, &Operand::Register(register.clone())),
                     // If the operand is the program ID, convert the program ID into an address.
                     Operand::ProgramID(program_id) => {
                         Ok(circuit::Value::Plaintext(circuit::Plaintext::from(circuit::Literal::Address(
@@ -343,6 +594,22 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                     Operand::BlockHeight => {
                         bail!("Illegal operation: cannot retrieve the block height in a function scope")
                     }
+                    // If the operand is the block timestamp, throw an error.
+                    Operand::BlockTimestamp => {
+                        bail!("Illegal operation: cannot retrieve the block timestamp in a function scope")
+                    }
+                    // If the operand is the block round, throw an error.
+                    Operand::BlockRound => {
+                        bail!("Illegal operation: cannot retrieve the block round in a function scope")
+                    }
+                    // If the operand is the block randomness, throw an error.
+                    Operand::BlockRandomness => {
+                        bail!("Illegal operation: cannot retrieve the block randomness in a function scope")
+                    }
+                    // If the operand is the network ID, throw an error.
+                    Operand::NetworkID => {
+                        bail!("Illegal operation: cannot retrieve the network ID in a function scope")
+                    }
                 }
             })
             .collect::<Result<Vec<_>>>()?;
@@ -358,7 +625,7 @@ impl<N: Network> StackExecute<N> for Stack<N> {
             .collect::<Vec<_>>();
 
         #[cfg(debug_assertions)]
-        Self::log_circuit::<A, _>(format!("Function '{}()'", function.name()));
+        Stack::<N>::log_circuit::<A, _>(format!("Function '{}()'", function.name()));
 
         // Retrieve the number of constraints for executing the function in the circuit.
         let num_function_constraints = A::num_constraints().saturating_sub(num_request_constraints);
@@ -384,14 +651,14 @@ impl<N: Network> StackExecute<N> for Stack<N> {
         lap!(timer, "Construct the response");
 
         #[cfg(debug_assertions)]
-        Self::log_circuit::<A, _>("Response");
+        Stack::<N>::log_circuit::<A, _>("Response");
 
         // Retrieve the number of constraints for verifying the response in the circuit.
         let num_response_constraints =
             A::num_constraints().saturating_sub(num_request_constraints).saturating_sub(num_function_constraints);
 
         #[cfg(debug_assertions)]
-        Self::log_circuit::<A, _>("Complete");
+        Stack::<N>::log_circuit::<A, _>("Complete");
 
         // Eject the response.
         let response = response.eject_value();
@@ -399,7 +666,7 @@ impl<N: Network> StackExecute<N> for Stack<N> {
         // Ensure the outputs matches the expected value types.
         response.outputs().iter().zip_eq(&output_types).try_for_each(|(output, output_type)| {
             // Ensure the output matches its expected type.
-            self.matches_value_type(output, output_type)
+            stack.matches_value_type(output, output_type)
         })?;
 
         // If the circuit is in `Execute` or `PackageRun` mode, then ensure the circuit is satisfied.
@@ -408,23 +675,32 @@ impl<N: Network> StackExecute<N> for Stack<N> {
             ensure!(
                 A::num_constraints() > 0 && A::is_satisfied(),
                 "'{}/{}' is not satisfied on the given inputs ({} constraints).",
-                self.program.id(),
+                stack.program.id(),
                 function.name(),
                 A::num_constraints()
             );
         }
 
+        // If the function is profiled, then report the time elapsed in synthesis.
+        if let Some(profiler) = &profiler {
+            profiler.on_phase(&locator, ProfilePhase::Synthesis, synthesis_start.elapsed());
+        }
+
         // Eject the circuit assignment and reset the circuit.
-        let assignment = A::eject_assignment_and_reset();
+        let assignment =
+            profile_phase(profiler.as_ref(), &locator, ProfilePhase::Witness, || A::eject_assignment_and_reset());
 
         // If the circuit is in `Synthesize` or `Execute` mode, synthesize the circuit key, if it does not exist.
+        #[cfg(feature = "prover")]
         if matches!(registers.call_stack(), CallStack::Synthesize(..))
             || matches!(registers.call_stack(), CallStack::Execute(..))
         {
             // If the proving key does not exist, then synthesize it.
-            if !self.contains_proving_key(function.name()) {
+            if !stack.contains_proving_key(function.name()) {
                 // Add the circuit key to the mapping.
-                self.synthesize_from_assignment(function.name(), &assignment)?;
+                profile_phase(profiler.as_ref(), &locator, ProfilePhase::Commit, || {
+                    stack.synthesize_from_assignment(function.name(), &assignment)
+                })?;
                 lap!(timer, "Synthesize the {} circuit key", function.name());
             }
         }
@@ -440,7 +716,7 @@ impl<N: Network> StackExecute<N> for Stack<N> {
         else if let CallStack::CheckDeployment(_, _, ref assignments, _) = registers.call_stack() {
             // Construct the call metrics.
             let metrics = CallMetrics {
-                program_id: *self.program_id(),
+                program_id: *stack.program_id(),
                 function_name: *function.name(),
                 num_instructions: function.instructions().len(),
                 num_request_constraints,
@@ -459,10 +735,10 @@ impl<N: Network> StackExecute<N> for Stack<N> {
             let transition = Transition::from(&console_request, &response, &output_types, &output_registers)?;
 
             // Retrieve the proving key.
-            let proving_key = self.get_proving_key(function.name())?;
+            let proving_key = stack.get_proving_key(function.name())?;
             // Construct the call metrics.
             let metrics = CallMetrics {
-                program_id: *self.program_id(),
+                program_id: *stack.program_id(),
                 function_name: *function.name(),
                 num_instructions: function.instructions().len(),
                 num_request_constraints,
@@ -482,7 +758,7 @@ impl<N: Network> StackExecute<N> for Stack<N> {
         else if let CallStack::PackageRun(_, _, ref assignments) = registers.call_stack() {
             // Construct the call metrics.
             let metrics = CallMetrics {
-                program_id: *self.program_id(),
+                program_id: *stack.program_id(),
                 function_name: *function.name(),
                 num_instructions: function.instructions().len(),
                 num_request_constraints,
@@ -501,6 +777,11 @@ impl<N: Network> StackExecute<N> for Stack<N> {
     }
 }
 
+/// Returns the given error of a callee, as the error of the `call` instructions of its callers.
+fn unwind_execute<N: Network, A: circuit::Aleo<Network = N>>(states: &[ExecuteState<N, A>], error: Error) -> Error {
+    states.iter().rev().fold(error, |error, state| state.call_error(error))
+}
+
 impl<N: Network> Stack<N> {
     /// Prints the current state of the circuit.
     #[cfg(debug_assertions)]
@@ -41,6 +41,26 @@ impl<N: Network> RegistersLoad<N> for FinalizeRegisters<N> {
             Operand::BlockHeight => {
                 return Ok(Value::Plaintext(Plaintext::from(Literal::U32(U32::new(self.state.block_height())))));
             }
+            // If the operand is the block timestamp, load the block timestamp.
+            Operand::BlockTimestamp => {
+                return Ok(Value::Plaintext(Plaintext::from(Literal::I64(I64::new(self.state.block_timestamp())))));
+            }
+            // If the operand is the block round, load the block round.
+            Operand::BlockRound => {
+                return Ok(Value::Plaintext(Plaintext::from(Literal::U64(U64::new(self.state.block_round())))));
+            }
+            // If the operand is the block randomness, load the block randomness.
+            Operand::BlockRandomness => {
+                // Ensure the block ratified an external randomness value.
+                let Some(randomness) = self.state.block_randomness() else {
+                    bail!("Cannot load 'block.randomness', as the block does not contain a randomness ratification")
+                };
+                return Ok(Value::Plaintext(Plaintext::from(Literal::Field(Field::from_bytes_le(randomness)?))));
+            }
+            // If the operand is the network ID, load the network ID.
+            Operand::NetworkID => {
+                return Ok(Value::Plaintext(Plaintext::from(Literal::U16(U16::new(N::ID)))));
+            }
         };
 
         // Retrieve the value.
@@ -19,7 +19,7 @@ use crate::FinalizeTypes;
 use console::{
     network::prelude::*,
     program::{Identifier, Literal, Plaintext, Register, Value},
-    types::U32,
+    types::{Field, I64, U16, U32, U64},
 };
 use synthesizer_program::{
     FinalizeGlobalState,
@@ -60,6 +60,22 @@ impl<N: Network> FinalizeRegisters<N> {
     ) -> Self {
         Self { state, transition_id, finalize_types, function_name, registers: IndexMap::new(), last_register: None }
     }
+
+    /// Returns the assigned registers.
+    #[inline]
+    pub const fn registers(&self) -> &IndexMap<u64, Value<N>> {
+        &self.registers
+    }
+
+    /// Clears the registers at or after the given locator, so that they may be assigned again.
+    /// Note: This is used to reassign the registers in the body of a loop on each iteration.
+    #[inline]
+    pub fn clear_from(&mut self, locator: u64) {
+        // Remove the registers at or after the given locator.
+        self.registers.retain(|register, _| *register < locator);
+        // Reset the last register locator.
+        self.last_register = self.registers.keys().last().copied();
+    }
 }
 
 impl<N: Network> FinalizeRegistersState<N> for FinalizeRegisters<N> {
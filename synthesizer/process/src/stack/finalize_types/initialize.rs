@@ -15,12 +15,18 @@
 use super::*;
 use crate::RegisterTypes;
 use synthesizer_program::{
+    ArrayGet,
+    ArraySet,
     Await,
     Branch,
+    CallDynamic,
     CastType,
+    CommandTrait,
     Contains,
+    Emit,
     Get,
     GetOrUse,
+    Iter,
     MappingLocator,
     RandChaCha,
     Remove,
@@ -79,6 +85,9 @@ impl<N: Network> FinalizeTypes<N> {
             finalize.name()
         );
 
+        // Step 3. Check the loops are well-formed.
+        Self::check_loops(finalize)?;
+
         Ok(finalize_types)
     }
 }
@@ -184,7 +193,62 @@ impl<N: Network> FinalizeTypes<N> {
             Command::BranchNeq(branch_neq) => self.check_branch(stack, finalize, branch_neq)?,
             // Note that the `Position`s are checked for uniqueness when constructing `Finalize`.
             Command::Position(_) => (),
+            Command::ArrayGet(array_get) => self.check_array_get(stack, array_get)?,
+            Command::ArraySet(array_set) => self.check_array_set(stack, array_set)?,
+            Command::Iter(iter) => self.check_iter(finalize, iter)?,
+            Command::CallDynamic(call_dynamic) => self.check_call_dynamic(stack, call_dynamic)?,
+            Command::Emit(emit) => self.check_emit(stack, emit)?,
+        }
+        Ok(())
+    }
+
+    /// Ensures the loops in the given finalize are well-formed.
+    /// The body of a loop must not contain an `iter`, `await`, or `call.dynamic` command,
+    /// and branches may not enter or exit it.
+    /// The number of write commands, counting each write in a loop body once per iteration, is bounded.
+    #[inline]
+    fn check_loops(finalize: &Finalize<N>) -> Result<()> {
+        // Collect the body of each loop, as the range of command indices `(start, end]`, and its number of iterations.
+        let mut bodies = Vec::new();
+        for (index, command) in finalize.commands().iter().enumerate() {
+            if let Command::Iter(iter) = command {
+                match finalize.positions().get(iter.position()) {
+                    Some(end) if *end > index => bodies.push((index, *end, iter.num_iterations())),
+                    _ => bail!("Command 'iter' expects a later position to loop until, found '{}'", iter.position()),
+                }
+            }
         }
+        // A helper to retrieve the loop body containing the given command index, if any.
+        let get_body = |index: usize| bodies.iter().find(|(start, end, _)| index > *start && index <= *end);
+
+        // Initialize a counter for the number of writes.
+        let mut num_writes = 0u64;
+        for (index, command) in finalize.commands().iter().enumerate() {
+            // Retrieve the loop body containing the command, if any.
+            let body = get_body(index);
+            match command {
+                Command::Iter(iter) => ensure!(body.is_none(), "Cannot nest '{iter}' in the body of another loop"),
+                Command::Await(await_) => ensure!(body.is_none(), "Cannot use '{await_}' in the body of a loop"),
+                Command::CallDynamic(call) => ensure!(body.is_none(), "Cannot use '{call}' in the body of a loop"),
+                Command::Set(_) | Command::Remove(_) | Command::Emit(_) => {
+                    num_writes += body.map_or(1, |(_, _, num_iterations)| *num_iterations as u64)
+                }
+                _ => {
+                    // Ensure a branch does not enter or exit a loop body.
+                    // Note: `check_branch` ensures that the position is defined.
+                    if let Some(target) = command.branch_to().and_then(|position| finalize.positions().get(position)) {
+                        ensure!(body == get_body(*target), "Cannot branch into or out of a loop in '{command}'");
+                    }
+                }
+            }
+        }
+        // Ensure the number of writes does not exceed the maximum.
+        ensure!(
+            num_writes <= N::MAX_WRITES as u64,
+            "Cannot perform more than {} writes in finalize '{}', found {num_writes}",
+            N::MAX_WRITES,
+            finalize.name()
+        );
         Ok(())
     }
 
@@ -247,6 +311,52 @@ impl<N: Network> FinalizeTypes<N> {
         Ok(())
     }
 
+    /// Ensures the given `iter` command is well-formed.
+    #[inline]
+    fn check_iter(&mut self, finalize: &Finalize<N>, iter: &Iter<N>) -> Result<()> {
+        // Check that the `Position` has been defined.
+        ensure!(
+            finalize.positions().get(iter.position()).is_some(),
+            "Command '{}' expects a defined position to loop until. Found undefined position '{}'",
+            Iter::<N>::opcode(),
+            iter.position()
+        );
+        // Get the destination register.
+        let destination = iter.destination().clone();
+        // Ensure the destination register is a locator (and does not reference an access).
+        ensure!(matches!(destination, Register::Locator(..)), "Destination '{destination}' must be a locator.");
+        // Insert the destination register, which holds the `u32` loop counter.
+        self.add_destination(destination, FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::U32)))
+    }
+
+    /// Ensures the given `call.dynamic` command is well-formed.
+    /// Note that the callee is resolved, and checked to be a finalize-only function, at runtime.
+    #[inline]
+    fn check_call_dynamic(
+        &mut self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        call_dynamic: &CallDynamic<N>,
+    ) -> Result<()> {
+        // Ensure the program operand is a string.
+        match self.get_type_from_operand(stack, call_dynamic.program())? {
+            FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::String)) => (),
+            finalize_type => bail!("The program operand of 'call.dynamic' must be a 'string', found '{finalize_type}'"),
+        }
+        // Ensure the number of operands is within bounds.
+        ensure!(
+            call_dynamic.operands().len() <= N::MAX_INPUTS,
+            "The number of operands of 'call.dynamic' must be <= {}",
+            N::MAX_INPUTS
+        );
+        // Ensure the operands are plaintexts.
+        for operand in call_dynamic.operands() {
+            if let FinalizeType::Future(..) = self.get_type_from_operand(stack, operand)? {
+                bail!("The operand '{operand}' of 'call.dynamic' cannot be a future")
+            }
+        }
+        Ok(())
+    }
+
     /// Ensures the given `contains` command is well-formed.
     #[inline]
     fn check_contains(
@@ -255,13 +365,40 @@ impl<N: Network> FinalizeTypes<N> {
         finalize_name: &Identifier<N>,
         contains: &Contains<N>,
     ) -> Result<()> {
-        // Ensure the declared mapping in `contains` is defined in the program.
-        if !stack.program().contains_mapping(contains.mapping_name()) {
-            bail!("Mapping '{}' in '{}/{finalize_name}' is not defined.", contains.mapping_name(), stack.program_id())
-        }
-        // Retrieve the mapping from the program.
-        // Note that the unwrap is safe, as we have already checked the mapping exists.
-        let mapping = stack.program().get_mapping(contains.mapping_name()).unwrap();
+        // Retrieve the mapping.
+        let mapping = match contains.mapping() {
+            MappingLocator::Locator(locator) => {
+                // Retrieve the program ID.
+                let program_id = locator.program_id();
+                // Retrieve the mapping_name.
+                let mapping_name = locator.resource();
+
+                // Ensure the locator does not reference the current program.
+                if stack.program_id() == program_id {
+                    bail!("Locator '{locator}' does not reference an external mapping.");
+                }
+                // Ensure the current program contains an import for this external program.
+                if !stack.program().imports().keys().contains(program_id) {
+                    bail!("External program '{program_id}' is not imported by '{}'.", stack.program_id());
+                }
+                // Retrieve the program.
+                let external = stack.get_external_program(program_id)?;
+                // Ensure the mapping exists in the program.
+                if !external.contains_mapping(mapping_name) {
+                    bail!("Mapping '{mapping_name}' in '{program_id}' is not defined.")
+                }
+                // Retrieve the mapping from the program.
+                external.get_mapping(mapping_name)?
+            }
+            MappingLocator::Resource(mapping_name) => {
+                // Ensure the declared mapping in `contains` is defined in the current program.
+                if !stack.program().contains_mapping(mapping_name) {
+                    bail!("Mapping '{mapping_name}' in '{}/{finalize_name}' is not defined.", stack.program_id())
+                }
+                // Retrieve the mapping from the program.
+                stack.program().get_mapping(mapping_name)?
+            }
+        };
         // Get the mapping key type.
         let mapping_key_type = mapping.key().plaintext_type();
         // Retrieve the register type of the key.
@@ -460,6 +597,69 @@ impl<N: Network> FinalizeTypes<N> {
         Ok(())
     }
 
+    /// Ensures the given `array.get` command is well-formed.
+    #[inline]
+    fn check_array_get(
+        &mut self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        array_get: &ArrayGet<N>,
+    ) -> Result<()> {
+        // Retrieve the array type.
+        let array_type = match self.get_type_from_operand(stack, array_get.array())? {
+            FinalizeType::Plaintext(PlaintextType::Array(array_type)) => array_type,
+            finalize_type => bail!("The first operand of `array.get` must be an array, found '{finalize_type}'"),
+        };
+        // Ensure the index is a `u32`.
+        self.check_array_index(stack, array_get.index())?;
+        // Get the destination register.
+        let destination = array_get.destination().clone();
+        // Ensure the destination register is a locator (and does not reference an access).
+        ensure!(matches!(destination, Register::Locator(..)), "Destination '{destination}' must be a locator.");
+        // Insert the destination register.
+        self.add_destination(destination, FinalizeType::Plaintext(array_type.next_element_type().clone()))?;
+        Ok(())
+    }
+
+    /// Ensures the given `array.set` command is well-formed.
+    #[inline]
+    fn check_array_set(
+        &mut self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        array_set: &ArraySet<N>,
+    ) -> Result<()> {
+        // Retrieve the array type.
+        let array_type = match self.get_type_from_operand(stack, array_set.array())? {
+            FinalizeType::Plaintext(PlaintextType::Array(array_type)) => array_type,
+            finalize_type => bail!("The first operand of `array.set` must be an array, found '{finalize_type}'"),
+        };
+        // Ensure the index is a `u32`.
+        self.check_array_index(stack, array_set.index())?;
+        // Ensure the value type matches the element type of the array.
+        let element_type = array_type.next_element_type();
+        match self.get_type_from_operand(stack, array_set.value())? {
+            FinalizeType::Plaintext(value_type) if &value_type == element_type => (),
+            value_type => {
+                bail!("Value type in `array.set` '{value_type}' does not match the element type '{element_type}'")
+            }
+        }
+        // Get the destination register.
+        let destination = array_set.destination().clone();
+        // Ensure the destination register is a locator (and does not reference an access).
+        ensure!(matches!(destination, Register::Locator(..)), "Destination '{destination}' must be a locator.");
+        // Insert the destination register.
+        self.add_destination(destination, FinalizeType::Plaintext(PlaintextType::Array(array_type)))?;
+        Ok(())
+    }
+
+    /// Ensures the given array index operand is a `u32`.
+    #[inline]
+    fn check_array_index(&self, stack: &(impl StackMatches<N> + StackProgram<N>), index: &Operand<N>) -> Result<()> {
+        match self.get_type_from_operand(stack, index)? {
+            FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::U32)) => Ok(()),
+            index_type => bail!("The index of an array command must be a 'u32', found '{index_type}'"),
+        }
+    }
+
     /// Ensures the given `set` command is well-formed.
     #[inline]
     fn check_set(
@@ -537,6 +737,16 @@ impl<N: Network> FinalizeTypes<N> {
         Ok(())
     }
 
+    /// Ensures the given `emit` command is well-formed.
+    #[inline]
+    fn check_emit(&self, stack: &(impl StackMatches<N> + StackProgram<N>), emit: &Emit<N>) -> Result<()> {
+        // Ensure the event is a plaintext type.
+        match self.get_type_from_operand(stack, emit.event())? {
+            FinalizeType::Plaintext(..) => Ok(()),
+            FinalizeType::Future(..) => bail!("A future cannot be used as an event in an `emit` command"),
+        }
+    }
+
     /// Ensures the given instruction is well-formed.
     #[inline]
     fn check_instruction(
@@ -706,6 +916,17 @@ impl<N: Network> FinalizeTypes<N> {
                     "Instruction '{instruction}' has multiple destinations."
                 );
             }
+            Opcode::Verify(opcode) => match opcode {
+                "ecdsa.verify" => ensure!(
+                    matches!(instruction, Instruction::EcdsaVerify(..)),
+                    "Instruction '{instruction}' is not for opcode '{opcode}'."
+                ),
+                "ed25519.verify" => ensure!(
+                    matches!(instruction, Instruction::Ed25519Verify(..)),
+                    "Instruction '{instruction}' is not for opcode '{opcode}'."
+                ),
+                _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
+            },
         }
         Ok(())
     }
@@ -96,6 +96,46 @@ impl<N: Network> FinalizeTypes<N> {
                         "Struct member '{struct_name}.{member_name}' expects {member_type}, but found '{block_height_type}' in the operand '{operand}'.",
                     )
                 }
+                // Ensure the block timestamp type (i64) matches the member type.
+                Operand::BlockTimestamp => {
+                    // Retrieve the block timestamp type.
+                    let block_timestamp_type = PlaintextType::Literal(LiteralType::I64);
+                    // Ensure the block timestamp type matches the member type.
+                    ensure!(
+                        &block_timestamp_type == member_type,
+                        "Struct member '{struct_name}.{member_name}' expects {member_type}, but found '{block_timestamp_type}' in the operand '{operand}'.",
+                    )
+                }
+                // Ensure the block round type (u64) matches the member type.
+                Operand::BlockRound => {
+                    // Retrieve the block round type.
+                    let block_round_type = PlaintextType::Literal(LiteralType::U64);
+                    // Ensure the block round type matches the member type.
+                    ensure!(
+                        &block_round_type == member_type,
+                        "Struct member '{struct_name}.{member_name}' expects {member_type}, but found '{block_round_type}' in the operand '{operand}'.",
+                    )
+                }
+                // Ensure the block randomness type (field) matches the member type.
+                Operand::BlockRandomness => {
+                    // Retrieve the block randomness type.
+                    let block_randomness_type = PlaintextType::Literal(LiteralType::Field);
+                    // Ensure the block randomness type matches the member type.
+                    ensure!(
+                        &block_randomness_type == member_type,
+                        "Struct member '{struct_name}.{member_name}' expects {member_type}, but found '{block_randomness_type}' in the operand '{operand}'.",
+                    )
+                }
+                // Ensure the network ID type (u16) matches the member type.
+                Operand::NetworkID => {
+                    // Retrieve the network ID type.
+                    let network_id_type = PlaintextType::Literal(LiteralType::U16);
+                    // Ensure the network ID type matches the member type.
+                    ensure!(
+                        &network_id_type == member_type,
+                        "Struct member '{struct_name}.{member_name}' expects {member_type}, but found '{network_id_type}' in the operand '{operand}'.",
+                    )
+                }
             }
         }
         Ok(())
@@ -177,6 +217,50 @@ impl<N: Network> FinalizeTypes<N> {
                         array_type.next_element_type()
                     )
                 }
+                // Ensure the block timestamp type (i64) matches the member type.
+                Operand::BlockTimestamp => {
+                    // Retrieve the block timestamp type.
+                    let block_timestamp_type = PlaintextType::Literal(LiteralType::I64);
+                    // Ensure the block timestamp type matches the member type.
+                    ensure!(
+                        &block_timestamp_type == array_type.next_element_type(),
+                        "Array element expects {}, but found '{block_timestamp_type}' in the operand '{operand}'.",
+                        array_type.next_element_type()
+                    )
+                }
+                // Ensure the block round type (u64) matches the member type.
+                Operand::BlockRound => {
+                    // Retrieve the block round type.
+                    let block_round_type = PlaintextType::Literal(LiteralType::U64);
+                    // Ensure the block round type matches the member type.
+                    ensure!(
+                        &block_round_type == array_type.next_element_type(),
+                        "Array element expects {}, but found '{block_round_type}' in the operand '{operand}'.",
+                        array_type.next_element_type()
+                    )
+                }
+                // Ensure the block randomness type (field) matches the member type.
+                Operand::BlockRandomness => {
+                    // Retrieve the block randomness type.
+                    let block_randomness_type = PlaintextType::Literal(LiteralType::Field);
+                    // Ensure the block randomness type matches the member type.
+                    ensure!(
+                        &block_randomness_type == array_type.next_element_type(),
+                        "Array element expects {}, but found '{block_randomness_type}' in the operand '{operand}'.",
+                        array_type.next_element_type()
+                    )
+                }
+                // Ensure the network ID type (u16) matches the member type.
+                Operand::NetworkID => {
+                    // Retrieve the network ID type.
+                    let network_id_type = PlaintextType::Literal(LiteralType::U16);
+                    // Ensure the network ID type matches the member type.
+                    ensure!(
+                        &network_id_type == array_type.next_element_type(),
+                        "Array element expects {}, but found '{network_id_type}' in the operand '{operand}'.",
+                        array_type.next_element_type()
+                    )
+                }
             }
         }
         Ok(())
@@ -79,6 +79,10 @@ impl<N: Network> FinalizeTypes<N> {
             Operand::Signer => bail!("'self.signer' is not a valid operand in a finalize context."),
             Operand::Caller => bail!("'self.caller' is not a valid operand in a finalize context."),
             Operand::BlockHeight => FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::U32)),
+            Operand::BlockTimestamp => FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::I64)),
+            Operand::BlockRound => FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::U64)),
+            Operand::BlockRandomness => FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::Field)),
+            Operand::NetworkID => FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::U16)),
         })
     }
 
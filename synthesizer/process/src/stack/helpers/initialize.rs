@@ -17,7 +17,7 @@ use super::*;
 impl<N: Network> Stack<N> {
     /// Initializes a new stack, given the process and program.
     #[inline]
-    pub(crate) fn initialize(process: &Process<N>, program: &Program<N>) -> Result<Self> {
+    pub(crate) fn initialize(process: &Process<N>, program: Arc<Program<N>>) -> Result<Self> {
         // Construct the stack for the program.
         let mut stack = Self {
             program: program.clone(),
@@ -29,6 +29,8 @@ impl<N: Network> Stack<N> {
             verifying_keys: Default::default(),
             number_of_calls: Default::default(),
             program_depth: 0,
+            profiler: Arc::new(RwLock::new(process.profiler().cloned())),
+            max_call_depth: Arc::new(RwLock::new(process.max_call_depth())),
         };
 
         // Add all the imports into the stack.
@@ -16,6 +16,7 @@ use super::*;
 
 impl<N: Network> Stack<N> {
     /// Synthesizes the proving key and verifying key for the given function name.
+    #[cfg(feature = "prover")]
     #[inline]
     pub fn synthesize_key<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
         &self,
@@ -59,12 +60,14 @@ impl<N: Network> Stack<N> {
         let caller = None;
 
         // Compute the request, with a burner private key.
-        let request = Request::sign(
+        let request = Request::sign_with_consumed_records(
             &burner_private_key,
             *program_id,
             *function_name,
             inputs.into_iter(),
             &input_types,
+            &self.get_consumed_external_records(function_name)?,
+            &self.get_time_locked_records(function_name)?,
             root_tvk,
             is_root,
             rng,
@@ -84,6 +87,7 @@ impl<N: Network> Stack<N> {
     }
 
     /// Synthesizes and stores the `(proving_key, verifying_key)` for the given function name and assignment.
+    #[cfg(feature = "prover")]
     #[inline]
     pub fn synthesize_from_assignment(
         &self,
@@ -36,7 +36,17 @@ mod evaluate;
 mod execute;
 mod helpers;
 
-use crate::{traits::*, CallMetrics, Process, Trace};
+use crate::{
+    call_depth::{ensure_call_depth, instruction_error},
+    profile_phase,
+    traits::*,
+    CallMetrics,
+    Process,
+    ProfilePhase,
+    Profiler,
+    RegisterTrace,
+    Trace,
+};
 use console::{
     account::{Address, PrivateKey},
     network::prelude::*,
@@ -47,6 +57,7 @@ use console::{
         FinalizeType,
         Future,
         Identifier,
+        InputID,
         Literal,
         Locator,
         Owner as RecordOwner,
@@ -77,12 +88,14 @@ use rayon::prelude::*;
 
 pub type Assignments<N> = Arc<RwLock<Vec<(circuit::Assignment<<N as Environment>::Field>, CallMetrics<N>)>>>;
 
+pub type RegisterTraces<N> = Arc<RwLock<Vec<RegisterTrace<N>>>>;
+
 #[derive(Clone)]
 pub enum CallStack<N: Network> {
     Authorize(Vec<Request<N>>, PrivateKey<N>, Authorization<N>),
     Synthesize(Vec<Request<N>>, PrivateKey<N>, Authorization<N>),
     CheckDeployment(Vec<Request<N>>, PrivateKey<N>, Assignments<N>, Option<u64>),
-    Evaluate(Authorization<N>),
+    Evaluate(Authorization<N>, Option<RegisterTraces<N>>),
     Execute(Authorization<N>, Arc<RwLock<Trace<N>>>),
     PackageRun(Vec<Request<N>>, PrivateKey<N>, Assignments<N>),
 }
@@ -90,7 +103,12 @@ pub enum CallStack<N: Network> {
 impl<N: Network> CallStack<N> {
     /// Initializes a call stack as `Self::Evaluate`.
     pub fn evaluate(authorization: Authorization<N>) -> Result<Self> {
-        Ok(CallStack::Evaluate(authorization))
+        Ok(CallStack::Evaluate(authorization, None))
+    }
+
+    /// Initializes a call stack as `Self::Evaluate`, which records the register traces of the evaluated calls.
+    pub fn evaluate_with_traces(authorization: Authorization<N>, traces: RegisterTraces<N>) -> Result<Self> {
+        Ok(CallStack::Evaluate(authorization, Some(traces)))
     }
 
     /// Initializes a call stack as `Self::Execute`.
@@ -117,7 +135,10 @@ impl<N: Network> CallStack<N> {
                     *constraint_limit,
                 )
             }
-            CallStack::Evaluate(authorization) => CallStack::Evaluate(authorization.replicate()),
+            CallStack::Evaluate(authorization, traces) => CallStack::Evaluate(
+                authorization.replicate(),
+                traces.as_ref().map(|traces| Arc::new(RwLock::new(traces.read().clone()))),
+            ),
             CallStack::Execute(authorization, trace) => {
                 CallStack::Execute(authorization.replicate(), Arc::new(RwLock::new(trace.read().clone())))
             }
@@ -134,7 +155,7 @@ impl<N: Network> CallStack<N> {
             | CallStack::Synthesize(requests, ..)
             | CallStack::CheckDeployment(requests, ..)
             | CallStack::PackageRun(requests, ..) => requests.push(request),
-            CallStack::Evaluate(authorization) => authorization.push(request),
+            CallStack::Evaluate(authorization, ..) => authorization.push(request),
             CallStack::Execute(authorization, ..) => authorization.push(request),
         }
         Ok(())
@@ -149,7 +170,7 @@ impl<N: Network> CallStack<N> {
             | CallStack::PackageRun(requests, ..) => {
                 requests.pop().ok_or_else(|| anyhow!("No more requests on the stack"))
             }
-            CallStack::Evaluate(authorization) => authorization.next(),
+            CallStack::Evaluate(authorization, ..) => authorization.next(),
             CallStack::Execute(authorization, ..) => authorization.next(),
         }
     }
@@ -163,7 +184,7 @@ impl<N: Network> CallStack<N> {
             | CallStack::PackageRun(requests, ..) => {
                 requests.last().cloned().ok_or_else(|| anyhow!("No more requests on the stack"))
             }
-            CallStack::Evaluate(authorization) => authorization.peek_next(),
+            CallStack::Evaluate(authorization, ..) => authorization.peek_next(),
             CallStack::Execute(authorization, ..) => authorization.peek_next(),
         }
     }
@@ -171,8 +192,8 @@ impl<N: Network> CallStack<N> {
 
 #[derive(Clone)]
 pub struct Stack<N: Network> {
-    /// The program (record types, structs, functions).
-    program: Program<N>,
+    /// The program (record types, structs, functions), shared with its deployment.
+    program: Arc<Program<N>>,
     /// The mapping of external stacks as `(program ID, stack)`.
     external_stacks: IndexMap<ProgramID<N>, Arc<Stack<N>>>,
     /// The mapping of closure and function names to their register types.
@@ -189,28 +210,56 @@ pub struct Stack<N: Network> {
     number_of_calls: IndexMap<Identifier<N>, usize>,
     /// The program depth.
     program_depth: usize,
+    /// The profiler, if the stack is profiled.
+    profiler: Arc<RwLock<Option<Arc<dyn Profiler<N>>>>>,
+    /// The maximum depth of nested function calls.
+    max_call_depth: Arc<RwLock<usize>>,
 }
 
 impl<N: Network> Stack<N> {
     /// Initializes a new stack, if it does not already exist, given the process and the program.
+    /// Note: A program given as an `Arc<Program>` is shared with the stack, instead of copied.
     #[inline]
-    pub fn new(process: &Process<N>, program: &Program<N>) -> Result<Self> {
+    pub fn new<P: Clone + Into<Arc<Program<N>>>>(process: &Process<N>, program: &P) -> Result<Self> {
+        let program: Arc<Program<N>> = program.clone().into();
         // Retrieve the program ID.
         let program_id = program.id();
         // Ensure the program does not already exist in the process.
         ensure!(!process.contains_program(program_id), "Program '{program_id}' already exists");
+        // Return the stack.
+        Self::new_unchecked(process, program)
+    }
+
+    /// Initializes a new stack for a new edition of a program that already exists in the process.
+    /// Note: This method does **not** check that the upgrade is compatible, see `Process::check_upgrade`.
+    #[inline]
+    pub fn new_upgrade<P: Clone + Into<Arc<Program<N>>>>(process: &Process<N>, program: &P) -> Result<Self> {
+        let program: Arc<Program<N>> = program.clone().into();
+        // Retrieve the program ID.
+        let program_id = program.id();
+        // Ensure the program already exists in the process.
+        ensure!(process.contains_program(program_id), "Program '{program_id}' does not exist");
+        // Return the stack.
+        Self::new_unchecked(process, program)
+    }
+
+    /// Initializes a new stack, given the process and the program, without checking the process for the program.
+    #[inline]
+    fn new_unchecked(process: &Process<N>, program: Arc<Program<N>>) -> Result<Self> {
+        // Retrieve the program ID.
+        let program_id = program.id();
         // Ensure the program contains functions.
         ensure!(!program.functions().is_empty(), "No functions present in the deployment for program '{program_id}'");
 
         // Serialize the program into bytes.
         let program_bytes = program.to_bytes_le()?;
         // Ensure the program deserializes from bytes correctly.
-        ensure!(program == &Program::from_bytes_le(&program_bytes)?, "Program byte serialization failed");
+        ensure!(*program == Program::from_bytes_le(&program_bytes)?, "Program byte serialization failed");
 
         // Serialize the program into string.
         let program_string = program.to_string();
         // Ensure the program deserializes from a string correctly.
-        ensure!(program == &Program::from_str(&program_string)?, "Program string serialization failed");
+        ensure!(*program == Program::from_str(&program_string)?, "Program string serialization failed");
 
         // Return the stack.
         Stack::initialize(process, program)
@@ -295,6 +344,61 @@ impl<N: Network> StackProgram<N> for Stack<N> {
             .ok_or_else(|| anyhow!("Function '{function_name}' does not exist"))
     }
 
+    /// Returns the indices of the external record inputs that are consumed by the given function.
+    ///
+    /// An external record input is consumed by the function if it is not passed to a function call.
+    /// A consumed external record is committed under the program that produced it, and its serial
+    /// number is published in the transition of this function. Otherwise, the record is consumed by the callee.
+    #[inline]
+    fn get_consumed_external_records(&self, function_name: &Identifier<N>) -> Result<Vec<usize>> {
+        // Retrieve the function.
+        let function = self.get_function_ref(function_name)?;
+        // Collect the registers that are passed to a function call.
+        let mut forwarded = Vec::new();
+        for instruction in function.instructions() {
+            if let Instruction::Call(call) = instruction {
+                if call.is_function_call(self)? {
+                    forwarded.extend(call.operands().iter().filter_map(|operand| match operand {
+                        Operand::Register(register) => Some(register),
+                        _ => None,
+                    }));
+                }
+            }
+        }
+        // Return the indices of the external record inputs that are not passed to a function call.
+        Ok(function
+            .inputs()
+            .iter()
+            .enumerate()
+            .filter(|(_, input)| {
+                matches!(input.value_type(), ValueType::ExternalRecord(..)) && !forwarded.contains(&input.register())
+            })
+            .map(|(index, _)| index)
+            .collect())
+    }
+
+    /// Returns the indices and lock entries of the time-locked record inputs that are consumed by the given function.
+    #[inline]
+    fn get_time_locked_records(&self, function_name: &Identifier<N>) -> Result<Vec<(usize, Identifier<N>)>> {
+        // Retrieve the function.
+        let function = self.get_function_ref(function_name)?;
+        // Retrieve the indices of the consumed external record inputs.
+        let consumed = self.get_consumed_external_records(function_name)?;
+        // Collect the lock entries of the consumed record inputs.
+        let mut time_locked_records = Vec::new();
+        for (index, input) in function.inputs().iter().enumerate() {
+            let record_type = match input.value_type() {
+                ValueType::Record(record_name) => self.program.get_record(record_name)?,
+                ValueType::ExternalRecord(locator) if consumed.contains(&index) => self.get_external_record(locator)?,
+                _ => continue,
+            };
+            if let Some(lock_height) = record_type.lock_height() {
+                time_locked_records.push((index, *lock_height));
+            }
+        }
+        Ok(time_locked_records)
+    }
+
     /// Returns a value for the given value type.
     fn sample_value<R: Rng + CryptoRng>(
         &self,
@@ -353,6 +457,49 @@ impl<N: Network> StackProgramTypes<N> for Stack<N> {
 }
 
 impl<N: Network> Stack<N> {
+    /// Ensures the given request consumes exactly the external record inputs that are consumed by its function.
+    #[inline]
+    pub fn check_consumed_external_records(&self, request: &Request<N>) -> Result<()> {
+        // Retrieve the function.
+        let function = self.get_function_ref(request.function_name())?;
+        // Retrieve the indices of the consumed external record inputs.
+        let consumed = self.get_consumed_external_records(function.name())?;
+        // Ensure each external record input is consumed if and only if the function consumes it.
+        for (index, (input, input_id)) in function.inputs().iter().zip_eq(request.input_ids()).enumerate() {
+            if let ValueType::ExternalRecord(locator) = input.value_type() {
+                match (input_id, consumed.contains(&index)) {
+                    (InputID::Record(..), true) | (InputID::ExternalRecord(..), false) => (),
+                    (_, true) => bail!("Input '{locator}' at index {index} must be consumed by '{}'", function.name()),
+                    (_, false) => {
+                        bail!("Input '{locator}' at index {index} cannot be consumed by '{}'", function.name())
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Ensures the given request reveals the lock height of exactly the time-locked record inputs of its function.
+    #[inline]
+    pub fn check_time_locked_records(&self, request: &Request<N>) -> Result<()> {
+        // Retrieve the time-locked record inputs.
+        let time_locked_records = self.get_time_locked_records(request.function_name())?;
+        // Ensure each record input reveals the lock height of its record if and only if it is time-locked.
+        for (index, (input, input_id)) in request.inputs().iter().zip_eq(request.input_ids()).enumerate() {
+            if let (Value::Record(record), InputID::Record(_, _, _, _, lock_height)) = (input, input_id) {
+                match (time_locked_records.iter().find(|(locked_index, _)| *locked_index == index), lock_height) {
+                    (Some((_, entry_name)), Some(lock_height)) => ensure!(
+                        record.lock_height(entry_name)? == *lock_height,
+                        "Input at index {index} reveals an incorrect lock height"
+                    ),
+                    (None, None) => (),
+                    _ => bail!("Input at index {index} has an invalid lock height for '{}'", request.function_name()),
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// Returns `true` if the proving key for the given function name exists.
     #[inline]
     pub fn contains_proving_key(&self, function_name: &Identifier<N>) -> bool {
@@ -426,6 +573,30 @@ impl<N: Network> Stack<N> {
     pub fn remove_verifying_key(&self, function_name: &Identifier<N>) {
         self.verifying_keys.write().shift_remove(function_name);
     }
+
+    /// Returns the profiler, if the stack is profiled.
+    #[inline]
+    pub fn profiler(&self) -> Option<Arc<dyn Profiler<N>>> {
+        self.profiler.read().clone()
+    }
+
+    /// Sets the profiler of the stack.
+    #[inline]
+    pub fn set_profiler(&self, profiler: Option<Arc<dyn Profiler<N>>>) {
+        *self.profiler.write() = profiler;
+    }
+
+    /// Returns the maximum depth of nested function calls.
+    #[inline]
+    pub fn max_call_depth(&self) -> usize {
+        *self.max_call_depth.read()
+    }
+
+    /// Sets the maximum depth of nested function calls.
+    #[inline]
+    pub fn set_max_call_depth(&self, max_call_depth: usize) {
+        *self.max_call_depth.write() = max_call_depth;
+    }
 }
 
 impl<N: Network> Stack<N> {
@@ -581,6 +581,9 @@ impl<N: Network> RegisterTypes<N> {
                 bail!("Forbidden operation: Instruction '{instruction}' cannot invoke command '{opcode}'.");
             }
             Opcode::Commit(opcode) => Self::check_commit_opcode(opcode, instruction)?,
+            Opcode::Hash(opcode) if opcode.starts_with("hash_bytes.") => {
+                bail!("Instruction '{instruction}' is not supported outside of finalize, as '{opcode}' has no circuit")
+            }
             Opcode::Hash(opcode) => Self::check_hash_opcode(opcode, instruction)?,
             Opcode::Is(opcode) => match opcode {
                 "is.eq" => ensure!(
@@ -600,6 +603,9 @@ impl<N: Network> RegisterTypes<N> {
                     "Instruction '{instruction}' has multiple destinations."
                 );
             }
+            Opcode::Verify(opcode) => {
+                bail!("Instruction '{instruction}' is not supported outside of finalize, as '{opcode}' has no circuit")
+            }
         }
         Ok(())
     }
@@ -800,6 +806,14 @@ impl<N: Network> RegisterTypes<N> {
                 matches!(instruction, Instruction::HashManyPSD8(..)),
                 "Instruction '{instruction}' is not for opcode '{opcode}'."
             ),
+            "hash_bytes.keccak256" => ensure!(
+                matches!(instruction, Instruction::HashBytesKeccak256(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "hash_bytes.sha3_256" => ensure!(
+                matches!(instruction, Instruction::HashBytesSha3_256(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
             _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
         }
         Ok(())
@@ -97,6 +97,10 @@ impl<N: Network> RegisterTypes<N> {
                 RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Address))
             }
             Operand::BlockHeight => bail!("'block.height' is not a valid operand in a non-finalize context."),
+            Operand::BlockTimestamp => bail!("'block.timestamp' is not a valid operand in a non-finalize context."),
+            Operand::BlockRound => bail!("'block.round' is not a valid operand in a non-finalize context."),
+            Operand::BlockRandomness => bail!("'block.randomness' is not a valid operand in a non-finalize context."),
+            Operand::NetworkID => bail!("'network.id' is not a valid operand in a non-finalize context."),
         })
     }
 
@@ -38,6 +38,14 @@ impl<N: Network, A: circuit::Aleo<Network = N>> RegistersLoad<N> for Registers<N
             Operand::Caller => return Ok(Value::Plaintext(Plaintext::from(Literal::Address(self.caller()?)))),
             // If the operand is the block height, throw an error.
             Operand::BlockHeight => bail!("Cannot load the block height in a non-finalize context"),
+            // If the operand is the block timestamp, throw an error.
+            Operand::BlockTimestamp => bail!("Cannot load the block timestamp in a non-finalize context"),
+            // If the operand is the block round, throw an error.
+            Operand::BlockRound => bail!("Cannot load the block round in a non-finalize context"),
+            // If the operand is the block randomness, throw an error.
+            Operand::BlockRandomness => bail!("Cannot load the block randomness in a non-finalize context"),
+            // If the operand is the network ID, throw an error.
+            Operand::NetworkID => bail!("Cannot load the network ID in a non-finalize context"),
         };
 
         // Retrieve the stack value.
@@ -121,6 +129,14 @@ impl<N: Network, A: circuit::Aleo<Network = N>> RegistersLoadCircuit<N, A> for R
             }
             // If the operand is the block height, throw an error.
             Operand::BlockHeight => bail!("Cannot load the block height in a non-finalize context"),
+            // If the operand is the block timestamp, throw an error.
+            Operand::BlockTimestamp => bail!("Cannot load the block timestamp in a non-finalize context"),
+            // If the operand is the block round, throw an error.
+            Operand::BlockRound => bail!("Cannot load the block round in a non-finalize context"),
+            // If the operand is the block randomness, throw an error.
+            Operand::BlockRandomness => bail!("Cannot load the block randomness in a non-finalize context"),
+            // If the operand is the network ID, throw an error.
+            Operand::NetworkID => bail!("Cannot load the network ID in a non-finalize context"),
         };
 
         // Retrieve the circuit value.
@@ -85,6 +85,12 @@ impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
         }
     }
 
+    /// Returns the assigned console registers, in the order they were assigned.
+    #[inline]
+    pub const fn console_registers(&self) -> &IndexMap<u64, Value<N>> {
+        &self.console_registers
+    }
+
     /// Ensure the console and circuit registers match.
     #[inline]
     pub fn ensure_console_and_circuit_registers_match(&self) -> Result<()> {
@@ -0,0 +1,95 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for AuthorizationTemplate<N> {
+    /// Reads the authorization template from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid authorization template version"));
+        }
+        // Read the signer.
+        let signer = Address::read_le(&mut reader)?;
+        // Read the program ID.
+        let program_id = ProgramID::read_le(&mut reader)?;
+        // Read the function name.
+        let function_name = Identifier::read_le(&mut reader)?;
+        // Read the maximum amount.
+        let max_amount = match u8::read_le(&mut reader)? {
+            0 => None,
+            1 => Some((u8::read_le(&mut reader)?, u64::read_le(&mut reader)?)),
+            variant => return Err(error(format!("Invalid authorization template amount variant '{variant}'"))),
+        };
+        // Read the expiration height.
+        let expiration_height = u32::read_le(&mut reader)?;
+        // Read the nonce.
+        let nonce = Field::read_le(&mut reader)?;
+        // Read the signature.
+        let signature = Signature::read_le(&mut reader)?;
+        // Return the authorization template.
+        Self::from(signer, program_id, function_name, max_amount, expiration_height, nonce, signature).map_err(error)
+    }
+}
+
+impl<N: Network> ToBytes for AuthorizationTemplate<N> {
+    /// Writes the authorization template to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the signer.
+        self.signer.write_le(&mut writer)?;
+        // Write the program ID.
+        self.program_id.write_le(&mut writer)?;
+        // Write the function name.
+        self.function_name.write_le(&mut writer)?;
+        // Write the maximum amount.
+        match self.max_amount {
+            None => 0u8.write_le(&mut writer)?,
+            Some((index, amount)) => {
+                1u8.write_le(&mut writer)?;
+                index.write_le(&mut writer)?;
+                amount.write_le(&mut writer)?;
+            }
+        }
+        // Write the expiration height.
+        self.expiration_height.write_le(&mut writer)?;
+        // Write the nonce.
+        self.nonce.write_le(&mut writer)?;
+        // Write the signature.
+        self.signature.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::prelude::TestRng;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample the authorization template.
+        let expected = crate::template::test_helpers::sample_authorization_template(rng);
+
+        // Check the byte representation.
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, AuthorizationTemplate::read_le(&expected_bytes[..])?);
+        Ok(())
+    }
+}
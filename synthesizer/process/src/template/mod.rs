@@ -0,0 +1,357 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod serialize;
+mod string;
+
+use super::*;
+use console::account::{Address, Signature};
+
+/// An authorization template pre-approves a program function for recurring executions.
+///
+/// A wallet signs the template once, and may then authorize calls to the function without
+/// re-entering the signing flow, as long as each call stays within the bounds of the template:
+///   1. The call is to the program function of the template.
+///   2. The call is signed by the signer of the template.
+///   3. The `u64` input at the amount index (if any) does not exceed the maximum amount.
+///   4. The call is made at or before the expiration height.
+#[derive(Clone, PartialEq, Eq)]
+pub struct AuthorizationTemplate<N: Network> {
+    /// The signer of the template.
+    signer: Address<N>,
+    /// The program ID.
+    program_id: ProgramID<N>,
+    /// The function name.
+    function_name: Identifier<N>,
+    /// The index of the `u64` amount input, and the maximum amount.
+    max_amount: Option<(u8, u64)>,
+    /// The last block height at which the template may be used.
+    expiration_height: u32,
+    /// The nonce, which distinguishes templates with the same bounds.
+    nonce: Field<N>,
+    /// The signature of the signer over the template.
+    signature: Signature<N>,
+}
+
+impl<N: Network> AuthorizationTemplate<N> {
+    /// Initializes a new authorization template, signed with the given private key.
+    pub fn new<R: Rng + CryptoRng>(
+        private_key: &PrivateKey<N>,
+        program_id: ProgramID<N>,
+        function_name: Identifier<N>,
+        max_amount: Option<(u8, u64)>,
+        expiration_height: u32,
+        rng: &mut R,
+    ) -> Result<Self> {
+        // Sample the nonce.
+        let nonce = Field::rand(rng);
+        // Compute the message.
+        let message = Self::to_message(&program_id, &function_name, max_amount, expiration_height, nonce)?;
+        // Sign the message.
+        let signature = private_key.sign(&message, rng)?;
+        // Return the template.
+        Self::from(
+            Address::try_from(private_key)?,
+            program_id,
+            function_name,
+            max_amount,
+            expiration_height,
+            nonce,
+            signature,
+        )
+    }
+
+    /// Initializes a new authorization template from its components.
+    pub fn from(
+        signer: Address<N>,
+        program_id: ProgramID<N>,
+        function_name: Identifier<N>,
+        max_amount: Option<(u8, u64)>,
+        expiration_height: u32,
+        nonce: Field<N>,
+        signature: Signature<N>,
+    ) -> Result<Self> {
+        // Initialize the template.
+        let template = Self { signer, program_id, function_name, max_amount, expiration_height, nonce, signature };
+        // Ensure the signature is valid.
+        ensure!(template.verify_signature(), "Invalid signature for the authorization template");
+        // Return the template.
+        Ok(template)
+    }
+}
+
+impl<N: Network> AuthorizationTemplate<N> {
+    /// Returns the signer of the template.
+    pub const fn signer(&self) -> &Address<N> {
+        &self.signer
+    }
+
+    /// Returns the program ID.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the function name.
+    pub const fn function_name(&self) -> &Identifier<N> {
+        &self.function_name
+    }
+
+    /// Returns the index of the `u64` amount input, and the maximum amount.
+    pub const fn max_amount(&self) -> Option<(u8, u64)> {
+        self.max_amount
+    }
+
+    /// Returns the last block height at which the template may be used.
+    pub const fn expiration_height(&self) -> u32 {
+        self.expiration_height
+    }
+
+    /// Returns the nonce.
+    pub const fn nonce(&self) -> &Field<N> {
+        &self.nonce
+    }
+
+    /// Returns the signature of the signer over the template.
+    pub const fn signature(&self) -> &Signature<N> {
+        &self.signature
+    }
+}
+
+impl<N: Network> AuthorizationTemplate<N> {
+    /// Returns `true` if the signature is valid for the template.
+    pub fn verify_signature(&self) -> bool {
+        match Self::to_message(
+            &self.program_id,
+            &self.function_name,
+            self.max_amount,
+            self.expiration_height,
+            self.nonce,
+        ) {
+            Ok(message) => self.signature.verify(&self.signer, &message),
+            Err(_) => false,
+        }
+    }
+
+    /// Ensures the given call to the program function is within the bounds of the template at the given block height.
+    pub fn check_call(
+        &self,
+        program_id: &ProgramID<N>,
+        function_name: &Identifier<N>,
+        inputs: &[Value<N>],
+        block_height: u32,
+    ) -> Result<()> {
+        // Ensure the program function matches.
+        ensure!(
+            program_id == &self.program_id && function_name == &self.function_name,
+            "The authorization template is for '{}/{}', not '{program_id}/{function_name}'",
+            self.program_id,
+            self.function_name
+        );
+        // Ensure the template has not expired.
+        ensure!(
+            block_height <= self.expiration_height,
+            "The authorization template expired at block height {}",
+            self.expiration_height
+        );
+        // Ensure the amount does not exceed the maximum amount.
+        if let Some((index, max_amount)) = self.max_amount {
+            let amount = match inputs.get(index as usize) {
+                Some(Value::Plaintext(Plaintext::Literal(Literal::U64(amount), _))) => **amount,
+                _ => bail!("The authorization template expects a 'u64' amount at input {index}"),
+            };
+            ensure!(amount <= max_amount, "The amount {amount} exceeds the template maximum of {max_amount}");
+        }
+        Ok(())
+    }
+
+    /// Returns the message signed by the signer of the template.
+    fn to_message(
+        program_id: &ProgramID<N>,
+        function_name: &Identifier<N>,
+        max_amount: Option<(u8, u64)>,
+        expiration_height: u32,
+        nonce: Field<N>,
+    ) -> Result<Vec<Field<N>>> {
+        // Encode the maximum amount, with a flag for whether it is set.
+        let (flag, index, amount) = match max_amount {
+            Some((index, amount)) => (1u8, index, amount),
+            None => (0u8, 0u8, 0u64),
+        };
+        // Construct the message.
+        let mut message = vec![Field::new_domain_separator("AleoAuthorizationTemplate0"), Field::from_u16(N::ID)];
+        message.extend(program_id.to_fields()?);
+        message.push(function_name.to_field()?);
+        message.push(Field::from_u8(flag));
+        message.push(Field::from_u8(index));
+        message.push(Field::from_u64(amount));
+        message.push(Field::from_u32(expiration_height));
+        message.push(nonce);
+        Ok(message)
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Authorizes a call to the program function of the given template, without re-entering the signing flow.
+    /// The call must be within the bounds of the template at the given block height.
+    #[inline]
+    pub fn authorize_with_template<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        template: &AuthorizationTemplate<N>,
+        inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
+        block_height: u32,
+        rng: &mut R,
+    ) -> Result<Authorization<N>> {
+        // Ensure the private key is for the signer of the template.
+        ensure!(
+            Address::try_from(private_key)? == template.signer,
+            "The private key does not belong to the signer of the authorization template"
+        );
+        // Prepare the inputs.
+        let inputs = inputs
+            .map(|input| input.try_into().map_err(|_| anyhow!("Failed to parse input")))
+            .collect::<Result<Vec<Value<N>>>>()?;
+        // Ensure the call is within the bounds of the template.
+        template.check_call(&template.program_id, &template.function_name, &inputs, block_height)?;
+        // Authorize the call.
+        self.authorize::<A, R>(private_key, template.program_id, template.function_name, inputs.into_iter(), rng)
+    }
+
+    /// Ensures the given authorization is an instantiation of the given template at the given block height.
+    pub fn verify_template_authorization(
+        &self,
+        template: &AuthorizationTemplate<N>,
+        authorization: &Authorization<N>,
+        block_height: u32,
+    ) -> Result<()> {
+        // Ensure the template is signed by its signer.
+        ensure!(template.verify_signature(), "Invalid signature for the authorization template");
+        // Retrieve the main request.
+        let request = authorization.peek_next()?;
+        // Ensure the request is signed by the signer of the template.
+        ensure!(request.signer() == &template.signer, "The authorization is not signed by the template signer");
+        // Ensure the call is within the bounds of the template.
+        template.check_call(request.program_id(), request.function_name(), request.inputs(), block_height)?;
+        // Ensure the request is valid for the function.
+        let function = self.get_stack(*request.program_id())?.get_function(request.function_name())?;
+        ensure!(request.verify(&function.input_types(), true), "The authorization request is invalid");
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use super::*;
+    use console::{network::MainnetV0, prelude::TestRng};
+
+    type CurrentNetwork = MainnetV0;
+
+    /// Samples a random authorization template for `credits.aleo/transfer_public`.
+    pub(crate) fn sample_authorization_template(rng: &mut TestRng) -> AuthorizationTemplate<CurrentNetwork> {
+        let private_key = PrivateKey::new(rng).unwrap();
+        AuthorizationTemplate::new(
+            &private_key,
+            ProgramID::from_str("credits.aleo").unwrap(),
+            Identifier::from_str("transfer_public").unwrap(),
+            Some((1, 1_000_000)),
+            rng.gen(),
+            rng,
+        )
+        .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::prelude::TestRng;
+
+    type CurrentNetwork = console::network::MainnetV0;
+    type CurrentAleo = circuit::AleoV0;
+
+    #[test]
+    fn test_authorize_with_template() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the process.
+        let process = Process::<CurrentNetwork>::load().unwrap();
+        // Sign a template for transfers of up to 1 credit, until block height 100.
+        let private_key = PrivateKey::new(rng).unwrap();
+        let template = AuthorizationTemplate::new(
+            &private_key,
+            ProgramID::from_str("credits.aleo").unwrap(),
+            Identifier::from_str("transfer_public").unwrap(),
+            Some((1, 1_000_000)),
+            100,
+            rng,
+        )
+        .unwrap();
+        let recipient = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+
+        // Authorize a call within the bounds of the template.
+        let inputs = [Value::from_str(&recipient.to_string()).unwrap(), Value::from_str("500000u64").unwrap()];
+        let authorization =
+            process.authorize_with_template::<CurrentAleo, _>(&private_key, &template, inputs.iter(), 50, rng).unwrap();
+        assert!(process.verify_template_authorization(&template, &authorization, 50).is_ok());
+        // Check the authorization is rejected after the template expires.
+        assert!(process.verify_template_authorization(&template, &authorization, 101).is_err());
+
+        // Check a call above the maximum amount is rejected.
+        let inputs = [Value::from_str(&recipient.to_string()).unwrap(), Value::from_str("2000000u64").unwrap()];
+        assert!(process
+            .authorize_with_template::<CurrentAleo, _>(&private_key, &template, inputs.iter(), 50, rng)
+            .is_err());
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&private_key, "credits.aleo", "transfer_public", inputs.iter(), rng)
+            .unwrap();
+        assert!(process.verify_template_authorization(&template, &authorization, 50).is_err());
+
+        // Check a call signed by another account is rejected.
+        let other_private_key = PrivateKey::new(rng).unwrap();
+        assert!(process
+            .authorize_with_template::<CurrentAleo, _>(&other_private_key, &template, inputs.iter(), 50, rng)
+            .is_err());
+        let inputs = [Value::from_str(&recipient.to_string()).unwrap(), Value::from_str("500000u64").unwrap()];
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&other_private_key, "credits.aleo", "transfer_public", inputs.iter(), rng)
+            .unwrap();
+        assert!(process.verify_template_authorization(&template, &authorization, 50).is_err());
+
+        // Check a call to another function is rejected.
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&private_key, "credits.aleo", "transfer_public_to_private", inputs.iter(), rng)
+            .unwrap();
+        assert!(process.verify_template_authorization(&template, &authorization, 50).is_err());
+    }
+
+    #[test]
+    fn test_template_signature() {
+        let rng = &mut TestRng::default();
+
+        // Check a template with a modified bound is rejected.
+        let template = test_helpers::sample_authorization_template(rng);
+        assert!(template.verify_signature());
+        let result = AuthorizationTemplate::from(
+            template.signer,
+            template.program_id,
+            template.function_name,
+            Some((1, u64::MAX)),
+            template.expiration_height,
+            template.nonce,
+            template.signature,
+        );
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,105 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Serialize for AuthorizationTemplate<N> {
+    /// Serializes the authorization template into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let mut template = serializer.serialize_struct("AuthorizationTemplate", 7)?;
+                template.serialize_field("signer", &self.signer)?;
+                template.serialize_field("program_id", &self.program_id)?;
+                template.serialize_field("function_name", &self.function_name)?;
+                template.serialize_field("max_amount", &self.max_amount)?;
+                template.serialize_field("expiration_height", &self.expiration_height)?;
+                template.serialize_field("nonce", &self.nonce)?;
+                template.serialize_field("signature", &self.signature)?;
+                template.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for AuthorizationTemplate<N> {
+    /// Deserializes the authorization template from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                // Parse the authorization template from a string into a value.
+                let mut template = serde_json::Value::deserialize(deserializer)?;
+                // Recover the authorization template.
+                Self::from(
+                    DeserializeExt::take_from_value::<D>(&mut template, "signer")?,
+                    DeserializeExt::take_from_value::<D>(&mut template, "program_id")?,
+                    DeserializeExt::take_from_value::<D>(&mut template, "function_name")?,
+                    DeserializeExt::take_from_value::<D>(&mut template, "max_amount")?,
+                    DeserializeExt::take_from_value::<D>(&mut template, "expiration_height")?,
+                    DeserializeExt::take_from_value::<D>(&mut template, "nonce")?,
+                    DeserializeExt::take_from_value::<D>(&mut template, "signature")?,
+                )
+                .map_err(de::Error::custom)
+            }
+            false => {
+                FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "authorization template")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::prelude::TestRng;
+
+    #[test]
+    fn test_serde_json() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample the authorization template.
+        let expected = crate::template::test_helpers::sample_authorization_template(rng);
+
+        // Serialize
+        let expected_string = &expected.to_string();
+        let candidate_string = serde_json::to_string(&expected)?;
+        assert_eq!(expected, serde_json::from_str(&candidate_string)?);
+
+        // Deserialize
+        assert_eq!(expected, AuthorizationTemplate::from_str(expected_string)?);
+        assert_eq!(expected, serde_json::from_str(&candidate_string)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bincode() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample the authorization template.
+        let expected = crate::template::test_helpers::sample_authorization_template(rng);
+
+        // Serialize
+        let expected_bytes = expected.to_bytes_le()?;
+        let expected_bytes_with_size_encoding = bincode::serialize(&expected)?;
+        assert_eq!(&expected_bytes[..], &expected_bytes_with_size_encoding[8..]);
+
+        // Deserialize
+        assert_eq!(expected, AuthorizationTemplate::read_le(&expected_bytes[..])?);
+        assert_eq!(expected, bincode::deserialize(&expected_bytes_with_size_encoding[..])?);
+
+        Ok(())
+    }
+}
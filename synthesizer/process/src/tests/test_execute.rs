@@ -14,18 +14,39 @@
 
 use crate::{
     traits::{StackEvaluate, StackExecute},
+    Authorization,
+    CallDepthExceeded,
     CallStack,
+    CostModel,
+    FinalizeDebugger,
+    FutureIssue,
+    Halt,
     Process,
+    ProfilePhase,
+    ProfileRecorder,
     Trace,
 };
 use circuit::{network::AleoV0, Aleo};
 use console::{
     account::{Address, PrivateKey, ViewKey},
     network::{prelude::*, MainnetV0},
-    program::{Identifier, Literal, Plaintext, ProgramID, Record, Value},
-    types::{Field, U64},
+    program::{
+        Disclosure,
+        DisclosureOperator,
+        Identifier,
+        InputID,
+        Literal,
+        Locator,
+        Plaintext,
+        ProgramID,
+        Record,
+        Register,
+        Request,
+        Value,
+    },
+    types::{Field, Group, Scalar, U64},
 };
-use ledger_block::{Fee, Transaction};
+use ledger_block::{Execution, Fee, Transaction};
 use ledger_query::Query;
 use ledger_store::{
     helpers::memory::{BlockMemory, FinalizeMemory},
@@ -34,7 +55,15 @@ use ledger_store::{
     FinalizeStorage,
     FinalizeStore,
 };
-use synthesizer_program::{FinalizeGlobalState, FinalizeStoreTrait, Program, StackProgram};
+use synthesizer_program::{
+    Command,
+    FinalizeGlobalState,
+    FinalizeOperation,
+    FinalizeStoreTrait,
+    MappingLocator,
+    Program,
+    StackProgram,
+};
 use synthesizer_snark::UniversalSRS;
 
 use indexmap::IndexMap;
@@ -46,7 +75,7 @@ type CurrentAleo = AleoV0;
 
 /// Samples a new finalize state.
 pub fn sample_finalize_state(block_height: u32) -> FinalizeGlobalState {
-    FinalizeGlobalState::from(block_height as u64, block_height, [0u8; 32])
+    FinalizeGlobalState::from(block_height as u64, block_height, CurrentNetwork::GENESIS_TIMESTAMP, [0u8; 32])
 }
 
 /// Samples a valid fee for the given process, block store, and finalize store.
@@ -216,6 +245,91 @@ output r1 as field.private;",
     assert_eq!(expected, candidate[0]);
 }
 
+#[test]
+fn test_program_evaluate_commit_struct_and_array() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program example.aleo;
+
+struct message:
+    first as field;
+    second as u8;
+
+function compute:
+    input r0 as message.private;
+    input r1 as [boolean; 2u32].private;
+    input r2 as scalar.private;
+    commit.bhp256 r0 r2 into r3 as group;
+    commit.ped128 r1 r2 into r4 as group;
+    output r3 as group.private;
+    output r4 as group.private;",
+    )
+    .unwrap();
+
+    // Declare the function name.
+    let function_name = Identifier::from_str("compute").unwrap();
+    // Declare the function inputs.
+    let input_struct = Plaintext::<CurrentNetwork>::from_str("{ first: 2field, second: 3u8 }").unwrap();
+    let input_array = Plaintext::<CurrentNetwork>::from_str("[true, false]").unwrap();
+    let randomizer = Scalar::<CurrentNetwork>::from_str("6scalar").unwrap();
+    let inputs = [
+        Value::Plaintext(input_struct.clone()),
+        Value::Plaintext(input_array.clone()),
+        Value::Plaintext(Plaintext::from(Literal::Scalar(randomizer))),
+    ];
+
+    // Declare the expected outputs, which commit to the canonical packing of the plaintexts.
+    let expected = [
+        CurrentNetwork::commit_to_group_bhp256(&input_struct.to_bits_le(), &randomizer).unwrap(),
+        CurrentNetwork::commit_to_group_ped128(&input_array.to_bits_le(), &randomizer).unwrap(),
+    ]
+    .map(|output| Value::Plaintext(Plaintext::from(Literal::Group(output))));
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Initialize an RNG.
+    let rng = &mut TestRng::default();
+
+    // Authorize the function call.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
+        .unwrap();
+    assert_eq!(authorization.len(), 1);
+
+    // Retrieve the stack.
+    let stack = process.get_stack(program.id()).unwrap();
+
+    // Evaluate the function.
+    let response =
+        stack.evaluate_function::<CurrentAleo>(CallStack::evaluate(authorization.replicate()).unwrap(), None).unwrap();
+    assert_eq!(expected.as_slice(), response.outputs());
+
+    // Execute the function, to ensure the circuit packs the plaintexts identically.
+    let (response, _trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    assert_eq!(expected.as_slice(), response.outputs());
+
+    // Ensure a struct that exceeds the bits of a Pedersen committer is rejected.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program example.aleo;
+
+struct message:
+    first as field;
+    second as u8;
+
+function compute:
+    input r0 as message.private;
+    input r1 as scalar.private;
+    commit.ped128 r0 r1 into r2 as group;
+    output r2 as group.private;",
+    )
+    .unwrap();
+    assert!(Process::load().unwrap().add_program(&program).is_err());
+}
+
 #[test]
 fn test_program_evaluate_record_and_function() {
     // Initialize a new program.
@@ -764,6 +878,128 @@ fn test_process_self_caller() {
     assert_eq!(output, candidate[0]);
 }
 
+#[test]
+fn test_process_execute_halt() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"program halt.aleo;
+
+  function divide_by_zero:
+    input r0 as u8.private;
+    div r0 0u8 into r1;
+    output r1 as u8.private;
+
+  function halve:
+    input r0 as u8.private;
+    div r0 2u8 into r1;
+    output r1 as u8.private;",
+    )
+    .unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Ensure a division by zero, which halts the circuit environment, is returned as an error.
+    let inputs = [Value::<CurrentNetwork>::from_str("1u8").unwrap()];
+    let error = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "divide_by_zero", inputs.iter(), rng)
+        .unwrap_err();
+    assert!(error.downcast_ref::<Halt>().is_some());
+    // Ensure the circuit environment is reset.
+    assert_eq!(<CurrentAleo as circuit::Environment>::num_constraints(), 0);
+
+    // Ensure a valid division still executes.
+    let inputs = [Value::<CurrentNetwork>::from_str("6u8").unwrap()];
+    let authorization =
+        process.authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "halve", inputs.iter(), rng).unwrap();
+    let (response, _trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    assert_eq!(response.outputs(), &[Value::from_str("3u8").unwrap()]);
+}
+
+#[test]
+fn test_process_max_call_depth() {
+    // Initialize a chain of programs, where each function calls the function of the program it imports.
+    let program0 = Program::<CurrentNetwork>::from_str(
+        r"
+program one.aleo;
+
+function one:
+    input r0 as u64.private;
+    add r0 1u64 into r1;
+    output r1 as u64.private;",
+    )
+    .unwrap();
+    let program1 = Program::<CurrentNetwork>::from_str(
+        r"
+import one.aleo;
+
+program two.aleo;
+
+function two:
+    input r0 as u64.private;
+    call one.aleo/one r0 into r1;
+    add r1 1u64 into r2;
+    output r2 as u64.private;",
+    )
+    .unwrap();
+    let program2 = Program::<CurrentNetwork>::from_str(
+        r"
+import two.aleo;
+
+program three.aleo;
+
+function three:
+    input r0 as u64.private;
+    call two.aleo/two r0 into r1;
+    add r1 1u64 into r2;
+    output r2 as u64.private;",
+    )
+    .unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let mut process = crate::test_helpers::sample_process(&program0);
+    process.add_program(&program1).unwrap();
+    process.add_program(&program2).unwrap();
+    assert_eq!(process.max_call_depth(), CurrentNetwork::MAX_PROGRAM_DEPTH);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Ensure the nested calls are authorized and evaluated within the maximum call depth.
+    let inputs = [Value::<CurrentNetwork>::from_str("1u64").unwrap()];
+    let authorization =
+        process.authorize::<CurrentAleo, _>(&caller_private_key, program2.id(), "three", inputs.iter(), rng).unwrap();
+    assert_eq!(authorization.len(), 3);
+    let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+    assert_eq!(response.outputs(), &[Value::from_str("4u64").unwrap()]);
+
+    // Lower the maximum call depth, so that 'one.aleo/one' (at depth 2) exceeds it.
+    let process = process.with_max_call_depth(1);
+
+    // Ensure the authorization fails with a `CallDepthExceeded` error.
+    let error = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program2.id(), "three", inputs.iter(), rng)
+        .unwrap_err();
+    let error = error.downcast_ref::<CallDepthExceeded>().unwrap();
+    assert_eq!(error.locator(), "one.aleo/one");
+    assert_eq!(error.max_depth(), 1);
+    // Ensure the circuit environment is reset.
+    assert_eq!(<CurrentAleo as circuit::Environment>::num_constraints(), 0);
+
+    // Ensure the evaluation fails with a `CallDepthExceeded` error.
+    let error = process.evaluate::<CurrentAleo>(authorization).unwrap_err();
+    assert!(error.downcast_ref::<CallDepthExceeded>().is_some());
+}
+
 #[test]
 fn test_process_program_id() {
     // Initialize a new program.
@@ -1191,6 +1427,125 @@ function transfer:
     // assert_eq!(215810, CurrentAleo::num_gates());
 }
 
+#[test]
+fn test_process_execute_consume_external_record() {
+    // Initialize a new program.
+    let (string, program0) = Program::<CurrentNetwork>::parse(
+        r"
+program token.aleo;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+function transfer:
+    input r0 as token.record;
+    input r1 as address.private;
+    input r2 as u64.private;
+    sub r0.amount r2 into r3;
+    cast r1 r2 into r4 as token.record;
+    cast r0.owner r3 into r5 as token.record;
+    output r4 as token.record;
+    output r5 as token.record;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Construct the process.
+    let mut process = crate::test_helpers::sample_process(&program0);
+    // Initialize another program, which consumes a `token.aleo/token` record in `burn`,
+    // and passes it to `token.aleo/transfer` in `transfer`.
+    let (string, program1) = Program::<CurrentNetwork>::parse(
+        r"
+import token.aleo;
+
+program vault.aleo;
+
+function burn:
+    input r0 as token.aleo/token.record;
+    input r1 as u64.private;
+
+function transfer:
+    input r0 as token.aleo/token.record;
+    input r1 as address.private;
+    input r2 as u64.private;
+    call token.aleo/transfer r0 r1 r2 into r3 r4;
+    output r3 as token.aleo/token.record;
+    output r4 as token.aleo/token.record;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Add the program to the process.
+    process.add_program(&program1).unwrap();
+
+    // Ensure only the external record in `burn` is consumed by the calling program.
+    assert_eq!(process.get_consumed_external_records(program1.id(), "burn").unwrap(), vec![0]);
+    assert!(process.get_consumed_external_records(program1.id(), "transfer").unwrap().is_empty());
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Initialize the caller.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let caller = Address::try_from(&caller_private_key).unwrap();
+
+    // Declare the function name.
+    let function_name = Identifier::from_str("burn").unwrap();
+
+    // Declare the input values.
+    let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(&format!(
+        "{{ owner: {caller}.private, amount: 100u64.private, _nonce: 0group.public }}"
+    ))
+    .unwrap();
+    let r0 = Value::Record(record.clone());
+    let r1 = Value::<CurrentNetwork>::from_str("100u64").unwrap();
+
+    // Authorize the function call.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program1.id(), function_name, [r0, r1].iter(), rng)
+        .unwrap();
+    assert_eq!(authorization.len(), 1);
+
+    // Ensure the record is consumed under the program that produced it.
+    let commitment = record
+        .to_commitment(&ProgramID::from_str("token.aleo").unwrap(), &Identifier::from_str("token").unwrap())
+        .unwrap();
+    let serial_number =
+        Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::serial_number(caller_private_key, commitment).unwrap();
+    let request = authorization.peek_next().unwrap();
+    match &request.input_ids()[0] {
+        InputID::Record(candidate_commitment, _, candidate_serial_number, ..) => {
+            assert_eq!(*candidate_commitment, commitment);
+            assert_eq!(*candidate_serial_number, serial_number);
+        }
+        _ => panic!("Expected the external record to be consumed"),
+    }
+
+    // Evaluate the request.
+    let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+    assert!(response.outputs().is_empty());
+
+    // Execute the request.
+    let (_response, trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    assert_eq!(trace.transitions().len(), 1);
+    assert_eq!(trace.transitions()[0].serial_numbers().copied().collect::<Vec<_>>(), vec![serial_number]);
+
+    // Ensure a request that does not consume the external record is rejected.
+    let request = Request::sign(
+        &caller_private_key,
+        *program1.id(),
+        function_name,
+        [Value::Record(record), Value::from_str("100u64").unwrap()].into_iter(),
+        &program1.get_function(&function_name).unwrap().input_types(),
+        None,
+        true,
+        rng,
+    )
+    .unwrap();
+    assert!(process.evaluate::<CurrentAleo>(Authorization::new(request)).is_err());
+}
+
 #[test]
 fn test_process_execute_and_finalize_get_add_set() {
     // Initialize a new program.
@@ -1304,9 +1659,9 @@ finalize compute:
 }
 
 #[test]
-fn test_process_execute_and_finalize_increment_decrement_via_get_set() {
+fn test_process_evaluate_only() {
     // Initialize a new program.
-    let (string, program) = Program::<CurrentNetwork>::parse(
+    let program = Program::<CurrentNetwork>::from_str(
         r"
 program testing.aleo;
 
@@ -1314,31 +1669,32 @@ mapping account:
     key as address.public;
     value as u64.public;
 
+closure twice:
+    input r0 as u64;
+    add r0 r0 into r1;
+    output r1 as u64;
+
 function compute:
     input r0 as address.public;
     input r1 as u64.public;
-    input r2 as u64.public;
-    add r1 r2 into r3;
-    async compute r0 r3 into r4;
-    output r4 as testing.aleo/compute.future;
+    call twice r1 into r2;
+    async compute r0 r2 into r3;
+    output r2 as u64.public;
+    output r3 as testing.aleo/compute.future;
 
 finalize compute:
     input r0 as address.public;
     input r1 as u64.public;
     get.or_use account[r0] 0u64 into r2;
     add r2 r1 into r3;
-    sub r3 r1 into r4;
-    set r4 into account[r0];
+    set r3 into account[r0];
 ",
     )
     .unwrap();
-    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
 
-    // Declare the program ID.
-    let program_id = program.id();
-    // Declare the mapping.
+    // Declare the program ID, mapping, and function name.
+    let program_id = *program.id();
     let mapping_name = Identifier::from_str("account").unwrap();
-    // Declare the function name.
     let function_name = Identifier::from_str("compute").unwrap();
 
     // Initialize the RNG.
@@ -1346,64 +1702,371 @@ finalize compute:
 
     // Construct the process.
     let process = crate::test_helpers::sample_process(&program);
-    // Check that the circuit key can be synthesized.
-    process.synthesize_key::<CurrentAleo, _>(program.id(), &function_name, rng).unwrap();
-
-    // Reset the process.
-    let mut process = Process::load().unwrap();
-
-    // Initialize a new block store.
-    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
     // Initialize a new finalize store.
     let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
-
-    // Add the program to the process.
-    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
-    // Check that the deployment verifies.
-    process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
-    // Compute the fee.
-    let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
-    // Finalize the deployment.
-    let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
-    // Add the stack *manually* to the process.
-    process.add_stack(stack);
+    finalize_store.initialize_mapping(program_id, mapping_name).unwrap();
 
     // Initialize a new caller account.
     let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
-    let _caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
     let caller = Address::try_from(&caller_private_key).unwrap();
 
-    // Declare the input value.
+    // Authorize the function call.
     let r0 = Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap();
     let r1 = Value::<CurrentNetwork>::from_str("3u64").unwrap();
-    let r2 = Value::<CurrentNetwork>::from_str("5u64").unwrap();
-
-    // Authorize the function call.
     let authorization = process
-        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1, r2].iter(), rng)
+        .authorize::<CurrentAleo, _>(&caller_private_key, program_id, function_name, [r0, r1].iter(), rng)
         .unwrap();
-    assert_eq!(authorization.len(), 1);
 
-    // Compute the output value.
-    let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
-    let candidate = response.outputs();
-    assert_eq!(1, candidate.len());
+    // Evaluate the function, without proving.
+    let evaluation = process.evaluate_only::<CurrentAleo>(authorization).unwrap();
+    assert_eq!(evaluation.outputs()[0], Value::from_str("6u64").unwrap());
+    assert_eq!(evaluation.transitions().len(), 1);
+
+    // Check the register traces, in the order the calls completed.
+    let traces = evaluation.traces();
+    assert_eq!(traces.len(), 2);
+    assert_eq!(traces[0].locator(), &Locator::new(program_id, Identifier::from_str("twice").unwrap()));
+    assert_eq!(traces[0].registers().get(&1), Some(&Value::from_str("6u64").unwrap()));
+    assert_eq!(traces[1].locator(), &Locator::new(program_id, function_name));
+    assert_eq!(traces[1].registers().len(), 4);
+
+    // Check the execution trace.
+    #[cfg(feature = "trace")]
+    {
+        let trace = evaluation.to_trace();
+        assert_eq!(trace.transitions().len(), 1);
+        assert_eq!(trace.transitions()[0].function_name(), &function_name);
+        assert_eq!(trace.timeline().len(), 2);
+        assert_eq!(trace.timeline()[1].registers(), traces[1].registers());
+        assert_eq!(evaluation.response().to_trace().outputs()[0].1, Value::from_str("6u64").unwrap());
+    }
 
-    // Check again to make sure we didn't modify the authorization after calling `evaluate`.
-    assert_eq!(authorization.len(), 1);
+    // Project the finalize operations.
+    let key = Plaintext::from(Literal::Address(caller));
+    let operations = process.project_finalize(sample_finalize_state(1), &finalize_store, &evaluation).unwrap();
+    assert_eq!(operations.len(), 1);
+    assert!(matches!(operations[0], FinalizeOperation::UpdateKeyValue(..)));
 
-    // Execute the request.
-    let (response, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
-    let candidate = response.outputs();
-    assert_eq!(1, candidate.len());
+    // Ensure the projection did not modify the store.
+    assert!(finalize_store.get_value_speculative(program_id, mapping_name, &key).unwrap().is_none());
+}
 
-    // Prepare the trace.
-    trace.prepare(Query::from(block_store)).unwrap();
-    // Prove the execution.
-    let execution = trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap();
+#[test]
+fn test_process_finalize_debugger() {
+    /// An event of finalize, as recorded by the debugger.
+    #[derive(Debug, PartialEq, Eq)]
+    enum Event {
+        Command(Locator<CurrentNetwork>, usize, usize),
+        Read(usize, String, Value<CurrentNetwork>, Value<CurrentNetwork>),
+        Write(usize),
+        Branch(usize, bool),
+    }
 
-    // Verify the execution.
-    process.verify_execution(&execution).unwrap();
+    /// A debugger that records the events of finalize.
+    #[derive(Default)]
+    struct Recorder {
+        events: RwLock<Vec<Event>>,
+    }
+
+    impl FinalizeDebugger<CurrentNetwork> for Recorder {
+        fn on_command(
+            &self,
+            locator: &Locator<CurrentNetwork>,
+            index: usize,
+            _command: &Command<CurrentNetwork>,
+            registers: &IndexMap<u64, Value<CurrentNetwork>>,
+        ) {
+            self.events.write().push(Event::Command(*locator, index, registers.len()));
+        }
+
+        fn on_mapping_read(
+            &self,
+            _locator: &Locator<CurrentNetwork>,
+            index: usize,
+            mapping: &MappingLocator<CurrentNetwork>,
+            key: &Value<CurrentNetwork>,
+            value: &Value<CurrentNetwork>,
+        ) {
+            self.events.write().push(Event::Read(index, mapping.to_string(), key.clone(), value.clone()));
+        }
+
+        fn on_mapping_write(
+            &self,
+            _locator: &Locator<CurrentNetwork>,
+            index: usize,
+            _operation: &FinalizeOperation<CurrentNetwork>,
+        ) {
+            self.events.write().push(Event::Write(index));
+        }
+
+        fn on_branch(&self, _locator: &Locator<CurrentNetwork>, index: usize, taken: bool) {
+            self.events.write().push(Event::Branch(index, taken));
+        }
+    }
+
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program debugging.aleo;
+
+mapping account:
+    key as address.public;
+    value as u64.public;
+
+function compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    async compute r0 r1 into r2;
+    output r2 as debugging.aleo/compute.future;
+
+finalize compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get.or_use account[r0] 0u64 into r2;
+    branch.eq r1 0u64 to done;
+    add r2 r1 into r3;
+    set r3 into account[r0];
+    position done;
+",
+    )
+    .unwrap();
+
+    // Declare the program ID, mapping, and function name.
+    let program_id = *program.id();
+    let mapping_name = Identifier::from_str("account").unwrap();
+    let function_name = Identifier::from_str("compute").unwrap();
+    let locator = Locator::new(program_id, function_name);
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process in debug mode.
+    let recorder = Arc::new(Recorder::default());
+    let process = crate::test_helpers::sample_process(&program).with_finalize_debugger(recorder.clone());
+    // Initialize a new finalize store.
+    let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+    finalize_store.initialize_mapping(program_id, mapping_name).unwrap();
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let caller =
+        Value::<CurrentNetwork>::from_str(&Address::try_from(&caller_private_key).unwrap().to_string()).unwrap();
+
+    for (amount, taken) in [("3u64", false), ("0u64", true)] {
+        // Authorize and evaluate the function call.
+        let r1 = Value::<CurrentNetwork>::from_str(amount).unwrap();
+        let authorization = process
+            .authorize::<CurrentAleo, _>(
+                &caller_private_key,
+                program_id,
+                function_name,
+                [caller.clone(), r1].iter(),
+                rng,
+            )
+            .unwrap();
+        let evaluation = process.evaluate_only::<CurrentAleo>(authorization).unwrap();
+        // Finalize the evaluation, as a dry run.
+        recorder.events.write().clear();
+        process.project_finalize(sample_finalize_state(1), &finalize_store, &evaluation).unwrap();
+
+        // Ensure the debugger observed the registers, the mapping read, the branch decision, and the mapping write.
+        let mut expected = vec![
+            Event::Command(locator, 0, 2),
+            Event::Read(0, "account".to_string(), caller.clone(), Value::from_str("0u64").unwrap()),
+            Event::Command(locator, 1, 3),
+            Event::Branch(1, taken),
+        ];
+        match taken {
+            true => expected.push(Event::Command(locator, 4, 3)),
+            false => expected.extend([
+                Event::Command(locator, 2, 3),
+                Event::Command(locator, 3, 4),
+                Event::Write(3),
+                Event::Command(locator, 4, 4),
+            ]),
+        }
+        assert_eq!(*recorder.events.read(), expected);
+    }
+}
+
+#[test]
+fn test_process_profiler() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program profiling.aleo;
+
+function compute:
+    input r0 as u32.private;
+    input r1 as u32.private;
+    add r0 r1 into r2;
+    mul r2 r2 into r3;
+    output r3 as u32.private;",
+    )
+    .unwrap();
+
+    // Declare the function name.
+    let function_name = Identifier::from_str("compute").unwrap();
+    let locator = Locator::new(*program.id(), function_name);
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Construct the process with a profiler.
+    let recorder = Arc::new(ProfileRecorder::default());
+    let process = crate::test_helpers::sample_process(&program).with_profiler(recorder.clone());
+
+    // Authorize the function call.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(
+            &caller_private_key,
+            program.id(),
+            function_name,
+            ["3u32", "4u32"].into_iter(),
+            rng,
+        )
+        .unwrap();
+    // Ensure authorization is not profiled.
+    assert!(recorder.instructions().is_empty());
+    assert!(recorder.phases().is_empty());
+
+    // Execute the request.
+    let (response, _trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    assert_eq!(response.outputs()[0], Value::from_str("49u32").unwrap());
+
+    // Ensure each instruction is profiled, in order.
+    let instructions = recorder.instructions();
+    assert_eq!(instructions.len(), 2);
+    for (index, (profile, opcode)) in instructions.iter().zip_eq(["add", "mul"]).enumerate() {
+        assert_eq!(profile.locator, locator);
+        assert_eq!(profile.index, index);
+        assert_eq!(profile.opcode, opcode);
+        assert!(profile.num_constraints > 0);
+    }
+
+    // Ensure the synthesis, witness, and commit phases are profiled, as the circuit key is synthesized on execution.
+    let phases = recorder.phases().into_iter().map(|profile| (profile.locator, profile.phase)).collect::<Vec<_>>();
+    assert_eq!(phases, vec![
+        (locator, ProfilePhase::Synthesis),
+        (locator, ProfilePhase::Witness),
+        (locator, ProfilePhase::Commit)
+    ]);
+
+    // Ensure the folded stacks contain a line for each instruction and phase.
+    let folded_constraints = recorder.to_folded_constraints();
+    let lines = folded_constraints.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].starts_with("profiling.aleo/compute;0:add "));
+    assert!(lines[1].starts_with("profiling.aleo/compute;1:mul "));
+    let folded_timings = recorder.to_folded_timings();
+    let lines = folded_timings.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 3);
+    assert!(lines[2].starts_with("profiling.aleo/compute;commit "));
+}
+
+#[test]
+fn test_process_execute_and_finalize_increment_decrement_via_get_set() {
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+program testing.aleo;
+
+mapping account:
+    key as address.public;
+    value as u64.public;
+
+function compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    input r2 as u64.public;
+    add r1 r2 into r3;
+    async compute r0 r3 into r4;
+    output r4 as testing.aleo/compute.future;
+
+finalize compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get.or_use account[r0] 0u64 into r2;
+    add r2 r1 into r3;
+    sub r3 r1 into r4;
+    set r4 into account[r0];
+",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Declare the program ID.
+    let program_id = program.id();
+    // Declare the mapping.
+    let mapping_name = Identifier::from_str("account").unwrap();
+    // Declare the function name.
+    let function_name = Identifier::from_str("compute").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+    // Check that the circuit key can be synthesized.
+    process.synthesize_key::<CurrentAleo, _>(program.id(), &function_name, rng).unwrap();
+
+    // Reset the process.
+    let mut process = Process::load().unwrap();
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Initialize a new finalize store.
+    let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+
+    // Add the program to the process.
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    // Check that the deployment verifies.
+    process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+    // Compute the fee.
+    let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
+    // Finalize the deployment.
+    let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
+    // Add the stack *manually* to the process.
+    process.add_stack(stack);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let _caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
+    let caller = Address::try_from(&caller_private_key).unwrap();
+
+    // Declare the input value.
+    let r0 = Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap();
+    let r1 = Value::<CurrentNetwork>::from_str("3u64").unwrap();
+    let r2 = Value::<CurrentNetwork>::from_str("5u64").unwrap();
+
+    // Authorize the function call.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1, r2].iter(), rng)
+        .unwrap();
+    assert_eq!(authorization.len(), 1);
+
+    // Compute the output value.
+    let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+    let candidate = response.outputs();
+    assert_eq!(1, candidate.len());
+
+    // Check again to make sure we didn't modify the authorization after calling `evaluate`.
+    assert_eq!(authorization.len(), 1);
+
+    // Execute the request.
+    let (response, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    let candidate = response.outputs();
+    assert_eq!(1, candidate.len());
+
+    // Prepare the trace.
+    trace.prepare(Query::from(block_store)).unwrap();
+    // Prove the execution.
+    let execution = trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap();
+
+    // Verify the execution.
+    process.verify_execution(&execution).unwrap();
 
     // Now, finalize the execution.
     process.finalize_execution(sample_finalize_state(1), &finalize_store, &execution, None).unwrap();
@@ -2259,69 +2922,1064 @@ finalize compute:
 }
 
 #[test]
-fn test_process_execute_and_verify_call_to_closure() {
+fn test_process_execute_and_finalize_array_get_set() {
     // Initialize a new program.
     let (string, program) = Program::<CurrentNetwork>::parse(
         r"
 program testing.aleo;
 
-// (a + (a + b)) + (a + b) == (3a + 2b)
-closure execute:
-    input r0 as field;
-    input r1 as field;
-    add r0 r1 into r2;
-    add r0 r2 into r3;
-    add r2 r3 into r4;
-    output r4 as field;
-    output r3 as field;
-    output r2 as field;
+mapping slots:
+    key as u8.public;
+    value as [u64; 4u32].public;
 
-closure check_not_equal:
-    input r0 as field;
-    input r1 as field;
-    assert.neq r0 r1;
+function update:
+    input r0 as u32.public;
+    input r1 as u64.public;
+    async update r0 r1 into r2;
+    output r2 as testing.aleo/update.future;
 
-function compute:
-    input r0 as field.private;
-    input r1 as field.public;
-    call check_not_equal r0 r1;
-    call execute r0 r1 into r2 r3 r4;
-    output r2 as field.private;
-    output r3 as field.private;
-    output r4 as field.private;",
+finalize update:
+    input r0 as u32.public;
+    input r1 as u64.public;
+    cast 0u64 0u64 0u64 0u64 into r2 as [u64; 4u32];
+    get.or_use slots[0u8] r2 into r3;
+    array.get r3 r0 into r4;
+    add r4 r1 into r5;
+    array.set r3 r0 r5 into r6;
+    set r6 into slots[0u8];
+",
     )
     .unwrap();
     assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
 
+    // Declare the program ID.
+    let program_id = program.id();
+    // Declare the mapping.
+    let mapping_name = Identifier::from_str("slots").unwrap();
     // Declare the function name.
-    let function_name = Identifier::from_str("compute").unwrap();
+    let function_name = Identifier::from_str("update").unwrap();
 
     // Initialize the RNG.
     let rng = &mut TestRng::default();
 
     // Construct the process.
-    let process = crate::test_helpers::sample_process(&program);
-    // Check that the circuit key can be synthesized.
-    process.synthesize_key::<CurrentAleo, _>(program.id(), &function_name, rng).unwrap();
+    let mut process = Process::load().unwrap();
 
-    // Reset the process.
-    let process = crate::test_helpers::sample_process(&program);
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Initialize a new finalize store.
+    let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+
+    // Add the program to the process.
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    // Check that the deployment verifies.
+    process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+    // Compute the fee.
+    let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
+    // Finalize the deployment.
+    let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
+    // Add the stack *manually* to the process.
+    process.add_stack(stack);
 
     // Initialize a new caller account.
     let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
 
-    // Declare the input value.
-    let r0 = Value::<CurrentNetwork>::from_str("3field").unwrap();
-    let r1 = Value::<CurrentNetwork>::from_str("5field").unwrap();
+    // Executes the function with the given index and amount.
+    let mut execute = |index: &str, amount: &str| {
+        // Declare the input values.
+        let inputs = [Value::<CurrentNetwork>::from_str(index).unwrap(), Value::from_str(amount).unwrap()];
+        // Authorize the function call.
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
+            .unwrap();
+        // Execute the request.
+        let (_, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        // Prepare the trace.
+        trace.prepare(Query::from(&block_store)).unwrap();
+        // Prove the execution.
+        let execution = trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap();
+        // Verify the execution.
+        process.verify_execution(&execution).unwrap();
+        execution
+    };
 
-    // Authorize the function call.
-    let authorization = process
-        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1].iter(), rng)
-        .unwrap();
-    assert_eq!(authorization.len(), 1);
+    // Update the element at index 2 twice.
+    let first = execute("2u32", "3u64");
+    let second = execute("2u32", "5u64");
+    // Update the element at index 4, which is out of bounds.
+    let third = execute("4u32", "1u64");
 
-    let r2 = Value::from_str("19field").unwrap();
-    let r3 = Value::from_str("11field").unwrap();
+    // Finalize the executions.
+    process.finalize_execution(sample_finalize_state(1), &finalize_store, &first, None).unwrap();
+    process.finalize_execution(sample_finalize_state(1), &finalize_store, &second, None).unwrap();
+    // Ensure the out of bounds update fails to finalize.
+    assert!(process.finalize_execution(sample_finalize_state(1), &finalize_store, &third, None).is_err());
+
+    // Check that the array is stored as expected.
+    let candidate = finalize_store
+        .get_value_speculative(*program_id, mapping_name, &Plaintext::from_str("0u8").unwrap())
+        .unwrap()
+        .unwrap();
+    assert_eq!(candidate, Value::from_str("[0u64, 0u64, 8u64, 0u64]").unwrap());
+}
+
+#[test]
+fn test_process_execute_and_finalize_iter() {
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+program testing.aleo;
+
+mapping balances:
+    key as u32.public;
+    value as u64.public;
+
+function payout:
+    input r0 as u64.public;
+    async payout r0 into r1;
+    output r1 as testing.aleo/payout.future;
+
+finalize payout:
+    input r0 as u64.public;
+    iter 0u32..4u32 into r1 until done;
+    branch.eq r1 2u32 to done;
+    get.or_use balances[r1] 0u64 into r2;
+    add r2 r0 into r3;
+    set r3 into balances[r1];
+    position done;
+",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Declare the program ID.
+    let program_id = program.id();
+    // Declare the mapping.
+    let mapping_name = Identifier::from_str("balances").unwrap();
+    // Declare the function name.
+    let function_name = Identifier::from_str("payout").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let mut process = Process::load().unwrap();
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Initialize a new finalize store.
+    let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+
+    // Add the program to the process.
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    // Check that the deployment verifies.
+    process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+    // Compute the fee.
+    let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
+    // Finalize the deployment.
+    let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
+    // Add the stack *manually* to the process.
+    process.add_stack(stack);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Execute the function twice.
+    for _ in 0..2 {
+        // Authorize the function call.
+        let inputs = [Value::<CurrentNetwork>::from_str("5u64").unwrap()];
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
+            .unwrap();
+        // Execute the request.
+        let (_, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        // Prepare the trace.
+        trace.prepare(Query::from(&block_store)).unwrap();
+        // Prove the execution.
+        let execution = trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap();
+        // Verify the execution.
+        process.verify_execution(&execution).unwrap();
+        // Finalize the execution.
+        process.finalize_execution(sample_finalize_state(1), &finalize_store, &execution, None).unwrap();
+    }
+
+    // Check that every iteration, except the skipped one, updated the mapping.
+    for (key, expected) in [("0u32", Some("10u64")), ("1u32", Some("10u64")), ("2u32", None), ("3u32", Some("10u64"))] {
+        let candidate = finalize_store
+            .get_value_speculative(*program_id, mapping_name, &Plaintext::from_str(key).unwrap())
+            .unwrap();
+        assert_eq!(candidate, expected.map(|value| Value::from_str(value).unwrap()));
+    }
+
+    // Ensure a loop that exceeds the maximum number of writes is rejected.
+    let program = Program::<CurrentNetwork>::from_str(&format!(
+        r"
+program testing_writes.aleo;
+
+mapping balances:
+    key as u32.public;
+    value as u64.public;
+
+function payout:
+    async payout into r0;
+    output r0 as testing_writes.aleo/payout.future;
+
+finalize payout:
+    iter 0u32..{}u32 into r0 until done;
+    set 1u64 into balances[r0];
+    position done;
+",
+        CurrentNetwork::MAX_WRITES + 1
+    ))
+    .unwrap();
+    assert!(process.deploy::<CurrentAleo, _>(&program, rng).is_err());
+
+    // Ensure a branch out of a loop is rejected.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program testing_branch.aleo;
+
+function payout:
+    async payout into r0;
+    output r0 as testing_branch.aleo/payout.future;
+
+finalize payout:
+    iter 0u32..4u32 into r0 until done;
+    branch.eq r0 2u32 to exit;
+    position done;
+    position exit;
+",
+    )
+    .unwrap();
+    assert!(process.deploy::<CurrentAleo, _>(&program, rng).is_err());
+}
+
+#[test]
+fn test_process_execute_and_finalize_call_dynamic() {
+    // Initialize the callee program.
+    let callee = Program::<CurrentNetwork>::from_str(
+        r"
+program hooks.aleo;
+
+mapping totals:
+    key as u8.public;
+    value as u64.public;
+
+function hook:
+    input r0 as u64.public;
+    async hook r0 into r1;
+    output r1 as hooks.aleo/hook.future;
+
+finalize hook:
+    input r0 as u64.public;
+    get.or_use totals[0u8] 0u64 into r1;
+    add r1 r0 into r2;
+    set r2 into totals[0u8];
+
+function hook_private:
+    input r0 as u64.private;
+    async hook_private r0 into r1;
+    output r1 as hooks.aleo/hook_private.future;
+
+finalize hook_private:
+    input r0 as u64.public;
+    set r0 into totals[1u8];
+
+function hook_expensive:
+    input r0 as u128.public;
+    async hook_expensive r0 into r1;
+    output r1 as hooks.aleo/hook_expensive.future;
+
+finalize hook_expensive:
+    input r0 as u128.public;
+    cast r0 r0 r0 r0 r0 r0 r0 r0 r0 r0 r0 r0 r0 r0 r0 r0 into r1 as [u128; 16u32];
+    cast r1 r1 r1 r1 r1 r1 r1 r1 r1 r1 r1 r1 r1 r1 r1 r1 into r2 as [[u128; 16u32]; 16u32];
+    hash.bhp256 r2 into r3 as field;
+    hash.bhp256 r2 into r4 as field;
+    hash.bhp256 r2 into r5 as field;
+",
+    )
+    .unwrap();
+
+    // Initialize the caller program, which reads the callee program ID from a mapping.
+    let caller = Program::<CurrentNetwork>::from_str(
+        r#"
+program router.aleo;
+
+mapping targets:
+    key as u8.public;
+    value as string.public;
+
+function register:
+    async register into r0;
+    output r0 as router.aleo/register.future;
+
+finalize register:
+    set "hooks.aleo" into targets[0u8];
+    set "router.aleo" into targets[1u8];
+
+function route:
+    input r0 as u8.public;
+    input r1 as u64.public;
+    async route r0 r1 into r2;
+    output r2 as router.aleo/route.future;
+
+finalize route:
+    input r0 as u8.public;
+    input r1 as u64.public;
+    get targets[r0] into r2;
+    call.dynamic r2 hook r1;
+
+function route_private:
+    input r0 as u64.public;
+    async route_private r0 into r1;
+    output r1 as router.aleo/route_private.future;
+
+finalize route_private:
+    input r0 as u64.public;
+    call.dynamic "hooks.aleo" hook_private r0;
+
+function route_expensive:
+    input r0 as u128.public;
+    async route_expensive r0 into r1;
+    output r1 as router.aleo/route_expensive.future;
+
+finalize route_expensive:
+    input r0 as u128.public;
+    call.dynamic "hooks.aleo" hook_expensive r0;
+
+function hook:
+    input r0 as u64.public;
+    async hook r0 into r1;
+    output r1 as router.aleo/hook.future;
+
+finalize hook:
+    input r0 as u64.public;
+    set "hooks.aleo" into targets[2u8];
+"#,
+    )
+    .unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let mut process = Process::load().unwrap();
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Initialize a new finalize store.
+    let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+
+    // Deploy the programs.
+    for program in [&callee, &caller] {
+        let deployment = process.deploy::<CurrentAleo, _>(program, rng).unwrap();
+        process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+        let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
+        let (stack, _) =
+            process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
+        process.add_stack(stack);
+    }
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // A helper to execute and finalize a function in the caller program.
+    let mut execute = |process: &Process<CurrentNetwork>, function_name: &str, inputs: &[&str]| {
+        let inputs = inputs.iter().map(|input| Value::<CurrentNetwork>::from_str(input).unwrap());
+        let authorization =
+            process.authorize::<CurrentAleo, _>(&caller_private_key, caller.id(), function_name, inputs, rng).unwrap();
+        let (_, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        trace.prepare(Query::from(&block_store)).unwrap();
+        let execution = trace.prove_execution::<CurrentAleo, _>("router", rng).unwrap();
+        process.verify_execution(&execution).unwrap();
+        process.finalize_execution(sample_finalize_state(1), &finalize_store, &execution, None)
+    };
+
+    // Register the targets.
+    execute(&process, "register", &[]).unwrap();
+    // Call the callee twice.
+    execute(&process, "route", &["0u8", "3u64"]).unwrap();
+    execute(&process, "route", &["0u8", "4u64"]).unwrap();
+    // Ensure a reentrant call is rejected.
+    assert!(execute(&process, "route", &["1u8", "5u64"]).is_err());
+    // Ensure a call to a function that is not finalize-only is rejected.
+    assert!(execute(&process, "route_private", &["6u64"]).is_err());
+    // Ensure a call to a function whose finalize cost exceeds the prepaid callee cost is rejected.
+    let cost_model = CostModel::for_network::<CurrentNetwork>();
+    let hook_expensive = Identifier::from_str("hook_expensive").unwrap();
+    let hook_expensive_cost =
+        cost_model.finalize_cost(process.get_stack(callee.id()).unwrap(), &hook_expensive).unwrap();
+    assert!(hook_expensive_cost > cost_model.call_dynamic_callee);
+    assert!(execute(&process, "route_expensive", &["7u128"]).is_err());
+
+    // Check that only the calls to the finalize-only function updated the callee mapping.
+    let mapping_name = Identifier::from_str("totals").unwrap();
+    for (key, expected) in [("0u8", Some("7u64")), ("1u8", None)] {
+        let candidate = finalize_store
+            .get_value_speculative(*callee.id(), mapping_name, &Plaintext::from_str(key).unwrap())
+            .unwrap();
+        assert_eq!(candidate, expected.map(|value| Value::from_str(value).unwrap()));
+    }
+
+    // Ensure a call to a deprecated program is rejected.
+    process.mark_deprecated(*callee.id());
+    assert!(execute(&process, "route", &["0u8", "8u64"]).is_err());
+}
+
+#[test]
+fn test_process_execute_and_finalize_sign_verify_external() {
+    use console::algorithms::{Ed25519, Secp256k1};
+
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program testing.aleo;
+
+mapping results:
+    key as u8.public;
+    value as boolean.public;
+
+function check_ecdsa:
+    input r0 as [[u8; 32u32]; 2u32].public;
+    input r1 as [[u8; 11u32]; 3u32].public;
+    input r2 as [u8; 32u32].public;
+    async check_ecdsa r0 r1 r2 into r3;
+    output r3 as testing.aleo/check_ecdsa.future;
+
+finalize check_ecdsa:
+    input r0 as [[u8; 32u32]; 2u32].public;
+    input r1 as [[u8; 11u32]; 3u32].public;
+    input r2 as [u8; 32u32].public;
+    ecdsa.verify r0 r1 r2 into r3;
+    set r3 into results[0u8];
+
+function check_ed25519:
+    input r0 as [[u8; 32u32]; 2u32].public;
+    input r1 as [u8; 32u32].public;
+    input r2 as [u8; 5u32].public;
+    async check_ed25519 r0 r1 r2 into r3;
+    output r3 as testing.aleo/check_ed25519.future;
+
+finalize check_ed25519:
+    input r0 as [[u8; 32u32]; 2u32].public;
+    input r1 as [u8; 32u32].public;
+    input r2 as [u8; 5u32].public;
+    ed25519.verify r0 r1 r2 into r3;
+    set r3 into results[1u8];
+",
+    )
+    .unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let mut process = Process::load().unwrap();
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Initialize a new finalize store.
+    let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+
+    // Add the program to the process.
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+    let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
+    let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
+    process.add_stack(stack);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Helpers to format bytes as a `u8` array, and as a nested `u8` array with rows of the given length.
+    let format_array = |bytes: &[u8]| {
+        let elements = bytes.iter().map(|byte| format!("{byte}u8")).collect::<Vec<_>>();
+        format!("[{}]", elements.join(", "))
+    };
+    let to_array = |bytes: &[u8]| Value::<CurrentNetwork>::from_str(&format_array(bytes)).unwrap();
+    let to_nested_array = |bytes: &[u8], length: usize| {
+        let rows = bytes.chunks(length).map(format_array).collect::<Vec<_>>();
+        Value::<CurrentNetwork>::from_str(&format!("[{}]", rows.join(", "))).unwrap()
+    };
+
+    // Sample the signatures, and tamper with the ECDSA signature.
+    let secret_key: [u8; 32] = rng.gen();
+    let digest: [u8; 32] = rng.gen();
+    let mut ecdsa_signature = Secp256k1::sign(&secret_key, &digest, rng).unwrap();
+    ecdsa_signature[63] ^= 1;
+    let public_key = Secp256k1::public_key(&secret_key).unwrap();
+    let ecdsa_inputs = [to_nested_array(&ecdsa_signature, 32), to_nested_array(&public_key, 11), to_array(&digest)];
+    let seed: [u8; 32] = rng.gen();
+    let message = b"hello";
+    let ed25519_signature = Ed25519::sign(&seed, message);
+    let ed25519_inputs =
+        [to_nested_array(&ed25519_signature, 32), to_array(&Ed25519::public_key(&seed)), to_array(message)];
+
+    // Execute and finalize both functions.
+    for (function_name, inputs) in [("check_ecdsa", ecdsa_inputs), ("check_ed25519", ed25519_inputs)] {
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
+            .unwrap();
+        let (_, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        trace.prepare(Query::from(&block_store)).unwrap();
+        let execution = trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap();
+        process.verify_execution(&execution).unwrap();
+        process.finalize_execution(sample_finalize_state(1), &finalize_store, &execution, None).unwrap();
+    }
+
+    // Check the tampered ECDSA signature is rejected, and the Ed25519 signature is accepted.
+    let mapping_name = Identifier::from_str("results").unwrap();
+    for (key, expected) in [("0u8", "false"), ("1u8", "true")] {
+        let candidate = finalize_store
+            .get_value_speculative(*program.id(), mapping_name, &Plaintext::from_str(key).unwrap())
+            .unwrap();
+        assert_eq!(candidate, Some(Value::from_str(expected).unwrap()));
+    }
+
+    // Ensure the instructions are rejected outside of finalize.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program testing_function.aleo;
+
+function check:
+    input r0 as [[u8; 32u32]; 2u32].public;
+    input r1 as [u8; 32u32].public;
+    input r2 as [u8; 5u32].public;
+    ed25519.verify r0 r1 r2 into r3;
+    output r3 as boolean.public;
+",
+    )
+    .unwrap();
+    assert!(process.deploy::<CurrentAleo, _>(&program, rng).is_err());
+}
+
+#[test]
+fn test_process_execute_and_finalize_hash_bytes() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program testing.aleo;
+
+mapping digests:
+    key as u8.public;
+    value as [u8; 32u32].public;
+
+function hash:
+    input r0 as [u8; 8u32].public;
+    input r1 as u32.public;
+    async hash r0 r1 into r2;
+    output r2 as testing.aleo/hash.future;
+
+finalize hash:
+    input r0 as [u8; 8u32].public;
+    input r1 as u32.public;
+    hash_bytes.keccak256 r0 r1 into r2;
+    set r2 into digests[0u8];
+    hash_bytes.sha3_256 r0 r1 into r3;
+    set r3 into digests[1u8];
+",
+    )
+    .unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let mut process = Process::load().unwrap();
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Initialize a new finalize store.
+    let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+
+    // Add the program to the process.
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+    let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
+    let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
+    process.add_stack(stack);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Hash the first 3 bytes of the payload, i.e. "abc".
+    let inputs = [
+        Value::<CurrentNetwork>::from_str("[97u8, 98u8, 99u8, 0u8, 0u8, 0u8, 0u8, 0u8]").unwrap(),
+        Value::<CurrentNetwork>::from_str("3u32").unwrap(),
+    ];
+    let authorization =
+        process.authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "hash", inputs.iter(), rng).unwrap();
+    let (_, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    trace.prepare(Query::from(&block_store)).unwrap();
+    let execution = trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap();
+    process.verify_execution(&execution).unwrap();
+    process.finalize_execution(sample_finalize_state(1), &finalize_store, &execution, None).unwrap();
+
+    // Check the digests match the standard Keccak256 and SHA3-256 test vectors for "abc".
+    let mapping_name = Identifier::from_str("digests").unwrap();
+    for (key, expected) in [
+        ("0u8", "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45"),
+        ("1u8", "3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532"),
+    ] {
+        let expected = (0..expected.len())
+            .step_by(2)
+            .map(|i| format!("{}u8", u8::from_str_radix(&expected[i..i + 2], 16).unwrap()))
+            .collect::<Vec<_>>();
+        let expected = Value::from_str(&format!("[{}]", expected.join(", "))).unwrap();
+        let candidate = finalize_store
+            .get_value_speculative(*program.id(), mapping_name, &Plaintext::from_str(key).unwrap())
+            .unwrap();
+        assert_eq!(candidate, Some(expected));
+    }
+}
+
+#[test]
+fn test_process_execute_and_finalize_block_context() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program testing.aleo;
+
+mapping timestamps:
+    key as u8.public;
+    value as i64.public;
+
+mapping networks:
+    key as u8.public;
+    value as u16.public;
+
+mapping rounds:
+    key as u8.public;
+    value as u64.public;
+
+function run:
+    async run into r0;
+    output r0 as testing.aleo/run.future;
+
+finalize run:
+    set block.timestamp into timestamps[0u8];
+    set network.id into networks[0u8];
+    set block.round into rounds[0u8];
+",
+    )
+    .unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let mut process = Process::load().unwrap();
+
+    // Ensure the block context and network ID are not valid operands in a function scope.
+    for operand in ["block.timestamp", "block.round", "network.id"] {
+        let invalid = Program::<CurrentNetwork>::from_str(&format!(
+            "program invalid.aleo; function run: assert.eq {operand} {operand};"
+        ))
+        .unwrap();
+        assert!(process.add_program(&invalid).is_err());
+    }
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Initialize a new finalize store.
+    let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+
+    // Add the program to the process.
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+    let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
+    let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
+    process.add_stack(stack);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Execute and finalize the function.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "run", Vec::<Value<_>>::new().iter(), rng)
+        .unwrap();
+    let (_, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    trace.prepare(Query::from(&block_store)).unwrap();
+    let execution = trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap();
+    process.verify_execution(&execution).unwrap();
+    let state = sample_finalize_state(1);
+    process.finalize_execution(state, &finalize_store, &execution, None).unwrap();
+
+    // Check the block timestamp, network ID, and block round are stored.
+    let key = Plaintext::from_str("0u8").unwrap();
+    let timestamp =
+        finalize_store.get_value_speculative(*program.id(), Identifier::from_str("timestamps").unwrap(), &key).unwrap();
+    assert_eq!(timestamp, Some(Value::from_str(&format!("{}i64", state.block_timestamp())).unwrap()));
+    let network_id =
+        finalize_store.get_value_speculative(*program.id(), Identifier::from_str("networks").unwrap(), &key).unwrap();
+    assert_eq!(network_id, Some(Value::from_str(&format!("{}u16", CurrentNetwork::ID)).unwrap()));
+    let round =
+        finalize_store.get_value_speculative(*program.id(), Identifier::from_str("rounds").unwrap(), &key).unwrap();
+    assert_eq!(round, Some(Value::from_str(&format!("{}u64", state.block_round())).unwrap()));
+}
+
+#[test]
+fn test_process_execute_and_finalize_block_randomness() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program testing.aleo;
+
+mapping randomness:
+    key as u8.public;
+    value as field.public;
+
+function run:
+    async run into r0;
+    output r0 as testing.aleo/run.future;
+
+finalize run:
+    set block.randomness into randomness[0u8];
+",
+    )
+    .unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let mut process = Process::load().unwrap();
+
+    // Ensure the block randomness is not a valid operand in a function scope.
+    let invalid = Program::<CurrentNetwork>::from_str(
+        "program invalid.aleo; function run: assert.eq block.randomness block.randomness;",
+    )
+    .unwrap();
+    assert!(process.add_program(&invalid).is_err());
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Initialize a new finalize store.
+    let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+
+    // Add the program to the process.
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+    let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
+    let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
+    process.add_stack(stack);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Execute the function.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "run", Vec::<Value<_>>::new().iter(), rng)
+        .unwrap();
+    let (_, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    trace.prepare(Query::from(&block_store)).unwrap();
+    let execution = trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap();
+    process.verify_execution(&execution).unwrap();
+
+    // Ensure finalize fails if the block does not contain a randomness ratification.
+    assert!(process.finalize_execution(sample_finalize_state(1), &finalize_store, &execution, None).is_err());
+
+    // Finalize the execution with the block randomness.
+    let randomness = Field::<CurrentNetwork>::rand(rng);
+    let state = sample_finalize_state(1).with_block_randomness(Some(randomness)).unwrap();
+    process.finalize_execution(state, &finalize_store, &execution, None).unwrap();
+
+    // Check the block randomness is stored.
+    let key = Plaintext::from_str("0u8").unwrap();
+    let stored =
+        finalize_store.get_value_speculative(*program.id(), Identifier::from_str("randomness").unwrap(), &key).unwrap();
+    assert_eq!(stored, Some(Value::from(Literal::Field(randomness))));
+}
+
+#[test]
+fn test_process_execute_and_finalize_emit() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program testing.aleo;
+
+struct transfer:
+    amount as u64;
+    height as u32;
+
+function run:
+    input r0 as u64.public;
+    async run r0 into r1;
+    output r1 as testing.aleo/run.future;
+
+finalize run:
+    input r0 as u64.public;
+    cast r0 block.height into r1 as transfer;
+    emit r1;
+    emit r0;
+",
+    )
+    .unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let mut process = Process::load().unwrap();
+
+    // Ensure a future cannot be emitted.
+    let invalid = Program::<CurrentNetwork>::from_str(
+        r"
+program invalid.aleo;
+
+function run:
+    async run into r0;
+    output r0 as invalid.aleo/run.future;
+
+finalize run:
+    emit r0;
+",
+    );
+    assert!(invalid.map_or(true, |invalid| process.add_program(&invalid).is_err()));
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Initialize a new finalize store.
+    let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+
+    // Add the program to the process.
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+    let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
+    let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
+    process.add_stack(stack);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Execute and finalize the function.
+    let inputs = [Value::<CurrentNetwork>::from_str("5u64").unwrap()];
+    let authorization =
+        process.authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "run", inputs.iter(), rng).unwrap();
+    let (_, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    trace.prepare(Query::from(&block_store)).unwrap();
+    let execution = trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap();
+    process.verify_execution(&execution).unwrap();
+    let operations = process.finalize_execution(sample_finalize_state(1), &finalize_store, &execution, None).unwrap();
+
+    // Check the events are recorded in order.
+    let program_id = CurrentNetwork::hash_bhp1024(&program.id().to_bits_le()).unwrap();
+    assert_eq!(operations, vec![
+        FinalizeOperation::Event(program_id, Plaintext::from_str("{ amount: 5u64, height: 1u32 }").unwrap()),
+        FinalizeOperation::Event(program_id, Plaintext::from_str("5u64").unwrap()),
+    ]);
+}
+
+#[test]
+fn test_process_analyze_futures_and_call_graph() {
+    // Initialize the process.
+    let mut process = Process::<CurrentNetwork>::load().unwrap();
+
+    // Initialize a program that misuses its futures.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+import credits.aleo;
+
+program relay.aleo;
+
+function relay:
+    input r0 as address.public;
+    call credits.aleo/transfer_public r0 1u64 into r1;
+    call credits.aleo/transfer_public r0 2u64 into r2;
+    call credits.aleo/transfer_public r0 3u64 into r3;
+    async relay r2 r1 into r4;
+    output r4 as relay.aleo/relay.future;
+
+finalize relay:
+    input r0 as credits.aleo/transfer_public.future;
+    input r1 as credits.aleo/transfer_public.future;
+    await r1;
+",
+    )
+    .unwrap();
+
+    // Ensure every issue is reported.
+    let report = process.analyze_futures(&program).unwrap();
+    assert!(!report.is_valid());
+    let function = Locator::from_str("relay.aleo/relay").unwrap();
+    let future = Locator::from_str("credits.aleo/transfer_public").unwrap();
+    let register = |register: &str| Register::from_str(register).unwrap();
+    assert_eq!(report.issues(), &[
+        FutureIssue::Unconsumed { function, register: register("r3"), future },
+        FutureIssue::ConsumedOutOfOrder { function, register: register("r2"), future },
+        FutureIssue::ConsumedOutOfOrder { function, register: register("r1"), future },
+        FutureIssue::Unawaited { function, register: register("r0"), future },
+    ]);
+    assert!(process.add_program(&program).is_err());
+
+    // Initialize a program that uses its futures correctly.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+import credits.aleo;
+
+program relay.aleo;
+
+function relay:
+    input r0 as address.public;
+    call credits.aleo/transfer_public r0 1u64 into r1;
+    call credits.aleo/transfer_public r0 2u64 into r2;
+    async relay r1 r2 into r3;
+    output r3 as relay.aleo/relay.future;
+
+finalize relay:
+    input r0 as credits.aleo/transfer_public.future;
+    input r1 as credits.aleo/transfer_public.future;
+    await r0;
+    await r1;
+",
+    )
+    .unwrap();
+    assert!(process.analyze_futures(&program).unwrap().is_valid());
+    process.add_program(&program).unwrap();
+
+    // Ensure the call graph includes the calls into the imported program.
+    let call_graph = process.call_graph(program.id()).unwrap();
+    assert_eq!(call_graph.calls().len(), 2);
+    assert_eq!(call_graph.callees(&function).unwrap().iter().collect::<Vec<_>>(), vec![&future]);
+    assert!(call_graph.callees(&future).unwrap().is_empty());
+    assert_eq!(call_graph.callers(&future), vec![&function]);
+}
+
+#[test]
+fn test_process_external_mapping_reads() {
+    // Initialize the process.
+    let mut process = Process::<CurrentNetwork>::load().unwrap();
+
+    // Ensure a finalize can read the mappings of an imported program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+import credits.aleo;
+
+program reader.aleo;
+
+function read:
+    input r0 as address.public;
+    async read r0 into r1;
+    output r1 as reader.aleo/read.future;
+
+finalize read:
+    input r0 as address.public;
+    contains credits.aleo/account[r0] into r1;
+    get.or_use credits.aleo/account[r0] 0u64 into r2;
+    branch.eq r1 false to end;
+    get credits.aleo/account[r0] into r3;
+    assert.eq r2 r3;
+    position end;
+",
+    )
+    .unwrap();
+    process.add_program(&program).unwrap();
+
+    // Ensure a finalize cannot read the mappings of a program it does not import.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program unimported.aleo;
+
+function read:
+    input r0 as address.public;
+    async read r0 into r1;
+    output r1 as unimported.aleo/read.future;
+
+finalize read:
+    input r0 as address.public;
+    contains credits.aleo/account[r0] into r1;
+",
+    )
+    .unwrap();
+    assert!(process.add_program(&program).is_err());
+
+    // Ensure a finalize cannot write to the mappings of an imported program.
+    for command in ["set 0u64 into credits.aleo/account[r0];", "remove credits.aleo/account[r0];"] {
+        let program = Program::<CurrentNetwork>::from_str(&format!(
+            r"
+import credits.aleo;
+
+program writer.aleo;
+
+function write:
+    input r0 as address.public;
+    async write r0 into r1;
+    output r1 as writer.aleo/write.future;
+
+finalize write:
+    input r0 as address.public;
+    {command}
+"
+        ));
+        assert!(program.is_err());
+    }
+}
+
+#[test]
+fn test_process_execute_and_verify_call_to_closure() {
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+program testing.aleo;
+
+// (a + (a + b)) + (a + b) == (3a + 2b)
+closure execute:
+    input r0 as field;
+    input r1 as field;
+    add r0 r1 into r2;
+    add r0 r2 into r3;
+    add r2 r3 into r4;
+    output r4 as field;
+    output r3 as field;
+    output r2 as field;
+
+closure check_not_equal:
+    input r0 as field;
+    input r1 as field;
+    assert.neq r0 r1;
+
+function compute:
+    input r0 as field.private;
+    input r1 as field.public;
+    call check_not_equal r0 r1;
+    call execute r0 r1 into r2 r3 r4;
+    output r2 as field.private;
+    output r3 as field.private;
+    output r4 as field.private;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Declare the function name.
+    let function_name = Identifier::from_str("compute").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+    // Check that the circuit key can be synthesized.
+    process.synthesize_key::<CurrentAleo, _>(program.id(), &function_name, rng).unwrap();
+
+    // Reset the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Declare the input value.
+    let r0 = Value::<CurrentNetwork>::from_str("3field").unwrap();
+    let r1 = Value::<CurrentNetwork>::from_str("5field").unwrap();
+
+    // Authorize the function call.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1].iter(), rng)
+        .unwrap();
+    assert_eq!(authorization.len(), 1);
+
+    let r2 = Value::from_str("19field").unwrap();
+    let r3 = Value::from_str("11field").unwrap();
     let r4 = Value::from_str("8field").unwrap();
 
     // Check again to make sure we didn't modify the authorization before calling `evaluate`.
@@ -2357,13 +4015,88 @@ function compute:
     process.verify_execution(&execution).unwrap();
 }
 
+#[test]
+fn test_process_verify_executions_batch() {
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+program testing.aleo;
+
+function compute:
+    input r0 as u32.private;
+    input r1 as u32.public;
+    add r0 r1 into r2;
+    output r2 as u32.private;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Declare the function name.
+    let function_name = Identifier::from_str("compute").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+
+    // Construct the executions.
+    let executions = (0..3u32)
+        .map(|i| {
+            // Declare the input values.
+            let r0 = Value::<CurrentNetwork>::from_str(&format!("{i}u32")).unwrap();
+            let r1 = Value::<CurrentNetwork>::from_str("5u32").unwrap();
+            // Authorize the function call.
+            let authorization = process
+                .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1].iter(), rng)
+                .unwrap();
+            // Execute the request.
+            let (_, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+            // Prepare the trace.
+            trace.prepare(Query::from(block_store.clone())).unwrap();
+            // Prove the execution.
+            trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    // Verify the executions individually.
+    for execution in &executions {
+        process.verify_execution(execution).unwrap();
+    }
+    // Verify the executions as a batch.
+    process.verify_executions_batch(&executions).unwrap();
+    // Verify an empty batch.
+    process.verify_executions_batch(&[]).unwrap();
+
+    // Construct an execution with a mismatched proof.
+    let invalid_execution = Execution::from(
+        executions[0].transitions().cloned(),
+        executions[0].global_state_root(),
+        executions[1].proof().cloned(),
+    )
+    .unwrap();
+    // Ensure the batch fails to verify.
+    assert!(process.verify_executions_batch(&[executions[2].clone(), invalid_execution]).is_err());
+}
+
 #[test]
 fn test_process_deploy_credits_program() {
     let rng = &mut TestRng::default();
 
     // Initialize an empty process without the `credits` program.
-    let empty_process =
-        Process { universal_srs: Arc::new(UniversalSRS::<CurrentNetwork>::load().unwrap()), stacks: IndexMap::new() };
+    let empty_process = Process {
+        universal_srs: Arc::new(UniversalSRS::<CurrentNetwork>::load().unwrap()),
+        stacks: IndexMap::new(),
+        finalize_debugger: None,
+        profiler: None,
+        max_call_depth: Process::<CurrentNetwork>::DEFAULT_MAX_CALL_DEPTH,
+    };
 
     // Construct the process.
     let process = Process::load().unwrap();
@@ -2611,3 +4344,66 @@ fn test_max_imports() {
     ));
     assert!(result.is_err());
 }
+
+#[test]
+fn test_process_disclosure() {
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = Process::<CurrentNetwork>::load().unwrap();
+
+    // Construct a credits record.
+    let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let view_key = ViewKey::try_from(&private_key).unwrap();
+    let address = Address::try_from(&private_key).unwrap();
+    let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(&format!(
+        "{{ owner: {address}.private, microcredits: 150u64.private, _nonce: {}.public }}",
+        Group::<CurrentNetwork>::rand(rng)
+    ))
+    .unwrap();
+    let program_id = ProgramID::from_str("credits.aleo").unwrap();
+    let record_name = Identifier::from_str("credits").unwrap();
+    let commitment = record.to_commitment(&program_id, &record_name).unwrap();
+
+    // Disclose that the record holds at least 100 microcredits.
+    let disclosure = Disclosure::new(
+        program_id,
+        record_name,
+        commitment,
+        Identifier::from_str("microcredits").unwrap(),
+        DisclosureOperator::Gte,
+        Literal::from_str("100u64").unwrap(),
+    )
+    .unwrap();
+    let proof = process.prove_disclosure::<CurrentAleo, _>(&disclosure, &view_key, &record, rng).unwrap();
+    // Ensure the proof verifies with only the commitment.
+    process.verify_disclosure::<CurrentAleo, _>(&disclosure, &proof, rng).unwrap();
+
+    // Ensure the proof does not verify against another value.
+    let other = Disclosure::new(
+        program_id,
+        record_name,
+        commitment,
+        Identifier::from_str("microcredits").unwrap(),
+        DisclosureOperator::Gte,
+        Literal::from_str("10u64").unwrap(),
+    )
+    .unwrap();
+    assert!(process.verify_disclosure::<CurrentAleo, _>(&other, &proof, rng).is_err());
+
+    // Ensure a disclosure that does not hold cannot be proven.
+    let invalid = Disclosure::new(
+        program_id,
+        record_name,
+        commitment,
+        Identifier::from_str("microcredits").unwrap(),
+        DisclosureOperator::Gte,
+        Literal::from_str("200u64").unwrap(),
+    )
+    .unwrap();
+    assert!(process.prove_disclosure::<CurrentAleo, _>(&invalid, &view_key, &record, rng).is_err());
+
+    // Ensure the disclosure cannot be proven with the view key of another account.
+    let other_view_key = ViewKey::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+    assert!(process.prove_disclosure::<CurrentAleo, _>(&disclosure, &other_view_key, &record, rng).is_err());
+}
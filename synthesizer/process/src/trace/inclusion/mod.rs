@@ -135,7 +135,7 @@ impl<N: Network> Inclusion<N> {
             // Iterate through the inputs.
             for input in transition.inputs() {
                 // Filter the inputs for records.
-                if let Input::Record(serial_number, _) = input {
+                if let Input::Record(serial_number, ..) = input {
                     // Add the public inputs to the batch verifier inputs.
                     let verifier_inputs =
                         vec![N::Field::one(), **global_state_root, *local_state_root, **serial_number];
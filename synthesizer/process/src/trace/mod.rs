@@ -27,8 +27,11 @@ use ledger_block::{Execution, Fee, Transition};
 use ledger_query::QueryTrait;
 use synthesizer_snark::{Proof, ProvingKey, VerifyingKey};
 
+use crate::Profiler;
+#[cfg(feature = "prover")]
+use crate::{profile_phase, ProfilePhase};
 use once_cell::sync::OnceCell;
-use std::collections::HashMap;
+use std::{collections::HashMap, sync::Arc};
 
 #[derive(Clone, Debug, Default)]
 pub struct Trace<N: Network> {
@@ -45,6 +48,8 @@ pub struct Trace<N: Network> {
     inclusion_assignments: OnceCell<Vec<InclusionAssignment<N>>>,
     /// A tracker for the global state root.
     global_state_root: OnceCell<N::StateRoot>,
+    /// The profiler, if the trace is profiled.
+    profiler: Option<Arc<dyn Profiler<N>>>,
 }
 
 impl<N: Network> Trace<N> {
@@ -57,6 +62,7 @@ impl<N: Network> Trace<N> {
             inclusion_assignments: OnceCell::new(),
             global_state_root: OnceCell::new(),
             call_metrics: Vec::new(),
+            profiler: None,
         }
     }
 
@@ -69,6 +75,11 @@ impl<N: Network> Trace<N> {
     pub fn call_metrics(&self) -> &[CallMetrics<N>] {
         &self.call_metrics
     }
+
+    /// Sets the profiler, which is notified of the time elapsed in proving the trace.
+    pub fn set_profiler(&mut self, profiler: Option<Arc<dyn Profiler<N>>>) {
+        self.profiler = profiler;
+    }
 }
 
 impl<N: Network> Trace<N> {
@@ -147,6 +158,7 @@ impl<N: Network> Trace<N> {
     }
 
     /// Returns a new execution with a proof, for the current inclusion assignments and global state root.
+    #[cfg(feature = "prover")]
     pub fn prove_execution<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
         &self,
         locator: &str,
@@ -168,13 +180,15 @@ impl<N: Network> Trace<N> {
         // Construct the proving tasks.
         let proving_tasks = self.transition_tasks.values().cloned().collect();
         // Compute the proof.
-        let (global_state_root, proof) =
-            Self::prove_batch::<A, R>(locator, proving_tasks, inclusion_assignments, *global_state_root, rng)?;
+        let (global_state_root, proof) = self.profile_prove(|| {
+            Self::prove_batch::<A, R>(locator, proving_tasks, inclusion_assignments, *global_state_root, rng)
+        })?;
         // Return the execution.
         Execution::from(self.transitions.iter().cloned(), global_state_root, Some(proof))
     }
 
     /// Returns a new fee with a proof, for the current inclusion assignment and global state root.
+    #[cfg(feature = "prover")]
     pub fn prove_fee<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(&self, rng: &mut R) -> Result<Fee<N>> {
         // Ensure this is a fee.
         let is_fee_public = self.is_fee_public();
@@ -196,13 +210,15 @@ impl<N: Network> Trace<N> {
         // Construct the proving tasks.
         let proving_tasks = self.transition_tasks.values().cloned().collect();
         // Compute the proof.
-        let (global_state_root, proof) = Self::prove_batch::<A, R>(
-            "credits.aleo/fee (private or public)",
-            proving_tasks,
-            inclusion_assignments,
-            *global_state_root,
-            rng,
-        )?;
+        let (global_state_root, proof) = self.profile_prove(|| {
+            Self::prove_batch::<A, R>(
+                "credits.aleo/fee (private or public)",
+                proving_tasks,
+                inclusion_assignments,
+                *global_state_root,
+                rng,
+            )
+        })?;
         // Return the fee.
         Ok(Fee::from_unchecked(fee_transition.clone(), global_state_root, Some(proof)))
     }
@@ -229,6 +245,36 @@ impl<N: Network> Trace<N> {
         }
     }
 
+    /// Checks the proofs for the given executions, with a single combined pairing check.
+    /// Each execution is given with the verifier inputs for its transitions.
+    /// Note: This does *not* check that the global state roots exist in the ledger.
+    #[allow(clippy::type_complexity)]
+    pub fn verify_execution_proofs(
+        executions: Vec<(Vec<(VerifyingKey<N>, Vec<Vec<N::Field>>)>, &Execution<N>)>,
+    ) -> Result<()> {
+        // Construct the batch verifier inputs for each execution.
+        let instances = executions
+            .into_iter()
+            .enumerate()
+            .map(|(index, (verifier_inputs, execution))| {
+                // Retrieve the global state root.
+                let global_state_root = execution.global_state_root();
+                // Ensure the global state root is not zero.
+                if global_state_root == N::StateRoot::default() {
+                    bail!("Inclusion expected the global state root in execution {index} to *not* be zero")
+                }
+                // Retrieve the proof.
+                let Some(proof) = execution.proof() else { bail!("Expected execution {index} to contain a proof") };
+                // Construct the batch verifier inputs.
+                let verifier_inputs =
+                    Self::to_batch_verifier_inputs(verifier_inputs, global_state_root, execution.transitions())?;
+                Ok((verifier_inputs, proof))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        // Verify the execution proofs.
+        VerifyingKey::verify_many(&instances).map_err(|e| anyhow!("Executions are invalid - {e}"))
+    }
+
     /// Checks the proof for the fee.
     /// Note: This does *not* check that the global state root exists in the ledger.
     pub fn verify_fee_proof(verifier_inputs: (VerifyingKey<N>, Vec<Vec<N::Field>>), fee: &Fee<N>) -> Result<()> {
@@ -255,7 +301,21 @@ impl<N: Network> Trace<N> {
 }
 
 impl<N: Network> Trace<N> {
+    /// Runs the given proving closure, and notifies the profiler (if any) of the time elapsed,
+    /// under the locator of the root transition.
+    #[cfg(feature = "prover")]
+    fn profile_prove<T>(&self, f: impl FnOnce() -> T) -> T {
+        match (&self.profiler, self.transitions.last()) {
+            (Some(profiler), Some(root)) => {
+                let locator = Locator::new(*root.program_id(), *root.function_name());
+                profile_phase(Some(profiler), &locator, ProfilePhase::Prove, f)
+            }
+            _ => f(),
+        }
+    }
+
     /// Returns the global state root and proof for the given assignments.
+    #[cfg(feature = "prover")]
     fn prove_batch<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
         locator: &str,
         mut proving_tasks: Vec<(ProvingKey<N>, Vec<Assignment<N::Field>>)>,
@@ -299,11 +359,24 @@ impl<N: Network> Trace<N> {
     /// Note: This does *not* check that the global state root exists in the ledger.
     fn verify_batch<'a>(
         locator: &str,
-        mut verifier_inputs: Vec<(VerifyingKey<N>, Vec<Vec<N::Field>>)>,
+        verifier_inputs: Vec<(VerifyingKey<N>, Vec<Vec<N::Field>>)>,
         global_state_root: N::StateRoot,
         transitions: impl ExactSizeIterator<Item = &'a Transition<N>>,
         proof: &Proof<N>,
     ) -> Result<()> {
+        // Construct the batch verifier inputs.
+        let verifier_inputs = Self::to_batch_verifier_inputs(verifier_inputs, global_state_root, transitions)?;
+        // Verify the proof.
+        VerifyingKey::verify_batch(locator, verifier_inputs, proof).map_err(|e| anyhow!("Failed to verify proof - {e}"))
+    }
+
+    /// Returns the given verifier inputs, with the inclusion verifier inputs for the given transitions.
+    #[allow(clippy::type_complexity)]
+    fn to_batch_verifier_inputs<'a>(
+        mut verifier_inputs: Vec<(VerifyingKey<N>, Vec<Vec<N::Field>>)>,
+        global_state_root: N::StateRoot,
+        transitions: impl ExactSizeIterator<Item = &'a Transition<N>>,
+    ) -> Result<Vec<(VerifyingKey<N>, Vec<Vec<N::Field>>)>> {
         // Construct the batch of inclusion verifier inputs.
         let batch_inclusion_inputs = Inclusion::prepare_verifier_inputs(global_state_root, transitions)?;
         // Insert the batch of inclusion verifier inputs to the verifier inputs.
@@ -313,7 +386,6 @@ impl<N: Network> Trace<N> {
             // Insert the inclusion verifier inputs.
             verifier_inputs.push((verifying_key, batch_inclusion_inputs));
         }
-        // Verify the proof.
-        VerifyingKey::verify_batch(locator, verifier_inputs, proof).map_err(|e| anyhow!("Failed to verify proof - {e}"))
+        Ok(verifier_inputs)
     }
 }
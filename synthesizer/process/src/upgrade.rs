@@ -0,0 +1,276 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Process<N> {
+    /// Returns a deployment for the given edition of an existing program.
+    #[cfg(feature = "prover")]
+    #[inline]
+    pub fn deploy_upgrade<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        program: &Program<N>,
+        edition: u16,
+        rng: &mut R,
+    ) -> Result<Deployment<N>> {
+        let timer = timer!("Process::deploy_upgrade");
+
+        // Ensure the edition is an upgrade.
+        ensure!(edition > N::EDITION, "An upgrade of '{}' must have an edition above {}", program.id(), N::EDITION);
+        // Ensure the upgrade is compatible with the existing program.
+        self.check_upgrade(program)?;
+        lap!(timer, "Check the upgrade");
+
+        // Compute the stack.
+        let stack = Stack::new_upgrade(self, program)?;
+        lap!(timer, "Compute the stack");
+
+        // Compute the deployment.
+        let deployment = stack.deploy::<A, R>(rng)?;
+        lap!(timer, "Construct the deployment");
+
+        finish!(timer);
+
+        // Return the deployment, with the given edition.
+        Deployment::new(edition, deployment.shared_program().clone(), deployment.verifying_keys().clone())
+    }
+
+    /// Checks that the given program is a compatible upgrade of the existing program with the same ID.
+    ///
+    /// An upgrade must preserve the interface of the existing program:
+    ///   1. Every mapping must exist with the same key and value types.
+    ///   2. Every struct and record must exist with the same definition.
+    ///   3. Every function must exist with the same input, output, and finalize input types.
+    ///
+    /// An upgrade may add new mappings, structs, records, closures, and functions,
+    /// and may change the logic of existing closures, functions, and finalize blocks.
+    /// Programs that are deprecated, or imported by another program, may not be upgraded.
+    pub fn check_upgrade(&self, program: &Program<N>) -> Result<()> {
+        // Retrieve the program ID.
+        let program_id = program.id();
+        // Ensure the program is not 'credits.aleo'.
+        ensure!(program_id != &ProgramID::from_str("credits.aleo")?, "Cannot upgrade 'credits.aleo'");
+        // Ensure the program is not deprecated.
+        ensure!(!self.is_deprecated(program_id), "Cannot upgrade '{program_id}', as it is deprecated");
+        // Retrieve the existing program.
+        let existing = self.get_program(program_id)?;
+        // Ensure the upgrade changes the program.
+        ensure!(program != existing, "The upgrade of '{program_id}' is identical to the existing program");
+
+        // Ensure the program is not imported by another program.
+        if let Some(stack) = self.stacks.values().find(|stack| stack.program().contains_import(program_id)) {
+            bail!("Cannot upgrade '{program_id}', as it is imported by '{}'", stack.program_id())
+        }
+
+        // Ensure the existing mappings are preserved.
+        for (name, mapping) in existing.mappings() {
+            match program.mappings().get(name) {
+                Some(candidate) => {
+                    ensure!(candidate == mapping, "The upgrade of '{program_id}' changes the types of mapping '{name}'")
+                }
+                None => bail!("The upgrade of '{program_id}' removes mapping '{name}'"),
+            }
+        }
+        // Ensure the existing structs are preserved.
+        for (name, struct_) in existing.structs() {
+            match program.structs().get(name) {
+                Some(candidate) => {
+                    ensure!(candidate == struct_, "The upgrade of '{program_id}' changes struct '{name}'")
+                }
+                None => bail!("The upgrade of '{program_id}' removes struct '{name}'"),
+            }
+        }
+        // Ensure the existing records are preserved.
+        for (name, record) in existing.records() {
+            match program.records().get(name) {
+                Some(candidate) => {
+                    ensure!(candidate == record, "The upgrade of '{program_id}' changes record '{name}'")
+                }
+                None => bail!("The upgrade of '{program_id}' removes record '{name}'"),
+            }
+        }
+        // Ensure the existing function interfaces are preserved.
+        for (name, function) in existing.functions() {
+            let Some(candidate) = program.functions().get(name) else {
+                bail!("The upgrade of '{program_id}' removes function '{name}'")
+            };
+            ensure!(
+                candidate.input_types() == function.input_types(),
+                "The upgrade of '{program_id}' changes the inputs of function '{name}'"
+            );
+            ensure!(
+                candidate.output_types() == function.output_types(),
+                "The upgrade of '{program_id}' changes the outputs of function '{name}'"
+            );
+            ensure!(
+                candidate.finalize_logic().map(|finalize| finalize.input_types())
+                    == function.finalize_logic().map(|finalize| finalize.input_types()),
+                "The upgrade of '{program_id}' changes the finalize inputs of function '{name}'"
+            );
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+    type CurrentAleo = circuit::network::AleoV0;
+
+    /// Returns a process with the given program added.
+    fn sample_process(program: &Program<CurrentNetwork>) -> Process<CurrentNetwork> {
+        let mut process = Process::load().unwrap();
+        process.add_program(program).unwrap();
+        process
+    }
+
+    #[test]
+    fn test_check_upgrade() {
+        // Initialize the existing program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program upgrade.aleo;
+
+mapping counter:
+    key as address.public;
+    value as u64.public;
+
+function compute:
+    input r0 as u32.private;
+    output r0 as u32.private;",
+        )
+        .unwrap();
+        let mut process = sample_process(&program);
+
+        // Ensure an identical program is rejected.
+        assert!(process.check_upgrade(&program).is_err());
+
+        // Ensure a compatible upgrade is accepted.
+        let upgrade = Program::<CurrentNetwork>::from_str(
+            r"
+program upgrade.aleo;
+
+mapping counter:
+    key as address.public;
+    value as u64.public;
+
+mapping totals:
+    key as u8.public;
+    value as u64.public;
+
+function compute:
+    input r0 as u32.private;
+    add r0 r0 into r1;
+    output r1 as u32.private;
+
+function extra:
+    input r0 as u8.public;
+    output r0 as u8.public;",
+        )
+        .unwrap();
+        process.check_upgrade(&upgrade).unwrap();
+        let compatible_upgrade = upgrade;
+
+        // Ensure an upgrade that changes a mapping is rejected.
+        let upgrade = Program::<CurrentNetwork>::from_str(
+            r"
+program upgrade.aleo;
+
+mapping counter:
+    key as address.public;
+    value as u128.public;
+
+function compute:
+    input r0 as u32.private;
+    output r0 as u32.private;",
+        )
+        .unwrap();
+        assert!(process.check_upgrade(&upgrade).is_err());
+
+        // Ensure an upgrade that removes a mapping is rejected.
+        let upgrade = Program::<CurrentNetwork>::from_str(
+            r"
+program upgrade.aleo;
+
+function compute:
+    input r0 as u32.private;
+    output r0 as u32.private;",
+        )
+        .unwrap();
+        assert!(process.check_upgrade(&upgrade).is_err());
+
+        // Ensure an upgrade that changes a function interface is rejected.
+        let upgrade = Program::<CurrentNetwork>::from_str(
+            r"
+program upgrade.aleo;
+
+mapping counter:
+    key as address.public;
+    value as u64.public;
+
+function compute:
+    input r0 as u32.public;
+    output r0 as u32.private;",
+        )
+        .unwrap();
+        assert!(process.check_upgrade(&upgrade).is_err());
+
+        // Ensure a compatible upgrade of a deprecated program is rejected.
+        process.mark_deprecated(*program.id());
+        assert!(process.check_upgrade(&compatible_upgrade).is_err());
+    }
+
+    #[test]
+    fn test_deploy_upgrade() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the existing program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program upgrade.aleo;
+
+function compute:
+    input r0 as u32.private;
+    output r0 as u32.private;",
+        )
+        .unwrap();
+        let process = sample_process(&program);
+
+        // Construct the upgrade.
+        let upgrade = Program::<CurrentNetwork>::from_str(
+            r"
+program upgrade.aleo;
+
+function compute:
+    input r0 as u32.private;
+    add r0 r0 into r1;
+    output r1 as u32.private;",
+        )
+        .unwrap();
+
+        // Ensure the upgrade must have an edition above the network edition.
+        assert!(process.deploy_upgrade::<CurrentAleo, _>(&upgrade, CurrentNetwork::EDITION, rng).is_err());
+
+        // Deploy the upgrade.
+        let deployment = process.deploy_upgrade::<CurrentAleo, _>(&upgrade, CurrentNetwork::EDITION + 1, rng).unwrap();
+        assert!(deployment.is_upgrade());
+        assert_eq!(deployment.program(), &upgrade);
+
+        // Ensure the upgrade verifies.
+        process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+    }
+}
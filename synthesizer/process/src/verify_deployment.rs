@@ -26,15 +26,29 @@ impl<N: Network> Process<N> {
 
         // Retrieve the program ID.
         let program_id = deployment.program().id();
-        // Ensure the program does not already exist in the process.
-        ensure!(!self.contains_program(program_id), "Program '{program_id}' already exists");
 
         // Ensure the program is well-formed, by computing the stack.
-        let stack = Stack::new(self, deployment.program())?;
+        let stack = match deployment.is_upgrade() {
+            // Ensure the upgrade is compatible with the existing program.
+            true => {
+                self.check_upgrade(deployment.program())?;
+                Stack::new_upgrade(self, deployment.shared_program())?
+            }
+            // Ensure the program does not already exist in the process.
+            false => {
+                ensure!(!self.contains_program(program_id), "Program '{program_id}' already exists");
+                Stack::new(self, deployment.shared_program())?
+            }
+        };
         lap!(timer, "Compute the stack");
 
         // Ensure the verifying keys are well-formed and the certificates are valid.
-        let verification = stack.verify_deployment::<A, R>(deployment, rng);
+        // Note: If the circuit environment halts, the halt is returned as an error.
+        let verification = catch_halt(|| stack.verify_deployment::<A, R>(deployment, rng)).map_err(|error| {
+            // Reset the circuit environment, as the verification may have halted midway.
+            A::reset();
+            error
+        });
         lap!(timer, "Verify the deployment");
 
         finish!(timer);
@@ -42,12 +56,84 @@ impl<N: Network> Process<N> {
     }
 }
 
+impl<N: Network> Process<N> {
+    /// Ensures the given program only accesses the `credits.aleo` mappings that are enabled at the given block height.
+    /// Note: The `credits.aleo/signer` mapping is enabled from `ConsensusVersion::V3`.
+    #[inline]
+    pub fn check_credits_mappings(&self, program: &Program<N>, block_height: u32) -> Result<()> {
+        // If the signer mapping is enabled, return early.
+        if N::consensus_version(block_height)? >= ConsensusVersion::V3 {
+            return Ok(());
+        }
+        // Construct the locator of the signer mapping.
+        let signer_mapping = Locator::from_str("credits.aleo/signer")?;
+        // Ensure no finalize scope reads the signer mapping.
+        for (function_name, function) in program.functions() {
+            let Some(finalize) = function.finalize_logic() else {
+                continue;
+            };
+            for command in finalize.commands() {
+                let mapping = match command {
+                    Command::Contains(contains) => contains.mapping(),
+                    Command::Get(get) => get.mapping(),
+                    Command::GetOrUse(get_or_use) => get_or_use.mapping(),
+                    _ => continue,
+                };
+                if let MappingLocator::Locator(locator) = mapping {
+                    ensure!(
+                        locator != &signer_mapping,
+                        "'{}/{function_name}' reads '{signer_mapping}', which is not enabled at block height {block_height}",
+                        program.id()
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    type CurrentNetwork = console::network::MainnetV0;
     type CurrentAleo = circuit::network::AleoV0;
 
+    #[test]
+    fn test_check_credits_mappings() {
+        let process = Process::<CurrentNetwork>::load().unwrap();
+
+        // Initialize a program that reads the signer mapping.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+import credits.aleo;
+
+program signer_reader.aleo;
+
+function read:
+    input r0 as address.public;
+    async read r0 into r1;
+    output r1 as signer_reader.aleo/read.future;
+
+finalize read:
+    input r0 as address.public;
+    contains credits.aleo/signer[r0] into r1;",
+        )
+        .unwrap();
+
+        // Ensure the program is rejected until the signer mapping is enabled.
+        let (_, height) = CurrentNetwork::CONSENSUS_VERSION_HEIGHTS[2];
+        assert!(process.check_credits_mappings(&program, height - 1).is_err());
+        assert!(process.check_credits_mappings(&program, height).is_ok());
+
+        // Ensure a program that does not read the signer mapping is accepted at any height.
+        let program = Program::<CurrentNetwork>::from_str(
+            &program.to_string().replace("credits.aleo/signer", "credits.aleo/account"),
+        )
+        .unwrap();
+        assert!(process.check_credits_mappings(&program, 0).is_ok());
+    }
+
     /// Use `cargo test profiler --features timer` to run this test.
     #[ignore]
     #[test]
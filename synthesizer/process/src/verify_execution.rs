@@ -21,6 +21,60 @@ impl<N: Network> Process<N> {
     pub fn verify_execution(&self, execution: &Execution<N>) -> Result<()> {
         let timer = timer!("Process::verify_execution");
 
+        // Construct the verifier inputs for the execution.
+        let (locator, verifier_inputs) = catch_halt(|| self.to_execution_verifier_inputs(execution))?;
+        lap!(timer, "Construct the verifier inputs");
+
+        // Verify the execution proof.
+        catch_halt(|| Trace::verify_execution_proof(&locator, verifier_inputs, execution))?;
+        lap!(timer, "Verify the proof");
+
+        finish!(timer);
+        Ok(())
+    }
+
+    /// Verifies the given executions are valid.
+    /// The final pairing checks of the execution proofs are combined with random coefficients,
+    /// so that all of the proofs are checked with a single product of pairings.
+    /// Note: This does *not* check that the global state roots exist in the ledger.
+    #[inline]
+    pub fn verify_executions_batch(&self, executions: &[Execution<N>]) -> Result<()> {
+        let timer = timer!("Process::verify_executions_batch");
+
+        // An empty batch is trivially valid.
+        if executions.is_empty() {
+            finish!(timer);
+            return Ok(());
+        }
+        // Construct the verifier inputs for each execution.
+        let verifier_inputs = executions
+            .iter()
+            .enumerate()
+            .map(|(index, execution)| {
+                catch_halt(|| self.to_execution_verifier_inputs(execution))
+                    .map(|(_, verifier_inputs)| (verifier_inputs, execution))
+                    .map_err(|e| anyhow!("Execution {index} is invalid - {e}"))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        lap!(timer, "Construct the verifier inputs for {} executions", executions.len());
+
+        // Verify the execution proofs.
+        catch_halt(|| Trace::verify_execution_proofs(verifier_inputs))?;
+        lap!(timer, "Verify the proofs");
+
+        finish!(timer);
+        Ok(())
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Returns the locator of the main function and the verifier inputs for the given execution.
+    fn to_execution_verifier_inputs(
+        &self,
+        execution: &Execution<N>,
+    ) -> Result<(String, Vec<(VerifyingKey<N>, Vec<Vec<N::Field>>)>)> {
+        let timer = timer!("Process::to_execution_verifier_inputs");
+
         // Ensure the execution contains transitions.
         ensure!(!execution.is_empty(), "There are no transitions in the execution");
 
@@ -142,15 +196,9 @@ impl<N: Network> Process<N> {
             })
         })?;
 
-        // Construct the list of verifier inputs.
-        let verifier_inputs: Vec<_> = verifier_inputs.values().cloned().collect();
-        // Verify the execution proof.
-        Trace::verify_execution_proof(&locator, verifier_inputs, execution)?;
-
-        lap!(timer, "Verify the proof");
-
         finish!(timer);
-        Ok(())
+        // Return the locator and the list of verifier inputs.
+        Ok((locator, verifier_inputs.into_values().collect()))
     }
 }
 
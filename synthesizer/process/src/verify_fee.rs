@@ -62,10 +62,11 @@ impl<N: Network> Process<N> {
         lap!(timer, "Verify the deployment or execution ID");
 
         // Verify the fee transition is well-formed.
-        match is_fee_private {
-            true => self.verify_fee_private(&fee)?,
-            false => self.verify_fee_public(&fee)?,
-        }
+        // Note: If the verification halts, the halt is returned as an error.
+        catch_halt(|| match is_fee_private {
+            true => self.verify_fee_private(&fee),
+            false => self.verify_fee_public(&fee),
+        })?;
         finish!(timer, "Verify the fee transition");
         Ok(())
     }
@@ -228,6 +229,8 @@ mod tests {
             ledger_test_helpers::sample_execution_transaction_with_fee(false, rng),
             ledger_test_helpers::sample_fee_private_transaction(rng),
             ledger_test_helpers::sample_fee_public_transaction(rng),
+            ledger_test_helpers::sample_deprecation_transaction(true, rng),
+            ledger_test_helpers::sample_deprecation_transaction(false, rng),
         ];
 
         // Construct a new process.
@@ -251,6 +254,12 @@ mod tests {
                     true => process.verify_fee_private(&&fee).unwrap(),
                     false => process.verify_fee_public(&&fee).unwrap(),
                 },
+                Transaction::Deprecate(_, _, program_id, mapping_policy, fee) => {
+                    // Compute the deprecation ID.
+                    let deprecation_id = Transaction::deprecation_id(&program_id, mapping_policy).unwrap();
+                    // Verify the fee.
+                    process.verify_fee(&fee, deprecation_id).unwrap();
+                }
             }
         }
     }
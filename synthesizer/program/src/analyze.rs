@@ -0,0 +1,314 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{Command, CommandTrait, Instruction, InstructionTrait, Operand, Program};
+use console::{
+    network::prelude::*,
+    program::{Identifier, Literal, Register},
+};
+
+use std::collections::HashSet;
+
+/// The scope of a statement in a program, as reported by `Program::analyze`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AnalysisScope<N: Network> {
+    /// The closure with the given name.
+    Closure(Identifier<N>),
+    /// The function with the given name.
+    Function(Identifier<N>),
+    /// The finalize scope of the function with the given name.
+    Finalize(Identifier<N>),
+}
+
+impl<N: Network> Display for AnalysisScope<N> {
+    /// Prints the scope as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Closure(name) => write!(f, "closure '{name}'"),
+            Self::Function(name) => write!(f, "function '{name}'"),
+            Self::Finalize(name) => write!(f, "finalize '{name}'"),
+        }
+    }
+}
+
+/// The location of a statement in a program.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SourceLocation<N: Network> {
+    /// The input at the given index of the scope.
+    Input(AnalysisScope<N>, usize),
+    /// The instruction or command at the given index of the scope.
+    Statement(AnalysisScope<N>, usize),
+}
+
+impl<N: Network> Display for SourceLocation<N> {
+    /// Prints the location as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Input(scope, index) => write!(f, "{scope}, input {index}"),
+            Self::Statement(scope @ AnalysisScope::Finalize(..), index) => write!(f, "{scope}, command {index}"),
+            Self::Statement(scope, index) => write!(f, "{scope}, instruction {index}"),
+        }
+    }
+}
+
+/// A warning found by `Program::analyze`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AnalysisWarning<N: Network> {
+    /// A register is assigned, but never read.
+    UnusedRegister { location: SourceLocation<N>, register: Register<N> },
+    /// An input is never read by an instruction or command, so it is not used in any constraint.
+    UnusedInput { location: SourceLocation<N>, register: Register<N> },
+    /// A `ternary` has a constant condition, so one of its branches is never selected.
+    DeadBranch { location: SourceLocation<N>, branch: Operand<N> },
+}
+
+impl<N: Network> AnalysisWarning<N> {
+    /// Returns the location of the warning.
+    pub const fn location(&self) -> &SourceLocation<N> {
+        match self {
+            Self::UnusedRegister { location, .. } => location,
+            Self::UnusedInput { location, .. } => location,
+            Self::DeadBranch { location, .. } => location,
+        }
+    }
+}
+
+impl<N: Network> Display for AnalysisWarning<N> {
+    /// Prints the warning as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::UnusedRegister { location, register } => {
+                write!(f, "{location}: '{register}' is assigned, but never used")
+            }
+            Self::UnusedInput { location, register } => {
+                write!(f, "{location}: '{register}' is never used by an instruction")
+            }
+            Self::DeadBranch { location, branch } => {
+                write!(f, "{location}: the branch '{branch}' is never selected, as the condition is constant")
+            }
+        }
+    }
+}
+
+impl<N: Network> Program<N> {
+    /// Returns the warnings found by statically analyzing the closures, functions, and finalize scopes of the program.
+    /// The warnings do not affect the validity of the program.
+    pub fn analyze(&self) -> Vec<AnalysisWarning<N>> {
+        // Initialize the warnings.
+        let mut warnings = Vec::new();
+
+        for closure in self.closures().values() {
+            let scope = AnalysisScope::Closure(*closure.name());
+            let inputs = closure.inputs().iter().map(|input| input.register().clone()).collect::<Vec<_>>();
+            let outputs = closure.outputs().iter().map(|output| output.operand().clone()).collect::<Vec<_>>();
+            let statements = closure.instructions().iter().map(Statement::Instruction).collect::<Vec<_>>();
+            Self::analyze_scope(scope, &inputs, &statements, &outputs, &mut warnings);
+        }
+
+        for function in self.functions().values() {
+            let scope = AnalysisScope::Function(*function.name());
+            let inputs = function.inputs().iter().map(|input| input.register().clone()).collect::<Vec<_>>();
+            let outputs = function.outputs().iter().map(|output| output.operand().clone()).collect::<Vec<_>>();
+            let statements = function.instructions().iter().map(Statement::Instruction).collect::<Vec<_>>();
+            Self::analyze_scope(scope, &inputs, &statements, &outputs, &mut warnings);
+
+            if let Some(finalize) = function.finalize_logic() {
+                let scope = AnalysisScope::Finalize(*finalize.name());
+                let inputs = finalize.inputs().iter().map(|input| input.register().clone()).collect::<Vec<_>>();
+                let statements = finalize.commands().iter().map(Statement::Command).collect::<Vec<_>>();
+                Self::analyze_scope(scope, &inputs, &statements, &[], &mut warnings);
+            }
+        }
+        warnings
+    }
+
+    /// Analyzes the given scope, appending the warnings found to `warnings`.
+    fn analyze_scope(
+        scope: AnalysisScope<N>,
+        inputs: &[Register<N>],
+        statements: &[Statement<N>],
+        outputs: &[Operand<N>],
+        warnings: &mut Vec<AnalysisWarning<N>>,
+    ) {
+        // Collect the locators of the registers read by the statements.
+        let read_by_statements = statements
+            .iter()
+            .flat_map(|statement| statement.reads())
+            .map(|register| register.locator())
+            .collect::<HashSet<_>>();
+        // Collect the locators of the registers read by the outputs.
+        let read_by_outputs = outputs
+            .iter()
+            .filter_map(|operand| match operand {
+                Operand::Register(register) => Some(register.locator()),
+                _ => None,
+            })
+            .collect::<HashSet<_>>();
+
+        // Report the inputs that are never read by a statement.
+        for (index, register) in inputs.iter().enumerate() {
+            if !read_by_statements.contains(&register.locator()) {
+                let location = SourceLocation::Input(scope, index);
+                warnings.push(AnalysisWarning::UnusedInput { location, register: register.clone() });
+            }
+        }
+
+        for (index, statement) in statements.iter().enumerate() {
+            let location = SourceLocation::Statement(scope, index);
+            // Report the destinations that are never read.
+            for register in statement.destinations() {
+                let locator = register.locator();
+                if !read_by_statements.contains(&locator) && !read_by_outputs.contains(&locator) {
+                    warnings.push(AnalysisWarning::UnusedRegister { location, register });
+                }
+            }
+            // Report the branch of a `ternary` that is never selected.
+            if let Some(Instruction::Ternary(ternary)) = statement.instruction() {
+                if let [Operand::Literal(Literal::Boolean(condition)), first, second] = ternary.operands() {
+                    let branch = if **condition { second.clone() } else { first.clone() };
+                    warnings.push(AnalysisWarning::DeadBranch { location, branch });
+                }
+            }
+        }
+    }
+}
+
+/// An instruction or command in a scope.
+enum Statement<'a, N: Network> {
+    Instruction(&'a Instruction<N>),
+    Command(&'a Command<N>),
+}
+
+impl<'a, N: Network> Statement<'a, N> {
+    /// Returns the instruction of the statement, if it is one.
+    fn instruction(&self) -> Option<&'a Instruction<N>> {
+        match self {
+            Self::Instruction(instruction) => Some(instruction),
+            Self::Command(Command::Instruction(instruction)) => Some(instruction),
+            Self::Command(..) => None,
+        }
+    }
+
+    /// Returns the destination registers of the statement.
+    fn destinations(&self) -> Vec<Register<N>> {
+        match self {
+            Self::Instruction(instruction) => instruction.destinations(),
+            // The destination of `iter` is the loop index, which is not required to be read.
+            Self::Command(Command::Iter(..)) => vec![],
+            Self::Command(command) => command.destinations(),
+        }
+    }
+
+    /// Returns the registers read by the statement.
+    fn reads(&self) -> Vec<Register<N>> {
+        let operands = match self {
+            Self::Instruction(instruction) => instruction.operands().to_vec(),
+            Self::Command(command) => match command {
+                Command::Instruction(instruction) => instruction.operands().to_vec(),
+                Command::Await(await_) => vec![Operand::Register(await_.register().clone())],
+                Command::Contains(contains) => contains.operands(),
+                Command::Get(get) => get.operands(),
+                Command::GetOrUse(get_or_use) => get_or_use.operands(),
+                Command::RandChaCha(rand_chacha) => rand_chacha.operands(),
+                Command::Remove(remove) => remove.operands(),
+                Command::Set(set) => set.operands(),
+                Command::BranchEq(branch) => vec![branch.first().clone(), branch.second().clone()],
+                Command::BranchNeq(branch) => vec![branch.first().clone(), branch.second().clone()],
+                Command::ArrayGet(array_get) => array_get.operands(),
+                Command::ArraySet(array_set) => array_set.operands(),
+                Command::CallDynamic(call_dynamic) => {
+                    std::iter::once(call_dynamic.program().clone()).chain(call_dynamic.operands().to_vec()).collect()
+                }
+                Command::Emit(emit) => emit.operands(),
+                Command::Position(..) | Command::Iter(..) => vec![],
+            },
+        };
+        operands
+            .into_iter()
+            .filter_map(|operand| match operand {
+                Operand::Register(register) => Some(register),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_analyze() -> Result<()> {
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program analyze.aleo;
+
+mapping counts:
+    key as address.public;
+    value as u64.public;
+
+closure clean:
+    input r0 as u64;
+    add r0 r0 into r1;
+    output r1 as u64;
+
+function noisy:
+    input r0 as u64.public;
+    input r1 as u64.public;
+    input r2 as u64.public;
+    add r0 1u64 into r3;
+    mul r0 2u64 into r4;
+    ternary true r3 r0 into r5;
+    async noisy self.caller r5 into r6;
+    output r5 as u64.public;
+    output r2 as u64.public;
+    output r6 as analyze.aleo/noisy.future;
+
+finalize noisy:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get.or_use counts[r0] 0u64 into r2;
+    set r2 into counts[r0];
+",
+        )?;
+
+        let scope = AnalysisScope::Function(Identifier::from_str("noisy")?);
+        let finalize = AnalysisScope::Finalize(Identifier::from_str("noisy")?);
+        let expected = vec![
+            AnalysisWarning::UnusedInput { location: SourceLocation::Input(scope, 1), register: Register::Locator(1) },
+            AnalysisWarning::UnusedInput { location: SourceLocation::Input(scope, 2), register: Register::Locator(2) },
+            AnalysisWarning::UnusedRegister {
+                location: SourceLocation::Statement(scope, 1),
+                register: Register::Locator(4),
+            },
+            AnalysisWarning::DeadBranch {
+                location: SourceLocation::Statement(scope, 2),
+                branch: Operand::Register(Register::Locator(0)),
+            },
+            AnalysisWarning::UnusedInput {
+                location: SourceLocation::Input(finalize, 1),
+                register: Register::Locator(1),
+            },
+        ];
+        assert_eq!(program.analyze(), expected);
+
+        // Ensure the warnings are printed with their locations.
+        assert_eq!(expected[2].to_string(), "function 'noisy', instruction 1: 'r4' is assigned, but never used");
+        assert_eq!(expected[4].to_string(), "finalize 'noisy', input 1: 'r1' is never used by an instruction");
+        Ok(())
+    }
+}
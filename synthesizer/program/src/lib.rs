@@ -21,6 +21,9 @@ pub type Function<N> = crate::FunctionCore<N, Instruction<N>, Command<N>>;
 pub type Finalize<N> = crate::FinalizeCore<N, Command<N>>;
 pub type Closure<N> = crate::ClosureCore<N, Instruction<N>>;
 
+mod analyze;
+pub use analyze::*;
+
 mod closure;
 pub use closure::*;
 
@@ -84,7 +87,7 @@ use console::{
         TypeName,
         Write,
     },
-    program::{Identifier, PlaintextType, ProgramID, RecordType, StructType},
+    program::{Identifier, PlaintextType, ProgramID, RecordType, StructType, ValueSchema},
 };
 
 use indexmap::IndexMap;
@@ -245,6 +248,11 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         Ok(record)
     }
 
+    /// Returns the schema of the structs and records in the program, for converting its values to and from JSON.
+    pub fn to_value_schema(&self) -> ValueSchema<N> {
+        ValueSchema::new(self.structs.values().cloned(), self.records.values().cloned())
+    }
+
     /// Returns the closure with the given name.
     pub fn get_closure(&self, name: &Identifier<N>) -> Result<ClosureCore<N, Instruction>> {
         // Attempt to retrieve the closure.
@@ -665,7 +673,7 @@ mod tests {
     use super::*;
     use console::{
         network::MainnetV0,
-        program::{Locator, ValueType},
+        program::{Locator, Plaintext, ValueType},
     };
 
     type CurrentNetwork = MainnetV0;
@@ -731,6 +739,69 @@ record foo:
         Ok(())
     }
 
+    #[test]
+    fn test_program_value_schema() -> Result<()> {
+        // Initialize a new program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program unknown.aleo;
+
+struct message:
+    first as field;
+    second as u64;
+
+record foo:
+    owner as address.private;
+    first as message.private;",
+        )?;
+
+        // Ensure the schema contains the struct and the record.
+        let schema = program.to_value_schema();
+        assert_eq!(schema.structs(), program.structs());
+        assert_eq!(schema.records(), program.records());
+
+        // Ensure a struct of the program is converted to and from JSON.
+        let plaintext_type = PlaintextType::from_str("message")?;
+        let json = serde_json::json!({ "first": "1", "second": 2 });
+        let plaintext = schema.plaintext_from_json(&json, &plaintext_type)?;
+        assert_eq!(plaintext, Plaintext::from_str("{ first: 1field, second: 2u64 }")?);
+        assert_eq!(schema.plaintext_to_json(&plaintext, &plaintext_type)?, json);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_program_time_locked_record() -> Result<()> {
+        // Ensure a time-locked record is accepted, and round-trips through its string and byte encodings.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program unknown.aleo;
+
+record vesting:
+    owner as address.private;
+    amount as u64.private;
+    release as u32.public;
+    lock release;",
+        )?;
+        let record = program.get_record(&Identifier::from_str("vesting")?)?;
+        assert_eq!(record.lock_height(), Some(&Identifier::from_str("release")?));
+        assert_eq!(program, Program::from_str(&program.to_string())?);
+        assert_eq!(program, Program::from_bytes_le(&program.to_bytes_le()?)?);
+
+        // Ensure a record with an entry named 'lock_height' is not time-locked.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program unknown.aleo;
+
+record vesting:
+    owner as address.private;
+    lock_height as u64.public;",
+        )?;
+        assert_eq!(program.get_record(&Identifier::from_str("vesting")?)?.lock_height(), None);
+
+        Ok(())
+    }
+
     #[test]
     fn test_program_function() -> Result<()> {
         // Create a new function.
@@ -0,0 +1,212 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{RegistersLoad, RegistersStore, StackMatches, StackProgram},
+    Opcode,
+    Operand,
+};
+use console::{
+    network::prelude::*,
+    program::{Literal, Plaintext, Register, Value},
+};
+
+/// An array get command, e.g. `array.get r0 r1 into r2;`.
+/// Gets the element of the `array` at the `index`, and stores the result in `destination`.
+/// The index must be a `u32` that is less than the length of the array.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ArrayGet<N: Network> {
+    /// The array.
+    array: Operand<N>,
+    /// The index of the element.
+    index: Operand<N>,
+    /// The destination register.
+    destination: Register<N>,
+}
+
+impl<N: Network> ArrayGet<N> {
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Command("array.get")
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> Vec<Operand<N>> {
+        vec![self.array.clone(), self.index.clone()]
+    }
+
+    /// Returns the operand containing the array.
+    #[inline]
+    pub const fn array(&self) -> &Operand<N> {
+        &self.array
+    }
+
+    /// Returns the operand containing the index.
+    #[inline]
+    pub const fn index(&self) -> &Operand<N> {
+        &self.index
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub const fn destination(&self) -> &Register<N> {
+        &self.destination
+    }
+}
+
+impl<N: Network> ArrayGet<N> {
+    /// Finalizes the command.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Load the array.
+        let elements = match registers.load(stack, &self.array)? {
+            Value::Plaintext(Plaintext::Array(elements, _)) => elements,
+            _ => bail!("The first operand of 'array.get' must be an array"),
+        };
+        // Load the index.
+        let index = match registers.load(stack, &self.index)? {
+            Value::Plaintext(Plaintext::Literal(Literal::U32(index), _)) => *index as usize,
+            _ => bail!("The index of 'array.get' must be a 'u32'"),
+        };
+        // Retrieve the element, ensuring the index is within bounds.
+        let element = match elements.get(index) {
+            Some(element) => element.clone(),
+            None => bail!("Index '{index}' is out of bounds for an array of length {}", elements.len()),
+        };
+        // Assign the element to the destination register.
+        registers.store(stack, &self.destination, Value::Plaintext(element))
+    }
+}
+
+impl<N: Network> Parser for ArrayGet<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the array operand from the string.
+        let (string, array) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the index operand from the string.
+        let (string, index) = Operand::parse(string)?;
+
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" keyword from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the ";" from the string.
+        let (string, _) = tag(";")(string)?;
+
+        Ok((string, Self { array, index, destination }))
+    }
+}
+
+impl<N: Network> FromStr for ArrayGet<N> {
+    type Err = Error;
+
+    /// Parses a string into the command.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for ArrayGet<N> {
+    /// Prints the command as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for ArrayGet<N> {
+    /// Prints the command to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{} {} {} into {};", Self::opcode(), self.array, self.index, self.destination)
+    }
+}
+
+impl<N: Network> FromBytes for ArrayGet<N> {
+    /// Reads the command from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the array operand.
+        let array = Operand::read_le(&mut reader)?;
+        // Read the index operand.
+        let index = Operand::read_le(&mut reader)?;
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+        // Return the command.
+        Ok(Self { array, index, destination })
+    }
+}
+
+impl<N: Network> ToBytes for ArrayGet<N> {
+    /// Writes the command to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the array operand.
+        self.array.write_le(&mut writer)?;
+        // Write the index operand.
+        self.index.write_le(&mut writer)?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_parse() {
+        let (string, array_get) = ArrayGet::<CurrentNetwork>::parse("array.get r0 r1 into r2;").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(array_get.operands().len(), 2, "The number of operands is incorrect");
+        assert_eq!(array_get.array, Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(array_get.index, Operand::Register(Register::Locator(1)), "The second operand is incorrect");
+        assert_eq!(array_get.destination, Register::Locator(2), "The destination register is incorrect");
+
+        let (string, array_get) = ArrayGet::<CurrentNetwork>::parse("array.get r0 3u32 into r1;").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(array_get.index, Operand::Literal(Literal::from_str("3u32").unwrap()), "The index is incorrect");
+        assert_eq!(array_get.to_string(), "array.get r0 3u32 into r1;");
+    }
+}
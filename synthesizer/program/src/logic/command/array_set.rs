@@ -0,0 +1,234 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{RegistersLoad, RegistersStore, StackMatches, StackProgram},
+    Opcode,
+    Operand,
+};
+use console::{
+    network::prelude::*,
+    program::{Literal, Plaintext, Register, Value},
+};
+
+/// An array set command, e.g. `array.set r0 r1 r2 into r3;`.
+/// Copies the `array` with the element at the `index` replaced by `value`, and stores the result in `destination`.
+/// The index must be a `u32` that is less than the length of the array.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ArraySet<N: Network> {
+    /// The array.
+    array: Operand<N>,
+    /// The index of the element.
+    index: Operand<N>,
+    /// The value of the element.
+    value: Operand<N>,
+    /// The destination register.
+    destination: Register<N>,
+}
+
+impl<N: Network> ArraySet<N> {
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Command("array.set")
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> Vec<Operand<N>> {
+        vec![self.array.clone(), self.index.clone(), self.value.clone()]
+    }
+
+    /// Returns the operand containing the array.
+    #[inline]
+    pub const fn array(&self) -> &Operand<N> {
+        &self.array
+    }
+
+    /// Returns the operand containing the index.
+    #[inline]
+    pub const fn index(&self) -> &Operand<N> {
+        &self.index
+    }
+
+    /// Returns the operand containing the value.
+    #[inline]
+    pub const fn value(&self) -> &Operand<N> {
+        &self.value
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub const fn destination(&self) -> &Register<N> {
+        &self.destination
+    }
+}
+
+impl<N: Network> ArraySet<N> {
+    /// Finalizes the command.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Load the array.
+        let mut elements = match registers.load(stack, &self.array)? {
+            Value::Plaintext(Plaintext::Array(elements, _)) => elements,
+            _ => bail!("The first operand of 'array.set' must be an array"),
+        };
+        // Load the index.
+        let index = match registers.load(stack, &self.index)? {
+            Value::Plaintext(Plaintext::Literal(Literal::U32(index), _)) => *index as usize,
+            _ => bail!("The index of 'array.set' must be a 'u32'"),
+        };
+        // Load the value.
+        let value = match registers.load(stack, &self.value)? {
+            Value::Plaintext(plaintext) => plaintext,
+            _ => bail!("The value of 'array.set' must be a plaintext"),
+        };
+        // Replace the element, ensuring the index is within bounds.
+        let length = elements.len();
+        match elements.get_mut(index) {
+            Some(element) => *element = value,
+            None => bail!("Index '{index}' is out of bounds for an array of length {length}"),
+        }
+        // Assign the updated array to the destination register.
+        registers.store(stack, &self.destination, Value::Plaintext(Plaintext::Array(elements, Default::default())))
+    }
+}
+
+impl<N: Network> Parser for ArraySet<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the array operand from the string.
+        let (string, array) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the index operand from the string.
+        let (string, index) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the value operand from the string.
+        let (string, value) = Operand::parse(string)?;
+
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" keyword from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the ";" from the string.
+        let (string, _) = tag(";")(string)?;
+
+        Ok((string, Self { array, index, value, destination }))
+    }
+}
+
+impl<N: Network> FromStr for ArraySet<N> {
+    type Err = Error;
+
+    /// Parses a string into the command.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for ArraySet<N> {
+    /// Prints the command as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for ArraySet<N> {
+    /// Prints the command to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{} {} {} {} into {};", Self::opcode(), self.array, self.index, self.value, self.destination)
+    }
+}
+
+impl<N: Network> FromBytes for ArraySet<N> {
+    /// Reads the command from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the array operand.
+        let array = Operand::read_le(&mut reader)?;
+        // Read the index operand.
+        let index = Operand::read_le(&mut reader)?;
+        // Read the value operand.
+        let value = Operand::read_le(&mut reader)?;
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+        // Return the command.
+        Ok(Self { array, index, value, destination })
+    }
+}
+
+impl<N: Network> ToBytes for ArraySet<N> {
+    /// Writes the command to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the array operand.
+        self.array.write_le(&mut writer)?;
+        // Write the index operand.
+        self.index.write_le(&mut writer)?;
+        // Write the value operand.
+        self.value.write_le(&mut writer)?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_parse() {
+        let (string, array_set) = ArraySet::<CurrentNetwork>::parse("array.set r0 r1 r2 into r3;").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(array_set.operands().len(), 3, "The number of operands is incorrect");
+        assert_eq!(array_set.array, Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(array_set.index, Operand::Register(Register::Locator(1)), "The second operand is incorrect");
+        assert_eq!(array_set.value, Operand::Register(Register::Locator(2)), "The third operand is incorrect");
+        assert_eq!(array_set.destination, Register::Locator(3), "The destination register is incorrect");
+
+        let (string, array_set) = ArraySet::<CurrentNetwork>::parse("array.set r0 0u32 5u8 into r1;").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(array_set.to_string(), "array.set r0 0u32 5u8 into r1;");
+    }
+}
@@ -0,0 +1,234 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{RegistersLoad, StackMatches, StackProgram},
+    Opcode,
+    Operand,
+};
+use console::{
+    network::prelude::*,
+    program::{Identifier, Literal, Plaintext, ProgramID, Value},
+};
+
+/// A dynamic call command, e.g. `call.dynamic r0 hook r1 r2;`.
+/// Invokes the finalize scope of `function_name` in the program whose ID is stored as a `string` in `program`,
+/// passing the `operands` as the finalize inputs.
+///
+/// The callee must be a finalize-only function, whose body only forwards its public inputs to its finalize scope.
+/// The callee finalize scope must not contain an `await`, `iter`, or `call.dynamic` command, must have at most
+/// `MAX_CALLEE_COMMANDS` commands, and must not cost more than the prepaid callee cost of the cost model.
+/// The callee must not belong to a program that is deprecated, or that is already being finalized.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct CallDynamic<N: Network> {
+    /// The operand containing the program ID of the callee.
+    program: Operand<N>,
+    /// The name of the callee function.
+    function_name: Identifier<N>,
+    /// The operands passed to the callee.
+    operands: Vec<Operand<N>>,
+}
+
+impl<N: Network> CallDynamic<N> {
+    /// The maximum number of commands in the finalize scope of the callee.
+    pub const MAX_CALLEE_COMMANDS: usize = 32;
+
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Command("call.dynamic")
+    }
+
+    /// Returns the operand containing the program ID of the callee.
+    #[inline]
+    pub const fn program(&self) -> &Operand<N> {
+        &self.program
+    }
+
+    /// Returns the name of the callee function.
+    #[inline]
+    pub const fn function_name(&self) -> &Identifier<N> {
+        &self.function_name
+    }
+
+    /// Returns the operands passed to the callee.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        &self.operands
+    }
+}
+
+impl<N: Network> CallDynamic<N> {
+    /// Loads the program ID of the callee and the arguments passed to it.
+    /// Note that the call itself is processed by the caller of this method.
+    #[inline]
+    pub fn load(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &impl RegistersLoad<N>,
+    ) -> Result<(ProgramID<N>, Vec<Value<N>>)> {
+        // Load the program ID.
+        let program_id = match registers.load(stack, &self.program)? {
+            Value::Plaintext(Plaintext::Literal(Literal::String(program_id), _)) => ProgramID::from_str(&program_id)?,
+            _ => bail!("The program ID of 'call.dynamic' must be a 'string'"),
+        };
+        // Load the arguments.
+        let arguments = self.operands.iter().map(|operand| registers.load(stack, operand)).collect::<Result<_>>()?;
+        Ok((program_id, arguments))
+    }
+}
+
+impl<N: Network> Parser for CallDynamic<N> {
+    /// Parses a string into a command.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        /// Parses an operand.
+        fn parse_operand<N: Network>(string: &str) -> ParserResult<Operand<N>> {
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the operand from the string.
+            Operand::parse(string)
+        }
+
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the program operand from the string.
+        let (string, program) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the function name from the string.
+        let (string, function_name) = Identifier::parse(string)?;
+        // Parse the operands from the string.
+        let (string, operands) = many0(parse_operand)(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the ";" from the string.
+        let (string, _) = tag(";")(string)?;
+
+        // Ensure the number of operands is less than or equal to MAX_INPUTS.
+        match operands.len() <= N::MAX_INPUTS {
+            true => Ok((string, Self { program, function_name, operands })),
+            false => map_res(fail, |_: ParserResult<Self>| {
+                Err(error(format!("The number of operands must be <= {}, found {}", N::MAX_INPUTS, operands.len())))
+            })(string),
+        }
+    }
+}
+
+impl<N: Network> FromStr for CallDynamic<N> {
+    type Err = Error;
+
+    /// Parses a string into the command.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for CallDynamic<N> {
+    /// Prints the command as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for CallDynamic<N> {
+    /// Prints the command to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is less than or equal to MAX_INPUTS.
+        if self.operands.len() > N::MAX_INPUTS {
+            return Err(fmt::Error);
+        }
+        // Print the command.
+        write!(f, "{} {} {}", Self::opcode(), self.program, self.function_name)?;
+        self.operands.iter().try_for_each(|operand| write!(f, " {operand}"))?;
+        write!(f, ";")
+    }
+}
+
+impl<N: Network> FromBytes for CallDynamic<N> {
+    /// Reads the command from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the program operand.
+        let program = Operand::read_le(&mut reader)?;
+        // Read the function name.
+        let function_name = Identifier::read_le(&mut reader)?;
+
+        // Read the number of operands.
+        let num_operands = u8::read_le(&mut reader)?;
+        // Ensure the number of operands is less than or equal to MAX_INPUTS.
+        if num_operands as usize > N::MAX_INPUTS {
+            return Err(error(format!("The number of operands must be <= {}, found {}", N::MAX_INPUTS, num_operands)));
+        }
+        // Read the operands.
+        let operands = (0..num_operands).map(|_| Operand::read_le(&mut reader)).collect::<IoResult<_>>()?;
+
+        // Return the command.
+        Ok(Self { program, function_name, operands })
+    }
+}
+
+impl<N: Network> ToBytes for CallDynamic<N> {
+    /// Writes the command to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is less than or equal to MAX_INPUTS.
+        if self.operands.len() > N::MAX_INPUTS {
+            return Err(error(format!("The number of operands must be <= {}", N::MAX_INPUTS)));
+        }
+        // Write the program operand.
+        self.program.write_le(&mut writer)?;
+        // Write the function name.
+        self.function_name.write_le(&mut writer)?;
+        // Write the number of operands.
+        u8::try_from(self.operands.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{network::MainnetV0, program::Register};
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_parse() {
+        let (string, call) = CallDynamic::<CurrentNetwork>::parse("call.dynamic r0 hook r1 r2;").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(call.program, Operand::Register(Register::Locator(0)), "The program operand is incorrect");
+        assert_eq!(call.function_name, Identifier::from_str("hook").unwrap(), "The function name is incorrect");
+        assert_eq!(call.operands.len(), 2, "The number of operands is incorrect");
+        assert_eq!(call.operands[0], Operand::Register(Register::Locator(1)), "The first operand is incorrect");
+        assert_eq!(call.operands[1], Operand::Register(Register::Locator(2)), "The second operand is incorrect");
+
+        let (string, call) = CallDynamic::<CurrentNetwork>::parse("call.dynamic r3 ping;").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert!(call.operands.is_empty(), "The number of operands is incorrect");
+        assert_eq!(call.to_string(), "call.dynamic r3 ping;");
+    }
+}
@@ -14,6 +14,7 @@
 
 use crate::{
     traits::{FinalizeStoreTrait, RegistersLoad, RegistersStore, StackMatches, StackProgram},
+    MappingLocator,
     Opcode,
     Operand,
 };
@@ -23,12 +24,15 @@ use console::{
     types::Boolean,
 };
 
+use std::io::{BufRead, BufReader};
+
 /// A contains command, e.g. `contains accounts[r0] into r1;`.
 /// Contains is `true` if a (`key`, `value`) entry exists in `mapping`, stores the result in `destination`.
+/// The mapping may belong to an imported program, e.g. `contains credits.aleo/account[r0] into r1;`.
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct Contains<N: Network> {
-    /// The mapping name.
-    mapping: Identifier<N>,
+    /// The mapping.
+    mapping: MappingLocator<N>,
     /// The key to access the mapping.
     key: Operand<N>,
     /// The destination register.
@@ -48,9 +52,9 @@ impl<N: Network> Contains<N> {
         vec![self.key.clone()]
     }
 
-    /// Returns the mapping name.
+    /// Returns the mapping.
     #[inline]
-    pub const fn mapping_name(&self) -> &Identifier<N> {
+    pub const fn mapping(&self) -> &MappingLocator<N> {
         &self.mapping
     }
 
@@ -76,16 +80,22 @@ impl<N: Network> Contains<N> {
         store: &impl FinalizeStoreTrait<N>,
         registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
     ) -> Result<()> {
+        // Determine the program ID and mapping name.
+        let (program_id, mapping_name) = match self.mapping {
+            MappingLocator::Locator(locator) => (*locator.program_id(), *locator.resource()),
+            MappingLocator::Resource(mapping_name) => (*stack.program_id(), mapping_name),
+        };
+
         // Ensure the mapping exists in storage.
-        if !store.contains_mapping_confirmed(stack.program_id(), &self.mapping)? {
-            bail!("Mapping '{}/{}' does not exist in storage", stack.program_id(), self.mapping);
+        if !store.contains_mapping_confirmed(&program_id, &mapping_name)? {
+            bail!("Mapping '{program_id}/{mapping_name}' does not exist in storage");
         }
 
         // Load the operand as a plaintext.
         let key = registers.load_plaintext(stack, &self.key)?;
 
         // Determine if the key exists in the mapping.
-        let contains_key = store.contains_key_speculative(*stack.program_id(), self.mapping, &key)?;
+        let contains_key = store.contains_key_speculative(program_id, mapping_name, &key)?;
 
         // Assign the value to the destination register.
         registers.store(stack, &self.destination, Value::from(Literal::Boolean(Boolean::new(contains_key))))?;
@@ -106,7 +116,7 @@ impl<N: Network> Parser for Contains<N> {
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
 
         // Parse the mapping name from the string.
-        let (string, mapping) = Identifier::parse(string)?;
+        let (string, mapping) = MappingLocator::parse(string)?;
         // Parse the "[" from the string.
         let (string, _) = tag("[")(string)?;
         // Parse the whitespace from the string.
@@ -175,9 +185,21 @@ impl<N: Network> Display for Contains<N> {
 
 impl<N: Network> FromBytes for Contains<N> {
     /// Reads the command from a buffer.
-    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
-        // Read the mapping name.
-        let mapping = Identifier::read_le(&mut reader)?;
+    fn read_le<R: Read>(reader: R) -> IoResult<Self> {
+        // Peek at the first byte.
+        let mut reader = BufReader::with_capacity(1, reader);
+        let first_byte = {
+            let buffer = reader.fill_buf()?;
+            match buffer.first() {
+                Some(byte) => *byte,
+                None => return Err(error("Failed to read `contains`. Expected byte.")),
+            }
+        };
+        // If the first byte is zero, then read a `MappingLocator`, otherwise read an `Identifier`.
+        let mapping = match first_byte {
+            0u8 => MappingLocator::read_le(&mut reader)?,
+            _ => MappingLocator::Resource(Identifier::read_le(&mut reader)?),
+        };
         // Read the key operand.
         let key = Operand::read_le(&mut reader)?;
         // Read the destination register.
@@ -191,7 +213,11 @@ impl<N: Network> ToBytes for Contains<N> {
     /// Writes the operation to a buffer.
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
         // Write the mapping name.
-        self.mapping.write_le(&mut writer)?;
+        // Note: A local mapping is written as an identifier, for compatibility with existing programs.
+        match &self.mapping {
+            MappingLocator::Locator(_) => self.mapping.write_le(&mut writer)?,
+            MappingLocator::Resource(identifier) => identifier.write_le(&mut writer)?,
+        }
         // Write the key operand.
         self.key.write_le(&mut writer)?;
         // Write the destination register.
@@ -210,9 +236,31 @@ mod tests {
     fn test_parse() {
         let (string, contains) = Contains::<CurrentNetwork>::parse("contains account[r0] into r1;").unwrap();
         assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
-        assert_eq!(contains.mapping, Identifier::from_str("account").unwrap());
+        assert_eq!(contains.mapping, MappingLocator::from_str("account").unwrap());
         assert_eq!(contains.operands().len(), 1, "The number of operands is incorrect");
         assert_eq!(contains.key, Operand::Register(Register::Locator(0)), "The first operand is incorrect");
         assert_eq!(contains.destination, Register::Locator(1), "The second operand is incorrect");
+
+        let (string, contains) =
+            Contains::<CurrentNetwork>::parse("contains credits.aleo/account[r0] into r1;").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(contains.mapping, MappingLocator::from_str("credits.aleo/account").unwrap());
+        assert_eq!(contains.to_string(), "contains credits.aleo/account[r0] into r1;");
+    }
+
+    #[test]
+    fn test_bytes() {
+        for string in ["contains account[r0] into r1;", "contains credits.aleo/account[r0] into r1;"] {
+            let expected = Contains::<CurrentNetwork>::from_str(string).unwrap();
+            let bytes = expected.to_bytes_le().unwrap();
+            assert_eq!(expected, Contains::from_bytes_le(&bytes).unwrap());
+        }
+
+        // Ensure a local mapping is written as an identifier.
+        let contains = Contains::<CurrentNetwork>::from_str("contains account[r0] into r1;").unwrap();
+        let mut expected_bytes = Identifier::<CurrentNetwork>::from_str("account").unwrap().to_bytes_le().unwrap();
+        expected_bytes.extend(contains.key.to_bytes_le().unwrap());
+        expected_bytes.extend(contains.destination.to_bytes_le().unwrap());
+        assert_eq!(contains.to_bytes_le().unwrap(), expected_bytes);
     }
 }
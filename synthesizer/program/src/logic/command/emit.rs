@@ -0,0 +1,154 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{RegistersLoad, StackMatches, StackProgram},
+    FinalizeOperation,
+    Opcode,
+    Operand,
+};
+use console::network::prelude::*;
+
+/// An emit command, e.g. `emit r0;`
+/// Records the plaintext `event` as an event of the program, which is committed in the block.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Emit<N: Network> {
+    /// The event to be emitted.
+    event: Operand<N>,
+}
+
+impl<N: Network> Emit<N> {
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Command("emit")
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> Vec<Operand<N>> {
+        vec![self.event.clone()]
+    }
+
+    /// Returns the operand containing the event.
+    #[inline]
+    pub const fn event(&self) -> &Operand<N> {
+        &self.event
+    }
+}
+
+impl<N: Network> Emit<N> {
+    /// Finalizes the command.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut impl RegistersLoad<N>,
+    ) -> Result<FinalizeOperation<N>> {
+        // Load the event operand as a plaintext.
+        let event = registers.load_plaintext(stack, &self.event)?;
+        // Compute the program ID field.
+        let program_id = N::hash_bhp1024(&stack.program_id().to_bits_le())?;
+        // Return the finalize operation.
+        Ok(FinalizeOperation::Event(program_id, event))
+    }
+}
+
+impl<N: Network> Parser for Emit<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the event operand from the string.
+        let (string, event) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the ";" from the string.
+        let (string, _) = tag(";")(string)?;
+
+        Ok((string, Self { event }))
+    }
+}
+
+impl<N: Network> FromStr for Emit<N> {
+    type Err = Error;
+
+    /// Parses a string into the command.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for Emit<N> {
+    /// Prints the command as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for Emit<N> {
+    /// Prints the command to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{} {};", Self::opcode(), self.event)
+    }
+}
+
+impl<N: Network> FromBytes for Emit<N> {
+    /// Reads the command from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the event operand.
+        let event = Operand::read_le(&mut reader)?;
+        // Return the command.
+        Ok(Self { event })
+    }
+}
+
+impl<N: Network> ToBytes for Emit<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the event operand.
+        self.event.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{network::MainnetV0, program::Register};
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_parse() {
+        let (string, emit) = Emit::<CurrentNetwork>::parse("emit r0;").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(emit.operands().len(), 1, "The number of operands is incorrect");
+        assert_eq!(emit.event, Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(emit.to_string(), "emit r0;");
+    }
+}
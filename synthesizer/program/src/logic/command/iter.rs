@@ -0,0 +1,246 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{RegistersStore, StackMatches, StackProgram},
+    Opcode,
+};
+use console::{
+    network::prelude::*,
+    program::{Identifier, Literal, Plaintext, Register, Value},
+    types::U32,
+};
+
+/// A bounded loop command, e.g. `iter 0u32..10u32 into r0 until done;`.
+/// Executes the commands up to `position done` once for each value in the range `start..end`,
+/// storing the current value of the loop counter in `destination`.
+/// The bounds are constants, and the number of iterations is at most `N::MAX_ITERATIONS`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Iter<N: Network> {
+    /// The (inclusive) start of the range.
+    start: U32<N>,
+    /// The (exclusive) end of the range.
+    end: U32<N>,
+    /// The destination register for the loop counter.
+    destination: Register<N>,
+    /// The position that marks the end of the loop body.
+    position: Identifier<N>,
+}
+
+impl<N: Network> Iter<N> {
+    /// Initializes a new `iter` command.
+    pub fn new(start: U32<N>, end: U32<N>, destination: Register<N>, position: Identifier<N>) -> Result<Self> {
+        // Ensure the loop executes at least once.
+        ensure!(*start < *end, "The range of 'iter' must be non-empty, found '{start}..{end}'");
+        // Ensure the number of iterations does not exceed the maximum.
+        ensure!(
+            *end - *start <= N::MAX_ITERATIONS,
+            "The range of 'iter' must not exceed {} iterations, found '{start}..{end}'",
+            N::MAX_ITERATIONS
+        );
+        Ok(Self { start, end, destination, position })
+    }
+
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Command("iter")
+    }
+
+    /// Returns the (inclusive) start of the range.
+    #[inline]
+    pub const fn start(&self) -> &U32<N> {
+        &self.start
+    }
+
+    /// Returns the (exclusive) end of the range.
+    #[inline]
+    pub const fn end(&self) -> &U32<N> {
+        &self.end
+    }
+
+    /// Returns the destination register for the loop counter.
+    #[inline]
+    pub const fn destination(&self) -> &Register<N> {
+        &self.destination
+    }
+
+    /// Returns the position that marks the end of the loop body.
+    #[inline]
+    pub const fn position(&self) -> &Identifier<N> {
+        &self.position
+    }
+
+    /// Returns the number of iterations of the loop.
+    #[inline]
+    pub fn num_iterations(&self) -> u32 {
+        *self.end - *self.start
+    }
+}
+
+impl<N: Network> Iter<N> {
+    /// Stores the given value of the loop counter into the destination register.
+    /// Note that the control flow of the loop is processed by the caller of this method.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut impl RegistersStore<N>,
+        counter: U32<N>,
+    ) -> Result<()> {
+        // Ensure the loop counter is within the range.
+        ensure!(counter >= self.start && counter < self.end, "The loop counter '{counter}' is out of range");
+        // Assign the loop counter to the destination register.
+        registers.store(stack, &self.destination, Value::Plaintext(Plaintext::from(Literal::U32(counter))))
+    }
+}
+
+impl<N: Network> Parser for Iter<N> {
+    /// Parses a string into a command.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+
+        // Parse the start of the range from the string.
+        let (string, start) = U32::parse(string)?;
+        // Parse the ".." from the string.
+        let (string, _) = tag("..")(string)?;
+        // Parse the end of the range from the string.
+        let (string, end) = U32::parse(string)?;
+
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" keyword from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "until" keyword from the string.
+        let (string, _) = tag("until")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the position from the string.
+        let (string, position) = Identifier::parse(string)?;
+
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the ";" from the string.
+        let (string, _) = tag(";")(string)?;
+
+        // Construct the command, ensuring the range is well-formed.
+        match Self::new(start, end, destination, position) {
+            Ok(iter) => Ok((string, iter)),
+            Err(e) => map_res(fail, |_: ParserResult<Self>| Err(error(format!("Failed to parse 'iter': {e}"))))(string),
+        }
+    }
+}
+
+impl<N: Network> FromStr for Iter<N> {
+    type Err = Error;
+
+    /// Parses a string into the command.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for Iter<N> {
+    /// Prints the command as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for Iter<N> {
+    /// Prints the command to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{} {}..{} into {} until {};", Self::opcode(), self.start, self.end, self.destination, self.position)
+    }
+}
+
+impl<N: Network> FromBytes for Iter<N> {
+    /// Reads the command from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the start of the range.
+        let start = U32::read_le(&mut reader)?;
+        // Read the end of the range.
+        let end = U32::read_le(&mut reader)?;
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+        // Read the position.
+        let position = Identifier::read_le(&mut reader)?;
+        // Return the command.
+        Self::new(start, end, destination, position).map_err(error)
+    }
+}
+
+impl<N: Network> ToBytes for Iter<N> {
+    /// Writes the command to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the start of the range.
+        self.start.write_le(&mut writer)?;
+        // Write the end of the range.
+        self.end.write_le(&mut writer)?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)?;
+        // Write the position.
+        self.position.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_parse() {
+        let (string, iter) = Iter::<CurrentNetwork>::parse("iter 0u32..10u32 into r3 until done;").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(*iter.start, 0, "The start of the range is incorrect");
+        assert_eq!(*iter.end, 10, "The end of the range is incorrect");
+        assert_eq!(iter.destination, Register::Locator(3), "The destination register is incorrect");
+        assert_eq!(iter.position, Identifier::from_str("done").unwrap(), "The position is incorrect");
+        assert_eq!(iter.num_iterations(), 10);
+        assert_eq!(iter.to_string(), "iter 0u32..10u32 into r3 until done;");
+
+        // Ensure the range must be non-empty.
+        assert!(Iter::<CurrentNetwork>::from_str("iter 5u32..5u32 into r0 until done;").is_err());
+        assert!(Iter::<CurrentNetwork>::from_str("iter 6u32..5u32 into r0 until done;").is_err());
+        // Ensure the range must not exceed the maximum number of iterations.
+        let max = CurrentNetwork::MAX_ITERATIONS;
+        assert!(Iter::<CurrentNetwork>::from_str(&format!("iter 0u32..{max}u32 into r0 until done;")).is_ok());
+        assert!(Iter::<CurrentNetwork>::from_str(&format!("iter 0u32..{}u32 into r0 until done;", max + 1)).is_err());
+    }
+}
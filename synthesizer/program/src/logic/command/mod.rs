@@ -12,21 +12,36 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod array_get;
+pub use array_get::*;
+
+mod array_set;
+pub use array_set::*;
+
 mod await_;
 pub use await_::*;
 
 mod branch;
 pub use branch::*;
 
+mod call_dynamic;
+pub use call_dynamic::*;
+
 mod contains;
 pub use contains::*;
 
+mod emit;
+pub use emit::*;
+
 mod get;
 pub use get::*;
 
 mod get_or_use;
 pub use get_or_use::*;
 
+mod iter;
+pub use iter::*;
+
 mod rand_chacha;
 pub use crate::command::rand_chacha::*;
 
@@ -53,6 +68,7 @@ use crate::{
     FinalizeOperation,
     FinalizeRegistersState,
     Instruction,
+    Opcode,
 };
 use console::{
     network::prelude::*,
@@ -84,6 +100,16 @@ pub enum Command<N: Network> {
     BranchNeq(BranchNeq<N>),
     /// Indicates a position to which the program can branch to.
     Position(Position<N>),
+    /// Gets the element of `array` at the `index` operand, and stores the result into `destination`.
+    ArrayGet(ArrayGet<N>),
+    /// Sets the element of `array` at the `index` operand to `value`, and stores the array into `destination`.
+    ArraySet(ArraySet<N>),
+    /// Executes the commands up to the `position` once for each value in a constant range.
+    Iter(Iter<N>),
+    /// Invokes the finalize scope of a function in the program whose ID is read from the `program` operand.
+    CallDynamic(CallDynamic<N>),
+    /// Emits the `event` operand as an event of the program.
+    Emit(Emit<N>),
 }
 
 impl<N: Network> CommandTrait<N> for Command<N> {
@@ -96,9 +122,14 @@ impl<N: Network> CommandTrait<N> for Command<N> {
             Command::Get(get) => vec![get.destination().clone()],
             Command::GetOrUse(get_or_use) => vec![get_or_use.destination().clone()],
             Command::RandChaCha(rand_chacha) => vec![rand_chacha.destination().clone()],
+            Command::ArrayGet(array_get) => vec![array_get.destination().clone()],
+            Command::ArraySet(array_set) => vec![array_set.destination().clone()],
+            Command::Iter(iter) => vec![iter.destination().clone()],
             Command::Await(_)
             | Command::BranchEq(_)
             | Command::BranchNeq(_)
+            | Command::CallDynamic(_)
+            | Command::Emit(_)
             | Command::Position(_)
             | Command::Remove(_)
             | Command::Set(_) => vec![],
@@ -112,6 +143,7 @@ impl<N: Network> CommandTrait<N> for Command<N> {
         match self {
             Command::BranchEq(branch_eq) => Some(branch_eq.position()),
             Command::BranchNeq(branch_neq) => Some(branch_neq.position()),
+            Command::Iter(iter) => Some(iter.position()),
             _ => None,
         }
     }
@@ -140,11 +172,34 @@ impl<N: Network> CommandTrait<N> for Command<N> {
     /// Returns `true` if the command is a write operation.
     #[inline]
     fn is_write(&self) -> bool {
-        matches!(self, Command::Set(_) | Command::Remove(_))
+        matches!(self, Command::Set(_) | Command::Remove(_) | Command::Emit(_))
     }
 }
 
 impl<N: Network> Command<N> {
+    /// Returns the opcode of the command.
+    #[inline]
+    pub fn opcode(&self) -> Opcode {
+        match self {
+            Command::Instruction(instruction) => instruction.opcode(),
+            Command::Await(_) => Await::<N>::opcode(),
+            Command::Contains(_) => Contains::<N>::opcode(),
+            Command::Get(_) => Get::<N>::opcode(),
+            Command::GetOrUse(_) => GetOrUse::<N>::opcode(),
+            Command::RandChaCha(_) => RandChaCha::<N>::opcode(),
+            Command::Remove(_) => Remove::<N>::opcode(),
+            Command::Set(_) => Set::<N>::opcode(),
+            Command::BranchEq(_) => BranchEq::<N>::opcode(),
+            Command::BranchNeq(_) => BranchNeq::<N>::opcode(),
+            Command::Position(_) => Position::<N>::opcode(),
+            Command::ArrayGet(_) => ArrayGet::<N>::opcode(),
+            Command::ArraySet(_) => ArraySet::<N>::opcode(),
+            Command::Iter(_) => Iter::<N>::opcode(),
+            Command::CallDynamic(_) => CallDynamic::<N>::opcode(),
+            Command::Emit(_) => Emit::<N>::opcode(),
+        }
+    }
+
     /// Finalizes the command.
     #[inline]
     pub fn finalize(
@@ -176,6 +231,16 @@ impl<N: Network> Command<N> {
             }
             // Finalize the `position` command, and return no finalize operation.
             Command::Position(position) => position.finalize().map(|_| None),
+            // Finalize the `array.get` command, and return no finalize operation.
+            Command::ArrayGet(array_get) => array_get.finalize(stack, registers).map(|_| None),
+            // Finalize the `array.set` command, and return no finalize operation.
+            Command::ArraySet(array_set) => array_set.finalize(stack, registers).map(|_| None),
+            // `iter` commands are processed by the caller of this method.
+            Command::Iter(_) => bail!("`iter` commands cannot be finalized directly."),
+            // `call.dynamic` commands are processed by the caller of this method.
+            Command::CallDynamic(_) => bail!("`call.dynamic` commands cannot be finalized directly."),
+            // Finalize the `emit` command, and return the finalize operation.
+            Command::Emit(emit) => emit.finalize(stack, registers).map(Some),
         }
     }
 }
@@ -208,8 +273,18 @@ impl<N: Network> FromBytes for Command<N> {
             9 => Ok(Self::BranchNeq(BranchNeq::read_le(&mut reader)?)),
             // Read the `position` command.
             10 => Ok(Self::Position(Position::read_le(&mut reader)?)),
+            // Read the `array.get` command.
+            11 => Ok(Self::ArrayGet(ArrayGet::read_le(&mut reader)?)),
+            // Read the `array.set` command.
+            12 => Ok(Self::ArraySet(ArraySet::read_le(&mut reader)?)),
+            // Read the `iter` command.
+            13 => Ok(Self::Iter(Iter::read_le(&mut reader)?)),
+            // Read the `call.dynamic` command.
+            14 => Ok(Self::CallDynamic(CallDynamic::read_le(&mut reader)?)),
+            // Read the `emit` command.
+            15 => Ok(Self::Emit(Emit::read_le(&mut reader)?)),
             // Invalid variant.
-            11.. => Err(error(format!("Invalid command variant: {variant}"))),
+            16.. => Err(error(format!("Invalid command variant: {variant}"))),
         }
     }
 }
@@ -284,6 +359,36 @@ impl<N: Network> ToBytes for Command<N> {
                 // Write the position command.
                 position.write_le(&mut writer)
             }
+            Self::ArrayGet(array_get) => {
+                // Write the variant.
+                11u8.write_le(&mut writer)?;
+                // Write the `array.get` command.
+                array_get.write_le(&mut writer)
+            }
+            Self::ArraySet(array_set) => {
+                // Write the variant.
+                12u8.write_le(&mut writer)?;
+                // Write the `array.set` command.
+                array_set.write_le(&mut writer)
+            }
+            Self::Iter(iter) => {
+                // Write the variant.
+                13u8.write_le(&mut writer)?;
+                // Write the `iter` command.
+                iter.write_le(&mut writer)
+            }
+            Self::CallDynamic(call_dynamic) => {
+                // Write the variant.
+                14u8.write_le(&mut writer)?;
+                // Write the `call.dynamic` command.
+                call_dynamic.write_le(&mut writer)
+            }
+            Self::Emit(emit) => {
+                // Write the variant.
+                15u8.write_le(&mut writer)?;
+                // Write the `emit` command.
+                emit.write_le(&mut writer)
+            }
         }
     }
 }
@@ -305,6 +410,11 @@ impl<N: Network> Parser for Command<N> {
             map(BranchEq::parse, |branch_eq| Self::BranchEq(branch_eq)),
             map(BranchNeq::parse, |branch_neq| Self::BranchNeq(branch_neq)),
             map(Position::parse, |position| Self::Position(position)),
+            map(ArrayGet::parse, |array_get| Self::ArrayGet(array_get)),
+            map(ArraySet::parse, |array_set| Self::ArraySet(array_set)),
+            map(Iter::parse, |iter| Self::Iter(iter)),
+            map(CallDynamic::parse, |call_dynamic| Self::CallDynamic(call_dynamic)),
+            map(Emit::parse, |emit| Self::Emit(emit)),
             map(Instruction::parse, |instruction| Self::Instruction(instruction)),
         ))(string)
     }
@@ -350,6 +460,11 @@ impl<N: Network> Display for Command<N> {
             Self::BranchEq(branch_eq) => Display::fmt(branch_eq, f),
             Self::BranchNeq(branch_neq) => Display::fmt(branch_neq, f),
             Self::Position(position) => Display::fmt(position, f),
+            Self::ArrayGet(array_get) => Display::fmt(array_get, f),
+            Self::ArraySet(array_set) => Display::fmt(array_set, f),
+            Self::Iter(iter) => Display::fmt(iter, f),
+            Self::CallDynamic(call_dynamic) => Display::fmt(call_dynamic, f),
+            Self::Emit(emit) => Display::fmt(emit, f),
         }
     }
 }
@@ -442,6 +557,36 @@ mod tests {
         let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
         let bytes = command.to_bytes_le().unwrap();
         assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
+        // ArrayGet
+        let expected = "array.get r0 r1 into r2;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
+        // ArraySet
+        let expected = "array.set r0 r1 r2 into r3;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
+        // Iter
+        let expected = "iter 0u32..10u32 into r0 until done;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
+        // CallDynamic
+        let expected = "call.dynamic r0 hook r1 r2;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
+        // Emit
+        let expected = "emit r0;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
     }
 
     #[test]
@@ -519,5 +664,35 @@ mod tests {
         let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
         assert_eq!(Command::Position(Position::from_str(expected).unwrap()), command);
         assert_eq!(expected, command.to_string());
+
+        // ArrayGet
+        let expected = "array.get r0 r1 into r2;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::ArrayGet(ArrayGet::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
+
+        // ArraySet
+        let expected = "array.set r0 r1 r2 into r3;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::ArraySet(ArraySet::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
+
+        // Iter
+        let expected = "iter 0u32..10u32 into r0 until done;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::Iter(Iter::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
+
+        // CallDynamic
+        let expected = "call.dynamic r0 hook r1 r2;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::CallDynamic(CallDynamic::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
+
+        // Emit
+        let expected = "emit r0;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::Emit(Emit::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
     }
 }
@@ -35,6 +35,23 @@ pub const MAX_ADDITIONAL_SEEDS: usize = 2;
 /// This command samples a deterministic and unique element, and stores the result in `destination`.
 /// When the optional operand(s) are provided, it is used as additional seed(s) to the
 /// random-number generator. Note that the maximum number of additional seeds is currently 2.
+///
+/// The random-number generator is seeded with the hash of:
+///  - the block-specific random seed, derived from the block round, block height,
+///    cumulative weight, cumulative proof target, and previous block hash,
+///  - the transition ID, which binds the output to the transaction being finalized,
+///  - the program ID, function name, destination register, and destination type, and
+///  - the additional seed(s), if any.
+///
+/// As such, the output is domain-separated by program, function, and destination,
+/// and every validator samples the same element when finalizing the same block.
+///
+/// Note that the output is *deterministic*, not secret. It is unpredictable to the transaction
+/// author at the time of execution, as the block metadata is not yet known. However, it is
+/// computable by anyone once the block is proposed, and the block proposer may influence it,
+/// for example by choosing which transactions to include in the block. Programs should not rely
+/// on this command for randomness that must remain hidden, or that must resist manipulation
+/// by validators.
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct RandChaCha<N: Network> {
     /// The operand(s) as `seed(s)`.
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use console::network::prelude::*;
+use console::{network::prelude::*, types::Field};
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct FinalizeGlobalState {
@@ -20,8 +20,12 @@ pub struct FinalizeGlobalState {
     block_round: u64,
     /// The block height.
     block_height: u32,
+    /// The block timestamp.
+    block_timestamp: i64,
     /// The block-specific random seed.
     random_seed: [u8; 32],
+    /// The external randomness ratified in the block, if any.
+    block_randomness: Option<[u8; 32]>,
 }
 
 impl FinalizeGlobalState {
@@ -31,6 +35,7 @@ impl FinalizeGlobalState {
         // Initialize the parameters.
         let block_round = 0;
         let block_height = 0;
+        let block_timestamp = N::GENESIS_TIMESTAMP;
         let block_cumulative_weight = 0;
         let block_cumulative_proof_target = 0;
         let previous_block_hash = N::BlockHash::default();
@@ -38,6 +43,7 @@ impl FinalizeGlobalState {
         Self::new::<N>(
             block_round,
             block_height,
+            block_timestamp,
             block_cumulative_weight,
             block_cumulative_proof_target,
             previous_block_hash,
@@ -49,6 +55,7 @@ impl FinalizeGlobalState {
     pub fn new<N: Network>(
         block_round: u64,
         block_height: u32,
+        block_timestamp: i64,
         block_cumulative_weight: u128,
         block_cumulative_proof_target: u128,
         previous_block_hash: N::BlockHash,
@@ -71,13 +78,31 @@ impl FinalizeGlobalState {
         let mut random_seed = [0u8; 32];
         random_seed.copy_from_slice(&seed[..32]);
 
-        Ok(Self { block_round, block_height, random_seed })
+        Ok(Self { block_round, block_height, block_timestamp, random_seed, block_randomness: None })
     }
 
     /// Initializes a new global state.
     #[inline]
-    pub const fn from(block_round: u64, block_height: u32, random_seed: [u8; 32]) -> Self {
-        Self { block_round, block_height, random_seed }
+    pub const fn from(block_round: u64, block_height: u32, block_timestamp: i64, random_seed: [u8; 32]) -> Self {
+        Self { block_round, block_height, block_timestamp, random_seed, block_randomness: None }
+    }
+
+    /// Returns the global state with the given external randomness, as ratified in the block.
+    #[inline]
+    pub fn with_block_randomness<N: Network>(self, block_randomness: Option<Field<N>>) -> Result<Self> {
+        // Convert the randomness into a 32-byte array.
+        let block_randomness = match block_randomness {
+            Some(randomness) => {
+                let bytes = randomness.to_bytes_le()?;
+                // Ensure the randomness is 32-bytes.
+                ensure!(bytes.len() == 32, "Invalid randomness length for finalize global state.");
+                let mut block_randomness = [0u8; 32];
+                block_randomness.copy_from_slice(&bytes);
+                Some(block_randomness)
+            }
+            None => None,
+        };
+        Ok(Self { block_randomness, ..self })
     }
 
     /// Returns the block round.
@@ -92,9 +117,27 @@ impl FinalizeGlobalState {
         self.block_height
     }
 
+    /// Returns the block timestamp.
+    #[inline]
+    pub const fn block_timestamp(&self) -> i64 {
+        self.block_timestamp
+    }
+
     /// Returns the random seed.
+    ///
+    /// The random seed is known to every validator once the block is proposed,
+    /// and is therefore not a source of secret randomness.
     #[inline]
     pub const fn random_seed(&self) -> &[u8; 32] {
         &self.random_seed
     }
+
+    /// Returns the external randomness ratified in the block, if any.
+    ///
+    /// Unlike the random seed, this value is produced by the network's randomness beacon,
+    /// and cannot be influenced by the block proposer.
+    #[inline]
+    pub const fn block_randomness(&self) -> Option<&[u8; 32]> {
+        self.block_randomness.as_ref()
+    }
 }
@@ -79,7 +79,21 @@ impl<N: Network> FromBits for FinalizeOperation<N> {
                 // Return the finalize operation.
                 Ok(Self::RemoveMapping(mapping_id))
             }
-            6.. => bail!("Invalid finalize operation variant '{variant}'"),
+            6 => {
+                // Read the program ID.
+                let program_id = Field::from_bits_le(&next_bits(Field::<N>::size_in_bits())?)?;
+                // Return the finalize operation.
+                Ok(Self::DeprecateProgram(program_id))
+            }
+            7 => {
+                // Read the program ID.
+                let program_id = Field::from_bits_le(&next_bits(Field::<N>::size_in_bits())?)?;
+                // Read the event, from the remaining bits.
+                let event = Plaintext::from_bits_le(&bits.collect::<Vec<_>>())?;
+                // Return the finalize operation.
+                Ok(Self::Event(program_id, event))
+            }
+            8.. => bail!("Invalid finalize operation variant '{variant}'"),
         }
     }
 
@@ -147,7 +161,21 @@ impl<N: Network> FromBits for FinalizeOperation<N> {
                 // Return the finalize operation.
                 Ok(Self::RemoveMapping(mapping_id))
             }
-            6.. => bail!("Invalid finalize operation variant '{variant}'"),
+            6 => {
+                // Read the program ID.
+                let program_id = Field::from_bits_be(&next_bits(Field::<N>::size_in_bits())?)?;
+                // Return the finalize operation.
+                Ok(Self::DeprecateProgram(program_id))
+            }
+            7 => {
+                // Read the program ID.
+                let program_id = Field::from_bits_be(&next_bits(Field::<N>::size_in_bits())?)?;
+                // Read the event, from the remaining bits.
+                let event = Plaintext::from_bits_be(&bits.collect::<Vec<_>>())?;
+                // Return the finalize operation.
+                Ok(Self::Event(program_id, event))
+            }
+            8.. => bail!("Invalid finalize operation variant '{variant}'"),
         }
     }
 }
@@ -202,6 +230,20 @@ impl<N: Network> ToBits for FinalizeOperation<N> {
                 // Write the mapping ID.
                 mapping_id.write_bits_le(vec);
             }
+            Self::DeprecateProgram(program_id) => {
+                // Write the variant.
+                6u8.write_bits_le(vec);
+                // Write the program ID.
+                program_id.write_bits_le(vec);
+            }
+            Self::Event(program_id, event) => {
+                // Write the variant.
+                7u8.write_bits_le(vec);
+                // Write the program ID.
+                program_id.write_bits_le(vec);
+                // Write the event.
+                event.write_bits_le(vec);
+            }
         }
     }
 
@@ -254,6 +296,20 @@ impl<N: Network> ToBits for FinalizeOperation<N> {
                 // Write the mapping ID.
                 mapping_id.write_bits_be(vec);
             }
+            Self::DeprecateProgram(program_id) => {
+                // Write the variant.
+                6u8.write_bits_be(vec);
+                // Write the program ID.
+                program_id.write_bits_be(vec);
+            }
+            Self::Event(program_id, event) => {
+                // Write the variant.
+                7u8.write_bits_be(vec);
+                // Write the program ID.
+                program_id.write_bits_be(vec);
+                // Write the event.
+                event.write_bits_be(vec);
+            }
         }
     }
 }
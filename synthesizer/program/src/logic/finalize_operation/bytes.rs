@@ -67,7 +67,21 @@ impl<N: Network> FromBytes for FinalizeOperation<N> {
                 // Return the finalize operation.
                 Ok(Self::RemoveMapping(mapping_id))
             }
-            6.. => Err(error(format!("Failed to decode finalize operation variant {variant}"))),
+            6 => {
+                // Read the program ID.
+                let program_id = Field::read_le(&mut reader)?;
+                // Return the finalize operation.
+                Ok(Self::DeprecateProgram(program_id))
+            }
+            7 => {
+                // Read the program ID.
+                let program_id = Field::read_le(&mut reader)?;
+                // Read the event.
+                let event = Plaintext::read_le(&mut reader)?;
+                // Return the finalize operation.
+                Ok(Self::Event(program_id, event))
+            }
+            8.. => Err(error(format!("Failed to decode finalize operation variant {variant}"))),
         }
     }
 }
@@ -122,6 +136,20 @@ impl<N: Network> ToBytes for FinalizeOperation<N> {
                 // Write the mapping ID.
                 mapping_id.write_le(&mut writer)?;
             }
+            Self::DeprecateProgram(program_id) => {
+                // Write the variant.
+                6u8.write_le(&mut writer)?;
+                // Write the program ID.
+                program_id.write_le(&mut writer)?;
+            }
+            Self::Event(program_id, event) => {
+                // Write the variant.
+                7u8.write_le(&mut writer)?;
+                // Write the program ID.
+                program_id.write_le(&mut writer)?;
+                // Write the event.
+                event.write_le(&mut writer)?;
+            }
         }
         Ok(())
     }
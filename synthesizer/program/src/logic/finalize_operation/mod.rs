@@ -17,10 +17,10 @@ mod bytes;
 mod serialize;
 mod string;
 
-use console::{network::prelude::*, types::Field};
+use console::{network::prelude::*, program::Plaintext, types::Field};
 
 /// Enum to represent the allowed set of Merkle tree operations.
-#[derive(Copy, Clone, PartialEq, Eq)]
+#[derive(Clone, PartialEq, Eq)]
 pub enum FinalizeOperation<N: Network> {
     /// Appends a mapping to the program tree, as (`mapping ID`).
     InitializeMapping(Field<N>),
@@ -37,6 +37,11 @@ pub enum FinalizeOperation<N: Network> {
     ReplaceMapping(Field<N>),
     /// Removes a mapping from the program tree, as (`mapping ID`).
     RemoveMapping(Field<N>),
+    /// Marks a program as deprecated, as (`program ID`).
+    /// The mappings of the program are preserved, so its state remains readable.
+    DeprecateProgram(Field<N>),
+    /// Records an event emitted by a program, as (`program ID`, `event`).
+    Event(Field<N>, Plaintext<N>),
 }
 
 #[cfg(test)]
@@ -76,6 +81,17 @@ pub(crate) mod test_helpers {
         FinalizeOperation::RemoveMapping(Uniform::rand(rng))
     }
 
+    /// Samples a random `DeprecateProgram`.
+    pub(crate) fn sample_deprecate_program(rng: &mut TestRng) -> FinalizeOperation<CurrentNetwork> {
+        FinalizeOperation::DeprecateProgram(Uniform::rand(rng))
+    }
+
+    /// Samples a random `Event`.
+    pub(crate) fn sample_event(rng: &mut TestRng) -> FinalizeOperation<CurrentNetwork> {
+        let event = Plaintext::from_str(&format!("{{ amount: {}u64, recipient: {}field }}", rng.gen::<u64>(), rng.gen::<u32>()));
+        FinalizeOperation::Event(Uniform::rand(rng), event.unwrap())
+    }
+
     /// Samples a list of random `FinalizeOperation`.
     pub(crate) fn sample_finalize_operations() -> Vec<FinalizeOperation<CurrentNetwork>> {
         let rng = &mut TestRng::default();
@@ -93,6 +109,8 @@ pub(crate) mod test_helpers {
             sample_remove_key_value(rng),
             sample_replace_mapping(rng),
             sample_remove_mapping(rng),
+            sample_deprecate_program(rng),
+            sample_event(rng),
         ]
     }
 }
@@ -62,6 +62,19 @@ impl<N: Network> Serialize for FinalizeOperation<N> {
                         operation.serialize_field("mapping_id", mapping_id)?;
                         operation.end()
                     }
+                    Self::DeprecateProgram(program_id) => {
+                        let mut operation = serializer.serialize_struct("FinalizeOperation", 2)?;
+                        operation.serialize_field("type", "deprecate_program")?;
+                        operation.serialize_field("program_id", program_id)?;
+                        operation.end()
+                    }
+                    Self::Event(program_id, event) => {
+                        let mut operation = serializer.serialize_struct("FinalizeOperation", 3)?;
+                        operation.serialize_field("type", "event")?;
+                        operation.serialize_field("program_id", program_id)?;
+                        operation.serialize_field("event", event)?;
+                        operation.end()
+                    }
                 }
             }
             false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
@@ -123,6 +136,20 @@ impl<'de, N: Network> Deserialize<'de> for FinalizeOperation<N> {
                         // Return the operation.
                         Self::RemoveMapping(mapping_id)
                     }
+                    Some("deprecate_program") => {
+                        // Deserialize the program ID.
+                        let program_id = DeserializeExt::take_from_value::<D>(&mut operation, "program_id")?;
+                        // Return the operation.
+                        Self::DeprecateProgram(program_id)
+                    }
+                    Some("event") => {
+                        // Deserialize the program ID.
+                        let program_id = DeserializeExt::take_from_value::<D>(&mut operation, "program_id")?;
+                        // Deserialize the event.
+                        let event = DeserializeExt::take_from_value::<D>(&mut operation, "event")?;
+                        // Return the operation.
+                        Self::Event(program_id, event)
+                    }
                     _ => return Err(de::Error::custom("Invalid finalize operation type")),
                 };
                 // Return the operation.
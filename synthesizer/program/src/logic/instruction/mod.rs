@@ -201,6 +201,18 @@ pub enum Instruction<N: Network> {
     Ternary(Ternary<N>),
     /// Performs a bitwise `xor` on `first` and `second`, storing the outcome in `destination`.
     Xor(Xor<N>),
+    /// Computes whether an ECDSA `signature` over secp256k1 is valid for the given `public_key` and `digest`.
+    EcdsaVerify(EcdsaVerify<N>),
+    /// Computes whether an Ed25519 `signature` is valid for the given `public_key` and `message`.
+    Ed25519Verify(Ed25519Verify<N>),
+    /// Performs a Keccak256 hash on the first `length` bytes of `data`, storing the 32-byte digest in `destination`.
+    HashBytesKeccak256(HashBytesKeccak256<N>),
+    /// Performs a SHA3-256 hash on the first `length` bytes of `data`, storing the 32-byte digest in `destination`.
+    HashBytesSha3_256(HashBytesSha3_256<N>),
+    /// Divides `first` by `second`, checking the division over the halves of the operands, storing the outcome in `destination`.
+    DivWide(DivWide<N>),
+    /// Divides `first` by `second`, checking the division over the halves of the operands, storing the remainder in `destination`.
+    RemWide(RemWide<N>),
 }
 
 /// Creates a match statement that applies the given operation for each instruction.
@@ -300,6 +312,12 @@ macro_rules! instruction {
             SubWrapped,
             Ternary,
             Xor,
+            EcdsaVerify,
+            Ed25519Verify,
+            HashBytesKeccak256,
+            HashBytesSha3_256,
+            DivWide,
+            RemWide,
         }}
     };
     // A variant **without** curly braces:
@@ -469,7 +487,7 @@ mod tests {
     fn test_opcodes() {
         // Sanity check the number of instructions is unchanged.
         assert_eq!(
-            68,
+            74,
             Instruction::<CurrentNetwork>::OPCODES.len(),
             "Update me if the number of instructions changes."
         );
@@ -0,0 +1,249 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use console::program::LiteralType;
+
+/// The number of constants, public variables, private variables, and constraints
+/// synthesized by an opcode on one combination of input types.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CostProfile {
+    /// The input types.
+    input_types: &'static [LiteralType],
+    /// The number of constants.
+    num_constants: u64,
+    /// The number of public variables.
+    num_public: u64,
+    /// The number of private variables.
+    num_private: u64,
+    /// The number of constraints.
+    num_constraints: u64,
+}
+
+impl CostProfile {
+    /// Initializes a new cost profile.
+    pub(super) const fn new(
+        input_types: &'static [LiteralType],
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) -> Self {
+        Self { input_types, num_constants, num_public, num_private, num_constraints }
+    }
+
+    /// Returns the input types.
+    pub const fn input_types(&self) -> &'static [LiteralType] {
+        self.input_types
+    }
+
+    /// Returns the number of constants.
+    pub const fn num_constants(&self) -> u64 {
+        self.num_constants
+    }
+
+    /// Returns the number of public variables.
+    pub const fn num_public(&self) -> u64 {
+        self.num_public
+    }
+
+    /// Returns the number of private variables.
+    pub const fn num_private(&self) -> u64 {
+        self.num_private
+    }
+
+    /// Returns the number of variables.
+    pub const fn num_variables(&self) -> u64 {
+        self.num_public + self.num_private
+    }
+
+    /// Returns the number of constraints.
+    pub const fn num_constraints(&self) -> u64 {
+        self.num_constraints
+    }
+}
+
+impl Opcode {
+    /// Returns the cost profile of the opcode for each supported combination of input types.
+    /// The profiles are measured on private inputs, and exclude the cost of injecting the inputs.
+    ///
+    /// Note: Only literal opcodes (i.e. `add`) are profiled. For all other opcodes, this returns an empty slice.
+    pub fn cost_profile(&self) -> &'static [CostProfile] {
+        match self {
+            Self::Literal(opcode) => {
+                COST_TABLE.iter().find(|(name, _)| name == opcode).map_or(&[], |(_, profiles)| profiles)
+            }
+            _ => &[],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+    use circuit::{Environment, Inject, Mode};
+    use console::program::Literal;
+
+    type CurrentNetwork = console::network::MainnetV0;
+    type CurrentAleo = circuit::network::AleoV0;
+
+    /// The path of the generated cost table.
+    const COST_TABLE_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/logic/instruction/opcode/cost_table.rs");
+
+    /// Returns the path of the given literal type variant, i.e. `LiteralType::I8`.
+    fn variant_of(input_type: &LiteralType) -> String {
+        // Note: The variant name is the capitalized type name.
+        let type_name = input_type.type_name();
+        format!("LiteralType::{}{}", type_name[..1].to_uppercase(), &type_name[1..])
+    }
+
+    /// Returns the cost table entry for the given operation.
+    fn profile<const NUM_OPERANDS: usize>(
+        opcode: Opcode,
+        input_types: &[[LiteralType; NUM_OPERANDS]],
+        execute: fn(&[circuit::Literal<CurrentAleo>; NUM_OPERANDS]) -> Result<circuit::Literal<CurrentAleo>>,
+    ) -> Result<String> {
+        // Use a fixed seed, so the table is reproducible.
+        let rng = &mut TestRng::fixed(1234567890);
+
+        let mut entry = format!("    (\"{opcode}\", &[\n");
+        for input_types in input_types {
+            // Inject the inputs as private variables.
+            CurrentAleo::reset();
+            let inputs = input_types
+                .map(|input_type| circuit::Literal::new(Mode::Private, Literal::<CurrentNetwork>::sample(input_type, rng)));
+            // Count the constants, variables, and constraints synthesized by the operation.
+            let (constants, public, private, constraints) = (
+                CurrentAleo::num_constants(),
+                CurrentAleo::num_public(),
+                CurrentAleo::num_private(),
+                CurrentAleo::num_constraints(),
+            );
+            execute(&inputs)?;
+            entry.push_str(&format!(
+                "        CostProfile::new(&[{}], {}, {}, {}, {}),\n",
+                input_types.iter().map(variant_of).collect::<Vec<_>>().join(", "),
+                CurrentAleo::num_constants() - constants,
+                CurrentAleo::num_public() - public,
+                CurrentAleo::num_private() - private,
+                CurrentAleo::num_constraints() - constraints,
+            ));
+        }
+        CurrentAleo::reset();
+        entry.push_str("    ]),\n");
+        Ok(entry)
+    }
+
+    /// Returns the generated cost table, as a Rust source file.
+    fn generate_cost_table() -> Result<String> {
+        macro_rules! profile {
+            ($($operation:ident),+ $(,)?) => {
+                [$(profile(
+                    <$operation<CurrentNetwork>>::OPCODE,
+                    $operation::<CurrentNetwork>::INPUT_TYPES,
+                    $operation::<CurrentNetwork>::execute::<CurrentAleo>,
+                )?),+]
+            };
+        }
+
+        let entries = profile!(
+            AbsOperation,
+            AbsWrappedOperation,
+            AddOperation,
+            AddWrappedOperation,
+            AndOperation,
+            DivOperation,
+            DivWrappedOperation,
+            DivWideOperation,
+            DoubleOperation,
+            GreaterThanOperation,
+            GreaterThanOrEqualOperation,
+            InvOperation,
+            LessThanOperation,
+            LessThanOrEqualOperation,
+            ModuloOperation,
+            MulOperation,
+            MulWrappedOperation,
+            NandOperation,
+            NegOperation,
+            NorOperation,
+            NotOperation,
+            OrOperation,
+            PowOperation,
+            PowWrappedOperation,
+            RemOperation,
+            RemWrappedOperation,
+            RemWideOperation,
+            ShlOperation,
+            ShlWrappedOperation,
+            ShrOperation,
+            ShrWrappedOperation,
+            SquareOperation,
+            SquareRootOperation,
+            SubOperation,
+            SubWrappedOperation,
+            TernaryOperation,
+            XorOperation,
+        );
+
+        // Retrieve the license header of this file.
+        let header = include_str!("cost_profile.rs")
+            .lines()
+            .take_while(|line| line.is_empty() || line.starts_with("//"))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let mut table = format!("{}\n\n", header.trim_end());
+        table.push_str("// @generated by `test_cost_table`. Do not edit this file manually.\n");
+        table.push_str("// To regenerate it, run the test with the `REWRITE_EXPECTATIONS` environment variable set.\n\n");
+        table.push_str("use super::CostProfile;\nuse console::program::LiteralType;\n\n");
+        table.push_str("/// The cost profiles of the literal opcodes, for each supported combination of input types.\n");
+        table.push_str("#[rustfmt::skip]\npub(super) const COST_TABLE: &[(&str, &[CostProfile])] = &[\n");
+        entries.iter().for_each(|entry| table.push_str(entry));
+        table.push_str("];\n");
+        Ok(table)
+    }
+
+    #[test]
+    fn test_cost_table() -> Result<()> {
+        let expected = generate_cost_table()?;
+        if std::env::var("REWRITE_EXPECTATIONS").is_ok() {
+            std::fs::write(COST_TABLE_PATH, expected)?;
+        } else {
+            assert_eq!(
+                include_str!("cost_table.rs"),
+                expected,
+                "The cost table is out of date. Rerun this test with `REWRITE_EXPECTATIONS` set to regenerate it."
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_cost_profile() {
+        // Check that every literal opcode has a cost profile for each combination of input types.
+        let add = AddOperation::<CurrentNetwork>::OPCODE.cost_profile();
+        assert_eq!(add.len(), AddOperation::<CurrentNetwork>::INPUT_TYPES.len());
+        let ternary = TernaryOperation::<CurrentNetwork>::OPCODE.cost_profile();
+        assert_eq!(ternary.len(), TernaryOperation::<CurrentNetwork>::INPUT_TYPES.len());
+        // Check that the opcodes that are not profiled return an empty profile.
+        assert!(Opcode::Call.cost_profile().is_empty());
+        assert!(Opcode::Hash("hash.bhp256").cost_profile().is_empty());
+        // Check that adding fields synthesizes no variables or constraints.
+        let field = add.iter().find(|profile| profile.input_types() == [LiteralType::Field, LiteralType::Field]).unwrap();
+        assert_eq!(field.num_variables(), 0);
+        assert_eq!(field.num_constraints(), 0);
+    }
+}
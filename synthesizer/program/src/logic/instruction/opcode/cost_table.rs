@@ -0,0 +1,529 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// @generated by `test_cost_table`. Do not edit this file manually.
+// To regenerate it, run the test with the `REWRITE_EXPECTATIONS` environment variable set.
+
+use super::CostProfile;
+use console::program::LiteralType;
+
+/// The cost profiles of the literal opcodes, for each supported combination of input types.
+#[rustfmt::skip]
+pub(super) const COST_TABLE: &[(&str, &[CostProfile])] = &[
+    ("abs", &[
+        CostProfile::new(&[LiteralType::I8], 8, 0, 19, 21),
+        CostProfile::new(&[LiteralType::I16], 16, 0, 35, 37),
+        CostProfile::new(&[LiteralType::I32], 32, 0, 67, 69),
+        CostProfile::new(&[LiteralType::I64], 64, 0, 131, 133),
+        CostProfile::new(&[LiteralType::I128], 128, 0, 259, 261),
+    ]),
+    ("abs.w", &[
+        CostProfile::new(&[LiteralType::I8], 8, 0, 17, 18),
+        CostProfile::new(&[LiteralType::I16], 16, 0, 33, 34),
+        CostProfile::new(&[LiteralType::I32], 32, 0, 65, 66),
+        CostProfile::new(&[LiteralType::I64], 64, 0, 129, 130),
+        CostProfile::new(&[LiteralType::I128], 128, 0, 257, 258),
+    ]),
+    ("add", &[
+        CostProfile::new(&[LiteralType::Field, LiteralType::Field], 0, 0, 0, 0),
+        CostProfile::new(&[LiteralType::Group, LiteralType::Group], 2, 0, 6, 6),
+        CostProfile::new(&[LiteralType::I8, LiteralType::I8], 0, 0, 12, 14),
+        CostProfile::new(&[LiteralType::I16, LiteralType::I16], 0, 0, 20, 22),
+        CostProfile::new(&[LiteralType::I32, LiteralType::I32], 0, 0, 36, 38),
+        CostProfile::new(&[LiteralType::I64, LiteralType::I64], 0, 0, 68, 70),
+        CostProfile::new(&[LiteralType::I128, LiteralType::I128], 0, 0, 132, 134),
+        CostProfile::new(&[LiteralType::U8, LiteralType::U8], 0, 0, 8, 9),
+        CostProfile::new(&[LiteralType::U16, LiteralType::U16], 0, 0, 16, 17),
+        CostProfile::new(&[LiteralType::U32, LiteralType::U32], 0, 0, 32, 33),
+        CostProfile::new(&[LiteralType::U64, LiteralType::U64], 0, 0, 64, 65),
+        CostProfile::new(&[LiteralType::U128, LiteralType::U128], 0, 0, 128, 129),
+        CostProfile::new(&[LiteralType::Scalar, LiteralType::Scalar], 1, 0, 755, 757),
+    ]),
+    ("add.w", &[
+        CostProfile::new(&[LiteralType::I8, LiteralType::I8], 0, 0, 9, 10),
+        CostProfile::new(&[LiteralType::I16, LiteralType::I16], 0, 0, 17, 18),
+        CostProfile::new(&[LiteralType::I32, LiteralType::I32], 0, 0, 33, 34),
+        CostProfile::new(&[LiteralType::I64, LiteralType::I64], 0, 0, 65, 66),
+        CostProfile::new(&[LiteralType::I128, LiteralType::I128], 0, 0, 129, 130),
+        CostProfile::new(&[LiteralType::U8, LiteralType::U8], 0, 0, 9, 10),
+        CostProfile::new(&[LiteralType::U16, LiteralType::U16], 0, 0, 17, 18),
+        CostProfile::new(&[LiteralType::U32, LiteralType::U32], 0, 0, 33, 34),
+        CostProfile::new(&[LiteralType::U64, LiteralType::U64], 0, 0, 65, 66),
+        CostProfile::new(&[LiteralType::U128, LiteralType::U128], 0, 0, 129, 130),
+    ]),
+    ("and", &[
+        CostProfile::new(&[LiteralType::Boolean, LiteralType::Boolean], 0, 0, 1, 1),
+        CostProfile::new(&[LiteralType::I8, LiteralType::I8], 0, 0, 8, 8),
+        CostProfile::new(&[LiteralType::I16, LiteralType::I16], 0, 0, 16, 16),
+        CostProfile::new(&[LiteralType::I32, LiteralType::I32], 0, 0, 32, 32),
+        CostProfile::new(&[LiteralType::I64, LiteralType::I64], 0, 0, 64, 64),
+        CostProfile::new(&[LiteralType::I128, LiteralType::I128], 0, 0, 128, 128),
+        CostProfile::new(&[LiteralType::U8, LiteralType::U8], 0, 0, 8, 8),
+        CostProfile::new(&[LiteralType::U16, LiteralType::U16], 0, 0, 16, 16),
+        CostProfile::new(&[LiteralType::U32, LiteralType::U32], 0, 0, 32, 32),
+        CostProfile::new(&[LiteralType::U64, LiteralType::U64], 0, 0, 64, 64),
+        CostProfile::new(&[LiteralType::U128, LiteralType::U128], 0, 0, 128, 128),
+    ]),
+    ("div", &[
+        CostProfile::new(&[LiteralType::Field, LiteralType::Field], 0, 0, 2, 2),
+        CostProfile::new(&[LiteralType::I8, LiteralType::I8], 48, 0, 83, 90),
+        CostProfile::new(&[LiteralType::I16, LiteralType::I16], 96, 0, 155, 162),
+        CostProfile::new(&[LiteralType::I32, LiteralType::I32], 192, 0, 299, 306),
+        CostProfile::new(&[LiteralType::I64, LiteralType::I64], 384, 0, 587, 594),
+        CostProfile::new(&[LiteralType::I128, LiteralType::I128], 768, 0, 1486, 1497),
+        CostProfile::new(&[LiteralType::U8, LiteralType::U8], 8, 0, 26, 29),
+        CostProfile::new(&[LiteralType::U16, LiteralType::U16], 16, 0, 50, 53),
+        CostProfile::new(&[LiteralType::U32, LiteralType::U32], 32, 0, 98, 101),
+        CostProfile::new(&[LiteralType::U64, LiteralType::U64], 64, 0, 194, 197),
+        CostProfile::new(&[LiteralType::U128, LiteralType::U128], 128, 0, 709, 716),
+    ]),
+    ("div.w", &[
+        CostProfile::new(&[LiteralType::I8, LiteralType::I8], 32, 0, 78, 84),
+        CostProfile::new(&[LiteralType::I16, LiteralType::I16], 64, 0, 150, 156),
+        CostProfile::new(&[LiteralType::I32, LiteralType::I32], 128, 0, 294, 300),
+        CostProfile::new(&[LiteralType::I64, LiteralType::I64], 256, 0, 582, 588),
+        CostProfile::new(&[LiteralType::I128, LiteralType::I128], 512, 0, 1481, 1491),
+        CostProfile::new(&[LiteralType::U8, LiteralType::U8], 8, 0, 26, 29),
+        CostProfile::new(&[LiteralType::U16, LiteralType::U16], 16, 0, 50, 53),
+        CostProfile::new(&[LiteralType::U32, LiteralType::U32], 32, 0, 98, 101),
+        CostProfile::new(&[LiteralType::U64, LiteralType::U64], 64, 0, 194, 197),
+        CostProfile::new(&[LiteralType::U128, LiteralType::U128], 128, 0, 709, 716),
+    ]),
+    ("div.wide", &[
+        CostProfile::new(&[LiteralType::I128, LiteralType::I128], 768, 0, 1164, 1172),
+        CostProfile::new(&[LiteralType::U128, LiteralType::U128], 128, 0, 387, 391),
+    ]),
+    ("double", &[
+        CostProfile::new(&[LiteralType::Field], 0, 0, 0, 0),
+        CostProfile::new(&[LiteralType::Group], 1, 0, 5, 5),
+    ]),
+    ("gt", &[
+        CostProfile::new(&[LiteralType::Field, LiteralType::Field], 0, 0, 1516, 1520),
+        CostProfile::new(&[LiteralType::I8, LiteralType::I8], 8, 0, 12, 13),
+        CostProfile::new(&[LiteralType::I16, LiteralType::I16], 16, 0, 20, 21),
+        CostProfile::new(&[LiteralType::I32, LiteralType::I32], 32, 0, 36, 37),
+        CostProfile::new(&[LiteralType::I64, LiteralType::I64], 64, 0, 68, 69),
+        CostProfile::new(&[LiteralType::I128, LiteralType::I128], 128, 0, 132, 133),
+        CostProfile::new(&[LiteralType::U8, LiteralType::U8], 8, 0, 9, 10),
+        CostProfile::new(&[LiteralType::U16, LiteralType::U16], 16, 0, 17, 18),
+        CostProfile::new(&[LiteralType::U32, LiteralType::U32], 32, 0, 33, 34),
+        CostProfile::new(&[LiteralType::U64, LiteralType::U64], 64, 0, 65, 66),
+        CostProfile::new(&[LiteralType::U128, LiteralType::U128], 128, 0, 129, 130),
+        CostProfile::new(&[LiteralType::Scalar, LiteralType::Scalar], 0, 0, 505, 507),
+    ]),
+    ("gte", &[
+        CostProfile::new(&[LiteralType::Field, LiteralType::Field], 0, 0, 1516, 1520),
+        CostProfile::new(&[LiteralType::I8, LiteralType::I8], 8, 0, 12, 13),
+        CostProfile::new(&[LiteralType::I16, LiteralType::I16], 16, 0, 20, 21),
+        CostProfile::new(&[LiteralType::I32, LiteralType::I32], 32, 0, 36, 37),
+        CostProfile::new(&[LiteralType::I64, LiteralType::I64], 64, 0, 68, 69),
+        CostProfile::new(&[LiteralType::I128, LiteralType::I128], 128, 0, 132, 133),
+        CostProfile::new(&[LiteralType::U8, LiteralType::U8], 8, 0, 9, 10),
+        CostProfile::new(&[LiteralType::U16, LiteralType::U16], 16, 0, 17, 18),
+        CostProfile::new(&[LiteralType::U32, LiteralType::U32], 32, 0, 33, 34),
+        CostProfile::new(&[LiteralType::U64, LiteralType::U64], 64, 0, 65, 66),
+        CostProfile::new(&[LiteralType::U128, LiteralType::U128], 128, 0, 129, 130),
+        CostProfile::new(&[LiteralType::Scalar, LiteralType::Scalar], 0, 0, 505, 507),
+    ]),
+    ("inv", &[
+        CostProfile::new(&[LiteralType::Field], 0, 0, 1, 1),
+    ]),
+    ("lt", &[
+        CostProfile::new(&[LiteralType::Field, LiteralType::Field], 0, 0, 1516, 1520),
+        CostProfile::new(&[LiteralType::I8, LiteralType::I8], 8, 0, 12, 13),
+        CostProfile::new(&[LiteralType::I16, LiteralType::I16], 16, 0, 20, 21),
+        CostProfile::new(&[LiteralType::I32, LiteralType::I32], 32, 0, 36, 37),
+        CostProfile::new(&[LiteralType::I64, LiteralType::I64], 64, 0, 68, 69),
+        CostProfile::new(&[LiteralType::I128, LiteralType::I128], 128, 0, 132, 133),
+        CostProfile::new(&[LiteralType::U8, LiteralType::U8], 8, 0, 9, 10),
+        CostProfile::new(&[LiteralType::U16, LiteralType::U16], 16, 0, 17, 18),
+        CostProfile::new(&[LiteralType::U32, LiteralType::U32], 32, 0, 33, 34),
+        CostProfile::new(&[LiteralType::U64, LiteralType::U64], 64, 0, 65, 66),
+        CostProfile::new(&[LiteralType::U128, LiteralType::U128], 128, 0, 129, 130),
+        CostProfile::new(&[LiteralType::Scalar, LiteralType::Scalar], 0, 0, 505, 507),
+    ]),
+    ("lte", &[
+        CostProfile::new(&[LiteralType::Field, LiteralType::Field], 0, 0, 1516, 1520),
+        CostProfile::new(&[LiteralType::I8, LiteralType::I8], 8, 0, 12, 13),
+        CostProfile::new(&[LiteralType::I16, LiteralType::I16], 16, 0, 20, 21),
+        CostProfile::new(&[LiteralType::I32, LiteralType::I32], 32, 0, 36, 37),
+        CostProfile::new(&[LiteralType::I64, LiteralType::I64], 64, 0, 68, 69),
+        CostProfile::new(&[LiteralType::I128, LiteralType::I128], 128, 0, 132, 133),
+        CostProfile::new(&[LiteralType::U8, LiteralType::U8], 8, 0, 9, 10),
+        CostProfile::new(&[LiteralType::U16, LiteralType::U16], 16, 0, 17, 18),
+        CostProfile::new(&[LiteralType::U32, LiteralType::U32], 32, 0, 33, 34),
+        CostProfile::new(&[LiteralType::U64, LiteralType::U64], 64, 0, 65, 66),
+        CostProfile::new(&[LiteralType::U128, LiteralType::U128], 128, 0, 129, 130),
+        CostProfile::new(&[LiteralType::Scalar, LiteralType::Scalar], 0, 0, 505, 507),
+    ]),
+    ("mod", &[
+        CostProfile::new(&[LiteralType::U8, LiteralType::U8], 8, 0, 26, 29),
+        CostProfile::new(&[LiteralType::U16, LiteralType::U16], 16, 0, 50, 53),
+        CostProfile::new(&[LiteralType::U32, LiteralType::U32], 32, 0, 98, 101),
+        CostProfile::new(&[LiteralType::U64, LiteralType::U64], 64, 0, 194, 197),
+        CostProfile::new(&[LiteralType::U128, LiteralType::U128], 128, 0, 709, 716),
+    ]),
+    ("mul", &[
+        CostProfile::new(&[LiteralType::Field, LiteralType::Field], 0, 0, 1, 1),
+        CostProfile::new(&[LiteralType::Group, LiteralType::Scalar], 750, 0, 3753, 3755),
+        CostProfile::new(&[LiteralType::Scalar, LiteralType::Group], 750, 0, 3753, 3755),
+        CostProfile::new(&[LiteralType::I8, LiteralType::I8], 24, 0, 70, 76),
+        CostProfile::new(&[LiteralType::I16, LiteralType::I16], 48, 0, 134, 140),
+        CostProfile::new(&[LiteralType::I32, LiteralType::I32], 96, 0, 262, 268),
+        CostProfile::new(&[LiteralType::I64, LiteralType::I64], 192, 0, 518, 524),
+        CostProfile::new(&[LiteralType::I128, LiteralType::I128], 384, 0, 1098, 1106),
+        CostProfile::new(&[LiteralType::U8, LiteralType::U8], 0, 0, 8, 9),
+        CostProfile::new(&[LiteralType::U16, LiteralType::U16], 0, 0, 16, 17),
+        CostProfile::new(&[LiteralType::U32, LiteralType::U32], 0, 0, 32, 33),
+        CostProfile::new(&[LiteralType::U64, LiteralType::U64], 0, 0, 64, 65),
+        CostProfile::new(&[LiteralType::U128, LiteralType::U128], 0, 0, 196, 199),
+    ]),
+    ("mul.w", &[
+        CostProfile::new(&[LiteralType::I8, LiteralType::I8], 0, 0, 16, 17),
+        CostProfile::new(&[LiteralType::I16, LiteralType::I16], 0, 0, 28, 29),
+        CostProfile::new(&[LiteralType::I32, LiteralType::I32], 0, 0, 52, 53),
+        CostProfile::new(&[LiteralType::I64, LiteralType::I64], 0, 0, 100, 101),
+        CostProfile::new(&[LiteralType::I128, LiteralType::I128], 0, 0, 196, 197),
+        CostProfile::new(&[LiteralType::U8, LiteralType::U8], 0, 0, 16, 17),
+        CostProfile::new(&[LiteralType::U16, LiteralType::U16], 0, 0, 28, 29),
+        CostProfile::new(&[LiteralType::U32, LiteralType::U32], 0, 0, 52, 53),
+        CostProfile::new(&[LiteralType::U64, LiteralType::U64], 0, 0, 100, 101),
+        CostProfile::new(&[LiteralType::U128, LiteralType::U128], 0, 0, 196, 197),
+    ]),
+    ("nand", &[
+        CostProfile::new(&[LiteralType::Boolean, LiteralType::Boolean], 0, 0, 1, 1),
+    ]),
+    ("neg", &[
+        CostProfile::new(&[LiteralType::Field], 0, 0, 0, 0),
+        CostProfile::new(&[LiteralType::Group], 0, 0, 0, 0),
+        CostProfile::new(&[LiteralType::I8], 8, 0, 10, 12),
+        CostProfile::new(&[LiteralType::I16], 16, 0, 18, 20),
+        CostProfile::new(&[LiteralType::I32], 32, 0, 34, 36),
+        CostProfile::new(&[LiteralType::I64], 64, 0, 66, 68),
+        CostProfile::new(&[LiteralType::I128], 128, 0, 130, 132),
+    ]),
+    ("nor", &[
+        CostProfile::new(&[LiteralType::Boolean, LiteralType::Boolean], 0, 0, 1, 1),
+    ]),
+    ("not", &[
+        CostProfile::new(&[LiteralType::Boolean], 0, 0, 0, 0),
+        CostProfile::new(&[LiteralType::I8], 0, 0, 0, 0),
+        CostProfile::new(&[LiteralType::I16], 0, 0, 0, 0),
+        CostProfile::new(&[LiteralType::I32], 0, 0, 0, 0),
+        CostProfile::new(&[LiteralType::I64], 0, 0, 0, 0),
+        CostProfile::new(&[LiteralType::I128], 0, 0, 0, 0),
+        CostProfile::new(&[LiteralType::U8], 0, 0, 0, 0),
+        CostProfile::new(&[LiteralType::U16], 0, 0, 0, 0),
+        CostProfile::new(&[LiteralType::U32], 0, 0, 0, 0),
+        CostProfile::new(&[LiteralType::U64], 0, 0, 0, 0),
+        CostProfile::new(&[LiteralType::U128], 0, 0, 0, 0),
+    ]),
+    ("or", &[
+        CostProfile::new(&[LiteralType::Boolean, LiteralType::Boolean], 0, 0, 1, 1),
+        CostProfile::new(&[LiteralType::I8, LiteralType::I8], 0, 0, 8, 8),
+        CostProfile::new(&[LiteralType::I16, LiteralType::I16], 0, 0, 16, 16),
+        CostProfile::new(&[LiteralType::I32, LiteralType::I32], 0, 0, 32, 32),
+        CostProfile::new(&[LiteralType::I64, LiteralType::I64], 0, 0, 64, 64),
+        CostProfile::new(&[LiteralType::I128, LiteralType::I128], 0, 0, 128, 128),
+        CostProfile::new(&[LiteralType::U8, LiteralType::U8], 0, 0, 8, 8),
+        CostProfile::new(&[LiteralType::U16, LiteralType::U16], 0, 0, 16, 16),
+        CostProfile::new(&[LiteralType::U32, LiteralType::U32], 0, 0, 32, 32),
+        CostProfile::new(&[LiteralType::U64, LiteralType::U64], 0, 0, 64, 64),
+        CostProfile::new(&[LiteralType::U128, LiteralType::U128], 0, 0, 128, 128),
+    ]),
+    ("pow", &[
+        CostProfile::new(&[LiteralType::Field, LiteralType::Field], 0, 0, 1262, 1264),
+        CostProfile::new(&[LiteralType::I8, LiteralType::U8], 384, 0, 1143, 1216),
+        CostProfile::new(&[LiteralType::I8, LiteralType::U16], 768, 0, 2375, 2528),
+        CostProfile::new(&[LiteralType::I8, LiteralType::U32], 1536, 0, 4839, 5152),
+        CostProfile::new(&[LiteralType::I16, LiteralType::U8], 768, 0, 2151, 2224),
+        CostProfile::new(&[LiteralType::I16, LiteralType::U16], 1536, 0, 4471, 4624),
+        CostProfile::new(&[LiteralType::I16, LiteralType::U32], 3072, 0, 9111, 9424),
+        CostProfile::new(&[LiteralType::I32, LiteralType::U8], 1536, 0, 4167, 4240),
+        CostProfile::new(&[LiteralType::I32, LiteralType::U16], 3072, 0, 8663, 8816),
+        CostProfile::new(&[LiteralType::I32, LiteralType::U32], 6144, 0, 17655, 17968),
+        CostProfile::new(&[LiteralType::I64, LiteralType::U8], 3072, 0, 8199, 8272),
+        CostProfile::new(&[LiteralType::I64, LiteralType::U16], 6144, 0, 17047, 17200),
+        CostProfile::new(&[LiteralType::I64, LiteralType::U32], 12288, 0, 34743, 35056),
+        CostProfile::new(&[LiteralType::I128, LiteralType::U8], 6144, 0, 17273, 17368),
+        CostProfile::new(&[LiteralType::I128, LiteralType::U16], 12288, 0, 35905, 36104),
+        CostProfile::new(&[LiteralType::I128, LiteralType::U32], 24576, 0, 73169, 73576),
+        CostProfile::new(&[LiteralType::U8, LiteralType::U8], 16, 0, 215, 230),
+        CostProfile::new(&[LiteralType::U8, LiteralType::U16], 16, 0, 439, 470),
+        CostProfile::new(&[LiteralType::U8, LiteralType::U32], 16, 0, 887, 950),
+        CostProfile::new(&[LiteralType::U16, LiteralType::U8], 32, 0, 399, 414),
+        CostProfile::new(&[LiteralType::U16, LiteralType::U16], 32, 0, 815, 846),
+        CostProfile::new(&[LiteralType::U16, LiteralType::U32], 32, 0, 1647, 1710),
+        CostProfile::new(&[LiteralType::U32, LiteralType::U8], 64, 0, 767, 782),
+        CostProfile::new(&[LiteralType::U32, LiteralType::U16], 64, 0, 1567, 1598),
+        CostProfile::new(&[LiteralType::U32, LiteralType::U32], 64, 0, 3167, 3230),
+        CostProfile::new(&[LiteralType::U64, LiteralType::U8], 128, 0, 1503, 1518),
+        CostProfile::new(&[LiteralType::U64, LiteralType::U16], 128, 0, 3071, 3102),
+        CostProfile::new(&[LiteralType::U64, LiteralType::U32], 128, 0, 6207, 6270),
+        CostProfile::new(&[LiteralType::U128, LiteralType::U8], 256, 0, 3985, 4022),
+        CostProfile::new(&[LiteralType::U128, LiteralType::U16], 256, 0, 8169, 8246),
+        CostProfile::new(&[LiteralType::U128, LiteralType::U32], 256, 0, 16537, 16694),
+    ]),
+    ("pow.w", &[
+        CostProfile::new(&[LiteralType::I8, LiteralType::U8], 16, 0, 301, 316),
+        CostProfile::new(&[LiteralType::I8, LiteralType::U16], 16, 0, 621, 652),
+        CostProfile::new(&[LiteralType::I8, LiteralType::U32], 16, 0, 1261, 1324),
+        CostProfile::new(&[LiteralType::I16, LiteralType::U8], 32, 0, 545, 560),
+        CostProfile::new(&[LiteralType::I16, LiteralType::U16], 32, 0, 1121, 1152),
+        CostProfile::new(&[LiteralType::I16, LiteralType::U32], 32, 0, 2273, 2336),
+        CostProfile::new(&[LiteralType::I32, LiteralType::U8], 64, 0, 1033, 1048),
+        CostProfile::new(&[LiteralType::I32, LiteralType::U16], 64, 0, 2121, 2152),
+        CostProfile::new(&[LiteralType::I32, LiteralType::U32], 64, 0, 4297, 4360),
+        CostProfile::new(&[LiteralType::I64, LiteralType::U8], 128, 0, 2009, 2024),
+        CostProfile::new(&[LiteralType::I64, LiteralType::U16], 128, 0, 4121, 4152),
+        CostProfile::new(&[LiteralType::I64, LiteralType::U32], 128, 0, 8345, 8408),
+        CostProfile::new(&[LiteralType::I128, LiteralType::U8], 256, 0, 3961, 3976),
+        CostProfile::new(&[LiteralType::I128, LiteralType::U16], 256, 0, 8121, 8152),
+        CostProfile::new(&[LiteralType::I128, LiteralType::U32], 256, 0, 16441, 16504),
+        CostProfile::new(&[LiteralType::U8, LiteralType::U8], 16, 0, 301, 316),
+        CostProfile::new(&[LiteralType::U8, LiteralType::U16], 16, 0, 621, 652),
+        CostProfile::new(&[LiteralType::U8, LiteralType::U32], 16, 0, 1261, 1324),
+        CostProfile::new(&[LiteralType::U16, LiteralType::U8], 32, 0, 545, 560),
+        CostProfile::new(&[LiteralType::U16, LiteralType::U16], 32, 0, 1121, 1152),
+        CostProfile::new(&[LiteralType::U16, LiteralType::U32], 32, 0, 2273, 2336),
+        CostProfile::new(&[LiteralType::U32, LiteralType::U8], 64, 0, 1033, 1048),
+        CostProfile::new(&[LiteralType::U32, LiteralType::U16], 64, 0, 2121, 2152),
+        CostProfile::new(&[LiteralType::U32, LiteralType::U32], 64, 0, 4297, 4360),
+        CostProfile::new(&[LiteralType::U64, LiteralType::U8], 128, 0, 2009, 2024),
+        CostProfile::new(&[LiteralType::U64, LiteralType::U16], 128, 0, 4121, 4152),
+        CostProfile::new(&[LiteralType::U64, LiteralType::U32], 128, 0, 8345, 8408),
+        CostProfile::new(&[LiteralType::U128, LiteralType::U8], 256, 0, 3961, 3976),
+        CostProfile::new(&[LiteralType::U128, LiteralType::U16], 256, 0, 8121, 8152),
+        CostProfile::new(&[LiteralType::U128, LiteralType::U32], 256, 0, 16441, 16504),
+    ]),
+    ("rem", &[
+        CostProfile::new(&[LiteralType::I8, LiteralType::I8], 48, 0, 82, 89),
+        CostProfile::new(&[LiteralType::I16, LiteralType::I16], 96, 0, 154, 161),
+        CostProfile::new(&[LiteralType::I32, LiteralType::I32], 192, 0, 298, 305),
+        CostProfile::new(&[LiteralType::I64, LiteralType::I64], 384, 0, 586, 593),
+        CostProfile::new(&[LiteralType::I128, LiteralType::I128], 768, 0, 1485, 1496),
+        CostProfile::new(&[LiteralType::U8, LiteralType::U8], 8, 0, 26, 29),
+        CostProfile::new(&[LiteralType::U16, LiteralType::U16], 16, 0, 50, 53),
+        CostProfile::new(&[LiteralType::U32, LiteralType::U32], 32, 0, 98, 101),
+        CostProfile::new(&[LiteralType::U64, LiteralType::U64], 64, 0, 194, 197),
+        CostProfile::new(&[LiteralType::U128, LiteralType::U128], 128, 0, 709, 716),
+    ]),
+    ("rem.w", &[
+        CostProfile::new(&[LiteralType::I8, LiteralType::I8], 32, 0, 77, 83),
+        CostProfile::new(&[LiteralType::I16, LiteralType::I16], 64, 0, 149, 155),
+        CostProfile::new(&[LiteralType::I32, LiteralType::I32], 128, 0, 293, 299),
+        CostProfile::new(&[LiteralType::I64, LiteralType::I64], 256, 0, 581, 587),
+        CostProfile::new(&[LiteralType::I128, LiteralType::I128], 512, 0, 1480, 1490),
+        CostProfile::new(&[LiteralType::U8, LiteralType::U8], 8, 0, 26, 29),
+        CostProfile::new(&[LiteralType::U16, LiteralType::U16], 16, 0, 50, 53),
+        CostProfile::new(&[LiteralType::U32, LiteralType::U32], 32, 0, 98, 101),
+        CostProfile::new(&[LiteralType::U64, LiteralType::U64], 64, 0, 194, 197),
+        CostProfile::new(&[LiteralType::U128, LiteralType::U128], 128, 0, 709, 716),
+    ]),
+    ("rem.wide", &[
+        CostProfile::new(&[LiteralType::I128, LiteralType::I128], 768, 0, 1163, 1171),
+        CostProfile::new(&[LiteralType::U128, LiteralType::U128], 128, 0, 387, 391),
+    ]),
+    ("shl", &[
+        CostProfile::new(&[LiteralType::I8, LiteralType::U8], 27, 0, 27, 37),
+        CostProfile::new(&[LiteralType::I8, LiteralType::U16], 27, 0, 27, 37),
+        CostProfile::new(&[LiteralType::I8, LiteralType::U32], 27, 0, 27, 37),
+        CostProfile::new(&[LiteralType::I16, LiteralType::U8], 52, 0, 52, 70),
+        CostProfile::new(&[LiteralType::I16, LiteralType::U16], 52, 0, 52, 70),
+        CostProfile::new(&[LiteralType::I16, LiteralType::U32], 52, 0, 52, 70),
+        CostProfile::new(&[LiteralType::I32, LiteralType::U8], 101, 0, 101, 135),
+        CostProfile::new(&[LiteralType::I32, LiteralType::U16], 101, 0, 101, 135),
+        CostProfile::new(&[LiteralType::I32, LiteralType::U32], 101, 0, 101, 135),
+        CostProfile::new(&[LiteralType::I64, LiteralType::U8], 198, 0, 198, 264),
+        CostProfile::new(&[LiteralType::I64, LiteralType::U16], 198, 0, 198, 264),
+        CostProfile::new(&[LiteralType::I64, LiteralType::U32], 198, 0, 198, 264),
+        CostProfile::new(&[LiteralType::I128, LiteralType::U8], 1474, 0, 4996, 5039),
+        CostProfile::new(&[LiteralType::I128, LiteralType::U16], 1474, 0, 9156, 9239),
+        CostProfile::new(&[LiteralType::I128, LiteralType::U32], 1474, 0, 17476, 17639),
+        CostProfile::new(&[LiteralType::U8, LiteralType::U8], 27, 0, 19, 22),
+        CostProfile::new(&[LiteralType::U8, LiteralType::U16], 27, 0, 19, 22),
+        CostProfile::new(&[LiteralType::U8, LiteralType::U32], 27, 0, 19, 22),
+        CostProfile::new(&[LiteralType::U16, LiteralType::U8], 52, 0, 36, 39),
+        CostProfile::new(&[LiteralType::U16, LiteralType::U16], 52, 0, 36, 39),
+        CostProfile::new(&[LiteralType::U16, LiteralType::U32], 52, 0, 36, 39),
+        CostProfile::new(&[LiteralType::U32, LiteralType::U8], 101, 0, 69, 72),
+        CostProfile::new(&[LiteralType::U32, LiteralType::U16], 101, 0, 69, 72),
+        CostProfile::new(&[LiteralType::U32, LiteralType::U32], 101, 0, 69, 72),
+        CostProfile::new(&[LiteralType::U64, LiteralType::U8], 198, 0, 134, 137),
+        CostProfile::new(&[LiteralType::U64, LiteralType::U16], 198, 0, 134, 137),
+        CostProfile::new(&[LiteralType::U64, LiteralType::U32], 198, 0, 134, 137),
+        CostProfile::new(&[LiteralType::U128, LiteralType::U8], 834, 0, 3835, 3872),
+        CostProfile::new(&[LiteralType::U128, LiteralType::U16], 834, 0, 7995, 8072),
+        CostProfile::new(&[LiteralType::U128, LiteralType::U32], 834, 0, 16315, 16472),
+    ]),
+    ("shl.w", &[
+        CostProfile::new(&[LiteralType::I8, LiteralType::U8], 3, 0, 19, 20),
+        CostProfile::new(&[LiteralType::I8, LiteralType::U16], 3, 0, 19, 20),
+        CostProfile::new(&[LiteralType::I8, LiteralType::U32], 3, 0, 19, 20),
+        CostProfile::new(&[LiteralType::I16, LiteralType::U8], 4, 0, 36, 37),
+        CostProfile::new(&[LiteralType::I16, LiteralType::U16], 4, 0, 36, 37),
+        CostProfile::new(&[LiteralType::I16, LiteralType::U32], 4, 0, 36, 37),
+        CostProfile::new(&[LiteralType::I32, LiteralType::U8], 5, 0, 69, 70),
+        CostProfile::new(&[LiteralType::I32, LiteralType::U16], 5, 0, 69, 70),
+        CostProfile::new(&[LiteralType::I32, LiteralType::U32], 5, 0, 69, 70),
+        CostProfile::new(&[LiteralType::I64, LiteralType::U8], 6, 0, 134, 135),
+        CostProfile::new(&[LiteralType::I64, LiteralType::U16], 6, 0, 134, 135),
+        CostProfile::new(&[LiteralType::I64, LiteralType::U32], 6, 0, 134, 135),
+        CostProfile::new(&[LiteralType::I128, LiteralType::U8], 0, 0, 336, 338),
+        CostProfile::new(&[LiteralType::I128, LiteralType::U16], 0, 0, 336, 338),
+        CostProfile::new(&[LiteralType::I128, LiteralType::U32], 0, 0, 336, 338),
+        CostProfile::new(&[LiteralType::U8, LiteralType::U8], 3, 0, 19, 20),
+        CostProfile::new(&[LiteralType::U8, LiteralType::U16], 3, 0, 19, 20),
+        CostProfile::new(&[LiteralType::U8, LiteralType::U32], 3, 0, 19, 20),
+        CostProfile::new(&[LiteralType::U16, LiteralType::U8], 4, 0, 36, 37),
+        CostProfile::new(&[LiteralType::U16, LiteralType::U16], 4, 0, 36, 37),
+        CostProfile::new(&[LiteralType::U16, LiteralType::U32], 4, 0, 36, 37),
+        CostProfile::new(&[LiteralType::U32, LiteralType::U8], 5, 0, 69, 70),
+        CostProfile::new(&[LiteralType::U32, LiteralType::U16], 5, 0, 69, 70),
+        CostProfile::new(&[LiteralType::U32, LiteralType::U32], 5, 0, 69, 70),
+        CostProfile::new(&[LiteralType::U64, LiteralType::U8], 6, 0, 134, 135),
+        CostProfile::new(&[LiteralType::U64, LiteralType::U16], 6, 0, 134, 135),
+        CostProfile::new(&[LiteralType::U64, LiteralType::U32], 6, 0, 134, 135),
+        CostProfile::new(&[LiteralType::U128, LiteralType::U8], 0, 0, 336, 338),
+        CostProfile::new(&[LiteralType::U128, LiteralType::U16], 0, 0, 336, 338),
+        CostProfile::new(&[LiteralType::U128, LiteralType::U32], 0, 0, 336, 338),
+    ]),
+    ("shr", &[
+        CostProfile::new(&[LiteralType::I8, LiteralType::U8], 6, 0, 19, 21),
+        CostProfile::new(&[LiteralType::I8, LiteralType::U16], 6, 0, 19, 21),
+        CostProfile::new(&[LiteralType::I8, LiteralType::U32], 6, 0, 19, 21),
+        CostProfile::new(&[LiteralType::I16, LiteralType::U8], 8, 0, 36, 38),
+        CostProfile::new(&[LiteralType::I16, LiteralType::U16], 8, 0, 36, 38),
+        CostProfile::new(&[LiteralType::I16, LiteralType::U32], 8, 0, 36, 38),
+        CostProfile::new(&[LiteralType::I32, LiteralType::U8], 10, 0, 69, 71),
+        CostProfile::new(&[LiteralType::I32, LiteralType::U16], 10, 0, 69, 71),
+        CostProfile::new(&[LiteralType::I32, LiteralType::U32], 10, 0, 69, 71),
+        CostProfile::new(&[LiteralType::I64, LiteralType::U8], 12, 0, 134, 136),
+        CostProfile::new(&[LiteralType::I64, LiteralType::U16], 12, 0, 134, 136),
+        CostProfile::new(&[LiteralType::I64, LiteralType::U32], 12, 0, 134, 136),
+        CostProfile::new(&[LiteralType::I128, LiteralType::U8], 512, 0, 1622, 1634),
+        CostProfile::new(&[LiteralType::I128, LiteralType::U16], 512, 0, 1622, 1634),
+        CostProfile::new(&[LiteralType::I128, LiteralType::U32], 512, 0, 1622, 1634),
+        CostProfile::new(&[LiteralType::U8, LiteralType::U8], 3, 0, 19, 21),
+        CostProfile::new(&[LiteralType::U8, LiteralType::U16], 3, 0, 19, 21),
+        CostProfile::new(&[LiteralType::U8, LiteralType::U32], 3, 0, 19, 21),
+        CostProfile::new(&[LiteralType::U16, LiteralType::U8], 4, 0, 36, 38),
+        CostProfile::new(&[LiteralType::U16, LiteralType::U16], 4, 0, 36, 38),
+        CostProfile::new(&[LiteralType::U16, LiteralType::U32], 4, 0, 36, 38),
+        CostProfile::new(&[LiteralType::U32, LiteralType::U8], 5, 0, 69, 71),
+        CostProfile::new(&[LiteralType::U32, LiteralType::U16], 5, 0, 69, 71),
+        CostProfile::new(&[LiteralType::U32, LiteralType::U32], 5, 0, 69, 71),
+        CostProfile::new(&[LiteralType::U64, LiteralType::U8], 6, 0, 134, 136),
+        CostProfile::new(&[LiteralType::U64, LiteralType::U16], 6, 0, 134, 136),
+        CostProfile::new(&[LiteralType::U64, LiteralType::U32], 6, 0, 134, 136),
+        CostProfile::new(&[LiteralType::U128, LiteralType::U8], 128, 0, 849, 858),
+        CostProfile::new(&[LiteralType::U128, LiteralType::U16], 128, 0, 849, 858),
+        CostProfile::new(&[LiteralType::U128, LiteralType::U32], 128, 0, 849, 858),
+    ]),
+    ("shr.w", &[
+        CostProfile::new(&[LiteralType::I8, LiteralType::U8], 6, 0, 19, 20),
+        CostProfile::new(&[LiteralType::I8, LiteralType::U16], 6, 0, 19, 20),
+        CostProfile::new(&[LiteralType::I8, LiteralType::U32], 6, 0, 19, 20),
+        CostProfile::new(&[LiteralType::I16, LiteralType::U8], 8, 0, 36, 37),
+        CostProfile::new(&[LiteralType::I16, LiteralType::U16], 8, 0, 36, 37),
+        CostProfile::new(&[LiteralType::I16, LiteralType::U32], 8, 0, 36, 37),
+        CostProfile::new(&[LiteralType::I32, LiteralType::U8], 10, 0, 69, 70),
+        CostProfile::new(&[LiteralType::I32, LiteralType::U16], 10, 0, 69, 70),
+        CostProfile::new(&[LiteralType::I32, LiteralType::U32], 10, 0, 69, 70),
+        CostProfile::new(&[LiteralType::I64, LiteralType::U8], 12, 0, 134, 135),
+        CostProfile::new(&[LiteralType::I64, LiteralType::U16], 12, 0, 134, 135),
+        CostProfile::new(&[LiteralType::I64, LiteralType::U32], 12, 0, 134, 135),
+        CostProfile::new(&[LiteralType::I128, LiteralType::U8], 512, 0, 1622, 1633),
+        CostProfile::new(&[LiteralType::I128, LiteralType::U16], 512, 0, 1622, 1633),
+        CostProfile::new(&[LiteralType::I128, LiteralType::U32], 512, 0, 1622, 1633),
+        CostProfile::new(&[LiteralType::U8, LiteralType::U8], 3, 0, 19, 20),
+        CostProfile::new(&[LiteralType::U8, LiteralType::U16], 3, 0, 19, 20),
+        CostProfile::new(&[LiteralType::U8, LiteralType::U32], 3, 0, 19, 20),
+        CostProfile::new(&[LiteralType::U16, LiteralType::U8], 4, 0, 36, 37),
+        CostProfile::new(&[LiteralType::U16, LiteralType::U16], 4, 0, 36, 37),
+        CostProfile::new(&[LiteralType::U16, LiteralType::U32], 4, 0, 36, 37),
+        CostProfile::new(&[LiteralType::U32, LiteralType::U8], 5, 0, 69, 70),
+        CostProfile::new(&[LiteralType::U32, LiteralType::U16], 5, 0, 69, 70),
+        CostProfile::new(&[LiteralType::U32, LiteralType::U32], 5, 0, 69, 70),
+        CostProfile::new(&[LiteralType::U64, LiteralType::U8], 6, 0, 134, 135),
+        CostProfile::new(&[LiteralType::U64, LiteralType::U16], 6, 0, 134, 135),
+        CostProfile::new(&[LiteralType::U64, LiteralType::U32], 6, 0, 134, 135),
+        CostProfile::new(&[LiteralType::U128, LiteralType::U8], 128, 0, 849, 857),
+        CostProfile::new(&[LiteralType::U128, LiteralType::U16], 128, 0, 849, 857),
+        CostProfile::new(&[LiteralType::U128, LiteralType::U32], 128, 0, 849, 857),
+    ]),
+    ("square", &[
+        CostProfile::new(&[LiteralType::Field], 0, 0, 1, 1),
+    ]),
+    ("sqrt", &[
+        CostProfile::new(&[LiteralType::Field], 1, 0, 758, 761),
+    ]),
+    ("sub", &[
+        CostProfile::new(&[LiteralType::Field, LiteralType::Field], 0, 0, 0, 0),
+        CostProfile::new(&[LiteralType::Group, LiteralType::Group], 2, 0, 6, 6),
+        CostProfile::new(&[LiteralType::I8, LiteralType::I8], 0, 0, 12, 14),
+        CostProfile::new(&[LiteralType::I16, LiteralType::I16], 0, 0, 20, 22),
+        CostProfile::new(&[LiteralType::I32, LiteralType::I32], 0, 0, 36, 38),
+        CostProfile::new(&[LiteralType::I64, LiteralType::I64], 0, 0, 68, 70),
+        CostProfile::new(&[LiteralType::I128, LiteralType::I128], 0, 0, 132, 134),
+        CostProfile::new(&[LiteralType::U8, LiteralType::U8], 0, 0, 9, 11),
+        CostProfile::new(&[LiteralType::U16, LiteralType::U16], 0, 0, 17, 19),
+        CostProfile::new(&[LiteralType::U32, LiteralType::U32], 0, 0, 33, 35),
+        CostProfile::new(&[LiteralType::U64, LiteralType::U64], 0, 0, 65, 67),
+        CostProfile::new(&[LiteralType::U128, LiteralType::U128], 0, 0, 129, 131),
+    ]),
+    ("sub.w", &[
+        CostProfile::new(&[LiteralType::I8, LiteralType::I8], 0, 0, 9, 10),
+        CostProfile::new(&[LiteralType::I16, LiteralType::I16], 0, 0, 17, 18),
+        CostProfile::new(&[LiteralType::I32, LiteralType::I32], 0, 0, 33, 34),
+        CostProfile::new(&[LiteralType::I64, LiteralType::I64], 0, 0, 65, 66),
+        CostProfile::new(&[LiteralType::I128, LiteralType::I128], 0, 0, 129, 130),
+        CostProfile::new(&[LiteralType::U8, LiteralType::U8], 0, 0, 9, 10),
+        CostProfile::new(&[LiteralType::U16, LiteralType::U16], 0, 0, 17, 18),
+        CostProfile::new(&[LiteralType::U32, LiteralType::U32], 0, 0, 33, 34),
+        CostProfile::new(&[LiteralType::U64, LiteralType::U64], 0, 0, 65, 66),
+        CostProfile::new(&[LiteralType::U128, LiteralType::U128], 0, 0, 129, 130),
+    ]),
+    ("ternary", &[
+        CostProfile::new(&[LiteralType::Boolean, LiteralType::Address, LiteralType::Address], 0, 0, 2, 2),
+        CostProfile::new(&[LiteralType::Boolean, LiteralType::Boolean, LiteralType::Boolean], 0, 0, 1, 1),
+        CostProfile::new(&[LiteralType::Boolean, LiteralType::Field, LiteralType::Field], 0, 0, 1, 1),
+        CostProfile::new(&[LiteralType::Boolean, LiteralType::Group, LiteralType::Group], 0, 0, 2, 2),
+        CostProfile::new(&[LiteralType::Boolean, LiteralType::I8, LiteralType::I8], 0, 0, 8, 8),
+        CostProfile::new(&[LiteralType::Boolean, LiteralType::I16, LiteralType::I16], 0, 0, 16, 16),
+        CostProfile::new(&[LiteralType::Boolean, LiteralType::I32, LiteralType::I32], 0, 0, 32, 32),
+        CostProfile::new(&[LiteralType::Boolean, LiteralType::I64, LiteralType::I64], 0, 0, 64, 64),
+        CostProfile::new(&[LiteralType::Boolean, LiteralType::I128, LiteralType::I128], 0, 0, 128, 128),
+        CostProfile::new(&[LiteralType::Boolean, LiteralType::U8, LiteralType::U8], 0, 0, 8, 8),
+        CostProfile::new(&[LiteralType::Boolean, LiteralType::U16, LiteralType::U16], 0, 0, 16, 16),
+        CostProfile::new(&[LiteralType::Boolean, LiteralType::U32, LiteralType::U32], 0, 0, 32, 32),
+        CostProfile::new(&[LiteralType::Boolean, LiteralType::U64, LiteralType::U64], 0, 0, 64, 64),
+        CostProfile::new(&[LiteralType::Boolean, LiteralType::U128, LiteralType::U128], 0, 0, 128, 128),
+        CostProfile::new(&[LiteralType::Boolean, LiteralType::Scalar, LiteralType::Scalar], 0, 0, 1, 1),
+        CostProfile::new(&[LiteralType::Boolean, LiteralType::Signature, LiteralType::Signature], 0, 0, 7, 7),
+    ]),
+    ("xor", &[
+        CostProfile::new(&[LiteralType::Boolean, LiteralType::Boolean], 0, 0, 1, 1),
+        CostProfile::new(&[LiteralType::I8, LiteralType::I8], 0, 0, 8, 8),
+        CostProfile::new(&[LiteralType::I16, LiteralType::I16], 0, 0, 16, 16),
+        CostProfile::new(&[LiteralType::I32, LiteralType::I32], 0, 0, 32, 32),
+        CostProfile::new(&[LiteralType::I64, LiteralType::I64], 0, 0, 64, 64),
+        CostProfile::new(&[LiteralType::I128, LiteralType::I128], 0, 0, 128, 128),
+        CostProfile::new(&[LiteralType::U8, LiteralType::U8], 0, 0, 8, 8),
+        CostProfile::new(&[LiteralType::U16, LiteralType::U16], 0, 0, 16, 16),
+        CostProfile::new(&[LiteralType::U32, LiteralType::U32], 0, 0, 32, 32),
+        CostProfile::new(&[LiteralType::U64, LiteralType::U64], 0, 0, 64, 64),
+        CostProfile::new(&[LiteralType::U128, LiteralType::U128], 0, 0, 128, 128),
+    ]),
+];
@@ -12,6 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod cost_profile;
+pub use cost_profile::*;
+
+mod cost_table;
+use cost_table::COST_TABLE;
+
 use console::network::prelude::*;
 
 /// The `Opcode` enum stores the mnemonic for the instruction.
@@ -37,6 +43,8 @@ pub enum Opcode {
     Literal(&'static str),
     /// The opcode is for signature verification (i.e. `sign.verify`).
     Sign,
+    /// The opcode is for external signature verification (i.e. `ecdsa.verify`).
+    Verify(&'static str),
 }
 
 impl Deref for Opcode {
@@ -55,6 +63,7 @@ impl Deref for Opcode {
             Opcode::Is(opcode) => opcode,
             Opcode::Literal(opcode) => opcode,
             Opcode::Sign => &"sign.verify",
+            Opcode::Verify(opcode) => opcode,
         }
     }
 }
@@ -80,6 +89,7 @@ impl Display for Opcode {
             Self::Is(opcode) => write!(f, "{opcode}"),
             Self::Literal(opcode) => write!(f, "{opcode}"),
             Self::Sign => write!(f, "{}", self.deref()),
+            Self::Verify(opcode) => write!(f, "{opcode}"),
         }
     }
 }
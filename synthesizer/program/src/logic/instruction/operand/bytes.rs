@@ -23,6 +23,10 @@ impl<N: Network> FromBytes for Operand<N> {
             3 => Ok(Self::Signer),
             4 => Ok(Self::Caller),
             5 => Ok(Self::BlockHeight),
+            6 => Ok(Self::BlockTimestamp),
+            7 => Ok(Self::NetworkID),
+            8 => Ok(Self::BlockRound),
+            9 => Ok(Self::BlockRandomness),
             variant => Err(error(format!("Failed to deserialize operand variant {variant}"))),
         }
     }
@@ -46,6 +50,10 @@ impl<N: Network> ToBytes for Operand<N> {
             Self::Signer => 3u8.write_le(&mut writer),
             Self::Caller => 4u8.write_le(&mut writer),
             Self::BlockHeight => 5u8.write_le(&mut writer),
+            Self::BlockTimestamp => 6u8.write_le(&mut writer),
+            Self::NetworkID => 7u8.write_le(&mut writer),
+            Self::BlockRound => 8u8.write_le(&mut writer),
+            Self::BlockRandomness => 9u8.write_le(&mut writer),
         }
     }
 }
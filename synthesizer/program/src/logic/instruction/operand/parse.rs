@@ -26,6 +26,10 @@ impl<N: Network> Parser for Operand<N> {
             map(tag("self.signer"), |_| Self::Signer),
             map(tag("self.caller"), |_| Self::Caller),
             map(tag("block.height"), |_| Self::BlockHeight),
+            map(tag("block.timestamp"), |_| Self::BlockTimestamp),
+            map(tag("block.round"), |_| Self::BlockRound),
+            map(tag("block.randomness"), |_| Self::BlockRandomness),
+            map(tag("network.id"), |_| Self::NetworkID),
             // Note that `Operand::ProgramID`s must be parsed before `Operand::Literal`s, since a program ID can be implicitly parsed as a literal address.
             // This ensures that the string representation of a program uses the `Operand::ProgramID` variant.
             map(ProgramID::parse, |program_id| Self::ProgramID(program_id)),
@@ -76,6 +80,14 @@ impl<N: Network> Display for Operand<N> {
             Self::Caller => write!(f, "self.caller"),
             // Prints the identifier for the block height, i.e. block.height
             Self::BlockHeight => write!(f, "block.height"),
+            // Prints the identifier for the block timestamp, i.e. block.timestamp
+            Self::BlockTimestamp => write!(f, "block.timestamp"),
+            // Prints the identifier for the block round, i.e. block.round
+            Self::BlockRound => write!(f, "block.round"),
+            // Prints the identifier for the block randomness, i.e. block.randomness
+            Self::BlockRandomness => write!(f, "block.randomness"),
+            // Prints the identifier for the network ID, i.e. network.id
+            Self::NetworkID => write!(f, "network.id"),
         }
     }
 }
@@ -110,6 +122,18 @@ mod tests {
         let operand = Operand::<CurrentNetwork>::parse("block.height").unwrap().1;
         assert_eq!(Operand::BlockHeight, operand);
 
+        let operand = Operand::<CurrentNetwork>::parse("block.timestamp").unwrap().1;
+        assert_eq!(Operand::BlockTimestamp, operand);
+
+        let operand = Operand::<CurrentNetwork>::parse("block.round").unwrap().1;
+        assert_eq!(Operand::BlockRound, operand);
+
+        let operand = Operand::<CurrentNetwork>::parse("block.randomness").unwrap().1;
+        assert_eq!(Operand::BlockRandomness, operand);
+
+        let operand = Operand::<CurrentNetwork>::parse("network.id").unwrap().1;
+        assert_eq!(Operand::NetworkID, operand);
+
         let operand = Operand::<CurrentNetwork>::parse("group::GEN").unwrap().1;
         assert_eq!(Operand::Literal(Literal::Group(Group::generator())), operand);
 
@@ -141,6 +165,18 @@ mod tests {
         let operand = Operand::<CurrentNetwork>::parse("self.caller").unwrap().1;
         assert_eq!(format!("{operand}"), "self.caller");
 
+        let operand = Operand::<CurrentNetwork>::parse("block.timestamp").unwrap().1;
+        assert_eq!(format!("{operand}"), "block.timestamp");
+
+        let operand = Operand::<CurrentNetwork>::parse("block.round").unwrap().1;
+        assert_eq!(format!("{operand}"), "block.round");
+
+        let operand = Operand::<CurrentNetwork>::parse("block.randomness").unwrap().1;
+        assert_eq!(format!("{operand}"), "block.randomness");
+
+        let operand = Operand::<CurrentNetwork>::parse("network.id").unwrap().1;
+        assert_eq!(format!("{operand}"), "network.id");
+
         let operand = Operand::<CurrentNetwork>::parse("group::GEN").unwrap().1;
         assert_eq!(
             format!("{operand}"),
@@ -45,12 +45,22 @@ enum Committer {
     CommitPED128,
 }
 
+/// Returns the maximum number of input bits for the given variant, if the committer is bounded.
+const fn max_input_bits(variant: u8) -> Option<usize> {
+    match variant {
+        4 => Some(64),
+        5 => Some(128),
+        _ => None,
+    }
+}
+
 /// Returns 'true' if the destination type is valid.
 fn is_valid_destination_type(destination_type: LiteralType) -> bool {
     matches!(destination_type, LiteralType::Address | LiteralType::Field | LiteralType::Group)
 }
 
 /// Commits the operand into the declared type.
+/// The input may be a literal, struct, or array, and is packed into bits by `Plaintext::to_bits_le`.
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct CommitInstruction<N: Network, const VARIANT: u8> {
     /// The operand as `input`.
@@ -127,6 +137,11 @@ impl<N: Network, const VARIANT: u8> CommitInstruction<N, VARIANT> {
         // Retrieve the input and randomizer.
         let input = registers.load(stack, &self.operands[0])?;
         let randomizer = registers.load(stack, &self.operands[1])?;
+        // Retrieve the input.
+        let input = match input {
+            Value::Plaintext(input) => input,
+            _ => bail!("Invalid input type for the commit evaluation, expected a plaintext"),
+        };
         // Retrieve the randomizer.
         let randomizer = match randomizer {
             Value::Plaintext(Plaintext::Literal(Literal::Scalar(randomizer), ..)) => randomizer,
@@ -168,6 +183,11 @@ impl<N: Network, const VARIANT: u8> CommitInstruction<N, VARIANT> {
         // Retrieve the input and randomizer.
         let input = registers.load_circuit(stack, &self.operands[0])?;
         let randomizer = registers.load_circuit(stack, &self.operands[1])?;
+        // Retrieve the input.
+        let input = match input {
+            circuit::Value::Plaintext(input) => input,
+            _ => bail!("Invalid input type for the commit execution, expected a plaintext"),
+        };
         // Retrieve the randomizer.
         let randomizer = match randomizer {
             circuit::Value::Plaintext(circuit::Plaintext::Literal(circuit::Literal::Scalar(randomizer), ..)) => {
@@ -207,7 +227,7 @@ impl<N: Network, const VARIANT: u8> CommitInstruction<N, VARIANT> {
     #[inline]
     pub fn output_types(
         &self,
-        _stack: &impl StackProgram<N>,
+        stack: &impl StackProgram<N>,
         input_types: &[RegisterType<N>],
     ) -> Result<Vec<RegisterType<N>>> {
         // Ensure the number of input types is correct.
@@ -221,7 +241,30 @@ impl<N: Network, const VARIANT: u8> CommitInstruction<N, VARIANT> {
         // Ensure the destination type is valid.
         ensure!(is_valid_destination_type(self.destination_type), "Invalid destination type in 'commit' instruction");
 
-        // TODO (howardwu): If the operation is Pedersen, check that it is within the number of bits.
+        // Ensure the input is a plaintext.
+        let input_type = match &input_types[0] {
+            RegisterType::Plaintext(input_type) => input_type,
+            input_type => bail!("Instruction '{}' cannot commit to a '{input_type}'", Self::opcode()),
+        };
+        // Ensure the randomizer is a scalar.
+        ensure!(
+            input_types[1] == RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Scalar)),
+            "Instruction '{}' expects a scalar randomizer, found '{}'",
+            Self::opcode(),
+            input_types[1]
+        );
+        // If the committer is bounded, ensure a struct or array input is within the number of bits.
+        // Note: Literal inputs are checked on evaluation, as a string literal may be shorter than its maximum size.
+        if let (Some(max_bits), PlaintextType::Struct(..) | PlaintextType::Array(..)) =
+            (max_input_bits(VARIANT), input_type)
+        {
+            let num_bits = input_type.size_in_bits(&|struct_name| stack.program().get_struct(struct_name))?;
+            ensure!(
+                num_bits <= max_bits,
+                "Instruction '{}' supports up to {max_bits} input bits, found '{input_type}' with {num_bits} bits",
+                Self::opcode()
+            );
+        }
 
         match VARIANT {
             0..=5 => Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(self.destination_type))]),
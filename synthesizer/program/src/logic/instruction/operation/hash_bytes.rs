@@ -0,0 +1,339 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::sign_verify_external::{load_bytes, num_bytes};
+use crate::{
+    traits::{RegistersLoad, RegistersLoadCircuit, RegistersStore, RegistersStoreCircuit, StackMatches, StackProgram},
+    Opcode,
+    Operand,
+};
+use console::{
+    network::prelude::*,
+    program::{ArrayType, Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+    types::{U32, U8},
+};
+
+/// Keccak256 over a byte payload, with the 32-byte digest used by Ethereum.
+pub type HashBytesKeccak256<N> = HashBytes<N, { ByteHasher::Keccak256 as u8 }>;
+/// SHA3-256 over a byte payload, with the 32-byte digest specified in FIPS 202.
+pub type HashBytesSha3_256<N> = HashBytes<N, { ByteHasher::Sha3_256 as u8 }>;
+
+enum ByteHasher {
+    Keccak256,
+    Sha3_256,
+}
+
+/// The number of bytes in the digest.
+const DIGEST_SIZE_IN_BYTES: u32 = 32;
+
+/// Hashes the first `length` bytes of `data` into a 32-byte digest, stored as a `[u8; 32u32]` in `destination`.
+///
+/// The data is a byte array, which may be nested to exceed the maximum array length, and is read in order.
+/// As the length is an operand, programs may hash variable-length payloads up to the size of the array.
+/// Unlike `hash.keccak256` and `hash.sha3_256`, the digest is computed over the raw bytes,
+/// so it matches the digests computed by external chains, i.e. for commitments made on Ethereum.
+///
+/// Note: These instructions are only supported in a finalize scope.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct HashBytes<N: Network, const VARIANT: u8> {
+    /// The operands as `data` and `length`.
+    operands: Vec<Operand<N>>,
+    /// The destination register.
+    destination: Register<N>,
+}
+
+impl<N: Network, const VARIANT: u8> HashBytes<N, VARIANT> {
+    /// Initializes a new `hash_bytes` instruction.
+    #[inline]
+    pub fn new(operands: Vec<Operand<N>>, destination: Register<N>) -> Result<Self> {
+        // Sanity check the number of operands.
+        ensure!(operands.len() == 2, "Instruction '{}' must have two operands", Self::opcode());
+        // Return the instruction.
+        Ok(Self { operands, destination })
+    }
+
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        match VARIANT {
+            0 => Opcode::Hash("hash_bytes.keccak256"),
+            1 => Opcode::Hash("hash_bytes.sha3_256"),
+            2.. => panic!("Invalid 'hash_bytes' instruction opcode"),
+        }
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        // Sanity check that there are exactly two operands.
+        debug_assert!(self.operands.len() == 2, "Instruction '{}' must have two operands", Self::opcode());
+        // Return the operands.
+        &self.operands
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![self.destination.clone()]
+    }
+
+    /// Returns the digest of the given bytes.
+    fn digest(data: &[u8]) -> Result<Vec<u8>> {
+        let bits = data.iter().flat_map(|byte| byte.to_bits_le()).collect::<Vec<_>>();
+        let digest = match VARIANT {
+            0 => N::hash_keccak256(&bits)?,
+            1 => N::hash_sha3_256(&bits)?,
+            2.. => bail!("Invalid 'hash_bytes' variant: {VARIANT}"),
+        };
+        Vec::<u8>::from_bits_le(&digest)
+    }
+
+    /// Returns the type of the digest, i.e. `[u8; 32u32]`.
+    fn digest_type() -> Result<PlaintextType<N>> {
+        let array_type = ArrayType::new(PlaintextType::Literal(LiteralType::U8), vec![U32::new(DIGEST_SIZE_IN_BYTES)])?;
+        Ok(PlaintextType::Array(array_type))
+    }
+}
+
+impl<N: Network, const VARIANT: u8> HashBytes<N, VARIANT> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 2 {
+            bail!("Instruction '{}' expects 2 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the inputs.
+        let mut data = load_bytes(registers.load(stack, &self.operands[0])?)?;
+        let length = match registers.load_literal(stack, &self.operands[1])? {
+            Literal::U32(length) => *length as usize,
+            _ => bail!("Instruction '{}' expects the length to be a 'u32'", Self::opcode()),
+        };
+        // Truncate the data to the given length.
+        ensure!(
+            length <= data.len(),
+            "Instruction '{}' cannot hash {length} bytes of a {}-byte array",
+            Self::opcode(),
+            data.len()
+        );
+        data.truncate(length);
+
+        // Hash the data, and store the digest as a byte array.
+        let digest = Self::digest(&data)?
+            .into_iter()
+            .map(|byte| Plaintext::from(Literal::U8(U8::new(byte))))
+            .collect::<Vec<_>>();
+        registers.store(stack, &self.destination, Value::Plaintext(Plaintext::Array(digest, Default::default())))
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        _stack: &(impl StackMatches<N> + StackProgram<N>),
+        _registers: &mut (impl RegistersLoadCircuit<N, A> + RegistersStoreCircuit<N, A>),
+    ) -> Result<()> {
+        bail!("Instruction '{}' is only supported in a finalize scope", Self::opcode())
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        self.evaluate(stack, registers)
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        _stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != 2 {
+            bail!("Instruction '{}' expects 2 inputs, found {} inputs", Self::opcode(), input_types.len())
+        }
+
+        // Ensure the data is a byte array.
+        match &input_types[0] {
+            RegisterType::Plaintext(plaintext_type @ PlaintextType::Array(..)) => ensure!(
+                num_bytes(plaintext_type).is_some(),
+                "Instruction '{}' expects the data to be a 'u8' array",
+                Self::opcode()
+            ),
+            input_type => {
+                bail!("Instruction '{}' expects the data to be a 'u8' array, found '{input_type}'", Self::opcode())
+            }
+        }
+        // Ensure the length is a `u32`.
+        ensure!(
+            input_types[1] == RegisterType::Plaintext(PlaintextType::Literal(LiteralType::U32)),
+            "Instruction '{}' expects the length to be a 'u32', found '{}'",
+            Self::opcode(),
+            input_types[1]
+        );
+
+        Ok(vec![RegisterType::Plaintext(Self::digest_type()?)])
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Parser for HashBytes<N, VARIANT> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the data operand from the string.
+        let (string, data) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the length operand from the string.
+        let (string, length) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+
+        Ok((string, Self { operands: vec![data, length], destination }))
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromStr for HashBytes<N, VARIANT> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Debug for HashBytes<N, VARIANT> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Display for HashBytes<N, VARIANT> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is 2.
+        if self.operands.len() != 2 {
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} ", Self::opcode())?;
+        self.operands.iter().try_for_each(|operand| write!(f, "{operand} "))?;
+        write!(f, "into {}", self.destination)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromBytes for HashBytes<N, VARIANT> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the operands.
+        let operands = vec![Operand::read_le(&mut reader)?, Operand::read_le(&mut reader)?];
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+
+        // Return the operation.
+        Ok(Self { operands, destination })
+    }
+}
+
+impl<N: Network, const VARIANT: u8> ToBytes for HashBytes<N, VARIANT> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is 2.
+        if self.operands.len() != 2 {
+            return Err(error(format!("The number of operands must be 2, found {}", self.operands.len())));
+        }
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_parse() {
+        let (string, hash) = HashBytesKeccak256::<CurrentNetwork>::parse("hash_bytes.keccak256 r0 r1 into r2").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(hash.operands.len(), 2, "The number of operands is incorrect");
+        assert_eq!(hash.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(hash.operands[1], Operand::Register(Register::Locator(1)), "The second operand is incorrect");
+        assert_eq!(hash.destination, Register::Locator(2), "The destination register is incorrect");
+
+        let (string, hash) = HashBytesSha3_256::<CurrentNetwork>::parse("hash_bytes.sha3_256 r0 5u32 into r1").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(hash.to_string(), "hash_bytes.sha3_256 r0 5u32 into r1");
+
+        // Ensure the opcodes are not interchangeable.
+        assert!(HashBytesKeccak256::<CurrentNetwork>::parse("hash_bytes.sha3_256 r0 r1 into r2").is_err());
+    }
+
+    /// Returns the hexadecimal encoding of the given bytes.
+    fn to_hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+
+    #[test]
+    fn test_digest() {
+        // Check the digests of the empty string match the standard test vectors.
+        assert_eq!(
+            to_hex(&HashBytesKeccak256::<CurrentNetwork>::digest(&[]).unwrap()),
+            "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+        );
+        assert_eq!(
+            to_hex(&HashBytesSha3_256::<CurrentNetwork>::digest(&[]).unwrap()),
+            "a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a"
+        );
+        // Check the digest of "abc" matches the standard Keccak256 test vector.
+        assert_eq!(
+            to_hex(&HashBytesKeccak256::<CurrentNetwork>::digest(b"abc").unwrap()),
+            "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45"
+        );
+    }
+}
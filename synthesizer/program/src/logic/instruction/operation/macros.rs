@@ -58,6 +58,12 @@ macro_rules! operation {
         #[derive(Clone, PartialEq, Eq, Hash)]
         $vis struct $name<N: Network>(core::marker::PhantomData<N>);
 
+        impl<N: Network> $name<N> {
+            /// The input types supported by the operation.
+            pub const INPUT_TYPES: &'static [[console::program::LiteralType; $num_inputs]] =
+                &[ $( [ $(console::program::LiteralType::$input),+ ] ),+ ];
+        }
+
         impl<N: Network> $crate::Operation<N, console::program::Literal<N>, console::program::LiteralType, $num_inputs> for $name<N> {
             /// The opcode of the operation.
             const OPCODE: $crate::Opcode = Opcode::Literal($opcode);
@@ -113,6 +119,12 @@ macro_rules! operation {
         #[derive(Clone, PartialEq, Eq, Hash)]
         $vis struct $name<N: Network>(core::marker::PhantomData<N>);
 
+        impl<N: Network> $name<N> {
+            /// The input types supported by the operation.
+            pub const INPUT_TYPES: &'static [[console::program::LiteralType; $num_inputs]] =
+                &[ $( [ $(console::program::LiteralType::$input),+ ] ),+ ];
+        }
+
         impl<N: Network> $crate::Operation<N, console::program::Literal<N>, console::program::LiteralType, $num_inputs> for $name<N> {
             /// The opcode of the operation.
             const OPCODE: $crate::Opcode = Opcode::Literal($opcode);
@@ -30,6 +30,9 @@ pub use commit::*;
 mod hash;
 pub use hash::*;
 
+mod hash_bytes;
+pub use hash_bytes::*;
+
 mod is;
 pub use is::*;
 
@@ -41,6 +44,9 @@ mod macros;
 mod sign_verify;
 pub use sign_verify::*;
 
+mod sign_verify_external;
+pub use sign_verify_external::*;
+
 use crate::Opcode;
 use console::network::prelude::*;
 
@@ -185,6 +191,16 @@ crate::operation!(
     }
 );
 
+/// Divides `first` by `second`, checking the division over the halves of the operands, storing the outcome in `destination`.
+pub type DivWide<N> = BinaryLiteral<N, DivWideOperation<N>>;
+
+crate::operation!(
+    pub struct DivWideOperation<console::prelude::DivWide, circuit::traits::DivWide, div_wide, "div.wide"> {
+        (I128, I128) => I128 ("ensure overflows halt", "ensure divide by zero halts"),
+        (U128, U128) => U128 ("ensure divide by zero halts"),
+    }
+);
+
 /// Doubles `first`, storing the outcome in `destination`.
 pub type Double<N> = UnaryLiteral<N, DoubleOperation<N>>;
 
@@ -525,6 +541,16 @@ crate::operation!(
     }
 );
 
+/// Divides `first` by `second`, checking the division over the halves of the operands, storing the remainder in `destination`.
+pub type RemWide<N> = BinaryLiteral<N, RemWideOperation<N>>;
+
+crate::operation!(
+    pub struct RemWideOperation<console::prelude::RemWide, circuit::traits::RemWide, rem_wide, "rem.wide"> {
+        (I128, I128) => I128 ("ensure overflows halt", "ensure divide by zero halts"),
+        (U128, U128) => U128 ("ensure divide by zero halts"),
+    }
+);
+
 /// Shifts `first` left by `second` bits, storing the outcome in `destination`.
 pub type Shl<N> = BinaryLiteral<N, ShlOperation<N>>;
 
@@ -0,0 +1,354 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{RegistersLoad, RegistersLoadCircuit, RegistersStore, RegistersStoreCircuit, StackMatches, StackProgram},
+    Opcode,
+    Operand,
+};
+use console::{
+    algorithms::{Ed25519, Secp256k1},
+    network::prelude::*,
+    program::{Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+    types::Boolean,
+};
+
+/// Computes whether an ECDSA signature over secp256k1 is valid for the given public key and message digest.
+pub type EcdsaVerify<N> = SignVerifyExternal<N, { SignatureScheme::Ecdsa as u8 }>;
+/// Computes whether an Ed25519 signature is valid for the given public key and message.
+pub type Ed25519Verify<N> = SignVerifyExternal<N, { SignatureScheme::Ed25519 as u8 }>;
+
+enum SignatureScheme {
+    Ecdsa,
+    Ed25519,
+}
+
+/// Computes whether `signature` is valid for the given `public_key` and `message`,
+/// for a signature scheme used by external chains and devices.
+///
+/// The operands are byte arrays, which may be nested to exceed the maximum array length,
+/// and are read in order, i.e. a 64-byte signature is given as a `[[u8; 32u32]; 2u32]`.
+/// For `ecdsa.verify`, the public key is 33 bytes (compressed), 64 bytes (`x || y`), or 65 bytes (uncompressed),
+/// and the message is a 32-byte digest. As in Bitcoin and Ethereum, the signature must be low-S normalized.
+/// For `ed25519.verify`, the public key is 32 bytes, and the message is a byte array of any length.
+/// The signature is verified strictly, which rejects non-canonical encodings and small-order points.
+///
+/// Note: These instructions are only supported in a finalize scope.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct SignVerifyExternal<N: Network, const VARIANT: u8> {
+    /// The operands.
+    operands: Vec<Operand<N>>,
+    /// The destination register.
+    destination: Register<N>,
+}
+
+impl<N: Network, const VARIANT: u8> SignVerifyExternal<N, VARIANT> {
+    /// Initializes a new external signature verification instruction.
+    #[inline]
+    pub fn new(operands: Vec<Operand<N>>, destination: Register<N>) -> Result<Self> {
+        // Sanity check the number of operands.
+        ensure!(operands.len() == 3, "Instruction '{}' must have three operands", Self::opcode());
+        // Return the instruction.
+        Ok(Self { operands, destination })
+    }
+
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        match VARIANT {
+            0 => Opcode::Verify("ecdsa.verify"),
+            1 => Opcode::Verify("ed25519.verify"),
+            2.. => panic!("Invalid external signature verification instruction opcode"),
+        }
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        // Sanity check that there are exactly three operands.
+        debug_assert!(self.operands.len() == 3, "Instruction '{}' must have three operands", Self::opcode());
+        // Return the operands.
+        &self.operands
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![self.destination.clone()]
+    }
+}
+
+impl<N: Network, const VARIANT: u8> SignVerifyExternal<N, VARIANT> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 3 {
+            bail!("Instruction '{}' expects 3 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the inputs.
+        let signature = load_bytes(registers.load(stack, &self.operands[0])?)?;
+        let public_key = load_bytes(registers.load(stack, &self.operands[1])?)?;
+        let message = load_bytes(registers.load(stack, &self.operands[2])?)?;
+        let Ok(signature) = <[u8; 64]>::try_from(signature) else {
+            bail!("Instruction '{}' expects a 64-byte signature", Self::opcode())
+        };
+
+        // Verify the signature.
+        let is_valid = match VARIANT {
+            0 => match <[u8; 32]>::try_from(message) {
+                Ok(digest) => Secp256k1::verify(&public_key, &digest, &signature),
+                Err(_) => bail!("Instruction '{}' expects a 32-byte message digest", Self::opcode()),
+            },
+            1 => match <[u8; 32]>::try_from(public_key) {
+                Ok(public_key) => Ed25519::verify(&public_key, &message, &signature),
+                Err(_) => bail!("Instruction '{}' expects a 32-byte public key", Self::opcode()),
+            },
+            2.. => bail!("Invalid external signature verification variant: {VARIANT}"),
+        };
+
+        // Store the output.
+        registers.store_literal(stack, &self.destination, Literal::Boolean(Boolean::new(is_valid)))
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        _stack: &(impl StackMatches<N> + StackProgram<N>),
+        _registers: &mut (impl RegistersLoadCircuit<N, A> + RegistersStoreCircuit<N, A>),
+    ) -> Result<()> {
+        bail!("Instruction '{}' is only supported in a finalize scope", Self::opcode())
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        self.evaluate(stack, registers)
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        _stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != 3 {
+            bail!("Instruction '{}' expects 3 inputs, found {} inputs", Self::opcode(), input_types.len())
+        }
+
+        // Retrieve the number of bytes in each byte array.
+        let mut lengths = Vec::with_capacity(3);
+        for (input_type, name) in input_types.iter().zip_eq(["signature", "public key", "message"]) {
+            match input_type {
+                RegisterType::Plaintext(plaintext_type @ PlaintextType::Array(..)) => match num_bytes(plaintext_type) {
+                    Some(num_bytes) => lengths.push(num_bytes),
+                    None => bail!("Instruction '{}' expects the {name} to be a 'u8' array", Self::opcode()),
+                },
+                _ => bail!(
+                    "Instruction '{}' expects the {name} to be a 'u8' array, found '{input_type}'",
+                    Self::opcode()
+                ),
+            }
+        }
+
+        // Ensure the byte arrays have the expected lengths.
+        ensure!(lengths[0] == 64, "Instruction '{}' expects a 64-byte signature", Self::opcode());
+        match VARIANT {
+            0 => {
+                ensure!(
+                    matches!(lengths[1], 33 | 64 | 65),
+                    "Instruction '{}' expects a 33-byte, 64-byte, or 65-byte public key",
+                    Self::opcode()
+                );
+                ensure!(lengths[2] == 32, "Instruction '{}' expects a 32-byte message digest", Self::opcode());
+            }
+            1 => ensure!(lengths[1] == 32, "Instruction '{}' expects a 32-byte public key", Self::opcode()),
+            2.. => bail!("Invalid external signature verification variant: {VARIANT}"),
+        }
+
+        Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Boolean))])
+    }
+}
+
+/// Returns the number of bytes in the given (possibly nested) `u8` array type, if it is one.
+pub(super) fn num_bytes<N: Network>(plaintext_type: &PlaintextType<N>) -> Option<u32> {
+    match plaintext_type {
+        PlaintextType::Literal(LiteralType::U8) => Some(1),
+        PlaintextType::Array(array_type) => {
+            num_bytes(array_type.next_element_type())?.checked_mul(**array_type.length())
+        }
+        _ => None,
+    }
+}
+
+/// Returns the bytes of the given (possibly nested) `u8` array, in order.
+pub(super) fn load_bytes<N: Network>(value: Value<N>) -> Result<Vec<u8>> {
+    /// Appends the bytes of the given plaintext to the given buffer.
+    fn append_bytes<N: Network>(plaintext: &Plaintext<N>, bytes: &mut Vec<u8>) -> Result<()> {
+        match plaintext {
+            Plaintext::Literal(Literal::U8(byte), _) => bytes.push(**byte),
+            Plaintext::Array(elements, _) => elements.iter().try_for_each(|element| append_bytes(element, bytes))?,
+            _ => bail!("Expected a 'u8' array element, found '{plaintext}'"),
+        }
+        Ok(())
+    }
+
+    match value {
+        Value::Plaintext(plaintext @ Plaintext::Array(..)) => {
+            let mut bytes = Vec::new();
+            append_bytes(&plaintext, &mut bytes)?;
+            Ok(bytes)
+        }
+        _ => bail!("Expected a 'u8' array, found '{value}'"),
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Parser for SignVerifyExternal<N, VARIANT> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the first operand from the string.
+        let (string, first) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the second operand from the string.
+        let (string, second) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the third operand from the string.
+        let (string, third) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+
+        Ok((string, Self { operands: vec![first, second, third], destination }))
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromStr for SignVerifyExternal<N, VARIANT> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Debug for SignVerifyExternal<N, VARIANT> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Display for SignVerifyExternal<N, VARIANT> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is 3.
+        if self.operands.len() != 3 {
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} ", Self::opcode())?;
+        self.operands.iter().try_for_each(|operand| write!(f, "{operand} "))?;
+        write!(f, "into {}", self.destination)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromBytes for SignVerifyExternal<N, VARIANT> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Initialize the vector for the operands.
+        let mut operands = Vec::with_capacity(3);
+        // Read the operands.
+        for _ in 0..3 {
+            operands.push(Operand::read_le(&mut reader)?);
+        }
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+
+        // Return the operation.
+        Ok(Self { operands, destination })
+    }
+}
+
+impl<N: Network, const VARIANT: u8> ToBytes for SignVerifyExternal<N, VARIANT> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is 3.
+        if self.operands.len() != 3 {
+            return Err(error(format!("The number of operands must be 3, found {}", self.operands.len())));
+        }
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_parse() {
+        let (string, ecdsa) = EcdsaVerify::<CurrentNetwork>::parse("ecdsa.verify r0 r1 r2 into r3").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(ecdsa.operands.len(), 3, "The number of operands is incorrect");
+        assert_eq!(ecdsa.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(ecdsa.operands[1], Operand::Register(Register::Locator(1)), "The second operand is incorrect");
+        assert_eq!(ecdsa.operands[2], Operand::Register(Register::Locator(2)), "The third operand is incorrect");
+        assert_eq!(ecdsa.destination, Register::Locator(3), "The destination register is incorrect");
+
+        let (string, ed25519) = Ed25519Verify::<CurrentNetwork>::parse("ed25519.verify r0 r1 r2 into r3").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(ed25519.to_string(), "ed25519.verify r0 r1 r2 into r3");
+
+        // Ensure the opcodes are not interchangeable.
+        assert!(EcdsaVerify::<CurrentNetwork>::parse("ed25519.verify r0 r1 r2 into r3").is_err());
+    }
+}
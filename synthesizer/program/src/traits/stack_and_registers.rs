@@ -89,6 +89,12 @@ pub trait StackProgram<N: Network> {
     /// Returns the expected number of calls for the given function name.
     fn get_number_of_calls(&self, function_name: &Identifier<N>) -> Result<usize>;
 
+    /// Returns the indices of the external record inputs that are consumed by the given function.
+    fn get_consumed_external_records(&self, function_name: &Identifier<N>) -> Result<Vec<usize>>;
+
+    /// Returns the indices and lock entries of the time-locked record inputs that are consumed by the given function.
+    fn get_time_locked_records(&self, function_name: &Identifier<N>) -> Result<Vec<(usize, Identifier<N>)>>;
+
     /// Samples a value for the given value_type.
     fn sample_value<R: Rng + CryptoRng>(
         &self,
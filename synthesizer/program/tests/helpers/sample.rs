@@ -68,7 +68,7 @@ pub fn sample_finalize_registers(
 ) -> Result<FinalizeRegisters<CurrentNetwork>> {
     // Initialize the registers.
     let mut finalize_registers = FinalizeRegisters::<CurrentNetwork>::new(
-        FinalizeGlobalState::from(1, 1, [0; 32]),
+        FinalizeGlobalState::from(1, 1, CurrentNetwork::GENESIS_TIMESTAMP, [0; 32]),
         <CurrentNetwork as Network>::TransitionID::default(),
         *function_name,
         stack.get_finalize_types(function_name)?.clone(),
@@ -26,7 +26,7 @@ impl<N: Network> FromBytes for Certificate<N> {
         // Read the certificate.
         let certificate = FromBytes::read_le(&mut reader)?;
         // Return the certificate.
-        Ok(Self { certificate })
+        Ok(Self::new(certificate))
     }
 }
 
@@ -20,17 +20,18 @@ mod serialize;
 
 #[derive(Clone, PartialEq, Eq)]
 pub struct Certificate<N: Network> {
-    /// The certificate.
-    certificate: varuna::Certificate<N::PairingCurve>,
+    /// The certificate, shared between the clones of the certificate.
+    certificate: Arc<varuna::Certificate<N::PairingCurve>>,
 }
 
 impl<N: Network> Certificate<N> {
     /// Initializes a new certificate.
-    pub(super) const fn new(certificate: varuna::Certificate<N::PairingCurve>) -> Self {
-        Self { certificate }
+    pub(super) fn new(certificate: varuna::Certificate<N::PairingCurve>) -> Self {
+        Self { certificate: Arc::new(certificate) }
     }
 
     /// Returns the certificate from the proving and verifying key.
+    #[cfg(feature = "prover")]
     pub fn certify(
         function_name: &str,
         proving_key: &ProvingKey<N>,
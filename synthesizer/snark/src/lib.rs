@@ -27,10 +27,14 @@ use std::sync::Arc;
 use colored::Colorize;
 
 type Varuna<N> = varuna::VarunaSNARK<<N as Environment>::PairingCurve, FiatShamir<N>, varuna::VarunaHidingMode>;
+type Ahp<N> = varuna::AHPForR1CS<<N as Environment>::Field, varuna::VarunaHidingMode>;
 
 mod certificate;
 pub use certificate::Certificate;
 
+mod mapped;
+pub use mapped::MappedProvingKey;
+
 mod proof;
 pub use proof::Proof;
 
@@ -0,0 +1,185 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use snarkvm_utilities::{CanonicalDeserialize, CanonicalSerialize};
+
+/// The magic bytes of a mapped proving key.
+const PROVING_KEY_MAGIC: [u8; 8] = *b"aleo-pk1";
+/// The magic bytes of a mapped verifying key.
+const VERIFYING_KEY_MAGIC: [u8; 8] = *b"aleo-vk1";
+/// The alignment of each section of a mapped key, in bytes.
+const SECTION_ALIGNMENT: usize = 64;
+
+/// Returns the mapped layout of the given sections, which is the magic bytes, the number of sections (u64),
+/// the offset and length of each section (u64, u64), and then each section, starting at a multiple of
+/// `SECTION_ALIGNMENT` bytes from the start of the layout.
+fn write_sections(magic: &[u8; 8], sections: &[Vec<u8>]) -> Result<Vec<u8>> {
+    // Compute the size of the header.
+    let header_size = magic.len() + 8 + sections.len() * 16;
+    let align = |offset: usize| (offset + SECTION_ALIGNMENT - 1) / SECTION_ALIGNMENT * SECTION_ALIGNMENT;
+
+    // Write the header.
+    let mut bytes = magic.to_vec();
+    (sections.len() as u64).write_le(&mut bytes)?;
+    let mut offset = align(header_size);
+    for section in sections {
+        (offset as u64).write_le(&mut bytes)?;
+        (section.len() as u64).write_le(&mut bytes)?;
+        offset = align(offset + section.len());
+    }
+    // Write the sections, padded with zeros.
+    for section in sections {
+        bytes.resize(align(bytes.len()), 0);
+        bytes.extend_from_slice(section);
+    }
+    Ok(bytes)
+}
+
+/// Returns the sections of the given mapped layout, without reading their contents.
+fn read_sections<'a, const NUM_SECTIONS: usize>(magic: &[u8; 8], bytes: &'a [u8]) -> Result<[&'a [u8]; NUM_SECTIONS]> {
+    // Ensure the magic bytes are correct.
+    ensure!(bytes.get(..magic.len()) == Some(magic.as_slice()), "Invalid magic bytes in the mapped key");
+    let mut header = &bytes[magic.len()..];
+    // Ensure the number of sections is correct.
+    let num_sections = u64::read_le(&mut header)?;
+    ensure!(
+        num_sections == NUM_SECTIONS as u64,
+        "Expected {NUM_SECTIONS} sections in the mapped key, found {num_sections}"
+    );
+    // Read the sections.
+    let mut sections = Vec::with_capacity(NUM_SECTIONS);
+    for _ in 0..NUM_SECTIONS {
+        let (offset, length) = (u64::read_le(&mut header)?, u64::read_le(&mut header)?);
+        // Ensure the section is aligned, and within the layout.
+        let section = usize::try_from(offset)
+            .ok()
+            .filter(|offset| offset % SECTION_ALIGNMENT == 0)
+            .zip(usize::try_from(length).ok())
+            .and_then(|(offset, length)| bytes.get(offset..offset.checked_add(length)?))
+            .ok_or_else(|| anyhow!("Invalid section at offset {offset} with length {length} in the mapped key"))?;
+        sections.push(section);
+    }
+    sections.try_into().map_err(|_| anyhow!("Failed to read the sections of the mapped key"))
+}
+
+impl<N: Network> ProvingKey<N> {
+    /// Returns the proving key in the mapped layout, for use with `MappedProvingKey`.
+    pub fn to_mapped_bytes(&self) -> Result<Vec<u8>> {
+        // Serialize the verifying key and the circuit without compression, so they are read without decompression.
+        let mut verifying_key = Vec::new();
+        self.circuit_verifying_key.serialize_uncompressed(&mut verifying_key)?;
+        let mut circuit = Vec::new();
+        self.circuit.serialize_uncompressed(&mut circuit)?;
+        // Note: The committer key is already serialized without compression.
+        let committer_key = self.committer_key.to_bytes_le()?;
+        write_sections(&PROVING_KEY_MAGIC, &[verifying_key, circuit, committer_key])
+    }
+}
+
+impl<N: Network> VerifyingKey<N> {
+    /// Returns the verifying key in the mapped layout, for use with `VerifyingKey::from_mapped_bytes`.
+    pub fn to_mapped_bytes(&self) -> Result<Vec<u8>> {
+        let mut verifying_key = Vec::new();
+        self.deref().serialize_uncompressed(&mut verifying_key)?;
+        write_sections(&VERIFYING_KEY_MAGIC, &[verifying_key])
+    }
+
+    /// Returns the verifying key from the given mapped layout.
+    ///
+    /// The points of the verifying key are not checked to be in the correct subgroup,
+    /// so the bytes must come from a trusted source, such as a parameter file with a known checksum.
+    pub fn from_mapped_bytes(bytes: &[u8]) -> Result<Self> {
+        let [verifying_key] = read_sections::<1>(&VERIFYING_KEY_MAGIC, bytes)?;
+        Ok(Self::new(Arc::new(CanonicalDeserialize::deserialize_uncompressed_unchecked(verifying_key)?)))
+    }
+}
+
+/// A proving key in the mapped layout, such as the bytes of a memory-mapped file.
+///
+/// The mapped layout stores the verifying key, the circuit, and the committer key in separate sections,
+/// each aligned to `SECTION_ALIGNMENT` bytes, and uncompressed. Only the header is read on initialization,
+/// and each section is decoded once, when it is first used. As such, the verifying key is available
+/// without decoding the circuit or the committer key.
+///
+/// The points of the key are not checked to be in the correct subgroup,
+/// so the bytes must come from a trusted source, such as a parameter file with a known checksum.
+pub struct MappedProvingKey<'a, N: Network> {
+    /// The sections of the verifying key, the circuit, and the committer key.
+    sections: [&'a [u8]; 3],
+    /// The verifying key, once decoded.
+    verifying_key: OnceCell<VerifyingKey<N>>,
+    /// The proving key, once decoded.
+    proving_key: OnceCell<ProvingKey<N>>,
+}
+
+impl<'a, N: Network> MappedProvingKey<'a, N> {
+    /// Initializes a new mapped proving key, from the given bytes in the mapped layout.
+    pub fn new(bytes: &'a [u8]) -> Result<Self> {
+        let sections = read_sections::<3>(&PROVING_KEY_MAGIC, bytes)?;
+        Ok(Self { sections, verifying_key: OnceCell::new(), proving_key: OnceCell::new() })
+    }
+
+    /// Returns the verifying key, decoding only its section.
+    pub fn verifying_key(&self) -> Result<&VerifyingKey<N>> {
+        self.verifying_key.get_or_try_init(|| {
+            Ok(VerifyingKey::new(Arc::new(CanonicalDeserialize::deserialize_uncompressed_unchecked(self.sections[0])?)))
+        })
+    }
+
+    /// Returns the proving key, decoding the circuit and the committer key on first use.
+    pub fn proving_key(&self) -> Result<&ProvingKey<N>> {
+        self.proving_key.get_or_try_init(|| {
+            let circuit_verifying_key = self.verifying_key()?.deref().clone();
+            let circuit = Arc::new(CanonicalDeserialize::deserialize_uncompressed_unchecked(self.sections[1])?);
+            let committer_key = Arc::new(FromBytes::read_le(self.sections[2])?);
+            Ok(ProvingKey::new(Arc::new(varuna::CircuitProvingKey { circuit_verifying_key, circuit, committer_key })))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mapped_bytes() -> Result<()> {
+        let (expected_proving_key, expected_verifying_key) = crate::test_helpers::sample_keys();
+
+        // Check the verifying key.
+        let bytes = expected_verifying_key.to_mapped_bytes()?;
+        assert_eq!(expected_verifying_key, VerifyingKey::from_mapped_bytes(&bytes)?);
+        assert!(VerifyingKey::<console::network::MainnetV0>::from_mapped_bytes(&bytes[..bytes.len() - 1]).is_err());
+
+        // Check the proving key.
+        let bytes = expected_proving_key.to_mapped_bytes()?;
+        let mapped = MappedProvingKey::new(&bytes)?;
+        assert_eq!(&expected_verifying_key, mapped.verifying_key()?);
+        assert_eq!(expected_proving_key.to_bytes_le()?, mapped.proving_key()?.to_bytes_le()?);
+
+        // Ensure the sections are aligned.
+        let [verifying_key, circuit, committer_key] = mapped.sections;
+        for section in [verifying_key, circuit, committer_key] {
+            assert_eq!((section.as_ptr() as usize - bytes.as_ptr() as usize) % SECTION_ALIGNMENT, 0);
+        }
+
+        // Ensure the layouts are not interchangeable.
+        assert!(VerifyingKey::<console::network::MainnetV0>::from_mapped_bytes(&bytes).is_err());
+        assert!(
+            MappedProvingKey::<console::network::MainnetV0>::new(&expected_verifying_key.to_mapped_bytes()?).is_err()
+        );
+        Ok(())
+    }
+}
@@ -23,10 +23,26 @@ impl<N: Network> FromBytes for Proof<N> {
         if version != 1 {
             return Err(error("Invalid proof version"));
         }
-        // Read the proof.
-        let proof = FromBytes::read_le(&mut reader)?;
+        // Read the number of circuits.
+        let num_circuits = u64::read_le(&mut reader)?;
+        // Ensure the number of circuits is within bounds.
+        if num_circuits == 0 || num_circuits > Self::MAX_CIRCUITS as u64 {
+            return Err(error("Invalid number of circuits in the proof"));
+        }
+        // Read the batch sizes.
+        let batch_sizes = (0..num_circuits).map(|_| u64::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        // Ensure every circuit has an instance, and the number of instances is within bounds.
+        match batch_sizes.iter().try_fold(0u64, |acc, &size| if size == 0 { None } else { acc.checked_add(size) }) {
+            Some(num_instances) if num_instances <= Self::MAX_INSTANCES as u64 => (),
+            _ => return Err(error("Invalid number of instances in the proof")),
+        }
+        // Read the proof, now that its batch sizes are known to be bounded.
+        let mut prefix = Vec::new();
+        num_circuits.write_le(&mut prefix)?;
+        batch_sizes.iter().try_for_each(|size| size.write_le(&mut prefix))?;
+        let proof = FromBytes::read_le(prefix.as_slice().chain(reader))?;
         // Return the proof.
-        Ok(Self { proof })
+        Ok(Self::new(proof))
     }
 }
 
@@ -55,4 +71,31 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_bytes_bounds() {
+        type CurrentNetwork = console::network::MainnetV0;
+
+        // Ensure a proof without circuits is rejected.
+        let bytes = [&[1u8][..], &0u64.to_le_bytes()].concat();
+        assert!(Proof::<CurrentNetwork>::read_le(&bytes[..]).is_err());
+
+        // Ensure a proof with too many circuits is rejected.
+        let num_circuits = Proof::<CurrentNetwork>::MAX_CIRCUITS + 1;
+        let bytes = [&[1u8][..], &(num_circuits as u64).to_le_bytes(), &vec![1u8; 8 * num_circuits]].concat();
+        assert!(Proof::<CurrentNetwork>::read_le(&bytes[..]).is_err());
+
+        // Ensure a proof with an empty batch is rejected.
+        let bytes = [&[1u8][..], &2u64.to_le_bytes(), &1u64.to_le_bytes(), &0u64.to_le_bytes()].concat();
+        assert!(Proof::<CurrentNetwork>::read_le(&bytes[..]).is_err());
+
+        // Ensure a proof with too many instances is rejected.
+        let num_instances = Proof::<CurrentNetwork>::MAX_INSTANCES as u64 + 1;
+        let bytes = [&[1u8][..], &1u64.to_le_bytes(), &num_instances.to_le_bytes()].concat();
+        assert!(Proof::<CurrentNetwork>::read_le(&bytes[..]).is_err());
+
+        // Ensure a proof with an unreasonable batch size is rejected.
+        let bytes = [&[1u8][..], &1u64.to_le_bytes(), &u64::MAX.to_le_bytes()].concat();
+        assert!(Proof::<CurrentNetwork>::read_le(&bytes[..]).is_err());
+    }
 }
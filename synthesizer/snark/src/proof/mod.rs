@@ -20,14 +20,21 @@ mod serialize;
 
 #[derive(Clone, PartialEq, Eq)]
 pub struct Proof<N: Network> {
-    /// The proof.
-    proof: varuna::Proof<N::PairingCurve>,
+    /// The proof, shared between the clones of the proof.
+    proof: Arc<varuna::Proof<N::PairingCurve>>,
 }
 
 impl<N: Network> Proof<N> {
+    /// The maximum number of circuits in a batch proof, i.e. one per transition and one for inclusion.
+    pub const MAX_CIRCUITS: usize = Self::MAX_TRANSITIONS + 1;
+    /// The maximum number of instances in a batch proof, i.e. one per transition and one per input.
+    pub const MAX_INSTANCES: usize = Self::MAX_TRANSITIONS * (1 + N::MAX_INPUTS);
+    /// The maximum number of transitions in a transaction.
+    const MAX_TRANSITIONS: usize = usize::pow(2, console::program::TRANSACTION_DEPTH as u32);
+
     /// Initializes a new proof.
-    pub const fn new(proof: varuna::Proof<N::PairingCurve>) -> Self {
-        Self { proof }
+    pub fn new(proof: varuna::Proof<N::PairingCurve>) -> Self {
+        Self { proof: Arc::new(proof) }
     }
 }
 
@@ -18,6 +18,7 @@ mod bytes;
 mod parse;
 mod serialize;
 
+#[cfg(feature = "prover")]
 use std::collections::BTreeMap;
 
 #[derive(Clone)]
@@ -33,6 +34,7 @@ impl<N: Network> ProvingKey<N> {
     }
 
     /// Returns a proof for the given assignment on the circuit.
+    #[cfg(feature = "prover")]
     pub fn prove<R: Rng + CryptoRng>(
         &self,
         function_name: &str,
@@ -55,6 +57,7 @@ impl<N: Network> ProvingKey<N> {
     }
 
     /// Returns a proof for the given batch of proving keys and assignments.
+    #[cfg(feature = "prover")]
     #[allow(clippy::type_complexity)]
     pub fn prove_batch<R: Rng + CryptoRng>(
         locator: &str,
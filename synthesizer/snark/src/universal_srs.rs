@@ -27,6 +27,7 @@ impl<N: Network> UniversalSRS<N> {
     }
 
     /// Returns the circuit proving and verifying key.
+    #[cfg(feature = "prover")]
     pub fn to_circuit_key(
         &self,
         function_name: &str,
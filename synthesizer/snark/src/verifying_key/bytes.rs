@@ -23,8 +23,44 @@ impl<N: Network> FromBytes for VerifyingKey<N> {
         if version != 1 {
             return Err(error("Invalid verifying key version"));
         }
-        // Read the verifying key.
-        let verifying_key = Arc::new(FromBytes::read_le(&mut reader)?);
+        // Read the circuit info.
+        let num_public_inputs = u64::read_le(&mut reader)?;
+        let num_variables = u64::read_le(&mut reader)?;
+        let num_constraints = u64::read_le(&mut reader)?;
+        let num_non_zero_a = u64::read_le(&mut reader)?;
+        let num_non_zero_b = u64::read_le(&mut reader)?;
+        let num_non_zero_c = u64::read_le(&mut reader)?;
+        // Ensure the evaluation domains of the circuit are supported.
+        let max_non_zero = num_non_zero_a.max(num_non_zero_b).max(num_non_zero_c);
+        match (usize::try_from(num_constraints), usize::try_from(num_variables), usize::try_from(max_non_zero)) {
+            (Ok(num_constraints), Ok(num_variables), Ok(max_non_zero)) => {
+                match Ahp::<N>::max_degree(num_constraints, num_variables, max_non_zero) {
+                    Ok(max_degree) if max_degree <= Self::MAX_DEGREE => (),
+                    _ => return Err(error("Invalid circuit size in the verifying key")),
+                }
+            }
+            _ => return Err(error("Invalid circuit size in the verifying key")),
+        }
+        // Read the number of circuit commitments.
+        let num_commitments = u64::read_le(&mut reader)?;
+        // Ensure there is one commitment per indexed polynomial.
+        if num_commitments != Self::NUM_COMMITMENTS as u64 {
+            return Err(error("Invalid number of commitments in the verifying key"));
+        }
+        // Read the verifying key, now that its circuit is known to be bounded.
+        let mut prefix = Vec::new();
+        [
+            num_public_inputs,
+            num_variables,
+            num_constraints,
+            num_non_zero_a,
+            num_non_zero_b,
+            num_non_zero_c,
+            num_commitments,
+        ]
+        .iter()
+        .try_for_each(|value| value.write_le(&mut prefix))?;
+        let verifying_key = Arc::new(FromBytes::read_le(prefix.as_slice().chain(reader))?);
         // Return the verifying key.
         Ok(Self { verifying_key })
     }
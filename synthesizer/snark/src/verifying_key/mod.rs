@@ -27,6 +27,11 @@ pub struct VerifyingKey<N: Network> {
 }
 
 impl<N: Network> VerifyingKey<N> {
+    /// The maximum degree of the indexed polynomials, as supported by the universal SRS.
+    pub const MAX_DEGREE: usize = 1 << 28;
+    /// The number of circuit commitments, i.e. `row`, `col`, `row_col`, and `row_col_val` for each of `A`, `B`, and `C`.
+    pub const NUM_COMMITMENTS: usize = 12;
+
     /// Initializes a new verifying key.
     pub const fn new(verifying_key: Arc<varuna::CircuitVerifyingKey<N::PairingCurve>>) -> Self {
         Self { verifying_key }
@@ -98,6 +103,57 @@ impl<N: Network> VerifyingKey<N> {
     }
 }
 
+impl<N: Network> VerifyingKey<N> {
+    /// Ensures the given batch proofs are valid for their public inputs.
+    /// The final pairing checks of all of the proofs are combined, and checked at once.
+    #[allow(clippy::type_complexity)]
+    pub fn verify_many(instances: &[(Vec<(VerifyingKey<N>, Vec<Vec<N::Field>>)>, &Proof<N>)]) -> Result<()> {
+        #[cfg(feature = "aleo-cli")]
+        let timer = std::time::Instant::now();
+
+        // Convert the instances.
+        let instances = instances
+            .iter()
+            .map(|(inputs, proof)| {
+                let num_expected_keys = inputs.len();
+                let keys_to_inputs: BTreeMap<_, _> =
+                    inputs.iter().map(|(verifying_key, inputs)| (verifying_key.deref(), inputs.as_slice())).collect();
+                ensure!(
+                    keys_to_inputs.len() == num_expected_keys,
+                    "Incorrect number of verifying keys for batch proof"
+                );
+                Ok((keys_to_inputs, proof.deref()))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Retrieve the verification parameters.
+        let universal_verifier = N::varuna_universal_verifier();
+        let fiat_shamir = N::varuna_fs_parameters();
+
+        // Verify the batch proofs.
+        match Varuna::<N>::verify_many(universal_verifier, fiat_shamir, &instances) {
+            Ok(is_valid) => {
+                #[cfg(feature = "aleo-cli")]
+                println!(
+                    "{}",
+                    format!(
+                        " • Verified {} proofs: {is_valid} (in {} ms)",
+                        instances.len(),
+                        timer.elapsed().as_millis()
+                    )
+                    .dimmed()
+                );
+                if is_valid { Ok(()) } else { bail!("'verify_many' failed") }
+            }
+            Err(error) => {
+                #[cfg(feature = "aleo-cli")]
+                println!("{}", format!(" • Verifier failed: {error}").dimmed());
+                bail!(error)
+            }
+        }
+    }
+}
+
 impl<N: Network> Deref for VerifyingKey<N> {
     type Target = varuna::CircuitVerifyingKey<N::PairingCurve>;
 
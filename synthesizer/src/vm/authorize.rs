@@ -49,6 +49,24 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         result
     }
 
+    /// Prepares an unsigned authorization of a call to the program function for the given inputs.
+    ///
+    /// The unsigned authorization does not require the private key. It carries the programs in
+    /// the call graph, so it may be signed on an offline machine with `Authorization::sign`,
+    /// and the signed authorization proven with `VM::execute_authorization`.
+    #[inline]
+    pub fn authorize_unsigned(
+        &self,
+        program_id: impl TryInto<ProgramID<N>>,
+        function_name: impl TryInto<Identifier<N>>,
+        inputs: impl IntoIterator<IntoIter = impl ExactSizeIterator<Item = impl TryInto<Value<N>>>>,
+    ) -> Result<UnsignedAuthorization<N>> {
+        let timer = timer!("VM::authorize_unsigned");
+        let result = self.process.read().authorize_unsigned(program_id, function_name, inputs.into_iter());
+        finish!(timer, "Prepare the unsigned authorization");
+        result
+    }
+
     /// Authorizes the fee given the credits record, the fee amount (in microcredits),
     /// and the deployment or execution ID.
     #[inline]
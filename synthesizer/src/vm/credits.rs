@@ -0,0 +1,356 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![allow(clippy::too_many_arguments)]
+
+use super::*;
+
+use console::types::Boolean;
+
+impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
+    /// Returns a typed interface for the functions in `credits.aleo`.
+    pub const fn credits(&self) -> Credits<'_, N, C> {
+        Credits { vm: self }
+    }
+}
+
+/// A typed interface for the functions in `credits.aleo`.
+///
+/// Each method constructs the inputs for the corresponding function, and returns a new execute transaction.
+/// If a `fee_record` is provided, then a private fee will be included in the transaction;
+/// otherwise, a public fee will be included in the transaction.
+/// The `priority_fee_in_microcredits` is an additional fee **on top** of the execution fee.
+///
+/// Note: The fee functions (`fee_private` and `fee_public`) are authorized with
+/// `VM::authorize_fee_private` and `VM::authorize_fee_public`, respectively.
+pub struct Credits<'a, N: Network, C: ConsensusStorage<N>> {
+    /// The VM.
+    vm: &'a VM<N, C>,
+}
+
+impl<'a, N: Network, C: ConsensusStorage<N>> Credits<'a, N, C> {
+    /// Returns a transaction that bonds the given amount of microcredits from the caller to the given validator.
+    /// If the validator is the caller, then the caller bonds as a validator.
+    pub fn bond_public<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        validator: Address<N>,
+        amount_in_microcredits: u64,
+        fee_record: Option<Record<N, Plaintext<N>>>,
+        priority_fee_in_microcredits: u64,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<Transaction<N>> {
+        // Prepare the inputs.
+        let inputs = [address_input(validator), u64_input(amount_in_microcredits)];
+        // Execute the function.
+        self.execute(private_key, "bond_public", inputs, fee_record, priority_fee_in_microcredits, query, rng)
+    }
+
+    /// Returns a transaction that unbonds the given amount of microcredits for the caller.
+    pub fn unbond_public<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        amount_in_microcredits: u64,
+        fee_record: Option<Record<N, Plaintext<N>>>,
+        priority_fee_in_microcredits: u64,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<Transaction<N>> {
+        // Prepare the inputs.
+        let inputs = [u64_input(amount_in_microcredits)];
+        // Execute the function.
+        self.execute(private_key, "unbond_public", inputs, fee_record, priority_fee_in_microcredits, query, rng)
+    }
+
+    /// Returns a transaction in which the caller, as a validator, unbonds the given delegator.
+    pub fn unbond_delegator_as_validator<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        delegator: Address<N>,
+        fee_record: Option<Record<N, Plaintext<N>>>,
+        priority_fee_in_microcredits: u64,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<Transaction<N>> {
+        // Prepare the inputs.
+        let inputs = [address_input(delegator)];
+        // Execute the function.
+        self.execute(
+            private_key,
+            "unbond_delegator_as_validator",
+            inputs,
+            fee_record,
+            priority_fee_in_microcredits,
+            query,
+            rng,
+        )
+    }
+
+    /// Returns a transaction that claims the unbonded microcredits of the caller, after the unbonding period.
+    pub fn claim_unbond_public<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        fee_record: Option<Record<N, Plaintext<N>>>,
+        priority_fee_in_microcredits: u64,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<Transaction<N>> {
+        // Execute the function.
+        self.execute(private_key, "claim_unbond_public", [], fee_record, priority_fee_in_microcredits, query, rng)
+    }
+
+    /// Returns a transaction in which the caller, as a validator, opens or closes itself to stakers.
+    pub fn set_validator_state<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        is_open: bool,
+        fee_record: Option<Record<N, Plaintext<N>>>,
+        priority_fee_in_microcredits: u64,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<Transaction<N>> {
+        // Prepare the inputs.
+        let inputs = [Value::from(Literal::Boolean(Boolean::new(is_open)))];
+        // Execute the function.
+        self.execute(private_key, "set_validator_state", inputs, fee_record, priority_fee_in_microcredits, query, rng)
+    }
+
+    /// Returns a transaction that transfers the given amount of microcredits
+    /// from the caller's public balance to the recipient's public balance.
+    pub fn transfer_public<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        recipient: Address<N>,
+        amount_in_microcredits: u64,
+        fee_record: Option<Record<N, Plaintext<N>>>,
+        priority_fee_in_microcredits: u64,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<Transaction<N>> {
+        // Prepare the inputs.
+        let inputs = [address_input(recipient), u64_input(amount_in_microcredits)];
+        // Execute the function.
+        self.execute(private_key, "transfer_public", inputs, fee_record, priority_fee_in_microcredits, query, rng)
+    }
+
+    /// Returns a transaction that transfers the given amount of microcredits
+    /// from the given record to a new record owned by the recipient.
+    pub fn transfer_private<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        record: Record<N, Plaintext<N>>,
+        recipient: Address<N>,
+        amount_in_microcredits: u64,
+        fee_record: Option<Record<N, Plaintext<N>>>,
+        priority_fee_in_microcredits: u64,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<Transaction<N>> {
+        // Ensure the record has a sufficient balance.
+        ensure_sufficient_balance(&record, amount_in_microcredits)?;
+        // Prepare the inputs.
+        let inputs = [Value::Record(record), address_input(recipient), u64_input(amount_in_microcredits)];
+        // Execute the function.
+        self.execute(private_key, "transfer_private", inputs, fee_record, priority_fee_in_microcredits, query, rng)
+    }
+
+    /// Returns a transaction that transfers the given amount of microcredits
+    /// from the given record to the recipient's public balance.
+    pub fn transfer_private_to_public<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        record: Record<N, Plaintext<N>>,
+        recipient: Address<N>,
+        amount_in_microcredits: u64,
+        fee_record: Option<Record<N, Plaintext<N>>>,
+        priority_fee_in_microcredits: u64,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<Transaction<N>> {
+        // Ensure the record has a sufficient balance.
+        ensure_sufficient_balance(&record, amount_in_microcredits)?;
+        // Prepare the inputs.
+        let inputs = [Value::Record(record), address_input(recipient), u64_input(amount_in_microcredits)];
+        // Execute the function.
+        self.execute(
+            private_key,
+            "transfer_private_to_public",
+            inputs,
+            fee_record,
+            priority_fee_in_microcredits,
+            query,
+            rng,
+        )
+    }
+
+    /// Returns a transaction that transfers the given amount of microcredits
+    /// from the caller's public balance to a new record owned by the recipient.
+    pub fn transfer_public_to_private<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        recipient: Address<N>,
+        amount_in_microcredits: u64,
+        fee_record: Option<Record<N, Plaintext<N>>>,
+        priority_fee_in_microcredits: u64,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<Transaction<N>> {
+        // Prepare the inputs.
+        let inputs = [address_input(recipient), u64_input(amount_in_microcredits)];
+        // Execute the function.
+        self.execute(
+            private_key,
+            "transfer_public_to_private",
+            inputs,
+            fee_record,
+            priority_fee_in_microcredits,
+            query,
+            rng,
+        )
+    }
+
+    /// Returns a transaction that joins the two given records into a single record.
+    pub fn join<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        first: Record<N, Plaintext<N>>,
+        second: Record<N, Plaintext<N>>,
+        fee_record: Option<Record<N, Plaintext<N>>>,
+        priority_fee_in_microcredits: u64,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<Transaction<N>> {
+        // Prepare the inputs.
+        let inputs = [Value::Record(first), Value::Record(second)];
+        // Execute the function.
+        self.execute(private_key, "join", inputs, fee_record, priority_fee_in_microcredits, query, rng)
+    }
+
+    /// Returns a transaction that splits the given record into two records, where the first
+    /// record contains the given amount of microcredits and the second record contains the remainder.
+    /// Note: The `split` function does not require a fee, unless a priority fee is declared.
+    pub fn split<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        record: Record<N, Plaintext<N>>,
+        amount_in_microcredits: u64,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<Transaction<N>> {
+        // Ensure the record has a sufficient balance.
+        ensure_sufficient_balance(&record, amount_in_microcredits)?;
+        // Prepare the inputs.
+        let inputs = [Value::Record(record), u64_input(amount_in_microcredits)];
+        // Execute the function.
+        self.execute(private_key, "split", inputs, None, 0, query, rng)
+    }
+}
+
+impl<'a, N: Network, C: ConsensusStorage<N>> Credits<'a, N, C> {
+    /// Returns a new execute transaction for the given `credits.aleo` function and inputs.
+    fn execute<R: Rng + CryptoRng, const NUM_INPUTS: usize>(
+        &self,
+        private_key: &PrivateKey<N>,
+        function_name: &str,
+        inputs: [Value<N>; NUM_INPUTS],
+        fee_record: Option<Record<N, Plaintext<N>>>,
+        priority_fee_in_microcredits: u64,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<Transaction<N>> {
+        self.vm.execute(
+            private_key,
+            ("credits.aleo", function_name),
+            inputs.into_iter(),
+            fee_record,
+            priority_fee_in_microcredits,
+            query,
+            rng,
+        )
+    }
+}
+
+/// Returns the given address as an input value.
+fn address_input<N: Network>(address: Address<N>) -> Value<N> {
+    Value::from(Literal::Address(address))
+}
+
+/// Returns the given amount as an input value.
+fn u64_input<N: Network>(amount: u64) -> Value<N> {
+    Value::from(Literal::U64(U64::new(amount)))
+}
+
+/// Ensures the given `credits.aleo` record contains at least the given amount of microcredits.
+fn ensure_sufficient_balance<N: Network>(record: &Record<N, Plaintext<N>>, amount_in_microcredits: u64) -> Result<()> {
+    // Retrieve the balance of the record.
+    let balance = record.microcredits()?;
+    // Ensure the balance is sufficient.
+    ensure!(
+        balance >= amount_in_microcredits,
+        "The record contains {balance} microcredits, which is less than the requested {amount_in_microcredits} microcredits"
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::account::ViewKey;
+    use ledger_block::Transition;
+
+    #[test]
+    fn test_credits_transfer_public_and_private() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
+        let address = Address::try_from(&caller_private_key).unwrap();
+
+        // Initialize the genesis block.
+        let genesis = crate::vm::test_helpers::sample_genesis_block(rng);
+        // Fetch the unspent records.
+        let records = genesis.transitions().cloned().flat_map(Transition::into_records).collect::<Vec<_>>();
+        let record = records[0].1.decrypt(&caller_view_key).unwrap();
+
+        // Initialize the VM.
+        let vm = crate::vm::test_helpers::sample_vm();
+        // Update the VM.
+        vm.add_next_block(&genesis).unwrap();
+
+        // Construct a `transfer_public` transaction.
+        let transaction = vm.credits().transfer_public(&caller_private_key, address, 1, None, 0, None, rng).unwrap();
+        vm.check_transaction(&transaction, None, rng).unwrap();
+        // Ensure the transaction calls the expected function.
+        let transition = transaction.transitions().next().unwrap();
+        assert_eq!(transition.program_id().to_string(), "credits.aleo");
+        assert_eq!(transition.function_name().to_string(), "transfer_public");
+
+        // Ensure `transfer_private` rejects an amount that exceeds the record balance.
+        let balance = record.microcredits().unwrap();
+        assert!(
+            vm.credits()
+                .transfer_private(&caller_private_key, record.clone(), address, balance + 1, None, 0, None, rng)
+                .is_err()
+        );
+
+        // Construct a `transfer_private` transaction.
+        let transaction =
+            vm.credits().transfer_private(&caller_private_key, record, address, 1, None, 0, None, rng).unwrap();
+        vm.check_transaction(&transaction, None, rng).unwrap();
+        let transition = transaction.transitions().next().unwrap();
+        assert_eq!(transition.function_name().to_string(), "transfer_private");
+    }
+}
@@ -32,6 +32,167 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     ) -> Result<Transaction<N>> {
         // Compute the deployment.
         let deployment = self.deploy_raw(program, rng)?;
+        // Return the deploy transaction.
+        self.deploy_with_fee(private_key, deployment, fee_record, priority_fee_in_microcredits, query, rng)
+    }
+
+    /// Returns a new deploy transaction, for the next edition of an existing program.
+    ///
+    /// If a `fee_record` is provided, then a private fee will be included in the transaction;
+    /// otherwise, a public fee will be included in the transaction.
+    ///
+    /// The `priority_fee_in_microcredits` is an additional fee **on top** of the deployment fee.
+    pub fn deploy_upgrade<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        program: &Program<N>,
+        fee_record: Option<Record<N, Plaintext<N>>>,
+        priority_fee_in_microcredits: u64,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<Transaction<N>> {
+        // Compute the deployment.
+        let deployment = self.prepare_deployment_upgrade(program, rng)?;
+        // Return the deploy transaction.
+        self.deploy_with_fee(private_key, deployment, fee_record, priority_fee_in_microcredits, query, rng)
+    }
+
+    /// Returns a deployment for the given program, to be signed by the program owner.
+    ///
+    /// The program owner signs the deployment ID (see `Deployment::to_deployment_id`) with `ProgramOwner::new`,
+    /// which does not require the deployment itself. This allows the owner key to be kept offline, while this
+    /// machine synthesizes the deployment. The transaction is then constructed with `VM::deploy_with_owner`.
+    pub fn prepare_deployment<R: Rng + CryptoRng>(&self, program: &Program<N>, rng: &mut R) -> Result<Deployment<N>> {
+        self.deploy_raw(program, rng)
+    }
+
+    /// Returns a deployment for the next edition of an existing program, to be signed by the program owner.
+    ///
+    /// See `VM::prepare_deployment` for how the deployment is signed.
+    pub fn prepare_deployment_upgrade<R: Rng + CryptoRng>(
+        &self,
+        program: &Program<N>,
+        rng: &mut R,
+    ) -> Result<Deployment<N>> {
+        // Retrieve the latest edition of the program.
+        let Some(edition) = self.transaction_store().deployment_store().get_edition(program.id())? else {
+            bail!("Cannot upgrade '{}', as it has not been deployed", program.id())
+        };
+        // Determine the next edition.
+        let Some(edition) = edition.checked_add(1) else {
+            bail!("Cannot upgrade '{}', as it has reached the maximum edition", program.id())
+        };
+        // Compute the deployment.
+        self.deploy_upgrade_raw(program, edition, rng)
+    }
+
+    /// Returns a new deploy transaction for the given deployment, signed by the given program owner.
+    ///
+    /// The `owner` and `fee_authorization` must both be over the deployment ID of the deployment,
+    /// and may be produced on a separate machine from the deployment (see `VM::prepare_deployment`).
+    pub fn deploy_with_owner<R: Rng + CryptoRng>(
+        &self,
+        owner: ProgramOwner<N>,
+        deployment: Deployment<N>,
+        fee_authorization: Authorization<N>,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<Transaction<N>> {
+        // Ensure the transaction is not empty.
+        ensure!(!deployment.program().functions().is_empty(), "Attempted to create an empty transaction deployment");
+        // Compute the deployment ID.
+        let deployment_id = deployment.to_deployment_id()?;
+        // Ensure the owner signed the deployment ID.
+        ensure!(
+            owner.verify(deployment_id),
+            "Invalid owner signature for the deployment of '{}'",
+            deployment.program_id()
+        );
+
+        // Ensure the fee authorization is for the deployment ID.
+        ensure!(
+            fee_authorization.is_fee_private() || fee_authorization.is_fee_public(),
+            "Expected a fee authorization for the deployment of '{}'",
+            deployment.program_id()
+        );
+        match fee_authorization.peek_next()?.inputs().last() {
+            Some(Value::Plaintext(Plaintext::Literal(Literal::Field(id), _))) if *id == deployment_id => (),
+            _ => bail!("The fee authorization is not for the deployment of '{}'", deployment.program_id()),
+        }
+        // Compute the fee.
+        let fee = self.execute_fee_authorization(fee_authorization, query, rng)?;
+
+        // Return the deploy transaction.
+        Transaction::from_deployment(owner, deployment, fee)
+    }
+
+    /// Returns a new deprecate transaction, which marks the given program as deprecated.
+    ///
+    /// If a `fee_record` is provided, then a private fee will be included in the transaction;
+    /// otherwise, a public fee will be included in the transaction.
+    ///
+    /// If the `mapping_policy` is `MappingPolicy::Freeze`, then the program mappings are preserved as read-only state;
+    /// otherwise, the program mappings are removed when the transaction is finalized.
+    ///
+    /// The `priority_fee_in_microcredits` is an additional fee **on top** of the deprecation fee.
+    pub fn deprecate<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        program_id: &ProgramID<N>,
+        mapping_policy: MappingPolicy,
+        fee_record: Option<Record<N, Plaintext<N>>>,
+        priority_fee_in_microcredits: u64,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<Transaction<N>> {
+        // Ensure the program exists.
+        ensure!(self.contains_program(program_id), "Cannot deprecate '{program_id}', as it has not been deployed");
+        // Compute the deprecation ID.
+        let deprecation_id = Transaction::<N>::deprecation_id(program_id, mapping_policy)?;
+        // Construct the owner.
+        let owner = ProgramOwner::new(private_key, deprecation_id, rng)?;
+
+        // Compute the minimum deprecation cost.
+        let minimum_deprecation_cost = deprecation_cost(program_id)?;
+        // Authorize the fee.
+        let fee_authorization = match fee_record {
+            Some(record) => self.authorize_fee_private(
+                private_key,
+                record,
+                minimum_deprecation_cost,
+                priority_fee_in_microcredits,
+                deprecation_id,
+                rng,
+            )?,
+            None => self.authorize_fee_public(
+                private_key,
+                minimum_deprecation_cost,
+                priority_fee_in_microcredits,
+                deprecation_id,
+                rng,
+            )?,
+        };
+        // Compute the fee.
+        let fee = self.execute_fee_authorization(fee_authorization, query, rng)?;
+
+        // Return the deprecate transaction.
+        TransactionBuilder::new()
+            .deprecation(owner, *program_id, mapping_policy)?
+            .fee(fee)?
+            .priority_fee(priority_fee_in_microcredits)?
+            .build()
+    }
+
+    /// Returns a new deploy transaction for the given deployment, with the fee authorized by the given private key.
+    fn deploy_with_fee<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        deployment: Deployment<N>,
+        fee_record: Option<Record<N, Plaintext<N>>>,
+        priority_fee_in_microcredits: u64,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<Transaction<N>> {
         // Ensure the transaction is not empty.
         ensure!(!deployment.program().functions().is_empty(), "Attempted to create an empty transaction deployment");
         // Compute the deployment ID.
@@ -59,11 +220,9 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 rng,
             )?,
         };
-        // Compute the fee.
-        let fee = self.execute_fee_authorization(fee_authorization, query, rng)?;
 
         // Return the deploy transaction.
-        Transaction::from_deployment(owner, deployment, fee)
+        self.deploy_with_owner(owner, deployment, fee_authorization, query, rng)
     }
 }
 
@@ -88,4 +247,30 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         finish!(timer, "Compute the deployment");
         result
     }
+
+    /// Returns a deployment for the given edition of an existing program.
+    #[inline]
+    pub(super) fn deploy_upgrade_raw<R: Rng + CryptoRng>(
+        &self,
+        program: &Program<N>,
+        edition: u16,
+        rng: &mut R,
+    ) -> Result<Deployment<N>> {
+        macro_rules! logic {
+            ($process:expr, $network:path, $aleo:path) => {{
+                // Prepare the program.
+                let program = cast_ref!(&program as Program<$network>);
+                // Compute the deployment.
+                let deployment = $process.deploy_upgrade::<$aleo, _>(program, edition, rng)?;
+                // Prepare the deployment.
+                Ok(cast_ref!(deployment as Deployment<N>).clone())
+            }};
+        }
+
+        // Compute the deployment.
+        let timer = timer!("VM::deploy_upgrade_raw");
+        let result = process!(self, logic);
+        finish!(timer, "Compute the deployment");
+        result
+    }
 }
@@ -23,6 +23,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     /// otherwise, a public fee will be included in the transaction.
     ///
     /// The `priority_fee_in_microcredits` is an additional fee **on top** of the execution fee.
+    #[cfg(feature = "prover")]
     pub fn execute<R: Rng + CryptoRng>(
         &self,
         private_key: &PrivateKey<N>,
@@ -72,10 +73,61 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             false => None,
         };
         // Return the execute transaction.
-        Transaction::from_execution(execution, fee)
+        let builder = TransactionBuilder::new().execution(execution)?;
+        match fee {
+            Some(fee) => builder.fee(fee)?.priority_fee(priority_fee_in_microcredits)?.build(),
+            None => builder.build(),
+        }
+    }
+
+    /// Returns a new sequenced execute transaction, with a public fee.
+    ///
+    /// The execution must not consume or produce records, and is only accepted if the `sequence`
+    /// is the next sequence number of the fee payer, as given by `VM::next_sequence`.
+    /// As such, the sequenced executions of an address are accepted in order, and at most once.
+    ///
+    /// The `priority_fee_in_microcredits` is an additional fee **on top** of the execution fee.
+    #[cfg(feature = "prover")]
+    pub fn execute_sequenced<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        (program_id, function_name): (impl TryInto<ProgramID<N>>, impl TryInto<Identifier<N>>),
+        inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
+        sequence: u64,
+        priority_fee_in_microcredits: u64,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<Transaction<N>> {
+        // Compute the authorization.
+        let authorization = self.authorize(private_key, program_id, function_name, inputs, rng)?;
+        // Compute the execution, with the sequence number.
+        let execution =
+            self.execute_authorization_raw(authorization, query.clone(), rng)?.with_sequence(Some(sequence))?;
+        // Ensure the execution does not consume or produce records.
+        ensure!(execution.is_public_only(), "A sequenced execution cannot consume or produce records");
+        // Compute the minimum execution cost.
+        let (minimum_execution_cost, (_, _)) = execution_cost(self, &execution)?;
+        // Authorize the public fee, which binds the sequence number through the execution ID.
+        let authorization = self.authorize_fee_public(
+            private_key,
+            minimum_execution_cost,
+            priority_fee_in_microcredits,
+            execution.to_execution_id()?,
+            rng,
+        )?;
+        // Execute the fee.
+        let fee = self.execute_fee_authorization_raw(authorization, query, rng)?;
+        // Return the execute transaction.
+        TransactionBuilder::new().execution(execution)?.fee(fee)?.priority_fee(priority_fee_in_microcredits)?.build()
+    }
+
+    /// Returns the next sequence number of the given address, for use in `VM::execute_sequenced`.
+    pub fn next_sequence(&self, address: &Address<N>) -> Result<u64> {
+        Process::next_sequence_confirmed(self.finalize_store(), address)
     }
 
     /// Returns a new execute transaction for the given authorization.
+    #[cfg(feature = "prover")]
     pub fn execute_authorization<R: Rng + CryptoRng>(
         &self,
         execute_authorization: Authorization<N>,
@@ -95,6 +147,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     }
 
     /// Returns a new fee for the given authorization.
+    #[cfg(feature = "prover")]
     pub fn execute_fee_authorization<R: Rng + CryptoRng>(
         &self,
         authorization: Authorization<N>,
@@ -104,11 +157,29 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         debug_assert!(authorization.is_fee_private() || authorization.is_fee_public(), "Expected a fee authorization");
         self.execute_fee_authorization_raw(authorization, query, rng)
     }
+
+    /// Returns the proving response for the given proving request.
+    ///
+    /// The prover does not need the private key, as the request carries a signed authorization.
+    /// The client checks the response with `ProvingRequest::verify_response` before attaching a fee.
+    #[cfg(feature = "prover")]
+    pub fn prove<R: Rng + CryptoRng>(
+        &self,
+        request: ProvingRequest<N>,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<ProvingResponse<N>> {
+        // Compute the execution.
+        let execution = self.execute_authorization_raw(request.into_authorization(), query, rng)?;
+        // Return the proving response.
+        Ok(ProvingResponse::new(execution))
+    }
 }
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     /// Executes a call to the program function for the given authorization.
     /// Returns the execution.
+    #[cfg(feature = "prover")]
     #[inline]
     fn execute_authorization_raw<R: Rng + CryptoRng>(
         &self,
@@ -159,6 +230,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
     /// Executes a call to the program function for the given fee authorization.
     /// Returns the fee.
+    #[cfg(feature = "prover")]
     #[inline]
     fn execute_fee_authorization_raw<R: Rng + CryptoRng>(
         &self,
@@ -242,6 +314,83 @@ mod tests {
         Ok((vm, records))
     }
 
+    #[test]
+    fn test_offline_signing() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let address = Address::try_from(&caller_private_key).unwrap();
+
+        // Prepare the VM.
+        let (vm, _) = prepare_vm(rng).unwrap();
+
+        // Prepare the unsigned authorization, without the private key.
+        let inputs = [
+            Value::<CurrentNetwork>::from_str(&address.to_string()).unwrap(),
+            Value::<CurrentNetwork>::from_str("1u64").unwrap(),
+        ];
+        let unsigned = vm.authorize_unsigned("credits.aleo", "transfer_public", inputs).unwrap();
+        let unsigned = UnsignedAuthorization::<CurrentNetwork>::from_str(&unsigned.to_string()).unwrap();
+
+        // Sign the execution and the fee, independently of the VM.
+        let authorization = Authorization::sign::<circuit::AleoV0, _>(&unsigned, &caller_private_key, rng).unwrap();
+        let execution_id = authorization.to_execution_id().unwrap();
+        let fee_authorization =
+            Authorization::sign_fee_public::<circuit::AleoV0, _>(&caller_private_key, 1_000_000, 0, execution_id, rng)
+                .unwrap();
+        let authorization = Authorization::<CurrentNetwork>::from_str(&authorization.to_string()).unwrap();
+        let fee_authorization = Authorization::<CurrentNetwork>::from_str(&fee_authorization.to_string()).unwrap();
+
+        // Prove and assemble the transaction.
+        let transaction = vm.execute_authorization(authorization, Some(fee_authorization), None, rng).unwrap();
+        // Verify.
+        vm.check_transaction(&transaction, None, rng).unwrap();
+    }
+
+    #[test]
+    fn test_delegated_proving() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let address = Address::try_from(&caller_private_key).unwrap();
+
+        // Prepare the VM.
+        let (vm, _) = prepare_vm(rng).unwrap();
+
+        // Prepare the proving requests.
+        let mut sample_request = |amount: &str| {
+            let inputs = [
+                Value::<CurrentNetwork>::from_str(&address.to_string()).unwrap(),
+                Value::<CurrentNetwork>::from_str(amount).unwrap(),
+            ];
+            let authorization =
+                vm.authorize(&caller_private_key, "credits.aleo", "transfer_public", inputs.into_iter(), rng).unwrap();
+            ProvingRequest::new(authorization).unwrap()
+        };
+        let request = sample_request("1u64");
+        let other_request = sample_request("2u64");
+
+        // Prove the request, as an untrusted prover would.
+        let prover_request = ProvingRequest::<CurrentNetwork>::from_str(&request.to_string()).unwrap();
+        let response = vm.prove(prover_request, None, rng).unwrap();
+        let response = ProvingResponse::<CurrentNetwork>::from_str(&response.to_string()).unwrap();
+
+        // Check the response is valid for the request, and only for the request.
+        request.verify_response(&vm.process().read(), &response).unwrap();
+        let other_response = vm.prove(other_request.clone(), None, rng).unwrap();
+        assert!(request.verify_response(&vm.process().read(), &other_response).is_err());
+        assert!(other_request.verify_response(&vm.process().read(), &response).is_err());
+
+        // Attach the fee, and verify the transaction.
+        let fee_authorization =
+            vm.authorize_fee_public(&caller_private_key, 1_000_000, 0, request.execution_id(), rng).unwrap();
+        let fee = vm.execute_fee_authorization(fee_authorization, None, rng).unwrap();
+        let transaction = Transaction::from_execution(response.into_execution(), Some(fee)).unwrap();
+        vm.check_transaction(&transaction, None, rng).unwrap();
+    }
+
     #[test]
     fn test_transfer_private_transaction_size() {
         let rng = &mut TestRng::default();
@@ -195,13 +195,13 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
             // Initialize an iterator for ratifications before finalize.
             let pre_ratifications = ratifications.iter().filter(|r| match r {
-                Ratify::Genesis(_, _, _) => true,
-                Ratify::BlockReward(..) | Ratify::PuzzleReward(..) => false,
+                Ratify::Genesis(_, _, _) | Ratify::Randomness(..) => true,
+                Ratify::BlockReward(..) | Ratify::PuzzleReward(..) | Ratify::SigningKey(..) => false,
             });
             // Initialize an iterator for ratifications after finalize.
             let post_ratifications = ratifications.iter().filter(|r| match r {
-                Ratify::Genesis(_, _, _) => false,
-                Ratify::BlockReward(..) | Ratify::PuzzleReward(..) => true,
+                Ratify::Genesis(_, _, _) | Ratify::Randomness(..) => false,
+                Ratify::BlockReward(..) | Ratify::PuzzleReward(..) | Ratify::SigningKey(..) => true,
             });
 
             // Initialize a list of finalize operations.
@@ -225,6 +225,8 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             // Note: Due to the highly-sensitive nature of processing all `finalize` calls,
             // we choose to acquire the write lock for the entire duration of this atomic batch.
             let process = self.process.write();
+            // Retrieve the finalize profiler, if it is enabled.
+            let profiler = self.finalize_profiler.read().clone();
 
             // Initialize a list of the confirmed transactions.
             let mut confirmed = Vec::with_capacity(num_transactions);
@@ -232,6 +234,10 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             let mut aborted = Vec::new();
             // Initialize a list of the successful deployments.
             let mut deployments = IndexSet::new();
+            // Initialize a list of the programs imported by the successful deployments.
+            let mut deployed_imports = IndexSet::new();
+            // Initialize a list of the successful deprecations.
+            let mut deprecations = IndexSet::new();
             // Initialize a counter for the confirmed transaction index.
             let mut counter = 0u32;
             // Initialize a list of created transition IDs.
@@ -242,6 +248,8 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             let mut output_ids: IndexSet<Field<N>> = IndexSet::new();
             // Initialize the list of created transition public keys.
             let mut tpks: IndexSet<Group<N>> = IndexSet::new();
+            // Initialize the finalize cost of the executions in the block.
+            let mut block_finalize_cost = 0u64;
 
             // Finalize the transactions.
             'outer: for transaction in transactions {
@@ -305,6 +313,58 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                     }
                 }
 
+                // Ensure that the transaction is not executing a program deprecated in this block.
+                for transition in transaction.transitions() {
+                    // If the program was deprecated in this block, abort the transaction.
+                    if deprecations.contains(transition.program_id()) {
+                        // Store the aborted transaction.
+                        let error = format!("Execution of deprecated program {}", transition.program_id());
+                        aborted.push((transaction.clone(), error));
+                        // Continue to the next transaction.
+                        continue 'outer;
+                    }
+                }
+
+                // Ensure that a sequenced execution carries the next sequence number of its fee payer.
+                if let Transaction::Execute(_, execution, fee) = transaction {
+                    if execution.sequence().is_some() {
+                        // If the sequence number is used or out of order, abort the transaction.
+                        if let Err(error) = Process::check_sequence(store, execution, fee.as_ref()) {
+                            // Store the aborted transaction.
+                            aborted.push((transaction.clone(), error.to_string()));
+                            // Continue to the next transaction.
+                            continue 'outer;
+                        }
+                    }
+                }
+
+                // Ensure that the execution does not exceed the finalize budget of the block.
+                if let Transaction::Execute(_, execution, _) = transaction {
+                    // Compute the finalize cost of the execution.
+                    let finalize_cost = match execution_finalize_cost(&process, execution) {
+                        Ok(finalize_cost) => finalize_cost,
+                        Err(error) => {
+                            // Store the aborted transaction.
+                            let error = format!("Failed to compute the finalize cost - {error}");
+                            aborted.push((transaction.clone(), error));
+                            // Continue to the next transaction.
+                            continue 'outer;
+                        }
+                    };
+                    // Compute the finalize cost of the block, including the execution.
+                    match block_finalize_cost.checked_add(finalize_cost) {
+                        Some(cost) if cost <= CostModel::for_network::<N>().max_block_finalize_cost => {
+                            block_finalize_cost = cost
+                        }
+                        _ => {
+                            // Store the aborted transaction.
+                            aborted.push((transaction.clone(), "Exceeds block finalize budget".to_string()));
+                            // Continue to the next transaction.
+                            continue 'outer;
+                        }
+                    }
+                }
+
                 // Process the transaction in an isolated atomic batch.
                 // - If the transaction succeeds, the finalize operations are stored.
                 // - If the transaction fails, the atomic batch is aborted and no finalize operations are stored.
@@ -350,6 +410,8 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                                 Ok((_, finalize)) => {
                                     // Add the program id to the list of deployments.
                                     deployments.insert(*deployment.program_id());
+                                    // Add the imports of the program to the list of deployed imports.
+                                    deployed_imports.extend(deployment.program().imports().keys().copied());
                                     ConfirmedTransaction::accepted_deploy(counter, transaction.clone(), finalize)
                                         .map_err(|e| e.to_string())
                                 }
@@ -372,7 +434,13 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                     // The finalize operation here involves calling 'update_key_value',
                     // and update the respective leaves of the finalize tree.
                     Transaction::Execute(_, execution, fee) => {
-                        match process.finalize_execution(state, store, execution, fee.as_ref()) {
+                        match process.finalize_execution_with_profiler(
+                            state,
+                            store,
+                            execution,
+                            fee.as_ref(),
+                            profiler.as_deref(),
+                        ) {
                             // Construct the accepted execute transaction.
                             Ok(finalize) => {
                                 ConfirmedTransaction::accepted_execute(counter, transaction.clone(), finalize)
@@ -412,6 +480,54 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                     // There are no finalize operations here.
                     // Note: This will abort the entire atomic batch.
                     Transaction::Fee(..) => Err("Cannot speculate on a fee transaction".to_string()),
+                    // The finalize operation here involves marking the program as deprecated.
+                    Transaction::Deprecate(_, _, program_id, mapping_policy, fee) => {
+                        // Ensure the program has not been deployed or deprecated in this block.
+                        if deployments.contains(program_id) || deprecations.contains(program_id) {
+                            // Store the aborted transaction.
+                            aborted.push((transaction.clone(), format!("Program {program_id} changed in this block")));
+                            // Continue to the next transaction.
+                            continue 'outer;
+                        }
+                        // Ensure the mappings are not removed while other programs import the program.
+                        if mapping_policy.is_remove() {
+                            // Ensure the program is not imported by a program deployed in this block.
+                            if deployed_imports.contains(program_id) {
+                                // Store the aborted transaction.
+                                aborted.push((
+                                    transaction.clone(),
+                                    format!("Program {program_id} imported in this block"),
+                                ));
+                                // Continue to the next transaction.
+                                continue 'outer;
+                            }
+                            // Ensure the program is not imported by a program that is not deprecated.
+                            if let Err(error) = self.check_mapping_removal(program_id) {
+                                // Store the aborted transaction.
+                                aborted.push((transaction.clone(), error.to_string()));
+                                // Continue to the next transaction.
+                                continue 'outer;
+                            }
+                        }
+                        match process.finalize_deprecation(state, store, program_id, *mapping_policy, fee) {
+                            // Construct the accepted deprecate transaction.
+                            Ok(finalize) => {
+                                // Add the program id to the list of deprecations.
+                                deprecations.insert(*program_id);
+                                ConfirmedTransaction::accepted_deprecate(counter, transaction.clone(), finalize)
+                                    .map_err(|e| e.to_string())
+                            }
+                            Err(error) => {
+                                // Note: On failure, skip this transaction, and continue speculation.
+                                #[cfg(debug_assertions)]
+                                eprintln!("Failed to finalize the deprecate transaction - {error}");
+                                // Store the aborted transaction.
+                                aborted.push((transaction.clone(), error.to_string()));
+                                // Continue to the next transaction.
+                                continue 'outer;
+                            }
+                        }
+                    }
                 };
                 lap!(timer, "Speculated on transaction '{}'", transaction.id());
 
@@ -497,6 +613,12 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 return Err("Failed to construct the ratifications after speculation".to_string());
             };
 
+            // Publish the finalize profiles to the metrics registry.
+            #[cfg(feature = "metrics")]
+            if let Some(profiler) = &profiler {
+                profiler.publish_metrics();
+            }
+
             finish!(timer);
 
             // On return, 'atomic_finalize!' will abort the batch, and return the ratifications,
@@ -527,13 +649,13 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         atomic_finalize!(self.finalize_store(), FinalizeMode::RealRun, {
             // Initialize an iterator for ratifications before finalize.
             let pre_ratifications = ratifications.iter().filter(|r| match r {
-                Ratify::Genesis(_, _, _) => true,
-                Ratify::BlockReward(..) | Ratify::PuzzleReward(..) => false,
+                Ratify::Genesis(_, _, _) | Ratify::Randomness(..) => true,
+                Ratify::BlockReward(..) | Ratify::PuzzleReward(..) | Ratify::SigningKey(..) => false,
             });
             // Initialize an iterator for ratifications after finalize.
             let post_ratifications = ratifications.iter().filter(|r| match r {
-                Ratify::Genesis(_, _, _) => false,
-                Ratify::BlockReward(..) | Ratify::PuzzleReward(..) => true,
+                Ratify::Genesis(_, _, _) | Ratify::Randomness(..) => false,
+                Ratify::BlockReward(..) | Ratify::PuzzleReward(..) | Ratify::SigningKey(..) => true,
             });
 
             // Initialize a list of finalize operations.
@@ -560,6 +682,8 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
             // Initialize a list for the deployed stacks.
             let mut stacks = Vec::new();
+            // Initialize a list for the deprecated program IDs.
+            let mut deprecations = Vec::new();
 
             // Finalize the transactions.
             for (index, transaction) in transactions.iter().enumerate() {
@@ -628,6 +752,35 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                         }
                         Ok(())
                     }
+                    ConfirmedTransaction::AcceptedDeprecate(_, transaction, finalize) => {
+                        // Extract the program ID, mapping policy, and fee from the transaction.
+                        let (program_id, mapping_policy, fee) = match transaction {
+                            Transaction::Deprecate(_, _, program_id, mapping_policy, fee) => {
+                                (program_id, *mapping_policy, fee)
+                            }
+                            // Note: This will abort the entire atomic batch.
+                            _ => return Err("Expected deprecate transaction".to_string()),
+                        };
+                        // The finalize operation here involves marking the program as deprecated.
+                        match process.finalize_deprecation(state, store, program_id, mapping_policy, fee) {
+                            // Ensure the finalize operations match the expected.
+                            Ok(finalize_operations) => match finalize == &finalize_operations {
+                                // Store the program ID.
+                                true => deprecations.push(*program_id),
+                                // Note: This will abort the entire atomic batch.
+                                false => {
+                                    return Err(format!(
+                                        "Mismatch in finalize operations for an accepted deprecate - (found: {finalize_operations:?}, expected: {finalize:?})"
+                                    ));
+                                }
+                            },
+                            // Note: This will abort the entire atomic batch.
+                            Err(error) => {
+                                return Err(format!("Failed to finalize an accepted deprecate transaction - {error}"));
+                            }
+                        }
+                        Ok(())
+                    }
                     ConfirmedTransaction::RejectedDeploy(_, Transaction::Fee(_, fee), rejected, finalize) => {
                         // Extract the rejected deployment.
                         let Some(deployment) = rejected.deployment() else {
@@ -738,6 +891,9 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             if !stacks.is_empty() {
                 stacks.into_iter().for_each(|stack| process.add_stack(stack))
             }
+            // Commit all of the deprecations to the process.
+            // Note: A deprecation applies to dynamic calls from the next block, which matches `VM::speculate`.
+            deprecations.into_iter().for_each(|program_id| process.mark_deprecated(program_id));
 
             finish!(timer); // <- Note: This timer does **not** include the time to write batch to DB.
 
@@ -768,6 +924,8 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
         // Initialize a flag for the genesis ratification.
         let mut is_genesis_ratified = false;
+        // Initialize a flag for the randomness ratification.
+        let mut is_randomness_ratified = false;
 
         // Iterate over the ratifications.
         for ratify in pre_ratifications {
@@ -862,7 +1020,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                     // Insert the next committee into storage.
                     store.committee_store().insert(state.block_height(), *(committee.clone()))?;
                     // Store the finalize operations for updating the committee and bonded mapping.
-                    finalize_operations.extend(&[
+                    finalize_operations.extend([
                         // Replace the committee mapping in storage.
                         store.replace_mapping(program_id, committee_mapping, next_committee_map)?,
                         // Replace the bonded mapping in storage.
@@ -870,7 +1028,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                     ]);
 
                     // Update the number of validators.
-                    finalize_operations.extend(&[
+                    finalize_operations.extend([
                         // Update the number of validators in the metadata mapping.
                         store.update_key_value(
                             program_id,
@@ -881,7 +1039,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                     ]);
 
                     // Update the number of delegators.
-                    finalize_operations.extend(&[
+                    finalize_operations.extend([
                         // Update the number of delegators in the metadata mapping.
                         store.update_key_value(
                             program_id,
@@ -903,7 +1061,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                         .collect::<Vec<_>>();
 
                     // Update the public balances.
-                    finalize_operations.extend(&[
+                    finalize_operations.extend([
                         // Update the public balances in storage.
                         store.replace_mapping(program_id, account_mapping, public_balances)?,
                     ]);
@@ -911,10 +1069,40 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                     // Set the genesis ratification flag.
                     is_genesis_ratified = true;
                 }
-                Ratify::BlockReward(..) | Ratify::PuzzleReward(..) => continue,
+                Ratify::Randomness(round, randomness, _) => {
+                    // Ensure the randomness has not been ratified yet.
+                    ensure!(!is_randomness_ratified, "Ratify::Randomness(..) has already been ratified");
+                    // Retrieve the randomness beacon of the network.
+                    let Some(beacon) = N::randomness_beacon() else {
+                        bail!(
+                            "Ratify::Randomness(..) is not supported, as the network does not have a randomness beacon"
+                        )
+                    };
+                    // Ensure the randomness is signed by the randomness beacon.
+                    ensure!(
+                        ratify.verify_randomness(&beacon),
+                        "Ratify::Randomness(..) has an invalid signature for the beacon round {round}"
+                    );
+                    // Ensure the randomness matches the finalize state.
+                    let expected_randomness = randomness.to_bytes_le()?;
+                    ensure!(
+                        state.block_randomness().map(|bytes| &bytes[..]) == Some(&expected_randomness[..]),
+                        "Ratify::Randomness(..) does not match the randomness in the finalize state"
+                    );
+
+                    // Set the randomness ratification flag.
+                    is_randomness_ratified = true;
+                }
+                Ratify::BlockReward(..) | Ratify::PuzzleReward(..) | Ratify::SigningKey(..) => continue,
             }
         }
 
+        // Ensure the finalize state does not contain randomness that was not ratified.
+        ensure!(
+            is_randomness_ratified || state.block_randomness().is_none(),
+            "The finalize state contains randomness without a randomness ratification"
+        );
+
         // Return the finalize operations.
         Ok(finalize_operations)
     }
@@ -935,6 +1123,8 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         let bonded_mapping = Identifier::from_str("bonded")?;
         // Construct the account mapping name.
         let account_mapping = Identifier::from_str("account")?;
+        // Construct the signer mapping name.
+        let signer_mapping = Identifier::from_str("signer")?;
 
         // Initialize a list of finalize operations.
         let mut finalize_operations = Vec::new();
@@ -947,7 +1137,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         // Iterate over the ratifications.
         for ratify in post_ratifications {
             match ratify {
-                Ratify::Genesis(..) => continue,
+                Ratify::Genesis(..) | Ratify::Randomness(..) => continue,
                 Ratify::BlockReward(block_reward) => {
                     // Ensure the block reward has not been ratified yet.
                     ensure!(!is_block_reward_ratified, "Ratify::BlockReward(..) has already been ratified");
@@ -968,6 +1158,25 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                     let next_stakers = staking_rewards(&current_stakers, &current_committee, *block_reward);
                     // Compute the updated committee, using the stakers.
                     let next_committee = to_next_committee(&current_committee, state.block_round(), &next_stakers)?;
+                    // Retrieve the signer mapping from storage.
+                    // Note: The signer mapping is only read once signing key rotations are enabled.
+                    let current_signer_map = match N::consensus_version(state.block_height())? >= ConsensusVersion::V3 {
+                        true => store.get_mapping_speculative(program_id, signer_mapping)?,
+                        false => vec![],
+                    };
+                    // Retain the rotated signing keys of validators that remain in the next committee.
+                    let next_signers = signer_map_into_signers(current_signer_map)?
+                        .into_iter()
+                        .map(|(validator, (signer, _))| (validator, signer))
+                        .filter(|(validator, signer)| {
+                            signer != validator
+                                && next_committee.is_committee_member(*validator)
+                                && !next_committee.is_committee_member(*signer)
+                        })
+                        .collect();
+                    // Assign the signing keys to the next committee.
+                    // Note: A rotated signing key takes effect from the next committee onwards.
+                    let next_committee = next_committee.with_signers(next_signers)?;
 
                     // Construct the next committee map and next bonded map.
                     let (next_committee_map, next_bonded_map) =
@@ -976,7 +1185,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                     // Insert the next committee into storage.
                     store.committee_store().insert(state.block_height(), next_committee)?;
                     // Store the finalize operations for updating the committee and bonded mapping.
-                    finalize_operations.extend(&[
+                    finalize_operations.extend([
                         // Replace the committee mapping in storage.
                         store.replace_mapping(program_id, committee_mapping, next_committee_map)?,
                         // Replace the bonded mapping in storage.
@@ -1025,6 +1234,63 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                     // Set the puzzle reward ratification flag.
                     is_puzzle_reward_ratified = true;
                 }
+                Ratify::SigningKey(validator, signer, signature) => {
+                    // Ensure signing key rotations are enabled at this block height.
+                    ensure!(
+                        N::consensus_version(state.block_height())? >= ConsensusVersion::V3,
+                        "Ratify::SigningKey(..) is not enabled at block height {}",
+                        state.block_height()
+                    );
+                    // Construct the key.
+                    let key = Plaintext::from(Literal::Address(**validator));
+                    // Ensure the validator is in the committee.
+                    ensure!(
+                        store.get_value_speculative(program_id, committee_mapping, &key)?.is_some(),
+                        "Ratify::SigningKey(..) the validator {validator} is not in the committee"
+                    );
+                    // Ensure the signer is the validator, or is not in the committee.
+                    let signer_key = Plaintext::from(Literal::Address(**signer));
+                    ensure!(
+                        signer == validator
+                            || store.get_value_speculative(program_id, committee_mapping, &signer_key)?.is_none(),
+                        "Ratify::SigningKey(..) the signer {signer} is already in the committee"
+                    );
+
+                    // Retrieve the signer mapping from storage.
+                    let current_signers =
+                        signer_map_into_signers(store.get_mapping_speculative(program_id, signer_mapping)?)?;
+                    // Ensure the signer is not in use by another validator.
+                    ensure!(
+                        current_signers
+                            .iter()
+                            .all(|(other, (other_signer, _))| other == &**validator || other_signer != &**signer),
+                        "Ratify::SigningKey(..) the signer {signer} is already in use"
+                    );
+                    // Retrieve the current signer and nonce of the validator.
+                    let (current_signer, nonce) =
+                        current_signers.get(&**validator).copied().unwrap_or((**validator, 0));
+                    // Compute the next nonce.
+                    let next_nonce = nonce.checked_add(1).ok_or_else(|| {
+                        anyhow!("Ratify::SigningKey(..) the nonce overflowed for the validator {validator}")
+                    })?;
+
+                    // Ensure the signature is from the validator, over the current and next signer, and the nonce.
+                    // Note: The nonce increases with every rotation, so a signature cannot be replayed.
+                    ensure!(
+                        signature
+                            .verify(validator, &Ratify::<N>::signing_key_message(current_signer, **signer, nonce)?),
+                        "Ratify::SigningKey(..) has an invalid signature for the validator {validator}"
+                    );
+
+                    // Update the signer and nonce in finalize storage.
+                    // Note: Rotating the signing key back to the validator address keeps the entry, to retain the nonce.
+                    finalize_operations.push(store.update_key_value(
+                        program_id,
+                        signer_mapping,
+                        key,
+                        to_signer_state(**signer, next_nonce),
+                    )?);
+                }
             }
         }
 
@@ -1431,6 +1697,196 @@ finalize transfer_public:
         assert_eq!(candidate_transactions[0].to_unconfirmed_transaction_id().unwrap(), deployment_transaction_id);
     }
 
+    #[test]
+    fn test_speculate_with_finalize_profiler() {
+        let rng = &mut TestRng::default();
+
+        // Sample a private key.
+        let private_key = test_helpers::sample_genesis_private_key(rng);
+        let address = Address::try_from(&private_key).unwrap();
+
+        // Initialize the vm.
+        let vm = test_helpers::sample_vm_with_genesis_block(rng);
+        let genesis =
+            vm.block_store().get_block(&vm.block_store().get_block_hash(0).unwrap().unwrap()).unwrap().unwrap();
+
+        // Get the unspent records.
+        let mut unspent_records = genesis
+            .transitions()
+            .cloned()
+            .flat_map(Transition::into_records)
+            .map(|(_, record)| record)
+            .collect::<Vec<_>>();
+
+        // Generate more records to use for the next block.
+        let splits_block = generate_splits(&vm, &private_key, &genesis, &mut unspent_records, rng).unwrap();
+        vm.add_next_block(&splits_block).unwrap();
+
+        // Deploy a new program.
+        let (program_id, deployment_block) =
+            new_program_deployment(&vm, &private_key, &splits_block, &mut unspent_records, rng).unwrap();
+        vm.add_next_block(&deployment_block).unwrap();
+
+        // Enable the finalize profiler.
+        assert!(vm.finalize_profiler().is_none());
+        vm.enable_finalize_profiler();
+
+        // Speculate on a mint.
+        let mint = sample_mint_public(&vm, private_key, &program_id, address, 10, &mut unspent_records, rng);
+        let next_block =
+            sample_next_block(&vm, &private_key, &[mint], &deployment_block, &mut unspent_records, rng).unwrap();
+        assert!(next_block.aborted_transaction_ids().is_empty());
+
+        // Ensure each command of the finalize scope is recorded once.
+        let profiler = vm.finalize_profiler().unwrap();
+        let locator = Locator::from_str(&format!("{program_id}/mint_public")).unwrap();
+        let profiles = profiler.get(&locator).unwrap();
+        assert_eq!(
+            profiles.iter().map(|(opcode, profile)| (opcode.to_string(), profile.count)).collect::<Vec<_>>(),
+            vec![("get.or_use".to_string(), 1), ("add".to_string(), 1), ("set".to_string(), 1)]
+        );
+        // Ensure the program is reported in the program timings.
+        let program_timings = profiler.to_program_timings();
+        assert_eq!(program_timings.len(), 1);
+        assert_eq!(program_timings[0].0, ProgramID::from_str(&program_id).unwrap());
+
+        // Ensure the profiler can be disabled.
+        vm.disable_finalize_profiler();
+        assert!(vm.finalize_profiler().is_none());
+    }
+
+    #[test]
+    fn test_finalize_signing_key() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the VM, with a genesis committee containing the genesis private key.
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+        let private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let validator = Address::try_from(&private_key).unwrap();
+
+        // Construct the program ID and signer mapping name.
+        let program_id = ProgramID::from_str("credits.aleo").unwrap();
+        let signer_mapping = Identifier::from_str("signer").unwrap();
+        let key = Plaintext::from(Literal::Address(validator));
+
+        // Ensure a signing key rotation is rejected before signing key rotations are enabled.
+        let signer = Address::try_from(&PrivateKey::new(rng).unwrap()).unwrap();
+        let rotation = Ratify::new_signing_key(&private_key, validator, signer, 0, rng).unwrap();
+        assert!(vm
+            .speculate(sample_finalize_state(1), Some(0), vec![rotation.clone()], &None.into(), [].iter())
+            .is_err());
+
+        // Finalize the blocks until signing key rotations are enabled.
+        let (_, height) = CurrentNetwork::CONSENSUS_VERSION_HEIGHTS[2];
+        for height in 1..height {
+            assert!(CurrentNetwork::consensus_version(height).unwrap() < ConsensusVersion::V3);
+            let (ratifications, transactions, _, _) =
+                vm.speculate(sample_finalize_state(height), Some(0), vec![], &None.into(), [].iter()).unwrap();
+            vm.finalize(sample_finalize_state(height), &ratifications, &None.into(), &transactions).unwrap();
+        }
+        assert_eq!(CurrentNetwork::consensus_version(height).unwrap(), ConsensusVersion::V3);
+
+        // Ensure a signing key rotation signed by another key is rejected.
+        let invalid = Ratify::new_signing_key(&PrivateKey::new(rng).unwrap(), validator, signer, 0, rng).unwrap();
+        assert!(vm.speculate(sample_finalize_state(height), Some(0), vec![invalid], &None.into(), [].iter()).is_err());
+
+        // Rotate the signing key of the validator.
+        let (ratifications, transactions, _, _) = vm
+            .speculate(sample_finalize_state(height), Some(0), vec![rotation.clone()], &None.into(), [].iter())
+            .unwrap();
+        vm.finalize(sample_finalize_state(height), &ratifications, &None.into(), &transactions).unwrap();
+
+        // Ensure the signer mapping is updated, and the nonce is incremented.
+        let value = vm.finalize_store().get_value_confirmed(program_id, signer_mapping, &key).unwrap();
+        assert_eq!(value, Some(to_signer_state(signer, 1)));
+        // Ensure the rotated signing key is not active in the committee of the current block.
+        let committee = vm.finalize_store().committee_store().get_committee(height).unwrap().unwrap();
+        assert_eq!(committee.get_signer(validator), Some(validator));
+
+        // Finalize the next block.
+        let (ratifications, transactions, _, _) =
+            vm.speculate(sample_finalize_state(height + 1), Some(0), vec![], &None.into(), [].iter()).unwrap();
+        vm.finalize(sample_finalize_state(height + 1), &ratifications, &None.into(), &transactions).unwrap();
+        // Ensure the rotated signing key is active in the next committee.
+        let committee = vm.finalize_store().committee_store().get_committee(height + 1).unwrap().unwrap();
+        assert_eq!(committee.get_signer(validator), Some(signer));
+        assert_eq!(committee.get_validator(signer), Some(validator));
+
+        // Ensure a replayed signing key rotation is rejected, as the current signer has changed.
+        assert!(vm
+            .speculate(sample_finalize_state(height + 2), Some(0), vec![rotation.clone()], &None.into(), [].iter())
+            .is_err());
+        // Ensure a signing key rotation with a stale nonce is rejected.
+        let stale = Ratify::new_signing_key(&private_key, signer, validator, 0, rng).unwrap();
+        assert!(vm
+            .speculate(sample_finalize_state(height + 2), Some(0), vec![stale], &None.into(), [].iter())
+            .is_err());
+
+        // Rotate the signing key back to the validator.
+        let rotation_back = Ratify::new_signing_key(&private_key, signer, validator, 1, rng).unwrap();
+        let (ratifications, transactions, _, _) = vm
+            .speculate(sample_finalize_state(height + 2), Some(0), vec![rotation_back], &None.into(), [].iter())
+            .unwrap();
+        vm.finalize(sample_finalize_state(height + 2), &ratifications, &None.into(), &transactions).unwrap();
+        // Ensure the signer mapping entry is kept, to retain the nonce.
+        let value = vm.finalize_store().get_value_confirmed(program_id, signer_mapping, &key).unwrap();
+        assert_eq!(value, Some(to_signer_state(validator, 2)));
+
+        // Ensure the first rotation cannot be replayed, even though the current signer matches it again.
+        assert!(vm
+            .speculate(sample_finalize_state(height + 3), Some(0), vec![rotation], &None.into(), [].iter())
+            .is_err());
+    }
+
+    #[test]
+    fn test_finalize_legacy_genesis() {
+        // Load the genesis block of the network, which predates signing key rotations.
+        let genesis = Block::<CurrentNetwork>::from_bytes_le(CurrentNetwork::genesis_bytes()).unwrap();
+
+        // Ensure the genesis block replays.
+        let vm = crate::vm::test_helpers::sample_vm();
+        vm.add_next_block(&genesis).unwrap();
+
+        // Ensure the signer mapping is empty, and the genesis committee has no rotated signing keys.
+        let program_id = ProgramID::from_str("credits.aleo").unwrap();
+        let signer_mapping = Identifier::from_str("signer").unwrap();
+        assert!(vm.finalize_store().get_mapping_confirmed(program_id, signer_mapping).unwrap().is_empty());
+        let committee = vm.finalize_store().committee_store().get_committee(0).unwrap().unwrap();
+        assert!(committee.signers().is_empty());
+
+        // Ensure the next committee is computed as before, without reading the signer mapping.
+        assert!(CurrentNetwork::consensus_version(1).unwrap() < ConsensusVersion::V3);
+        let (ratifications, transactions, _, _) =
+            vm.speculate(sample_finalize_state(1), Some(0), vec![], &None.into(), [].iter()).unwrap();
+        vm.finalize(sample_finalize_state(1), &ratifications, &None.into(), &transactions).unwrap();
+        let next_committee = vm.finalize_store().committee_store().get_committee(1).unwrap().unwrap();
+        assert!(next_committee.signers().is_empty());
+        assert_eq!(next_committee.members().keys().collect::<Vec<_>>(), committee.members().keys().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_finalize_randomness() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the VM.
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+
+        // Sample a randomness ratification.
+        let randomness = Field::rand(rng);
+        let ratify = Ratify::new_randomness(&PrivateKey::new(rng).unwrap(), 1, randomness, rng).unwrap();
+        let state = sample_finalize_state(1).with_block_randomness(Some(randomness)).unwrap();
+
+        // Ensure the randomness ratification is rejected, as the network does not have a randomness beacon.
+        assert!(CurrentNetwork::randomness_beacon().is_none());
+        assert!(vm.speculate(state, Some(0), vec![ratify], &None.into(), [].iter()).is_err());
+
+        // Ensure a finalize state with randomness is rejected, if the randomness is not ratified.
+        assert!(vm.speculate(state, Some(0), vec![], &None.into(), [].iter()).is_err());
+
+        // Ensure a finalize state without randomness is accepted.
+        assert!(vm.speculate(sample_finalize_state(1), Some(0), vec![], &None.into(), [].iter()).is_ok());
+    }
+
     #[test]
     fn test_atomic_finalize_many() {
         let rng = &mut TestRng::default();
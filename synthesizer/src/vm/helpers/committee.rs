@@ -112,6 +112,59 @@ pub fn bonded_map_into_stakers<N: Network>(
     bonded_map.into_iter().map(|(key, value)| convert(key, value)).collect::<Result<IndexMap<_, _>>>()
 }
 
+/// Returns the map of validator to `(signer, nonce)` given the signer map from finalize storage.
+pub fn signer_map_into_signers<N: Network>(
+    signer_map: Vec<(Plaintext<N>, Value<N>)>,
+) -> Result<IndexMap<Address<N>, (Address<N>, u64)>> {
+    // Prepare the identifiers.
+    let signer_identifier = Identifier::from_str("signer")?;
+    let nonce_identifier = Identifier::from_str("nonce")?;
+
+    // Convert the given key and value into a signer entry.
+    let convert = |key, value| {
+        // Extract the validator from the key.
+        let validator = match key {
+            Plaintext::Literal(Literal::Address(validator), _) => validator,
+            _ => bail!("Invalid signer key (missing validator) - {key}"),
+        };
+        // Extract the signer state from the value.
+        match &value {
+            Value::Plaintext(Plaintext::Struct(state, _)) => {
+                // Extract the signer from the value.
+                let signer = match state.get(&signer_identifier) {
+                    Some(Plaintext::Literal(Literal::Address(signer), _)) => *signer,
+                    _ => bail!("Invalid signer state (missing signer) - {value}"),
+                };
+                // Extract the nonce from the value.
+                let nonce = match state.get(&nonce_identifier) {
+                    Some(Plaintext::Literal(Literal::U64(nonce), _)) => **nonce,
+                    _ => bail!("Invalid signer state (missing nonce) - {value}"),
+                };
+                // Return the signer state.
+                Ok((validator, (signer, nonce)))
+            }
+            _ => bail!("Invalid signer value (missing struct) - {value}"),
+        }
+    };
+
+    // Convert the signer map into signers.
+    signer_map.into_iter().map(|(key, value)| convert(key, value)).collect::<Result<IndexMap<_, _>>>()
+}
+
+/// Returns the signer state for the given `signer` and `nonce`, as stored in the signer map.
+pub fn to_signer_state<N: Network>(signer: Address<N>, nonce: u64) -> Value<N> {
+    // Prepare the identifiers.
+    let signer_identifier = Identifier::from_str("signer").expect("Failed to parse 'signer'");
+    let nonce_identifier = Identifier::from_str("nonce").expect("Failed to parse 'nonce'");
+
+    // Construct the signer state.
+    let signer_state = indexmap! {
+        signer_identifier => Plaintext::from(Literal::Address(signer)),
+        nonce_identifier => Plaintext::from(Literal::U64(U64::new(nonce))),
+    };
+    Value::Plaintext(Plaintext::Struct(signer_state, Default::default()))
+}
+
 /// Checks that the given committee from committee storage matches the given stakers.
 pub fn ensure_stakers_matches<N: Network>(
     committee: &Committee<N>,
@@ -13,49 +13,24 @@
 // limitations under the License.
 
 use crate::{
-    prelude::{Stack, StackProgramTypes},
+    prelude::{Process, Stack},
     VM,
 };
 use console::{
     prelude::*,
-    program::{FinalizeType, Identifier, LiteralType, PlaintextType},
+    program::{Identifier, ProgramID},
 };
 use ledger_block::{Deployment, Execution};
 use ledger_store::ConsensusStorage;
-use synthesizer_program::{CastType, Command, Finalize, Instruction, Operand, StackProgram};
+use synthesizer_program::Program;
+
+pub use synthesizer_process::{CostModel, SizeCost};
 
 /// Returns the *minimum* cost in microcredits to publish the given deployment (total cost, (storage cost, synthesis cost, namespace cost)).
 pub fn deployment_cost<N: Network>(deployment: &Deployment<N>) -> Result<(u64, (u64, u64, u64))> {
-    // Determine the number of bytes in the deployment.
-    let size_in_bytes = deployment.size_in_bytes()?;
-    // Retrieve the program ID.
-    let program_id = deployment.program_id();
-    // Determine the number of characters in the program ID.
-    let num_characters = u32::try_from(program_id.name().to_string().len())?;
-    // Compute the number of combined constraints in the program.
-    let num_combined_constraints = deployment.num_combined_constraints()?;
-
-    // Compute the storage cost in microcredits.
-    let storage_cost = size_in_bytes
-        .checked_mul(N::DEPLOYMENT_FEE_MULTIPLIER)
-        .ok_or(anyhow!("The storage cost computation overflowed for a deployment"))?;
-
-    // Compute the synthesis cost in microcredits.
-    let synthesis_cost = num_combined_constraints * N::SYNTHESIS_FEE_MULTIPLIER;
-
-    // Compute the namespace cost in credits: 10^(10 - num_characters).
-    let namespace_cost = 10u64
-        .checked_pow(10u32.saturating_sub(num_characters))
-        .ok_or(anyhow!("The namespace cost computation overflowed for a deployment"))?
-        .saturating_mul(1_000_000); // 1 microcredit = 1e-6 credits.
-
-    // Compute the total cost in microcredits.
-    let total_cost = storage_cost
-        .checked_add(synthesis_cost)
-        .and_then(|x| x.checked_add(namespace_cost))
-        .ok_or(anyhow!("The total cost computation overflowed for a deployment"))?;
-
-    Ok((total_cost, (storage_cost, synthesis_cost, namespace_cost)))
+    // Compute the itemized cost of the deployment.
+    let cost = deployment.cost_breakdown()?;
+    Ok((cost.total_cost(), (cost.storage_cost(), cost.synthesis_cost(), cost.namespace_cost())))
 }
 
 /// Returns the *minimum* cost in microcredits to publish the given execution (total cost, (storage cost, finalize cost)).
@@ -67,13 +42,28 @@ pub fn execution_cost<N: Network, C: ConsensusStorage<N>>(
     let storage_cost = execution.size_in_bytes()?;
 
     // Compute the finalize cost in microcredits.
+    let finalize_cost = execution_finalize_cost(&vm.process().read(), execution)?;
+
+    // Compute the total cost in microcredits.
+    let total_cost = storage_cost
+        .checked_add(finalize_cost)
+        .ok_or(anyhow!("The total cost computation overflowed for an execution"))?;
+
+    Ok((total_cost, (storage_cost, finalize_cost)))
+}
+
+/// Returns the cost in microcredits to run the finalize scopes of the given execution.
+pub fn execution_finalize_cost<N: Network>(process: &Process<N>, execution: &Execution<N>) -> Result<u64> {
+    // Retrieve the cost model.
+    let cost_model = CostModel::for_network::<N>();
+    // Initialize the finalize cost.
     let mut finalize_cost = 0u64;
     // Iterate over the transitions to accumulate the finalize cost.
     for transition in execution.transitions() {
         // Retrieve the program ID and function name.
         let (program_id, function_name) = (transition.program_id(), transition.function_name());
         // Retrieve the finalize cost.
-        let cost = cost_in_microcredits(vm.process().read().get_stack(program_id)?, function_name)?;
+        let cost = cost_model.finalize_cost(process.get_stack(program_id)?, function_name)?;
         // Accumulate the finalize cost.
         if cost > 0 {
             finalize_cost = finalize_cost
@@ -81,305 +71,127 @@ pub fn execution_cost<N: Network, C: ConsensusStorage<N>>(
                 .ok_or(anyhow!("The finalize cost computation overflowed on '{program_id}/{function_name}'"))?;
         }
     }
+    Ok(finalize_cost)
+}
 
-    // Compute the total cost in microcredits.
-    let total_cost = storage_cost
-        .checked_add(finalize_cost)
-        .ok_or(anyhow!("The total cost computation overflowed for an execution"))?;
-
-    Ok((total_cost, (storage_cost, finalize_cost)))
+/// Returns the *minimum* cost in microcredits to deprecate the given program.
+pub fn deprecation_cost<N: Network>(program_id: &ProgramID<N>) -> Result<u64> {
+    // Determine the number of bytes in the program ID.
+    let size_in_bytes = u64::try_from(program_id.to_bytes_le()?.len())?;
+    // Compute the storage cost in microcredits.
+    size_in_bytes
+        .checked_mul(N::DEPLOYMENT_FEE_MULTIPLIER)
+        .ok_or(anyhow!("The storage cost computation overflowed for a deprecation"))
 }
 
 /// Returns the minimum number of microcredits required to run the finalize.
 pub fn cost_in_microcredits<N: Network>(stack: &Stack<N>, function_name: &Identifier<N>) -> Result<u64> {
-    /// A helper function to determine the plaintext type in bytes.
-    fn plaintext_size_in_bytes<N: Network>(stack: &Stack<N>, plaintext_type: &PlaintextType<N>) -> Result<u64> {
-        match plaintext_type {
-            PlaintextType::Literal(literal_type) => Ok(literal_type.size_in_bytes::<N>() as u64),
-            PlaintextType::Struct(struct_name) => {
-                // Retrieve the struct from the stack.
-                let struct_ = stack.program().get_struct(struct_name)?;
-                // Retrieve the size of the struct name.
-                let size_of_name = struct_.name().to_bytes_le()?.len() as u64;
-                // Retrieve the size of all the members of the struct.
-                let size_of_members = struct_.members().iter().try_fold(0u64, |acc, (_, member_type)| {
-                    acc.checked_add(plaintext_size_in_bytes(stack, member_type)?).ok_or(anyhow!(
-                        "Overflowed while computing the size of the struct '{}/{struct_name}' - {member_type}",
-                        stack.program_id()
-                    ))
-                })?;
-                // Return the size of the struct.
-                Ok(size_of_name.saturating_add(size_of_members))
-            }
-            PlaintextType::Array(array_type) => {
-                // Retrieve the number of elements in the array.
-                let num_elements = **array_type.length() as u64;
-                // Compute the size of an array element.
-                let size_of_element = plaintext_size_in_bytes(stack, array_type.next_element_type())?;
-                // Return the size of the array.
-                Ok(num_elements.saturating_mul(size_of_element))
-            }
+    CostModel::for_network::<N>().finalize_cost(stack, function_name)
+}
+
+/// A trait to compute the finalize cost of the functions in a program.
+pub trait ProgramCost<N: Network> {
+    /// Returns the minimum number of microcredits required to run the finalize of the given function,
+    /// using the given process to resolve the imports of the program.
+    fn finalize_cost(&self, process: &Process<N>, function_name: &Identifier<N>) -> Result<u64>;
+}
+
+impl<N: Network> ProgramCost<N> for Program<N> {
+    /// Returns the minimum number of microcredits required to run the finalize of the given function,
+    /// using the given process to resolve the imports of the program.
+    fn finalize_cost(&self, process: &Process<N>, function_name: &Identifier<N>) -> Result<u64> {
+        // Retrieve the cost model.
+        let cost_model = CostModel::for_network::<N>();
+        // Compute the finalize cost with the stack of the program in the process, if it matches.
+        // Otherwise, construct a stack for the program.
+        match process.contains_program(self.id()) {
+            true => match process.get_program(self.id())? == self {
+                true => cost_model.finalize_cost(process.get_stack(self.id())?, function_name),
+                false => cost_model.finalize_cost(&Stack::new_upgrade(process, self)?, function_name),
+            },
+            false => cost_model.finalize_cost(&Stack::new(process, self)?, function_name),
         }
     }
+}
 
-    /// A helper function to compute the following: base_cost + (byte_multiplier * size_of_operands).
-    fn cost_in_size<'a, N: Network>(
-        stack: &Stack<N>,
-        finalize: &Finalize<N>,
-        operands: impl IntoIterator<Item = &'a Operand<N>>,
-        byte_multiplier: u64,
-        base_cost: u64,
-    ) -> Result<u64> {
-        // Retrieve the finalize types.
-        let finalize_types = stack.get_finalize_types(finalize.name())?;
-        // Compute the size of the operands.
-        let size_of_operands = operands.into_iter().try_fold(0u64, |acc, operand| {
-            // Determine the size of the operand.
-            let operand_size = match finalize_types.get_type_from_operand(stack, operand)? {
-                FinalizeType::Plaintext(plaintext_type) => plaintext_size_in_bytes(stack, &plaintext_type)?,
-                FinalizeType::Future(future) => {
-                    bail!("Future '{future}' is not a valid operand in the finalize scope");
-                }
-            };
-            // Safely add the size to the accumulator.
-            acc.checked_add(operand_size).ok_or(anyhow!(
-                "Overflowed while computing the size of the operand '{operand}' in '{}/{}' (finalize)",
-                stack.program_id(),
-                finalize.name()
-            ))
-        })?;
-        // Return the cost.
-        Ok(base_cost.saturating_add(byte_multiplier.saturating_mul(size_of_operands)))
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{network::MainnetV0, program::LiteralType};
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_deployment_cost_matches_cost_breakdown() {
+        let rng = &mut TestRng::default();
+
+        // Sample a deployment.
+        let transaction = crate::vm::test_helpers::sample_deployment_transaction(rng);
+        let deployment = transaction.deployment().unwrap();
+
+        // Check the deployment cost matches the itemized cost of the deployment.
+        let (total_cost, (storage_cost, synthesis_cost, namespace_cost)) = deployment_cost(deployment).unwrap();
+        let cost = deployment.cost_breakdown().unwrap();
+        assert_eq!(total_cost, cost.total_cost());
+        assert_eq!(storage_cost, cost.storage_cost());
+        assert_eq!(synthesis_cost, cost.synthesis_cost());
+        assert_eq!(namespace_cost, cost.namespace_cost());
+
+        // Check the synthesis cost is the combined constraints of the functions.
+        assert_eq!(
+            synthesis_cost,
+            deployment.num_combined_constraints().unwrap() * CurrentNetwork::SYNTHESIS_FEE_MULTIPLIER
+        );
+        assert_eq!(storage_cost, deployment.size_in_bytes().unwrap() * CurrentNetwork::DEPLOYMENT_FEE_MULTIPLIER);
+        assert_eq!(total_cost, storage_cost + synthesis_cost + namespace_cost);
     }
 
-    // Finalize costs for compute heavy operations, derived as:
-    // `BASE_COST + (PER_BYTE_COST * SIZE_IN_BYTES)`.
-
-    const CAST_BASE_COST: u64 = 500;
-    const CAST_PER_BYTE_COST: u64 = 30;
-
-    const HASH_BASE_COST: u64 = 10_000;
-    const HASH_PER_BYTE_COST: u64 = 30;
-
-    const HASH_BHP_BASE_COST: u64 = 50_000;
-    const HASH_BHP_PER_BYTE_COST: u64 = 300;
-
-    const HASH_PSD_BASE_COST: u64 = 40_000;
-    const HASH_PSD_PER_BYTE_COST: u64 = 75;
-
-    const MAPPING_BASE_COST: u64 = 10_000;
-    const MAPPING_PER_BYTE_COST: u64 = 10;
-
-    const SET_BASE_COST: u64 = 10_000;
-    const SET_PER_BYTE_COST: u64 = 100;
-
-    // Retrieve the finalize logic.
-    let Some(finalize) = stack.get_function_ref(function_name)?.finalize_logic() else {
-        // Return a finalize cost of 0, if the function does not have a finalize scope.
-        return Ok(0);
-    };
-
-    // Measure the cost of each command.
-    let cost = |command: &Command<N>| match command {
-        Command::Instruction(Instruction::Abs(_)) => Ok(500),
-        Command::Instruction(Instruction::AbsWrapped(_)) => Ok(500),
-        Command::Instruction(Instruction::Add(_)) => Ok(500),
-        Command::Instruction(Instruction::AddWrapped(_)) => Ok(500),
-        Command::Instruction(Instruction::And(_)) => Ok(500),
-        Command::Instruction(Instruction::AssertEq(_)) => Ok(500),
-        Command::Instruction(Instruction::AssertNeq(_)) => Ok(500),
-        Command::Instruction(Instruction::Async(_)) => bail!("'async' is not supported in finalize"),
-        Command::Instruction(Instruction::Call(_)) => bail!("'call' is not supported in finalize"),
-        Command::Instruction(Instruction::Cast(cast)) => match cast.cast_type() {
-            CastType::Plaintext(PlaintextType::Literal(_)) => Ok(500),
-            CastType::Plaintext(plaintext_type) => Ok(plaintext_size_in_bytes(stack, plaintext_type)?
-                .saturating_mul(CAST_PER_BYTE_COST)
-                .saturating_add(CAST_BASE_COST)),
-            CastType::GroupXCoordinate
-            | CastType::GroupYCoordinate
-            | CastType::Record(_)
-            | CastType::ExternalRecord(_) => Ok(500),
-        },
-        Command::Instruction(Instruction::CastLossy(cast_lossy)) => match cast_lossy.cast_type() {
-            CastType::Plaintext(PlaintextType::Literal(_)) => Ok(500),
-            CastType::Plaintext(plaintext_type) => Ok(plaintext_size_in_bytes(stack, plaintext_type)?
-                .saturating_mul(CAST_PER_BYTE_COST)
-                .saturating_add(CAST_BASE_COST)),
-            CastType::GroupXCoordinate
-            | CastType::GroupYCoordinate
-            | CastType::Record(_)
-            | CastType::ExternalRecord(_) => Ok(500),
-        },
-        Command::Instruction(Instruction::CommitBHP256(commit)) => {
-            cost_in_size(stack, finalize, commit.operands(), HASH_BHP_PER_BYTE_COST, HASH_BHP_BASE_COST)
-        }
-        Command::Instruction(Instruction::CommitBHP512(commit)) => {
-            cost_in_size(stack, finalize, commit.operands(), HASH_BHP_PER_BYTE_COST, HASH_BHP_BASE_COST)
-        }
-        Command::Instruction(Instruction::CommitBHP768(commit)) => {
-            cost_in_size(stack, finalize, commit.operands(), HASH_BHP_PER_BYTE_COST, HASH_BHP_BASE_COST)
-        }
-        Command::Instruction(Instruction::CommitBHP1024(commit)) => {
-            cost_in_size(stack, finalize, commit.operands(), HASH_BHP_PER_BYTE_COST, HASH_BHP_BASE_COST)
-        }
-        Command::Instruction(Instruction::CommitPED64(commit)) => {
-            cost_in_size(stack, finalize, commit.operands(), HASH_PER_BYTE_COST, HASH_BASE_COST)
-        }
-        Command::Instruction(Instruction::CommitPED128(commit)) => {
-            cost_in_size(stack, finalize, commit.operands(), HASH_PER_BYTE_COST, HASH_BASE_COST)
-        }
-        Command::Instruction(Instruction::Div(div)) => {
-            // Ensure `div` has exactly two operands.
-            ensure!(div.operands().len() == 2, "'div' must contain exactly 2 operands");
-            // Retrieve the finalize types.
-            let finalize_types = stack.get_finalize_types(finalize.name())?;
-            // Retrieve the price by the operand type.
-            match finalize_types.get_type_from_operand(stack, &div.operands()[0])? {
-                FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::Field)) => Ok(1_500),
-                FinalizeType::Plaintext(PlaintextType::Literal(_)) => Ok(500),
-                FinalizeType::Plaintext(PlaintextType::Array(_)) => bail!("'div' does not support arrays"),
-                FinalizeType::Plaintext(PlaintextType::Struct(_)) => bail!("'div' does not support structs"),
-                FinalizeType::Future(_) => bail!("'div' does not support futures"),
-            }
-        }
-        Command::Instruction(Instruction::DivWrapped(_)) => Ok(500),
-        Command::Instruction(Instruction::Double(_)) => Ok(500),
-        Command::Instruction(Instruction::GreaterThan(_)) => Ok(500),
-        Command::Instruction(Instruction::GreaterThanOrEqual(_)) => Ok(500),
-        Command::Instruction(Instruction::HashBHP256(hash)) => {
-            cost_in_size(stack, finalize, hash.operands(), HASH_BHP_PER_BYTE_COST, HASH_BHP_BASE_COST)
-        }
-        Command::Instruction(Instruction::HashBHP512(hash)) => {
-            cost_in_size(stack, finalize, hash.operands(), HASH_BHP_PER_BYTE_COST, HASH_BHP_BASE_COST)
-        }
-        Command::Instruction(Instruction::HashBHP768(hash)) => {
-            cost_in_size(stack, finalize, hash.operands(), HASH_BHP_PER_BYTE_COST, HASH_BHP_BASE_COST)
-        }
-        Command::Instruction(Instruction::HashBHP1024(hash)) => {
-            cost_in_size(stack, finalize, hash.operands(), HASH_BHP_PER_BYTE_COST, HASH_BHP_BASE_COST)
-        }
-        Command::Instruction(Instruction::HashKeccak256(hash)) => {
-            cost_in_size(stack, finalize, hash.operands(), HASH_PER_BYTE_COST, HASH_BASE_COST)
-        }
-        Command::Instruction(Instruction::HashKeccak384(hash)) => {
-            cost_in_size(stack, finalize, hash.operands(), HASH_PER_BYTE_COST, HASH_BASE_COST)
-        }
-        Command::Instruction(Instruction::HashKeccak512(hash)) => {
-            cost_in_size(stack, finalize, hash.operands(), HASH_PER_BYTE_COST, HASH_BASE_COST)
-        }
-        Command::Instruction(Instruction::HashPED64(hash)) => {
-            cost_in_size(stack, finalize, hash.operands(), HASH_PER_BYTE_COST, HASH_BASE_COST)
-        }
-        Command::Instruction(Instruction::HashPED128(hash)) => {
-            cost_in_size(stack, finalize, hash.operands(), HASH_PER_BYTE_COST, HASH_BASE_COST)
-        }
-        Command::Instruction(Instruction::HashPSD2(hash)) => {
-            cost_in_size(stack, finalize, hash.operands(), HASH_PSD_PER_BYTE_COST, HASH_PSD_BASE_COST)
-        }
-        Command::Instruction(Instruction::HashPSD4(hash)) => {
-            cost_in_size(stack, finalize, hash.operands(), HASH_PSD_PER_BYTE_COST, HASH_PSD_BASE_COST)
-        }
-        Command::Instruction(Instruction::HashPSD8(hash)) => {
-            cost_in_size(stack, finalize, hash.operands(), HASH_PSD_PER_BYTE_COST, HASH_PSD_BASE_COST)
-        }
-        Command::Instruction(Instruction::HashSha3_256(hash)) => {
-            cost_in_size(stack, finalize, hash.operands(), HASH_PER_BYTE_COST, HASH_BASE_COST)
-        }
-        Command::Instruction(Instruction::HashSha3_384(hash)) => {
-            cost_in_size(stack, finalize, hash.operands(), HASH_PER_BYTE_COST, HASH_BASE_COST)
-        }
-        Command::Instruction(Instruction::HashSha3_512(hash)) => {
-            cost_in_size(stack, finalize, hash.operands(), HASH_PER_BYTE_COST, HASH_BASE_COST)
-        }
-        Command::Instruction(Instruction::HashManyPSD2(_)) => {
-            bail!("`hash_many.psd2` is not supported in finalize")
-        }
-        Command::Instruction(Instruction::HashManyPSD4(_)) => {
-            bail!("`hash_many.psd4` is not supported in finalize")
-        }
-        Command::Instruction(Instruction::HashManyPSD8(_)) => {
-            bail!("`hash_many.psd8` is not supported in finalize")
-        }
-        Command::Instruction(Instruction::Inv(_)) => Ok(2_500),
-        Command::Instruction(Instruction::IsEq(_)) => Ok(500),
-        Command::Instruction(Instruction::IsNeq(_)) => Ok(500),
-        Command::Instruction(Instruction::LessThan(_)) => Ok(500),
-        Command::Instruction(Instruction::LessThanOrEqual(_)) => Ok(500),
-        Command::Instruction(Instruction::Modulo(_)) => Ok(500),
-        Command::Instruction(Instruction::Mul(mul)) => {
-            // Ensure `mul` has exactly two operands.
-            ensure!(mul.operands().len() == 2, "'mul' must contain exactly 2 operands");
-            // Retrieve the finalize types.
-            let finalize_types = stack.get_finalize_types(finalize.name())?;
-            // Retrieve the price by operand type.
-            match finalize_types.get_type_from_operand(stack, &mul.operands()[0])? {
-                FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::Group)) => Ok(10_000),
-                FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::Scalar)) => Ok(10_000),
-                FinalizeType::Plaintext(PlaintextType::Literal(_)) => Ok(500),
-                FinalizeType::Plaintext(PlaintextType::Array(_)) => bail!("'mul' does not support arrays"),
-                FinalizeType::Plaintext(PlaintextType::Struct(_)) => bail!("'mul' does not support structs"),
-                FinalizeType::Future(_) => bail!("'mul' does not support futures"),
-            }
-        }
-        Command::Instruction(Instruction::MulWrapped(_)) => Ok(500),
-        Command::Instruction(Instruction::Nand(_)) => Ok(500),
-        Command::Instruction(Instruction::Neg(_)) => Ok(500),
-        Command::Instruction(Instruction::Nor(_)) => Ok(500),
-        Command::Instruction(Instruction::Not(_)) => Ok(500),
-        Command::Instruction(Instruction::Or(_)) => Ok(500),
-        Command::Instruction(Instruction::Pow(pow)) => {
-            // Ensure `pow` has at least one operand.
-            ensure!(!pow.operands().is_empty(), "'pow' must contain at least 1 operand");
-            // Retrieve the finalize types.
-            let finalize_types = stack.get_finalize_types(finalize.name())?;
-            // Retrieve the price by operand type.
-            match finalize_types.get_type_from_operand(stack, &pow.operands()[0])? {
-                FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::Field)) => Ok(1_500),
-                FinalizeType::Plaintext(PlaintextType::Literal(_)) => Ok(500),
-                FinalizeType::Plaintext(PlaintextType::Array(_)) => bail!("'pow' does not support arrays"),
-                FinalizeType::Plaintext(PlaintextType::Struct(_)) => bail!("'pow' does not support structs"),
-                FinalizeType::Future(_) => bail!("'pow' does not support futures"),
-            }
-        }
-        Command::Instruction(Instruction::PowWrapped(_)) => Ok(500),
-        Command::Instruction(Instruction::Rem(_)) => Ok(500),
-        Command::Instruction(Instruction::RemWrapped(_)) => Ok(500),
-        Command::Instruction(Instruction::SignVerify(sign)) => {
-            cost_in_size(stack, finalize, sign.operands(), HASH_PSD_PER_BYTE_COST, HASH_PSD_BASE_COST)
-        }
-        Command::Instruction(Instruction::Shl(_)) => Ok(500),
-        Command::Instruction(Instruction::ShlWrapped(_)) => Ok(500),
-        Command::Instruction(Instruction::Shr(_)) => Ok(500),
-        Command::Instruction(Instruction::ShrWrapped(_)) => Ok(500),
-        Command::Instruction(Instruction::Square(_)) => Ok(500),
-        Command::Instruction(Instruction::SquareRoot(_)) => Ok(2_500),
-        Command::Instruction(Instruction::Sub(_)) => Ok(500),
-        Command::Instruction(Instruction::SubWrapped(_)) => Ok(500),
-        Command::Instruction(Instruction::Ternary(_)) => Ok(500),
-        Command::Instruction(Instruction::Xor(_)) => Ok(500),
-        Command::Await(_) => Ok(500),
-        Command::Contains(command) => {
-            cost_in_size(stack, finalize, [command.key()], MAPPING_PER_BYTE_COST, MAPPING_BASE_COST)
-        }
-        Command::Get(command) => {
-            cost_in_size(stack, finalize, [command.key()], MAPPING_PER_BYTE_COST, MAPPING_BASE_COST)
-        }
-        Command::GetOrUse(command) => {
-            cost_in_size(stack, finalize, [command.key()], MAPPING_PER_BYTE_COST, MAPPING_BASE_COST)
-        }
-        Command::RandChaCha(_) => Ok(25_000),
-        Command::Remove(_) => Ok(MAPPING_BASE_COST),
-        Command::Set(command) => {
-            cost_in_size(stack, finalize, [command.key(), command.value()], SET_PER_BYTE_COST, SET_BASE_COST)
-        }
-        Command::BranchEq(_) | Command::BranchNeq(_) => Ok(500),
-        Command::Position(_) => Ok(100),
-    };
-
-    // Aggregate the cost of all commands in the program.
-    finalize
-        .commands()
-        .iter()
-        .map(cost)
-        .try_fold(0u64, |acc, res| res.and_then(|x| acc.checked_add(x).ok_or(anyhow!("Finalize cost overflowed"))))
+    #[test]
+    fn test_program_finalize_cost() {
+        let mut process = Process::<CurrentNetwork>::load().unwrap();
+
+        // Initialize a program that increments a counter in its finalize scope.
+        let program = Program::from_str(
+            r"
+program cost_model.aleo;
+
+mapping counts:
+    key as address.public;
+    value as u64.public;
+
+function increment:
+    input r0 as u64.public;
+    async increment self.caller r0 into r1;
+    output r1 as cost_model.aleo/increment.future;
+
+finalize increment:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get.or_use counts[r0] 0u64 into r2;
+    add r2 r1 into r3;
+    set r3 into counts[r0];",
+        )
+        .unwrap();
+        let function_name = Identifier::from_str("increment").unwrap();
+
+        // Compute the expected cost from the cost model.
+        let cost_model = CostModel::for_network::<CurrentNetwork>();
+        let address_size = LiteralType::Address.size_in_bytes::<CurrentNetwork>() as u64;
+        let u64_size = LiteralType::U64.size_in_bytes::<CurrentNetwork>() as u64;
+        let expected =
+            cost_model.mapping.cost(address_size) + cost_model.basic + cost_model.set.cost(address_size + u64_size);
+
+        // Ensure the cost is computed for a program that is not in the process.
+        assert_eq!(program.finalize_cost(&process, &function_name).unwrap(), expected);
+
+        // Ensure the cost matches once the program is in the process.
+        process.add_program(&program).unwrap();
+        assert_eq!(program.finalize_cost(&process, &function_name).unwrap(), expected);
+        assert_eq!(cost_in_microcredits(process.get_stack(program.id()).unwrap(), &function_name).unwrap(), expected);
+
+        // Ensure functions without a finalize scope have no cost.
+        let credits = process.get_program("credits.aleo").unwrap().clone();
+        let transfer_private = Identifier::from_str("transfer_private").unwrap();
+        assert_eq!(credits.finalize_cost(&process, &transfer_private).unwrap(), 0);
+    }
 }
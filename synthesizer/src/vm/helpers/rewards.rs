@@ -184,6 +184,8 @@ mod tests {
             assert_eq!(validator, next_validator);
             let reward = block_reward as u128 * stake as u128 / committee.total_stake() as u128;
             assert_eq!(stake + u64::try_from(reward).unwrap(), next_stake, "stake: {stake}, reward: {reward}");
+            // Ensure the committee computes the same staking reward.
+            assert_eq!(stake + committee.staking_reward(validator, stake, block_reward), next_stake);
         }
     }
 
@@ -15,7 +15,13 @@
 mod helpers;
 pub use helpers::*;
 
+#[cfg(feature = "prover")]
+mod credits;
+#[cfg(feature = "prover")]
+pub use credits::*;
+
 mod authorize;
+#[cfg(feature = "prover")]
 mod deploy;
 mod execute;
 mod finalize;
@@ -24,7 +30,7 @@ mod verify;
 use crate::{cast_mut_ref, cast_ref, process};
 use console::{
     account::{Address, PrivateKey},
-    network::prelude::*,
+    network::{prelude::*, ConsensusVersion},
     program::{Identifier, Literal, Locator, Plaintext, ProgramID, ProgramOwner, Record, Value},
     types::{Field, Group, U64},
 };
@@ -35,11 +41,13 @@ use ledger_block::{
     Execution,
     Fee,
     Header,
+    MappingPolicy,
     Ratifications,
     Ratify,
     Rejected,
     Solutions,
     Transaction,
+    TransactionBuilder,
     Transactions,
 };
 use ledger_committee::Committee;
@@ -55,7 +63,15 @@ use ledger_store::{
     TransactionStore,
     TransitionStore,
 };
-use synthesizer_process::{Authorization, Process, Trace};
+use synthesizer_process::{
+    Authorization,
+    FinalizeProfiler,
+    Process,
+    ProvingRequest,
+    ProvingResponse,
+    Trace,
+    UnsignedAuthorization,
+};
 use synthesizer_program::{FinalizeGlobalState, FinalizeOperation, FinalizeStoreTrait, Program};
 
 use aleo_std::prelude::{finish, lap, timer};
@@ -77,8 +93,10 @@ pub struct VM<N: Network, C: ConsensusStorage<N>> {
     atomic_lock: Arc<Mutex<()>>,
     /// The lock for ensuring there is no concurrency when advancing blocks.
     block_lock: Arc<Mutex<()>>,
-    /// A cache containing the list of recent partially-verified transactions.
-    partially_verified_transactions: Arc<RwLock<LruCache<N::TransactionID, ()>>>,
+    /// A cache containing the list of recent partially-verified transactions, and the state root each was verified at.
+    partially_verified_transactions: Arc<RwLock<LruCache<N::TransactionID, N::StateRoot>>>,
+    /// An optional profiler of the finalize scopes evaluated during speculation.
+    finalize_profiler: Arc<RwLock<Option<Arc<FinalizeProfiler<N>>>>>,
 }
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
@@ -118,6 +136,11 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             if process.contains_program(program_id) {
                 return Ok(vec![]);
             }
+            // Return early if the deployment has been superseded by a newer edition of the program.
+            // Note: The latest edition is loaded from its own deployment transaction.
+            if transaction_store.deployment_store().get_edition(program_id)? != Some(deployment.edition()) {
+                return Ok(vec![]);
+            }
 
             // Prepare a vector for the deployments.
             let mut deployments = vec![];
@@ -175,6 +198,13 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             }
         }
 
+        // Mark the deprecated programs in the process.
+        for program_id in transaction_store.deployment_store().program_ids() {
+            if transaction_store.is_deprecated(&program_id)? {
+                process.mark_deprecated(*program_id);
+            }
+        }
+
         // Return the new VM.
         Ok(Self {
             process: Arc::new(RwLock::new(process)),
@@ -184,6 +214,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             partially_verified_transactions: Arc::new(RwLock::new(LruCache::new(
                 NonZeroUsize::new(Transactions::<N>::MAX_TRANSACTIONS).unwrap(),
             ))),
+            finalize_profiler: Arc::new(RwLock::new(None)),
         })
     }
 
@@ -201,9 +232,30 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
     /// Returns the partially-verified transactions.
     #[inline]
-    pub fn partially_verified_transactions(&self) -> Arc<RwLock<LruCache<N::TransactionID, ()>>> {
+    pub fn partially_verified_transactions(&self) -> Arc<RwLock<LruCache<N::TransactionID, N::StateRoot>>> {
         self.partially_verified_transactions.clone()
     }
+
+    /// Returns the finalize profiler, if it is enabled.
+    #[inline]
+    pub fn finalize_profiler(&self) -> Option<Arc<FinalizeProfiler<N>>> {
+        self.finalize_profiler.read().clone()
+    }
+
+    /// Enables the finalize profiler, discarding any previously recorded profiles.
+    ///
+    /// When enabled, `VM::speculate` and `VM::check_speculate` record the evaluations of each opcode
+    /// in the finalize scopes of executions, and publish them to the metrics registry, if enabled.
+    #[inline]
+    pub fn enable_finalize_profiler(&self) {
+        *self.finalize_profiler.write() = Some(Arc::new(FinalizeProfiler::new()));
+    }
+
+    /// Disables the finalize profiler.
+    #[inline]
+    pub fn disable_finalize_profiler(&self) {
+        *self.finalize_profiler.write() = None;
+    }
 }
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
@@ -234,6 +286,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     /// Returns a new genesis block for a beacon chain.
+    #[cfg(feature = "prover")]
     pub fn genesis_beacon<R: Rng + CryptoRng>(&self, private_key: &PrivateKey<N>, rng: &mut R) -> Result<Block<N>> {
         let private_keys = [*private_key, PrivateKey::new(rng)?, PrivateKey::new(rng)?, PrivateKey::new(rng)?];
 
@@ -264,6 +317,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     }
 
     /// Returns a new genesis block for a quorum chain.
+    #[cfg(feature = "prover")]
     pub fn genesis_quorum<R: Rng + CryptoRng>(
         &self,
         private_key: &PrivateKey<N>,
@@ -351,10 +405,12 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         let state = FinalizeGlobalState::new::<N>(
             block.round(),
             block.height(),
+            block.timestamp(),
             block.cumulative_weight(),
             block.cumulative_proof_target(),
             block.previous_hash(),
-        )?;
+        )?
+        .with_block_randomness(block.ratifications().iter().find_map(Ratify::randomness))?;
 
         // Attention: The following order is crucial because if 'finalize' fails, we can rollback the block.
         // If one first calls 'finalize', then calls 'insert(block)' and it fails, there is no way to rollback 'finalize'.
@@ -383,12 +439,12 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 pub(crate) mod test_helpers {
     use super::*;
     use console::{
-        account::{Address, ViewKey},
+        account::{Address, Signature, ViewKey},
         network::MainnetV0,
         program::Value,
-        types::Field,
+        types::{Field, U32},
     };
-    use ledger_block::{Block, Header, Metadata, Transition};
+    use ledger_block::{Block, Header, Input, Metadata, Transition};
     use ledger_store::helpers::memory::ConsensusMemory;
     use synthesizer_program::Program;
 
@@ -401,7 +457,7 @@ pub(crate) mod test_helpers {
 
     /// Samples a new finalize state.
     pub(crate) fn sample_finalize_state(block_height: u32) -> FinalizeGlobalState {
-        FinalizeGlobalState::from(block_height as u64, block_height, [0u8; 32])
+        FinalizeGlobalState::from(block_height as u64, block_height, CurrentNetwork::GENESIS_TIMESTAMP, [0u8; 32])
     }
 
     pub(crate) fn sample_vm() -> VM<CurrentNetwork, ConsensusMemory<CurrentNetwork>> {
@@ -977,6 +1033,37 @@ function a:
             );
         }
 
+        // Ensure the reverse imports of each program are indexed.
+        {
+            let deployment_store = vm.transaction_store().deployment_store();
+            let dependents_of = |program_id: &str| -> IndexSet<_> {
+                let program_id = ProgramID::from_str(program_id).unwrap();
+                deployment_store.get_dependents(&program_id).unwrap().into_iter().collect()
+            };
+            let transitive_dependents_of = |program_id: &str| -> IndexSet<_> {
+                let program_id = ProgramID::from_str(program_id).unwrap();
+                deployment_store.get_transitive_dependents(&program_id).unwrap().into_iter().collect()
+            };
+            let program_ids = |program_ids: &[&str]| {
+                program_ids.iter().map(|program_id| ProgramID::from_str(program_id).unwrap()).collect::<IndexSet<_>>()
+            };
+            assert_eq!(dependents_of("first_program.aleo"), program_ids(&["second_program.aleo", "fourth_program.aleo"]));
+            assert_eq!(dependents_of("second_program.aleo"), program_ids(&["third_program.aleo", "fourth_program.aleo"]));
+            assert!(dependents_of("third_program.aleo").is_empty());
+            assert!(dependents_of("fourth_program.aleo").is_empty());
+
+            // Ensure the transitive dependents include the programs that import a dependent.
+            assert_eq!(
+                transitive_dependents_of("first_program.aleo"),
+                program_ids(&["second_program.aleo", "third_program.aleo", "fourth_program.aleo"])
+            );
+            assert_eq!(
+                transitive_dependents_of("second_program.aleo"),
+                program_ids(&["third_program.aleo", "fourth_program.aleo"])
+            );
+            assert!(transitive_dependents_of("third_program.aleo").is_empty());
+        }
+
         // Enforce that the VM can load properly with the imports.
         assert!(VM::from(vm.store.clone()).is_ok());
     }
@@ -1104,6 +1191,528 @@ function check:
         assert!(vm.contains_program(&ProgramID::from_str("parent_program.aleo").unwrap()));
     }
 
+    #[test]
+    fn test_deploy_upgrade() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a private key.
+        let private_key = sample_genesis_private_key(rng);
+
+        // Initialize the genesis block.
+        let genesis = sample_genesis_block(rng);
+
+        // Initialize the VM.
+        let vm = sample_vm();
+        // Update the VM.
+        vm.add_next_block(&genesis).unwrap();
+
+        // Deploy the program.
+        let program = Program::from_str(
+            r"
+program upgrade_program.aleo;
+
+mapping counter:
+    key as u8.public;
+    value as u64.public;
+
+function compute:
+    input r0 as u32.private;
+    output r0 as u32.private;
+        ",
+        )
+        .unwrap();
+        let program_id = *program.id();
+
+        // Ensure a program that has not been deployed cannot be upgraded.
+        assert!(vm.deploy_upgrade(&private_key, &program, None, 0, None, rng).is_err());
+
+        let deployment = vm.deploy(&private_key, &program, None, 0, None, rng).unwrap();
+        assert!(vm.check_transaction(&deployment, None, rng).is_ok());
+        vm.add_next_block(&sample_next_block(&vm, &private_key, &[deployment.clone()], rng).unwrap()).unwrap();
+
+        // Upgrade the program.
+        let upgrade = Program::from_str(
+            r"
+program upgrade_program.aleo;
+
+mapping counter:
+    key as u8.public;
+    value as u64.public;
+
+function compute:
+    input r0 as u32.private;
+    add r0 r0 into r1;
+    output r1 as u32.private;
+        ",
+        )
+        .unwrap();
+        let transaction = vm.deploy_upgrade(&private_key, &upgrade, None, 0, None, rng).unwrap();
+        // Ensure the upgrade is rejected before upgrades are enabled.
+        assert!(CurrentNetwork::consensus_version(2).unwrap() < ConsensusVersion::V2);
+        assert!(vm.check_transaction(&transaction, None, rng).is_err());
+        vm.add_next_block(&sample_next_block(&vm, &private_key, &[], rng).unwrap()).unwrap();
+        // Ensure the upgrade is accepted once upgrades are enabled.
+        assert_eq!(CurrentNetwork::consensus_version(3).unwrap(), ConsensusVersion::V2);
+        assert!(vm.check_transaction(&transaction, None, rng).is_ok());
+
+        // Ensure an upgrade from a different owner is rejected.
+        let Transaction::Deploy(_, _, upgrade_deployment, fee) = &transaction else { unreachable!() };
+        let deployment_id = upgrade_deployment.to_deployment_id().unwrap();
+        let other_owner = ProgramOwner::new(&PrivateKey::new(rng).unwrap(), deployment_id, rng).unwrap();
+        let forged = Transaction::from_deployment(other_owner, *upgrade_deployment.clone(), fee.clone()).unwrap();
+        assert!(vm.check_transaction(&forged, None, rng).is_err());
+
+        vm.add_next_block(&sample_next_block(&vm, &private_key, &[transaction.clone()], rng).unwrap()).unwrap();
+
+        // Ensure the upgrade is loaded, and the mapping is preserved.
+        assert_eq!(vm.process().read().get_program(program_id).unwrap(), &upgrade);
+        assert_eq!(vm.transaction_store().deployment_store().get_edition(&program_id).unwrap(), Some(1));
+        let mapping_name = Identifier::from_str("counter").unwrap();
+        assert!(vm.finalize_store().contains_mapping_confirmed(&program_id, &mapping_name).unwrap());
+
+        // Ensure both editions can be retrieved by their transaction IDs.
+        assert_eq!(vm.transaction_store().get_transaction(&deployment.id()).unwrap(), Some(deployment));
+        assert_eq!(vm.transaction_store().get_transaction(&transaction.id()).unwrap(), Some(transaction));
+    }
+
+    #[test]
+    fn test_deploy_with_offline_owner() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the fee payer, and a separate (offline) program owner.
+        let private_key = sample_genesis_private_key(rng);
+        let owner_private_key = PrivateKey::new(rng).unwrap();
+
+        // Initialize the VM.
+        let vm = sample_vm();
+        vm.add_next_block(&sample_genesis_block(rng)).unwrap();
+
+        let program = Program::from_str(
+            r"
+program offline_owner.aleo;
+
+function compute:
+    input r0 as u32.private;
+    add r0 r0 into r1;
+    output r1 as u32.private;
+        ",
+        )
+        .unwrap();
+
+        // Compute the deployment, and export its deployment ID for the owner to sign.
+        let deployment = vm.prepare_deployment(&program, rng).unwrap();
+        let deployment_id = deployment.to_deployment_id().unwrap();
+        let (minimum_deployment_cost, _) = deployment_cost(&deployment).unwrap();
+
+        // Sign the deployment ID with the owner key, and import the owner signature.
+        let signature = ProgramOwner::new(&owner_private_key, deployment_id, rng).unwrap().signature().to_string();
+        let owner_address = Address::try_from(&owner_private_key).unwrap();
+        let owner = ProgramOwner::from(owner_address, Signature::from_str(&signature).unwrap());
+
+        // Authorize the fee.
+        let fee_authorization =
+            vm.authorize_fee_public(&private_key, minimum_deployment_cost, 0, deployment_id, rng).unwrap();
+
+        // Ensure an owner signature over a different deployment ID is rejected.
+        let other_owner = ProgramOwner::new(&owner_private_key, Field::rand(rng), rng).unwrap();
+        assert!(vm.deploy_with_owner(other_owner, deployment.clone(), fee_authorization.clone(), None, rng).is_err());
+        // Ensure a fee authorization over a different deployment ID is rejected.
+        let other_fee_authorization =
+            vm.authorize_fee_public(&private_key, minimum_deployment_cost, 0, Field::rand(rng), rng).unwrap();
+        assert!(vm.deploy_with_owner(owner, deployment.clone(), other_fee_authorization, None, rng).is_err());
+
+        // Construct the deploy transaction.
+        let transaction = vm.deploy_with_owner(owner, deployment, fee_authorization, None, rng).unwrap();
+        assert!(vm.check_transaction(&transaction, None, rng).is_ok());
+        let Transaction::Deploy(_, transaction_owner, _, _) = &transaction else { unreachable!() };
+        assert_eq!(transaction_owner.address(), owner_address);
+    }
+
+    #[test]
+    fn test_deprecate_program() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a private key.
+        let private_key = sample_genesis_private_key(rng);
+
+        // Initialize the genesis block.
+        let genesis = sample_genesis_block(rng);
+
+        // Initialize the VM.
+        let vm = sample_vm();
+        // Update the VM.
+        vm.add_next_block(&genesis).unwrap();
+
+        // Deploy the program.
+        let program = Program::from_str(
+            r"
+program deprecate_program.aleo;
+
+mapping counter:
+    key as u8.public;
+    value as u64.public;
+
+function compute:
+    input r0 as u32.private;
+    output r0 as u32.private;
+        ",
+        )
+        .unwrap();
+        let program_id = *program.id();
+
+        // Ensure a program that has not been deployed cannot be deprecated.
+        assert!(vm.deprecate(&private_key, &program_id, MappingPolicy::Freeze, None, 0, None, rng).is_err());
+
+        let deployment = vm.deploy(&private_key, &program, None, 0, None, rng).unwrap();
+        vm.add_next_block(&sample_next_block(&vm, &private_key, &[deployment], rng).unwrap()).unwrap();
+
+        // Ensure 'credits.aleo' cannot be deprecated.
+        let credits_id = ProgramID::from_str("credits.aleo").unwrap();
+        let transaction = vm.deprecate(&private_key, &credits_id, MappingPolicy::Freeze, None, 0, None, rng).unwrap();
+        assert!(vm.check_transaction(&transaction, None, rng).is_err());
+
+        // Deprecate the program.
+        let transaction = vm.deprecate(&private_key, &program_id, MappingPolicy::Freeze, None, 0, None, rng).unwrap();
+        assert!(vm.check_transaction(&transaction, None, rng).is_ok());
+
+        // Ensure a deprecation from a different owner is rejected.
+        let Transaction::Deprecate(_, _, _, _, fee) = &transaction else { unreachable!() };
+        let deprecation_id = Transaction::<CurrentNetwork>::deprecation_id(&program_id, MappingPolicy::Freeze).unwrap();
+        let other_owner = ProgramOwner::new(&PrivateKey::new(rng).unwrap(), deprecation_id, rng).unwrap();
+        let forged =
+            Transaction::from_deprecation(other_owner, program_id, MappingPolicy::Freeze, fee.clone()).unwrap();
+        assert!(vm.check_transaction(&forged, None, rng).is_err());
+
+        vm.add_next_block(&sample_next_block(&vm, &private_key, &[transaction.clone()], rng).unwrap()).unwrap();
+
+        // Ensure the program is deprecated, and remains readable.
+        assert!(vm.transaction_store().is_deprecated(&program_id).unwrap());
+        assert!(vm.contains_program(&program_id));
+        assert_eq!(vm.transaction_store().get_program(&program_id).unwrap(), Some(program));
+        let mapping_name = Identifier::from_str("counter").unwrap();
+        assert!(vm.finalize_store().contains_mapping_confirmed(&program_id, &mapping_name).unwrap());
+        assert_eq!(vm.transaction_store().get_transaction(&transaction.id()).unwrap(), Some(transaction));
+
+        // Ensure the program cannot be deprecated again.
+        let transaction = vm.deprecate(&private_key, &program_id, MappingPolicy::Freeze, None, 0, None, rng).unwrap();
+        assert!(vm.check_transaction(&transaction, None, rng).is_err());
+
+        // Ensure new executions of the program are rejected.
+        let execution = vm
+            .execute(
+                &private_key,
+                (program_id, "compute"),
+                [Value::<CurrentNetwork>::from_str("1u32").unwrap()].into_iter(),
+                None,
+                0,
+                None,
+                rng,
+            )
+            .unwrap();
+        assert!(vm.check_transaction(&execution, None, rng).is_err());
+    }
+
+    #[test]
+    fn test_deprecate_program_without_freezing_mappings() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a private key.
+        let private_key = sample_genesis_private_key(rng);
+
+        // Initialize the genesis block.
+        let genesis = sample_genesis_block(rng);
+
+        // Initialize the VM.
+        let vm = sample_vm();
+        // Update the VM.
+        vm.add_next_block(&genesis).unwrap();
+
+        // Deploy the program.
+        let program = Program::from_str(
+            r"
+program retire_program.aleo;
+
+mapping counter:
+    key as u8.public;
+    value as u64.public;
+
+function compute:
+    input r0 as u32.private;
+    output r0 as u32.private;
+        ",
+        )
+        .unwrap();
+        let program_id = *program.id();
+
+        let deployment = vm.deploy(&private_key, &program, None, 0, None, rng).unwrap();
+        vm.add_next_block(&sample_next_block(&vm, &private_key, &[deployment], rng).unwrap()).unwrap();
+
+        // Ensure the mapping exists.
+        let mapping_name = Identifier::from_str("counter").unwrap();
+        assert!(vm.finalize_store().contains_mapping_confirmed(&program_id, &mapping_name).unwrap());
+
+        // Deprecate the program, without freezing its mappings.
+        let transaction = vm.deprecate(&private_key, &program_id, MappingPolicy::Remove, None, 0, None, rng).unwrap();
+        assert_eq!(transaction.mapping_policy(), Some(MappingPolicy::Remove));
+        assert!(vm.check_transaction(&transaction, None, rng).is_ok());
+
+        // Ensure the deprecation ID commits to the mapping policy.
+        assert_ne!(
+            Transaction::<CurrentNetwork>::deprecation_id(&program_id, MappingPolicy::Remove).unwrap(),
+            Transaction::<CurrentNetwork>::deprecation_id(&program_id, MappingPolicy::Freeze).unwrap()
+        );
+
+        vm.add_next_block(&sample_next_block(&vm, &private_key, &[transaction.clone()], rng).unwrap()).unwrap();
+
+        // Ensure the program is deprecated and remains readable, while its mapping is removed.
+        assert!(vm.transaction_store().is_deprecated(&program_id).unwrap());
+        assert!(vm.contains_program(&program_id));
+        assert_eq!(vm.transaction_store().get_program(&program_id).unwrap(), Some(program));
+        assert!(!vm.finalize_store().contains_mapping_confirmed(&program_id, &mapping_name).unwrap());
+        assert_eq!(vm.transaction_store().get_transaction(&transaction.id()).unwrap(), Some(transaction));
+    }
+
+    #[test]
+    fn test_deprecate_program_with_dependents() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a private key.
+        let private_key = sample_genesis_private_key(rng);
+
+        // Initialize the genesis block.
+        let genesis = sample_genesis_block(rng);
+
+        // Initialize the VM.
+        let vm = sample_vm();
+        // Update the VM.
+        vm.add_next_block(&genesis).unwrap();
+
+        // Deploy the program, and a program that imports it.
+        let program = Program::from_str(
+            r"
+program retire_program.aleo;
+
+mapping counter:
+    key as u8.public;
+    value as u64.public;
+
+function compute:
+    input r0 as u32.private;
+    output r0 as u32.private;
+        ",
+        )
+        .unwrap();
+        let dependent = Program::from_str(
+            r"
+import retire_program.aleo;
+
+program reader_program.aleo;
+
+function compute:
+    input r0 as u32.private;
+    output r0 as u32.private;
+        ",
+        )
+        .unwrap();
+        let program_id = *program.id();
+
+        let deployment = vm.deploy(&private_key, &program, None, 0, None, rng).unwrap();
+        vm.add_next_block(&sample_next_block(&vm, &private_key, &[deployment], rng).unwrap()).unwrap();
+        let deployment = vm.deploy(&private_key, &dependent, None, 0, None, rng).unwrap();
+        vm.add_next_block(&sample_next_block(&vm, &private_key, &[deployment], rng).unwrap()).unwrap();
+
+        // Ensure the mappings cannot be removed while the dependent is not deprecated.
+        let transaction = vm.deprecate(&private_key, &program_id, MappingPolicy::Remove, None, 0, None, rng).unwrap();
+        assert!(vm.check_transaction(&transaction, None, rng).is_err());
+        // Ensure the program can still be deprecated with its mappings frozen.
+        let transaction = vm.deprecate(&private_key, &program_id, MappingPolicy::Freeze, None, 0, None, rng).unwrap();
+        assert!(vm.check_transaction(&transaction, None, rng).is_ok());
+
+        // Deprecate the dependent.
+        let transaction =
+            vm.deprecate(&private_key, dependent.id(), MappingPolicy::Freeze, None, 0, None, rng).unwrap();
+        vm.add_next_block(&sample_next_block(&vm, &private_key, &[transaction], rng).unwrap()).unwrap();
+
+        // Ensure the mappings can now be removed.
+        let transaction = vm.deprecate(&private_key, &program_id, MappingPolicy::Remove, None, 0, None, rng).unwrap();
+        assert!(vm.check_transaction(&transaction, None, rng).is_ok());
+        vm.add_next_block(&sample_next_block(&vm, &private_key, &[transaction], rng).unwrap()).unwrap();
+        let mapping_name = Identifier::from_str("counter").unwrap();
+        assert!(!vm.finalize_store().contains_mapping_confirmed(&program_id, &mapping_name).unwrap());
+    }
+
+    #[test]
+    fn test_sequenced_executions() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a private key and address.
+        let private_key = sample_genesis_private_key(rng);
+        let address = Address::try_from(&private_key).unwrap();
+
+        // Initialize the genesis block.
+        let genesis = sample_genesis_block(rng);
+
+        // Initialize the VM.
+        let vm = sample_vm();
+        // Update the VM.
+        vm.add_next_block(&genesis).unwrap();
+
+        // Ensure the address has no sequenced executions.
+        assert_eq!(vm.next_sequence(&address).unwrap(), 0);
+
+        // Construct the sequenced public transfers.
+        let mut transfer = |sequence: u64| {
+            let inputs =
+                [Value::<CurrentNetwork>::from_str(&address.to_string()).unwrap(), Value::from_str("1u64").unwrap()];
+            vm.execute_sequenced(
+                &private_key,
+                ("credits.aleo", "transfer_public"),
+                inputs.into_iter(),
+                sequence,
+                0,
+                None,
+                rng,
+            )
+        };
+        let transaction_0 = transfer(0).unwrap();
+        let transaction_1 = transfer(1).unwrap();
+        let duplicate_1 = transfer(1).unwrap();
+        assert_eq!(transaction_1.execution().unwrap().sequence(), Some(1));
+        assert!(vm.check_transaction(&transaction_0, None, rng).is_ok());
+
+        // Ensure an execution that produces records cannot be sequenced.
+        let inputs =
+            [Value::<CurrentNetwork>::from_str(&address.to_string()).unwrap(), Value::from_str("1u64").unwrap()];
+        assert!(vm
+            .execute_sequenced(
+                &private_key,
+                ("credits.aleo", "transfer_public_to_private"),
+                inputs.into_iter(),
+                0,
+                0,
+                None,
+                rng
+            )
+            .is_err());
+
+        // Ensure an out-of-order sequence number is aborted.
+        let (_, _, aborted_transaction_ids, _) =
+            vm.speculate(sample_finalize_state(1), None, vec![], &None.into(), [&transaction_1].into_iter()).unwrap();
+        assert_eq!(aborted_transaction_ids, vec![transaction_1.id()]);
+
+        // Ensure a duplicate sequence number is aborted.
+        let transactions = [&transaction_0, &transaction_1, &duplicate_1];
+        let (_, confirmed, aborted_transaction_ids, _) =
+            vm.speculate(sample_finalize_state(1), None, vec![], &None.into(), transactions.into_iter()).unwrap();
+        assert_eq!(confirmed.len(), 2);
+        assert_eq!(aborted_transaction_ids, vec![duplicate_1.id()]);
+
+        // Add the sequenced executions, in order.
+        let transactions = [transaction_0, transaction_1.clone()];
+        vm.add_next_block(&sample_next_block(&vm, &private_key, &transactions, rng).unwrap()).unwrap();
+
+        // Ensure the next sequence number is incremented, and the duplicate is no longer valid.
+        assert_eq!(vm.next_sequence(&address).unwrap(), 2);
+        assert!(vm.check_transaction(&duplicate_1, None, rng).is_err());
+        // Ensure the sequence number is stored with the execution.
+        assert_eq!(vm.transaction_store().get_transaction(&transaction_1.id()).unwrap(), Some(transaction_1));
+    }
+
+    #[test]
+    fn test_time_locked_records() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a private key and view key.
+        let private_key = sample_genesis_private_key(rng);
+        let view_key = ViewKey::try_from(&private_key).unwrap();
+
+        // Initialize the genesis block.
+        let genesis = sample_genesis_block(rng);
+
+        // Initialize the VM.
+        let vm = sample_vm();
+        // Update the VM.
+        vm.add_next_block(&genesis).unwrap();
+
+        // Deploy the program.
+        let program = Program::from_str(
+            r"
+program vesting.aleo;
+
+record vesting:
+    owner as address.private;
+    amount as u64.private;
+    release as u32.public;
+    lock release;
+
+function lock:
+    input r0 as u64.private;
+    input r1 as u32.public;
+    cast self.caller r0 r1 into r2 as vesting.record;
+    output r2 as vesting.record;
+
+function unlock:
+    input r0 as vesting.record;
+    output r0.amount as u64.private;
+        ",
+        )
+        .unwrap();
+        let deployment = vm.deploy(&private_key, &program, None, 0, None, rng).unwrap();
+        vm.add_next_block(&sample_next_block(&vm, &private_key, &[deployment], rng).unwrap()).unwrap();
+
+        // Lock a record until a future block, and a record until the current block.
+        let lock = |release: &str, rng: &mut TestRng| {
+            let inputs = [Value::<CurrentNetwork>::from_str("100u64").unwrap(), Value::from_str(release).unwrap()];
+            vm.execute(&private_key, ("vesting.aleo", "lock"), inputs.iter(), None, 0, None, rng).unwrap()
+        };
+        let locked = lock("100u32", rng);
+        let unlocked = lock("2u32", rng);
+        vm.add_next_block(&sample_next_block(&vm, &private_key, &[locked.clone(), unlocked.clone()], rng).unwrap())
+            .unwrap();
+        assert_eq!(vm.block_store().current_block_height(), 2);
+
+        // Spend the locked record early.
+        let record = locked.records().next().unwrap().1.decrypt(&view_key).unwrap();
+        let inputs = [Value::Record(record)];
+        let transaction =
+            vm.execute(&private_key, ("vesting.aleo", "unlock"), inputs.iter(), None, 0, None, rng).unwrap();
+        // Ensure the lock height is revealed in the transition.
+        let input = transaction.transitions().next().unwrap().inputs()[0].clone();
+        assert_eq!(input.lock_height().map(|lock_height| **lock_height), Some(100));
+        // Ensure the early spend is rejected at verification.
+        assert!(vm.check_transaction(&transaction, None, rng).is_err());
+
+        // Ensure a forged lock height is rejected at verification.
+        let Transaction::Execute(_, execution, fee) = &transaction else { unreachable!() };
+        let mut transitions: Vec<_> = execution.transitions().cloned().collect();
+        let transition = &transitions[0];
+        let mut inputs = transition.inputs().to_vec();
+        let Input::Record(serial_number, tag, _) = inputs[0] else { unreachable!() };
+        inputs[0] = Input::Record(serial_number, tag, Some(U32::new(2)));
+        transitions[0] = Transition::new(
+            *transition.program_id(),
+            *transition.function_name(),
+            inputs,
+            transition.outputs().to_vec(),
+            *transition.tpk(),
+            *transition.tcm(),
+            *transition.scm(),
+        )
+        .unwrap();
+        let execution =
+            Execution::from(transitions.into_iter(), execution.global_state_root(), execution.proof().cloned())
+                .unwrap();
+        let forged = Transaction::from_execution(execution, fee.clone()).unwrap();
+        assert!(vm.check_transaction(&forged, None, rng).is_err());
+
+        // Ensure the unlocked record can be spent.
+        let record = unlocked.records().next().unwrap().1.decrypt(&view_key).unwrap();
+        let inputs = [Value::Record(record)];
+        let transaction =
+            vm.execute(&private_key, ("vesting.aleo", "unlock"), inputs.iter(), None, 0, None, rng).unwrap();
+        assert!(vm.check_transaction(&transaction, None, rng).is_ok());
+    }
+
     #[test]
     fn test_deployment_with_external_records() {
         let rng = &mut TestRng::default();
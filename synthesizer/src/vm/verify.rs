@@ -33,6 +33,9 @@ macro_rules! ensure_is_unique {
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     /// Verifies the transaction in the VM. On failure, returns an error.
+    ///
+    /// If the transaction was already verified at the current state root, then only its transaction ID
+    /// is re-checked, as the remaining checks depend only on the contents committed to by the ID and the ledger state.
     #[inline]
     pub fn check_transaction<R: CryptoRng + Rng>(
         &self,
@@ -60,6 +63,21 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         };
         lap!(timer, "Verify the transaction ID");
 
+        // Ensure the transaction is in canonical form.
+        ensure!(transaction.is_canonical(), "Transaction '{}' is not in canonical form", transaction.id());
+        lap!(timer, "Verify the transaction is canonical");
+
+        // Retrieve the current state root.
+        let state_root = self.block_store().current_state_root();
+        // Retrieve the state root the transaction was last verified at, if it is in the partially-verified cache.
+        let verified_at = self.partially_verified_transactions.read().peek(&transaction.id()).copied();
+        // Skip the remaining checks, if the transaction was verified at the current state root.
+        // Note: The result of a check with a rejected ID also depends on the rejected ID, so it is always re-checked.
+        if rejected_id.is_none() && verified_at == Some(state_root) {
+            finish!(timer, "Skip the checks of a transaction verified at the current state root");
+            return Ok(());
+        }
+
         /* Transition */
 
         // Ensure the transition IDs are unique.
@@ -73,6 +91,15 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         ensure_is_unique!("serial number", self, contains_serial_number, transaction.serial_numbers());
         // Ensure the tags are unique.
         ensure_is_unique!("tag", self, contains_tag, transaction.tags());
+        // Ensure the time-locked records are unlocked at the latest block height.
+        let block_height = self.block_store().current_block_height();
+        for input in transaction.transitions().flat_map(|transition| transition.inputs()) {
+            if let Some(lock_height) = input.lock_height() {
+                if **lock_height > block_height {
+                    bail!("Transaction '{}' spends a record locked until block {lock_height}", transaction.id())
+                }
+            }
+        }
 
         /* Output */
 
@@ -96,7 +123,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         self.check_fee(transaction, rejected_id)?;
 
         // Check if the transaction exists in the partially-verified cache.
-        let is_partially_verified = self.partially_verified_transactions.read().peek(&transaction.id()).is_some();
+        let is_partially_verified = verified_at.is_some();
 
         // Next, verify the deployment or execution.
         match transaction {
@@ -107,42 +134,118 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 };
                 // Verify the signature corresponds to the transaction ID.
                 ensure!(owner.verify(deployment_id), "Invalid owner signature for deployment transaction '{id}'");
-                // Ensure the edition is correct.
-                if deployment.edition() != N::EDITION {
-                    bail!("Invalid deployment transaction '{id}' - expected edition {}", N::EDITION)
-                }
-                // Ensure the program ID does not already exist in the store.
-                if self.transaction_store().contains_program_id(deployment.program_id())? {
-                    bail!("Program ID '{}' is already deployed", deployment.program_id())
-                }
-                // Ensure the program does not already exist in the process.
-                if self.contains_program(deployment.program_id()) {
-                    bail!("Program ID '{}' already exists", deployment.program_id());
+                // Retrieve the deployment store.
+                let deployment_store = self.transaction_store().deployment_store();
+                // Retrieve the height of the block that would include the deployment.
+                let next_height = self.block_store().current_block_height().saturating_add(1);
+                // Retrieve the latest edition of the program, if it exists.
+                match deployment_store.get_edition(deployment.program_id())? {
+                    // Ensure the upgrade is the next edition, and is owned by the same address.
+                    Some(edition) => {
+                        // Ensure program upgrades are enabled at the next block height.
+                        if N::consensus_version(next_height)? < ConsensusVersion::V2 {
+                            bail!("Invalid upgrade transaction '{id}' - upgrades are not enabled at block {next_height}")
+                        }
+                        if deployment.edition() != edition.saturating_add(1) {
+                            bail!("Invalid upgrade transaction '{id}' - expected edition {}", edition.saturating_add(1))
+                        }
+                        match deployment_store.get_owner(deployment.program_id())? {
+                            Some(previous) if previous.address() == owner.address() => (),
+                            _ => bail!("Invalid upgrade transaction '{id}' - the owner does not match"),
+                        }
+                        // Ensure the program exists in the process.
+                        if !self.contains_program(deployment.program_id()) {
+                            bail!("Program ID '{}' does not exist", deployment.program_id());
+                        }
+                    }
+                    None => {
+                        // Ensure the edition is correct.
+                        if deployment.edition() != N::EDITION {
+                            bail!("Invalid deployment transaction '{id}' - expected edition {}", N::EDITION)
+                        }
+                        // Ensure the program ID does not already exist in the store.
+                        if self.transaction_store().contains_program_id(deployment.program_id())? {
+                            bail!("Program ID '{}' is already deployed", deployment.program_id())
+                        }
+                        // Ensure the program does not already exist in the process.
+                        if self.contains_program(deployment.program_id()) {
+                            bail!("Program ID '{}' already exists", deployment.program_id());
+                        }
+                    }
                 }
+                // Ensure the program only reads the 'credits.aleo' mappings that are enabled at the next block height.
+                self.process.read().check_credits_mappings(deployment.program(), next_height)?;
                 // Verify the deployment if it has not been verified before.
                 if !is_partially_verified {
                     self.check_deployment_internal(deployment, rng)?;
                 }
             }
-            Transaction::Execute(id, execution, _) => {
+            Transaction::Execute(id, execution, fee) => {
                 // Compute the execution ID.
                 let Ok(execution_id) = execution.to_execution_id() else {
                     bail!("Failed to compute the Merkle root for an execution transaction '{id}'")
                 };
+                // Ensure a sequenced execution is public-only, with a public fee and an unused sequence number.
+                if let Some(sequence) = execution.sequence() {
+                    if !execution.is_public_only() {
+                        bail!("Transaction '{id}' is a sequenced execution that consumes or produces records")
+                    }
+                    let Some(payer) = fee.as_ref().and_then(Fee::payer) else {
+                        bail!("Transaction '{id}' is a sequenced execution without a public fee")
+                    };
+                    if sequence < Process::next_sequence_confirmed(self.finalize_store(), &payer)? {
+                        bail!("Transaction '{id}' reuses the sequence number {sequence} of '{payer}'")
+                    }
+                }
                 // Ensure the execution was not previously rejected (replay attack prevention).
                 if self.block_store().contains_rejected_deployment_or_execution_id(&execution_id)? {
                     bail!("Transaction '{id}' contains a previously rejected execution")
                 }
+                // Ensure the execution does not call a deprecated program.
+                for transition in execution.transitions() {
+                    if self.transaction_store().is_deprecated(transition.program_id())? {
+                        bail!("Transaction '{id}' executes the deprecated program '{}'", transition.program_id())
+                    }
+                }
                 // Verify the execution.
                 self.check_execution_internal(execution, is_partially_verified)?;
             }
             Transaction::Fee(..) => { /* no-op */ }
+            Transaction::Deprecate(id, owner, program_id, mapping_policy, _) => {
+                // Compute the deprecation ID.
+                let Ok(deprecation_id) = Transaction::<N>::deprecation_id(program_id, *mapping_policy) else {
+                    bail!("Failed to compute the Merkle root for a deprecate transaction '{id}'")
+                };
+                // Verify the signature corresponds to the transaction ID.
+                ensure!(owner.verify(deprecation_id), "Invalid owner signature for deprecate transaction '{id}'");
+                // Ensure the program is not 'credits.aleo'.
+                ensure!(program_id != &ProgramID::from_str("credits.aleo")?, "Cannot deprecate 'credits.aleo'");
+                // Ensure the program exists in the process.
+                if !self.contains_program(program_id) {
+                    bail!("Program ID '{program_id}' does not exist");
+                }
+                // Retrieve the deployment store.
+                let deployment_store = self.transaction_store().deployment_store();
+                // Ensure the deprecation is signed by the program owner.
+                match deployment_store.get_owner(program_id)? {
+                    Some(previous) if previous.address() == owner.address() => (),
+                    _ => bail!("Invalid deprecate transaction '{id}' - the owner does not match"),
+                }
+                // Ensure the program is not already deprecated.
+                if deployment_store.is_deprecated(program_id)? {
+                    bail!("Program ID '{program_id}' is already deprecated")
+                }
+                // Ensure the mappings are not removed while other programs import the program.
+                if mapping_policy.is_remove() {
+                    self.check_mapping_removal(program_id)?;
+                }
+            }
         }
 
         // If the above checks have passed and this is not a fee transaction,
-        // then add the transaction ID to the partially-verified transactions cache.
-        if !matches!(transaction, Transaction::Fee(..)) && !is_partially_verified {
-            self.partially_verified_transactions.write().push(transaction.id(), ());
+        // then record the transaction ID and the current state root in the partially-verified transactions cache.
+        if !matches!(transaction, Transaction::Fee(..)) {
+            self.partially_verified_transactions.write().push(transaction.id(), state_root);
         }
 
         finish!(timer, "Verify the transaction");
@@ -211,6 +314,22 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                     None => bail!("Transaction '{id}' is missing a rejected ID (fee)"),
                 }
             }
+            Transaction::Deprecate(id, _, program_id, mapping_policy, fee) => {
+                // Ensure the rejected ID is not present.
+                ensure!(rejected_id.is_none(), "Transaction '{id}' should not have a rejected ID (deprecation)");
+                // Compute the deprecation ID.
+                let Ok(deprecation_id) = Transaction::<N>::deprecation_id(program_id, *mapping_policy) else {
+                    bail!("Failed to compute the Merkle root for deprecate transaction '{id}'")
+                };
+                // Compute the minimum deprecation cost.
+                let cost = deprecation_cost(program_id)?;
+                // Ensure the fee is sufficient to cover the cost.
+                if *fee.base_amount()? < cost {
+                    bail!("Transaction '{id}' has an insufficient base fee (deprecation) - requires {cost} microcredits")
+                }
+                // Verify the fee.
+                self.check_fee_internal(fee, deprecation_id)?;
+            }
         }
         Ok(())
     }
@@ -318,6 +437,22 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         finish!(timer, "Check the global state root");
         result
     }
+
+    /// Ensures the mappings of the given program may be removed. On failure, returns an error.
+    ///
+    /// Note: The mappings may only be removed if every program that imports the given program is deprecated,
+    /// as any other dependent may still read the mappings.
+    pub(super) fn check_mapping_removal(&self, program_id: &ProgramID<N>) -> Result<()> {
+        // Retrieve the deployment store.
+        let deployment_store = self.transaction_store().deployment_store();
+        // Ensure every dependent of the program is deprecated.
+        for dependent in deployment_store.get_dependents(program_id)? {
+            if !deployment_store.is_deprecated(&dependent)? {
+                bail!("Cannot remove the mappings of '{program_id}', as it is imported by '{dependent}'")
+            }
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -354,6 +489,35 @@ mod tests {
         vm.check_transaction(&execution_transaction, None, rng).unwrap();
     }
 
+    #[test]
+    fn test_verify_with_partially_verified_transactions() {
+        let rng = &mut TestRng::default();
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+
+        // Fetch an execution transaction.
+        let transaction = crate::vm::test_helpers::sample_execution_transaction_with_public_fee(rng);
+        // Ensure the transaction verifies, and is recorded at the current state root.
+        vm.check_transaction(&transaction, None, rng).unwrap();
+        let state_root = vm.block_store().current_state_root();
+        assert_eq!(vm.partially_verified_transactions().read().peek(&transaction.id()), Some(&state_root));
+        // Ensure the recorded transaction still verifies.
+        vm.check_transaction(&transaction, None, rng).unwrap();
+
+        // Ensure a check with a rejected ID is not skipped.
+        assert!(vm.check_transaction(&transaction, Some(Field::rand(rng)), rng).is_err());
+
+        // Advance the ledger to the next block.
+        let private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let block = crate::vm::test_helpers::sample_next_block(&vm, &private_key, &[], rng).unwrap();
+        vm.add_next_block(&block).unwrap();
+        let next_state_root = vm.block_store().current_state_root();
+        assert_ne!(state_root, next_state_root);
+
+        // Ensure the transaction is re-checked, and is recorded at the new state root.
+        vm.check_transaction(&transaction, None, rng).unwrap();
+        assert_eq!(vm.partially_verified_transactions().read().peek(&transaction.id()), Some(&next_state_root));
+    }
+
     #[test]
     fn test_verify_deployment() {
         let rng = &mut TestRng::default();
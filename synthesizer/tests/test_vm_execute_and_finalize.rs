@@ -281,6 +281,9 @@ fn run_test(test: &ProgramTest) -> serde_yaml::Mapping {
                             | ConfirmedTransaction::RejectedDeploy(_, _, _, _) => {
                                 unreachable!("unexpected deployment transaction")
                             }
+                            ConfirmedTransaction::AcceptedDeprecate(_, _, _) => {
+                                unreachable!("unexpected deprecation transaction")
+                            }
                         }),
                     );
                     (ratifications, transactions, aborted_transaction_ids, ratified_finalize_operations)
@@ -522,6 +525,7 @@ fn construct_finalize_global_state<C: ConsensusStorage<CurrentNetwork>>(
     FinalizeGlobalState::new::<CurrentNetwork>(
         next_round,
         next_height,
+        CurrentNetwork::GENESIS_TIMESTAMP + 1,
         latest_cumulative_weight,
         0u128,
         latest_block.hash(),
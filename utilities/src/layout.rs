@@ -0,0 +1,121 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    io::{Result as IoResult, Write},
+    ToBytes,
+    Vec,
+};
+use serde_json::{json, Value as Json};
+
+/// A contiguous segment of a byte layout, as emitted by a single write.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LayoutSegment {
+    /// The offset of the segment, in bytes.
+    pub offset: usize,
+    /// The width of the segment, in bytes.
+    pub width: usize,
+    /// The value of the segment, if it is a single byte (i.e. a version or a variant).
+    pub tag: Option<u8>,
+}
+
+/// The byte layout of a `ToBytes` implementation, recorded from a sample value.
+///
+/// Two layouts recorded from the same value are equal if and only if the
+/// implementation writes the same sequence of segments, so comparing layouts
+/// across releases detects layout drift.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ByteLayout {
+    /// The name of the recorded type.
+    pub name: String,
+    /// The total size, in bytes.
+    pub size: usize,
+    /// The segments, in the order they are written.
+    pub segments: Vec<LayoutSegment>,
+}
+
+impl ByteLayout {
+    /// Records the byte layout of the given value.
+    pub fn record<T: ToBytes>(name: &str, value: &T) -> anyhow::Result<Self> {
+        let mut recorder = LayoutRecorder::default();
+        value.write_le(&mut recorder)?;
+        Ok(Self { name: name.to_string(), size: recorder.size, segments: recorder.segments })
+    }
+
+    /// Returns the version, if the layout begins with a single-byte segment.
+    pub fn version(&self) -> Option<u8> {
+        self.segments.first().and_then(|segment| segment.tag)
+    }
+
+    /// Returns the machine-readable description of the layout.
+    pub fn to_json(&self) -> Json {
+        let segments = self
+            .segments
+            .iter()
+            .map(|segment| match segment.tag {
+                Some(tag) => json!({ "offset": segment.offset, "width": segment.width, "tag": tag }),
+                None => json!({ "offset": segment.offset, "width": segment.width }),
+            })
+            .collect::<Vec<_>>();
+        json!({ "name": self.name, "version": self.version(), "size": self.size, "segments": segments })
+    }
+}
+
+/// A writer that records the offset and width of every write, instead of the bytes themselves.
+#[derive(Clone, Debug, Default)]
+pub struct LayoutRecorder {
+    /// The number of bytes written so far.
+    size: usize,
+    /// The recorded segments.
+    segments: Vec<LayoutSegment>,
+}
+
+impl Write for LayoutRecorder {
+    fn write(&mut self, buf: &[u8]) -> IoResult<usize> {
+        if !buf.is_empty() {
+            let tag = match buf.len() {
+                1 => Some(buf[0]),
+                _ => None,
+            };
+            self.segments.push(LayoutSegment { offset: self.size, width: buf.len(), tag });
+            self.size += buf.len();
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> IoResult<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_layout() {
+        let layout = ByteLayout::record("tuple", &((1u8, 2u32), 3u64)).unwrap();
+        assert_eq!(layout.size, 13);
+        assert_eq!(layout.version(), Some(1));
+        assert_eq!(layout.segments, vec![
+            LayoutSegment { offset: 0, width: 1, tag: Some(1) },
+            LayoutSegment { offset: 1, width: 4, tag: None },
+            LayoutSegment { offset: 5, width: 8, tag: None },
+        ]);
+
+        // Check the machine-readable description.
+        let expected = r#"{"name":"tuple","version":1,"size":13,"segments":[{"offset":0,"width":1,"tag":1},{"offset":1,"width":4},{"offset":5,"width":8}]}"#;
+        assert_eq!(layout.to_json().to_string(), expected);
+    }
+}
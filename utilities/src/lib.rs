@@ -61,6 +61,9 @@ pub use error::*;
 pub mod iterator;
 pub use iterator::*;
 
+pub mod layout;
+pub use layout::*;
+
 #[macro_use]
 pub mod parallel;
 pub use parallel::*;
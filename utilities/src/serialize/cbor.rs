@@ -0,0 +1,76 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use serde::{de::DeserializeOwned, Serialize};
+
+/// A helper trait to serialize a value into canonical CBOR.
+///
+/// The value is serialized with its non-human-readable `serde` representation,
+/// which is deterministic, so equal values always produce identical bytes.
+pub trait ToCbor: Serialize {
+    /// Returns `self` as canonical CBOR bytes.
+    fn to_cbor(&self) -> anyhow::Result<Vec<u8>> {
+        let mut buffer = Vec::new();
+        ciborium::ser::into_writer(self, &mut buffer).map_err(|e| anyhow::anyhow!("Failed to encode CBOR - {e}"))?;
+        Ok(buffer)
+    }
+}
+
+impl<T: Serialize + ?Sized> ToCbor for T {}
+
+/// A helper trait to deserialize a value from canonical CBOR.
+pub trait FromCbor: DeserializeOwned + ToCbor {
+    /// Reads `Self` from the given canonical CBOR bytes.
+    ///
+    /// This method fails if the bytes are not the canonical encoding of the value,
+    /// i.e. if they contain trailing bytes or a non-minimal encoding.
+    fn from_cbor(bytes: &[u8]) -> anyhow::Result<Self> {
+        let value: Self =
+            ciborium::de::from_reader(bytes).map_err(|e| anyhow::anyhow!("Failed to decode CBOR - {e}"))?;
+        // Ensure the bytes are the canonical encoding of the value.
+        if value.to_cbor()? != bytes {
+            anyhow::bail!("Failed to decode CBOR - the encoding is not canonical");
+        }
+        Ok(value)
+    }
+}
+
+impl<T: DeserializeOwned + ToCbor> FromCbor for T {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cbor() {
+        let expected: (u64, Vec<u8>, String) = (42, vec![1, 2, 3], "aleo".to_string());
+
+        // Ensure the value round-trips.
+        let bytes = expected.to_cbor().unwrap();
+        assert_eq!(expected, <(u64, Vec<u8>, String)>::from_cbor(&bytes).unwrap());
+
+        // Ensure the encoding is deterministic.
+        assert_eq!(bytes, expected.to_cbor().unwrap());
+
+        // Ensure trailing bytes are rejected.
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        assert!(<(u64, Vec<u8>, String)>::from_cbor(&trailing).is_err());
+
+        // Ensure a non-minimal integer encoding is rejected.
+        // Note: `0x18 0x05` encodes the integer 5 with a one-byte argument, instead of `0x05`.
+        assert_eq!(5u64.to_cbor().unwrap(), vec![0x05]);
+        assert!(u64::from_cbor(&[0x18, 0x05]).is_err());
+    }
+}
@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod cbor;
+pub use cbor::*;
+
 pub mod error;
 pub use error::*;
 
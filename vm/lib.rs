@@ -22,26 +22,28 @@ extern crate thiserror;
 
 #[cfg(feature = "cli")]
 pub mod cli;
+#[cfg(all(feature = "console", feature = "synthesizer"))]
 pub mod file;
+#[cfg(all(feature = "console", feature = "ledger", feature = "synthesizer"))]
 pub mod package;
 
-#[cfg(feature = "algorithms")]
+#[cfg(any(feature = "algorithms", feature = "verifier"))]
 pub use snarkvm_algorithms as algorithms;
 #[cfg(feature = "circuit")]
 pub use snarkvm_circuit as circuit;
-#[cfg(feature = "console")]
+#[cfg(any(feature = "console", feature = "sdk", feature = "verifier"))]
 pub use snarkvm_console as console;
 #[cfg(feature = "curves")]
 pub use snarkvm_curves as curves;
 #[cfg(feature = "fields")]
 pub use snarkvm_fields as fields;
-#[cfg(feature = "ledger")]
+#[cfg(any(feature = "ledger", feature = "verifier"))]
 pub use snarkvm_ledger as ledger;
 #[cfg(feature = "metrics")]
 pub use snarkvm_metrics as metrics;
 #[cfg(feature = "parameters")]
 pub use snarkvm_parameters as parameters;
-#[cfg(feature = "synthesizer")]
+#[cfg(any(feature = "synthesizer", feature = "verifier"))]
 pub use snarkvm_synthesizer as synthesizer;
 #[cfg(feature = "utilities")]
 pub use snarkvm_utilities as utilities;
@@ -49,10 +51,10 @@ pub use snarkvm_utilities as utilities;
 pub use snarkvm_wasm as wasm;
 
 pub mod prelude {
-    #[cfg(feature = "console")]
+    #[cfg(any(feature = "console", feature = "sdk", feature = "verifier"))]
     pub use crate::console::{account::*, network::*, program::*};
-    #[cfg(feature = "ledger")]
+    #[cfg(any(feature = "ledger", feature = "verifier"))]
     pub use crate::ledger::*;
-    #[cfg(feature = "synthesizer")]
+    #[cfg(any(feature = "synthesizer", feature = "verifier"))]
     pub use crate::synthesizer::prelude::*;
 }
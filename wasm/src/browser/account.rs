@@ -0,0 +1,106 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{to_js_error, CurrentNetwork};
+use snarkvm_console::{
+    account::{Address, PrivateKey, Signature, ViewKey},
+    prelude::*,
+    program::{Ciphertext, Record},
+};
+
+use wasm_bindgen::prelude::*;
+
+/// An Aleo account, held as its private key.
+#[wasm_bindgen]
+pub struct Account {
+    private_key: PrivateKey<CurrentNetwork>,
+    view_key: ViewKey<CurrentNetwork>,
+    address: Address<CurrentNetwork>,
+}
+
+#[wasm_bindgen]
+impl Account {
+    /// Samples a new account.
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Result<Account, JsError> {
+        Self::try_from(PrivateKey::new(&mut rand::thread_rng()).map_err(to_js_error)?)
+    }
+
+    /// Initializes an account from the given private key.
+    #[wasm_bindgen(js_name = fromPrivateKey)]
+    pub fn from_private_key(private_key: &str) -> Result<Account, JsError> {
+        Self::try_from(PrivateKey::from_str(private_key).map_err(to_js_error)?)
+    }
+
+    /// Returns the private key.
+    #[wasm_bindgen(js_name = privateKey)]
+    pub fn private_key(&self) -> String {
+        self.private_key.to_string()
+    }
+
+    /// Returns the view key.
+    #[wasm_bindgen(js_name = viewKey)]
+    pub fn view_key(&self) -> String {
+        self.view_key.to_string()
+    }
+
+    /// Returns the address.
+    pub fn address(&self) -> String {
+        self.address.to_string()
+    }
+
+    /// Signs the given message, and returns the signature.
+    pub fn sign(&self, message: &[u8]) -> Result<String, JsError> {
+        Ok(self.private_key.sign_bytes(message, &mut rand::thread_rng()).map_err(to_js_error)?.to_string())
+    }
+
+    /// Returns `true` if the given signature is valid for the given message and address.
+    pub fn verify(address: &str, message: &[u8], signature: &str) -> Result<bool, JsError> {
+        let address = Address::<CurrentNetwork>::from_str(address).map_err(to_js_error)?;
+        let signature = Signature::<CurrentNetwork>::from_str(signature).map_err(to_js_error)?;
+        Ok(signature.verify_bytes(&address, message))
+    }
+
+    /// Returns `true` if the given record ciphertext is owned by this account.
+    #[wasm_bindgen(js_name = isOwner)]
+    pub fn is_owner(&self, ciphertext: &str) -> Result<bool, JsError> {
+        let record = Record::<CurrentNetwork, Ciphertext<CurrentNetwork>>::from_str(ciphertext).map_err(to_js_error)?;
+        Ok(record.is_owner(&self.view_key))
+    }
+
+    /// Decrypts the given record ciphertext, and returns the plaintext record.
+    #[wasm_bindgen(js_name = decryptRecord)]
+    pub fn decrypt_record(&self, ciphertext: &str) -> Result<String, JsError> {
+        let record = Record::<CurrentNetwork, Ciphertext<CurrentNetwork>>::from_str(ciphertext).map_err(to_js_error)?;
+        Ok(record.decrypt(&self.view_key).map_err(to_js_error)?.to_string())
+    }
+}
+
+impl Account {
+    /// Returns the private key of the account.
+    pub(crate) const fn inner(&self) -> &PrivateKey<CurrentNetwork> {
+        &self.private_key
+    }
+}
+
+impl TryFrom<PrivateKey<CurrentNetwork>> for Account {
+    type Error = JsError;
+
+    /// Initializes an account from the given private key.
+    fn try_from(private_key: PrivateKey<CurrentNetwork>) -> Result<Self, Self::Error> {
+        let view_key = ViewKey::try_from(&private_key).map_err(to_js_error)?;
+        let address = view_key.to_address();
+        Ok(Self { private_key, view_key, address })
+    }
+}
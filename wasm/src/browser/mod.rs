@@ -0,0 +1,37 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A `wasm-bindgen` API for authorizing and proving executions in the browser.
+//!
+//! Keys, programs, inputs, authorizations, and executions cross the boundary as strings,
+//! and proving and verifying keys as `Uint8Array`s.
+
+mod account;
+pub use account::*;
+
+mod program_manager;
+pub use program_manager::*;
+
+use snarkvm_console::prelude::Error;
+use wasm_bindgen::JsError;
+
+/// The network used by the browser API.
+type CurrentNetwork = snarkvm_console::network::MainnetV0;
+/// The circuit environment used by the browser API.
+type CurrentAleo = snarkvm_circuit_network::AleoV0;
+
+/// Converts the given error into a JavaScript `Error`.
+fn to_js_error(error: Error) -> JsError {
+    JsError::new(&error.to_string())
+}
@@ -0,0 +1,159 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{to_js_error, Account, CurrentAleo, CurrentNetwork};
+use snarkvm_console::{
+    prelude::*,
+    program::{Identifier, Locator, ProgramID},
+};
+use snarkvm_ledger_block::Execution;
+use snarkvm_ledger_query::Query;
+use snarkvm_ledger_store::helpers::memory::BlockMemory;
+use snarkvm_synthesizer::{
+    execution_finalize_cost,
+    prelude::{ProvingKey, VerifyingKey},
+    Authorization,
+    Process,
+    Program,
+};
+
+use js_sys::Promise;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::future_to_promise;
+
+/// Authorizes and proves program executions.
+///
+/// The manager starts with 'credits.aleo', and without any circuit keys. Keys that are not inserted
+/// with `insertProvingKey` are downloaded with a synchronous request when they are first needed,
+/// which is only permitted in a web worker. To prove on the main thread, fetch the keys
+/// asynchronously and insert them before proving.
+#[wasm_bindgen]
+pub struct ProgramManager {
+    process: Process<CurrentNetwork>,
+}
+
+#[wasm_bindgen]
+impl ProgramManager {
+    /// Initializes a new program manager.
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Result<ProgramManager, JsError> {
+        Ok(Self { process: Process::load_web().map_err(to_js_error)? })
+    }
+
+    /// Adds the given program, given as Aleo source code.
+    ///
+    /// The imports of the program must be added first.
+    #[wasm_bindgen(js_name = addProgram)]
+    pub fn add_program(&mut self, program: &str) -> Result<(), JsError> {
+        let program = Program::<CurrentNetwork>::from_str(program).map_err(to_js_error)?;
+        self.process.add_program(&program).map_err(to_js_error)
+    }
+
+    /// Returns `true` if the program with the given ID has been added.
+    #[wasm_bindgen(js_name = containsProgram)]
+    pub fn contains_program(&self, program_id: &str) -> Result<bool, JsError> {
+        Ok(self.process.contains_program(&ProgramID::from_str(program_id).map_err(to_js_error)?))
+    }
+
+    /// Inserts the proving key for the given function, from its byte representation.
+    #[wasm_bindgen(js_name = insertProvingKey)]
+    pub fn insert_proving_key(&self, program_id: &str, function_name: &str, proving_key: &[u8]) -> Result<(), JsError> {
+        let (program_id, function_name) = parse_function(program_id, function_name)?;
+        let proving_key = ProvingKey::<CurrentNetwork>::from_bytes_le(proving_key).map_err(to_js_error)?;
+        self.process.insert_proving_key(&program_id, &function_name, proving_key).map_err(to_js_error)
+    }
+
+    /// Inserts the verifying key for the given function, from its byte representation.
+    #[wasm_bindgen(js_name = insertVerifyingKey)]
+    pub fn insert_verifying_key(
+        &self,
+        program_id: &str,
+        function_name: &str,
+        verifying_key: &[u8],
+    ) -> Result<(), JsError> {
+        let (program_id, function_name) = parse_function(program_id, function_name)?;
+        let verifying_key = VerifyingKey::<CurrentNetwork>::from_bytes_le(verifying_key).map_err(to_js_error)?;
+        self.process.insert_verifying_key(&program_id, &function_name, verifying_key).map_err(to_js_error)
+    }
+
+    /// Authorizes a call to the given function with the given inputs, and returns the authorization.
+    pub fn authorize(
+        &self,
+        account: &Account,
+        program_id: &str,
+        function_name: &str,
+        inputs: Vec<JsValue>,
+    ) -> Result<String, JsError> {
+        let (program_id, function_name) = parse_function(program_id, function_name)?;
+        let inputs = inputs
+            .iter()
+            .map(|input| input.as_string().ok_or_else(|| JsError::new("Inputs must be strings")))
+            .collect::<Result<Vec<_>, _>>()?;
+        let authorization = self
+            .process
+            .authorize::<CurrentAleo, _>(
+                account.inner(),
+                program_id,
+                function_name,
+                inputs.iter().map(String::as_str),
+                &mut rand::thread_rng(),
+            )
+            .map_err(to_js_error)?;
+        Ok(authorization.to_string())
+    }
+
+    /// Executes the given authorization, and proves it against the state of the node at the given URL.
+    ///
+    /// Returns a promise that resolves to the execution.
+    #[wasm_bindgen(js_name = proveExecution)]
+    pub fn prove_execution(&self, authorization: &str, query: &str) -> Result<Promise, JsError> {
+        let authorization = Authorization::<CurrentNetwork>::from_str(authorization).map_err(to_js_error)?;
+        // Retrieve the locator of the main request.
+        let request = authorization.peek_next().map_err(to_js_error)?;
+        let locator = Locator::new(*request.program_id(), *request.function_name()).to_string();
+        // Execute the authorization.
+        let (_, mut trace) =
+            self.process.execute::<CurrentAleo, _>(authorization, &mut rand::thread_rng()).map_err(to_js_error)?;
+        // Prepare the trace against the node, and prove the execution.
+        let query = Query::<CurrentNetwork, BlockMemory<CurrentNetwork>>::from(query);
+        Ok(future_to_promise(async move {
+            trace.prepare_async(query).await.map_err(|error| JsValue::from(to_js_error(error)))?;
+            let execution = trace
+                .prove_execution::<CurrentAleo, _>(&locator, &mut rand::thread_rng())
+                .map_err(|error| JsValue::from(to_js_error(error)))?;
+            Ok(JsValue::from_str(&execution.to_string()))
+        }))
+    }
+
+    /// Returns the minimum fee in microcredits for the given execution.
+    ///
+    /// This is the storage cost of the execution, plus the cost of its finalize scopes.
+    #[wasm_bindgen(js_name = estimateFee)]
+    pub fn estimate_fee(&self, execution: &str) -> Result<u64, JsError> {
+        let execution = Execution::<CurrentNetwork>::from_str(execution).map_err(to_js_error)?;
+        let storage_cost = execution.size_in_bytes().map_err(to_js_error)?;
+        let finalize_cost = execution_finalize_cost(&self.process, &execution).map_err(to_js_error)?;
+        storage_cost.checked_add(finalize_cost).ok_or_else(|| JsError::new("The fee computation overflowed"))
+    }
+}
+
+/// Parses the given program ID and function name.
+fn parse_function(
+    program_id: &str,
+    function_name: &str,
+) -> Result<(ProgramID<CurrentNetwork>, Identifier<CurrentNetwork>), JsError> {
+    let program_id = ProgramID::from_str(program_id).map_err(to_js_error)?;
+    let function_name = Identifier::from_str(function_name).map_err(to_js_error)?;
+    Ok((program_id, function_name))
+}
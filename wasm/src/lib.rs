@@ -33,5 +33,8 @@ pub use snarkvm_synthesizer as synthesizer;
 #[cfg(feature = "utilities")]
 pub use snarkvm_utilities as utilities;
 
+#[cfg(feature = "browser")]
+pub mod browser;
+
 #[cfg(test)]
 mod tests;
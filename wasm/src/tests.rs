@@ -58,3 +58,23 @@ fn test_account_sign() {
         assert!(result, "Failed to execute signature verification");
     }
 }
+
+#[cfg(feature = "browser")]
+#[wasm_bindgen_test]
+fn test_browser_account() {
+    use crate::browser::Account;
+
+    const ALEO_PRIVATE_KEY: &str = "APrivateKey1zkp8cC4jgHEBnbtu3xxs1Ndja2EMizcvTRDq5Nikdkukg1p";
+    const ALEO_VIEW_KEY: &str = "AViewKey1n1n3ZbnVEtXVe3La2xWkUvY3EY7XaCG6RZJJ3tbvrrrD";
+    const ALEO_ADDRESS: &str = "aleo1wvgwnqvy46qq0zemj0k6sfp3zv0mp77rw97khvwuhac05yuwscxqmfyhwf";
+
+    let account = Account::from_private_key(ALEO_PRIVATE_KEY).unwrap();
+    assert_eq!(ALEO_PRIVATE_KEY, account.private_key());
+    assert_eq!(ALEO_VIEW_KEY, account.view_key());
+    assert_eq!(ALEO_ADDRESS, account.address());
+
+    // Sign and verify a message.
+    let signature = account.sign(b"hello world!").unwrap();
+    assert!(Account::verify(ALEO_ADDRESS, b"hello world!", &signature).unwrap());
+    assert!(!Account::verify(ALEO_ADDRESS, b"goodbye world!", &signature).unwrap());
+}